@@ -0,0 +1,35 @@
+// Auto exposure: averages the scene into a small target for luminance readback
+struct VSOut {
+  @builtin(position) pos: vec4<f32>,
+  @location(0) uv: vec2<f32>,
+};
+
+@group(0) @binding(0) var t_scene: texture_2d<f32>;
+@group(0) @binding(1) var s_scene: sampler;
+
+// Taps per axis inside each output texel's cell of the scene.
+const TAPS: i32 = 4;
+
+// One oversized triangle covers the whole target; no vertex buffer needed.
+@vertex
+fn vs_main(@builtin(vertex_index) index: u32) -> VSOut {
+  let uv = vec2<f32>(f32((index << 1u) & 2u), f32(index & 2u));
+  var out: VSOut;
+  out.pos = vec4<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0, 0.0, 1.0);
+  out.uv = uv;
+  return out;
+}
+
+@fragment
+fn fs_main(in: VSOut) -> @location(0) vec4<f32> {
+  let cell = fwidth(in.uv);
+  let origin = in.uv - cell * 0.5;
+  var sum = vec3<f32>(0.0);
+  for (var y = 0; y < TAPS; y = y + 1) {
+    for (var x = 0; x < TAPS; x = x + 1) {
+      let offset = (vec2<f32>(f32(x), f32(y)) + 0.5) / f32(TAPS);
+      sum = sum + textureSampleLevel(t_scene, s_scene, origin + cell * offset, 0.0).rgb;
+    }
+  }
+  return vec4<f32>(sum / f32(TAPS * TAPS), 1.0);
+}
@@ -0,0 +1,29 @@
+// Debug line shader
+struct Globals {
+  proj: mat4x4<f32>,
+};
+
+struct VSOut {
+  @builtin(position) pos: vec4<f32>,
+  @location(0) color: vec4<f32>,
+};
+
+@group(0) @binding(0) var<uniform> u_globals: Globals;
+
+struct VIn {
+  @location(0) pos: vec3<f32>,
+  @location(1) color: vec4<f32>,
+};
+
+@vertex
+fn vs_main(v: VIn) -> VSOut {
+  var out: VSOut;
+  out.pos = u_globals.proj * vec4<f32>(v.pos, 1.0);
+  out.color = v.color;
+  return out;
+}
+
+@fragment
+fn fs_main(in: VSOut) -> @location(0) vec4<f32> {
+  return in.color;
+}
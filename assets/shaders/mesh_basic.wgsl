@@ -28,7 +28,7 @@ struct SkinPalette {
 struct MaterialUniform {
     base_color_factor : vec4<f32>,
    emissive_factor : vec4<f32>,
-    params : vec4<f32>,
+    params : vec4<f32>, // metallic, roughness, normal_scale, use_vertex_colors
     texture_flags : vec4<f32>,
 }
 
@@ -41,6 +41,8 @@ struct ShadowUniform {
 struct PointLight {
     position_radius : vec4<f32>,
     color_intensity : vec4<f32>,
+    // x: falloff mode (0 inverse square, 1 smoothstep, 2 linear)
+    params : vec4<u32>,
 }
 
 struct ClusterRecord {
@@ -124,6 +126,7 @@ struct VertexIn {
     @location(3) uv : vec2<f32>,
     @location(4) joints : vec4<u32>,
     @location(5) weights : vec4<f32>,
+    @location(6) color : vec4<f32>,
 }
 
 struct VertexOut {
@@ -134,6 +137,7 @@ struct VertexOut {
     @location(3) uv : vec2<f32>,
     @location(4) clip_pos : vec4<f32>,
     @location(5) view_pos : vec3<f32>,
+    @location(6) color : vec4<f32>,
 }
 
 fn identity_matrix() -> mat4x4<f32> {
@@ -198,9 +202,18 @@ fn vs_main(input : VertexIn) -> VertexOut {
     out.uv = input.uv;
     out.clip_pos = clip_position;
     out.view_pos = (frame.view * world_pos).xyz;
+    out.color = input.color;
     return out;
 }
 
+// Rotates an environment lookup about +Y by exposure_params.w turns (the equirect U scroll offset).
+fn environment_direction(dir : vec3<f32>) -> vec3<f32> {
+    let angle = frame.exposure_params.w * 6.28318530718;
+    let c = cos(angle);
+    let s = sin(angle);
+    return vec3<f32>(c * dir.x - s * dir.z, dir.y, s * dir.x + c * dir.z);
+}
+
 fn fresnel_schlick(cos_theta : f32, f0 : vec3<f32>) -> vec3<f32> {
     return f0 + (vec3<f32>(1.0) - f0) * pow(1.0 - cos_theta, 5.0);
 }
@@ -323,7 +336,11 @@ fn fs_main(input : VertexOut) -> @location(0) vec4<f32> {
     let base_sample = textureSample(base_color_tex, material_sampler, input.uv);
     let material_color = material.base_color_factor;
     var base_color = draw.base_color.xyz * material_color.xyz * base_sample.xyz;
-    let base_alpha = clamp(base_sample.w * material_color.w, 0.0, 1.0);
+    var base_alpha = clamp(base_sample.w * material_color.w, 0.0, 1.0);
+    if (material.params.w > 0.5) {
+        base_color = base_color * input.color.xyz;
+        base_alpha = base_alpha * clamp(input.color.w, 0.0, 1.0);
+    }
 
     var metallic = material.params.x;
     var roughness = material.params.y;
@@ -379,13 +396,13 @@ fn fs_main(input : VertexOut) -> @location(0) vec4<f32> {
     if (env_intensity > 0.0001) {
         let mip_count = max(frame.exposure_params.y, 1.0);
         let max_mip = max(mip_count - 1.0, 0.0);
-        let irradiance = textureSample(diffuse_env, env_sampler, N).xyz;
+        let irradiance = textureSample(diffuse_env, env_sampler, environment_direction(N)).xyz;
         let ks = fresnel_schlick(n_dot_v, f0);
         let kd = (vec3<f32>(1.0) - ks) * (1.0 - metallic);
         let diffuse_ibl = irradiance * base_color * kd;
         let R = reflect(-V, N);
         let lod = roughness * max_mip;
-        let prefiltered = textureSampleLevel(specular_env, env_sampler, R, lod).xyz;
+        let prefiltered = textureSampleLevel(specular_env, env_sampler, environment_direction(R), lod).xyz;
         let brdf_sample = textureSample(brdf_lut, env_sampler, vec2<f32>(n_dot_v, roughness)).xy;
         let specular_ibl = prefiltered * (ks * brdf_sample.x + brdf_sample.y);
         color = color + (diffuse_ibl + specular_ibl) * env_intensity;
@@ -446,6 +463,22 @@ fn load_cluster_light_index(index : u32) -> u32 {
     return (packed >> 16u) & 0xFFFFu;
 }
 
+// `t` is distance over radius; modes match `PointLightFalloff::gpu_mode`.
+fn point_light_attenuation(t : f32, mode : u32) -> f32 {
+    let fade = clamp(1.0 - t, 0.0, 1.0);
+    switch mode {
+        case 1u: {
+            return fade * fade * (3.0 - 2.0 * fade);
+        }
+        case 2u: {
+            return fade;
+        }
+        default: {
+            return fade * fade;
+        }
+    }
+}
+
 fn shade_point_light(
     world_pos : vec3<f32>,
     normal : vec3<f32>,
@@ -462,7 +495,7 @@ fn shade_point_light(
     if dist <= 0.001 || dist > radius {
         return vec3<f32>(0.0);
     }
-    let attenuation = pow(max(1.0 - dist / radius, 0.0), 2.0);
+    let attenuation = point_light_attenuation(dist / radius, light.params.x);
     if attenuation <= 0.0 {
         return vec3<f32>(0.0);
     }
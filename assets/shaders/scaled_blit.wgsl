@@ -0,0 +1,23 @@
+// Render scale upscale/downscale blit
+struct VSOut {
+  @builtin(position) pos: vec4<f32>,
+  @location(0) uv: vec2<f32>,
+};
+
+@group(0) @binding(0) var t_scene: texture_2d<f32>;
+@group(0) @binding(1) var s_scene: sampler;
+
+// One oversized triangle covers the whole target; no vertex buffer needed.
+@vertex
+fn vs_main(@builtin(vertex_index) index: u32) -> VSOut {
+  let uv = vec2<f32>(f32((index << 1u) & 2u), f32(index & 2u));
+  var out: VSOut;
+  out.pos = vec4<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0, 0.0, 1.0);
+  out.uv = uv;
+  return out;
+}
+
+@fragment
+fn fs_main(in: VSOut) -> @location(0) vec4<f32> {
+  return textureSample(t_scene, s_scene, in.uv);
+}
@@ -1,12 +1,16 @@
 // Batched sprite shader
 struct Globals {
   proj: mat4x4<f32>,
+  // xyz: directional light direction (pointing away from the light), w: normal-map ambient term.
+  light: vec4<f32>,
 };
 
 struct VSOut {
   @builtin(position) pos: vec4<f32>,
   @location(0) uv: vec2<f32>,
   @location(1) color: vec4<f32>,
+  @location(2) tangent: vec2<f32>,
+  @location(3) bitangent: vec2<f32>,
 };
 
 @group(0) @binding(0) var<uniform> u_globals: Globals;
@@ -24,6 +28,28 @@ struct IIn {
 };
 @group(1) @binding(0) var t_atlas: texture_2d<f32>;
 @group(1) @binding(1) var s_linear: sampler;
+// Tangent-space normal map laid out like the atlas. Alpha is the lighting weight, so the flat
+// fallback texel (alpha 0) leaves sprites without a normal map untouched.
+@group(1) @binding(2) var t_normal: texture_2d<f32>;
+
+// Set by the renderer for `sprites.color_space = "linear"`: tints are authored in sRGB and decoded
+// before modulating the (already linear) atlas texel.
+override LINEAR_TINT: bool = false;
+// Set alongside LINEAR_TINT when the render target is not sRGB and the output must be encoded here.
+override ENCODE_OUTPUT: bool = false;
+
+fn srgb_to_linear(c: vec3<f32>) -> vec3<f32> {
+  let low = c / 12.92;
+  let high = pow((c + vec3<f32>(0.055)) / 1.055, vec3<f32>(2.4));
+  return select(high, low, c <= vec3<f32>(0.04045));
+}
+
+fn linear_to_srgb(c: vec3<f32>) -> vec3<f32> {
+  let low = c * 12.92;
+  let high = 1.055 * pow(max(c, vec3<f32>(0.0)), vec3<f32>(1.0 / 2.4)) - vec3<f32>(0.055);
+  return select(high, low, c <= vec3<f32>(0.0031308));
+}
+
 @vertex
 fn vs_main(v: VIn, i: IIn) -> VSOut {
   let dx = v.pos.x;
@@ -38,9 +64,34 @@ fn vs_main(v: VIn, i: IIn) -> VSOut {
   out.pos = u_globals.proj * world;
   out.uv = vec2<f32>(mix(i.uv_rect.x, i.uv_rect.z, v.uv.x), mix(i.uv_rect.y, i.uv_rect.w, v.uv.y));
   out.color = i.tint;
+  out.tangent = i.axis_x.xy / max(length(i.axis_x.xy), 1e-6);
+  out.bitangent = i.axis_y.xy / max(length(i.axis_y.xy), 1e-6);
   return out;
 }
+
+fn normal_shade(input: VSOut, normal_texel: vec4<f32>) -> f32 {
+  let n = normal_texel.xyz * 2.0 - vec3<f32>(1.0);
+  let bent = n.x * input.tangent + n.y * input.bitangent;
+  let world_n = normalize(vec3<f32>(bent, n.z) + vec3<f32>(0.0, 0.0, 1e-6));
+  // Sprites face the camera, so light arriving from behind the sprite plane is folded to the front.
+  let d = u_globals.light.xyz;
+  let l = normalize(vec3<f32>(-d.xy, abs(d.z)) + vec3<f32>(0.0, 0.0, 1e-6));
+  let ambient = u_globals.light.w;
+  let shade = ambient + (1.0 - ambient) * max(dot(world_n, l), 0.0);
+  return mix(1.0, shade, normal_texel.a);
+}
+
 @fragment
 fn fs_main(input: VSOut) -> @location(0) vec4<f32> {
-  return textureSample(t_atlas, s_linear, input.uv) * input.color;
+  let albedo = textureSample(t_atlas, s_linear, input.uv);
+  let normal_texel = textureSample(t_normal, s_linear, input.uv);
+  let texel = vec4<f32>(albedo.rgb * normal_shade(input, normal_texel), albedo.a);
+  if (!LINEAR_TINT) {
+    return texel * input.color;
+  }
+  var color = texel * vec4<f32>(srgb_to_linear(input.color.rgb), input.color.a);
+  if (ENCODE_OUTPUT) {
+    color = vec4<f32>(linear_to_srgb(color.rgb), color.a);
+  }
+  return color;
 }
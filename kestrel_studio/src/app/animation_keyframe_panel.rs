@@ -1,7 +1,8 @@
-use crate::assets::ClipInterpolation;
+use crate::assets::{ClipInterpolation, ClipKeyframe};
 use crate::ecs::AnimationTime;
+use crate::tr;
 use bevy_ecs::prelude::Entity;
-use egui::{self, pos2, Color32, FontId, Id, Key, Modifiers, Rect, Sense, Stroke, Ui};
+use egui::{self, pos2, Color32, FontId, Id, Key, Modifiers, Pos2, Rect, Sense, Stroke, Ui};
 use std::cmp::Ordering;
 use std::collections::BTreeSet;
 
@@ -45,6 +46,18 @@ pub enum AnimationTrackKind {
     Tint,
 }
 
+impl AnimationTrackKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            AnimationTrackKind::SpriteTimeline => "Sprite",
+            AnimationTrackKind::Translation => "Translation",
+            AnimationTrackKind::Rotation => "Rotation",
+            AnimationTrackKind::Scale => "Scale",
+            AnimationTrackKind::Tint => "Tint",
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Default)]
 pub enum KeyframeValue {
     #[default]
@@ -78,6 +91,30 @@ impl KeyframeValue {
             None
         }
     }
+
+    /// Components in channel order; empty for `None`.
+    pub fn components(&self) -> &[f32] {
+        match self {
+            KeyframeValue::None => &[],
+            KeyframeValue::Scalar(value) => std::slice::from_ref(value),
+            KeyframeValue::Vec2(value) => value,
+            KeyframeValue::Vec4(value) => value,
+        }
+    }
+
+    /// The same variant with each component replaced by `map(component_index, component)`.
+    pub fn map_components(self, mut map: impl FnMut(usize, f32) -> f32) -> KeyframeValue {
+        match self {
+            KeyframeValue::None => KeyframeValue::None,
+            KeyframeValue::Scalar(value) => KeyframeValue::Scalar(map(0, value)),
+            KeyframeValue::Vec2(value) => KeyframeValue::Vec2(std::array::from_fn(|i| map(i, value[i]))),
+            KeyframeValue::Vec4(value) => KeyframeValue::Vec4(std::array::from_fn(|i| map(i, value[i]))),
+        }
+    }
+
+    fn authored(self) -> Option<KeyframeValue> {
+        (self != KeyframeValue::None).then_some(self)
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -113,10 +150,48 @@ pub enum AnimationPanelCommand {
         time_delta: Option<f32>,
         value_delta: Option<KeyframeValue>,
     },
+    /// Replace both tangents of one key; `None` makes that side of the key linear.
+    SetKeyTangents {
+        binding: AnimationTrackBinding,
+        index: usize,
+        in_tangent: Option<KeyframeValue>,
+        out_tangent: Option<KeyframeValue>,
+    },
+    /// Scale keys in time about `time_pivot` and, when `value_pivot` is set, in value about it.
+    ScaleKeys {
+        binding: AnimationTrackBinding,
+        indices: Vec<usize>,
+        time_pivot: f32,
+        time_scale: f32,
+        value_pivot: Option<KeyframeValue>,
+        value_scale: f32,
+    },
+    /// Replace the editor clipboard with keys copied from one track.
+    CopyKeys {
+        clipboard: AnimationKeyClipboard,
+    },
+    /// Insert the clipboard keys into the bound track, the earliest one landing at `time`.
+    PasteKeys {
+        binding: AnimationTrackBinding,
+        time: f32,
+    },
     Undo,
     Redo,
 }
 
+/// Keys copied from a single track, timed relative to the earliest copied key.
+#[derive(Clone, Debug)]
+pub struct AnimationKeyClipboard {
+    pub kind: AnimationTrackKind,
+    pub entries: Vec<AnimationKeyClipboardEntry>,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct AnimationKeyClipboardEntry {
+    pub time_offset: f32,
+    pub value: KeyframeValue,
+}
+
 /// Lightweight summary for each animation track shown in the panel.
 #[derive(Clone)]
 pub struct AnimationTrackSummary {
@@ -139,6 +214,9 @@ pub struct KeyframeDetail {
     pub time: Option<f32>,
     pub value_preview: Option<String>,
     pub value: KeyframeValue,
+    /// Authored tangents in value per second; `None` where that side of the key is linear.
+    pub in_tangent: KeyframeValue,
+    pub out_tangent: KeyframeValue,
 }
 
 /// Snapshot of editor state passed into the panel each frame.
@@ -149,6 +227,7 @@ pub struct AnimationKeyframePanelState<'a> {
     pub can_undo: bool,
     pub can_redo: bool,
     pub status_message: Option<String>,
+    pub clipboard: Option<AnimationKeyClipboard>,
 }
 
 #[derive(Clone, Debug)]
@@ -177,16 +256,187 @@ impl KeyDragState {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
-struct KeyClipboardEntry {
-    time_offset: f32,
-    value: KeyframeValue,
+/// Screen length of a tangent handle, independent of the tangent's magnitude.
+const CURVE_HANDLE_LENGTH: f32 = 36.0;
+/// Points plotted per curved segment.
+const CURVE_SEGMENT_STEPS: usize = 24;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+enum KeyframePanelView {
+    #[default]
+    DopeSheet,
+    Curves,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TangentSide {
+    In,
+    Out,
 }
 
 #[derive(Clone, Debug)]
-struct KeyClipboard {
-    kind: AnimationTrackKind,
-    entries: Vec<KeyClipboardEntry>,
+enum CurveDragState {
+    /// Moves keys in time and along the dragged component's value.
+    Keys {
+        binding: AnimationTrackBinding,
+        track_id: AnimationTrackId,
+        indices: Vec<usize>,
+        component: usize,
+        anchor_time: f32,
+        anchor_value: KeyframeValue,
+        time_delta: f32,
+        value_delta: f32,
+    },
+    /// Bends one key's tangent; both sides follow unless the handles are broken with Alt.
+    Tangent {
+        binding: AnimationTrackBinding,
+        track_id: AnimationTrackId,
+        index: usize,
+        component: usize,
+        side: TangentSide,
+        slope: f32,
+        broken: bool,
+        /// Tangents the key was drawn with when the drag began, per component.
+        start_in: KeyframeValue,
+        start_out: KeyframeValue,
+        authored_in: Option<KeyframeValue>,
+        authored_out: Option<KeyframeValue>,
+    },
+    BoxSelect {
+        origin: Pos2,
+        current: Pos2,
+    },
+}
+
+impl CurveDragState {
+    fn preview(&self, key: &mut CurveKey) {
+        match self {
+            CurveDragState::Keys { track_id, indices, component, time_delta, value_delta, .. } => {
+                if key.id.track == *track_id && indices.contains(&key.index) {
+                    key.time = (key.time + time_delta).max(0.0);
+                    key.value =
+                        key.value.map_components(|i, v| if i == *component { v + value_delta } else { v });
+                }
+            }
+            CurveDragState::Tangent { track_id, index, .. } => {
+                if key.id.track == *track_id && key.index == *index {
+                    (key.in_tangent, key.out_tangent) = self.tangents().unwrap_or_default();
+                }
+            }
+            CurveDragState::BoxSelect { .. } => {}
+        }
+    }
+
+    /// In/out tangents a tangent drag would commit.
+    fn tangents(&self) -> Option<(Option<KeyframeValue>, Option<KeyframeValue>)> {
+        let CurveDragState::Tangent {
+            component,
+            side,
+            slope,
+            broken,
+            start_in,
+            start_out,
+            authored_in,
+            authored_out,
+            ..
+        } = self
+        else {
+            return None;
+        };
+        let bend = |value: KeyframeValue| {
+            Some(value.map_components(|i, v| if i == *component { *slope } else { v }))
+        };
+        Some(match (broken, side) {
+            (false, _) => (bend(*start_in), bend(*start_out)),
+            (true, TangentSide::In) => (bend(authored_in.unwrap_or(*start_in)), *authored_out),
+            (true, TangentSide::Out) => (*authored_in, bend(authored_out.unwrap_or(*start_out))),
+        })
+    }
+}
+
+/// A key as plotted in the curve view, with any in-progress drag applied.
+#[derive(Clone, Debug)]
+struct CurveKey {
+    id: KeyframeId,
+    index: usize,
+    time: f32,
+    value: KeyframeValue,
+    in_tangent: Option<KeyframeValue>,
+    out_tangent: Option<KeyframeValue>,
+}
+
+impl CurveKey {
+    fn keyframe(&self, component: usize) -> ClipKeyframe<f32> {
+        let part = |value: Option<KeyframeValue>| value.and_then(|v| v.components().get(component).copied());
+        ClipKeyframe {
+            time: self.time,
+            value: self.value.components().get(component).copied().unwrap_or(0.0),
+            in_tangent: part(self.in_tangent),
+            out_tangent: part(self.out_tangent),
+        }
+    }
+}
+
+/// Maps between clip time/value and screen space for the curve view.
+#[derive(Clone, Copy, Debug)]
+struct CurvePlot {
+    rect: Rect,
+    duration: f32,
+    min: f32,
+    max: f32,
+}
+
+impl CurvePlot {
+    /// Screen pixels per second (x) and per value unit (y).
+    fn scale(&self) -> egui::Vec2 {
+        let range = (self.max - self.min).max(1e-6);
+        egui::vec2(self.rect.width() / self.duration.max(1e-3), self.rect.height() / range)
+    }
+
+    fn pos(&self, time: f32, value: f32) -> Pos2 {
+        let scale = self.scale();
+        pos2(self.rect.left() + time * scale.x, self.rect.bottom() - (value - self.min) * scale.y)
+    }
+
+    fn time_at(&self, x: f32) -> f32 {
+        (x - self.rect.left()) / self.scale().x
+    }
+
+    fn value_at(&self, y: f32) -> f32 {
+        self.min + (self.rect.bottom() - y) / self.scale().y
+    }
+
+    /// Slope, in value per second, of a handle at `offset` from its key. Handles stay on their own
+    /// side of the key so the slope never flips through vertical.
+    fn slope_from_offset(&self, offset: egui::Vec2, side: TangentSide) -> f32 {
+        let dx = match side {
+            TangentSide::In => offset.x.min(-1.0),
+            TangentSide::Out => offset.x.max(1.0),
+        };
+        let scale = self.scale();
+        (-offset.y / scale.y) / (dx / scale.x)
+    }
+
+    fn handle_offset(&self, slope: f32, side: TangentSide) -> egui::Vec2 {
+        let scale = self.scale();
+        let direction = egui::vec2(scale.x, -slope * scale.y).normalized() * CURVE_HANDLE_LENGTH;
+        match side {
+            TangentSide::In => -direction,
+            TangentSide::Out => direction,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct CurveScaleInput {
+    time: f32,
+    value: f32,
+}
+
+impl Default for CurveScaleInput {
+    fn default() -> Self {
+        Self { time: 1.0, value: 1.0 }
+    }
 }
 
 #[derive(Default)]
@@ -205,7 +455,11 @@ pub struct AnimationKeyframePanel {
     multi_vec2_offset: [f32; 2],
     multi_vec4_offset: [f32; 4],
     key_drag: Option<KeyDragState>,
-    clipboard: Option<KeyClipboard>,
+    view: KeyframePanelView,
+    curve_drag: Option<CurveDragState>,
+    /// Value range held while a curve drag is in progress so the plot does not rescale under it.
+    curve_value_range: Option<(f32, f32)>,
+    curve_scale: CurveScaleInput,
 }
 
 impl AnimationKeyframePanel {
@@ -223,7 +477,8 @@ impl AnimationKeyframePanel {
 
     pub fn render_window(&mut self, ctx: &egui::Context, state: AnimationKeyframePanelState<'_>) {
         let mut open = self.open;
-        egui::Window::new("Keyframe Editor (Milestone 5)")
+        egui::Window::new(tr!("window.keyframe_editor"))
+            .id(Id::new("window.keyframe_editor"))
             .open(&mut open)
             .default_width(480.0)
             .min_height(320.0)
@@ -234,42 +489,59 @@ impl AnimationKeyframePanel {
     }
 
     fn render_contents(&mut self, ui: &mut Ui, state: &AnimationKeyframePanelState<'_>) {
-        ui.heading("Keyframe Timeline");
+        ui.heading(tr!("keyframe.keyframe_timeline"));
         if let Some(status) = &state.status_message {
             ui.small(status);
         }
         match state.selected_entity {
             Some(entity) => {
-                ui.label(format!("Entity ID {}", entity.index()));
+                ui.label(tr!("keyframe.entity_id", index = entity.index()));
             }
             None => {
-                ui.label("Select an entity to inspect its animation clips.");
+                ui.label(tr!("keyframe.select_an_entity_to_inspect_its"));
                 return;
             }
         }
         ui.horizontal(|ui| {
-            ui.label("Track filter:");
+            ui.label(tr!("keyframe.track_filter"));
             let response = ui.text_edit_singleline(&mut self.track_filter);
             if response.changed() && self.track_filter.is_empty() {
                 self.selected_tracks.clear();
                 self.clear_key_selection();
             }
-            if ui.button("Clear").clicked() {
+            if ui.button(tr!("keyframe.clear")).clicked() {
                 self.track_filter.clear();
             }
-            if ui.add_enabled(state.can_undo, egui::Button::new("Undo")).clicked() {
+            if ui.add_enabled(state.can_undo, egui::Button::new(tr!("keyframe.undo"))).clicked() {
                 self.pending_commands.push(AnimationPanelCommand::Undo);
             }
-            if ui.add_enabled(state.can_redo, egui::Button::new("Redo")).clicked() {
+            if ui.add_enabled(state.can_redo, egui::Button::new(tr!("keyframe.redo"))).clicked() {
                 self.pending_commands.push(AnimationPanelCommand::Redo);
             }
         });
         ui.add_space(4.0);
         let filtered_tracks = self.filtered_tracks(state);
         self.reconcile_selection(&filtered_tracks);
-        self.handle_keyboard_shortcuts(ui, &filtered_tracks, state.can_undo, state.can_redo);
+        self.handle_keyboard_shortcuts(ui, &filtered_tracks, state);
+        ui.horizontal(|ui| {
+            let can_copy = !self.selected_keys.is_empty();
+            if ui.add_enabled(can_copy, egui::Button::new(tr!("keyframe.copy_keys"))).clicked() {
+                self.copy_selected_keys(&filtered_tracks);
+            }
+            let can_paste = state.clipboard.is_some() && !self.selected_tracks.is_empty();
+            if ui.add_enabled(can_paste, egui::Button::new(tr!("keyframe.paste_at_playhead"))).clicked() {
+                self.paste_clipboard_keys(&filtered_tracks, state.clipboard.as_ref());
+            }
+            if let Some(clipboard) = &state.clipboard {
+                ui.small(tr!(
+                    "keyframe.clipboard_key_s",
+                    entries = clipboard.entries.len(),
+                    kind = clipboard.kind.label()
+                ));
+            }
+        });
         if filtered_tracks.is_empty() {
-            ui.label("No animation tracks match the current filter.");
+            ui.label(tr!("keyframe.no_animation_tracks_match_the_current"));
             return;
         }
         let max_duration =
@@ -281,7 +553,7 @@ impl AnimationKeyframePanel {
         }
         self.scrub_time = self.scrub_time.clamp(0.0, self.visible_duration);
         ui.horizontal(|ui| {
-            ui.label("Scrub");
+            ui.label(tr!("keyframe.scrub"));
             let scrub_label = format!("{:.2}s / {:.2}s", self.scrub_time, self.visible_duration);
             if ui
                 .add(egui::Slider::new(&mut self.scrub_time, 0.0..=self.visible_duration).text(scrub_label))
@@ -289,39 +561,53 @@ impl AnimationKeyframePanel {
             {
                 self.queue_scrub_for_selection(&filtered_tracks);
             }
-            if ui.button("Reset").clicked() {
+            if ui.button(tr!("keyframe.reset")).clicked() {
                 self.scrub_time = 0.0;
                 self.queue_scrub_for_selection(&filtered_tracks);
             }
         });
         ui.separator();
+        ui.horizontal(|ui| {
+            ui.selectable_value(&mut self.view, KeyframePanelView::DopeSheet, tr!("keyframe.dope_sheet"));
+            ui.selectable_value(&mut self.view, KeyframePanelView::Curves, tr!("keyframe.curves"));
+        });
         ui.horizontal(|ui| {
             let track_area_height = (filtered_tracks.len() as f32 * 40.0 + 80.0).clamp(240.0, 560.0);
             ui.set_height(track_area_height);
             ui.vertical(|ui| {
                 ui.set_min_width(220.0);
-                ui.strong("Tracks");
+                ui.strong(tr!("keyframe.tracks"));
                 self.render_track_list(ui, &filtered_tracks);
             });
             ui.separator();
-            ui.vertical(|ui| {
-                ui.strong("Timeline");
-                self.render_timeline(ui, &filtered_tracks);
+            ui.vertical(|ui| match self.view {
+                KeyframePanelView::DopeSheet => {
+                    ui.strong(tr!("keyframe.timeline"));
+                    self.render_timeline(ui, &filtered_tracks);
+                }
+                KeyframePanelView::Curves => {
+                    ui.strong(tr!("keyframe.curves"));
+                    self.render_curves(ui, &filtered_tracks);
+                }
             });
         });
         ui.separator();
         self.render_selection_overview(ui, &filtered_tracks);
         ui.separator();
-        ui.label(format!(
-            "Animation clock: scale {:.2}, paused {}, fixed_step {:?}",
-            state.animation_time.scale, state.animation_time.paused, state.animation_time.fixed_step
+        ui.label(tr!(
+            "keyframe.animation_clock_scale_paused_fixed",
+            scale = format!("{:.2}", state.animation_time.scale),
+            paused = state.animation_time.paused,
+            fixed_step = format!("{:?}", state.animation_time.fixed_step)
         ));
         if !state.animation_time.group_scales.is_empty() {
-            egui::CollapsingHeader::new("Per-group Scale Overrides").show(ui, |ui| {
-                for (group, scale) in state.animation_time.group_scales.iter() {
-                    ui.label(format!("{group}: {scale:.2}x"));
-                }
-            });
+            egui::CollapsingHeader::new(tr!("keyframe.per_group_scale_overrides"))
+                .id_salt("keyframe.per_group_scale_overrides")
+                .show(ui, |ui| {
+                    for (group, scale) in state.animation_time.group_scales.iter() {
+                        ui.label(format!("{group}: {scale:.2}x"));
+                    }
+                });
         }
     }
 
@@ -345,8 +631,7 @@ impl AnimationKeyframePanel {
         &mut self,
         ui: &Ui,
         tracks: &[&AnimationTrackSummary],
-        can_undo: bool,
-        can_redo: bool,
+        state: &AnimationKeyframePanelState<'_>,
     ) {
         if tracks.is_empty() {
             return;
@@ -366,12 +651,12 @@ impl AnimationKeyframePanel {
             self.copy_selected_keys(tracks);
         }
         if paste_requested {
-            self.paste_clipboard_keys(tracks);
+            self.paste_clipboard_keys(tracks, state.clipboard.as_ref());
         }
-        if undo_requested && can_undo {
+        if undo_requested && state.can_undo {
             self.pending_commands.push(AnimationPanelCommand::Undo);
         }
-        if redo_requested && can_redo {
+        if redo_requested && state.can_redo {
             self.pending_commands.push(AnimationPanelCommand::Redo);
         }
     }
@@ -394,6 +679,14 @@ impl AnimationKeyframePanel {
                 self.key_drag = None;
             }
         }
+        if let Some(CurveDragState::Keys { track_id, .. } | CurveDragState::Tangent { track_id, .. }) =
+            &self.curve_drag
+        {
+            if !valid_tracks.contains(track_id) {
+                self.curve_drag = None;
+                self.curve_value_range = None;
+            }
+        }
         if let Some(last_selected) = self.last_selected_track {
             if !valid_tracks.contains(&last_selected) {
                 self.last_selected_track = None;
@@ -428,7 +721,7 @@ impl AnimationKeyframePanel {
                 let text = format!(
                     "{} ({}){}\n{} keys | {:.2}s",
                     summary.label,
-                    self.track_kind_label(summary.kind),
+                    summary.kind.label(),
                     dirty_suffix,
                     summary.key_count,
                     summary.duration
@@ -621,6 +914,454 @@ impl AnimationKeyframePanel {
         response.on_hover_text(format!("{} #{} @ {:.3}s\n{}", summary.label, detail.index, time, preview));
     }
 
+    fn render_curves(&mut self, ui: &mut Ui, tracks: &[&AnimationTrackSummary]) {
+        let Some(summary) = self.primary_selected_track_summary(tracks) else {
+            ui.label(tr!("keyframe.select_a_track_to_plot_its"));
+            return;
+        };
+        if !self.can_edit_track(summary.kind) {
+            ui.label(tr!("keyframe.sprite_timelines_have_no_value_curves"));
+            return;
+        }
+        let keys = self.curve_keys(summary);
+        self.render_curve_tools(ui, summary, &keys);
+        let component_count = keys.first().map_or(0, |key| key.value.components().len());
+        let step = matches!(summary.interpolation, Some(ClipInterpolation::Step));
+        let polylines: Vec<Vec<(f32, f32)>> =
+            (0..component_count).map(|component| curve_polyline(&keys, component, step)).collect();
+        let (min, max) = self.curve_value_range.unwrap_or_else(|| curve_value_range(&polylines));
+        let desired_size = egui::vec2(ui.available_width(), ui.available_height().max(180.0));
+        let (response, painter) = ui.allocate_painter(desired_size, Sense::click_and_drag());
+        let canvas = response.rect;
+        let plot = CurvePlot {
+            rect: canvas.shrink2(egui::vec2(6.0, 10.0)),
+            duration: self.visible_duration.max(0.001),
+            min,
+            max,
+        };
+        let painter = painter.with_clip_rect(canvas);
+        painter.rect_filled(canvas, 4.0, ui.visuals().extreme_bg_color);
+        if min < 0.0 && max > 0.0 {
+            let zero_y = plot.pos(0.0, 0.0).y;
+            painter.line_segment(
+                [pos2(canvas.left(), zero_y), pos2(canvas.right(), zero_y)],
+                Stroke::new(1.0, Color32::from_gray(70)),
+            );
+        }
+        let scrub_x = plot.pos(self.scrub_time, 0.0).x;
+        painter.line_segment(
+            [pos2(scrub_x, canvas.top()), pos2(scrub_x, canvas.bottom())],
+            Stroke::new(2.0, Color32::from_rgb(255, 196, 94)),
+        );
+        self.handle_curve_background(ui, &response, plot, summary, &keys);
+        for (component, points) in polylines.iter().enumerate() {
+            let points = points.iter().map(|&(time, value)| plot.pos(time, value)).collect();
+            painter.add(egui::Shape::line(points, Stroke::new(1.5, curve_component_color(component))));
+        }
+        for position in 0..keys.len() {
+            for component in 0..component_count {
+                self.draw_curve_key(ui, &painter, plot, summary, &keys, position, component, step);
+            }
+        }
+        if let Some(CurveDragState::BoxSelect { origin, current }) = &self.curve_drag {
+            let area = Rect::from_two_pos(*origin, *current);
+            painter.rect_filled(area, 0.0, Color32::from_rgba_unmultiplied(110, 170, 255, 32));
+            painter.rect_stroke(
+                area,
+                0.0,
+                Stroke::new(1.0, Color32::from_rgb(110, 170, 255)),
+                egui::StrokeKind::Inside,
+            );
+        }
+        ui.small(tr!("keyframe.drag_keys_to_move_them_handles"));
+    }
+
+    fn render_curve_tools(&mut self, ui: &mut Ui, summary: &AnimationTrackSummary, keys: &[CurveKey]) {
+        let selected: Vec<&CurveKey> =
+            keys.iter().filter(|key| self.selected_keys.contains(&key.id)).collect();
+        let any_selected = !selected.is_empty();
+        ui.horizontal(|ui| {
+            let ease = ui
+                .add_enabled(any_selected, egui::Button::new(tr!("keyframe.ease")))
+                .on_hover_text(tr!("keyframe.ease_hint"));
+            if ease.clicked() {
+                for key in &selected {
+                    let flat = Some(key.value.map_components(|_, _| 0.0));
+                    self.pending_commands.push(AnimationPanelCommand::SetKeyTangents {
+                        binding: summary.binding,
+                        index: key.index,
+                        in_tangent: flat,
+                        out_tangent: flat,
+                    });
+                }
+            }
+            let linear = ui
+                .add_enabled(any_selected, egui::Button::new(tr!("keyframe.linear")))
+                .on_hover_text(tr!("keyframe.linear_hint"));
+            if linear.clicked() {
+                for key in &selected {
+                    self.pending_commands.push(AnimationPanelCommand::SetKeyTangents {
+                        binding: summary.binding,
+                        index: key.index,
+                        in_tangent: None,
+                        out_tangent: None,
+                    });
+                }
+            }
+            ui.separator();
+            ui.label(tr!("keyframe.scale_time"));
+            ui.add(egui::DragValue::new(&mut self.curve_scale.time).speed(0.01).range(0.01..=100.0));
+            ui.label(tr!("keyframe.value"));
+            ui.add(egui::DragValue::new(&mut self.curve_scale.value).speed(0.01));
+            if ui.add_enabled(any_selected, egui::Button::new(tr!("keyframe.apply_scale"))).clicked() {
+                self.queue_scale_for_keys(summary, &selected);
+            }
+        });
+    }
+
+    /// Scales about the earliest selected key in time and the selection's mean value.
+    fn queue_scale_for_keys(&mut self, summary: &AnimationTrackSummary, keys: &[&CurveKey]) {
+        let scale = self.curve_scale;
+        let time_pivot = keys.iter().map(|key| key.time).fold(f32::INFINITY, f32::min);
+        if !time_pivot.is_finite() {
+            return;
+        }
+        let value_pivot = ((scale.value - 1.0).abs() > f32::EPSILON).then(|| {
+            let count = keys.len() as f32;
+            keys[0].value.map_components(|component, _| {
+                let sum: f32 = keys
+                    .iter()
+                    .map(|key| key.value.components().get(component).copied().unwrap_or(0.0))
+                    .sum();
+                sum / count
+            })
+        });
+        if (scale.time - 1.0).abs() <= f32::EPSILON && value_pivot.is_none() {
+            return;
+        }
+        self.pending_commands.push(AnimationPanelCommand::ScaleKeys {
+            binding: summary.binding,
+            indices: keys.iter().map(|key| key.index).collect(),
+            time_pivot,
+            time_scale: scale.time.max(0.01),
+            value_pivot,
+            value_scale: scale.value,
+        });
+        self.curve_scale = CurveScaleInput::default();
+    }
+
+    fn handle_curve_background(
+        &mut self,
+        ui: &Ui,
+        response: &egui::Response,
+        plot: CurvePlot,
+        summary: &AnimationTrackSummary,
+        keys: &[CurveKey],
+    ) {
+        let shift = ui.input(|input| input.modifiers.shift);
+        if response.double_clicked() {
+            if let Some(pos) = response.interact_pointer_pos() {
+                self.handle_insert_request(summary, plot.time_at(pos.x).clamp(0.0, plot.duration));
+            }
+        } else if response.clicked() && !shift {
+            self.clear_key_selection();
+        }
+        if response.drag_started() {
+            if let Some(pos) = response.interact_pointer_pos() {
+                self.begin_curve_drag(plot, CurveDragState::BoxSelect { origin: pos, current: pos });
+            }
+        }
+        if response.dragged() {
+            if let (Some(pos), Some(CurveDragState::BoxSelect { current, .. })) =
+                (response.interact_pointer_pos(), self.curve_drag.as_mut())
+            {
+                *current = pos;
+            }
+        }
+        if response.drag_stopped() {
+            self.finish_box_select(plot, keys, shift);
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn draw_curve_key(
+        &mut self,
+        ui: &mut Ui,
+        painter: &egui::Painter,
+        plot: CurvePlot,
+        summary: &AnimationTrackSummary,
+        keys: &[CurveKey],
+        position: usize,
+        component: usize,
+        step: bool,
+    ) {
+        let key = &keys[position];
+        let Some(&value) = key.value.components().get(component) else {
+            return;
+        };
+        let center = plot.pos(key.time, value);
+        let selected = self.selected_keys.contains(&key.id);
+        if selected && !step {
+            for side in [TangentSide::In, TangentSide::Out] {
+                self.draw_tangent_handle(ui, painter, plot, summary, keys, position, component, side, center);
+            }
+        }
+        let fill = if selected { Color32::from_rgb(250, 138, 64) } else { Color32::from_rgb(110, 170, 255) };
+        painter.circle(center, 4.5, fill, Stroke::new(1.0, curve_component_color(component)));
+        let id = Id::new(("curve_key", key.id.track.raw(), key.id.index, component));
+        let hit_rect = Rect::from_center_size(center, egui::vec2(12.0, 12.0));
+        let response = ui.interact(hit_rect, id, Sense::click_and_drag());
+        if response.drag_started() {
+            if !selected {
+                self.handle_key_click(key.id, summary.id, Modifiers::default());
+            }
+            self.begin_curve_key_drag(plot, summary, key, component);
+        }
+        if response.dragged() {
+            if let Some(pos) = response.interact_pointer_pos() {
+                let snap = ui.input(|input| input.modifiers.shift);
+                self.update_curve_key_drag(plot, pos, snap);
+            }
+        }
+        if response.drag_stopped() {
+            self.finish_curve_drag();
+        } else if response.clicked() {
+            let modifiers = ui.input(|input| input.modifiers);
+            self.handle_key_click(key.id, summary.id, modifiers);
+        }
+        response.on_hover_text(format!("{} #{} @ {:.3}s\n{value:.3}", summary.label, key.index, key.time));
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn draw_tangent_handle(
+        &mut self,
+        ui: &mut Ui,
+        painter: &egui::Painter,
+        plot: CurvePlot,
+        summary: &AnimationTrackSummary,
+        keys: &[CurveKey],
+        position: usize,
+        component: usize,
+        side: TangentSide,
+        center: Pos2,
+    ) {
+        let key = &keys[position];
+        let handle = center + plot.handle_offset(effective_tangent(keys, position, component, side), side);
+        painter.line_segment([center, handle], Stroke::new(1.0, Color32::from_gray(180)));
+        painter.circle_filled(handle, 3.5, Color32::from_gray(220));
+        let out_side = side == TangentSide::Out;
+        let id = Id::new(("curve_tangent", key.id.track.raw(), key.id.index, component, out_side));
+        let response = ui.interact(Rect::from_center_size(handle, egui::vec2(10.0, 10.0)), id, Sense::drag());
+        if response.drag_started() {
+            self.begin_tangent_drag(plot, summary, keys, position, component, side);
+        }
+        if response.dragged() {
+            if let Some(pos) = response.interact_pointer_pos() {
+                let broken = ui.input(|input| input.modifiers.alt);
+                self.update_tangent_drag(plot, pos - center, broken);
+            }
+        }
+        if response.drag_stopped() {
+            self.finish_curve_drag();
+        }
+        response.on_hover_text(tr!("keyframe.drag_to_bend_the_hint"));
+    }
+
+    fn curve_keys(&self, summary: &AnimationTrackSummary) -> Vec<CurveKey> {
+        let mut keys: Vec<CurveKey> = summary
+            .key_details
+            .iter()
+            .filter(|detail| detail.value != KeyframeValue::None)
+            .filter_map(|detail| {
+                Some(CurveKey {
+                    id: detail.id,
+                    index: detail.index,
+                    time: detail.time?,
+                    value: detail.value,
+                    in_tangent: detail.in_tangent.authored(),
+                    out_tangent: detail.out_tangent.authored(),
+                })
+            })
+            .collect();
+        if let Some(drag) = &self.curve_drag {
+            for key in &mut keys {
+                drag.preview(key);
+            }
+        }
+        keys.sort_by(|a, b| a.time.partial_cmp(&b.time).unwrap_or(Ordering::Equal));
+        keys
+    }
+
+    fn begin_curve_drag(&mut self, plot: CurvePlot, drag: CurveDragState) {
+        if self.curve_drag.is_none() {
+            self.curve_value_range = Some((plot.min, plot.max));
+            self.curve_drag = Some(drag);
+        }
+    }
+
+    fn begin_curve_key_drag(
+        &mut self,
+        plot: CurvePlot,
+        summary: &AnimationTrackSummary,
+        key: &CurveKey,
+        component: usize,
+    ) {
+        let mut indices: Vec<usize> = self
+            .selected_keys
+            .iter()
+            .filter(|selected| selected.track == summary.id)
+            .map(|selected| selected.index as usize)
+            .collect();
+        if indices.is_empty() {
+            indices.push(key.index);
+        }
+        indices.sort_unstable();
+        indices.dedup();
+        self.begin_curve_drag(
+            plot,
+            CurveDragState::Keys {
+                binding: summary.binding,
+                track_id: summary.id,
+                indices,
+                component,
+                anchor_time: key.time,
+                anchor_value: key.value,
+                time_delta: 0.0,
+                value_delta: 0.0,
+            },
+        );
+    }
+
+    fn update_curve_key_drag(&mut self, plot: CurvePlot, pointer: Pos2, snap: bool) {
+        let time = self.apply_snap_if_needed(plot.duration, plot.time_at(pointer.x), snap);
+        if let Some(CurveDragState::Keys {
+            component,
+            anchor_time,
+            anchor_value,
+            time_delta,
+            value_delta,
+            ..
+        }) = self.curve_drag.as_mut()
+        {
+            *time_delta = time - *anchor_time;
+            let anchor = anchor_value.components().get(*component).copied().unwrap_or(0.0);
+            *value_delta = plot.value_at(pointer.y) - anchor;
+        }
+    }
+
+    fn begin_tangent_drag(
+        &mut self,
+        plot: CurvePlot,
+        summary: &AnimationTrackSummary,
+        keys: &[CurveKey],
+        position: usize,
+        component: usize,
+        side: TangentSide,
+    ) {
+        let key = &keys[position];
+        let effective = |side| key.value.map_components(|c, _| effective_tangent(keys, position, c, side));
+        self.begin_curve_drag(
+            plot,
+            CurveDragState::Tangent {
+                binding: summary.binding,
+                track_id: summary.id,
+                index: key.index,
+                component,
+                side,
+                slope: effective_tangent(keys, position, component, side),
+                broken: false,
+                start_in: effective(TangentSide::In),
+                start_out: effective(TangentSide::Out),
+                authored_in: key.in_tangent,
+                authored_out: key.out_tangent,
+            },
+        );
+    }
+
+    /// `offset` is the pointer position relative to the key.
+    fn update_tangent_drag(&mut self, plot: CurvePlot, offset: egui::Vec2, broken: bool) {
+        if let Some(CurveDragState::Tangent { side, slope, broken: drag_broken, .. }) =
+            self.curve_drag.as_mut()
+        {
+            *slope = plot.slope_from_offset(offset, *side);
+            *drag_broken = broken;
+        }
+    }
+
+    fn finish_curve_drag(&mut self) {
+        let Some(drag) = self.curve_drag.take() else {
+            return;
+        };
+        self.curve_value_range = None;
+        if let Some((in_tangent, out_tangent)) = drag.tangents() {
+            if let CurveDragState::Tangent { binding, index, .. } = drag {
+                self.pending_commands.push(AnimationPanelCommand::SetKeyTangents {
+                    binding,
+                    index,
+                    in_tangent,
+                    out_tangent,
+                });
+            }
+            return;
+        }
+        let CurveDragState::Keys {
+            binding,
+            indices,
+            component,
+            anchor_time,
+            anchor_value,
+            time_delta,
+            value_delta,
+            ..
+        } = drag
+        else {
+            return;
+        };
+        let moved_time = time_delta.abs() >= 1e-4;
+        let moved_value = value_delta.abs() >= 1e-5;
+        if !moved_time && !moved_value {
+            return;
+        }
+        if indices.len() == 1 {
+            self.pending_commands.push(AnimationPanelCommand::UpdateKey {
+                binding,
+                index: indices[0],
+                new_time: moved_time.then(|| (anchor_time + time_delta).max(0.0)),
+                new_value: moved_value.then(|| {
+                    anchor_value.map_components(|i, v| if i == component { v + value_delta } else { v })
+                }),
+            });
+        } else {
+            self.pending_commands.push(AnimationPanelCommand::AdjustKeys {
+                binding,
+                indices,
+                time_delta: moved_time.then_some(time_delta),
+                value_delta: moved_value.then(|| {
+                    anchor_value.map_components(|i, _| if i == component { value_delta } else { 0.0 })
+                }),
+            });
+        }
+    }
+
+    /// Selects every key with a plotted component inside the box; `additive` keeps the current
+    /// selection.
+    fn finish_box_select(&mut self, plot: CurvePlot, keys: &[CurveKey], additive: bool) {
+        let Some(CurveDragState::BoxSelect { origin, current }) = self.curve_drag.take() else {
+            return;
+        };
+        self.curve_value_range = None;
+        let area = Rect::from_two_pos(origin, current);
+        if !additive {
+            self.selected_keys.clear();
+        }
+        for key in keys {
+            if key.value.components().iter().any(|&value| area.contains(plot.pos(key.time, value))) {
+                self.selected_keys.insert(key.id);
+            }
+        }
+        self.sync_selection_anchor();
+    }
+
     fn render_selection_overview(&mut self, ui: &mut Ui, tracks: &[&AnimationTrackSummary]) {
         let selected_summary = self
             .selected_tracks
@@ -629,14 +1370,16 @@ impl AnimationKeyframePanel {
             .and_then(|track_id| tracks.iter().copied().find(|summary| summary.id == *track_id));
         if let Some(summary) = selected_summary {
             ui.horizontal(|ui| {
-                ui.label(format!(
-                    "Selected track: {} ({}) • {} keys • {}",
-                    summary.label,
-                    self.track_kind_label(summary.kind),
-                    summary.key_count,
-                    self.interpolation_label(summary.interpolation)
+                ui.label(tr!(
+                    "keyframe.selected_track_keys",
+                    summary = summary.label,
+                    kind = summary.kind.label(),
+                    key_count = summary.key_count,
+                    interpolation_label = self.interpolation_label(summary.interpolation)
                 ));
-                if self.can_edit_track(summary.kind) && ui.button("Insert Key at Scrub").clicked() {
+                if self.can_edit_track(summary.kind)
+                    && ui.button(tr!("keyframe.insert_key_at_scrub")).clicked()
+                {
                     let clamped = self.scrub_time.min(summary.duration.max(0.0));
                     self.pending_commands.push(AnimationPanelCommand::InsertKey {
                         binding: summary.binding,
@@ -646,16 +1389,17 @@ impl AnimationKeyframePanel {
                 }
             });
         } else {
-            ui.label("Selected track: none");
+            ui.label(tr!("keyframe.selected_track_none"));
         }
         let selection_info = self.selection_binding_and_indices(tracks);
         if self.selected_keys.is_empty() {
-            ui.label("Selected keys: none");
+            ui.label(tr!("keyframe.selected_keys_none"));
         } else {
-            ui.label(format!("Selected keys: {}", self.selected_keys.len()));
+            ui.label(tr!("keyframe.selected_keys", selected_keys = self.selected_keys.len()));
         }
         let delete_enabled = selection_info.is_some();
-        let delete_button = ui.add_enabled(delete_enabled, egui::Button::new("Delete Selected Keys"));
+        let delete_button =
+            ui.add_enabled(delete_enabled, egui::Button::new(tr!("keyframe.delete_selected_keys")));
         let delete_request =
             delete_button.clicked() || (delete_enabled && ui.input(|i| i.key_pressed(Key::Delete)));
         if delete_request {
@@ -681,15 +1425,15 @@ impl AnimationKeyframePanel {
             return;
         };
         if matches!(summary.kind, AnimationTrackKind::SpriteTimeline) {
-            ui.label("Sprite keys are read-only.");
+            ui.label(tr!("keyframe.sprite_keys_are_read_only"));
             return;
         }
         ui.separator();
-        ui.strong("Key Editor");
+        ui.strong(tr!("keyframe.key_editor"));
         if let Some(mut time) = detail.time {
             let mut changed = false;
             let mut time_widget = egui::DragValue::new(&mut time).speed(0.01);
-            time_widget = time_widget.prefix("Time ");
+            time_widget = time_widget.prefix(tr!("keyframe.time"));
             if ui.add(time_widget).changed() {
                 changed = true;
             }
@@ -702,14 +1446,14 @@ impl AnimationKeyframePanel {
                 });
             }
         } else {
-            ui.label("Key time unavailable.");
+            ui.label(tr!("keyframe.key_time_unavailable"));
         }
         match summary.kind {
             AnimationTrackKind::Translation | AnimationTrackKind::Scale => {
                 if let Some(mut value) = detail.value.as_vec2() {
                     let mut changed = false;
                     ui.horizontal(|ui| {
-                        ui.label("Value (X,Y)");
+                        ui.label(tr!("keyframe.value_x_y"));
                         if ui.add(egui::DragValue::new(&mut value[0]).speed(0.01)).changed() {
                             changed = true;
                         }
@@ -726,12 +1470,15 @@ impl AnimationKeyframePanel {
                         });
                     }
                 } else {
-                    ui.label("Value unavailable.");
+                    ui.label(tr!("keyframe.value_unavailable"));
                 }
             }
             AnimationTrackKind::Rotation => {
                 if let Some(mut value) = detail.value.as_scalar() {
-                    if ui.add(egui::DragValue::new(&mut value).speed(0.01).prefix("Degrees ")).changed() {
+                    if ui
+                        .add(egui::DragValue::new(&mut value).speed(0.01).prefix(tr!("keyframe.degrees")))
+                        .changed()
+                    {
                         self.pending_commands.push(AnimationPanelCommand::UpdateKey {
                             binding: summary.binding,
                             index: detail.index,
@@ -740,14 +1487,14 @@ impl AnimationKeyframePanel {
                         });
                     }
                 } else {
-                    ui.label("Value unavailable.");
+                    ui.label(tr!("keyframe.value_unavailable"));
                 }
             }
             AnimationTrackKind::Tint => {
                 if let Some(mut value) = detail.value.as_vec4() {
                     let mut changed = false;
                     ui.horizontal(|ui| {
-                        ui.label("RGBA");
+                        ui.label(tr!("keyframe.rgba"));
                         for channel in value.iter_mut() {
                             if ui.add(egui::DragValue::new(channel).speed(0.01)).changed() {
                                 changed = true;
@@ -763,11 +1510,18 @@ impl AnimationKeyframePanel {
                         });
                     }
                 } else {
-                    ui.label("Value unavailable.");
+                    ui.label(tr!("keyframe.value_unavailable"));
                 }
             }
             AnimationTrackKind::SpriteTimeline => {}
         }
+        if detail.in_tangent != KeyframeValue::None || detail.out_tangent != KeyframeValue::None {
+            ui.small(tr!(
+                "keyframe.tangents_in_out",
+                in_tangent = format_tangent(detail.in_tangent),
+                out_tangent = format_tangent(detail.out_tangent)
+            ));
+        }
     }
 
     fn clear_key_selection(&mut self) {
@@ -886,43 +1640,53 @@ impl AnimationKeyframePanel {
             at.partial_cmp(&bt).unwrap_or(Ordering::Equal)
         });
         let anchor = ordered.first().and_then(|detail| detail.time).unwrap_or(0.0);
-        let mut entries: Vec<KeyClipboardEntry> = Vec::new();
+        let mut entries: Vec<AnimationKeyClipboardEntry> = Vec::new();
         for detail in ordered {
             if matches!(detail.value, KeyframeValue::None) {
                 continue;
             }
             if let Some(time) = detail.time {
-                entries.push(KeyClipboardEntry { time_offset: time - anchor, value: detail.value });
+                entries.push(AnimationKeyClipboardEntry { time_offset: time - anchor, value: detail.value });
             }
         }
         if entries.is_empty() {
             return;
         }
-        self.clipboard = Some(KeyClipboard { kind: summary.kind, entries });
+        self.pending_commands.push(AnimationPanelCommand::CopyKeys {
+            clipboard: AnimationKeyClipboard { kind: summary.kind, entries },
+        });
     }
 
-    fn paste_clipboard_keys(&mut self, tracks: &[&AnimationTrackSummary]) {
-        let Some(clipboard) = self.clipboard.clone() else {
-            return;
-        };
-        if clipboard.entries.is_empty() {
+    #[cfg(test)]
+    pub(super) fn copy_keys_for_test(
+        &mut self,
+        summary: &AnimationTrackSummary,
+        indices: &[usize],
+    ) -> AnimationKeyClipboard {
+        self.selected_tracks.insert(summary.id);
+        self.selected_keys.extend(indices.iter().map(|&index| KeyframeId::new(summary.id, index)));
+        self.copy_selected_keys(&[summary]);
+        match self.pending_commands.pop() {
+            Some(AnimationPanelCommand::CopyKeys { clipboard }) => clipboard,
+            other => panic!("expected a CopyKeys command, got {other:?}"),
+        }
+    }
+
+    /// Track kinds are checked by the editor when the paste is applied, so a mismatch can be
+    /// reported instead of dropped.
+    fn paste_clipboard_keys(
+        &mut self,
+        tracks: &[&AnimationTrackSummary],
+        clipboard: Option<&AnimationKeyClipboard>,
+    ) {
+        if clipboard.is_none_or(|clipboard| clipboard.entries.is_empty()) {
             return;
         }
         let Some(target) = self.primary_selected_track_summary(tracks) else {
             return;
         };
-        if target.kind != clipboard.kind || !self.can_edit_track(target.kind) {
-            return;
-        }
-        let max_duration = target.duration.max(0.0);
-        for entry in clipboard.entries {
-            let time = (self.scrub_time + entry.time_offset).max(0.0).min(max_duration);
-            self.pending_commands.push(AnimationPanelCommand::InsertKey {
-                binding: target.binding,
-                time,
-                value: Some(entry.value),
-            });
-        }
+        self.pending_commands
+            .push(AnimationPanelCommand::PasteKeys { binding: target.binding, time: self.scrub_time });
     }
 
     fn handle_key_click(&mut self, key_id: KeyframeId, track_id: AnimationTrackId, modifiers: Modifiers) {
@@ -1094,16 +1858,6 @@ impl AnimationKeyframePanel {
         }
     }
 
-    fn track_kind_label(&self, kind: AnimationTrackKind) -> &'static str {
-        match kind {
-            AnimationTrackKind::SpriteTimeline => "Sprite",
-            AnimationTrackKind::Translation => "Translation",
-            AnimationTrackKind::Rotation => "Rotation",
-            AnimationTrackKind::Scale => "Scale",
-            AnimationTrackKind::Tint => "Tint",
-        }
-    }
-
     fn selection_binding_and_indices(
         &self,
         tracks: &[&AnimationTrackSummary],
@@ -1152,11 +1906,11 @@ impl AnimationKeyframePanel {
             return;
         }
         ui.separator();
-        ui.strong("Multi-Key Adjustments");
+        ui.strong(tr!("keyframe.multi_key_adjustments"));
         ui.horizontal(|ui| {
-            ui.label("Time Offset (s)");
+            ui.label(tr!("keyframe.time_offset_s"));
             ui.add(egui::DragValue::new(&mut self.multi_time_offset).speed(0.01));
-            if ui.button("Apply Time Offset").clicked() && self.multi_time_offset != 0.0 {
+            if ui.button(tr!("keyframe.apply_time_offset")).clicked() && self.multi_time_offset != 0.0 {
                 self.pending_commands.push(AnimationPanelCommand::AdjustKeys {
                     binding,
                     indices: indices.clone(),
@@ -1165,17 +1919,17 @@ impl AnimationKeyframePanel {
                 });
                 self.multi_time_offset = 0.0;
             }
-            if ui.button("Reset").clicked() {
+            if ui.button(tr!("keyframe.reset")).clicked() {
                 self.multi_time_offset = 0.0;
             }
         });
         match kind {
             AnimationTrackKind::Translation | AnimationTrackKind::Scale => {
                 ui.horizontal(|ui| {
-                    ui.label("Value Offset (X,Y)");
+                    ui.label(tr!("keyframe.value_offset_x_y"));
                     ui.add(egui::DragValue::new(&mut self.multi_vec2_offset[0]).speed(0.01));
                     ui.add(egui::DragValue::new(&mut self.multi_vec2_offset[1]).speed(0.01));
-                    if ui.button("Apply Value Offset").clicked()
+                    if ui.button(tr!("keyframe.apply_value_offset")).clicked()
                         && (self.multi_vec2_offset[0] != 0.0 || self.multi_vec2_offset[1] != 0.0)
                     {
                         self.pending_commands.push(AnimationPanelCommand::AdjustKeys {
@@ -1186,16 +1940,18 @@ impl AnimationKeyframePanel {
                         });
                         self.multi_vec2_offset = [0.0; 2];
                     }
-                    if ui.button("Reset Vec2").clicked() {
+                    if ui.button(tr!("keyframe.reset_vec2")).clicked() {
                         self.multi_vec2_offset = [0.0; 2];
                     }
                 });
             }
             AnimationTrackKind::Rotation => {
                 ui.horizontal(|ui| {
-                    ui.label("Value Offset (degrees)");
+                    ui.label(tr!("keyframe.value_offset_degrees"));
                     ui.add(egui::DragValue::new(&mut self.multi_scalar_offset).speed(0.1));
-                    if ui.button("Apply Rotation Offset").clicked() && self.multi_scalar_offset != 0.0 {
+                    if ui.button(tr!("keyframe.apply_rotation_offset")).clicked()
+                        && self.multi_scalar_offset != 0.0
+                    {
                         self.pending_commands.push(AnimationPanelCommand::AdjustKeys {
                             binding,
                             indices: indices.clone(),
@@ -1204,18 +1960,18 @@ impl AnimationKeyframePanel {
                         });
                         self.multi_scalar_offset = 0.0;
                     }
-                    if ui.button("Reset Scalar").clicked() {
+                    if ui.button(tr!("keyframe.reset_scalar")).clicked() {
                         self.multi_scalar_offset = 0.0;
                     }
                 });
             }
             AnimationTrackKind::Tint => {
                 ui.horizontal(|ui| {
-                    ui.label("Value Offset (RGBA)");
+                    ui.label(tr!("keyframe.value_offset_rgba"));
                     for component in self.multi_vec4_offset.iter_mut() {
                         ui.add(egui::DragValue::new(component).speed(0.01));
                     }
-                    if ui.button("Apply Tint Offset").clicked()
+                    if ui.button(tr!("keyframe.apply_tint_offset")).clicked()
                         && self.multi_vec4_offset.iter().any(|&v| v != 0.0)
                     {
                         self.pending_commands.push(AnimationPanelCommand::AdjustKeys {
@@ -1226,7 +1982,7 @@ impl AnimationKeyframePanel {
                         });
                         self.multi_vec4_offset = [0.0; 4];
                     }
-                    if ui.button("Reset Tint Offset").clicked() {
+                    if ui.button(tr!("keyframe.reset_tint_offset")).clicked() {
                         self.multi_vec4_offset = [0.0; 4];
                     }
                 });
@@ -1236,6 +1992,88 @@ impl AnimationKeyframePanel {
     }
 }
 
+/// Tangent of `component` on one side of the key at `position`: the authored slope, else the slope
+/// of the adjoining segment, which is what sampling uses for a side without a tangent.
+fn effective_tangent(keys: &[CurveKey], position: usize, component: usize, side: TangentSide) -> f32 {
+    let frame = keys[position].keyframe(component);
+    let authored = match side {
+        TangentSide::In => frame.in_tangent,
+        TangentSide::Out => frame.out_tangent,
+    };
+    authored.unwrap_or_else(|| {
+        let before = position.checked_sub(1).and_then(|index| keys.get(index));
+        let after = keys.get(position + 1);
+        let neighbour = match side {
+            TangentSide::In => before.or(after),
+            TangentSide::Out => after.or(before),
+        };
+        neighbour.map_or(0.0, |other| {
+            let other = other.keyframe(component);
+            let span = other.time - frame.time;
+            if span.abs() > f32::EPSILON {
+                (other.value - frame.value) / span
+            } else {
+                0.0
+            }
+        })
+    })
+}
+
+/// Time/value points tracing one component through the keys, sampled the way clips are.
+fn curve_polyline(keys: &[CurveKey], component: usize, step: bool) -> Vec<(f32, f32)> {
+    let mut points = Vec::new();
+    if let Some(first) = keys.first() {
+        let first = first.keyframe(component);
+        points.push((first.time, first.value));
+    }
+    for pair in keys.windows(2) {
+        let start = pair[0].keyframe(component);
+        let end = pair[1].keyframe(component);
+        if step {
+            points.push((end.time, start.value));
+        } else if start.curves_to(&end) {
+            let span = end.time - start.time;
+            for step_index in 1..CURVE_SEGMENT_STEPS {
+                let offset = span * step_index as f32 / CURVE_SEGMENT_STEPS as f32;
+                if let Some(value) = start.curve_to(&end, offset) {
+                    points.push((start.time + offset, value));
+                }
+            }
+        }
+        points.push((end.time, end.value));
+    }
+    points
+}
+
+fn curve_value_range(polylines: &[Vec<(f32, f32)>]) -> (f32, f32) {
+    let (min, max) = polylines
+        .iter()
+        .flatten()
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), &(_, value)| (min.min(value), max.max(value)));
+    if !min.is_finite() || !max.is_finite() {
+        return (-1.0, 1.0);
+    }
+    let padding = if max - min > 1e-4 { (max - min) * 0.1 } else { 1.0 };
+    (min - padding, max + padding)
+}
+
+fn curve_component_color(component: usize) -> Color32 {
+    match component {
+        0 => Color32::from_rgb(236, 96, 96),
+        1 => Color32::from_rgb(120, 210, 110),
+        2 => Color32::from_rgb(100, 150, 255),
+        _ => Color32::from_gray(200),
+    }
+}
+
+fn format_tangent(tangent: KeyframeValue) -> String {
+    if tangent == KeyframeValue::None {
+        return "linear".to_string();
+    }
+    let parts: Vec<String> = tangent.components().iter().map(|value| format!("{value:.2}")).collect();
+    format!("({})", parts.join(", "))
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(deprecated)]
@@ -1267,6 +2105,8 @@ mod tests {
             time: Some(0.25),
             value_preview: None,
             value: KeyframeValue::Vec2([0.0, 0.0]),
+            in_tangent: KeyframeValue::None,
+            out_tangent: KeyframeValue::None,
         };
         let summary = AnimationTrackSummary {
             id: track_id,
@@ -1341,6 +2181,7 @@ mod tests {
             can_undo: false,
             can_redo: false,
             status_message: None,
+            clipboard: None,
         };
         assert_eq!(state.track_summaries.len(), 2);
     }
@@ -1367,6 +2208,8 @@ mod tests {
                     time: Some(0.0),
                     value_preview: Some("Translation (0,0)".to_string()),
                     value: KeyframeValue::Vec2([0.0, 0.0]),
+                    in_tangent: KeyframeValue::None,
+                    out_tangent: KeyframeValue::None,
                 },
                 KeyframeDetail {
                     id: KeyframeId::new(AnimationTrackId(42), 1),
@@ -1374,6 +2217,8 @@ mod tests {
                     time: Some(1.0),
                     value_preview: Some("Rotation 90deg".to_string()),
                     value: KeyframeValue::Scalar(90.0),
+                    in_tangent: KeyframeValue::None,
+                    out_tangent: KeyframeValue::None,
                 },
             ],
         };
@@ -1408,6 +2253,8 @@ mod tests {
                     time: Some(0.1),
                     value_preview: None,
                     value: KeyframeValue::Vec2([0.0, 0.0]),
+                    in_tangent: KeyframeValue::None,
+                    out_tangent: KeyframeValue::None,
                 },
                 KeyframeDetail {
                     id: KeyframeId::new(track_id, 1),
@@ -1415,27 +2262,36 @@ mod tests {
                     time: Some(0.4),
                     value_preview: None,
                     value: KeyframeValue::Vec2([1.0, 2.0]),
+                    in_tangent: KeyframeValue::None,
+                    out_tangent: KeyframeValue::None,
                 },
             ],
         };
         let tracks = vec![&summary];
         panel.copy_selected_keys(&tracks);
-        let clipboard = panel.clipboard.expect("clipboard populated");
+        let mut commands = panel.drain_commands();
+        assert_eq!(commands.len(), 1);
+        let AnimationPanelCommand::CopyKeys { clipboard } = commands.remove(0) else {
+            panic!("copy should emit a CopyKeys command");
+        };
         assert_eq!(clipboard.kind, AnimationTrackKind::Translation);
         assert_eq!(clipboard.entries.len(), 2);
         assert!(clipboard.entries[1].time_offset > clipboard.entries[0].time_offset);
     }
 
     #[test]
-    fn paste_clipboard_generates_insert_commands() {
+    fn paste_clipboard_generates_paste_command_at_playhead() {
         let mut panel = AnimationKeyframePanel::default();
         let track_id = AnimationTrackId(9);
         panel.selected_tracks.insert(track_id);
         panel.scrub_time = 0.2;
-        panel.clipboard = Some(KeyClipboard {
+        let clipboard = AnimationKeyClipboard {
             kind: AnimationTrackKind::Rotation,
-            entries: vec![KeyClipboardEntry { time_offset: 0.0, value: KeyframeValue::Scalar(45.0) }],
-        });
+            entries: vec![AnimationKeyClipboardEntry {
+                time_offset: 0.0,
+                value: KeyframeValue::Scalar(45.0),
+            }],
+        };
         let summary = AnimationTrackSummary {
             id: track_id,
             label: "Rotation".to_string(),
@@ -1452,13 +2308,14 @@ mod tests {
             key_details: Vec::new(),
         };
         let tracks = vec![&summary];
-        panel.paste_clipboard_keys(&tracks);
+        panel.paste_clipboard_keys(&tracks, None);
+        assert!(panel.drain_commands().is_empty(), "nothing to paste without a clipboard");
+        panel.paste_clipboard_keys(&tracks, Some(&clipboard));
         let mut commands = panel.drain_commands();
         assert_eq!(commands.len(), 1);
         match commands.remove(0) {
-            AnimationPanelCommand::InsertKey { binding, time, value } => {
+            AnimationPanelCommand::PasteKeys { binding, time } => {
                 assert_eq!(time, 0.2);
-                assert!(matches!(value, Some(KeyframeValue::Scalar(v)) if (v - 45.0).abs() < 1e-4));
                 assert!(matches!(
                     binding,
                     AnimationTrackBinding::TransformChannel { channel: AnimationTrackKind::Rotation, .. }
@@ -1550,4 +2407,108 @@ mod tests {
             "Expected scrub command after dragging axis"
         );
     }
+
+    fn curve_test_summary(track_id: AnimationTrackId, values: &[(f32, [f32; 2])]) -> AnimationTrackSummary {
+        AnimationTrackSummary {
+            id: track_id,
+            label: "Translation".to_string(),
+            kind: AnimationTrackKind::Translation,
+            binding: AnimationTrackBinding::TransformChannel {
+                entity: Entity::from_raw(1),
+                channel: AnimationTrackKind::Translation,
+            },
+            duration: 1.0,
+            key_count: values.len(),
+            interpolation: Some(ClipInterpolation::Linear),
+            playhead: Some(0.0),
+            dirty: false,
+            key_details: values
+                .iter()
+                .enumerate()
+                .map(|(index, &(time, value))| KeyframeDetail {
+                    id: KeyframeId::new(track_id, index),
+                    index,
+                    time: Some(time),
+                    value_preview: None,
+                    value: KeyframeValue::Vec2(value),
+                    in_tangent: KeyframeValue::None,
+                    out_tangent: KeyframeValue::None,
+                })
+                .collect(),
+        }
+    }
+
+    fn unit_plot() -> CurvePlot {
+        let rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 100.0));
+        CurvePlot { rect, duration: 1.0, min: 0.0, max: 1.0 }
+    }
+
+    #[test]
+    fn dragging_tangent_handle_emits_set_key_tangents() {
+        let mut panel = AnimationKeyframePanel::default();
+        let track_id = AnimationTrackId(11);
+        let summary = curve_test_summary(track_id, &[(0.0, [0.0, 0.0]), (1.0, [1.0, 0.0])]);
+        panel.selected_tracks.insert(track_id);
+        panel.selected_keys.insert(KeyframeId::new(track_id, 0));
+        let plot = unit_plot();
+
+        let keys = panel.curve_keys(&summary);
+        let linear = effective_tangent(&keys, 0, 0, TangentSide::Out);
+        assert_eq!(linear, 1.0, "a side without a tangent follows its segment");
+        panel.begin_tangent_drag(plot, &summary, &keys, 0, 0, TangentSide::Out);
+        assert!(panel.curve_value_range.is_some(), "value range is frozen while dragging");
+        panel.update_tangent_drag(plot, egui::vec2(30.0, 0.0), false);
+        let previewed = panel.curve_keys(&summary);
+        assert_eq!(previewed[0].out_tangent, Some(KeyframeValue::Vec2([0.0, 0.0])), "drag previews live");
+        panel.finish_curve_drag();
+        assert!(panel.curve_value_range.is_none());
+        let commands = panel.drain_commands();
+        match commands.as_slice() {
+            [AnimationPanelCommand::SetKeyTangents { index: 0, in_tangent, out_tangent, .. }] => {
+                let flat = Some(KeyframeValue::Vec2([0.0, 0.0]));
+                assert_eq!((*in_tangent, *out_tangent), (flat, flat), "aligned handles flatten both sides");
+            }
+            other => panic!("expected SetKeyTangents, got {other:?}"),
+        }
+
+        panel.begin_tangent_drag(plot, &summary, &keys, 0, 0, TangentSide::Out);
+        panel.update_tangent_drag(plot, egui::vec2(20.0, -40.0), true);
+        panel.finish_curve_drag();
+        match panel.drain_commands().as_slice() {
+            [AnimationPanelCommand::SetKeyTangents { in_tangent, out_tangent, .. }] => {
+                assert_eq!(*in_tangent, None, "broken handles leave the other side alone");
+                assert_eq!(*out_tangent, Some(KeyframeValue::Vec2([2.0, 0.0])));
+            }
+            other => panic!("expected SetKeyTangents, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn box_select_picks_keys_inside_the_rectangle() {
+        let mut panel = AnimationKeyframePanel::default();
+        let track_id = AnimationTrackId(12);
+        let summary =
+            curve_test_summary(track_id, &[(0.1, [0.9, 0.9]), (0.5, [0.5, 0.5]), (0.9, [0.9, 0.9])]);
+        panel.selected_tracks.insert(track_id);
+        let plot = unit_plot();
+        let keys = panel.curve_keys(&summary);
+
+        let drag = CurveDragState::BoxSelect { origin: pos2(40.0, 40.0), current: pos2(60.0, 60.0) };
+        panel.begin_curve_drag(plot, drag);
+        panel.finish_box_select(plot, &keys, false);
+        let selected: Vec<KeyframeId> = panel.selected_keys.iter().copied().collect();
+        assert_eq!(selected, vec![KeyframeId::new(track_id, 1)]);
+
+        let drag = CurveDragState::BoxSelect { origin: pos2(80.0, 0.0), current: pos2(100.0, 20.0) };
+        panel.begin_curve_drag(plot, drag);
+        panel.finish_box_select(plot, &keys, true);
+        assert_eq!(panel.selected_keys.len(), 2, "shift adds to the selection");
+        assert!(panel.selected_keys.contains(&KeyframeId::new(track_id, 2)));
+
+        let drag = CurveDragState::BoxSelect { origin: pos2(0.0, 0.0), current: pos2(20.0, 20.0) };
+        panel.begin_curve_drag(plot, drag);
+        panel.finish_box_select(plot, &keys, false);
+        let selected: Vec<KeyframeId> = panel.selected_keys.iter().copied().collect();
+        assert_eq!(selected, vec![KeyframeId::new(track_id, 0)], "a fresh box replaces the selection");
+    }
 }
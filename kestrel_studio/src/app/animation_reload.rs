@@ -8,8 +8,8 @@ use std::thread;
 use anyhow::Result;
 
 use crate::animation_validation::{AnimationValidationEvent, AnimationValidator};
-use crate::assets::{self, AnimationClip, AnimationGraphAsset};
-use crate::assets::{parse_animation_clip_bytes, parse_animation_graph_bytes};
+use crate::assets::{self, AnimationClip, AnimationGraphAsset, ColorCurve};
+use crate::assets::{parse_animation_clip_bytes, parse_animation_graph_bytes, parse_color_curve_bytes};
 
 use super::animation_watch::AnimationAssetKind;
 use super::ANIMATION_RELOAD_WORKER_QUEUE_DEPTH;
@@ -34,6 +34,7 @@ pub(super) enum AnimationReloadData {
     Clip { clip: Box<AnimationClip>, bytes: Vec<u8> },
     Graph { graph: AnimationGraphAsset, bytes: Vec<u8> },
     Skeletal { import: assets::skeletal::SkeletonImport },
+    ColorCurve { curve: Box<ColorCurve>, bytes: Vec<u8> },
 }
 
 pub(super) struct AnimationReloadQueue {
@@ -44,7 +45,11 @@ pub(super) struct AnimationReloadQueue {
 
 impl AnimationReloadQueue {
     pub(super) fn new(max_len: usize) -> Self {
-        Self { buckets: [VecDeque::new(), VecDeque::new(), VecDeque::new()], next_bucket: 0, max_len }
+        Self {
+            buckets: [VecDeque::new(), VecDeque::new(), VecDeque::new(), VecDeque::new()],
+            next_bucket: 0,
+            max_len,
+        }
     }
 
     pub(super) fn enqueue(&mut self, request: AnimationReloadRequest) -> Option<AnimationReloadRequest> {
@@ -326,6 +331,13 @@ pub(super) fn run_animation_validation_job(job: AnimationValidationJob) -> Anima
             }
         }
         AnimationAssetKind::Skeletal => AnimationValidator::validate_path(&path),
+        AnimationAssetKind::ColorCurve => {
+            if let Some(payload) = bytes.as_deref() {
+                AnimationValidator::validate_color_curve_bytes(&path, payload)
+            } else {
+                AnimationValidator::validate_path(&path)
+            }
+        }
     };
     AnimationValidationResult { path, kind, events }
 }
@@ -359,6 +371,17 @@ pub(super) fn run_animation_reload_job(job: AnimationReloadJob) -> AnimationRelo
             Ok(import) => Ok(AnimationReloadData::Skeletal { import }),
             Err(err) => Err(err),
         },
+        AnimationAssetKind::ColorCurve => {
+            let bytes = match fs::read(&request.path) {
+                Ok(bytes) => bytes,
+                Err(err) => return AnimationReloadResult { request, data: Err(err.into()) },
+            };
+            let label = request.path.to_string_lossy().to_string();
+            match parse_color_curve_bytes(&bytes, &request.key, &label) {
+                Ok(curve) => Ok(AnimationReloadData::ColorCurve { curve: Box::new(curve), bytes }),
+                Err(err) => Err(err),
+            }
+        }
     };
     AnimationReloadResult { request, data }
 }
@@ -4,6 +4,7 @@ use std::sync::Arc;
 
 use crate::animation_validation::{AnimationValidationEvent, AnimationValidationSeverity};
 use crate::assets::TextureAtlasDiagnostics;
+use crate::tr;
 
 use super::animation_reload::{
     AnimationAssetReload, AnimationReloadData, AnimationReloadRequest, AnimationReloadResult,
@@ -40,6 +41,7 @@ impl App {
                 self.assets.graph_key_for_source_path(&path).unwrap_or_else(|| default_graph_key(&path))
             }
             AnimationAssetKind::Skeletal => self.assets.skeleton_key_for_source_path(&path)?,
+            AnimationAssetKind::ColorCurve => self.assets.color_curve_key_for_source_path(&path)?,
         };
         Some(AnimationReloadRequest { path, key, kind, skip_validation: false })
     }
@@ -96,11 +98,13 @@ impl App {
                 let path_string = result.request.path.to_string_lossy().to_string();
                 self.assets.replace_animation_graph(&key, &path_string, graph);
                 self.queue_animation_watch_root(&result.request.path, AnimationAssetKind::Graph);
+                let rebound = self.ecs.rebind_animation_graphs(&self.assets, &key);
                 self.with_editor_ui_state_mut(|state| {
-                    state.animation_clip_status = Some(format!(
-                        "Reloaded animation graph '{}' from {}",
-                        key,
-                        result.request.path.display()
+                    state.animation_clip_status = Some(tr!(
+                        "animation.graph_reloaded",
+                        key = key,
+                        path = result.request.path.display(),
+                        rebound = rebound
                     ));
                 });
                 if !result.request.skip_validation {
@@ -155,13 +159,41 @@ impl App {
                     });
                 }
             }
+            Ok(AnimationReloadData::ColorCurve { curve, bytes }) => {
+                let key = result.request.key.clone();
+                let path_string = result.request.path.to_string_lossy().to_string();
+                self.assets.replace_color_curve(&key, &path_string, *curve);
+                self.queue_animation_watch_root(&result.request.path, AnimationAssetKind::ColorCurve);
+                let rebound = self.ecs.refresh_color_curve(&self.assets, &key);
+                let status_key = if rebound == 1 {
+                    "animation.color_curve_reloaded_one"
+                } else {
+                    "animation.color_curve_reloaded_many"
+                };
+                self.with_editor_ui_state_mut(|state| {
+                    state.animation_clip_status = Some(tr!(
+                        status_key,
+                        key = key,
+                        path = result.request.path.display(),
+                        count = rebound
+                    ));
+                });
+                if !result.request.skip_validation {
+                    self.enqueue_animation_validation_job(AnimationAssetReload {
+                        path: result.request.path.clone(),
+                        kind: AnimationAssetKind::ColorCurve,
+                        bytes: Some(bytes),
+                    });
+                }
+            }
             Err(err) => {
                 eprintln!("[animation] reload failed for {}: {err:?}", result.request.path.display());
                 self.with_editor_ui_state_mut(|state| {
-                    state.animation_clip_status = Some(format!(
-                        "Reload failed for {} from {}: {err}",
-                        result.request.key,
-                        result.request.path.display()
+                    state.animation_clip_status = Some(tr!(
+                        "animation.asset_reload_failed",
+                        key = result.request.key,
+                        path = result.request.path.display(),
+                        error = err
                     ));
                 });
             }
@@ -1,12 +1,42 @@
 use super::*;
 use crate::animation_validation::AnimationValidator;
-use std::cmp::Ordering;
+use crate::assets::{ClipKeyValue, ClipTrack};
+use anyhow::Result;
 
 enum TrackEditOperation {
-    Insert { time: f32, value: Option<KeyframeValue> },
-    Delete { indices: Vec<usize> },
-    Update { index: usize, new_time: Option<f32>, new_value: Option<KeyframeValue> },
-    Adjust { indices: Vec<usize>, time_delta: Option<f32>, value_delta: Option<KeyframeValue> },
+    Insert {
+        time: f32,
+        value: Option<KeyframeValue>,
+    },
+    Paste {
+        time: f32,
+        entries: Vec<AnimationKeyClipboardEntry>,
+    },
+    Delete {
+        indices: Vec<usize>,
+    },
+    Update {
+        index: usize,
+        new_time: Option<f32>,
+        new_value: Option<KeyframeValue>,
+    },
+    Adjust {
+        indices: Vec<usize>,
+        time_delta: Option<f32>,
+        value_delta: Option<KeyframeValue>,
+    },
+    Tangents {
+        index: usize,
+        in_tangent: Option<KeyframeValue>,
+        out_tangent: Option<KeyframeValue>,
+    },
+    Scale {
+        indices: Vec<usize>,
+        time_pivot: f32,
+        time_scale: f32,
+        value_pivot: Option<KeyframeValue>,
+        value_scale: f32,
+    },
 }
 
 impl App {
@@ -31,6 +61,7 @@ impl App {
                 can_undo: !state.clip_edit_history.is_empty(),
                 can_redo: !state.clip_edit_redo.is_empty(),
                 status_message: state.animation_clip_status.clone(),
+                clipboard: state.animation_key_clipboard.clone(),
             }
         };
         self.with_editor_ui_state_mut(|state| {
@@ -105,6 +136,58 @@ impl App {
                         });
                     }
                 }
+                AnimationPanelCommand::SetKeyTangents { binding, index, in_tangent, out_tangent } => {
+                    let track_kind = Self::analytics_track_kind(&binding);
+                    let edit = TrackEditOperation::Tangents { index, in_tangent, out_tangent };
+                    self.apply_track_edit(binding, edit);
+                    self.log_keyframe_editor_event(KeyframeEditorEventKind::UpdateKey {
+                        track: track_kind,
+                        changed_time: false,
+                        changed_value: true,
+                    });
+                }
+                AnimationPanelCommand::ScaleKeys {
+                    binding,
+                    indices,
+                    time_pivot,
+                    time_scale,
+                    value_pivot,
+                    value_scale,
+                } => {
+                    if !indices.is_empty() {
+                        let track_kind = Self::analytics_track_kind(&binding);
+                        let count = indices.len();
+                        let time_changed = (time_scale - 1.0).abs() > f32::EPSILON;
+                        let value_changed = value_pivot.is_some();
+                        let edit = TrackEditOperation::Scale {
+                            indices,
+                            time_pivot,
+                            time_scale,
+                            value_pivot,
+                            value_scale,
+                        };
+                        self.apply_track_edit(binding, edit);
+                        self.log_keyframe_editor_event(KeyframeEditorEventKind::AdjustKeys {
+                            track: track_kind,
+                            count,
+                            time_delta: time_changed,
+                            value_delta: value_changed,
+                        });
+                    }
+                }
+                AnimationPanelCommand::CopyKeys { clipboard } => {
+                    self.with_editor_ui_state_mut(|state| {
+                        state.animation_clip_status = Some(tr!(
+                            "animation.keys_copied",
+                            count = clipboard.entries.len(),
+                            kind = clipboard.kind.label()
+                        ));
+                        state.animation_key_clipboard = Some(clipboard);
+                    });
+                }
+                AnimationPanelCommand::PasteKeys { binding, time } => {
+                    self.paste_animation_keys(binding, time);
+                }
                 AnimationPanelCommand::Undo => {
                     self.undo_clip_edit();
                     self.log_keyframe_editor_event(KeyframeEditorEventKind::Undo);
@@ -397,6 +480,50 @@ impl App {
         let _ = self.ecs.set_transform_clip_time(entity, clamped);
     }
 
+    fn paste_animation_keys(&mut self, binding: AnimationTrackBinding, time: f32) {
+        let Some(clipboard) = self.editor_ui_state().animation_key_clipboard.clone() else {
+            return;
+        };
+        let target_kind = match binding {
+            AnimationTrackBinding::SpriteTimeline { .. } => AnimationTrackKind::SpriteTimeline,
+            AnimationTrackBinding::TransformChannel { channel, .. } => channel,
+        };
+        if target_kind != clipboard.kind || target_kind == AnimationTrackKind::SpriteTimeline {
+            self.with_editor_ui_state_mut(|state| {
+                state.animation_clip_status = Some(tr!(
+                    "animation.paste_kind_mismatch",
+                    kind = clipboard.kind.label(),
+                    target_kind = target_kind.label()
+                ));
+            });
+            return;
+        }
+        let count = clipboard.entries.len();
+        self.apply_track_edit(binding, TrackEditOperation::Paste { time, entries: clipboard.entries });
+        let track_kind = Self::analytics_track_kind(&binding);
+        for _ in 0..count {
+            self.log_keyframe_editor_event(KeyframeEditorEventKind::InsertKey { track: track_kind });
+        }
+    }
+
+    /// Inserts every clipboard entry at `time` plus its offset, as a single clip edit so one undo
+    /// removes the whole paste. Keys past the end extend the clip.
+    fn paste_clip_keys(
+        clip: &AnimationClip,
+        track: ClipTrack,
+        time: f32,
+        entries: &[AnimationKeyClipboardEntry],
+    ) -> Result<AnimationClip> {
+        let mut pasted = clip.clone();
+        for entry in entries {
+            let Some(value) = Self::clip_key_value(track, entry.value) else {
+                continue;
+            };
+            pasted = pasted.insert_keyframe(track, time + entry.time_offset, value, false)?;
+        }
+        Ok(pasted)
+    }
+
     fn apply_track_edit(&mut self, binding: AnimationTrackBinding, edit: TrackEditOperation) {
         match binding {
             AnimationTrackBinding::TransformChannel { entity, channel } => {
@@ -412,6 +539,13 @@ impl App {
         channel: AnimationTrackKind,
         edit: TrackEditOperation,
     ) {
+        let track = match channel {
+            AnimationTrackKind::Translation => ClipTrack::Translation,
+            AnimationTrackKind::Rotation => ClipTrack::Rotation,
+            AnimationTrackKind::Scale => ClipTrack::Scale,
+            AnimationTrackKind::Tint => ClipTrack::Tint,
+            AnimationTrackKind::SpriteTimeline => return,
+        };
         let Some(info) = self.ecs.entity_info(entity) else {
             return;
         };
@@ -421,57 +555,110 @@ impl App {
         let Some(source_clip) = self.clip_resource(&clip_info.clip_key) else {
             return;
         };
-        let before_arc = Arc::clone(&source_clip);
-        let mut clip = (*source_clip).clone();
-        let mut dirty = false;
-        match channel {
-            AnimationTrackKind::Translation => {
-                dirty = self.edit_vec2_track(
-                    &mut clip.translation,
-                    edit,
-                    clip_info.sample_translation.or(Some(info.translation)),
-                    Vec2::ZERO,
-                );
+        let key_count = source_clip.keyframe_count(track);
+        let result = match edit {
+            TrackEditOperation::Insert { time, value } => {
+                let value =
+                    value.and_then(|value| Self::clip_key_value(track, value)).unwrap_or(match track {
+                        ClipTrack::Translation => {
+                            ClipKeyValue::Vec2(clip_info.sample_translation.unwrap_or(info.translation))
+                        }
+                        ClipTrack::Rotation => {
+                            ClipKeyValue::Scalar(clip_info.sample_rotation.unwrap_or(info.rotation))
+                        }
+                        ClipTrack::Scale => ClipKeyValue::Vec2(clip_info.sample_scale.unwrap_or(info.scale)),
+                        ClipTrack::Tint => {
+                            ClipKeyValue::Vec4(clip_info.sample_tint.or(info.tint).unwrap_or(Vec4::ONE))
+                        }
+                    });
+                source_clip.insert_keyframe(track, time, value, false)
             }
-            AnimationTrackKind::Rotation => {
-                dirty = self.edit_scalar_track(
-                    &mut clip.rotation,
-                    edit,
-                    clip_info.sample_rotation.or(Some(info.rotation)),
-                    0.0,
-                );
+            TrackEditOperation::Paste { time, entries } => {
+                Self::paste_clip_keys(&source_clip, track, time, &entries)
             }
-            AnimationTrackKind::Scale => {
-                dirty = self.edit_vec2_track(
-                    &mut clip.scale,
-                    edit,
-                    clip_info.sample_scale.or(Some(info.scale)),
-                    Vec2::ONE,
-                );
+            TrackEditOperation::Delete { indices } => {
+                let indices: Vec<usize> = indices.into_iter().filter(|&index| index < key_count).collect();
+                if indices.is_empty() {
+                    return;
+                }
+                source_clip.delete_keyframes(track, &indices)
             }
-            AnimationTrackKind::Tint => {
-                dirty = self.edit_vec4_track(
-                    &mut clip.tint,
-                    edit,
-                    clip_info.sample_tint.or(info.tint),
-                    Vec4::ONE,
-                );
+            TrackEditOperation::Update { index, new_time, new_value } => {
+                if index >= key_count {
+                    return;
+                }
+                let mut result = Ok((*source_clip).clone());
+                if let Some(value) = new_value.and_then(|value| Self::clip_key_value(track, value)) {
+                    result = result.and_then(|clip| clip.set_keyframe_value(track, index, value));
+                }
+                if let Some(time) = new_time {
+                    result = result.and_then(|clip| clip.move_keyframe(track, index, time));
+                }
+                result
             }
-            AnimationTrackKind::SpriteTimeline => {}
-        }
-        if !dirty {
+            TrackEditOperation::Adjust { indices, time_delta, value_delta } => {
+                let indices: Vec<usize> = indices.into_iter().filter(|&index| index < key_count).collect();
+                if indices.is_empty() {
+                    return;
+                }
+                let value_delta = value_delta.and_then(|value| Self::clip_key_value(track, value));
+                source_clip.offset_keyframes(track, &indices, time_delta.unwrap_or(0.0), value_delta)
+            }
+            TrackEditOperation::Tangents { index, in_tangent, out_tangent } => {
+                if index >= key_count {
+                    return;
+                }
+                let in_tangent = in_tangent.and_then(|value| Self::clip_key_value(track, value));
+                let out_tangent = out_tangent.and_then(|value| Self::clip_key_value(track, value));
+                source_clip.set_keyframe_tangents(track, index, in_tangent, out_tangent)
+            }
+            TrackEditOperation::Scale { indices, time_pivot, time_scale, value_pivot, value_scale } => {
+                let indices: Vec<usize> = indices.into_iter().filter(|&index| index < key_count).collect();
+                if indices.is_empty() {
+                    return;
+                }
+                let value_scale = value_pivot
+                    .and_then(|pivot| Self::clip_key_value(track, pivot))
+                    .map(|pivot| (pivot, value_scale));
+                source_clip.scale_keyframes(track, &indices, time_pivot, time_scale, value_scale)
+            }
+        };
+        let clip = match result {
+            Ok(clip) => clip,
+            Err(err) => {
+                self.with_editor_ui_state_mut(|state| {
+                    state.animation_clip_status = Some(tr!(
+                        "animation.failed_to_edit_keys_on",
+                        track = track.label(),
+                        clip_key = clip_info.clip_key,
+                        error = err
+                    ));
+                });
+                return;
+            }
+        };
+        if clip.keyframes(track) == source_clip.keyframes(track) {
             return;
         }
-        self.recompute_clip_duration(&mut clip);
         let clip_arc = Arc::new(clip);
         self.with_editor_ui_state_mut(|state| {
             state.clip_edit_overrides.insert(clip_info.clip_key.clone(), Arc::clone(&clip_arc));
         });
         self.apply_clip_override_to_instances(&clip_info.clip_key, Arc::clone(&clip_arc));
-        self.record_clip_edit(&clip_info.clip_key, before_arc, Arc::clone(&clip_arc));
+        self.record_clip_edit(&clip_info.clip_key, source_clip, Arc::clone(&clip_arc));
         self.persist_clip_edit(&clip_info.clip_key, clip_arc);
     }
 
+    fn clip_key_value(track: ClipTrack, value: KeyframeValue) -> Option<ClipKeyValue> {
+        match track {
+            ClipTrack::Translation | ClipTrack::Scale => {
+                value.as_vec2().map(|v| ClipKeyValue::Vec2(Vec2::from(v)))
+            }
+            ClipTrack::Rotation => value.as_scalar().map(ClipKeyValue::Scalar),
+            ClipTrack::Tint => value.as_vec4().map(|v| ClipKeyValue::Vec4(Vec4::from(v))),
+        }
+    }
+
     fn sprite_key_details(
         track_id: AnimationTrackId,
         animation: &SpriteAnimationInfo,
@@ -500,6 +687,8 @@ impl App {
                         time,
                         value_preview: Some(preview),
                         value: KeyframeValue::None,
+                        in_tangent: KeyframeValue::None,
+                        out_tangent: KeyframeValue::None,
                     }
                 })
                 .collect()
@@ -511,6 +700,8 @@ impl App {
                     time: if index == animation.frame_index { Some(animation.frame_elapsed) } else { None },
                     value_preview: animation.frame_region.clone(),
                     value: KeyframeValue::None,
+                    in_tangent: KeyframeValue::None,
+                    out_tangent: KeyframeValue::None,
                 })
                 .collect()
         }
@@ -527,6 +718,8 @@ impl App {
                 time: Some(keyframe.time),
                 value_preview: Some(format!("({:.2}, {:.2})", keyframe.value.x, keyframe.value.y)),
                 value: KeyframeValue::Vec2([keyframe.value.x, keyframe.value.y]),
+                in_tangent: keyframe.in_tangent.map(|t| KeyframeValue::Vec2(t.into())).unwrap_or_default(),
+                out_tangent: keyframe.out_tangent.map(|t| KeyframeValue::Vec2(t.into())).unwrap_or_default(),
             })
             .collect()
     }
@@ -542,6 +735,8 @@ impl App {
                 time: Some(keyframe.time),
                 value_preview: Some(format!("{:.2}", keyframe.value)),
                 value: KeyframeValue::Scalar(keyframe.value),
+                in_tangent: keyframe.in_tangent.map_or(KeyframeValue::None, KeyframeValue::Scalar),
+                out_tangent: keyframe.out_tangent.map_or(KeyframeValue::None, KeyframeValue::Scalar),
             })
             .collect()
     }
@@ -553,6 +748,7 @@ impl App {
             .enumerate()
             .map(|(index, keyframe)| {
                 let value = keyframe.value;
+                let tangent = |t: Option<Vec4>| t.map(|t| KeyframeValue::Vec4(t.into())).unwrap_or_default();
                 KeyframeDetail {
                     id: KeyframeId::new(track_id, index),
                     index,
@@ -562,6 +758,8 @@ impl App {
                         value.x, value.y, value.z, value.w
                     )),
                     value: KeyframeValue::Vec4([value.x, value.y, value.z, value.w]),
+                    in_tangent: tangent(keyframe.in_tangent),
+                    out_tangent: tangent(keyframe.out_tangent),
                 }
             })
             .collect()
@@ -580,6 +778,8 @@ impl App {
                     time: Some(time),
                     value_preview: Some(preview),
                     value: KeyframeValue::None,
+                    in_tangent: KeyframeValue::None,
+                    out_tangent: KeyframeValue::None,
                 }]
             })
             .unwrap_or_default()
@@ -617,7 +817,8 @@ impl App {
             self.with_editor_ui_state_mut(|state| state.clip_edit_redo.push(record.clone()));
             self.apply_clip_history_state(&record.clip_key, Arc::clone(&record.before));
             self.with_editor_ui_state_mut(|state| {
-                state.animation_clip_status = Some(format!("Undid edit on '{}'", record.clip_key));
+                state.animation_clip_status =
+                    Some(tr!("animation.undid_edit_on", clip_key = record.clip_key));
             });
         }
     }
@@ -628,7 +829,7 @@ impl App {
             self.with_editor_ui_state_mut(|state| state.clip_edit_history.push(record.clone()));
             self.apply_clip_history_state(&clip_key, Arc::clone(&record.after));
             self.with_editor_ui_state_mut(|state| {
-                state.animation_clip_status = Some(format!("Redid edit on '{}'", clip_key));
+                state.animation_clip_status = Some(tr!("animation.redid_edit_on", clip_key = clip_key));
             });
         }
     }
@@ -652,7 +853,8 @@ impl App {
         if let Err(err) = self.assets.save_clip(clip_key, clip.as_ref()) {
             eprintln!("[animation] failed to save clip '{clip_key}': {err:?}");
             self.with_editor_ui_state_mut(|state| {
-                state.animation_clip_status = Some(format!("Failed to save '{clip_key}': {err}"));
+                state.animation_clip_status =
+                    Some(tr!("animation.failed_to_save", clip_key = clip_key, error = err));
             });
             return;
         }
@@ -661,7 +863,8 @@ impl App {
             if let Err(err) = self.assets.load_clip(clip_key, path) {
                 eprintln!("[animation] failed to reload clip '{clip_key}' after save: {err:?}");
                 self.with_editor_ui_state_mut(|state| {
-                    state.animation_clip_status = Some(format!("Reload failed for '{clip_key}': {err}"));
+                    state.animation_clip_status =
+                        Some(tr!("animation.reload_failed_for", clip_key = clip_key, error = err));
                 });
                 return;
             }
@@ -687,485 +890,10 @@ impl App {
             state.animation_clip_status = Some(status_note);
         });
     }
-
-    fn edit_vec2_track(
-        &self,
-        target: &mut Option<ClipVec2Track>,
-        edit: TrackEditOperation,
-        sample: Option<Vec2>,
-        fallback: Vec2,
-    ) -> bool {
-        let interpolation =
-            target.as_ref().map(|track| track.interpolation).unwrap_or(ClipInterpolation::Linear);
-        let mut frames: Vec<ClipKeyframe<Vec2>> =
-            target.as_ref().map(|track| track.keyframes.iter().copied().collect()).unwrap_or_default();
-        match edit {
-            TrackEditOperation::Insert { time, value } => {
-                let insert_value = value
-                    .and_then(|v| v.as_vec2())
-                    .map(|arr| Vec2::new(arr[0], arr[1]))
-                    .or(sample)
-                    .unwrap_or(fallback);
-                frames.push(ClipKeyframe { time, value: insert_value });
-            }
-            TrackEditOperation::Delete { indices } => Self::remove_key_indices(&mut frames, &indices),
-            TrackEditOperation::Update { index, new_time, new_value } => {
-                if frames.is_empty() || index >= frames.len() {
-                    return false;
-                }
-                let mut changed = false;
-                if let Some(time) = new_time {
-                    let clamped = time.max(0.0);
-                    if (frames[index].time - clamped).abs() > f32::EPSILON {
-                        frames[index].time = clamped;
-                        changed = true;
-                    }
-                }
-                if let Some(KeyframeValue::Vec2(value)) = new_value {
-                    let new_vec = Vec2::new(value[0], value[1]);
-                    if frames[index].value != new_vec {
-                        frames[index].value = new_vec;
-                        changed = true;
-                    }
-                }
-                if !changed {
-                    return false;
-                }
-            }
-            TrackEditOperation::Adjust { indices, time_delta, value_delta } => {
-                if frames.is_empty() {
-                    return false;
-                }
-                let mut changed = false;
-                for index in indices {
-                    if index >= frames.len() {
-                        continue;
-                    }
-                    if let Some(delta) = time_delta {
-                        let clamped = (frames[index].time + delta).max(0.0);
-                        if (frames[index].time - clamped).abs() > f32::EPSILON {
-                            frames[index].time = clamped;
-                            changed = true;
-                        }
-                    }
-                    if let Some(KeyframeValue::Vec2(offset)) = value_delta {
-                        let offset_vec = Vec2::new(offset[0], offset[1]);
-                        let new_value = frames[index].value + offset_vec;
-                        if frames[index].value != new_value {
-                            frames[index].value = new_value;
-                            changed = true;
-                        }
-                    }
-                }
-                if !changed {
-                    return false;
-                }
-            }
-        }
-        Self::apply_vec2_frames(target, frames, interpolation)
-    }
-
-    fn edit_scalar_track(
-        &self,
-        target: &mut Option<ClipScalarTrack>,
-        edit: TrackEditOperation,
-        sample: Option<f32>,
-        fallback: f32,
-    ) -> bool {
-        let interpolation =
-            target.as_ref().map(|track| track.interpolation).unwrap_or(ClipInterpolation::Linear);
-        let mut frames: Vec<ClipKeyframe<f32>> =
-            target.as_ref().map(|track| track.keyframes.iter().copied().collect()).unwrap_or_default();
-        match edit {
-            TrackEditOperation::Insert { time, value } => {
-                let insert_value = value.and_then(|v| v.as_scalar()).or(sample).unwrap_or(fallback);
-                frames.push(ClipKeyframe { time, value: insert_value });
-            }
-            TrackEditOperation::Delete { indices } => Self::remove_key_indices(&mut frames, &indices),
-            TrackEditOperation::Update { index, new_time, new_value } => {
-                if frames.is_empty() || index >= frames.len() {
-                    return false;
-                }
-                let mut changed = false;
-                if let Some(time) = new_time {
-                    let clamped = time.max(0.0);
-                    if (frames[index].time - clamped).abs() > f32::EPSILON {
-                        frames[index].time = clamped;
-                        changed = true;
-                    }
-                }
-                if let Some(KeyframeValue::Scalar(value)) = new_value {
-                    if (frames[index].value - value).abs() > f32::EPSILON {
-                        frames[index].value = value;
-                        changed = true;
-                    }
-                }
-                if !changed {
-                    return false;
-                }
-            }
-            TrackEditOperation::Adjust { indices, time_delta, value_delta } => {
-                if frames.is_empty() {
-                    return false;
-                }
-                let mut changed = false;
-                for index in indices {
-                    if index >= frames.len() {
-                        continue;
-                    }
-                    if let Some(delta) = time_delta {
-                        let clamped = (frames[index].time + delta).max(0.0);
-                        if (frames[index].time - clamped).abs() > f32::EPSILON {
-                            frames[index].time = clamped;
-                            changed = true;
-                        }
-                    }
-                    if let Some(KeyframeValue::Scalar(offset)) = value_delta {
-                        let new_value = frames[index].value + offset;
-                        if (frames[index].value - new_value).abs() > f32::EPSILON {
-                            frames[index].value = new_value;
-                            changed = true;
-                        }
-                    }
-                }
-                if !changed {
-                    return false;
-                }
-            }
-        }
-        Self::apply_scalar_frames(target, frames, interpolation)
-    }
-
-    fn edit_vec4_track(
-        &self,
-        target: &mut Option<ClipVec4Track>,
-        edit: TrackEditOperation,
-        sample: Option<Vec4>,
-        fallback: Vec4,
-    ) -> bool {
-        let interpolation =
-            target.as_ref().map(|track| track.interpolation).unwrap_or(ClipInterpolation::Linear);
-        let mut frames: Vec<ClipKeyframe<Vec4>> =
-            target.as_ref().map(|track| track.keyframes.iter().copied().collect()).unwrap_or_default();
-        match edit {
-            TrackEditOperation::Insert { time, value } => {
-                let insert_value = value
-                    .and_then(|v| v.as_vec4())
-                    .map(|arr| Vec4::new(arr[0], arr[1], arr[2], arr[3]))
-                    .or(sample)
-                    .unwrap_or(fallback);
-                frames.push(ClipKeyframe { time, value: insert_value });
-            }
-            TrackEditOperation::Delete { indices } => Self::remove_key_indices(&mut frames, &indices),
-            TrackEditOperation::Update { index, new_time, new_value } => {
-                if frames.is_empty() || index >= frames.len() {
-                    return false;
-                }
-                let mut changed = false;
-                if let Some(time) = new_time {
-                    let clamped = time.max(0.0);
-                    if (frames[index].time - clamped).abs() > f32::EPSILON {
-                        frames[index].time = clamped;
-                        changed = true;
-                    }
-                }
-                if let Some(KeyframeValue::Vec4(value)) = new_value {
-                    let new_vec = Vec4::new(value[0], value[1], value[2], value[3]);
-                    if frames[index].value != new_vec {
-                        frames[index].value = new_vec;
-                        changed = true;
-                    }
-                }
-                if !changed {
-                    return false;
-                }
-            }
-            TrackEditOperation::Adjust { indices, time_delta, value_delta } => {
-                if frames.is_empty() {
-                    return false;
-                }
-                let mut changed = false;
-                for index in indices {
-                    if index >= frames.len() {
-                        continue;
-                    }
-                    if let Some(delta) = time_delta {
-                        let clamped = (frames[index].time + delta).max(0.0);
-                        if (frames[index].time - clamped).abs() > f32::EPSILON {
-                            frames[index].time = clamped;
-                            changed = true;
-                        }
-                    }
-                    if let Some(KeyframeValue::Vec4(offset)) = value_delta {
-                        let offset_vec = Vec4::new(offset[0], offset[1], offset[2], offset[3]);
-                        let new_value = frames[index].value + offset_vec;
-                        if frames[index].value != new_value {
-                            frames[index].value = new_value;
-                            changed = true;
-                        }
-                    }
-                }
-                if !changed {
-                    return false;
-                }
-            }
-        }
-        Self::apply_vec4_frames(target, frames, interpolation)
-    }
-
-    fn remove_key_indices<T>(frames: &mut Vec<ClipKeyframe<T>>, indices: &[usize]) {
-        if frames.is_empty() || indices.is_empty() {
-            return;
-        }
-        let mut sorted = indices.to_vec();
-        sorted.sort_unstable_by(|a, b| b.cmp(a));
-        for index in sorted {
-            if index < frames.len() {
-                frames.remove(index);
-            }
-        }
-    }
-
-    fn apply_vec2_frames(
-        target: &mut Option<ClipVec2Track>,
-        frames: Vec<ClipKeyframe<Vec2>>,
-        interpolation: ClipInterpolation,
-    ) -> bool {
-        if frames.is_empty() {
-            let had_track = target.is_some();
-            *target = None;
-            return had_track;
-        }
-        let normalized = Self::normalize_keyframes(frames);
-        let track = Self::build_vec2_track_from_frames(interpolation, normalized);
-        let changed = target
-            .as_ref()
-            .map(|existing| {
-                existing.keyframes.len() != track.keyframes.len() || existing.duration != track.duration
-            })
-            .unwrap_or(true);
-        *target = Some(track);
-        changed
-    }
-
-    fn apply_scalar_frames(
-        target: &mut Option<ClipScalarTrack>,
-        frames: Vec<ClipKeyframe<f32>>,
-        interpolation: ClipInterpolation,
-    ) -> bool {
-        if frames.is_empty() {
-            let had_track = target.is_some();
-            *target = None;
-            return had_track;
-        }
-        let normalized = Self::normalize_keyframes(frames);
-        let track = Self::build_scalar_track_from_frames(interpolation, normalized);
-        let changed = target
-            .as_ref()
-            .map(|existing| {
-                existing.keyframes.len() != track.keyframes.len() || existing.duration != track.duration
-            })
-            .unwrap_or(true);
-        *target = Some(track);
-        changed
-    }
-
-    fn apply_vec4_frames(
-        target: &mut Option<ClipVec4Track>,
-        frames: Vec<ClipKeyframe<Vec4>>,
-        interpolation: ClipInterpolation,
-    ) -> bool {
-        if frames.is_empty() {
-            let had_track = target.is_some();
-            *target = None;
-            return had_track;
-        }
-        let normalized = Self::normalize_keyframes(frames);
-        let track = Self::build_vec4_track_from_frames(interpolation, normalized);
-        let changed = target
-            .as_ref()
-            .map(|existing| {
-                existing.keyframes.len() != track.keyframes.len() || existing.duration != track.duration
-            })
-            .unwrap_or(true);
-        *target = Some(track);
-        changed
-    }
-
-    fn normalize_keyframes<T: Copy>(mut frames: Vec<ClipKeyframe<T>>) -> Vec<ClipKeyframe<T>> {
-        if frames.is_empty() {
-            return frames;
-        }
-        frames.sort_by(|a, b| a.time.partial_cmp(&b.time).unwrap_or(Ordering::Equal));
-        let mut normalized: Vec<ClipKeyframe<T>> = Vec::with_capacity(frames.len());
-        for mut frame in frames {
-            frame.time = frame.time.max(0.0);
-            if let Some(last) = normalized.last_mut() {
-                if (last.time - frame.time).abs() < 1e-4 {
-                    *last = frame;
-                    continue;
-                }
-            }
-            normalized.push(frame);
-        }
-        normalized
-    }
-
-    fn build_vec2_track_from_frames(
-        interpolation: ClipInterpolation,
-        frames: Vec<ClipKeyframe<Vec2>>,
-    ) -> ClipVec2Track {
-        let duration = frames.last().map(|frame| frame.time).unwrap_or(0.0);
-        let duration_inv = if duration > 0.0 { 1.0 / duration } else { 0.0 };
-        let (segment_deltas, segments, segment_offsets) = Self::build_segment_cache_vec2(&frames);
-        ClipVec2Track {
-            interpolation,
-            keyframes: Arc::from(frames.into_boxed_slice()),
-            duration,
-            duration_inv,
-            segment_deltas,
-            segments,
-            segment_offsets,
-        }
-    }
-
-    fn build_scalar_track_from_frames(
-        interpolation: ClipInterpolation,
-        frames: Vec<ClipKeyframe<f32>>,
-    ) -> ClipScalarTrack {
-        let duration = frames.last().map(|frame| frame.time).unwrap_or(0.0);
-        let duration_inv = if duration > 0.0 { 1.0 / duration } else { 0.0 };
-        let (segment_deltas, segments, segment_offsets) = Self::build_segment_cache_scalar(&frames);
-        ClipScalarTrack {
-            interpolation,
-            keyframes: Arc::from(frames.into_boxed_slice()),
-            duration,
-            duration_inv,
-            segment_deltas,
-            segments,
-            segment_offsets,
-        }
-    }
-
-    fn build_vec4_track_from_frames(
-        interpolation: ClipInterpolation,
-        frames: Vec<ClipKeyframe<Vec4>>,
-    ) -> ClipVec4Track {
-        let duration = frames.last().map(|frame| frame.time).unwrap_or(0.0);
-        let duration_inv = if duration > 0.0 { 1.0 / duration } else { 0.0 };
-        let (segment_deltas, segments, segment_offsets) = Self::build_segment_cache_vec4(&frames);
-        ClipVec4Track {
-            interpolation,
-            keyframes: Arc::from(frames.into_boxed_slice()),
-            duration,
-            duration_inv,
-            segment_deltas,
-            segments,
-            segment_offsets,
-        }
-    }
-
-    #[allow(clippy::type_complexity)]
-    fn build_segment_cache_vec2(
-        frames: &[ClipKeyframe<Vec2>],
-    ) -> (Arc<[Vec2]>, Arc<[ClipSegment<Vec2>]>, Arc<[f32]>) {
-        if frames.len() < 2 {
-            return (Arc::from([]), Arc::from([]), Arc::from([]));
-        }
-        let mut deltas = Vec::with_capacity(frames.len() - 1);
-        let mut segments = Vec::with_capacity(frames.len() - 1);
-        let mut offsets = Vec::with_capacity(frames.len() - 1);
-        for window in frames.windows(2) {
-            let start = &window[0];
-            let end = &window[1];
-            let span = (end.time - start.time).max(f32::EPSILON);
-            let inv_span = 1.0 / span;
-            offsets.push(start.time);
-            let delta = end.value - start.value;
-            deltas.push(delta);
-            segments.push(ClipSegment { slope: delta * inv_span, span, inv_span });
-        }
-        (
-            Arc::from(deltas.into_boxed_slice()),
-            Arc::from(segments.into_boxed_slice()),
-            Arc::from(offsets.into_boxed_slice()),
-        )
-    }
-
-    #[allow(clippy::type_complexity)]
-    fn build_segment_cache_scalar(
-        frames: &[ClipKeyframe<f32>],
-    ) -> (Arc<[f32]>, Arc<[ClipSegment<f32>]>, Arc<[f32]>) {
-        if frames.len() < 2 {
-            return (Arc::from([]), Arc::from([]), Arc::from([]));
-        }
-        let mut deltas = Vec::with_capacity(frames.len() - 1);
-        let mut segments = Vec::with_capacity(frames.len() - 1);
-        let mut offsets = Vec::with_capacity(frames.len() - 1);
-        for window in frames.windows(2) {
-            let start = &window[0];
-            let end = &window[1];
-            let span = (end.time - start.time).max(f32::EPSILON);
-            let inv_span = 1.0 / span;
-            offsets.push(start.time);
-            let delta = end.value - start.value;
-            deltas.push(delta);
-            segments.push(ClipSegment { slope: delta * inv_span, span, inv_span });
-        }
-        (
-            Arc::from(deltas.into_boxed_slice()),
-            Arc::from(segments.into_boxed_slice()),
-            Arc::from(offsets.into_boxed_slice()),
-        )
-    }
-
-    #[allow(clippy::type_complexity)]
-    fn build_segment_cache_vec4(
-        frames: &[ClipKeyframe<Vec4>],
-    ) -> (Arc<[Vec4]>, Arc<[ClipSegment<Vec4>]>, Arc<[f32]>) {
-        if frames.len() < 2 {
-            return (Arc::from([]), Arc::from([]), Arc::from([]));
-        }
-        let mut deltas = Vec::with_capacity(frames.len() - 1);
-        let mut segments = Vec::with_capacity(frames.len() - 1);
-        let mut offsets = Vec::with_capacity(frames.len() - 1);
-        for window in frames.windows(2) {
-            let start = &window[0];
-            let end = &window[1];
-            let span = (end.time - start.time).max(f32::EPSILON);
-            let inv_span = 1.0 / span;
-            offsets.push(start.time);
-            let delta = end.value - start.value;
-            deltas.push(delta);
-            segments.push(ClipSegment { slope: delta * inv_span, span, inv_span });
-        }
-        (
-            Arc::from(deltas.into_boxed_slice()),
-            Arc::from(segments.into_boxed_slice()),
-            Arc::from(offsets.into_boxed_slice()),
-        )
-    }
-
-    fn recompute_clip_duration(&self, clip: &mut AnimationClip) {
-        let mut duration = 0.0_f32;
-        if let Some(track) = clip.translation.as_ref() {
-            duration = duration.max(track.duration);
-        }
-        if let Some(track) = clip.rotation.as_ref() {
-            duration = duration.max(track.duration);
-        }
-        if let Some(track) = clip.scale.as_ref() {
-            duration = duration.max(track.duration);
-        }
-        if let Some(track) = clip.tint.as_ref() {
-            duration = duration.max(track.duration);
-        }
-        clip.duration = duration;
-        clip.duration_inv = if duration > 0.0 { 1.0 / duration } else { 0.0 };
-    }
 }
 #[cfg(test)]
 mod tests {
+    use super::super::animation_keyframe_panel::AnimationKeyframePanel;
     use super::*;
     use crate::ecs::{SpriteAnimationFrame, SpriteAnimationLoopMode, SpriteFrameHotData, TransformClipInfo};
     use glam::{Vec2, Vec4};
@@ -1190,6 +918,7 @@ mod tests {
             start_offset: 0.0,
             random_start: false,
             group: Some("default".to_string()),
+            loop_count: None,
         };
         let track_id = AnimationTrackId::for_entity_slot(Entity::from_raw(1), 0);
         let details = App::sprite_key_details(track_id, &animation, None);
@@ -1204,10 +933,12 @@ mod tests {
             clip_key: "transform_clip".to_string(),
             playing: true,
             looped: false,
+            loop_count: None,
             speed: 1.0,
             time: 0.5,
             duration: 2.0,
             group: None,
+            events: Vec::new(),
             has_translation: true,
             has_rotation: true,
             has_scale: false,
@@ -1249,6 +980,7 @@ mod tests {
             start_offset: 0.0,
             random_start: false,
             group: None,
+            loop_count: None,
         };
         let frames = vec![
             SpriteAnimationFrame {
@@ -1290,4 +1022,54 @@ mod tests {
         assert_eq!(details[1].time, Some(0.5));
         assert!(details[1].value_preview.as_ref().unwrap().contains("0.75"));
     }
+
+    fn translation_clip(name: &str, keys: &str) -> AnimationClip {
+        let json = format!(
+            r#"{{"version": 1, "name": "{name}", "tracks": {{"translation": {{"interpolation": "linear", "keyframes": [{keys}]}}}}}}"#
+        );
+        crate::assets::parse_animation_clip_bytes(json.as_bytes(), name, "test").expect("parse clip")
+    }
+
+    #[test]
+    fn pasted_translation_keys_shift_to_the_playhead_of_another_clip() {
+        let source = translation_clip(
+            "source",
+            r#"{"time": 0.0, "value": [0, 0]}, {"time": 0.2, "value": [1, 2]}, {"time": 0.5, "value": [3, 4]}"#,
+        );
+        let target =
+            translation_clip("target", r#"{"time": 0.0, "value": [0, 0]}, {"time": 1.0, "value": [5, 5]}"#);
+
+        let entity = Entity::from_raw(3);
+        let track_id = AnimationTrackId::for_entity_slot(entity, 1);
+        let mut panel = AnimationKeyframePanel::default();
+        let summary = AnimationTrackSummary {
+            id: track_id,
+            label: "Translation".to_string(),
+            kind: AnimationTrackKind::Translation,
+            binding: AnimationTrackBinding::TransformChannel {
+                entity,
+                channel: AnimationTrackKind::Translation,
+            },
+            duration: source.duration,
+            key_count: 3,
+            interpolation: None,
+            playhead: None,
+            dirty: false,
+            key_details: App::vec2_track_details(track_id, source.translation.as_ref().unwrap()),
+        };
+        let clipboard = panel.copy_keys_for_test(&summary, &[1, 2]);
+        assert_eq!(clipboard.kind, AnimationTrackKind::Translation);
+        assert_eq!(clipboard.entries.len(), 2);
+
+        let pasted = App::paste_clip_keys(&target, ClipTrack::Translation, 0.6, &clipboard.entries)
+            .expect("paste into target clip");
+        let keys = pasted.keyframes(ClipTrack::Translation);
+        let times: Vec<f32> = keys.iter().map(|key| key.time).collect();
+        assert_eq!(times.len(), 4);
+        assert!((times[1] - 0.6).abs() < 1e-5, "first pasted key lands on the playhead: {times:?}");
+        assert!((times[2] - 0.9).abs() < 1e-5, "relative spacing is kept: {times:?}");
+        assert_eq!(keys[1].value, ClipKeyValue::Vec2(Vec2::new(1.0, 2.0)));
+        assert_eq!(keys[2].value, ClipKeyValue::Vec2(Vec2::new(3.0, 4.0)));
+        assert_eq!(target.keyframe_count(ClipTrack::Translation), 2, "target is kept for undo");
+    }
 }
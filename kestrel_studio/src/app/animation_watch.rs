@@ -13,16 +13,18 @@ pub enum AnimationAssetKind {
     Clip,
     Graph,
     Skeletal,
+    ColorCurve,
 }
 
 impl AnimationAssetKind {
-    pub const COUNT: usize = 3;
+    pub const COUNT: usize = 4;
 
     pub fn label(self) -> &'static str {
         match self {
             AnimationAssetKind::Clip => "clip",
             AnimationAssetKind::Graph => "graph",
             AnimationAssetKind::Skeletal => "skeletal",
+            AnimationAssetKind::ColorCurve => "color_curve",
         }
     }
 
@@ -31,6 +33,7 @@ impl AnimationAssetKind {
             AnimationAssetKind::Clip => 0,
             AnimationAssetKind::Graph => 1,
             AnimationAssetKind::Skeletal => 2,
+            AnimationAssetKind::ColorCurve => 3,
         }
     }
 }
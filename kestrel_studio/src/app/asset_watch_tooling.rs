@@ -2,6 +2,7 @@ use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 
 use super::{
+    animation_reload::AnimationReloadRequest,
     animation_watch::{AnimationAssetKind, AnimationAssetWatcher},
     atlas_watch::normalize_path_for_watch,
     mesh_reload::run_mesh_reload_job,
@@ -9,15 +10,20 @@ use super::{
     App,
 };
 use crate::assets::TextureAtlasDiagnostics;
+use crate::mesh::MeshImportSettings;
+use crate::plugins::AssetKind;
 use anyhow::Result;
 
 const MAX_MESH_RELOADS_PER_FRAME: usize = 1;
 
 impl App {
     pub fn hot_reload_atlas(&mut self, key: &str) -> Result<(usize, TextureAtlasDiagnostics)> {
-        let diagnostics = self.assets.reload_atlas(key)?;
+        let previous_regions: HashSet<String> = self.assets.atlas_region_names(key).into_iter().collect();
+        let previous_timelines: HashSet<String> = self.assets.atlas_timeline_names(key).into_iter().collect();
+        let mut diagnostics = self.assets.reload_atlas(key)?;
         self.invalidate_atlas_view(key);
         let refreshed = self.ecs.refresh_sprite_animations_for_atlas(key, &self.assets);
+        diagnostics.warnings.extend(self.check_atlas_references(key, &previous_regions, &previous_timelines));
         Ok((refreshed, diagnostics))
     }
 
@@ -77,6 +83,9 @@ impl App {
         for (_, source) in self.assets.animation_graph_sources() {
             self.queue_animation_watch_root(Path::new(&source), AnimationAssetKind::Graph);
         }
+        for (_, source) in self.assets.color_curve_sources() {
+            self.queue_animation_watch_root(Path::new(&source), AnimationAssetKind::ColorCurve);
+        }
     }
 
     pub(super) fn queue_animation_watch_root(&mut self, path: &Path, kind: AnimationAssetKind) {
@@ -119,6 +128,7 @@ impl App {
             (asset_root.join("animations/clips"), AnimationAssetKind::Clip),
             (asset_root.join("animations/graphs"), AnimationAssetKind::Graph),
             (asset_root.join("animations/skeletal"), AnimationAssetKind::Skeletal),
+            (asset_root.join("animations/curves"), AnimationAssetKind::ColorCurve),
         ];
         for (path, kind) in watch_roots {
             let path = path.as_path();
@@ -160,12 +170,78 @@ impl App {
         self.drain_animation_validation_results();
     }
 
+    /// Routes reloads requested through `PluginContext::request_asset_reload` into the same queues
+    /// the file watchers feed.
+    pub(super) fn process_plugin_asset_reload_requests(&mut self) {
+        for request in self.plugin_manager_mut().drain_asset_reload_requests() {
+            let key = request.key;
+            let animation_kind = match request.kind {
+                AssetKind::Atlas => {
+                    self.atlas_reload_requests.push(key);
+                    continue;
+                }
+                AssetKind::Material => {
+                    self.queue_material_reload(&key);
+                    continue;
+                }
+                AssetKind::Clip => AnimationAssetKind::Clip,
+                AssetKind::Graph => AnimationAssetKind::Graph,
+                AssetKind::Skeleton => AnimationAssetKind::Skeletal,
+            };
+            let source = match animation_kind {
+                AnimationAssetKind::Clip => self.assets.clip_source(&key),
+                AnimationAssetKind::Graph => self.assets.animation_graph_source(&key),
+                AnimationAssetKind::Skeletal => self.assets.skeleton_source(&key),
+                AnimationAssetKind::ColorCurve => self.assets.color_curve_source(&key),
+            };
+            let Some(path) = source.map(PathBuf::from) else {
+                eprintln!(
+                    "[assets] Plugin reload skipped for {} '{key}': no source path recorded",
+                    request.kind.label()
+                );
+                continue;
+            };
+            self.enqueue_animation_reload(AnimationReloadRequest {
+                path,
+                key,
+                kind: animation_kind,
+                skip_validation: false,
+            });
+        }
+    }
+
+    /// Materials come from glTF imports, so reloading one re-imports every mesh sharing its source.
+    fn queue_material_reload(&mut self, key: &str) {
+        let Some(source) = self.material_registry.material_source(key).map(PathBuf::from) else {
+            eprintln!("[assets] Plugin reload skipped for material '{key}': no source path recorded");
+            return;
+        };
+        let meshes: Vec<String> = self
+            .mesh_registry
+            .keys()
+            .filter(|mesh| self.mesh_registry.mesh_source(mesh) == Some(source.as_path()))
+            .map(|mesh| mesh.to_string())
+            .collect();
+        if meshes.is_empty() {
+            eprintln!(
+                "[assets] Plugin reload skipped for material '{key}': no mesh imported from {}",
+                source.display()
+            );
+        }
+        for mesh in meshes {
+            if self.mesh_hot_reload_pending_set.insert(mesh.clone()) {
+                self.mesh_hot_reload_pending.push_back(mesh);
+            }
+        }
+    }
+
     pub(super) fn process_atlas_hot_reload_events(&mut self) {
-        let keys = if let Some(watcher) = self.atlas_hot_reload.as_mut() {
+        let mut keys = if let Some(watcher) = self.atlas_hot_reload.as_mut() {
             watcher.drain_keys()
         } else {
             Vec::new()
         };
+        keys.append(&mut self.atlas_reload_requests);
         if keys.is_empty() {
             return;
         }
@@ -198,6 +274,13 @@ impl App {
                 if let Some((original, normalized)) = normalize_path_for_watch(path) {
                     desired.push((original, normalized, key.to_string()));
                 }
+                // Editing the import settings sidecar re-imports the file with the new options.
+                let sidecar = MeshImportSettings::sidecar_path(path);
+                if sidecar.exists() {
+                    if let Some((original, normalized)) = normalize_path_for_watch(&sidecar) {
+                        desired.push((original, normalized, key.to_string()));
+                    }
+                }
             }
         }
         if let Err(err) = watcher.sync(&desired) {
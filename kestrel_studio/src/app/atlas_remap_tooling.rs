@@ -0,0 +1,222 @@
+use super::*;
+use crate::ecs::{AtlasReferenceKind, MissingAtlasReference};
+use std::collections::BTreeMap;
+
+const MAX_LISTED_ENTITIES: usize = 8;
+
+/// Sprites an atlas hot-reload left pointing at regions or timelines the atlas no longer defines,
+/// grouped by the missing name so each group can be remapped in one action.
+pub(crate) struct AtlasRemapDialog {
+    pub atlas: String,
+    pub groups: Vec<AtlasRemapGroup>,
+    pub region_names: Vec<String>,
+    pub timeline_names: Vec<String>,
+}
+
+pub(crate) struct AtlasRemapGroup {
+    pub kind: AtlasReferenceKind,
+    pub name: String,
+    pub entities: Vec<Entity>,
+    pub replacement: String,
+}
+
+impl AtlasRemapGroup {
+    fn label(&self) -> &'static str {
+        match self.kind {
+            AtlasReferenceKind::Region => "region",
+            AtlasReferenceKind::Timeline => "timeline",
+        }
+    }
+}
+
+fn group_missing_references(missing: Vec<MissingAtlasReference>) -> Vec<AtlasRemapGroup> {
+    let mut grouped: BTreeMap<(AtlasReferenceKind, String), Vec<Entity>> = BTreeMap::new();
+    for reference in missing {
+        grouped.entry((reference.kind, reference.name.to_string())).or_default().push(reference.entity);
+    }
+    grouped
+        .into_iter()
+        .map(|((kind, name), entities)| AtlasRemapGroup { kind, name, entities, replacement: String::new() })
+        .collect()
+}
+
+fn format_entity_list(entities: &[Entity]) -> String {
+    let mut listed: Vec<String> =
+        entities.iter().take(MAX_LISTED_ENTITIES).map(|entity| format!("#{:04}", entity.index())).collect();
+    if entities.len() > MAX_LISTED_ENTITIES {
+        listed.push(format!("and {} more", entities.len() - MAX_LISTED_ENTITIES));
+    }
+    listed.join(", ")
+}
+
+/// Picks the obvious replacement when the reload removed exactly one name and added exactly one,
+/// which is what a rename looks like.
+fn suggest_replacement(name: &str, previous: &HashSet<String>, current: &[String]) -> Option<String> {
+    if !previous.contains(name) {
+        return None;
+    }
+    let removed = previous.iter().filter(|old| !current.contains(old)).count();
+    let mut added = current.iter().filter(|new| !previous.contains(*new));
+    match (removed, added.next(), added.next()) {
+        (1, Some(new), None) => Some(new.clone()),
+        _ => None,
+    }
+}
+
+impl App {
+    /// Diffs the atlas against its pre-reload region/timeline names, opens the remap dialog for any
+    /// sprites that reference names the atlas no longer defines, and returns validation warnings
+    /// naming the affected entities.
+    pub(super) fn check_atlas_references(
+        &mut self,
+        key: &str,
+        previous_regions: &HashSet<String>,
+        previous_timelines: &HashSet<String>,
+    ) -> Vec<String> {
+        let missing = self.ecs.missing_atlas_references(key, &self.assets);
+        let region_names = self.assets.atlas_region_names(key);
+        let mut timeline_names = self.assets.atlas_timeline_names(key);
+        timeline_names.sort();
+        let mut groups = group_missing_references(missing);
+        let mut warnings = Vec::with_capacity(groups.len());
+        for group in &mut groups {
+            let (previous, current) = match group.kind {
+                AtlasReferenceKind::Region => (previous_regions, &region_names),
+                AtlasReferenceKind::Timeline => (previous_timelines, &timeline_names),
+            };
+            let change = if previous.contains(&group.name) { "was removed" } else { "is missing" };
+            warnings.push(format!(
+                "atlas '{key}': {} '{}' {change} but is still used by {} entit{}: {}",
+                group.label(),
+                group.name,
+                group.entities.len(),
+                if group.entities.len() == 1 { "y" } else { "ies" },
+                format_entity_list(&group.entities)
+            ));
+            group.replacement = suggest_replacement(&group.name, previous, current).unwrap_or_default();
+        }
+        self.with_editor_ui_state_mut(|state| {
+            if !groups.is_empty() {
+                state.atlas_remap_dialog =
+                    Some(AtlasRemapDialog { atlas: key.to_string(), groups, region_names, timeline_names });
+            } else if state.atlas_remap_dialog.as_ref().is_some_and(|dialog| dialog.atlas == key) {
+                state.atlas_remap_dialog = None;
+            }
+        });
+        warnings
+    }
+
+    /// Applies the chosen replacement to every sprite in a group. Returns the number updated.
+    fn apply_atlas_remap(&mut self, atlas: &str, kind: AtlasReferenceKind, from: &str, to: &str) -> usize {
+        match kind {
+            AtlasReferenceKind::Region => self.ecs.remap_sprite_region(atlas, from, to, &self.assets),
+            AtlasReferenceKind::Timeline => self.ecs.remap_sprite_timeline(atlas, from, to, &self.assets),
+        }
+    }
+
+    pub(super) fn show_atlas_remap_dialog(&mut self, ctx: &egui::Context) {
+        let Some(mut dialog) = self.with_editor_ui_state_mut(|state| state.atlas_remap_dialog.take()) else {
+            return;
+        };
+        let mut open = true;
+        let mut apply: Vec<usize> = Vec::new();
+        let mut select: Option<Entity> = None;
+        egui::Window::new("Atlas References").open(&mut open).resizable(true).default_width(420.0).show(
+            ctx,
+            |ui| {
+                ui.label(format!(
+                    "Atlas '{}' no longer defines names that sprites in the scene still use.",
+                    dialog.atlas
+                ));
+                ui.separator();
+                for (index, group) in dialog.groups.iter_mut().enumerate() {
+                    ui.push_id(index, |ui| {
+                        ui.strong(format!(
+                            "Missing {} '{}' ({} entit{})",
+                            group.label(),
+                            group.name,
+                            group.entities.len(),
+                            if group.entities.len() == 1 { "y" } else { "ies" }
+                        ));
+                        egui::CollapsingHeader::new("Affected entities").default_open(false).show(ui, |ui| {
+                            ui.horizontal_wrapped(|ui| {
+                                for entity in &group.entities {
+                                    if ui.link(format!("#{:04}", entity.index())).clicked() {
+                                        select = Some(*entity);
+                                    }
+                                }
+                            });
+                        });
+                        let choices = match group.kind {
+                            AtlasReferenceKind::Region => &dialog.region_names,
+                            AtlasReferenceKind::Timeline => &dialog.timeline_names,
+                        };
+                        ui.horizontal(|ui| {
+                            ui.label("Replace with");
+                            let selected_text = if group.replacement.is_empty() {
+                                "Choose..."
+                            } else {
+                                group.replacement.as_str()
+                            };
+                            egui::ComboBox::from_id_salt("atlas_remap_choice")
+                                .selected_text(selected_text.to_string())
+                                .show_ui(ui, |ui| {
+                                    for choice in choices {
+                                        ui.selectable_value(&mut group.replacement, choice.clone(), choice);
+                                    }
+                                });
+                            if ui
+                                .add_enabled(!group.replacement.is_empty(), egui::Button::new("Remap"))
+                                .clicked()
+                            {
+                                apply.push(index);
+                            }
+                        });
+                    });
+                    ui.separator();
+                }
+                let ready = dialog.groups.iter().filter(|group| !group.replacement.is_empty()).count();
+                if ui.add_enabled(ready > 0, egui::Button::new(format!("Remap all ({ready})"))).clicked() {
+                    apply = (0..dialog.groups.len())
+                        .filter(|i| !dialog.groups[*i].replacement.is_empty())
+                        .collect();
+                }
+            },
+        );
+        if let Some(entity) = select {
+            self.set_selected_entity(Some(entity));
+        }
+        if !apply.is_empty() {
+            let mut updated = 0usize;
+            let mut failed = Vec::new();
+            for index in apply.iter().rev() {
+                let group = &dialog.groups[*index];
+                let count =
+                    self.apply_atlas_remap(&dialog.atlas, group.kind, &group.name, &group.replacement);
+                if count == 0 {
+                    failed.push(group.name.clone());
+                    continue;
+                }
+                updated += count;
+                dialog.groups.remove(*index);
+            }
+            let status = if failed.is_empty() {
+                format!(
+                    "Remapped {updated} sprite{} in atlas '{}'.",
+                    if updated == 1 { "" } else { "s" },
+                    dialog.atlas
+                )
+            } else {
+                format!("Failed to remap {} in atlas '{}'.", failed.join(", "), dialog.atlas)
+            };
+            self.set_inspector_status(Some(status));
+        }
+        if open && !dialog.groups.is_empty() {
+            self.with_editor_ui_state_mut(|state| {
+                if state.atlas_remap_dialog.is_none() {
+                    state.atlas_remap_dialog = Some(dialog);
+                }
+            });
+        }
+    }
+}
@@ -1,5 +1,10 @@
 use super::*;
 
+/// Keyboard panning speed in viewport heights per second, so it feels the same at every zoom level.
+const KEYBOARD_PAN_VIEWPORTS_PER_SECOND: f32 = 0.75;
+/// Scroll-wheel equivalent of a single +/- press (about 22% per step).
+const KEYBOARD_ZOOM_STEP: f32 = 2.0;
+
 #[derive(Debug, Clone)]
 pub(crate) struct CameraBookmark {
     pub(crate) name: String,
@@ -30,8 +35,15 @@ impl App {
         self.editor_ui_state().selected_entity
     }
 
+    /// Sets the primary selection. The rest of a multi-selection is kept while the primary entity
+    /// stays the same and dropped once it changes.
     pub(crate) fn set_selected_entity(&self, entity: Option<Entity>) {
-        self.editor_ui_state_mut().selected_entity = entity;
+        self.with_editor_ui_state_mut(|state| {
+            if state.selected_entity != entity || entity.is_none() {
+                state.selected_entities = entity.into_iter().collect();
+            }
+            state.selected_entity = entity;
+        });
     }
 
     pub(crate) fn gizmo_mode(&self) -> GizmoMode {
@@ -52,7 +64,12 @@ impl App {
     }
 
     pub(crate) fn set_gizmo_interaction(&self, interaction: Option<GizmoInteraction>) {
-        self.editor_ui_state_mut().gizmo_interaction = interaction;
+        self.with_editor_ui_state_mut(|state| {
+            if interaction.is_none() {
+                state.bulk_gizmo_snapshot = None;
+            }
+            state.gizmo_interaction = interaction;
+        });
     }
 
     pub(crate) fn take_gizmo_interaction(&self) -> Option<GizmoInteraction> {
@@ -158,6 +175,35 @@ impl App {
         self.camera_follow_target = None;
     }
 
+    /// WASD/arrow panning and +/- zoom steps for the 2D viewport.
+    pub(crate) fn apply_keyboard_navigation(&mut self, viewport_size: PhysicalSize<u32>, dt: f32) {
+        let zoom_steps = self.input.take_zoom_steps();
+        if zoom_steps != 0 {
+            self.camera.apply_scroll_zoom(zoom_steps as f32 * KEYBOARD_ZOOM_STEP);
+            self.set_active_camera_bookmark(None);
+        }
+        let (x, y) = self.input.viewport_pan_axis();
+        let direction = Vec2::new(x, y).normalize_or_zero();
+        if direction == Vec2::ZERO || dt <= 0.0 {
+            return;
+        }
+        if let Some((_, half_height)) = self.camera.half_extents(viewport_size) {
+            self.camera.position += direction * half_height * 2.0 * KEYBOARD_PAN_VIEWPORTS_PER_SECOND * dt;
+            self.set_active_camera_bookmark(None);
+            self.camera_follow_target = None;
+        }
+    }
+
+    /// Home / View > Reset camera: back to the origin at the default zoom, dropping any bookmark,
+    /// follow target or in-flight gizmo drag.
+    pub(crate) fn center_camera_on_world_origin(&mut self) {
+        self.camera_follow_target = None;
+        self.set_active_camera_bookmark(None);
+        self.set_gizmo_interaction(None);
+        self.camera.position = Vec2::ZERO;
+        self.camera.set_zoom(1.0);
+    }
+
     pub(crate) fn focus_selection(&mut self) -> bool {
         let Some(entity) = self.selected_entity() else {
             return false;
@@ -174,4 +220,19 @@ impl App {
             true
         }
     }
+
+    /// Frames the union of every sprite's bounds, as gathered by the last instance collection.
+    pub(crate) fn focus_all(&mut self) -> bool {
+        let Some((min, max)) = self.sprite_world_bounds else {
+            return false;
+        };
+        self.camera_follow_target = None;
+        self.set_active_camera_bookmark(None);
+        let viewport_size = self.viewport_physical_size();
+        let padding = self.config.editor.focus_padding;
+        if !self.camera.frame_bounds(min, max, viewport_size, padding) {
+            self.camera.position = (min + max) * 0.5;
+        }
+        true
+    }
 }
@@ -0,0 +1,94 @@
+use super::*;
+
+/// Writes one PNG per rendered frame until the requested frame count has been captured.
+#[derive(Debug, Clone)]
+pub(crate) struct ScreenshotSequence {
+    pub(crate) frames_remaining: u32,
+    pub(crate) output_dir: PathBuf,
+    pub(crate) frame_index: u32,
+}
+
+impl ScreenshotSequence {
+    pub(crate) fn new(frames: u32, output_dir: PathBuf) -> Self {
+        Self { frames_remaining: frames, output_dir, frame_index: 0 }
+    }
+
+    fn next_frame_path(&mut self) -> Option<PathBuf> {
+        if self.frames_remaining == 0 {
+            return None;
+        }
+        let path = self.output_dir.join(format!("frame_{:06}.png", self.frame_index));
+        self.frame_index += 1;
+        self.frames_remaining -= 1;
+        Some(path)
+    }
+
+    fn is_complete(&self) -> bool {
+        self.frames_remaining == 0
+    }
+
+    fn ffmpeg_hint(&self) -> String {
+        format!(
+            "ffmpeg -framerate 60 -i {} -c:v libx264 -pix_fmt yuv420p capture.mp4",
+            self.output_dir.join("frame_%06d.png").display()
+        )
+    }
+}
+
+impl App {
+    /// Starts rendering offscreen and saving `frames` consecutive frames to `output_dir`; the app
+    /// exits once the sequence is complete.
+    pub fn screenshot_sequence(&mut self, frames: u32, output_dir: impl Into<PathBuf>) -> Result<()> {
+        let output_dir = output_dir.into();
+        if frames == 0 {
+            return Err(anyhow!("Screenshot sequence needs at least one frame"));
+        }
+        fs::create_dir_all(&output_dir)
+            .with_context(|| format!("Creating capture directory '{}'", output_dir.display()))?;
+        self.renderer.set_offscreen_capture(true)?;
+        println!("[capture] Recording {frames} frame(s) to {}", output_dir.display());
+        self.screenshot_sequence = Some(ScreenshotSequence::new(frames, output_dir));
+        Ok(())
+    }
+
+    pub(super) fn capture_screenshot_sequence_frame(&mut self) {
+        let Some(sequence) = self.screenshot_sequence.as_mut() else {
+            return;
+        };
+        let Some(path) = sequence.next_frame_path() else {
+            return;
+        };
+        let result = self.renderer.read_offscreen_frame().and_then(|image| {
+            image.save(&path).with_context(|| format!("Writing capture frame '{}'", path.display()))
+        });
+        if let Err(err) = result {
+            eprintln!("[capture] Failed to capture frame: {err:?}");
+        }
+        if !sequence.is_complete() {
+            return;
+        }
+        println!("[capture] Wrote {} frame(s) to {}", sequence.frame_index, sequence.output_dir.display());
+        println!("[capture] Convert to video with: {}", sequence.ffmpeg_hint());
+        self.screenshot_sequence = None;
+        if let Err(err) = self.renderer.set_offscreen_capture(false) {
+            eprintln!("[capture] Failed to restore window output: {err:?}");
+        }
+        self.should_close = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sequence_numbers_frames_until_exhausted() {
+        let mut sequence = ScreenshotSequence::new(2, PathBuf::from("out"));
+        assert_eq!(sequence.next_frame_path(), Some(PathBuf::from("out").join("frame_000000.png")));
+        assert!(!sequence.is_complete());
+        assert_eq!(sequence.next_frame_path(), Some(PathBuf::from("out").join("frame_000001.png")));
+        assert!(sequence.is_complete());
+        assert_eq!(sequence.next_frame_path(), None);
+        assert!(sequence.ffmpeg_hint().contains("frame_%06d.png"));
+    }
+}
@@ -0,0 +1,74 @@
+use super::*;
+use crate::ecs::ColliderGeometry;
+use crate::gizmo::{self, ColliderGrip, GIZMO_COLLIDER_GRIP_RADIUS_PX};
+
+/// One finished collider drag, replayed by the inspector's collider undo/redo buttons.
+#[derive(Clone, Copy)]
+pub(crate) struct ColliderEditRecord {
+    pub(crate) entity: Entity,
+    pub(crate) before: ColliderGeometry,
+    pub(crate) after: ColliderGeometry,
+}
+
+impl App {
+    /// Collider grips are live while the "Edit collider" toggle or the collider debug overlay is on.
+    pub(crate) fn collider_edit_active(&self) -> bool {
+        let state = self.editor_ui_state();
+        state.collider_edit_mode || state.debug_show_colliders
+    }
+
+    pub(crate) fn collider_grip_under_pointer(
+        &self,
+        info: Option<&EntityInfo>,
+        cursor_viewport: Option<Vec2>,
+        viewport_size: PhysicalSize<u32>,
+    ) -> Option<ColliderGrip> {
+        if self.viewport_camera_mode != ViewportCameraMode::Ortho2D || !self.collider_edit_active() {
+            return None;
+        }
+        let info = info?;
+        let geometry = info.collider?;
+        let pointer = cursor_viewport?;
+        gizmo::collider_grips(&geometry, info.translation).into_iter().find_map(|(grip, world)| {
+            let screen = self.camera.world_to_screen_pixels(world, viewport_size)?;
+            (screen.distance(pointer) <= GIZMO_COLLIDER_GRIP_RADIUS_PX).then_some(grip)
+        })
+    }
+
+    pub(crate) fn record_collider_edit(
+        &mut self,
+        entity: Entity,
+        before: ColliderGeometry,
+        after: ColliderGeometry,
+    ) {
+        if before == after {
+            return;
+        }
+        self.with_editor_ui_state_mut(|state| {
+            state.collider_edit_history.push(ColliderEditRecord { entity, before, after });
+            state.collider_edit_redo.clear();
+        });
+    }
+
+    pub(crate) fn undo_collider_edit(&mut self) {
+        if let Some(record) = self.with_editor_ui_state_mut(|state| state.collider_edit_history.pop()) {
+            self.with_editor_ui_state_mut(|state| state.collider_edit_redo.push(record));
+            if self.ecs.set_collider_geometry(record.entity, record.before) {
+                self.set_inspector_status(Some(tr!("inspector.collider_edit_undone")));
+            } else {
+                self.set_inspector_status(Some(tr!("inspector.collider_edit_failed")));
+            }
+        }
+    }
+
+    pub(crate) fn redo_collider_edit(&mut self) {
+        if let Some(record) = self.with_editor_ui_state_mut(|state| state.collider_edit_redo.pop()) {
+            self.with_editor_ui_state_mut(|state| state.collider_edit_history.push(record));
+            if self.ecs.set_collider_geometry(record.entity, record.after) {
+                self.set_inspector_status(Some(tr!("inspector.collider_edit_redone")));
+            } else {
+                self.set_inspector_status(Some(tr!("inspector.collider_edit_failed")));
+            }
+        }
+    }
+}
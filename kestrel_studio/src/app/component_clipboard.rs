@@ -0,0 +1,364 @@
+use super::*;
+use crate::ecs::{ForceField, ParticleAttractor, ParticleEmitter, ParticleTrail, Sprite, Velocity};
+use crate::scene::{
+    ColorData, ForceFieldData, ParticleAttractorData, ParticleEmitterData, SceneEntity, SpriteData,
+    TransformData, Vec2Data,
+};
+use editor_ui::{ComponentClipboardRequest, ComponentKind, InspectorAction};
+use serde::{Deserialize, Serialize};
+
+/// One component snapshot, stored with the same data structs scenes serialize so the JSON placed
+/// on the OS clipboard can be pasted into another editor instance.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kestrel_component", content = "data", rename_all = "snake_case")]
+pub(super) enum ComponentClipboard {
+    Transform(TransformData),
+    Velocity(Vec2Data),
+    Tint(ColorData),
+    Sprite {
+        sprite: SpriteData,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        atlas_source: Option<String>,
+    },
+    ParticleEmitter(ParticleEmitterData),
+    ForceField(ForceFieldData),
+    Attractor(ParticleAttractorData),
+}
+
+impl ComponentClipboard {
+    /// Extracts `kind` from an exported entity, or `None` when the entity lacks that component.
+    pub(super) fn capture(kind: ComponentKind, entity: &SceneEntity, assets: &AssetManager) -> Option<Self> {
+        match kind {
+            ComponentKind::Transform => Some(Self::Transform(entity.transform.clone())),
+            ComponentKind::Velocity => entity.velocity.clone().map(Self::Velocity),
+            ComponentKind::Tint => entity.tint.clone().map(Self::Tint),
+            ComponentKind::Sprite => entity.sprite.clone().map(|sprite| Self::Sprite {
+                atlas_source: assets.atlas_source(&sprite.atlas).map(str::to_string),
+                sprite,
+            }),
+            ComponentKind::ParticleEmitter => entity.particle_emitter.clone().map(Self::ParticleEmitter),
+            ComponentKind::ForceField => entity.force_field.clone().map(Self::ForceField),
+            ComponentKind::Attractor => entity.attractor.clone().map(Self::Attractor),
+        }
+    }
+
+    pub(super) fn kind(&self) -> ComponentKind {
+        match self {
+            Self::Transform(_) => ComponentKind::Transform,
+            Self::Velocity(_) => ComponentKind::Velocity,
+            Self::Tint(_) => ComponentKind::Tint,
+            Self::Sprite { .. } => ComponentKind::Sprite,
+            Self::ParticleEmitter(_) => ComponentKind::ParticleEmitter,
+            Self::ForceField(_) => ComponentKind::ForceField,
+            Self::Attractor(_) => ComponentKind::Attractor,
+        }
+    }
+
+    pub(super) fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self).context("Failed to serialize component")
+    }
+
+    /// Parses clipboard JSON and checks that it holds the component `expected` is pasting into.
+    pub(super) fn from_json(json: &str, expected: ComponentKind) -> Result<Self> {
+        let value: serde_json::Value =
+            serde_json::from_str(json.trim()).context("Clipboard does not contain component JSON")?;
+        let tag = value.get("kestrel_component").and_then(|tag| tag.as_str()).map(str::to_string);
+        let Some(tag) = tag else {
+            return Err(anyhow!("Clipboard JSON is not a copied component"));
+        };
+        let clipboard: Self = serde_json::from_value(value)
+            .with_context(|| format!("Clipboard '{tag}' component is malformed"))?;
+        if clipboard.kind() != expected {
+            return Err(anyhow!(
+                "Clipboard holds a {} component; cannot paste it as {}",
+                clipboard.kind().label(),
+                expected.label()
+            ));
+        }
+        Ok(clipboard)
+    }
+
+    /// Inspector actions that apply this snapshot to `entity`. Sprites also need their atlas
+    /// resolved first, which `App::paste_component` handles before calling this.
+    pub(super) fn into_actions(self, entity: Entity, had_animation: bool) -> Vec<InspectorAction> {
+        match self {
+            Self::Transform(transform) => vec![
+                InspectorAction::SetTranslation { entity, translation: transform.translation.into() },
+                InspectorAction::SetRotation { entity, rotation: transform.rotation },
+                InspectorAction::SetScale { entity, scale: transform.scale.into() },
+            ],
+            Self::Velocity(velocity) => {
+                vec![InspectorAction::SetVelocity { entity, velocity: velocity.into() }]
+            }
+            Self::Tint(color) => vec![InspectorAction::SetMeshTint { entity, tint: Some(color.into()) }],
+            Self::Sprite { sprite, .. } => {
+                let mut actions = vec![
+                    InspectorAction::SetSpriteAtlas {
+                        entity,
+                        atlas: sprite.atlas.clone(),
+                        cleared_timeline: had_animation && sprite.animation.is_none(),
+                    },
+                    InspectorAction::SetSpriteRegion {
+                        entity,
+                        atlas: sprite.atlas.clone(),
+                        region: sprite.region.clone(),
+                    },
+                    InspectorAction::SetSpriteZOrder { entity, z_order: sprite.z_order },
+                    InspectorAction::SetSpriteNormalMap { entity, path: sprite.normal_map.clone() },
+                ];
+                match sprite.animation {
+                    Some(animation) => actions.extend([
+                        InspectorAction::SetSpriteTimeline { entity, timeline: Some(animation.timeline) },
+                        InspectorAction::SetSpriteAnimationSpeed { entity, speed: animation.speed },
+                        InspectorAction::SetSpriteAnimationLooped { entity, looped: animation.looped },
+                        InspectorAction::SetSpriteAnimationLoopCount {
+                            entity,
+                            loop_count: animation.loop_count,
+                        },
+                        InspectorAction::SetSpriteAnimationStartOffset {
+                            entity,
+                            start_offset: animation.start_offset,
+                        },
+                        InspectorAction::SetSpriteAnimationRandomStart {
+                            entity,
+                            random_start: animation.random_start,
+                        },
+                        InspectorAction::SetSpriteAnimationGroup { entity, group: animation.group },
+                        InspectorAction::SetSpriteAnimationPlaying { entity, playing: animation.playing },
+                    ]),
+                    None => actions.push(InspectorAction::SetSpriteTimeline { entity, timeline: None }),
+                }
+                actions
+            }
+            Self::ParticleEmitter(emitter) => vec![
+                InspectorAction::SetEmitterSettings {
+                    entity,
+                    rate: emitter.rate,
+                    spread: emitter.spread,
+                    speed: emitter.speed,
+                    lifetime: emitter.lifetime,
+                    start_color: emitter.start_color.into(),
+                    end_color: emitter.end_color.into(),
+                    start_size: emitter.start_size,
+                    end_size: emitter.end_size,
+                },
+                InspectorAction::SetEmitterTrail { entity, trail: emitter.trail.map(ParticleTrail::from) },
+                InspectorAction::SetEmitterShape { entity, shape: emitter.shape },
+                InspectorAction::SetEmitterCollision {
+                    entity,
+                    mode: emitter.collision_mode,
+                    events: emitter.collision_events,
+                },
+            ],
+            Self::ForceField(data) => {
+                let field = ForceField::from(data);
+                vec![InspectorAction::SetForceField {
+                    entity,
+                    field: Some((field.kind, field.strength, field.radius, field.falloff, field.direction)),
+                }]
+            }
+            Self::Attractor(data) => {
+                let attractor = ParticleAttractor::from(data);
+                vec![InspectorAction::SetAttractor {
+                    entity,
+                    attractor: Some((
+                        attractor.strength,
+                        attractor.radius,
+                        attractor.min_distance,
+                        attractor.max_acceleration,
+                        attractor.falloff,
+                    )),
+                }]
+            }
+        }
+    }
+}
+
+impl App {
+    pub(super) fn handle_component_clipboard_request(&mut self, request: ComponentClipboardRequest) {
+        match request {
+            ComponentClipboardRequest::Copy { entity, kind } => self.copy_component(entity, kind),
+            ComponentClipboardRequest::Paste { entity, kind } => {
+                if let Err(err) = self.paste_component(entity, kind) {
+                    self.set_inspector_status(Some(format!("{err:#}")));
+                }
+            }
+        }
+    }
+
+    fn copy_component(&mut self, entity: Entity, kind: ComponentKind) {
+        let exported = self.ecs.export_prefab(entity, &self.assets);
+        let Some(clipboard) = exported
+            .as_ref()
+            .and_then(|scene| scene.entities.first())
+            .and_then(|scene_entity| ComponentClipboard::capture(kind, scene_entity, &self.assets))
+        else {
+            self.set_inspector_status(Some(tr!("clipboard.component_missing", kind = kind.label())));
+            return;
+        };
+        match clipboard.to_json() {
+            Ok(json) => {
+                if let Some(state) = self.editor_shell.egui_winit.as_mut() {
+                    state.set_clipboard_text(json);
+                }
+                self.component_clipboard = Some(clipboard);
+                self.set_inspector_status(Some(tr!("clipboard.component_copied", kind = kind.label())));
+            }
+            Err(err) => self.set_inspector_status(Some(tr!("clipboard.copy_failed", error = err))),
+        }
+    }
+
+    /// Prefers component JSON on the OS clipboard so pastes work across editor instances, then
+    /// falls back to the last component copied in this editor. The snapshot is applied through
+    /// the regular inspector action handlers.
+    fn paste_component(&mut self, entity: Entity, kind: ComponentKind) -> Result<()> {
+        if !self.ecs.entity_exists(entity) {
+            return Err(anyhow!("Entity no longer exists"));
+        }
+        let os_text = self.editor_shell.egui_winit.as_mut().and_then(|state| state.clipboard_text());
+        let os_clipboard = os_text.as_deref().map(|text| ComponentClipboard::from_json(text, kind));
+        let clipboard = match (os_clipboard, self.component_clipboard.as_ref()) {
+            (Some(Ok(clipboard)), _) => clipboard,
+            (_, Some(internal)) if internal.kind() == kind => internal.clone(),
+            (Some(Err(err)), _) => return Err(err),
+            (None, Some(internal)) => {
+                return Err(anyhow!(
+                    "Clipboard holds a {} component; cannot paste it as {}",
+                    internal.kind().label(),
+                    kind.label()
+                ))
+            }
+            (None, None) => return Err(anyhow!("Nothing has been copied yet")),
+        };
+        let had_animation = self.ecs.world.get::<SpriteAnimation>(entity).is_some();
+        match &clipboard {
+            ComponentClipboard::Sprite { sprite, atlas_source } => {
+                if self.ecs.world.get::<Sprite>(entity).is_none() {
+                    return Err(anyhow!("Entity has no sprite to paste onto"));
+                }
+                self.resolve_sprite_atlas(&sprite.atlas, atlas_source.as_deref())?;
+            }
+            ComponentClipboard::ParticleEmitter(_)
+                if self.ecs.world.get::<ParticleEmitter>(entity).is_none() =>
+            {
+                return Err(anyhow!("Entity has no particle emitter to paste onto"));
+            }
+            ComponentClipboard::Velocity(_) if self.ecs.world.get::<Velocity>(entity).is_none() => {
+                return Err(anyhow!("Entity has no velocity to paste onto"));
+            }
+            _ => {}
+        }
+        let mut actions = clipboard.into_actions(entity, had_animation);
+        self.handle_inspector_actions(&mut actions);
+        self.set_inspector_status(Some(tr!("clipboard.component_pasted", kind = kind.label())));
+        Ok(())
+    }
+
+    /// Ctrl+C: places the selected entity (with its children) on the OS clipboard as scene JSON.
+    pub(super) fn copy_selected_entity(&mut self) {
+        let Some(entity) = self.selected_entity() else {
+            return;
+        };
+        match self.ecs.serialize_entity(entity, &self.assets) {
+            Ok(json) => {
+                if let Some(state) = self.editor_shell.egui_winit.as_mut() {
+                    state.set_clipboard_text(json);
+                }
+                self.set_ui_scene_status(tr!("clipboard.entity_copied"));
+            }
+            Err(err) => self.set_ui_scene_status(tr!("clipboard.copy_failed", error = err)),
+        }
+    }
+
+    /// Ctrl+V: spawns the entity JSON on the OS clipboard with fresh scene IDs, centred on the
+    /// 2D cursor when it is over the viewport, and selects the copy.
+    pub(super) fn paste_entity_at_cursor(&mut self) {
+        let Some(json) = self.editor_shell.egui_winit.as_mut().and_then(|state| state.clipboard_text())
+        else {
+            self.set_ui_scene_status(tr!("clipboard.empty"));
+            return;
+        };
+        let cursor = self.input.cursor_world_position().map(|(x, y)| Vec2::new(x, y));
+        let result = self.ecs.deserialize_entity_with_mesh(&json, cursor, &mut self.assets, |key, path| {
+            self.mesh_registry.ensure_mesh(key, path, &mut self.material_registry)
+        });
+        match result {
+            Ok(root) => {
+                self.set_selected_entity(Some(root));
+                self.set_gizmo_interaction(None);
+                self.set_ui_scene_status(tr!("clipboard.entity_pasted"));
+            }
+            Err(err) => self.set_ui_scene_status(tr!("clipboard.paste_failed", error = format!("{err:#}"))),
+        }
+    }
+
+    /// Makes sure `atlas` is loaded, retaining it from `path` the same way the inspector's
+    /// "Load & Assign" request does when it is missing.
+    pub(super) fn resolve_sprite_atlas(&mut self, atlas: &str, path: Option<&str>) -> Result<()> {
+        if self.assets.has_atlas(atlas) {
+            return Ok(());
+        }
+        let Some(path) = path else {
+            return Err(anyhow!("Atlas '{atlas}' is not loaded and no source path is known"));
+        };
+        self.assets
+            .retain_atlas(atlas, Some(path))
+            .with_context(|| format!("Failed to load atlas '{atlas}'"))?;
+        self.scene_atlas_refs.insert(atlas.to_string());
+        self.invalidate_atlas_view(atlas);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::{ForceFalloff, ForceFieldKind, Transform};
+
+    #[test]
+    fn force_field_round_trips_through_clipboard_json() {
+        let assets = AssetManager::new();
+        let mut ecs = EcsWorld::new();
+        let field = ForceField {
+            kind: ForceFieldKind::Directional,
+            strength: 3.25,
+            radius: 4.5,
+            falloff: ForceFalloff::None,
+            direction: Vec2::new(0.6, -0.8),
+        };
+        let source = ecs.world.spawn((Transform::default(), field)).id();
+        let target = ecs.world.spawn(Transform::default()).id();
+
+        let scene = ecs.export_prefab(source, &assets).expect("export source");
+        let copied = ComponentClipboard::capture(ComponentKind::ForceField, &scene.entities[0], &assets)
+            .expect("source has a force field");
+        assert!(ComponentClipboard::capture(ComponentKind::Sprite, &scene.entities[0], &assets).is_none());
+        let json = copied.to_json().expect("serialize");
+
+        let pasted = ComponentClipboard::from_json(&json, ComponentKind::ForceField).expect("parse");
+        let actions = pasted.into_actions(target, false);
+        let [InspectorAction::SetForceField { entity, field: Some(pasted_field) }] = actions.as_slice()
+        else {
+            panic!("expected a single force field action");
+        };
+        assert_eq!(*entity, target);
+        assert_eq!(*pasted_field, (field.kind, field.strength, field.radius, field.falloff, field.direction));
+    }
+
+    #[test]
+    fn mismatched_or_foreign_clipboard_text_is_rejected_readably() {
+        let json = ComponentClipboard::Velocity(Vec2Data { x: 1.0, y: 2.0 }).to_json().expect("serialize");
+        let err = ComponentClipboard::from_json(&json, ComponentKind::Sprite).unwrap_err();
+        assert_eq!(err.to_string(), "Clipboard holds a Velocity component; cannot paste it as Sprite");
+        let err = ComponentClipboard::from_json("hello", ComponentKind::Velocity).unwrap_err();
+        assert_eq!(err.to_string(), "Clipboard does not contain component JSON");
+        let err = ComponentClipboard::from_json("{\"x\": 1}", ComponentKind::Velocity).unwrap_err();
+        assert_eq!(err.to_string(), "Clipboard JSON is not a copied component");
+        let err = ComponentClipboard::from_json(
+            "{\"kestrel_component\": \"force_field\", \"data\": {}}",
+            ComponentKind::ForceField,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("'force_field' component is malformed"));
+    }
+}
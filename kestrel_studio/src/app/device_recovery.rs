@@ -0,0 +1,92 @@
+use super::*;
+
+/// Failed recovery attempts are logged once and then every this many frames.
+const DEVICE_RECOVERY_LOG_INTERVAL: u32 = 120;
+
+impl App {
+    /// Rebuilds the renderer on a new device after a driver reset, an adapter switch, or
+    /// `Device::destroy`, then re-uploads everything that lived on the old device. Frames are skipped
+    /// until this succeeds, so a loss shows up as a few black frames instead of a stream of errors.
+    pub(super) fn recover_graphics_device(&mut self) {
+        if self.device_recovery_attempts == 0 {
+            eprintln!("[renderer] GPU device lost; recovering");
+            self.set_ui_scene_status(tr!("device.recovering"));
+        }
+        self.device_recovery_attempts += 1;
+        self.editor_shell.egui_renderer = None;
+        if let Err(err) = self.renderer.recover_device() {
+            let attempt = self.device_recovery_attempts;
+            if attempt == 1 || attempt.is_multiple_of(DEVICE_RECOVERY_LOG_INTERVAL) {
+                eprintln!("[renderer] device recovery attempt {attempt} failed: {err:?}");
+            }
+            return;
+        }
+        self.device_recovery_attempts = 0;
+        match self.reupload_gpu_resources() {
+            Ok(()) => {
+                let adapter = self.renderer.adapter_info().map(|info| info.name.clone()).unwrap_or_default();
+                eprintln!("[renderer] graphics device recovered on '{adapter}'");
+                self.set_ui_scene_status(tr!("device.recovered", adapter = adapter));
+            }
+            Err(err) => {
+                eprintln!("[renderer] re-uploading GPU resources failed: {err:?}");
+                self.set_ui_scene_status(tr!("device.recovered_with_errors", error = err));
+            }
+        }
+    }
+
+    /// Points every registry at the new device and rebuilds the pipelines `resumed` created.
+    /// Registries drop their GPU copies and upload again on demand; the scene's meshes and the
+    /// active environment are uploaded right away.
+    fn reupload_gpu_resources(&mut self) -> Result<()> {
+        let (device, queue) = self.renderer.device_and_queue()?;
+        self.assets.set_device(device, queue);
+        self.mesh_registry.release_gpu();
+        self.material_registry.release_gpu();
+        self.environment_registry.release_gpu();
+        self.thumbnails.reload_textures();
+        self.apply_texture_anisotropy(self.config.textures.anisotropy);
+        self.clear_atlas_view_cache();
+        self.static_sprite_bake_dirty = true;
+
+        let atlas_view = self.assets.atlas_texture_view("main")?;
+        self.sprite_atlas_views.insert("main".to_string(), Arc::new(atlas_view.clone()));
+        let sampler = self.assets.default_sampler().clone();
+        self.renderer.init_sprite_pipeline_with_atlas(atlas_view, sampler)?;
+        self.renderer.init_mesh_pipeline()?;
+        self.apply_environment_to_renderer()?;
+        let keys: Vec<&str> = self.scene_mesh_refs.iter().map(String::as_str).collect();
+        self.mesh_registry
+            .batch_ensure_gpu(&keys, &mut self.renderer)
+            .context("Failed to upload scene meshes")?;
+        self.with_plugins(|plugins, ctx| {
+            if let Some(plugin) = plugins.get_mut::<MeshPreviewPlugin>() {
+                if let Err(err) = plugin.ensure_preview_gpu(ctx) {
+                    eprintln!("[mesh_preview] ensure_preview_gpu failed: {err:?}");
+                }
+            }
+        });
+
+        let device = self.renderer.device()?;
+        let mut egui_renderer =
+            EguiRenderer::new(device, self.renderer.surface_format()?, RendererOptions::default());
+        // egui only sends texture deltas, so a fresh painter is handed the whole font atlas once.
+        // Thumbnails are reloaded above; other egui textures reappear when they are next set.
+        let ctx = self.editor_shell.egui_ctx.clone();
+        let font_options = ctx
+            .tex_manager()
+            .read()
+            .meta(egui::TextureId::default())
+            .map(|meta| meta.options)
+            .unwrap_or_default();
+        let font_image = ctx.fonts(|fonts| fonts.image());
+        egui_renderer.update_texture(
+            device,
+            self.renderer.queue()?,
+            egui::TextureId::default(),
+            &egui::epaint::ImageDelta::full(font_image, font_options),
+        );
+        self.editor_shell.egui_renderer = Some(egui_renderer);
+        Ok(())
+    }
+}
@@ -1,21 +1,31 @@
-use super::animation_keyframe_panel::AnimationKeyframePanel;
+use super::animation_keyframe_panel::{AnimationKeyClipboard, AnimationKeyframePanel};
+use super::atlas_remap_tooling::AtlasRemapDialog;
+use super::collider_tooling::ColliderEditRecord;
+use super::editor_state_file::EditorStateFile;
+use super::editor_theme::EditorTheme;
+use super::layout_tooling::{load_layout_presets, LayoutPreset, LAYOUT_PRESETS_PATH};
+use super::selection_tooling::SelectionSnapshot;
 use super::telemetry_tooling::{FrameProfiler, GpuTimingFrame, TelemetryCache};
 use super::{CameraBookmark, ClipEditRecord, FrameBudgetSnapshot, ScriptConsoleEntry};
 use crate::analytics::{
-    AnimationBudgetSample, GpuPassMetric, KeyframeEditorEvent, KeyframeEditorUsageSnapshot,
+    AnimationBudgetSample, CustomCounterHistory, GpuPassMetric, KeyframeEditorEvent,
+    KeyframeEditorUsageSnapshot,
 };
 use crate::animation_validation::AnimationValidationEvent;
 use crate::assets::AnimationClip;
 use crate::config::{EditorConfig, ParticleConfig, SpriteGuardrailMode};
+use crate::ecs::SpriteTimelineUsageEntry;
 use crate::gizmo::{GizmoInteraction, GizmoMode};
+use crate::locale;
 use crate::plugins::{
     AssetReadbackStats, CapabilityViolationLog, PluginAssetReadbackEvent, PluginCapabilityEvent,
     PluginManifestEntry, PluginStatus, PluginWatchdogEvent,
 };
 use crate::prefab::{PrefabFormat, PrefabStatusMessage};
-use crate::renderer::{GpuPassTiming, LightClusterMetrics, SceneLightingState};
+use crate::renderer::{AutoExposureSettings, GpuPassTiming, LightClusterMetrics, SceneLightingState};
 use crate::scene::{SceneDependencies, SceneDependencyFingerprints, SceneEntityId};
-use crate::scripts::{ScriptHandle, ScriptTimingSummary};
+use crate::scripts::{ScriptErrorInfo, ScriptHandle, ScriptTimingSummary};
+use anyhow::Result;
 use bevy_ecs::prelude::Entity;
 use egui::Context as EguiCtx;
 use egui_plot as eplot;
@@ -23,7 +33,7 @@ use egui_wgpu::{Renderer as EguiRenderer, ScreenDescriptor};
 use egui_winit::State as EguiWinit;
 use std::cell::{Ref, RefCell, RefMut};
 use std::collections::{HashMap, HashSet, VecDeque};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use super::SCRIPT_CONSOLE_CAPACITY;
@@ -39,8 +49,10 @@ pub(crate) struct EditorShell {
 
 impl EditorShell {
     pub fn new(ui_state: EditorUiState) -> Self {
+        let egui_ctx = EguiCtx::default();
+        ui_state.ui_theme.apply(&egui_ctx);
         Self {
-            egui_ctx: EguiCtx::default(),
+            egui_ctx,
             egui_winit: None,
             egui_renderer: None,
             egui_screen: None,
@@ -63,7 +75,7 @@ pub(crate) struct ScriptDebuggerStatus {
     pub script_path: Option<String>,
     pub enabled: bool,
     pub paused: bool,
-    pub last_error: Option<String>,
+    pub last_error: Option<ScriptErrorInfo>,
     pub handles: Vec<ScriptHandleBinding>,
     pub timings: Vec<ScriptTimingSummary>,
     pub offenders: Vec<ScriptOffenderStatus>,
@@ -118,6 +130,7 @@ pub(crate) struct EditorUiState {
     pub ui_light_ambient: glam::Vec3,
     pub ui_light_exposure: f32,
     pub ui_environment_intensity: f32,
+    pub ui_environment_scroll_speed: f32,
     pub ui_shadow_distance: f32,
     pub ui_shadow_bias: f32,
     pub ui_shadow_strength: f32,
@@ -125,16 +138,27 @@ pub(crate) struct EditorUiState {
     pub ui_shadow_resolution: u32,
     pub ui_shadow_split_lambda: f32,
     pub ui_shadow_pcf_radius: f32,
+    pub ui_auto_exposure: AutoExposureSettings,
     pub ui_camera_zoom_min: f32,
     pub ui_camera_zoom_max: f32,
     pub ui_sprite_guard_pixels: f32,
     pub ui_sprite_guard_mode: SpriteGuardrailMode,
+    pub ui_static_sprite_batching: bool,
+    pub ui_low_latency_input: bool,
+    /// `(code, display name)` of the locale files found at startup, offered by the language picker.
+    pub ui_languages: Arc<[(String, String)]>,
     pub ui_scale: f32,
+    pub ui_theme: EditorTheme,
+    /// Where [`EditorUiState::set_theme`] persists the theme, alongside the saved window geometry.
+    pub editor_state_path: PathBuf,
     pub selected_entity: Option<Entity>,
+    pub selected_entities: Vec<Entity>,
+    pub bulk_gizmo_snapshot: Option<SelectionSnapshot>,
     pub gizmo_mode: GizmoMode,
     pub gizmo_interaction: Option<GizmoInteraction>,
     pub ui_scene_path: String,
     pub ui_scene_status: Option<String>,
+    pub ui_fragment_include_children: bool,
     pub prefab_name_input: String,
     pub prefab_format: PrefabFormat,
     pub prefab_status: Option<PrefabStatusMessage>,
@@ -143,6 +167,10 @@ pub(crate) struct EditorUiState {
     pub camera_bookmark_input: String,
     pub camera_bookmarks: Vec<CameraBookmark>,
     pub active_camera_bookmark: Option<String>,
+    pub layout_presets: Vec<LayoutPreset>,
+    pub layout_preset: Option<String>,
+    pub layout_name_input: String,
+    pub pending_layout: Option<LayoutPreset>,
     pub scene_dependencies: Option<SceneDependencies>,
     pub scene_dependency_fingerprints: Option<SceneDependencyFingerprints>,
     pub scene_history: VecDeque<String>,
@@ -155,6 +183,14 @@ pub(crate) struct EditorUiState {
     pub id_lookup_active: bool,
     pub debug_show_spatial_hash: bool,
     pub debug_show_colliders: bool,
+    /// Shows collider grips on the selection even with the collider overlay off.
+    pub collider_edit_mode: bool,
+    pub collider_edit_history: Vec<ColliderEditRecord>,
+    pub collider_edit_redo: Vec<ColliderEditRecord>,
+    pub debug_show_nav_grid: bool,
+    pub debug_show_culled_bounds: bool,
+    pub debug_show_skeletons: bool,
+    pub debug_skeleton_labels: bool,
     pub sprite_guardrail_status: Option<String>,
     pub gpu_metrics_status: Option<String>,
     pub frame_budget_idle_snapshot: Option<FrameBudgetSnapshot>,
@@ -162,6 +198,7 @@ pub(crate) struct EditorUiState {
     pub frame_budget_status: Option<String>,
     pub shadow_pass_metric: Option<GpuPassMetric>,
     pub mesh_pass_metric: Option<GpuPassMetric>,
+    pub gpu_baseline_report: Option<Arc<[String]>>,
     pub plugin_capability_metrics: Arc<HashMap<String, CapabilityViolationLog>>,
     pub plugin_capability_events: Arc<[PluginCapabilityEvent]>,
     pub plugin_asset_readbacks: Arc<[PluginAssetReadbackEvent]>,
@@ -180,6 +217,8 @@ pub(crate) struct EditorUiState {
     pub light_cluster_metrics_overlay: Option<LightClusterMetrics>,
     pub keyframe_editor_usage: Option<KeyframeEditorUsageSnapshot>,
     pub keyframe_event_log: Arc<[KeyframeEditorEvent]>,
+    pub timeline_usage: Arc<[SpriteTimelineUsageEntry]>,
+    pub custom_counters: Arc<[CustomCounterHistory]>,
     pub script_debugger_open: bool,
     pub script_focus_repl: bool,
     pub script_repl_input: String,
@@ -189,7 +228,7 @@ pub(crate) struct EditorUiState {
     pub script_console: VecDeque<ScriptConsoleEntry>,
     pub script_console_snapshot: Option<Arc<[ScriptConsoleEntry]>>,
     pub script_console_parse_hits: bool,
-    pub last_reported_script_error: Option<String>,
+    pub last_reported_script_error: Option<(String, u32)>,
     pub script_debugger_status: ScriptDebuggerStatus,
     pub script_timing_threshold_ms: Option<f32>,
     pub script_timing_pins: HashSet<String>,
@@ -198,9 +237,11 @@ pub(crate) struct EditorUiState {
     pub clip_edit_history: Vec<ClipEditRecord>,
     pub clip_edit_redo: Vec<ClipEditRecord>,
     pub animation_clip_status: Option<String>,
+    pub animation_key_clipboard: Option<AnimationKeyClipboard>,
     pub clip_edit_overrides: HashMap<String, Arc<AnimationClip>>,
     pub pending_animation_validation_events: Vec<AnimationValidationEvent>,
     pub suppressed_validation_paths: HashSet<PathBuf>,
+    pub atlas_remap_dialog: Option<AtlasRemapDialog>,
     pub telemetry_cache: TelemetryCache,
     pub script_timing_history: HashMap<String, Vec<f32>>,
     pub frame_plot_points: Arc<[eplot::PlotPoint]>,
@@ -219,6 +260,7 @@ pub(crate) struct EditorUiStateParams {
     pub environment_intensity: f32,
     pub editor_config: EditorConfig,
     pub default_scene_path: PathBuf,
+    pub editor_state_path: PathBuf,
 }
 
 #[derive(Clone, Copy)]
@@ -261,6 +303,7 @@ impl EditorUiState {
             ui_light_ambient: params.lighting_state.ambient,
             ui_light_exposure: params.lighting_state.exposure,
             ui_environment_intensity: params.environment_intensity,
+            ui_environment_scroll_speed: 0.0,
             ui_shadow_distance: params.lighting_state.shadow_distance,
             ui_shadow_bias: params.lighting_state.shadow_bias,
             ui_shadow_strength: params.lighting_state.shadow_strength,
@@ -268,16 +311,25 @@ impl EditorUiState {
             ui_shadow_resolution: params.lighting_state.shadow_resolution,
             ui_shadow_split_lambda: params.lighting_state.shadow_split_lambda,
             ui_shadow_pcf_radius: params.lighting_state.shadow_pcf_radius,
+            ui_auto_exposure: params.lighting_state.auto_exposure,
             ui_camera_zoom_min: params.editor_config.camera_zoom_min,
             ui_camera_zoom_max: params.editor_config.camera_zoom_max,
             ui_sprite_guard_pixels: params.editor_config.sprite_guard_max_pixels,
             ui_sprite_guard_mode: params.editor_config.sprite_guardrail_mode,
+            ui_static_sprite_batching: params.editor_config.static_sprite_batching,
+            ui_low_latency_input: params.editor_config.low_latency_input,
+            ui_languages: Arc::from(locale::available_languages(locale::LOCALE_DIR).into_boxed_slice()),
             ui_scale: 1.0,
+            ui_theme: EditorStateFile::load(&params.editor_state_path).theme,
+            editor_state_path: params.editor_state_path,
             selected_entity: None,
+            selected_entities: Vec::new(),
+            bulk_gizmo_snapshot: None,
             gizmo_mode: GizmoMode::default(),
             gizmo_interaction: None,
             ui_scene_path: default_scene_path,
             ui_scene_status: None,
+            ui_fragment_include_children: true,
             prefab_name_input: String::new(),
             prefab_format: PrefabFormat::Json,
             prefab_status: None,
@@ -286,6 +338,10 @@ impl EditorUiState {
             camera_bookmark_input: String::new(),
             camera_bookmarks: Vec::new(),
             active_camera_bookmark: None,
+            layout_presets: load_layout_presets(Path::new(LAYOUT_PRESETS_PATH)),
+            layout_preset: None,
+            layout_name_input: String::new(),
+            pending_layout: None,
             scene_dependencies: None,
             scene_dependency_fingerprints: None,
             scene_history,
@@ -298,6 +354,13 @@ impl EditorUiState {
             id_lookup_active: false,
             debug_show_spatial_hash: false,
             debug_show_colliders: false,
+            collider_edit_mode: false,
+            collider_edit_history: Vec::new(),
+            collider_edit_redo: Vec::new(),
+            debug_show_nav_grid: false,
+            debug_show_culled_bounds: false,
+            debug_show_skeletons: false,
+            debug_skeleton_labels: false,
             sprite_guardrail_status: None,
             gpu_metrics_status: None,
             frame_budget_idle_snapshot: None,
@@ -305,6 +368,7 @@ impl EditorUiState {
             frame_budget_status: None,
             shadow_pass_metric: None,
             mesh_pass_metric: None,
+            gpu_baseline_report: None,
             plugin_capability_metrics: Arc::new(HashMap::new()),
             plugin_capability_events: Arc::from(Vec::<PluginCapabilityEvent>::new().into_boxed_slice()),
             plugin_asset_readbacks: Arc::from(Vec::<PluginAssetReadbackEvent>::new().into_boxed_slice()),
@@ -323,6 +387,8 @@ impl EditorUiState {
             light_cluster_metrics_overlay: None,
             keyframe_editor_usage: None,
             keyframe_event_log: Arc::from(Vec::<KeyframeEditorEvent>::new().into_boxed_slice()),
+            timeline_usage: Arc::from(Vec::<SpriteTimelineUsageEntry>::new().into_boxed_slice()),
+            custom_counters: Arc::from(Vec::<CustomCounterHistory>::new().into_boxed_slice()),
             script_debugger_open: false,
             script_focus_repl: false,
             script_repl_input: String::new(),
@@ -341,9 +407,11 @@ impl EditorUiState {
             clip_edit_history: Vec::new(),
             clip_edit_redo: Vec::new(),
             animation_clip_status: None,
+            animation_key_clipboard: None,
             clip_edit_overrides: HashMap::new(),
             pending_animation_validation_events: Vec::new(),
             suppressed_validation_paths: HashSet::new(),
+            atlas_remap_dialog: None,
             telemetry_cache: TelemetryCache::default(),
             script_timing_history: HashMap::new(),
             frame_plot_points: Arc::from(Vec::<eplot::PlotPoint>::new().into_boxed_slice()),
@@ -355,4 +423,13 @@ impl EditorUiState {
             gpu_frame_counter: 0,
         }
     }
+
+    /// Stores `theme` as the active theme and writes it to the editor state file so the next
+    /// session starts with it.
+    pub fn set_theme(&mut self, theme: EditorTheme) -> Result<()> {
+        self.ui_theme = theme;
+        let mut state = EditorStateFile::load(&self.editor_state_path);
+        state.theme = theme;
+        state.store(&self.editor_state_path)
+    }
 }
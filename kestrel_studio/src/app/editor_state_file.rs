@@ -0,0 +1,130 @@
+use super::editor_theme::EditorTheme;
+use super::*;
+use crate::renderer::WindowGeometry;
+use serde::{Deserialize, Serialize};
+
+pub(crate) const EDITOR_STATE_PATH: &str = "config/editor_state.json";
+
+/// Editor state carried between sessions.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub(crate) struct EditorStateFile {
+    pub(crate) window: Option<WindowGeometry>,
+    pub(crate) theme: EditorTheme,
+}
+
+impl EditorStateFile {
+    pub(crate) fn load(path: &Path) -> Self {
+        if !path.exists() {
+            return Self::default();
+        }
+        let data = match fs::read_to_string(path) {
+            Ok(data) => data,
+            Err(err) => {
+                eprintln!("[editor_state] failed to read {}: {err}", path.display());
+                return Self::default();
+            }
+        };
+        match serde_json::from_str(&data) {
+            Ok(state) => state,
+            Err(err) => {
+                eprintln!("[editor_state] failed to parse {}: {err}", path.display());
+                Self::default()
+            }
+        }
+    }
+
+    pub(crate) fn store(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create editor state dir {}", parent.display()))?;
+        }
+        let data = serde_json::to_string_pretty(self)?;
+        fs::write(path, data).with_context(|| format!("Failed to write editor state {}", path.display()))?;
+        Ok(())
+    }
+
+    /// Records `current` as the window geometry. A maximized or fullscreen window reports the size of
+    /// the whole monitor, so the previously saved size is kept as the size to restore down to.
+    pub(crate) fn record_window(&mut self, mut current: WindowGeometry) {
+        if current.maximized || current.fullscreen {
+            if let Some(previous) = self.window.as_ref() {
+                current.size = previous.size;
+            }
+        }
+        self.window = Some(current);
+    }
+}
+
+impl App {
+    /// Queues the geometry saved by the previous session so the window reopens where it was left.
+    pub(crate) fn restore_window_geometry(&mut self) {
+        let state = EditorStateFile::load(Path::new(EDITOR_STATE_PATH));
+        self.renderer.set_restore_geometry(state.window);
+    }
+
+    pub(crate) fn persist_window_geometry(&self) {
+        let Some(geometry) = self.renderer.window_geometry() else {
+            return;
+        };
+        let path = Path::new(EDITOR_STATE_PATH);
+        let mut state = EditorStateFile::load(path);
+        state.record_window(geometry);
+        if let Err(err) = state.store(path) {
+            eprintln!("[editor_state] failed to persist window geometry: {err:#}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::editor_theme::EditorThemeKind;
+    use tempfile::tempdir;
+
+    fn geometry(size: [f64; 2], maximized: bool) -> WindowGeometry {
+        WindowGeometry {
+            position: Some([1920, 0]),
+            size,
+            maximized,
+            fullscreen: false,
+            monitor: Some("side".to_string()),
+        }
+    }
+
+    #[test]
+    fn maximized_window_keeps_restored_size() {
+        let mut state = EditorStateFile::default();
+        state.record_window(geometry([1280.0, 720.0], false));
+        state.record_window(geometry([2560.0, 1400.0], true));
+        let window = state.window.as_ref().expect("window recorded");
+        assert!(window.maximized);
+        assert_eq!(window.size, [1280.0, 720.0]);
+        assert_eq!(window.monitor.as_deref(), Some("side"));
+    }
+
+    #[test]
+    fn state_roundtrips_through_disk() {
+        let dir = tempdir().expect("tempdir");
+        let path = dir.path().join("config").join("editor_state.json");
+        assert_eq!(EditorStateFile::load(&path), EditorStateFile::default());
+        let state = EditorStateFile { window: Some(geometry([1600.0, 900.0], true)), ..Default::default() };
+        state.store(&path).expect("store");
+        assert_eq!(EditorStateFile::load(&path), state);
+    }
+
+    #[test]
+    fn theme_persists_and_defaults_for_older_files() {
+        let dir = tempdir().expect("tempdir");
+        let path = dir.path().join("editor_state.json");
+        std::fs::write(&path, r#"{ "window": null }"#).expect("write legacy state");
+        assert_eq!(EditorStateFile::load(&path).theme, EditorTheme::default());
+
+        let theme = EditorTheme { kind: EditorThemeKind::HighContrast, accent: Some([255, 90, 20]) };
+        let state = EditorStateFile { theme, ..Default::default() };
+        state.store(&path).expect("store");
+        let raw = std::fs::read_to_string(&path).expect("read state");
+        assert!(raw.contains("high_contrast"), "theme kind should serialize in snake_case: {raw}");
+        assert_eq!(EditorStateFile::load(&path).theme, theme);
+    }
+}
@@ -0,0 +1,201 @@
+use egui::{Color32, Stroke, Theme, Visuals};
+use serde::{Deserialize, Serialize};
+
+/// Base color scheme for the editor chrome.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum EditorThemeKind {
+    #[default]
+    Dark,
+    Light,
+    HighContrast,
+}
+
+impl EditorThemeKind {
+    pub(crate) const ALL: [EditorThemeKind; 3] =
+        [EditorThemeKind::Dark, EditorThemeKind::Light, EditorThemeKind::HighContrast];
+
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            EditorThemeKind::Dark => "Dark",
+            EditorThemeKind::Light => "Light",
+            EditorThemeKind::HighContrast => "High contrast",
+        }
+    }
+}
+
+/// Editor color scheme plus an optional accent used for selection, links and hovered widgets.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default)]
+pub(crate) struct EditorTheme {
+    pub(crate) kind: EditorThemeKind,
+    pub(crate) accent: Option<[u8; 3]>,
+}
+
+/// Colors for the shapes painted over the viewport, chosen to stay readable under each theme.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct OverlayPalette {
+    pub(crate) viewport_outline: Color32,
+    pub(crate) selection: Color32,
+    pub(crate) spatial_hash: Color32,
+    pub(crate) collider: Color32,
+    pub(crate) nav_blocked: Color32,
+    pub(crate) nav_path: Color32,
+    pub(crate) emitter_outline: Color32,
+    pub(crate) skeleton: Color32,
+    pub(crate) axis_x: Color32,
+    pub(crate) axis_y: Color32,
+    pub(crate) axis_z: Color32,
+    pub(crate) axis_x_active: Color32,
+    pub(crate) axis_y_active: Color32,
+    pub(crate) ring: Color32,
+    pub(crate) ring_inner: Color32,
+    pub(crate) ring_active: Color32,
+    pub(crate) ring_inner_active: Color32,
+    /// Multiplier applied to overlay stroke widths.
+    pub(crate) stroke_scale: f32,
+}
+
+impl EditorTheme {
+    fn egui_theme(&self) -> Theme {
+        match self.kind {
+            EditorThemeKind::Light => Theme::Light,
+            EditorThemeKind::Dark | EditorThemeKind::HighContrast => Theme::Dark,
+        }
+    }
+
+    pub(crate) fn accent_color(&self) -> Option<Color32> {
+        self.accent.map(|[r, g, b]| Color32::from_rgb(r, g, b))
+    }
+
+    pub(crate) fn visuals(&self) -> Visuals {
+        let mut visuals = match self.kind {
+            EditorThemeKind::Dark => Visuals::dark(),
+            EditorThemeKind::Light => Visuals::light(),
+            EditorThemeKind::HighContrast => high_contrast_visuals(),
+        };
+        if let Some(accent) = self.accent_color() {
+            visuals.selection.bg_fill = accent;
+            visuals.selection.stroke = Stroke::new(1.0, readable_text_on(accent));
+            visuals.hyperlink_color = accent;
+            visuals.widgets.hovered.bg_stroke.color = accent;
+            visuals.widgets.active.bg_stroke.color = accent;
+        }
+        visuals
+    }
+
+    /// Switches `ctx` to this theme; egui otherwise follows the OS light/dark preference.
+    pub(crate) fn apply(&self, ctx: &egui::Context) {
+        let theme = self.egui_theme();
+        ctx.set_theme(theme);
+        ctx.set_visuals_of(theme, self.visuals());
+    }
+
+    pub(crate) fn overlay_palette(&self) -> OverlayPalette {
+        let mut palette = match self.kind {
+            EditorThemeKind::Dark => OverlayPalette {
+                viewport_outline: Color32::from_rgba_premultiplied(220, 220, 240, 80),
+                selection: Color32::YELLOW,
+                spatial_hash: Color32::from_rgba_premultiplied(80, 200, 255, 80),
+                collider: Color32::from_rgba_premultiplied(255, 140, 60, 120),
+                nav_blocked: Color32::from_rgba_premultiplied(120, 40, 160, 60),
+                nav_path: Color32::from_rgb(90, 230, 140),
+                emitter_outline: Color32::from_rgb(255, 210, 90),
+                skeleton: Color32::from_rgb(140, 220, 255),
+                axis_x: Color32::from_rgb(240, 120, 120),
+                axis_y: Color32::from_rgb(100, 220, 100),
+                axis_z: Color32::from_rgb(120, 150, 255),
+                axis_x_active: Color32::from_rgb(255, 185, 185),
+                axis_y_active: Color32::from_rgb(185, 225, 255),
+                ring: Color32::from_rgb(255, 210, 90),
+                ring_inner: Color32::from_rgb(180, 160, 60),
+                ring_active: Color32::from_rgb(255, 235, 150),
+                ring_inner_active: Color32::from_rgb(220, 200, 110),
+                stroke_scale: 1.0,
+            },
+            // Light chrome usually sits next to bright scenes, so overlays get darker, opaque tones.
+            EditorThemeKind::Light => OverlayPalette {
+                viewport_outline: Color32::from_rgba_premultiplied(40, 40, 60, 140),
+                selection: Color32::from_rgb(200, 120, 0),
+                spatial_hash: Color32::from_rgba_premultiplied(0, 90, 160, 150),
+                collider: Color32::from_rgba_premultiplied(190, 70, 0, 190),
+                nav_blocked: Color32::from_rgba_premultiplied(90, 20, 130, 90),
+                nav_path: Color32::from_rgb(0, 140, 70),
+                emitter_outline: Color32::from_rgb(170, 110, 0),
+                skeleton: Color32::from_rgb(0, 110, 170),
+                axis_x: Color32::from_rgb(200, 40, 40),
+                axis_y: Color32::from_rgb(30, 150, 30),
+                axis_z: Color32::from_rgb(40, 70, 210),
+                axis_x_active: Color32::from_rgb(240, 90, 90),
+                axis_y_active: Color32::from_rgb(60, 120, 230),
+                ring: Color32::from_rgb(190, 130, 0),
+                ring_inner: Color32::from_rgb(120, 90, 0),
+                ring_active: Color32::from_rgb(230, 160, 0),
+                ring_inner_active: Color32::from_rgb(160, 120, 0),
+                stroke_scale: 1.0,
+            },
+            EditorThemeKind::HighContrast => OverlayPalette {
+                viewport_outline: Color32::WHITE,
+                selection: Color32::YELLOW,
+                spatial_hash: Color32::from_rgb(0, 220, 255),
+                collider: Color32::from_rgb(255, 120, 0),
+                nav_blocked: Color32::from_rgba_premultiplied(150, 0, 200, 120),
+                nav_path: Color32::from_rgb(0, 255, 120),
+                emitter_outline: Color32::from_rgb(255, 230, 0),
+                skeleton: Color32::from_rgb(0, 255, 255),
+                axis_x: Color32::from_rgb(255, 60, 60),
+                axis_y: Color32::from_rgb(0, 255, 80),
+                axis_z: Color32::from_rgb(80, 140, 255),
+                axis_x_active: Color32::WHITE,
+                axis_y_active: Color32::WHITE,
+                ring: Color32::YELLOW,
+                ring_inner: Color32::from_rgb(255, 160, 0),
+                ring_active: Color32::WHITE,
+                ring_inner_active: Color32::from_rgb(255, 220, 120),
+                stroke_scale: 1.5,
+            },
+        };
+        if let Some(accent) = self.accent_color() {
+            palette.selection = accent;
+        }
+        palette
+    }
+}
+
+fn high_contrast_visuals() -> Visuals {
+    let mut visuals = Visuals::dark();
+    visuals.override_text_color = Some(Color32::WHITE);
+    visuals.window_fill = Color32::BLACK;
+    visuals.panel_fill = Color32::BLACK;
+    visuals.extreme_bg_color = Color32::BLACK;
+    visuals.faint_bg_color = Color32::from_gray(24);
+    visuals.window_stroke = Stroke::new(1.5, Color32::WHITE);
+    visuals.hyperlink_color = Color32::from_rgb(0, 220, 255);
+    visuals.selection.bg_fill = Color32::from_rgb(0, 90, 200);
+    visuals.selection.stroke = Stroke::new(1.5, Color32::WHITE);
+    let widgets = &mut visuals.widgets;
+    widgets.noninteractive.bg_stroke = Stroke::new(1.0, Color32::from_gray(200));
+    widgets.noninteractive.fg_stroke = Stroke::new(1.0, Color32::WHITE);
+    for state in [&mut widgets.inactive, &mut widgets.hovered, &mut widgets.active, &mut widgets.open] {
+        state.bg_stroke = Stroke::new(1.5, Color32::WHITE);
+        state.fg_stroke = Stroke::new(1.5, Color32::WHITE);
+    }
+    widgets.inactive.bg_fill = Color32::from_gray(20);
+    widgets.inactive.weak_bg_fill = Color32::from_gray(20);
+    widgets.hovered.bg_fill = Color32::from_gray(50);
+    widgets.hovered.weak_bg_fill = Color32::from_gray(50);
+    widgets.active.bg_fill = Color32::from_gray(80);
+    widgets.active.weak_bg_fill = Color32::from_gray(80);
+    visuals
+}
+
+/// Black or white, whichever reads better on `background`.
+fn readable_text_on(background: Color32) -> Color32 {
+    let [r, g, b, _] = background.to_array();
+    let luma = 0.299 * r as f32 + 0.587 * g as f32 + 0.114 * b as f32;
+    if luma > 150.0 {
+        Color32::BLACK
+    } else {
+        Color32::WHITE
+    }
+}
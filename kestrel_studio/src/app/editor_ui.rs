@@ -1,29 +1,42 @@
 use super::{
     editor_shell::{ScriptHandleBinding, ScriptOffenderStatus, ScriptTimingHistory},
+    editor_theme::{EditorTheme, EditorThemeKind, OverlayPalette},
+    entity_search::{entity_search, EntitySearchCandidate, MAX_ENTITY_SEARCH_RESULTS},
+    layout_tooling::{
+        LayoutAction, LayoutPreset, DEFAULT_SIDEBAR_WIDTH, PANEL_ANIMATION_TIME, PANEL_KEYFRAME_EDITOR,
+        PANEL_MESH_PREVIEW, PANEL_PREFAB_SHELF, PANEL_SCRIPT_DEBUGGER,
+    },
+    scene_streaming_tooling::SceneChunkStatus,
+    selection_tooling::BulkTransform,
     App, CameraBookmark, FrameTimingSample, LabUpgrade, MeshControlMode, OpenWorldCameraMode,
     ScriptConsoleEntry, ScriptConsoleKind, ViewportCameraMode,
 };
 #[cfg(feature = "alloc_profiler")]
 use crate::alloc_profiler::AllocationDelta;
+use crate::alloc_sampler;
 use crate::analytics::{
-    AnimationBudgetSample, GpuPassMetric, KeyframeEditorEvent, KeyframeEditorEventKind,
+    AnimationBudgetSample, CustomCounterHistory, GpuPassMetric, KeyframeEditorEvent, KeyframeEditorEventKind,
     KeyframeEditorTrackKind, KeyframeEditorUsageSnapshot,
 };
 use crate::animation_validation::{AnimationValidationEvent, AnimationValidationSeverity};
-use crate::audio::{AudioHealthSnapshot, AudioSpatialConfig};
+use crate::audio::{AudioHealthSnapshot, AudioSpatialConfig, MAX_VOICE_COUNT};
 use crate::camera::Camera2D;
 use crate::camera3d::Camera3D;
 use crate::ecs::{
-    AnimationTime, EntityInfo, ForceFalloff, ForceFieldKind, ParticleBudgetMetrics, ParticleTrail,
-    PropertyTrackPlayer, SpatialMetrics, SpatialMode, SpriteAnimPerfSample, SystemTimingSummary,
-    TransformTrackPlayer,
+    AnimationTime, ColliderGeometry, ColliderKind, ComponentFieldValue, EmitterShape, EntityInfo,
+    ForceFalloff, ForceFieldKind, ParticleBudgetMetrics, ParticleCollisionMode, ParticleTrail,
+    PropertyTrackPlayer, SkeletonJointPose, SpatialMetrics, SpatialMode, SpriteAnimPerfSample,
+    SpriteAnimationLoopMode, SpriteTimelineUsageEntry, SystemTimingSummary, TransformTrackPlayer,
 };
 use crate::events::GameEvent;
 use crate::gizmo::{
-    Axis2, GizmoInteraction, GizmoMode, ScaleHandleKind, GIZMO_ROTATE_INNER_RADIUS_PX,
-    GIZMO_ROTATE_OUTER_RADIUS_PX, GIZMO_SCALE_AXIS_LENGTH_PX, GIZMO_SCALE_AXIS_THICKNESS_PX,
-    GIZMO_SCALE_HANDLE_SIZE_PX, GIZMO_SCALE_INNER_RADIUS_PX, GIZMO_SCALE_OUTER_RADIUS_PX,
+    collider_grips, Axis2, ColliderGrip, GizmoInteraction, GizmoMode, ScaleHandleKind,
+    GIZMO_COLLIDER_GRIP_RADIUS_PX, GIZMO_ROTATE_INNER_RADIUS_PX, GIZMO_ROTATE_OUTER_RADIUS_PX,
+    GIZMO_SCALE_AXIS_LENGTH_PX, GIZMO_SCALE_AXIS_THICKNESS_PX, GIZMO_SCALE_HANDLE_SIZE_PX,
+    GIZMO_SCALE_INNER_RADIUS_PX, GIZMO_SCALE_OUTER_RADIUS_PX,
 };
+use crate::material_registry::MaterialCullMode;
+use crate::mesh::{gltf_part_source, MeshAxisConvention, MeshImportSettings};
 use crate::mesh_preview::{GIZMO_3D_AXIS_LENGTH_SCALE, GIZMO_3D_AXIS_MAX, GIZMO_3D_AXIS_MIN};
 use crate::plugins::{
     AssetReadbackStats, CapabilityViolationLog, PluginAssetReadbackEvent, PluginCapability,
@@ -31,20 +44,22 @@ use crate::plugins::{
 };
 use crate::prefab::{PrefabFormat, PrefabStatusKind, PrefabStatusMessage};
 use crate::renderer::{
-    GpuPassTiming, LightClusterMetrics, ScenePointLight, LIGHT_CLUSTER_MAX_LIGHTS, MAX_SHADOW_CASCADES,
+    AutoExposureSettings, GpuMemoryReport, GpuPassTiming, LightClusterMetrics, PointLightFalloff, RenderStats,
+    ScenePointLight, LIGHT_CLUSTER_MAX_LIGHTS, MAX_RENDER_SCALE, MAX_SHADOW_CASCADES, MIN_RENDER_SCALE,
 };
 use crate::runtime_host::PlayState;
 use crate::scene::SceneShadowData;
-use crate::scripts::ScriptTimingSummary;
+use crate::scripts::{ScriptErrorInfo, ScriptErrorLocation, ScriptTimingSummary};
+use crate::tr;
 
-use crate::config::SpriteGuardrailMode;
+use crate::config::{SpriteColorSpace, SpriteGuardrailMode, SpriteSortMode};
 use bevy_ecs::prelude::Entity;
 use egui::{Checkbox, DragAndDrop, Key, SliderClamping};
 use egui_plot as eplot;
 use glam::{Vec2, Vec3, Vec4};
 use serde_json::Value as JsonValue;
 use std::collections::{BTreeMap, HashMap, HashSet};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use winit::dpi::PhysicalSize;
 
@@ -76,6 +91,7 @@ pub(super) enum PrefabDropTarget {
 pub(super) struct PrefabShelfEntry {
     pub name: String,
     pub format: PrefabFormat,
+    pub path: PathBuf,
     pub path_display: String,
 }
 
@@ -97,6 +113,7 @@ pub(super) struct PrefabInstantiateRequest {
 pub(super) enum ProjectAction {
     OpenExisting { path: String },
     CreateNew { name: String, path: String },
+    Browse,
 }
 
 #[derive(Clone, Debug)]
@@ -121,6 +138,7 @@ pub(super) struct AtlasAssetSummary {
 pub(super) struct MaterialOption {
     pub key: String,
     pub label: String,
+    pub cull_mode: MaterialCullMode,
 }
 
 #[derive(Clone, Copy)]
@@ -134,6 +152,10 @@ const SCRIPT_API_DOCS: &[ScriptApiDoc] = &[
         signature: "spawn_prefab(path) / spawn_template(name)",
         detail: "Queue a prefab/template spawn; returns a handle usable with entity_* helpers.",
     },
+    ScriptApiDoc {
+        signature: "spawn_sprite_timed(atlas, region, x, y, scale, vx, vy, lifetime)",
+        detail: "Spawn a sprite that despawns itself after lifetime seconds; its physics body is pooled for reuse.",
+    },
     ScriptApiDoc {
         signature: "entity_set_* / entity_despawn(handle/entity)",
         detail: "Move/tint/despawn entities via the command queue; resolves either handles or raw entities.",
@@ -142,6 +164,26 @@ const SCRIPT_API_DOCS: &[ScriptApiDoc] = &[
         signature: "entity_snapshot(entity)",
         detail: "Read pos/rot/scale/vel/tint/half_extents from the per-frame snapshot (read-only).",
     },
+    ScriptApiDoc {
+        signature: "request_path(handle, x, y)",
+        detail: "Plan a route around static colliders on the nav grid and steer the handle there (adds a PathAgent if needed).",
+    },
+    ScriptApiDoc {
+        signature: "emitter_burst(handle, count)",
+        detail: "Queue a one-shot particle burst from the handle's emitter shape; bursts over the spawn budget drain across frames.",
+    },
+    ScriptApiDoc {
+        signature: "set_emitter_burst(handle, count, speed)",
+        detail: "Spawn count particles at once on the next fixed step, launched at speed; whatever the particle caps cannot fit is dropped.",
+    },
+    ScriptApiDoc {
+        signature: "entity_set_graph_parameter(entity, name, value)",
+        detail: "Set a float or bool parameter on the entity's animation graph; transitions re-evaluate next frame.",
+    },
+    ScriptApiDoc {
+        signature: "entity_trigger_graph_event(entity, name)",
+        detail: "Raise a one-shot trigger consumed by the graph's next transition check.",
+    },
     ScriptApiDoc {
         signature: "raycast(origin, dir, max[, filters])",
         detail: "Ray vs. physics colliders when present (returns entity/distance/point/normal/collider id) else snapshot AABBs; include/exclude filters accept entity arrays.",
@@ -156,7 +198,19 @@ const SCRIPT_API_DOCS: &[ScriptApiDoc] = &[
     },
     ScriptApiDoc {
         signature: "listen(name, handler) / emit(name[, payload])",
-        detail: "Subscribe and emit events; use emit_to(name, entity, payload) for scoped delivery.",
+        detail: "Subscribe and emit events; untargeted emits also reach plugins and the event log. Use emit_to(name, entity, payload) for scoped delivery.",
+    },
+    ScriptApiDoc {
+        signature: "play_sound(clip, x, y)",
+        detail: "Plays a positioned audio cue; ignored with a log line when the audio plugin is disabled.",
+    },
+    ScriptApiDoc {
+        signature: "set_audio_voice_count(count)",
+        detail: "Changes how many cues may play at once (1-256); shrinking stops the quietest playing voices.",
+    },
+    ScriptApiDoc {
+        signature: "create_material(key, #{ base_color, metallic, roughness, emissive, albedo_texture }) / release_material(key)",
+        detail: "Registers a file-less mesh material (omitted fields use defaults); script materials are released on release_material or when the scene unloads.",
     },
     ScriptApiDoc {
         signature: "kit_enemy_contact / kit_enemy_hit events",
@@ -196,6 +250,13 @@ pub(super) struct MeshSubsetEntry {
     pub material: Option<String>,
 }
 
+/// Mesh keys loaded from one source file; `source` is `None` for built-in meshes.
+#[derive(Clone, Debug)]
+pub(super) struct MeshKeyGroup {
+    pub source: Option<String>,
+    pub keys: Vec<String>,
+}
+
 #[derive(Clone, Debug)]
 pub(super) struct SkeletonEntityBinding {
     pub entity: Entity,
@@ -226,6 +287,34 @@ pub(super) enum InspectorAction {
         entity: Entity,
         velocity: Vec2,
     },
+    ResetTransform {
+        entity: Entity,
+    },
+    SetColliderGeometry {
+        entity: Entity,
+        geometry: ColliderGeometry,
+    },
+    UndoColliderEdit,
+    RedoColliderEdit,
+    ResetVelocity {
+        entity: Entity,
+    },
+    ResetTint {
+        entity: Entity,
+    },
+    SetPluginComponentField {
+        entity: Entity,
+        key: String,
+        field: String,
+        value: ComponentFieldValue,
+    },
+    SetParent {
+        entity: Entity,
+        parent_scene_id: String,
+    },
+    DetachFromParent {
+        entity: Entity,
+    },
     SetScript {
         entity: Entity,
         path: String,
@@ -256,6 +345,15 @@ pub(super) enum InspectorAction {
         entity: Entity,
         playing: bool,
     },
+    SetTintCurve {
+        entity: Entity,
+        curve_key: String,
+        duration: f32,
+        loop_mode: SpriteAnimationLoopMode,
+    },
+    ClearTintCurve {
+        entity: Entity,
+    },
     ResetTransformClip {
         entity: Entity,
     },
@@ -263,6 +361,10 @@ pub(super) enum InspectorAction {
         entity: Entity,
         speed: f32,
     },
+    SetTransformClipLoopCount {
+        entity: Entity,
+        loop_count: Option<u32>,
+    },
     SetTransformClipGroup {
         entity: Entity,
         group: Option<String>,
@@ -271,6 +373,10 @@ pub(super) enum InspectorAction {
         entity: Entity,
         time: f32,
     },
+    SetTransformClipEvents {
+        entity: Entity,
+        events: Vec<(f32, String)>,
+    },
     SetTransformTrackMask {
         entity: Entity,
         mask: TransformTrackPlayer,
@@ -304,6 +410,10 @@ pub(super) enum InspectorAction {
         entity: Entity,
         speed: f32,
     },
+    SetSkeletonClipLoopCount {
+        entity: Entity,
+        loop_count: Option<u32>,
+    },
     SetSkeletonClipGroup {
         entity: Entity,
         group: Option<String>,
@@ -312,6 +422,11 @@ pub(super) enum InspectorAction {
         entity: Entity,
         time: f32,
     },
+    SetGraphParameter {
+        entity: Entity,
+        name: String,
+        value: f32,
+    },
     SetSpriteAtlas {
         entity: Entity,
         atlas: String,
@@ -322,6 +437,14 @@ pub(super) enum InspectorAction {
         atlas: String,
         region: String,
     },
+    SetSpriteZOrder {
+        entity: Entity,
+        z_order: i16,
+    },
+    SetSpriteNormalMap {
+        entity: Entity,
+        path: Option<String>,
+    },
     SetSpriteTimeline {
         entity: Entity,
         timeline: Option<String>,
@@ -337,6 +460,10 @@ pub(super) enum InspectorAction {
         entity: Entity,
         looped: bool,
     },
+    SetSpriteAnimationLoopCount {
+        entity: Entity,
+        loop_count: Option<u32>,
+    },
     SetSpriteAnimationSpeed {
         entity: Entity,
         speed: f32,
@@ -369,6 +496,14 @@ pub(super) enum InspectorAction {
         cast: bool,
         receive: bool,
     },
+    SetMeshMaxDrawDistance {
+        entity: Entity,
+        distance: Option<f32>,
+    },
+    SetMaterialCullMode {
+        key: String,
+        cull_mode: MaterialCullMode,
+    },
     SetMeshMaterialParams {
         entity: Entity,
         base_color: Vec3,
@@ -413,6 +548,35 @@ pub(super) enum InspectorAction {
         entity: Entity,
         trail: Option<ParticleTrail>,
     },
+    SetEmitterShape {
+        entity: Entity,
+        shape: EmitterShape,
+    },
+    SetEmitterCollision {
+        entity: Entity,
+        mode: ParticleCollisionMode,
+        events: bool,
+    },
+    EmitterBurst {
+        entity: Entity,
+        count: u32,
+    },
+    SetEmitterBurst {
+        entity: Entity,
+        count: u32,
+        impulse_speed: f32,
+    },
+    SetEmitterSettings {
+        entity: Entity,
+        rate: f32,
+        spread: f32,
+        speed: f32,
+        lifetime: f32,
+        start_color: Vec4,
+        end_color: Vec4,
+        start_size: f32,
+        end_size: f32,
+    },
     SetForceField {
         entity: Entity,
         field: Option<(ForceFieldKind, f32, f32, ForceFalloff, Vec2)>,
@@ -437,6 +601,8 @@ pub(super) struct MeshDependencyStatus {
     pub persistent: bool,
     pub loaded: bool,
     pub ref_count: usize,
+    /// `None` while the mesh is not loaded.
+    pub triangle_count: Option<u64>,
     pub path: Option<String>,
 }
 
@@ -473,6 +639,7 @@ pub(super) struct FrameBudgetSnapshotView {
 pub(super) enum PluginToggleKind {
     Dynamic { new_enabled: bool },
     Builtin { disable: bool },
+    Config { config: Option<serde_json::Value> },
 }
 
 #[derive(Debug, Clone)]
@@ -499,44 +666,219 @@ struct ParsedAudioTrigger {
     force: Option<f32>,
 }
 
+/// Collapsing panel titled by the translation of `key`, keyed by `key` so its open state survives
+/// language switches.
+fn panel_header(key: &'static str) -> egui::CollapsingHeader {
+    egui::CollapsingHeader::new(tr!(key)).id_salt(key)
+}
+
 fn summarize_game_event(event: &GameEvent) -> (String, egui::Color32) {
     match event {
         GameEvent::SpriteSpawned { entity, atlas, region, .. } => (
-            format!("Sprite #{:04} spawned - {atlas}/{region}", entity.index()),
+            tr!(
+                "ui.event_sprite_spawned",
+                entity = format!("{:04}", entity.index()),
+                atlas = atlas,
+                region = region
+            ),
             egui::Color32::from_rgb(120, 200, 120),
         ),
-        GameEvent::EntityDespawned { entity } => {
-            (format!("Entity #{:04} despawned", entity.index()), egui::Color32::from_rgb(210, 130, 130))
-        }
+        GameEvent::EntityDespawned { entity } => (
+            tr!("ui.event_entity_despawned", entity = format!("{:04}", entity.index())),
+            egui::Color32::from_rgb(210, 130, 130),
+        ),
         GameEvent::CollisionStarted { a, b, .. } => (
-            format!("Collision started between #{:04} and #{:04}", a.index(), b.index()),
+            tr!(
+                "ui.event_collision_started",
+                a = format!("{:04}", a.index()),
+                b = format!("{:04}", b.index())
+            ),
             egui::Color32::from_rgb(220, 180, 90),
         ),
         GameEvent::CollisionEnded { a, b, .. } => (
-            format!("Collision resolved between #{:04} and #{:04}", a.index(), b.index()),
+            tr!("ui.event_collision_ended", a = format!("{:04}", a.index()), b = format!("{:04}", b.index())),
             egui::Color32::from_rgb(130, 170, 220),
         ),
         GameEvent::CollisionForce { a, b, force, .. } => (
-            format!("Impact #{:04}/{:04} - force {:.1}", a.index(), b.index(), force),
+            tr!(
+                "ui.event_collision_force",
+                a = format!("{:04}", a.index()),
+                b = format!("{:04}", b.index()),
+                force = format!("{force:.1}")
+            ),
             egui::Color32::from_rgb(200, 150, 240),
         ),
         GameEvent::SpriteAnimationEvent { entity, timeline, event } => (
-            format!("Anim event #{:04} {}::{}", entity.index(), timeline, event),
+            tr!(
+                "ui.event_sprite_animation",
+                entity = format!("{:04}", entity.index()),
+                timeline = timeline,
+                event = event
+            ),
             egui::Color32::from_rgb(180, 200, 255),
         ),
+        GameEvent::AnimationFinished { entity, atlas, timeline } => (
+            tr!(
+                "ui.event_animation_finished",
+                entity = format!("{:04}", entity.index()),
+                atlas = atlas,
+                timeline = timeline
+            ),
+            egui::Color32::from_rgb(150, 210, 230),
+        ),
+        GameEvent::ClipFinished { entity, clip } => (
+            tr!("ui.event_clip_finished", entity = format!("{:04}", entity.index()), clip = clip),
+            egui::Color32::from_rgb(150, 210, 230),
+        ),
         GameEvent::ScriptMessage { message } => {
-            (format!("Script: {message}"), egui::Color32::from_rgb(170, 170, 170))
+            (tr!("ui.event_script_message", message = message), egui::Color32::from_rgb(170, 170, 170))
         }
+        GameEvent::ScriptEvent { name, payload } => match payload {
+            Some(payload) => (
+                tr!("ui.event_script_payload", name = name, payload = payload),
+                egui::Color32::from_rgb(190, 190, 140),
+            ),
+            None => (tr!("ui.event_script", name = name), egui::Color32::from_rgb(190, 190, 140)),
+        },
+        GameEvent::ParticleCollision { emitter, position } => (
+            tr!(
+                "ui.event_particle_collision",
+                emitter = format!("{:04}", emitter.index()),
+                x = format!("{:.2}", position.x),
+                y = format!("{:.2}", position.y)
+            ),
+            egui::Color32::from_rgb(230, 170, 120),
+        ),
+        GameEvent::BoneTransformResult { handle, position, .. } => (
+            tr!(
+                "ui.event_bone_transform",
+                handle = handle,
+                x = format!("{:.2}", position.x),
+                y = format!("{:.2}", position.y),
+                z = format!("{:.2}", position.z)
+            ),
+            egui::Color32::from_rgb(180, 200, 255),
+        ),
     }
 }
 
 fn plugin_status_summary(status: &PluginStatus) -> (egui::Color32, String) {
     match &status.state {
-        PluginState::Loaded => (egui::Color32::LIGHT_GREEN, "Loaded".to_string()),
+        PluginState::Loaded if status.muted => (egui::Color32::GRAY, tr!("ui.plugin_loaded_muted")),
+        PluginState::Loaded => (egui::Color32::LIGHT_GREEN, tr!("ui.plugin_loaded")),
         PluginState::Disabled(reason) => {
-            (egui::Color32::from_rgb(220, 180, 80), format!("Disabled: {reason}"))
+            (egui::Color32::from_rgb(220, 180, 80), tr!("ui.plugin_disabled", reason = reason))
+        }
+        PluginState::Failed(reason) => {
+            (egui::Color32::from_rgb(220, 120, 120), tr!("ui.plugin_failed", reason = reason))
+        }
+        PluginState::Quarantined(quarantine) => {
+            (egui::Color32::from_rgb(235, 80, 80), tr!("ui.plugin_quarantined", reason = quarantine.reason))
+        }
+    }
+}
+
+/// Raw JSON editor for a manifest entry's `config`; an empty buffer removes the config.
+fn plugin_config_ui(
+    ui: &mut egui::Ui,
+    plugin_name: &str,
+    config: Option<&serde_json::Value>,
+    actions: &mut UiActions,
+) {
+    let draft_id = egui::Id::new(("plugin_config_draft", plugin_name));
+    let error_id = egui::Id::new(("plugin_config_error", plugin_name));
+    egui::CollapsingHeader::new(tr!("ui.config")).id_salt(("plugin_config", plugin_name)).show(ui, |ui| {
+        let mut draft = ui.ctx().data_mut(|d| d.get_persisted::<String>(draft_id)).unwrap_or_else(|| {
+            config.and_then(|config| serde_json::to_string_pretty(config).ok()).unwrap_or_default()
+        });
+        let editor = egui::TextEdit::multiline(&mut draft)
+            .code_editor()
+            .desired_rows(4)
+            .desired_width(f32::INFINITY)
+            .hint_text("{ }");
+        if ui.add(editor).changed() {
+            ui.ctx().data_mut(|d| d.insert_persisted(draft_id, draft.clone()));
+        }
+        ui.horizontal(|ui| {
+            if ui.button(tr!("ui.apply")).on_hover_text(tr!("ui.apply_hint")).clicked() {
+                let parsed = if draft.trim().is_empty() {
+                    Ok(None)
+                } else {
+                    serde_json::from_str::<serde_json::Value>(&draft).map(Some)
+                };
+                match parsed {
+                    Ok(config) => {
+                        actions.plugin_toggles.push(PluginToggleRequest {
+                            name: plugin_name.to_string(),
+                            kind: PluginToggleKind::Config { config },
+                        });
+                        ui.ctx().data_mut(|d| {
+                            d.remove::<String>(draft_id);
+                            d.remove::<String>(error_id);
+                        });
+                    }
+                    Err(err) => ui
+                        .ctx()
+                        .data_mut(|d| d.insert_temp(error_id, tr!("plugin.config_invalid", error = err))),
+                }
+            }
+            if ui.button(tr!("ui.revert")).clicked() {
+                ui.ctx().data_mut(|d| {
+                    d.remove::<String>(draft_id);
+                    d.remove::<String>(error_id);
+                });
+            }
+        });
+        if let Some(error) = ui.ctx().data(|d| d.get_temp::<String>(error_id)) {
+            ui.colored_label(egui::Color32::from_rgb(235, 80, 80), error);
+        }
+    });
+}
+
+/// Mute/solo toggles for debugging a loaded plugin without unloading it.
+fn plugin_dispatch_ui(ui: &mut egui::Ui, status: &PluginStatus, actions: &mut UiActions) {
+    if !matches!(status.state, PluginState::Loaded) {
+        return;
+    }
+    ui.horizontal(|ui| {
+        if ui.selectable_label(status.muted, tr!("ui.mute")).on_hover_text(tr!("ui.mute_hint")).clicked() {
+            actions.plugin_mute.push((status.name.clone(), !status.muted));
+        }
+        if ui.selectable_label(status.soloed, tr!("ui.solo")).on_hover_text(tr!("ui.solo_hint")).clicked() {
+            actions.plugin_solo.push((status.name.clone(), !status.soloed));
+        }
+    });
+}
+
+fn plugin_quarantine_ui(ui: &mut egui::Ui, status: &PluginStatus, actions: &mut UiActions) {
+    let PluginState::Quarantined(quarantine) = &status.state else {
+        return;
+    };
+    ui.horizontal(|ui| {
+        let ago = quarantine
+            .timestamp
+            .elapsed()
+            .map(|duration| tr!("ui.seconds_ago", seconds = format!("{:.1}", duration.as_secs_f32())))
+            .unwrap_or_else(|_| tr!("ui.just_now"));
+        ui.colored_label(egui::Color32::from_rgb(235, 80, 80), tr!("ui.skipped_since_panic", ago = ago));
+        if ui.button(tr!("ui.re_enable")).on_hover_text(tr!("ui.re_enable_hint")).clicked() {
+            actions.plugin_reenable.push(status.name.clone());
+        }
+    });
+    match quarantine.backtrace.as_deref() {
+        Some(backtrace) => {
+            egui::CollapsingHeader::new(tr!("ui.panic_backtrace"))
+                .id_salt(("plugin_backtrace", &status.name))
+                .show(ui, |ui| {
+                    egui::ScrollArea::vertical().max_height(200.0).show(ui, |ui| {
+                        ui.monospace(backtrace);
+                    });
+                },
+            );
+        }
+        None => {
+            ui.small(tr!("ui.no_backtrace_captured_run_with_rust"));
         }
-        PluginState::Failed(reason) => (egui::Color32::from_rgb(220, 120, 120), format!("Failed: {reason}")),
     }
 }
 
@@ -576,7 +918,7 @@ fn frame_budget_snapshot_row(ui: &mut egui::Ui, label: &str, snapshot: &FrameBud
         }
         ui.label(text);
     } else {
-        ui.label(format!("{label}: awaiting timing sample"));
+        ui.label(tr!("ui.timing_awaiting_sample", label = label));
     }
 }
 
@@ -607,7 +949,7 @@ fn frame_budget_delta_row(
         }
         ui.label(text);
     } else {
-        ui.label("Δ frame budget: awaiting timing samples.");
+        ui.label(tr!("ui.frame_budget_awaiting_timing_samples"));
     }
 }
 
@@ -617,11 +959,11 @@ fn capability_violation_summary(log: Option<&CapabilityViolationLog>) -> (egui::
             let last = log.last_capability.map(|cap| cap.label()).unwrap_or("unknown");
             return (
                 egui::Color32::from_rgb(220, 120, 80),
-                format!("Capability violations: {} (last: {last})", log.count),
+                tr!("ui.capability_violation_count", count = log.count, last = last),
             );
         }
     }
-    (egui::Color32::from_rgb(120, 200, 120), "Capability violations: 0".to_string())
+    (egui::Color32::from_rgb(120, 200, 120), tr!("ui.capability_violations_none"))
 }
 
 fn animation_validation_color(severity: AnimationValidationSeverity) -> egui::Color32 {
@@ -637,72 +979,168 @@ fn render_keyframe_editor_usage(
     usage: KeyframeEditorUsageSnapshot,
     events: &[KeyframeEditorEvent],
 ) {
-    ui.label("Keyframe Editor Usage");
-    ui.label(format!("Opened {} | Closed {}", usage.panel_open_count, usage.panel_close_count));
-    ui.label(format!(
-        "Scrubs {} | Inserts {} | Deletes {} ({} keys)",
-        usage.scrub_count, usage.insert_count, usage.delete_count, usage.delete_key_total
+    ui.label(tr!("ui.keyframe_editor_usage"));
+    ui.label(tr!(
+        "ui.opened_closed",
+        panel_open_count = usage.panel_open_count,
+        panel_close_count = usage.panel_close_count
     ));
-    ui.label(format!(
-        "Updates {} (time {} | value {})",
-        usage.update_count, usage.update_time_edits, usage.update_value_edits
+    ui.label(tr!(
+        "ui.scrubs_inserts_deletes_keys",
+        scrub_count = usage.scrub_count,
+        insert_count = usage.insert_count,
+        delete_count = usage.delete_count,
+        delete_key_total = usage.delete_key_total
     ));
-    ui.label(format!(
-        "Adjustments {} (time {} | value {})",
-        usage.adjust_count, usage.adjust_time_edits, usage.adjust_value_edits
+    ui.label(tr!(
+        "ui.updates_time_value",
+        update_count = usage.update_count,
+        update_time_edits = usage.update_time_edits,
+        update_value_edits = usage.update_value_edits
     ));
-    ui.label(format!("Undo {} | Redo {}", usage.undo_count, usage.redo_count));
+    ui.label(tr!(
+        "ui.adjustments_time_value",
+        adjust_count = usage.adjust_count,
+        adjust_time_edits = usage.adjust_time_edits,
+        adjust_value_edits = usage.adjust_value_edits
+    ));
+    ui.label(tr!("ui.undo_redo", undo_count = usage.undo_count, redo_count = usage.redo_count));
     if events.is_empty() {
-        ui.small("No recent keyframe events.");
+        ui.small(tr!("ui.no_recent_keyframe_events"));
     } else {
-        ui.label("Recent events:");
+        ui.label(tr!("ui.recent_events"));
         for event in events.iter().take(5) {
             let ago = event.timestamp.elapsed().as_secs_f32();
-            ui.small(format!("[{ago:>4.1}s ago] {}", format_keyframe_event(&event.kind)));
+            ui.small(tr!(
+                "ui.keyframe_event_ago",
+                ago = format!("{ago:>4.1}"),
+                kind = format_keyframe_event(&event.kind)
+            ));
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+enum TimelineUsageSort {
+    #[default]
+    Plays,
+    Loops,
+    Events,
+}
+
+impl TimelineUsageSort {
+    const ALL: [Self; 3] = [Self::Plays, Self::Loops, Self::Events];
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Plays => "Plays",
+            Self::Loops => "Loops",
+            Self::Events => "Events",
+        }
+    }
+
+    fn key(self, entry: &SpriteTimelineUsageEntry) -> u64 {
+        match self {
+            Self::Plays => entry.plays,
+            Self::Loops => entry.loops,
+            Self::Events => entry.total_events(),
+        }
+    }
+}
+
+fn render_timeline_usage(ui: &mut egui::Ui, usage: &[SpriteTimelineUsageEntry], actions: &mut UiActions) {
+    let sort_id = egui::Id::new("timeline_usage_sort");
+    let mut sort = ui.ctx().data_mut(|d| d.get_temp::<TimelineUsageSort>(sort_id)).unwrap_or_default();
+    ui.horizontal(|ui| {
+        egui::ComboBox::from_id_salt(sort_id).selected_text(tr!("ui.sort", sort = sort.label())).show_ui(
+            ui,
+            |ui| {
+                for option in TimelineUsageSort::ALL {
+                    ui.selectable_value(&mut sort, option, option.label());
+                }
+            },
+        );
+        if ui.button(tr!("ui.reset")).clicked() {
+            actions.timeline_usage_reset = true;
+        }
+        if ui.button(tr!("ui.export_session")).clicked() {
+            actions.export_analytics_session = true;
+        }
+    });
+    ui.ctx().data_mut(|d| d.insert_temp(sort_id, sort));
+    if usage.is_empty() {
+        ui.small(tr!("ui.no_sprite_timelines_played_this_session"));
+        return;
+    }
+    let mut rows = usage.iter().collect::<Vec<_>>();
+    rows.sort_by(|a, b| sort.key(b).cmp(&sort.key(a)).then_with(|| a.timeline.cmp(&b.timeline)));
+    for entry in rows {
+        ui.label(tr!(
+            "ui.timeline_usage_entry",
+            atlas = entry.atlas,
+            timeline = entry.timeline,
+            plays = entry.plays,
+            loops = entry.loops,
+            total_events = entry.total_events()
+        ));
+        let mut events = entry.events.iter().filter(|(_, count)| *count > 0).collect::<Vec<_>>();
+        events.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        for (name, count) in events {
+            ui.small(format!("    {name}: {count}"));
         }
     }
 }
 
 fn format_keyframe_event(event: &KeyframeEditorEventKind) -> String {
     match event {
-        KeyframeEditorEventKind::PanelOpened => "Panel opened".to_string(),
-        KeyframeEditorEventKind::PanelClosed => "Panel closed".to_string(),
+        KeyframeEditorEventKind::PanelOpened => tr!("ui.keyframe_panel_opened"),
+        KeyframeEditorEventKind::PanelClosed => tr!("ui.keyframe_panel_closed"),
         KeyframeEditorEventKind::Scrub { track } => {
-            format!("Scrubbed {}", keyframe_track_label(*track))
+            tr!("ui.keyframe_scrubbed", track = keyframe_track_label(*track))
         }
         KeyframeEditorEventKind::InsertKey { track } => {
-            format!("Inserted key on {}", keyframe_track_label(*track))
+            tr!("ui.keyframe_inserted", track = keyframe_track_label(*track))
         }
         KeyframeEditorEventKind::DeleteKeys { track, count } => {
-            format!("Deleted {count} key(s) from {}", keyframe_track_label(*track))
+            tr!("ui.keyframe_deleted", count = count, track = keyframe_track_label(*track))
         }
         KeyframeEditorEventKind::UpdateKey { track, changed_time, changed_value } => {
             let mut details = Vec::new();
             if *changed_time {
-                details.push("time");
+                details.push(tr!("ui.keyframe_detail_time"));
             }
             if *changed_value {
-                details.push("value");
+                details.push(tr!("ui.keyframe_detail_value"));
             }
             if details.is_empty() {
-                format!("Updated {}", keyframe_track_label(*track))
+                tr!("ui.keyframe_updated", track = keyframe_track_label(*track))
             } else {
-                format!("Updated {} ({})", keyframe_track_label(*track), details.join(" + "))
+                tr!(
+                    "ui.keyframe_updated_details",
+                    track = keyframe_track_label(*track),
+                    details = details.join(" + ")
+                )
             }
         }
         KeyframeEditorEventKind::AdjustKeys { track, count, time_delta, value_delta } => {
             let mut details = Vec::new();
             if *time_delta {
-                details.push("time offset");
+                details.push(tr!("ui.keyframe_detail_time_offset"));
             }
             if *value_delta {
-                details.push("value offset");
+                details.push(tr!("ui.keyframe_detail_value_offset"));
             }
-            let descriptor = if details.is_empty() { "offset".to_string() } else { details.join(" & ") };
-            format!("Adjusted {} key(s) on {} ({descriptor})", count, keyframe_track_label(*track))
+            let descriptor =
+                if details.is_empty() { tr!("ui.keyframe_detail_offset") } else { details.join(" & ") };
+            tr!(
+                "ui.keyframe_adjusted",
+                count = count,
+                track = keyframe_track_label(*track),
+                descriptor = descriptor
+            )
         }
-        KeyframeEditorEventKind::Undo => "Undo edit".to_string(),
-        KeyframeEditorEventKind::Redo => "Redo edit".to_string(),
+        KeyframeEditorEventKind::Undo => tr!("ui.keyframe_undo"),
+        KeyframeEditorEventKind::Redo => tr!("ui.keyframe_redo"),
     }
 }
 
@@ -744,7 +1182,7 @@ fn show_capability_info(
     trust: PluginTrust,
     log: Option<&CapabilityViolationLog>,
 ) {
-    ui.small(format!("Capabilities: {} (trust: {})", format_capability_list(caps), trust.label()));
+    ui.small(tr!("ui.capabilities_trust", caps = format_capability_list(caps), trust = trust.label()));
     show_capability_badges(ui, caps);
     let (color, text) = capability_violation_summary(log);
     ui.colored_label(color, text);
@@ -782,52 +1220,55 @@ fn plugin_debug_ui(
         ui.horizontal(|ui| {
             ui.colored_label(
                 egui::Color32::from_rgb(230, 190, 90),
-                format!("Watchdog events: {}", events.len()),
+                tr!("ui.watchdog_events", count = events.len()),
             );
-            if ui.button("Clear").clicked() {
+            if ui.button(tr!("ui.clear")).clicked() {
                 actions.plugin_watchdog_clear.push(plugin_name.to_string());
             }
         });
-        egui::CollapsingHeader::new("Watchdog history").default_open(false).show(ui, |ui| {
+        panel_header("ui.watchdog_history").default_open(false).show(ui, |ui| {
             for event in events {
                 let ago = event
                     .timestamp
                     .elapsed()
-                    .map(|duration| format!("{:.1}s ago", duration.as_secs_f32()))
-                    .unwrap_or_else(|_| "just now".to_string());
-                ui.label(format!(
-                    "{} | {} ({} ms) | last request: {}",
-                    ago, event.reason, event.elapsed_ms, event.last_request
+                    .map(|duration| tr!("ui.seconds_ago", seconds = format!("{:.1}", duration.as_secs_f32())))
+                    .unwrap_or_else(|_| tr!("ui.just_now"));
+                ui.label(tr!(
+                    "ui.watchdog_history_entry",
+                    ago = ago,
+                    reason = event.reason,
+                    elapsed_ms = event.elapsed_ms,
+                    last_request = event.last_request
                 ));
             }
         });
     }
     if let Some(history) = ecs_history.get(plugin_name).filter(|entries| !entries.is_empty()) {
-        egui::CollapsingHeader::new("Read-only ECS").default_open(false).show(ui, |ui| {
+        panel_header("ui.read_only_ecs").default_open(false).show(ui, |ui| {
             let max_rows = 8;
             for bits in history.iter().take(max_rows) {
                 ui.small(format_ecs_entity(*bits));
             }
             if history.len() > max_rows {
-                ui.small(format!("... {} older queries hidden", history.len() - max_rows));
+                ui.small(tr!("ui.older_queries_hidden", count = history.len() - max_rows));
             }
         });
     }
     if let Some(stats) = asset_metrics.get(plugin_name) {
-        ui.small(format!(
-            "Asset readbacks: {} req / {} cache hits / {} throttled – {} transferred",
-            stats.requests,
-            stats.cache_hits,
-            stats.throttled,
-            format_data_size(stats.bytes),
+        ui.small(tr!(
+            "ui.asset_readbacks_req_cache_hits",
+            requests = stats.requests,
+            cache_hits = stats.cache_hits,
+            throttled = stats.throttled,
+            bytes = format_data_size(stats.bytes)
         ));
     }
     let retry_enabled = pending_asset_requests.contains(plugin_name);
-    let retry_button = ui.add_enabled(retry_enabled, egui::Button::new("Retry asset readback"));
+    let retry_button = ui.add_enabled(retry_enabled, egui::Button::new(tr!("ui.retry_asset_readback")));
     if retry_button.clicked() {
         actions.plugin_retry_asset_readback.push(plugin_name.to_string());
     } else if !retry_enabled {
-        ui.small("No asset readbacks recorded yet.");
+        ui.small(tr!("ui.no_asset_readbacks_recorded_yet"));
     }
 }
 
@@ -863,7 +1304,8 @@ fn render_script_hit_summary(ui: &mut egui::Ui, text: &str) {
         JsonValue::Array(arr) => {
             for item in arr {
                 if let JsonValue::Object(map) = item {
-                    if map.contains_key("entity") && (map.contains_key("point") || map.contains_key("normal")) {
+                    if map.contains_key("entity") && (map.contains_key("point") || map.contains_key("normal"))
+                    {
                         draw_hit_row(ui, &map);
                     }
                 }
@@ -877,29 +1319,83 @@ fn draw_hit_row(ui: &mut egui::Ui, map: &serde_json::Map<String, JsonValue>) {
     let entity = map.get("entity").and_then(|v| v.as_i64()).unwrap_or(-1);
     let dist = map.get("distance").and_then(|v| v.as_f64());
     let collider = map.get("collider").and_then(|v| v.as_i64());
-    let normal = map
-        .get("normal")
-        .and_then(|v| v.as_array())
-        .and_then(|a| match (a.get(0), a.get(1)) {
-            (Some(JsonValue::Number(x)), Some(JsonValue::Number(y))) => Some((x.as_f64(), y.as_f64())),
-            _ => None,
-        });
+    let normal = map.get("normal").and_then(|v| v.as_array()).and_then(|a| match (a.get(0), a.get(1)) {
+        (Some(JsonValue::Number(x)), Some(JsonValue::Number(y))) => Some((x.as_f64(), y.as_f64())),
+        _ => None,
+    });
     ui.horizontal(|ui| {
-        ui.small(format!("entity {}", entity));
+        ui.small(tr!("ui.hit_entity", entity = entity));
         if let Some(d) = dist {
-            ui.small(format!("dist {:.3}", d));
+            ui.small(tr!("ui.dist", d = format!("{:.3}", d)));
         }
         if let Some((nx, ny)) = normal {
             if let (Some(nx), Some(ny)) = (nx, ny) {
-                ui.small(format!("normal ({:.2}, {:.2})", nx, ny));
+                ui.small(tr!("ui.normal", nx = format!("{:.2}", nx), ny = format!("{:.2}", ny)));
             }
         }
         if let Some(c) = collider {
-            ui.small(format!("collider {}", c));
+            ui.small(tr!("ui.collider", c = c));
         }
     });
 }
 
+fn script_error_headline(err: &ScriptErrorInfo) -> String {
+    let first_line = err.message.lines().next().unwrap_or_default();
+    if err.repeat_count > 1 {
+        format!("{first_line} (x{})", err.repeat_count)
+    } else {
+        first_line.to_string()
+    }
+}
+
+/// Draws the error message with its source snippet and a caret under the failing column.
+/// Returns true when "Open in editor" was clicked.
+fn render_script_error(ui: &mut egui::Ui, err: &ScriptErrorInfo) -> bool {
+    let mut open_clicked = false;
+    ui.colored_label(egui::Color32::RED, tr!("ui.script_error", message = script_error_headline(err)));
+    if let Some(callback) = err.callback.as_deref() {
+        ui.small(tr!("ui.while_running", callback = callback));
+    }
+    if let Some(location) = err.location.as_ref() {
+        ui.horizontal(|ui| {
+            match location.column {
+                Some(column) => ui.small(format!("{}:{}:{column}", location.script_path, location.line)),
+                None => ui.small(format!("{}:{}", location.script_path, location.line)),
+            };
+            if ui.small_button(tr!("ui.open_in_editor")).clicked() {
+                open_clicked = true;
+            }
+        });
+        if !err.snippet.is_empty() {
+            egui::Frame::group(ui.style()).show(ui, |ui| {
+                for (number, text) in &err.snippet {
+                    let row = format!("{number:>5} | {text}");
+                    if *number == location.line {
+                        ui.label(
+                            egui::RichText::new(row)
+                                .monospace()
+                                .color(egui::Color32::WHITE)
+                                .background_color(egui::Color32::from_rgb(110, 30, 30)),
+                        );
+                        if let Some(column) = location.column {
+                            let caret = format!("{:>5} | {}^", "", " ".repeat(column.saturating_sub(1)));
+                            ui.label(egui::RichText::new(caret).monospace().color(egui::Color32::RED));
+                        }
+                    } else {
+                        ui.label(egui::RichText::new(row).monospace().weak());
+                    }
+                }
+            });
+        }
+    }
+    if err.message.contains('\n') {
+        panel_header("ui.details").show(ui, |ui| {
+            ui.label(egui::RichText::new(err.message.as_str()).monospace().small());
+        });
+    }
+    open_clicked
+}
+
 fn parse_audio_trigger(label: &str) -> ParsedAudioTrigger {
     if let Some(rest) = label.strip_prefix("spawn:") {
         let mut parts = rest.splitn(2, ':');
@@ -979,16 +1475,22 @@ pub(super) struct UiActions {
     pub spawn_demo: bool,
     pub spawn_now: bool,
     pub delete_entity: Option<Entity>,
+    pub duplicate_selection: bool,
+    pub bulk_transform: Option<BulkTransform>,
     pub clear_particles: bool,
     pub reset_world: bool,
     pub save_scene: bool,
     pub load_scene: bool,
+    pub export_selection: Option<bool>,
+    pub import_scene: bool,
+    pub open_scene_chunk: Option<String>,
     pub play_enter: bool,
     pub play_pause: bool,
     pub play_resume: bool,
     pub play_stop: bool,
     pub play_step: bool,
     pub spawn_mesh: Option<String>,
+    pub import_mesh_scene: Option<MeshSceneImportRequest>,
     pub retain_atlases: Vec<(String, Option<String>)>,
     pub retain_clips: Vec<(String, Option<String>)>,
     pub retain_meshes: Vec<(String, Option<String>)>,
@@ -996,7 +1498,11 @@ pub(super) struct UiActions {
     pub sprite_atlas_requests: Vec<SpriteAtlasRequest>,
     pub plugin_toggles: Vec<PluginToggleRequest>,
     pub reload_plugins: bool,
+    pub unload_plugins: bool,
     pub plugin_watchdog_clear: Vec<String>,
+    pub plugin_reenable: Vec<String>,
+    pub plugin_mute: Vec<(String, bool)>,
+    pub plugin_solo: Vec<(String, bool)>,
     pub plugin_retry_asset_readback: Vec<String>,
     pub audio_set_enabled: Option<bool>,
     pub audio_clear_log: bool,
@@ -1004,12 +1510,31 @@ pub(super) struct UiActions {
     pub audio_spatial_min_distance: Option<f32>,
     pub audio_spatial_max_distance: Option<f32>,
     pub audio_spatial_pan_width: Option<f32>,
+    pub audio_lpf_cutoff: Option<Option<f32>>,
+    pub audio_hpf_cutoff: Option<Option<f32>>,
+    pub audio_voice_count: Option<usize>,
     pub gpu_timing_enable: Option<bool>,
+    pub alloc_sampling_enable: Option<bool>,
+    pub language_select: Option<String>,
+    pub theme_select: Option<EditorTheme>,
+    pub sprite_sort_select: Option<SpriteSortMode>,
+    pub reset_camera: bool,
+    pub timeline_usage_reset: bool,
+    pub export_analytics_session: bool,
+    pub dump_untranslated_strings: bool,
     pub frame_budget_action: Option<FrameBudgetAction>,
+    pub compare_gpu_baseline: bool,
     pub save_prefab: Option<PrefabSaveRequest>,
     pub instantiate_prefab: Option<PrefabInstantiateRequest>,
     pub point_light_update: Option<Vec<ScenePointLight>>,
     pub inspector_actions: Vec<InspectorAction>,
+    pub component_clipboard: Option<ComponentClipboardRequest>,
+}
+
+pub(super) struct MeshSceneImportRequest {
+    pub path: String,
+    pub settings: MeshImportSettings,
+    pub spawn_hierarchy: bool,
 }
 
 pub(super) struct SpriteAtlasRequest {
@@ -1018,6 +1543,37 @@ pub(super) struct SpriteAtlasRequest {
     pub path: Option<String>,
 }
 
+/// Inspector sections that support per-component copy/paste.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum ComponentKind {
+    Transform,
+    Velocity,
+    Tint,
+    Sprite,
+    ParticleEmitter,
+    ForceField,
+    Attractor,
+}
+
+impl ComponentKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            ComponentKind::Transform => "Transform",
+            ComponentKind::Velocity => "Velocity",
+            ComponentKind::Tint => "Tint",
+            ComponentKind::Sprite => "Sprite",
+            ComponentKind::ParticleEmitter => "Particle Emitter",
+            ComponentKind::ForceField => "Force Field",
+            ComponentKind::Attractor => "Attractor",
+        }
+    }
+}
+
+pub(super) enum ComponentClipboardRequest {
+    Copy { entity: Entity, kind: ComponentKind },
+    Paste { entity: Entity, kind: ComponentKind },
+}
+
 pub(super) struct SelectionResult {
     pub entity: Option<Entity>,
     pub details: Option<EntityInfo>,
@@ -1029,7 +1585,7 @@ pub(super) struct ScriptDebuggerParams {
     pub script_path: Option<String>,
     pub enabled: bool,
     pub paused: bool,
-    pub last_error: Option<String>,
+    pub last_error: Option<ScriptErrorInfo>,
     pub handles: Vec<ScriptHandleBinding>,
     pub timings: Arc<[ScriptTimingSummary]>,
     pub offenders: Arc<[ScriptOffenderStatus]>,
@@ -1060,6 +1616,7 @@ pub(super) struct ScriptDebuggerOutput {
     pub reload: bool,
     pub set_timing_threshold_ms: Option<Option<f32>>,
     pub toggle_pin: Option<String>,
+    pub open_in_editor: Option<ScriptErrorLocation>,
 }
 
 pub(super) struct EditorUiParams {
@@ -1074,6 +1631,7 @@ pub(super) struct EditorUiParams {
     pub frame_budget_status: Option<String>,
     pub shadow_pass_metric: Option<GpuPassMetric>,
     pub mesh_pass_metric: Option<GpuPassMetric>,
+    pub gpu_baseline_report: Option<Arc<[String]>>,
     pub plugin_capability_metrics: Arc<HashMap<String, CapabilityViolationLog>>,
     pub plugin_capability_events: Arc<[PluginCapabilityEvent]>,
     pub plugin_asset_readback_log: Arc<[PluginAssetReadbackEvent]>,
@@ -1102,13 +1660,28 @@ pub(super) struct EditorUiParams {
     pub recent_projects: Arc<[String]>,
     pub light_cluster_metrics_overlay: Option<LightClusterMetrics>,
     pub light_cluster_metrics: LightClusterMetrics,
+    /// Exposure the renderer used last frame; differs from `ui_light_exposure` under auto exposure.
+    pub effective_exposure: f32,
+    pub gpu_memory: GpuMemoryReport,
     pub point_lights: Vec<ScenePointLight>,
     pub keyframe_editor_usage: Option<KeyframeEditorUsageSnapshot>,
     pub keyframe_event_log: Arc<[KeyframeEditorEvent]>,
+    pub timeline_usage: Arc<[SpriteTimelineUsageEntry]>,
+    pub custom_counters: Arc<[CustomCounterHistory]>,
     pub system_timings: Vec<SystemTimingSummary>,
     pub entity_count: usize,
+    pub max_entities: Option<usize>,
     pub instances_drawn: usize,
+    /// Triangles across every mesh instance in the scene, including ones culled this frame.
+    pub total_scene_triangles: u64,
+    pub render_stats: Option<RenderStats>,
     pub vsync_enabled: bool,
+    pub texture_anisotropy: u16,
+    pub texture_anisotropy_limit: u16,
+    pub render_scale: f32,
+    pub sprite_color_space: SpriteColorSpace,
+    pub sprite_color_summary: String,
+    pub sprite_sort_mode: SpriteSortMode,
     pub particle_budget: Option<ParticleBudgetMetrics>,
     pub spatial_metrics: Option<SpatialMetrics>,
     pub sprite_perf_sample: Option<SpriteAnimPerfSample>,
@@ -1116,12 +1689,14 @@ pub(super) struct EditorUiParams {
     pub sprite_pack_ms: Option<f32>,
     pub sprite_upload_ms: Option<f32>,
     pub ui_scale: f32,
+    pub ui_theme: EditorTheme,
     pub ui_cell_size: f32,
     pub ui_spatial_use_quadtree: bool,
     pub ui_spatial_density_threshold: f32,
     pub ui_spawn_per_press: i32,
     pub ui_auto_spawn_rate: f32,
     pub ui_environment_intensity: f32,
+    pub ui_environment_scroll_speed: f32,
     pub ui_root_spin: f32,
     pub ui_emitter_rate: f32,
     pub ui_emitter_spread: f32,
@@ -1145,17 +1720,23 @@ pub(super) struct EditorUiParams {
     pub ui_shadow_resolution: u32,
     pub ui_shadow_split_lambda: f32,
     pub ui_shadow_pcf_radius: f32,
+    pub ui_auto_exposure: AutoExposureSettings,
     pub ui_camera_zoom_min: f32,
     pub ui_camera_zoom_max: f32,
     pub ui_sprite_guard_pixels: f32,
     pub ui_sprite_guard_mode: SpriteGuardrailMode,
+    pub ui_static_sprite_batching: bool,
+    pub ui_low_latency_input: bool,
     pub selected_entity: Option<Entity>,
+    pub selection_count: usize,
     pub selected_script_error: bool,
     pub selection_details: Option<EntityInfo>,
     pub prev_selection_details: Option<EntityInfo>,
     pub prev_selected_entity: Option<Entity>,
     pub prev_gizmo_interaction: Option<GizmoInteraction>,
     pub selection_bounds_2d: Option<(Vec2, Vec2)>,
+    /// Centroid the 2D gizmo sits on while several entities are selected.
+    pub selection_centroid_2d: Option<Vec2>,
     pub prev_selection_bounds_2d: Option<(Vec2, Vec2)>,
     pub gizmo_interaction: Option<GizmoInteraction>,
     pub selection_changed: bool,
@@ -1181,17 +1762,34 @@ pub(super) struct EditorUiParams {
     pub mesh_frustum_lock: bool,
     pub mesh_orbit_radius: f32,
     pub mesh_freefly_speed: f32,
+    pub mesh_clip_planes: (f32, f32),
     pub mesh_status_message: Option<String>,
     pub camera_bookmark_input: String,
-    pub mesh_keys: Arc<[String]>,
+    pub mesh_groups: Arc<[MeshKeyGroup]>,
     pub environment_options: Arc<[(String, String)]>,
     pub active_environment: String,
     pub persistent_materials: HashSet<String>,
     pub debug_show_spatial_hash: bool,
     pub debug_show_colliders: bool,
+    pub debug_show_nav_grid: bool,
+    pub debug_show_culled_bounds: bool,
+    pub debug_show_skeletons: bool,
+    pub debug_skeleton_labels: bool,
+    pub collider_edit_mode: bool,
+    pub collider_undo_available: bool,
+    pub collider_redo_available: bool,
     pub spatial_hash_rects: Vec<(Vec2, Vec2)>,
     pub collider_rects: Vec<(Vec2, Vec2)>,
+    /// World origin and collider of the selected entity while collider grips are shown.
+    pub collider_edit_overlay: Option<(Vec2, ColliderGeometry)>,
+    pub nav_blocked_rects: Vec<(Vec2, Vec2)>,
+    pub nav_paths: Vec<Vec<Vec2>>,
+    /// Spawn-shape outline of the selected particle emitter, in world space.
+    pub emitter_shape_outline: Vec<Vec<Vec2>>,
+    /// Joint poses of every skeleton while the skeleton overlay is on; empty otherwise.
+    pub skeleton_overlays: Vec<(Entity, Vec<SkeletonJointPose>)>,
     pub scene_history_list: Arc<[String]>,
+    pub scene_chunks: Vec<SceneChunkStatus>,
     pub atlas_dependencies: Arc<[AtlasDependencyStatus]>,
     pub mesh_dependencies: Arc<[MeshDependencyStatus]>,
     pub clip_dependencies: Arc<[ClipDependencyStatus]>,
@@ -1207,11 +1805,13 @@ pub(super) struct EditorUiParams {
     pub audio_spatial_config: AudioSpatialConfig,
     pub binary_prefabs_enabled: bool,
     pub prefab_entries: Arc<[PrefabShelfEntry]>,
+    pub thumbnails: Arc<HashMap<PathBuf, egui::TextureHandle>>,
     pub prefab_name_input: String,
     pub prefab_format: PrefabFormat,
     pub prefab_status: Option<PrefabStatusMessage>,
     pub clip_keys: Arc<[String]>,
     pub clip_assets: Arc<HashMap<String, ClipAssetSummary>>,
+    pub color_curve_keys: Arc<[String]>,
     pub skeleton_keys: Arc<[String]>,
     pub skeleton_assets: Arc<HashMap<String, SkeletonAssetSummary>>,
     pub atlas_keys: Arc<[String]>,
@@ -1223,20 +1823,29 @@ pub(super) struct EditorUiParams {
     pub input_modifiers: InputModifierState,
     pub ui_scene_path: String,
     pub ui_scene_status: Option<String>,
+    pub ui_fragment_include_children: bool,
     pub animation_group_input: String,
     pub animation_group_scale_input: f32,
     pub inspector_status: Option<String>,
     pub sprite_guardrail_status: Option<String>,
     pub gpu_metrics_status: Option<String>,
     pub keyframe_panel_open: bool,
+    pub layout_presets: Arc<[String]>,
+    pub active_layout: Option<String>,
+    pub layout_name_input: String,
+    pub layout_apply: Option<LayoutPreset>,
     pub script_debugger: ScriptDebuggerParams,
     pub id_lookup_input: String,
     pub id_lookup_active: bool,
+    /// Scene entities offered by the Entity Lookup search; only gathered while the window is open.
+    pub entity_search_candidates: Vec<EntitySearchCandidate>,
     pub gpu_timing_snapshot: Arc<[GpuPassTiming]>,
     pub gpu_history_empty: bool,
     pub gpu_timing_averages: BTreeMap<&'static str, (f32, usize)>,
     pub gpu_timing_supported: bool,
     pub gpu_timing_enabled: bool,
+    pub languages: Arc<[(String, String)]>,
+    pub active_language: String,
     pub gizmo_mode: GizmoMode,
 }
 
@@ -1251,6 +1860,7 @@ pub(super) struct EditorUiOutput {
     pub ui_spawn_per_press: i32,
     pub ui_auto_spawn_rate: f32,
     pub ui_environment_intensity: f32,
+    pub ui_environment_scroll_speed: f32,
     pub ui_root_spin: f32,
     pub ui_emitter_rate: f32,
     pub ui_emitter_spread: f32,
@@ -1274,10 +1884,13 @@ pub(super) struct EditorUiOutput {
     pub ui_shadow_resolution: u32,
     pub ui_shadow_split_lambda: f32,
     pub ui_shadow_pcf_radius: f32,
+    pub ui_auto_exposure: AutoExposureSettings,
     pub ui_camera_zoom_min: f32,
     pub ui_camera_zoom_max: f32,
     pub ui_sprite_guard_pixels: f32,
     pub ui_sprite_guard_mode: SpriteGuardrailMode,
+    pub ui_static_sprite_batching: bool,
+    pub ui_low_latency_input: bool,
     pub gizmo_mode: GizmoMode,
     pub selection: SelectionResult,
     pub gizmo_interaction: Option<GizmoInteraction>,
@@ -1287,6 +1900,7 @@ pub(super) struct EditorUiOutput {
     pub camera_bookmark_delete: Option<String>,
     pub mesh_control_request: Option<MeshControlMode>,
     pub mesh_frustum_request: Option<bool>,
+    pub mesh_clip_request: Option<(f32, f32)>,
     pub mesh_frustum_snap: bool,
     pub mesh_reset_request: bool,
     pub mesh_selection_request: Option<String>,
@@ -1305,18 +1919,28 @@ pub(super) struct EditorUiOutput {
     pub camera_follow_clear: bool,
     pub debug_show_spatial_hash: bool,
     pub debug_show_colliders: bool,
+    pub debug_show_nav_grid: bool,
+    pub debug_show_culled_bounds: bool,
+    pub debug_show_skeletons: bool,
+    pub debug_skeleton_labels: bool,
+    pub collider_edit_mode: bool,
     pub vsync_request: Option<bool>,
+    pub anisotropy_request: Option<u16>,
+    pub render_scale_request: Option<f32>,
     pub script_debugger: ScriptDebuggerOutput,
     pub prefab_name_input: String,
     pub prefab_format: PrefabFormat,
     pub prefab_status: Option<PrefabStatusMessage>,
     pub ui_scene_path: String,
     pub ui_scene_status: Option<String>,
+    pub ui_fragment_include_children: bool,
     pub animation_group_input: String,
     pub animation_group_scale_input: f32,
     pub inspector_status: Option<String>,
     pub clear_scene_history: bool,
     pub keyframe_panel_open: bool,
+    pub layout_name_input: String,
+    pub layout_action: Option<LayoutAction>,
     pub gpu_metrics_status: Option<String>,
     pub project_action: Option<ProjectAction>,
     pub start_screen_open: bool,
@@ -1341,6 +1965,7 @@ impl App {
             frame_budget_status,
             shadow_pass_metric,
             mesh_pass_metric,
+            gpu_baseline_report,
             plugin_capability_metrics,
             plugin_capability_events,
             plugin_asset_readback_log,
@@ -1369,20 +1994,35 @@ impl App {
             recent_projects,
             light_cluster_metrics_overlay,
             light_cluster_metrics,
+            effective_exposure,
+            gpu_memory,
             mut point_lights,
             keyframe_editor_usage,
             keyframe_event_log,
+            timeline_usage,
+            custom_counters,
             system_timings,
             entity_count,
+            max_entities,
             instances_drawn,
+            total_scene_triangles,
+            render_stats,
             mut vsync_enabled,
+            texture_anisotropy,
+            texture_anisotropy_limit,
+            render_scale,
+            sprite_color_space,
+            sprite_color_summary,
+            sprite_sort_mode,
             mut ui_scale,
+            ui_theme,
             mut ui_cell_size,
             mut ui_spatial_use_quadtree,
             mut ui_spatial_density_threshold,
             mut ui_spawn_per_press,
             mut ui_auto_spawn_rate,
             mut ui_environment_intensity,
+            mut ui_environment_scroll_speed,
             mut ui_root_spin,
             mut ui_emitter_rate,
             mut ui_emitter_spread,
@@ -1406,17 +2046,22 @@ impl App {
             mut ui_shadow_resolution,
             mut ui_shadow_split_lambda,
             mut ui_shadow_pcf_radius,
+            mut ui_auto_exposure,
             mut ui_camera_zoom_min,
             mut ui_camera_zoom_max,
             mut ui_sprite_guard_pixels,
             mut ui_sprite_guard_mode,
+            mut ui_static_sprite_batching,
+            mut ui_low_latency_input,
             mut selected_entity,
+            selection_count,
             selected_script_error,
             mut selection_details,
             prev_selection_details,
             prev_selected_entity,
             prev_gizmo_interaction,
             mut selection_bounds_2d,
+            selection_centroid_2d,
             prev_selection_bounds_2d,
             mut gizmo_interaction,
             mut selection_changed,
@@ -1442,17 +2087,31 @@ impl App {
             mesh_frustum_lock: mesh_frustum_lock_state,
             mesh_orbit_radius,
             mesh_freefly_speed: mesh_freefly_speed_state,
+            mesh_clip_planes: mesh_clip_planes_state,
             mesh_status_message,
             mut camera_bookmark_input,
-            mesh_keys,
+            mesh_groups,
             environment_options,
             active_environment,
             persistent_materials: _persistent_materials,
             mut debug_show_spatial_hash,
             mut debug_show_colliders,
+            mut debug_show_nav_grid,
+            mut debug_show_culled_bounds,
+            mut debug_show_skeletons,
+            mut debug_skeleton_labels,
+            mut collider_edit_mode,
+            collider_undo_available,
+            collider_redo_available,
             spatial_hash_rects,
             collider_rects,
+            collider_edit_overlay,
+            nav_blocked_rects,
+            nav_paths,
+            emitter_shape_outline,
+            skeleton_overlays,
             scene_history_list,
+            scene_chunks,
             atlas_dependencies,
             mesh_dependencies,
             clip_dependencies,
@@ -1473,13 +2132,16 @@ impl App {
             sprite_upload_ms,
             mut id_lookup_input,
             mut id_lookup_active,
+            entity_search_candidates,
             binary_prefabs_enabled,
             prefab_entries,
+            thumbnails,
             mut prefab_name_input,
             mut prefab_format,
             prefab_status,
             clip_keys,
             clip_assets,
+            color_curve_keys,
             skeleton_keys,
             skeleton_assets,
             atlas_keys,
@@ -1491,18 +2153,25 @@ impl App {
             input_modifiers,
             mut ui_scene_path,
             ui_scene_status,
+            mut ui_fragment_include_children,
             mut animation_group_input,
             mut animation_group_scale_input,
             mut inspector_status,
             sprite_guardrail_status,
             mut gpu_metrics_status,
             mut keyframe_panel_open,
+            layout_presets,
+            active_layout,
+            mut layout_name_input,
+            layout_apply,
             mut script_debugger,
             gpu_timing_snapshot,
             gpu_history_empty,
             gpu_timing_averages,
             gpu_timing_supported,
             gpu_timing_enabled,
+            languages,
+            active_language,
             gizmo_mode: mut gizmo_mode_state,
             audio_spatial_config,
         } = params;
@@ -1511,20 +2180,20 @@ impl App {
 
         fn show_script_handle_table(ui: &mut egui::Ui, handles: &[ScriptHandleBinding], id_suffix: &str) {
             if handles.is_empty() {
-                ui.small("No active handles.");
+                ui.small(tr!("ui.no_active_handles"));
                 return;
             }
             egui::ScrollArea::vertical().max_height(150.0).show(ui, |ui| {
                 egui::Grid::new(format!("script_handle_grid_{id_suffix}")).striped(true).show(ui, |ui| {
-                    ui.label("Handle");
-                    ui.label("Entity");
+                    ui.label(tr!("ui.handle"));
+                    ui.label(tr!("ui.entity"));
                     ui.end_row();
                     for entry in handles {
                         ui.monospace(entry.handle.to_string());
                         if let Some(scene_id) = entry.scene_id.as_ref() {
                             ui.label(scene_id.as_str());
                         } else {
-                            ui.weak("None");
+                            ui.weak(tr!("ui.none"));
                         }
                         ui.end_row();
                     }
@@ -1535,6 +2204,12 @@ impl App {
         let mut camera_bookmark_select: Option<Option<String>> = None;
         let mut camera_bookmark_save: Option<String> = None;
         let mut camera_bookmark_delete: Option<String> = None;
+        let mut layout_action: Option<LayoutAction> = None;
+        let mut layout_save_name: Option<String> = None;
+        let mut layout_sidebar_width = DEFAULT_SIDEBAR_WIDTH;
+        let mut animation_time_open = false;
+        let mut mesh_preview_open = true;
+        let mut prefab_shelf_open = true;
         let mut camera_follow_selection = false;
         let mut camera_follow_clear = false;
         let mut clear_scene_history = false;
@@ -1543,6 +2218,7 @@ impl App {
         let mut mesh_control_request: Option<MeshControlMode> = None;
         let mut gpu_export_requested = false;
         let mut mesh_frustum_request: Option<bool> = None;
+        let mut mesh_clip_request: Option<(f32, f32)> = None;
         let mut mesh_frustum_snap = false;
         let mut mesh_reset_request = false;
         let mut mesh_selection_request: Option<String> = None;
@@ -1573,6 +2249,8 @@ impl App {
         }
 
         let mut vsync_toggle_request: Option<bool> = None;
+        let mut anisotropy_request: Option<u16> = None;
+        let mut render_scale_request: Option<f32> = None;
 
         let mut script_debugger_output = ScriptDebuggerOutput {
             open: script_debugger.open,
@@ -1588,6 +2266,7 @@ impl App {
             reload: false,
             set_timing_threshold_ms: None,
             toggle_pin: None,
+            open_in_editor: None,
         };
 
         let plugin_manifest_loaded = plugin_manifest_entries.is_some();
@@ -1703,80 +2382,193 @@ impl App {
                 }
             }
             let left_panel = if show_editor_ui {
-                Some(egui::SidePanel::left("kestrel_left_panel").default_width(340.0).show(ctx, |ui| {
-                    egui::CollapsingHeader::new("Stats").default_open(true).show(ui, |ui| {
-                        ui.label(format!("Entities: {}", entity_count));
-                        ui.label(format!("Instances drawn: {}", instances_drawn));
+                let mut sidebar = egui::SidePanel::left("kestrel_left_panel").default_width(DEFAULT_SIDEBAR_WIDTH);
+                if let Some(preset) = layout_apply.as_ref() {
+                    sidebar = sidebar.exact_width(preset.sidebar_width);
+                }
+                Some(sidebar.show(ctx, |ui| {
+                    panel_header("panel.stats").default_open(true).show(ui, |ui| {
+                        match max_entities {
+                            Some(max) if entity_count >= max => {
+                                ui.colored_label(
+                                    egui::Color32::from_rgb(230, 200, 120),
+                                    format!("Entities: {entity_count} / {max} (cap reached)"),
+                                );
+                            }
+                            Some(max) => {
+                                ui.label(tr!("ui.entities_of_max", entity_count = entity_count, max = max));
+                            }
+                            None => {
+                                ui.label(tr!("ui.entities", entity_count = entity_count));
+                            }
+                        }
+                        ui.label(tr!("ui.instances_drawn", instances_drawn = instances_drawn));
+                        ui.label(tr!("ui.scene_triangles", total_scene_triangles = total_scene_triangles));
                         ui.horizontal(|ui| {
-                            ui.label(format!(
-                                "Project: {} ({})",
-                                project_name.as_deref().unwrap_or("<unnamed>"),
-                                project_root
+                            ui.label(tr!(
+                                "ui.project",
+                                project_name = project_name.as_deref().unwrap_or("<unnamed>"),
+                                project_root = project_root
                             ));
-                            if ui.button("Start screen").clicked() {
+                            if ui.button(tr!("ui.start_screen")).clicked() {
                                 start_screen_open_state = true;
                             }
+                            if ui.button(tr!("ui.open_project_menu")).clicked() {
+                                project_action = Some(ProjectAction::Browse);
+                            }
+                            ui.menu_button(tr!("ui.layouts"), |menu| {
+                                if layout_presets.is_empty() {
+                                    menu.label(tr!("ui.no_saved_layouts"));
+                                }
+                                for name in layout_presets.iter() {
+                                    let active = active_layout.as_deref() == Some(name.as_str());
+                                    if menu.selectable_label(active, name).clicked() {
+                                        layout_action = Some(LayoutAction::Apply(name.clone()));
+                                        menu.close();
+                                    }
+                                }
+                                menu.separator();
+                                menu.add(
+                                    egui::TextEdit::singleline(&mut layout_name_input)
+                                        .hint_text(tr!("ui.layout_name")),
+                                );
+                                let trimmed = layout_name_input.trim().to_string();
+                                if menu
+                                    .add_enabled(
+                                        !trimmed.is_empty(),
+                                        egui::Button::new(tr!("ui.save_current_layout")),
+                                    )
+                                    .clicked()
+                                {
+                                    layout_save_name = Some(trimmed);
+                                    menu.close();
+                                }
+                                menu.add_enabled_ui(!layout_presets.is_empty(), |menu| {
+                                    menu.menu_button(tr!("ui.delete_layout"), |menu| {
+                                        for name in layout_presets.iter() {
+                                            if menu.button(name).clicked() {
+                                                layout_action = Some(LayoutAction::Delete(name.clone()));
+                                                menu.close();
+                                            }
+                                        }
+                                    });
+                                });
+                            });
+                            ui.menu_button(tr!("ui.view"), |menu| {
+                                if menu
+                                    .add(egui::Button::new(tr!("ui.reset_camera")).shortcut_text("Home"))
+                                    .clicked()
+                                {
+                                    actions.reset_camera = true;
+                                    menu.close();
+                                }
+                            });
                         });
                         if let Some(path) = project_manifest.as_ref() {
-                            ui.label(format!("Manifest: {path}"));
+                            ui.label(tr!("ui.manifest_at", path = path));
                         }
                         let mut checkbox_state = vsync_enabled;
-                        if ui.checkbox(&mut checkbox_state, "Enable VSync").changed() {
+                        if ui.checkbox(&mut checkbox_state, tr!("ui.enable_vsync")).changed() {
                             vsync_enabled = checkbox_state;
                             vsync_toggle_request = Some(checkbox_state);
                         }
+                        ui.horizontal(|ui| {
+                            ui.label(tr!("ui.texture_anisotropy"));
+                            egui::ComboBox::from_id_salt("texture_anisotropy")
+                                .selected_text(format!("{texture_anisotropy}x"))
+                                .show_ui(ui, |ui| {
+                                    for level in [1u16, 2, 4, 8, 16] {
+                                        let enabled = level <= texture_anisotropy_limit;
+                                        let response = ui.add_enabled(
+                                            enabled,
+                                            egui::Button::selectable(
+                                                level == texture_anisotropy,
+                                                format!("{level}x"),
+                                            ),
+                                        );
+                                        if response.clicked() && level != texture_anisotropy {
+                                            anisotropy_request = Some(level);
+                                        }
+                                    }
+                                });
+                        });
+                        ui.horizontal(|ui| {
+                            ui.label(tr!("ui.render_scale"));
+                            let mut scale = render_scale;
+                            let response = ui
+                                .add(
+                                    egui::Slider::new(&mut scale, MIN_RENDER_SCALE..=MAX_RENDER_SCALE)
+                                        .step_by(0.05)
+                                        .suffix("x"),
+                                )
+                                .on_hover_text(
+                                    "Renders the scene at this fraction of the window resolution and \
+                                     stretches it to fit.",
+                                );
+                            if response.changed() && scale != render_scale {
+                                render_scale_request = Some(scale);
+                            }
+                        });
+                        ui.label(tr!(
+                            "ui.sprite_color_space",
+                            sprite_color_space = sprite_color_space.label()
+                        ))
+                            .on_hover_text(tr!(
+                                "ui.set_sprites_color_space_hint",
+                                sprite_color_summary = sprite_color_summary
+                            ));
                         ui.separator();
-                        ui.label("Play Controls");
+                        ui.label(tr!("ui.play_controls"));
                         let (state_label, paused_label) = match play_state {
                             PlayState::Editing => ("Editing", None),
                             PlayState::Playing { paused: false } => ("Playing", Some("running")),
                             PlayState::Playing { paused: true } => ("Playing", Some("paused")),
                         };
                         ui.horizontal(|ui| {
-                            ui.label(format!(
-                                "State: {}{}",
-                                state_label,
-                                paused_label.map(|p| format!(" ({p})")).unwrap_or_default()
+                            ui.label(tr!(
+                                "ui.state",
+                                state_label = state_label,
+                                paused_label = paused_label.map(|p| format!(" ({p})")).unwrap_or_default()
                             ));
                             match play_state {
                                 PlayState::Editing => {
-                                    if ui.button("Play").clicked() {
+                                    if ui.button(tr!("ui.play")).clicked() {
                                         play_enter = true;
                                     }
-                                    if ui.button("Step").clicked() {
+                                    if ui.button(tr!("ui.step")).clicked() {
                                         play_enter = true;
                                         play_pause = true;
                                         play_step = true;
                                     }
                                 }
                                 PlayState::Playing { paused: false } => {
-                                    if ui.button("Pause").clicked() {
+                                    if ui.button(tr!("ui.pause")).clicked() {
                                         play_pause = true;
                                     }
-                                    if ui.button("Stop").clicked() {
+                                    if ui.button(tr!("ui.stop")).clicked() {
                                         play_stop = true;
                                     }
-                                    if ui.button("Step").clicked() {
+                                    if ui.button(tr!("ui.step")).clicked() {
                                         play_pause = true;
                                         play_step = true;
                                     }
                                 }
                                 PlayState::Playing { paused: true } => {
-                                    if ui.button("Resume").clicked() {
+                                    if ui.button(tr!("ui.resume")).clicked() {
                                         play_resume = true;
                                     }
-                                    if ui.button("Stop").clicked() {
+                                    if ui.button(tr!("ui.stop")).clicked() {
                                         play_stop = true;
                                     }
-                                    if ui.button("Step").clicked() {
+                                    if ui.button(tr!("ui.step")).clicked() {
                                         play_step = true;
                                     }
                                 }
                             }
                         });
-                        ui.small("Shortcuts: F5 play/pause/resume, Shift+F5 stop, F6 step");
+                        ui.small(tr!("ui.shortcuts_f5_play_pause_resume_shift"));
                         ui.separator();
-                        ui.label("Frame time (ms)");
+                        ui.label(tr!("ui.frame_time_ms"));
                         let hist = eplot::Plot::new("fps_plot").height(120.0).include_y(0.0).include_y(40.0);
                         hist.show(ui, |plot_ui| {
                             plot_ui.line(eplot::Line::new(
@@ -1784,33 +2576,53 @@ impl App {
                                 eplot::PlotPoints::from(hist_points.as_ref()),
                             ));
                         });
-                        ui.label("Target: 16.7ms for 60 FPS");
+                        ui.label(tr!("ui.target_16_7ms_for_60_fps"));
                         #[cfg(feature = "alloc_profiler")]
                         if let Some(delta) = allocation_delta {
                             let allocated_kb = delta.allocated_bytes as f64 / 1024.0;
                             let deallocated_kb = delta.deallocated_bytes as f64 / 1024.0;
                             let net_kb = delta.net_bytes() as f64 / 1024.0;
-                            ui.label(format!(
-                                "Alloc Δ: +{:.2} KB / -{:.2} KB (net {:+.2} KB)",
-                                allocated_kb, deallocated_kb, net_kb
+                            ui.label(tr!(
+                                "ui.alloc_kb_kb_net_kb",
+                                allocated_kb = format!("{:.2}", allocated_kb),
+                                deallocated_kb = format!("{:.2}", deallocated_kb),
+                                net_kb = format!("{:+.2}", net_kb)
+                            ));
+                        }
+                        let mut alloc_sampling = alloc_sampler::is_enabled();
+                        if ui
+                            .checkbox(&mut alloc_sampling, tr!("ui.sample_hot_path_allocations"))
+                            .on_hover_text(tr!("ui.sample_hot_path_allocations_hint"))
+                            .changed()
+                        {
+                            actions.alloc_sampling_enable = Some(alloc_sampling);
+                        }
+                        if let Some(pressure) = animation_budget_sample.and_then(|sample| sample.allocation_pressure) {
+                            ui.small(tr!(
+                                "ui.hot_path_allocs_kb_sprites",
+                                total_allocations = pressure.total_allocations(),
+                                total_bytes = format!("{:.2}", pressure.total_bytes() as f64 / 1024.0),
+                                sprites = pressure.sprite_instances.allocations,
+                                batches = pressure.sprite_batches.allocations,
+                                meshes = pressure.mesh_instances.allocations
                             ));
                         }
-                        egui::CollapsingHeader::new("Frame Budget Capture").default_open(false).show(ui, |ui| {
+                        panel_header("panel.frame_budget").default_open(false).show(ui, |ui| {
                             ui.horizontal(|ui| {
-                                if ui.button("Capture Idle Baseline").clicked() {
+                                if ui.button(tr!("ui.capture_idle_baseline")).clicked() {
                                     actions.frame_budget_action = Some(FrameBudgetAction::CaptureIdle);
                                 }
-                                if ui.button("Capture Panel Snapshot").clicked() {
+                                if ui.button(tr!("ui.capture_panel_snapshot")).clicked() {
                                     actions.frame_budget_action = Some(FrameBudgetAction::CapturePanel);
                                 }
-                                if ui.button("Clear").clicked() {
+                                if ui.button(tr!("ui.clear")).clicked() {
                                     actions.frame_budget_action = Some(FrameBudgetAction::Clear);
                                 }
                             });
                             if let Some(status) = frame_budget_status.as_deref() {
                                 ui.small(status);
                             } else {
-                                ui.small("Capture an idle baseline with panels hidden, then capture a panel snapshot after opening UI panels.");
+                                ui.small(tr!("ui.capture_an_idle_baseline_with_panels"));
                             }
                             if let Some(snapshot) = frame_budget_idle.as_ref() {
                                 frame_budget_snapshot_row(ui, "Idle", snapshot);
@@ -1825,42 +2637,57 @@ impl App {
                         });
                         ui.separator();
                         if shadow_pass_metric.is_some() || mesh_pass_metric.is_some() {
-                            egui::CollapsingHeader::new("GPU Pass Baselines").default_open(false).show(
+                            panel_header("panel.gpu_baselines").default_open(false).show(
                                 ui,
                                 |ui| {
                                     for metric in [shadow_pass_metric, mesh_pass_metric].into_iter().flatten()
                                     {
-                                        ui.label(format!(
-                                            "{:<12} {:>5.2} ms (avg {:>5.2} ms over {} frames)",
-                                            metric.label,
-                                            metric.latest_ms,
-                                            metric.average_ms,
-                                            metric.sample_count
+                                        ui.label(tr!(
+                                            "ui.frame_budget_metric",
+                                            metric = format!("{:<12}", metric.label),
+                                            latest_ms = format!("{:>5.2}", metric.latest_ms),
+                                            average_ms = format!("{:>5.2}", metric.average_ms),
+                                            sample_count = metric.sample_count
                                         ));
                                     }
+                                    if ui
+                                        .button(tr!("ui.compare_with_stored_baseline"))
+                                        .on_hover_text(crate::gpu_baseline::DEFAULT_BASELINE_PATH)
+                                        .clicked()
+                                    {
+                                        actions.compare_gpu_baseline = true;
+                                    }
+                                    for line in gpu_baseline_report.iter().flat_map(|report| report.iter()) {
+                                        if line.ends_with("REGRESSION") {
+                                            ui.colored_label(egui::Color32::from_rgb(220, 80, 80), line);
+                                        } else {
+                                            ui.small(line);
+                                        }
+                                    }
                                 },
                             );
                             ui.separator();
                         }
                         let metrics = light_cluster_metrics;
-                        egui::CollapsingHeader::new("Light Culling").default_open(false).show(ui, |ui| {
-                            ui.label(format!(
-                                "Lights: {} visible / {} total (culled {})",
-                                metrics.visible_lights,
-                                metrics.total_lights,
-                                metrics.culled_lights()
+                        panel_header("panel.light_culling").default_open(false).show(ui, |ui| {
+                            ui.label(tr!(
+                                "ui.lights_visible_total_culled",
+                                visible_lights = metrics.visible_lights,
+                                total_lights = metrics.total_lights,
+                                culled_lights = metrics.culled_lights()
                             ));
-                            ui.label(format!(
-                                "Grid: {}x{}x{} (active {} / {})",
-                                metrics.grid_dims[0],
-                                metrics.grid_dims[1],
-                                metrics.grid_dims[2],
-                                metrics.active_clusters,
-                                metrics.total_clusters
+                            ui.label(tr!(
+                                "ui.cluster_grid",
+                                x = metrics.grid_dims[0],
+                                y = metrics.grid_dims[1],
+                                z = metrics.grid_dims[2],
+                                active_clusters = metrics.active_clusters,
+                                total_clusters = metrics.total_clusters
                             ));
-                            ui.label(format!(
-                                "Avg lights/cluster: {:.2} (max {})",
-                                metrics.average_lights_per_cluster, metrics.max_lights_per_cluster
+                            ui.label(tr!(
+                                "ui.avg_lights_cluster_max",
+                                average = format!("{:.2}", metrics.average_lights_per_cluster),
+                                max = metrics.max_lights_per_cluster
                             ));
                             if metrics.overflow_clusters > 0 {
                                 ui.colored_label(
@@ -1868,7 +2695,7 @@ impl App {
                                     format!("Cluster overflow events: {}", metrics.overflow_clusters),
                                 );
                             } else {
-                                ui.label("Cluster overflow events: 0");
+                                ui.label(tr!("ui.cluster_overflow_events_0"));
                             }
                             if metrics.truncated_lights > 0 {
                                 ui.colored_label(
@@ -1879,49 +2706,99 @@ impl App {
                                     ),
                                 );
                             } else {
-                                ui.label("Lights over budget: 0");
+                                ui.label(tr!("ui.lights_over_budget_0"));
                             }
                         });
                         ui.separator();
+                        panel_header("panel.gpu_memory").default_open(false).show(ui, |ui| {
+                            ui.label(tr!("ui.total_est", value = format_data_size(gpu_memory.total_bytes())));
+                            ui.label(tr!(
+                                "ui.atlases",
+                                atlas_bytes = format_data_size(gpu_memory.atlas_bytes)
+                            ));
+                            ui.label(tr!("ui.meshes", mesh_bytes = format_data_size(gpu_memory.mesh_bytes)));
+                            ui.label(tr!(
+                                "ui.materials",
+                                material_bytes = format_data_size(gpu_memory.material_bytes)
+                            ));
+                            ui.label(tr!(
+                                "ui.environments",
+                                environment_bytes = format_data_size(gpu_memory.environment_bytes)
+                            ));
+                            ui.label(tr!(
+                                "ui.block_compressed_textures",
+                                bytes = format_data_size(gpu_memory.compressed_texture_bytes)
+                            ));
+                        });
+                        ui.separator();
                         if let Some(metrics) = particle_budget {
-                            egui::CollapsingHeader::new("Particle Budget").default_open(false).show(
+                            panel_header("panel.particle_budget").default_open(false).show(
                                 ui,
                                 |ui| {
                                     let utilization = metrics.cap_utilization() * 100.0;
-                                    ui.label(format!(
-                                        "Active: {} / {} ({utilization:.1}%)",
-                                        metrics.active_particles, metrics.max_total
+                                    ui.label(tr!(
+                                        "ui.active",
+                                        active_particles = metrics.active_particles,
+                                        max_total = metrics.max_total,
+                                        utilization = format!("{utilization:.1}")
                                     ));
-                                    ui.label(format!(
-                                        "Spawn budget: {} / {} available",
-                                        metrics.available_spawn_this_frame, metrics.max_spawn_per_frame
+                                    ui.label(tr!(
+                                        "ui.spawn_budget_available",
+                                        available_spawn_this_frame = metrics.available_spawn_this_frame,
+                                        max_spawn_per_frame = metrics.max_spawn_per_frame
                                     ));
                                     if metrics.total_emitters > 0 {
-                                        ui.label(format!(
-                                            "Emitters: {} (avg backlog {:.1} / {:.0}, max {:.1})",
-                                            metrics.total_emitters,
-                                            metrics.average_backlog(),
-                                            metrics.emitter_backlog_limit,
-                                            metrics.emitter_backlog_max_observed
+                                        ui.label(tr!(
+                                            "ui.emitters_avg_backlog_max",
+                                            total_emitters = metrics.total_emitters,
+                                            average_backlog = format!("{:.1}", metrics.average_backlog()),
+                                            backlog_limit = format!("{:.0}", metrics.emitter_backlog_limit),
+                                            max = format!("{:.1}", metrics.emitter_backlog_max_observed)
                                         ));
-                                        ui.label(format!(
-                                            "Trail emitters: {} | Force fields: {} | Attractors: {}",
-                                            metrics.trail_emitters, metrics.force_fields, metrics.attractors
+                                        if metrics.burst_backlog_total > 0 {
+                                            ui.label(tr!(
+                                                "ui.burst_backlog_particles_queued",
+                                                burst_backlog_total = metrics.burst_backlog_total
+                                            ));
+                                        }
+                                        ui.label(tr!(
+                                            "ui.trail_emitters_force_fields_attractors",
+                                            trail_emitters = metrics.trail_emitters,
+                                            force_fields = metrics.force_fields,
+                                            attractors = metrics.attractors
                                         ));
+                                        if metrics.collision_emitters > 0 {
+                                            if metrics.collision_checks_skipped {
+                                                ui.colored_label(
+                                                    egui::Color32::from_rgb(255, 140, 0),
+                                                    format!(
+                                                        "Collision: skipped (over {} particles)",
+                                                        metrics.collision_particle_limit
+                                                    ),
+                                                );
+                                            } else {
+                                                ui.label(tr!(
+                                                    "ui.collision_emitters_tests_hits_per",
+                                                    collision_emitters = metrics.collision_emitters,
+                                                    collision_tests = metrics.collision_tests,
+                                                    collision_hits = metrics.collision_hits
+                                                ));
+                                            }
+                                        }
                                     } else {
-                                        ui.label("Emitters: none active");
+                                        ui.label(tr!("ui.emitters_none_active"));
                                     }
                                 },
                             );
                             ui.separator();
                         }
-                        egui::CollapsingHeader::new("Sprite Animation Perf").default_open(false).show(
+                        panel_header("panel.sprite_animation_perf").default_open(false).show(
                             ui,
                             |ui| {
                                 let warn_color = egui::Color32::from_rgb(255, 140, 0);
                                 if let Some(perf) = sprite_perf_sample {
                                     if perf.total_animators() == 0 {
-                                        ui.label("No sprite animators updated last frame.");
+                                        ui.label(tr!("ui.no_sprite_animators_updated_last_frame"));
                                     } else {
                                         let slow_pct = perf.slow_ratio() * 100.0;
                                         let slow_text =
@@ -1931,20 +2808,26 @@ impl App {
                                         } else {
                                             ui.label(slow_text);
                                         }
-                                        ui.label(format!("Fast bucket: {}", perf.fast_animators));
-                                        ui.label(format!(
-                                            "Δt mix – variable: {} | fixed: {}",
-                                            perf.var_dt_animators, perf.const_dt_animators
+                                        ui.label(tr!("ui.fast_bucket", fast_animators = perf.fast_animators));
+                                        ui.label(tr!(
+                                            "ui.dt_mix",
+                                            var_dt_animators = perf.var_dt_animators,
+                                            const_dt_animators = perf.const_dt_animators
                                         ));
-                                        ui.label(format!(
-                                            "Ping-pong: {} | Event-heavy: {}",
-                                            perf.ping_pong_animators, perf.events_heavy_animators
+                                        ui.label(tr!(
+                                            "ui.ping_pong_event_heavy",
+                                            ping_pong_animators = perf.ping_pong_animators,
+                                            events_heavy_animators = perf.events_heavy_animators
                                         ));
-                                        ui.label(format!(
-                                            "Events emitted: {} (coalesced {})",
-                                            perf.events_emitted, perf.events_coalesced
+                                        ui.label(tr!(
+                                            "ui.events_emitted_coalesced",
+                                            events_emitted = perf.events_emitted,
+                                            events_coalesced = perf.events_coalesced
+                                        ));
+                                        ui.label(tr!(
+                                            "ui.modulo_fallbacks",
+                                            mod_or_div_calls = perf.mod_or_div_calls
                                         ));
-                                        ui.label(format!("Modulo fallbacks: {}", perf.mod_or_div_calls));
                                         if perf.simd_supported && perf.fast_animators > 0 {
                                             let tail_pct = perf.tail_scalar_ratio() * 100.0;
                                             let lanes_text = format!(
@@ -1960,18 +2843,18 @@ impl App {
                                                 ui.label(lanes_text);
                                             }
                                         } else if perf.simd_supported {
-                                            ui.label("SIMD lanes: no fast animators recorded");
+                                            ui.label(tr!("ui.simd_lanes_no_fast_animators_recorded"));
                                         } else {
-                                            ui.label("SIMD lanes: scalar path (feature disabled)");
+                                            ui.label(tr!("ui.simd_lanes_scalar_path_feature_disabled"));
                                         }
                                     }
                                 } else {
-                                    ui.label("No sprite perf samples recorded yet.");
+                                    ui.label(tr!("ui.no_sprite_perf_samples_recorded_yet"));
                                 }
                             },
                         );
                         ui.separator();
-                        egui::CollapsingHeader::new("Sprite Stage Timings").default_open(false).show(
+                        panel_header("panel.sprite_stage_timings").default_open(false).show(
                             ui,
                             |ui| {
                                 sprite_stage_bar(
@@ -1990,27 +2873,39 @@ impl App {
                             },
                         );
                         ui.separator();
-                        egui::CollapsingHeader::new("Spatial Index").default_open(false).show(ui, |ui| {
+                        panel_header("panel.sprite_timeline_usage").default_open(false).show(
+                            ui,
+                            |ui| {
+                                render_timeline_usage(ui, timeline_usage.as_ref(), &mut actions);
+                            },
+                        );
+                        ui.separator();
+                        panel_header("panel.spatial_index").default_open(false).show(ui, |ui| {
                             if let Some(metrics) = spatial_metrics {
-                                ui.label(format!(
-                                    "Mode: {:?} | Cells: {} | Avg occ {:.2} | Max {}",
-                                    metrics.mode,
-                                    metrics.occupied_cells,
-                                    metrics.average_occupancy,
-                                    metrics.max_cell_occupancy
+                                ui.label(tr!(
+                                    "ui.mode_cells_avg_occ_max",
+                                    mode = format!("{:?}", metrics.mode),
+                                    occupied_cells = metrics.occupied_cells,
+                                    average_occupancy = format!("{:.2}", metrics.average_occupancy),
+                                    max_cell_occupancy = metrics.max_cell_occupancy
                                 ));
                                 if metrics.mode == SpatialMode::Quadtree {
-                                    ui.label(format!("Quadtree nodes: {}", metrics.quadtree_nodes));
+                                    ui.label(tr!(
+                                        "ui.quadtree_nodes",
+                                        quadtree_nodes = metrics.quadtree_nodes
+                                    ));
                                 }
                             } else {
-                                ui.label("Metrics unavailable.");
+                                ui.label(tr!("ui.metrics_unavailable"));
                             }
-                            if ui.checkbox(&mut ui_spatial_use_quadtree, "Enable quadtree fallback").changed()
+                            if ui
+                                .checkbox(&mut ui_spatial_use_quadtree, tr!("ui.enable_quadtree_fallback"))
+                                .changed()
                             {
                                 inspector_status = Some(if ui_spatial_use_quadtree {
-                                    "Quadtree fallback enabled.".to_string()
+                                    tr!("ui.quadtree_fallback_enabled")
                                 } else {
-                                    "Quadtree fallback disabled.".to_string()
+                                    tr!("ui.quadtree_fallback_disabled")
                                 });
                             }
                             let mut threshold = ui_spatial_density_threshold;
@@ -2019,19 +2914,19 @@ impl App {
                                     egui::DragValue::new(&mut threshold)
                                         .speed(0.1)
                                         .range(1.0..=64.0)
-                                        .prefix("Density threshold "),
+                                        .prefix(tr!("ui.density_threshold")),
                                 )
                                 .changed()
                             {
                                 ui_spatial_density_threshold = threshold.max(1.0);
                             }
-                            if ui.button("Find entity by ID...").clicked() {
+                            if ui.button(tr!("ui.find_entity")).clicked() {
                                 id_lookup_active = true;
                             }
                         });
                         if !plugin_capability_metrics.is_empty() {
                             ui.separator();
-                            ui.label("Plugin Capability Metrics");
+                            ui.label(tr!("ui.plugin_capability_metrics"));
                             let mut rows = plugin_capability_metrics.iter().collect::<Vec<_>>();
                             rows.sort_by(|a, b| a.0.cmp(b.0));
                             for (plugin, log) in rows {
@@ -2040,31 +2935,34 @@ impl App {
                                     ui.label(format!("{plugin}:"));
                                     ui.colored_label(color, summary);
                                     if let Some(last) = log.last_capability {
-                                        ui.small(format!("last missing: {}", last.label()));
+                                        ui.small(tr!("ui.last_missing", last = last.label()));
                                     }
                                 });
                             }
                         }
                         if !plugin_capability_events.is_empty() {
                             ui.separator();
-                            ui.label("Capability Violations");
+                            ui.label(tr!("ui.capability_violations"));
                             for event in plugin_capability_events.iter().take(6) {
                                 let ago = event
                                     .timestamp
                                     .elapsed()
-                                    .map(|duration| format!("{:.1}s ago", duration.as_secs_f32()))
-                                    .unwrap_or_else(|_| "just now".to_string());
-                                ui.small(format!(
-                                    "[{}] {} attempted {}",
-                                    ago,
-                                    event.plugin,
-                                    event.capability.label()
+                                    .map(|duration| tr!(
+                                        "ui.seconds_ago",
+                                        seconds = format!("{:.1}", duration.as_secs_f32())
+                                    ))
+                                    .unwrap_or_else(|_| tr!("ui.just_now"));
+                                ui.small(tr!(
+                                    "ui.capability_violation",
+                                    ago = ago,
+                                    plugin = event.plugin,
+                                    capability = event.capability.label()
                                 ));
                             }
                         }
                         if !animation_validation_log.is_empty() {
                             ui.separator();
-                            ui.label("Animation Validation Alerts");
+                            ui.label(tr!("ui.animation_validation_alerts"));
                             for event in animation_validation_log.iter().take(6) {
                                 let color = animation_validation_color(event.severity);
                                 ui.colored_label(
@@ -2079,8 +2977,11 @@ impl App {
                             }
                         }
                         ui.separator();
-                        let button_label =
-                            if keyframe_panel_open { "Hide Keyframe Editor" } else { "Open Keyframe Editor" };
+                        let button_label = if keyframe_panel_open {
+                            tr!("ui.hide_keyframe_editor")
+                        } else {
+                            tr!("ui.open_keyframe_editor")
+                        };
                         if ui.button(button_label).clicked() {
                             let was_open = keyframe_panel_open;
                             keyframe_panel_open = !keyframe_panel_open;
@@ -2095,103 +2996,117 @@ impl App {
                             render_keyframe_editor_usage(ui, usage, keyframe_event_log.as_ref());
                         }
                         ui.separator();
-                        egui::CollapsingHeader::new("Animation Time").default_open(false).show(ui, |ui| {
-                            ui.checkbox(&mut animation_paused, "Pause playback");
-                            ui.add(egui::Slider::new(&mut animation_scale, 0.0..=4.0).text("Global scale"));
-                            ui.horizontal(|ui| {
-                                let mut enabled = animation_fixed_enabled;
-                                if ui.checkbox(&mut enabled, "Fixed step (s)").changed() {
-                                    animation_fixed_enabled = enabled;
-                                }
-                                let response = ui.add_enabled(
-                                    animation_fixed_enabled,
-                                    egui::DragValue::new(&mut animation_fixed_step)
-                                        .speed(0.001)
-                                        .range(0.001..=0.5)
-                                        .suffix(" s"),
+                        let animation_time_header = panel_header("panel.animation_time")
+                            .default_open(false)
+                            .open(layout_apply.as_ref().and_then(|preset| preset.panel_open(PANEL_ANIMATION_TIME)))
+                            .show(ui, |ui| {
+                                ui.checkbox(&mut animation_paused, tr!("ui.pause_playback"));
+                                ui.add(
+                                    egui::Slider::new(&mut animation_scale, 0.0..=4.0)
+                                        .text(tr!("ui.global_scale")),
                                 );
-                                if response.changed() {
-                                    animation_fixed_step = animation_fixed_step.max(0.0);
-                                }
-                            });
-                            ui.label(format!("Accumulated remainder: {:.4} s", animation_remainder));
-                            ui.separator();
-                            if animation_group_entries.is_empty() {
-                                ui.small("No group overrides active.");
-                            } else {
-                                ui.label("Group overrides");
-                                let mut remove_indices = Vec::new();
-                                for (index, entry) in animation_group_entries.iter_mut().enumerate() {
-                                    let (group_name, value) = entry;
-                                    let mut remove_flag = false;
-                                    ui.horizontal(|ui| {
-                                        ui.label(group_name.as_str());
-                                        if ui
-                                            .add(
-                                                egui::Slider::new(value, 0.0..=4.0)
-                                                    .clamping(SliderClamping::Always)
-                                                    .text("Scale"),
-                                            )
-                                            .changed()
-                                        {
-                                            *value = value.max(0.0);
-                                        }
-                                        if ui.button("Remove").clicked() {
-                                            remove_flag = true;
+                                ui.horizontal(|ui| {
+                                    let mut enabled = animation_fixed_enabled;
+                                    if ui.checkbox(&mut enabled, tr!("ui.fixed_step_s")).changed() {
+                                        animation_fixed_enabled = enabled;
+                                    }
+                                    let response = ui.add_enabled(
+                                        animation_fixed_enabled,
+                                        egui::DragValue::new(&mut animation_fixed_step)
+                                            .speed(0.001)
+                                            .range(0.001..=0.5)
+                                            .suffix(" s"),
+                                    );
+                                    if response.changed() {
+                                        animation_fixed_step = animation_fixed_step.max(0.0);
+                                    }
+                                });
+                                ui.label(tr!(
+                                    "ui.accumulated_remainder_s",
+                                    animation_remainder = format!("{:.4}", animation_remainder)
+                                ));
+                                ui.separator();
+                                if animation_group_entries.is_empty() {
+                                    ui.small(tr!("ui.no_group_overrides_active"));
+                                } else {
+                                    ui.label(tr!("ui.group_overrides"));
+                                    let mut remove_indices = Vec::new();
+                                    for (index, entry) in animation_group_entries.iter_mut().enumerate() {
+                                        let (group_name, value) = entry;
+                                        let mut remove_flag = false;
+                                        ui.horizontal(|ui| {
+                                            ui.label(group_name.as_str());
+                                            if ui
+                                                .add(
+                                                    egui::Slider::new(value, 0.0..=4.0)
+                                                        .clamping(SliderClamping::Always)
+                                                        .text(tr!("ui.scale")),
+                                                )
+                                                .changed()
+                                            {
+                                                *value = value.max(0.0);
+                                            }
+                                            if ui.button(tr!("ui.remove")).clicked() {
+                                                remove_flag = true;
+                                            }
+                                        });
+                                        if remove_flag {
+                                            remove_indices.push(index);
                                         }
-                                    });
-                                    if remove_flag {
-                                        remove_indices.push(index);
                                     }
+                                    for index in remove_indices.into_iter().rev() {
+                                        animation_group_entries.remove(index);
+                                    }
+                                    ui.small(tr!("ui.setting_a_group_to_1_0"));
                                 }
-                                for index in remove_indices.into_iter().rev() {
-                                    animation_group_entries.remove(index);
-                                }
-                                ui.small("Setting a group to 1.0 clears the override on apply.");
-                            }
-                            ui.separator();
-                            ui.label("Add / update group override");
-                            ui.horizontal(|ui| {
-                                ui.label("Group");
-                                ui.text_edit_singleline(&mut animation_group_input);
-                            });
-                            ui.horizontal(|ui| {
-                                ui.label("Scale");
-                                ui.add(
-                                    egui::Slider::new(&mut animation_group_scale_input, 0.0..=4.0)
-                                        .clamping(SliderClamping::Always)
-                                        .text("x"),
-                                );
-                                if ui.button("Apply").clicked() {
-                                    let name = animation_group_input.trim();
-                                    if !name.is_empty() {
-                                        let value = animation_group_scale_input.max(0.0);
-                                        if let Some(entry) =
-                                            animation_group_entries.iter_mut().find(|(existing, _)| existing == name)
-                                        {
-                                            entry.1 = value;
-                                        } else {
-                                            animation_group_entries.push((name.to_string(), value));
-                                            animation_group_entries.sort_by(|a, b| a.0.cmp(&b.0));
+                                ui.separator();
+                                ui.label(tr!("ui.add_update_group_override"));
+                                ui.horizontal(|ui| {
+                                    ui.label(tr!("ui.group"));
+                                    ui.text_edit_singleline(&mut animation_group_input);
+                                });
+                                ui.horizontal(|ui| {
+                                    ui.label(tr!("ui.scale"));
+                                    ui.add(
+                                        egui::Slider::new(&mut animation_group_scale_input, 0.0..=4.0)
+                                            .clamping(SliderClamping::Always)
+                                            .text("x"),
+                                    );
+                                    if ui.button(tr!("ui.apply")).clicked() {
+                                        let name = animation_group_input.trim();
+                                        if !name.is_empty() {
+                                            let value = animation_group_scale_input.max(0.0);
+                                            if let Some(entry) =
+                                                animation_group_entries.iter_mut().find(|(existing, _)| existing == name)
+                                            {
+                                                entry.1 = value;
+                                            } else {
+                                                animation_group_entries.push((name.to_string(), value));
+                                                animation_group_entries.sort_by(|a, b| a.0.cmp(&b.0));
+                                            }
+                                            animation_group_input.clear();
+                                            animation_group_scale_input = 1.0;
                                         }
-                                        animation_group_input.clear();
-                                        animation_group_scale_input = 1.0;
                                     }
-                                }
+                                });
+                                ui.small(tr!("ui.group_overrides_drive_per_tag_multipliers"));
                             });
-                            ui.small("Group overrides drive per-tag multipliers for sprite animations.");
-                        });
-                        egui::CollapsingHeader::new("Profiler").default_open(false).show(ui, |ui| {
+                        animation_time_open = animation_time_header.openness > 0.0;
+                        panel_header("panel.profiler").default_open(false).show(ui, |ui| {
                             ui.monospace(frame_summary_text(frame_timing_sample.as_ref()));
+                            if let Some(sample) = frame_timing_sample.as_ref() {
+                                ui.monospace(sprite_upload_summary_text(sample));
+                                ui.monospace(mesh_culling_summary_text(sample));
+                            }
                             if system_timings.is_empty() {
-                                ui.label("System timings unavailable");
+                                ui.label(tr!("ui.system_timings_unavailable"));
                             } else {
                                 egui::Grid::new("system_profiler_grid").striped(true).show(ui, |ui| {
-                                    ui.label("System");
-                                    ui.label("Last (ms)");
-                                    ui.label("Avg (ms)");
-                                    ui.label("Max (ms)");
-                                    ui.label("Samples");
+                                    ui.label(tr!("ui.system"));
+                                    ui.label(tr!("ui.last_ms"));
+                                    ui.label(tr!("ui.avg_ms"));
+                                    ui.label(tr!("ui.max_ms"));
+                                    ui.label(tr!("ui.samples"));
                                     ui.end_row();
                                     for timing in system_timings.iter().take(12) {
                                         ui.label(timing.name);
@@ -2205,18 +3120,40 @@ impl App {
                                 });
                             }
                         });
+                        if !custom_counters.is_empty() {
+                            ui.separator();
+                            ui.label(tr!("ui.custom_counters"));
+                            render_custom_counters(ui, custom_counters.as_ref());
+                        }
                     });
 
-                    egui::CollapsingHeader::new("Debug Overlays").default_open(false).show(ui, |ui| {
+                    panel_header("panel.debug_overlays").default_open(false).show(ui, |ui| {
                         if viewport_camera_mode != ViewportCameraMode::Ortho2D {
-                            ui.label("Overlays render in the 2D viewport.");
+                            ui.label(tr!("ui.overlays_render_in_the_2d_viewport"));
                         }
-                        ui.checkbox(&mut debug_show_spatial_hash, "Spatial hash cells");
-                        ui.checkbox(&mut debug_show_colliders, "Collider bounds");
+                        ui.checkbox(&mut debug_show_spatial_hash, tr!("ui.spatial_hash_cells"));
+                        ui.checkbox(&mut debug_show_colliders, tr!("ui.collider_bounds"));
+                        ui.checkbox(&mut debug_show_nav_grid, tr!("ui.nav_grid_agent_paths"))
+                            .on_hover_text(tr!("ui.nav_grid_agent_paths_hint"));
+                        ui.add_enabled(
+                            cfg!(feature = "debug_draw"),
+                            egui::Checkbox::new(&mut debug_show_culled_bounds, tr!("ui.culled_mesh_bounds")),
+                        )
+                        .on_hover_text(tr!("ui.culled_mesh_bounds_hint"))
+                        .on_disabled_hover_text(tr!("ui.requires_the_debug_draw_hint"));
+                        ui.checkbox(&mut debug_show_skeletons, tr!("ui.skeleton_bones_all_skeletons"))
+                            .on_hover_text(tr!("ui.skeleton_bones_all_skeletons_hint"));
+                        ui.add_enabled(
+                            debug_show_skeletons || selection_details.as_ref().is_some_and(|info| info.skeleton.is_some()),
+                            egui::Checkbox::new(&mut debug_skeleton_labels, tr!("ui.joint_names")),
+                        );
                     });
 
-                    egui::CollapsingHeader::new("UI & Camera").default_open(false).show(ui, |ui| {
-                        if ui.add(egui::Slider::new(&mut ui_scale, 0.5..=2.0).text("UI scale")).changed() {
+                    panel_header("panel.ui_camera").default_open(false).show(ui, |ui| {
+                        if ui
+                            .add(egui::Slider::new(&mut ui_scale, 0.5..=2.0).text(tr!("ui.ui_scale")))
+                            .changed()
+                        {
                             ui_scale = ui_scale.clamp(0.5, 2.0);
                             self.editor_shell.egui_ctx.set_pixels_per_point(base_pixels_per_point * ui_scale);
                             if let Some(screen) = self.editor_shell.egui_screen.as_mut() {
@@ -2237,35 +3174,65 @@ impl App {
                         if viewport_mode != viewport_camera_mode {
                             viewport_mode_request = Some(viewport_mode);
                         }
-                        ui.label(format!(
-                            "Camera: pos({:.2}, {:.2}) zoom {:.2}",
-                            camera_position.x, camera_position.y, camera_zoom
+                        let mut sort_mode = sprite_sort_mode;
+                        egui::ComboBox::from_label(tr!("ui.sprite_sort"))
+                            .selected_text(sort_mode.label())
+                            .show_ui(ui, |ui| {
+                                for mode in SpriteSortMode::ALL {
+                                    ui.selectable_value(&mut sort_mode, mode, mode.label());
+                                }
+                            })
+                            .response
+                            .on_hover_text(tr!("ui.draw_order_of_sprites_hint"));
+                        if sort_mode != sprite_sort_mode {
+                            actions.sprite_sort_select = Some(sort_mode);
+                        }
+                        ui.label(tr!(
+                            "ui.camera_pos_zoom",
+                            x = format!("{:.2}", camera_position.x),
+                            y = format!("{:.2}", camera_position.y),
+                            camera_zoom = format!("{:.2}", camera_zoom)
                         ));
                         if viewport_camera_mode == ViewportCameraMode::Perspective3D {
                             let pos = mesh_camera_for_ui.position;
-                            ui.label(format!("3D camera pos: ({:.2}, {:.2}, {:.2})", pos.x, pos.y, pos.z));
+                            ui.label(tr!(
+                                "ui.3d_camera_pos",
+                                x = format!("{:.2}", pos.x),
+                                y = format!("{:.2}", pos.y),
+                                z = format!("{:.2}", pos.z)
+                            ));
                         }
-                        let display_mode = if window_fullscreen { "Fullscreen" } else { "Windowed" };
-                        ui.label(format!(
-                            "Display: {}x{} {}",
-                            window_config_width, window_config_height, display_mode
+                        let display_mode =
+                            if window_fullscreen { tr!("ui.fullscreen") } else { tr!("ui.windowed") };
+                        ui.label(tr!(
+                            "ui.display_mode",
+                            window_config_width = window_config_width,
+                            window_config_height = window_config_height,
+                            display_mode = display_mode
+                        ));
+                        ui.label(tr!(
+                            "ui.vsync",
+                            state = if vsync_enabled { tr!("ui.on") } else { tr!("ui.off") }
                         ));
-                        ui.label(format!("VSync: {}", if vsync_enabled { "On" } else { "Off" }));
                         if let Some(cursor) = cursor_world_2d {
-                            ui.label(format!("Cursor world: ({:.2}, {:.2})", cursor.x, cursor.y));
+                            ui.label(tr!(
+                                "ui.cursor_world",
+                                x = format!("{:.2}", cursor.x),
+                                y = format!("{:.2}", cursor.y)
+                            ));
                         } else {
-                            ui.label("Cursor world: n/a");
+                            ui.label(tr!("ui.cursor_world_n_a"));
                         }
                         if let Some(status) = sprite_guardrail_status.as_ref() {
                             ui.colored_label(egui::Color32::from_rgb(255, 180, 80), status);
                         }
                         ui.separator();
-                        ui.label("Zoom guardrails");
+                        ui.label(tr!("ui.zoom_guardrails"));
                         let mut guardrail_dirty = false;
                         if ui
                             .add(
                                 egui::Slider::new(&mut ui_camera_zoom_min, 0.05..=10.0)
-                                    .text("Min zoom")
+                                    .text(tr!("ui.min_zoom"))
                                     .logarithmic(true),
                             )
                             .changed()
@@ -2275,7 +3242,7 @@ impl App {
                         if ui
                             .add(
                                 egui::Slider::new(&mut ui_camera_zoom_max, 0.1..=20.0)
-                                    .text("Max zoom")
+                                    .text(tr!("ui.max_zoom"))
                                     .logarithmic(true),
                             )
                             .changed()
@@ -2285,7 +3252,7 @@ impl App {
                         if ui
                             .add(
                                 egui::Slider::new(&mut ui_sprite_guard_pixels, 256.0..=8192.0)
-                                    .text("Sprite guard (px)")
+                                    .text(tr!("ui.sprite_guard_px"))
                                     .logarithmic(true),
                             )
                             .changed()
@@ -2312,11 +3279,76 @@ impl App {
                             ui_sprite_guard_mode = guard_mode;
                             guardrail_dirty = true;
                         }
+                        if ui
+                            .checkbox(&mut ui_static_sprite_batching, tr!("ui.bake_static_sprites"))
+                            .on_hover_text(
+                                "Keep non-moving, non-animated sprites in a buffer that is only \
+                                 re-uploaded when the static set changes",
+                            )
+                            .changed()
+                        {
+                            guardrail_dirty = true;
+                        }
                         if guardrail_dirty {
                             editor_settings_dirty = true;
                         }
+                        if ui
+                            .checkbox(&mut ui_low_latency_input, tr!("ui.low_latency_input"))
+                            .on_hover_text(
+                                "Pan the camera before sampling the cursor so picking and gizmo drags \
+                                 track the frame being rendered; clicks still register at frame start",
+                            )
+                            .changed()
+                        {
+                            editor_settings_dirty = true;
+                        }
+                        ui.horizontal(|ui| {
+                            let active_name = languages
+                                .iter()
+                                .find(|(code, _)| *code == active_language)
+                                .map(|(_, name)| name.as_str())
+                                .unwrap_or(active_language.as_str());
+                            egui::ComboBox::from_label(tr!("ui.language"))
+                                .selected_text(active_name)
+                                .show_ui(ui, |ui| {
+                                    for (code, name) in languages.iter() {
+                                        if ui.selectable_label(*code == active_language, name).clicked()
+                                            && *code != active_language
+                                        {
+                                            actions.language_select = Some(code.clone());
+                                        }
+                                    }
+                                });
+                            if ui
+                                .button(tr!("ui.dump_untranslated"))
+                                .on_hover_text(tr!("ui.dump_untranslated_hint"))
+                                .clicked()
+                            {
+                                actions.dump_untranslated_strings = true;
+                            }
+                        });
+                        ui.horizontal(|ui| {
+                            let mut theme = ui_theme;
+                            egui::ComboBox::from_label(tr!("ui.theme"))
+                                .selected_text(theme.kind.label())
+                                .show_ui(ui, |ui| {
+                                    for kind in EditorThemeKind::ALL {
+                                        ui.selectable_value(&mut theme.kind, kind, kind.label());
+                                    }
+                                });
+                            let mut custom_accent = theme.accent.is_some();
+                            ui.checkbox(&mut custom_accent, tr!("ui.accent"));
+                            let mut accent = theme.accent.unwrap_or([70, 140, 230]);
+                            if custom_accent {
+                                egui::color_picker::color_edit_button_srgb(ui, &mut accent);
+                            }
+                            theme.accent = custom_accent.then_some(accent);
+                            if theme != ui_theme {
+                                actions.theme_select = Some(theme);
+                            }
+                        });
                         ui.separator();
-                        ui.label("Camera bookmarks");
+                        ui.label(tr!("ui.camera_bookmarks"));
                         let combo_label = if let Some(target) = camera_follow_target.as_ref() {
                             format!("Following {}", target)
                         } else if let Some(active) = active_camera_bookmark.as_ref() {
@@ -2329,7 +3361,7 @@ impl App {
                             .show_ui(ui, |ui| {
                                 let free_selected =
                                     camera_follow_target.is_none() && active_camera_bookmark.is_none();
-                                if ui.selectable_label(free_selected, "Free camera").clicked() {
+                                if ui.selectable_label(free_selected, tr!("ui.free_camera")).clicked() {
                                     camera_bookmark_select = Some(None);
                                 }
                                 for bookmark in &camera_bookmarks {
@@ -2343,29 +3375,35 @@ impl App {
                         ui.horizontal(|ui| {
                             let response = ui.add(
                                 egui::TextEdit::singleline(&mut camera_bookmark_input)
-                                    .hint_text("Bookmark name"),
+                                    .hint_text(tr!("ui.bookmark_name")),
                             );
                             let trimmed = camera_bookmark_input.trim().to_string();
                             let can_save = !trimmed.is_empty();
                             if response.lost_focus() && ui.input(|i| i.key_pressed(Key::Enter)) && can_save {
                                 camera_bookmark_save = Some(trimmed.clone());
                             }
-                            if ui.add_enabled(can_save, egui::Button::new("Save / Overwrite")).clicked() {
+                            if ui
+                                .add_enabled(can_save, egui::Button::new(tr!("ui.save_overwrite")))
+                                .clicked()
+                            {
                                 camera_bookmark_save = Some(trimmed);
                             }
+                            if ui.button(tr!("ui.reset_camera")).on_hover_text("Home").clicked() {
+                                actions.reset_camera = true;
+                            }
                         });
                         if let Some(active) = active_camera_bookmark.as_ref() {
                             ui.horizontal(|ui| {
-                                if ui.button("Update Active").clicked() {
+                                if ui.button(tr!("ui.update_active")).clicked() {
                                     camera_bookmark_save = Some(active.clone());
                                 }
-                                if ui.button("Delete Active").clicked() {
+                                if ui.button(tr!("ui.delete_active")).clicked() {
                                     camera_bookmark_delete = Some(active.clone());
                                 }
                             });
                         }
                         ui.separator();
-                        ui.label("Camera follow");
+                        ui.label(tr!("ui.camera_follow"));
                         let follow_label = camera_follow_target
                             .as_ref()
                             .map(|id| format!("Following entity {id}"))
@@ -2373,12 +3411,12 @@ impl App {
                         ui.label(follow_label);
                         ui.horizontal(|ui| {
                             ui.add_enabled_ui(selection_details.is_some(), |ui| {
-                                if ui.button("Follow Selection").clicked() {
+                                if ui.button(tr!("ui.follow_selection")).clicked() {
                                     camera_follow_selection = true;
                                 }
                             });
                             ui.add_enabled_ui(camera_follow_target.is_some(), |ui| {
-                                if ui.button("Clear Follow").clicked() {
+                                if ui.button(tr!("ui.clear_follow")).clicked() {
                                     camera_follow_clear = true;
                                 }
                             });
@@ -2388,20 +3426,20 @@ impl App {
 
                     // Spawn controls moved to the right panel.
 
-                    egui::CollapsingHeader::new("Scripts").default_open(false).show(ui, |ui| {
+                    panel_header("panel.scripts").default_open(false).show(ui, |ui| {
                         if script_debugger.available {
                             if let Some(path) = script_debugger.script_path.as_ref() {
-                                ui.label(format!("Path: {path}"));
+                                ui.label(tr!("ui.path_at", path = path));
                             }
                             let mut enabled = script_debugger.enabled;
-                            if ui.checkbox(&mut enabled, "Enable scripts").changed() {
+                            if ui.checkbox(&mut enabled, tr!("ui.enable_scripts")).changed() {
                                 script_debugger.enabled = enabled;
                                 script_debugger_output.set_enabled = Some(enabled);
                             }
                             let mut paused = script_debugger.paused;
                             if ui
-                                .checkbox(&mut paused, "Pause updates")
-                                .on_hover_text("Stop invoking update; use Step to run once while paused.")
+                                .checkbox(&mut paused, tr!("ui.pause_updates"))
+                                .on_hover_text(tr!("ui.pause_updates_hint"))
                                 .changed()
                             {
                                 script_debugger.paused = paused;
@@ -2409,76 +3447,83 @@ impl App {
                             }
                             ui.horizontal(|ui| {
                                 ui.add_enabled_ui(script_debugger.paused, |ui| {
-                                    if ui.button("Step").clicked() {
+                                    if ui.button(tr!("ui.step")).clicked() {
                                         script_debugger_output.step_once = true;
                                     }
                                 });
-                                if ui.button("Reload").clicked() {
+                                if ui.button(tr!("ui.reload")).clicked() {
                                     script_debugger_output.reload = true;
                                 }
-                                if ui.button("Open debugger").clicked() {
+                                if ui.button(tr!("ui.open_debugger")).clicked() {
                                     script_debugger.open = true;
                                 }
                             });
                             if let Some(err) = script_debugger.last_error.as_ref() {
-                                ui.colored_label(egui::Color32::RED, format!("Error: {err}"));
+                                ui.colored_label(egui::Color32::RED, tr!(
+                                    "ui.script_error",
+                                    message = script_error_headline(err)
+                                ));
                             } else if script_debugger.enabled {
-                                let status = if script_debugger.paused { "paused" } else { "running" };
-                                ui.label(format!("Scripts {status}"));
+                                let status = if script_debugger.paused {
+                                    tr!("ui.script_status_paused")
+                                } else {
+                                    tr!("ui.script_status_running")
+                                };
+                                ui.label(tr!("ui.scripts", status = status));
                             } else {
-                                ui.label("Scripts disabled");
+                                ui.label(tr!("ui.scripts_disabled"));
                             }
                             if script_debugger.invalid_handle_uses > 0
                                 || script_debugger.despawn_dead_uses > 0
                                 || !script_debugger.spawn_failures.is_empty()
                             {
                                 ui.separator();
-                                ui.label("Script safety");
+                                ui.label(tr!("ui.script_safety"));
                                 if script_debugger.invalid_handle_uses > 0 {
-                                    ui.label(format!(
-                                        "Invalid handle uses ignored: {}",
-                                        script_debugger.invalid_handle_uses
+                                    ui.label(tr!(
+                                        "ui.invalid_handle_uses_ignored",
+                                        invalid_handle_uses = script_debugger.invalid_handle_uses
                                     ));
                                 }
                                 if script_debugger.despawn_dead_uses > 0 {
-                                    ui.label(format!(
-                                        "Despawn on dead handles ignored: {}",
-                                        script_debugger.despawn_dead_uses
+                                    ui.label(tr!(
+                                        "ui.despawn_on_dead_handles_ignored",
+                                        despawn_dead_uses = script_debugger.despawn_dead_uses
                                     ));
                                 }
                                 if !script_debugger.spawn_failures.is_empty() {
-                                    ui.label("Spawn failures:");
+                                    ui.label(tr!("ui.spawn_failures"));
                                     for (reason, count) in script_debugger.spawn_failures.iter() {
                                         ui.label(format!("- {reason}: {count}"));
                                     }
                                 }
                             }
                             ui.separator();
-                            ui.label("Active handles");
+                            ui.label(tr!("ui.active_handles"));
                             show_script_handle_table(ui, &script_debugger.handles, "sidebar");
                             if !script_debugger.timings.is_empty() {
                                 ui.separator();
-                                ui.label("Script timings (ms)");
+                                ui.label(tr!("ui.script_timings_ms"));
                                 let mut threshold = script_debugger.timing_threshold_ms.unwrap_or(0.0);
                                 ui.horizontal(|ui| {
-                                    ui.label("Threshold");
+                                    ui.label(tr!("ui.threshold"));
                                     if ui
                                         .add(egui::DragValue::new(&mut threshold).speed(0.1).range(0.0..=50.0))
                                         .changed()
                                     {
                                         script_debugger_output.set_timing_threshold_ms = Some(Some(threshold));
                                     }
-                                    if ui.button("Clear").clicked() {
+                                    if ui.button(tr!("ui.clear")).clicked() {
                                         threshold = 0.0;
                                         script_debugger_output.set_timing_threshold_ms = Some(None);
                                     }
                                 });
                                 egui::Grid::new("script_timings_sidebar").striped(true).show(ui, |ui| {
-                                    ui.label("Name");
-                                    ui.label("Last");
-                                    ui.label("Avg");
-                                    ui.label("Max");
-                                    ui.label("Samples");
+                                    ui.label(tr!("ui.name"));
+                                    ui.label(tr!("ui.last"));
+                                    ui.label(tr!("ui.avg"));
+                                    ui.label(tr!("ui.max"));
+                                    ui.label(tr!("ui.samples"));
                                     ui.end_row();
                                     for timing in script_debugger.timings.iter() {
                                         ui.label(timing.name);
@@ -2492,12 +3537,12 @@ impl App {
                             }
                             if !script_debugger.offenders.is_empty() {
                                 ui.separator();
-                                ui.label("Slow callbacks (ms)");
+                                ui.label(tr!("ui.slow_callbacks_ms"));
                                 egui::Grid::new("script_offenders_sidebar").striped(true).show(ui, |ui| {
-                                    ui.label("Script");
-                                    ui.label("Fn");
-                                    ui.label("Entity");
-                                    ui.label("Last");
+                                    ui.label(tr!("ui.script"));
+                                    ui.label(tr!("ui.fn"));
+                                    ui.label(tr!("ui.entity"));
+                                    ui.label(tr!("ui.last"));
                                     ui.end_row();
                                     for off in script_debugger.offenders.iter() {
                                         ui.label(&off.script_path);
@@ -2512,7 +3557,7 @@ impl App {
                             }
                             if !script_debugger.timing_history.is_empty() {
                                 ui.separator();
-                                ui.label("Script timing history (last frames)");
+                                ui.label(tr!("ui.script_timing_history_last_frames"));
                                 for hist in script_debugger.timing_history.iter() {
                                     ui.label(&hist.name);
                                     let points: Vec<[f64; 2]> = hist
@@ -2535,8 +3580,8 @@ impl App {
                                             plot_ui.line(eplot::Line::new(hist.name.clone(), points));
                                         });
                                     if ui
-                                        .selectable_label(hist.pinned, "Pin")
-                                        .on_hover_text("Pin this callback for focus")
+                                        .selectable_label(hist.pinned, tr!("ui.pin"))
+                                        .on_hover_text(tr!("ui.pin_hint"))
                                         .clicked()
                                     {
                                         script_debugger_output.toggle_pin = Some(hist.name.clone());
@@ -2546,7 +3591,7 @@ impl App {
                             ui.separator();
                             render_script_api_reference(ui);
                         } else {
-                            ui.label("Script plugin unavailable");
+                            ui.label(tr!("ui.script_plugin_unavailable"));
                         }
                     });
                     ui.separator();
@@ -2555,18 +3600,22 @@ impl App {
                         gizmo_interaction: &mut gizmo_interaction,
                         inspector_status: &mut inspector_status,
                         input: input_modifiers,
+                        selection_count,
                         clip_keys: clip_keys.as_ref(),
                         clip_assets: clip_assets.as_ref(),
+                        color_curve_keys: color_curve_keys.as_ref(),
                         skeleton_keys: skeleton_keys.as_ref(),
                         skeleton_assets: skeleton_assets.as_ref(),
                         atlas_keys: atlas_keys.as_ref(),
                         atlas_assets: atlas_assets.as_ref(),
                         script_paths: script_paths.as_ref(),
-                        script_error: script_debugger.last_error.as_deref(),
+                        script_error: script_debugger.last_error.as_ref().map(|err| err.message.as_str()),
                         script_error_for_entity: selected_script_error,
                         skeleton_entities: skeleton_entities.as_ref(),
                         material_options: material_options.as_ref(),
                         mesh_subsets: mesh_subsets.as_ref(),
+                        collider_edit_mode: &mut collider_edit_mode,
+                        collider_history: (collider_undo_available, collider_redo_available),
                     };
                     entity_inspector::show_entity_inspector(
                         inspector_ctx,
@@ -2588,33 +3637,57 @@ impl App {
                 let mut lookup_open = id_lookup_active;
                 let mut lookup_submit: Option<String> = None;
                 let mut lookup_close = false;
-                egui::Window::new("Entity Lookup")
+                egui::Window::new(tr!("window.entity_lookup"))
+                    .id(egui::Id::new("window.entity_lookup"))
                     .open(&mut lookup_open)
                     .resizable(false)
                     .collapsible(false)
                     .default_width(320.0)
                     .anchor(egui::Align2::CENTER_TOP, [0.0, 40.0])
                     .show(ctx, |ui| {
-                        ui.label("Paste an entity ID to jump selection to that entity.");
+                        ui.label(tr!("ui.search_by_scene_id_sprite_mesh"));
                         let response = ui.add(
                             egui::TextEdit::singleline(&mut id_lookup_input)
-                                .hint_text("entity::...")
+                                .hint_text(tr!("ui.entity_or_enemy"))
                                 .desired_width(260.0),
                         );
                         let submitted = response.lost_focus() && ui.input(|i| i.key_pressed(Key::Enter));
                         let mut triggered = submitted;
                         ui.horizontal(|ui| {
-                            if ui.button("Select").clicked() {
+                            if ui.button(tr!("ui.select")).clicked() {
                                 triggered = true;
                             }
-                            if ui.button("Close").clicked() {
+                            if ui.button(tr!("ui.close")).clicked() {
                                 lookup_close = true;
                             }
                         });
+                        let matches = entity_search(&id_lookup_input, &entity_search_candidates);
+                        ui.small(tr!(
+                            "ui.of_entities_match",
+                            matches = matches.len(),
+                            entity_search_candidates = entity_search_candidates.len()
+                        ));
+                        egui::ScrollArea::vertical().max_height(240.0).show(ui, |ui| {
+                            for found in matches.iter().take(MAX_ENTITY_SEARCH_RESULTS) {
+                                let label = if found.matched == found.scene_id {
+                                    found.scene_id.clone()
+                                } else {
+                                    format!("{}  ({})", found.scene_id, found.matched)
+                                };
+                                if ui.selectable_label(false, label).clicked() {
+                                    lookup_submit = Some(found.scene_id.clone());
+                                }
+                            }
+                        });
                         if triggered {
                             let trimmed = id_lookup_input.trim();
                             if !trimmed.is_empty() {
-                                lookup_submit = Some(trimmed.to_string());
+                                // A pasted ID wins; otherwise Enter takes the top-ranked match.
+                                let exact = matches.iter().any(|found| found.scene_id == trimmed);
+                                let best = if exact { None } else { matches.first() };
+                                lookup_submit = Some(
+                                    best.map_or_else(|| trimmed.to_string(), |found| found.scene_id.clone()),
+                                );
                             }
                         }
                     });
@@ -2630,83 +3703,86 @@ impl App {
 
             if show_editor_ui && script_debugger.open {
                 let mut debugger_open = script_debugger.open;
-                egui::Window::new("Script Debugger")
+                egui::Window::new(tr!("window.script_debugger"))
+                    .id(egui::Id::new("window.script_debugger"))
                     .open(&mut debugger_open)
                     .resizable(true)
                     .default_width(460.0)
                     .min_height(360.0)
                     .show(ctx, |ui| {
                         if !script_debugger.available {
-                            ui.label("Script plugin unavailable.");
+                            ui.label(tr!("ui.script_plugin_unavailable"));
                             return;
                         }
                         if let Some(path) = script_debugger.script_path.as_ref() {
-                            ui.label(format!("Path: {path}"));
+                            ui.label(tr!("ui.path_at", path = path));
                         }
                         let mut enabled = script_debugger.enabled;
-                        if ui.checkbox(&mut enabled, "Enable scripts").changed() {
+                        if ui.checkbox(&mut enabled, tr!("ui.enable_scripts")).changed() {
                             script_debugger.enabled = enabled;
                             script_debugger_output.set_enabled = Some(enabled);
                         }
                         let mut paused = script_debugger.paused;
-                        if ui.checkbox(&mut paused, "Pause updates").changed() {
+                        if ui.checkbox(&mut paused, tr!("ui.pause_updates")).changed() {
                             script_debugger.paused = paused;
                             script_debugger_output.set_paused = Some(paused);
                         }
                         ui.horizontal(|ui| {
                             ui.add_enabled_ui(script_debugger.paused, |ui| {
-                                if ui.button("Step").clicked() {
+                                if ui.button(tr!("ui.step")).clicked() {
                                     script_debugger_output.step_once = true;
                                 }
                             });
-                            if ui.button("Reload").clicked() {
+                            if ui.button(tr!("ui.reload")).clicked() {
                                 script_debugger_output.reload = true;
                             }
-                            if ui.button("Clear Console").clicked() {
+                            if ui.button(tr!("ui.clear_console")).clicked() {
                                 script_debugger_output.clear_console = true;
                             }
                             ui.checkbox(
                                 &mut script_debugger_output.parse_hits_in_console,
-                                "Parse hit JSON in console",
+                                tr!("ui.parse_hit_json_in_console"),
                             );
                         });
                         if let Some(err) = script_debugger.last_error.as_ref() {
-                            ui.colored_label(egui::Color32::RED, format!("Error: {err}"));
+                            if render_script_error(ui, err) {
+                                script_debugger_output.open_in_editor = err.location.clone();
+                            }
                         }
                         if script_debugger.invalid_handle_uses > 0
                             || script_debugger.despawn_dead_uses > 0
                             || !script_debugger.spawn_failures.is_empty()
                         {
                             ui.separator();
-                            ui.label("Script safety");
+                            ui.label(tr!("ui.script_safety"));
                             if script_debugger.invalid_handle_uses > 0 {
-                                ui.label(format!(
-                                    "Invalid handle uses ignored: {}",
-                                    script_debugger.invalid_handle_uses
+                                ui.label(tr!(
+                                    "ui.invalid_handle_uses_ignored",
+                                    invalid_handle_uses = script_debugger.invalid_handle_uses
                                 ));
                             }
                             if script_debugger.despawn_dead_uses > 0 {
-                                ui.label(format!(
-                                    "Despawn on dead handles ignored: {}",
-                                    script_debugger.despawn_dead_uses
+                                ui.label(tr!(
+                                    "ui.despawn_on_dead_handles_ignored",
+                                    despawn_dead_uses = script_debugger.despawn_dead_uses
                                 ));
                             }
                             if !script_debugger.spawn_failures.is_empty() {
-                                ui.label("Spawn failures:");
+                                ui.label(tr!("ui.spawn_failures"));
                                 for (reason, count) in script_debugger.spawn_failures.iter() {
                                     ui.label(format!("- {reason}: {count}"));
                                 }
                             }
                         }
                         ui.separator();
-                        ui.label("Active handles");
+                        ui.label(tr!("ui.active_handles"));
                         show_script_handle_table(ui, &script_debugger.handles, "window");
                         ui.separator();
-                        ui.label("Console");
+                        ui.label(tr!("ui.console"));
                         egui::ScrollArea::vertical().stick_to_bottom(true).max_height(220.0).show(ui, |ui| {
                             let entries = script_debugger.console_entries.as_ref();
                             if entries.is_empty() {
-                                ui.small("No console output yet.");
+                                ui.small(tr!("ui.no_console_output_yet"));
                             } else {
                                 for entry in entries {
                                     let color = match entry.kind {
@@ -2715,7 +3791,11 @@ impl App {
                                         ScriptConsoleKind::Error => egui::Color32::from_rgb(255, 120, 120),
                                         ScriptConsoleKind::Log => egui::Color32::WHITE,
                                     };
-                                    ui.colored_label(color, entry.text.as_str());
+                                    if entry.repeat > 1 {
+                                        ui.colored_label(color, format!("{} (x{})", entry.text, entry.repeat));
+                                    } else {
+                                        ui.colored_label(color, entry.text.as_str());
+                                    }
                                     if script_debugger_output.parse_hits_in_console {
                                         render_script_hit_summary(ui, entry.text.as_str());
                                     }
@@ -2723,7 +3803,7 @@ impl App {
                             }
                         });
                         ui.separator();
-                        ui.label("REPL");
+                        ui.label(tr!("ui.repl"));
                         let mut submitted = false;
                         let response = ui.add(
                             egui::TextEdit::singleline(&mut script_debugger.repl_input)
@@ -2776,10 +3856,10 @@ impl App {
                             submitted = true;
                         }
                         ui.horizontal(|ui| {
-                            if ui.button("Run").clicked() {
+                            if ui.button(tr!("ui.run")).clicked() {
                                 submitted = true;
                             }
-                            if ui.button("Clear Input").clicked() {
+                            if ui.button(tr!("ui.clear_input")).clicked() {
                                 script_debugger.repl_input.clear();
                                 script_debugger.repl_history_index = None;
                                 script_debugger.focus_repl = true;
@@ -2795,10 +3875,10 @@ impl App {
                             }
                         }
                         ui.separator();
-                        ui.label("History");
+                        ui.label(tr!("ui.history"));
                         egui::ScrollArea::vertical().max_height(140.0).show(ui, |ui| {
                             if script_debugger.repl_history.is_empty() {
-                                ui.small("No commands yet.");
+                                ui.small(tr!("ui.no_commands_yet"));
                             } else {
                                 for (idx, entry) in script_debugger.repl_history.iter().enumerate().rev() {
                                     let selected = script_debugger.repl_history_index == Some(idx);
@@ -2817,122 +3897,324 @@ impl App {
             }
             let right_panel = if show_editor_ui {
                 Some(egui::SidePanel::right("kestrel_right_panel").default_width(360.0).show(ctx, |ui| {
-                    ui.heading("3D Preview");
-                    egui::ComboBox::from_label("Mesh asset").selected_text(&preview_mesh_key).show_ui(
-                        ui,
-                        |ui| {
-                            for key in mesh_keys.iter() {
-                                let selected = preview_mesh_key == *key;
-                                if ui.selectable_label(selected, key).clicked() && !selected {
-                                    mesh_selection_request = Some(key.clone());
-                                }
+                    let mesh_preview_header = panel_header("panel.mesh_preview")
+                        .default_open(true)
+                        .open(layout_apply.as_ref().and_then(|preset| preset.panel_open(PANEL_MESH_PREVIEW)))
+                        .show(ui, |ui| {
+                            egui::ComboBox::from_label(tr!("ui.mesh_asset"))
+                                .selected_text(&preview_mesh_key)
+                                .show_ui(ui, |ui| {
+                                    for group in mesh_groups.iter() {
+                                        if let Some(source) = &group.source {
+                                            ui.label(egui::RichText::new(source).small().strong());
+                                        }
+                                        for key in &group.keys {
+                                            let selected = preview_mesh_key == *key;
+                                            let label = match gltf_part_source(key) {
+                                                Some(source) => key[source.len() + 1..].to_string(),
+                                                None => key.clone(),
+                                            };
+                                            let response = ui.selectable_label(selected, label).on_hover_text(key);
+                                            if response.clicked() && !selected {
+                                                mesh_selection_request = Some(key.clone());
+                                            }
+                                        }
+                                    }
+                                });
+                            let mut mesh_control_mode = mesh_control_mode_state;
+                            egui::ComboBox::from_id_salt("mesh_control_mode")
+                                .selected_text(mesh_control_mode.label())
+                                .show_ui(ui, |ui| {
+                                    for mode in
+                                        [MeshControlMode::Disabled, MeshControlMode::Orbit, MeshControlMode::Freefly]
+                                    {
+                                        if ui.selectable_label(mesh_control_mode == mode, mode.label()).clicked() {
+                                            mesh_control_mode = mode;
+                                        }
+                                    }
+                                });
+                            if mesh_control_mode != mesh_control_mode_state {
+                                mesh_control_request = Some(mesh_control_mode);
                             }
-                        },
-                    );
-                    let mut mesh_control_mode = mesh_control_mode_state;
-                    egui::ComboBox::from_id_salt("mesh_control_mode")
-                        .selected_text(mesh_control_mode.label())
-                        .show_ui(ui, |ui| {
-                            for mode in
-                                [MeshControlMode::Disabled, MeshControlMode::Orbit, MeshControlMode::Freefly]
-                            {
-                                if ui.selectable_label(mesh_control_mode == mode, mode.label()).clicked() {
-                                    mesh_control_mode = mode;
-                                }
+                            let mut frustum_lock = mesh_frustum_lock_state;
+                            if ui.checkbox(&mut frustum_lock, tr!("ui.frustum_lock_l")).changed() {
+                                mesh_frustum_request = Some(frustum_lock);
                             }
-                        });
-                    if mesh_control_mode != mesh_control_mode_state {
-                        mesh_control_request = Some(mesh_control_mode);
-                    }
-                    let mut frustum_lock = mesh_frustum_lock_state;
-                    if ui.checkbox(&mut frustum_lock, "Frustum lock (L)").changed() {
-                        mesh_frustum_request = Some(frustum_lock);
-                    }
-                    if frustum_lock && ui.button("Snap to selection").clicked() {
-                        mesh_frustum_snap = true;
-                    }
-                    if ui.button("Reset camera").clicked() {
-                        mesh_reset_request = true;
-                    }
-                    if ui.button("Spawn mesh entity").clicked() {
-                        actions.spawn_mesh = Some(preview_mesh_key.clone());
-                    }
-                    match mesh_control_mode_state {
-                        MeshControlMode::Orbit => {
-                            ui.label(format!("Orbit radius: {:.2}", mesh_orbit_radius));
-                        }
-                        MeshControlMode::Freefly => {
-                            ui.label(format!("Free-fly speed: {:.2}", mesh_freefly_speed_state));
-                        }
-                        MeshControlMode::Disabled => {
-                            ui.label(format!("Orbit radius: {:.2}", mesh_orbit_radius));
-                        }
-                    }
-                    if let Some(status) = &mesh_status_message {
-                        ui.label(status);
-                    } else {
-                        match mesh_control_mode_state {
-                            MeshControlMode::Disabled => {
-                                ui.label("Scripted orbit animates the camera.");
+                            if frustum_lock && ui.button(tr!("ui.snap_to_selection")).clicked() {
+                                mesh_frustum_snap = true;
+                            }
+                            let (mut near, mut far) = mesh_clip_planes_state;
+                            ui.horizontal(|ui| {
+                                ui.label(tr!("ui.clip_planes"));
+                                ui.add(
+                                    egui::DragValue::new(&mut near)
+                                        .speed(0.01)
+                                        .range(0.001..=10_000.0)
+                                        .prefix(tr!("ui.near")),
+                                )
+                                .on_hover_text(tr!("ui.near_hint"));
+                                ui.add(
+                                    egui::DragValue::new(&mut far)
+                                        .speed(1.0)
+                                        .range(0.01..=1_000_000.0)
+                                        .prefix(tr!("ui.far")),
+                                )
+                                .on_hover_text(tr!("ui.far_hint"));
+                            });
+                            if (near, far) != mesh_clip_planes_state {
+                                mesh_clip_request = Some((near, far));
                             }
-                            MeshControlMode::Orbit => {
-                                ui.label("Right drag to orbit, scroll to zoom.");
+                            if ui.button(tr!("ui.reset_camera")).clicked() {
+                                mesh_reset_request = true;
                             }
-                            MeshControlMode::Freefly => {
-                                ui.label("Hold RMB to look, use WASD/QE and Shift for boost.");
+                            if ui.button(tr!("ui.spawn_mesh_entity")).clicked() {
+                                actions.spawn_mesh = Some(preview_mesh_key.clone());
                             }
-                        }
-                    }
+                            let import_header = egui::CollapsingHeader::new(tr!("ui.import_gltf"))
+                                .id_salt("mesh_import_gltf");
+                            import_header.show(ui, |ui| {
+                                let path_id = egui::Id::new("mesh_import_path");
+                                let loaded_id = egui::Id::new("mesh_import_settings_path");
+                                let settings_id = egui::Id::new("mesh_import_settings");
+                                let mut import_path = ui
+                                    .ctx()
+                                    .data_mut(|d| d.get_persisted::<String>(path_id))
+                                    .unwrap_or_else(|| "assets/models/".to_string());
+                                ui.horizontal(|ui| {
+                                    ui.label(tr!("ui.path"));
+                                    ui.text_edit_singleline(&mut import_path);
+                                    ui.menu_button(tr!("ui.browse_menu"), |menu| {
+                                        let mut files: Vec<String> = std::fs::read_dir("assets/models")
+                                            .into_iter()
+                                            .flatten()
+                                            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                                            .filter(|path| {
+                                                path.extension()
+                                                    .and_then(|ext| ext.to_str())
+                                                    .is_some_and(|ext| matches!(ext, "gltf" | "glb"))
+                                            })
+                                            .map(|path| path.to_string_lossy().replace('\\', "/"))
+                                            .collect();
+                                        files.sort();
+                                        if files.is_empty() {
+                                            menu.label(tr!("ui.no_gltf_files_in_assets_models"));
+                                        }
+                                        for file in files {
+                                            if menu.button(&file).clicked() {
+                                                import_path = file;
+                                                menu.close();
+                                            }
+                                        }
+                                    });
+                                });
+                                let loaded_for = ui.ctx().data_mut(|d| d.get_persisted::<String>(loaded_id));
+                                let mut settings = match loaded_for {
+                                    Some(loaded) if loaded == import_path => ui
+                                        .ctx()
+                                        .data_mut(|d| d.get_persisted::<MeshImportSettings>(settings_id))
+                                        .unwrap_or_default(),
+                                    _ => MeshImportSettings::load_for(Path::new(&import_path)),
+                                };
+                                ui.horizontal(|ui| {
+                                    ui.label(tr!("ui.scale"));
+                                    ui.add(egui::DragValue::new(&mut settings.scale).speed(0.01).range(0.001..=1000.0));
+                                    egui::ComboBox::from_id_salt("mesh_import_axis")
+                                        .selected_text(settings.axis.label())
+                                        .show_ui(ui, |ui| {
+                                            for axis in [MeshAxisConvention::YUp, MeshAxisConvention::ZUp] {
+                                                ui.selectable_value(&mut settings.axis, axis, axis.label());
+                                            }
+                                        });
+                                });
+                                ui.checkbox(&mut settings.merge_primitives, tr!("ui.merge_primitives"))
+                                    .on_hover_text(tr!("ui.merge_primitives_hint"));
+                                ui.horizontal(|ui| {
+                                    let trimmed = import_path.trim();
+                                    let enabled = !trimmed.is_empty() && !trimmed.ends_with('/');
+                                    let request = |spawn_hierarchy| MeshSceneImportRequest {
+                                        path: trimmed.to_string(),
+                                        settings: settings.clone(),
+                                        spawn_hierarchy,
+                                    };
+                                    if ui
+                                        .add_enabled(enabled, egui::Button::new(tr!("ui.import_parts")))
+                                        .clicked()
+                                    {
+                                        actions.import_mesh_scene = Some(request(false));
+                                    }
+                                    if ui
+                                        .add_enabled(
+                                            enabled,
+                                            egui::Button::new(tr!("ui.import_as_hierarchy")),
+                                        )
+                                        .clicked()
+                                    {
+                                        actions.import_mesh_scene = Some(request(true));
+                                    }
+                                });
+                                ui.ctx().data_mut(|d| {
+                                    d.insert_persisted(loaded_id, import_path.clone());
+                                    d.insert_persisted(path_id, import_path);
+                                    d.insert_persisted(settings_id, settings);
+                                });
+                            });
+                            match mesh_control_mode_state {
+                                MeshControlMode::Orbit => {
+                                    ui.label(tr!(
+                                        "ui.orbit_radius",
+                                        mesh_orbit_radius = format!("{:.2}", mesh_orbit_radius)
+                                    ));
+                                }
+                                MeshControlMode::Freefly => {
+                                    ui.label(tr!(
+                                        "ui.free_fly_speed",
+                                        mesh_freefly_speed_state = format!("{:.2}", mesh_freefly_speed_state)
+                                    ));
+                                }
+                                MeshControlMode::Disabled => {
+                                    ui.label(tr!(
+                                        "ui.orbit_radius",
+                                        mesh_orbit_radius = format!("{:.2}", mesh_orbit_radius)
+                                    ));
+                                }
+                            }
+                            if let Some(status) = &mesh_status_message {
+                                ui.label(status);
+                            } else {
+                                match mesh_control_mode_state {
+                                    MeshControlMode::Disabled => {
+                                        ui.label(tr!("ui.scripted_orbit_animates_the_camera"));
+                                    }
+                                    MeshControlMode::Orbit => {
+                                        ui.label(tr!("ui.right_drag_to_orbit_scroll_to"));
+                                    }
+                                    MeshControlMode::Freefly => {
+                                        ui.label(tr!("ui.hold_rmb_to_look_use_wasd"));
+                                    }
+                                }
+                            }
+                        });
+                    mesh_preview_open = mesh_preview_header.openness > 0.0;
 
                     ui.separator();
-                    ui.heading("Scene");
+                    ui.heading(tr!("panel.scene"));
                     ui.horizontal(|ui| {
-                        ui.label("Path");
+                        ui.label(tr!("ui.path"));
                         ui.text_edit_singleline(&mut ui_scene_path);
-                        ui.menu_button("Recent", |menu| {
+                        ui.menu_button(tr!("ui.recent"), |menu| {
                             if scene_history_list.is_empty() {
-                                menu.label("No saved paths yet");
+                                menu.label(tr!("ui.no_saved_paths_yet"));
                             } else {
                                 for entry in scene_history_list.iter() {
-                                    if menu.button(entry).clicked() {
+                                    let clicked = menu
+                                        .horizontal(|row| {
+                                            if let Some(texture) = thumbnails.get(Path::new(entry.as_str())) {
+                                                row.image((texture.id(), egui::vec2(32.0, 32.0)));
+                                            }
+                                            row.button(entry).clicked()
+                                        })
+                                        .inner;
+                                    if clicked {
                                         ui_scene_path = entry.clone();
                                         menu.close();
                                     }
                                 }
                                 menu.separator();
-                                if menu.button("Clear history").clicked() {
+                                if menu.button(tr!("ui.clear_history")).clicked() {
                                     clear_scene_history = true;
                                     menu.close();
                                 }
                             }
                         });
-                        if ui.button("Save").clicked() {
+                        if ui.button(tr!("ui.save")).clicked() {
                             actions.save_scene = true;
                         }
-                        if ui.button("Load").clicked() {
+                        if ui.button(tr!("ui.load")).clicked() {
                             actions.load_scene = true;
                         }
                     });
+                    ui.horizontal(|ui| {
+                        let export = egui::Button::new(tr!("ui.export_selection_as_scene"));
+                        if ui
+                            .add_enabled(selection_count > 0, export)
+                            .on_hover_text(tr!("ui.save_the_selection_and_hint"))
+                            .clicked()
+                        {
+                            actions.export_selection = Some(ui_fragment_include_children);
+                        }
+                        ui.checkbox(&mut ui_fragment_include_children, tr!("ui.include_children"));
+                    });
+                    if ui
+                        .button(tr!("ui.import_scene_into_current"))
+                        .on_hover_text(tr!("ui.import_scene_into_current_hint"))
+                        .clicked()
+                    {
+                        actions.import_scene = true;
+                    }
                     if let Some(status) = ui_scene_status.as_ref() {
                         ui.label(status);
                     }
-                    ui.collapsing("Dependency Summary", |ui| {
+                    if !scene_chunks.is_empty() {
+                        let loaded = scene_chunks.iter().filter(|chunk| chunk.loaded).count();
+                        let chunks_title =
+                            tr!("ui.scene_chunks_loaded", loaded = loaded, total = scene_chunks.len());
+                        ui.collapsing(chunks_title, |ui| {
+                            for chunk in &scene_chunks {
+                                ui.horizontal(|ui| {
+                                    let (state, color) = if chunk.error.is_some() {
+                                        ("error", egui::Color32::from_rgb(220, 80, 80))
+                                    } else if chunk.loaded {
+                                        ("loaded", egui::Color32::from_rgb(120, 200, 120))
+                                    } else {
+                                        ("unloaded", egui::Color32::GRAY)
+                                    };
+                                    ui.colored_label(color, state);
+                                    ui.label(&chunk.key);
+                                    if chunk.loaded {
+                                        ui.small(tr!(
+                                            "ui.entity_count_entities",
+                                            entity_count = chunk.entity_count
+                                        ));
+                                    }
+                                    if ui.small_button(tr!("ui.open")).on_hover_text(&chunk.path).clicked() {
+                                        actions.open_scene_chunk = Some(chunk.key.clone());
+                                    }
+                                });
+                                ui.small(format!(
+                                    "  ({:.1}, {:.1}) .. ({:.1}, {:.1})",
+                                    chunk.min.x, chunk.min.y, chunk.max.x, chunk.max.y
+                                ));
+                                if let Some(error) = chunk.error.as_ref() {
+                                    ui.colored_label(egui::Color32::from_rgb(220, 80, 80), error);
+                                }
+                            }
+                        });
+                    }
+                    panel_header("ui.dependency_summary").show(ui, |ui| {
                         if atlas_dependencies.is_empty() {
-                            ui.small("Atlases: none retained");
+                            ui.small(tr!("ui.atlases_none_retained"));
                         } else {
-                            ui.label(format!(
-                                "Atlases retained: {} (persistent: {})",
-                                atlas_dependencies.len(),
-                                atlas_persistent_count
+                            ui.label(tr!(
+                                "ui.atlases_retained_persistent",
+                                atlas_dependencies = atlas_dependencies.len(),
+                                atlas_persistent_count = atlas_persistent_count
                             ));
                             for entry in atlas_dependencies.iter() {
-                                let scope = if entry.persistent { "persistent" } else { "scene" };
+                                let scope = if entry.persistent {
+                                    tr!("ui.scope_persistent")
+                                } else {
+                                    tr!("ui.scope_scene")
+                                };
                                 let color = if entry.loaded {
                                     egui::Color32::LIGHT_GREEN
                                 } else {
                                     egui::Color32::from_rgb(220, 120, 120)
                                 };
-                                let status_label = if entry.loaded { "loaded" } else { "missing" };
+                                let status_label = if entry.loaded {
+                                    tr!("ui.dependency_loaded")
+                                } else {
+                                    tr!("ui.dependency_missing")
+                                };
                                 let path_display = entry.path.as_deref().unwrap_or("n/a");
                                 ui.horizontal(|ui| {
                                     ui.colored_label(
@@ -2943,69 +4225,89 @@ impl App {
                                         ),
                                     );
                                     if !entry.loaded {
-                                        if ui.button("Retain").clicked() {
+                                        if ui.button(tr!("ui.retain")).clicked() {
                                             actions
                                                 .retain_atlases
                                                 .push((entry.key.clone(), entry.path.clone()));
                                         }
                                         if entry.path.is_none() {
-                                            ui.small("no recorded path");
+                                            ui.small(tr!("ui.no_recorded_path"));
                                         }
                                     }
                                 });
                             }
                         }
                         if mesh_dependencies.is_empty() {
-                            ui.small("Meshes: none retained");
+                            ui.small(tr!("ui.meshes_none_retained"));
                         } else {
                             ui.separator();
-                            ui.label(format!(
-                                "Meshes retained: {} (persistent: {})",
-                                mesh_dependencies.len(),
-                                mesh_persistent_count
+                            ui.label(tr!(
+                                "ui.meshes_retained_persistent",
+                                mesh_dependencies = mesh_dependencies.len(),
+                                mesh_persistent_count = mesh_persistent_count
                             ));
                             for entry in mesh_dependencies.iter() {
-                                let scope = if entry.persistent { "persistent" } else { "scene" };
+                                let scope = if entry.persistent {
+                                    tr!("ui.scope_persistent")
+                                } else {
+                                    tr!("ui.scope_scene")
+                                };
                                 let color = if entry.loaded {
                                     egui::Color32::LIGHT_GREEN
                                 } else {
                                     egui::Color32::from_rgb(220, 120, 120)
                                 };
-                                let status_label = if entry.loaded { "loaded" } else { "missing" };
+                                let status_label = if entry.loaded {
+                                    tr!("ui.dependency_loaded")
+                                } else {
+                                    tr!("ui.dependency_missing")
+                                };
                                 let path_display = entry.path.as_deref().unwrap_or("n/a");
+                                let triangles = entry
+                                    .triangle_count
+                                    .map_or_else(|| "n/a".to_string(), |count| count.to_string());
                                 ui.horizontal(|ui| {
                                     ui.colored_label(
                                         color,
                                         format!(
-                                            "- {} ({}, refs={}, {}, path={})",
-                                            entry.key, scope, entry.ref_count, status_label, path_display
+                                            "- {} ({}, refs={}, tris={}, {}, path={})",
+                                            entry.key,
+                                            scope,
+                                            entry.ref_count,
+                                            triangles,
+                                            status_label,
+                                            path_display
                                         ),
                                     );
                                     if !entry.loaded {
-                                        if ui.button("Retain").clicked() {
+                                        if ui.button(tr!("ui.retain")).clicked() {
                                             actions
                                                 .retain_meshes
                                                 .push((entry.key.clone(), entry.path.clone()));
                                         }
                                         if entry.path.is_none() {
-                                            ui.small("no recorded path");
+                                            ui.small(tr!("ui.no_recorded_path"));
                                         }
                                     }
                                 });
                             }
                         }
                         if clip_dependencies.is_empty() {
-                            ui.small("Clips: none retained");
+                            ui.small(tr!("ui.clips_none_retained"));
                         } else {
                             ui.separator();
-                            ui.label(format!("Clips retained: {}", clip_dependencies.len()));
+                            ui.label(tr!("ui.clips_retained", clip_dependencies = clip_dependencies.len()));
                             for entry in clip_dependencies.iter() {
                                 let color = if entry.loaded {
                                     egui::Color32::LIGHT_GREEN
                                 } else {
                                     egui::Color32::from_rgb(220, 120, 120)
                                 };
-                                let status_label = if entry.loaded { "loaded" } else { "missing" };
+                                let status_label = if entry.loaded {
+                                    tr!("ui.dependency_loaded")
+                                } else {
+                                    tr!("ui.dependency_missing")
+                                };
                                 let path_display = entry.path.as_deref().unwrap_or("n/a");
                                 ui.horizontal(|ui| {
                                     ui.colored_label(
@@ -3013,11 +4315,11 @@ impl App {
                                         format!("- {} ({}, path={})", entry.key, status_label, path_display),
                                     );
                                     if !entry.loaded {
-                                        if ui.button("Retain").clicked() {
+                                        if ui.button(tr!("ui.retain")).clicked() {
                                             actions.retain_clips.push((entry.key.clone(), entry.path.clone()));
                                         }
                                         if entry.path.is_none() {
-                                            ui.small("no recorded path");
+                                            ui.small(tr!("ui.no_recorded_path"));
                                         }
                                     }
                                 });
@@ -3025,13 +4327,21 @@ impl App {
                         }
                         match (scene_dependency_data_available, environment_dependency.as_ref()) {
                             (true, Some(env_entry)) => {
-                                let scope = if env_entry.persistent { "persistent" } else { "scene" };
+                                let scope = if env_entry.persistent {
+                                    tr!("ui.scope_persistent")
+                                } else {
+                                    tr!("ui.scope_scene")
+                                };
                                 let color = if env_entry.loaded {
                                     egui::Color32::LIGHT_GREEN
                                 } else {
                                     egui::Color32::from_rgb(220, 120, 120)
                                 };
-                                let status_label = if env_entry.loaded { "loaded" } else { "missing" };
+                                let status_label = if env_entry.loaded {
+                                    tr!("ui.dependency_loaded")
+                                } else {
+                                    tr!("ui.dependency_missing")
+                                };
                                 let path_display = env_entry.path.as_deref().unwrap_or("n/a");
                                 ui.horizontal(|ui| {
                                     ui.colored_label(
@@ -3042,38 +4352,38 @@ impl App {
                                         ),
                                     );
                                     if !env_entry.loaded {
-                                        if ui.button("Retain").clicked() {
+                                        if ui.button(tr!("ui.retain")).clicked() {
                                             actions
                                                 .retain_environments
                                                 .push((env_entry.key.clone(), env_entry.path.clone()));
                                         }
                                         if env_entry.path.is_none() {
-                                            ui.small("no recorded path");
+                                            ui.small(tr!("ui.no_recorded_path"));
                                         }
                                     }
                                 });
                             }
                             (true, None) => {
-                                ui.small("Environment: none recorded");
+                                ui.small(tr!("ui.environment_none_recorded"));
                             }
                             (false, _) => {
-                                ui.small("Load or save a scene to populate environment dependencies.");
+                                ui.small(tr!("ui.load_or_save_a_scene_to"));
                             }
                         }
                         if !scene_dependency_data_available {
-                            ui.small("Load or save a scene to populate dependency details.");
+                            ui.small(tr!("ui.load_or_save_a_scene_to_populate_dependency"));
                         }
                     });
 
                     ui.separator();
-                    egui::CollapsingHeader::new("Lighting & Environment").default_open(false).show(
+                    panel_header("panel.lighting").default_open(false).show(
                         ui,
                         |ui| {
                             let mut lighting_dirty = false;
                             let default_dir = glam::Vec3::new(0.4, 0.8, 0.35).normalize();
                             let mut light_dir = ui_light_direction;
                             ui.horizontal(|ui| {
-                                ui.label("Direction (XYZ)");
+                                ui.label(tr!("ui.direction_xyz"));
                                 let mut changed = false;
                                 changed |= ui
                                     .add(egui::DragValue::new(&mut light_dir.x).speed(0.01).range(-1.0..=1.0))
@@ -3098,7 +4408,7 @@ impl App {
                                 }
                             });
                             ui.horizontal(|ui| {
-                                ui.label("Color");
+                                ui.label(tr!("ui.color"));
                                 let mut color_arr = ui_light_color.to_array();
                                 if ui.color_edit_button_rgb(&mut color_arr).changed() {
                                     ui_light_color = Vec3::from_array(color_arr);
@@ -3106,7 +4416,7 @@ impl App {
                                 }
                             });
                             ui.horizontal(|ui| {
-                                ui.label("Ambient");
+                                ui.label(tr!("ui.ambient"));
                                 let mut ambient_arr = ui_light_ambient.to_array();
                                 if ui.color_edit_button_rgb(&mut ambient_arr).changed() {
                                     ui_light_ambient = Vec3::from_array(ambient_arr);
@@ -3114,9 +4424,10 @@ impl App {
                                 }
                             });
                             if ui
-                                .add(
+                                .add_enabled(
+                                    !ui_auto_exposure.enabled,
                                     egui::Slider::new(&mut ui_light_exposure, 0.1..=5.0)
-                                        .text("Exposure")
+                                        .text(tr!("ui.exposure"))
                                         .logarithmic(true),
                                 )
                                 .changed()
@@ -3124,10 +4435,34 @@ impl App {
                                 ui_light_exposure = ui_light_exposure.clamp(0.1, 20.0);
                                 lighting_dirty = true;
                             }
+                            if ui.checkbox(&mut ui_auto_exposure.enabled, tr!("ui.auto_exposure")).changed() {
+                                lighting_dirty = true;
+                            }
+                            if ui_auto_exposure.enabled {
+                                if ui
+                                    .add(
+                                        egui::Slider::new(&mut ui_auto_exposure.adaptation_rate, 0.1..=10.0)
+                                            .text(tr!("ui.adaptation_rate"))
+                                            .suffix(" /s")
+                                            .logarithmic(true),
+                                    )
+                                    .changed()
+                                {
+                                    ui_auto_exposure.adaptation_rate =
+                                        ui_auto_exposure.adaptation_rate.clamp(0.1, 10.0);
+                                    lighting_dirty = true;
+                                }
+                                ui.label(tr!(
+                                    "ui.current_exposure_clamped_to",
+                                    effective_exposure = format!("{:.2}", effective_exposure),
+                                    min_exposure = format!("{:.2}", ui_auto_exposure.min_exposure),
+                                    max_exposure = format!("{:.2}", ui_auto_exposure.max_exposure)
+                                ));
+                            }
                             if ui
                                 .add(
                                     egui::Slider::new(&mut ui_shadow_distance, 5.0..=200.0)
-                                        .text("Shadow distance"),
+                                        .text(tr!("ui.shadow_distance")),
                                 )
                                 .changed()
                             {
@@ -3137,7 +4472,7 @@ impl App {
                             if ui
                                 .add(
                                     egui::Slider::new(&mut ui_shadow_bias, 0.0001..=0.02)
-                                        .text("Shadow bias")
+                                        .text(tr!("ui.shadow_bias"))
                                         .logarithmic(true),
                                 )
                                 .changed()
@@ -3148,7 +4483,7 @@ impl App {
                             if ui
                                 .add(
                                     egui::Slider::new(&mut ui_shadow_strength, 0.0..=1.0)
-                                        .text("Shadow strength"),
+                                        .text(tr!("ui.shadow_strength")),
                                 )
                                 .changed()
                             {
@@ -3161,7 +4496,7 @@ impl App {
                                         &mut ui_shadow_cascade_count,
                                         1..=MAX_SHADOW_CASCADES as u32,
                                     )
-                                    .text("Shadow cascades"),
+                                    .text(tr!("ui.shadow_cascades")),
                                 )
                                 .changed()
                             {
@@ -3169,7 +4504,7 @@ impl App {
                             }
                             let mut resolution_changed = false;
                             ui.horizontal(|ui| {
-                                ui.label("Shadow resolution");
+                                ui.label(tr!("ui.shadow_resolution"));
                                 if ui
                                     .add(
                                         egui::DragValue::new(&mut ui_shadow_resolution)
@@ -3188,7 +4523,7 @@ impl App {
                             if ui
                                 .add(
                                     egui::Slider::new(&mut ui_shadow_split_lambda, 0.0..=1.0)
-                                        .text("Cascade split bias"),
+                                        .text(tr!("ui.cascade_split_bias")),
                                 )
                                 .changed()
                             {
@@ -3198,7 +4533,7 @@ impl App {
                             if ui
                                 .add(
                                     egui::Slider::new(&mut ui_shadow_pcf_radius, 0.0..=4.0)
-                                        .text("PCF radius"),
+                                        .text(tr!("ui.pcf_radius")),
                                 )
                                 .changed()
                             {
@@ -3207,25 +4542,25 @@ impl App {
                             }
                             ui.separator();
                             let cluster_metrics = light_cluster_metrics;
-                            ui.label("Clustered light culling");
-                            ui.label(format!(
-                                "Runtime lights: {} visible / {} total (culled {})",
-                                cluster_metrics.visible_lights,
-                                cluster_metrics.total_lights,
-                                cluster_metrics.culled_lights()
+                            ui.label(tr!("ui.clustered_light_culling"));
+                            ui.label(tr!(
+                                "ui.runtime_lights_visible_total_culled",
+                                visible_lights = cluster_metrics.visible_lights,
+                                total_lights = cluster_metrics.total_lights,
+                                culled_lights = cluster_metrics.culled_lights()
                             ));
-                            ui.label(format!(
-                                "Grid: {}×{}×{} (active {} / {})",
-                                cluster_metrics.grid_dims[0],
-                                cluster_metrics.grid_dims[1],
-                                cluster_metrics.grid_dims[2],
-                                cluster_metrics.active_clusters,
-                                cluster_metrics.total_clusters
+                            ui.label(tr!(
+                                "ui.cluster_grid_runtime",
+                                x = cluster_metrics.grid_dims[0],
+                                y = cluster_metrics.grid_dims[1],
+                                z = cluster_metrics.grid_dims[2],
+                                active_clusters = cluster_metrics.active_clusters,
+                                total_clusters = cluster_metrics.total_clusters
                             ));
-                            ui.label(format!(
-                                "Avg lights/cluster: {:.2} (max {})",
-                                cluster_metrics.average_lights_per_cluster,
-                                cluster_metrics.max_lights_per_cluster
+                            ui.label(tr!(
+                                "ui.avg_lights_cluster_max",
+                                average = format!("{:.2}", cluster_metrics.average_lights_per_cluster),
+                                max = cluster_metrics.max_lights_per_cluster
                             ));
                             if cluster_metrics.overflow_clusters > 0 {
                                 ui.colored_label(
@@ -3236,7 +4571,7 @@ impl App {
                                     ),
                                 );
                             } else {
-                                ui.label("Cluster overflow events this frame: 0");
+                                ui.label(tr!("ui.cluster_overflow_events_this_frame_0"));
                             }
 
                             ui.separator();
@@ -3246,7 +4581,7 @@ impl App {
                                 .default_open(point_light_count > 0 && point_light_count <= 2)
                                 .show(ui, |ui| {
                                     if point_lights.is_empty() {
-                                        ui.label("No point lights configured.");
+                                        ui.label(tr!("ui.no_point_lights_configured"));
                                     }
                                     let mut removal: Option<usize> = None;
                                     for (index, light) in point_lights.iter_mut().enumerate() {
@@ -3255,7 +4590,7 @@ impl App {
                                             ui,
                                             |ui| {
                                                 ui.horizontal(|ui| {
-                                                    ui.label("Position");
+                                                    ui.label(tr!("ui.position"));
                                                     let mut changed = false;
                                                     changed |= ui
                                                         .add(
@@ -3280,7 +4615,7 @@ impl App {
                                                     }
                                                 });
                                                 ui.horizontal(|ui| {
-                                                    ui.label("Color");
+                                                    ui.label(tr!("ui.color"));
                                                     let mut color_arr = light.color.to_array();
                                                     if ui.color_edit_button_rgb(&mut color_arr).changed() {
                                                         light.color = Vec3::from_array(color_arr);
@@ -3290,7 +4625,7 @@ impl App {
                                                 if ui
                                                     .add(
                                                         egui::Slider::new(&mut light.radius, 0.1..=100.0)
-                                                            .text("Radius")
+                                                            .text(tr!("ui.radius"))
                                                             .logarithmic(true),
                                                     )
                                                     .changed()
@@ -3300,13 +4635,31 @@ impl App {
                                                 if ui
                                                     .add(
                                                         egui::Slider::new(&mut light.intensity, 0.0..=20.0)
-                                                            .text("Intensity"),
+                                                            .text(tr!("ui.intensity")),
                                                     )
                                                     .changed()
                                                 {
                                                     point_lights_dirty = true;
                                                 }
-                                                if ui.button("Remove light").clicked() {
+                                                let previous_falloff = light.falloff;
+                                                ui.horizontal(|ui| {
+                                                    ui.label(tr!("ui.falloff"));
+                                                    egui::ComboBox::from_id_salt(("point_light_falloff", index))
+                                                        .selected_text(light.falloff.label())
+                                                        .show_ui(ui, |ui| {
+                                                            for falloff in PointLightFalloff::ALL {
+                                                                ui.selectable_value(
+                                                                    &mut light.falloff,
+                                                                    falloff,
+                                                                    falloff.label(),
+                                                                );
+                                                            }
+                                                        });
+                                                });
+                                                if light.falloff != previous_falloff {
+                                                    point_lights_dirty = true;
+                                                }
+                                                if ui.button(tr!("ui.remove_light")).clicked() {
                                                     removal = Some(index);
                                                 }
                                             },
@@ -3319,7 +4672,7 @@ impl App {
                                         point_lights.remove(idx);
                                         point_lights_dirty = true;
                                     }
-                                    if ui.button("Add point light").clicked() {
+                                    if ui.button(tr!("ui.add_point_light")).clicked() {
                                         let default_position = Vec3::new(0.0, 2.0, 0.0);
                                         let default_color = Vec3::splat(1.0);
                                         point_lights.push(ScenePointLight::new(
@@ -3333,9 +4686,9 @@ impl App {
                                 });
 
                             ui.separator();
-                            ui.label("Environment");
+                            ui.label(tr!("ui.environment"));
                             if environment_options.is_empty() {
-                                ui.label("No environments available.");
+                                ui.label(tr!("ui.no_environments_available"));
                             } else {
                                 let mut selected_environment = active_environment.clone();
                                 let current_label = environment_options
@@ -3361,15 +4714,20 @@ impl App {
                             if ui
                                 .add(
                                     egui::Slider::new(&mut ui_environment_intensity, 0.0..=5.0)
-                                        .text("Environment intensity")
+                                        .text(tr!("ui.environment_intensity"))
                                         .logarithmic(true),
                                 )
                                 .changed()
                             {
                                 ui_environment_intensity = ui_environment_intensity.clamp(0.0, 20.0);
                             }
+                            ui.add(
+                                egui::Slider::new(&mut ui_environment_scroll_speed, -0.1..=0.1)
+                                    .text(tr!("ui.environment_scroll_widths_s")),
+                            )
+                            .on_hover_text(tr!("ui.environment_scroll_widths_s_hint"));
 
-                            if ui.button("Reset lighting").clicked() {
+                            if ui.button(tr!("ui.reset_lighting")).clicked() {
                                 let default_shadow = SceneShadowData::default();
                                 ui_light_direction = default_dir;
                                 ui_light_color = Vec3::new(1.05, 0.98, 0.92);
@@ -3382,7 +4740,9 @@ impl App {
                                 ui_shadow_resolution = default_shadow.resolution;
                                 ui_shadow_split_lambda = default_shadow.split_lambda;
                                 ui_shadow_pcf_radius = default_shadow.pcf_radius;
+                                ui_auto_exposure = AutoExposureSettings::default();
                                 ui_environment_intensity = 1.0;
+                                ui_environment_scroll_speed = 0.0;
                                 lighting_dirty = true;
                                 point_lights.clear();
                                 point_lights_dirty = true;
@@ -3394,69 +4754,80 @@ impl App {
                     );
 
                     ui.separator();
-                    egui::CollapsingHeader::new("Spawn & Emitters").default_open(false).show(ui, |ui| {
-                        ui.add(egui::Slider::new(&mut ui_cell_size, 0.05..=0.8).text("Spatial cell size"));
-                        ui.add(egui::Slider::new(&mut ui_spawn_per_press, 1..=5000).text("Spawn per press"));
+                    panel_header("panel.spawn_emitters").default_open(false).show(ui, |ui| {
+                        ui.add(
+                            egui::Slider::new(&mut ui_cell_size, 0.05..=0.8)
+                                .text(tr!("ui.spatial_cell_size")),
+                        );
+                        ui.add(
+                            egui::Slider::new(&mut ui_spawn_per_press, 1..=5000)
+                                .text(tr!("ui.spawn_per_press")),
+                        );
                         ui.add(
                             egui::Slider::new(&mut ui_auto_spawn_rate, 0.0..=5000.0)
-                                .text("Auto-spawn per second"),
+                                .text(tr!("ui.auto_spawn_per_second")),
                         );
                         ui.add(
                             egui::Slider::new(&mut ui_emitter_rate, 0.0..=200.0)
-                                .text("Emitter rate (particles/s)"),
+                                .text(tr!("ui.emitter_rate_particles_s")),
                         );
                         ui.add(
                             egui::Slider::new(&mut ui_emitter_spread, 0.0..=std::f32::consts::PI)
-                                .text("Emitter spread (rad)"),
+                                .text(tr!("ui.emitter_spread_rad")),
+                        );
+                        ui.add(
+                            egui::Slider::new(&mut ui_emitter_speed, 0.0..=3.0).text(tr!("ui.emitter_speed")),
                         );
-                        ui.add(egui::Slider::new(&mut ui_emitter_speed, 0.0..=3.0).text("Emitter speed"));
                         ui.add(
                             egui::Slider::new(&mut ui_emitter_lifetime, 0.1..=5.0)
-                                .text("Particle lifetime (s)"),
+                                .text(tr!("ui.particle_lifetime_s")),
                         );
                         ui.add(
                             egui::Slider::new(&mut ui_emitter_start_size, 0.01..=0.5)
-                                .text("Particle start size"),
+                                .text(tr!("ui.particle_start_size")),
                         );
                         ui.add(
-                            egui::Slider::new(&mut ui_emitter_end_size, 0.01..=0.5).text("Particle end size"),
+                            egui::Slider::new(&mut ui_emitter_end_size, 0.01..=0.5)
+                                .text(tr!("ui.particle_end_size")),
                         );
                         ui.horizontal(|ui| {
-                            ui.label("Start color");
+                            ui.label(tr!("ui.start_color"));
                             ui.color_edit_button_rgba_unmultiplied(&mut ui_emitter_start_color);
                         });
                         ui.horizontal(|ui| {
-                            ui.label("End color");
+                            ui.label(tr!("ui.end_color"));
                             ui.color_edit_button_rgba_unmultiplied(&mut ui_emitter_end_color);
                         });
-                        ui.add(egui::Slider::new(&mut ui_root_spin, -5.0..=5.0).text("Root spin speed"));
+                        ui.add(
+                            egui::Slider::new(&mut ui_root_spin, -5.0..=5.0).text(tr!("ui.root_spin_speed")),
+                        );
                         ui.horizontal(|ui| {
-                            if ui.button("Spawn demo").clicked() {
+                            if ui.button(tr!("ui.spawn_demo")).clicked() {
                                 actions.spawn_demo = true;
                             }
-                            if ui.button("Spawn now").clicked() {
+                            if ui.button(tr!("ui.spawn_now")).clicked() {
                                 actions.spawn_now = true;
                             }
-                            if ui.button("Clear particles").clicked() {
+                            if ui.button(tr!("ui.clear_particles")).clicked() {
                                 actions.clear_particles = true;
                             }
-                            if ui.button("Reset world").clicked() {
+                            if ui.button(tr!("ui.reset_world")).clicked() {
                                 actions.reset_world = true;
                             }
                         });
                         ui.separator();
-                        ui.label("Particle caps");
+                        ui.label(tr!("ui.particle_caps"));
                         ui.add(
                             egui::Slider::new(&mut ui_particle_max_total, 0..=10_000)
-                                .text("Max total particles"),
+                                .text(tr!("ui.max_total_particles")),
                         );
                         ui.add(
                             egui::Slider::new(&mut ui_particle_max_spawn_per_frame, 0..=2_000)
-                                .text("Max spawn per frame"),
+                                .text(tr!("ui.max_spawn_per_frame")),
                         );
                         ui.add(
                             egui::Slider::new(&mut ui_particle_max_emitter_backlog, 0.0..=256.0)
-                                .text("Emitter backlog cap"),
+                                .text(tr!("ui.emitter_backlog_cap")),
                         );
                         if ui_particle_max_spawn_per_frame > ui_particle_max_total {
                             ui_particle_max_spawn_per_frame = ui_particle_max_total;
@@ -3478,7 +4849,7 @@ impl App {
                     };
                     egui::CollapsingHeader::new(events_header_label).default_open(false).show(ui, |ui| {
                         if recent_events.is_empty() {
-                            ui.label("No events recorded");
+                            ui.label(tr!("ui.no_events_recorded"));
                         } else {
                             const MAX_EVENT_ROWS: usize = 6;
                             for event in recent_events.iter().rev().take(MAX_EVENT_ROWS) {
@@ -3487,25 +4858,35 @@ impl App {
                             }
                             let remaining = recent_events.len().saturating_sub(MAX_EVENT_ROWS);
                             if remaining > 0 {
-                                ui.small(format!("... {remaining} older events hidden"));
+                                ui.small(tr!("ui.older_events_hidden", remaining = remaining));
                             }
                         }
                     });
 
                     ui.separator();
-                    ui.heading("Plugins");
+                    ui.heading(tr!("panel.plugins"));
                     if let Some(error) = plugin_manifest_error.as_deref() {
                         ui.colored_label(
                             egui::Color32::from_rgb(230, 120, 120),
                             format!("Manifest error: {error}"),
                         );
                     }
-                    if ui.button("Reload plugins").clicked() {
-                        actions.reload_plugins = true;
-                    }
-                    ui.small("Rebuild plugin cdylibs, then click reload to rescan manifest entries.");
+                    let any_dynamic_loaded = plugin_statuses.iter().any(|status| status.dynamic);
+                    ui.horizontal(|ui| {
+                        if ui.button(tr!("ui.reload_plugins")).clicked() {
+                            actions.reload_plugins = true;
+                        }
+                        if ui
+                            .add_enabled(any_dynamic_loaded, egui::Button::new(tr!("ui.unload_all_plugins")))
+                            .on_hover_text(tr!("ui.unload_all_plugins_hint"))
+                            .clicked()
+                        {
+                            actions.unload_plugins = true;
+                        }
+                    });
+                    ui.small(tr!("ui.rebuild_plugin_cdylibs_then_click_reload"));
                     ui.small(
-                        "Toggle entries below to update config/plugins.json without leaving the editor.",
+                        tr!("ui.toggle_entries_below_to_update_config"),
                     );
                     let status_slice: &[PluginStatus] = plugin_statuses.as_ref();
                     let capability_metrics = plugin_capability_metrics.as_ref();
@@ -3523,11 +4904,11 @@ impl App {
                     }
                     builtin_statuses.sort_by(|a, b| a.name.cmp(&b.name));
                     if let Some(path) = plugin_manifest_path.as_deref() {
-                        ui.small(format!("Manifest: {path}"));
+                        ui.small(tr!("ui.manifest_at", path = path));
                     }
                     if let Some(entries) = plugin_manifest_entries.as_ref() {
                         if entries.is_empty() {
-                            ui.label("No dynamic plugins listed in manifest.");
+                            ui.label(tr!("ui.no_dynamic_plugins_listed_in_manifest"));
                         } else {
                             for entry in entries.iter() {
                                 let plugin_name = entry.name.clone();
@@ -3555,33 +4936,41 @@ impl App {
                                                 capability_metrics.get(&status.name),
                                             );
                                         } else if let Some(version) = entry.version.as_deref() {
-                                            ui.small(format!("v{} (manifest)", version));
+                                            ui.small(tr!("ui.manifest_version", version = version));
                                         } else {
-                                            ui.small("not loaded");
+                                            ui.small(tr!("ui.not_loaded"));
                                         }
                                     });
                                     if !entry.path.trim().is_empty() {
-                                        ui.small(format!("Path: {}", entry.path));
+                                        ui.small(tr!("ui.path_at", path = entry.path));
                                     }
                                     if let Some(status) = status.as_ref() {
                                         let status = *status;
                                         if !status.depends_on.is_empty() {
-                                            ui.small(format!("Depends on: {}", status.depends_on.join(", ")));
+                                            ui.small(tr!(
+                                                "ui.depends_on",
+                                                plugins = status.depends_on.join(", ")
+                                            ));
                                         }
                                         if !status.provides.is_empty() {
-                                            ui.small(format!("Provides: {}", status.provides.join(", ")));
+                                            ui.small(tr!(
+                                                "ui.provides",
+                                                plugins = status.provides.join(", ")
+                                            ));
                                         }
+                                        plugin_dispatch_ui(ui, status, &mut actions);
+                                        plugin_quarantine_ui(ui, status, &mut actions);
                                     }
                                     if !entry.requires_features.is_empty() {
-                                        ui.small(format!(
-                                            "Requires (manifest): {}",
-                                            entry.requires_features.join(", ")
+                                        ui.small(tr!(
+                                            "ui.requires_manifest",
+                                            features = entry.requires_features.join(", ")
                                         ));
                                     }
                                     if !entry.provides_features.is_empty() {
-                                        ui.small(format!(
-                                            "Provides (manifest): {}",
-                                            entry.provides_features.join(", ")
+                                        ui.small(tr!(
+                                            "ui.provides_manifest",
+                                            features = entry.provides_features.join(", ")
                                         ));
                                     }
                                     if status.is_none() {
@@ -3592,6 +4981,7 @@ impl App {
                                             capability_metrics.get(&plugin_name),
                                         );
                                     }
+                                    plugin_config_ui(ui, &plugin_name, entry.config.as_ref(), &mut actions);
                                     plugin_debug_ui(
                                         ui,
                                         &plugin_name,
@@ -3611,11 +5001,11 @@ impl App {
                             }
                         }
                     } else {
-                        ui.label("No plugin manifest loaded.");
+                        ui.label(tr!("ui.no_plugin_manifest_loaded"));
                     }
                     if !dynamic_statuses.is_empty() {
                         ui.separator();
-                        ui.small("Dynamic plugins without manifest entries:");
+                        ui.small(tr!("ui.dynamic_plugins_without_manifest_entries"));
                         for status in dynamic_statuses.values() {
                             let status = *status;
                             let (color, summary) = plugin_status_summary(status);
@@ -3623,11 +5013,13 @@ impl App {
                                 format!("{} v{}", status.name, status.version.as_deref().unwrap_or("n/a"));
                             ui.colored_label(color, format!("{label} - {summary}"));
                             if !status.depends_on.is_empty() {
-                                ui.small(format!("Depends on: {}", status.depends_on.join(", ")));
+                                ui.small(tr!("ui.depends_on", plugins = status.depends_on.join(", ")));
                             }
                             if !status.provides.is_empty() {
-                                ui.small(format!("Provides: {}", status.provides.join(", ")));
+                                ui.small(tr!("ui.provides", plugins = status.provides.join(", ")));
                             }
+                            plugin_dispatch_ui(ui, status, &mut actions);
+                            plugin_quarantine_ui(ui, status, &mut actions);
                             show_capability_info(
                                 ui,
                                 &status.capabilities,
@@ -3670,14 +5062,16 @@ impl App {
                                     let (color, summary) = plugin_status_summary(status);
                                     ui.colored_label(color, summary);
                                     let version_label = status.version.as_deref().unwrap_or("n/a");
-                                    ui.small(format!("v{} (built-in)", version_label));
+                                    ui.small(tr!("ui.builtin_version", version_label = version_label));
                                 });
                                 if !status.depends_on.is_empty() {
-                                    ui.small(format!("Depends on: {}", status.depends_on.join(", ")));
+                                    ui.small(tr!("ui.depends_on", plugins = status.depends_on.join(", ")));
                                 }
                                 if !status.provides.is_empty() {
-                                    ui.small(format!("Provides: {}", status.provides.join(", ")));
+                                    ui.small(tr!("ui.provides", plugins = status.provides.join(", ")));
                                 }
+                                plugin_dispatch_ui(ui, status, &mut actions);
+                                plugin_quarantine_ui(ui, status, &mut actions);
                                 show_capability_info(
                                     ui,
                                     &status.capabilities,
@@ -3702,39 +5096,54 @@ impl App {
                             }
                         }
                         if manifest_loaded {
-                            ui.small("Built-in plugin changes take effect after restarting the engine.");
+                            ui.small(tr!("ui.built_in_plugin_changes_take_effect"));
                         } else {
-                            ui.small("Load config/plugins.json to edit built-in toggles.");
+                            ui.small(tr!("ui.load_config_plugins_json_to_edit"));
                         }
                     } else if !plugin_manifest_loaded && dynamic_statuses.is_empty() {
-                        ui.label("No plugins reported");
+                        ui.label(tr!("ui.no_plugins_reported"));
                     }
 
                     ui.separator();
-                    ui.heading("GPU Timings");
+                    ui.heading(tr!("panel.draw_calls"));
+                    if let Some(stats) = render_stats {
+                        ui.label(tr!(
+                            "ui.draw_call_breakdown",
+                            draw_calls = stats.draw_calls(),
+                            sprite_draw_calls = stats.sprite_draw_calls,
+                            mesh_draw_calls = stats.mesh_draw_calls,
+                            shadow_draw_calls = stats.shadow_draw_calls
+                        ));
+                        ui.label(tr!("ui.triangles", total_triangles = stats.total_triangles));
+                    } else {
+                        ui.small(tr!("ui.analytics_plugin_not_loaded_render_stats"));
+                    }
+
+                    ui.separator();
+                    ui.heading(tr!("panel.gpu_timings"));
                     if !gpu_timing_supported {
-                        ui.small("Device does not support GPU timestamp queries.");
+                        ui.small(tr!("ui.device_does_not_support_gpu_timestamp"));
                     } else if !gpu_timing_enabled {
                         let mut toggle = false;
-                        if ui.checkbox(&mut toggle, "Enable GPU timing").changed() {
+                        if ui.checkbox(&mut toggle, tr!("ui.enable_gpu_timing")).changed() {
                             actions.gpu_timing_enable = Some(true);
                         }
-                        ui.small("GPU timing disabled (editor.gpu_timing = false). Enable it to capture samples.");
+                        ui.small(tr!("ui.gpu_timing_disabled_editor_gpu_timing"));
                         if let Some(status) = gpu_metrics_status.as_ref() {
                             ui.small(status.as_str());
                         }
                     } else if gpu_history_empty {
                         let mut toggle = true;
-                        if ui.checkbox(&mut toggle, "Enable GPU timing").changed() {
+                        if ui.checkbox(&mut toggle, tr!("ui.enable_gpu_timing")).changed() {
                             actions.gpu_timing_enable = Some(toggle);
                         }
-                        ui.small("No GPU timing samples captured yet.");
+                        ui.small(tr!("ui.no_gpu_timing_samples_captured_yet"));
                         if let Some(status) = gpu_metrics_status.as_ref() {
                             ui.small(status.as_str());
                         }
                     } else {
                         let mut toggle = true;
-                        if ui.checkbox(&mut toggle, "Enable GPU timing").changed() {
+                        if ui.checkbox(&mut toggle, tr!("ui.enable_gpu_timing")).changed() {
                             actions.gpu_timing_enable = Some(toggle);
                         }
                         if !gpu_timing_snapshot.is_empty() {
@@ -3743,15 +5152,17 @@ impl App {
                                     .get(&timing.label)
                                     .map(|(sum, count)| sum / (*count as f32))
                                     .unwrap_or(timing.duration_ms);
-                                ui.label(format!(
-                                    "{:<20} {:>6.2} ms (avg {:>6.2} ms)",
-                                    timing.label, timing.duration_ms, average
+                                ui.label(tr!(
+                                    "ui.gpu_timing",
+                                    timing = format!("{:<20}", timing.label),
+                                    duration_ms = format!("{:>6.2}", timing.duration_ms),
+                                    average = format!("{:>6.2}", average)
                                 ));
                             }
                         } else {
-                            ui.label("No GPU timing snapshot available.");
+                            ui.label(tr!("ui.no_gpu_timing_snapshot_available"));
                         }
-                        if ui.button("Export GPU CSV").clicked() {
+                        if ui.button(tr!("ui.export_gpu_csv")).clicked() {
                             gpu_export_requested = true;
                         }
                         if let Some(status) = gpu_metrics_status.as_ref() {
@@ -3761,122 +5172,149 @@ impl App {
 
                     if !plugin_watchdog_log.is_empty() {
                         ui.separator();
-                        ui.label("Plugin Watchdog Alerts");
+                        ui.label(tr!("ui.plugin_watchdog_alerts"));
                         for event in plugin_watchdog_log.iter().take(6) {
                             let ago = event
                                 .timestamp
                                 .elapsed()
-                                .map(|duration| format!("{:.1}s ago", duration.as_secs_f32()))
-                                .unwrap_or_else(|_| "just now".to_string());
-                            ui.small(format!(
-                                "[{}] {} - {} ({:.1} ms) [{}]",
-                                ago, event.plugin, event.reason, event.elapsed_ms, event.last_request
+                                .map(|duration| tr!(
+                                    "ui.seconds_ago",
+                                    seconds = format!("{:.1}", duration.as_secs_f32())
+                                ))
+                                .unwrap_or_else(|_| tr!("ui.just_now"));
+                            ui.small(tr!(
+                                "ui.watchdog_event",
+                                ago = ago,
+                                plugin = event.plugin,
+                                reason = event.reason,
+                                elapsed_ms = format!("{:.1}", event.elapsed_ms),
+                                last_request = event.last_request
                             ));
                         }
                     }
 
                     if !plugin_asset_readback_log.is_empty() {
                         ui.separator();
-                        ui.label("Recent Asset Readbacks");
+                        ui.label(tr!("ui.recent_asset_readbacks"));
                         for event in plugin_asset_readback_log.iter().take(6) {
                             let ago = event
                                 .timestamp
                                 .elapsed()
-                                .map(|duration| format!("{:.1}s ago", duration.as_secs_f32()))
-                                .unwrap_or_else(|_| "just now".to_string());
-                            let cache_hint = if event.cache_hit { "cache" } else { "live" };
-                            ui.small(format!(
-                                "[{}] {} -> {}:{} ({} bytes, {:.1} ms, {})",
-                                ago,
-                                event.plugin,
-                                event.kind,
-                                event.target,
-                                event.bytes,
-                                event.duration_ms,
-                                cache_hint
+                                .map(|duration| tr!(
+                                    "ui.seconds_ago",
+                                    seconds = format!("{:.1}", duration.as_secs_f32())
+                                ))
+                                .unwrap_or_else(|_| tr!("ui.just_now"));
+                            let cache_hint = if event.cache_hit {
+                                tr!("ui.readback_cache")
+                            } else {
+                                tr!("ui.readback_live")
+                            };
+                            ui.small(tr!(
+                                "ui.readback_event",
+                                ago = ago,
+                                plugin = event.plugin,
+                                kind = event.kind,
+                                target = event.target,
+                                bytes = event.bytes,
+                                duration_ms = format!("{:.1}", event.duration_ms),
+                                cache_hint = cache_hint
                             ));
                         }
                     }
 
                     ui.separator();
-                    ui.heading("Prefab Shelf");
-                    if let Some(status) = prefab_status.as_ref() {
-                        let color = match status.kind {
-                            PrefabStatusKind::Info => egui::Color32::from_rgb(120, 180, 250),
-                            PrefabStatusKind::Success => egui::Color32::LIGHT_GREEN,
-                            PrefabStatusKind::Warning => egui::Color32::from_rgb(230, 200, 120),
-                            PrefabStatusKind::Error => egui::Color32::from_rgb(240, 120, 120),
-                        };
-                        ui.colored_label(color, status.message.as_str());
-                    }
-                    ui.horizontal(|ui| {
-                        ui.label("Name");
-                        ui.add(
-                            egui::TextEdit::singleline(&mut prefab_name_input).hint_text("e.g. crate_small"),
-                        );
-                    });
-                    ui.horizontal(|ui| {
-                        ui.label("Format");
-                        for format in [PrefabFormat::Json, PrefabFormat::Binary] {
-                            let enabled = format != PrefabFormat::Binary || binary_prefabs_enabled;
-                            let label = if enabled {
-                                format.label().to_string()
-                            } else {
-                                format!("{} (requires 'binary_scene')", format.label())
-                            };
-                            let button = egui::Button::new(label).selected(prefab_format == format);
-                            let response = ui.add_enabled(enabled, button);
-                            if enabled && response.clicked() {
-                                prefab_format = format;
+                    let prefab_shelf_header = panel_header("panel.prefab_shelf")
+                        .default_open(true)
+                        .open(layout_apply.as_ref().and_then(|preset| preset.panel_open(PANEL_PREFAB_SHELF)))
+                        .show(ui, |ui| {
+                            if let Some(status) = prefab_status.as_ref() {
+                                let color = match status.kind {
+                                    PrefabStatusKind::Info => egui::Color32::from_rgb(120, 180, 250),
+                                    PrefabStatusKind::Success => egui::Color32::LIGHT_GREEN,
+                                    PrefabStatusKind::Warning => egui::Color32::from_rgb(230, 200, 120),
+                                    PrefabStatusKind::Error => egui::Color32::from_rgb(240, 120, 120),
+                                };
+                                ui.colored_label(color, status.message.as_str());
                             }
-                        }
-                    });
-                    if !binary_prefabs_enabled {
-                        ui.small("Enable the 'binary_scene' Cargo feature to export binary prefabs.");
-                    }
-                    let drop_result =
-                        ui.dnd_drop_zone::<PrefabDragPayload, _>(egui::Frame::group(ui.style()), |ui| {
-                            ui.set_min_height(48.0);
-                            if selected_entity.is_some() {
-                                ui.label("Drag the selected entity here to save it as a prefab.");
-                            } else {
-                                ui.label("Select an entity, then drag it here to save a prefab.");
+                            ui.horizontal(|ui| {
+                                ui.label(tr!("ui.name"));
+                                ui.add(
+                                    egui::TextEdit::singleline(&mut prefab_name_input)
+                                        .hint_text(tr!("ui.prefab_name_example")),
+                                );
+                            });
+                            ui.horizontal(|ui| {
+                                ui.label(tr!("ui.format"));
+                                for format in [PrefabFormat::Json, PrefabFormat::Binary] {
+                                    let enabled = format != PrefabFormat::Binary || binary_prefabs_enabled;
+                                    let label = if enabled {
+                                        format.label().to_string()
+                                    } else {
+                                        format!("{} (requires 'binary_scene')", format.label())
+                                    };
+                                    let button = egui::Button::new(label).selected(prefab_format == format);
+                                    let response = ui.add_enabled(enabled, button);
+                                    if enabled && response.clicked() {
+                                        prefab_format = format;
+                                    }
+                                }
+                            });
+                            if !binary_prefabs_enabled {
+                                ui.small(tr!("ui.enable_the_binary_scene_cargo_feature"));
                             }
-                        });
-                    let dropped_prefab = drop_result.1;
-                    if let Some(payload) = dropped_prefab {
-                        let payload = *payload;
-                        let mut prefab_name = prefab_name_input.trim().to_string();
-                        if prefab_name.is_empty() {
-                            prefab_name = format!("prefab_{}", payload.entity.index());
-                            prefab_name_input = prefab_name.clone();
-                        }
-                        actions.save_prefab = Some(PrefabSaveRequest {
-                            entity: payload.entity,
-                            name: prefab_name,
-                            format: prefab_format,
-                        });
-                    }
-                    egui::ScrollArea::vertical().max_height(200.0).show(ui, |ui| {
-                        if prefab_entries.is_empty() {
-                            ui.small("No prefabs saved yet.");
-                        } else {
-                            for entry in prefab_entries.iter() {
-                                let entry_label = format!("{} ({})", entry.name, entry.format.short_label());
-                                let payload =
-                                    PrefabSpawnPayload { name: entry.name.clone(), format: entry.format };
-                                let drag_id = egui::Id::new((
-                                    "prefab_shelf_entry",
-                                    entry.name.as_str(),
-                                    entry.format.short_label(),
-                                ));
-                                ui.dnd_drag_source(drag_id, payload.clone(), |ui| {
-                                    ui.label(&entry_label);
-                                    ui.weak(entry.path_display.as_str());
+                            let drop_result =
+                                ui.dnd_drop_zone::<PrefabDragPayload, _>(egui::Frame::group(ui.style()), |ui| {
+                                    ui.set_min_height(48.0);
+                                    if selected_entity.is_some() {
+                                        ui.label(tr!("ui.drag_the_selected_entity_here_to"));
+                                    } else {
+                                        ui.label(tr!("ui.select_an_entity_then_drag_it"));
+                                    }
+                                });
+                            let dropped_prefab = drop_result.1;
+                            if let Some(payload) = dropped_prefab {
+                                let payload = *payload;
+                                let mut prefab_name = prefab_name_input.trim().to_string();
+                                if prefab_name.is_empty() {
+                                    prefab_name = format!("prefab_{}", payload.entity.index());
+                                    prefab_name_input = prefab_name.clone();
+                                }
+                                actions.save_prefab = Some(PrefabSaveRequest {
+                                    entity: payload.entity,
+                                    name: prefab_name,
+                                    format: prefab_format,
                                 });
                             }
-                        }
-                    });
+                            egui::ScrollArea::vertical().max_height(200.0).show(ui, |ui| {
+                                if prefab_entries.is_empty() {
+                                    ui.small(tr!("ui.no_prefabs_saved_yet"));
+                                } else {
+                                    for entry in prefab_entries.iter() {
+                                        let entry_label = format!("{} ({})", entry.name, entry.format.short_label());
+                                        let payload =
+                                            PrefabSpawnPayload { name: entry.name.clone(), format: entry.format };
+                                        let drag_id = egui::Id::new((
+                                            "prefab_shelf_entry",
+                                            entry.name.as_str(),
+                                            entry.format.short_label(),
+                                        ));
+                                        ui.dnd_drag_source(drag_id, payload.clone(), |ui| {
+                                            ui.horizontal(|ui| {
+                                                if let Some(texture) = thumbnails.get(&entry.path) {
+                                                    ui.image((texture.id(), egui::vec2(48.0, 48.0)));
+                                                }
+                                                ui.vertical(|ui| {
+                                                    ui.label(&entry_label);
+                                                    ui.weak(entry.path_display.as_str());
+                                                });
+                                            });
+                                        });
+                                    }
+                                }
+                            });
+                        });
+                    prefab_shelf_open = prefab_shelf_header.openness > 0.0;
 
                     ui.separator();
                     let parsed_triggers: Vec<ParsedAudioTrigger> =
@@ -3925,28 +5363,41 @@ impl App {
                         if let (Some(name), Some(rate)) =
                             (audio_health.device_name.as_deref(), audio_health.sample_rate_hz)
                         {
-                            ui.small(format!("Device: {name} @ {rate} Hz"));
+                            ui.small(tr!("ui.device_hz", name = name, rate = rate));
                         } else if let Some(name) = audio_health.device_name.as_deref() {
-                            ui.small(format!("Device: {name}"));
+                            ui.small(tr!("ui.device", name = name));
                         } else if let Some(rate) = audio_health.sample_rate_hz {
-                            ui.small(format!("Sample rate: {rate} Hz"));
+                            ui.small(tr!("ui.sample_rate_hz", rate = rate));
                         }
-                        if ui.checkbox(&mut audio_enabled, "Enable audio triggers").changed() {
+                        if ui.checkbox(&mut audio_enabled, tr!("ui.enable_audio_triggers")).changed() {
                             actions.audio_set_enabled = Some(audio_enabled);
                         }
+                        ui.horizontal(|ui| {
+                            let mut voice_limit = audio_health.voice_limit;
+                            ui.label(tr!("ui.voice_limit"));
+                            let response = ui.add(egui::DragValue::new(&mut voice_limit).range(1..=MAX_VOICE_COUNT));
+                            if response.changed() && voice_limit != audio_health.voice_limit {
+                                actions.audio_voice_count = Some(voice_limit);
+                            }
+                            ui.small(tr!(
+                                "ui.voices",
+                                active_voices = audio_health.active_voices,
+                                stolen_voices = audio_health.stolen_voices
+                            ));
+                        });
                         let mut spatial_enabled = audio_spatial_config.enabled;
                         let mut min_distance = audio_spatial_config.min_distance;
                         let mut max_distance = audio_spatial_config.max_distance;
                         let mut pan_width = audio_spatial_config.pan_width;
-                        ui.checkbox(&mut spatial_enabled, "Enable positional audio");
+                        ui.checkbox(&mut spatial_enabled, tr!("ui.enable_positional_audio"));
                         ui.horizontal(|ui| {
-                            ui.label("Min dist");
+                            ui.label(tr!("ui.min_dist"));
                             ui.add(egui::Slider::new(&mut min_distance, 0.0..=5.0).logarithmic(true));
-                            ui.label("Max dist");
+                            ui.label(tr!("ui.max_dist"));
                             ui.add(egui::Slider::new(&mut max_distance, 0.5..=50.0).logarithmic(true));
                         });
                         ui.horizontal(|ui| {
-                            ui.label("Pan width");
+                            ui.label(tr!("ui.pan_width"));
                             ui.add(egui::Slider::new(&mut pan_width, 1.0..=30.0));
                         });
                         if spatial_enabled != audio_spatial_config.enabled {
@@ -3961,6 +5412,36 @@ impl App {
                         if (pan_width - audio_spatial_config.pan_width).abs() > f32::EPSILON {
                             actions.audio_spatial_pan_width = Some(pan_width.max(0.1));
                         }
+                        let mut lpf_enabled = audio_spatial_config.lpf_cutoff_hz.is_some();
+                        let mut lpf_cutoff = audio_spatial_config.lpf_cutoff_hz.unwrap_or(8_000.0);
+                        let mut hpf_enabled = audio_spatial_config.hpf_cutoff_hz.is_some();
+                        let mut hpf_cutoff = audio_spatial_config.hpf_cutoff_hz.unwrap_or(80.0);
+                        ui.horizontal(|ui| {
+                            ui.checkbox(&mut lpf_enabled, tr!("ui.low_pass"));
+                            ui.add_enabled(
+                                lpf_enabled,
+                                egui::Slider::new(&mut lpf_cutoff, 20.0..=20_000.0)
+                                    .logarithmic(true)
+                                    .suffix(" Hz"),
+                            );
+                        });
+                        ui.horizontal(|ui| {
+                            ui.checkbox(&mut hpf_enabled, tr!("ui.high_pass"));
+                            ui.add_enabled(
+                                hpf_enabled,
+                                egui::Slider::new(&mut hpf_cutoff, 20.0..=20_000.0)
+                                    .logarithmic(true)
+                                    .suffix(" Hz"),
+                            );
+                        });
+                        let lpf = lpf_enabled.then_some(lpf_cutoff);
+                        if lpf != audio_spatial_config.lpf_cutoff_hz {
+                            actions.audio_lpf_cutoff = Some(lpf);
+                        }
+                        let hpf = hpf_enabled.then_some(hpf_cutoff);
+                        if hpf != audio_spatial_config.hpf_cutoff_hz {
+                            actions.audio_hpf_cutoff = Some(hpf);
+                        }
                         if !audio_plugin_present {
                             ui.colored_label(
                                 egui::Color32::from_rgb(200, 80, 80),
@@ -3992,11 +5473,11 @@ impl App {
                         if let Some(force_text) = peak_force_text.as_deref() {
                             ui.small(force_text);
                         }
-                        if ui.button("Clear audio log").clicked() {
+                        if ui.button(tr!("ui.clear_audio_log")).clicked() {
                             actions.audio_clear_log = true;
                         }
                         if parsed_triggers.is_empty() {
-                            ui.label("No audio triggers");
+                            ui.label(tr!("ui.no_audio_triggers"));
                         } else {
                             const MAX_AUDIO_ROWS: usize = 8;
                             for parsed in parsed_triggers.iter().rev().take(MAX_AUDIO_ROWS) {
@@ -4004,7 +5485,7 @@ impl App {
                             }
                             let remaining = parsed_triggers.len().saturating_sub(MAX_AUDIO_ROWS);
                             if remaining > 0 {
-                                ui.small(format!("... {remaining} older triggers hidden"));
+                                ui.small(tr!("ui.older_triggers_hidden", remaining = remaining));
                             }
                         }
                     });
@@ -4012,6 +5493,9 @@ impl App {
             } else {
                 None
             };
+            if let Some(panel) = left_panel.as_ref() {
+                layout_sidebar_width = panel.response.rect.width();
+            }
             left_panel_width_px = left_panel
                 .as_ref()
                 .map(|panel| panel.response.rect.width() * ui_pixels_per_point)
@@ -4059,58 +5543,71 @@ impl App {
                                 match play_state {
                                     PlayState::Editing => {}
                                     PlayState::Playing { paused: false } => {
-                                        if ui.button("Pause").clicked() {
+                                        if ui.button(tr!("ui.pause")).clicked() {
                                             play_pause = true;
                                         }
-                                        if ui.button("Stop").clicked() {
+                                        if ui.button(tr!("ui.stop")).clicked() {
                                             play_stop = true;
                                         }
-                                        if ui.button("Step").clicked() {
+                                        if ui.button(tr!("ui.step")).clicked() {
                                             play_pause = true;
                                             play_step = true;
                                         }
                                         ui.separator();
-                                        ui.small("Running");
+                                        ui.small(tr!("ui.running"));
                                     }
                                     PlayState::Playing { paused: true } => {
-                                        if ui.button("Resume").clicked() {
+                                        if ui.button(tr!("ui.resume")).clicked() {
                                             play_resume = true;
                                         }
-                                        if ui.button("Stop").clicked() {
+                                        if ui.button(tr!("ui.stop")).clicked() {
                                             play_stop = true;
                                         }
-                                        if ui.button("Step").clicked() {
+                                        if ui.button(tr!("ui.step")).clicked() {
                                             play_step = true;
                                         }
                                         ui.separator();
-                                        ui.small("Paused");
+                                        ui.small(tr!("ui.paused"));
                                     }
                                 }
                             });
-                            ui.small("F5 play/pause/resume, Shift+F5 stop, F6 step");
+                            ui.small(tr!("ui.f5_play_pause_resume_shift_f5"));
 
                             if let Some(lab) = open_world_lab_hud {
                                     ui.separator();
                                     let seconds = lab.time_alive.max(0.0);
                                     let mins = (seconds / 60.0).floor() as i32;
                                     let secs = (seconds % 60.0).floor() as i32;
-                                    ui.label(format!("Time: {mins:02}:{secs:02}"));
-                                    ui.label(format!(
-                                        "HP: {:.0}/{:.0}   Level: {}   XP: {:.0}/{:.0}",
-                                        lab.health, lab.max_health, lab.level, lab.xp, lab.xp_next
+                                    ui.label(tr!(
+                                        "ui.time",
+                                        mins = format!("{mins:02}"),
+                                        secs = format!("{secs:02}")
+                                    ));
+                                    ui.label(tr!(
+                                        "ui.hp_level_xp",
+                                        health = format!("{:.0}", lab.health),
+                                        max_health = format!("{:.0}", lab.max_health),
+                                        level = lab.level,
+                                        xp = format!("{:.0}", lab.xp),
+                                        xp_next = format!("{:.0}", lab.xp_next)
                                     ));
-                                    ui.label(format!(
-                                        "Enemies: {}   Bullets: {}   Orbs: {}",
-                                        lab.enemies, lab.bullets, lab.orbs
+                                    ui.label(tr!(
+                                        "ui.enemies_bullets_orbs",
+                                        enemies = lab.enemies,
+                                        bullets = lab.bullets,
+                                        orbs = lab.orbs
                                     ));
                                     let camera_label = match lab.camera_mode {
                                         OpenWorldCameraMode::ThirdPerson => "3rd-person",
                                         OpenWorldCameraMode::FirstPerson => "1st-person",
                                     };
-                                    ui.small(format!("Camera: {camera_label} (V toggles)"));
-                                    ui.small(format!(
-                                        "Move: {:.1}   Fire: {:.1}/s   Damage: {:.1}   Pickup: {:.1}",
-                                        lab.move_speed, lab.fire_rate, lab.damage, lab.pickup_radius
+                                    ui.small(tr!("ui.camera_v_toggles", camera_label = camera_label));
+                                    ui.small(tr!(
+                                        "ui.move_fire_s_damage_pickup",
+                                        move_speed = format!("{:.1}", lab.move_speed),
+                                        fire_rate = format!("{:.1}", lab.fire_rate),
+                                        damage = format!("{:.1}", lab.damage),
+                                        pickup_radius = format!("{:.1}", lab.pickup_radius)
                                     ));
 
                                     if lab.health <= 0.0 {
@@ -4120,7 +5617,7 @@ impl App {
 
                                     if let Some(options) = lab.pending_upgrades {
                                         ui.separator();
-                                        ui.strong("Level up: choose an upgrade");
+                                        ui.strong(tr!("ui.level_up_choose_an_upgrade"));
                                         for upgrade in options {
                                             let (title, detail) = match upgrade {
                                                 LabUpgrade::Damage => ("Damage", "+0.9 projectile damage"),
@@ -4210,6 +5707,13 @@ impl App {
                             gizmo_center_px = Some((min_screen + max_screen) * 0.5);
                         }
                     }
+                    if let Some(centroid) = selection_centroid_2d {
+                        if let Some(center_view) =
+                            camera_2d.world_to_screen_pixels(centroid, viewport_size_physical)
+                        {
+                            gizmo_center_px = Some(center_view + viewport_origin_vec2);
+                        }
+                    }
                 } else if let Some(info) = selection_details.as_ref() {
                     if let Some(mesh_tx) = info.mesh_transform.as_ref() {
                         if let Some(center_view) =
@@ -4225,6 +5729,8 @@ impl App {
 
             if show_editor_ui {
                 let painter = ctx.debug_painter();
+                let overlay = ui_theme.overlay_palette();
+                let stroke_width = |width: f32| width * overlay.stroke_scale;
                 let viewport_outline = egui::Rect::from_min_size(
                     egui::pos2(
                         viewport_origin_vec2.x / ui_pixels_per_point,
@@ -4238,7 +5744,7 @@ impl App {
                 painter.rect_stroke(
                     viewport_outline,
                     0.0,
-                    egui::Stroke::new(1.0, egui::Color32::from_rgba_premultiplied(220, 220, 240, 80)),
+                    egui::Stroke::new(stroke_width(1.0), overlay.viewport_outline),
                     egui::StrokeKind::Outside,
                 );
                 if !matches!(play_state, PlayState::Playing { paused: false }) {
@@ -4246,7 +5752,7 @@ impl App {
                         painter.rect_stroke(
                             rect,
                             0.0,
-                            egui::Stroke::new(2.0, egui::Color32::YELLOW),
+                            egui::Stroke::new(stroke_width(2.0), overlay.selection),
                             egui::StrokeKind::Inside,
                         );
                     }
@@ -4272,10 +5778,7 @@ impl App {
                                 painter.rect_stroke(
                                     cell_rect,
                                     0.0,
-                                    egui::Stroke::new(
-                                        1.0,
-                                        egui::Color32::from_rgba_premultiplied(80, 200, 255, 80),
-                                    ),
+                                    egui::Stroke::new(stroke_width(1.0), overlay.spatial_hash),
                                     egui::StrokeKind::Inside,
                                 );
                             }
@@ -4301,15 +5804,94 @@ impl App {
                                 painter.rect_stroke(
                                     collider_rect,
                                     0.0,
-                                    egui::Stroke::new(
-                                        1.5,
-                                        egui::Color32::from_rgba_premultiplied(255, 140, 60, 120),
-                                    ),
+                                    egui::Stroke::new(stroke_width(1.5), overlay.collider),
                                     egui::StrokeKind::Inside,
                                 );
                             }
                         }
                     }
+                    if let Some((origin, geometry)) = collider_edit_overlay {
+                        let to_screen = |world: Vec2| {
+                            camera_2d.world_to_screen_pixels(world, viewport_size_physical).map(|px| {
+                                let screen = px + viewport_origin_vec2;
+                                egui::pos2(screen.x / ui_pixels_per_point, screen.y / ui_pixels_per_point)
+                            })
+                        };
+                        let stroke = egui::Stroke::new(stroke_width(1.5), overlay.collider);
+                        let center_world = origin + geometry.offset;
+                        if geometry.kind == ColliderKind::Circle {
+                            let edge_world = center_world + Vec2::new(geometry.radius(), 0.0);
+                            if let (Some(center), Some(edge)) = (to_screen(center_world), to_screen(edge_world)) {
+                                painter.circle_stroke(center, center.distance(edge), stroke);
+                            }
+                        }
+                        let grip_radius = GIZMO_COLLIDER_GRIP_RADIUS_PX * 0.6 / ui_pixels_per_point;
+                        for (grip, world) in collider_grips(&geometry, origin) {
+                            let Some(pos) = to_screen(world) else {
+                                continue;
+                            };
+                            if grip == ColliderGrip::Center {
+                                painter.circle_stroke(pos, grip_radius, stroke);
+                            } else {
+                                let handle = egui::Rect::from_center_size(pos, egui::Vec2::splat(grip_radius * 2.0));
+                                painter.rect_filled(handle, 0.0, overlay.collider);
+                            }
+                        }
+                    }
+                    if debug_show_nav_grid {
+                        for (min, max) in &nav_blocked_rects {
+                            if let Some((min_px_view, max_px_view)) =
+                                camera_2d.world_rect_to_screen_bounds(*min, *max, viewport_size_physical)
+                            {
+                                let min_screen = min_px_view + viewport_origin_vec2;
+                                let max_screen = max_px_view + viewport_origin_vec2;
+                                let cell_rect = egui::Rect::from_two_pos(
+                                    egui::pos2(
+                                        min_screen.x / ui_pixels_per_point,
+                                        min_screen.y / ui_pixels_per_point,
+                                    ),
+                                    egui::pos2(
+                                        max_screen.x / ui_pixels_per_point,
+                                        max_screen.y / ui_pixels_per_point,
+                                    ),
+                                );
+                                painter.rect_filled(cell_rect, 0.0, overlay.nav_blocked);
+                            }
+                        }
+                        for path in &nav_paths {
+                            let points: Vec<egui::Pos2> = path
+                                .iter()
+                                .filter_map(|point| {
+                                    camera_2d.world_to_screen_pixels(*point, viewport_size_physical)
+                                })
+                                .map(|px| {
+                                    let screen = px + viewport_origin_vec2;
+                                    egui::pos2(screen.x / ui_pixels_per_point, screen.y / ui_pixels_per_point)
+                                })
+                                .collect();
+                            let stroke = egui::Stroke::new(stroke_width(2.0), overlay.nav_path);
+                            for segment in points.windows(2) {
+                                painter.line_segment([segment[0], segment[1]], stroke);
+                            }
+                            if let Some(goal) = points.last() {
+                                painter.circle_stroke(*goal, 4.0, stroke);
+                            }
+                        }
+                    }
+                    let emitter_stroke = egui::Stroke::new(stroke_width(1.5), overlay.emitter_outline);
+                    for polyline in &emitter_shape_outline {
+                        let points: Vec<egui::Pos2> = polyline
+                            .iter()
+                            .filter_map(|point| camera_2d.world_to_screen_pixels(*point, viewport_size_physical))
+                            .map(|px| {
+                                let screen = px + viewport_origin_vec2;
+                                egui::pos2(screen.x / ui_pixels_per_point, screen.y / ui_pixels_per_point)
+                            })
+                            .collect();
+                        for segment in points.windows(2) {
+                            painter.line_segment([segment[0], segment[1]], emitter_stroke);
+                        }
+                    }
                     if let Some(sample) = animation_budget_sample {
                         draw_animation_budget_overlay(ctx, viewport_outline, sample);
                     }
@@ -4317,6 +5899,36 @@ impl App {
                         draw_light_cluster_overlay(ctx, viewport_outline, metrics);
                     }
                 }
+                let selected_skeleton = selected_entity
+                    .filter(|_| !debug_show_skeletons)
+                    .zip(selection_details.as_ref().and_then(|info| info.skeleton.as_ref()))
+                    .map(|(entity, skeleton)| (entity, skeleton.joints.as_slice()));
+                let skeletons = skeleton_overlays
+                    .iter()
+                    .map(|(entity, joints)| (*entity, joints.as_slice()))
+                    .chain(selected_skeleton);
+                for (entity, joints) in skeletons {
+                    let project = |world: Vec3| {
+                        let view = match viewport_camera_mode {
+                            ViewportCameraMode::Ortho2D => {
+                                camera_2d.world_to_screen_pixels(world.truncate(), viewport_size_physical)
+                            }
+                            ViewportCameraMode::Perspective3D => {
+                                mesh_camera_for_ui.project_point(world, viewport_size_physical)
+                            }
+                        }?;
+                        let screen = view + viewport_origin_vec2;
+                        Some(egui::pos2(screen.x / ui_pixels_per_point, screen.y / ui_pixels_per_point))
+                    };
+                    draw_skeleton_overlay(
+                        &painter,
+                        joints,
+                        project,
+                        entity_inspector::skeleton_joint_highlight(ctx, entity),
+                        debug_skeleton_labels,
+                        &overlay,
+                    );
+                }
                 if !matches!(play_state, PlayState::Playing { paused: false }) {
                     let active_scale_handle_kind = gizmo_interaction.and_then(|interaction| match interaction {
                         GizmoInteraction::Scale { handle, .. } => Some(handle.kind()),
@@ -4334,9 +5946,9 @@ impl App {
                                 let axis_length = (distance * GIZMO_3D_AXIS_LENGTH_SCALE)
                                     .clamp(GIZMO_3D_AXIS_MIN, GIZMO_3D_AXIS_MAX);
                                 let axes = [
-                                    (Vec3::X, egui::Color32::from_rgb(240, 100, 100)),
-                                    (Vec3::Y, egui::Color32::from_rgb(100, 220, 100)),
-                                    (Vec3::Z, egui::Color32::from_rgb(120, 150, 255)),
+                                    (Vec3::X, overlay.axis_x),
+                                    (Vec3::Y, overlay.axis_y),
+                                    (Vec3::Z, overlay.axis_z),
                                 ];
                                 for (axis, color) in axes {
                                     let end_world = center_world + axis * axis_length;
@@ -4348,7 +5960,7 @@ impl App {
                                             end_screen.x / ui_pixels_per_point,
                                             end_screen.y / ui_pixels_per_point,
                                         );
-                                        painter.line_segment([center, end_pos], egui::Stroke::new(2.0, color));
+                                        painter.line_segment([center, end_pos], egui::Stroke::new(stroke_width(2.0), color));
                                         painter.circle_filled(end_pos, 3.0 / ui_pixels_per_point, color);
                                     }
                                 }
@@ -4362,14 +5974,14 @@ impl App {
                                             egui::pos2(center.x - extent, center.y),
                                             egui::pos2(center.x + extent, center.y),
                                         ],
-                                        egui::Stroke::new(2.0, egui::Color32::YELLOW),
+                                        egui::Stroke::new(stroke_width(2.0), overlay.selection),
                                     );
                                     painter.line_segment(
                                         [
                                             egui::pos2(center.x, center.y - extent),
                                             egui::pos2(center.x, center.y + extent),
                                         ],
-                                        egui::Stroke::new(2.0, egui::Color32::YELLOW),
+                                        egui::Stroke::new(stroke_width(2.0), overlay.selection),
                                     );
                                 }
                                 GizmoMode::Scale => {
@@ -4387,14 +5999,14 @@ impl App {
                                     };
 
                                     let base_x = if matches!(active_axis, Some(Axis2::X)) {
-                                        egui::Color32::from_rgb(255, 185, 185)
+                                        overlay.axis_x_active
                                     } else {
-                                        egui::Color32::from_rgb(240, 120, 120)
+                                        overlay.axis_x
                                     };
                                     let base_y = if matches!(active_axis, Some(Axis2::Y)) {
-                                        egui::Color32::from_rgb(185, 225, 255)
+                                        overlay.axis_y_active
                                     } else {
-                                        egui::Color32::from_rgb(120, 180, 255)
+                                        overlay.axis_z
                                     };
 
                                     let horiz_pos = egui::Rect::from_min_max(
@@ -4453,18 +6065,21 @@ impl App {
                                         base_y,
                                     );
 
-                                    let outer_color = if active_uniform {
-                                        egui::Color32::from_rgb(255, 235, 150)
-                                    } else {
-                                        egui::Color32::from_rgb(255, 210, 90)
-                                    };
-                                    let inner_color = if active_uniform {
-                                        egui::Color32::from_rgb(220, 200, 110)
+                                    let (outer_color, inner_color) = if active_uniform {
+                                        (overlay.ring_active, overlay.ring_inner_active)
                                     } else {
-                                        egui::Color32::from_rgb(180, 160, 60)
+                                        (overlay.ring, overlay.ring_inner)
                                     };
-                                    painter.circle_stroke(center, outer, egui::Stroke::new(2.0, outer_color));
-                                    painter.circle_stroke(center, inner, egui::Stroke::new(1.0, inner_color));
+                                    painter.circle_stroke(
+                                        center,
+                                        outer,
+                                        egui::Stroke::new(stroke_width(2.0), outer_color),
+                                    );
+                                    painter.circle_stroke(
+                                        center,
+                                        inner,
+                                        egui::Stroke::new(stroke_width(1.0), inner_color),
+                                    );
                                 }
                                 GizmoMode::Rotate => {
                                     let inner = GIZMO_ROTATE_INNER_RADIUS_PX / ui_pixels_per_point;
@@ -4472,12 +6087,12 @@ impl App {
                                     painter.circle_stroke(
                                         center,
                                         outer,
-                                        egui::Stroke::new(2.0, egui::Color32::from_rgb(255, 210, 40)),
+                                        egui::Stroke::new(stroke_width(2.0), overlay.ring),
                                     );
                                     painter.circle_stroke(
                                         center,
                                         inner,
-                                        egui::Stroke::new(1.0, egui::Color32::from_rgb(180, 160, 40)),
+                                        egui::Stroke::new(stroke_width(1.0), overlay.ring_inner),
                                     );
                                 }
                             }
@@ -4485,7 +6100,7 @@ impl App {
                         painter.circle_stroke(
                             center,
                             3.0 / ui_pixels_per_point,
-                            egui::Stroke::new(2.0, egui::Color32::YELLOW),
+                            egui::Stroke::new(stroke_width(2.0), overlay.selection),
                         );
                     }
                 }
@@ -4496,6 +6111,18 @@ impl App {
                 self.log_keyframe_editor_event(event);
             }
             self.show_animation_keyframe_panel(&keyframe_panel_ctx, &animation_snapshot);
+            self.show_atlas_remap_dialog(&keyframe_panel_ctx);
+        }
+
+        if let Some(name) = layout_save_name {
+            let panels = [
+                (PANEL_SCRIPT_DEBUGGER, script_debugger.open),
+                (PANEL_KEYFRAME_EDITOR, keyframe_panel_open),
+                (PANEL_ANIMATION_TIME, animation_time_open),
+                (PANEL_MESH_PREVIEW, mesh_preview_open),
+                (PANEL_PREFAB_SHELF, prefab_shelf_open),
+            ];
+            layout_action = Some(LayoutAction::Save(LayoutPreset::new(name, &panels, layout_sidebar_width)));
         }
 
         script_debugger_output.open = script_debugger.open;
@@ -4506,10 +6133,10 @@ impl App {
         if gpu_export_requested {
             match self.export_gpu_timings_csv("target/gpu_timings.csv") {
                 Ok(path) => {
-                    gpu_metrics_status = Some(format!("GPU timings exported to {}", path.display()));
+                    gpu_metrics_status = Some(tr!("ui.gpu_timings_exported", path = path.display()));
                 }
                 Err(err) => {
-                    gpu_metrics_status = Some(format!("GPU timing export failed: {err}"));
+                    gpu_metrics_status = Some(tr!("ui.gpu_timing_export_failed", error = err));
                 }
             }
         }
@@ -4571,6 +6198,7 @@ impl App {
             ui_spawn_per_press,
             ui_auto_spawn_rate,
             ui_environment_intensity,
+            ui_environment_scroll_speed,
             ui_root_spin,
             ui_emitter_rate,
             ui_emitter_spread,
@@ -4594,10 +6222,13 @@ impl App {
             ui_shadow_resolution,
             ui_shadow_split_lambda,
             ui_shadow_pcf_radius,
+            ui_auto_exposure,
             ui_camera_zoom_min,
             ui_camera_zoom_max,
             ui_sprite_guard_pixels,
             ui_sprite_guard_mode,
+            ui_static_sprite_batching,
+            ui_low_latency_input,
             gizmo_mode: gizmo_mode_state,
             selection: SelectionResult { entity: selected_entity, details: selection_details },
             gizmo_interaction,
@@ -4607,6 +6238,7 @@ impl App {
             camera_bookmark_delete,
             mesh_control_request,
             mesh_frustum_request,
+            mesh_clip_request,
             mesh_frustum_snap,
             mesh_reset_request,
             mesh_selection_request,
@@ -4625,18 +6257,28 @@ impl App {
             camera_follow_clear,
             debug_show_spatial_hash,
             debug_show_colliders,
+            debug_show_nav_grid,
+            debug_show_culled_bounds,
+            debug_show_skeletons,
+            debug_skeleton_labels,
+            collider_edit_mode,
             vsync_request: vsync_toggle_request,
+            anisotropy_request,
+            render_scale_request,
             script_debugger: script_debugger_output,
             prefab_name_input,
             prefab_format,
             prefab_status,
             ui_scene_path,
             ui_scene_status,
+            ui_fragment_include_children,
             animation_group_input,
             animation_group_scale_input,
             inspector_status,
             clear_scene_history,
             keyframe_panel_open,
+            layout_name_input,
+            layout_action,
             gpu_metrics_status,
             project_action,
             start_screen_open: start_screen_open_state,
@@ -4650,8 +6292,8 @@ impl App {
 }
 
 fn render_script_api_reference(ui: &mut egui::Ui) {
-    ui.collapsing("World API reference", |ui| {
-        ui.small("Core `world` helpers available to scripts:");
+    panel_header("ui.world_api_reference").show(ui, |ui| {
+        ui.small(tr!("ui.core_world_helpers_available_to_scripts"));
         for doc in SCRIPT_API_DOCS {
             ui.horizontal(|ui| {
                 ui.monospace(doc.signature);
@@ -4661,6 +6303,28 @@ fn render_script_api_reference(ui: &mut egui::Ui) {
     });
 }
 
+/// One collapsible time-series chart per counter plugins pushed through
+/// `AnalyticsPlugin::record_custom_counter`.
+fn render_custom_counters(ui: &mut egui::Ui, counters: &[CustomCounterHistory]) {
+    for counter in counters {
+        let latest = counter.samples.last().copied().unwrap_or_default();
+        egui::CollapsingHeader::new(format!("{}: {latest:.2}", counter.name))
+            .id_salt(("custom_counter", &counter.name))
+            .default_open(false)
+            .show(ui, |ui| {
+                let points: Vec<[f64; 2]> =
+                    counter.samples.iter().enumerate().map(|(i, v)| [i as f64, *v]).collect();
+                eplot::Plot::new(format!("custom_counter_plot_{}", counter.name))
+                    .height(80.0)
+                    .allow_zoom(false)
+                    .allow_scroll(false)
+                    .show(ui, |plot_ui| {
+                        plot_ui.line(eplot::Line::new(counter.name.clone(), points));
+                    });
+            });
+    }
+}
+
 fn frame_summary_text(sample: Option<&FrameTimingSample>) -> String {
     if let Some(sample) = sample {
         format!(
@@ -4676,6 +6340,18 @@ fn frame_summary_text(sample: Option<&FrameTimingSample>) -> String {
     }
 }
 
+fn sprite_upload_summary_text(sample: &FrameTimingSample) -> String {
+    format!(
+        "Sprite instances: {} rewritten | {} reused",
+        format_data_size(sample.sprite_bytes_uploaded),
+        format_data_size(sample.sprite_bytes_reused)
+    )
+}
+
+fn mesh_culling_summary_text(sample: &FrameTimingSample) -> String {
+    format!("Meshes: {} drawn | {} culled", sample.meshes_drawn, sample.meshes_culled)
+}
+
 fn system_row_strings(timing: &SystemTimingSummary) -> [String; 4] {
     [
         format!("{:.2}", timing.last_ms),
@@ -4703,9 +6379,9 @@ fn sprite_stage_bar(ui: &mut egui::Ui, label: &str, value_ms: Option<f32>, budge
         }
         None => {
             if label.contains("Upload") {
-                ui.label(format!("{label}: enable GPU timers to capture sprite pass"));
+                ui.label(tr!("ui.timing_needs_gpu_timers", label = label));
             } else {
-                ui.label(format!("{label}: timing unavailable"));
+                ui.label(tr!("ui.timing_unavailable_for", label = label));
             }
         }
     }
@@ -4741,8 +6417,8 @@ fn render_start_screen(
                     ui.set_max_width(620.0);
                     ui.vertical(|ui| {
                         ui.vertical_centered(|ui| {
-                            ui.heading("Kestrel Studio");
-                            ui.label("Pick a project to open or create a new one.");
+                            ui.heading(tr!("ui.kestrel_studio"));
+                            ui.label(tr!("ui.pick_a_project_to_open_or"));
                         });
                         if let Some(msg) = status.as_ref() {
                             ui.add_space(8.0);
@@ -4752,22 +6428,22 @@ fn render_start_screen(
                         egui::Grid::new("start_screen_meta").num_columns(2).spacing([12.0, 8.0]).show(
                             ui,
                             |ui| {
-                                ui.label("Current project:");
+                                ui.label(tr!("ui.current_project"));
                                 ui.label(project_name.cloned().unwrap_or_else(|| "<unnamed>".to_string()));
                                 ui.end_row();
-                                ui.label("Root:");
+                                ui.label(tr!("ui.root"));
                                 ui.label(project_root);
                                 ui.end_row();
-                                ui.label("Manifest:");
+                                ui.label(tr!("ui.manifest"));
                                 ui.label(project_manifest.as_deref().unwrap_or("N/A"));
                                 ui.end_row();
                             },
                         );
                         ui.add_space(12.0);
-                        ui.heading("Recent projects");
+                        ui.heading(tr!("ui.recent_projects"));
                         egui::ScrollArea::vertical().max_height(140.0).show(ui, |ui| {
                             if recent_projects.is_empty() {
-                                ui.small("No recent projects found.");
+                                ui.small(tr!("ui.no_recent_projects_found"));
                             }
                             for path in recent_projects {
                                 let exists = Path::new(path).exists();
@@ -4784,39 +6460,43 @@ fn render_start_screen(
                             }
                         });
                         ui.add_space(12.0);
-                        ui.heading("Open project");
+                        ui.heading(tr!("ui.open_project_heading"));
                         ui.horizontal(|ui| {
-                            ui.label("Manifest path");
+                            ui.label(tr!("ui.manifest_path"));
                             let edited = ui.text_edit_singleline(open_path).changed();
                             if edited {
                                 status.take();
                             }
-                            if ui.button("Open").clicked() {
+                            if ui.button(tr!("ui.open")).clicked() {
                                 if open_path.trim().is_empty() {
-                                    *status = Some("Enter a manifest path to open.".to_string());
+                                    *status = Some(tr!("project.manifest_path_required"));
                                 } else {
                                     let path = open_path.trim().to_string();
                                     status.take();
                                     action = Some(ProjectAction::OpenExisting { path });
                                 }
                             }
+                            if ui.button(tr!("ui.browse_button")).clicked() {
+                                status.take();
+                                action = Some(ProjectAction::Browse);
+                            }
                         });
                         ui.add_space(12.0);
-                        ui.heading("Create new project");
+                        ui.heading(tr!("ui.create_new_project"));
                         ui.horizontal(|ui| {
-                            ui.label("Project name");
+                            ui.label(tr!("ui.project_name"));
                             if ui.text_edit_singleline(new_name).changed() {
                                 status.take();
                             }
                         });
                         ui.horizontal(|ui| {
-                            ui.label("Project folder");
+                            ui.label(tr!("ui.project_folder"));
                             if ui.text_edit_singleline(new_path).changed() {
                                 status.take();
                             }
-                            if ui.button("Create").clicked() {
+                            if ui.button(tr!("ui.create")).clicked() {
                                 if new_path.trim().is_empty() {
-                                    *status = Some("Enter a project folder to create.".to_string());
+                                    *status = Some(tr!("project.folder_required"));
                                 } else {
                                     let path = new_path.trim().to_string();
                                     let name = new_name.trim().to_string();
@@ -4825,14 +6505,14 @@ fn render_start_screen(
                                 }
                             }
                         });
-                        ui.small("Manifests are saved as 'project.kestrelproj' in the chosen folder.");
+                        ui.small(tr!("ui.manifests_are_saved_as_project_kestrelproj"));
                         ui.add_space(12.0);
                         ui.horizontal(|ui| {
-                            if ui.button("Hide start screen").clicked() {
+                            if ui.button(tr!("ui.hide_start_screen")).clicked() {
                                 *open_flag = false;
                                 status.take();
                             }
-                            ui.small("You can reopen this via the Stats panel.");
+                            ui.small(tr!("ui.you_can_reopen_this_via_the"));
                         });
                     });
                 });
@@ -4841,6 +6521,42 @@ fn render_start_screen(
     action
 }
 
+/// Draws bones from each joint to its parent and a dot per joint. Painted over the viewport, so the
+/// bones never reach the renderer or its shadow passes.
+fn draw_skeleton_overlay(
+    painter: &egui::Painter,
+    joints: &[SkeletonJointPose],
+    project: impl Fn(Vec3) -> Option<egui::Pos2>,
+    highlight: Option<usize>,
+    labels: bool,
+    overlay: &OverlayPalette,
+) {
+    let points: Vec<Option<egui::Pos2>> =
+        joints.iter().map(|joint| project(joint.world.w_axis.truncate())).collect();
+    let bone_stroke = egui::Stroke::new(1.5 * overlay.stroke_scale, overlay.skeleton);
+    for (index, joint) in joints.iter().enumerate() {
+        let Some(parent) = joint.parent else { continue };
+        if let (Some(Some(from)), Some(to)) = (points.get(parent), points[index]) {
+            painter.line_segment([*from, to], bone_stroke);
+        }
+    }
+    for (index, (joint, point)) in joints.iter().zip(&points).enumerate() {
+        let Some(point) = *point else { continue };
+        let highlighted = highlight == Some(index);
+        let (radius, color) = if highlighted { (5.0, overlay.selection) } else { (2.5, overlay.skeleton) };
+        painter.circle_filled(point, radius, color);
+        if labels || highlighted {
+            painter.text(
+                point + egui::vec2(6.0, -6.0),
+                egui::Align2::LEFT_BOTTOM,
+                joint.name.as_ref(),
+                egui::FontId::proportional(11.0),
+                color,
+            );
+        }
+    }
+}
+
 fn draw_animation_budget_overlay(
     ctx: &egui::Context,
     viewport_rect: egui::Rect,
@@ -4861,7 +6577,7 @@ fn draw_animation_budget_overlay(
             frame.show(ui, |ui| {
                 ui.set_width(240.0);
                 ui.vertical_centered(|ui| {
-                    ui.label(egui::RichText::new("Animation HUD").strong());
+                    ui.label(egui::RichText::new(tr!("ui.animation_hud")).strong());
                 });
                 ui.add_space(4.0);
                 hud_budget_row(
@@ -4881,7 +6597,7 @@ fn draw_animation_budget_overlay(
                 if let Some(upload) = sample.sprite_upload_ms {
                     hud_budget_row(ui, "Sprite Upload", upload, SPRITE_UPLOAD_BUDGET_MS, String::new());
                 } else {
-                    ui.small("Sprite Upload: GPU timers disabled");
+                    ui.small(tr!("ui.sprite_upload_gpu_timers_disabled"));
                 }
                 ui.separator();
                 hud_budget_row(
@@ -4910,7 +6626,17 @@ fn draw_animation_budget_overlay(
                         ),
                     );
                 } else {
-                    ui.small("Palette Upload: no skinning this frame");
+                    ui.small(tr!("ui.palette_upload_no_skinning_this_frame"));
+                }
+                if let Some(pressure) = sample.allocation_pressure {
+                    ui.separator();
+                    ui.small(tr!(
+                        "ui.allocs_sprite_batch_mesh_kb",
+                        sprites = pressure.sprite_instances.allocations,
+                        batches = pressure.sprite_batches.allocations,
+                        meshes = pressure.mesh_instances.allocations,
+                        total_bytes = format!("{:.1}", pressure.total_bytes() as f64 / 1024.0)
+                    ));
                 }
             });
         });
@@ -4936,24 +6662,26 @@ fn draw_light_cluster_overlay(ctx: &egui::Context, viewport_rect: egui::Rect, me
                 ui.set_width(260.0);
                 ui.vertical_centered(|ui| {
                     ui.label(
-                        egui::RichText::new("Lighting Budget")
+                        egui::RichText::new(tr!("ui.lighting_budget"))
                             .strong()
                             .color(egui::Color32::from_rgb(255, 120, 120)),
                     );
                 });
                 ui.add_space(4.0);
-                ui.label(format!(
-                    "Point lights over budget: {} / {}",
-                    metrics.truncated_lights, LIGHT_CLUSTER_MAX_LIGHTS
+                ui.label(tr!(
+                    "ui.point_lights_over_budget",
+                    truncated_lights = metrics.truncated_lights,
+                    max_lights = LIGHT_CLUSTER_MAX_LIGHTS
                 ));
-                ui.label(format!(
-                    "Visible lights this frame: {} ({} total)",
-                    metrics.visible_lights, metrics.total_lights
+                ui.label(tr!(
+                    "ui.visible_lights_this_frame_total",
+                    visible_lights = metrics.visible_lights,
+                    total_lights = metrics.total_lights
                 ));
                 if metrics.overflow_clusters > 0 {
-                    ui.small(format!("Clusters saturated: {}", metrics.overflow_clusters));
+                    ui.small(tr!("ui.clusters_saturated", overflow_clusters = metrics.overflow_clusters));
                 }
-                ui.small("Reduce point lights or adjust clustered-light settings to restore coverage.");
+                ui.small(tr!("ui.reduce_point_lights_or_adjust_clustered"));
             });
         });
 }
@@ -4991,12 +6719,21 @@ mod tests {
             fixed_ms: 3.5,
             render_ms: 6.1,
             ui_ms: 1.82,
+            sprite_bytes_uploaded: 160,
+            sprite_bytes_reused: 819_200,
+            meshes_drawn: 240,
+            meshes_culled: 760,
         };
         assert_eq!(
             frame_summary_text(Some(&sample)),
             "Frame 16.67 ms | Update 5.25 ms | Fixed 3.50 ms | Render 6.10 ms | UI 1.82 ms"
         );
         assert_eq!(frame_summary_text(None), "Frame timings unavailable");
+        assert_eq!(
+            sprite_upload_summary_text(&sample),
+            "Sprite instances: 160.0 B rewritten | 800.0 KB reused"
+        );
+        assert_eq!(mesh_culling_summary_text(&sample), "Meshes: 240 drawn | 760 culled");
     }
 
     #[test]
@@ -1,13 +1,17 @@
 use super::{
-    AtlasAssetSummary, ClipAssetSummary, InputModifierState, InspectorAction, MaterialOption,
-    MeshSubsetEntry, PrefabDragPayload, SkeletonAssetSummary, SkeletonEntityBinding, SpriteAtlasRequest,
-    UiActions,
+    panel_header, AtlasAssetSummary, BulkTransform, ClipAssetSummary, ComponentClipboardRequest,
+    ComponentKind, InputModifierState, InspectorAction, MaterialOption, MeshSubsetEntry, PrefabDragPayload,
+    SkeletonAssetSummary, SkeletonEntityBinding, SpriteAtlasRequest, UiActions,
 };
 use crate::ecs::{
-    EntityInfo, ForceFalloff, ForceFieldKind, ParticleAttractor, ParticleTrail, PropertyTrackPlayer, ScriptInfo,
-    SkeletonInfo, TransformClipInfo, TransformTrackPlayer,
+    ColliderGeometry, ColliderKind, ComponentFieldKind, ComponentFieldValue, EmitterShape, EntityInfo,
+    ForceFalloff, ForceFieldKind, ParticleAttractor, ParticleCollisionMode, ParticleTrail,
+    PluginComponentView, PropertyTrackPlayer, ScriptInfo, SkeletonInfo, SkeletonJointPose,
+    SpriteAnimationLoopMode, TintCurveInfo, TransformClipInfo, TransformTrackPlayer,
 };
 use crate::gizmo::{GizmoInteraction, GizmoMode, ScaleHandle};
+use crate::material_registry::MaterialCullMode;
+use crate::tr;
 use bevy_ecs::prelude::Entity;
 use egui::Ui;
 use glam::{EulerRot, Quat, Vec2, Vec3, Vec4};
@@ -19,8 +23,10 @@ pub(super) struct InspectorContext<'a> {
     pub gizmo_interaction: &'a mut Option<GizmoInteraction>,
     pub inspector_status: &'a mut Option<String>,
     pub input: InputModifierState,
+    pub selection_count: usize,
     pub clip_keys: &'a [String],
     pub clip_assets: &'a HashMap<String, ClipAssetSummary>,
+    pub color_curve_keys: &'a [String],
     pub skeleton_keys: &'a [String],
     pub skeleton_assets: &'a HashMap<String, SkeletonAssetSummary>,
     pub atlas_keys: &'a [String],
@@ -31,6 +37,9 @@ pub(super) struct InspectorContext<'a> {
     pub skeleton_entities: &'a [SkeletonEntityBinding],
     pub material_options: &'a [MaterialOption],
     pub mesh_subsets: &'a HashMap<String, Arc<[MeshSubsetEntry]>>,
+    pub collider_edit_mode: &'a mut bool,
+    /// Whether collider undo and redo have anything to replay.
+    pub collider_history: (bool, bool),
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -48,23 +57,23 @@ pub(super) fn show_entity_inspector(
     let mut selection_details_value = selection_details.clone();
 
     if let Some(entity) = selected_entity_value {
-        ui.heading("Entity Inspector");
-        ui.label(format!("Entity: {:?}", entity));
+        ui.heading(tr!("inspector.entity_inspector"));
+        ui.label(tr!("inspector.entity", entity = format!("{:?}", entity)));
         ui.horizontal(|ui| {
-            ui.label("Gizmo");
-            ui.selectable_value(ctx.gizmo_mode, GizmoMode::Translate, "Translate");
-            ui.selectable_value(ctx.gizmo_mode, GizmoMode::Rotate, "Rotate");
-            ui.selectable_value(ctx.gizmo_mode, GizmoMode::Scale, "Scale");
+            ui.label(tr!("inspector.gizmo"));
+            ui.selectable_value(ctx.gizmo_mode, GizmoMode::Translate, tr!("inspector.translate"));
+            ui.selectable_value(ctx.gizmo_mode, GizmoMode::Rotate, tr!("inspector.rotate"));
+            ui.selectable_value(ctx.gizmo_mode, GizmoMode::Scale, tr!("inspector.scale"));
         });
         match *ctx.gizmo_mode {
             GizmoMode::Scale => {
-                ui.small("Shift = uniform scale, Ctrl = snap steps");
+                ui.small(tr!("inspector.shift_uniform_scale_ctrl_snap_steps"));
             }
             GizmoMode::Translate => {
-                ui.small("Shift = lock axis, Ctrl = snap to grid");
+                ui.small(tr!("inspector.shift_lock_axis_ctrl_snap_to"));
             }
             GizmoMode::Rotate => {
-                ui.small("Ctrl = snap to 15 deg increments");
+                ui.small(tr!("inspector.ctrl_snap_to_15_deg_increments"));
             }
         }
         if let Some(interaction) = ctx.gizmo_interaction.as_ref() {
@@ -88,8 +97,11 @@ pub(super) fn show_entity_inspector(
                     ui.colored_label(egui::Color32::LIGHT_GREEN, msg);
                 }
                 GizmoInteraction::Rotate { .. } => {
-                    let msg =
-                        if ctx.input.ctrl { "Rotate gizmo active [snap]" } else { "Rotate gizmo active" };
+                    let msg = if ctx.input.ctrl {
+                        tr!("inspector.rotate_gizmo_active_snap")
+                    } else {
+                        tr!("inspector.rotate_gizmo_active")
+                    };
                     ui.colored_label(egui::Color32::LIGHT_GREEN, msg);
                 }
                 GizmoInteraction::Scale { handle, .. } => {
@@ -120,6 +132,25 @@ pub(super) fn show_entity_inspector(
                     };
                     ui.colored_label(egui::Color32::LIGHT_GREEN, msg);
                 }
+                GizmoInteraction::Collider { grip, .. } => {
+                    let mut msg = format!("Collider gizmo active ({})", grip.label());
+                    if ctx.input.ctrl {
+                        msg.push_str(" [snap]");
+                    }
+                    ui.colored_label(egui::Color32::LIGHT_GREEN, msg);
+                }
+                GizmoInteraction::Bulk { mode, .. } => {
+                    let label = match mode {
+                        GizmoMode::Translate => "Translate",
+                        GizmoMode::Rotate => "Rotate",
+                        GizmoMode::Scale => "Scale",
+                    };
+                    let mut msg = format!("{label} gizmo active on {} entities", ctx.selection_count);
+                    if ctx.input.ctrl {
+                        msg.push_str(" [snap]");
+                    }
+                    ui.colored_label(egui::Color32::LIGHT_GREEN, msg);
+                }
                 GizmoInteraction::Scale3D { .. } => {
                     let mut msg = String::from("3D scale gizmo active");
                     if ctx.input.shift {
@@ -132,24 +163,84 @@ pub(super) fn show_entity_inspector(
                 }
             }
         }
+        if ctx.selection_count > 1 {
+            show_bulk_transform(ui, ctx.selection_count, actions);
+        }
         let mut _inspector_refresh = false;
         let mut inspector_info = selection_details_value.clone();
-    if let Some(mut info) = inspector_info {
+        if let Some(mut info) = inspector_info {
             ui.horizontal(|ui| {
-                ui.label("Entity ID");
+                ui.label(tr!("inspector.entity_id"));
                 ui.monospace(info.scene_id.as_str());
-                if ui.button("Copy").clicked() {
+                if ui.button(tr!("inspector.copy")).clicked() {
                     let id_string = info.scene_id.as_str().to_string();
                     ui.ctx().copy_text(id_string);
                 }
-                if ui.button("Find by ID").clicked() {
+                if ui.button(tr!("inspector.find_by_id")).clicked() {
                     *id_lookup_input = info.scene_id.as_str().to_string();
                     *id_lookup_active = true;
                 }
             });
+            let parent_buffer_id = egui::Id::new(("entity_parent_input", entity.index()));
+            let mut parent_input =
+                ui.ctx().data_mut(|d| d.get_persisted::<String>(parent_buffer_id)).unwrap_or_else(|| {
+                    info.parent.as_ref().map(|id| id.as_str().to_string()).unwrap_or_default()
+                });
+            ui.horizontal(|ui| {
+                ui.label(tr!("inspector.parent"));
+                match info.parent.as_ref() {
+                    Some(parent_id) => {
+                        ui.monospace(parent_id.as_str());
+                        if ui.button(tr!("inspector.find_by_id")).clicked() {
+                            *id_lookup_input = parent_id.as_str().to_string();
+                            *id_lookup_active = true;
+                        }
+                        if ui.button(tr!("inspector.detach")).clicked() {
+                            actions.inspector_actions.push(InspectorAction::DetachFromParent { entity });
+                            ui.ctx().data_mut(|d| d.remove::<String>(parent_buffer_id));
+                        }
+                    }
+                    None => {
+                        ui.weak(tr!("inspector.root"));
+                    }
+                }
+            });
+            ui.horizontal(|ui| {
+                if ui
+                    .add(
+                        egui::TextEdit::singleline(&mut parent_input)
+                            .hint_text(tr!("inspector.parent_scene_id")),
+                    )
+                    .changed()
+                {
+                    ui.ctx().data_mut(|d| d.insert_persisted(parent_buffer_id, parent_input.clone()));
+                }
+                if ui.button(tr!("inspector.set_parent")).clicked() {
+                    let trimmed = parent_input.trim();
+                    if trimmed.is_empty() {
+                        *ctx.inspector_status = Some(tr!("inspector.parent_scene_id_required"));
+                    } else {
+                        actions.inspector_actions.push(InspectorAction::SetParent {
+                            entity,
+                            parent_scene_id: trimmed.to_string(),
+                        });
+                    }
+                }
+            });
+            ui.horizontal(|ui| {
+                ui.label(tr!("inspector.transform"));
+                component_clipboard_buttons(ui, entity, ComponentKind::Transform, actions);
+                if ui.button(tr!("inspector.reset")).on_hover_text(tr!("inspector.reset_hint")).clicked() {
+                    actions.inspector_actions.push(InspectorAction::ResetTransform { entity });
+                    info.translation = Vec2::ZERO;
+                    info.rotation = 0.0;
+                    info.scale = Vec2::ONE;
+                    _inspector_refresh = true;
+                }
+            });
             let mut translation = info.translation;
             ui.horizontal(|ui| {
-                ui.label("Position");
+                ui.label(tr!("inspector.position"));
                 if ui.add(egui::DragValue::new(&mut translation.x).speed(0.01)).changed()
                     | ui.add(egui::DragValue::new(&mut translation.y).speed(0.01)).changed()
                 {
@@ -171,7 +262,7 @@ pub(super) fn show_entity_inspector(
 
             let mut scale = info.scale;
             ui.horizontal(|ui| {
-                ui.label("Scale");
+                ui.label(tr!("inspector.scale"));
                 if ui.add(egui::DragValue::new(&mut scale.x).speed(0.01)).changed()
                     | ui.add(egui::DragValue::new(&mut scale.y).speed(0.01)).changed()
                 {
@@ -184,7 +275,7 @@ pub(super) fn show_entity_inspector(
 
             if let Some(mut velocity) = info.velocity {
                 ui.horizontal(|ui| {
-                    ui.label("Velocity");
+                    ui.label(tr!("inspector.velocity"));
                     if ui.add(egui::DragValue::new(&mut velocity.x).speed(0.01)).changed()
                         | ui.add(egui::DragValue::new(&mut velocity.y).speed(0.01)).changed()
                     {
@@ -192,187 +283,394 @@ pub(super) fn show_entity_inspector(
                         info.velocity = Some(velocity);
                         _inspector_refresh = true;
                     }
+                    component_clipboard_buttons(ui, entity, ComponentKind::Velocity, actions);
+                    if ui.button(tr!("inspector.reset")).clicked() {
+                        actions.inspector_actions.push(InspectorAction::ResetVelocity { entity });
+                        info.velocity = Some(Vec2::ZERO);
+                        _inspector_refresh = true;
+                    }
                 });
             } else {
-                ui.label("Velocity: n/a");
+                ui.label(tr!("inspector.velocity_n_a"));
             }
 
-        ui.separator();
-        ui.collapsing("Script", |ui| {
-            let mut script_path = info.script.as_ref().map(|s| s.path.clone()).unwrap_or_default();
-            let instance_id = info.script.as_ref().map(|s| s.instance_id).unwrap_or(0);
-            let mut mute_errors = info.script.as_ref().map(|s| s.mute_errors).unwrap_or(false);
-            let mut persist_state = info.script.as_ref().map(|s| s.persist_state).unwrap_or(false);
-            let has_script = info.script.is_some();
-            let path_trimmed = script_path.trim();
-            let path_known = ctx.script_paths.iter().any(|p| p == path_trimmed);
-
-            if let Some(err) = ctx.script_error {
-                ui.colored_label(egui::Color32::RED, format!("Last script error: {err}"));
-            }
-            if ctx.script_error_for_entity {
-                ui.colored_label(
-                    egui::Color32::RED,
-                    "This entity's script is currently errored; callbacks are paused until it succeeds.",
-                );
-            }
-
-            ui.label("Assign a script to this entity:");
-            ui.horizontal(|ui| {
-                ui.label("Path");
-                let edit_response = ui
-                    .add(egui::TextEdit::singleline(&mut script_path).hint_text("assets/scripts/example.rhai"));
-                if edit_response.changed() {
-                if script_path.trim().is_empty() {
-                    info.script = None;
-                } else {
-                    info.script = Some(ScriptInfo {
-                        path: script_path.clone(),
-                        instance_id,
-                        mute_errors,
-                        persist_state,
-                        persisted_state: None,
-                    });
-                }
-            }
-            if ui.button("Apply").clicked() && !script_path.trim().is_empty() {
-                let trimmed = script_path.trim().to_string();
-                actions.inspector_actions.push(InspectorAction::SetScript { entity, path: trimmed.clone() });
-                info.script = Some(ScriptInfo {
-                    path: trimmed,
-                    instance_id: 0,
-                    mute_errors,
-                    persist_state,
-                    persisted_state: None,
-                });
-                _inspector_refresh = true;
-            }
-                ui.add_enabled_ui(has_script, |ui| {
-                    if ui.button("Remove").clicked() {
-                        actions.inspector_actions.push(InspectorAction::RemoveScript { entity });
-                        info.script = None;
-                        script_path.clear();
+            if let Some(collider) = info.collider {
+                ui.horizontal(|ui| {
+                    ui.label(tr!("inspector.collider"));
+                    let mut kind = collider.kind;
+                    ui.selectable_value(&mut kind, ColliderKind::Rect, tr!("inspector.rect"));
+                    ui.selectable_value(&mut kind, ColliderKind::Circle, tr!("inspector.circle"));
+                    if kind != collider.kind {
+                        let half = match kind {
+                            ColliderKind::Rect => Vec2::splat(collider.radius()),
+                            ColliderKind::Circle => Vec2::splat(collider.half.max_element()),
+                        };
+                        let geometry = ColliderGeometry { kind, half, ..collider };
+                        actions
+                            .inspector_actions
+                            .push(InspectorAction::SetColliderGeometry { entity, geometry });
+                        info.collider = Some(geometry);
                         _inspector_refresh = true;
                     }
                 });
-            });
+                match collider.kind {
+                    ColliderKind::Rect => ui.label(tr!(
+                        "inspector.size_x_offset",
+                        width = format!("{:.2}", collider.half.x * 2.0),
+                        height = format!("{:.2}", collider.half.y * 2.0),
+                        x = format!("{:.2}", collider.offset.x),
+                        y = format!("{:.2}", collider.offset.y)
+                    )),
+                    ColliderKind::Circle => ui.label(tr!(
+                        "inspector.radius_offset",
+                        radius = format!("{:.2}", collider.radius()),
+                        x = format!("{:.2}", collider.offset.x),
+                        y = format!("{:.2}", collider.offset.y)
+                    )),
+                };
+                ui.horizontal(|ui| {
+                    ui.checkbox(ctx.collider_edit_mode, tr!("inspector.edit_collider"));
+                    let (can_undo, can_redo) = ctx.collider_history;
+                    if ui.add_enabled(can_undo, egui::Button::new(tr!("inspector.undo"))).clicked() {
+                        actions.inspector_actions.push(InspectorAction::UndoColliderEdit);
+                    }
+                    if ui.add_enabled(can_redo, egui::Button::new(tr!("inspector.redo"))).clicked() {
+                        actions.inspector_actions.push(InspectorAction::RedoColliderEdit);
+                    }
+                });
+                if *ctx.collider_edit_mode {
+                    ui.small(tr!("inspector.drag_edge_radius_grips_to_resize"));
+                }
+            }
 
-            let mut picker_selection: Option<String> = None;
-            let selected_label = if script_path.trim().is_empty() {
-                "Select script asset".to_string()
-            } else {
-                script_path.clone()
-            };
-            egui::ComboBox::from_id_salt(("script_picker", entity.index()))
-                .selected_text(selected_label)
-                .show_ui(ui, |ui| {
-                    if ui.selectable_label(script_path.is_empty(), "<None>").clicked() {
-                        picker_selection = Some(String::new());
-                    }
-                    for path in ctx.script_paths {
-                        if ui.selectable_label(script_path == *path, path).clicked() {
-                            picker_selection = Some(path.clone());
+            ui.separator();
+            panel_header("inspector.script").show(ui, |ui| {
+                let mut script_path = info.script.as_ref().map(|s| s.path.clone()).unwrap_or_default();
+                let instance_id = info.script.as_ref().map(|s| s.instance_id).unwrap_or(0);
+                let mut mute_errors = info.script.as_ref().map(|s| s.mute_errors).unwrap_or(false);
+                let mut persist_state = info.script.as_ref().map(|s| s.persist_state).unwrap_or(false);
+                let has_script = info.script.is_some();
+                let path_trimmed = script_path.trim();
+                let path_known = ctx.script_paths.iter().any(|p| p == path_trimmed);
+
+                if let Some(err) = ctx.script_error {
+                    ui.colored_label(egui::Color32::RED, format!("Last script error: {err}"));
+                }
+                if ctx.script_error_for_entity {
+                    ui.colored_label(
+                        egui::Color32::RED,
+                        "This entity's script is currently errored; callbacks are paused until it succeeds.",
+                    );
+                }
+
+                ui.label(tr!("inspector.assign_a_script_to_this_entity"));
+                ui.horizontal(|ui| {
+                    ui.label(tr!("inspector.path"));
+                    let edit_response = ui.add(
+                        egui::TextEdit::singleline(&mut script_path)
+                            .hint_text("assets/scripts/example.rhai"),
+                    );
+                    if edit_response.changed() {
+                        if script_path.trim().is_empty() {
+                            info.script = None;
+                        } else {
+                            info.script = Some(ScriptInfo {
+                                path: script_path.clone(),
+                                instance_id,
+                                mute_errors,
+                                persist_state,
+                                persisted_state: None,
+                            });
                         }
                     }
-            });
-            if let Some(picked) = picker_selection {
-                let trimmed = picked.trim().to_string();
-                if trimmed.is_empty() {
-                    actions.inspector_actions.push(InspectorAction::RemoveScript { entity });
-                    info.script = None;
-                    script_path.clear();
-                } else {
-                    actions.inspector_actions.push(InspectorAction::SetScript { entity, path: trimmed.clone() });
-                    info.script = Some(ScriptInfo {
-                        path: trimmed,
-                        instance_id: 0,
-                        mute_errors,
-                        persist_state,
-                        persisted_state: None,
+                    if ui.button(tr!("inspector.apply")).clicked() && !script_path.trim().is_empty() {
+                        let trimmed = script_path.trim().to_string();
+                        actions
+                            .inspector_actions
+                            .push(InspectorAction::SetScript { entity, path: trimmed.clone() });
+                        info.script = Some(ScriptInfo {
+                            path: trimmed,
+                            instance_id: 0,
+                            mute_errors,
+                            persist_state,
+                            persisted_state: None,
+                        });
+                        _inspector_refresh = true;
+                    }
+                    ui.add_enabled_ui(has_script, |ui| {
+                        if ui.button(tr!("inspector.remove")).clicked() {
+                            actions.inspector_actions.push(InspectorAction::RemoveScript { entity });
+                            info.script = None;
+                            script_path.clear();
+                            _inspector_refresh = true;
+                        }
                     });
-                }
-                _inspector_refresh = true;
-            }
+                });
 
-            if has_script && !script_path.trim().is_empty() && !path_known {
-                ui.colored_label(
-                    egui::Color32::YELLOW,
-                    "Script not found under assets/scripts; path will be kept as-is.",
-                );
-            }
-            if has_script {
-                if instance_id != 0 {
-                    ui.small(format!("Instance id (runtime): {instance_id}"));
+                let mut picker_selection: Option<String> = None;
+                let selected_label = if script_path.trim().is_empty() {
+                    "Select script asset".to_string()
                 } else {
-                    ui.small("Instance id will be assigned at runtime.");
-                }
-                let mut muted = mute_errors;
-                if ui
-                    .checkbox(&mut muted, "Mute errors")
-                    .on_hover_text("Suppress console popups for this behaviour's errors.")
-                    .changed()
-                {
-                    mute_errors = muted;
-                    actions.inspector_actions.push(InspectorAction::SetScriptMute { entity, muted: mute_errors });
-                    if let Some(script) = info.script.as_mut() {
-                        script.mute_errors = mute_errors;
+                    script_path.clone()
+                };
+                egui::ComboBox::from_id_salt(("script_picker", entity.index()))
+                    .selected_text(selected_label)
+                    .show_ui(ui, |ui| {
+                        if ui.selectable_label(script_path.is_empty(), "<None>").clicked() {
+                            picker_selection = Some(String::new());
+                        }
+                        for path in ctx.script_paths {
+                            if ui.selectable_label(script_path == *path, path).clicked() {
+                                picker_selection = Some(path.clone());
+                            }
+                        }
+                    });
+                if let Some(picked) = picker_selection {
+                    let trimmed = picked.trim().to_string();
+                    if trimmed.is_empty() {
+                        actions.inspector_actions.push(InspectorAction::RemoveScript { entity });
+                        info.script = None;
+                        script_path.clear();
+                    } else {
+                        actions
+                            .inspector_actions
+                            .push(InspectorAction::SetScript { entity, path: trimmed.clone() });
+                        info.script = Some(ScriptInfo {
+                            path: trimmed,
+                            instance_id: 0,
+                            mute_errors,
+                            persist_state,
+                            persisted_state: None,
+                        });
                     }
                     _inspector_refresh = true;
                 }
-                let mut persist = persist_state;
-                if ui
-                    .checkbox(&mut persist, "Persist state")
-                    .on_hover_text("Save behaviour state across reloads and into scenes/checkpoints.")
-                    .changed()
-                {
-                    persist_state = persist;
-                    actions.inspector_actions.push(InspectorAction::SetScriptPersist { entity, persist });
-                    if let Some(script) = info.script.as_mut() {
-                        script.persist_state = persist_state;
-                    }
-                    _inspector_refresh = true;
+
+                if has_script && !script_path.trim().is_empty() && !path_known {
+                    ui.colored_label(
+                        egui::Color32::YELLOW,
+                        "Script not found under assets/scripts; path will be kept as-is.",
+                    );
                 }
-                ui.horizontal(|ui| {
+                if has_script {
+                    if instance_id != 0 {
+                        ui.small(tr!("inspector.instance_id_runtime", instance_id = instance_id));
+                    } else {
+                        ui.small(tr!("inspector.instance_id_will_be_assigned_at"));
+                    }
+                    let mut muted = mute_errors;
                     if ui
-                        .button("Reload")
-                        .on_hover_text("Restart this behaviour and rerun ready(); preserves persistent state when enabled.")
-                        .clicked()
+                        .checkbox(&mut muted, tr!("inspector.mute_errors"))
+                        .on_hover_text(tr!("inspector.mute_errors_hint"))
+                        .changed()
                     {
-                        actions.inspector_actions.push(InspectorAction::ReloadScript { entity, reset_state: false });
+                        mute_errors = muted;
+                        actions
+                            .inspector_actions
+                            .push(InspectorAction::SetScriptMute { entity, muted: mute_errors });
+                        if let Some(script) = info.script.as_mut() {
+                            script.mute_errors = mute_errors;
+                        }
                         _inspector_refresh = true;
                     }
+                    let mut persist = persist_state;
                     if ui
-                        .button("Reset state")
-                        .on_hover_text("Drop the behaviour instance and clear any persisted state before rerunning ready().")
-                        .clicked()
+                        .checkbox(&mut persist, tr!("inspector.persist_state"))
+                        .on_hover_text(tr!("inspector.persist_state_hint"))
+                        .changed()
                     {
-                        actions.inspector_actions.push(InspectorAction::ReloadScript { entity, reset_state: true });
+                        persist_state = persist;
+                        actions.inspector_actions.push(InspectorAction::SetScriptPersist { entity, persist });
+                        if let Some(script) = info.script.as_mut() {
+                            script.persist_state = persist_state;
+                        }
                         _inspector_refresh = true;
                     }
-                });
-            }
-            ui.small("Scripts are relative to the project root, e.g. assets/scripts/my_behaviour.rhai");
-        });
-        ui.collapsing("Particles", |ui| {
-            if let Some(mut emitter) = info.particle_emitter {
+                    ui.horizontal(|ui| {
+                        if ui
+                            .button(tr!("inspector.reload"))
+                            .on_hover_text(tr!("inspector.reload_hint"))
+                            .clicked()
+                        {
+                            actions
+                                .inspector_actions
+                                .push(InspectorAction::ReloadScript { entity, reset_state: false });
+                            _inspector_refresh = true;
+                        }
+                        if ui
+                            .button(tr!("inspector.reset_state"))
+                            .on_hover_text(tr!("inspector.reset_state_hint"))
+                            .clicked()
+                        {
+                            actions
+                                .inspector_actions
+                                .push(InspectorAction::ReloadScript { entity, reset_state: true });
+                            _inspector_refresh = true;
+                        }
+                    });
+                }
+                ui.small(tr!("inspector.scripts_are_relative_to_the_project"));
+            });
+            panel_header("inspector.particles").show(ui, |ui| {
+                if let Some(mut emitter) = info.particle_emitter {
                     let mut trail_enabled = emitter.trail.is_some();
                     let mut trail: ParticleTrail = emitter.trail.unwrap_or_default();
-                    ui.label("Emitter trail");
                     ui.horizontal(|ui| {
-                        ui.checkbox(&mut trail_enabled, "Enable");
-                        ui.label("Length scale");
+                        ui.label(tr!("inspector.particle_emitter"));
+                        component_clipboard_buttons(ui, entity, ComponentKind::ParticleEmitter, actions);
+                    });
+                    let mut shape = emitter.shape;
+                    ui.horizontal(|ui| {
+                        ui.label(tr!("inspector.shape"));
+                        egui::ComboBox::from_id_salt(("emitter_shape", entity.index()))
+                            .selected_text(shape.label())
+                            .show_ui(ui, |ui| {
+                                for option in [
+                                    EmitterShape::Point,
+                                    EmitterShape::Cone,
+                                    EmitterShape::CircleEdge { radius: 1.0 },
+                                    EmitterShape::Disc { radius: 1.0 },
+                                    EmitterShape::Box { half_width: 0.5, half_height: 0.5 },
+                                    EmitterShape::Line { length: 1.0 },
+                                ] {
+                                    let selected =
+                                        std::mem::discriminant(&shape) == std::mem::discriminant(&option);
+                                    if ui.selectable_label(selected, option.label()).clicked() && !selected {
+                                        shape = option;
+                                    }
+                                }
+                            });
+                    });
+                    match &mut shape {
+                        EmitterShape::CircleEdge { radius } | EmitterShape::Disc { radius } => {
+                            ui.horizontal(|ui| {
+                                ui.label(tr!("inspector.radius"));
+                                ui.add(egui::DragValue::new(radius).range(0.0..=50.0).speed(0.02));
+                            });
+                        }
+                        EmitterShape::Box { half_width, half_height } => {
+                            ui.horizontal(|ui| {
+                                ui.label(tr!("inspector.half_width"));
+                                ui.add(egui::DragValue::new(half_width).range(0.0..=50.0).speed(0.02));
+                                ui.label(tr!("inspector.half_height"));
+                                ui.add(egui::DragValue::new(half_height).range(0.0..=50.0).speed(0.02));
+                            });
+                        }
+                        EmitterShape::Line { length } => {
+                            ui.horizontal(|ui| {
+                                ui.label(tr!("inspector.length"));
+                                ui.add(egui::DragValue::new(length).range(0.0..=100.0).speed(0.02));
+                            });
+                        }
+                        EmitterShape::Point | EmitterShape::Cone => {}
+                    }
+                    if shape != emitter.shape {
+                        actions.inspector_actions.push(InspectorAction::SetEmitterShape { entity, shape });
+                        emitter.shape = shape;
+                        info.particle_emitter = Some(emitter);
+                        _inspector_refresh = true;
+                    }
+                    let mut collision_mode = emitter.collision_mode;
+                    let mut collision_events = emitter.collision_events;
+                    ui.horizontal(|ui| {
+                        ui.label(tr!("inspector.collision"));
+                        egui::ComboBox::from_id_salt(("emitter_collision", entity.index()))
+                            .selected_text(collision_mode.label())
+                            .show_ui(ui, |ui| {
+                                for option in [
+                                    ParticleCollisionMode::None,
+                                    ParticleCollisionMode::Bounce { restitution: 0.5 },
+                                    ParticleCollisionMode::Kill,
+                                    ParticleCollisionMode::Stick,
+                                ] {
+                                    let selected = std::mem::discriminant(&collision_mode)
+                                        == std::mem::discriminant(&option);
+                                    if ui.selectable_label(selected, option.label()).clicked() && !selected {
+                                        collision_mode = option;
+                                    }
+                                }
+                            });
+                    });
+                    if let ParticleCollisionMode::Bounce { restitution } = &mut collision_mode {
+                        ui.horizontal(|ui| {
+                            ui.label(tr!("inspector.restitution"));
+                            ui.add(egui::DragValue::new(restitution).range(0.0..=1.0).speed(0.01));
+                        });
+                    }
+                    if collision_mode.is_enabled() {
+                        ui.checkbox(&mut collision_events, tr!("inspector.raise_collision_events"));
+                    }
+                    if collision_mode != emitter.collision_mode
+                        || collision_events != emitter.collision_events
+                    {
+                        actions.inspector_actions.push(InspectorAction::SetEmitterCollision {
+                            entity,
+                            mode: collision_mode,
+                            events: collision_events,
+                        });
+                        emitter.collision_mode = collision_mode;
+                        emitter.collision_events = collision_events;
+                        info.particle_emitter = Some(emitter);
+                        _inspector_refresh = true;
+                    }
+                    let burst_count_id = egui::Id::new(("emitter_burst_count", entity.index()));
+                    let mut burst_count =
+                        ui.ctx().data_mut(|d| d.get_persisted::<u32>(burst_count_id).unwrap_or(100));
+                    ui.horizontal(|ui| {
+                        ui.label(tr!("inspector.burst"));
+                        if ui.add(egui::DragValue::new(&mut burst_count).range(1..=10_000)).changed() {
+                            ui.ctx().data_mut(|d| d.insert_persisted(burst_count_id, burst_count));
+                        }
+                        if ui
+                            .button(tr!("inspector.emit"))
+                            .on_hover_text(tr!("inspector.emit_hint"))
+                            .clicked()
+                        {
+                            actions
+                                .inspector_actions
+                                .push(InspectorAction::EmitterBurst { entity, count: burst_count });
+                        }
+                    });
+                    let impulse_speed_id = egui::Id::new(("emitter_burst_impulse", entity.index()));
+                    let mut impulse_speed = ui
+                        .ctx()
+                        .data_mut(|d| d.get_persisted::<f32>(impulse_speed_id).unwrap_or(emitter.speed));
+                    ui.horizontal(|ui| {
+                        ui.label(tr!("inspector.impulse"));
+                        if ui
+                            .add(egui::DragValue::new(&mut impulse_speed).range(0.0..=50.0).speed(0.05))
+                            .changed()
+                        {
+                            ui.ctx().data_mut(|d| d.insert_persisted(impulse_speed_id, impulse_speed));
+                        }
+                        if ui
+                            .button(tr!("inspector.burst_now"))
+                            .on_hover_text(tr!("inspector.burst_now_hint"))
+                            .clicked()
+                        {
+                            actions.inspector_actions.push(InspectorAction::SetEmitterBurst {
+                                entity,
+                                count: burst_count,
+                                impulse_speed,
+                            });
+                        }
+                    });
+                    ui.label(tr!("inspector.emitter_trail"));
+                    ui.horizontal(|ui| {
+                        ui.checkbox(&mut trail_enabled, tr!("inspector.enable"));
+                        ui.label(tr!("inspector.length_scale"));
                         ui.add(egui::DragValue::new(&mut trail.length_scale).range(0.01..=2.0).speed(0.01));
                     });
                     ui.horizontal(|ui| {
-                        ui.label("Min len");
+                        ui.label(tr!("inspector.min_len"));
                         ui.add(egui::DragValue::new(&mut trail.min_length).range(0.0..=5.0).speed(0.01));
-                        ui.label("Max len");
+                        ui.label(tr!("inspector.max_len"));
                         ui.add(egui::DragValue::new(&mut trail.max_length).range(0.01..=5.0).speed(0.01));
                     });
                     ui.horizontal(|ui| {
-                        ui.label("Width");
+                        ui.label(tr!("inspector.width"));
                         ui.add(egui::DragValue::new(&mut trail.width).range(0.01..=1.0).speed(0.01));
-                        ui.label("Fade");
+                        ui.label(tr!("inspector.fade"));
                         ui.add(egui::DragValue::new(&mut trail.fade).range(0.0..=1.0).speed(0.01));
                     });
                     let desired_trail = if trail_enabled { Some(trail) } else { None };
@@ -385,11 +683,14 @@ pub(super) fn show_entity_inspector(
                         _inspector_refresh = true;
                     }
                 } else {
-                    ui.label("No particle emitter on entity");
+                    ui.label(tr!("inspector.no_particle_emitter_on_entity"));
                 }
 
                 ui.separator();
-                ui.label("Force Field");
+                ui.horizontal(|ui| {
+                    ui.label(tr!("inspector.force_field"));
+                    component_clipboard_buttons(ui, entity, ComponentKind::ForceField, actions);
+                });
                 let mut field_enabled = info.force_field.is_some();
                 let mut field = info.force_field.unwrap_or_default();
                 let mut kind_label = match field.kind {
@@ -398,10 +699,10 @@ pub(super) fn show_entity_inspector(
                 }
                 .to_string();
                 ui.horizontal(|ui| {
-                    ui.checkbox(&mut field_enabled, "Enabled");
-                    ui.label("Strength");
+                    ui.checkbox(&mut field_enabled, tr!("inspector.enabled"));
+                    ui.label(tr!("inspector.strength"));
                     ui.add(egui::DragValue::new(&mut field.strength).speed(0.05));
-                    ui.label("Radius");
+                    ui.label(tr!("inspector.radius"));
                     ui.add(egui::DragValue::new(&mut field.radius).range(0.0..=10.0).speed(0.05));
                 });
                 egui::ComboBox::from_id_salt(("force_field_kind", entity.index()))
@@ -421,12 +722,16 @@ pub(super) fn show_entity_inspector(
                         ForceFalloff::Linear => "Linear",
                     })
                     .show_ui(ui, |ui| {
-                        ui.selectable_value(&mut field.falloff, ForceFalloff::None, "None");
-                        ui.selectable_value(&mut field.falloff, ForceFalloff::Linear, "Linear");
+                        ui.selectable_value(&mut field.falloff, ForceFalloff::None, tr!("inspector.none"));
+                        ui.selectable_value(
+                            &mut field.falloff,
+                            ForceFalloff::Linear,
+                            tr!("inspector.linear"),
+                        );
                     });
                 if matches!(field.kind, ForceFieldKind::Directional) {
                     ui.horizontal(|ui| {
-                        ui.label("Direction");
+                        ui.label(tr!("inspector.direction"));
                         ui.add(egui::DragValue::new(&mut field.direction.x).speed(0.01));
                         ui.add(egui::DragValue::new(&mut field.direction.y).speed(0.01));
                     });
@@ -446,20 +751,23 @@ pub(super) fn show_entity_inspector(
                 }
 
                 ui.separator();
-                ui.label("Attractor");
+                ui.horizontal(|ui| {
+                    ui.label(tr!("inspector.attractor"));
+                    component_clipboard_buttons(ui, entity, ComponentKind::Attractor, actions);
+                });
                 let mut attractor_enabled = info.attractor.is_some();
                 let mut attractor: ParticleAttractor = info.attractor.unwrap_or_default();
                 ui.horizontal(|ui| {
-                    ui.checkbox(&mut attractor_enabled, "Enabled");
-                    ui.label("Strength");
+                    ui.checkbox(&mut attractor_enabled, tr!("inspector.enabled"));
+                    ui.label(tr!("inspector.strength"));
                     ui.add(egui::DragValue::new(&mut attractor.strength).speed(0.05));
-                    ui.label("Radius");
+                    ui.label(tr!("inspector.radius"));
                     ui.add(egui::DragValue::new(&mut attractor.radius).range(0.0..=10.0).speed(0.05));
                 });
                 ui.horizontal(|ui| {
-                    ui.label("Min dist");
+                    ui.label(tr!("inspector.min_dist"));
                     ui.add(egui::DragValue::new(&mut attractor.min_distance).range(0.0..=5.0).speed(0.01));
-                    ui.label("Max accel");
+                    ui.label(tr!("inspector.max_accel"));
                     ui.add(
                         egui::DragValue::new(&mut attractor.max_acceleration).range(0.0..=50.0).speed(0.05),
                     );
@@ -470,8 +778,16 @@ pub(super) fn show_entity_inspector(
                         ForceFalloff::Linear => "Linear",
                     })
                     .show_ui(ui, |ui| {
-                        ui.selectable_value(&mut attractor.falloff, ForceFalloff::None, "None");
-                        ui.selectable_value(&mut attractor.falloff, ForceFalloff::Linear, "Linear");
+                        ui.selectable_value(
+                            &mut attractor.falloff,
+                            ForceFalloff::None,
+                            tr!("inspector.none"),
+                        );
+                        ui.selectable_value(
+                            &mut attractor.falloff,
+                            ForceFalloff::Linear,
+                            tr!("inspector.linear"),
+                        );
                     });
                 let desired_attractor = if attractor_enabled { Some(attractor) } else { None };
                 if desired_attractor != info.attractor {
@@ -502,7 +818,7 @@ pub(super) fn show_entity_inspector(
             let mut combo_items = clip_keys.clone();
             combo_items.insert(0, "<None>".to_string());
             ui.horizontal(|ui| {
-                ui.label("Transform Clip");
+                ui.label(tr!("inspector.transform_clip"));
                 egui::ComboBox::from_id_salt(("transform_clip_selector", entity.index()))
                     .selected_text(clip_combo.clone())
                     .show_ui(ui, |ui| {
@@ -531,29 +847,29 @@ pub(super) fn show_entity_inspector(
             if let Some(mut clip_info) = clip_info_opt.clone() {
                 if let Some(summary) = ctx.clip_assets.get(&clip_info.clip_key) {
                     if let Some(source) = summary.source.as_deref() {
-                        ui.small(format!("Source: {}", source));
+                        ui.small(tr!("inspector.source", source = source));
                     } else {
-                        ui.small("Source: n/a");
+                        ui.small(tr!("inspector.source_n_a"));
                     }
                 } else {
-                    ui.small("Source: n/a");
+                    ui.small(tr!("inspector.source_n_a"));
                 }
                 ui.horizontal(|ui| {
                     let mut playing = clip_info.playing;
-                    if ui.checkbox(&mut playing, "Playing").changed() {
+                    if ui.checkbox(&mut playing, tr!("inspector.playing")).changed() {
                         actions
                             .inspector_actions
                             .push(InspectorAction::SetTransformClipPlaying { entity, playing });
                         clip_info.playing = playing;
                         _inspector_refresh = true;
                     }
-                    if ui.button("Reset").clicked() {
+                    if ui.button(tr!("inspector.reset")).clicked() {
                         actions.inspector_actions.push(InspectorAction::ResetTransformClip { entity });
                         _inspector_refresh = true;
                     }
                 });
                 ui.horizontal(|ui| {
-                    ui.label("Speed");
+                    ui.label(tr!("inspector.speed"));
                     let mut speed = clip_info.speed;
                     if ui
                         .add(egui::DragValue::new(&mut speed).speed(0.05).range(0.0..=8.0).suffix("x"))
@@ -566,11 +882,22 @@ pub(super) fn show_entity_inspector(
                         _inspector_refresh = true;
                     }
                 });
+                if clip_info.looped {
+                    if let Some(loop_count) = loop_count_editor(ui, clip_info.loop_count) {
+                        actions
+                            .inspector_actions
+                            .push(InspectorAction::SetTransformClipLoopCount { entity, loop_count });
+                        clip_info.loop_count = loop_count;
+                        _inspector_refresh = true;
+                    }
+                }
                 ui.horizontal(|ui| {
-                    ui.label("Group");
+                    ui.label(tr!("inspector.group"));
                     let mut group_value = clip_info.group.clone().unwrap_or_default();
-                    let response =
-                        ui.add(egui::TextEdit::singleline(&mut group_value).hint_text("optional group id"));
+                    let response = ui.add(
+                        egui::TextEdit::singleline(&mut group_value)
+                            .hint_text(tr!("inspector.optional_group_id")),
+                    );
                     if response.changed() {
                         let trimmed = group_value.trim();
                         let group = if trimmed.is_empty() { None } else { Some(trimmed.to_string()) };
@@ -585,7 +912,9 @@ pub(super) fn show_entity_inspector(
                 let mut clip_time = clip_info.time.clamp(0.0, duration);
                 let slider_response = ui.add_enabled(
                     duration > 0.0,
-                    egui::Slider::new(&mut clip_time, 0.0..=duration).text("Time (s)").smart_aim(false),
+                    egui::Slider::new(&mut clip_time, 0.0..=duration)
+                        .text(tr!("inspector.time_s"))
+                        .smart_aim(false),
                 );
                 if slider_response.changed() {
                     actions
@@ -595,24 +924,65 @@ pub(super) fn show_entity_inspector(
                     _inspector_refresh = true;
                 }
                 if duration <= 0.0 {
-                    ui.label("Duration: 0 (static clip)");
+                    ui.label(tr!("inspector.duration_0_static_clip"));
                 } else {
-                    ui.label(format!("Duration: {:.3} s", duration));
+                    ui.label(tr!("inspector.duration_s", duration = format!("{:.3}", duration)));
                 }
                 if let Some(summary) = ctx.clip_assets.get(&clip_info.clip_key) {
                     let markers: Vec<f32> = summary.keyframe_markers.iter().copied().collect();
                     if !markers.is_empty() {
                         let formatted =
                             markers.iter().map(|t| format!("{:.3}", t)).collect::<Vec<_>>().join(", ");
-                        ui.small(format!("Keyframes: {}", formatted));
+                        ui.small(tr!("inspector.keyframes", formatted = formatted));
+                    }
+                }
+                ui.horizontal(|ui| {
+                    ui.label(tr!("inspector.events"));
+                    if ui.small_button(tr!("inspector.add_at_playhead")).clicked() {
+                        clip_info.events.push((clip_time, "clip_event".to_string()));
+                        actions.inspector_actions.push(InspectorAction::SetTransformClipEvents {
+                            entity,
+                            events: clip_info.events.clone(),
+                        });
+                        _inspector_refresh = true;
                     }
+                });
+                let mut events_changed = false;
+                let mut remove_event = None;
+                for (index, (event_time, event_name)) in clip_info.events.iter_mut().enumerate() {
+                    ui.horizontal(|ui| {
+                        events_changed |= ui
+                            .add(
+                                egui::DragValue::new(event_time)
+                                    .speed(0.01)
+                                    .range(0.0..=duration)
+                                    .suffix(" s"),
+                            )
+                            .changed();
+                        events_changed |=
+                            ui.add(egui::TextEdit::singleline(event_name).desired_width(120.0)).changed();
+                        if ui.small_button(tr!("inspector.remove")).clicked() {
+                            remove_event = Some(index);
+                        }
+                    });
+                }
+                if let Some(index) = remove_event {
+                    clip_info.events.remove(index);
+                    events_changed = true;
+                }
+                if events_changed {
+                    actions.inspector_actions.push(InspectorAction::SetTransformClipEvents {
+                        entity,
+                        events: clip_info.events.clone(),
+                    });
+                    _inspector_refresh = true;
                 }
 
                 let mut transform_mask = transform_mask_opt.unwrap_or_default();
                 ui.horizontal(|ui| {
-                    ui.label("Apply Transform");
+                    ui.label(tr!("inspector.apply_transform"));
                     let mut apply_translation = transform_mask.apply_translation;
-                    if ui.checkbox(&mut apply_translation, "Translation").changed() {
+                    if ui.checkbox(&mut apply_translation, tr!("inspector.translation")).changed() {
                         transform_mask.apply_translation = apply_translation;
                         actions
                             .inspector_actions
@@ -621,7 +991,7 @@ pub(super) fn show_entity_inspector(
                         _inspector_refresh = true;
                     }
                     let mut apply_rotation = transform_mask.apply_rotation;
-                    if ui.checkbox(&mut apply_rotation, "Rotation").changed() {
+                    if ui.checkbox(&mut apply_rotation, tr!("inspector.rotation")).changed() {
                         transform_mask.apply_rotation = apply_rotation;
                         actions
                             .inspector_actions
@@ -630,7 +1000,7 @@ pub(super) fn show_entity_inspector(
                         _inspector_refresh = true;
                     }
                     let mut apply_scale = transform_mask.apply_scale;
-                    if ui.checkbox(&mut apply_scale, "Scale").changed() {
+                    if ui.checkbox(&mut apply_scale, tr!("inspector.scale")).changed() {
                         transform_mask.apply_scale = apply_scale;
                         actions
                             .inspector_actions
@@ -642,9 +1012,9 @@ pub(super) fn show_entity_inspector(
 
                 let mut property_mask = property_mask_opt.unwrap_or_default();
                 ui.horizontal(|ui| {
-                    ui.label("Apply Properties");
+                    ui.label(tr!("inspector.apply_properties"));
                     let mut apply_tint = property_mask.apply_tint;
-                    if ui.checkbox(&mut apply_tint, "Tint").changed() {
+                    if ui.checkbox(&mut apply_tint, tr!("inspector.tint")).changed() {
                         property_mask.apply_tint = apply_tint;
                         actions
                             .inspector_actions
@@ -655,7 +1025,7 @@ pub(super) fn show_entity_inspector(
                 });
 
                 ui.horizontal(|ui| {
-                    ui.label("Tracks");
+                    ui.label(tr!("inspector.tracks"));
                     track_badge(
                         ui,
                         "Translation",
@@ -683,21 +1053,21 @@ pub(super) fn show_entity_inspector(
                 });
 
                 if let Some(value) = clip_info.sample_translation {
-                    ui.label(format!("Current translation: {}", format_vec2(value)));
+                    ui.label(tr!("inspector.current_translation", value = format_vec2(value)));
                 }
                 if let Some(value) = clip_info.sample_rotation {
-                    ui.label(format!("Current rotation: {:.3} rad", value));
+                    ui.label(tr!("inspector.current_rotation_rad", value = format!("{:.3}", value)));
                 }
                 if let Some(value) = clip_info.sample_scale {
-                    ui.label(format!("Current scale: {}", format_vec2(value)));
+                    ui.label(tr!("inspector.current_scale", value = format_vec2(value)));
                 }
                 if let Some(value) = clip_info.sample_tint {
-                    ui.label(format!("Current tint: {}", format_vec4(value)));
+                    ui.label(tr!("inspector.current_tint", value = format_vec4(value)));
                 }
 
                 clip_info_opt = Some(clip_info);
             } else if clip_keys.is_empty() {
-                ui.label("Transform Clip: n/a");
+                ui.label(tr!("inspector.transform_clip_n_a"));
             }
 
             info.transform_clip = clip_info_opt;
@@ -720,7 +1090,7 @@ pub(super) fn show_entity_inspector(
                 .map(|s| s.skeleton_key.clone())
                 .unwrap_or_else(|| "<None>".to_string());
             ui.horizontal(|ui| {
-                ui.label("Skeleton");
+                ui.label(tr!("inspector.skeleton"));
                 egui::ComboBox::from_id_salt(("skeleton_selector", entity.index()))
                     .selected_text(skeleton_combo.clone())
                     .show_ui(ui, |ui| {
@@ -749,15 +1119,15 @@ pub(super) fn show_entity_inspector(
             if let Some(mut skeleton_info) = skeleton_info_opt.clone() {
                 if let Some(summary) = ctx.skeleton_assets.get(&skeleton_info.skeleton_key) {
                     if let Some(source) = summary.source.as_deref() {
-                        ui.small(format!("Source: {}", source));
+                        ui.small(tr!("inspector.source", source = source));
                     } else {
-                        ui.small("Source: n/a");
+                        ui.small(tr!("inspector.source_n_a"));
                     }
                 } else {
-                    ui.small("Source: n/a");
+                    ui.small(tr!("inspector.source_n_a"));
                 }
                 ui.horizontal(|ui| {
-                    ui.label(format!("Bones: {}", skeleton_info.joint_count));
+                    ui.label(tr!("inspector.bones", joint_count = skeleton_info.joint_count));
                     let palette_text = format!(
                         "Palette: {}/{}",
                         skeleton_info.palette_joint_count, skeleton_info.joint_count
@@ -788,7 +1158,7 @@ pub(super) fn show_entity_inspector(
                     .unwrap_or_else(|| "<None>".to_string());
                 clip_keys.insert(0, "<None>".to_string());
                 ui.horizontal(|ui| {
-                    ui.label("Skeletal Clip");
+                    ui.label(tr!("inspector.skeletal_clip"));
                     egui::ComboBox::from_id_salt(("skeletal_clip_selector", entity.index()))
                         .selected_text(clip_combo.clone())
                         .show_ui(ui, |ui| {
@@ -818,20 +1188,20 @@ pub(super) fn show_entity_inspector(
                 if let Some(mut clip_info) = skeleton_info.clip.clone() {
                     ui.horizontal(|ui| {
                         let mut playing = clip_info.playing;
-                        if ui.checkbox(&mut playing, "Playing").changed() {
+                        if ui.checkbox(&mut playing, tr!("inspector.playing")).changed() {
                             actions
                                 .inspector_actions
                                 .push(InspectorAction::SetSkeletonClipPlaying { entity, playing });
                             clip_info.playing = playing;
                             _inspector_refresh = true;
                         }
-                        if ui.button("Reset Pose").clicked() {
+                        if ui.button(tr!("inspector.reset_pose")).clicked() {
                             actions.inspector_actions.push(InspectorAction::ResetSkeletonPose { entity });
                             _inspector_refresh = true;
                         }
                     });
                     ui.horizontal(|ui| {
-                        ui.label("Speed");
+                        ui.label(tr!("inspector.speed"));
                         let mut speed = clip_info.speed;
                         if ui
                             .add(egui::DragValue::new(&mut speed).speed(0.05).range(0.0..=4.0).suffix("x"))
@@ -844,11 +1214,22 @@ pub(super) fn show_entity_inspector(
                             _inspector_refresh = true;
                         }
                     });
+                    if clip_info.looped {
+                        if let Some(loop_count) = loop_count_editor(ui, clip_info.loop_count) {
+                            actions
+                                .inspector_actions
+                                .push(InspectorAction::SetSkeletonClipLoopCount { entity, loop_count });
+                            clip_info.loop_count = loop_count;
+                            _inspector_refresh = true;
+                        }
+                    }
                     ui.horizontal(|ui| {
-                        ui.label("Group");
+                        ui.label(tr!("inspector.group"));
                         let mut group_value = clip_info.group.clone().unwrap_or_default();
-                        let response = ui
-                            .add(egui::TextEdit::singleline(&mut group_value).hint_text("optional group id"));
+                        let response = ui.add(
+                            egui::TextEdit::singleline(&mut group_value)
+                                .hint_text(tr!("inspector.optional_group_id")),
+                        );
                         if response.changed() {
                             let trimmed = group_value.trim();
                             let group = if trimmed.is_empty() { None } else { Some(trimmed.to_string()) };
@@ -863,7 +1244,9 @@ pub(super) fn show_entity_inspector(
                     let mut clip_time = clip_info.time.clamp(0.0, duration);
                     let slider_response = ui.add_enabled(
                         duration > 0.0,
-                        egui::Slider::new(&mut clip_time, 0.0..=duration).text("Time (s)").smart_aim(false),
+                        egui::Slider::new(&mut clip_time, 0.0..=duration)
+                            .text(tr!("inspector.time_s"))
+                            .smart_aim(false),
                     );
                     if slider_response.changed() {
                         actions
@@ -873,22 +1256,72 @@ pub(super) fn show_entity_inspector(
                         _inspector_refresh = true;
                     }
                     if duration <= 0.0 {
-                        ui.label("Duration: 0 (static pose)");
+                        ui.label(tr!("inspector.duration_0_static_pose"));
                     } else {
-                        ui.label(format!("Duration: {:.3} s", duration));
+                        ui.label(tr!("inspector.duration_s", duration = format!("{:.3}", duration)));
                     }
                     skeleton_info.clip = Some(clip_info);
                 } else {
-                    ui.label("Skeletal clip: n/a");
+                    ui.label(tr!("inspector.skeletal_clip_n_a"));
                 }
+                show_skeleton_joints(ui, entity, &skeleton_info.joints);
                 skeleton_info_opt = Some(skeleton_info);
             } else if skeleton_items.len() <= 1 {
-                ui.label("Skeleton: n/a");
+                ui.label(tr!("inspector.skeleton_n_a"));
             }
             info.skeleton = skeleton_info_opt;
 
+            if let Some(mut graph_info) = info.animation_graph.clone() {
+                ui.separator();
+                ui.label(tr!("inspector.animation_graph", graph_key = graph_info.graph_key));
+                match graph_info.previous_state.as_deref() {
+                    Some(previous) => {
+                        ui.label(tr!(
+                            "inspector.graph_transition",
+                            previous = previous,
+                            state = graph_info.state
+                        ));
+                        ui.add(egui::ProgressBar::new(graph_info.transition_progress.clamp(0.0, 1.0)).text(
+                            tr!(
+                                "inspector.transition",
+                                percent = format!("{:.0}", graph_info.transition_progress * 100.0)
+                            ),
+                        ));
+                    }
+                    None => {
+                        ui.label(tr!("inspector.graph_state", state = graph_info.state));
+                    }
+                }
+                for (name, value) in graph_info.parameters.iter_mut() {
+                    ui.horizontal(|ui| {
+                        ui.label(name.as_str());
+                        if ui.add(egui::DragValue::new(value).speed(0.01)).changed() {
+                            actions.inspector_actions.push(InspectorAction::SetGraphParameter {
+                                entity,
+                                name: name.clone(),
+                                value: *value,
+                            });
+                            _inspector_refresh = true;
+                        }
+                    });
+                }
+                if !graph_info.blend_weights.is_empty() {
+                    let weights: Vec<String> = graph_info
+                        .blend_weights
+                        .iter()
+                        .map(|(clip, weight)| format!("{clip} {:.0}%", weight * 100.0))
+                        .collect();
+                    ui.label(tr!("inspector.blend", plugins = weights.join(", ")));
+                }
+                info.animation_graph = Some(graph_info);
+            }
+
             if let Some(mut sprite) = info.sprite.clone() {
                 ui.separator();
+                ui.horizontal(|ui| {
+                    ui.label(tr!("inspector.sprite"));
+                    component_clipboard_buttons(ui, entity, ComponentKind::Sprite, actions);
+                });
                 let mut skip_sprite_controls = false;
                 let mut atlas_selection = sprite.atlas.clone();
                 let mut atlas_keys: Vec<String> = ctx.atlas_keys.to_vec();
@@ -897,7 +1330,7 @@ pub(super) fn show_entity_inspector(
                     atlas_keys.sort();
                 }
                 ui.horizontal(|ui| {
-                    ui.label("Atlas");
+                    ui.label(tr!("inspector.atlas"));
                     egui::ComboBox::from_id_salt(("sprite_atlas_combo", entity.index()))
                         .selected_text(atlas_selection.clone())
                         .show_ui(ui, |ui| {
@@ -921,12 +1354,12 @@ pub(super) fn show_entity_inspector(
                 }
                 if let Some(summary) = ctx.atlas_assets.get(&sprite.atlas) {
                     if let Some(source) = summary.source.as_deref() {
-                        ui.small(format!("Source: {}", source));
+                        ui.small(tr!("inspector.source", source = source));
                     } else {
-                        ui.small("Source: n/a");
+                        ui.small(tr!("inspector.source_n_a"));
                     }
                 } else {
-                    ui.small("Source: n/a");
+                    ui.small(tr!("inspector.source_n_a"));
                 }
                 let key_buffer_id = egui::Id::new(("sprite_atlas_new_key", entity.index()));
                 let mut atlas_key_input = ui
@@ -944,9 +1377,12 @@ pub(super) fn show_entity_inspector(
                     .data_mut(|d| d.get_persisted::<String>(path_buffer_id))
                     .unwrap_or_else(|| default_source.clone());
                 ui.horizontal(|ui| {
-                    ui.label("Load atlas");
+                    ui.label(tr!("inspector.load_atlas"));
                     if ui
-                        .add(egui::TextEdit::singleline(&mut atlas_key_input).hint_text("atlas key"))
+                        .add(
+                            egui::TextEdit::singleline(&mut atlas_key_input)
+                                .hint_text(tr!("inspector.atlas_key")),
+                        )
                         .changed()
                     {
                         ui.ctx().data_mut(|d| {
@@ -959,7 +1395,8 @@ pub(super) fn show_entity_inspector(
                     }
                     if ui
                         .add(
-                            egui::TextEdit::singleline(&mut atlas_path_input).hint_text("path/to/atlas.json"),
+                            egui::TextEdit::singleline(&mut atlas_path_input)
+                                .hint_text(tr!("inspector.path_to_atlas_json")),
                         )
                         .changed()
                     {
@@ -971,11 +1408,11 @@ pub(super) fn show_entity_inspector(
                             }
                         });
                     }
-                    if ui.button("Load & Assign").clicked() {
+                    if ui.button(tr!("inspector.load_assign")).clicked() {
                         let key_trimmed = atlas_key_input.trim();
                         let path_trimmed = atlas_path_input.trim();
                         if key_trimmed.is_empty() || path_trimmed.is_empty() {
-                            *ctx.inspector_status = Some("Atlas key and path required".to_string());
+                            *ctx.inspector_status = Some(tr!("inspector.atlas_key_and_path_required"));
                         } else {
                             actions.sprite_atlas_requests.push(SpriteAtlasRequest {
                                 entity,
@@ -1005,24 +1442,88 @@ pub(super) fn show_entity_inspector(
                         info.sprite = Some(sprite.clone());
                         _inspector_refresh = true;
                     }
+                    let mut z_order = sprite.z_order;
+                    ui.horizontal(|ui| {
+                        ui.label(tr!("inspector.z_order"));
+                        if ui
+                            .add(egui::DragValue::new(&mut z_order).speed(0.25))
+                            .on_hover_text(tr!("inspector.draw_order_within_the_hint"))
+                            .changed()
+                        {
+                            actions
+                                .inspector_actions
+                                .push(InspectorAction::SetSpriteZOrder { entity, z_order });
+                            sprite.z_order = z_order;
+                            info.sprite = Some(sprite.clone());
+                        }
+                    });
+                    let normal_buffer_id = egui::Id::new(("sprite_normal_map_path", entity.index()));
+                    let mut normal_path_input = ui
+                        .ctx()
+                        .data_mut(|d| d.get_persisted::<String>(normal_buffer_id))
+                        .unwrap_or_else(|| sprite.normal_map.clone().unwrap_or_default());
+                    ui.horizontal(|ui| {
+                        ui.label(tr!("inspector.normal_map"));
+                        if ui
+                            .add(
+                                egui::TextEdit::singleline(&mut normal_path_input)
+                                    .hint_text(tr!("inspector.path_to_normal_png")),
+                            )
+                            .on_hover_text(tr!("inspector.path_to_normal_png_hint"))
+                            .changed()
+                        {
+                            ui.ctx().data_mut(|d| {
+                                d.insert_persisted(normal_buffer_id, normal_path_input.clone())
+                            });
+                        }
+                        let trimmed = normal_path_input.trim();
+                        if ui
+                            .add_enabled(
+                                !trimmed.is_empty() && sprite.normal_map.as_deref() != Some(trimmed),
+                                egui::Button::new(tr!("inspector.apply")),
+                            )
+                            .clicked()
+                        {
+                            actions.inspector_actions.push(InspectorAction::SetSpriteNormalMap {
+                                entity,
+                                path: Some(trimmed.to_string()),
+                            });
+                            sprite.normal_map = Some(trimmed.to_string());
+                            info.sprite = Some(sprite.clone());
+                        }
+                        if ui
+                            .add_enabled(
+                                sprite.normal_map.is_some(),
+                                egui::Button::new(tr!("inspector.clear")),
+                            )
+                            .clicked()
+                        {
+                            actions
+                                .inspector_actions
+                                .push(InspectorAction::SetSpriteNormalMap { entity, path: None });
+                            ui.ctx().data_mut(|d| d.remove::<String>(normal_buffer_id));
+                            sprite.normal_map = None;
+                            info.sprite = Some(sprite.clone());
+                        }
+                    });
                     let timeline_names = ctx
                         .atlas_assets
                         .get(&sprite.atlas)
                         .map(|summary| summary.timeline_names.iter().cloned().collect::<Vec<_>>())
                         .unwrap_or_default();
                     if timeline_names.is_empty() {
-                        ui.label("Timelines: none defined for atlas");
+                        ui.label(tr!("inspector.timelines_none_defined_for_atlas"));
                     } else {
                         let mut desired_timeline =
                             sprite.animation.as_ref().map(|anim| anim.timeline.clone());
                         let original_timeline = desired_timeline.clone();
                         ui.horizontal(|ui| {
-                            ui.label("Timeline");
+                            ui.label(tr!("inspector.timeline"));
                             let combo_id = ("sprite_timeline_combo", entity.index());
                             egui::ComboBox::from_id_salt(combo_id)
                                 .selected_text(desired_timeline.as_deref().unwrap_or("None").to_string())
                                 .show_ui(ui, |ui| {
-                                    ui.selectable_value(&mut desired_timeline, None, "None");
+                                    ui.selectable_value(&mut desired_timeline, None, tr!("inspector.none"));
                                     for name in &timeline_names {
                                         ui.selectable_value(&mut desired_timeline, Some(name.clone()), name);
                                     }
@@ -1038,9 +1539,10 @@ pub(super) fn show_entity_inspector(
                             info.sprite = Some(sprite.clone());
                         }
                         if let Some(anim) = sprite.animation.as_ref() {
-                            ui.label(format!("Loop Mode: {}", anim.loop_mode));
+                            ui.label(tr!("inspector.loop_mode", loop_mode = anim.loop_mode));
                             ui.horizontal(|ui| {
-                                let play_label = if anim.playing { "Pause" } else { "Play" };
+                                let play_label =
+                                    if anim.playing { tr!("inspector.pause") } else { tr!("inspector.play") };
                                 if ui.button(play_label).clicked() {
                                     actions.inspector_actions.push(
                                         InspectorAction::SetSpriteAnimationPlaying {
@@ -1050,22 +1552,33 @@ pub(super) fn show_entity_inspector(
                                     );
                                     _inspector_refresh = true;
                                 }
-                                if ui.button("Reset").clicked() {
+                                if ui.button(tr!("inspector.reset")).clicked() {
                                     actions
                                         .inspector_actions
                                         .push(InspectorAction::ResetSpriteAnimation { entity });
                                     _inspector_refresh = true;
                                 }
                                 let mut looped = anim.looped;
-                                if ui.checkbox(&mut looped, "Loop").changed() {
+                                if ui.checkbox(&mut looped, tr!("inspector.loop")).changed() {
                                     actions
                                         .inspector_actions
                                         .push(InspectorAction::SetSpriteAnimationLooped { entity, looped });
                                     _inspector_refresh = true;
                                 }
                             });
+                            if anim.looped {
+                                if let Some(loop_count) = loop_count_editor(ui, anim.loop_count) {
+                                    actions.inspector_actions.push(
+                                        InspectorAction::SetSpriteAnimationLoopCount { entity, loop_count },
+                                    );
+                                    _inspector_refresh = true;
+                                }
+                            }
                             let mut speed = anim.speed;
-                            if ui.add(egui::Slider::new(&mut speed, 0.0..=5.0).text("Speed")).changed() {
+                            if ui
+                                .add(egui::Slider::new(&mut speed, 0.0..=5.0).text(tr!("inspector.speed")))
+                                .changed()
+                            {
                                 actions
                                     .inspector_actions
                                     .push(InspectorAction::SetSpriteAnimationSpeed { entity, speed });
@@ -1073,7 +1586,7 @@ pub(super) fn show_entity_inspector(
                             }
                             let mut start_offset = anim.start_offset;
                             ui.horizontal(|ui| {
-                                ui.label("Start Offset");
+                                ui.label(tr!("inspector.start_offset"));
                                 if ui
                                     .add(
                                         egui::DragValue::new(&mut start_offset)
@@ -1093,7 +1606,7 @@ pub(super) fn show_entity_inspector(
                                 }
                             });
                             let mut random_start = anim.random_start;
-                            if ui.checkbox(&mut random_start, "Randomize Start").changed() {
+                            if ui.checkbox(&mut random_start, tr!("inspector.randomize_start")).changed() {
                                 actions.inspector_actions.push(
                                     InspectorAction::SetSpriteAnimationRandomStart { entity, random_start },
                                 );
@@ -1101,7 +1614,7 @@ pub(super) fn show_entity_inspector(
                             }
                             let mut group_label = anim.group.clone().unwrap_or_default();
                             ui.horizontal(|ui| {
-                                ui.label("Group");
+                                ui.label(tr!("inspector.group"));
                                 if ui.text_edit_singleline(&mut group_label).changed() {
                                     let trimmed = group_label.trim();
                                     let group =
@@ -1119,16 +1632,16 @@ pub(super) fn show_entity_inspector(
                                 let duration_ms =
                                     (anim.frame_duration.max(0.0) * 1_000.0).clamp(0.0, f32::MAX);
                                 let elapsed_ms = (anim.frame_elapsed.max(0.0) * 1_000.0).clamp(0.0, f32::MAX);
-                                ui.label(format!(
-                                    "Frame {}/{} - duration: {:.0} ms - elapsed: {:.0} ms - region: {}",
-                                    frame_index + 1,
-                                    frame_count,
-                                    duration_ms,
-                                    elapsed_ms,
-                                    region_label
+                                ui.label(tr!(
+                                    "inspector.frame_duration_ms_elapsed_ms",
+                                    frame = frame_index + 1,
+                                    frame_count = frame_count,
+                                    duration_ms = format!("{:.0}", duration_ms),
+                                    elapsed_ms = format!("{:.0}", elapsed_ms),
+                                    region_label = region_label
                                 ));
                                 if anim.frame_events.is_empty() {
-                                    ui.label("Events: none");
+                                    ui.label(tr!("inspector.events_none"));
                                 } else {
                                     let joined = anim.frame_events.join(", ");
                                     ui.colored_label(
@@ -1142,10 +1655,9 @@ pub(super) fn show_entity_inspector(
                                 let mut preview_events_enabled = ui.ctx().data_mut(|d| {
                                     d.get_persisted::<bool>(preview_toggle_id).unwrap_or(false)
                                 });
-                                let preview_toggle_response =
-                                    ui.checkbox(&mut preview_events_enabled, "Preview events").on_hover_text(
-                                        "When enabled, scrubbing logs frame events to the inspector log",
-                                    );
+                                let preview_toggle_response = ui
+                                    .checkbox(&mut preview_events_enabled, tr!("inspector.preview_events"))
+                                    .on_hover_text(tr!("inspector.preview_events_hint"));
                                 if preview_toggle_response.changed() {
                                     ui.ctx().data_mut(|d| {
                                         if preview_events_enabled {
@@ -1160,8 +1672,8 @@ pub(super) fn show_entity_inspector(
                                 ui.separator();
                                 let max_index = (frame_count - 1) as i32;
                                 let mut preview_frame = frame_index as i32;
-                                let slider =
-                                    egui::Slider::new(&mut preview_frame, 0..=max_index).text("Scrub");
+                                let slider = egui::Slider::new(&mut preview_frame, 0..=max_index)
+                                    .text(tr!("inspector.scrub"));
                                 if ui.add(slider).changed() {
                                     let target = preview_frame.clamp(0, max_index) as usize;
                                     actions.inspector_actions.push(
@@ -1212,12 +1724,12 @@ pub(super) fn show_entity_inspector(
                     }
                 }
             } else {
-                ui.label("Sprite: n/a");
+                ui.label(tr!("inspector.sprite_n_a"));
             }
 
             if let Some(mut mesh) = info.mesh.clone() {
                 ui.separator();
-                ui.label(format!("Mesh: {}", mesh.key));
+                ui.label(tr!("inspector.mesh", mesh = mesh.key));
                 let mut desired_material = mesh.material.clone();
                 let selected_text = match mesh.material.as_ref() {
                     Some(key) => {
@@ -1231,16 +1743,19 @@ pub(super) fn show_entity_inspector(
                     }
                     None => "Use mesh material (asset default)".to_string(),
                 };
-                egui::ComboBox::from_label("Material Override").selected_text(selected_text).show_ui(
-                    ui,
-                    |ui| {
-                        ui.selectable_value(&mut desired_material, None, "Use mesh material (asset default)");
+                egui::ComboBox::from_label(tr!("inspector.material_override"))
+                    .selected_text(selected_text)
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(
+                            &mut desired_material,
+                            None,
+                            tr!("inspector.use_mesh_material_asset_default"),
+                        );
                         for option in ctx.material_options {
                             let entry_label = format!("{} ({})", option.label, option.key);
                             ui.selectable_value(&mut desired_material, Some(option.key.clone()), entry_label);
                         }
-                    },
-                );
+                    });
                 if desired_material != mesh.material {
                     actions.inspector_actions.push(InspectorAction::SetMeshMaterial {
                         entity,
@@ -1250,15 +1765,51 @@ pub(super) fn show_entity_inspector(
                     info.mesh = Some(mesh.clone());
                     _inspector_refresh = true;
                 }
+                // Cull mode belongs to the material, so it edits the override or, without one, the
+                // materials the mesh's submeshes draw with.
+                let mut cull_targets: Vec<&str> = match mesh.material.as_deref() {
+                    Some(key) => vec![key],
+                    None => ctx
+                        .mesh_subsets
+                        .get(&mesh.key)
+                        .map(|subsets| {
+                            subsets.iter().filter_map(|subset| subset.material.as_deref()).collect()
+                        })
+                        .unwrap_or_default(),
+                };
+                cull_targets.sort_unstable();
+                cull_targets.dedup();
+                for option in cull_targets
+                    .iter()
+                    .filter_map(|key| ctx.material_options.iter().find(|option| option.key == *key))
+                {
+                    let mut cull_mode = option.cull_mode;
+                    ui.horizontal(|ui| {
+                        ui.label(tr!("inspector.cull_mode", material = option.label));
+                        egui::ComboBox::from_id_salt(("material_cull_mode", option.key.as_str()))
+                            .selected_text(cull_mode_label(cull_mode))
+                            .show_ui(ui, |ui| {
+                                for mode in MaterialCullMode::ALL {
+                                    ui.selectable_value(&mut cull_mode, mode, cull_mode_label(mode));
+                                }
+                            });
+                    });
+                    if cull_mode != option.cull_mode {
+                        actions.inspector_actions.push(InspectorAction::SetMaterialCullMode {
+                            key: option.key.clone(),
+                            cull_mode,
+                        });
+                    }
+                }
                 let mut cast_shadows = mesh.lighting.cast_shadows;
                 let mut receive_shadows = mesh.lighting.receive_shadows;
                 let mut shadow_flags_changed = false;
                 ui.horizontal(|ui| {
-                    ui.label("Shadows");
-                    if ui.checkbox(&mut cast_shadows, "Cast").changed() {
+                    ui.label(tr!("inspector.shadows"));
+                    if ui.checkbox(&mut cast_shadows, tr!("inspector.cast")).changed() {
                         shadow_flags_changed = true;
                     }
-                    if ui.checkbox(&mut receive_shadows, "Receive").changed() {
+                    if ui.checkbox(&mut receive_shadows, tr!("inspector.receive")).changed() {
                         shadow_flags_changed = true;
                     }
                 });
@@ -1273,17 +1824,42 @@ pub(super) fn show_entity_inspector(
                     info.mesh = Some(mesh.clone());
                     _inspector_refresh = true;
                 }
+                let mut limit_distance = mesh.max_draw_distance.is_some();
+                let mut max_distance = mesh.max_draw_distance.unwrap_or(100.0);
+                let mut draw_distance_changed = false;
+                ui.horizontal(|ui| {
+                    if ui.checkbox(&mut limit_distance, tr!("inspector.max_draw_distance")).changed() {
+                        draw_distance_changed = true;
+                    }
+                    if limit_distance
+                        && ui
+                            .add(egui::DragValue::new(&mut max_distance).speed(1.0).range(0.1..=100_000.0))
+                            .changed()
+                    {
+                        draw_distance_changed = true;
+                    }
+                });
+                if draw_distance_changed {
+                    let distance = limit_distance.then_some(max_distance);
+                    actions
+                        .inspector_actions
+                        .push(InspectorAction::SetMeshMaxDrawDistance { entity, distance });
+                    mesh.max_draw_distance = distance;
+                    info.mesh = Some(mesh.clone());
+                    _inspector_refresh = true;
+                }
                 if let Some(subsets) = ctx.mesh_subsets.get(&mesh.key).map(|arc| arc.as_ref()) {
-                    ui.collapsing("Submeshes", |ui| {
+                    panel_header("inspector.submeshes").show(ui, |ui| {
                         for (index, subset) in subsets.iter().enumerate() {
                             let subset_name = subset.name.as_deref().unwrap_or("unnamed");
                             let material_label = subset.material.as_deref().unwrap_or("default");
-                            ui.label(format!(
-                                "#{index}: {} | indices {}-{} | material: {}",
-                                subset_name,
-                                subset.index_offset,
-                                subset.index_offset + subset.index_count,
-                                material_label
+                            ui.label(tr!(
+                                "inspector.indices_material",
+                                index = index,
+                                subset_name = subset_name,
+                                index_offset = subset.index_offset,
+                                index_end = subset.index_offset + subset.index_count,
+                                material_label = material_label
                             ));
                         }
                     });
@@ -1296,17 +1872,19 @@ pub(super) fn show_entity_inspector(
 
                 let base_color_changed = ui
                     .horizontal(|ui| {
-                        ui.label("Base Color");
+                        ui.label(tr!("inspector.base_color"));
                         ui.color_edit_button_rgb(&mut base_color_arr).changed()
                     })
                     .inner;
-                let metallic_changed =
-                    ui.add(egui::Slider::new(&mut metallic, 0.0..=1.0).text("Metallic")).changed();
-                let roughness_changed =
-                    ui.add(egui::Slider::new(&mut roughness, 0.04..=1.0).text("Roughness")).changed();
+                let metallic_changed = ui
+                    .add(egui::Slider::new(&mut metallic, 0.0..=1.0).text(tr!("inspector.metallic")))
+                    .changed();
+                let roughness_changed = ui
+                    .add(egui::Slider::new(&mut roughness, 0.04..=1.0).text(tr!("inspector.roughness")))
+                    .changed();
                 let mut emissive_changed = false;
                 ui.horizontal(|ui| {
-                    if ui.checkbox(&mut emissive_enabled, "Emissive").changed() {
+                    if ui.checkbox(&mut emissive_enabled, tr!("inspector.emissive")).changed() {
                         emissive_changed = true;
                     }
                     if emissive_enabled && ui.color_edit_button_rgb(&mut emissive_arr).changed() {
@@ -1337,7 +1915,7 @@ pub(super) fn show_entity_inspector(
                 if let Some(mut mesh_tx) = info.mesh_transform.clone() {
                     let mut translation3 = mesh_tx.translation;
                     ui.horizontal(|ui| {
-                        ui.label("Position (X/Y/Z)");
+                        ui.label(tr!("inspector.position_x_y_z"));
                         let mut changed = false;
                         changed |= ui.add(egui::DragValue::new(&mut translation3.x).speed(0.01)).changed();
                         changed |= ui.add(egui::DragValue::new(&mut translation3.y).speed(0.01)).changed();
@@ -1359,7 +1937,7 @@ pub(super) fn show_entity_inspector(
                         rotation_euler.2.to_degrees(),
                     );
                     ui.horizontal(|ui| {
-                        ui.label("Rotation (deg)");
+                        ui.label(tr!("inspector.rotation_deg"));
                         let mut changed = false;
                         changed |= ui.add(egui::DragValue::new(&mut rotation_deg.x).speed(0.5)).changed();
                         changed |= ui.add(egui::DragValue::new(&mut rotation_deg.y).speed(0.5)).changed();
@@ -1381,7 +1959,7 @@ pub(super) fn show_entity_inspector(
 
                     let mut scale3 = mesh_tx.scale;
                     ui.horizontal(|ui| {
-                        ui.label("Scale (XYZ)");
+                        ui.label(tr!("inspector.scale_xyz"));
                         let mut changed = false;
                         changed |= ui.add(egui::DragValue::new(&mut scale3.x).speed(0.01)).changed();
                         changed |= ui.add(egui::DragValue::new(&mut scale3.y).speed(0.01)).changed();
@@ -1399,20 +1977,20 @@ pub(super) fn show_entity_inspector(
 
                     info.mesh_transform = Some(mesh_tx);
                 } else {
-                    ui.label("Mesh transform: n/a");
+                    ui.label(tr!("inspector.mesh_transform_n_a"));
                 }
             }
 
             let skeleton_entities = ctx.skeleton_entities;
             if let Some(mut skin_mesh) = info.skin_mesh.clone() {
                 ui.separator();
-                ui.label("Skinning");
+                ui.label(tr!("inspector.skinning"));
                 if let Some(ref mesh_key) = skin_mesh.mesh_key {
-                    ui.small(format!("Mesh key: {}", mesh_key));
+                    ui.small(tr!("inspector.mesh_key", mesh_key = mesh_key));
                 }
                 let mut joint_count = skin_mesh.joint_count as u32;
                 ui.horizontal(|ui| {
-                    ui.label("Joint Count");
+                    ui.label(tr!("inspector.joint_count"));
                     if ui.add(egui::DragValue::new(&mut joint_count).speed(1.0).range(0..=4096)).changed() {
                         actions.inspector_actions.push(InspectorAction::SetSkinMeshJointCount {
                             entity,
@@ -1441,7 +2019,7 @@ pub(super) fn show_entity_inspector(
                     }
                 }
                 ui.horizontal(|ui| {
-                    ui.label("Skeleton");
+                    ui.label(tr!("inspector.skeleton"));
                     egui::ComboBox::from_id_salt(("skin_mesh_skeleton", entity.index()))
                         .selected_text(match desired_skeleton {
                             Some(current) => skin_mesh
@@ -1472,11 +2050,11 @@ pub(super) fn show_entity_inspector(
                 }
                 let mut skin_mesh_removed = false;
                 ui.horizontal(|ui| {
-                    if ui.button("Reset Joint Count from Skeleton").clicked() {
+                    if ui.button(tr!("inspector.reset_joint_count_from_skeleton")).clicked() {
                         actions.inspector_actions.push(InspectorAction::SyncSkinMeshJointCount { entity });
                         _inspector_refresh = true;
                     }
-                    if ui.button("Remove Skin Mesh").clicked() {
+                    if ui.button(tr!("inspector.remove_skin_mesh")).clicked() {
                         actions.inspector_actions.push(InspectorAction::DetachSkinMesh { entity });
                         _inspector_refresh = true;
                         info.skin_mesh = None;
@@ -1489,8 +2067,8 @@ pub(super) fn show_entity_inspector(
             } else {
                 ui.separator();
                 ui.horizontal(|ui| {
-                    ui.label("Skinning: n/a");
-                    if ui.button("Add Skin Mesh").clicked() {
+                    ui.label(tr!("inspector.skinning_n_a"));
+                    if ui.button(tr!("inspector.add_skin_mesh")).clicked() {
                         actions.inspector_actions.push(InspectorAction::AttachSkinMesh { entity });
                         _inspector_refresh = true;
                     }
@@ -1499,7 +2077,7 @@ pub(super) fn show_entity_inspector(
 
             ui.separator();
             let mut tinted = info.tint.is_some();
-            if ui.checkbox(&mut tinted, "Tint override").changed() {
+            if ui.checkbox(&mut tinted, tr!("inspector.tint_override")).changed() {
                 if tinted {
                     let color = Vec4::splat(1.0);
                     actions
@@ -1514,21 +2092,127 @@ pub(super) fn show_entity_inspector(
             }
             if let Some(color) = info.tint {
                 let mut color_arr = color.to_array();
-                if ui.color_edit_button_rgba_unmultiplied(&mut color_arr).changed() {
-                    let vec = Vec4::from_array(color_arr);
-                    actions.inspector_actions.push(InspectorAction::SetMeshTint { entity, tint: Some(vec) });
-                    info.tint = Some(vec);
+                ui.horizontal(|ui| {
+                    if ui.color_edit_button_rgba_unmultiplied(&mut color_arr).changed() {
+                        let vec = Vec4::from_array(color_arr);
+                        actions
+                            .inspector_actions
+                            .push(InspectorAction::SetMeshTint { entity, tint: Some(vec) });
+                        info.tint = Some(vec);
+                        _inspector_refresh = true;
+                    }
+                    component_clipboard_buttons(ui, entity, ComponentKind::Tint, actions);
+                    if ui.button(tr!("inspector.reset")).clicked() {
+                        actions.inspector_actions.push(InspectorAction::ResetTint { entity });
+                        info.tint = Some(Vec4::ONE);
+                        _inspector_refresh = true;
+                    }
+                });
+            }
+
+            let mut curve_info_opt: Option<TintCurveInfo> = info.tint_curve.clone();
+            let mut curve_items: Vec<String> = ctx.color_curve_keys.to_vec();
+            if let Some(ref curve_info) = curve_info_opt {
+                if !curve_items.contains(&curve_info.curve_key) {
+                    curve_items.push(curve_info.curve_key.clone());
+                    curve_items.sort();
+                }
+            }
+            if !curve_items.is_empty() {
+                curve_items.insert(0, "<None>".to_string());
+                let mut curve_combo = curve_info_opt
+                    .as_ref()
+                    .map(|curve| curve.curve_key.clone())
+                    .unwrap_or_else(|| "<None>".to_string());
+                ui.horizontal(|ui| {
+                    ui.label(tr!("inspector.tint_curve"));
+                    egui::ComboBox::from_id_salt(("tint_curve_selector", entity.index()))
+                        .selected_text(curve_combo.clone())
+                        .show_ui(ui, |ui| {
+                            for key in &curve_items {
+                                ui.selectable_value(&mut curve_combo, key.clone(), key);
+                            }
+                        });
+                });
+                if curve_combo == "<None>" {
+                    if curve_info_opt.is_some() {
+                        actions.inspector_actions.push(InspectorAction::ClearTintCurve { entity });
+                        curve_info_opt = None;
+                        _inspector_refresh = true;
+                    }
+                } else if curve_info_opt.as_ref().is_none_or(|curve| curve.curve_key != curve_combo) {
+                    let (duration, loop_mode) = curve_info_opt
+                        .as_ref()
+                        .map(|curve| (curve.duration, curve.loop_mode))
+                        .unwrap_or((1.0, SpriteAnimationLoopMode::Loop));
+                    actions.inspector_actions.push(InspectorAction::SetTintCurve {
+                        entity,
+                        curve_key: curve_combo.clone(),
+                        duration,
+                        loop_mode,
+                    });
                     _inspector_refresh = true;
                 }
             }
+            if let Some(mut curve_info) = curve_info_opt.clone() {
+                let mut changed = false;
+                ui.horizontal(|ui| {
+                    ui.label(tr!("inspector.duration"));
+                    changed |= ui
+                        .add(
+                            egui::DragValue::new(&mut curve_info.duration)
+                                .speed(0.05)
+                                .range(0.01..=60.0)
+                                .suffix("s"),
+                        )
+                        .changed();
+                    ui.label(tr!("inspector.mode"));
+                    egui::ComboBox::from_id_salt(("tint_curve_mode", entity.index()))
+                        .selected_text(curve_info.loop_mode.as_str())
+                        .show_ui(ui, |ui| {
+                            for mode in [
+                                SpriteAnimationLoopMode::Loop,
+                                SpriteAnimationLoopMode::PingPong,
+                                SpriteAnimationLoopMode::OnceHold,
+                                SpriteAnimationLoopMode::OnceStop,
+                            ] {
+                                changed |= ui
+                                    .selectable_value(&mut curve_info.loop_mode, mode, mode.as_str())
+                                    .changed();
+                            }
+                        });
+                });
+                if changed {
+                    actions.inspector_actions.push(InspectorAction::SetTintCurve {
+                        entity,
+                        curve_key: curve_info.curve_key.clone(),
+                        duration: curve_info.duration,
+                        loop_mode: curve_info.loop_mode,
+                    });
+                    _inspector_refresh = true;
+                }
+                ui.label(tr!(
+                    "inspector.progress_s_s_current_tint",
+                    elapsed = format!("{:.2}", curve_info.elapsed),
+                    duration = format!("{:.2}", curve_info.duration),
+                    sample = format_vec4(curve_info.sample)
+                ));
+                curve_info_opt = Some(curve_info);
+            }
+            info.tint_curve = curve_info_opt;
+
+            for component in &mut info.plugin_components {
+                ui.separator();
+                show_plugin_component(ui, entity, component, actions);
+            }
 
             inspector_info = Some(info);
         } else {
-            ui.label("Selection data unavailable");
+            ui.label(tr!("inspector.selection_data_unavailable"));
         }
 
         ui.horizontal(|ui| {
-            if ui.button("Frame selection").clicked() {
+            if ui.button(tr!("inspector.frame_selection")).clicked() {
                 *frame_selection_request = true;
             }
         });
@@ -1536,30 +2220,180 @@ pub(super) fn show_entity_inspector(
         let drag_id = egui::Id::new(("prefab_drag_source", entity.index()));
         let drag_response = ui
             .dnd_drag_source(drag_id, PrefabDragPayload { entity }, |ui| {
-                ui.label("Drag to Prefab Shelf");
+                ui.label(tr!("inspector.drag_to_prefab_shelf"));
             })
             .response;
-        drag_response
-            .on_hover_text("Drop onto the Prefab Shelf to save this entity (and children) as a prefab.");
+        drag_response.on_hover_text(tr!("inspector.drop_onto_the_prefab_hint"));
 
         selection_details_value = inspector_info;
         if let Some(status) = ctx.inspector_status.as_ref() {
             ui.colored_label(egui::Color32::YELLOW, status);
         }
-        if ui.button("Delete selected").clicked() {
-            actions.delete_entity = Some(entity);
-            selected_entity_value = None;
-            selection_details_value = None;
-            *ctx.inspector_status = None;
-        }
+        ui.horizontal(|ui| {
+            if ui.button(tr!("inspector.delete_selected")).clicked() {
+                actions.delete_entity = Some(entity);
+                selected_entity_value = None;
+                selection_details_value = None;
+                *ctx.inspector_status = None;
+            }
+            if ui.button(tr!("inspector.duplicate_selected")).on_hover_text("Ctrl+D").clicked() {
+                actions.duplicate_selection = true;
+            }
+        });
     } else {
-        ui.label("No entity selected");
+        ui.label(tr!("inspector.no_entity_selected"));
     }
 
     *selected_entity = selected_entity_value;
     *selection_details = selection_details_value;
 }
 
+/// Relative translate/rotate/scale for a multi-selection, pivoting around the selection centroid.
+fn show_bulk_transform(ui: &mut Ui, selection_count: usize, actions: &mut UiActions) {
+    let input_id = egui::Id::new("bulk_transform_input");
+    let mut input = ui.ctx().data_mut(|d| d.get_temp::<BulkTransform>(input_id)).unwrap_or_default();
+    let mut apply = false;
+    egui::CollapsingHeader::new(format!("Bulk Transform ({selection_count} selected)"))
+        .default_open(true)
+        .show(ui, |ui| {
+            ui.horizontal(|ui| {
+                ui.label(tr!("inspector.move"));
+                ui.add(egui::DragValue::new(&mut input.translation.x).speed(0.01).prefix("x: "));
+                ui.add(egui::DragValue::new(&mut input.translation.y).speed(0.01).prefix("y: "));
+            });
+            let mut degrees = input.rotation.to_degrees();
+            ui.horizontal(|ui| {
+                ui.label(tr!("inspector.rotate"));
+                if ui.add(egui::DragValue::new(&mut degrees).speed(1.0).suffix(" deg")).changed() {
+                    input.rotation = degrees.to_radians();
+                }
+            });
+            ui.horizontal(|ui| {
+                ui.label(tr!("inspector.scale"));
+                ui.add(
+                    egui::DragValue::new(&mut input.scale.x).speed(0.01).range(0.01..=100.0).prefix("x: "),
+                );
+                ui.add(
+                    egui::DragValue::new(&mut input.scale.y).speed(0.01).range(0.01..=100.0).prefix("y: "),
+                );
+            });
+            ui.horizontal(|ui| {
+                apply =
+                    ui.add_enabled(!input.is_identity(), egui::Button::new(tr!("inspector.apply"))).clicked();
+                if ui.button(tr!("inspector.reset")).clicked() {
+                    input = BulkTransform::default();
+                }
+            });
+            ui.small(tr!("inspector.rotation_and_scale_pivot_around_the"));
+        });
+    if apply {
+        actions.bulk_transform = Some(input);
+        input = BulkTransform::default();
+    }
+    ui.ctx().data_mut(|d| d.insert_temp(input_id, input));
+}
+
+/// Copy/paste buttons for one inspector section; the app resolves them against the clipboard.
+/// Generic editor for a plugin-registered component; widgets come from the registered field kinds.
+fn show_plugin_component(
+    ui: &mut Ui,
+    entity: Entity,
+    component: &mut PluginComponentView,
+    actions: &mut UiActions,
+) {
+    ui.label(tr!("inspector.plugin_component", component = component.key));
+    for field in &mut component.fields {
+        let mut value = field.value.clone();
+        let changed = ui
+            .horizontal(|ui| {
+                ui.label(&field.name);
+                match (&field.kind, &mut value) {
+                    (ComponentFieldKind::F32 { min, max }, ComponentFieldValue::F32(v)) => {
+                        ui.add(egui::Slider::new(v, *min..=*max)).changed()
+                    }
+                    (ComponentFieldKind::Vec2, ComponentFieldValue::Vec2(v)) => {
+                        let x = ui.add(egui::DragValue::new(&mut v.x).speed(0.01)).changed();
+                        let y = ui.add(egui::DragValue::new(&mut v.y).speed(0.01)).changed();
+                        x || y
+                    }
+                    (ComponentFieldKind::Vec3, ComponentFieldValue::Vec3(v)) => {
+                        let x = ui.add(egui::DragValue::new(&mut v.x).speed(0.01)).changed();
+                        let y = ui.add(egui::DragValue::new(&mut v.y).speed(0.01)).changed();
+                        let z = ui.add(egui::DragValue::new(&mut v.z).speed(0.01)).changed();
+                        x || y || z
+                    }
+                    (ComponentFieldKind::Color, ComponentFieldValue::Color(v)) => {
+                        let mut rgba = v.to_array();
+                        let changed = ui.color_edit_button_rgba_unmultiplied(&mut rgba).changed();
+                        *v = Vec4::from_array(rgba);
+                        changed
+                    }
+                    (ComponentFieldKind::Text, ComponentFieldValue::Text(v)) => {
+                        ui.text_edit_singleline(v).changed()
+                    }
+                    (ComponentFieldKind::Bool, ComponentFieldValue::Bool(v)) => ui.checkbox(v, "").changed(),
+                    (ComponentFieldKind::Enum { options }, ComponentFieldValue::Enum(v)) => {
+                        let before = *v;
+                        let selected = options.get(*v).map(String::as_str).unwrap_or("?");
+                        egui::ComboBox::from_id_salt((component.key.as_str(), field.name.as_str()))
+                            .selected_text(selected)
+                            .show_ui(ui, |ui| {
+                                for (index, option) in options.iter().enumerate() {
+                                    ui.selectable_value(v, index, option);
+                                }
+                            });
+                        *v != before
+                    }
+                    _ => {
+                        ui.label(format!("{:?}", field.value));
+                        false
+                    }
+                }
+            })
+            .inner;
+        if changed {
+            actions.inspector_actions.push(InspectorAction::SetPluginComponentField {
+                entity,
+                key: component.key.clone(),
+                field: field.name.clone(),
+                value: value.clone(),
+            });
+            field.value = value;
+        }
+    }
+}
+
+fn component_clipboard_buttons(ui: &mut Ui, entity: Entity, kind: ComponentKind, actions: &mut UiActions) {
+    if ui.small_button("📋").on_hover_text(tr!("inspector.copy_hint", kind = kind.label())).clicked() {
+        actions.component_clipboard = Some(ComponentClipboardRequest::Copy { entity, kind });
+    }
+    if ui.small_button("📥").on_hover_text(tr!("inspector.paste_hint", kind = kind.label())).clicked() {
+        actions.component_clipboard = Some(ComponentClipboardRequest::Paste { entity, kind });
+    }
+}
+
+/// "Loops" row for looping clips: unchecked plays forever, checked stops after N cycles.
+/// Returns the new loop count when the user edits it.
+fn loop_count_editor(ui: &mut Ui, loop_count: Option<u32>) -> Option<Option<u32>> {
+    let mut edited = None;
+    ui.horizontal(|ui| {
+        let mut limited = loop_count.is_some();
+        if ui
+            .checkbox(&mut limited, tr!("inspector.limit_loops"))
+            .on_hover_text(tr!("inspector.limit_loops_hint"))
+            .changed()
+        {
+            edited = Some(limited.then_some(loop_count.unwrap_or(1)));
+        }
+        if let Some(mut count) = loop_count {
+            if ui.add(egui::DragValue::new(&mut count).range(1..=10_000).suffix("x")).changed() {
+                edited = Some(Some(count));
+            }
+        }
+    });
+    edited
+}
+
 fn track_badge(ui: &mut egui::Ui, label: &str, available: bool, enabled: bool) {
     let (color, text) = if !available {
         (egui::Color32::DARK_GRAY, format!("{label}: n/a"))
@@ -1578,3 +2412,125 @@ fn format_vec2(value: Vec2) -> String {
 fn format_vec4(value: Vec4) -> String {
     format!("({:.3}, {:.3}, {:.3}, {:.3})", value.x, value.y, value.z, value.w)
 }
+
+fn skeleton_joint_hover_id(entity: Entity) -> egui::Id {
+    egui::Id::new(("skeleton_joint_hover", entity.index()))
+}
+
+fn skeleton_joint_selected_id(entity: Entity) -> egui::Id {
+    egui::Id::new(("skeleton_joint_selected", entity.index()))
+}
+
+/// Joint the skeleton overlay should highlight: the one hovered in the inspector joint list, falling
+/// back to the selected one.
+pub(super) fn skeleton_joint_highlight(ctx: &egui::Context, entity: Entity) -> Option<usize> {
+    ctx.data(|d| {
+        d.get_temp::<usize>(skeleton_joint_hover_id(entity))
+            .or_else(|| d.get_temp::<usize>(skeleton_joint_selected_id(entity)))
+    })
+}
+
+fn show_skeleton_joints(ui: &mut Ui, entity: Entity, joints: &[SkeletonJointPose]) {
+    if joints.is_empty() {
+        return;
+    }
+    let hover_id = skeleton_joint_hover_id(entity);
+    let selected_id = skeleton_joint_selected_id(entity);
+    let mut selected =
+        ui.ctx().data(|d| d.get_temp::<usize>(selected_id)).filter(|index| *index < joints.len());
+    let mut hovered = None;
+    egui::CollapsingHeader::new(format!("Joints ({})", joints.len()))
+        .id_salt(("skeleton_joints", entity.index()))
+        .show(ui, |ui| {
+            egui::ScrollArea::vertical()
+                .max_height(180.0)
+                .id_salt(("skeleton_joint_list", entity.index()))
+                .show(ui, |ui| {
+                    for (index, joint) in joints.iter().enumerate() {
+                        let depth = skeleton_joint_depth(joints, index);
+                        ui.horizontal(|ui| {
+                            ui.add_space(depth as f32 * 12.0);
+                            let response = ui.selectable_label(selected == Some(index), joint.name.as_ref());
+                            if response.hovered() {
+                                hovered = Some(index);
+                            }
+                            if response.clicked() {
+                                selected = if selected == Some(index) { None } else { Some(index) };
+                            }
+                        });
+                    }
+                });
+        });
+    ui.ctx().data_mut(|d| {
+        match hovered {
+            Some(index) => d.insert_temp(hover_id, index),
+            None => d.remove::<usize>(hover_id),
+        }
+        match selected {
+            Some(index) => d.insert_temp(selected_id, index),
+            None => d.remove::<usize>(selected_id),
+        }
+    });
+    let Some(joint) = selected.and_then(|index| joints.get(index)) else {
+        return;
+    };
+    ui.label(tr!("inspector.joint", joint = joint.name));
+    let parent = joint.parent.and_then(|parent| joints.get(parent)).map(|parent| parent.name.as_ref());
+    ui.small(tr!("inspector.joint_parent", parent = parent.unwrap_or("none")));
+    for (label, transform) in [("Local", joint.local), ("World", joint.world)] {
+        let (scale, rotation, translation) = transform.to_scale_rotation_translation();
+        ui.label(format!(
+            "{label}: T {} R {} S {}",
+            format_vec3(translation),
+            format_euler_degrees(rotation),
+            format_vec3(scale)
+        ));
+    }
+    match joint.clip_tracks {
+        Some(tracks) => {
+            let (scale, rotation, translation) = joint.local.to_scale_rotation_translation();
+            if tracks.translation {
+                ui.label(tr!("inspector.clip_translation", translation = format_vec3(translation)));
+            }
+            if tracks.rotation {
+                ui.label(tr!("inspector.clip_rotation", rotation = format_euler_degrees(rotation)));
+            }
+            if tracks.scale {
+                ui.label(tr!("inspector.clip_scale", scale = format_vec3(scale)));
+            }
+        }
+        None => {
+            ui.small(tr!("inspector.not_animated_by_the_active_clip"));
+        }
+    }
+}
+
+fn skeleton_joint_depth(joints: &[SkeletonJointPose], index: usize) -> usize {
+    let mut depth = 0;
+    let mut parent = joints[index].parent;
+    while let Some(current) = parent {
+        depth += 1;
+        if depth > joints.len() {
+            break;
+        }
+        parent = joints.get(current).and_then(|joint| joint.parent);
+    }
+    depth
+}
+
+fn format_vec3(value: Vec3) -> String {
+    format!("({:.3}, {:.3}, {:.3})", value.x, value.y, value.z)
+}
+
+fn format_euler_degrees(rotation: Quat) -> String {
+    let (x, y, z) = rotation.to_euler(EulerRot::XYZ);
+    format!("({:.1}°, {:.1}°, {:.1}°)", x.to_degrees(), y.to_degrees(), z.to_degrees())
+}
+
+fn cull_mode_label(mode: MaterialCullMode) -> String {
+    match mode {
+        MaterialCullMode::Back => tr!("inspector.cull_back"),
+        MaterialCullMode::Front => tr!("inspector.cull_front"),
+        MaterialCullMode::None => tr!("inspector.cull_none"),
+    }
+}
@@ -0,0 +1,79 @@
+use crate::ecs::EmitterShape;
+use glam::Vec2;
+
+const OUTLINE_CIRCLE_SEGMENTS: usize = 32;
+/// Length of the launch-direction rays drawn for shapes that emit along the facing direction.
+const DIRECTION_RAY_LENGTH: f32 = 0.6;
+/// Radius of the marker drawn for point emitters, which have no area of their own.
+const POINT_MARKER_RADIUS: f32 = 0.08;
+
+/// World-space polylines outlining where an emitter at `origin`, rotated by `rotation`, spawns
+/// particles. Cone, box and line shapes also get a pair of rays showing the spread.
+pub(super) fn emitter_shape_outline(
+    shape: EmitterShape,
+    spread: f32,
+    origin: Vec2,
+    rotation: f32,
+) -> Vec<Vec<Vec2>> {
+    let mut local = match shape.sanitized() {
+        EmitterShape::Point => vec![circle(POINT_MARKER_RADIUS)],
+        EmitterShape::Cone => Vec::new(),
+        EmitterShape::CircleEdge { radius } | EmitterShape::Disc { radius } => vec![circle(radius)],
+        EmitterShape::Box { half_width, half_height } => vec![vec![
+            Vec2::new(-half_width, -half_height),
+            Vec2::new(half_width, -half_height),
+            Vec2::new(half_width, half_height),
+            Vec2::new(-half_width, half_height),
+            Vec2::new(-half_width, -half_height),
+        ]],
+        EmitterShape::Line { length } => {
+            vec![vec![Vec2::new(-length * 0.5, 0.0), Vec2::new(length * 0.5, 0.0)]]
+        }
+    };
+    if matches!(shape, EmitterShape::Cone | EmitterShape::Box { .. } | EmitterShape::Line { .. }) {
+        let half_angle = spread.clamp(0.0, std::f32::consts::PI);
+        let left = Vec2::from_angle(std::f32::consts::FRAC_PI_2 + half_angle) * DIRECTION_RAY_LENGTH;
+        let right = Vec2::from_angle(std::f32::consts::FRAC_PI_2 - half_angle) * DIRECTION_RAY_LENGTH;
+        local.push(vec![left, Vec2::ZERO, right]);
+    }
+    let facing = Vec2::from_angle(rotation);
+    local
+        .into_iter()
+        .map(|polyline| polyline.into_iter().map(|point| origin + facing.rotate(point)).collect())
+        .collect()
+}
+
+fn circle(radius: f32) -> Vec<Vec2> {
+    (0..=OUTLINE_CIRCLE_SEGMENTS)
+        .map(|i| Vec2::from_angle(i as f32 / OUTLINE_CIRCLE_SEGMENTS as f32 * std::f32::consts::TAU) * radius)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn circle_edge_outline_is_a_closed_ring_at_the_radius() {
+        let origin = Vec2::new(2.0, -1.0);
+        let outline = emitter_shape_outline(EmitterShape::CircleEdge { radius: 3.0 }, 0.4, origin, 0.7);
+        assert_eq!(outline.len(), 1, "ring shapes have no direction rays");
+        let ring = &outline[0];
+        assert!(ring.first().unwrap().distance(*ring.last().unwrap()) < 1e-4);
+        for point in ring {
+            assert!((point.distance(origin) - 3.0).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn box_outline_follows_emitter_rotation() {
+        let shape = EmitterShape::Box { half_width: 2.0, half_height: 0.5 };
+        let outline = emitter_shape_outline(shape, 0.0, Vec2::ZERO, std::f32::consts::FRAC_PI_2);
+        assert_eq!(outline.len(), 2);
+        // A quarter turn swaps the box axes, so the first corner (-2, -0.5) lands on (0.5, -2).
+        assert!(outline[0][0].distance(Vec2::new(0.5, -2.0)) < 1e-4);
+        // With no spread the direction rays collapse onto the rotated facing (-X).
+        let rays = &outline[1];
+        assert!(rays[0].distance(Vec2::new(-DIRECTION_RAY_LENGTH, 0.0)) < 1e-4);
+    }
+}
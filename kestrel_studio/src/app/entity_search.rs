@@ -0,0 +1,190 @@
+use super::App;
+use crate::ecs::{MeshRef, SceneEntityTag, Sprite};
+use crate::scripts::ScriptBehaviour;
+use bevy_ecs::prelude::Entity;
+
+/// Most matches the Entity Lookup window lists at once.
+pub(super) const MAX_ENTITY_SEARCH_RESULTS: usize = 50;
+
+const EXACT_MATCH_SCORE: i32 = 3_000;
+const PREFIX_MATCH_SCORE: i32 = 2_000;
+const SUBSTRING_MATCH_SCORE: i32 = 1_000;
+
+/// Searchable text for one scene entity: its scene id plus tags taken from its components
+/// (sprite atlas and region, mesh key, script path).
+#[derive(Debug, Clone)]
+pub(crate) struct EntitySearchCandidate {
+    pub entity: Entity,
+    pub scene_id: String,
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct EntitySearchMatch {
+    pub entity: Entity,
+    pub scene_id: String,
+    /// The scene id or tag that scored best against the query.
+    pub matched: String,
+    pub score: i32,
+}
+
+/// Ranks `candidates` against `query`, best match first. Exact, prefix and substring hits outrank
+/// fuzzy ones, where every query character must appear in order. Ties, and an empty query, fall
+/// back to scene id order so the list does not reshuffle between frames.
+pub(crate) fn entity_search(query: &str, candidates: &[EntitySearchCandidate]) -> Vec<EntitySearchMatch> {
+    let query = query.trim().to_lowercase();
+    let mut matches: Vec<EntitySearchMatch> = candidates
+        .iter()
+        .filter_map(|candidate| {
+            let mut best: Option<(i32, &str)> = None;
+            for text in std::iter::once(&candidate.scene_id).chain(&candidate.tags) {
+                let Some(score) = match_score(&query, text) else {
+                    continue;
+                };
+                if best.is_none_or(|(best_score, _)| score > best_score) {
+                    best = Some((score, text));
+                }
+            }
+            best.map(|(score, matched)| EntitySearchMatch {
+                entity: candidate.entity,
+                scene_id: candidate.scene_id.clone(),
+                matched: matched.to_string(),
+                score,
+            })
+        })
+        .collect();
+    matches.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| a.scene_id.cmp(&b.scene_id)));
+    matches
+}
+
+/// Scores `text` against an already lower-cased `query`; `None` when it does not match at all.
+fn match_score(query: &str, text: &str) -> Option<i32> {
+    if query.is_empty() {
+        return Some(0);
+    }
+    let text = text.to_lowercase();
+    if text == query {
+        return Some(EXACT_MATCH_SCORE);
+    }
+    let extra_chars = (text.chars().count() - query.chars().count().min(text.chars().count())) as i32;
+    if text.starts_with(query) {
+        return Some(PREFIX_MATCH_SCORE - extra_chars.min(999));
+    }
+    if let Some(index) = text.find(query) {
+        return Some(SUBSTRING_MATCH_SCORE - (index as i32 + extra_chars).min(999));
+    }
+    fuzzy_score(query, &text).map(|score| score.clamp(1, SUBSTRING_MATCH_SCORE - 1))
+}
+
+/// Subsequence match rewarding runs of consecutive characters and hits at word starts, and
+/// charging for every skipped character.
+fn fuzzy_score(query: &str, text: &str) -> Option<i32> {
+    let mut score = 0;
+    let mut previous: Option<char> = None;
+    let mut previous_matched = false;
+    let mut remaining = query.chars().peekable();
+    for ch in text.chars() {
+        let Some(&wanted) = remaining.peek() else {
+            break;
+        };
+        if ch == wanted {
+            remaining.next();
+            score += 10;
+            if previous_matched {
+                score += 15;
+            }
+            if previous.is_none_or(|prev| matches!(prev, '_' | '-' | ':' | '/' | '.' | ' ')) {
+                score += 10;
+            }
+            previous_matched = true;
+        } else {
+            score -= 1;
+            previous_matched = false;
+        }
+        previous = Some(ch);
+    }
+    remaining.peek().is_none().then_some(score)
+}
+
+impl App {
+    /// Snapshot of every scene entity for the Entity Lookup search list.
+    pub(super) fn entity_search_candidates(&mut self) -> Vec<EntitySearchCandidate> {
+        let mut query = self.ecs.world.query::<(
+            Entity,
+            &SceneEntityTag,
+            Option<&Sprite>,
+            Option<&MeshRef>,
+            Option<&ScriptBehaviour>,
+        )>();
+        query
+            .iter(&self.ecs.world)
+            .map(|(entity, tag, sprite, mesh, script)| {
+                let mut tags = Vec::new();
+                if let Some(sprite) = sprite {
+                    tags.push(sprite.region.to_string());
+                    tags.push(sprite.atlas_key.to_string());
+                }
+                if let Some(mesh) = mesh {
+                    tags.push(mesh.key.clone());
+                }
+                if let Some(script) = script {
+                    tags.push(script.script_path.clone());
+                }
+                EntitySearchCandidate { entity, scene_id: tag.id.as_str().to_string(), tags }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(index: u32, scene_id: &str, tags: &[&str]) -> EntitySearchCandidate {
+        EntitySearchCandidate {
+            entity: Entity::from_raw(index),
+            scene_id: scene_id.to_string(),
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+        }
+    }
+
+    fn ranked(query: &str, candidates: &[EntitySearchCandidate]) -> Vec<String> {
+        entity_search(query, candidates).into_iter().map(|m| m.scene_id).collect()
+    }
+
+    #[test]
+    fn fuzzy_query_ranks_closest_entity_first() {
+        let candidates = [candidate(0, "player", &[]), candidate(1, "enemy_1", &[])];
+        let results = entity_search("enmy", &candidates);
+        assert_eq!(results.first().map(|m| m.scene_id.as_str()), Some("enemy_1"));
+        assert!(results.iter().all(|m| m.scene_id != "player"), "player does not contain 'enmy' in order");
+    }
+
+    #[test]
+    fn empty_query_returns_everything_in_scene_id_order() {
+        let candidates = [candidate(0, "zeta", &[]), candidate(1, "alpha", &[]), candidate(2, "mid", &[])];
+        assert_eq!(ranked("", &candidates), ["alpha", "mid", "zeta"]);
+        assert_eq!(ranked("   ", &candidates), ["alpha", "mid", "zeta"]);
+    }
+
+    #[test]
+    fn exact_and_prefix_matches_outrank_fuzzy_ones() {
+        let candidates = [
+            candidate(0, "e_n_e_m_y", &[]),
+            candidate(1, "enemy_boss", &[]),
+            candidate(2, "enemy", &[]),
+            candidate(3, "big_enemy", &[]),
+        ];
+        assert_eq!(ranked("enemy", &candidates), ["enemy", "enemy_boss", "big_enemy", "e_n_e_m_y"]);
+    }
+
+    #[test]
+    fn tags_are_searched_and_reported() {
+        let candidates =
+            [candidate(0, "entity::1", &["hero_idle", "main"]), candidate(1, "entity::2", &["crate"])];
+        let results = entity_search("HERO", &candidates);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].scene_id, "entity::1");
+        assert_eq!(results[0].matched, "hero_idle");
+    }
+}
@@ -1,5 +1,6 @@
+use super::selection_tooling::{BulkTransform, SelectionSnapshot};
 use super::{App, ViewportCameraMode};
-use crate::ecs::EntityInfo;
+use crate::ecs::{EntityInfo, PathAgent};
 use crate::gizmo;
 use crate::gizmo::{
     Axis2, GizmoInteraction, GizmoMode, ScaleHandle, ScaleHandleKind, GIZMO_ROTATE_INNER_RADIUS_PX,
@@ -7,6 +8,7 @@ use crate::gizmo::{
     ROTATE_SNAP_STEP_RADIANS, TRANSLATE_SNAP_STEP,
 };
 use crate::mesh_preview::MeshControlMode;
+use crate::tr;
 use crate::wrap_angle;
 
 use glam::{EulerRot, Quat, Vec2, Vec3};
@@ -16,7 +18,69 @@ pub(crate) struct GizmoUpdate {
     pub hovered_scale_kind: Option<ScaleHandleKind>,
 }
 
+/// The cursor resolved against the viewport and the active camera at one instant, so picking and
+/// gizmo math agree on where the pointer is.
+#[derive(Clone, Copy, Default)]
+pub(crate) struct PointerSample {
+    pub cursor_viewport: Option<Vec2>,
+    pub cursor_world_2d: Option<Vec2>,
+    pub cursor_ray: Option<(Vec3, Vec3)>,
+}
+
 impl App {
+    pub(crate) fn sample_pointer(&self, viewport_size: PhysicalSize<u32>) -> PointerSample {
+        let cursor_viewport =
+            self.input.cursor_position().and_then(|(sx, sy)| self.screen_to_viewport(Vec2::new(sx, sy)));
+        let Some(pos) = cursor_viewport else {
+            return PointerSample::default();
+        };
+        match self.viewport_camera_mode {
+            ViewportCameraMode::Ortho2D => PointerSample {
+                cursor_viewport,
+                cursor_world_2d: self.camera.screen_to_world(pos, viewport_size),
+                cursor_ray: None,
+            },
+            ViewportCameraMode::Perspective3D => PointerSample {
+                cursor_viewport,
+                cursor_world_2d: None,
+                cursor_ray: self
+                    .mesh_preview_plugin()
+                    .and_then(|plugin| plugin.mesh_camera().screen_ray(pos, viewport_size)),
+            },
+        }
+    }
+
+    pub(crate) fn pan_camera_from_mouse_delta(&mut self, viewport_size: PhysicalSize<u32>) {
+        let (dx, dy) = self.input.mouse_delta;
+        if dx.abs() > f32::EPSILON || dy.abs() > f32::EPSILON {
+            self.camera.pan_screen_delta(Vec2::new(dx, dy), viewport_size);
+            self.set_active_camera_bookmark(None);
+            self.camera_follow_target = None;
+        }
+    }
+
+    /// Takes the frame's one pointer sample, shared by picking, the gizmo drag and the gizmo's
+    /// on-screen center. Every `CursorMoved` reaches [`Input`](crate::input::Input) before
+    /// `about_to_wait`, so the cursor read here is the latest the window reported. With
+    /// `low_latency` the frame's right-drag pan is applied first, so the sample resolves against
+    /// the camera the frame renders with rather than the previous frame's.
+    pub(crate) fn latch_pointer(
+        &mut self,
+        viewport_size: PhysicalSize<u32>,
+        low_latency: bool,
+    ) -> PointerSample {
+        let mesh_control_mode =
+            self.mesh_preview_plugin().map(|plugin| plugin.mesh_control_mode()).unwrap_or_default();
+        if low_latency
+            && self.viewport_camera_mode == ViewportCameraMode::Ortho2D
+            && mesh_control_mode == MeshControlMode::Disabled
+            && self.input.right_mouse_held()
+        {
+            self.pan_camera_from_mouse_delta(viewport_size);
+        }
+        self.sample_pointer(viewport_size)
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub(crate) fn update_gizmo_interactions(
         &mut self,
@@ -28,11 +92,10 @@ impl App {
         mesh_center_world: Option<Vec3>,
         gizmo_center_viewport: Option<Vec2>,
         selected_info: &Option<EntityInfo>,
+        pan_latched: bool,
     ) -> GizmoUpdate {
-        let mesh_control_mode = self
-            .mesh_preview_plugin()
-            .map(|plugin| plugin.mesh_control_mode())
-            .unwrap_or(MeshControlMode::Disabled);
+        let mesh_control_mode =
+            self.mesh_preview_plugin().map(|plugin| plugin.mesh_control_mode()).unwrap_or_default();
         if self.viewport_camera_mode == ViewportCameraMode::Ortho2D
             && mesh_control_mode == MeshControlMode::Disabled
         {
@@ -40,7 +103,7 @@ impl App {
                 self.camera.apply_scroll_zoom(delta);
             }
 
-            if self.input.right_mouse_held() {
+            if self.input.right_mouse_held() && !pan_latched {
                 let (dx, dy) = self.input.mouse_delta;
                 if dx.abs() > f32::EPSILON || dy.abs() > f32::EPSILON {
                     self.camera.pan_screen_delta(Vec2::new(dx, dy), viewport_size);
@@ -70,6 +133,8 @@ impl App {
             None
         };
 
+        let collider_grip =
+            self.collider_grip_under_pointer(selected_info.as_ref(), cursor_viewport, viewport_size);
         let mut gizmo_click_consumed = false;
         if self.input.take_left_click() {
             if let Some(entity) = self.selected_entity() {
@@ -186,6 +251,27 @@ impl App {
                             }
                         }
                     }
+                    ViewportCameraMode::Ortho2D if self.has_multi_selection() => {
+                        gizmo_click_consumed =
+                            self.begin_bulk_gizmo(cursor_world_2d, cursor_viewport, gizmo_center_viewport);
+                    }
+                    ViewportCameraMode::Ortho2D if collider_grip.is_some() => {
+                        if let (Some(grip), Some(pointer_world), Some(info)) =
+                            (collider_grip, cursor_world_2d, selected_info.as_ref())
+                        {
+                            if let Some(start) = info.collider {
+                                self.set_gizmo_interaction(Some(GizmoInteraction::Collider {
+                                    entity,
+                                    grip,
+                                    origin: info.translation,
+                                    start,
+                                    start_pointer: pointer_world,
+                                }));
+                                gizmo_click_consumed = true;
+                                self.set_inspector_status(None);
+                            }
+                        }
+                    }
                     ViewportCameraMode::Ortho2D => match self.gizmo_mode() {
                         GizmoMode::Translate => {
                             if let (Some(center_viewport), Some(pointer_viewport)) =
@@ -287,7 +373,7 @@ impl App {
                                 }
                             }
                             let has_selection = picked.is_some();
-                            self.set_selected_entity(picked);
+                            self.apply_viewport_pick(picked);
                             if has_selection {
                                 self.set_inspector_status(None);
                             }
@@ -297,9 +383,22 @@ impl App {
                         }
                     }
                     ViewportCameraMode::Ortho2D => {
-                        if let Some(world) = cursor_world_2d {
+                        let path_agent = self.selected_entity().filter(|entity| {
+                            self.input.ctrl_held() && self.ecs.world.get::<PathAgent>(*entity).is_some()
+                        });
+                        if let (Some(agent), Some(world)) = (path_agent, cursor_world_2d) {
+                            if self.ecs.request_path(agent, world) {
+                                self.set_inspector_status(None);
+                            } else {
+                                self.set_inspector_status(Some(tr!(
+                                    "inspector.no_path_to",
+                                    x = format!("{:.2}", world.x),
+                                    y = format!("{:.2}", world.y)
+                                )));
+                            }
+                        } else if let Some(world) = cursor_world_2d {
                             let result = self.ecs.pick_entity(world);
-                            self.set_selected_entity(result);
+                            self.apply_viewport_pick(result);
                             self.set_inspector_status(None);
                         } else if cursor_in_viewport {
                             self.set_selected_entity(None);
@@ -317,6 +416,17 @@ impl App {
             self.set_gizmo_interaction(None);
         }
 
+        self.drive_gizmo_drag(cursor_world_2d, cursor_ray);
+        GizmoUpdate { hovered_scale_kind }
+    }
+
+    /// Moves the entity under an active gizmo drag to follow the pointer, ending the drag once the
+    /// left button is released.
+    pub(crate) fn drive_gizmo_drag(
+        &mut self,
+        cursor_world_2d: Option<Vec2>,
+        cursor_ray: Option<(Vec3, Vec3)>,
+    ) {
         if let Some(mut interaction) = self.take_gizmo_interaction() {
             let mut keep_active = true;
             match &mut interaction {
@@ -568,6 +678,76 @@ impl App {
                         keep_active = false;
                     }
                 }
+                GizmoInteraction::Bulk { mode, centroid, start_pointer } => {
+                    if !self.input.left_mouse_held() {
+                        keep_active = false;
+                    } else if let Some(pointer_world) = cursor_world_2d {
+                        let snap = self.input.ctrl_held();
+                        let mut transform = BulkTransform::default();
+                        match mode {
+                            GizmoMode::Translate => {
+                                let mut delta = pointer_world - *start_pointer;
+                                if snap {
+                                    delta = (delta / TRANSLATE_SNAP_STEP).round() * TRANSLATE_SNAP_STEP;
+                                }
+                                transform.translation = delta;
+                            }
+                            GizmoMode::Rotate => {
+                                let start = *start_pointer - *centroid;
+                                let current = pointer_world - *centroid;
+                                if start.length_squared() > f32::EPSILON
+                                    && current.length_squared() > f32::EPSILON
+                                {
+                                    let mut delta =
+                                        wrap_angle(current.y.atan2(current.x) - start.y.atan2(start.x));
+                                    if snap {
+                                        delta = (delta / ROTATE_SNAP_STEP_RADIANS).round()
+                                            * ROTATE_SNAP_STEP_RADIANS;
+                                    }
+                                    transform.rotation = delta;
+                                }
+                            }
+                            GizmoMode::Scale => {
+                                let start_distance = start_pointer.distance(*centroid);
+                                let distance = pointer_world.distance(*centroid);
+                                if start_distance > f32::EPSILON && distance > f32::EPSILON {
+                                    let ratio = gizmo::apply_scale_ratio(distance / start_distance, snap);
+                                    transform.scale = Vec2::splat(ratio);
+                                }
+                            }
+                        }
+                        let snapshot =
+                            self.with_editor_ui_state_mut(|state| state.bulk_gizmo_snapshot.take());
+                        if let Some(snapshot) = snapshot {
+                            snapshot.apply(&mut self.ecs, &transform);
+                            self.with_editor_ui_state_mut(|state| state.bulk_gizmo_snapshot = Some(snapshot));
+                        } else {
+                            keep_active = false;
+                        }
+                    } else {
+                        keep_active = false;
+                    }
+                }
+                GizmoInteraction::Collider { entity, grip, origin, start, start_pointer } => {
+                    let pointer_world = cursor_world_2d
+                        .filter(|_| self.input.left_mouse_held() && self.ecs.entity_exists(*entity));
+                    if let Some(pointer_world) = pointer_world {
+                        let geometry = gizmo::drag_collider_grip(
+                            *start,
+                            *grip,
+                            *origin,
+                            *start_pointer,
+                            pointer_world,
+                            self.input.ctrl_held(),
+                        );
+                        self.ecs.set_collider_geometry(*entity, geometry);
+                    } else {
+                        keep_active = false;
+                        if let Some(current) = self.ecs.collider_geometry(*entity) {
+                            self.record_collider_edit(*entity, *start, current);
+                        }
+                    }
+                }
             }
             if !keep_active {
                 self.set_gizmo_interaction(None);
@@ -575,7 +755,44 @@ impl App {
                 self.set_gizmo_interaction(Some(interaction));
             }
         }
-        GizmoUpdate { hovered_scale_kind }
+    }
+
+    /// Starts a centroid gizmo drag on a 2D multi-selection when the click lands on the gizmo.
+    fn begin_bulk_gizmo(
+        &mut self,
+        cursor_world_2d: Option<Vec2>,
+        cursor_viewport: Option<Vec2>,
+        gizmo_center_viewport: Option<Vec2>,
+    ) -> bool {
+        let (Some(pointer_world), Some(pointer_viewport), Some(center_viewport)) =
+            (cursor_world_2d, cursor_viewport, gizmo_center_viewport)
+        else {
+            return false;
+        };
+        let dist = pointer_viewport.distance(center_viewport);
+        let mode = self.gizmo_mode();
+        let grabbed = match mode {
+            GizmoMode::Translate => dist <= GIZMO_TRANSLATE_RADIUS_PX,
+            GizmoMode::Rotate => {
+                (GIZMO_ROTATE_INNER_RADIUS_PX..=GIZMO_ROTATE_OUTER_RADIUS_PX).contains(&dist)
+            }
+            GizmoMode::Scale => dist <= GIZMO_SCALE_OUTER_RADIUS_PX,
+        };
+        if !grabbed {
+            return false;
+        }
+        let Some(snapshot) = SelectionSnapshot::capture(&self.ecs, &self.selected_entities()) else {
+            return false;
+        };
+        let centroid = snapshot.centroid;
+        self.set_gizmo_interaction(Some(GizmoInteraction::Bulk {
+            mode,
+            centroid,
+            start_pointer: pointer_world,
+        }));
+        self.with_editor_ui_state_mut(|state| state.bulk_gizmo_snapshot = Some(snapshot));
+        self.set_inspector_status(None);
+        true
     }
 }
 
@@ -583,7 +800,8 @@ impl App {
 mod tests {
     use super::*;
     use crate::assets::AssetManager;
-    use crate::ecs::{EcsWorld, SceneEntityTag};
+    use crate::ecs::{ColliderGeometry, ColliderKind, EcsWorld, SceneEntityTag};
+    use crate::gizmo::ColliderGrip;
     use bevy_ecs::prelude::Entity;
     use glam::{Vec2, Vec4};
     use std::collections::BTreeSet;
@@ -687,6 +905,39 @@ mod tests {
         assert_eq!(original_ids, reloaded_ids, "scene entity IDs should remain stable across save/load");
     }
 
+    #[test]
+    fn collider_edge_drag_keeps_the_opposite_edge_and_snaps() {
+        let start = ColliderGeometry { kind: ColliderKind::Rect, half: Vec2::splat(0.5), offset: Vec2::ZERO };
+        let origin = Vec2::new(4.0, 4.0);
+        let dragged = gizmo::drag_collider_grip(
+            start,
+            ColliderGrip::Right,
+            origin,
+            origin,
+            origin + Vec2::new(0.54, 0.3),
+            true,
+        );
+        assert!((dragged.half.x - 0.775).abs() < 1e-5, "right edge snapped to 1.05: {:?}", dragged.half);
+        assert!((dragged.offset.x - 0.275).abs() < 1e-5, "left edge stays at -0.5: {:?}", dragged.offset);
+        assert_eq!(dragged.half.y, 0.5);
+
+        let circle = ColliderGeometry { kind: ColliderKind::Circle, ..start };
+        let grown = gizmo::drag_collider_grip(
+            circle,
+            ColliderGrip::Radius,
+            origin,
+            origin,
+            origin + Vec2::new(0.0, 2.0),
+            false,
+        );
+        assert_eq!(grown.half, Vec2::splat(2.0));
+        assert_eq!(
+            gizmo::collider_grips(&circle, origin).len(),
+            2,
+            "circles show a radius and a centre grip"
+        );
+    }
+
     fn collect_scene_ids(world: &mut EcsWorld) -> BTreeSet<String> {
         let mut query = world.world.query::<&SceneEntityTag>();
         query.iter(&world.world).map(|tag| tag.id.as_str().to_string()).collect()
@@ -0,0 +1,143 @@
+use super::*;
+use crate::plugins::PluginState;
+
+/// Frame budget and collected engine errors for `--headless-run frames=N`.
+#[derive(Debug, Clone)]
+pub(crate) struct HeadlessRun {
+    frames_requested: u32,
+    frames_remaining: u32,
+    frames_run: u32,
+    errors: Vec<String>,
+    skipped: Option<String>,
+}
+
+impl HeadlessRun {
+    pub(crate) fn new(frames: u32) -> Self {
+        Self {
+            frames_requested: frames,
+            frames_remaining: frames,
+            frames_run: 0,
+            errors: Vec::new(),
+            skipped: None,
+        }
+    }
+
+    /// Counts one finished frame and returns true for the frame that spends the budget.
+    fn finish_frame(&mut self) -> bool {
+        if self.frames_remaining == 0 {
+            return false;
+        }
+        self.frames_run += 1;
+        self.frames_remaining -= 1;
+        self.frames_remaining == 0
+    }
+
+    /// Ok when the run completed without errors or was skipped for lack of a renderer.
+    pub(crate) fn outcome(&self) -> Result<()> {
+        if let Some(reason) = self.skipped.as_ref() {
+            println!("[headless] Skipped: {reason}");
+            return Ok(());
+        }
+        let mut errors = self.errors.clone();
+        if self.frames_run < self.frames_requested {
+            let (run, requested) = (self.frames_run, self.frames_requested);
+            errors.push(format!("app exited after {run} of {requested} frame(s)"));
+        }
+        if errors.is_empty() {
+            println!("[headless] Ran {} frame(s) without errors", self.frames_run);
+            return Ok(());
+        }
+        Err(anyhow!(
+            "Headless run failed after {} frame(s) with {} error(s):\n  {}",
+            self.frames_run,
+            errors.len(),
+            errors.join("\n  ")
+        ))
+    }
+}
+
+impl App {
+    /// Runs the normal frame loop (plugins, ECS update, rendering) in play mode for `frames`
+    /// frames and then exits. Engine errors seen along the way fail the run.
+    pub fn headless_run(&mut self, frames: u32) -> Result<()> {
+        if frames == 0 {
+            return Err(anyhow!("Headless run needs at least one frame"));
+        }
+        println!("[headless] Running {frames} frame(s)");
+        self.headless_run = Some(HeadlessRun::new(frames));
+        Ok(())
+    }
+
+    pub(super) fn headless_run_active(&self) -> bool {
+        self.headless_run.is_some()
+    }
+
+    pub(super) fn record_headless_error(&mut self, message: impl Into<String>) {
+        if let Some(run) = self.headless_run.as_mut() {
+            run.errors.push(message.into());
+        }
+    }
+
+    /// Ends the run without failing it, e.g. when no display or GPU adapter is available.
+    pub(super) fn skip_headless_run(&mut self, reason: impl Into<String>) {
+        if let Some(run) = self.headless_run.as_mut() {
+            run.skipped = Some(reason.into());
+        }
+        self.should_close = true;
+    }
+
+    pub(super) fn advance_headless_run(&mut self) {
+        let Some(run) = self.headless_run.as_mut() else {
+            return;
+        };
+        if !run.finish_frame() {
+            return;
+        }
+        let mut errors = Vec::new();
+        for status in self.plugin_manager().statuses() {
+            match &status.state {
+                PluginState::Failed(reason) => {
+                    errors.push(format!("plugin '{}' failed: {reason}", status.name))
+                }
+                PluginState::Quarantined(quarantine) => {
+                    errors.push(format!("plugin '{}' quarantined: {}", status.name, quarantine.reason))
+                }
+                PluginState::Loaded | PluginState::Disabled(_) => {}
+            }
+        }
+        if let Some(err) = self.script_plugin().and_then(|plugin| plugin.last_error()) {
+            errors.push(format!("script error: {err}"));
+        }
+        for error in errors {
+            self.record_headless_error(error);
+        }
+        self.should_close = true;
+    }
+
+    /// Result of the headless run once the event loop has exited; `None` outside headless mode.
+    pub fn take_headless_outcome(&mut self) -> Option<Result<()>> {
+        self.headless_run.take().map(|run| run.outcome())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn run_fails_only_when_errors_were_recorded() {
+        let mut run = HeadlessRun::new(2);
+        assert!(!run.finish_frame());
+        assert!(run.outcome().unwrap_err().to_string().contains("exited after 1 of 2"));
+        assert!(run.finish_frame());
+        assert!(!run.finish_frame(), "frames past the budget are not counted");
+        assert!(run.outcome().is_ok());
+
+        run.errors.push("Render error: lost device".to_string());
+        let err = run.outcome().unwrap_err().to_string();
+        assert!(err.contains("after 2 frame(s)") && err.contains("lost device"), "{err}");
+
+        run.skipped = Some("no adapter".to_string());
+        assert!(run.outcome().is_ok(), "skipping is not a failure");
+    }
+}
@@ -2,6 +2,8 @@ use std::{collections::HashSet, sync::Arc};
 
 use super::{editor_shell::SCENE_HISTORY_CAPACITY, editor_ui, App};
 use crate::ecs::{ForceField, ParticleAttractor};
+use crate::tr;
+use glam::{Vec2, Vec4};
 
 impl App {
     pub(super) fn set_inspector_status(&self, status: Option<String>) {
@@ -84,34 +86,100 @@ impl App {
                     if self.ecs.set_translation(entity, translation) {
                         self.set_inspector_status(None);
                     } else {
-                        self.set_inspector_status(Some("Failed to update position.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.position_failed")));
                     }
                 }
                 editor_ui::InspectorAction::SetRotation { entity, rotation } => {
                     if self.ecs.set_rotation(entity, rotation) {
                         self.set_inspector_status(None);
                     } else {
-                        self.set_inspector_status(Some("Failed to update rotation.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.rotation_failed")));
                     }
                 }
                 editor_ui::InspectorAction::SetScale { entity, scale } => {
                     if self.ecs.set_scale(entity, scale) {
                         self.set_inspector_status(None);
                     } else {
-                        self.set_inspector_status(Some("Failed to update scale.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.scale_failed")));
                     }
                 }
+                editor_ui::InspectorAction::SetColliderGeometry { entity, geometry } => {
+                    match self.ecs.collider_geometry(entity) {
+                        Some(before) if self.ecs.set_collider_geometry(entity, geometry) => {
+                            let after = self.ecs.collider_geometry(entity).unwrap_or(geometry);
+                            self.record_collider_edit(entity, before, after);
+                            self.set_inspector_status(None);
+                        }
+                        _ => self.set_inspector_status(Some(tr!("inspector.collider_edit_failed"))),
+                    }
+                }
+                editor_ui::InspectorAction::UndoColliderEdit => self.undo_collider_edit(),
+                editor_ui::InspectorAction::RedoColliderEdit => self.redo_collider_edit(),
                 editor_ui::InspectorAction::SetVelocity { entity, velocity } => {
                     if self.ecs.set_velocity(entity, velocity) {
                         self.set_inspector_status(None);
                     } else {
-                        self.set_inspector_status(Some("Failed to update velocity.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.velocity_failed")));
+                    }
+                }
+                editor_ui::InspectorAction::ResetTransform { entity } => {
+                    if self.ecs.reset_transform(entity) {
+                        self.set_inspector_status(Some(tr!("inspector.transform_reset")));
+                    } else {
+                        self.set_inspector_status(Some(tr!("inspector.transform_reset_failed")));
+                    }
+                }
+                editor_ui::InspectorAction::ResetVelocity { entity } => {
+                    if self.ecs.set_velocity(entity, Vec2::ZERO) {
+                        self.set_inspector_status(Some(tr!("inspector.velocity_reset")));
+                    } else {
+                        self.set_inspector_status(Some(tr!("inspector.velocity_reset_failed")));
+                    }
+                }
+                editor_ui::InspectorAction::ResetTint { entity } => {
+                    if self.ecs.set_tint(entity, Some(Vec4::ONE)) {
+                        self.set_inspector_status(Some(tr!("inspector.tint_reset")));
+                    } else {
+                        self.set_inspector_status(Some(tr!("inspector.tint_reset_failed")));
+                    }
+                }
+                editor_ui::InspectorAction::SetPluginComponentField { entity, key, field, value } => {
+                    if self.ecs.set_plugin_component_field(entity, &key, &field, value) {
+                        self.set_inspector_status(None);
+                    } else {
+                        self.set_inspector_status(Some(tr!(
+                            "inspector.plugin_field_failed",
+                            key = key,
+                            field = field
+                        )));
+                    }
+                }
+                editor_ui::InspectorAction::SetParent { entity, parent_scene_id } => {
+                    let Some(parent) = self.ecs.find_entity_by_scene_id(&parent_scene_id) else {
+                        self.set_inspector_status(Some(tr!(
+                            "inspector.parent_not_found",
+                            id = parent_scene_id
+                        )));
+                        continue;
+                    };
+                    match self.ecs.set_parent(entity, parent) {
+                        Ok(()) => self.set_inspector_status(None),
+                        Err(err) => {
+                            self.set_inspector_status(Some(tr!("inspector.set_parent_failed", error = err)))
+                        }
+                    }
+                }
+                editor_ui::InspectorAction::DetachFromParent { entity } => {
+                    if self.ecs.detach_from_parent(entity) {
+                        self.set_inspector_status(None);
+                    } else {
+                        self.set_inspector_status(Some(tr!("inspector.no_parent")));
                     }
                 }
                 editor_ui::InspectorAction::SetScript { entity, path } => {
                     let trimmed = path.trim();
                     if trimmed.is_empty() {
-                        self.set_inspector_status(Some("Script path cannot be empty.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.script_path_empty")));
                     } else {
                         let mut entity_ref = self.ecs.world.entity_mut(entity);
                         if let Some(mut behaviour) = entity_ref.get_mut::<crate::scripts::ScriptBehaviour>() {
@@ -120,7 +188,7 @@ impl App {
                         } else {
                             entity_ref.insert(crate::scripts::ScriptBehaviour::new(trimmed.to_string()));
                         }
-                        self.set_inspector_status(Some(format!("Script set to {trimmed}.")));
+                        self.set_inspector_status(Some(tr!("inspector.script_set", path = trimmed)));
                     }
                 }
                 editor_ui::InspectorAction::SetScriptMute { entity, muted } => {
@@ -128,11 +196,11 @@ impl App {
                         if let Some(mut behaviour) = entity_ref.get_mut::<crate::scripts::ScriptBehaviour>() {
                             behaviour.mute_errors = muted;
                             let status = if muted {
-                                "Script errors muted for this entity."
+                                tr!("inspector.script_errors_muted")
                             } else {
-                                "Script errors unmuted for this entity."
+                                tr!("inspector.script_errors_unmuted")
                             };
-                            self.set_inspector_status(Some(status.to_string()));
+                            self.set_inspector_status(Some(status));
                         }
                     }
                 }
@@ -144,18 +212,18 @@ impl App {
                                 entity_ref.remove::<crate::scripts::ScriptPersistedState>();
                             }
                             let status = if persist {
-                                "Script state will persist across reloads and serialize into scenes."
+                                tr!("inspector.script_persist_enabled")
                             } else {
-                                "Script state persistence disabled; serialized state cleared."
+                                tr!("inspector.script_persist_disabled")
                             };
-                            self.set_inspector_status(Some(status.to_string()));
+                            self.set_inspector_status(Some(status));
                         }
                     }
                 }
                 editor_ui::InspectorAction::RemoveScript { entity } => {
                     let mut entity_ref = self.ecs.world.entity_mut(entity);
                     entity_ref.remove::<crate::scripts::ScriptBehaviour>();
-                    self.set_inspector_status(Some("Script removed.".to_string()));
+                    self.set_inspector_status(Some(tr!("inspector.script_removed")));
                 }
                 editor_ui::InspectorAction::ReloadScript { entity, reset_state } => {
                     let preserve_state = !reset_state;
@@ -168,14 +236,61 @@ impl App {
                         }
                     }
                     if reset_state {
-                        self.set_inspector_status(Some("Script reset and state cleared.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.script_reset")));
                     } else {
-                        self.set_inspector_status(Some("Script reloaded for this entity.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.script_reloaded")));
                     }
                 }
                 editor_ui::InspectorAction::SetEmitterTrail { entity, trail } => {
                     self.ecs.set_emitter_trail(entity, trail);
-                    self.set_inspector_status(Some("Emitter trail updated.".to_string()));
+                    self.set_inspector_status(Some(tr!("inspector.emitter_trail_updated")));
+                }
+                editor_ui::InspectorAction::SetEmitterShape { entity, shape } => {
+                    self.ecs.set_emitter_shape(entity, shape);
+                    self.set_inspector_status(Some(tr!(
+                        "inspector.emitter_shape_set",
+                        shape = shape.label()
+                    )));
+                }
+                editor_ui::InspectorAction::SetEmitterCollision { entity, mode, events } => {
+                    self.ecs.set_emitter_collision(entity, mode, events);
+                    self.set_inspector_status(Some(tr!(
+                        "inspector.emitter_collision_set",
+                        mode = mode.label()
+                    )));
+                }
+                editor_ui::InspectorAction::EmitterBurst { entity, count } => {
+                    if self.ecs.emitter_burst(entity, count) {
+                        self.set_inspector_status(Some(tr!("inspector.burst_queued", count = count)));
+                    } else {
+                        self.set_inspector_status(Some(tr!("inspector.burst_failed")));
+                    }
+                }
+                editor_ui::InspectorAction::SetEmitterBurst { entity, count, impulse_speed } => {
+                    if self.ecs.set_emitter_burst(entity, count, impulse_speed) {
+                        self.set_inspector_status(Some(tr!("inspector.burst_fired", count = count)));
+                    } else {
+                        self.set_inspector_status(Some(tr!("inspector.burst_failed")));
+                    }
+                }
+                editor_ui::InspectorAction::SetEmitterSettings {
+                    entity,
+                    rate,
+                    spread,
+                    speed,
+                    lifetime,
+                    start_color,
+                    end_color,
+                    start_size,
+                    end_size,
+                } => {
+                    self.ecs.set_emitter_rate(entity, rate);
+                    self.ecs.set_emitter_spread(entity, spread);
+                    self.ecs.set_emitter_speed(entity, speed);
+                    self.ecs.set_emitter_lifetime(entity, lifetime);
+                    self.ecs.set_emitter_colors(entity, start_color, end_color);
+                    self.ecs.set_emitter_sizes(entity, start_size, end_size);
+                    self.set_inspector_status(Some(tr!("inspector.emitter_settings_updated")));
                 }
                 editor_ui::InspectorAction::SetForceField { entity, field } => {
                     let field = field.map(|(kind, strength, radius, falloff, direction)| ForceField {
@@ -186,7 +301,7 @@ impl App {
                         direction,
                     });
                     self.ecs.set_force_field(entity, field);
-                    self.set_inspector_status(Some("Force field updated.".to_string()));
+                    self.set_inspector_status(Some(tr!("inspector.force_field_updated")));
                 }
                 editor_ui::InspectorAction::SetAttractor { entity, attractor } => {
                     let attractor =
@@ -194,224 +309,343 @@ impl App {
                             ParticleAttractor { strength, radius, min_distance, max_acceleration, falloff }
                         });
                     self.ecs.set_attractor(entity, attractor);
-                    self.set_inspector_status(Some("Attractor updated.".to_string()));
+                    self.set_inspector_status(Some(tr!("inspector.attractor_updated")));
                 }
                 editor_ui::InspectorAction::ClearTransformClip { entity } => {
                     if self.ecs.clear_transform_clip(entity) {
-                        self.set_inspector_status(Some("Transform clip cleared.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.transform_clip_cleared")));
                     } else {
-                        self.set_inspector_status(Some("Failed to clear transform clip.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.failed_to_clear_transform_clip")));
                     }
                 }
                 editor_ui::InspectorAction::SetTransformClip { entity, clip_key } => {
                     if self.ecs.set_transform_clip(entity, &self.assets, &clip_key) {
-                        self.set_inspector_status(Some(format!("Transform clip set to {}", clip_key)));
+                        self.set_inspector_status(Some(tr!(
+                            "inspector.transform_clip_set_to",
+                            clip_key = clip_key
+                        )));
                     } else {
-                        self.set_inspector_status(Some(format!(
-                            "Transform clip '{}' not available",
-                            clip_key
+                        self.set_inspector_status(Some(tr!(
+                            "inspector.transform_clip_not_available",
+                            clip_key = clip_key
                         )));
                     }
                 }
+                editor_ui::InspectorAction::SetTintCurve { entity, curve_key, duration, loop_mode } => {
+                    if self.ecs.set_tint_curve(entity, &self.assets, &curve_key, duration, loop_mode) {
+                        self.set_inspector_status(Some(tr!(
+                            "inspector.tint_curve_set_to",
+                            curve_key = curve_key
+                        )));
+                    } else {
+                        self.set_inspector_status(Some(tr!(
+                            "inspector.tint_curve_not_available",
+                            curve_key = curve_key
+                        )));
+                    }
+                }
+                editor_ui::InspectorAction::ClearTintCurve { entity } => {
+                    if self.ecs.clear_tint_curve(entity) {
+                        self.set_inspector_status(Some(tr!("inspector.tint_curve_cleared")));
+                    } else {
+                        self.set_inspector_status(Some(tr!("inspector.failed_to_clear_tint_curve")));
+                    }
+                }
                 editor_ui::InspectorAction::SetTransformClipPlaying { entity, playing } => {
                     if self.ecs.set_transform_clip_playing(entity, playing) {
                         self.set_inspector_status(None);
                     } else {
-                        self.set_inspector_status(Some("Failed to update clip playback.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.failed_to_update_clip_playback")));
                     }
                 }
                 editor_ui::InspectorAction::ResetTransformClip { entity } => {
                     if self.ecs.reset_transform_clip(entity) {
-                        self.set_inspector_status(Some("Transform clip reset.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.transform_clip_reset")));
                     } else {
-                        self.set_inspector_status(Some("Failed to reset transform clip.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.failed_to_reset_transform_clip")));
                     }
                 }
                 editor_ui::InspectorAction::SetTransformClipSpeed { entity, speed } => {
                     if self.ecs.set_transform_clip_speed(entity, speed) {
                         self.set_inspector_status(None);
                     } else {
-                        self.set_inspector_status(Some("Failed to update clip speed.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.failed_to_update_clip_speed")));
+                    }
+                }
+                editor_ui::InspectorAction::SetTransformClipLoopCount { entity, loop_count } => {
+                    if self.ecs.set_transform_clip_loop_count(entity, loop_count) {
+                        self.set_inspector_status(None);
+                    } else {
+                        self.set_inspector_status(Some(tr!("inspector.failed_to_update_clip_loop_count")));
                     }
                 }
                 editor_ui::InspectorAction::SetTransformClipGroup { entity, group } => {
                     if self.ecs.set_transform_clip_group(entity, group.as_deref()) {
                         self.set_inspector_status(None);
                     } else {
-                        self.set_inspector_status(Some("Failed to update clip group.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.failed_to_update_clip_group")));
                     }
                 }
                 editor_ui::InspectorAction::SetTransformClipTime { entity, time } => {
                     if self.ecs.set_transform_clip_time(entity, time) {
                         self.set_inspector_status(None);
                     } else {
-                        self.set_inspector_status(Some("Failed to scrub clip time.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.failed_to_scrub_clip_time")));
+                    }
+                }
+                editor_ui::InspectorAction::SetTransformClipEvents { entity, events } => {
+                    if self.ecs.set_transform_clip_events(entity, events) {
+                        self.set_inspector_status(None);
+                    } else {
+                        self.set_inspector_status(Some(tr!("inspector.failed_to_update_clip_events")));
                     }
                 }
                 editor_ui::InspectorAction::SetTransformTrackMask { entity, mask } => {
                     if self.ecs.set_transform_track_mask(entity, mask) {
                         self.set_inspector_status(None);
                     } else {
-                        self.set_inspector_status(Some("Failed to update transform track mask.".to_string()));
+                        self.set_inspector_status(Some(tr!(
+                            "inspector.failed_to_update_transform_track_mask"
+                        )));
                     }
                 }
                 editor_ui::InspectorAction::SetPropertyTrackMask { entity, mask } => {
                     if self.ecs.set_property_track_mask(entity, mask) {
                         self.set_inspector_status(None);
                     } else {
-                        self.set_inspector_status(Some("Failed to update property track mask.".to_string()));
+                        self.set_inspector_status(Some(tr!(
+                            "inspector.failed_to_update_property_track_mask"
+                        )));
                     }
                 }
                 editor_ui::InspectorAction::ClearSkeleton { entity } => {
                     if self.ecs.clear_skeleton(entity) {
-                        self.set_inspector_status(Some("Skeleton detached.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.skeleton_detached")));
                     } else {
-                        self.set_inspector_status(Some("Failed to detach skeleton.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.failed_to_detach_skeleton")));
                     }
                 }
                 editor_ui::InspectorAction::SetSkeleton { entity, skeleton_key } => {
                     if self.ecs.set_skeleton(entity, &self.assets, &skeleton_key) {
-                        self.set_inspector_status(Some(format!("Skeleton set to {}", skeleton_key)));
+                        self.set_inspector_status(Some(tr!(
+                            "inspector.skeleton_set_to",
+                            skeleton_key = skeleton_key
+                        )));
                     } else {
-                        self.set_inspector_status(Some(format!("Skeleton '{}' unavailable", skeleton_key)));
+                        self.set_inspector_status(Some(tr!(
+                            "inspector.skeleton_unavailable",
+                            skeleton_key = skeleton_key
+                        )));
                     }
                 }
                 editor_ui::InspectorAction::ClearSkeletonClip { entity } => {
                     if self.ecs.clear_skeleton_clip(entity) {
-                        self.set_inspector_status(Some("Skeletal clip cleared.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.skeletal_clip_cleared")));
                     } else {
-                        self.set_inspector_status(Some("Failed to clear skeletal clip.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.failed_to_clear_skeletal_clip")));
                     }
                 }
                 editor_ui::InspectorAction::SetSkeletonClip { entity, clip_key } => {
                     if self.ecs.set_skeleton_clip(entity, &self.assets, &clip_key) {
-                        self.set_inspector_status(Some(format!("Skeletal clip set to {}", clip_key)));
+                        self.set_inspector_status(Some(tr!(
+                            "inspector.skeletal_clip_set_to",
+                            clip_key = clip_key
+                        )));
                     } else {
-                        self.set_inspector_status(Some(format!("Skeletal clip '{}' unavailable", clip_key)));
+                        self.set_inspector_status(Some(tr!(
+                            "inspector.skeletal_clip_unavailable",
+                            clip_key = clip_key
+                        )));
                     }
                 }
                 editor_ui::InspectorAction::SetSkeletonClipPlaying { entity, playing } => {
                     if self.ecs.set_skeleton_clip_playing(entity, playing) {
                         self.set_inspector_status(None);
                     } else {
-                        self.set_inspector_status(Some(
-                            "Failed to update skeletal clip playback.".to_string(),
-                        ));
+                        self.set_inspector_status(Some(tr!(
+                            "inspector.failed_to_update_skeletal_clip_playback"
+                        )));
                     }
                 }
                 editor_ui::InspectorAction::ResetSkeletonPose { entity } => {
                     if self.ecs.reset_skeleton_pose(entity) {
-                        self.set_inspector_status(Some("Skeletal pose reset.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.skeletal_pose_reset")));
                     } else {
-                        self.set_inspector_status(Some("Failed to reset skeletal pose.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.failed_to_reset_skeletal_pose")));
                     }
                 }
                 editor_ui::InspectorAction::SetSkeletonClipSpeed { entity, speed } => {
                     if self.ecs.set_skeleton_clip_speed(entity, speed) {
                         self.set_inspector_status(None);
                     } else {
-                        self.set_inspector_status(Some("Failed to update skeletal clip speed.".to_string()));
+                        self.set_inspector_status(Some(tr!(
+                            "inspector.failed_to_update_skeletal_clip_speed"
+                        )));
+                    }
+                }
+                editor_ui::InspectorAction::SetSkeletonClipLoopCount { entity, loop_count } => {
+                    if self.ecs.set_skeleton_clip_loop_count(entity, loop_count) {
+                        self.set_inspector_status(None);
+                    } else {
+                        self.set_inspector_status(Some(tr!(
+                            "inspector.failed_to_update_skeletal_clip_loop_count"
+                        )));
                     }
                 }
                 editor_ui::InspectorAction::SetSkeletonClipGroup { entity, group } => {
                     if self.ecs.set_skeleton_clip_group(entity, group.as_deref()) {
                         self.set_inspector_status(None);
                     } else {
-                        self.set_inspector_status(Some("Failed to update skeletal clip group.".to_string()));
+                        self.set_inspector_status(Some(tr!(
+                            "inspector.failed_to_update_skeletal_clip_group"
+                        )));
                     }
                 }
                 editor_ui::InspectorAction::SetSkeletonClipTime { entity, time } => {
                     if self.ecs.set_skeleton_clip_time(entity, time) {
                         self.set_inspector_status(None);
                     } else {
-                        self.set_inspector_status(Some("Failed to scrub skeletal clip.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.failed_to_scrub_skeletal_clip")));
+                    }
+                }
+                editor_ui::InspectorAction::SetGraphParameter { entity, name, value } => {
+                    if self.ecs.set_graph_parameter(entity, &name, value) {
+                        self.set_inspector_status(None);
+                    } else {
+                        self.set_inspector_status(Some(tr!(
+                            "inspector.failed_to_set_graph_parameter",
+                            name = name
+                        )));
                     }
                 }
                 editor_ui::InspectorAction::SetSpriteAtlas { entity, atlas, cleared_timeline } => {
                     if self.ecs.set_sprite_atlas(entity, &self.assets, &atlas) {
                         if cleared_timeline {
-                            self.set_inspector_status(Some(format!(
-                                "Sprite atlas set to {} (timeline cleared)",
-                                atlas
+                            self.set_inspector_status(Some(tr!(
+                                "inspector.sprite_atlas_set_timeline_cleared",
+                                atlas = atlas
                             )));
                         } else {
-                            self.set_inspector_status(Some(format!("Sprite atlas set to {}", atlas)));
+                            self.set_inspector_status(Some(tr!(
+                                "inspector.sprite_atlas_set_to",
+                                atlas = atlas
+                            )));
                         }
                     } else {
-                        self.set_inspector_status(Some(format!("Atlas '{}' unavailable", atlas)));
+                        self.set_inspector_status(Some(tr!("inspector.atlas_unavailable", atlas = atlas)));
                     }
                 }
                 editor_ui::InspectorAction::SetSpriteRegion { entity, atlas, region } => {
                     if self.ecs.set_sprite_region(entity, &self.assets, &region) {
-                        self.set_inspector_status(Some(format!("Sprite region set to {}", region)));
+                        self.set_inspector_status(Some(tr!(
+                            "inspector.sprite_region_set_to",
+                            region = region
+                        )));
                     } else {
-                        self.set_inspector_status(Some(format!(
-                            "Region '{}' not found in atlas {}",
-                            region, atlas
+                        self.set_inspector_status(Some(tr!(
+                            "inspector.region_not_found",
+                            region = region,
+                            atlas = atlas
                         )));
                     }
                 }
+                editor_ui::InspectorAction::SetSpriteZOrder { entity, z_order } => {
+                    if self.ecs.set_sprite_z_order(entity, z_order) {
+                        self.set_inspector_status(Some(tr!(
+                            "inspector.sprite_z_order_set_to",
+                            z_order = z_order
+                        )));
+                    } else {
+                        self.set_inspector_status(Some(tr!("inspector.sprite_z_order_requires_sprite")));
+                    }
+                }
+                editor_ui::InspectorAction::SetSpriteNormalMap { entity, path } => {
+                    match self.ecs.set_sprite_normal_map(entity, path.as_deref(), &mut self.assets) {
+                        Ok(true) => self.set_inspector_status(Some(match path {
+                            Some(path) => tr!("inspector.sprite_normal_map_set_to", path = path),
+                            None => tr!("inspector.sprite_normal_map_cleared"),
+                        })),
+                        Ok(false) => {
+                            self.set_inspector_status(Some(tr!("inspector.normal_map_requires_sprite")));
+                        }
+                        Err(err) => {
+                            self.set_inspector_status(Some(tr!(
+                                "inspector.normal_map_failed",
+                                error = format!("{err:#}")
+                            )));
+                        }
+                    }
+                }
                 editor_ui::InspectorAction::SetSpriteTimeline { entity, timeline } => {
                     if self.ecs.set_sprite_timeline(entity, &self.assets, timeline.as_deref()) {
                         self.set_inspector_status(
                             timeline
                                 .as_ref()
-                                .map(|name| format!("Sprite timeline set to {name}"))
-                                .or_else(|| Some("Sprite timeline cleared".to_string())),
+                                .map(|name| tr!("inspector.sprite_timeline_set_to", name = name))
+                                .or_else(|| Some(tr!("inspector.sprite_timeline_cleared"))),
                         );
                     } else if let Some(name) = timeline {
-                        self.set_inspector_status(Some(format!("Timeline '{name}' unavailable")));
+                        self.set_inspector_status(Some(tr!("inspector.timeline_unavailable", name = name)));
                     } else {
-                        self.set_inspector_status(Some("Failed to change sprite timeline.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.failed_to_change_sprite_timeline")));
                     }
                 }
                 editor_ui::InspectorAction::SetSpriteAnimationPlaying { entity, playing } => {
                     if self.ecs.set_sprite_animation_playing(entity, playing) {
                         self.set_inspector_status(None);
                     } else {
-                        self.set_inspector_status(Some("Failed to update animation playback.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.failed_to_update_animation_playback")));
                     }
                 }
                 editor_ui::InspectorAction::ResetSpriteAnimation { entity } => {
                     if self.ecs.reset_sprite_animation(entity) {
-                        self.set_inspector_status(Some("Sprite animation reset.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.sprite_animation_reset")));
                     } else {
-                        self.set_inspector_status(Some("Failed to reset sprite animation.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.failed_to_reset_sprite_animation")));
                     }
                 }
                 editor_ui::InspectorAction::SetSpriteAnimationLooped { entity, looped } => {
                     if self.ecs.set_sprite_animation_looped(entity, looped) {
                         self.set_inspector_status(None);
                     } else {
-                        self.set_inspector_status(Some("Failed to update loop flag.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.failed_to_update_loop_flag")));
+                    }
+                }
+                editor_ui::InspectorAction::SetSpriteAnimationLoopCount { entity, loop_count } => {
+                    if self.ecs.set_sprite_animation_loop_count(entity, loop_count) {
+                        self.set_inspector_status(None);
+                    } else {
+                        self.set_inspector_status(Some(tr!(
+                            "inspector.failed_to_update_animation_loop_count"
+                        )));
                     }
                 }
                 editor_ui::InspectorAction::SetSpriteAnimationSpeed { entity, speed } => {
                     if self.ecs.set_sprite_animation_speed(entity, speed) {
                         self.set_inspector_status(None);
                     } else {
-                        self.set_inspector_status(Some("Failed to update animation speed.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.failed_to_update_animation_speed")));
                     }
                 }
                 editor_ui::InspectorAction::SetSpriteAnimationStartOffset { entity, start_offset } => {
                     if self.ecs.set_sprite_animation_start_offset(entity, start_offset) {
                         self.set_inspector_status(None);
                     } else {
-                        self.set_inspector_status(Some("Failed to update start offset.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.failed_to_update_start_offset")));
                     }
                 }
                 editor_ui::InspectorAction::SetSpriteAnimationRandomStart { entity, random_start } => {
                     if self.ecs.set_sprite_animation_random_start(entity, random_start) {
                         self.set_inspector_status(None);
                     } else {
-                        self.set_inspector_status(Some("Failed to update random start.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.failed_to_update_random_start")));
                     }
                 }
                 editor_ui::InspectorAction::SetSpriteAnimationGroup { entity, group } => {
                     if self.ecs.set_sprite_animation_group(entity, group.as_deref()) {
                         self.set_inspector_status(None);
                     } else {
-                        self.set_inspector_status(Some("Failed to update animation group.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.failed_to_update_animation_group")));
                     }
                 }
                 editor_ui::InspectorAction::SeekSpriteAnimationFrame {
@@ -428,7 +662,7 @@ impl App {
                             self.set_inspector_status(None);
                         }
                     } else {
-                        self.set_inspector_status(Some("Failed to seek animation frame.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.failed_to_seek_animation_frame")));
                     }
                 }
                 editor_ui::InspectorAction::SetMeshMaterial { entity, material } => {
@@ -439,12 +673,16 @@ impl App {
                     let mut apply_change = true;
                     if let Some(ref key) = material {
                         if !self.material_registry.has(key) {
-                            self.set_inspector_status(Some(format!("Material '{}' not registered", key)));
+                            self.set_inspector_status(Some(tr!(
+                                "inspector.material_not_registered",
+                                key = key
+                            )));
                             apply_change = false;
                         } else if let Err(err) = self.material_registry.retain(key) {
-                            self.set_inspector_status(Some(format!(
-                                "Failed to retain material '{}': {err}",
-                                key
+                            self.set_inspector_status(Some(tr!(
+                                "inspector.failed_to_retain_material",
+                                key = key,
+                                error = err
                             )));
                             apply_change = false;
                         }
@@ -472,7 +710,7 @@ impl App {
                             if let Some(ref key) = material {
                                 self.material_registry.release(key);
                             }
-                            self.set_inspector_status(Some("Failed to update mesh material.".to_string()));
+                            self.set_inspector_status(Some(tr!("inspector.failed_to_update_mesh_material")));
                         }
                     } else if let Some(ref key) = material {
                         if material.as_ref() != previous.as_ref() {
@@ -484,7 +722,24 @@ impl App {
                     if self.ecs.set_mesh_shadow_flags(entity, cast, receive) {
                         self.set_inspector_status(None);
                     } else {
-                        self.set_inspector_status(Some("Failed to update mesh shadow flags.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.failed_to_update_mesh_shadow_flags")));
+                    }
+                }
+                editor_ui::InspectorAction::SetMeshMaxDrawDistance { entity, distance } => {
+                    if self.ecs.set_mesh_max_draw_distance(entity, distance) {
+                        self.set_inspector_status(None);
+                    } else {
+                        self.set_inspector_status(Some(tr!("inspector.failed_to_update_mesh_draw_distance")));
+                    }
+                }
+                editor_ui::InspectorAction::SetMaterialCullMode { key, cull_mode } => {
+                    match self.material_registry.set_cull_mode(&key, cull_mode) {
+                        Ok(()) => self.set_inspector_status(None),
+                        Err(err) => self.set_inspector_status(Some(tr!(
+                            "inspector.failed_to_set_material_cull_mode",
+                            key = key,
+                            error = err
+                        ))),
                     }
                 }
                 editor_ui::InspectorAction::SetMeshMaterialParams {
@@ -497,46 +752,46 @@ impl App {
                     if self.ecs.set_mesh_material_params(entity, base_color, metallic, roughness, emissive) {
                         self.set_inspector_status(None);
                     } else {
-                        self.set_inspector_status(Some(
-                            "Failed to update mesh material parameters.".to_string(),
-                        ));
+                        self.set_inspector_status(Some(tr!(
+                            "inspector.failed_to_update_mesh_material_parameters"
+                        )));
                     }
                 }
                 editor_ui::InspectorAction::SetMeshTranslation { entity, translation } => {
                     if self.ecs.set_mesh_translation(entity, translation) {
                         self.set_inspector_status(None);
                     } else {
-                        self.set_inspector_status(Some("Failed to update mesh translation.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.failed_to_update_mesh_translation")));
                     }
                 }
                 editor_ui::InspectorAction::SetMeshRotationEuler { entity, rotation } => {
                     if self.ecs.set_mesh_rotation_euler(entity, rotation) {
                         self.set_inspector_status(None);
                     } else {
-                        self.set_inspector_status(Some("Failed to update mesh rotation.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.failed_to_update_mesh_rotation")));
                     }
                 }
                 editor_ui::InspectorAction::SetMeshScale3D { entity, scale } => {
                     if self.ecs.set_mesh_scale(entity, scale) {
                         self.set_inspector_status(None);
                     } else {
-                        self.set_inspector_status(Some("Failed to update mesh scale.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.failed_to_update_mesh_scale")));
                     }
                 }
                 editor_ui::InspectorAction::SetMeshTint { entity, tint } => {
                     if self.ecs.set_tint(entity, tint) {
                         self.set_inspector_status(None);
                     } else {
-                        self.set_inspector_status(Some("Failed to update tint.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.failed_to_update_tint")));
                     }
                 }
                 editor_ui::InspectorAction::SetSkinMeshJointCount { entity, joint_count } => {
                     if self.ecs.set_skin_mesh_joint_count(entity, joint_count) {
                         self.set_inspector_status(None);
                     } else {
-                        self.set_inspector_status(Some(
-                            "Failed to update skin mesh joint count.".to_string(),
-                        ));
+                        self.set_inspector_status(Some(tr!(
+                            "inspector.failed_to_update_skin_mesh_joint_count"
+                        )));
                     }
                 }
                 editor_ui::InspectorAction::SetSkinMeshSkeleton { entity, skeleton } => {
@@ -546,7 +801,7 @@ impl App {
                             .unwrap_or_else(|| "Skin mesh skeleton cleared.".to_string());
                         self.set_inspector_status(Some(status));
                     } else {
-                        self.set_inspector_status(Some("Failed to update skin mesh skeleton.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.failed_to_update_skin_mesh_skeleton")));
                     }
                 }
                 editor_ui::InspectorAction::SyncSkinMeshJointCount { entity } => {
@@ -560,40 +815,36 @@ impl App {
                                 self.ecs.entity_info(skel_entity).and_then(|info| info.skeleton)
                             {
                                 if self.ecs.set_skin_mesh_joint_count(entity, skeleton_info.joint_count) {
-                                    self.set_inspector_status(Some(format!(
-                                        "Skin mesh joints set to {}",
-                                        skeleton_info.joint_count
+                                    self.set_inspector_status(Some(tr!(
+                                        "inspector.skin_mesh_joints_set_to",
+                                        joint_count = skeleton_info.joint_count
                                     )));
                                 } else {
-                                    self.set_inspector_status(Some(
-                                        "Failed to sync joint count from skeleton.".to_string(),
-                                    ));
+                                    self.set_inspector_status(Some(tr!(
+                                        "inspector.failed_to_sync_joint_count"
+                                    )));
                                 }
                             } else {
-                                self.set_inspector_status(Some(
-                                    "Selected skeleton is missing SkeletonInstance.".to_string(),
-                                ));
+                                self.set_inspector_status(Some(tr!("inspector.skeleton_instance_missing")));
                             }
                         }
                         None => {
-                            self.set_inspector_status(Some(
-                                "Assign a skeleton before syncing joints.".to_string(),
-                            ));
+                            self.set_inspector_status(Some(tr!("inspector.skeleton_required_for_sync")));
                         }
                     }
                 }
                 editor_ui::InspectorAction::DetachSkinMesh { entity } => {
                     if self.ecs.detach_skin_mesh(entity) {
-                        self.set_inspector_status(Some("Skin mesh component removed.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.skin_mesh_component_removed")));
                     } else {
-                        self.set_inspector_status(Some("Failed to remove skin mesh.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.failed_to_remove_skin_mesh")));
                     }
                 }
                 editor_ui::InspectorAction::AttachSkinMesh { entity } => {
                     if self.ecs.attach_skin_mesh(entity, 0) {
-                        self.set_inspector_status(Some("Skin mesh component added.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.skin_mesh_component_added")));
                     } else {
-                        self.set_inspector_status(Some("Failed to add skin mesh component.".to_string()));
+                        self.set_inspector_status(Some(tr!("inspector.failed_to_add_skin_mesh")));
                     }
                 }
             }
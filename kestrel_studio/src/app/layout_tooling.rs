@@ -0,0 +1,217 @@
+use super::*;
+use serde::{Deserialize, Serialize};
+
+pub(crate) const LAYOUT_PRESETS_PATH: &str = "config/layouts.json";
+pub(crate) const DEFAULT_SIDEBAR_WIDTH: f32 = 340.0;
+
+/// Panel keys understood by [`LayoutPreset::panels_open`].
+pub(crate) const PANEL_SCRIPT_DEBUGGER: &str = "script_debugger";
+pub(crate) const PANEL_KEYFRAME_EDITOR: &str = "keyframe_editor";
+pub(crate) const PANEL_ANIMATION_TIME: &str = "animation_time";
+pub(crate) const PANEL_PREFAB_SHELF: &str = "prefab_shelf";
+pub(crate) const PANEL_MESH_PREVIEW: &str = "mesh_preview";
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub(crate) struct LayoutPreset {
+    pub(crate) name: String,
+    #[serde(default)]
+    pub(crate) panels_open: HashMap<String, bool>,
+    #[serde(default = "default_sidebar_width")]
+    pub(crate) sidebar_width: f32,
+}
+
+impl LayoutPreset {
+    pub(crate) fn new(name: impl Into<String>, panels: &[(&str, bool)], sidebar_width: f32) -> Self {
+        Self {
+            name: name.into(),
+            panels_open: panels.iter().map(|(key, open)| ((*key).to_string(), *open)).collect(),
+            sidebar_width,
+        }
+    }
+
+    /// Returns the stored state for `panel`, or `None` when the preset leaves it untouched.
+    pub(crate) fn panel_open(&self, panel: &str) -> Option<bool> {
+        self.panels_open.get(panel).copied()
+    }
+}
+
+/// Requests raised by the "Layouts" menu; applied by [`App::handle_layout_action`].
+#[derive(Debug, Clone)]
+pub(crate) enum LayoutAction {
+    Apply(String),
+    Save(LayoutPreset),
+    Delete(String),
+}
+
+fn default_sidebar_width() -> f32 {
+    DEFAULT_SIDEBAR_WIDTH
+}
+
+pub(crate) fn default_layout_presets() -> Vec<LayoutPreset> {
+    vec![
+        LayoutPreset::new(
+            "Animation",
+            &[
+                (PANEL_SCRIPT_DEBUGGER, false),
+                (PANEL_KEYFRAME_EDITOR, true),
+                (PANEL_ANIMATION_TIME, true),
+                (PANEL_PREFAB_SHELF, false),
+                (PANEL_MESH_PREVIEW, false),
+            ],
+            380.0,
+        ),
+        LayoutPreset::new(
+            "Code",
+            &[
+                (PANEL_SCRIPT_DEBUGGER, true),
+                (PANEL_KEYFRAME_EDITOR, false),
+                (PANEL_ANIMATION_TIME, false),
+                (PANEL_PREFAB_SHELF, false),
+                (PANEL_MESH_PREVIEW, false),
+            ],
+            DEFAULT_SIDEBAR_WIDTH,
+        ),
+        LayoutPreset::new(
+            "3D Preview",
+            &[
+                (PANEL_SCRIPT_DEBUGGER, false),
+                (PANEL_KEYFRAME_EDITOR, false),
+                (PANEL_ANIMATION_TIME, false),
+                (PANEL_PREFAB_SHELF, true),
+                (PANEL_MESH_PREVIEW, true),
+            ],
+            280.0,
+        ),
+    ]
+}
+
+/// Loads presets from `path`, falling back to the built-in defaults when the file is missing or invalid.
+pub(crate) fn load_layout_presets(path: &Path) -> Vec<LayoutPreset> {
+    if !path.exists() {
+        return default_layout_presets();
+    }
+    let data = match fs::read_to_string(path) {
+        Ok(data) => data,
+        Err(err) => {
+            eprintln!("[layouts] failed to read {}: {err}", path.display());
+            return default_layout_presets();
+        }
+    };
+    match serde_json::from_str::<Vec<LayoutPreset>>(&data) {
+        Ok(presets) => presets,
+        Err(err) => {
+            eprintln!("[layouts] failed to parse {}: {err}", path.display());
+            default_layout_presets()
+        }
+    }
+}
+
+pub(crate) fn store_layout_presets(path: &Path, presets: &[LayoutPreset]) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create layout preset dir {}", parent.display()))?;
+    }
+    let data = serde_json::to_string_pretty(presets)?;
+    fs::write(path, data).with_context(|| format!("Failed to write layout presets {}", path.display()))?;
+    Ok(())
+}
+
+impl App {
+    pub(crate) fn handle_layout_action(&self, action: LayoutAction) {
+        match action {
+            LayoutAction::Apply(name) => {
+                let applied = self.with_editor_ui_state_mut(|state| {
+                    let Some(preset) =
+                        state.layout_presets.iter().find(|preset| preset.name == name).cloned()
+                    else {
+                        return false;
+                    };
+                    if let Some(open) = preset.panel_open(PANEL_SCRIPT_DEBUGGER) {
+                        state.script_debugger_open = open;
+                    }
+                    if let Some(open) = preset.panel_open(PANEL_KEYFRAME_EDITOR) {
+                        if state.animation_keyframe_panel.is_open() != open {
+                            state.animation_keyframe_panel.toggle();
+                        }
+                    }
+                    state.layout_preset = Some(preset.name.clone());
+                    state.pending_layout = Some(preset);
+                    true
+                });
+                if applied {
+                    self.set_ui_scene_status(tr!("layout.applied", name = name));
+                } else {
+                    self.set_ui_scene_status(tr!("layout.not_found", name = name));
+                }
+            }
+            LayoutAction::Save(preset) => {
+                let name = preset.name.trim().to_string();
+                if name.is_empty() {
+                    self.set_ui_scene_status(tr!("layout.name_required"));
+                    return;
+                }
+                let presets = self.with_editor_ui_state_mut(|state| {
+                    let preset = LayoutPreset { name: name.clone(), ..preset };
+                    match state.layout_presets.iter_mut().find(|existing| existing.name == name) {
+                        Some(existing) => *existing = preset,
+                        None => state.layout_presets.push(preset),
+                    }
+                    state.layout_preset = Some(name.clone());
+                    state.layout_presets.clone()
+                });
+                self.persist_layout_presets(&presets, format!("Saved layout '{name}'."));
+            }
+            LayoutAction::Delete(name) => {
+                let presets = self.with_editor_ui_state_mut(|state| {
+                    let before = state.layout_presets.len();
+                    state.layout_presets.retain(|preset| preset.name != name);
+                    if state.layout_preset.as_deref() == Some(name.as_str()) {
+                        state.layout_preset = None;
+                    }
+                    (state.layout_presets.len() != before).then(|| state.layout_presets.clone())
+                });
+                match presets {
+                    Some(presets) => {
+                        self.persist_layout_presets(&presets, format!("Deleted layout '{name}'."))
+                    }
+                    None => self.set_ui_scene_status(tr!("layout.not_found", name = name)),
+                }
+            }
+        }
+    }
+
+    fn persist_layout_presets(&self, presets: &[LayoutPreset], status: String) {
+        match store_layout_presets(Path::new(LAYOUT_PRESETS_PATH), presets) {
+            Ok(()) => self.set_ui_scene_status(status),
+            Err(err) => self.set_ui_scene_status(tr!("layout.save_failed", error = format!("{err:#}"))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn missing_or_invalid_file_falls_back_to_defaults() {
+        let dir = tempdir().expect("tempdir");
+        let path = dir.path().join("layouts.json");
+        let names = |presets: Vec<LayoutPreset>| presets.into_iter().map(|p| p.name).collect::<Vec<_>>();
+        assert_eq!(names(load_layout_presets(&path)), ["Animation", "Code", "3D Preview"]);
+        fs::write(&path, "not json").expect("write");
+        assert_eq!(names(load_layout_presets(&path)), ["Animation", "Code", "3D Preview"]);
+    }
+
+    #[test]
+    fn presets_roundtrip_through_disk() {
+        let dir = tempdir().expect("tempdir");
+        let path = dir.path().join("nested").join("layouts.json");
+        let presets = vec![LayoutPreset::new("Mine", &[(PANEL_PREFAB_SHELF, false)], 412.0)];
+        store_layout_presets(&path, &presets).expect("store");
+        let loaded = load_layout_presets(&path);
+        assert_eq!(loaded, presets);
+        assert_eq!(loaded[0].panel_open(PANEL_PREFAB_SHELF), Some(false));
+        assert_eq!(loaded[0].panel_open(PANEL_MESH_PREVIEW), None);
+    }
+}
@@ -0,0 +1,68 @@
+use crate::camera3d::Frustum;
+use glam::{Vec3, Vec4};
+
+/// Wireframe colour used when the culled-bounds debug overlay is enabled.
+#[cfg_attr(not(feature = "debug_draw"), allow(dead_code))]
+pub(super) const CULLED_BOUNDS_COLOR: Vec4 = Vec4::new(1.0, 0.35, 0.2, 1.0);
+
+/// Per-frame tally of scene meshes that reached the renderer versus those rejected before upload.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(super) struct MeshCullCounts {
+    pub drawn: u32,
+    pub culled: u32,
+}
+
+/// Whether a mesh whose world-space bounds span `min..max` should be drawn from `eye`.
+///
+/// The distance limit is measured to the nearest point of the box, so large meshes are not
+/// dropped while the camera is standing inside or right next to them.
+pub(super) fn mesh_instance_visible(
+    frustum: &Frustum,
+    eye: Vec3,
+    min: Vec3,
+    max: Vec3,
+    max_draw_distance: Option<f32>,
+) -> bool {
+    if let Some(limit) = max_draw_distance {
+        if eye.clamp(min, max).distance(eye) > limit {
+            return false;
+        }
+    }
+    frustum.intersects_aabb(min, max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::camera3d::Camera3D;
+    use winit::dpi::PhysicalSize;
+
+    fn test_frustum() -> (Frustum, Vec3) {
+        let camera = Camera3D::new(Vec3::new(0.0, 0.0, 10.0), Vec3::ZERO, 60f32.to_radians(), 0.1, 1000.0);
+        (camera.frustum(PhysicalSize::new(1280, 720)), camera.position)
+    }
+
+    #[test]
+    fn meshes_outside_the_frustum_are_culled() {
+        let (frustum, eye) = test_frustum();
+        assert!(mesh_instance_visible(&frustum, eye, Vec3::splat(-1.0), Vec3::splat(1.0), None));
+        assert!(!mesh_instance_visible(
+            &frustum,
+            eye,
+            Vec3::new(-1.0, -1.0, 20.0),
+            Vec3::new(1.0, 1.0, 22.0),
+            None
+        ));
+    }
+
+    #[test]
+    fn max_draw_distance_measures_to_the_nearest_box_point() {
+        let (frustum, eye) = test_frustum();
+        let (min, max) = (Vec3::new(-1.0, -1.0, -60.0), Vec3::new(1.0, 1.0, -40.0));
+        assert!(mesh_instance_visible(&frustum, eye, min, max, None));
+        assert!(mesh_instance_visible(&frustum, eye, min, max, Some(55.0)));
+        assert!(!mesh_instance_visible(&frustum, eye, min, max, Some(45.0)));
+        // The camera sits inside this box, so any limit keeps it.
+        assert!(mesh_instance_visible(&frustum, eye, Vec3::splat(-50.0), Vec3::splat(50.0), Some(1.0)));
+    }
+}
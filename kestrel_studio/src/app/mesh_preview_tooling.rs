@@ -1,5 +1,9 @@
+use super::editor_ui::MeshSceneImportRequest;
 use super::{App, MeshControlMode};
 use crate::mesh_preview::MeshPreviewPlugin;
+use crate::tr;
+use glam::Vec3;
+use std::path::Path;
 
 impl App {
     pub(super) fn set_mesh_status<S: Into<String>>(&mut self, message: S) {
@@ -28,6 +32,15 @@ impl App {
         });
     }
 
+    pub(super) fn set_mesh_clip_planes(&mut self, near: f32, far: f32) {
+        let Some(plugin) = self.mesh_preview_plugin_mut() else {
+            return;
+        };
+        if let Err(err) = plugin.set_mesh_clip_planes(near, far) {
+            self.set_ui_scene_status(tr!("camera.clip_planes_invalid", error = err));
+        }
+    }
+
     pub(super) fn reset_mesh_camera(&mut self) {
         self.with_plugins(|plugins, ctx| {
             if let Some(plugin) = plugins.get_mut::<MeshPreviewPlugin>() {
@@ -64,4 +77,37 @@ impl App {
             self.set_selected_entity(Some(entity));
         }
     }
+
+    pub(super) fn import_mesh_scene(&mut self, request: MeshSceneImportRequest) {
+        let MeshSceneImportRequest { path, settings, spawn_hierarchy } = request;
+        if let Err(err) = settings.save_for(Path::new(&path)) {
+            eprintln!("[mesh] failed to save import settings for {path}: {err:?}");
+        }
+        let nodes = match self.mesh_registry.import_gltf_scene(&path, &mut self.material_registry) {
+            Ok(nodes) => nodes,
+            Err(err) => {
+                eprintln!("[mesh] import of {path} failed: {err:?}");
+                self.set_mesh_status(format!("Import of {path} failed: {err}"));
+                return;
+            }
+        };
+        let parts: usize = nodes.iter().map(|node| node.meshes.len()).sum();
+        if !spawn_hierarchy {
+            self.set_mesh_status(format!("Imported {parts} mesh part(s) from {path}"));
+            return;
+        }
+        match self.ecs.spawn_mesh_hierarchy(&nodes, Vec3::ZERO) {
+            Ok(root) => {
+                self.set_selected_entity(Some(root));
+                self.set_mesh_status(format!(
+                    "Spawned {} node(s) with {parts} mesh part(s) from {path}",
+                    nodes.len()
+                ));
+            }
+            Err(err) => {
+                eprintln!("[mesh] spawning hierarchy for {path} failed: {err:?}");
+                self.set_mesh_status(format!("Spawning {path} failed: {err}"));
+            }
+        }
+    }
 }
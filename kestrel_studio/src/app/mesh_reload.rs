@@ -23,7 +23,7 @@ pub(super) struct MeshReloadResult {
 pub(super) fn run_mesh_reload_job(job: MeshReloadJob) -> MeshReloadResult {
     let MeshReloadJob { request } = job;
     let MeshReloadRequest { key, path } = request;
-    let data = Mesh::load_gltf_with_materials(&path);
+    let data = Mesh::load_gltf_for_key(&path, &key);
     MeshReloadResult { key, path, data }
 }
 
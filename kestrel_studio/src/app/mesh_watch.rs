@@ -11,7 +11,8 @@ const MESH_HOT_RELOAD_DEBOUNCE: Duration = Duration::from_millis(100);
 
 #[derive(Debug, Clone)]
 pub(crate) struct MeshWatchEntry {
-    pub(crate) key: String,
+    /// Every mesh key loaded from this file; glTF scene imports register one key per part.
+    pub(crate) keys: Vec<String>,
     pub(crate) original: PathBuf,
 }
 
@@ -39,19 +40,23 @@ impl MeshHotReload {
     }
 
     pub(crate) fn sync(&mut self, desired: &[(PathBuf, PathBuf, String)]) -> Result<()> {
-        let mut desired_map: HashMap<PathBuf, (PathBuf, String)> = HashMap::new();
+        let mut desired_map: HashMap<PathBuf, (PathBuf, Vec<String>)> = HashMap::new();
         for (original, normalized, key) in desired {
-            desired_map.insert(normalized.clone(), (original.clone(), key.clone()));
+            let (_, keys) =
+                desired_map.entry(normalized.clone()).or_insert_with(|| (original.clone(), Vec::new()));
+            if !keys.contains(key) {
+                keys.push(key.clone());
+            }
         }
-        for (normalized, (original, key)) in desired_map.iter() {
+        for (normalized, (original, keys)) in desired_map.iter() {
             match self.watched.get_mut(normalized) {
                 Some(entry) => {
-                    if entry.key != *key {
-                        entry.key = key.clone();
+                    if entry.keys != *keys {
+                        entry.keys = keys.clone();
                     }
                 }
                 None => {
-                    self.watch_path(original.clone(), normalized.clone(), key.clone())
+                    self.watch_path(original.clone(), normalized.clone(), keys.clone())
                         .map_err(|err| anyhow!("watch failed for '{}': {err}", original.display()))?;
                 }
             }
@@ -74,7 +79,7 @@ impl MeshHotReload {
                         continue;
                     }
                     for path in event.paths {
-                        if let Some(key) = self.resolve_path(&path) {
+                        for key in self.resolve_path(&path) {
                             let now = Instant::now();
                             let accept = match self.last_event.get(&key) {
                                 Some(prev) => now.duration_since(*prev) >= MESH_HOT_RELOAD_DEBOUNCE,
@@ -97,33 +102,36 @@ impl MeshHotReload {
         &mut self,
         original: PathBuf,
         normalized: PathBuf,
-        key: String,
+        keys: Vec<String>,
     ) -> notify::Result<()> {
         self.watcher.watch(&original, RecursiveMode::NonRecursive)?;
-        self.watched.insert(normalized, MeshWatchEntry { key, original });
+        self.watched.insert(normalized, MeshWatchEntry { keys, original });
         Ok(())
     }
 
     pub(crate) fn unwatch_path(&mut self, normalized: &Path) -> notify::Result<()> {
         if let Some(entry) = self.watched.remove(normalized) {
             self.watcher.unwatch(&entry.original)?;
-            self.last_event.remove(&entry.key);
+            for key in &entry.keys {
+                self.last_event.remove(key);
+            }
         }
         Ok(())
     }
 
-    fn resolve_path(&self, path: &Path) -> Option<String> {
+    fn resolve_path(&self, path: &Path) -> Vec<String> {
         let normalized = normalize_event_path(path);
         if let Some(entry) = self.watched.get(&normalized) {
-            return Some(entry.key.clone());
-        }
-        let absolute = normalize_path_for_watch(path).map(|(absolute, _)| absolute)?;
-        for entry in self.watched.values() {
-            if entry.original == absolute {
-                return Some(entry.key.clone());
-            }
+            return entry.keys.clone();
         }
-        None
+        let Some((absolute, _)) = normalize_path_for_watch(path) else {
+            return Vec::new();
+        };
+        self.watched
+            .values()
+            .find(|entry| entry.original == absolute)
+            .map(|entry| entry.keys.clone())
+            .unwrap_or_default()
     }
 
     fn is_relevant(kind: &EventKind) -> bool {
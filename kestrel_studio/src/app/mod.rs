@@ -4,12 +4,24 @@ mod animation_reload_tooling;
 mod animation_tooling;
 mod animation_watch;
 mod asset_watch_tooling;
+mod atlas_remap_tooling;
 mod atlas_watch;
 mod camera_tooling;
+mod capture_tooling;
+mod collider_tooling;
+mod component_clipboard;
+mod device_recovery;
 mod editor_shell;
+mod editor_state_file;
+mod editor_theme;
 mod editor_ui;
+mod emitter_preview;
+mod entity_search;
 mod gizmo_interaction;
+mod headless_tooling;
 mod inspector_tooling;
+mod layout_tooling;
+mod mesh_culling;
 mod mesh_preview_tooling;
 mod mesh_reload;
 mod mesh_watch;
@@ -17,71 +29,88 @@ mod plugin_host;
 mod plugin_runtime;
 mod prefab_tooling;
 mod runtime_loop;
+mod scene_fragment_tooling;
+mod scene_streaming_tooling;
 mod script_console;
+mod selection_tooling;
+mod startup_scene;
 mod telemetry_tooling;
+mod thumbnail_tooling;
 
 pub(crate) use self::camera_tooling::CameraBookmark;
 
 use self::animation_keyframe_panel::{
-    AnimationKeyframePanelState, AnimationPanelCommand, AnimationTrackBinding, AnimationTrackId,
-    AnimationTrackKind, AnimationTrackSummary, KeyframeDetail, KeyframeId, KeyframeValue,
+    AnimationKeyClipboardEntry, AnimationKeyframePanelState, AnimationPanelCommand, AnimationTrackBinding,
+    AnimationTrackId, AnimationTrackKind, AnimationTrackSummary, KeyframeDetail, KeyframeId, KeyframeValue,
 };
 use self::animation_reload::{AnimationReloadController, AnimationReloadWorker, AnimationValidationWorker};
 use self::animation_watch::{AnimationAssetKind, AnimationAssetWatcher};
 use self::atlas_watch::AtlasHotReload;
+use self::capture_tooling::ScreenshotSequence;
+use self::component_clipboard::ComponentClipboard;
 use self::editor_shell::{
     EditorShell, EditorUiState, EditorUiStateParams, EmitterUiDefaults, ScriptDebuggerStatus,
     ScriptHandleBinding, ScriptOffenderStatus, ScriptTimingHistory,
 };
+use self::gizmo_interaction::PointerSample;
+use self::headless_tooling::HeadlessRun;
+use self::mesh_culling::{mesh_instance_visible, MeshCullCounts};
 use self::mesh_reload::MeshReloadWorker;
 use self::mesh_watch::MeshHotReload;
 use self::plugin_host::{BuiltinPluginFactory, PluginHost};
 use self::plugin_runtime::{PluginContextInputs, PluginRuntime};
 use self::runtime_loop::{RuntimeLoop, RuntimeTick};
+use self::startup_scene::{resolve_startup_scene, StartupSceneOutcome};
 pub(crate) use self::telemetry_tooling::FrameBudgetSnapshot;
+use self::thumbnail_tooling::ThumbnailCache;
 #[cfg(feature = "alloc_profiler")]
 use crate::alloc_profiler;
+use crate::alloc_sampler;
 use crate::analytics::{
-    AnalyticsPlugin, AnimationBudgetSample, KeyframeEditorEvent, KeyframeEditorEventKind,
+    AnalyticsPlugin, AnimationBudgetSample, CustomCounterHistory, KeyframeEditorEvent, KeyframeEditorEventKind,
     KeyframeEditorTrackKind, KeyframeEditorUsageSnapshot,
 };
 use crate::animation_validation::AnimationValidationEvent;
 use crate::assets::{
-    AnimationClip, AssetManager, ClipInterpolation, ClipKeyframe, ClipScalarTrack, ClipSegment,
-    ClipVec2Track, ClipVec4Track, SpriteTimeline,
+    AnimationClip, AssetManager, ClipScalarTrack, ClipVec2Track, ClipVec4Track, SpriteTimeline,
 };
 use crate::audio::{AudioHealthSnapshot, AudioListenerState, AudioPlugin, AudioSpatialConfig};
 use crate::camera::Camera2D;
 use crate::camera3d::Camera3D;
-use crate::config::{AppConfig, AppConfigOverrides, SpriteGuardrailMode};
+use crate::config::{AppConfig, AppConfigOverrides, SpriteGuardrailMode, SpriteSortMode};
 use crate::ecs::{
     AnimationTime, ClipInstance, EcsWorld, EntityInfo, InstanceData, MeshLightingInfo, ParticleCaps,
-    SpriteAnimation, SpriteAnimationInfo, SpriteInstance,
+    ParticleEmitter, SpriteAnimation, SpriteAnimationInfo, SpriteAnimationLoopMode, SpriteInstance,
+    SpriteTimelineUsageEntry, Transform,
 };
-use crate::environment::EnvironmentRegistry;
+use crate::environment::{EnvironmentRegistry, EnvironmentScroll};
 use crate::events::{AudioEmitter, GameEvent};
 use crate::gizmo::{GizmoInteraction, GizmoMode};
 use crate::input::{Input, InputEvent};
+use crate::locale::{self, Locale};
 use crate::material_registry::{MaterialGpu, MaterialRegistry};
-use crate::mesh_preview::{MeshControlMode, MeshPreviewPlugin};
+use crate::mesh_preview::{MeshControlMode, MeshPreviewPlugin, MESH_CAMERA_FAR, MESH_CAMERA_NEAR};
 use crate::mesh_registry::MeshRegistry;
 use crate::plugins::{
-    ManifestBuiltinToggle, ManifestDynamicToggle, PluginAssetReadbackEvent, PluginCapabilityEvent,
-    PluginContext, PluginManager, PluginWatchdogEvent,
+    ManifestBuiltinToggle, ManifestConfigUpdate, ManifestDynamicToggle, PluginAssetReadbackEvent,
+    PluginCapabilityEvent, PluginContext, PluginManager, PluginWatchdogEvent,
 };
 use crate::prefab::{PrefabFormat, PrefabLibrary};
 use crate::project::Project;
 use crate::renderer::{
-    MeshDraw, RenderViewport, Renderer, ScenePointLight, SpriteBatch, MAX_SHADOW_CASCADES,
+    scaled_render_size, MeshDraw, RenderViewport, Renderer, ScenePointLight, SpriteBatch, SpriteBatchKey,
+    SpriteBatchTextures, SpriteBatcher, MAX_SHADOW_CASCADES,
 };
 use crate::runtime_host::{PlayState, RuntimeHost};
 use crate::scene::{
     EnvironmentDependency, Scene, SceneCamera2D, SceneCameraBookmark, SceneDependencies, SceneEntityId,
-    SceneEnvironment, SceneLightingData, SceneMetadata, ScenePointLightData, SceneShadowData,
-    SceneViewportMode, Vec2Data,
+    SceneEnvironment, SceneLightingData, SceneMetadata, ScenePointLightData, SceneReferenceWarning,
+    SceneShadowData, SceneViewportMode, Vec2Data,
 };
+use crate::scene_streaming::ChunkStreamer;
 use crate::scripts::{ScriptCommand, ScriptHandle, ScriptPlugin};
 use crate::time::Time;
+use crate::tr;
 use bevy_ecs::prelude::Entity;
 use glam::{Mat4, Vec2, Vec3, Vec4};
 
@@ -105,6 +134,12 @@ use egui_wgpu::{Renderer as EguiRenderer, RendererOptions, ScreenDescriptor};
 use egui_winit::State as EguiWinit;
 
 const CAMERA_BASE_HALF_HEIGHT: f32 = 1.2;
+/// Collision impulses below this do not shake the camera.
+const CAMERA_SHAKE_FORCE_THRESHOLD: f32 = 200.0;
+/// Trauma added per unit of impulse above the threshold.
+const CAMERA_SHAKE_TRAUMA_PER_FORCE: f32 = 0.0005;
+/// Audible range given to positioned sounds that do not carry their own emitter.
+const DEFAULT_EMITTER_MAX_DISTANCE: f32 = 25.0;
 const MAX_PENDING_ANIMATION_RELOADS_PER_KIND: usize = 32;
 const ANIMATION_RELOAD_WORKER_QUEUE_DEPTH: usize = 8;
 const SCRIPT_CONSOLE_CAPACITY: usize = 200;
@@ -172,6 +207,7 @@ impl Viewport {
 pub(crate) struct ScriptConsoleEntry {
     pub kind: ScriptConsoleKind,
     pub text: String,
+    pub repeat: u32,
 }
 
 #[derive(Clone)]
@@ -196,6 +232,19 @@ pub struct FrameTimingSample {
     pub fixed_ms: f32,
     pub render_ms: f32,
     pub ui_ms: f32,
+    pub sprite_bytes_uploaded: u64,
+    pub sprite_bytes_reused: u64,
+    pub meshes_drawn: u32,
+    pub meshes_culled: u32,
+}
+
+/// Appends unresolved scene references to a load status line so they are not silently dropped.
+fn scene_load_status(message: String, warnings: &[SceneReferenceWarning]) -> String {
+    if warnings.is_empty() {
+        return message;
+    }
+    let details: Vec<String> = warnings.iter().map(ToString::to_string).collect();
+    format!("{message} ({} unresolved reference(s): {})", warnings.len(), details.join("; "))
 }
 
 pub async fn run() -> Result<()> {
@@ -229,9 +278,30 @@ async fn run_single(project: Project, overrides: AppConfigOverrides) -> Result<O
         }
     }
     config.apply_overrides(&overrides);
-    let event_loop = EventLoop::new().context("Failed to create winit event loop")?;
+    let event_loop = match EventLoop::new() {
+        Ok(event_loop) => event_loop,
+        Err(err) if overrides.headless_frames.is_some() => {
+            println!("[headless] Skipped: no display available ({err})");
+            return Ok(None);
+        }
+        Err(err) => return Err(err).context("Failed to create winit event loop"),
+    };
     let mut app = App::new(config, project).await;
+    // An explicit CLI size or `--reset-window` wins over the geometry saved last session.
+    if !overrides.reset_window && overrides.width.is_none() && overrides.height.is_none() {
+        app.restore_window_geometry();
+    }
+    if let Some(frames) = overrides.capture_frames {
+        let dir = overrides.capture_dir.clone().unwrap_or_else(|| PathBuf::from("captures"));
+        app.screenshot_sequence(frames, dir)?;
+    }
+    if let Some(frames) = overrides.headless_frames {
+        app.headless_run(frames)?;
+    }
     event_loop.run_app(&mut app).context("Event loop execution failed")?;
+    if let Some(outcome) = app.take_headless_outcome() {
+        outcome?;
+    }
     Ok(app.next_project.take())
 }
 
@@ -247,10 +317,14 @@ pub struct App {
     scene_environment_ref: Option<String>,
     active_environment_key: String,
     environment_intensity: f32,
+    environment_scroll: EnvironmentScroll,
     play_state: PlayState,
     play_snapshot: Option<PlaySessionSnapshot>,
     step_pending: bool,
     should_close: bool,
+    screenshot_sequence: Option<ScreenshotSequence>,
+    headless_run: Option<HeadlessRun>,
+    thumbnails: ThumbnailCache,
 
     // egui
     editor_shell: EditorShell,
@@ -273,8 +347,16 @@ pub struct App {
     scene_atlas_refs: HashSet<String>,
     persistent_atlases: HashSet<String>,
     scene_clip_refs: HashMap<String, usize>,
+    scene_color_curve_refs: HashMap<String, usize>,
+    /// Curves retained from `assets/animations/curves` at startup, so the inspector and scripts
+    /// can use them before any scene depends on them.
+    persistent_color_curves: HashSet<String>,
     scene_mesh_refs: HashSet<String>,
+    scene_chunks: ChunkStreamer,
+    scene_chunk_dependencies: HashMap<String, SceneDependencies>,
     pub(crate) scene_material_refs: HashSet<String>,
+    /// Materials scripts created with `create_material` and have not released yet.
+    script_materials: HashSet<String>,
 
     pub(crate) material_registry: MaterialRegistry,
     pub(crate) mesh_registry: MeshRegistry,
@@ -289,7 +371,11 @@ pub struct App {
     emitter_entity: Option<Entity>,
 
     sprite_atlas_views: HashMap<String, Arc<wgpu::TextureView>>,
+    /// Normal map views by image path; `None` records a load failure so it is reported once.
+    sprite_normal_views: HashMap<String, Option<Arc<wgpu::TextureView>>>,
     atlas_hot_reload: Option<AtlasHotReload>,
+    /// Atlas keys queued by plugins, reloaded alongside watcher events.
+    atlas_reload_requests: Vec<String>,
     mesh_hot_reload: Option<MeshHotReload>,
     mesh_reload_worker: Option<MeshReloadWorker>,
     mesh_reload_inflight: HashSet<String>,
@@ -302,9 +388,15 @@ pub struct App {
     animation_reload: AnimationReloadController,
     sprite_guardrail_mode: SpriteGuardrailMode,
     sprite_guardrail_max_pixels: f32,
-    sprite_batch_map: HashMap<Arc<str>, Vec<InstanceData>>,
-    sprite_batch_pool: Vec<Vec<InstanceData>>,
-    sprite_batch_order: Vec<Arc<str>>,
+    sprite_guardrail_culled: usize,
+    /// Union of every sprite's world bounds from the last frame's instance collection.
+    sprite_world_bounds: Option<(Vec2, Vec2)>,
+    static_sprite_batching: bool,
+    static_sprite_bake_dirty: bool,
+    /// Consecutive failed `recover_graphics_device` attempts; zero while the device is healthy.
+    device_recovery_attempts: u32,
+    component_clipboard: Option<ComponentClipboard>,
+    sprite_batcher: SpriteBatcher,
     start_screen_open: bool,
     start_screen_status: Option<String>,
     start_screen_new_name: String,
@@ -821,9 +913,9 @@ impl App {
         if let Some(timeline_data) = self.assets.atlas_timeline(atlas, timeline) {
             if let Some(frame) = timeline_data.frames.get(frame_index) {
                 if frame.events.is_empty() {
-                    self.set_inspector_status(Some(format!(
-                        "Preview events: none (frame {})",
-                        frame_index + 1
+                    self.set_inspector_status(Some(tr!(
+                        "inspector.preview_events_none_frame",
+                        frame = frame_index + 1
                     )));
                 } else {
                     let joined = frame.events.join(", ");
@@ -834,13 +926,13 @@ impl App {
                         frame_index + 1,
                         joined
                     );
-                    self.set_inspector_status(Some(format!("Preview events: {}", joined)));
+                    self.set_inspector_status(Some(tr!("inspector.preview_events_list", events = joined)));
                 }
             } else {
-                self.set_inspector_status(Some("Preview events unavailable for frame.".to_string()));
+                self.set_inspector_status(Some(tr!("inspector.preview_events_unavailable")));
             }
         } else {
-            self.set_inspector_status(Some("Timeline unavailable for preview.".to_string()));
+            self.set_inspector_status(Some(tr!("inspector.preview_timeline_unavailable")));
         }
     }
 
@@ -1017,85 +1109,130 @@ impl App {
         match result {
             Ok(newly_loaded) => {
                 if newly_loaded.is_empty() {
-                    self.set_ui_scene_status("Plugin manifest reloaded".to_string());
+                    self.set_ui_scene_status(tr!("plugin.manifest_reloaded"));
                 } else {
-                    self.set_ui_scene_status(format!("Loaded plugins: {}", newly_loaded.join(", ")));
+                    self.set_ui_scene_status(tr!("plugin.loaded", names = newly_loaded.join(", ")));
                 }
             }
             Err(err) => {
-                self.set_ui_scene_status(format!("Plugin reload failed: {err}"));
+                self.set_ui_scene_status(tr!("plugin.reload_failed", error = err));
             }
         }
     }
 
+    fn unload_dynamic_plugins(&mut self) {
+        let result = self.with_plugin_runtime(|host, manager, ctx| host.unload_all_dynamic(manager, ctx));
+        match result {
+            Ok(()) => self.set_ui_scene_status(tr!("plugin.unloaded")),
+            Err(err) => self.set_ui_scene_status(tr!("plugin.unloaded_with_error", error = err)),
+        }
+    }
+
+    /// Hands changed manifest `config` objects to the dynamic plugins already running and returns
+    /// the names of the plugins that were notified.
+    fn deliver_plugin_config_changes(&mut self) -> Vec<String> {
+        self.with_plugin_runtime(|host, manager, ctx| match host.manifest() {
+            Some(manifest) => manager.apply_manifest_config(manifest, ctx),
+            None => Vec::new(),
+        })
+    }
+
+    /// Picks up hand edits to the plugin manifest; config changes reach running plugins without a reload.
+    fn poll_plugin_manifest(&mut self) {
+        if !self.plugin_host_mut().poll_manifest_changes() {
+            return;
+        }
+        let notified = self.deliver_plugin_config_changes();
+        if !notified.is_empty() {
+            self.set_ui_scene_status(tr!("plugin.config_applied", names = notified.join(", ")));
+        }
+    }
+
     fn apply_plugin_toggles(&mut self, toggles: &[editor_ui::PluginToggleRequest]) {
         if toggles.is_empty() {
             return;
         }
         let mut dynamic_requests = Vec::new();
         let mut builtin_requests = Vec::new();
+        let mut config_requests = Vec::new();
         for toggle in toggles {
             match &toggle.kind {
                 editor_ui::PluginToggleKind::Dynamic { new_enabled } => dynamic_requests
                     .push(ManifestDynamicToggle { name: toggle.name.clone(), new_enabled: *new_enabled }),
                 editor_ui::PluginToggleKind::Builtin { disable } => builtin_requests
                     .push(ManifestBuiltinToggle { name: toggle.name.clone(), disable: *disable }),
+                editor_ui::PluginToggleKind::Config { config } => config_requests
+                    .push(ManifestConfigUpdate { name: toggle.name.clone(), config: config.clone() }),
             }
         }
-        let summary =
-            match self.plugin_host_mut().apply_manifest_toggles(&dynamic_requests, &builtin_requests) {
-                Ok(summary) => summary,
-                Err(err) => {
-                    self.set_ui_scene_status(format!("Plugin manifest update failed: {err}"));
-                    if let Err(load_err) = self.plugin_host_mut().reload_manifest_from_disk() {
-                        eprintln!("[plugin] failed to reload manifest after error: {load_err:?}");
-                    }
-                    return;
+        let summary = match self.plugin_host_mut().apply_manifest_toggles(
+            &dynamic_requests,
+            &builtin_requests,
+            &config_requests,
+        ) {
+            Ok(summary) => summary,
+            Err(err) => {
+                self.set_ui_scene_status(tr!("plugin.manifest_update_failed", error = err));
+                if let Err(load_err) = self.plugin_host_mut().reload_manifest_from_disk() {
+                    eprintln!("[plugin] failed to reload manifest after error: {load_err:?}");
                 }
-            };
+                return;
+            }
+        };
         if !summary.changed() {
             if !summary.dynamic.missing.is_empty() {
-                self.set_ui_scene_status(format!(
-                    "Plugin toggle skipped; missing manifest entr{} {}",
-                    if summary.dynamic.missing.len() == 1 { "y:" } else { "ies:" },
-                    summary.dynamic.missing.join(", ")
-                ));
+                let key = if summary.dynamic.missing.len() == 1 {
+                    "plugin.toggle_skipped_missing_one"
+                } else {
+                    "plugin.toggle_skipped_missing_many"
+                };
+                self.set_ui_scene_status(tr!(key, names = summary.dynamic.missing.join(", ")));
                 if let Err(err) = self.plugin_host_mut().reload_manifest_from_disk() {
                     eprintln!("[plugin] failed to reload manifest after missing entries: {err:?}");
                 }
             } else {
-                self.set_ui_scene_status("Plugin manifest unchanged.".to_string());
+                self.set_ui_scene_status(tr!("plugin.manifest_unchanged"));
+            }
+            return;
+        }
+        if !summary.requires_reload() {
+            let notified = self.deliver_plugin_config_changes();
+            if notified.is_empty() {
+                self.set_ui_scene_status(tr!("plugin.config_saved"));
+            } else {
+                self.set_ui_scene_status(tr!("plugin.config_applied", names = notified.join(", ")));
             }
             return;
         }
         self.reload_dynamic_plugins();
         let mut parts = Vec::new();
         if !summary.dynamic.enabled.is_empty() {
-            parts.push(format!("enabled {}", summary.dynamic.enabled.join(", ")));
+            parts.push(tr!("plugin.part_enabled", names = summary.dynamic.enabled.join(", ")));
         }
         if !summary.dynamic.disabled.is_empty() {
-            parts.push(format!("disabled {}", summary.dynamic.disabled.join(", ")));
+            parts.push(tr!("plugin.part_disabled", names = summary.dynamic.disabled.join(", ")));
         }
         if !summary.builtin.enabled.is_empty() {
-            parts.push(format!("enabled built-ins {}", summary.builtin.enabled.join(", ")));
+            parts.push(tr!("plugin.part_enabled_builtins", names = summary.builtin.enabled.join(", ")));
         }
         if !summary.builtin.disabled.is_empty() {
-            parts.push(format!("disabled built-ins {}", summary.builtin.disabled.join(", ")));
+            parts.push(tr!("plugin.part_disabled_builtins", names = summary.builtin.disabled.join(", ")));
         }
         if !summary.dynamic.missing.is_empty() {
-            parts.push(format!(
-                "skipped unknown entr{} {}",
-                if summary.dynamic.missing.len() == 1 { "y" } else { "ies" },
-                summary.dynamic.missing.join(", ")
-            ));
+            let key = if summary.dynamic.missing.len() == 1 {
+                "plugin.part_skipped_one"
+            } else {
+                "plugin.part_skipped_many"
+            };
+            parts.push(tr!(key, names = summary.dynamic.missing.join(", ")));
         }
         if summary.builtin.changed {
-            parts.push("restart required for built-in changes".to_string());
+            parts.push(tr!("plugin.part_restart_required"));
         }
         if parts.is_empty() {
-            self.set_ui_scene_status("Plugin manifest updated.".to_string());
+            self.set_ui_scene_status(tr!("plugin.manifest_updated"));
         } else {
-            self.set_ui_scene_status(format!("Plugin manifest {}", parts.join("; ")));
+            self.set_ui_scene_status(tr!("plugin.manifest_changes", changes = parts.join("; ")));
         }
     }
     pub async fn new(config: AppConfig, project: Project) -> Self {
@@ -1151,16 +1288,30 @@ impl App {
         }
         renderer.mark_shadow_settings_dirty();
         renderer.set_gpu_timing_enabled(config.editor.gpu_timing);
+        alloc_sampler::set_enabled(config.editor.alloc_sampling);
+        match Locale::load(locale::LOCALE_DIR, &config.editor.language) {
+            Ok(loaded) => locale::set_active(loaded),
+            Err(err) => eprintln!("[locale] {err:#}; falling back to English"),
+        }
+        renderer.set_sprite_color_space(config.sprites.color_space);
+        renderer.set_sprite_sort_mode(config.render.sprite_sort_mode);
         let lighting_state = renderer.lighting().clone();
         let editor_lighting_state = lighting_state.clone();
         let particle_config = config.particles.clone();
         let editor_cfg = config.editor.clone();
         let mut ecs = EcsWorld::new();
-        ecs.set_particle_caps(ParticleCaps::new(
-            particle_config.max_spawn_per_frame,
-            particle_config.max_total,
-            particle_config.max_emitter_backlog,
-        ));
+        ecs.set_particle_caps(
+            ParticleCaps::new(
+                particle_config.max_spawn_per_frame,
+                particle_config.max_total,
+                particle_config.max_emitter_backlog,
+            )
+            .with_max_collision_particles(particle_config.max_collision_particles),
+        );
+        ecs.set_max_entities(config.max_entities);
+        if config.simulation.deterministic {
+            ecs.set_deterministic(Some(config.simulation.seed));
+        }
         let initial_events = ecs.drain_events();
         let emitter_snapshot = ecs.first_emitter().and_then(|entity| ecs.emitter_snapshot(entity));
         let (
@@ -1232,6 +1383,14 @@ impl App {
             }
             Err(err) => eprintln!("[environment] failed to scan assets/environments: {err:?}"),
         }
+        let persistent_color_curves: HashSet<String> =
+            match assets.retain_color_curve_directory(project.join_assets("animations/curves")) {
+                Ok(keys) => keys.into_iter().collect(),
+                Err(err) => {
+                    eprintln!("[animation] failed to scan assets/animations/curves: {err:?}");
+                    HashSet::new()
+                }
+            };
         let environment_intensity = default_environment_intensity;
         let mut material_registry = MaterialRegistry::new();
         let mut mesh_registry = MeshRegistry::new_with_hash(
@@ -1248,6 +1407,7 @@ impl App {
             environment_intensity,
             editor_config: editor_cfg.clone(),
             default_scene_path: project.startup_scene_path().to_path_buf(),
+            editor_state_path: PathBuf::from(editor_state_file::EDITOR_STATE_PATH),
         });
         let editor_shell = EditorShell::new(ui_state);
 
@@ -1359,10 +1519,14 @@ impl App {
             scene_environment_ref: None,
             active_environment_key: default_environment_key.clone(),
             environment_intensity,
+            environment_scroll: EnvironmentScroll::default(),
             play_state: PlayState::Editing,
             play_snapshot: None,
             step_pending: false,
             should_close: false,
+            screenshot_sequence: None,
+            headless_run: None,
+            thumbnails: ThumbnailCache::new(project.root()),
             editor_shell,
             plugin_runtime,
             camera,
@@ -1372,8 +1536,13 @@ impl App {
             scene_atlas_refs: HashSet::new(),
             persistent_atlases: HashSet::new(),
             scene_clip_refs,
+            scene_color_curve_refs: HashMap::new(),
+            persistent_color_curves,
             scene_mesh_refs: HashSet::new(),
+            scene_chunks: ChunkStreamer::default(),
+            scene_chunk_dependencies: HashMap::new(),
             scene_material_refs,
+            script_materials: HashSet::new(),
             material_registry,
             mesh_registry,
             viewport: Viewport::new(
@@ -1386,7 +1555,9 @@ impl App {
             startup_scene_loaded: false,
             emitter_entity,
             sprite_atlas_views: HashMap::new(),
+            sprite_normal_views: HashMap::new(),
             atlas_hot_reload,
+            atlas_reload_requests: Vec::new(),
             mesh_hot_reload,
             mesh_reload_worker,
             mesh_reload_inflight: HashSet::new(),
@@ -1399,13 +1570,17 @@ impl App {
             animation_reload,
             sprite_guardrail_mode: editor_cfg.sprite_guardrail_mode,
             sprite_guardrail_max_pixels: editor_cfg.sprite_guard_max_pixels,
+            sprite_guardrail_culled: 0,
+            sprite_world_bounds: None,
+            static_sprite_batching: editor_cfg.static_sprite_batching,
+            static_sprite_bake_dirty: true,
+            device_recovery_attempts: 0,
+            component_clipboard: None,
             #[cfg(feature = "alloc_profiler")]
             last_alloc_snapshot: alloc_profiler::allocation_snapshot(),
             #[cfg(feature = "alloc_profiler")]
             frame_budget_capture,
-            sprite_batch_map: HashMap::new(),
-            sprite_batch_pool: Vec::new(),
-            sprite_batch_order: Vec::new(),
+            sprite_batcher: SpriteBatcher::default(),
             start_screen_open,
             start_screen_status: None,
             start_screen_new_name,
@@ -1439,6 +1614,22 @@ impl App {
         if events.is_empty() {
             return;
         }
+        let mut despawned = HashSet::new();
+        for event in &events {
+            match event {
+                GameEvent::CollisionForce { force, .. } => {
+                    let excess = force - CAMERA_SHAKE_FORCE_THRESHOLD;
+                    if excess > 0.0 {
+                        self.camera.add_trauma(excess * CAMERA_SHAKE_TRAUMA_PER_FORCE);
+                    }
+                }
+                GameEvent::EntityDespawned { entity } => {
+                    despawned.insert(*entity);
+                }
+                _ => {}
+            }
+        }
+        self.forget_despawned_entities(&despawned);
         self.with_plugins(|plugins, ctx| plugins.handle_events(ctx, &events));
     }
 
@@ -1447,14 +1638,14 @@ impl App {
             editor_ui::ProjectAction::OpenExisting { path } => {
                 let trimmed = path.trim();
                 if trimmed.is_empty() {
-                    self.start_screen_status = Some("Enter a manifest path to open.".to_string());
+                    self.start_screen_status = Some(tr!("project.manifest_path_required"));
                     return;
                 }
                 let target = PathBuf::from(trimmed);
                 let current = self.project.manifest_path_or_default();
                 let is_same = target.canonicalize().ok() == current.canonicalize().ok();
                 if is_same {
-                    self.start_screen_status = Some("That project is already open.".to_string());
+                    self.start_screen_status = Some(tr!("project.already_open"));
                     self.start_screen_open = false;
                     return;
                 }
@@ -1467,14 +1658,14 @@ impl App {
                         self.start_screen_status = None;
                     }
                     Err(err) => {
-                        self.start_screen_status = Some(format!("Failed to open project: {err}"));
+                        self.start_screen_status = Some(tr!("project.open_failed", error = err));
                     }
                 }
             }
             editor_ui::ProjectAction::CreateNew { name, path } => {
                 let trimmed_path = path.trim();
                 if trimmed_path.is_empty() {
-                    self.start_screen_status = Some("Enter a project folder to create.".to_string());
+                    self.start_screen_status = Some(tr!("project.folder_required"));
                     return;
                 }
                 let project = match Project::create_new(trimmed_path, {
@@ -1487,7 +1678,7 @@ impl App {
                 }) {
                     Ok(project) => project,
                     Err(err) => {
-                        self.start_screen_status = Some(format!("Failed to create project: {err}"));
+                        self.start_screen_status = Some(tr!("project.create_failed", error = err));
                         return;
                     }
                 };
@@ -1497,6 +1688,22 @@ impl App {
                 self.should_close = true;
                 self.start_screen_status = None;
             }
+            editor_ui::ProjectAction::Browse => match Project::open_dialog() {
+                Some(path) => {
+                    let path = path.to_string_lossy().into_owned();
+                    self.start_screen_open_path = path.clone();
+                    self.handle_project_action(editor_ui::ProjectAction::OpenExisting { path });
+                    if self.start_screen_status.is_some() {
+                        self.start_screen_open = true;
+                    }
+                }
+                None if !Project::native_dialog_available() => {
+                    self.start_screen_open = true;
+                    self.start_screen_status =
+                        Some("No native file dialog available; enter the manifest path below.".to_string());
+                }
+                None => {}
+            },
         }
     }
 
@@ -1523,13 +1730,12 @@ impl App {
     }
 
     fn enrich_event_audio(&self, event: GameEvent) -> GameEvent {
-        const DEFAULT_MAX_DISTANCE: f32 = 25.0;
         match event {
             GameEvent::SpriteSpawned { entity, atlas, region, audio } => {
                 let audio = audio.or_else(|| {
                     self.ecs
                         .entity_world_position3d(entity)
-                        .map(|position| AudioEmitter { position, max_distance: DEFAULT_MAX_DISTANCE })
+                        .map(|position| AudioEmitter { position, max_distance: DEFAULT_EMITTER_MAX_DISTANCE })
                 });
                 GameEvent::SpriteSpawned { entity, atlas, region, audio }
             }
@@ -1538,7 +1744,7 @@ impl App {
                     match (self.ecs.entity_world_position3d(a), self.ecs.entity_world_position3d(b)) {
                         (Some(pa), Some(pb)) => {
                             let mid = (pa + pb) * 0.5;
-                            Some(AudioEmitter { position: mid, max_distance: DEFAULT_MAX_DISTANCE })
+                            Some(AudioEmitter { position: mid, max_distance: DEFAULT_EMITTER_MAX_DISTANCE })
                         }
                         _ => None,
                     }
@@ -1550,7 +1756,7 @@ impl App {
                     match (self.ecs.entity_world_position3d(a), self.ecs.entity_world_position3d(b)) {
                         (Some(pa), Some(pb)) => {
                             let mid = (pa + pb) * 0.5;
-                            Some(AudioEmitter { position: mid, max_distance: DEFAULT_MAX_DISTANCE })
+                            Some(AudioEmitter { position: mid, max_distance: DEFAULT_EMITTER_MAX_DISTANCE })
                         }
                         _ => None,
                     }
@@ -1562,7 +1768,7 @@ impl App {
                     match (self.ecs.entity_world_position3d(a), self.ecs.entity_world_position3d(b)) {
                         (Some(pa), Some(pb)) => {
                             let mid = (pa + pb) * 0.5;
-                            Some(AudioEmitter { position: mid, max_distance: DEFAULT_MAX_DISTANCE })
+                            Some(AudioEmitter { position: mid, max_distance: DEFAULT_EMITTER_MAX_DISTANCE })
                         }
                         _ => None,
                     }
@@ -1578,6 +1784,7 @@ impl App {
         sprite_instances: Vec<SpriteInstance>,
         viewport_size: PhysicalSize<u32>,
     ) -> Vec<SpriteInstance> {
+        self.sprite_guardrail_culled = 0;
         if sprite_instances.is_empty()
             || self.viewport_camera_mode != ViewportCameraMode::Ortho2D
             || viewport_size.width == 0
@@ -1647,25 +1854,12 @@ impl App {
             self.set_sprite_guardrail_status(None);
         }
 
+        self.sprite_guardrail_culled = culled;
         filtered
     }
 
-    fn take_sprite_batch_buffer(&mut self) -> Vec<InstanceData> {
-        self.sprite_batch_pool.pop().unwrap_or_default()
-    }
-
-    fn recycle_sprite_batch_buffers(&mut self) {
-        if self.sprite_batch_map.is_empty() && self.sprite_batch_order.is_empty() {
-            return;
-        }
-        for (_, mut instances) in self.sprite_batch_map.drain() {
-            instances.clear();
-            self.sprite_batch_pool.push(instances);
-        }
-        self.sprite_batch_order.clear();
-    }
     fn apply_editor_camera_settings(&mut self) {
-        let (zoom_min, zoom_max, guard_pixels, guard_mode) = {
+        let (zoom_min, zoom_max, guard_pixels, guard_mode, static_batching, low_latency_input) = {
             let mut state = self.editor_ui_state_mut();
             state.ui_camera_zoom_min = state.ui_camera_zoom_min.clamp(0.05, 20.0);
             state.ui_camera_zoom_max =
@@ -1676,6 +1870,8 @@ impl App {
                 state.ui_camera_zoom_max,
                 state.ui_sprite_guard_pixels,
                 state.ui_sprite_guard_mode,
+                state.ui_static_sprite_batching,
+                state.ui_low_latency_input,
             )
         };
         self.camera.set_zoom_limits(zoom_min, zoom_max);
@@ -1685,6 +1881,12 @@ impl App {
         self.config.editor.camera_zoom_max = zoom_max;
         self.config.editor.sprite_guard_max_pixels = guard_pixels;
         self.config.editor.sprite_guardrail_mode = guard_mode;
+        if self.static_sprite_batching != static_batching {
+            self.static_sprite_batching = static_batching;
+            self.static_sprite_bake_dirty = true;
+        }
+        self.config.editor.static_sprite_batching = static_batching;
+        self.config.editor.low_latency_input = low_latency_input;
     }
 
     fn apply_editor_lighting_settings(&mut self) {
@@ -1700,6 +1902,7 @@ impl App {
             ui_shadow_resolution,
             ui_shadow_split_lambda,
             ui_shadow_pcf_radius,
+            ui_auto_exposure,
         ) = {
             let state = self.editor_ui_state();
             (
@@ -1714,6 +1917,7 @@ impl App {
                 state.ui_shadow_resolution,
                 state.ui_shadow_split_lambda,
                 state.ui_shadow_pcf_radius,
+                state.ui_auto_exposure,
             )
         };
         let default_dir = glam::Vec3::new(0.4, 0.8, 0.35).normalize();
@@ -1738,6 +1942,7 @@ impl App {
         lighting.shadow_resolution = ui_shadow_resolution.clamp(256, 8192);
         lighting.shadow_split_lambda = ui_shadow_split_lambda.clamp(0.0, 1.0);
         lighting.shadow_pcf_radius = ui_shadow_pcf_radius.clamp(0.0, 10.0);
+        lighting.auto_exposure = ui_auto_exposure;
         self.renderer.mark_shadow_settings_dirty();
     }
 
@@ -1770,6 +1975,24 @@ impl App {
         Ok(path.to_path_buf())
     }
 
+    fn export_analytics_session_json<P: AsRef<std::path::Path>>(&self, path: P) -> Result<PathBuf> {
+        let path = path.as_ref();
+        let analytics = self
+            .analytics_plugin()
+            .ok_or_else(|| anyhow!("Analytics plugin not loaded; nothing to export."))?;
+        if let Some(parent) = path.parent() {
+            if !parent.as_os_str().is_empty() {
+                fs::create_dir_all(parent)
+                    .with_context(|| format!("Creating analytics export directory {}", parent.display()))?;
+            }
+        }
+        let json = serde_json::to_string_pretty(&analytics.session_export())
+            .context("Serializing analytics session export")?;
+        fs::write(path, json.as_bytes())
+            .with_context(|| format!("Writing analytics session export {}", path.display()))?;
+        Ok(path.to_path_buf())
+    }
+
     fn report_audio_startup_status(&mut self) {
         let Some(snapshot) = self.audio_plugin().map(|audio| audio.health_snapshot()) else {
             return;
@@ -1797,6 +2020,31 @@ impl App {
         self.record_events();
     }
 
+    /// Applies the normal map references sprites gained or dropped since the last frame (spawns,
+    /// despawns, scene loads and clears), and forgets cached views of images that were unloaded.
+    fn sync_normal_map_refs(&mut self) {
+        self.ecs.sync_normal_map_refs(&mut self.assets);
+        let assets = &self.assets;
+        self.sprite_normal_views.retain(|path, view| view.is_none() || assets.has_normal_map(path));
+    }
+
+    /// Texture view for a sprite normal map retained by `sync_normal_map_refs`. Failures are
+    /// reported once and the sprite is drawn unlit.
+    fn normal_map_view(&mut self, path: &str) -> Option<Arc<wgpu::TextureView>> {
+        if let Some(view) = self.sprite_normal_views.get(path) {
+            return view.clone();
+        }
+        let view = match self.assets.normal_map_texture_view(path) {
+            Ok(view) => Some(Arc::new(view)),
+            Err(err) => {
+                eprintln!("Normal map '{path}' unavailable, drawing unlit: {err:?}");
+                None
+            }
+        };
+        self.sprite_normal_views.insert(path.to_string(), view.clone());
+        view
+    }
+
     fn atlas_view(&mut self, key: &str) -> Result<Arc<wgpu::TextureView>> {
         if let Some(view) = self.sprite_atlas_views.get(key) {
             return Ok(view.clone());
@@ -1807,16 +2055,86 @@ impl App {
         Ok(arc)
     }
 
+    /// Moves static sprites into the renderer's baked buffer when static batching is enabled,
+    /// re-baking only when the static set changed. Returns the dynamic sprites and whether a new
+    /// bake was issued this frame. `skip_bake` keeps every sprite dynamic, e.g. while guardrail
+    /// culling (camera dependent) or a sort mode (order dependent) is active.
+    fn split_static_sprites(
+        &mut self,
+        sprite_instances: Vec<SpriteInstance>,
+        skip_bake: bool,
+    ) -> (Vec<SpriteInstance>, bool) {
+        if !self.static_sprite_batching || skip_bake {
+            if self.renderer.has_static_sprites() {
+                self.renderer.clear_static_sprites();
+                self.static_sprite_bake_dirty = true;
+                return (sprite_instances, true);
+            }
+            return (sprite_instances, false);
+        }
+        let rebake = self.static_sprite_bake_dirty || self.ecs.static_sprites_dirty();
+        let (static_instances, dynamic): (Vec<_>, Vec<_>) =
+            sprite_instances.into_iter().partition(|instance| instance.static_sprite);
+        if !rebake {
+            return (dynamic, false);
+        }
+        let mut order: Vec<SpriteBatchKey> = Vec::new();
+        let mut buckets: HashMap<SpriteBatchKey, Vec<InstanceData>> = HashMap::new();
+        for instance in static_instances {
+            let normal_map = instance.normal_map.clone();
+            let (atlas, data) = instance.into_gpu();
+            let key = (atlas, normal_map);
+            buckets
+                .entry(key.clone())
+                .or_insert_with(|| {
+                    order.push(key);
+                    Vec::new()
+                })
+                .push(data);
+        }
+        let mut baked: Vec<InstanceData> = Vec::new();
+        let mut batches: Vec<SpriteBatch> = Vec::new();
+        for key in order {
+            let Some(mut bucket) = buckets.remove(&key) else {
+                continue;
+            };
+            let (atlas, normal_map) = key;
+            match self.atlas_view(atlas.as_ref()) {
+                Ok(view) => {
+                    let normal_view = normal_map.and_then(|path| self.normal_map_view(&path));
+                    let start = baked.len() as u32;
+                    baked.append(&mut bucket);
+                    batches.push(SpriteBatch {
+                        atlas,
+                        range: start..baked.len() as u32,
+                        view,
+                        normal_view,
+                        dirty: true,
+                    });
+                }
+                Err(err) => {
+                    eprintln!("Atlas '{}' unavailable for static sprites: {err:?}", atlas.as_ref());
+                }
+            }
+        }
+        self.renderer.bake_static_sprites(baked, batches);
+        self.static_sprite_bake_dirty = false;
+        (dynamic, true)
+    }
+
     fn invalidate_atlas_view(&mut self, key: &str) {
         if self.sprite_atlas_views.remove(key).is_some() {
             self.renderer.invalidate_sprite_bind_group(key);
+            self.static_sprite_bake_dirty = true;
         }
     }
 
     fn clear_atlas_view_cache(&mut self) {
+        self.sprite_normal_views.clear();
         if !self.sprite_atlas_views.is_empty() {
             self.sprite_atlas_views.clear();
             self.renderer.clear_sprite_bind_cache();
+            self.static_sprite_bake_dirty = true;
         }
     }
 
@@ -1877,6 +2195,17 @@ impl App {
         self.plugin_manager_mut().get_mut::<AudioPlugin>()
     }
 
+    /// Applies settings plugins requested through `PluginContext::audio_mut` during the last frame.
+    fn apply_plugin_audio_requests(&mut self) {
+        let Some(count) = self.plugin_manager().audio_requests_handle().take_voice_count() else {
+            return;
+        };
+        match self.audio_plugin_mut() {
+            Some(audio) => audio.set_voice_count(count),
+            None => eprintln!("[audio] voice count request ignored: audio plugin unavailable"),
+        }
+    }
+
     fn analytics_plugin(&self) -> Option<&AnalyticsPlugin> {
         self.plugin_manager().get::<AnalyticsPlugin>()
     }
@@ -1915,6 +2244,23 @@ impl App {
         }
     }
 
+    /// Cleanup for despawns the world did on its own (`Lifetime`, `DespawnOutOfBounds`): drops
+    /// their script handles and takes them out of the selection.
+    fn forget_despawned_entities(&mut self, despawned: &HashSet<Entity>) {
+        if despawned.is_empty() {
+            return;
+        }
+        if let Some(plugin) = self.script_plugin_mut() {
+            plugin.forget_entities(despawned);
+        }
+        let selection = self.editor_ui_state().selected_entities.clone();
+        if selection.iter().any(|entity| despawned.contains(entity)) {
+            let remaining = selection.into_iter().filter(|entity| !despawned.contains(entity)).collect();
+            self.set_selected_entities(remaining);
+            self.set_gizmo_interaction(None);
+        }
+    }
+
     fn forget_script_handle(&mut self, handle: ScriptHandle) {
         if let Some(plugin) = self.script_plugin_mut() {
             plugin.forget_handle(handle);
@@ -1925,6 +2271,19 @@ impl App {
         self.script_plugin().and_then(|plugin| plugin.resolve_handle(handle))
     }
 
+    fn answer_bone_transform(&mut self, handle: ScriptHandle, entity: Entity, bone_name: &str) {
+        let Some(bones) = self.ecs.collect_skeleton_bone_world_transforms(entity) else {
+            eprintln!("[script] request_bone_transform: handle {handle} has no skeleton");
+            return;
+        };
+        let Some((_, world)) = bones.into_iter().find(|(name, _)| name == bone_name) else {
+            eprintln!("[script] request_bone_transform: no bone '{bone_name}' on handle {handle}");
+            return;
+        };
+        let (_, rotation, position) = world.to_scale_rotation_translation();
+        self.ecs.push_event(GameEvent::BoneTransformResult { handle, position, rotation });
+    }
+
     fn script_asset_paths(&self) -> Arc<[String]> {
         let scripts_root = self.project.join_assets("scripts");
         let mut paths = Vec::new();
@@ -1969,6 +2328,8 @@ impl App {
         let mut light_cluster_metrics_overlay = None;
         let mut keyframe_editor_usage: Option<KeyframeEditorUsageSnapshot> = None;
         let mut keyframe_event_log = Arc::from(Vec::<KeyframeEditorEvent>::new().into_boxed_slice());
+        let mut timeline_usage = Arc::from(Vec::<SpriteTimelineUsageEntry>::new().into_boxed_slice());
+        let mut custom_counters = Arc::from(Vec::<CustomCounterHistory>::new().into_boxed_slice());
 
         if let Some(analytics) = self.analytics_plugin_mut() {
             shadow_pass_metric = analytics.gpu_pass_metric("Shadow pass");
@@ -1982,6 +2343,8 @@ impl App {
             light_cluster_metrics_overlay = analytics.light_cluster_metrics();
             keyframe_editor_usage = Some(analytics.keyframe_editor_usage());
             keyframe_event_log = analytics.keyframe_editor_events_arc();
+            timeline_usage = analytics.timeline_usage_arc();
+            custom_counters = analytics.custom_counters_arc();
         }
 
         self.with_editor_ui_state_mut(|state| {
@@ -1996,6 +2359,8 @@ impl App {
             state.light_cluster_metrics_overlay = light_cluster_metrics_overlay;
             state.keyframe_editor_usage = keyframe_editor_usage;
             state.keyframe_event_log = keyframe_event_log;
+            state.timeline_usage = timeline_usage;
+            state.custom_counters = custom_counters;
         });
     }
 
@@ -2096,7 +2461,7 @@ impl App {
                 script_path: Some(plugin.script_path().display().to_string()),
                 enabled: plugin.enabled(),
                 paused: plugin.paused(),
-                last_error: plugin.last_error().map(|err| err.to_string()),
+                last_error: plugin.last_error_info().cloned(),
                 handles,
                 timings,
                 offenders,
@@ -2158,9 +2523,18 @@ impl App {
     }
 
     fn apply_environment_to_renderer(&mut self) -> Result<()> {
+        self.renderer.set_environment_offset(self.environment_scroll.offset());
         self.bind_environment(&self.active_environment_key.clone(), self.environment_intensity)
     }
 
+    fn advance_environment_animation(&mut self, dt: f32) {
+        if self.environment_scroll.speed == 0.0 && self.environment_scroll.offset() == 0.0 {
+            return;
+        }
+        let offset = self.environment_scroll.advance(dt);
+        self.renderer.set_environment_offset(offset);
+    }
+
     fn bind_environment(&mut self, key: &str, intensity: f32) -> Result<()> {
         if self.renderer.device().is_err() {
             return Ok(());
@@ -2196,15 +2570,28 @@ impl App {
         match self.renderer.set_vsync(enabled) {
             Ok(()) => {
                 self.config.window.vsync = enabled;
-                self.set_ui_scene_status(format!("VSync {}", if enabled { "enabled" } else { "disabled" }));
+                self.set_ui_scene_status(if enabled {
+                    tr!("scene.vsync_enabled")
+                } else {
+                    tr!("scene.vsync_disabled")
+                });
             }
             Err(err) => {
                 eprintln!("Failed to update VSync: {err:?}");
-                self.set_ui_scene_status(format!("Failed to update VSync: {err}"));
+                self.set_ui_scene_status(tr!("scene.vsync_failed", error = err));
             }
         }
     }
 
+    fn apply_texture_anisotropy(&mut self, level: u16) {
+        self.assets.set_anisotropy_limit(self.renderer.max_anisotropy());
+        let applied = self.assets.set_anisotropy(level);
+        self.config.textures.anisotropy = applied;
+        self.material_registry.set_anisotropy(applied);
+        // Sprite bind groups are keyed by the sampler's address, which survives recreation.
+        self.renderer.clear_sprite_bind_cache();
+    }
+
     fn apply_particle_caps(&mut self) {
         let (max_spawn_per_frame, max_total, max_emitter_backlog) = {
             let mut state = self.editor_ui_state_mut();
@@ -2217,7 +2604,8 @@ impl App {
                 state.ui_particle_max_emitter_backlog,
             )
         };
-        let caps = ParticleCaps::new(max_spawn_per_frame, max_total, max_emitter_backlog);
+        let caps = ParticleCaps::new(max_spawn_per_frame, max_total, max_emitter_backlog)
+            .with_max_collision_particles(self.config.particles.max_collision_particles);
         self.ecs.set_particle_caps(caps);
     }
 
@@ -2257,6 +2645,8 @@ impl App {
         }
         let atlas_dirty = cached_fingerprint.as_ref().is_none_or(|fp| fp.atlases != fingerprint.atlases);
         let clip_dirty = cached_fingerprint.as_ref().is_none_or(|fp| fp.clips != fingerprint.clips);
+        let curve_dirty =
+            cached_fingerprint.as_ref().is_none_or(|fp| fp.color_curves != fingerprint.color_curves);
         let mesh_dirty = cached_fingerprint.as_ref().is_none_or(|fp| fp.meshes != fingerprint.meshes);
         let material_dirty =
             cached_fingerprint.as_ref().is_none_or(|fp| fp.materials != fingerprint.materials);
@@ -2320,6 +2710,46 @@ impl App {
             });
         }
 
+        if curve_dirty {
+            let mut required_curves: HashMap<String, (usize, Option<PathBuf>)> = HashMap::new();
+            for dep in deps.color_curve_dependencies() {
+                let entry = required_curves
+                    .entry(dep.key().to_string())
+                    .or_insert((0, dep.path().map(PathBuf::from)));
+                entry.0 = entry.0.saturating_add(1);
+            }
+            let mut curve_watch_updates: Vec<PathBuf> = Vec::new();
+            for (key, (count, path)) in required_curves.iter() {
+                let entry = self.scene_color_curve_refs.entry(key.clone()).or_insert(0);
+                if *entry == 0 {
+                    // Preloaded curves keep their startup path even if the scene spells it differently.
+                    let retain_path = if self.persistent_color_curves.contains(key) {
+                        None
+                    } else {
+                        path.as_ref().and_then(|p| p.to_str())
+                    };
+                    self.assets
+                        .retain_color_curve(key, retain_path)
+                        .with_context(|| format!("Failed to retain color curve '{key}'"))?;
+                    if let Some(path) = path {
+                        curve_watch_updates.push(path.clone());
+                    }
+                }
+                *entry = *count;
+            }
+            for path in curve_watch_updates {
+                self.queue_animation_watch_root(&path, AnimationAssetKind::ColorCurve);
+            }
+            self.scene_color_curve_refs.retain(|key, _| {
+                if required_curves.contains_key(key) {
+                    true
+                } else {
+                    self.assets.release_color_curve(key);
+                    false
+                }
+            });
+        }
+
         if mesh_dirty {
             let previous_mesh = self.scene_mesh_refs.clone();
             let mut next_mesh = HashSet::new();
@@ -2345,6 +2775,13 @@ impl App {
                     .retain_mesh(key, None, &mut self.material_registry)
                     .with_context(|| format!("Failed to retain mesh '{key}'"))?;
             }
+            if self.renderer.device().is_ok() {
+                // Upload the scene's meshes together so a fresh load submits once instead of per mesh.
+                let keys: Vec<&str> = next_mesh.iter().map(String::as_str).collect();
+                self.mesh_registry
+                    .batch_ensure_gpu(&keys, &mut self.renderer)
+                    .context("Failed to upload scene meshes")?;
+            }
             self.scene_mesh_refs = next_mesh;
             self.with_editor_ui_state_mut(|state| state.scene_mesh_snapshot = None);
         }
@@ -2436,6 +2873,7 @@ impl App {
                 split_lambda: lighting.shadow_split_lambda,
                 pcf_radius: lighting.shadow_pcf_radius,
             },
+            auto_exposure: lighting.auto_exposure,
             point_lights: lighting
                 .point_lights
                 .iter()
@@ -2444,15 +2882,20 @@ impl App {
                     color: light.color.into(),
                     radius: light.radius,
                     intensity: light.intensity,
+                    falloff: light.falloff,
                 })
                 .collect(),
         });
-        metadata.environment =
-            Some(SceneEnvironment::new(self.active_environment_key.clone(), self.environment_intensity));
+        metadata.environment = Some(
+            SceneEnvironment::new(self.active_environment_key.clone(), self.environment_intensity)
+                .with_scroll_speed(self.environment_scroll.speed),
+        );
+        metadata.custom = self.plugin_manager().scene_custom_metadata_handle().snapshot();
         metadata
     }
 
     fn apply_scene_metadata(&mut self, metadata: &SceneMetadata) {
+        self.plugin_manager().scene_custom_metadata_handle().replace(metadata.custom.clone());
         self.set_viewport_camera_mode(ViewportCameraMode::from(metadata.viewport));
         if let Some(cam2d) = metadata.camera2d.as_ref() {
             self.camera.position = Vec2::from(cam2d.position.clone());
@@ -2502,6 +2945,7 @@ impl App {
                 lighting_mut.shadow_resolution = shadow.resolution.clamp(256, 8192);
                 lighting_mut.shadow_split_lambda = shadow.split_lambda.clamp(0.0, 1.0);
                 lighting_mut.shadow_pcf_radius = shadow.pcf_radius.clamp(0.0, 10.0);
+                lighting_mut.auto_exposure = lighting.auto_exposure;
                 lighting_mut.point_lights = point_lights
                     .into_iter()
                     .map(|data| ScenePointLight {
@@ -2509,6 +2953,7 @@ impl App {
                         color: Vec3::from(data.color),
                         radius: data.radius.max(0.0),
                         intensity: data.intensity.max(0.0),
+                        falloff: data.falloff,
                     })
                     .collect();
             }
@@ -2526,13 +2971,22 @@ impl App {
                 state.ui_shadow_resolution = renderer_lighting.shadow_resolution;
                 state.ui_shadow_split_lambda = renderer_lighting.shadow_split_lambda;
                 state.ui_shadow_pcf_radius = renderer_lighting.shadow_pcf_radius;
+                state.ui_auto_exposure = renderer_lighting.auto_exposure;
             }
             self.renderer.mark_shadow_settings_dirty();
         }
+        let scroll_speed = metadata.environment.as_ref().map_or(0.0, |environment| environment.scroll_speed);
+        self.environment_scroll = EnvironmentScroll::new(scroll_speed);
+        self.renderer.set_environment_offset(0.0);
+        self.editor_ui_state_mut().ui_environment_scroll_speed = scroll_speed;
         if let Some(environment) = metadata.environment.as_ref() {
             let intensity = environment.intensity.max(0.0);
             if let Err(err) = self.set_active_environment(&environment.key, intensity) {
-                self.set_ui_scene_status(format!("Environment '{}' unavailable: {err}", environment.key));
+                self.set_ui_scene_status(tr!(
+                    "asset.environment_unavailable",
+                    key = environment.key,
+                    error = err
+                ));
             }
         } else {
             let default_key = self.environment_registry.default_key().to_string();
@@ -2575,6 +3029,7 @@ impl App {
             });
         scene.dependencies.set_environment_dependency(environment_dependency);
         scene.metadata = self.capture_scene_metadata();
+        scene.chunks = self.scene_chunks.chunk_refs();
         scene.save_to_path(scene_path)?;
         self.remember_scene_path(scene_path);
         Ok(())
@@ -2608,6 +3063,7 @@ impl App {
             });
         scene.dependencies.set_environment_dependency(environment_dependency);
         scene.metadata = self.capture_scene_metadata();
+        scene.chunks = self.scene_chunks.chunk_refs();
 
         let selected_scene_id = self
             .selected_entity()
@@ -2618,6 +3074,7 @@ impl App {
     }
 
     fn restore_play_snapshot(&mut self, snapshot: PlaySessionSnapshot) -> Result<()> {
+        self.reset_scene_chunks(&snapshot.scene.chunks);
         if let Err(err) = self.update_scene_dependencies(&snapshot.scene.dependencies) {
             self.ecs.clear_world();
             self.clear_scene_atlases();
@@ -2657,8 +3114,14 @@ impl App {
         Ok(())
     }
 
-    fn load_scene_from_path(&mut self, scene_path: &str) -> Result<()> {
+    /// Loads a scene and returns the references in it that do not resolve; they are also logged.
+    fn load_scene_from_path(&mut self, scene_path: &str) -> Result<Vec<SceneReferenceWarning>> {
         let scene = Scene::load_from_path(scene_path)?;
+        let reference_warnings = scene.validate_entity_references();
+        for warning in &reference_warnings {
+            eprintln!("[scene] {scene_path}: {warning}");
+        }
+        self.reset_scene_chunks(&scene.chunks);
         if let Err(err) = self.update_scene_dependencies(&scene.dependencies) {
             self.ecs.clear_world();
             self.clear_scene_atlases();
@@ -2691,10 +3154,65 @@ impl App {
         }
         self.sync_emitter_ui();
         self.set_inspector_status(None);
-        Ok(())
+        Ok(reference_warnings)
+    }
+
+    /// Loads `config.startup_scene` (set from `startup_scene` in config/app.json or `--scene`),
+    /// falling back to the project's startup scene and then the built-in demo when it fails.
+    fn load_startup_scene(&mut self) {
+        let configured = self.config.startup_scene.clone();
+        let project_scene = self.project.startup_scene_path().to_path_buf();
+        let outcome = resolve_startup_scene(configured.as_deref(), &project_scene, |path| {
+            self.with_editor_ui_state_mut(|state| state.ui_scene_path = path.to_string());
+            self.load_scene_from_path(path)
+        });
+        match outcome {
+            StartupSceneOutcome::Configured { path, warnings } => {
+                self.set_ui_scene_status(scene_load_status(
+                    tr!("scene.startup_loaded", configured = path),
+                    &warnings,
+                ));
+            }
+            StartupSceneOutcome::Project { path, warnings, fallback_note } => {
+                let message = match fallback_note {
+                    Some(note) => format!("{note}; loaded project scene {path} instead"),
+                    None => format!("Loaded startup scene {path}"),
+                };
+                self.set_ui_scene_status(scene_load_status(message, &warnings));
+            }
+            StartupSceneOutcome::ProjectFailed { error } => {
+                self.set_ui_scene_status(tr!("scene.startup_load_failed", error = error));
+            }
+            StartupSceneOutcome::DemoFallback { note } => {
+                self.spawn_demo_world();
+                eprintln!("[scene] {note}; falling back to the demo scene");
+                self.set_ui_scene_status(tr!("scene.demo_fallback", note = note));
+            }
+            StartupSceneOutcome::NoScene => {}
+        }
+    }
+
+    /// Replaces the world with the built-in demo scene.
+    fn spawn_demo_world(&mut self) {
+        self.ecs.clear_world();
+        self.clear_scene_atlases();
+        self.clear_scene_clips();
+        self.set_selected_entity(None);
+        self.set_gizmo_interaction(None);
+        if let Some(plugin) = self.script_plugin_mut() {
+            plugin.clear_handles();
+        }
+        let emitter = self.ecs.spawn_demo_scene();
+        self.emitter_entity = Some(emitter);
+        if self.ecs.set_sprite_timeline(emitter, &self.assets, Some("demo_cycle")) {
+            self.ecs.set_sprite_animation_speed(emitter, 0.85);
+        }
+        self.sync_emitter_ui();
+        self.set_inspector_status(None);
     }
 
     fn clear_scene_atlases(&mut self) {
+        self.reset_scene_chunks(&[]);
         let to_release: Vec<String> = self
             .scene_atlas_refs
             .iter()
@@ -2733,6 +3251,9 @@ impl App {
             self.material_registry.release(key);
         }
         self.scene_material_refs = persistent_materials;
+        for key in std::mem::take(&mut self.script_materials) {
+            self.material_registry.release(&key);
+        }
         self.clear_scene_clips();
     }
 
@@ -2742,6 +3263,11 @@ impl App {
             self.assets.release_clip(&key);
         }
         self.scene_clip_refs.clear();
+        let curves: Vec<String> = self.scene_color_curve_refs.keys().cloned().collect();
+        for key in curves {
+            self.assets.release_color_curve(&key);
+        }
+        self.scene_color_curve_refs.clear();
         self.with_editor_ui_state_mut(|state| state.scene_clip_snapshot = None);
     }
 
@@ -2800,6 +3326,10 @@ impl App {
 impl ApplicationHandler for App {
     fn resumed(&mut self, event_loop: &ActiveEventLoop) {
         if let Err(err) = self.renderer.ensure_window(event_loop) {
+            if self.headless_run_active() {
+                self.skip_headless_run(format!("renderer unavailable ({err:#})"));
+                return;
+            }
             eprintln!("Renderer initialization error: {err:?}");
             self.should_close = true;
             return;
@@ -2813,6 +3343,7 @@ impl ApplicationHandler for App {
             }
         };
         self.assets.set_device(device, queue);
+        self.apply_texture_anisotropy(self.config.textures.anisotropy);
         self.clear_atlas_view_cache();
         if let Err(err) = self.apply_environment_to_renderer() {
             eprintln!(
@@ -2859,19 +3390,14 @@ impl ApplicationHandler for App {
             self.should_close = true;
             return;
         }
+        println!("[renderer] sprite color space: {}", self.renderer.sprite_color_space_summary());
 
         if !self.startup_scene_loaded {
             self.startup_scene_loaded = true;
-            let startup_path = self.project.startup_scene_path().to_path_buf();
-            if startup_path.exists() {
-                let startup_scene = Project::display_path(&startup_path);
-                self.with_editor_ui_state_mut(|state| state.ui_scene_path = startup_scene.clone());
-                if let Err(err) = self.load_scene_from_path(startup_scene.as_str()) {
-                    eprintln!("[scene] Failed to load startup scene {}: {err:?}", startup_scene);
-                    self.set_ui_scene_status(format!("Startup scene load failed: {err}"));
-                } else {
-                    self.set_ui_scene_status(format!("Loaded startup scene {}", startup_scene));
-                }
+            self.load_startup_scene();
+            self.report_prefab_validation();
+            if self.headless_run_active() {
+                self.enter_play_mode();
             }
         }
 
@@ -2931,7 +3457,10 @@ impl ApplicationHandler for App {
                 }
             }
         }
-        if !consumed || is_cursor_event {
+        // Key releases always reach `Input` so a key held while an egui text field takes focus
+        // does not stay stuck down.
+        let is_key_release = matches!(&input_event, InputEvent::Key { pressed: false, .. });
+        if !consumed || is_cursor_event || is_key_release {
             self.input.push(input_event);
         }
 
@@ -2967,9 +3496,18 @@ impl ApplicationHandler for App {
 
     fn about_to_wait(&mut self, event_loop: &ActiveEventLoop) {
         if self.should_close {
+            self.persist_window_geometry();
             event_loop.exit();
             return;
         }
+        if self.renderer.device_lost() {
+            self.recover_graphics_device();
+            if let Some(w) = self.renderer.window() {
+                w.request_redraw();
+            }
+            self.input.clear_frame();
+            return;
+        }
         let step_once = self.step_pending;
         if step_once {
             self.step_pending = false;
@@ -2984,12 +3522,17 @@ impl ApplicationHandler for App {
         if let Some(dropped) = dropped_backlog {
             eprintln!("[time] Dropping {:.3}s of fixed-step backlog to maintain responsiveness", dropped);
         }
+        self.process_plugin_asset_reload_requests();
+        self.apply_plugin_audio_requests();
         self.sync_mesh_hot_reload();
         self.process_mesh_hot_reload_events();
         self.sync_atlas_hot_reload();
         self.process_atlas_hot_reload_events();
         self.process_animation_asset_watchers();
+        self.poll_plugin_manifest();
         self.ecs.profiler_begin_frame();
+        #[cfg(feature = "debug_draw")]
+        self.renderer.clear_debug_draw();
         let frame_start = Instant::now();
         let mut fixed_time_ms = 0.0;
         #[allow(unused_assignments)]
@@ -3083,29 +3626,17 @@ impl ApplicationHandler for App {
 
         let window_size = self.renderer.size();
         let viewport_size = self.viewport_physical_size();
+        let low_latency_input = self.editor_ui_state().ui_low_latency_input;
+        let viewport_editing_enabled = matches!(self.play_state, PlayState::Editing);
         let cursor_screen = self.input.cursor_position().map(|(sx, sy)| Vec2::new(sx, sy));
-        let cursor_viewport = cursor_screen.and_then(|pos| self.screen_to_viewport(pos));
-        let cursor_world_2d = if self.viewport_camera_mode == ViewportCameraMode::Ortho2D {
-            cursor_viewport.and_then(|pos| self.camera.screen_to_world(pos, viewport_size))
-        } else {
-            None
-        };
+        let PointerSample { cursor_viewport, cursor_world_2d, cursor_ray } =
+            self.latch_pointer(viewport_size, low_latency_input && viewport_editing_enabled);
         let mesh_camera = self.mesh_preview_plugin().map(|plugin| plugin.mesh_camera().clone());
         let mesh_control_mode =
             self.mesh_preview_plugin().map(|plugin| plugin.mesh_control_mode()).unwrap_or_default();
-        let cursor_ray = if self.viewport_camera_mode == ViewportCameraMode::Perspective3D {
-            if let (Some(pos), Some(camera)) = (cursor_viewport, mesh_camera.as_ref()) {
-                camera.screen_ray(pos, viewport_size)
-            } else {
-                None
-            }
-        } else {
-            None
-        };
         let cursor_in_viewport = cursor_viewport.is_some();
         let mut selected_info = self.selected_entity().and_then(|entity| self.ecs.entity_info(entity));
-        let mut selection_bounds_2d =
-            self.selected_entity().and_then(|entity| self.ecs.entity_bounds(entity));
+        let mut selection_bounds_2d = self.selection_bounds_2d();
         let mesh_center_world = selected_info.as_ref().and_then(|info| {
             info.mesh_transform
                 .as_ref()
@@ -3113,9 +3644,14 @@ impl ApplicationHandler for App {
                 .or_else(|| Some(Vec3::new(info.translation.x, info.translation.y, 0.0)))
         });
         let gizmo_center_viewport = match self.viewport_camera_mode {
-            ViewportCameraMode::Ortho2D => selected_info
-                .as_ref()
-                .and_then(|info| self.camera.world_to_screen_pixels(info.translation, viewport_size)),
+            ViewportCameraMode::Ortho2D => {
+                let center = if self.has_multi_selection() {
+                    self.selection_centroid()
+                } else {
+                    selected_info.as_ref().map(|info| info.translation)
+                };
+                center.and_then(|center| self.camera.world_to_screen_pixels(center, viewport_size))
+            }
             ViewportCameraMode::Perspective3D => {
                 if let Some(camera) = mesh_camera.as_ref() {
                     mesh_center_world.and_then(|center| camera.project_point(center, viewport_size))
@@ -3129,7 +3665,6 @@ impl ApplicationHandler for App {
         let prev_selection_details = selected_info.clone();
         let prev_selection_bounds_2d = selection_bounds_2d;
 
-        let viewport_editing_enabled = matches!(self.play_state, PlayState::Editing);
         if viewport_editing_enabled
             && self.viewport_camera_mode == ViewportCameraMode::Ortho2D
             && mesh_control_mode == MeshControlMode::Disabled
@@ -3139,13 +3674,12 @@ impl ApplicationHandler for App {
                 self.set_active_camera_bookmark(None);
             }
 
-            if self.input.right_mouse_held() {
-                let (dx, dy) = self.input.mouse_delta;
-                if dx.abs() > f32::EPSILON || dy.abs() > f32::EPSILON {
-                    self.camera.pan_screen_delta(Vec2::new(dx, dy), viewport_size);
-                    self.set_active_camera_bookmark(None);
-                    self.camera_follow_target = None;
-                }
+            if self.input.right_mouse_held() && !low_latency_input {
+                self.pan_camera_from_mouse_delta(viewport_size);
+            }
+
+            if !self.editor_shell.egui_ctx.wants_keyboard_input() {
+                self.apply_keyboard_navigation(viewport_size, dt);
             }
         }
 
@@ -3159,6 +3693,7 @@ impl ApplicationHandler for App {
                 mesh_center_world,
                 gizmo_center_viewport,
                 &selected_info,
+                low_latency_input,
             )
         } else {
             if cursor_in_viewport {
@@ -3170,7 +3705,7 @@ impl ApplicationHandler for App {
         let selection_changed = self.selected_entity() != prev_selected_entity;
         let gizmo_changed = self.gizmo_interaction() != prev_gizmo_interaction;
         selected_info = self.selected_entity().and_then(|entity| self.ecs.entity_info(entity));
-        selection_bounds_2d = self.selected_entity().and_then(|entity| self.ecs.entity_bounds(entity));
+        selection_bounds_2d = self.selection_bounds_2d();
 
         let (cell_size, use_quadtree, density_threshold) = {
             let state = self.editor_ui_state();
@@ -3233,14 +3768,23 @@ impl ApplicationHandler for App {
                 fixed_time_ms += plugin_fixed_start.elapsed().as_secs_f32() * 1000.0;
             }
         }
+        let despawn_view = self.camera.half_extents(viewport_size).map(|(half_width, half_height)| {
+            let half = Vec2::new(half_width, half_height);
+            (self.camera.position - half, self.camera.position + half)
+        });
+        self.ecs.set_despawn_view(despawn_view);
         let update_start = Instant::now();
         self.ecs.update(sim_dt);
         update_time_ms = update_start.elapsed().as_secs_f32() * 1000.0;
         self.update_open_world_lab(dt);
+        self.advance_environment_animation(dt);
+        self.camera.update_shake(sim_dt);
+        self.update_scene_streaming();
         if self.camera_follow_target.is_some() && !self.refresh_camera_follow() {
             self.camera_follow_target = None;
         }
         self.record_events();
+        self.poll_thumbnails();
         let particle_budget_snapshot = self.ecs.particle_budget_metrics();
         let sprite_perf_sample = self.ecs.sprite_anim_perf_sample();
         let spatial_metrics_snapshot = self.ecs.spatial_metrics();
@@ -3248,7 +3792,13 @@ impl ApplicationHandler for App {
             analytics.record_particle_budget(particle_budget_snapshot);
             analytics.record_spatial_metrics(spatial_metrics_snapshot);
         }
+        if let (Some(usage), Some(analytics)) =
+            (self.ecs.sprite_timeline_usage(), self.plugin_runtime.manager_mut().get_mut::<AnalyticsPlugin>())
+        {
+            analytics.record_timeline_usage(usage);
+        }
 
+        self.sync_normal_map_refs();
         let sprite_instances = match self.ecs.collect_sprite_instances(&self.assets) {
             Ok(data) => data,
             Err(err) => {
@@ -3257,63 +3807,51 @@ impl ApplicationHandler for App {
                 return;
             }
         };
-        let sprite_instances = self.apply_sprite_guardrails(sprite_instances, viewport_size);
-        self.recycle_sprite_batch_buffers();
-        for instance in sprite_instances {
-            let (atlas_key, gpu_data) = instance.into_gpu();
-            if let Some(existing) = self.sprite_batch_map.get_mut(&atlas_key) {
-                existing.push(gpu_data);
-            } else {
-                let mut bucket = self.take_sprite_batch_buffer();
-                bucket.push(gpu_data);
-                self.sprite_batch_order.push(Arc::clone(&atlas_key));
-                self.sprite_batch_map.insert(atlas_key, bucket);
-            }
-        }
-        let mut instances: Vec<InstanceData> = Vec::new();
-        let total_instances: usize = self.sprite_batch_map.values().map(|bucket| bucket.len()).sum();
-        instances.reserve(total_instances);
-        let mut sprite_batches: Vec<SpriteBatch> = Vec::new();
-        let mut ordered_keys = mem::take(&mut self.sprite_batch_order);
-        for atlas in ordered_keys.drain(..) {
-            let mut batch_instances = match self.sprite_batch_map.remove(&atlas) {
-                Some(bucket) => bucket,
-                None => continue,
-            };
-            if batch_instances.is_empty() {
-                self.sprite_batch_pool.push(batch_instances);
-                continue;
-            }
-            let start_len = instances.len();
-            instances.append(&mut batch_instances);
-            if instances.len() > u32::MAX as usize {
-                eprintln!("Too many sprite instances to render ({}).", instances.len());
-                instances.truncate(start_len);
-                batch_instances.clear();
-                self.sprite_batch_pool.push(batch_instances);
-                break;
-            }
-            let start = start_len as u32;
-            let end = instances.len() as u32;
-            match self.atlas_view(atlas.as_ref()) {
-                Ok(view) => {
-                    sprite_batches.push(SpriteBatch { atlas: Arc::clone(&atlas), range: start..end, view });
-                }
+        self.sprite_world_bounds = sprite_instances.iter().fold(None, |bounds, instance| {
+            let center = instance.transform.translation.truncate();
+            let (min, max) = (center - instance.world_half_extent, center + instance.world_half_extent);
+            Some(bounds.map_or((min, max), |(lo, hi): (Vec2, Vec2)| (lo.min(min), hi.max(max))))
+        });
+        let culled_last_frame = self.sprite_guardrail_culled;
+        // Guardrail thresholds are in rendered pixels, which the render scale shrinks or grows.
+        let guardrail_viewport = scaled_render_size(viewport_size, self.renderer.render_scale());
+        let mut sprite_instances = self.apply_sprite_guardrails(sprite_instances, guardrail_viewport);
+        // Culling depends on the camera, so ECS change tracking alone cannot vouch for culled buckets.
+        let guardrail_culling = culled_last_frame > 0 || self.sprite_guardrail_culled > 0;
+        // Sorted modes interleave atlases, so batches become runs of neighbouring sprites. Their
+        // ranges shift whenever anything moves, and a baked static layer would draw out of order.
+        let sorted = self.renderer.sprite_sort_mode() != SpriteSortMode::CreationOrder;
+        self.renderer.sort_sprite_instances(&mut sprite_instances);
+        let (sprite_instances, static_rebaked) =
+            self.split_static_sprites(sprite_instances, guardrail_culling || sorted);
+        let mut batcher = mem::take(&mut self.sprite_batcher);
+        let (instances, sprite_batches) = batcher.build(sprite_instances, sorted, |atlas, normal_map| {
+            match self.atlas_view(atlas) {
+                Ok(view) => Some(SpriteBatchTextures {
+                    view,
+                    normal_view: normal_map.and_then(|path| self.normal_map_view(path)),
+                    dirty: sorted
+                        || guardrail_culling
+                        || static_rebaked
+                        || self.ecs.sprite_atlas_dirty(atlas),
+                }),
                 Err(err) => {
-                    eprintln!("Atlas '{}' unavailable for rendering: {err:?}", atlas.as_ref());
-                    instances.truncate(start_len);
-                    self.invalidate_atlas_view(atlas.as_ref());
+                    eprintln!("Atlas '{atlas}' unavailable for rendering: {err:?}");
+                    self.invalidate_atlas_view(atlas);
+                    None
                 }
             }
-            batch_instances.clear();
-            self.sprite_batch_pool.push(batch_instances);
-        }
-        self.sprite_batch_order = ordered_keys;
+        });
+        self.sprite_batcher = batcher;
         let render_viewport = RenderViewport {
             origin: (self.viewport.origin.x, self.viewport.origin.y),
             size: (self.viewport.size.x, self.viewport.size.y),
         };
-        let view_proj = self.camera.view_projection(viewport_size);
+        let thumbnail_capture = self.begin_thumbnail_capture();
+        let view_proj = match thumbnail_capture.as_ref() {
+            Some(request) => self.thumbnail_view_projection(request, viewport_size),
+            None => self.camera.view_projection(viewport_size),
+        };
         let default_material_key = self.material_registry.default_key().to_string();
         #[allow(clippy::type_complexity)]
         let mut mesh_draw_infos: Vec<(String, Mat4, MeshLightingInfo, String, Option<Arc<[Mat4]>>)> =
@@ -3342,7 +3880,26 @@ impl ApplicationHandler for App {
             }
         }
         let scene_meshes = self.ecs.collect_mesh_instances();
+        let mesh_cull_view = mesh_camera.as_ref().map(|camera| (camera.frustum(viewport_size), camera.position));
+        #[cfg(feature = "debug_draw")]
+        let show_culled_bounds = self.editor_ui_state().debug_show_culled_bounds;
+        let mut mesh_cull_counts = MeshCullCounts::default();
+        let mut total_scene_triangles: u64 = 0;
         for instance in scene_meshes {
+            total_scene_triangles += self.mesh_registry.mesh_triangle_count(&instance.key).unwrap_or(0);
+            if let (Some((frustum, eye)), Some(bounds)) =
+                (mesh_cull_view.as_ref(), self.mesh_registry.mesh_bounds(&instance.key))
+            {
+                let (min, max) = bounds.world_aabb(instance.model);
+                if !mesh_instance_visible(frustum, *eye, min, max, instance.max_draw_distance) {
+                    mesh_cull_counts.culled += 1;
+                    #[cfg(feature = "debug_draw")]
+                    if show_culled_bounds {
+                        self.renderer.debug_draw_aabb(min, max, mesh_culling::CULLED_BOUNDS_COLOR);
+                    }
+                    continue;
+                }
+            }
             match self.mesh_registry.ensure_gpu(&instance.key, &mut self.renderer) {
                 Ok(_) => {
                     let material_key =
@@ -3355,6 +3912,7 @@ impl ApplicationHandler for App {
                         material_key,
                         skin_palette,
                     ));
+                    mesh_cull_counts.drawn += 1;
                 }
                 Err(err) => {
                     eprintln!("[mesh] Unable to prepare '{}': {err:?}", instance.key);
@@ -3415,7 +3973,7 @@ impl ApplicationHandler for App {
         }
         let mesh_camera_opt = if mesh_draws.is_empty() { None } else { mesh_camera.as_ref() };
         let render_start = Instant::now();
-        let frame = match self.renderer.render_frame(
+        let (frame, render_stats) = match self.renderer.render_frame(
             &instances,
             &sprite_batches,
             self.assets.default_sampler(),
@@ -3424,22 +3982,33 @@ impl ApplicationHandler for App {
             &mesh_draws,
             mesh_camera_opt,
         ) {
-            Ok(frame) => frame,
+            Ok(rendered) => rendered,
             Err(err) => {
                 eprintln!("Render error: {err:?}");
+                self.record_headless_error(format!("Render error: {err:#}"));
+                if thumbnail_capture.is_some() {
+                    self.restore_output_after_thumbnail();
+                }
                 self.input.clear_frame();
                 return;
             }
         };
         render_time_ms = render_start.elapsed().as_secs_f32() * 1000.0;
+        if let Some(request) = thumbnail_capture {
+            self.finish_thumbnail_capture(request);
+        }
 
         let palette_upload_stats = self.renderer.take_palette_upload_metrics();
+        let sprite_upload_stats = self.renderer.take_sprite_upload_stats();
         let light_cluster_snapshot = *self.renderer.light_cluster_metrics();
         if let Some(analytics) = self.analytics_plugin_mut() {
             analytics.record_light_cluster_metrics(light_cluster_snapshot);
+            analytics.record_render_stats(render_stats);
         }
         if self.editor_shell.egui_winit.is_none() {
             frame.present();
+            self.capture_screenshot_sequence_frame();
+            self.advance_headless_run();
             let frame_ms = frame_start.elapsed().as_secs_f32() * 1000.0;
             self.record_frame_timing_sample(FrameTimingSample {
                 frame_ms,
@@ -3447,6 +4016,10 @@ impl ApplicationHandler for App {
                 fixed_ms: fixed_time_ms,
                 render_ms: render_time_ms,
                 ui_ms: ui_time_ms,
+                sprite_bytes_uploaded: sprite_upload_stats.bytes_uploaded,
+                sprite_bytes_reused: sprite_upload_stats.bytes_reused,
+                meshes_drawn: mesh_cull_counts.drawn,
+                meshes_culled: mesh_cull_counts.culled,
             });
             return;
         }
@@ -3511,16 +4084,11 @@ impl ApplicationHandler for App {
                     false,
                     AudioHealthSnapshot::default(),
                     false,
-                    AudioSpatialConfig {
-                        enabled: false,
-                        min_distance: 0.1,
-                        max_distance: 25.0,
-                        pan_width: 10.0,
-                    },
+                    AudioSpatialConfig { enabled: false, ..AudioSpatialConfig::default() },
                 )
             };
-        let (mesh_keys, environment_options, prefab_entries) = self.with_editor_ui_state_mut(|state| {
-            let mesh = state.telemetry_cache.mesh_keys(&self.mesh_registry);
+        let (mesh_groups, environment_options, prefab_entries) = self.with_editor_ui_state_mut(|state| {
+            let mesh = state.telemetry_cache.mesh_groups(&self.mesh_registry);
             let env = state.telemetry_cache.environment_options(&self.environment_registry);
             let prefabs = state.telemetry_cache.prefab_entries(&self.prefab_library);
             (mesh, env, prefabs)
@@ -3531,6 +4099,7 @@ impl ApplicationHandler for App {
             .unwrap_or_else(|| Arc::new(HashMap::new()));
         let (clip_keys, clip_assets) =
             self.with_editor_ui_state_mut(|state| state.telemetry_cache.clip_assets(&self.assets));
+        let color_curve_keys: Arc<[String]> = Arc::from(self.assets.color_curve_keys());
         let (skeleton_keys, skeleton_assets) =
             self.with_editor_ui_state_mut(|state| state.telemetry_cache.skeleton_assets(&self.assets));
         let (atlas_keys, atlas_assets) =
@@ -3548,12 +4117,10 @@ impl ApplicationHandler for App {
             .material_registry
             .keys()
             .map(|key| {
-                let label = self
-                    .material_registry
-                    .definition(key)
-                    .map(|def| def.label.clone())
-                    .unwrap_or_else(|| key.to_string());
-                editor_ui::MaterialOption { key: key.to_string(), label }
+                let definition = self.material_registry.definition(key);
+                let label = definition.map(|def| def.label.clone()).unwrap_or_else(|| key.to_string());
+                let cull_mode = definition.map(|def| def.cull_mode).unwrap_or_default();
+                editor_ui::MaterialOption { key: key.to_string(), label, cull_mode }
             })
             .collect();
         material_options.sort_by(|a, b| a.label.cmp(&b.label).then_with(|| a.key.cmp(&b.key)));
@@ -3564,13 +4131,36 @@ impl ApplicationHandler for App {
         let input_modifiers =
             editor_ui::InputModifierState { ctrl: self.input.ctrl_held(), shift: self.input.shift_held() };
         let scene_history_list = self.scene_history_arc();
+        self.thumbnails.track_sources(prefab_entries.iter().map(|entry| entry.path.as_path()));
+        self.thumbnails.track_sources(scene_history_list.iter().map(|entry| Path::new(entry.as_str())));
+        let thumbnails = self.thumbnails.textures();
         let atlas_snapshot = self.scene_atlas_refs_arc();
         let mesh_snapshot = self.scene_mesh_refs_arc();
         let clip_snapshot = self.scene_clip_refs_arc();
         let active_environment = self.active_environment_key.clone();
-        let (debug_show_spatial_hash_state, debug_show_colliders_state) = {
+        let (
+            debug_show_spatial_hash_state,
+            debug_show_colliders_state,
+            debug_show_nav_grid_state,
+            debug_show_culled_bounds_state,
+            debug_show_skeletons_state,
+            debug_skeleton_labels_state,
+            collider_edit_mode_state,
+            collider_undo_available,
+            collider_redo_available,
+        ) = {
             let state = self.editor_ui_state();
-            (state.debug_show_spatial_hash, state.debug_show_colliders)
+            (
+                state.debug_show_spatial_hash,
+                state.debug_show_colliders,
+                state.debug_show_nav_grid,
+                state.debug_show_culled_bounds,
+                state.debug_show_skeletons,
+                state.debug_skeleton_labels,
+                state.collider_edit_mode,
+                !state.collider_edit_history.is_empty(),
+                !state.collider_edit_redo.is_empty(),
+            )
         };
         let collider_rects =
             if debug_show_colliders_state && self.viewport_camera_mode == ViewportCameraMode::Ortho2D {
@@ -3578,12 +4168,46 @@ impl ApplicationHandler for App {
             } else {
                 Vec::new()
             };
+        let collider_edit_overlay = if self.collider_edit_active()
+            && self.viewport_camera_mode == ViewportCameraMode::Ortho2D
+            && !self.has_multi_selection()
+        {
+            self.selected_entity().and_then(|entity| {
+                let origin = self.ecs.entity_world_position3d(entity)?.truncate();
+                Some((origin, self.ecs.collider_geometry(entity)?))
+            })
+        } else {
+            None
+        };
         let spatial_hash_rects =
             if debug_show_spatial_hash_state && self.viewport_camera_mode == ViewportCameraMode::Ortho2D {
                 self.ecs.spatial_hash_rects()
             } else {
                 Vec::new()
             };
+        let (nav_blocked_rects, nav_paths) =
+            if debug_show_nav_grid_state && self.viewport_camera_mode == ViewportCameraMode::Ortho2D {
+                self.ecs.sync_nav_grid();
+                (self.ecs.nav_blocked_rects(), self.ecs.nav_agent_paths())
+            } else {
+                (Vec::new(), Vec::new())
+            };
+        let emitter_shape_outline = self
+            .selected_entity()
+            .filter(|_| self.viewport_camera_mode == ViewportCameraMode::Ortho2D)
+            .and_then(|entity| {
+                let emitter = self.ecs.world.get::<ParticleEmitter>(entity)?;
+                let transform = self.ecs.world.get::<Transform>(entity)?;
+                Some(emitter_preview::emitter_shape_outline(
+                    emitter.shape,
+                    emitter.spread,
+                    transform.translation,
+                    transform.rotation,
+                ))
+            })
+            .unwrap_or_default();
+        let skeleton_overlays =
+            if debug_show_skeletons_state { self.ecs.all_skeleton_joint_poses() } else { Vec::new() };
         if !BINARY_PREFABS_ENABLED {
             let mut state = self.editor_ui_state_mut();
             if state.prefab_format == PrefabFormat::Binary {
@@ -3619,18 +4243,20 @@ impl ApplicationHandler for App {
                 skeletal_bone_count: skeletal_metrics.bone_count,
                 palette_upload_calls: palette_upload_stats.calls,
                 palette_uploaded_joints: palette_upload_stats.joints_uploaded,
+                allocation_pressure: alloc_sampler::is_enabled().then(alloc_sampler::take_sample),
             });
         }
         self.refresh_editor_analytics_state();
         self.refresh_editor_plugin_state();
         self.refresh_script_debugger_state();
         let latest_frame_timing = self.latest_frame_timing();
-        let (frame_budget_idle, frame_budget_panel, frame_budget_status) = {
+        let (frame_budget_idle, frame_budget_panel, frame_budget_status, gpu_baseline_report) = {
             let state = self.editor_ui_state();
             (
                 state.frame_budget_idle_snapshot.as_ref().map(Self::frame_budget_snapshot_view),
                 state.frame_budget_panel_snapshot.as_ref().map(Self::frame_budget_snapshot_view),
                 state.frame_budget_status.clone(),
+                state.gpu_baseline_report.clone(),
             )
         };
 
@@ -3638,6 +4264,8 @@ impl ApplicationHandler for App {
             let state = self.editor_ui_state();
             (state.id_lookup_input.clone(), state.id_lookup_active)
         };
+        let entity_search_candidates =
+            if id_lookup_active_state { self.entity_search_candidates() } else { Vec::new() };
         let (script_debugger_open, script_repl_input, script_repl_history_index, script_focus_repl) = {
             let state = self.editor_ui_state();
             (
@@ -3647,6 +4275,16 @@ impl ApplicationHandler for App {
                 state.script_focus_repl,
             )
         };
+        let (layout_presets, active_layout, layout_name_input_state, layout_apply) = {
+            let mut state = self.editor_ui_state_mut();
+            let names: Vec<String> = state.layout_presets.iter().map(|preset| preset.name.clone()).collect();
+            (
+                Arc::<[String]>::from(names.into_boxed_slice()),
+                state.layout_preset.clone(),
+                state.layout_name_input.clone(),
+                state.pending_layout.take(),
+            )
+        };
         let script_repl_history = self.script_repl_history_arc();
         let script_console_entries = self.script_console_entries();
         let script_debugger_status = {
@@ -3675,6 +4313,8 @@ impl ApplicationHandler for App {
             light_cluster_metrics_overlay,
             keyframe_editor_usage,
             keyframe_event_log,
+            timeline_usage,
+            custom_counters,
         ) = {
             let state = self.editor_ui_state();
             (
@@ -3698,6 +4338,8 @@ impl ApplicationHandler for App {
                 state.light_cluster_metrics_overlay,
                 state.keyframe_editor_usage,
                 Arc::clone(&state.keyframe_event_log),
+                Arc::clone(&state.timeline_usage),
+                Arc::clone(&state.custom_counters),
             )
         };
 
@@ -3708,6 +4350,7 @@ impl ApplicationHandler for App {
             prefab_status_state,
             ui_scene_path_state,
             ui_scene_status_state,
+            ui_fragment_include_children_state,
             animation_group_input_state,
             animation_group_scale_input_state,
             inspector_status_state,
@@ -3717,6 +4360,7 @@ impl ApplicationHandler for App {
             ui_spawn_per_press_state,
             ui_auto_spawn_rate_state,
             ui_environment_intensity_state,
+            ui_environment_scroll_speed_state,
             ui_root_spin_state,
             ui_emitter_rate_state,
             ui_emitter_spread_state,
@@ -3740,10 +4384,13 @@ impl ApplicationHandler for App {
             ui_shadow_resolution_state,
             ui_shadow_split_lambda_state,
             ui_shadow_pcf_radius_state,
+            ui_auto_exposure_state,
             ui_camera_zoom_min_state,
             ui_camera_zoom_max_state,
             ui_sprite_guard_pixels_state,
             ui_sprite_guard_mode_state,
+            ui_static_sprite_batching_state,
+            ui_low_latency_input_state,
             keyframe_panel_open_state,
             sprite_guardrail_status_state,
             gpu_metrics_status_state,
@@ -3756,6 +4403,7 @@ impl ApplicationHandler for App {
                 state.prefab_status.clone(),
                 state.ui_scene_path.clone(),
                 state.ui_scene_status.clone(),
+                state.ui_fragment_include_children,
                 state.animation_group_input.clone(),
                 state.animation_group_scale_input,
                 state.inspector_status.clone(),
@@ -3765,6 +4413,7 @@ impl ApplicationHandler for App {
                 state.ui_spawn_per_press,
                 state.ui_auto_spawn_rate,
                 state.ui_environment_intensity,
+                state.ui_environment_scroll_speed,
                 state.ui_root_spin,
                 state.ui_emitter_rate,
                 state.ui_emitter_spread,
@@ -3788,10 +4437,13 @@ impl ApplicationHandler for App {
                 state.ui_shadow_resolution,
                 state.ui_shadow_split_lambda,
                 state.ui_shadow_pcf_radius,
+                state.ui_auto_exposure,
                 state.ui_camera_zoom_min,
                 state.ui_camera_zoom_max,
                 state.ui_sprite_guard_pixels,
                 state.ui_sprite_guard_mode,
+                state.ui_static_sprite_batching,
+                state.ui_low_latency_input,
                 state.animation_keyframe_panel.is_open(),
                 state.sprite_guardrail_status.clone(),
                 state.gpu_metrics_status.clone(),
@@ -3829,6 +4481,7 @@ impl ApplicationHandler for App {
             mesh_frustum_lock_state,
             mesh_orbit_radius,
             mesh_freefly_speed_state,
+            mesh_clip_planes_state,
             mesh_status_message,
             persistent_materials,
             persistent_meshes,
@@ -3839,12 +4492,23 @@ impl ApplicationHandler for App {
                 plugin.mesh_frustum_lock(),
                 plugin.mesh_orbit().radius,
                 plugin.mesh_freefly_speed(),
+                plugin.mesh_clip_planes(),
                 plugin.mesh_status().map(|s| s.to_string()),
                 plugin.persistent_materials().iter().cloned().collect(),
                 plugin.persistent_meshes().iter().cloned().collect(),
             )
         } else {
-            (String::new(), MeshControlMode::Disabled, false, 0.0, 0.0, None, HashSet::new(), HashSet::new())
+            (
+                String::new(),
+                MeshControlMode::Disabled,
+                false,
+                0.0,
+                0.0,
+                (MESH_CAMERA_NEAR, MESH_CAMERA_FAR),
+                None,
+                HashSet::new(),
+                HashSet::new(),
+            )
         };
 
         let scene_dependency_data_available = scene_dependencies_snapshot.is_some();
@@ -3879,6 +4543,7 @@ impl ApplicationHandler for App {
                     persistent: persistent_meshes.contains(mesh_key),
                     loaded: self.mesh_registry.has(mesh_key),
                     ref_count,
+                    triangle_count: self.mesh_registry.mesh_triangle_count(mesh_key),
                     path,
                 });
             }
@@ -3909,10 +4574,13 @@ impl ApplicationHandler for App {
             })
         });
         let selected_entity_opt = self.selected_entity();
+        let selection_count = self.selected_entities().len();
+        let selection_centroid_2d = if selection_count > 1 { self.selection_centroid() } else { None };
         let selected_script_error = selected_entity_opt
             .map(|entity| self.script_plugin().map_or(false, |plugin| plugin.entity_has_errored_instance(entity)))
             .unwrap_or(false);
 
+        let ui_theme = self.editor_ui_state().ui_theme;
         let editor_params = editor_ui::EditorUiParams {
             raw_input,
             base_pixels_per_point,
@@ -3925,6 +4593,7 @@ impl ApplicationHandler for App {
             frame_budget_status,
             shadow_pass_metric,
             mesh_pass_metric,
+            gpu_baseline_report,
             plugin_capability_metrics,
             plugin_capability_events,
             plugin_asset_readback_log,
@@ -3959,13 +4628,26 @@ impl ApplicationHandler for App {
             ),
             light_cluster_metrics_overlay,
             light_cluster_metrics: light_cluster_snapshot,
+            effective_exposure: self.renderer.effective_exposure(),
+            gpu_memory: self.gpu_memory_report(),
             point_lights: self.renderer.lighting().point_lights.clone(),
             keyframe_editor_usage,
             keyframe_event_log,
+            timeline_usage,
+            custom_counters,
             system_timings,
             entity_count,
+            max_entities: self.ecs.max_entities(),
             instances_drawn,
+            total_scene_triangles,
+            render_stats: self.analytics_plugin().and_then(|plugin| plugin.render_stats()),
             vsync_enabled: self.renderer.vsync_enabled(),
+            texture_anisotropy: self.assets.anisotropy(),
+            texture_anisotropy_limit: self.renderer.max_anisotropy(),
+            render_scale: self.renderer.render_scale(),
+            sprite_color_space: self.renderer.sprite_color_space(),
+            sprite_color_summary: self.renderer.sprite_color_space_summary(),
+            sprite_sort_mode: self.renderer.sprite_sort_mode(),
             particle_budget: Some(particle_budget_snapshot),
             spatial_metrics,
             sprite_perf_sample,
@@ -3973,12 +4655,14 @@ impl ApplicationHandler for App {
             sprite_pack_ms,
             sprite_upload_ms,
             ui_scale,
+            ui_theme,
             ui_cell_size: ui_cell_size_state,
             ui_spatial_use_quadtree: ui_spatial_use_quadtree_state,
             ui_spatial_density_threshold: ui_spatial_density_threshold_state,
             ui_spawn_per_press: ui_spawn_per_press_state,
             ui_auto_spawn_rate: ui_auto_spawn_rate_state,
             ui_environment_intensity: ui_environment_intensity_state,
+            ui_environment_scroll_speed: ui_environment_scroll_speed_state,
             ui_root_spin: ui_root_spin_state,
             ui_emitter_rate: ui_emitter_rate_state,
             ui_emitter_spread: ui_emitter_spread_state,
@@ -4002,17 +4686,22 @@ impl ApplicationHandler for App {
             ui_shadow_resolution: ui_shadow_resolution_state,
             ui_shadow_split_lambda: ui_shadow_split_lambda_state,
             ui_shadow_pcf_radius: ui_shadow_pcf_radius_state,
+            ui_auto_exposure: ui_auto_exposure_state,
             ui_camera_zoom_min: ui_camera_zoom_min_state,
             ui_camera_zoom_max: ui_camera_zoom_max_state,
             ui_sprite_guard_pixels: ui_sprite_guard_pixels_state,
             ui_sprite_guard_mode: ui_sprite_guard_mode_state,
+            ui_static_sprite_batching: ui_static_sprite_batching_state,
+            ui_low_latency_input: ui_low_latency_input_state,
             selected_entity: selected_entity_opt,
+            selection_count,
             selected_script_error,
             selection_details: selected_info.clone(),
             prev_selection_details: prev_selection_details.clone(),
             prev_selected_entity,
             prev_gizmo_interaction,
             selection_bounds_2d,
+            selection_centroid_2d,
             prev_selection_bounds_2d,
             gizmo_interaction: self.gizmo_interaction(),
             selection_changed,
@@ -4038,18 +4727,32 @@ impl ApplicationHandler for App {
             mesh_frustum_lock: mesh_frustum_lock_state,
             mesh_orbit_radius,
             mesh_freefly_speed: mesh_freefly_speed_state,
+            mesh_clip_planes: mesh_clip_planes_state,
             mesh_status_message,
             camera_bookmark_input: camera_bookmark_input_state,
-            mesh_keys,
+            mesh_groups,
             environment_options,
             active_environment,
             persistent_materials,
             debug_show_spatial_hash: debug_show_spatial_hash_state,
             debug_show_colliders: debug_show_colliders_state,
+            debug_show_nav_grid: debug_show_nav_grid_state,
+            debug_show_culled_bounds: debug_show_culled_bounds_state,
+            debug_show_skeletons: debug_show_skeletons_state,
+            debug_skeleton_labels: debug_skeleton_labels_state,
+            collider_edit_mode: collider_edit_mode_state,
+            collider_undo_available,
+            collider_redo_available,
             spatial_hash_rects,
             collider_rects,
+            collider_edit_overlay,
+            nav_blocked_rects,
+            nav_paths,
+            emitter_shape_outline,
+            skeleton_overlays,
 
             scene_history_list,
+            scene_chunks: self.scene_chunk_statuses(),
             atlas_dependencies: atlas_dependencies_view,
             mesh_dependencies: mesh_dependencies_view,
             clip_dependencies: clip_dependencies_view,
@@ -4065,11 +4768,13 @@ impl ApplicationHandler for App {
             audio_spatial_config,
             binary_prefabs_enabled: BINARY_PREFABS_ENABLED,
             prefab_entries,
+            thumbnails,
             prefab_name_input: prefab_name_input_state,
             prefab_format: prefab_format_state,
             prefab_status: prefab_status_state,
             clip_keys,
             clip_assets,
+            color_curve_keys,
             skeleton_keys,
             skeleton_assets,
             atlas_keys,
@@ -4081,12 +4786,17 @@ impl ApplicationHandler for App {
             input_modifiers,
             ui_scene_path: ui_scene_path_state,
             ui_scene_status: ui_scene_status_state,
+            ui_fragment_include_children: ui_fragment_include_children_state,
             animation_group_input: animation_group_input_state,
             animation_group_scale_input: animation_group_scale_input_state,
             inspector_status: inspector_status_state,
             sprite_guardrail_status: sprite_guardrail_status_state,
             gpu_metrics_status: gpu_metrics_status_state,
             keyframe_panel_open: keyframe_panel_open_state,
+            layout_presets,
+            active_layout,
+            layout_name_input: layout_name_input_state,
+            layout_apply,
             script_debugger: editor_ui::ScriptDebuggerParams {
                 open: script_debugger_open,
                 available: script_debugger_status.available,
@@ -4111,11 +4821,14 @@ impl ApplicationHandler for App {
             },
             id_lookup_input: id_lookup_input_state,
             id_lookup_active: id_lookup_active_state,
+            entity_search_candidates,
             gpu_timing_snapshot,
             gpu_history_empty,
             gpu_timing_averages,
             gpu_timing_supported: self.renderer.gpu_timing_supported(),
             gpu_timing_enabled: self.renderer.gpu_timing_enabled(),
+            languages: Arc::clone(&self.editor_ui_state().ui_languages),
+            active_language: locale::active().language().to_string(),
             gizmo_mode: gizmo_mode_state,
         };
 
@@ -4133,6 +4846,7 @@ impl ApplicationHandler for App {
             ui_spawn_per_press,
             ui_auto_spawn_rate,
             ui_environment_intensity,
+            ui_environment_scroll_speed,
             ui_root_spin,
             ui_emitter_rate,
             ui_emitter_spread,
@@ -4156,10 +4870,13 @@ impl ApplicationHandler for App {
             ui_shadow_resolution,
             ui_shadow_split_lambda,
             ui_shadow_pcf_radius,
+            ui_auto_exposure,
             ui_camera_zoom_min,
             ui_camera_zoom_max,
             ui_sprite_guard_pixels,
             ui_sprite_guard_mode,
+            ui_static_sprite_batching,
+            ui_low_latency_input,
             mut selection,
             gizmo_mode,
             gizmo_interaction,
@@ -4169,6 +4886,7 @@ impl ApplicationHandler for App {
             camera_bookmark_delete,
             mesh_control_request,
             mesh_frustum_request,
+            mesh_clip_request,
             mesh_frustum_snap,
             mesh_reset_request,
             mesh_selection_request,
@@ -4187,18 +4905,28 @@ impl ApplicationHandler for App {
             camera_follow_clear,
             debug_show_spatial_hash,
             debug_show_colliders,
+            debug_show_nav_grid,
+            debug_show_culled_bounds,
+            debug_show_skeletons,
+            debug_skeleton_labels,
+            collider_edit_mode,
             vsync_request,
+            anisotropy_request,
+            render_scale_request,
             script_debugger,
             prefab_name_input,
             prefab_format,
             prefab_status,
             ui_scene_path,
             ui_scene_status,
+            ui_fragment_include_children,
             animation_group_input,
             animation_group_scale_input,
             inspector_status,
             clear_scene_history,
             keyframe_panel_open,
+            layout_name_input,
+            layout_action,
             gpu_metrics_status,
             project_action,
             start_screen_open,
@@ -4217,6 +4945,9 @@ impl ApplicationHandler for App {
 
         let frame_budget_action = actions.frame_budget_action;
         self.handle_frame_budget_action(frame_budget_action);
+        if actions.compare_gpu_baseline {
+            self.compare_gpu_baseline();
+        }
 
         {
             let mut state = self.editor_ui_state_mut();
@@ -4227,9 +4958,11 @@ impl ApplicationHandler for App {
             state.prefab_status = prefab_status;
             state.ui_scene_path = ui_scene_path;
             state.ui_scene_status = ui_scene_status;
+            state.ui_fragment_include_children = ui_fragment_include_children;
             state.animation_group_input = animation_group_input;
             state.animation_group_scale_input = animation_group_scale_input;
             state.inspector_status = inspector_status;
+            state.layout_name_input = layout_name_input;
             if state.animation_keyframe_panel.is_open() != keyframe_panel_open {
                 state.animation_keyframe_panel.toggle();
             }
@@ -4240,6 +4973,7 @@ impl ApplicationHandler for App {
             state.ui_spawn_per_press = ui_spawn_per_press;
             state.ui_auto_spawn_rate = ui_auto_spawn_rate;
             state.ui_environment_intensity = ui_environment_intensity;
+            state.ui_environment_scroll_speed = ui_environment_scroll_speed;
             state.ui_root_spin = ui_root_spin;
             state.ui_emitter_rate = ui_emitter_rate;
             state.ui_emitter_spread = ui_emitter_spread;
@@ -4263,12 +4997,20 @@ impl ApplicationHandler for App {
             state.ui_shadow_resolution = ui_shadow_resolution;
             state.ui_shadow_split_lambda = ui_shadow_split_lambda;
             state.ui_shadow_pcf_radius = ui_shadow_pcf_radius;
+            state.ui_auto_exposure = ui_auto_exposure;
             state.ui_camera_zoom_min = ui_camera_zoom_min;
             state.ui_camera_zoom_max = ui_camera_zoom_max;
             state.ui_sprite_guard_pixels = ui_sprite_guard_pixels;
             state.ui_sprite_guard_mode = ui_sprite_guard_mode;
+            state.ui_static_sprite_batching = ui_static_sprite_batching;
+            state.ui_low_latency_input = ui_low_latency_input;
             state.debug_show_spatial_hash = debug_show_spatial_hash;
             state.debug_show_colliders = debug_show_colliders;
+            state.debug_show_nav_grid = debug_show_nav_grid;
+            state.debug_show_culled_bounds = debug_show_culled_bounds;
+            state.debug_show_skeletons = debug_show_skeletons;
+            state.debug_skeleton_labels = debug_skeleton_labels;
+            state.collider_edit_mode = collider_edit_mode;
             if clear_scene_history {
                 state.scene_history.clear();
                 state.scene_history_snapshot = None;
@@ -4290,19 +5032,23 @@ impl ApplicationHandler for App {
         }
         self.environment_intensity = ui_environment_intensity;
         self.renderer.set_environment_intensity(self.environment_intensity);
+        self.environment_scroll.speed = ui_environment_scroll_speed;
 
         self.handle_inspector_actions(&mut actions.inspector_actions);
+        if let Some(request) = actions.component_clipboard.take() {
+            self.handle_component_clipboard_request(request);
+        }
 
         if let Some(request) = id_lookup_request {
             let trimmed = request.trim();
             if trimmed.is_empty() {
-                self.set_ui_scene_status("Enter an entity ID to select.".to_string());
+                self.set_ui_scene_status(tr!("scene.enter_entity_id"));
             } else if let Some(entity) = self.ecs.find_entity_by_scene_id(trimmed) {
                 selection.entity = Some(entity);
                 selection.details = self.ecs.entity_info(entity);
-                self.set_ui_scene_status(format!("Selected entity {}", trimmed));
+                self.set_ui_scene_status(tr!("scene.entity_selected", id = trimmed));
             } else {
-                self.set_ui_scene_status(format!("Entity {} not found", trimmed));
+                self.set_ui_scene_status(tr!("scene.entity_not_found", id = trimmed));
             }
         }
 
@@ -4316,51 +5062,71 @@ impl ApplicationHandler for App {
                 }
             }
         }
+        if self.input.take_copy_selection() {
+            self.copy_selected_entity();
+        }
+        if self.input.take_paste_selection() {
+            self.paste_entity_at_cursor();
+        }
+        if self.input.take_duplicate_selection() {
+            actions.duplicate_selection = true;
+        }
+        if self.input.take_chord("save_scene") {
+            actions.save_scene = true;
+        }
+        let frame_selection_request = frame_selection_request || self.input.take_focus_selection();
+        if self.input.take_cycle_selection() {
+            self.cycle_selection(self.input.shift_held());
+        }
+        if self.input.take_reset_camera() || actions.reset_camera {
+            self.center_camera_on_world_origin();
+            self.set_ui_scene_status(tr!("camera.reset"));
+        }
         self.apply_particle_caps();
 
         if let Some(request) = camera_bookmark_select {
             match request {
                 Some(name) => {
                     if !self.apply_camera_bookmark_by_name(&name) {
-                        self.set_ui_scene_status(format!("Bookmark '{}' not found.", name));
+                        self.set_ui_scene_status(tr!("camera.bookmark_not_found", name = name));
                     }
                 }
                 None => {
                     self.set_active_camera_bookmark(None);
                     self.camera_follow_target = None;
-                    self.set_ui_scene_status("Camera set to free mode.".to_string());
+                    self.set_ui_scene_status(tr!("camera.free_mode"));
                 }
             }
         }
         if let Some(name) = camera_bookmark_save {
             if self.upsert_camera_bookmark(&name) {
-                self.set_ui_scene_status(format!("Saved camera bookmark '{}'.", name.trim()));
+                self.set_ui_scene_status(tr!("camera.bookmark_saved", name = name.trim()));
             } else {
-                self.set_ui_scene_status("Enter a bookmark name to save.".to_string());
+                self.set_ui_scene_status(tr!("camera.bookmark_name_required"));
             }
         }
         if let Some(name) = camera_bookmark_delete {
             if self.delete_camera_bookmark(&name) {
-                self.set_ui_scene_status(format!("Deleted camera bookmark '{}'.", name.trim()));
+                self.set_ui_scene_status(tr!("camera.bookmark_deleted", name = name.trim()));
             } else {
-                self.set_ui_scene_status(format!("Bookmark '{}' not found.", name.trim()));
+                self.set_ui_scene_status(tr!("camera.bookmark_not_found", name = name.trim()));
             }
         }
         if camera_follow_selection {
             if let Some(details) = selection.details.as_ref() {
                 let scene_id = details.scene_id.clone();
                 if self.set_camera_follow_scene_id(scene_id) {
-                    self.set_ui_scene_status(format!("Following entity {}.", details.scene_id.as_str()));
+                    self.set_ui_scene_status(tr!("camera.following", id = details.scene_id.as_str()));
                 } else {
-                    self.set_ui_scene_status("Unable to follow selected entity.".to_string());
+                    self.set_ui_scene_status(tr!("camera.follow_failed"));
                 }
             } else {
-                self.set_ui_scene_status("Select an entity to follow.".to_string());
+                self.set_ui_scene_status(tr!("camera.follow_needs_selection"));
             }
         }
         if camera_follow_clear && self.camera_follow_target.is_some() {
             self.clear_camera_follow();
-            self.set_ui_scene_status("Camera follow cleared.".to_string());
+            self.set_ui_scene_status(tr!("camera.follow_cleared"));
         }
 
         if let Some(mode) = viewport_mode_request {
@@ -4372,6 +5138,9 @@ impl ApplicationHandler for App {
         if let Some(lock) = mesh_frustum_request {
             self.set_frustum_lock(lock);
         }
+        if let Some((near, far)) = mesh_clip_request {
+            self.set_mesh_clip_planes(near, far);
+        }
         if mesh_frustum_snap {
             if let Some(plugin) = self.mesh_preview_plugin_mut() {
                 plugin.snap_frustum_to_selection(selection.details.as_ref(), orbit_target);
@@ -4386,10 +5155,14 @@ impl ApplicationHandler for App {
         if let Some(environment_key) = environment_selection_request {
             match self.set_active_environment(&environment_key, self.environment_intensity) {
                 Ok(()) => {
-                    self.set_ui_scene_status(format!("Environment set to {}", environment_key));
+                    self.set_ui_scene_status(tr!("asset.environment_set_to", key = environment_key));
                 }
                 Err(err) => {
-                    self.set_ui_scene_status(format!("Environment '{}' unavailable: {err}", environment_key));
+                    self.set_ui_scene_status(tr!(
+                        "asset.environment_unavailable",
+                        key = environment_key,
+                        error = err
+                    ));
                 }
             }
         }
@@ -4434,6 +5207,9 @@ impl ApplicationHandler for App {
                 state.script_console_snapshot = None;
             }
         }
+        if let Some(action) = layout_action {
+            self.handle_layout_action(action);
+        }
         if let Some(enabled) = script_debugger.set_enabled {
             if let Some(plugin) = self.script_plugin_mut() {
                 plugin.set_enabled(enabled);
@@ -4474,15 +5250,24 @@ impl ApplicationHandler for App {
         if let Some(command) = script_debugger.submit_command {
             self.execute_repl_command(command);
         }
+        if let Some(location) = script_debugger.open_in_editor {
+            self.open_script_in_editor(&location);
+        }
 
         if let Some((origin, size)) = pending_viewport {
             self.update_viewport(origin, size);
         }
         if frame_selection_request {
-            if self.focus_selection() {
-                self.set_inspector_status(Some("Viewport framed selection.".to_string()));
+            if self.selected_entity().is_none() {
+                if self.focus_all() {
+                    self.set_inspector_status(Some(tr!("inspector.viewport_framed_all_entities")));
+                } else {
+                    self.set_inspector_status(Some(tr!("inspector.no_entities_to_frame")));
+                }
+            } else if self.focus_selection() {
+                self.set_inspector_status(Some(tr!("inspector.viewport_framed_selection")));
             } else {
-                self.set_inspector_status(Some("Selection unavailable.".to_string()));
+                self.set_inspector_status(Some(tr!("inspector.selection_unavailable")));
             }
         }
 
@@ -4491,23 +5276,23 @@ impl ApplicationHandler for App {
                 Ok(()) => {
                     self.scene_atlas_refs.insert(key.clone());
                     self.invalidate_atlas_view(&key);
-                    self.set_ui_scene_status(format!("Retained atlas {}", key));
+                    self.set_ui_scene_status(tr!("asset.retained_atlas", key = key));
                 }
                 Err(err) => {
-                    self.set_ui_scene_status(format!("Atlas retain failed: {err}"));
+                    self.set_ui_scene_status(tr!("asset.atlas_retain_failed", error = err));
                 }
             }
         }
         for (key, path) in actions.retain_clips {
             match self.assets.retain_clip(&key, path.as_deref()) {
                 Ok(()) => {
-                    self.set_ui_scene_status(format!("Retained clip {}", key));
+                    self.set_ui_scene_status(tr!("asset.retained_clip", key = key));
                     if let Some(source) = path.as_deref() {
                         self.queue_animation_watch_root(Path::new(source), AnimationAssetKind::Clip);
                     }
                 }
                 Err(err) => {
-                    self.set_ui_scene_status(format!("Clip retain failed: {err}"));
+                    self.set_ui_scene_status(tr!("asset.clip_retain_failed", error = err));
                 }
             }
         }
@@ -4515,21 +5300,10 @@ impl ApplicationHandler for App {
             let entity = request.entity;
             let atlas = request.atlas;
             let path = request.path;
-            if let Some(path) = path.as_deref() {
-                if !self.assets.has_atlas(&atlas) {
-                    match self.assets.retain_atlas(&atlas, Some(path)) {
-                        Ok(()) => {
-                            self.scene_atlas_refs.insert(atlas.clone());
-                            self.invalidate_atlas_view(&atlas);
-                        }
-                        Err(err) => {
-                            self.set_inspector_status(Some(format!(
-                                "Failed to load atlas '{}': {err}",
-                                atlas
-                            )));
-                            continue;
-                        }
-                    }
+            if path.is_some() {
+                if let Err(err) = self.resolve_sprite_atlas(&atlas, path.as_deref()) {
+                    self.set_inspector_status(Some(format!("{err:#}")));
+                    continue;
                 }
             }
             if self.assets.has_atlas(&atlas) {
@@ -4544,10 +5318,10 @@ impl ApplicationHandler for App {
                     self.scene_atlas_refs.insert(atlas.clone());
                     self.invalidate_atlas_view(&atlas);
                 } else {
-                    self.set_inspector_status(Some(format!("Failed to assign atlas '{}' to sprite", atlas)));
+                    self.set_inspector_status(Some(tr!("inspector.atlas_assign_failed", atlas = atlas)));
                 }
             } else {
-                self.set_inspector_status(Some(format!("Atlas '{}' not loaded; unable to assign", atlas)));
+                self.set_inspector_status(Some(tr!("inspector.atlas_not_loaded", atlas = atlas)));
             }
         }
         for (key, path) in actions.retain_meshes {
@@ -4556,7 +5330,7 @@ impl ApplicationHandler for App {
                     self.scene_mesh_refs.insert(key.clone());
                     match self.mesh_registry.ensure_gpu(&key, &mut self.renderer) {
                         Ok(_) => {
-                            self.set_ui_scene_status(format!("Retained mesh {}", key));
+                            self.set_ui_scene_status(tr!("asset.retained_mesh", key = key));
                         }
                         Err(err) => {
                             self.set_mesh_status(format!("Mesh upload failed: {err}"));
@@ -4564,7 +5338,7 @@ impl ApplicationHandler for App {
                     }
                 }
                 Err(err) => {
-                    self.set_ui_scene_status(format!("Mesh retain failed: {err}"));
+                    self.set_ui_scene_status(tr!("asset.mesh_retain_failed", error = err));
                 }
             }
         }
@@ -4574,24 +5348,24 @@ impl ApplicationHandler for App {
                     let scene_requested = self.scene_environment_ref.as_deref() == Some(key.as_str());
                     let should_activate = scene_requested || self.active_environment_key == key;
                     if let Err(err) = self.environment_registry.ensure_gpu(&key, &mut self.renderer) {
-                        self.set_ui_scene_status(format!("Environment upload failed: {err}"));
+                        self.set_ui_scene_status(tr!("asset.environment_upload_failed", error = err));
                         continue;
                     }
                     if should_activate {
                         match self.set_active_environment(&key, self.environment_intensity) {
                             Ok(()) => {
-                                self.set_ui_scene_status(format!("Environment set to {}", key));
+                                self.set_ui_scene_status(tr!("asset.environment_set_to", key = key));
                             }
                             Err(err) => {
-                                self.set_ui_scene_status(format!("Environment bind failed: {err}"));
+                                self.set_ui_scene_status(tr!("asset.environment_bind_failed", error = err));
                             }
                         }
                     } else {
-                        self.set_ui_scene_status(format!("Retained environment {}", key));
+                        self.set_ui_scene_status(tr!("asset.retained_environment", key = key));
                     }
                 }
                 Err(err) => {
-                    self.set_ui_scene_status(format!("Environment retain failed: {err}"));
+                    self.set_ui_scene_status(tr!("asset.environment_retain_failed", error = err));
                 }
             }
         }
@@ -4599,17 +5373,32 @@ impl ApplicationHandler for App {
         if actions.save_scene {
             let scene_path = self.editor_ui_state().ui_scene_path.clone();
             match self.save_scene_to_path(&scene_path) {
-                Ok(()) => self.set_ui_scene_status(format!("Saved {}", scene_path)),
-                Err(err) => self.set_ui_scene_status(format!("Save failed: {err}")),
+                Ok(()) => {
+                    self.queue_thumbnail(Path::new(&scene_path), None);
+                    self.set_ui_scene_status(tr!("scene.saved", path = scene_path))
+                }
+                Err(err) => self.set_ui_scene_status(tr!("scene.save_failed", error = err)),
             }
         }
         if actions.load_scene {
             let scene_path = self.editor_ui_state().ui_scene_path.clone();
             match self.load_scene_from_path(&scene_path) {
-                Ok(()) => self.set_ui_scene_status(format!("Loaded {}", scene_path)),
-                Err(err) => self.set_ui_scene_status(format!("Load failed: {err}")),
+                Ok(warnings) => {
+                    let status = tr!("scene.loaded", path = scene_path);
+                    self.set_ui_scene_status(scene_load_status(status, &warnings))
+                }
+                Err(err) => self.set_ui_scene_status(tr!("scene.load_failed", error = err)),
             }
         }
+        if let Some(include_children) = actions.export_selection {
+            self.handle_export_selection(include_children);
+        }
+        if actions.import_scene {
+            self.handle_import_scene();
+        }
+        if let Some(key) = actions.open_scene_chunk {
+            self.open_scene_chunk(&key);
+        }
         if let Some(request) = actions.save_prefab {
             self.handle_save_prefab(request);
         }
@@ -4621,34 +5410,23 @@ impl ApplicationHandler for App {
             self.ecs.spawn_burst(&self.assets, spawn_per_press as usize);
         }
         if actions.spawn_demo {
-            self.ecs.clear_world();
-            self.clear_scene_atlases();
-            self.clear_scene_clips();
-            self.set_selected_entity(None);
-            self.set_gizmo_interaction(None);
-            if let Some(plugin) = self.script_plugin_mut() {
-                plugin.clear_handles();
-            }
-            let emitter = self.ecs.spawn_demo_scene();
-            self.emitter_entity = Some(emitter);
-            if self.ecs.set_sprite_timeline(emitter, &self.assets, Some("demo_cycle")) {
-                self.ecs.set_sprite_animation_speed(emitter, 0.85);
-            }
-            self.sync_emitter_ui();
-            self.set_inspector_status(None);
-            self.set_ui_scene_status("Spawned demo scene".to_string());
+            self.spawn_demo_world();
+            self.set_ui_scene_status(tr!("scene.demo_spawned"));
         }
         if let Some(mesh_key) = actions.spawn_mesh {
             self.spawn_mesh_entity(&mesh_key);
         }
+        if let Some(request) = actions.import_mesh_scene {
+            self.import_mesh_scene(request);
+        }
         if let Some(entity) = actions.delete_entity {
-            if self.ecs.despawn_entity(entity) {
-                if let Some(plugin) = self.script_plugin_mut() {
-                    plugin.forget_entity(entity);
-                }
-            }
-            self.set_selected_entity(None);
-            self.set_gizmo_interaction(None);
+            self.delete_selected_entities(entity);
+        }
+        if actions.duplicate_selection {
+            self.duplicate_selected_entities();
+        }
+        if let Some(transform) = actions.bulk_transform {
+            self.apply_bulk_transform_to_selection(&transform);
         }
         if actions.clear_particles {
             self.ecs.clear_particles();
@@ -4720,6 +5498,28 @@ impl ApplicationHandler for App {
                 manager.clear_watchdog_events(&plugin);
             }
         }
+        if !actions.plugin_reenable.is_empty() {
+            let manager = self.plugin_runtime.manager_mut();
+            for plugin in actions.plugin_reenable.drain(..) {
+                match manager.reenable_plugin(&plugin) {
+                    Ok(()) => println!("[plugin:{plugin}] quarantine lifted"),
+                    Err(err) => eprintln!("[plugin:{plugin}] re-enable failed: {err:?}"),
+                }
+            }
+        }
+        if !actions.plugin_mute.is_empty() || !actions.plugin_solo.is_empty() {
+            let manager = self.plugin_runtime.manager_mut();
+            for (plugin, muted) in actions.plugin_mute.drain(..) {
+                if let Err(err) = manager.set_plugin_muted(&plugin, muted) {
+                    eprintln!("[plugin:{plugin}] mute failed: {err:?}");
+                }
+            }
+            for (plugin, soloed) in actions.plugin_solo.drain(..) {
+                if let Err(err) = manager.set_plugin_soloed(&plugin, soloed) {
+                    eprintln!("[plugin:{plugin}] solo failed: {err:?}");
+                }
+            }
+        }
         if !actions.plugin_retry_asset_readback.is_empty() {
             let mut retry_results = Vec::new();
             {
@@ -4734,15 +5534,22 @@ impl ApplicationHandler for App {
                     Ok(Some(response)) => {
                         let bytes = response.byte_length;
                         let content_type = response.content_type.clone();
-                        self.set_ui_scene_status(format!(
-                            "Retried asset readback for {plugin}: {bytes} bytes ({content_type})"
+                        self.set_ui_scene_status(tr!(
+                            "asset.readback_retried",
+                            plugin = plugin,
+                            bytes = bytes,
+                            content_type = content_type
                         ));
                     }
                     Ok(None) => {
-                        self.set_ui_scene_status(format!("No asset readbacks recorded for {plugin}"));
+                        self.set_ui_scene_status(tr!("asset.readback_none_recorded", plugin = plugin));
                     }
                     Err(err) => {
-                        self.set_ui_scene_status(format!("Asset readback retry failed for {plugin}: {err}"));
+                        self.set_ui_scene_status(tr!(
+                            "asset.readback_retry_failed",
+                            plugin = plugin,
+                            error = err
+                        ));
                     }
                 }
             }
@@ -4750,7 +5557,7 @@ impl ApplicationHandler for App {
         if let Some(enabled) = actions.audio_set_enabled {
             match self.plugin_runtime.manager_mut().get_mut::<AudioPlugin>() {
                 Some(audio) => audio.set_enabled(enabled),
-                None => self.set_ui_scene_status("Audio plugin unavailable; cannot update audio state."),
+                None => self.set_ui_scene_status(tr!("audio.unavailable_state")),
             }
         }
         if actions.audio_spatial_enable.is_some()
@@ -4775,14 +5582,33 @@ impl ApplicationHandler for App {
                     }
                     audio.set_spatial_config(cfg);
                 }
-                None => self.set_ui_scene_status("Audio plugin unavailable; cannot update spatial audio."),
+                None => self.set_ui_scene_status(tr!("audio.unavailable_spatial")),
+            }
+        }
+        if actions.audio_lpf_cutoff.is_some() || actions.audio_hpf_cutoff.is_some() {
+            match self.plugin_runtime.manager_mut().get_mut::<AudioPlugin>() {
+                Some(audio) => {
+                    if let Some(cutoff) = actions.audio_lpf_cutoff {
+                        audio.set_low_pass_filter(cutoff);
+                    }
+                    if let Some(cutoff) = actions.audio_hpf_cutoff {
+                        audio.set_high_pass_filter(cutoff);
+                    }
+                }
+                None => self.set_ui_scene_status(tr!("audio.unavailable_filters")),
+            }
+        }
+        if let Some(count) = actions.audio_voice_count {
+            match self.audio_plugin_mut() {
+                Some(audio) => audio.set_voice_count(count),
+                None => self.set_ui_scene_status(tr!("audio.unavailable_voice_limit")),
             }
         }
         if actions.audio_clear_log {
             match self.plugin_runtime.manager_mut().get_mut::<AudioPlugin>() {
                 Some(audio) => audio.clear(),
                 None => {
-                    self.set_ui_scene_status("Audio plugin unavailable; cannot clear audio log.");
+                    self.set_ui_scene_status(tr!("audio.unavailable_clear_log"));
                 }
             }
         }
@@ -4796,12 +5622,77 @@ impl ApplicationHandler for App {
             };
             self.with_editor_ui_state_mut(|state| state.gpu_metrics_status = Some(status.to_string()));
         }
+        if let Some(enabled) = actions.alloc_sampling_enable {
+            alloc_sampler::set_enabled(enabled);
+            // Drop counts gathered before the toggle so the next sample covers a single frame.
+            alloc_sampler::take_sample();
+            self.config.editor.alloc_sampling = enabled;
+        }
+        if let Some(language) = actions.language_select {
+            match Locale::load(locale::LOCALE_DIR, &language) {
+                Ok(loaded) => {
+                    locale::set_active(loaded);
+                    self.config.editor.language = language;
+                    let name = locale::active().display_name().to_string();
+                    self.set_ui_scene_status(tr!("locale.switched", language = name));
+                }
+                Err(err) => self.set_ui_scene_status(tr!("locale.load_failed", error = format!("{err:#}"))),
+            }
+        }
+        if let Some(mode) = actions.sprite_sort_select {
+            self.renderer.set_sprite_sort_mode(mode);
+            if let Err(err) = AppConfig::persist_sprite_sort_mode(self.project.config_app_path(), mode) {
+                eprintln!("[config] failed to persist sprite sort mode: {err:#}");
+            }
+        }
+        if actions.timeline_usage_reset {
+            self.ecs.reset_sprite_timeline_usage();
+            if let Some(analytics) = self.analytics_plugin_mut() {
+                analytics.clear_timeline_usage();
+            }
+            self.with_editor_ui_state_mut(|state| {
+                state.timeline_usage = Arc::from(Vec::<SpriteTimelineUsageEntry>::new().into_boxed_slice());
+            });
+        }
+        if actions.export_analytics_session {
+            match self.export_analytics_session_json("target/analytics_session.json") {
+                Ok(path) => {
+                    self.set_ui_scene_status(tr!("telemetry.analytics_exported", path = path.display()))
+                }
+                Err(err) => self.set_ui_scene_status(tr!(
+                    "telemetry.analytics_export_failed",
+                    error = format!("{err:#}")
+                )),
+            }
+        }
+        if let Some(theme) = actions.theme_select {
+            theme.apply(&self.editor_shell.egui_ctx);
+            if let Err(err) = self.editor_ui_state_mut().set_theme(theme) {
+                eprintln!("[editor_state] failed to persist theme: {err:#}");
+            }
+        }
+        if actions.dump_untranslated_strings {
+            let active = locale::active();
+            let path =
+                PathBuf::from("target").join(format!("locale_untranslated_{}.json", active.language()));
+            match active.write_untranslated_keys(&path) {
+                Ok(count) => self.set_ui_scene_status(tr!(
+                    "locale.untranslated_dumped",
+                    count = count,
+                    path = path.display()
+                )),
+                Err(err) => self.set_ui_scene_status(tr!("locale.dump_failed", error = format!("{err:#}"))),
+            }
+        }
         if !actions.plugin_toggles.is_empty() {
             self.apply_plugin_toggles(&actions.plugin_toggles);
         }
         if actions.reload_plugins {
             self.reload_dynamic_plugins();
         }
+        if actions.unload_plugins {
+            self.unload_dynamic_plugins();
+        }
         if let (Some(ren), Some(screen)) =
             (self.editor_shell.egui_renderer.as_mut(), self.editor_shell.egui_screen.as_ref())
         {
@@ -4837,9 +5728,20 @@ impl ApplicationHandler for App {
             }
         }
 
+        self.capture_screenshot_sequence_frame();
+        self.advance_headless_run();
+
         if let Some(enabled) = vsync_request {
             self.apply_vsync_toggle(enabled);
         }
+        if let Some(level) = anisotropy_request {
+            self.apply_texture_anisotropy(level);
+            self.set_ui_scene_status(tr!("asset.texture_anisotropy", anisotropy = self.assets.anisotropy()));
+        }
+        if let Some(scale) = render_scale_request {
+            self.renderer.set_render_scale(scale);
+            self.config.window.render_scale = self.renderer.render_scale();
+        }
 
         let ui_root_spin = self.editor_ui_state().ui_root_spin;
         self.ecs.set_root_spin(ui_root_spin);
@@ -4855,6 +5757,10 @@ impl ApplicationHandler for App {
             fixed_ms: fixed_time_ms,
             render_ms: render_time_ms,
             ui_ms: ui_time_ms,
+            sprite_bytes_uploaded: sprite_upload_stats.bytes_uploaded,
+            sprite_bytes_reused: sprite_upload_stats.bytes_reused,
+            meshes_drawn: mesh_cull_counts.drawn,
+            meshes_culled: mesh_cull_counts.culled,
         });
         #[cfg(feature = "alloc_profiler")]
         if let Some(mut capture) = self.frame_budget_capture.take() {
@@ -5042,7 +5948,7 @@ impl RuntimeHost for App {
         }
         if let Some(snapshot) = self.play_snapshot.take() {
             if let Err(err) = self.restore_play_snapshot(snapshot) {
-                self.set_ui_scene_status(format!("Failed to restore play snapshot: {err}"));
+                self.set_ui_scene_status(tr!("scene.play_snapshot_restore_failed", error = err));
             }
         }
         self.runtime_loop.clear_accumulator();
@@ -5090,7 +5996,7 @@ impl RuntimeHost for App {
     fn load_scene(&mut self, path: &Path) -> Result<()> {
         let path_str = path.to_string_lossy();
         self.with_editor_ui_state_mut(|state| state.ui_scene_path = path_str.to_string());
-        self.load_scene_from_path(path_str.as_ref())
+        self.load_scene_from_path(path_str.as_ref()).map(|_| ())
     }
 
     fn save_scene(&mut self, path: &Path) -> Result<()> {
@@ -5117,14 +6023,15 @@ impl App {
         let mut deferred = Vec::new();
         for cmd in commands {
             match cmd {
-                ScriptCommand::Spawn { handle, atlas, region, position, scale, velocity } => {
-                    match self.ecs.spawn_scripted_sprite(
+                ScriptCommand::Spawn { handle, atlas, region, position, scale, velocity, lifetime } => {
+                    match self.ecs.spawn_scripted_sprite_with_lifetime(
                         &self.assets,
                         &atlas,
                         &region,
                         position,
                         scale,
                         velocity,
+                        lifetime,
                     ) {
                         Ok(entity) => {
                             self.register_script_spawn(handle, entity, None);
@@ -5183,6 +6090,21 @@ impl App {
                         deferred.push(ScriptCommand::SetTint { handle, tint });
                     }
                 }
+                ScriptCommand::SetTintCurve { handle, curve, duration } => {
+                    if let Some(entity) = self.resolve_script_handle(handle) {
+                        if !self.ecs.set_tint_curve(
+                            entity,
+                            &self.assets,
+                            &curve,
+                            duration,
+                            SpriteAnimationLoopMode::OnceStop,
+                        ) {
+                            eprintln!("[script] set_tint_curve '{curve}' failed for handle {handle}");
+                        }
+                    } else {
+                        deferred.push(ScriptCommand::SetTintCurve { handle, curve, duration });
+                    }
+                }
                 ScriptCommand::SetSpriteRegion { handle, region } => {
                     if let Some(entity) = self.resolve_script_handle(handle) {
                         if !self.ecs.set_sprite_region(entity, &self.assets, &region) {
@@ -5203,8 +6125,9 @@ impl App {
                         deferred.push(ScriptCommand::Despawn { handle });
                     }
                 }
-                ScriptCommand::SpawnPrefab { handle, path, tag } => {
-                    let load_result = Scene::load_from_path(&path).map(|scene| scene.with_fresh_entity_ids());
+                ScriptCommand::SpawnPrefab { handle, path, tag, overrides } => {
+                    let load_result =
+                        Scene::load_from_path(&path).map(|scene| self.ecs.with_allocated_entity_ids(&scene));
                     match load_result {
                         Ok(scene) => {
                             match self.ecs.instantiate_prefab_with_mesh(&scene, &mut self.assets, |key, path| {
@@ -5212,6 +6135,13 @@ impl App {
                             }) {
                                 Ok(spawned) => {
                                     if let Some(&root) = spawned.first() {
+                                        for warning in self.ecs.apply_prefab_overrides(root, &overrides) {
+                                            eprintln!("[script] prefab '{path}': {warning}");
+                                            self.push_script_console(
+                                                ScriptConsoleKind::Log,
+                                                format!("[script] prefab '{path}': {warning}"),
+                                            );
+                                        }
                                         self.register_script_spawn(handle, root, tag.clone());
                                     } else {
                                         eprintln!("[script] prefab '{path}' spawned zero entities");
@@ -5278,8 +6208,8 @@ impl App {
                         self.forget_script_handle(handle);
                         continue;
                     };
-                    let load_result =
-                        Scene::load_from_path(&entry.path).map(|scene| scene.with_fresh_entity_ids());
+                    let load_result = Scene::load_from_path(&entry.path)
+                        .map(|scene| self.ecs.with_allocated_entity_ids(&scene));
                     match load_result {
                         Ok(scene) => {
                             match self.ecs.instantiate_prefab_with_mesh(&scene, &mut self.assets, |key, path| {
@@ -5412,6 +6342,17 @@ impl App {
                         eprintln!("[script] entity_set_tint failed for entity {:?}", entity);
                     }
                 }
+                ScriptCommand::EntitySetTag { entity, tag, tagged } => {
+                    let applied = if tagged {
+                        self.ecs.set_entity_tag(entity, &tag)
+                    } else {
+                        self.ecs.remove_entity_tag(entity, &tag) || self.ecs.entity_exists(entity)
+                    };
+                    if !applied {
+                        let label = if tagged { "entity_set_tag" } else { "entity_remove_tag" };
+                        eprintln!("[script] {label} '{tag}' failed for entity {:?}", entity);
+                    }
+                }
                 ScriptCommand::EntitySetVelocity { entity, velocity } => {
                     if !self.ecs.set_velocity(entity, velocity) {
                         eprintln!("[script] entity_set_velocity failed for entity {:?}", entity);
@@ -5422,6 +6363,97 @@ impl App {
                         eprintln!("[script] entity_despawn failed for entity {:?}", entity);
                     }
                 }
+                ScriptCommand::SetClipEvents { entity, events } => {
+                    if !self.ecs.set_transform_clip_events(entity, events) {
+                        eprintln!("[script] entity_set_clip_events failed for entity {:?}", entity);
+                    }
+                }
+                ScriptCommand::SetGraphParameter { entity, name, value } => {
+                    if !self.ecs.set_graph_parameter(entity, &name, value) {
+                        eprintln!(
+                            "[script] entity_set_graph_parameter '{name}' failed for entity {:?}",
+                            entity
+                        );
+                    }
+                }
+                ScriptCommand::TriggerGraphEvent { entity, event } => {
+                    if !self.ecs.trigger_graph_event(entity, &event) {
+                        eprintln!(
+                            "[script] entity_trigger_graph_event '{event}' failed for entity {:?}",
+                            entity
+                        );
+                    }
+                }
+                ScriptCommand::RequestPath { handle, target } => {
+                    if let Some(entity) = self.resolve_script_handle(handle) {
+                        if !self.ecs.request_path(entity, target) {
+                            eprintln!("[script] request_path found no route for handle {handle}");
+                        }
+                    } else {
+                        deferred.push(ScriptCommand::RequestPath { handle, target });
+                    }
+                }
+                ScriptCommand::EmitterBurst { handle, count } => {
+                    if let Some(entity) = self.resolve_script_handle(handle) {
+                        if !self.ecs.emitter_burst(entity, count) {
+                            eprintln!("[script] emitter_burst failed for handle {handle}");
+                        }
+                    } else {
+                        deferred.push(ScriptCommand::EmitterBurst { handle, count });
+                    }
+                }
+                ScriptCommand::Burst { handle, count, speed } => {
+                    if let Some(entity) = self.resolve_script_handle(handle) {
+                        if !self.ecs.set_emitter_burst(entity, count, speed) {
+                            eprintln!("[script] set_emitter_burst failed for handle {handle}");
+                        }
+                    } else {
+                        deferred.push(ScriptCommand::Burst { handle, count, speed });
+                    }
+                }
+                ScriptCommand::PlaySound { clip, position } => {
+                    let emitter = AudioEmitter {
+                        position: position.extend(0.0),
+                        max_distance: DEFAULT_EMITTER_MAX_DISTANCE,
+                    };
+                    match self.audio_plugin_mut() {
+                        Some(audio) => audio.play_sound(&clip, Some(&emitter)),
+                        None => eprintln!("[script] play_sound '{clip}' ignored: audio plugin unavailable"),
+                    }
+                }
+                ScriptCommand::SetAudioVoiceCount { count } => match self.audio_plugin_mut() {
+                    Some(audio) => audio.set_voice_count(count),
+                    None => eprintln!("[script] set_audio_voice_count ignored: audio plugin unavailable"),
+                },
+                ScriptCommand::EmitEvent { name, payload } => {
+                    self.ecs.push_event(GameEvent::ScriptEvent { name, payload });
+                }
+                ScriptCommand::CreateMaterial { key, params } => {
+                    match self.material_registry.create_material_from_params(&key, params) {
+                        Ok(()) => {
+                            self.script_materials.insert(key);
+                        }
+                        Err(err) => eprintln!("[script] create_material '{key}' failed: {err:?}"),
+                    }
+                }
+                ScriptCommand::ReleaseMaterial { key } => {
+                    if self.script_materials.remove(&key) {
+                        self.material_registry.release(&key);
+                    } else {
+                        eprintln!("[script] release_material '{key}' ignored: not created by a script");
+                    }
+                }
+                ScriptCommand::GetWallTime => {
+                    let message = format!("wall_time {:.3}", self.runtime_loop.time().wall_clock_elapsed());
+                    self.ecs.push_event(GameEvent::ScriptMessage { message });
+                }
+                ScriptCommand::GetBoneTransform { handle, bone_name } => {
+                    if let Some(entity) = self.resolve_script_handle(handle) {
+                        self.answer_bone_transform(handle, entity, &bone_name);
+                    } else {
+                        deferred.push(ScriptCommand::GetBoneTransform { handle, bone_name });
+                    }
+                }
             }
         }
 
@@ -5472,6 +6504,21 @@ impl App {
                         eprintln!("[script] set_tint unknown handle {handle}");
                     }
                 }
+                ScriptCommand::SetTintCurve { handle, curve, duration } => {
+                    if let Some(entity) = self.resolve_script_handle(handle) {
+                        if !self.ecs.set_tint_curve(
+                            entity,
+                            &self.assets,
+                            &curve,
+                            duration,
+                            SpriteAnimationLoopMode::OnceStop,
+                        ) {
+                            eprintln!("[script] set_tint_curve '{curve}' failed for handle {handle}");
+                        }
+                    } else {
+                        eprintln!("[script] set_tint_curve unknown handle {handle}");
+                    }
+                }
                 ScriptCommand::SetSpriteRegion { handle, region } => {
                     if let Some(entity) = self.resolve_script_handle(handle) {
                         if !self.ecs.set_sprite_region(entity, &self.assets, &region) {
@@ -5492,6 +6539,40 @@ impl App {
                         eprintln!("[script] despawn unknown handle {handle}");
                     }
                 }
+                ScriptCommand::RequestPath { handle, target } => {
+                    if let Some(entity) = self.resolve_script_handle(handle) {
+                        if !self.ecs.request_path(entity, target) {
+                            eprintln!("[script] request_path found no route for handle {handle}");
+                        }
+                    } else {
+                        eprintln!("[script] request_path unknown handle {handle}");
+                    }
+                }
+                ScriptCommand::EmitterBurst { handle, count } => {
+                    if let Some(entity) = self.resolve_script_handle(handle) {
+                        if !self.ecs.emitter_burst(entity, count) {
+                            eprintln!("[script] emitter_burst failed for handle {handle}");
+                        }
+                    } else {
+                        eprintln!("[script] emitter_burst unknown handle {handle}");
+                    }
+                }
+                ScriptCommand::Burst { handle, count, speed } => {
+                    if let Some(entity) = self.resolve_script_handle(handle) {
+                        if !self.ecs.set_emitter_burst(entity, count, speed) {
+                            eprintln!("[script] set_emitter_burst failed for handle {handle}");
+                        }
+                    } else {
+                        eprintln!("[script] set_emitter_burst unknown handle {handle}");
+                    }
+                }
+                ScriptCommand::GetBoneTransform { handle, bone_name } => {
+                    if let Some(entity) = self.resolve_script_handle(handle) {
+                        self.answer_bone_transform(handle, entity, &bone_name);
+                    } else {
+                        eprintln!("[script] request_bone_transform unknown handle {handle}");
+                    }
+                }
                 _ => {}
             }
         }
@@ -1,11 +1,17 @@
 use crate::plugins::{
-    apply_manifest_builtin_toggles, apply_manifest_dynamic_toggles, EnginePlugin, ManifestBuiltinToggle,
-    ManifestBuiltinToggleOutcome, ManifestDynamicToggle, ManifestDynamicToggleOutcome, PluginContext,
+    apply_manifest_builtin_toggles, apply_manifest_config_updates, apply_manifest_dynamic_toggles,
+    EnginePlugin, ManifestBuiltinToggle, ManifestBuiltinToggleOutcome, ManifestConfigUpdate,
+    ManifestConfigUpdateOutcome, ManifestDynamicToggle, ManifestDynamicToggleOutcome, PluginContext,
     PluginManager, PluginManifest,
 };
 use anyhow::{anyhow, Result};
 use std::collections::HashSet;
-use std::path::PathBuf;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+
+/// How often the manifest's modification time is checked for hand edits.
+const MANIFEST_POLL_INTERVAL: Duration = Duration::from_secs(1);
 
 pub(crate) struct BuiltinPluginFactory {
     pub(crate) name: &'static str,
@@ -29,10 +35,16 @@ impl BuiltinPluginFactory {
 pub(crate) struct PluginToggleSummary {
     pub(crate) dynamic: ManifestDynamicToggleOutcome,
     pub(crate) builtin: ManifestBuiltinToggleOutcome,
+    pub(crate) config: ManifestConfigUpdateOutcome,
 }
 
 impl PluginToggleSummary {
     pub(crate) fn changed(&self) -> bool {
+        self.dynamic.changed || self.builtin.changed || self.config.changed
+    }
+
+    /// Whether plugins must be reloaded; config edits alone are delivered to running plugins.
+    pub(crate) fn requires_reload(&self) -> bool {
         self.dynamic.changed || self.builtin.changed
     }
 }
@@ -41,6 +53,12 @@ pub(crate) struct PluginHost {
     manifest: Option<PluginManifest>,
     manifest_path: PathBuf,
     manifest_error: Option<String>,
+    manifest_modified: Option<SystemTime>,
+    last_manifest_poll: Instant,
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|metadata| metadata.modified()).ok()
 }
 
 impl PluginHost {
@@ -54,7 +72,14 @@ impl PluginHost {
                 (None, Some(message))
             }
         };
-        Self { manifest, manifest_path, manifest_error }
+        let manifest_modified = modified_time(&manifest_path);
+        Self {
+            manifest,
+            manifest_path,
+            manifest_error,
+            manifest_modified,
+            last_manifest_poll: Instant::now(),
+        }
     }
 
     pub(crate) fn manifest(&self) -> Option<&PluginManifest> {
@@ -108,26 +133,69 @@ impl PluginHost {
         ctx: &mut PluginContext<'_>,
     ) -> Result<Vec<String>> {
         let manifest = self.manifest.clone().ok_or_else(|| anyhow!("Plugin manifest not found"))?;
-        manager.unload_dynamic_plugins(ctx);
-        manager.clear_dynamic_statuses();
-        manager.load_dynamic_from_manifest(&manifest, ctx)
+        manager.reload_dynamic_from_manifest(&manifest, ctx)
+    }
+
+    /// Unloads every dynamic plugin while leaving builtins running; the manifest is untouched, so
+    /// a later reload brings the enabled entries back. Errors when any plugin's `shutdown` failed,
+    /// after all of them have been unloaded.
+    pub(crate) fn unload_all_dynamic(
+        &mut self,
+        manager: &mut PluginManager,
+        ctx: &mut PluginContext<'_>,
+    ) -> Result<()> {
+        let shutdown_failures = manager.unload_dynamic_plugins(ctx);
+        if shutdown_failures.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow!("shutdown failed for {}", shutdown_failures.join(", ")))
+        }
     }
 
     pub(crate) fn apply_manifest_toggles(
         &mut self,
         dynamic: &[ManifestDynamicToggle],
         builtin: &[ManifestBuiltinToggle],
+        configs: &[ManifestConfigUpdate],
     ) -> Result<PluginToggleSummary> {
         let manifest = self.manifest.as_mut().ok_or_else(|| anyhow!("Plugin manifest not found"))?;
         let dynamic_outcome = apply_manifest_dynamic_toggles(manifest, dynamic);
         let builtin_outcome = apply_manifest_builtin_toggles(manifest, builtin);
-        if dynamic_outcome.changed || builtin_outcome.changed {
+        let config_outcome = apply_manifest_config_updates(manifest, configs);
+        let summary = PluginToggleSummary {
+            dynamic: dynamic_outcome,
+            builtin: builtin_outcome,
+            config: config_outcome,
+        };
+        if summary.changed() {
             manifest.save()?;
+            self.manifest_modified = modified_time(&self.manifest_path);
+        }
+        Ok(summary)
+    }
+
+    /// Reloads the manifest when its file changed on disk since the last load or save. Checks at
+    /// most once per [`MANIFEST_POLL_INTERVAL`] and returns whether a new manifest was loaded.
+    pub(crate) fn poll_manifest_changes(&mut self) -> bool {
+        if self.last_manifest_poll.elapsed() < MANIFEST_POLL_INTERVAL {
+            return false;
+        }
+        self.last_manifest_poll = Instant::now();
+        let modified = modified_time(&self.manifest_path);
+        if modified.is_none() || modified == self.manifest_modified {
+            return false;
+        }
+        match self.reload_manifest_from_disk() {
+            Ok(()) => true,
+            Err(err) => {
+                eprintln!("[plugin] manifest hot reload failed: {err:?}");
+                false
+            }
         }
-        Ok(PluginToggleSummary { dynamic: dynamic_outcome, builtin: builtin_outcome })
     }
 
     pub(crate) fn reload_manifest_from_disk(&mut self) -> Result<()> {
+        self.manifest_modified = modified_time(&self.manifest_path);
         match PluginManager::load_manifest(&self.manifest_path) {
             Ok(manifest) => {
                 self.manifest = manifest;
@@ -178,6 +246,7 @@ mod tests {
             .apply_manifest_toggles(
                 &[ManifestDynamicToggle { name: "alpha".to_string(), new_enabled: false }],
                 &[ManifestBuiltinToggle { name: "analytics".to_string(), disable: true }],
+                &[],
             )
             .expect("toggles applied");
         assert!(summary.dynamic.changed, "dynamic entry should change");
@@ -190,11 +259,36 @@ mod tests {
         assert!(reloaded.is_builtin_disabled("analytics"), "analytics disabled");
     }
 
+    #[test]
+    fn config_updates_persist_without_requiring_reload() {
+        let dir = tempdir().expect("temp dir");
+        let manifest_path = dir.path().join("plugins.json");
+        fs::write(&manifest_path, r#"{ "plugins": [{ "name": "alpha", "path": "alpha.dll" }] }"#)
+            .expect("manifest written");
+        let mut host = PluginHost::new(&manifest_path);
+        let config = serde_json::json!({ "sleep_ms": 250 });
+        let summary = host
+            .apply_manifest_toggles(
+                &[],
+                &[],
+                &[ManifestConfigUpdate { name: "alpha".to_string(), config: Some(config.clone()) }],
+            )
+            .expect("config applied");
+        assert!(summary.changed() && !summary.requires_reload(), "config edits apply in place");
+        assert_eq!(summary.config.updated, vec!["alpha".to_string()]);
+        let reloaded =
+            PluginManager::load_manifest(&manifest_path).expect("reload succeeds").expect("manifest present");
+        assert_eq!(reloaded.entries()[0].config.as_ref(), Some(&config));
+        host.last_manifest_poll =
+            Instant::now().checked_sub(MANIFEST_POLL_INTERVAL).expect("clock past poll interval");
+        assert!(!host.poll_manifest_changes(), "saving from the panel is not treated as a hand edit");
+    }
+
     #[test]
     fn apply_toggles_handles_missing_manifest() {
         let mut host = PluginHost::new("missing.json");
         host.manifest = None;
-        let err = host.apply_manifest_toggles(&[], &[]).expect_err("missing manifest errors");
+        let err = host.apply_manifest_toggles(&[], &[], &[]).expect_err("missing manifest errors");
         assert!(err.to_string().contains("manifest"), "error mentions manifest");
     }
 
@@ -69,7 +69,11 @@ impl PluginRuntime {
             feature_handle,
             inputs.selected_entity,
             capability_handle,
-        );
+        )
+        .with_asset_reload_queue(self.manager.asset_reload_handle())
+        .with_screenshot_requests(self.manager.screenshot_requests_handle())
+        .with_audio_requests(self.manager.audio_requests_handle())
+        .with_scene_custom_metadata(self.manager.scene_custom_metadata_handle());
         let result = f(&mut self.host, &mut self.manager, &mut ctx);
         drop(ctx);
         result
@@ -1,6 +1,7 @@
 use super::{editor_ui, App, BINARY_PREFABS_ENABLED};
 use crate::prefab::{PrefabFormat, PrefabStatusKind, PrefabStatusMessage};
 use crate::scene::Scene;
+use crate::tr;
 use glam::{Vec2, Vec3};
 use std::collections::HashMap;
 
@@ -10,6 +11,24 @@ impl App {
             Some(PrefabStatusMessage { kind, message: message.into() });
     }
 
+    /// Runs [`crate::prefab::PrefabLibrary::validate_all`] against the loaded assets and appends a
+    /// summary to the status bar when any prefab would fail to instantiate.
+    pub(super) fn report_prefab_validation(&mut self) {
+        let errors = self.prefab_library.validate_all(&self.assets, &self.mesh_registry);
+        let Some(first) = errors.first() else {
+            return;
+        };
+        for error in &errors {
+            eprintln!("[prefab] {error}");
+        }
+        let summary = tr!("prefab.validation_failed", count = errors.len(), first = first);
+        let mut state = self.editor_ui_state_mut();
+        state.ui_scene_status = Some(match state.ui_scene_status.take() {
+            Some(existing) => format!("{existing} | {summary}"),
+            None => summary,
+        });
+    }
+
     pub(super) fn handle_save_prefab(&mut self, request: editor_ui::PrefabSaveRequest) {
         let trimmed = request.name.trim();
         if trimmed.is_empty() {
@@ -58,6 +77,8 @@ impl App {
         match scene.save_to_path(&path) {
             Ok(()) => {
                 self.editor_ui_state_mut().prefab_name_input = sanitized_name.clone();
+                let focus = self.ecs.entity_bounds(request.entity);
+                self.queue_thumbnail(&path, focus);
                 if let Err(err) = self.prefab_library.refresh() {
                     self.set_prefab_status(
                         PrefabStatusKind::Warning,
@@ -111,7 +132,7 @@ impl App {
             );
             return;
         }
-        scene = scene.with_fresh_entity_ids();
+        scene = self.ecs.with_allocated_entity_ids(&scene);
         if let Some(target) = request.drop_target {
             match target {
                 editor_ui::PrefabDropTarget::World2D(target_2d) => {
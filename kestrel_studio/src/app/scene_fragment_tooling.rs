@@ -0,0 +1,95 @@
+use super::*;
+
+/// Native scene file picker for the fragment export/import buttons, or `None` when cancelled or
+/// no native dialog can be shown.
+fn scene_fragment_dialog(title: &str, save: bool) -> Option<PathBuf> {
+    if !Project::native_dialog_available() {
+        return None;
+    }
+    let dialog = rfd::FileDialog::new().set_title(title).add_filter("Kestrel scene", &["json", "kscene"]);
+    if save {
+        dialog.set_file_name("fragment.json").save_file()
+    } else {
+        dialog.pick_file()
+    }
+}
+
+impl App {
+    /// "Export selection as scene...": asks for a target file (falling back to the scene path field
+    /// when no native dialog is available) and writes the selection there.
+    pub(super) fn handle_export_selection(&mut self, include_children: bool) {
+        let path = match scene_fragment_dialog("Export Selection as Scene", true) {
+            Some(path) => path.to_string_lossy().into_owned(),
+            None if !Project::native_dialog_available() => self.editor_ui_state().ui_scene_path.clone(),
+            None => return,
+        };
+        match self.export_selection_to_path(&path, include_children) {
+            Ok(count) => self.set_ui_scene_status(tr!("fragment.exported", count = count, path = path)),
+            Err(err) => self.set_ui_scene_status(tr!("fragment.export_failed", error = format!("{err:#}"))),
+        }
+    }
+
+    /// "Import scene into current...": merges a scene file into the open world at the cursor (or
+    /// the origin) and selects everything it spawned.
+    pub(super) fn handle_import_scene(&mut self) {
+        let path = match scene_fragment_dialog("Import Scene into Current", false) {
+            Some(path) => path.to_string_lossy().into_owned(),
+            None if !Project::native_dialog_available() => self.editor_ui_state().ui_scene_path.clone(),
+            None => return,
+        };
+        match self.import_scene_from_path(&path) {
+            Ok(count) => self.set_ui_scene_status(tr!("fragment.imported", count = count, path = path)),
+            Err(err) => self.set_ui_scene_status(tr!("fragment.import_failed", error = format!("{err:#}"))),
+        }
+    }
+
+    pub(super) fn export_selection_to_path(&mut self, path: &str, include_children: bool) -> Result<usize> {
+        let selection = self.selected_entities();
+        if selection.is_empty() {
+            return Err(anyhow!("Nothing is selected."));
+        }
+        let mesh_source_map: HashMap<String, String> = self
+            .mesh_registry
+            .keys()
+            .filter_map(|key| {
+                self.mesh_registry
+                    .mesh_source(key)
+                    .map(|path| (key.to_string(), path.to_string_lossy().into_owned()))
+            })
+            .collect();
+        let material_source_map: HashMap<String, String> = self
+            .material_registry
+            .keys()
+            .filter_map(|key| {
+                self.material_registry.material_source(key).map(|path| (key.to_string(), path.to_string()))
+            })
+            .collect();
+        let scene = self
+            .ecs
+            .export_selection_with_sources(
+                &selection,
+                include_children,
+                &self.assets,
+                |key| mesh_source_map.get(key).cloned(),
+                |key| material_source_map.get(key).cloned(),
+            )
+            .ok_or_else(|| anyhow!("The selection contains no exportable entities."))?;
+        scene.save_to_path(path)?;
+        Ok(scene.entities.len())
+    }
+
+    pub(super) fn import_scene_from_path(&mut self, path: &str) -> Result<usize> {
+        let scene = Scene::load_from_path(path)?;
+        let cursor = self.input.cursor_world_position().map(|(x, y)| Vec2::new(x, y));
+        let spawned = self.ecs.import_scene_with_mesh(&scene, cursor, &mut self.assets, |key, path| {
+            self.mesh_registry.ensure_mesh(key, path, &mut self.material_registry)
+        })?;
+        let mut dependencies = self.editor_ui_state().scene_dependencies.clone().unwrap_or_default();
+        dependencies.merge(&scene.dependencies);
+        self.update_scene_dependencies(&dependencies)?;
+        let count = spawned.len();
+        self.set_selected_entities(spawned);
+        self.set_gizmo_interaction(None);
+        Ok(count)
+    }
+}
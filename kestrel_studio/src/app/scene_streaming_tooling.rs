@@ -0,0 +1,166 @@
+use super::*;
+use crate::scene::SceneChunkRef;
+
+/// Load margin around the camera view, as a fraction of the view's half-height.
+const CHUNK_STREAM_MARGIN_FRACTION: f32 = 0.5;
+
+#[derive(Debug, Clone)]
+pub(crate) struct SceneChunkStatus {
+    pub key: String,
+    pub path: String,
+    pub min: Vec2,
+    pub max: Vec2,
+    pub loaded: bool,
+    pub entity_count: usize,
+    pub error: Option<String>,
+}
+
+impl App {
+    /// Unloads every resident chunk and starts streaming the given chunk list (empty to stop).
+    pub(super) fn reset_scene_chunks(&mut self, chunks: &[SceneChunkRef]) {
+        for key in self.scene_chunks.loaded_keys() {
+            self.unload_scene_chunk(&key);
+        }
+        self.scene_chunks = ChunkStreamer::new(chunks);
+    }
+
+    pub(super) fn update_scene_streaming(&mut self) {
+        if self.scene_chunks.is_empty() {
+            return;
+        }
+        let Some((half_width, half_height)) = self.camera.half_extents(self.viewport_physical_size()) else {
+            return;
+        };
+        let half = Vec2::new(half_width, half_height);
+        let center = self.camera.position;
+        let plan =
+            self.scene_chunks.plan(center - half, center + half, half_height * CHUNK_STREAM_MARGIN_FRACTION);
+        for key in &plan.unload {
+            self.unload_scene_chunk(key);
+        }
+        for key in &plan.load {
+            if let Err(err) = self.load_scene_chunk(key) {
+                eprintln!("[streaming] failed to load chunk '{key}': {err:?}");
+                self.scene_chunks.set_error(key, Some(err.to_string()));
+            }
+        }
+    }
+
+    pub(super) fn scene_chunk_statuses(&mut self) -> Vec<SceneChunkStatus> {
+        let chunks: Vec<_> = self.scene_chunks.chunks().to_vec();
+        chunks
+            .into_iter()
+            .map(|chunk| SceneChunkStatus {
+                entity_count: if chunk.loaded { self.ecs.scene_chunk_entity_count(&chunk.key) } else { 0 },
+                key: chunk.key,
+                path: chunk.path,
+                min: chunk.min,
+                max: chunk.max,
+                loaded: chunk.loaded,
+                error: chunk.error,
+            })
+            .collect()
+    }
+
+    /// Opens a chunk's scene file on its own so it can be edited and saved in isolation.
+    pub(super) fn open_scene_chunk(&mut self, key: &str) {
+        let Some(path) = self.scene_chunks.chunk(key).map(|chunk| chunk.path.clone()) else {
+            return;
+        };
+        self.with_editor_ui_state_mut(|state| state.ui_scene_path = path.clone());
+        match self.load_scene_from_path(&path) {
+            Ok(warnings) => self.set_ui_scene_status(scene_load_status(
+                tr!("scene.editing_chunk", key = key, path = path),
+                &warnings,
+            )),
+            Err(err) => self.set_ui_scene_status(tr!("scene.failed_to_open_chunk", key = key, error = err)),
+        }
+    }
+
+    fn load_scene_chunk(&mut self, key: &str) -> Result<()> {
+        let path = self.scene_chunks.chunk(key).map(|chunk| chunk.path.clone()).context("Unknown chunk")?;
+        let scene = Scene::load_from_path(&path)?;
+        self.retain_chunk_dependencies(&scene.dependencies)?;
+        if let Err(err) = self.ecs.load_scene_chunk(key, &scene, &self.assets) {
+            self.release_chunk_dependencies(&scene.dependencies);
+            return Err(err);
+        }
+        self.scene_chunk_dependencies.insert(key.to_string(), scene.dependencies);
+        self.scene_chunks.set_loaded(key, true);
+        Ok(())
+    }
+
+    fn unload_scene_chunk(&mut self, key: &str) {
+        self.ecs.unload_scene_chunk(key);
+        if let Some(deps) = self.scene_chunk_dependencies.remove(key) {
+            self.release_chunk_dependencies(&deps);
+        }
+        self.scene_chunks.set_loaded(key, false);
+        if self.selected_entity().is_some_and(|entity| !self.ecs.entity_exists(entity)) {
+            self.set_selected_entity(None);
+            self.set_gizmo_interaction(None);
+        }
+    }
+
+    /// Takes one reference per chunk on each dependency, on top of whatever the master scene
+    /// holds, so overlapping chunks and the master release independently.
+    fn retain_chunk_dependencies(&mut self, deps: &SceneDependencies) -> Result<()> {
+        let mut retained = ChunkDependencyKeys::default();
+        let result = (|| -> Result<()> {
+            for dep in deps.atlas_dependencies() {
+                self.assets
+                    .retain_atlas(dep.key(), dep.path())
+                    .with_context(|| format!("Failed to retain atlas '{}'", dep.key()))?;
+                retained.atlases.push(dep.key().to_string());
+            }
+            for dep in deps.clip_dependencies() {
+                self.assets
+                    .retain_clip(dep.key(), dep.path())
+                    .with_context(|| format!("Failed to retain clip '{}'", dep.key()))?;
+                retained.clips.push(dep.key().to_string());
+            }
+            for dep in deps.mesh_dependencies() {
+                self.mesh_registry
+                    .retain_mesh(dep.key(), dep.path(), &mut self.material_registry)
+                    .with_context(|| format!("Failed to retain mesh '{}'", dep.key()))?;
+                retained.meshes.push(dep.key().to_string());
+            }
+            Ok(())
+        })();
+        if result.is_err() {
+            self.release_chunk_keys(&retained);
+        }
+        result
+    }
+
+    fn release_chunk_dependencies(&mut self, deps: &SceneDependencies) {
+        let keys = ChunkDependencyKeys {
+            atlases: deps.atlas_dependencies().map(|dep| dep.key().to_string()).collect(),
+            clips: deps.clip_dependencies().map(|dep| dep.key().to_string()).collect(),
+            meshes: deps.mesh_dependencies().map(|dep| dep.key().to_string()).collect(),
+        };
+        self.release_chunk_keys(&keys);
+    }
+
+    fn release_chunk_keys(&mut self, keys: &ChunkDependencyKeys) {
+        for key in &keys.atlases {
+            self.assets.release_atlas(key);
+            if !self.assets.has_atlas(key) {
+                self.invalidate_atlas_view(key);
+            }
+        }
+        for key in &keys.clips {
+            self.assets.release_clip(key);
+        }
+        for key in &keys.meshes {
+            self.mesh_registry.release_mesh(key, &mut self.material_registry);
+        }
+    }
+}
+
+#[derive(Default)]
+struct ChunkDependencyKeys {
+    atlases: Vec<String>,
+    clips: Vec<String>,
+    meshes: Vec<String>,
+}
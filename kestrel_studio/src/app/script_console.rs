@@ -1,15 +1,18 @@
+use std::env;
 use std::io::Write;
 use std::path::Path;
+use std::process::Command;
 use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use super::{App, ScriptConsoleEntry, ScriptConsoleKind, SCRIPT_CONSOLE_CAPACITY, SCRIPT_HISTORY_CAPACITY};
+use crate::scripts::ScriptErrorLocation;
 
 impl App {
     pub(super) fn push_script_console(&mut self, kind: ScriptConsoleKind, text: impl Into<String>) {
         let mut state = self.editor_ui_state_mut();
         let text = text.into();
-        state.script_console.push_back(ScriptConsoleEntry { kind, text: text.clone() });
+        state.script_console.push_back(ScriptConsoleEntry { kind, text: text.clone(), repeat: 1 });
         while state.script_console.len() > SCRIPT_CONSOLE_CAPACITY {
             state.script_console.pop_front();
         }
@@ -91,20 +94,69 @@ impl App {
     }
 
     pub(super) fn sync_script_error_state(&mut self) {
-        let current_error =
-            self.script_plugin().and_then(|plugin| plugin.last_error().map(|err| err.to_string()));
+        let Some((message, repeat)) = self
+            .script_plugin()
+            .and_then(|plugin| plugin.last_error_info().map(|err| (err.message.clone(), err.repeat_count)))
+        else {
+            return;
+        };
+        let text = format!("Runtime error: {message}");
         {
             let mut state = self.editor_ui_state_mut();
-            if current_error == state.last_reported_script_error {
+            let previous = state.last_reported_script_error.replace((message.clone(), repeat));
+            if previous.as_ref() == Some(&(message.clone(), repeat)) {
                 return;
             }
-            state.last_reported_script_error = current_error.clone();
+            // A repeat of the error already at the bottom of the console bumps its counter
+            // instead of appending another copy.
+            if repeat > 1 && previous.is_some_and(|(prev, _)| prev == message) {
+                if let Some(entry) = state
+                    .script_console
+                    .back_mut()
+                    .filter(|entry| matches!(entry.kind, ScriptConsoleKind::Error) && entry.text == text)
+                {
+                    entry.repeat = repeat;
+                    state.script_console_snapshot = None;
+                    return;
+                }
+            }
         }
-        if let Some(err) = current_error {
-            self.push_script_console(ScriptConsoleKind::Error, format!("Runtime error: {err}"));
-            let mut state = self.editor_ui_state_mut();
-            state.script_debugger_open = true;
-            state.script_focus_repl = true;
+        self.push_script_console(ScriptConsoleKind::Error, text);
+        let mut state = self.editor_ui_state_mut();
+        state.script_debugger_open = true;
+        state.script_focus_repl = true;
+    }
+
+    /// Launches the configured editor (or `$VISUAL`/`$EDITOR`) at the error location.
+    pub(super) fn open_script_in_editor(&mut self, location: &ScriptErrorLocation) {
+        let command = self
+            .config
+            .editor
+            .script_editor_command
+            .clone()
+            .or_else(|| env::var("VISUAL").ok())
+            .or_else(|| env::var("EDITOR").ok())
+            .filter(|command| !command.trim().is_empty());
+        let Some(command) = command else {
+            self.push_script_console(
+                ScriptConsoleKind::Error,
+                "No script editor configured; set editor.script_editor_command or $EDITOR.",
+            );
+            return;
+        };
+        let args = editor_command_args(&command, &location.script_path, location.line);
+        let Some((program, rest)) = args.split_first() else {
+            return;
+        };
+        match Command::new(program).args(rest).spawn() {
+            Ok(_) => self.push_script_console(
+                ScriptConsoleKind::Log,
+                format!("Opened {}:{} in {program}", location.script_path, location.line),
+            ),
+            Err(err) => self.push_script_console(
+                ScriptConsoleKind::Error,
+                format!("Failed to launch '{program}': {err}"),
+            ),
         }
     }
 
@@ -123,3 +175,33 @@ impl App {
         }
     }
 }
+
+/// Splits an editor command into program + args. `{file}`/`{line}` placeholders are substituted;
+/// otherwise the common `+line file` convention is appended.
+fn editor_command_args(command: &str, file: &str, line: usize) -> Vec<String> {
+    let parts: Vec<&str> = command.split_whitespace().collect();
+    if parts.iter().any(|part| part.contains("{file}") || part.contains("{line}")) {
+        return parts
+            .into_iter()
+            .map(|part| part.replace("{file}", file).replace("{line}", &line.to_string()))
+            .collect();
+    }
+    let mut args: Vec<String> = parts.into_iter().map(str::to_string).collect();
+    args.push(format!("+{line}"));
+    args.push(file.to_string());
+    args
+}
+
+#[cfg(test)]
+mod tests {
+    use super::editor_command_args;
+
+    #[test]
+    fn editor_command_appends_line_or_fills_placeholders() {
+        assert_eq!(editor_command_args("vim", "main.rhai", 212), vec!["vim", "+212", "main.rhai"]);
+        assert_eq!(
+            editor_command_args("code --goto {file}:{line}", "/p/main.rhai", 7),
+            vec!["code", "--goto", "/p/main.rhai:7"]
+        );
+    }
+}
@@ -0,0 +1,343 @@
+use super::*;
+use crate::ecs::{Parent, SceneEntityTag, Transform, Transform3DInfo};
+use crate::wrap_angle;
+use glam::{EulerRot, Mat2, Quat};
+
+/// Offset applied to duplicated entities so the copies do not sit exactly on the originals.
+const DUPLICATE_OFFSET: Vec2 = Vec2::new(0.25, -0.25);
+
+/// Relative transform applied to every selected entity at once. Rotation (radians) and scale pivot
+/// around the selection centroid; translation is added afterwards.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct BulkTransform {
+    pub translation: Vec2,
+    pub rotation: f32,
+    pub scale: Vec2,
+}
+
+impl Default for BulkTransform {
+    fn default() -> Self {
+        Self { translation: Vec2::ZERO, rotation: 0.0, scale: Vec2::ONE }
+    }
+}
+
+impl BulkTransform {
+    pub(crate) fn is_identity(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+struct SelectionEntry {
+    entity: Entity,
+    /// World-space position used for pivoting; edits are written back as a delta on `local`.
+    translation: Vec2,
+    local: Vec2,
+    rotation: f32,
+    scale: Vec2,
+    mesh: Option<Transform3DInfo>,
+}
+
+/// Transforms of a multi-selection captured before a bulk edit. Gizmo drags re-apply their running
+/// total to the snapshot every frame so the result does not drift.
+pub(crate) struct SelectionSnapshot {
+    pub centroid: Vec2,
+    entries: Vec<SelectionEntry>,
+}
+
+impl SelectionSnapshot {
+    /// Captures the selection roots: entities whose parent chain contains another selected entity
+    /// follow that ancestor instead of being transformed twice.
+    pub(crate) fn capture(ecs: &EcsWorld, entities: &[Entity]) -> Option<Self> {
+        let entries: Vec<SelectionEntry> = entities
+            .iter()
+            .copied()
+            .filter(|entity| !has_selected_ancestor(ecs, *entity, entities))
+            .filter_map(|entity| {
+                let info = ecs.entity_info(entity)?;
+                let local = ecs.world.get::<Transform>(entity)?;
+                Some(SelectionEntry {
+                    entity,
+                    translation: info.translation,
+                    local: local.translation,
+                    rotation: local.rotation,
+                    scale: local.scale,
+                    mesh: info.mesh_transform,
+                })
+            })
+            .collect();
+        if entries.is_empty() {
+            return None;
+        }
+        let centroid = entries.iter().map(|entry| entry.translation).sum::<Vec2>() / entries.len() as f32;
+        Some(Self { centroid, entries })
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Writes `snapshot + transform` back through the regular `EcsWorld` setters.
+    pub(crate) fn apply(&self, ecs: &mut EcsWorld, transform: &BulkTransform) {
+        let pivot_rotation = Mat2::from_angle(transform.rotation);
+        for entry in &self.entries {
+            let offset = pivot_rotation * ((entry.translation - self.centroid) * transform.scale);
+            let translation = self.centroid + offset + transform.translation;
+            let delta = translation - entry.translation;
+            ecs.set_translation(entry.entity, entry.local + delta);
+            ecs.set_rotation(entry.entity, wrap_angle(entry.rotation + transform.rotation));
+            let scale = entry.scale * transform.scale;
+            if scale.x.abs() >= 0.01 && scale.y.abs() >= 0.01 {
+                ecs.set_scale(entry.entity, scale);
+            }
+            if let Some(mesh) = entry.mesh.as_ref() {
+                ecs.set_mesh_translation(entry.entity, mesh.translation + delta.extend(0.0));
+                if transform.rotation != 0.0 {
+                    let rotation = Quat::from_rotation_z(transform.rotation) * mesh.rotation;
+                    let (x, y, z) = rotation.to_euler(EulerRot::XYZ);
+                    ecs.set_mesh_rotation_euler(entry.entity, Vec3::new(x, y, z));
+                }
+                if transform.scale != Vec2::ONE {
+                    let scale = mesh.scale * transform.scale.extend(1.0);
+                    ecs.set_mesh_scale(entry.entity, scale.max(Vec3::splat(0.01)));
+                }
+            }
+        }
+    }
+}
+
+fn has_selected_ancestor(ecs: &EcsWorld, entity: Entity, selection: &[Entity]) -> bool {
+    let mut current = ecs.world.get::<Parent>(entity).map(|parent| parent.0);
+    while let Some(ancestor) = current {
+        if selection.contains(&ancestor) {
+            return true;
+        }
+        current = ecs.world.get::<Parent>(ancestor).map(|parent| parent.0);
+    }
+    false
+}
+
+/// Applies `transform` to `entities` relative to their centroid. Returns how many were moved.
+pub(crate) fn apply_bulk_transform(
+    ecs: &mut EcsWorld,
+    entities: &[Entity],
+    transform: &BulkTransform,
+) -> usize {
+    let Some(snapshot) = SelectionSnapshot::capture(ecs, entities) else {
+        return 0;
+    };
+    snapshot.apply(ecs, transform);
+    snapshot.len()
+}
+
+/// Index after (or before, when `reverse`) `current` in a list of `len` entries, wrapping around.
+/// Without a current entry the cycle starts at the first (or last) one.
+fn cycle_index(len: usize, current: Option<usize>, reverse: bool) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+    Some(match (current, reverse) {
+        (Some(index), false) => (index + 1) % len,
+        (Some(index), true) => (index + len - 1) % len,
+        (None, false) => 0,
+        (None, true) => len - 1,
+    })
+}
+
+impl App {
+    /// Every selected entity that still exists; the primary selection is last.
+    pub(crate) fn selected_entities(&self) -> Vec<Entity> {
+        let state = self.editor_ui_state();
+        state.selected_entities.iter().copied().filter(|entity| self.ecs.entity_exists(*entity)).collect()
+    }
+
+    /// Replaces the selection; the last entity becomes the primary selection shown in the inspector.
+    pub(crate) fn set_selected_entities(&self, entities: Vec<Entity>) {
+        self.with_editor_ui_state_mut(|state| {
+            state.selected_entity = entities.last().copied();
+            state.selected_entities = entities;
+        });
+    }
+
+    /// Shift/Ctrl-click: adds `entity` to the selection or removes it when already selected.
+    pub(crate) fn toggle_selected_entity(&self, entity: Entity) {
+        let mut entities = self.selected_entities();
+        if let Some(index) = entities.iter().position(|selected| *selected == entity) {
+            entities.remove(index);
+        } else {
+            entities.push(entity);
+        }
+        self.set_selected_entities(entities);
+    }
+
+    /// Applies a viewport pick: Shift/Ctrl-click toggles the picked entity, a plain click replaces
+    /// the selection.
+    pub(crate) fn apply_viewport_pick(&self, picked: Option<Entity>) {
+        if self.input.shift_held() || self.input.ctrl_held() {
+            if let Some(entity) = picked {
+                self.toggle_selected_entity(entity);
+            }
+        } else {
+            self.set_selected_entities(picked.into_iter().collect());
+        }
+    }
+
+    /// Tab / Shift+Tab: selects the next (or previous) scene entity ordered by scene ID.
+    pub(crate) fn cycle_selection(&mut self, reverse: bool) {
+        let mut entities: Vec<(String, Entity)> = {
+            let mut query = self.ecs.world.query::<(Entity, &SceneEntityTag)>();
+            query.iter(&self.ecs.world).map(|(entity, tag)| (tag.id.as_str().to_string(), entity)).collect()
+        };
+        entities.sort();
+        let current = self
+            .selected_entity()
+            .and_then(|selected| entities.iter().position(|(_, entity)| *entity == selected));
+        if let Some(index) = cycle_index(entities.len(), current, reverse) {
+            self.set_selected_entities(vec![entities[index].1]);
+            self.set_gizmo_interaction(None);
+        }
+    }
+
+    pub(crate) fn has_multi_selection(&self) -> bool {
+        self.editor_ui_state().selected_entities.len() > 1
+    }
+
+    pub(crate) fn selection_centroid(&self) -> Option<Vec2> {
+        SelectionSnapshot::capture(&self.ecs, &self.selected_entities()).map(|snapshot| snapshot.centroid)
+    }
+
+    /// Union of the 2D bounds of every selected entity.
+    pub(crate) fn selection_bounds_2d(&self) -> Option<(Vec2, Vec2)> {
+        self.selected_entities()
+            .into_iter()
+            .filter_map(|entity| self.ecs.entity_bounds(entity))
+            .reduce(|(min_a, max_a), (min_b, max_b)| (min_a.min(min_b), max_a.max(max_b)))
+    }
+
+    pub(crate) fn apply_bulk_transform_to_selection(&mut self, transform: &BulkTransform) {
+        if transform.is_identity() {
+            return;
+        }
+        let entities = self.selected_entities();
+        let moved = apply_bulk_transform(&mut self.ecs, &entities, transform);
+        self.set_inspector_status(Some(tr!("selection.transformed", moved = moved)));
+    }
+
+    /// Despawns every selected entity, or just `fallback` when it is not part of the selection.
+    pub(crate) fn delete_selected_entities(&mut self, fallback: Entity) {
+        let mut entities = self.selected_entities();
+        if !entities.contains(&fallback) {
+            entities = vec![fallback];
+        }
+        for entity in entities {
+            if self.ecs.despawn_entity(entity) {
+                if let Some(plugin) = self.script_plugin_mut() {
+                    plugin.forget_entity(entity);
+                }
+            }
+        }
+        self.set_selected_entity(None);
+        self.set_gizmo_interaction(None);
+    }
+
+    /// Ctrl+D: copies every selected root (with its children) under fresh scene IDs, slightly offset,
+    /// and selects the copies.
+    pub(crate) fn duplicate_selected_entities(&mut self) {
+        let selection = self.selected_entities();
+        let mut copies = Vec::new();
+        for entity in selection.iter().copied() {
+            if has_selected_ancestor(&self.ecs, entity, &selection) {
+                continue;
+            }
+            let Some(info) = self.ecs.entity_info(entity) else {
+                continue;
+            };
+            let result = self.ecs.serialize_entity(entity, &self.assets).and_then(|json| {
+                self.ecs.deserialize_entity_with_mesh(
+                    &json,
+                    Some(info.translation + DUPLICATE_OFFSET),
+                    &mut self.assets,
+                    |key, path| self.mesh_registry.ensure_mesh(key, path, &mut self.material_registry),
+                )
+            });
+            match result {
+                Ok(copy) => copies.push(copy),
+                Err(err) => {
+                    self.set_ui_scene_status(tr!("selection.duplicate_failed", error = format!("{err:#}")));
+                    return;
+                }
+            }
+        }
+        if copies.is_empty() {
+            return;
+        }
+        self.set_ui_scene_status(tr!("selection.duplicated", count = copies.len()));
+        self.set_selected_entities(copies);
+        self.set_gizmo_interaction(None);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::{ForceFalloff, ForceFieldKind};
+
+    fn spawn_at(ecs: &mut EcsWorld, translation: Vec2) -> Entity {
+        ecs.spawn_force_field(translation, 1.0, 1.0, ForceFieldKind::Radial, ForceFalloff::None)
+    }
+
+    #[test]
+    fn cycle_index_wraps_in_both_directions() {
+        assert_eq!(cycle_index(0, None, false), None);
+        assert_eq!(cycle_index(3, None, false), Some(0));
+        assert_eq!(cycle_index(3, None, true), Some(2));
+        assert_eq!(cycle_index(3, Some(2), false), Some(0));
+        assert_eq!(cycle_index(3, Some(0), true), Some(2));
+        assert_eq!(cycle_index(3, Some(1), false), Some(2));
+    }
+
+    #[test]
+    fn bulk_translation_moves_every_selected_entity() {
+        let mut ecs = EcsWorld::new();
+        let starts = [Vec2::new(0.0, 0.0), Vec2::new(2.0, 1.0), Vec2::new(-1.0, 4.0)];
+        let selection: Vec<Entity> = starts.iter().map(|start| spawn_at(&mut ecs, *start)).collect();
+        let untouched = spawn_at(&mut ecs, Vec2::new(9.0, 9.0));
+        ecs.update(0.0);
+
+        let delta = Vec2::new(1.5, -0.5);
+        let moved = apply_bulk_transform(
+            &mut ecs,
+            &selection,
+            &BulkTransform { translation: delta, ..BulkTransform::default() },
+        );
+        ecs.update(0.0);
+
+        assert_eq!(moved, 3);
+        for (entity, start) in selection.iter().zip(starts) {
+            let info = ecs.entity_info(*entity).expect("selected entity");
+            assert!((info.translation - (start + delta)).length() < 1e-5);
+        }
+        assert_eq!(ecs.entity_info(untouched).expect("untouched").translation, Vec2::new(9.0, 9.0));
+    }
+
+    #[test]
+    fn bulk_rotation_and_scale_pivot_around_the_centroid() {
+        let mut ecs = EcsWorld::new();
+        let left = spawn_at(&mut ecs, Vec2::new(-1.0, 0.0));
+        let right = spawn_at(&mut ecs, Vec2::new(1.0, 0.0));
+        ecs.update(0.0);
+        let transform = BulkTransform {
+            rotation: std::f32::consts::FRAC_PI_2,
+            scale: Vec2::splat(2.0),
+            ..BulkTransform::default()
+        };
+        apply_bulk_transform(&mut ecs, &[left, right], &transform);
+        ecs.update(0.0);
+
+        let left_info = ecs.entity_info(left).expect("left");
+        let right_info = ecs.entity_info(right).expect("right");
+        assert!((left_info.translation - Vec2::new(0.0, -2.0)).length() < 1e-5);
+        assert!((right_info.translation - Vec2::new(0.0, 2.0)).length() < 1e-5);
+        assert!((right_info.rotation - std::f32::consts::FRAC_PI_2).abs() < 1e-5);
+        assert_eq!(right_info.scale, Vec2::splat(2.0));
+    }
+}
@@ -0,0 +1,117 @@
+use crate::project::Project;
+use anyhow::Result;
+use std::path::Path;
+
+/// Which scene `App::load_startup_scene` ended up with.
+#[derive(Debug)]
+pub(super) enum StartupSceneOutcome<W> {
+    /// The `startup_scene` from the app config loaded.
+    Configured { path: String, warnings: W },
+    /// The project's startup scene loaded; `fallback_note` is set when the configured scene
+    /// failed first.
+    Project { path: String, warnings: W, fallback_note: Option<String> },
+    /// Nothing was configured and the project's startup scene failed to load.
+    ProjectFailed { error: anyhow::Error },
+    /// The configured scene failed with no project scene to fall back on, so the demo scene
+    /// replaces it.
+    DemoFallback { note: String },
+    /// Nothing is configured and the project has no startup scene.
+    NoScene,
+}
+
+/// Tries the configured startup scene, then the project's startup scene at `project_scene`,
+/// loading each through `load`. Kept apart from `App` so the fallback order can be tested without
+/// a window.
+pub(super) fn resolve_startup_scene<W>(
+    configured: Option<&str>,
+    project_scene: &Path,
+    mut load: impl FnMut(&str) -> Result<W>,
+) -> StartupSceneOutcome<W> {
+    let mut fallback_note = None;
+    if let Some(configured) = configured {
+        match load(configured) {
+            Ok(warnings) => {
+                return StartupSceneOutcome::Configured { path: configured.to_string(), warnings };
+            }
+            Err(err) => {
+                eprintln!("[scene] Failed to load configured startup scene {configured}: {err:?}");
+                fallback_note = Some(format!("Startup scene {configured} failed to load ({err})"));
+            }
+        }
+    }
+    if project_scene.exists() {
+        let path = Project::display_path(project_scene);
+        match load(&path) {
+            Ok(warnings) => return StartupSceneOutcome::Project { path, warnings, fallback_note },
+            Err(err) => {
+                eprintln!("[scene] Failed to load startup scene {path}: {err:?}");
+                if fallback_note.is_none() {
+                    return StartupSceneOutcome::ProjectFailed { error: err };
+                }
+            }
+        }
+    }
+    match fallback_note {
+        Some(note) => StartupSceneOutcome::DemoFallback { note },
+        None => StartupSceneOutcome::NoScene,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scene::Scene;
+    use tempfile::tempdir;
+
+    fn load_entity_count(path: &str) -> Result<usize> {
+        Ok(Scene::load_from_path(path)?.entities.len())
+    }
+
+    #[test]
+    fn configured_scene_wins_over_the_project_scene() {
+        let dir = tempdir().expect("tempdir");
+        let configured = dir.path().join("configured.json");
+        let project_scene = dir.path().join("project.json");
+        Scene::default().save_to_path(&configured).expect("save configured scene");
+        Scene::default().save_to_path(&project_scene).expect("save project scene");
+
+        let configured = configured.display().to_string();
+        match resolve_startup_scene(Some(&configured), &project_scene, load_entity_count) {
+            StartupSceneOutcome::Configured { path, warnings } => {
+                assert_eq!(path, configured);
+                assert_eq!(warnings, 0);
+            }
+            other => panic!("unexpected outcome {other:?}"),
+        }
+    }
+
+    #[test]
+    fn broken_configured_scene_falls_back_to_project_then_demo() {
+        let dir = tempdir().expect("tempdir");
+        let missing = dir.path().join("missing.json").display().to_string();
+        let project_scene = dir.path().join("project.json");
+        Scene::default().save_to_path(&project_scene).expect("save project scene");
+
+        match resolve_startup_scene(Some(&missing), &project_scene, load_entity_count) {
+            StartupSceneOutcome::Project { path, fallback_note, .. } => {
+                assert_eq!(path, Project::display_path(&project_scene));
+                assert!(fallback_note.is_some_and(|note| note.contains(&missing)));
+            }
+            other => panic!("unexpected outcome {other:?}"),
+        }
+
+        std::fs::write(&project_scene, "not json").expect("corrupt project scene");
+        assert!(matches!(
+            resolve_startup_scene(Some(&missing), &project_scene, load_entity_count),
+            StartupSceneOutcome::DemoFallback { note } if note.contains(&missing)
+        ));
+        assert!(matches!(
+            resolve_startup_scene(None, &project_scene, load_entity_count),
+            StartupSceneOutcome::ProjectFailed { .. }
+        ));
+        assert!(matches!(
+            resolve_startup_scene(None, &dir.path().join("absent.json"), load_entity_count),
+            StartupSceneOutcome::NoScene
+        ));
+    }
+}
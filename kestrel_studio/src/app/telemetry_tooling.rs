@@ -1,20 +1,27 @@
 use std::cmp::Ordering;
-use std::collections::{HashMap, VecDeque};
+use std::collections::{BTreeMap, HashMap, VecDeque};
 use std::sync::Arc;
 
+use anyhow::{anyhow, Result};
+
 use super::{editor_ui, App, FrameTimingSample};
 #[cfg(feature = "alloc_profiler")]
 use crate::alloc_profiler;
 use crate::assets::AssetManager;
 use crate::environment::EnvironmentRegistry;
+use crate::gpu_baseline::{
+    GpuAdapterIdentity, GpuBaselineReport, GpuBaselineSnapshot, GpuBaselineTolerances, GpuTimingAccumulator,
+    DEFAULT_BASELINE_PATH,
+};
 use crate::mesh_registry::MeshRegistry;
 use crate::prefab::PrefabLibrary;
-use crate::renderer::GpuPassTiming;
+use crate::renderer::{GpuMemoryReport, GpuPassTiming};
+use crate::tr;
 use egui_plot as eplot;
 
 #[derive(Default)]
 pub(super) struct TelemetryCache {
-    mesh_keys: VersionedTelemetry<Arc<[String]>>,
+    mesh_groups: VersionedTelemetry<Arc<[editor_ui::MeshKeyGroup]>>,
     mesh_subsets: VersionedTelemetry<Arc<HashMap<String, Arc<[editor_ui::MeshSubsetEntry]>>>>,
     environment_options: VersionedTelemetry<Arc<[(String, String)]>>,
     prefab_entries: VersionedTelemetry<Arc<[editor_ui::PrefabShelfEntry]>>,
@@ -27,11 +34,22 @@ pub(super) struct TelemetryCache {
 }
 
 impl TelemetryCache {
-    pub(super) fn mesh_keys(&mut self, registry: &MeshRegistry) -> Arc<[String]> {
-        self.mesh_keys.get_or_update(registry.version(), || {
-            let mut keys = registry.keys().map(|k| k.to_string()).collect::<Vec<_>>();
-            keys.sort();
-            Arc::from(keys.into_boxed_slice())
+    /// Mesh keys grouped by source file (built-in meshes first), each group sorted by key.
+    pub(super) fn mesh_groups(&mut self, registry: &MeshRegistry) -> Arc<[editor_ui::MeshKeyGroup]> {
+        self.mesh_groups.get_or_update(registry.version(), || {
+            let mut by_source: BTreeMap<Option<String>, Vec<String>> = BTreeMap::new();
+            for key in registry.keys() {
+                let source = registry.mesh_source(key).map(|path| path.display().to_string());
+                by_source.entry(source).or_default().push(key.to_string());
+            }
+            let groups: Vec<editor_ui::MeshKeyGroup> = by_source
+                .into_iter()
+                .map(|(source, mut keys)| {
+                    keys.sort();
+                    editor_ui::MeshKeyGroup { source, keys }
+                })
+                .collect();
+            Arc::from(groups.into_boxed_slice())
         })
     }
 
@@ -88,6 +106,7 @@ impl TelemetryCache {
                     editor_ui::PrefabShelfEntry {
                         name: entry.name.clone(),
                         format: entry.format,
+                        path: entry.path.clone(),
                         path_display: relative,
                     }
                 })
@@ -268,6 +287,17 @@ impl App {
         self.editor_ui_state().frame_profiler.latest()
     }
 
+    pub fn gpu_memory_report(&self) -> GpuMemoryReport {
+        GpuMemoryReport {
+            atlas_bytes: self.assets.atlas_gpu_bytes(),
+            mesh_bytes: self.mesh_registry.gpu_memory_bytes(),
+            material_bytes: self.material_registry.gpu_memory_bytes(),
+            environment_bytes: self.environment_registry.gpu_memory_bytes(),
+            compressed_texture_bytes: self.assets.compressed_atlas_gpu_bytes()
+                + self.material_registry.compressed_gpu_memory_bytes(),
+        }
+    }
+
     pub(crate) fn update_gpu_timing_snapshots(&self, timings: Vec<GpuPassTiming>) {
         if timings.is_empty() {
             return;
@@ -367,9 +397,7 @@ impl App {
                 let snapshot = self.capture_frame_budget_snapshot();
                 self.with_editor_ui_state_mut(|state| {
                     state.frame_budget_idle_snapshot = Some(snapshot);
-                    state.frame_budget_status = Some(
-                        "Idle baseline captured. Toggle panels, then capture the panel snapshot.".to_string(),
-                    );
+                    state.frame_budget_status = Some(tr!("telemetry.idle_baseline_captured"));
                 });
             }
             FrameBudgetAction::CapturePanel => {
@@ -389,9 +417,36 @@ impl App {
                 self.with_editor_ui_state_mut(|state| {
                     state.frame_budget_idle_snapshot = None;
                     state.frame_budget_panel_snapshot = None;
-                    state.frame_budget_status = Some("Cleared frame budget snapshots.".to_string());
+                    state.frame_budget_status = Some(tr!("telemetry.snapshots_cleared"));
                 });
             }
         }
     }
+
+    /// Compares the analytics window of GPU pass timings against the stored baseline and keeps
+    /// the per-pass report for the telemetry panel.
+    pub(super) fn compare_gpu_baseline(&mut self) {
+        let lines = match self.gpu_baseline_report() {
+            Ok(report) => report.lines(),
+            Err(err) => vec![format!("GPU baseline comparison failed: {err:#}")],
+        };
+        self.with_editor_ui_state_mut(|state| state.gpu_baseline_report = Some(Arc::from(lines)));
+    }
+
+    fn gpu_baseline_report(&mut self) -> Result<GpuBaselineReport> {
+        let baseline = GpuBaselineSnapshot::load(DEFAULT_BASELINE_PATH)?;
+        let timings = self
+            .analytics_plugin_mut()
+            .map(|analytics| analytics.gpu_timings_snapshot())
+            .ok_or_else(|| anyhow!("analytics plugin unavailable"))?;
+        let mut accumulator = GpuTimingAccumulator::default();
+        for (label, samples) in timings.iter() {
+            accumulator.record_pass_samples(label, samples);
+        }
+        let particles = self.ecs.particle_budget_metrics();
+        accumulator.record_scene_metrics(self.renderer.light_cluster_metrics(), &particles);
+        let mut current = accumulator.snapshot("studio", "live", "working tree");
+        current.adapter = self.renderer.adapter_info().map(GpuAdapterIdentity::from_adapter_info);
+        GpuBaselineReport::compare(&baseline, &current, &GpuBaselineTolerances::default())
+    }
 }
@@ -0,0 +1,328 @@
+use super::*;
+use image::imageops::FilterType;
+use image::RgbaImage;
+use std::sync::mpsc;
+use std::thread;
+
+pub(crate) const THUMBNAIL_SIZE: u32 = 128;
+const THUMBNAIL_DIR: &str = ".kestrel/thumbnails";
+
+/// A viewport capture waiting for the next rendered frame.
+#[derive(Debug, Clone)]
+pub(super) struct ThumbnailRequest {
+    pub(super) source: PathBuf,
+    pub(super) hash: String,
+    /// World-space bounds to frame; `None` keeps the current viewport camera.
+    pub(super) focus: Option<(Vec2, Vec2)>,
+}
+
+enum ThumbnailJob {
+    Encode { source: PathBuf, hash: String, frame: RgbaImage, crop: [u32; 4], path: PathBuf },
+    Load { source: PathBuf, hash: String, path: PathBuf },
+}
+
+struct ThumbnailResult {
+    source: PathBuf,
+    hash: String,
+    image: Result<RgbaImage>,
+}
+
+fn run_thumbnail_job(job: ThumbnailJob) -> ThumbnailResult {
+    match job {
+        ThumbnailJob::Encode { source, hash, frame, crop, path } => {
+            let image = fit_thumbnail(&frame, crop);
+            let saved = path
+                .parent()
+                .map_or(Ok(()), fs::create_dir_all)
+                .map_err(anyhow::Error::from)
+                .and_then(|_| image.save(&path).map_err(anyhow::Error::from))
+                .with_context(|| format!("Writing thumbnail '{}'", path.display()));
+            ThumbnailResult { source, hash, image: saved.map(|_| image) }
+        }
+        ThumbnailJob::Load { source, hash, path } => {
+            let image = image::open(&path)
+                .map(|image| image.to_rgba8())
+                .with_context(|| format!("Reading thumbnail '{}'", path.display()));
+            ThumbnailResult { source, hash, image }
+        }
+    }
+}
+
+/// Crops `frame` to `[x, y, width, height]` and scales the result to a square thumbnail.
+pub(super) fn fit_thumbnail(frame: &RgbaImage, crop: [u32; 4]) -> RgbaImage {
+    let [x, y, width, height] = crop;
+    let cropped = image::imageops::crop_imm(frame, x, y, width, height).to_image();
+    image::imageops::resize(&cropped, THUMBNAIL_SIZE, THUMBNAIL_SIZE, FilterType::Triangle)
+}
+
+/// Largest square centred in the viewport rectangle, clamped to the frame.
+pub(super) fn centered_square(
+    origin: Vec2,
+    size: Vec2,
+    frame_width: u32,
+    frame_height: u32,
+) -> Option<[u32; 4]> {
+    let min = origin.max(Vec2::ZERO);
+    let max = (origin + size).min(Vec2::new(frame_width as f32, frame_height as f32));
+    let extent = max - min;
+    let side = extent.min_element().floor();
+    if side < 1.0 {
+        return None;
+    }
+    let corner = min + (extent - Vec2::splat(side)) * 0.5;
+    Some([corner.x as u32, corner.y as u32, side as u32, side as u32])
+}
+
+fn content_hash(path: &Path) -> Option<String> {
+    let bytes = fs::read(path).ok()?;
+    Some(blake3::hash(&bytes).to_hex()[..16].to_string())
+}
+
+/// Thumbnails for prefabs and scenes, cached on disk by content hash and uploaded to egui once
+/// decoded. Captures are queued for the next frame; cropping, scaling, and PNG encoding run on a
+/// worker thread.
+pub(super) struct ThumbnailCache {
+    dir: PathBuf,
+    pending: VecDeque<ThumbnailRequest>,
+    jobs: Option<mpsc::Sender<ThumbnailJob>>,
+    results: Option<mpsc::Receiver<ThumbnailResult>>,
+    /// Latest content hash per source (`None` when unreadable); results for older hashes are
+    /// discarded.
+    hashes: HashMap<PathBuf, Option<String>>,
+    textures: HashMap<PathBuf, egui::TextureHandle>,
+    snapshot: Option<Arc<HashMap<PathBuf, egui::TextureHandle>>>,
+}
+
+impl ThumbnailCache {
+    pub(super) fn new(project_root: &Path) -> Self {
+        Self {
+            dir: project_root.join(THUMBNAIL_DIR),
+            pending: VecDeque::new(),
+            jobs: None,
+            results: None,
+            hashes: HashMap::new(),
+            textures: HashMap::new(),
+            snapshot: None,
+        }
+    }
+
+    fn thumbnail_path(&self, hash: &str) -> PathBuf {
+        self.dir.join(format!("{hash}.png"))
+    }
+
+    fn submit(&mut self, job: ThumbnailJob) {
+        if self.jobs.is_none() {
+            let (job_tx, job_rx) = mpsc::channel::<ThumbnailJob>();
+            let (result_tx, result_rx) = mpsc::channel();
+            let spawned = thread::Builder::new().name("thumbnail-encoder".into()).spawn(move || {
+                while let Ok(job) = job_rx.recv() {
+                    if result_tx.send(run_thumbnail_job(job)).is_err() {
+                        break;
+                    }
+                }
+            });
+            if spawned.is_err() {
+                eprintln!("[thumbnail] failed to spawn encoder thread");
+                return;
+            }
+            self.jobs = Some(job_tx);
+            self.results = Some(result_rx);
+        }
+        if let Some(jobs) = self.jobs.as_ref() {
+            let _ = jobs.send(job);
+        }
+    }
+
+    /// Records the current content of `source`, loading its cached thumbnail if one exists.
+    /// Returns the hash when the thumbnail still needs to be rendered.
+    fn refresh_source(&mut self, source: &Path) -> Option<String> {
+        let hash = content_hash(source);
+        if self.hashes.get(source) == Some(&hash) {
+            return None;
+        }
+        self.hashes.insert(source.to_path_buf(), hash.clone());
+        if self.textures.remove(source).is_some() {
+            self.snapshot = None;
+        }
+        let hash = hash?;
+        let path = self.thumbnail_path(&hash);
+        if path.is_file() {
+            self.submit(ThumbnailJob::Load { source: source.to_path_buf(), hash, path });
+            None
+        } else {
+            Some(hash)
+        }
+    }
+
+    /// Queues a capture for `source` unless a thumbnail for its current content is already cached.
+    pub(super) fn request(&mut self, source: &Path, focus: Option<(Vec2, Vec2)>) {
+        if let Some(hash) = self.refresh_source(source) {
+            self.pending.retain(|pending| pending.source != source);
+            self.pending.push_back(ThumbnailRequest { source: source.to_path_buf(), hash, focus });
+        }
+    }
+
+    /// Picks up cached thumbnails for sources that were saved in an earlier session. Sources
+    /// without a cached image simply show no thumbnail until they are saved again.
+    pub(super) fn track_sources<'a>(&mut self, sources: impl IntoIterator<Item = &'a Path>) {
+        for source in sources {
+            if !self.hashes.contains_key(source) {
+                self.refresh_source(source);
+            }
+        }
+    }
+
+    /// Drops every uploaded thumbnail so `track_sources` loads them again from the disk cache,
+    /// e.g. after the egui painter was rebuilt on a new GPU device.
+    pub(super) fn reload_textures(&mut self) {
+        self.hashes.clear();
+        self.textures.clear();
+        self.snapshot = None;
+    }
+
+    pub(super) fn take_pending(&mut self) -> Option<ThumbnailRequest> {
+        self.pending.pop_front()
+    }
+
+    pub(super) fn clear_pending(&mut self) {
+        self.pending.clear();
+    }
+
+    pub(super) fn submit_capture(&mut self, request: ThumbnailRequest, frame: RgbaImage, crop: [u32; 4]) {
+        let path = self.thumbnail_path(&request.hash);
+        self.submit(ThumbnailJob::Encode { source: request.source, hash: request.hash, frame, crop, path });
+    }
+
+    /// Uploads finished thumbnails whose hash still matches the source's current content.
+    pub(super) fn poll(&mut self, ctx: &egui::Context) {
+        let Some(results) = self.results.as_ref() else {
+            return;
+        };
+        let finished: Vec<ThumbnailResult> = results.try_iter().collect();
+        for result in finished {
+            if self.hashes.get(&result.source).and_then(Option::as_ref) != Some(&result.hash) {
+                continue;
+            }
+            match result.image {
+                Ok(image) => {
+                    let size = [image.width() as usize, image.height() as usize];
+                    let color = egui::ColorImage::from_rgba_unmultiplied(size, image.as_raw());
+                    let name = format!("thumbnail:{}", result.hash);
+                    let texture = ctx.load_texture(name, color, egui::TextureOptions::LINEAR);
+                    self.textures.insert(result.source, texture);
+                    self.snapshot = None;
+                }
+                Err(err) => eprintln!("[thumbnail] {err:?}"),
+            }
+        }
+    }
+
+    pub(super) fn textures(&mut self) -> Arc<HashMap<PathBuf, egui::TextureHandle>> {
+        Arc::clone(self.snapshot.get_or_insert_with(|| Arc::new(self.textures.clone())))
+    }
+}
+
+impl App {
+    /// Queues a thumbnail for a freshly saved prefab or scene. Skipped when there is no editor UI
+    /// to show it in.
+    pub(super) fn queue_thumbnail(&mut self, source: &Path, focus: Option<(Vec2, Vec2)>) {
+        if self.editor_shell.egui_winit.is_none() {
+            return;
+        }
+        self.thumbnails.request(source, focus);
+    }
+
+    /// Routes this frame offscreen when a thumbnail capture is waiting.
+    pub(super) fn begin_thumbnail_capture(&mut self) -> Option<ThumbnailRequest> {
+        if self.editor_shell.egui_winit.is_none() {
+            self.thumbnails.clear_pending();
+            return None;
+        }
+        let request = self.thumbnails.take_pending()?;
+        if !self.renderer.offscreen_capture() {
+            if let Err(err) = self.renderer.set_offscreen_capture(true) {
+                eprintln!("[thumbnail] offscreen capture unavailable: {err:?}");
+                self.thumbnails.clear_pending();
+                return None;
+            }
+        }
+        Some(request)
+    }
+
+    /// View for the capture frame: frames the request's focus bounds, or the live camera otherwise.
+    pub(super) fn thumbnail_view_projection(
+        &self,
+        request: &ThumbnailRequest,
+        viewport_size: PhysicalSize<u32>,
+    ) -> Mat4 {
+        let Some((min, max)) = request.focus else {
+            return self.camera.view_projection(viewport_size);
+        };
+        let mut camera = self.camera.clone();
+        camera.shake = Default::default();
+        camera.position = (min + max) * 0.5;
+        let half = ((max - min) * 0.5).max_element().max(0.05) * 1.4;
+        camera.set_zoom(CAMERA_BASE_HALF_HEIGHT / half);
+        camera.view_projection(viewport_size)
+    }
+
+    /// Reads the world render back before the editor UI is painted over it and hands it to the
+    /// encoder thread.
+    pub(super) fn finish_thumbnail_capture(&mut self, request: ThumbnailRequest) {
+        match self.renderer.read_offscreen_frame() {
+            Ok(frame) => {
+                match centered_square(self.viewport.origin, self.viewport.size, frame.width(), frame.height())
+                {
+                    Some(crop) => self.thumbnails.submit_capture(request, frame, crop),
+                    None => eprintln!("[thumbnail] viewport too small to capture"),
+                }
+            }
+            Err(err) => eprintln!("[thumbnail] capture failed: {err:?}"),
+        }
+        self.restore_output_after_thumbnail();
+    }
+
+    /// Sends frames back to the window unless a screenshot sequence still needs them offscreen.
+    pub(super) fn restore_output_after_thumbnail(&mut self) {
+        if self.screenshot_sequence.is_some() {
+            return;
+        }
+        if let Err(err) = self.renderer.set_offscreen_capture(false) {
+            eprintln!("[thumbnail] failed to restore window output: {err:?}");
+        }
+    }
+
+    pub(super) fn poll_thumbnails(&mut self) {
+        if self.editor_shell.egui_winit.is_none() {
+            return;
+        }
+        self.thumbnails.poll(&self.editor_shell.egui_ctx);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn centered_square_fits_inside_viewport_and_frame() {
+        let crop = centered_square(Vec2::new(100.0, 20.0), Vec2::new(400.0, 300.0), 640, 480).unwrap();
+        assert_eq!(crop, [150, 20, 300, 300]);
+        let clamped = centered_square(Vec2::new(-10.0, 0.0), Vec2::new(100.0, 400.0), 80, 60).unwrap();
+        assert_eq!(clamped, [10, 0, 60, 60]);
+        assert!(centered_square(Vec2::ZERO, Vec2::new(0.5, 10.0), 64, 64).is_none());
+    }
+
+    #[test]
+    fn fit_thumbnail_scales_crop_to_thumbnail_size() {
+        let mut frame = RgbaImage::from_pixel(64, 32, image::Rgba([0, 0, 0, 255]));
+        for y in 0..32 {
+            for x in 16..48 {
+                frame.put_pixel(x, y, image::Rgba([200, 40, 10, 255]));
+            }
+        }
+        let thumbnail = fit_thumbnail(&frame, [16, 0, 32, 32]);
+        assert_eq!(thumbnail.dimensions(), (THUMBNAIL_SIZE, THUMBNAIL_SIZE));
+        assert!(thumbnail.pixels().all(|pixel| pixel.0 == [200, 40, 10, 255]));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::ecs::{ColliderGeometry, ColliderKind, MIN_COLLIDER_HALF_EXTENT};
 use bevy_ecs::prelude::Entity;
 use glam::{Quat, Vec2, Vec3};
 
@@ -15,6 +16,7 @@ pub(crate) const SCALE_MAX_RATIO: f32 = 20.0;
 pub(crate) const SCALE_SNAP_STEP: f32 = 0.1;
 pub(crate) const TRANSLATE_SNAP_STEP: f32 = 0.05;
 pub(crate) const ROTATE_SNAP_STEP_RADIANS: f32 = 15.0_f32.to_radians();
+pub(crate) const GIZMO_COLLIDER_GRIP_RADIUS_PX: f32 = 7.0;
 
 #[derive(Copy, Clone, PartialEq, Eq, Default)]
 pub(crate) enum GizmoMode {
@@ -61,6 +63,21 @@ pub(crate) enum GizmoInteraction {
         start_distance: f32,
         plane_normal: Vec3,
     },
+    /// 2D drag on a multi-selection around its centroid; the selection is re-posed from the
+    /// snapshot taken when the drag started.
+    Bulk {
+        mode: GizmoMode,
+        centroid: Vec2,
+        start_pointer: Vec2,
+    },
+    /// Collider edit mode drag on one grip; the geometry is rebuilt from `start` every frame.
+    Collider {
+        entity: Entity,
+        grip: ColliderGrip,
+        origin: Vec2,
+        start: ColliderGeometry,
+        start_pointer: Vec2,
+    },
 }
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -106,6 +123,101 @@ impl ScaleHandle {
     }
 }
 
+/// Grab points drawn on a collider in collider edit mode.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ColliderGrip {
+    Left,
+    Right,
+    Bottom,
+    Top,
+    Radius,
+    /// Moves the whole shape, changing only its offset.
+    Center,
+}
+
+impl ColliderGrip {
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            ColliderGrip::Left => "left edge",
+            ColliderGrip::Right => "right edge",
+            ColliderGrip::Bottom => "bottom edge",
+            ColliderGrip::Top => "top edge",
+            ColliderGrip::Radius => "radius",
+            ColliderGrip::Center => "offset",
+        }
+    }
+}
+
+/// World positions of the grips for a collider on an entity whose origin is at `origin`. Edge and
+/// radius grips come first so they win over the centre grip on tiny colliders.
+pub(crate) fn collider_grips(geometry: &ColliderGeometry, origin: Vec2) -> Vec<(ColliderGrip, Vec2)> {
+    let center = origin + geometry.offset;
+    let mut grips = match geometry.kind {
+        ColliderKind::Rect => vec![
+            (ColliderGrip::Left, center - Vec2::new(geometry.half.x, 0.0)),
+            (ColliderGrip::Right, center + Vec2::new(geometry.half.x, 0.0)),
+            (ColliderGrip::Bottom, center - Vec2::new(0.0, geometry.half.y)),
+            (ColliderGrip::Top, center + Vec2::new(0.0, geometry.half.y)),
+        ],
+        ColliderKind::Circle => vec![(ColliderGrip::Radius, center + Vec2::new(geometry.radius(), 0.0))],
+    };
+    grips.push((ColliderGrip::Center, center));
+    grips
+}
+
+/// Geometry after dragging `grip` from `start_pointer` to `pointer`. Edge drags keep the opposite
+/// edge in place, so the offset shifts by half the resize. With `snap`, the dragged edge, radius or
+/// offset lands on a multiple of [`TRANSLATE_SNAP_STEP`], measured from the entity origin.
+pub(crate) fn drag_collider_grip(
+    start: ColliderGeometry,
+    grip: ColliderGrip,
+    origin: Vec2,
+    start_pointer: Vec2,
+    pointer: Vec2,
+    snap: bool,
+) -> ColliderGeometry {
+    let snap_value = |value: f32| {
+        if snap {
+            (value / TRANSLATE_SNAP_STEP).round() * TRANSLATE_SNAP_STEP
+        } else {
+            value
+        }
+    };
+    let delta = pointer - start_pointer;
+    let min_size = MIN_COLLIDER_HALF_EXTENT * 2.0;
+    let (min, max) = (start.offset - start.half, start.offset + start.half);
+    let mut geometry = start;
+    match grip {
+        ColliderGrip::Left | ColliderGrip::Right => {
+            let (left, right) = if grip == ColliderGrip::Left {
+                (snap_value(min.x + delta.x).min(max.x - min_size), max.x)
+            } else {
+                (min.x, snap_value(max.x + delta.x).max(min.x + min_size))
+            };
+            geometry.half.x = (right - left) * 0.5;
+            geometry.offset.x = (right + left) * 0.5;
+        }
+        ColliderGrip::Bottom | ColliderGrip::Top => {
+            let (bottom, top) = if grip == ColliderGrip::Bottom {
+                (snap_value(min.y + delta.y).min(max.y - min_size), max.y)
+            } else {
+                (min.y, snap_value(max.y + delta.y).max(min.y + min_size))
+            };
+            geometry.half.y = (top - bottom) * 0.5;
+            geometry.offset.y = (top + bottom) * 0.5;
+        }
+        ColliderGrip::Radius => {
+            let radius = snap_value(pointer.distance(origin + start.offset)).max(MIN_COLLIDER_HALF_EXTENT);
+            geometry.half = Vec2::splat(radius);
+        }
+        ColliderGrip::Center => {
+            geometry.offset =
+                Vec2::new(snap_value(start.offset.x + delta.x), snap_value(start.offset.y + delta.y));
+        }
+    }
+    geometry
+}
+
 pub(crate) fn apply_scale_ratio(ratio: f32, snap: bool) -> f32 {
     let clamped = ratio.clamp(SCALE_MIN_RATIO, SCALE_MAX_RATIO);
     if snap {
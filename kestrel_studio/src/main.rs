@@ -1,11 +1,16 @@
 use anyhow::{anyhow, Result};
 use kestrel_engine::cli::CliOverrides;
+use kestrel_engine::config::AppConfig;
+use kestrel_engine::scene_screenshot::{render_scene_screenshot, ScreenshotArgs};
 use kestrel_studio::project::Project;
 use kestrel_studio::run_with_project;
 use std::env;
 use std::path::PathBuf;
 
 fn main() {
+    if env::args().nth(1).as_deref() == Some("--screenshot") {
+        std::process::exit(run_screenshot());
+    }
     let (project_path, cli_overrides) = match parse_args() {
         Ok(result) => result,
         Err(err) => {
@@ -17,6 +22,36 @@ fn main() {
     Project::record_recent(&project.manifest_path_or_default());
     if let Err(err) = pollster::block_on(run_with_project(project, cli_overrides)) {
         eprintln!("Application error: {err:?}");
+        std::process::exit(1);
+    }
+}
+
+/// Renders one frame of a scene offscreen and writes it as a PNG, without opening a window.
+fn run_screenshot() -> i32 {
+    let mut args = match ScreenshotArgs::parse(env::args().skip(2)) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("[cli] {err}");
+            return 2;
+        }
+    };
+    // Draw sprites in the order the editor would for this project.
+    args.sprite_sort_mode = AppConfig::load_or_default("config/app.json").render.sprite_sort_mode;
+    match pollster::block_on(render_scene_screenshot(&args)) {
+        Ok(()) => {
+            println!(
+                "[screenshot] Wrote {}x{} capture of {} to {}",
+                args.width,
+                args.height,
+                args.scene.display(),
+                args.output.display()
+            );
+            0
+        }
+        Err(err) => {
+            eprintln!("[screenshot] {err}");
+            err.exit_code()
+        }
     }
 }
 
@@ -34,7 +69,7 @@ fn parse_args() -> Result<(Option<PathBuf>, kestrel_engine::config::AppConfigOve
             continue;
         }
         passthrough.push(flag.clone());
-        if flag.starts_with("--") {
+        if flag.starts_with("--") && CliOverrides::flag_takes_value(&flag) {
             if let Some(value) = args.next() {
                 passthrough.push(value);
             } else {
@@ -4,6 +4,7 @@ use crate::plugins::{EnginePlugin, PluginContext};
 use crate::scene::{
     SceneFreeflyCamera, SceneOrbitCamera, ScenePreviewCamera, ScenePreviewCameraMode, Vec3Data,
 };
+use crate::tr;
 use crate::wrap_angle;
 use anyhow::Result;
 use bevy_ecs::prelude::Entity;
@@ -117,15 +118,10 @@ impl FreeflyController {
         self.orientation() * Vec3::Y
     }
 
-    pub(crate) fn as_camera(&self) -> Camera3D {
+    pub(crate) fn as_camera(&self, near: f32, far: f32) -> Camera3D {
         let forward = self.forward();
-        let mut camera = Camera3D::new(
-            self.position,
-            self.position + forward,
-            MESH_CAMERA_FOV_RADIANS,
-            MESH_CAMERA_NEAR,
-            MESH_CAMERA_FAR,
-        );
+        let mut camera =
+            Camera3D::new(self.position, self.position + forward, MESH_CAMERA_FOV_RADIANS, near, far);
         let up = self.up().normalize_or_zero();
         camera.up = if up.length_squared() > 0.0 { up } else { Vec3::Y };
         camera
@@ -146,6 +142,8 @@ pub struct MeshPreviewPlugin {
     mesh_frustum_lock: bool,
     mesh_frustum_focus: Vec3,
     mesh_frustum_distance: f32,
+    mesh_near: f32,
+    mesh_far: f32,
     mesh_status: Option<String>,
     persistent_meshes: HashSet<String>,
     persistent_materials: HashSet<String>,
@@ -170,6 +168,8 @@ impl Default for MeshPreviewPlugin {
             mesh_frustum_lock: false,
             mesh_frustum_focus: Vec3::ZERO,
             mesh_frustum_distance: 5.0,
+            mesh_near: MESH_CAMERA_NEAR,
+            mesh_far: MESH_CAMERA_FAR,
             mesh_status: None,
             persistent_meshes: HashSet::new(),
             persistent_materials: HashSet::new(),
@@ -210,6 +210,26 @@ impl MeshPreviewPlugin {
         self.mesh_frustum_lock
     }
 
+    pub fn mesh_clip_planes(&self) -> (f32, f32) {
+        (self.mesh_near, self.mesh_far)
+    }
+
+    /// Validates and applies new clip planes; the preview projection picks them up right away.
+    pub fn set_mesh_clip_planes(&mut self, near: f32, far: f32) -> Result<()> {
+        self.mesh_camera.set_clip_planes(near, far)?;
+        self.mesh_near = near;
+        self.mesh_far = far;
+        Ok(())
+    }
+
+    fn orbit_camera(&self) -> Camera3D {
+        self.mesh_orbit.to_camera(MESH_CAMERA_FOV_RADIANS, self.mesh_near, self.mesh_far)
+    }
+
+    fn freefly_camera(&self) -> Camera3D {
+        self.mesh_freefly.as_camera(self.mesh_near, self.mesh_far)
+    }
+
     pub fn mesh_status(&self) -> Option<&str> {
         self.mesh_status.as_deref()
     }
@@ -233,8 +253,7 @@ impl MeshPreviewPlugin {
     pub fn set_orbit_target(&mut self, target: Vec3) {
         self.mesh_orbit.target = target;
         if matches!(self.mesh_control_mode, MeshControlMode::Orbit | MeshControlMode::Disabled) {
-            self.mesh_camera =
-                self.mesh_orbit.to_camera(MESH_CAMERA_FOV_RADIANS, MESH_CAMERA_NEAR, MESH_CAMERA_FAR);
+            self.mesh_camera = self.orbit_camera();
             self.mesh_freefly = FreeflyController::from_camera(&self.mesh_camera);
         }
         if self.mesh_frustum_lock {
@@ -246,7 +265,7 @@ impl MeshPreviewPlugin {
     pub fn teleport_freefly(&mut self, position: Vec3) {
         self.mesh_freefly.position = position;
         if self.mesh_control_mode == MeshControlMode::Freefly {
-            self.mesh_camera = self.mesh_freefly.as_camera();
+            self.mesh_camera = self.freefly_camera();
         }
         if self.mesh_frustum_lock {
             self.mesh_frustum_focus = self.mesh_camera.target;
@@ -258,7 +277,7 @@ impl MeshPreviewPlugin {
         let (mesh_registry, renderer) = ctx.mesh_registry_and_renderer()?;
         if let Err(err) = mesh_registry.ensure_gpu(self.preview_mesh_key(), renderer) {
             eprintln!("Failed to upload preview mesh '{}': {err:?}", self.preview_mesh_key());
-            self.mesh_status = Some(format!("Mesh upload failed: {err}"));
+            self.mesh_status = Some(tr!("mesh.upload_failed", error = err));
         }
         Ok(())
     }
@@ -282,6 +301,8 @@ impl MeshPreviewPlugin {
             frustum_lock: self.mesh_frustum_lock,
             frustum_focus: Vec3Data::from(self.mesh_frustum_focus),
             frustum_distance: self.mesh_frustum_distance,
+            near: self.mesh_near,
+            far: self.mesh_far,
         }
     }
 
@@ -301,6 +322,10 @@ impl MeshPreviewPlugin {
         self.mesh_frustum_lock = preview.frustum_lock;
         self.mesh_frustum_focus = Vec3::from(preview.frustum_focus.clone());
         self.mesh_frustum_distance = preview.frustum_distance.max(0.1);
+        if self.set_mesh_clip_planes(preview.near, preview.far).is_err() {
+            self.mesh_near = MESH_CAMERA_NEAR;
+            self.mesh_far = MESH_CAMERA_FAR;
+        }
         self.mesh_freefly_velocity = Vec3::ZERO;
         self.mesh_freefly_rot_velocity = Vec3::ZERO;
 
@@ -308,12 +333,11 @@ impl MeshPreviewPlugin {
         self.mesh_control_mode = mode;
         match mode {
             MeshControlMode::Disabled | MeshControlMode::Orbit => {
-                self.mesh_camera =
-                    self.mesh_orbit.to_camera(MESH_CAMERA_FOV_RADIANS, MESH_CAMERA_NEAR, MESH_CAMERA_FAR);
+                self.mesh_camera = self.orbit_camera();
                 self.mesh_freefly = FreeflyController::from_camera(&self.mesh_camera);
             }
             MeshControlMode::Freefly => {
-                self.mesh_camera = self.mesh_freefly.as_camera();
+                self.mesh_camera = self.freefly_camera();
             }
         }
         self.mesh_status = Some(mode.status_message().to_string());
@@ -324,7 +348,7 @@ impl MeshPreviewPlugin {
             self.focus_mesh_center(mesh_tx.translation);
             true
         } else {
-            self.mesh_status = Some("Centered 2D camera on selection.".to_string());
+            self.mesh_status = Some(tr!("mesh.centered_2d_camera"));
             true
         }
     }
@@ -335,7 +359,7 @@ impl MeshPreviewPlugin {
             .or_else(|| selection.map(|info| Vec3::new(info.translation.x, info.translation.y, 0.0)))
             .unwrap_or(fallback_target);
         self.focus_mesh_center(focus);
-        self.mesh_status = Some("Frustum focus updated.".to_string());
+        self.mesh_status = Some(tr!("mesh.frustum_focus_updated"));
     }
 
     pub fn set_mesh_control_mode(
@@ -351,13 +375,12 @@ impl MeshPreviewPlugin {
         match mode {
             MeshControlMode::Disabled | MeshControlMode::Orbit => {
                 self.sync_orbit_from_camera_pose();
-                self.mesh_camera =
-                    self.mesh_orbit.to_camera(MESH_CAMERA_FOV_RADIANS, MESH_CAMERA_NEAR, MESH_CAMERA_FAR);
+                self.mesh_camera = self.orbit_camera();
                 self.mesh_freefly = FreeflyController::from_camera(&self.mesh_camera);
             }
             MeshControlMode::Freefly => {
                 self.mesh_freefly = FreeflyController::from_camera(&self.mesh_camera);
-                self.mesh_camera = self.mesh_freefly.as_camera();
+                self.mesh_camera = self.freefly_camera();
             }
         }
         self.mesh_control_mode = mode;
@@ -392,9 +415,9 @@ impl MeshPreviewPlugin {
                         .clamp(-std::f32::consts::FRAC_PI_2 + 0.01, std::f32::consts::FRAC_PI_2 - 0.01);
                 }
             }
-            self.mesh_status = Some("Frustum lock enabled (wheel adjusts focus distance).".to_string());
+            self.mesh_status = Some(tr!("mesh.frustum_lock_enabled"));
         } else {
-            self.mesh_status = Some("Frustum lock disabled.".to_string());
+            self.mesh_status = Some(tr!("mesh.frustum_lock_disabled"));
             self.mesh_frustum_distance = self.mesh_orbit.radius;
         }
         self.mesh_frustum_lock = enabled;
@@ -406,14 +429,13 @@ impl MeshPreviewPlugin {
     pub fn reset_mesh_camera(&mut self, ctx: &mut PluginContext<'_>) -> Result<()> {
         let radius = self.mesh_orbit.radius;
         self.mesh_orbit = OrbitCamera::new(self.mesh_orbit.target, radius);
-        self.mesh_camera =
-            self.mesh_orbit.to_camera(MESH_CAMERA_FOV_RADIANS, MESH_CAMERA_NEAR, MESH_CAMERA_FAR);
+        self.mesh_camera = self.orbit_camera();
         self.mesh_freefly = FreeflyController::from_camera(&self.mesh_camera);
         self.mesh_freefly_velocity = Vec3::ZERO;
         self.mesh_freefly_rot_velocity = Vec3::ZERO;
         self.mesh_freefly.roll = 0.0;
         if self.mesh_control_mode == MeshControlMode::Freefly {
-            self.mesh_camera = self.mesh_freefly.as_camera();
+            self.mesh_camera = self.freefly_camera();
         }
         if self.mesh_frustum_lock {
             self.mesh_frustum_focus = self.compute_focus_point(ctx)?;
@@ -422,7 +444,7 @@ impl MeshPreviewPlugin {
         } else {
             self.mesh_frustum_distance = self.mesh_orbit.radius;
         }
-        self.mesh_status = Some("Mesh camera reset.".to_string());
+        self.mesh_status = Some(tr!("mesh.camera_reset"));
         Ok(())
     }
 
@@ -465,7 +487,7 @@ impl MeshPreviewPlugin {
                     let material_registry = ctx.material_registry_mut()?;
                     for material in &new_materials {
                         if let Err(err) = material_registry.retain(material) {
-                            self.mesh_status = Some(format!("Material retain failed: {err}"));
+                            self.mesh_status = Some(tr!("mesh.material_retain_failed", error = err));
                         } else {
                             self.persistent_materials.insert(material.clone());
                         }
@@ -480,14 +502,14 @@ impl MeshPreviewPlugin {
                     }
                 }
 
-                self.mesh_status = Some(format!("Preview mesh: {}", new_key));
+                self.mesh_status = Some(tr!("mesh.preview_mesh", key = new_key));
                 let (mesh_registry, renderer) = ctx.mesh_registry_and_renderer()?;
                 if let Err(err) = mesh_registry.ensure_gpu(&self.preview_mesh_key, renderer) {
-                    self.mesh_status = Some(format!("Mesh upload failed: {err}"));
+                    self.mesh_status = Some(tr!("mesh.upload_failed", error = err));
                 }
             }
             Err(err) => {
-                self.mesh_status = Some(format!("Mesh '{}' unavailable: {err}", new_key));
+                self.mesh_status = Some(tr!("mesh.unavailable", key = new_key, error = err));
             }
         }
         Ok(())
@@ -501,14 +523,14 @@ impl MeshPreviewPlugin {
         {
             let (mesh_registry, material_registry) = ctx.mesh_registry_and_materials()?;
             if let Err(err) = mesh_registry.ensure_mesh(mesh_key, None, material_registry) {
-                self.mesh_status = Some(format!("Mesh '{}' unavailable: {err}", mesh_key));
+                self.mesh_status = Some(tr!("mesh.unavailable", key = mesh_key, error = err));
                 return Ok(None);
             }
         }
         {
             let (mesh_registry, renderer) = ctx.mesh_registry_and_renderer()?;
             if let Err(err) = mesh_registry.ensure_gpu(mesh_key, renderer) {
-                self.mesh_status = Some(format!("Failed to upload mesh '{}': {err}", mesh_key));
+                self.mesh_status = Some(tr!("mesh.upload_named_failed", key = mesh_key, error = err));
                 return Ok(None);
             }
         }
@@ -522,7 +544,7 @@ impl MeshPreviewPlugin {
         if let Some(material) = subset_copy.iter().find_map(|subset| subset.material.clone()) {
             ctx.ecs_mut()?.set_mesh_material(entity, Some(material));
         }
-        self.mesh_status = Some(format!("Spawned mesh '{}' as entity {:?}", mesh_key, entity));
+        self.mesh_status = Some(tr!("mesh.spawned", key = mesh_key, entity = format!("{:?}", entity)));
         Ok(Some(entity))
     }
 
@@ -567,8 +589,7 @@ impl MeshPreviewPlugin {
                 self.mesh_freefly_rot_velocity = Vec3::ZERO;
                 let auto_delta = Vec2::new(0.25 * dt, 0.12 * dt);
                 self.mesh_orbit.orbit(auto_delta);
-                self.mesh_camera =
-                    self.mesh_orbit.to_camera(MESH_CAMERA_FOV_RADIANS, MESH_CAMERA_NEAR, MESH_CAMERA_FAR);
+                self.mesh_camera = self.orbit_camera();
                 self.mesh_freefly = FreeflyController::from_camera(&self.mesh_camera);
             }
             MeshControlMode::Orbit => {
@@ -589,8 +610,7 @@ impl MeshPreviewPlugin {
                     self.mesh_orbit.zoom(factor);
                     ctx.input_mut()?.wheel = 0.0;
                 }
-                self.mesh_camera =
-                    self.mesh_orbit.to_camera(MESH_CAMERA_FOV_RADIANS, MESH_CAMERA_NEAR, MESH_CAMERA_FAR);
+                self.mesh_camera = self.orbit_camera();
                 self.mesh_freefly = FreeflyController::from_camera(&self.mesh_camera);
             }
             MeshControlMode::Freefly => {
@@ -686,11 +706,12 @@ impl MeshPreviewPlugin {
                 if !self.mesh_frustum_lock && snapshot.wheel.abs() > 0.0 {
                     let factor = (1.0 + snapshot.wheel * 0.06).clamp(0.2, 5.0);
                     self.mesh_freefly_speed = (self.mesh_freefly_speed * factor).clamp(0.1, 200.0);
-                    self.mesh_status = Some(format!("Free-fly speed: {:.2}", self.mesh_freefly_speed));
+                    self.mesh_status =
+                        Some(tr!("mesh.free_fly_speed", speed = format!("{:.2}", self.mesh_freefly_speed)));
                     ctx.input_mut()?.wheel = 0.0;
                 }
 
-                self.mesh_camera = self.mesh_freefly.as_camera();
+                self.mesh_camera = self.freefly_camera();
 
                 if self.mesh_frustum_lock {
                     let direction = (self.mesh_frustum_focus - self.mesh_camera.position).normalize_or_zero();
@@ -720,8 +741,7 @@ impl MeshPreviewPlugin {
                 } else {
                     self.mesh_orbit.target = self.mesh_frustum_focus;
                     self.mesh_orbit.radius = self.mesh_frustum_distance.max(0.1);
-                    self.mesh_camera =
-                        self.mesh_orbit.to_camera(MESH_CAMERA_FOV_RADIANS, MESH_CAMERA_NEAR, MESH_CAMERA_FAR);
+                    self.mesh_camera = self.orbit_camera();
                     self.mesh_camera.target = focus;
                 }
             }
@@ -746,10 +766,9 @@ impl MeshPreviewPlugin {
         self.mesh_frustum_distance = (self.mesh_camera.position - center).length().max(0.1);
         self.mesh_orbit.target = center;
         self.mesh_orbit.radius = self.mesh_frustum_distance;
-        self.mesh_camera =
-            self.mesh_orbit.to_camera(MESH_CAMERA_FOV_RADIANS, MESH_CAMERA_NEAR, MESH_CAMERA_FAR);
+        self.mesh_camera = self.orbit_camera();
         self.mesh_freefly = FreeflyController::from_camera(&self.mesh_camera);
-        self.mesh_status = Some("Framed selection in 3D viewport.".to_string());
+        self.mesh_status = Some(tr!("mesh.framed_selection"));
     }
 
     fn compute_focus_point(&self, ctx: &PluginContext<'_>) -> Result<Vec3> {
@@ -794,8 +813,7 @@ impl EnginePlugin for MeshPreviewPlugin {
             self.preview_mesh_key = ctx.mesh_registry()?.default_key().to_string();
         }
         self.mesh_orbit = OrbitCamera::new(Vec3::ZERO, 5.0);
-        self.mesh_camera =
-            self.mesh_orbit.to_camera(MESH_CAMERA_FOV_RADIANS, MESH_CAMERA_NEAR, MESH_CAMERA_FAR);
+        self.mesh_camera = self.orbit_camera();
         self.mesh_frustum_focus = self.mesh_orbit.target;
         self.mesh_frustum_distance = self.mesh_orbit.radius;
         self.mesh_freefly = FreeflyController::from_camera(&self.mesh_camera);
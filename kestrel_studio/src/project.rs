@@ -5,6 +5,7 @@ use std::path::{Path, PathBuf};
 
 const RECENT_PROJECTS_PATH: &str = "config/recent_projects.json";
 const DEFAULT_MANIFEST_NAME: &str = "project.kestrelproj";
+const MANIFEST_EXTENSION: &str = "kestrelproj";
 const RECENT_LIMIT: usize = 8;
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -354,6 +355,34 @@ impl Project {
         format!("{name}{id} @ {}", self.root.display())
     }
 
+    /// Ask the user for a project manifest with the platform's native file dialog.
+    ///
+    /// Returns `None` when the dialog is cancelled or no native dialog can be shown; callers can
+    /// tell the two apart with [`Project::native_dialog_available`].
+    pub fn open_dialog() -> Option<PathBuf> {
+        if !Self::native_dialog_available() {
+            return None;
+        }
+        let mut dialog = rfd::FileDialog::new()
+            .set_title("Open Kestrel Project")
+            .add_filter("Kestrel project", &[MANIFEST_EXTENSION]);
+        if let Some(dir) = Self::load_recent().as_deref().and_then(Path::parent).filter(|dir| dir.is_dir()) {
+            dialog = dialog.set_directory(dir);
+        }
+        dialog.pick_file()
+    }
+
+    /// Whether a native file dialog can be shown. On Linux/BSD the portal backend needs a display
+    /// and a session bus, which headless CI runners usually lack.
+    pub fn native_dialog_available() -> bool {
+        if !cfg!(unix) || cfg!(target_os = "macos") {
+            return true;
+        }
+        let has_display =
+            std::env::var_os("DISPLAY").is_some() || std::env::var_os("WAYLAND_DISPLAY").is_some();
+        has_display && std::env::var_os("DBUS_SESSION_BUS_ADDRESS").is_some()
+    }
+
     /// Load the most recently opened project path, if any.
     pub fn load_recent() -> Option<PathBuf> {
         Self::recent_projects().into_iter().next()
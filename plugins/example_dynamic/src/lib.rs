@@ -26,26 +26,23 @@ impl EnginePlugin for ExampleDynamicPlugin {
 
     fn build(&mut self, ctx: &mut PluginContext<'_>) -> Result<()> {
         ctx.assets_mut()?.load_atlas("main", "assets/images/atlas.json")?;
-        if let Ok(value) = std::env::var("EXAMPLE_DYNAMIC_SLEEP_MS") {
-            if let Ok(parsed) = value.parse::<u64>() {
-                self.watchdog_sleep_ms = Some(parsed);
-            }
-        }
-        if let Ok(value) = std::env::var("EXAMPLE_DYNAMIC_FORCE_RENDERER_VIOLATION") {
-            self.force_renderer_violation =
-                value == "1" || value.eq_ignore_ascii_case("true") || value.eq_ignore_ascii_case("yes");
-        }
-        if let Ok(value) = std::env::var("EXAMPLE_DYNAMIC_FORCE_PANIC") {
-            self.force_panic =
-                value == "1" || value.eq_ignore_ascii_case("true") || value.eq_ignore_ascii_case("yes");
-        }
+        self.apply_config(ctx);
+        Ok(())
+    }
+
+    fn on_config_changed(
+        &mut self,
+        ctx: &mut PluginContext<'_>,
+        _config: Option<&serde_json::Value>,
+    ) -> Result<()> {
+        self.apply_config(ctx);
         Ok(())
     }
 
     fn update(&mut self, ctx: &mut PluginContext<'_>, dt: f32) -> Result<()> {
         if self.force_panic && !self.panic_triggered {
             self.panic_triggered = true;
-            panic!("example_dynamic forced panic via EXAMPLE_DYNAMIC_FORCE_PANIC");
+            panic!("example_dynamic forced panic via manifest config `force_panic`");
         }
         if !self.watchdog_armed {
             if let Some(ms) = self.watchdog_sleep_ms.take() {
@@ -79,6 +76,21 @@ impl EnginePlugin for ExampleDynamicPlugin {
     }
 }
 
+impl ExampleDynamicPlugin {
+    /// Reads the test knobs from the manifest entry's `config` object, e.g.
+    /// `{ "sleep_ms": 250, "force_renderer_violation": true, "force_panic": false }`.
+    fn apply_config(&mut self, ctx: &PluginContext<'_>) {
+        let config = ctx.config();
+        let flag = |key: &str| config.and_then(|config| config.get(key)).and_then(|value| value.as_bool());
+        self.watchdog_sleep_ms =
+            config.and_then(|config| config.get("sleep_ms")).and_then(|value| value.as_u64());
+        self.watchdog_armed = false;
+        self.force_renderer_violation = flag("force_renderer_violation").unwrap_or(false);
+        self.force_panic = flag("force_panic").unwrap_or(false);
+        self.panic_triggered = false;
+    }
+}
+
 unsafe extern "C" fn create_plugin() -> PluginHandle {
     let plugin: Box<dyn EnginePlugin> = Box::new(ExampleDynamicPlugin::default());
     PluginHandle::from_box(plugin)
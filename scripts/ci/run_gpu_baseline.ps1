@@ -1,6 +1,8 @@
 Param(
+    [ValidateSet("record", "compare")]
+    [string]$Mode = "compare",
     [string]$Output = "perf/gpu_baseline_ci.json",
-    [string]$Baseline = ""
+    [string]$Baseline = "perf/gpu_baseline.json"
 )
 
 Set-StrictMode -Version 3
@@ -10,11 +12,14 @@ $workspaceRoot = [System.IO.Path]::GetFullPath((Join-Path $PSScriptRoot "..\..")
 Push-Location $workspaceRoot
 try {
     Write-Host "Running gpu_baseline..." -ForegroundColor Cyan
-    $args = @("--output", $Output, "--frames", "240")
-    if ($Baseline) {
-        $args += @("--baseline", $Baseline)
+    $args = @("--gpu-baseline", $Mode, "--baseline", $Baseline, "--frames", "240")
+    if ($Mode -eq "compare") {
+        $args += @("--output", $Output)
     }
     cargo run --bin gpu_baseline -- @args
+    if ($LASTEXITCODE -ne 0) {
+        throw "gpu_baseline $Mode failed with exit code $LASTEXITCODE"
+    }
 } finally {
     Pop-Location
 }
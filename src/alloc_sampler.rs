@@ -0,0 +1,109 @@
+//! Allocation estimates for render hot paths that work without the `alloc_profiler` feature.
+//!
+//! Instead of swapping the global allocator, instrumented call sites report how far their
+//! per-frame buffers grew. Every report first checks a relaxed atomic flag, so the counters cost a
+//! single load per call site while sampling is off.
+
+use serde::Serialize;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AllocationSite {
+    /// `EcsWorld::collect_sprite_instances`.
+    SpriteInstances,
+    /// Sprite batch assembly and the sprite pass instance upload.
+    SpriteBatches,
+    /// `EcsWorld::collect_mesh_instances`.
+    MeshInstances,
+}
+
+impl AllocationSite {
+    const COUNT: usize = 3;
+
+    fn index(self) -> usize {
+        match self {
+            AllocationSite::SpriteInstances => 0,
+            AllocationSite::SpriteBatches => 1,
+            AllocationSite::MeshInstances => 2,
+        }
+    }
+}
+
+struct SiteCounters {
+    allocations: AtomicU64,
+    bytes: AtomicU64,
+}
+
+impl SiteCounters {
+    const fn new() -> Self {
+        Self { allocations: AtomicU64::new(0), bytes: AtomicU64::new(0) }
+    }
+
+    fn take(&self) -> SiteAllocations {
+        SiteAllocations {
+            allocations: self.allocations.swap(0, Ordering::Relaxed),
+            bytes: self.bytes.swap(0, Ordering::Relaxed),
+        }
+    }
+}
+
+static COUNTERS: [SiteCounters; AllocationSite::COUNT] =
+    [SiteCounters::new(), SiteCounters::new(), SiteCounters::new()];
+
+/// Estimated allocations at one site since the previous [`take_sample`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct SiteAllocations {
+    pub allocations: u64,
+    pub bytes: u64,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct AllocationPressureSample {
+    pub sprite_instances: SiteAllocations,
+    pub sprite_batches: SiteAllocations,
+    pub mesh_instances: SiteAllocations,
+}
+
+impl AllocationPressureSample {
+    pub fn total_allocations(&self) -> u64 {
+        self.sprite_instances.allocations + self.sprite_batches.allocations + self.mesh_instances.allocations
+    }
+
+    pub fn total_bytes(&self) -> u64 {
+        self.sprite_instances.bytes + self.sprite_batches.bytes + self.mesh_instances.bytes
+    }
+}
+
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+#[inline]
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Records that a buffer of `T` grew from `before` to `after` elements of capacity. Vectors double
+/// on growth, so every doubling past the old capacity counts as one allocation.
+#[inline]
+pub fn record_growth<T>(site: AllocationSite, before: usize, after: usize) {
+    if !is_enabled() || after <= before {
+        return;
+    }
+    let steps = u64::from((after / before.max(1)).ilog2()).max(1);
+    let bytes = ((after - before) * std::mem::size_of::<T>()) as u64;
+    let counters = &COUNTERS[site.index()];
+    counters.allocations.fetch_add(steps, Ordering::Relaxed);
+    counters.bytes.fetch_add(bytes, Ordering::Relaxed);
+}
+
+/// Returns the counters gathered since the last call and resets them.
+pub fn take_sample() -> AllocationPressureSample {
+    AllocationPressureSample {
+        sprite_instances: COUNTERS[AllocationSite::SpriteInstances.index()].take(),
+        sprite_batches: COUNTERS[AllocationSite::SpriteBatches.index()].take(),
+        mesh_instances: COUNTERS[AllocationSite::MeshInstances.index()].take(),
+    }
+}
@@ -1,13 +1,14 @@
 #[cfg(feature = "alloc_profiler")]
 use crate::alloc_profiler::AllocationDelta;
+use crate::alloc_sampler::AllocationPressureSample;
 use crate::animation_validation::AnimationValidationEvent;
-use crate::ecs::{ParticleBudgetMetrics, SpatialMetrics};
+use crate::ecs::{ParticleBudgetMetrics, SpatialMetrics, SpriteTimelineUsage, SpriteTimelineUsageEntry};
 use crate::events::GameEvent;
 use crate::plugins::{
     CapabilityViolationLog, EnginePlugin, PluginAssetReadbackEvent, PluginCapabilityEvent, PluginContext,
     PluginWatchdogEvent,
 };
-use crate::renderer::{GpuPassTiming, LightClusterMetrics};
+use crate::renderer::{GpuPassTiming, LightClusterMetrics, RenderStats};
 use anyhow::Result;
 use serde::Serialize;
 use std::any::Any;
@@ -31,9 +32,12 @@ pub struct AnimationBudgetSample {
     pub skeletal_bone_count: usize,
     pub palette_upload_calls: u32,
     pub palette_uploaded_joints: u32,
+    /// Hot-path allocation estimates for the frame; `None` unless `alloc_sampler` is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allocation_pressure: Option<AllocationPressureSample>,
 }
 
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, Serialize)]
 pub struct KeyframeEditorUsageSnapshot {
     pub panel_open_count: u64,
     pub panel_close_count: u64,
@@ -115,6 +119,28 @@ pub struct KeyframeEditorEvent {
     pub kind: KeyframeEditorEventKind,
 }
 
+/// Serializable copy of a [`SpriteTimelineUsageEntry`] for session exports.
+#[derive(Clone, Debug, Serialize)]
+pub struct TimelineUsageExport {
+    pub atlas: String,
+    pub timeline: String,
+    pub plays: u64,
+    pub loops: u64,
+    pub events: BTreeMap<String, u64>,
+}
+
+/// Session-wide analytics written by [`AnalyticsPlugin::session_export`].
+#[derive(Clone, Debug, Serialize)]
+pub struct AnalyticsSessionExport {
+    pub frame_samples: usize,
+    pub average_frame_ms: Option<f32>,
+    pub keyframe_editor_usage: KeyframeEditorUsageSnapshot,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub animation_budget: Option<AnimationBudgetSample>,
+    /// Most played timelines first.
+    pub timeline_usage: Vec<TimelineUsageExport>,
+}
+
 pub struct AnalyticsPlugin {
     frame_hist: Vec<f32>,
     frame_capacity: usize,
@@ -125,6 +151,7 @@ pub struct AnalyticsPlugin {
     particle_budget: Option<ParticleBudgetMetrics>,
     spatial_metrics: Option<SpatialMetrics>,
     light_cluster_metrics: Option<LightClusterMetrics>,
+    render_stats: Option<RenderStats>,
     gpu_capacity: usize,
     gpu_timings: BTreeMap<&'static str, VecDeque<f32>>,
     gpu_timings_snapshot: Option<Arc<HashMap<&'static str, Vec<f32>>>>,
@@ -141,12 +168,26 @@ pub struct AnalyticsPlugin {
     keyframe_editor_usage: KeyframeEditorUsageSnapshot,
     keyframe_editor_events: VecDeque<KeyframeEditorEvent>,
     keyframe_events_snapshot: Option<Arc<[KeyframeEditorEvent]>>,
+    timeline_usage: Arc<[SpriteTimelineUsageEntry]>,
+    timeline_usage_revision: Option<u64>,
+    custom_counters: BTreeMap<String, Vec<f64>>,
+    custom_counters_snapshot: Option<Arc<[CustomCounterHistory]>>,
     #[cfg(feature = "alloc_profiler")]
     allocation_delta: Option<AllocationDelta>,
 }
 
 const SECURITY_EVENT_CAPACITY: usize = 64;
 const KEYFRAME_EVENT_CAPACITY: usize = 32;
+/// Samples kept per custom counter; older ones are dropped first.
+pub const CUSTOM_COUNTER_CAPACITY: usize = 256;
+
+/// Recent samples of one counter recorded with [`AnalyticsPlugin::record_custom_counter`], oldest
+/// first.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CustomCounterHistory {
+    pub name: String,
+    pub samples: Vec<f64>,
+}
 
 impl AnalyticsPlugin {
     pub fn new(frame_capacity: usize, event_capacity: usize) -> Self {
@@ -160,6 +201,7 @@ impl AnalyticsPlugin {
             particle_budget: None,
             spatial_metrics: None,
             light_cluster_metrics: None,
+            render_stats: None,
             gpu_capacity: 120,
             gpu_timings: BTreeMap::new(),
             gpu_timings_snapshot: None,
@@ -176,6 +218,10 @@ impl AnalyticsPlugin {
             keyframe_editor_usage: KeyframeEditorUsageSnapshot::default(),
             keyframe_editor_events: VecDeque::with_capacity(KEYFRAME_EVENT_CAPACITY),
             keyframe_events_snapshot: None,
+            timeline_usage: Arc::from(Vec::new()),
+            timeline_usage_revision: None,
+            custom_counters: BTreeMap::new(),
+            custom_counters_snapshot: None,
             #[cfg(feature = "alloc_profiler")]
             allocation_delta: None,
         }
@@ -232,6 +278,15 @@ impl AnalyticsPlugin {
         self.light_cluster_metrics
     }
 
+    pub fn record_render_stats(&mut self, stats: RenderStats) {
+        self.render_stats = Some(stats);
+    }
+
+    /// Draw calls and triangles of the most recently rendered frame.
+    pub fn render_stats(&self) -> Option<RenderStats> {
+        self.render_stats
+    }
+
     pub fn record_gpu_timings(&mut self, timings: &[GpuPassTiming]) {
         if timings.is_empty() {
             return;
@@ -414,6 +469,91 @@ impl AnalyticsPlugin {
         self.keyframe_events_snapshot = Some(Arc::clone(&arc));
         arc
     }
+
+    /// Mirrors the ECS timeline counters; skipped when nothing was recorded since the last call.
+    pub fn record_timeline_usage(&mut self, usage: &SpriteTimelineUsage) {
+        if self.timeline_usage_revision == Some(usage.revision()) {
+            return;
+        }
+        self.timeline_usage_revision = Some(usage.revision());
+        self.timeline_usage = usage.entries().cloned().collect();
+    }
+
+    /// Per `(atlas, timeline)` plays, loops and frame-event fires for this session.
+    pub fn timeline_usage(&self) -> &[SpriteTimelineUsageEntry] {
+        &self.timeline_usage
+    }
+
+    pub fn timeline_usage_arc(&self) -> Arc<[SpriteTimelineUsageEntry]> {
+        Arc::clone(&self.timeline_usage)
+    }
+
+    /// Appends `value` to the history of the counter `name`, creating it on first use. Plugins call
+    /// this for their own metrics (nodes evaluated, packets sent, ...); the last
+    /// [`CUSTOM_COUNTER_CAPACITY`] samples are kept. Empty names and non-finite values are ignored.
+    pub fn record_custom_counter(&mut self, name: &str, value: f64) {
+        if name.is_empty() || !value.is_finite() {
+            return;
+        }
+        let history = match self.custom_counters.get_mut(name) {
+            Some(history) => history,
+            None => self.custom_counters.entry(name.to_string()).or_default(),
+        };
+        if history.len() == CUSTOM_COUNTER_CAPACITY {
+            history.remove(0);
+        }
+        history.push(value);
+        self.custom_counters_snapshot = None;
+    }
+
+    /// Samples recorded for the counter `name`, oldest first.
+    pub fn custom_counter_history(&self, name: &str) -> Option<&[f64]> {
+        self.custom_counters.get(name).map(Vec::as_slice)
+    }
+
+    /// Every custom counter sorted by name, cached until the next sample is recorded.
+    pub fn custom_counters_arc(&mut self) -> Arc<[CustomCounterHistory]> {
+        if let Some(cache) = &self.custom_counters_snapshot {
+            return Arc::clone(cache);
+        }
+        let data = self
+            .custom_counters
+            .iter()
+            .map(|(name, samples)| CustomCounterHistory { name: name.clone(), samples: samples.clone() })
+            .collect::<Vec<_>>();
+        let arc: Arc<[CustomCounterHistory]> = Arc::from(data.into_boxed_slice());
+        self.custom_counters_snapshot = Some(Arc::clone(&arc));
+        arc
+    }
+
+    pub fn clear_timeline_usage(&mut self) {
+        self.timeline_usage = Arc::from(Vec::new());
+        self.timeline_usage_revision = None;
+    }
+
+    pub fn session_export(&self) -> AnalyticsSessionExport {
+        let average_frame_ms = (!self.frame_hist.is_empty())
+            .then(|| self.frame_hist.iter().sum::<f32>() / self.frame_hist.len() as f32);
+        let mut timeline_usage: Vec<TimelineUsageExport> = self
+            .timeline_usage
+            .iter()
+            .map(|entry| TimelineUsageExport {
+                atlas: entry.atlas.to_string(),
+                timeline: entry.timeline.to_string(),
+                plays: entry.plays,
+                loops: entry.loops,
+                events: entry.events.iter().map(|(name, count)| (name.to_string(), *count)).collect(),
+            })
+            .collect();
+        timeline_usage.sort_by(|a, b| b.plays.cmp(&a.plays).then_with(|| a.timeline.cmp(&b.timeline)));
+        AnalyticsSessionExport {
+            frame_samples: self.frame_hist.len(),
+            average_frame_ms,
+            keyframe_editor_usage: self.keyframe_editor_usage,
+            animation_budget: self.animation_budget_sample,
+            timeline_usage,
+        }
+    }
 }
 
 impl Default for AnalyticsPlugin {
@@ -463,6 +603,7 @@ impl EnginePlugin for AnalyticsPlugin {
         self.particle_budget = None;
         self.spatial_metrics = None;
         self.light_cluster_metrics = None;
+        self.render_stats = None;
         self.gpu_timings.clear();
         self.plugin_capability_events.clear();
         self.plugin_asset_readbacks.clear();
@@ -479,6 +620,9 @@ impl EnginePlugin for AnalyticsPlugin {
         }
         self.keyframe_editor_events.clear();
         self.keyframe_editor_usage = KeyframeEditorUsageSnapshot::default();
+        self.clear_timeline_usage();
+        self.custom_counters.clear();
+        self.custom_counters_snapshot = None;
         Ok(())
     }
 
@@ -545,6 +689,58 @@ mod tests {
         assert!(matches!(events[0].kind, KeyframeEditorEventKind::UpdateKey { changed_time: true, .. }));
     }
 
+    #[test]
+    fn timeline_usage_mirrors_ecs_counters_and_exports() {
+        let mut usage = SpriteTimelineUsage::default();
+        let (atlas, timeline): (Arc<str>, Arc<str>) = (Arc::from("fx"), Arc::from("explosion_big"));
+        let id = usage.intern(&atlas, &timeline);
+        assert_eq!(usage.intern(&atlas, &timeline), id, "keys are interned once");
+        usage.intern(&atlas, &Arc::from("unused"));
+        usage.record_play(id);
+        usage.record_play(id);
+
+        let mut analytics = AnalyticsPlugin::default();
+        analytics.record_timeline_usage(&usage);
+        let first = analytics.timeline_usage_arc();
+        assert_eq!(first.len(), 1, "timelines that never ran are left out");
+        assert_eq!(first[0].plays, 2);
+        analytics.record_timeline_usage(&usage);
+        assert!(Arc::ptr_eq(&first, &analytics.timeline_usage_arc()), "unchanged usage is not copied");
+
+        let export = analytics.session_export();
+        assert_eq!(export.timeline_usage[0].timeline, "explosion_big");
+        let json = serde_json::to_value(&export).expect("serialize export");
+        assert_eq!(json["timeline_usage"][0]["plays"], 2);
+
+        usage.clear();
+        analytics.record_timeline_usage(&usage);
+        assert!(analytics.timeline_usage().is_empty());
+    }
+
+    #[test]
+    fn custom_counters_keep_the_latest_samples() {
+        let mut analytics = AnalyticsPlugin::default();
+        assert!(analytics.custom_counter_history("nav.nodes").is_none());
+        for value in 0..300 {
+            analytics.record_custom_counter("nav.nodes", value as f64);
+        }
+        analytics.record_custom_counter("net.packets", 4.0);
+        analytics.record_custom_counter("net.packets", f64::NAN);
+        analytics.record_custom_counter("", 1.0);
+
+        let nodes = analytics.custom_counter_history("nav.nodes").expect("nodes history");
+        assert_eq!(nodes.len(), CUSTOM_COUNTER_CAPACITY);
+        assert_eq!(nodes.first().copied(), Some(44.0));
+        assert_eq!(nodes.last().copied(), Some(299.0));
+        assert_eq!(analytics.custom_counter_history("net.packets"), Some(&[4.0][..]));
+
+        let first = analytics.custom_counters_arc();
+        assert_eq!(first.iter().map(|c| c.name.as_str()).collect::<Vec<_>>(), ["nav.nodes", "net.packets"]);
+        assert!(Arc::ptr_eq(&first, &analytics.custom_counters_arc()));
+        analytics.record_custom_counter("net.packets", 5.0);
+        assert!(!Arc::ptr_eq(&first, &analytics.custom_counters_arc()));
+    }
+
     #[test]
     fn plugin_event_snapshots_cache_until_mutated() {
         let mut analytics = AnalyticsPlugin::default();
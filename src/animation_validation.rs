@@ -1,10 +1,10 @@
 use crate::assets::skeletal;
 use crate::assets::{
-    parse_animation_clip_bytes, parse_animation_graph_bytes, parse_texture_atlas_bytes, AnimationClip,
-    AnimationGraphAsset, ClipKeyframe, TextureAtlasParseResult,
+    parse_animation_clip_bytes, parse_animation_graph_bytes, parse_color_curve_bytes,
+    parse_texture_atlas_bytes, AnimationClip, AnimationGraphAsset, ClipKeyframe, TextureAtlasParseResult,
 };
 use serde_json::Value;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::fmt;
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -68,6 +68,7 @@ impl AnimationValidator {
                 JsonAssetKind::Atlas => Self::validate_atlas_bytes(path, &bytes),
                 JsonAssetKind::Clip => Self::validate_clip_bytes(path, &bytes),
                 JsonAssetKind::Graph => Self::validate_graph_bytes(path, &bytes),
+                JsonAssetKind::ColorCurve => Self::validate_color_curve_bytes(path, &bytes),
                 JsonAssetKind::Unknown => {
                     vec![Self::event(
                         path,
@@ -117,6 +118,30 @@ impl AnimationValidator {
         }
     }
 
+    pub fn validate_color_curve_bytes(path: &Path, bytes: &[u8]) -> Vec<AnimationValidationEvent> {
+        let key_hint = path.file_stem().and_then(|stem| stem.to_str()).unwrap_or("color_curve");
+        let source_label = path.display().to_string();
+        match parse_color_curve_bytes(bytes, key_hint, &source_label) {
+            Ok(curve) => {
+                let stop_count = curve.stops.len();
+                vec![Self::event(
+                    path,
+                    AnimationValidationSeverity::Info,
+                    format!(
+                        "Color curve '{}' OK: {stop_count} stop{}.",
+                        curve.name,
+                        if stop_count == 1 { "" } else { "s" }
+                    ),
+                )]
+            }
+            Err(err) => vec![Self::event(
+                path,
+                AnimationValidationSeverity::Error,
+                format!("Failed to parse color curve: {err}"),
+            )],
+        }
+    }
+
     fn validate_atlas_bytes(path: &Path, bytes: &[u8]) -> Vec<AnimationValidationEvent> {
         let key_hint = path.file_stem().and_then(|stem| stem.to_str()).unwrap_or("texture_atlas");
         let source_label = path.display().to_string();
@@ -170,6 +195,7 @@ impl AnimationValidator {
                         ),
                     ));
                 }
+                events.extend(Self::duplicate_bone_name_events(path, &import.skeleton));
                 if import.clips.is_empty() {
                     events.push(Self::event(
                         path,
@@ -208,6 +234,45 @@ impl AnimationValidator {
         }
     }
 
+    /// Report every joint name shared by more than one joint in the GLTF skin at `path`. Bone
+    /// lookups by name resolve to the first match, so duplicates silently bind the wrong joint.
+    pub fn validate_bone_name_uniqueness(path: &Path) -> Vec<AnimationValidationEvent> {
+        match skeletal::load_skeleton_from_gltf(path) {
+            Ok(import) => Self::duplicate_bone_name_events(path, &import.skeleton),
+            Err(err) => vec![Self::event(
+                path,
+                AnimationValidationSeverity::Error,
+                format!("Failed to import skeleton: {err}"),
+            )],
+        }
+    }
+
+    fn duplicate_bone_name_events(
+        path: &Path,
+        skeleton: &skeletal::SkeletonAsset,
+    ) -> Vec<AnimationValidationEvent> {
+        let mut joints_by_name: HashMap<&str, Vec<usize>> = HashMap::new();
+        for (index, joint) in skeleton.joints.iter().enumerate() {
+            joints_by_name.entry(joint.name.as_ref()).or_default().push(index);
+        }
+        let mut duplicates: Vec<(&str, Vec<usize>)> =
+            joints_by_name.into_iter().filter(|(_, indices)| indices.len() > 1).collect();
+        duplicates.sort_by_key(|(_, indices)| indices[0]);
+        duplicates
+            .into_iter()
+            .map(|(name, indices)| {
+                Self::event(
+                    path,
+                    AnimationValidationSeverity::Error,
+                    format!(
+                        "Bone name '{}' is shared by joints {:?}; lookups by name resolve to joint {} only.",
+                        name, indices, indices[0]
+                    ),
+                )
+            })
+            .collect()
+    }
+
     fn clip_success_events(path: &Path, clip: &AnimationClip) -> Vec<AnimationValidationEvent> {
         let mut events = Vec::new();
         if clip.translation.is_none()
@@ -306,6 +371,15 @@ impl AnimationValidator {
                 format!("Entry state '{entry_state}' is not defined in the graph."),
             ));
         }
+        if let Some(end_state) = graph.end_state.as_deref() {
+            if !state_names.contains(end_state) {
+                events.push(Self::event(
+                    path,
+                    AnimationValidationSeverity::Error,
+                    format!("End state '{end_state}' is not defined in the graph."),
+                ));
+            }
+        }
         let mut parameter_names = HashSet::new();
         for parameter in graph.parameters.iter() {
             let name = parameter.name.as_ref();
@@ -341,6 +415,16 @@ impl AnimationValidator {
                     format!("Transition from '{from}' to itself detected; confirm this is intentional."),
                 ));
             }
+            for condition in transition.conditions.iter() {
+                let parameter = condition.parameter.as_ref();
+                if !parameter_names.contains(parameter) {
+                    events.push(Self::event(
+                        path,
+                        AnimationValidationSeverity::Error,
+                        format!("Transition '{from}' -> '{to}' references unknown parameter '{parameter}'."),
+                    ));
+                }
+            }
         }
         for state in graph.states.iter() {
             let clip_empty = state.clip.as_deref().map(|clip| clip.trim().is_empty()).unwrap_or(true);
@@ -358,6 +442,8 @@ impl AnimationValidator {
                 AnimationValidationSeverity::Warning,
                 "Graph has multiple states but no transitions; states other than the entry will never be reached.",
             ));
+        } else {
+            events.extend(Self::graph_reachability_events(path, graph));
         }
         if !has_error(&events) {
             events.push(Self::event(
@@ -375,19 +461,74 @@ impl AnimationValidator {
         events
     }
 
+    /// Walks the graph breadth-first from the entry state, warning about states that can never be
+    /// entered and noting states that cannot be left (other than the graph's `end_state`).
+    /// Returned events carry an empty path; file-based validation fills in the asset path.
+    pub fn validate_graph_reachability(graph: &AnimationGraphAsset) -> Vec<AnimationValidationEvent> {
+        Self::graph_reachability_events(Path::new(""), graph)
+    }
+
+    fn graph_reachability_events(path: &Path, graph: &AnimationGraphAsset) -> Vec<AnimationValidationEvent> {
+        let mut events = Vec::new();
+        let entry_state = graph.entry_state.as_ref();
+        if !graph.states.iter().any(|state| state.name.as_ref() == entry_state) {
+            return events;
+        }
+        let mut outgoing: HashMap<&str, Vec<&str>> = HashMap::new();
+        for transition in graph.transitions.iter() {
+            if transition.from != transition.to {
+                outgoing.entry(transition.from.as_ref()).or_default().push(transition.to.as_ref());
+            }
+        }
+        let mut reachable = HashSet::from([entry_state]);
+        let mut queue = VecDeque::from([entry_state]);
+        while let Some(state) = queue.pop_front() {
+            for &next in outgoing.get(state).into_iter().flatten() {
+                if reachable.insert(next) {
+                    queue.push_back(next);
+                }
+            }
+        }
+        for state in graph.states.iter() {
+            let name = state.name.as_ref();
+            if !reachable.contains(name) {
+                events.push(Self::event(
+                    path,
+                    AnimationValidationSeverity::Warning,
+                    format!("State '{name}' is unreachable from entry state '{entry_state}'."),
+                ));
+            }
+        }
+        if graph.states.len() > 1 {
+            for state in graph.states.iter() {
+                let name = state.name.as_ref();
+                if graph.end_state.as_deref() != Some(name) && !outgoing.contains_key(name) {
+                    events.push(Self::event(
+                        path,
+                        AnimationValidationSeverity::Info,
+                        format!(
+                            "State '{name}' has no outgoing transitions; the graph stays there once entered."
+                        ),
+                    ));
+                }
+            }
+        }
+        events
+    }
+
     fn track_summary(clip: &AnimationClip) -> String {
         let mut segments = Vec::new();
         if let Some(track) = clip.translation.as_ref() {
-            segments.push(format!("translation ({} keys)", track.keyframes.len()));
+            segments.push(track_segment("translation", &track.keyframes));
         }
         if let Some(track) = clip.rotation.as_ref() {
-            segments.push(format!("rotation ({} keys)", track.keyframes.len()));
+            segments.push(track_segment("rotation", &track.keyframes));
         }
         if let Some(track) = clip.scale.as_ref() {
-            segments.push(format!("scale ({} keys)", track.keyframes.len()));
+            segments.push(track_segment("scale", &track.keyframes));
         }
         if let Some(track) = clip.tint.as_ref() {
-            segments.push(format!("tint ({} keys)", track.keyframes.len()));
+            segments.push(track_segment("tint", &track.keyframes));
         }
         if segments.is_empty() {
             "no tracks authored".to_string()
@@ -408,6 +549,9 @@ impl AnimationValidator {
         if path_contains_segment(path, "clips") {
             return JsonAssetKind::Clip;
         }
+        if path_contains_segment(path, "curves") {
+            return JsonAssetKind::ColorCurve;
+        }
         if looks_like_atlas_json(bytes) {
             return JsonAssetKind::Atlas;
         }
@@ -417,6 +561,9 @@ impl AnimationValidator {
         if looks_like_graph_json(bytes) {
             return JsonAssetKind::Graph;
         }
+        if looks_like_color_curve_json(bytes) {
+            return JsonAssetKind::ColorCurve;
+        }
         JsonAssetKind::Unknown
     }
 
@@ -438,6 +585,7 @@ enum JsonAssetKind {
     Atlas,
     Clip,
     Graph,
+    ColorCurve,
     Unknown,
 }
 
@@ -463,6 +611,13 @@ fn looks_like_graph_json(bytes: &[u8]) -> bool {
     false
 }
 
+fn looks_like_color_curve_json(bytes: &[u8]) -> bool {
+    if let Ok(Value::Object(map)) = serde_json::from_slice::<Value>(bytes) {
+        return map.get("stops").map(|stops| stops.is_array()).unwrap_or(false);
+    }
+    false
+}
+
 fn looks_like_atlas_json(bytes: &[u8]) -> bool {
     if let Ok(Value::Object(map)) = serde_json::from_slice::<Value>(bytes) {
         let image_ok = map.get("image").map(|value| value.is_string()).unwrap_or(false);
@@ -474,6 +629,16 @@ fn looks_like_atlas_json(bytes: &[u8]) -> bool {
     false
 }
 
+/// `"name (N keys)"`, noting how many keys carry tangents so curved tracks stand out.
+fn track_segment<T>(name: &str, keyframes: &[ClipKeyframe<T>]) -> String {
+    let curved = keyframes.iter().filter(|kf| kf.in_tangent.is_some() || kf.out_tangent.is_some()).count();
+    if curved > 0 {
+        format!("{name} ({} keys, {curved} with tangents)", keyframes.len())
+    } else {
+        format!("{name} ({} keys)", keyframes.len())
+    }
+}
+
 fn min_keyframe_span<T>(keyframes: &[ClipKeyframe<T>]) -> Option<f32> {
     if keyframes.len() < 2 {
         return None;
@@ -545,6 +710,50 @@ mod tests {
         assert!(events.iter().any(|event| event.severity == AnimationValidationSeverity::Info));
     }
 
+    #[test]
+    fn reachability_flags_unreachable_states_and_sinks() {
+        let graph = parse_animation_graph_bytes(
+            br#"{
+                "version": 1,
+                "entry_state": "Idle",
+                "end_state": "Dead",
+                "states": [
+                    {"name": "Idle", "clip": "idle"},
+                    {"name": "Run", "clip": "run"},
+                    {"name": "Stunned", "clip": "stunned"},
+                    {"name": "Dead", "clip": "dead"},
+                    {"name": "Unused", "clip": "unused"}
+                ],
+                "transitions": [
+                    {"from": "Idle", "to": "Run"},
+                    {"from": "Run", "to": "Idle"},
+                    {"from": "Run", "to": "Stunned"},
+                    {"from": "Stunned", "to": "Stunned"},
+                    {"from": "Idle", "to": "Dead"},
+                    {"from": "Unused", "to": "Idle"}
+                ]
+            }"#,
+            "reachability",
+            "inline",
+        )
+        .expect("parse graph");
+        let events = AnimationValidator::validate_graph_reachability(&graph);
+        let messages = |severity| {
+            events
+                .iter()
+                .filter(|event| event.severity == severity)
+                .map(|event| event.message.as_str())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            messages(AnimationValidationSeverity::Warning),
+            ["State 'Unused' is unreachable from entry state 'Idle'."]
+        );
+        let info = messages(AnimationValidationSeverity::Info);
+        assert_eq!(info.len(), 1, "only Stunned is a sink; Dead is the end state: {info:?}");
+        assert!(info[0].starts_with("State 'Stunned' has no outgoing transitions"));
+    }
+
     #[test]
     fn validator_reports_graph_error_for_unknown_state() {
         let mut file = Builder::new().suffix(".json").tempfile().unwrap();
@@ -561,6 +770,29 @@ mod tests {
         assert!(events.iter().any(|event| event.severity == AnimationValidationSeverity::Error));
     }
 
+    #[test]
+    fn validator_reports_graph_error_for_unknown_condition_parameter() {
+        let mut file = Builder::new().suffix(".json").tempfile().unwrap();
+        writeln!(
+            file,
+            r#"{{
+                "version": 1,
+                "states": [{{"name": "Idle", "clip": "idle_clip"}}, {{"name": "Run", "clip": "run_clip"}}],
+                "parameters": [{{"name": "speed"}}],
+                "transitions": [
+                    {{"from": "Idle", "to": "Run", "conditions": [{{"parameter": "speed", "op": ">", "value": 0.5}}]}},
+                    {{"from": "Run", "to": "Idle", "conditions": [{{"parameter": "sped", "op": "<=", "value": 0.5}}]}}
+                ]
+            }}"#
+        )
+        .unwrap();
+        let events = AnimationValidator::validate_path(file.path());
+        let errors: Vec<_> =
+            events.iter().filter(|event| event.severity == AnimationValidationSeverity::Error).collect();
+        assert_eq!(errors.len(), 1, "{errors:?}");
+        assert!(errors[0].message.contains("'sped'"));
+    }
+
     #[test]
     fn validator_accepts_skeletal_asset() {
         let path = Path::new("fixtures/gltf/skeletons/slime_rig.gltf");
@@ -569,6 +801,26 @@ mod tests {
         assert!(events.iter().any(|event| event.severity == AnimationValidationSeverity::Info));
     }
 
+    #[test]
+    fn validator_reports_duplicate_bone_names() {
+        let path = Path::new("fixtures/gltf/skeletons/duplicate_bones.gltf");
+        assert!(path.exists(), "Missing skeletal fixture at {}", path.display());
+        let events = AnimationValidator::validate_bone_name_uniqueness(path);
+        assert_eq!(events.len(), 1, "expected one event per duplicated name: {events:?}");
+        assert_eq!(events[0].severity, AnimationValidationSeverity::Error);
+        assert!(events[0].message.contains("'bone_0'"));
+
+        let events = AnimationValidator::validate_path(path);
+        assert!(events.iter().any(|event| event.severity == AnimationValidationSeverity::Error));
+        assert!(
+            !events.iter().any(|event| event.severity == AnimationValidationSeverity::Info),
+            "duplicate bones should suppress the OK summary"
+        );
+
+        let clean = Path::new("fixtures/gltf/skeletons/slime_rig.gltf");
+        assert!(AnimationValidator::validate_bone_name_uniqueness(clean).is_empty());
+    }
+
     #[test]
     fn validator_accepts_atlas_asset() {
         let path = Path::new("assets/images/atlas.json");
@@ -13,33 +13,67 @@ use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::SystemTime;
 
+mod atlas_pack;
+mod clip_edit;
+mod color_curve;
+pub mod ktx2;
+mod loader;
 pub mod skeletal;
 
+pub use atlas_pack::{AtlasPackReport, DEFAULT_ATLAS_PACK_MAX_SIZE};
+pub use clip_edit::{ClipKeyValue, ClipTrack};
+pub use color_curve::{parse_color_curve_bytes, ColorCurve};
+pub use loader::{AssetLoader, AssetLoaderRegistry};
+
 pub struct AssetManager {
     atlases: HashMap<String, TextureAtlas>,
     clips: HashMap<String, AnimationClip>,
+    color_curves: HashMap<String, Arc<ColorCurve>>,
     animation_graphs: HashMap<String, AnimationGraphAsset>,
     skeletons: HashMap<String, Arc<skeletal::SkeletonAsset>>,
     skeletal_clips: HashMap<String, Arc<skeletal::SkeletalClip>>,
     revision: u64,
     sampler: Option<wgpu::Sampler>,
+    anisotropy: u16,
+    anisotropy_limit: u16,
     device: Option<wgpu::Device>,
     queue: Option<wgpu::Queue>,
-    texture_cache: HashMap<PathBuf, (wgpu::TextureView, (u32, u32))>,
+    texture_cache: HashMap<PathBuf, CachedAtlasTexture>,
     texture_cache_order: VecDeque<PathBuf>,
     atlas_image_cache: HashMap<PathBuf, CachedAtlasImage>,
     atlas_upload_scratch: Vec<u8>,
     atlas_image_cache_order: VecDeque<PathBuf>,
+    packed_atlas_images: HashMap<PathBuf, CachedAtlasImage>,
     atlas_sources: HashMap<String, String>,
     atlas_refs: HashMap<String, usize>,
     clip_sources: HashMap<String, String>,
     clip_refs: HashMap<String, usize>,
+    color_curve_sources: HashMap<String, String>,
+    color_curve_refs: HashMap<String, usize>,
     animation_graph_sources: HashMap<String, String>,
     skeleton_sources: HashMap<String, String>,
     skeleton_refs: HashMap<String, usize>,
     skeletal_clip_sources: HashMap<String, String>,
     skeleton_clip_index: HashMap<String, Vec<String>>,
     atlas_view_fingerprints: HashMap<PathBuf, (SystemTime, Option<u64>)>,
+    normal_maps: HashMap<String, NormalMapEntry>,
+    atlas_loaders: AssetLoaderRegistry<TextureAtlas>,
+    clip_loaders: AssetLoaderRegistry<AnimationClip>,
+}
+
+/// A retained sprite normal map; the texture is uploaded on first use.
+struct NormalMapEntry {
+    refs: usize,
+    width: u32,
+    height: u32,
+    view: Option<wgpu::TextureView>,
+}
+
+/// An uploaded atlas texture and what it costs on the GPU.
+struct CachedAtlasTexture {
+    view: wgpu::TextureView,
+    format: wgpu::TextureFormat,
+    bytes: u64,
 }
 
 struct CachedAtlasImage {
@@ -59,17 +93,25 @@ fn build_vec2_track(raw: ClipVec2TrackFile) -> Result<(ClipVec2Track, f32)> {
         return Err(anyhow!("Clip vec2 track must contain at least one keyframe"));
     }
     let interpolation = convert_interpolation(raw.interpolation);
-    let (keyframes, duration) = build_keyframes(raw.keyframes, |kf| {
+    let (mut keyframes, duration) = build_keyframes(raw.keyframes, |kf| {
         let value = Vec2::new(kf.value[0], kf.value[1]);
-        if !value.is_finite() {
+        let in_tangent = kf.in_tangent.map(Vec2::from);
+        let out_tangent = kf.out_tangent.map(Vec2::from);
+        if !value.is_finite() || [in_tangent, out_tangent].iter().flatten().any(|t| !t.is_finite()) {
             return Err(anyhow!("Clip keyframe contains non-finite translation/scale value"));
         }
-        Ok(ClipKeyframe { time: kf.time, value })
+        Ok(ClipKeyframe { time: kf.time, value, in_tangent, out_tangent })
     })?;
+    if raw.auto_tangent {
+        let mut frames = keyframes.to_vec();
+        fill_auto_tangents(&mut frames, Vec2::ZERO);
+        keyframes = Arc::from(frames.into_boxed_slice());
+    }
     let (segment_deltas, segments, segment_offsets) = build_segment_cache_vec2(keyframes.as_ref());
     Ok((
         ClipVec2Track {
             interpolation,
+            auto_tangent: raw.auto_tangent,
             keyframes,
             duration,
             duration_inv: if duration > 0.0 { 1.0 / duration } else { 0.0 },
@@ -86,16 +128,27 @@ fn build_scalar_track(raw: ClipScalarTrackFile) -> Result<(ClipScalarTrack, f32)
         return Err(anyhow!("Clip scalar track must contain at least one keyframe"));
     }
     let interpolation = convert_interpolation(raw.interpolation);
-    let (keyframes, duration) = build_keyframes(raw.keyframes, |kf| {
-        if !kf.value.is_finite() {
+    let (mut keyframes, duration) = build_keyframes(raw.keyframes, |kf| {
+        if !kf.value.is_finite() || [kf.in_tangent, kf.out_tangent].iter().flatten().any(|t| !t.is_finite()) {
             return Err(anyhow!("Clip keyframe contains non-finite rotation value"));
         }
-        Ok(ClipKeyframe { time: kf.time, value: kf.value })
+        Ok(ClipKeyframe {
+            time: kf.time,
+            value: kf.value,
+            in_tangent: kf.in_tangent,
+            out_tangent: kf.out_tangent,
+        })
     })?;
+    if raw.auto_tangent {
+        let mut frames = keyframes.to_vec();
+        fill_auto_tangents(&mut frames, 0.0);
+        keyframes = Arc::from(frames.into_boxed_slice());
+    }
     let (segment_deltas, segments, segment_offsets) = build_segment_cache_scalar(keyframes.as_ref());
     Ok((
         ClipScalarTrack {
             interpolation,
+            auto_tangent: raw.auto_tangent,
             keyframes,
             duration,
             duration_inv: if duration > 0.0 { 1.0 / duration } else { 0.0 },
@@ -112,17 +165,25 @@ fn build_vec4_track(raw: ClipVec4TrackFile) -> Result<(ClipVec4Track, f32)> {
         return Err(anyhow!("Clip vec4 track must contain at least one keyframe"));
     }
     let interpolation = convert_interpolation(raw.interpolation);
-    let (keyframes, duration) = build_keyframes(raw.keyframes, |kf| {
-        let value = Vec4::new(kf.value[0], kf.value[1], kf.value[2], kf.value[3]);
-        if !value.is_finite() {
+    let (mut keyframes, duration) = build_keyframes(raw.keyframes, |kf| {
+        let value = Vec4::from(kf.value);
+        let in_tangent = kf.in_tangent.map(Vec4::from);
+        let out_tangent = kf.out_tangent.map(Vec4::from);
+        if !value.is_finite() || [in_tangent, out_tangent].iter().flatten().any(|t| !t.is_finite()) {
             return Err(anyhow!("Clip keyframe contains non-finite tint value"));
         }
-        Ok(ClipKeyframe { time: kf.time, value })
+        Ok(ClipKeyframe { time: kf.time, value, in_tangent, out_tangent })
     })?;
+    if raw.auto_tangent {
+        let mut frames = keyframes.to_vec();
+        fill_auto_tangents(&mut frames, Vec4::ZERO);
+        keyframes = Arc::from(frames.into_boxed_slice());
+    }
     let (segment_deltas, segments, segment_offsets) = build_segment_cache_vec4(keyframes.as_ref());
     Ok((
         ClipVec4Track {
             interpolation,
+            auto_tangent: raw.auto_tangent,
             keyframes,
             duration,
             duration_inv: if duration > 0.0 { 1.0 / duration } else { 0.0 },
@@ -245,6 +306,47 @@ where
     Ok((arc, duration))
 }
 
+/// Keys closer together than this count as sharing a time when auto tangents are computed.
+const AUTO_TANGENT_TIME_EPSILON: f32 = 1e-4;
+
+/// Fills missing tangents of a sorted track with Catmull-Rom slopes: the value change between the
+/// previous and next key over the time between them. The first and last key get a zero tangent, and
+/// a key sharing its time with a neighbour copies the nearest neighbour's tangent instead.
+fn fill_auto_tangents<T>(frames: &mut [ClipKeyframe<T>], zero: T)
+where
+    T: Copy + std::ops::Sub<Output = T> + std::ops::Mul<f32, Output = T>,
+{
+    let count = frames.len();
+    let mut tangents: Vec<Option<T>> = (0..count)
+        .map(|index| {
+            if index == 0 || index + 1 == count {
+                return Some(zero);
+            }
+            let (prev, current, next) = (&frames[index - 1], &frames[index], &frames[index + 1]);
+            if current.time - prev.time <= AUTO_TANGENT_TIME_EPSILON
+                || next.time - current.time <= AUTO_TANGENT_TIME_EPSILON
+            {
+                return None;
+            }
+            Some((next.value - prev.value) * (1.0 / (next.time - prev.time)))
+        })
+        .collect();
+    for index in 0..count {
+        if tangents[index].is_some() {
+            continue;
+        }
+        let nearest = (1..count).find_map(|distance| {
+            let before = index.checked_sub(distance).and_then(|i| tangents[i]);
+            before.or_else(|| tangents.get(index + distance).copied().flatten())
+        });
+        tangents[index] = Some(nearest.unwrap_or(zero));
+    }
+    for (frame, tangent) in frames.iter_mut().zip(tangents.into_iter().flatten()) {
+        frame.in_tangent.get_or_insert(tangent);
+        frame.out_tangent.get_or_insert(tangent);
+    }
+}
+
 fn convert_interpolation(file: ClipInterpolationFile) -> ClipInterpolation {
     match file {
         ClipInterpolationFile::Linear => ClipInterpolation::Linear,
@@ -372,6 +474,8 @@ impl<T: Copy> ClipSegment<T> {
 #[derive(Clone)]
 pub struct ClipVec2Track {
     pub interpolation: ClipInterpolation,
+    /// Missing key tangents were filled with Catmull-Rom slopes when the clip was parsed.
+    pub auto_tangent: bool,
     pub keyframes: Arc<[ClipKeyframe<Vec2>]>,
     pub duration: f32,
     pub duration_inv: f32,
@@ -383,6 +487,8 @@ pub struct ClipVec2Track {
 #[derive(Clone)]
 pub struct ClipScalarTrack {
     pub interpolation: ClipInterpolation,
+    /// Missing key tangents were filled with Catmull-Rom slopes when the clip was parsed.
+    pub auto_tangent: bool,
     pub keyframes: Arc<[ClipKeyframe<f32>]>,
     pub duration: f32,
     pub duration_inv: f32,
@@ -394,6 +500,8 @@ pub struct ClipScalarTrack {
 #[derive(Clone)]
 pub struct ClipVec4Track {
     pub interpolation: ClipInterpolation,
+    /// Missing key tangents were filled with Catmull-Rom slopes when the clip was parsed.
+    pub auto_tangent: bool,
     pub keyframes: Arc<[ClipKeyframe<Vec4>]>,
     pub duration: f32,
     pub duration_inv: f32,
@@ -402,10 +510,53 @@ pub struct ClipVec4Track {
     pub segment_offsets: Arc<[f32]>,
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct ClipKeyframe<T> {
     pub time: f32,
     pub value: T,
+    /// Slope (value per second) arriving at this key; `None` when the clip does not define one.
+    pub in_tangent: Option<T>,
+    /// Slope (value per second) leaving this key.
+    pub out_tangent: Option<T>,
+}
+
+impl<T> ClipKeyframe<T> {
+    /// A key without tangents.
+    pub const fn new(time: f32, value: T) -> Self {
+        Self { time, value, in_tangent: None, out_tangent: None }
+    }
+}
+
+impl<T> ClipKeyframe<T>
+where
+    T: Copy + std::ops::Add<Output = T> + std::ops::Sub<Output = T> + std::ops::Mul<f32, Output = T>,
+{
+    /// Whether the segment from this key to `next` is a cubic curve rather than a straight line.
+    #[inline(always)]
+    pub fn curves_to(&self, next: &Self) -> bool {
+        self.out_tangent.is_some() || next.in_tangent.is_some()
+    }
+
+    /// Cubic Hermite value `offset` seconds into the segment to `next`, or `None` when neither key
+    /// defines a tangent and the segment stays linear. A missing tangent on one side uses the
+    /// segment's linear slope, so a single eased key blends into its straight neighbour.
+    #[inline]
+    pub fn curve_to(&self, next: &Self, offset: f32) -> Option<T> {
+        let span = next.time - self.time;
+        if !self.curves_to(next) || span <= f32::EPSILON {
+            return None;
+        }
+        let linear = (next.value - self.value) * (1.0 / span);
+        let out_tangent = self.out_tangent.unwrap_or(linear);
+        let in_tangent = next.in_tangent.unwrap_or(linear);
+        let s = (offset / span).clamp(0.0, 1.0);
+        let (s2, s3) = (s * s, s * s * s);
+        let h00 = 2.0 * s3 - 3.0 * s2 + 1.0;
+        let h10 = s3 - 2.0 * s2 + s;
+        let h01 = 3.0 * s2 - 2.0 * s3;
+        let h11 = s3 - s2;
+        Some(self.value * h00 + out_tangent * (h10 * span) + next.value * h01 + in_tangent * (h11 * span))
+    }
 }
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -419,6 +570,8 @@ pub struct AnimationGraphAsset {
     pub name: Arc<str>,
     pub version: u32,
     pub entry_state: Arc<str>,
+    /// Terminal state that is expected to have no outgoing transitions.
+    pub end_state: Option<Arc<str>>,
     pub states: Arc<[AnimationGraphState]>,
     pub transitions: Arc<[AnimationGraphTransition]>,
     pub parameters: Arc<[AnimationGraphParameter]>,
@@ -428,12 +581,93 @@ pub struct AnimationGraphAsset {
 pub struct AnimationGraphState {
     pub name: Arc<str>,
     pub clip: Option<String>,
+    pub blend: Option<AnimationGraphBlend1D>,
+}
+
+/// One-dimensional blend space: clips placed along a parameter axis, weighted by the two
+/// positions that bracket the parameter's current value.
+#[derive(Clone)]
+pub struct AnimationGraphBlend1D {
+    pub parameter: Arc<str>,
+    /// Sorted by `position`.
+    pub clips: Arc<[AnimationGraphBlendClip]>,
+}
+
+#[derive(Clone)]
+pub struct AnimationGraphBlendClip {
+    pub clip: String,
+    pub position: f32,
+}
+
+impl AnimationGraphBlend1D {
+    /// Per-clip weights (summing to one) for `value`, clamped to the outermost positions.
+    pub fn weights(&self, value: f32) -> Vec<f32> {
+        let mut weights = vec![0.0; self.clips.len()];
+        let Some(last) = self.clips.len().checked_sub(1) else {
+            return weights;
+        };
+        if value <= self.clips[0].position {
+            weights[0] = 1.0;
+        } else if value >= self.clips[last].position {
+            weights[last] = 1.0;
+        } else {
+            for (index, pair) in self.clips.windows(2).enumerate() {
+                if value <= pair[1].position {
+                    let span = pair[1].position - pair[0].position;
+                    let t = if span > 0.0 { (value - pair[0].position) / span } else { 1.0 };
+                    weights[index] = 1.0 - t;
+                    weights[index + 1] = t;
+                    break;
+                }
+            }
+        }
+        weights
+    }
 }
 
 #[derive(Clone)]
 pub struct AnimationGraphTransition {
     pub from: Arc<str>,
     pub to: Arc<str>,
+    pub conditions: Arc<[AnimationGraphCondition]>,
+    pub trigger: Option<Arc<str>>,
+    pub duration: f32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+pub enum AnimationGraphComparison {
+    #[serde(rename = ">")]
+    Greater,
+    #[serde(rename = ">=")]
+    GreaterOrEqual,
+    #[serde(rename = "<")]
+    Less,
+    #[serde(rename = "<=")]
+    LessOrEqual,
+    #[serde(rename = "==")]
+    Equal,
+    #[serde(rename = "!=")]
+    NotEqual,
+}
+
+#[derive(Clone)]
+pub struct AnimationGraphCondition {
+    pub parameter: Arc<str>,
+    pub comparison: AnimationGraphComparison,
+    pub value: f32,
+}
+
+impl AnimationGraphCondition {
+    pub fn holds(&self, actual: f32) -> bool {
+        match self.comparison {
+            AnimationGraphComparison::Greater => actual > self.value,
+            AnimationGraphComparison::GreaterOrEqual => actual >= self.value,
+            AnimationGraphComparison::Less => actual < self.value,
+            AnimationGraphComparison::LessOrEqual => actual <= self.value,
+            AnimationGraphComparison::Equal => actual == self.value,
+            AnimationGraphComparison::NotEqual => actual != self.value,
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, Deserialize)]
@@ -543,6 +777,8 @@ struct ClipTracksFile {
 struct ClipVec2TrackFile {
     #[serde(default = "default_clip_interpolation")]
     interpolation: ClipInterpolationFile,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    auto_tangent: bool,
     keyframes: Vec<ClipVec2KeyframeFile>,
 }
 
@@ -550,6 +786,8 @@ struct ClipVec2TrackFile {
 struct ClipScalarTrackFile {
     #[serde(default = "default_clip_interpolation")]
     interpolation: ClipInterpolationFile,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    auto_tangent: bool,
     keyframes: Vec<ClipScalarKeyframeFile>,
 }
 
@@ -557,6 +795,8 @@ struct ClipScalarTrackFile {
 struct ClipVec4TrackFile {
     #[serde(default = "default_clip_interpolation")]
     interpolation: ClipInterpolationFile,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    auto_tangent: bool,
     keyframes: Vec<ClipVec4KeyframeFile>,
 }
 
@@ -571,18 +811,30 @@ enum ClipInterpolationFile {
 struct ClipVec2KeyframeFile {
     time: f32,
     value: [f32; 2],
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    in_tangent: Option<[f32; 2]>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    out_tangent: Option<[f32; 2]>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 struct ClipScalarKeyframeFile {
     time: f32,
     value: f32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    in_tangent: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    out_tangent: Option<f32>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 struct ClipVec4KeyframeFile {
     time: f32,
     value: [f32; 4],
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    in_tangent: Option<[f32; 4]>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    out_tangent: Option<[f32; 4]>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -590,6 +842,8 @@ struct AnimationGraphFile {
     version: Option<u32>,
     name: Option<String>,
     entry_state: Option<String>,
+    #[serde(default)]
+    end_state: Option<String>,
     states: Vec<AnimationGraphStateFile>,
     #[serde(default)]
     transitions: Vec<AnimationGraphTransitionFile>,
@@ -601,12 +855,39 @@ struct AnimationGraphFile {
 struct AnimationGraphStateFile {
     name: String,
     clip: Option<String>,
+    #[serde(default)]
+    blend: Option<AnimationGraphBlendFile>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AnimationGraphBlendFile {
+    parameter: String,
+    clips: Vec<AnimationGraphBlendClipFile>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AnimationGraphBlendClipFile {
+    clip: String,
+    position: f32,
 }
 
 #[derive(Debug, Deserialize)]
 struct AnimationGraphTransitionFile {
     from: String,
     to: String,
+    #[serde(default)]
+    conditions: Vec<AnimationGraphConditionFile>,
+    #[serde(default)]
+    trigger: Option<String>,
+    #[serde(default)]
+    duration: f32,
+}
+
+#[derive(Debug, Deserialize)]
+struct AnimationGraphConditionFile {
+    parameter: String,
+    op: AnimationGraphComparison,
+    value: f32,
 }
 
 #[derive(Debug, Deserialize)]
@@ -645,10 +926,16 @@ fn clip_to_file(clip: &AnimationClip) -> ClipFile {
 fn vec2_track_to_file(track: &ClipVec2Track) -> ClipVec2TrackFile {
     ClipVec2TrackFile {
         interpolation: convert_interpolation_to_file(track.interpolation),
+        auto_tangent: track.auto_tangent,
         keyframes: track
             .keyframes
             .iter()
-            .map(|kf| ClipVec2KeyframeFile { time: kf.time, value: [kf.value.x, kf.value.y] })
+            .map(|kf| ClipVec2KeyframeFile {
+                time: kf.time,
+                value: kf.value.to_array(),
+                in_tangent: kf.in_tangent.map(|t| t.to_array()),
+                out_tangent: kf.out_tangent.map(|t| t.to_array()),
+            })
             .collect(),
     }
 }
@@ -656,10 +943,16 @@ fn vec2_track_to_file(track: &ClipVec2Track) -> ClipVec2TrackFile {
 fn scalar_track_to_file(track: &ClipScalarTrack) -> ClipScalarTrackFile {
     ClipScalarTrackFile {
         interpolation: convert_interpolation_to_file(track.interpolation),
+        auto_tangent: track.auto_tangent,
         keyframes: track
             .keyframes
             .iter()
-            .map(|kf| ClipScalarKeyframeFile { time: kf.time, value: kf.value })
+            .map(|kf| ClipScalarKeyframeFile {
+                time: kf.time,
+                value: kf.value,
+                in_tangent: kf.in_tangent,
+                out_tangent: kf.out_tangent,
+            })
             .collect(),
     }
 }
@@ -667,12 +960,15 @@ fn scalar_track_to_file(track: &ClipScalarTrack) -> ClipScalarTrackFile {
 fn vec4_track_to_file(track: &ClipVec4Track) -> ClipVec4TrackFile {
     ClipVec4TrackFile {
         interpolation: convert_interpolation_to_file(track.interpolation),
+        auto_tangent: track.auto_tangent,
         keyframes: track
             .keyframes
             .iter()
             .map(|kf| ClipVec4KeyframeFile {
                 time: kf.time,
-                value: [kf.value.x, kf.value.y, kf.value.z, kf.value.w],
+                value: kf.value.to_array(),
+                in_tangent: kf.in_tangent.map(|t| t.to_array()),
+                out_tangent: kf.out_tangent.map(|t| t.to_array()),
             })
             .collect(),
     }
@@ -754,16 +1050,42 @@ pub fn parse_animation_graph_bytes(
         if state.name.trim().is_empty() {
             return Err(anyhow!("Animation graph contains a state with an empty name in {source_label}"));
         }
-        states.push(AnimationGraphState { name: Arc::from(state.name), clip: state.clip });
+        let blend = match state.blend {
+            Some(blend) => {
+                Some(parse_animation_graph_blend(&state.name, blend, &file.parameters, source_label)?)
+            }
+            None => None,
+        };
+        states.push(AnimationGraphState { name: Arc::from(state.name), clip: state.clip, blend });
     }
     let mut transitions: Vec<AnimationGraphTransition> = Vec::new();
     for transition in file.transitions {
         if transition.from.trim().is_empty() || transition.to.trim().is_empty() {
             return Err(anyhow!("Animation graph transition names cannot be empty in {source_label}"));
         }
+        if !transition.duration.is_finite() || transition.duration < 0.0 {
+            return Err(anyhow!(
+                "Animation graph transition '{}' -> '{}' has invalid duration {} in {source_label}",
+                transition.from,
+                transition.to,
+                transition.duration
+            ));
+        }
+        let conditions: Vec<AnimationGraphCondition> = transition
+            .conditions
+            .into_iter()
+            .map(|condition| AnimationGraphCondition {
+                parameter: Arc::from(condition.parameter),
+                comparison: condition.op,
+                value: condition.value,
+            })
+            .collect();
         transitions.push(AnimationGraphTransition {
             from: Arc::from(transition.from),
             to: Arc::from(transition.to),
+            conditions: Arc::from(conditions.into_boxed_slice()),
+            trigger: transition.trigger.filter(|name| !name.trim().is_empty()).map(Arc::from),
+            duration: transition.duration,
         });
     }
     let mut parameters: Vec<AnimationGraphParameter> = Vec::new();
@@ -785,12 +1107,46 @@ pub fn parse_animation_graph_bytes(
         name: Arc::from(graph_name),
         version,
         entry_state: Arc::from(entry_state),
+        end_state: file.end_state.filter(|name| !name.trim().is_empty()).map(Arc::from),
         states: Arc::from(states.into_boxed_slice()),
         transitions: Arc::from(transitions.into_boxed_slice()),
         parameters: Arc::from(parameters.into_boxed_slice()),
     })
 }
 
+fn parse_animation_graph_blend(
+    state: &str,
+    blend: AnimationGraphBlendFile,
+    parameters: &[AnimationGraphParameterFile],
+    source_label: &str,
+) -> Result<AnimationGraphBlend1D> {
+    if !parameters.iter().any(|param| param.name == blend.parameter) {
+        return Err(anyhow!(
+            "Animation graph state '{state}' blends on undeclared parameter '{}' in {source_label}",
+            blend.parameter
+        ));
+    }
+    if blend.clips.is_empty() {
+        return Err(anyhow!("Animation graph state '{state}' has a blend without clips in {source_label}"));
+    }
+    let mut clips = Vec::with_capacity(blend.clips.len());
+    for entry in blend.clips {
+        if entry.clip.trim().is_empty() || !entry.position.is_finite() {
+            return Err(anyhow!(
+                "Animation graph state '{state}' has an invalid blend clip '{}' at {} in {source_label}",
+                entry.clip,
+                entry.position
+            ));
+        }
+        clips.push(AnimationGraphBlendClip { clip: entry.clip, position: entry.position });
+    }
+    clips.sort_by(|a, b| a.position.total_cmp(&b.position));
+    Ok(AnimationGraphBlend1D {
+        parameter: Arc::from(blend.parameter),
+        clips: Arc::from(clips.into_boxed_slice()),
+    })
+}
+
 pub fn parse_texture_atlas_bytes(
     bytes: &[u8],
     key_hint: &str,
@@ -956,11 +1312,14 @@ impl AssetManager {
         Self {
             atlases: HashMap::new(),
             clips: HashMap::new(),
+            color_curves: HashMap::new(),
             animation_graphs: HashMap::new(),
             skeletons: HashMap::new(),
             skeletal_clips: HashMap::new(),
             revision: 0,
             sampler: None,
+            anisotropy: 1,
+            anisotropy_limit: MAX_SAMPLER_ANISOTROPY,
             device: None,
             queue: None,
             texture_cache: HashMap::new(),
@@ -968,19 +1327,42 @@ impl AssetManager {
             atlas_image_cache: HashMap::new(),
             atlas_upload_scratch: Vec::new(),
             atlas_image_cache_order: VecDeque::new(),
+            packed_atlas_images: HashMap::new(),
             atlas_sources: HashMap::new(),
             atlas_refs: HashMap::new(),
             clip_sources: HashMap::new(),
             clip_refs: HashMap::new(),
+            color_curve_sources: HashMap::new(),
+            color_curve_refs: HashMap::new(),
             animation_graph_sources: HashMap::new(),
             skeleton_sources: HashMap::new(),
             skeleton_refs: HashMap::new(),
             skeletal_clip_sources: HashMap::new(),
             skeleton_clip_index: HashMap::new(),
             atlas_view_fingerprints: HashMap::new(),
+            normal_maps: HashMap::new(),
+            atlas_loaders: AssetLoaderRegistry::default(),
+            clip_loaders: AssetLoaderRegistry::default(),
         }
     }
 
+    /// Routes atlases whose source path has one of the loader's extensions through it instead of
+    /// the built-in JSON parser. Errors if another atlas loader already claims an extension.
+    pub fn register_atlas_loader(
+        &mut self,
+        loader: Box<dyn AssetLoader<Asset = TextureAtlas>>,
+    ) -> Result<()> {
+        self.atlas_loaders.register(loader)
+    }
+
+    /// Clip counterpart of [`Self::register_atlas_loader`].
+    pub fn register_clip_loader(
+        &mut self,
+        loader: Box<dyn AssetLoader<Asset = AnimationClip>>,
+    ) -> Result<()> {
+        self.clip_loaders.register(loader)
+    }
+
     fn bump_revision(&mut self) {
         self.revision = self.revision.wrapping_add(1);
     }
@@ -998,32 +1380,67 @@ impl AssetManager {
     pub fn set_device(&mut self, device: &wgpu::Device, queue: &wgpu::Queue) {
         self.device = Some(device.clone());
         self.queue = Some(queue.clone());
-        self.sampler = Some(device.create_sampler(&wgpu::SamplerDescriptor {
-            label: Some("Default Sampler"),
-            address_mode_u: wgpu::AddressMode::ClampToEdge,
-            address_mode_v: wgpu::AddressMode::ClampToEdge,
-            address_mode_w: wgpu::AddressMode::ClampToEdge,
-            mag_filter: wgpu::FilterMode::Linear,
-            min_filter: wgpu::FilterMode::Linear,
-            mipmap_filter: wgpu::FilterMode::Nearest,
-            ..Default::default()
-        }));
+        self.sampler = Some(create_default_sampler(device, self.anisotropy));
         self.texture_cache.clear();
         self.atlas_image_cache.clear();
         self.atlas_image_cache_order.clear();
         self.atlas_view_fingerprints.clear();
+        for entry in self.normal_maps.values_mut() {
+            entry.view = None;
+        }
     }
     pub fn default_sampler(&self) -> &wgpu::Sampler {
         self.sampler.as_ref().expect("sampler")
     }
+
+    /// Highest anisotropy the adapter supports; 1 when anisotropic filtering is unavailable.
+    pub fn set_anisotropy_limit(&mut self, limit: u16) {
+        self.anisotropy_limit = limit.clamp(1, MAX_SAMPLER_ANISOTROPY);
+        if self.anisotropy > self.anisotropy_limit {
+            self.set_anisotropy(self.anisotropy);
+        }
+    }
+
+    pub fn anisotropy(&self) -> u16 {
+        self.anisotropy
+    }
+
+    /// Recreates the default sampler with the requested max anisotropy and returns the applied
+    /// (clamped) level. Callers must drop bind groups that captured the previous sampler.
+    pub fn set_anisotropy(&mut self, level: u16) -> u16 {
+        let applied = level.clamp(1, self.anisotropy_limit);
+        if applied < level {
+            if self.anisotropy_limit == 1 {
+                eprintln!("[assets] adapter lacks anisotropic filtering; clamped {level}x to 1x");
+            } else {
+                eprintln!("[assets] anisotropy {level}x exceeds adapter limit; clamped to {applied}x");
+            }
+        }
+        if applied != self.anisotropy {
+            self.anisotropy = applied;
+            if let Some(device) = self.device.as_ref() {
+                self.sampler = Some(create_default_sampler(device, applied));
+            }
+        }
+        applied
+    }
     pub fn load_atlas(&mut self, key: &str, json_path: &str) -> Result<()> {
         let _ = self.load_atlas_internal(key, json_path)?;
         Ok(())
     }
     fn load_atlas_internal(&mut self, key: &str, json_path: &str) -> Result<TextureAtlasDiagnostics> {
+        if Path::new(json_path).is_dir() {
+            let max_size = self.atlas_pack_max_size();
+            return self.pack_atlas_with_max_size(key, json_path, max_size).map(|report| report.diagnostics);
+        }
         let bytes = fs::read(json_path)?;
-        let TextureAtlasParseResult { atlas, diagnostics } =
-            parse_texture_atlas_bytes(&bytes, key, json_path)?;
+        let TextureAtlasParseResult { atlas, diagnostics } = match self.atlas_loaders.loader_for(json_path) {
+            Some(loader) => TextureAtlasParseResult {
+                atlas: loader.load(&bytes, key, json_path)?,
+                diagnostics: TextureAtlasDiagnostics::default(),
+            },
+            None => parse_texture_atlas_bytes(&bytes, key, json_path)?,
+        };
         for warning in &diagnostics.warnings {
             eprintln!("[assets] {warning}");
         }
@@ -1033,6 +1450,59 @@ impl AssetManager {
         Ok(diagnostics)
     }
 
+    /// Packs every PNG in `dir` into a single atlas texture registered as `key`, with one region
+    /// per image named after its file stem. Retaining or reloading an atlas whose source is a
+    /// directory re-packs it, so loose images can be dropped in without a packing tool.
+    pub fn pack_atlas(&mut self, key: &str, dir: &str) -> Result<AtlasPackReport> {
+        let max_size = self.atlas_pack_max_size();
+        self.pack_atlas_with_max_size(key, dir, max_size)
+    }
+
+    /// Like [`Self::pack_atlas`], but images that do not fit a `max_size` square are left out
+    /// and listed in [`AtlasPackReport::overflow`].
+    pub fn pack_atlas_with_max_size(
+        &mut self,
+        key: &str,
+        dir: &str,
+        max_size: u32,
+    ) -> Result<AtlasPackReport> {
+        let atlas_pack::PackedAtlas { atlas, pixels, report } =
+            atlas_pack::pack_atlas_dir(key, Path::new(dir), max_size)?;
+        for warning in &report.diagnostics.warnings {
+            eprintln!("[assets] {warning}");
+        }
+        println!(
+            "[assets] Packed {} image(s) into atlas '{key}' ({}x{}, {:.1}% wasted)",
+            report.packed,
+            report.width,
+            report.height,
+            report.wasted_fraction() * 100.0
+        );
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        hasher.write(&pixels);
+        self.packed_atlas_images.insert(
+            atlas.image_path.clone(),
+            CachedAtlasImage {
+                modified: SystemTime::now(),
+                width: atlas.width,
+                height: atlas.height,
+                pixels: Arc::from(pixels.into_boxed_slice()),
+                sample: Some(hasher.finish()),
+            },
+        );
+        self.atlases.insert(key.to_string(), atlas);
+        self.atlas_sources.insert(key.to_string(), dir.to_string());
+        self.bump_revision();
+        Ok(report)
+    }
+
+    fn atlas_pack_max_size(&self) -> u32 {
+        self.device
+            .as_ref()
+            .map(|device| device.limits().max_texture_dimension_2d)
+            .unwrap_or(DEFAULT_ATLAS_PACK_MAX_SIZE)
+    }
+
     pub fn load_clip(&mut self, key: &str, json_path: &str) -> Result<()> {
         self.load_clip_internal(key, json_path)
     }
@@ -1043,7 +1513,10 @@ impl AssetManager {
     }
 
     pub fn load_clip_from_bytes(&mut self, key: &str, json_path: &str, bytes: &[u8]) -> Result<()> {
-        let clip = parse_animation_clip_bytes(bytes, key, json_path)?;
+        let clip = match self.clip_loaders.loader_for(json_path) {
+            Some(loader) => loader.load(bytes, key, json_path)?,
+            None => parse_animation_clip_bytes(bytes, key, json_path)?,
+        };
         self.replace_clip(key, json_path, clip);
         Ok(())
     }
@@ -1205,6 +1678,122 @@ impl AssetManager {
         })
     }
 
+    pub fn load_color_curve(&mut self, key: &str, json_path: &str) -> Result<()> {
+        let bytes = fs::read(json_path)?;
+        self.load_color_curve_from_bytes(key, json_path, &bytes)
+    }
+
+    pub fn load_color_curve_from_bytes(&mut self, key: &str, json_path: &str, bytes: &[u8]) -> Result<()> {
+        let curve = parse_color_curve_bytes(bytes, key, json_path)?;
+        self.replace_color_curve(key, json_path, curve);
+        Ok(())
+    }
+
+    /// Swaps in a new curve for `key`. Entities keep the previous `Arc` until
+    /// [`EcsWorld::refresh_color_curve`](crate::ecs::EcsWorld::refresh_color_curve) rebinds them.
+    pub fn replace_color_curve(&mut self, key: &str, json_path: &str, curve: ColorCurve) {
+        self.color_curves.insert(key.to_string(), Arc::new(curve));
+        self.color_curve_sources.insert(key.to_string(), json_path.to_string());
+        self.bump_revision();
+    }
+
+    pub fn retain_color_curve(&mut self, key: &str, json_path: Option<&str>) -> Result<()> {
+        let path_owned = if let Some(path) = json_path {
+            path.to_string()
+        } else if let Some(stored) = self.color_curve_sources.get(key) {
+            stored.clone()
+        } else {
+            return Err(anyhow!("Color curve '{key}' is not loaded and no JSON path provided to retain it."));
+        };
+        if self.color_curves.contains_key(key) {
+            if let Some(stored) = self.color_curve_sources.get(key) {
+                if stored != &path_owned {
+                    return Err(anyhow!(
+                        "Color curve '{key}' is already loaded from '{}'; refusing to swap to '{}'",
+                        stored,
+                        path_owned
+                    ));
+                }
+            }
+            *self.color_curve_refs.entry(key.to_string()).or_insert(0) += 1;
+            return Ok(());
+        }
+        self.load_color_curve(key, &path_owned)?;
+        self.color_curve_refs.insert(key.to_string(), 1);
+        Ok(())
+    }
+
+    /// Retains every `*.json` curve directly inside `dir` under its file stem, so curves can be
+    /// picked and used by scripts before any scene depends on them. Keys that are already loaded
+    /// are skipped. Returns the keys retained.
+    pub fn retain_color_curve_directory<P: AsRef<Path>>(&mut self, dir: P) -> Result<Vec<String>> {
+        let dir_path = dir.as_ref();
+        if !dir_path.exists() {
+            return Ok(Vec::new());
+        }
+        let mut retained = Vec::new();
+        let entries = fs::read_dir(dir_path)
+            .with_context(|| format!("reading color curve directory '{}'", dir_path.display()))?;
+        for entry in entries {
+            let entry = entry?;
+            let path = entry.path();
+            if !entry.file_type()?.is_file() || path.extension().and_then(|ext| ext.to_str()) != Some("json")
+            {
+                continue;
+            }
+            let Some(key) = path.file_stem().and_then(|stem| stem.to_str()).map(str::to_string) else {
+                continue;
+            };
+            if self.color_curves.contains_key(&key) {
+                continue;
+            }
+            self.retain_color_curve(&key, Some(&path.to_string_lossy()))
+                .with_context(|| format!("loading color curve '{}'", path.display()))?;
+            retained.push(key);
+        }
+        retained.sort();
+        Ok(retained)
+    }
+
+    pub fn release_color_curve(&mut self, key: &str) -> bool {
+        let Some(count) = self.color_curve_refs.get_mut(key) else {
+            return false;
+        };
+        *count = count.saturating_sub(1);
+        if *count == 0 {
+            self.color_curve_refs.remove(key);
+            self.color_curves.remove(key);
+            self.color_curve_sources.remove(key);
+            self.bump_revision();
+        }
+        true
+    }
+
+    pub fn color_curve(&self, key: &str) -> Option<Arc<ColorCurve>> {
+        self.color_curves.get(key).cloned()
+    }
+
+    pub fn color_curve_keys(&self) -> Vec<String> {
+        let mut keys: Vec<String> = self.color_curves.keys().cloned().collect();
+        keys.sort();
+        keys
+    }
+
+    pub fn color_curve_source(&self, key: &str) -> Option<&str> {
+        self.color_curve_sources.get(key).map(|s| s.as_str())
+    }
+
+    pub fn color_curve_sources(&self) -> Vec<(String, String)> {
+        self.color_curve_sources.iter().map(|(key, path)| (key.clone(), path.clone())).collect()
+    }
+
+    pub fn color_curve_key_for_source_path<P: AsRef<Path>>(&self, path: P) -> Option<String> {
+        let target = normalize_asset_path(path.as_ref());
+        self.color_curve_sources.iter().find_map(|(key, stored)| {
+            (normalize_asset_path(Path::new(stored)) == target).then(|| key.clone())
+        })
+    }
+
     pub fn load_animation_graph(&mut self, key: &str, json_path: &str) -> Result<()> {
         let bytes = fs::read(json_path)?;
         self.load_animation_graph_from_bytes(key, json_path, &bytes)
@@ -1231,6 +1820,10 @@ impl AssetManager {
         self.animation_graphs.get(key)
     }
 
+    pub fn animation_graph_source(&self, key: &str) -> Option<&str> {
+        self.animation_graph_sources.get(key).map(|s| s.as_str())
+    }
+
     pub fn animation_graph_sources(&self) -> Vec<(String, String)> {
         self.animation_graph_sources.iter().map(|(key, path)| (key.clone(), path.clone())).collect()
     }
@@ -1406,6 +1999,7 @@ impl AssetManager {
                         self.texture_cache_order.retain(|p| p != &atlas.image_path);
                         self.atlas_view_fingerprints.remove(&atlas.image_path);
                         self.remove_cached_atlas_image(&atlas.image_path);
+                        self.packed_atlas_images.remove(&atlas.image_path);
                     }
                     self.atlas_sources.remove(key);
                     self.bump_revision();
@@ -1418,6 +2012,120 @@ impl AssetManager {
     pub fn atlas_ref_count(&self, key: &str) -> usize {
         self.atlas_refs.get(key).copied().unwrap_or(0)
     }
+
+    /// Loads the sprite normal map at `path` (keyed by the path itself), or bumps its reference
+    /// count when it is already loaded. Only the image header is read here.
+    pub fn retain_normal_map(&mut self, path: &str) -> Result<()> {
+        if let Some(entry) = self.normal_maps.get_mut(path) {
+            entry.refs += 1;
+            return Ok(());
+        }
+        let (width, height) =
+            image::image_dimensions(path).with_context(|| format!("Reading normal map '{path}'"))?;
+        self.normal_maps.insert(path.to_string(), NormalMapEntry { refs: 1, width, height, view: None });
+        self.bump_revision();
+        Ok(())
+    }
+
+    pub fn release_normal_map(&mut self, path: &str) -> bool {
+        let Some(entry) = self.normal_maps.get_mut(path) else {
+            return false;
+        };
+        entry.refs -= 1;
+        if entry.refs == 0 {
+            self.normal_maps.remove(path);
+            self.bump_revision();
+        }
+        true
+    }
+
+    pub fn has_normal_map(&self, path: &str) -> bool {
+        self.normal_maps.contains_key(path)
+    }
+
+    pub fn normal_map_ref_count(&self, path: &str) -> usize {
+        self.normal_maps.get(path).map(|entry| entry.refs).unwrap_or(0)
+    }
+
+    pub fn normal_map_dimensions(&self, path: &str) -> Option<(u32, u32)> {
+        self.normal_maps.get(path).map(|entry| (entry.width, entry.height))
+    }
+
+    /// Texture view for a retained normal map. It is uploaded as linear `Rgba8Unorm` so the
+    /// encoded vectors are not gamma-decoded like atlas colors.
+    pub fn normal_map_texture_view(&mut self, path: &str) -> Result<wgpu::TextureView> {
+        let entry =
+            self.normal_maps.get_mut(path).ok_or_else(|| anyhow!("normal map '{path}' not loaded"))?;
+        if let Some(view) = entry.view.as_ref() {
+            return Ok(view.clone());
+        }
+        let dev = self.device.as_ref().ok_or_else(|| anyhow!("GPU device not initialized"))?;
+        let q = self.queue.as_ref().ok_or_else(|| anyhow!("GPU queue not initialized"))?;
+        let bytes = fs::read(path).with_context(|| format!("Reading normal map '{path}'"))?;
+        let img = image::load_from_memory(&bytes)?.to_rgba8();
+        let (w, h) = img.dimensions();
+        let texture = dev.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Normal Map Texture"),
+            size: wgpu::Extent3d { width: w, height: h, depth_or_array_layers: 1 },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8Unorm,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+            view_formats: &[],
+        });
+        q.write_texture(
+            wgpu::TexelCopyTextureInfo {
+                texture: &texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            img.as_raw(),
+            wgpu::TexelCopyBufferLayout { offset: 0, bytes_per_row: Some(4 * w), rows_per_image: Some(h) },
+            wgpu::Extent3d { width: w, height: h, depth_or_array_layers: 1 },
+        );
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        entry.width = w;
+        entry.height = h;
+        entry.view = Some(view.clone());
+        Ok(view)
+    }
+    /// Estimated bytes held by cached atlas textures across all of their mips.
+    pub fn atlas_gpu_bytes(&self) -> u64 {
+        self.texture_cache.values().map(|cached| cached.bytes).sum()
+    }
+    /// The part of [`AssetManager::atlas_gpu_bytes`] held in block-compressed formats.
+    pub fn compressed_atlas_gpu_bytes(&self) -> u64 {
+        self.texture_cache
+            .values()
+            .filter(|cached| cached.format.is_compressed())
+            .map(|cached| cached.bytes)
+            .sum()
+    }
+    /// GPU format of the uploaded texture for `key`, if it has been uploaded. KTX2 atlases report
+    /// their BC format unless the adapter lacked support and they were decoded to RGBA8.
+    pub fn atlas_texture_format(&self, key: &str) -> Option<wgpu::TextureFormat> {
+        let atlas = self.atlases.get(key)?;
+        self.texture_cache.get(&atlas.image_path).map(|cached| cached.format)
+    }
+    /// Transcodes the image behind atlas `key` to BC7 and writes a `.ktx2` sidecar next to it,
+    /// which later loads upload instead of the image. Region UVs are unchanged.
+    pub fn compress_atlas_texture(&mut self, key: &str) -> Result<PathBuf> {
+        let atlas = self.atlases.get(key).ok_or_else(|| anyhow!("atlas '{key}' not loaded"))?;
+        if self.packed_atlas_images.contains_key(&atlas.image_path) {
+            return Err(anyhow!("atlas '{key}' is packed in memory and has no image to compress"));
+        }
+        if ktx2::is_ktx2_path(&atlas.image_path) {
+            return Err(anyhow!("atlas '{key}' already uses a KTX2 texture"));
+        }
+        let image_path = atlas.image_path.clone();
+        let sidecar = ktx2::write_bc7_sidecar(&image_path)?;
+        self.texture_cache.remove(&image_path);
+        self.texture_cache_order.retain(|p| p != &image_path);
+        self.atlas_view_fingerprints.remove(&image_path);
+        Ok(sidecar)
+    }
     pub fn atlas_texture_view(&mut self, key: &str) -> Result<wgpu::TextureView> {
         self.load_or_reload_view(key, false)
     }
@@ -1425,12 +2133,18 @@ impl AssetManager {
     fn load_or_reload_view(&mut self, key: &str, force: bool) -> Result<wgpu::TextureView> {
         let atlas = self.atlases.get(key).ok_or_else(|| anyhow!("atlas '{key}' not loaded"))?;
         let image_path = atlas.image_path.clone();
-        let metadata = fs::metadata(&image_path)
-            .with_context(|| format!("read metadata for '{}'", image_path.display()))?;
-        let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
-        let sample = quick_file_sample_hash(&image_path);
+        let mut texture_path = image_path.clone();
+        let (modified, sample) = match self.packed_atlas_images.get(&image_path) {
+            Some(packed) => (packed.modified, packed.sample),
+            None => {
+                texture_path = ktx2::preferred_texture_path(&image_path);
+                let metadata = fs::metadata(&texture_path)
+                    .with_context(|| format!("read metadata for '{}'", texture_path.display()))?;
+                (metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH), quick_file_sample_hash(&texture_path))
+            }
+        };
         let mut cached_view: Option<wgpu::TextureView> = None;
-        if let Some((view, _)) = self.texture_cache.get(&image_path) {
+        if let Some(CachedAtlasTexture { view, .. }) = self.texture_cache.get(&image_path) {
             let reusable = if !force {
                 true
             } else if let Some((cached_modified, cached_sample)) = self.atlas_view_fingerprints.get(&image_path) {
@@ -1446,6 +2160,22 @@ impl AssetManager {
             self.touch_texture_view(&image_path);
             return Ok(view);
         }
+        if ktx2::is_ktx2_path(&texture_path) {
+            let compressed = ktx2::Ktx2Texture::load(&texture_path)?;
+            let dev = self.device.as_ref().ok_or_else(|| anyhow!("GPU device not initialized"))?;
+            let q = self.queue.as_ref().ok_or_else(|| anyhow!("GPU queue not initialized"))?;
+            let texture = ktx2::create_texture(dev, q, &compressed, true, "Atlas Texture")?;
+            let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+            let cached = CachedAtlasTexture {
+                view: view.clone(),
+                format: texture.format(),
+                bytes: crate::renderer::estimate_texture_bytes(&texture),
+            };
+            self.atlas_view_fingerprints.insert(image_path.clone(), (modified, sample));
+            self.texture_cache.insert(image_path.clone(), cached);
+            self.touch_texture_view(&image_path);
+            return Ok(view);
+        }
         let (rgba, w, h) = self.cached_atlas_pixels(&image_path)?;
         let dev = self.device.as_ref().ok_or_else(|| anyhow!("GPU device not initialized"))?;
         let q = self.queue.as_ref().ok_or_else(|| anyhow!("GPU queue not initialized"))?;
@@ -1496,13 +2226,21 @@ impl AssetManager {
             wgpu::Extent3d { width: w, height: h, depth_or_array_layers: 1 },
         );
         let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let cached = CachedAtlasTexture {
+            view: view.clone(),
+            format: texture.format(),
+            bytes: crate::renderer::estimate_texture_bytes(&texture),
+        };
         self.atlas_view_fingerprints.insert(image_path.clone(), (modified, sample));
-        self.texture_cache.insert(image_path.clone(), (view.clone(), (w, h)));
+        self.texture_cache.insert(image_path.clone(), cached);
         self.touch_texture_view(&image_path);
         Ok(view)
     }
 
     fn cached_atlas_pixels(&mut self, image_path: &Path) -> Result<(Arc<[u8]>, u32, u32)> {
+        if let Some(packed) = self.packed_atlas_images.get(image_path) {
+            return Ok((Arc::clone(&packed.pixels), packed.width, packed.height));
+        }
         let metadata = fs::metadata(image_path)?;
         let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
         let sample = quick_file_sample_hash(image_path);
@@ -1739,6 +2477,21 @@ mod tests {
     use std::path::PathBuf;
     use tempfile::tempdir;
 
+    #[test]
+    fn anisotropy_clamps_to_adapter_limit() {
+        let mut assets = AssetManager::new();
+        assets.set_anisotropy_limit(1);
+        assert_eq!(assets.set_anisotropy(16), 1, "unsupported adapters fall back to 1x");
+        assert_eq!(assets.anisotropy(), 1);
+
+        assets.set_anisotropy_limit(8);
+        assert_eq!(assets.set_anisotropy(16), 8);
+        assert_eq!(assets.set_anisotropy(0), 1);
+        assert_eq!(assets.set_anisotropy(4), 4);
+        assets.set_anisotropy_limit(2);
+        assert_eq!(assets.anisotropy(), 2, "lowering the limit re-clamps the active level");
+    }
+
     #[test]
     fn clip_key_for_source_path_handles_equivalent_paths() {
         let mut assets = AssetManager::new();
@@ -1896,4 +2649,22 @@ mod tests {
 }
 }
 const TEXTURE_VIEW_CACHE_LIMIT: usize = 32;
+/// wgpu rejects sampler anisotropy clamps above 16.
+pub const MAX_SAMPLER_ANISOTROPY: u16 = 16;
+
+fn create_default_sampler(device: &wgpu::Device, anisotropy: u16) -> wgpu::Sampler {
+    // Anisotropic sampling requires every filter to be linear.
+    let mipmap_filter = if anisotropy > 1 { wgpu::FilterMode::Linear } else { wgpu::FilterMode::Nearest };
+    device.create_sampler(&wgpu::SamplerDescriptor {
+        label: Some("Default Sampler"),
+        address_mode_u: wgpu::AddressMode::ClampToEdge,
+        address_mode_v: wgpu::AddressMode::ClampToEdge,
+        address_mode_w: wgpu::AddressMode::ClampToEdge,
+        mag_filter: wgpu::FilterMode::Linear,
+        min_filter: wgpu::FilterMode::Linear,
+        mipmap_filter,
+        anisotropy_clamp: anisotropy.max(1),
+        ..Default::default()
+    })
+}
 const ATLAS_IMAGE_CACHE_LIMIT: usize = 16;
@@ -0,0 +1,212 @@
+use super::{AtlasRegion, Rect, TextureAtlas, TextureAtlasDiagnostics};
+use anyhow::{anyhow, Context, Result};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Largest packed atlas edge used when no GPU device reports its own limit.
+pub const DEFAULT_ATLAS_PACK_MAX_SIZE: u32 = 4096;
+/// Transparent gap left between packed images so filtering does not bleed neighbours together.
+const ATLAS_PACK_PADDING: u32 = 1;
+
+/// Outcome of packing a directory of loose images into one atlas texture.
+#[derive(Clone, Default)]
+pub struct AtlasPackReport {
+    pub width: u32,
+    pub height: u32,
+    pub packed: usize,
+    /// Texels of the packed texture not covered by any image (padding and shelf slack).
+    pub wasted_pixels: u64,
+    /// Images left out because they did not fit inside the maximum texture size.
+    pub overflow: Vec<String>,
+    pub diagnostics: TextureAtlasDiagnostics,
+}
+
+impl AtlasPackReport {
+    pub fn wasted_fraction(&self) -> f32 {
+        let total = self.width as u64 * self.height as u64;
+        if total == 0 {
+            return 0.0;
+        }
+        self.wasted_pixels as f32 / total as f32
+    }
+}
+
+pub(super) struct PackedAtlas {
+    pub atlas: TextureAtlas,
+    pub pixels: Vec<u8>,
+    pub report: AtlasPackReport,
+}
+
+struct LooseImage {
+    name: String,
+    image: image::RgbaImage,
+}
+
+struct ShelfLayout {
+    width: u32,
+    height: u32,
+    placements: Vec<Option<(u32, u32)>>,
+}
+
+impl ShelfLayout {
+    fn overflow_count(&self) -> usize {
+        self.placements.iter().filter(|slot| slot.is_none()).count()
+    }
+}
+
+/// Scans `dir` for PNG files and packs them with a shelf packer into a single RGBA texture.
+pub(super) fn pack_atlas_dir(key: &str, dir: &Path, max_size: u32) -> Result<PackedAtlas> {
+    let mut diagnostics = TextureAtlasDiagnostics::default();
+    let mut images = load_loose_images(key, dir, &mut diagnostics)?;
+    if images.is_empty() {
+        return Err(anyhow!("Atlas '{key}' found no PNG images in {}", dir.display()));
+    }
+    // Tallest first keeps shelves tight; names break ties so packing is stable across runs.
+    images.sort_by(|a, b| b.image.height().cmp(&a.image.height()).then_with(|| a.name.cmp(&b.name)));
+    let sizes: Vec<(u32, u32)> = images.iter().map(|loose| loose.image.dimensions()).collect();
+    let layout = choose_layout(&sizes, max_size.max(1));
+
+    let mut report = AtlasPackReport { width: layout.width, height: layout.height, ..Default::default() };
+    let mut placed: Vec<(String, Rect)> = Vec::with_capacity(images.len());
+    let mut pixels = vec![0u8; layout.width as usize * layout.height as usize * 4];
+    let mut covered = 0u64;
+    for (loose, slot) in images.iter().zip(layout.placements.iter()) {
+        let (w, h) = loose.image.dimensions();
+        let Some((x, y)) = *slot else {
+            diagnostics.warn(format!(
+                "Atlas '{key}': image '{}' ({w}x{h}) does not fit within {max_size}x{max_size}; skipped",
+                loose.name
+            ));
+            report.overflow.push(loose.name.clone());
+            continue;
+        };
+        blit(&mut pixels, layout.width, &loose.image, x, y);
+        covered += w as u64 * h as u64;
+        placed.push((loose.name.clone(), Rect { x, y, w, h }));
+    }
+    if placed.is_empty() {
+        return Err(anyhow!(
+            "Atlas '{key}': none of the {} image(s) in {} fit within {max_size}x{max_size}",
+            images.len(),
+            dir.display()
+        ));
+    }
+    report.packed = placed.len();
+    report.wasted_pixels = layout.width as u64 * layout.height as u64 - covered;
+
+    placed.sort_by(|a, b| a.0.cmp(&b.0));
+    let mut regions = HashMap::with_capacity(placed.len());
+    for (index, (name, rect)) in placed.into_iter().enumerate() {
+        let id = u16::try_from(index).map_err(|_| anyhow!("Atlas '{key}' has more than 65535 regions"))?;
+        let uv = [
+            rect.x as f32 / layout.width as f32,
+            rect.y as f32 / layout.height as f32,
+            (rect.x + rect.w) as f32 / layout.width as f32,
+            (rect.y + rect.h) as f32 / layout.height as f32,
+        ];
+        regions.insert(Arc::<str>::from(name), AtlasRegion { id, rect, uv });
+    }
+    report.diagnostics = diagnostics;
+    let atlas = TextureAtlas {
+        image_key: dir.to_string_lossy().into_owned(),
+        image_path: dir.to_path_buf(),
+        width: layout.width,
+        height: layout.height,
+        regions,
+        animations: HashMap::new(),
+        lint: Vec::new(),
+    };
+    Ok(PackedAtlas { atlas, pixels, report })
+}
+
+fn load_loose_images(
+    key: &str,
+    dir: &Path,
+    diagnostics: &mut TextureAtlasDiagnostics,
+) -> Result<Vec<LooseImage>> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(dir)
+        .with_context(|| format!("read atlas directory '{}'", dir.display()))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.is_file()
+                && path
+                    .extension()
+                    .and_then(|ext| ext.to_str())
+                    .is_some_and(|ext| ext.eq_ignore_ascii_case("png"))
+        })
+        .collect();
+    paths.sort();
+    let mut images: Vec<LooseImage> = Vec::with_capacity(paths.len());
+    for path in paths {
+        let Some(name) = path.file_stem().map(|stem| stem.to_string_lossy().into_owned()) else {
+            continue;
+        };
+        if images.iter().any(|loose| loose.name == name) {
+            diagnostics.warn(format!(
+                "Atlas '{key}': '{}' duplicates region name '{name}'; skipped",
+                path.display()
+            ));
+            continue;
+        }
+        let image = image::open(&path).with_context(|| format!("decode atlas image '{}'", path.display()))?;
+        images.push(LooseImage { name, image: image.to_rgba8() });
+    }
+    Ok(images)
+}
+
+/// Tries power-of-two widths from a square estimate upwards and keeps the first layout that fits
+/// every image without growing taller than it is wide; falls back to the widest allowed layout.
+fn choose_layout(sizes: &[(u32, u32)], max_size: u32) -> ShelfLayout {
+    let area: u64 =
+        sizes.iter().map(|&(w, h)| (w + ATLAS_PACK_PADDING) as u64 * (h + ATLAS_PACK_PADDING) as u64).sum();
+    let widest = sizes.iter().map(|&(w, _)| w).max().unwrap_or(1);
+    let estimate = (area as f64).sqrt().ceil() as u32;
+    let mut width = widest.max(estimate).max(1).next_power_of_two().min(max_size);
+    loop {
+        let layout = shelf_pack(sizes, width, max_size);
+        if width >= max_size || (layout.overflow_count() == 0 && layout.height <= width) {
+            return layout;
+        }
+        width = (width * 2).min(max_size);
+    }
+}
+
+fn shelf_pack(sizes: &[(u32, u32)], width: u32, max_height: u32) -> ShelfLayout {
+    let mut placements = Vec::with_capacity(sizes.len());
+    let (mut cursor_x, mut shelf_y, mut shelf_height, mut used_height) = (0u32, 0u32, 0u32, 0u32);
+    for &(w, h) in sizes {
+        if w > width || h > max_height {
+            placements.push(None);
+            continue;
+        }
+        let (mut x, mut y) = (cursor_x, shelf_y);
+        if x + w > width {
+            x = 0;
+            y = shelf_y + shelf_height + ATLAS_PACK_PADDING;
+        }
+        if y + h > max_height {
+            placements.push(None);
+            continue;
+        }
+        if y != shelf_y {
+            shelf_y = y;
+            shelf_height = 0;
+        }
+        placements.push(Some((x, y)));
+        cursor_x = x + w + ATLAS_PACK_PADDING;
+        shelf_height = shelf_height.max(h);
+        used_height = used_height.max(y + h);
+    }
+    ShelfLayout { width, height: used_height.max(1), placements }
+}
+
+fn blit(pixels: &mut [u8], atlas_width: u32, image: &image::RgbaImage, x: u32, y: u32) {
+    let row_bytes = image.width() as usize * 4;
+    let src = image.as_raw();
+    for row in 0..image.height() as usize {
+        let dst = ((y as usize + row) * atlas_width as usize + x as usize) * 4;
+        pixels[dst..dst + row_bytes].copy_from_slice(&src[row * row_bytes..(row + 1) * row_bytes]);
+    }
+}
@@ -0,0 +1,524 @@
+use super::{
+    build_segment_cache_scalar, build_segment_cache_vec2, build_segment_cache_vec4, fill_auto_tangents,
+    AnimationClip, ClipInterpolation, ClipKeyframe, ClipScalarTrack, ClipVec2Track, ClipVec4Track,
+};
+use anyhow::{anyhow, bail, Result};
+use glam::{Vec2, Vec4};
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+/// Keys closer together than this share a time slot.
+const KEYFRAME_TIME_EPSILON: f32 = 1e-4;
+
+/// A transform track of an [`AnimationClip`], as addressed by the keyframe editing methods.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClipTrack {
+    Translation,
+    Rotation,
+    Scale,
+    Tint,
+}
+
+impl ClipTrack {
+    pub fn label(self) -> &'static str {
+        match self {
+            ClipTrack::Translation => "translation",
+            ClipTrack::Rotation => "rotation",
+            ClipTrack::Scale => "scale",
+            ClipTrack::Tint => "tint",
+        }
+    }
+
+    fn accepts(self, value: ClipKeyValue) -> bool {
+        matches!(
+            (self, value),
+            (ClipTrack::Translation | ClipTrack::Scale, ClipKeyValue::Vec2(_))
+                | (ClipTrack::Rotation, ClipKeyValue::Scalar(_))
+                | (ClipTrack::Tint, ClipKeyValue::Vec4(_))
+        )
+    }
+}
+
+/// Keyframe value of any clip track; the variant must match the track being edited.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ClipKeyValue {
+    Scalar(f32),
+    Vec2(Vec2),
+    Vec4(Vec4),
+}
+
+impl ClipKeyValue {
+    fn as_scalar(self) -> Option<f32> {
+        match self {
+            ClipKeyValue::Scalar(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn as_vec2(self) -> Option<Vec2> {
+        match self {
+            ClipKeyValue::Vec2(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn as_vec4(self) -> Option<Vec4> {
+        match self {
+            ClipKeyValue::Vec4(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn is_finite(self) -> bool {
+        match self {
+            ClipKeyValue::Scalar(value) => value.is_finite(),
+            ClipKeyValue::Vec2(value) => value.is_finite(),
+            ClipKeyValue::Vec4(value) => value.is_finite(),
+        }
+    }
+
+    fn offset(self, delta: ClipKeyValue) -> Option<ClipKeyValue> {
+        match (self, delta) {
+            (ClipKeyValue::Scalar(value), ClipKeyValue::Scalar(delta)) => {
+                Some(ClipKeyValue::Scalar(value + delta))
+            }
+            (ClipKeyValue::Vec2(value), ClipKeyValue::Vec2(delta)) => Some(ClipKeyValue::Vec2(value + delta)),
+            (ClipKeyValue::Vec4(value), ClipKeyValue::Vec4(delta)) => Some(ClipKeyValue::Vec4(value + delta)),
+            _ => None,
+        }
+    }
+
+    /// `pivot + (self - pivot) * factor`, or `None` when the variants differ.
+    fn scale_about(self, pivot: ClipKeyValue, factor: f32) -> Option<ClipKeyValue> {
+        match (self, pivot) {
+            (ClipKeyValue::Scalar(value), ClipKeyValue::Scalar(pivot)) => {
+                Some(ClipKeyValue::Scalar(pivot + (value - pivot) * factor))
+            }
+            (ClipKeyValue::Vec2(value), ClipKeyValue::Vec2(pivot)) => {
+                Some(ClipKeyValue::Vec2(pivot + (value - pivot) * factor))
+            }
+            (ClipKeyValue::Vec4(value), ClipKeyValue::Vec4(pivot)) => {
+                Some(ClipKeyValue::Vec4(pivot + (value - pivot) * factor))
+            }
+            _ => None,
+        }
+    }
+
+    fn scaled(self, factor: f32) -> ClipKeyValue {
+        match self {
+            ClipKeyValue::Scalar(value) => ClipKeyValue::Scalar(value * factor),
+            ClipKeyValue::Vec2(value) => ClipKeyValue::Vec2(value * factor),
+            ClipKeyValue::Vec4(value) => ClipKeyValue::Vec4(value * factor),
+        }
+    }
+}
+
+struct EditedKey {
+    time: f32,
+    value: ClipKeyValue,
+    in_tangent: Option<ClipKeyValue>,
+    out_tangent: Option<ClipKeyValue>,
+    /// Edited keys win when re-sorting lands two keys on the same time.
+    edited: bool,
+}
+
+// Every edit returns a new clip with the track re-sorted, its segment cache and the clip duration
+// rebuilt, so the result can go straight to `replace_clip`. Times are clamped to zero. Keys keep
+// their tangents, except on `auto_tangent` tracks where every tangent is recomputed.
+impl AnimationClip {
+    pub fn keyframe_count(&self, track: ClipTrack) -> usize {
+        match track {
+            ClipTrack::Translation => self.translation.as_ref().map_or(0, |t| t.keyframes.len()),
+            ClipTrack::Rotation => self.rotation.as_ref().map_or(0, |t| t.keyframes.len()),
+            ClipTrack::Scale => self.scale.as_ref().map_or(0, |t| t.keyframes.len()),
+            ClipTrack::Tint => self.tint.as_ref().map_or(0, |t| t.keyframes.len()),
+        }
+    }
+
+    pub fn keyframes(&self, track: ClipTrack) -> Vec<ClipKeyframe<ClipKeyValue>> {
+        fn collect<T: Copy>(
+            frames: Option<&Arc<[ClipKeyframe<T>]>>,
+            wrap: impl Fn(T) -> ClipKeyValue,
+        ) -> Vec<ClipKeyframe<ClipKeyValue>> {
+            frames
+                .map(|frames| {
+                    frames
+                        .iter()
+                        .map(|kf| ClipKeyframe {
+                            time: kf.time,
+                            value: wrap(kf.value),
+                            in_tangent: kf.in_tangent.map(&wrap),
+                            out_tangent: kf.out_tangent.map(&wrap),
+                        })
+                        .collect()
+                })
+                .unwrap_or_default()
+        }
+        match track {
+            ClipTrack::Translation => {
+                collect(self.translation.as_ref().map(|t| &t.keyframes), ClipKeyValue::Vec2)
+            }
+            ClipTrack::Rotation => {
+                collect(self.rotation.as_ref().map(|t| &t.keyframes), ClipKeyValue::Scalar)
+            }
+            ClipTrack::Scale => collect(self.scale.as_ref().map(|t| &t.keyframes), ClipKeyValue::Vec2),
+            ClipTrack::Tint => collect(self.tint.as_ref().map(|t| &t.keyframes), ClipKeyValue::Vec4),
+        }
+    }
+
+    /// Adds a key at `time`, creating the track if needed. A key already at that time takes the new
+    /// value, unless `distinct_time` is set, in which case the insert fails instead.
+    pub fn insert_keyframe(
+        &self,
+        track: ClipTrack,
+        time: f32,
+        value: ClipKeyValue,
+        distinct_time: bool,
+    ) -> Result<AnimationClip> {
+        self.edit_track(track, |keys| {
+            let time = time.max(0.0);
+            if distinct_time && keys.iter().any(|key| (key.time - time).abs() < KEYFRAME_TIME_EPSILON) {
+                bail!("{} track already has a keyframe at {time:.4}s", track.label());
+            }
+            keys.push(EditedKey { time, value, in_tangent: None, out_tangent: None, edited: true });
+            Ok(())
+        })
+    }
+
+    /// Moves key `index` to `time`. A key already at the destination is replaced by the moved one.
+    pub fn move_keyframe(&self, track: ClipTrack, index: usize, time: f32) -> Result<AnimationClip> {
+        self.edit_track(track, |keys| {
+            let key = key_at(keys, track, index)?;
+            key.time = time;
+            key.edited = true;
+            Ok(())
+        })
+    }
+
+    pub fn set_keyframe_value(
+        &self,
+        track: ClipTrack,
+        index: usize,
+        value: ClipKeyValue,
+    ) -> Result<AnimationClip> {
+        self.edit_track(track, |keys| {
+            let key = key_at(keys, track, index)?;
+            key.value = value;
+            key.edited = true;
+            Ok(())
+        })
+    }
+
+    /// Shifts the keys at `indices` by `time_delta` and adds `value_delta` to their values.
+    pub fn offset_keyframes(
+        &self,
+        track: ClipTrack,
+        indices: &[usize],
+        time_delta: f32,
+        value_delta: Option<ClipKeyValue>,
+    ) -> Result<AnimationClip> {
+        self.edit_track(track, |keys| {
+            for &index in indices {
+                let key = key_at(keys, track, index)?;
+                key.time += time_delta;
+                if let Some(delta) = value_delta {
+                    key.value = key
+                        .value
+                        .offset(delta)
+                        .ok_or_else(|| anyhow!("value offset does not match the {} track", track.label()))?;
+                }
+                key.edited = true;
+            }
+            Ok(())
+        })
+    }
+
+    /// Sets the tangents (value per second) of key `index`; `None` leaves that side linear. An
+    /// `auto_tangent` track switches to authored tangents, keeping the computed ones on other keys.
+    pub fn set_keyframe_tangents(
+        &self,
+        track: ClipTrack,
+        index: usize,
+        in_tangent: Option<ClipKeyValue>,
+        out_tangent: Option<ClipKeyValue>,
+    ) -> Result<AnimationClip> {
+        self.edit_track_tangents(track, true, |keys| {
+            for tangent in [in_tangent, out_tangent].into_iter().flatten() {
+                if !tangent.is_finite() {
+                    bail!("keyframe tangent must be finite");
+                }
+                if !track.accepts(tangent) {
+                    bail!("{tangent:?} is not a valid {} keyframe tangent", track.label());
+                }
+            }
+            let key = key_at(keys, track, index)?;
+            key.in_tangent = in_tangent;
+            key.out_tangent = out_tangent;
+            key.edited = true;
+            Ok(())
+        })
+    }
+
+    /// Scales the keys at `indices` in time about `time_pivot` and, when `value_scale` is given, in
+    /// value about its pivot. Tangents follow so the curve keeps its shape.
+    pub fn scale_keyframes(
+        &self,
+        track: ClipTrack,
+        indices: &[usize],
+        time_pivot: f32,
+        time_scale: f32,
+        value_scale: Option<(ClipKeyValue, f32)>,
+    ) -> Result<AnimationClip> {
+        if !time_scale.is_finite() || time_scale <= 0.0 {
+            bail!("time scale must be positive, got {time_scale}");
+        }
+        let factor = value_scale.map_or(1.0, |(_, factor)| factor);
+        if !factor.is_finite() {
+            bail!("value scale must be finite");
+        }
+        let tangent_factor = factor / time_scale;
+        self.edit_track(track, |keys| {
+            let mut sorted = indices.to_vec();
+            sorted.sort_unstable();
+            sorted.dedup();
+            for index in sorted {
+                let key = key_at(keys, track, index)?;
+                key.time = time_pivot + (key.time - time_pivot) * time_scale;
+                if let Some((pivot, factor)) = value_scale {
+                    key.value = key
+                        .value
+                        .scale_about(pivot, factor)
+                        .ok_or_else(|| anyhow!("value pivot does not match the {} track", track.label()))?;
+                }
+                key.in_tangent = key.in_tangent.map(|tangent| tangent.scaled(tangent_factor));
+                key.out_tangent = key.out_tangent.map(|tangent| tangent.scaled(tangent_factor));
+                key.edited = true;
+            }
+            Ok(())
+        })
+    }
+
+    pub fn delete_keyframe(&self, track: ClipTrack, index: usize) -> Result<AnimationClip> {
+        self.delete_keyframes(track, &[index])
+    }
+
+    /// Removes the keys at `indices`. Removing every key drops the track from the clip.
+    pub fn delete_keyframes(&self, track: ClipTrack, indices: &[usize]) -> Result<AnimationClip> {
+        self.edit_track(track, |keys| {
+            let mut sorted = indices.to_vec();
+            sorted.sort_unstable_by(|a, b| b.cmp(a));
+            sorted.dedup();
+            for index in sorted {
+                key_at(keys, track, index)?;
+                keys.remove(index);
+            }
+            Ok(())
+        })
+    }
+
+    fn edit_track(
+        &self,
+        track: ClipTrack,
+        edit: impl FnOnce(&mut Vec<EditedKey>) -> Result<()>,
+    ) -> Result<AnimationClip> {
+        self.edit_track_tangents(track, false, edit)
+    }
+
+    /// `authored_tangents` turns off `auto_tangent` on the rebuilt track, so hand-set tangents stick.
+    fn edit_track_tangents(
+        &self,
+        track: ClipTrack,
+        authored_tangents: bool,
+        edit: impl FnOnce(&mut Vec<EditedKey>) -> Result<()>,
+    ) -> Result<AnimationClip> {
+        let mut keys: Vec<EditedKey> = self
+            .keyframes(track)
+            .into_iter()
+            .map(|kf| EditedKey {
+                time: kf.time,
+                value: kf.value,
+                in_tangent: kf.in_tangent,
+                out_tangent: kf.out_tangent,
+                edited: false,
+            })
+            .collect();
+        edit(&mut keys)?;
+        for key in &mut keys {
+            if !key.time.is_finite() {
+                bail!("keyframe time must be finite");
+            }
+            if !key.value.is_finite() {
+                bail!("keyframe value must be finite");
+            }
+            if !track.accepts(key.value) {
+                bail!("{:?} is not a valid {} keyframe value", key.value, track.label());
+            }
+            key.time = key.time.max(0.0);
+        }
+        keys.sort_by(|a, b| a.time.partial_cmp(&b.time).unwrap_or(Ordering::Equal));
+        let mut merged: Vec<EditedKey> = Vec::with_capacity(keys.len());
+        for key in keys {
+            if let Some(last) = merged.last_mut() {
+                if (key.time - last.time).abs() < KEYFRAME_TIME_EPSILON {
+                    if key.edited || !last.edited {
+                        *last = key;
+                    }
+                    continue;
+                }
+            }
+            merged.push(key);
+        }
+
+        let mut clip = self.clone();
+        match track {
+            ClipTrack::Translation => {
+                let (interpolation, auto_tangent) = clip
+                    .translation
+                    .as_ref()
+                    .map_or((ClipInterpolation::Linear, false), |t| (t.interpolation, t.auto_tangent));
+                clip.translation = vec2_track(interpolation, auto_tangent && !authored_tangents, &merged);
+            }
+            ClipTrack::Rotation => {
+                let (interpolation, auto_tangent) = clip
+                    .rotation
+                    .as_ref()
+                    .map_or((ClipInterpolation::Linear, false), |t| (t.interpolation, t.auto_tangent));
+                clip.rotation = scalar_track(interpolation, auto_tangent && !authored_tangents, &merged);
+            }
+            ClipTrack::Scale => {
+                let (interpolation, auto_tangent) = clip
+                    .scale
+                    .as_ref()
+                    .map_or((ClipInterpolation::Linear, false), |t| (t.interpolation, t.auto_tangent));
+                clip.scale = vec2_track(interpolation, auto_tangent && !authored_tangents, &merged);
+            }
+            ClipTrack::Tint => {
+                let (interpolation, auto_tangent) = clip
+                    .tint
+                    .as_ref()
+                    .map_or((ClipInterpolation::Linear, false), |t| (t.interpolation, t.auto_tangent));
+                clip.tint = vec4_track(interpolation, auto_tangent && !authored_tangents, &merged);
+            }
+        }
+        clip.recompute_duration();
+        Ok(clip)
+    }
+
+    fn recompute_duration(&mut self) {
+        let duration = [
+            self.translation.as_ref().map(|t| t.duration),
+            self.rotation.as_ref().map(|t| t.duration),
+            self.scale.as_ref().map(|t| t.duration),
+            self.tint.as_ref().map(|t| t.duration),
+        ]
+        .into_iter()
+        .flatten()
+        .fold(0.0_f32, f32::max);
+        self.duration = duration;
+        self.duration_inv = if duration > 0.0 { 1.0 / duration } else { 0.0 };
+    }
+}
+
+fn key_at(keys: &mut [EditedKey], track: ClipTrack, index: usize) -> Result<&mut EditedKey> {
+    let len = keys.len();
+    keys.get_mut(index).ok_or_else(|| anyhow!("{} track has no keyframe {index} (len {len})", track.label()))
+}
+
+fn vec2_track(
+    interpolation: ClipInterpolation,
+    auto_tangent: bool,
+    keys: &[EditedKey],
+) -> Option<ClipVec2Track> {
+    let mut keyframes: Vec<ClipKeyframe<Vec2>> = keys
+        .iter()
+        .filter_map(|key| {
+            Some(ClipKeyframe {
+                time: key.time,
+                value: key.value.as_vec2()?,
+                in_tangent: key.in_tangent.and_then(ClipKeyValue::as_vec2).filter(|_| !auto_tangent),
+                out_tangent: key.out_tangent.and_then(ClipKeyValue::as_vec2).filter(|_| !auto_tangent),
+            })
+        })
+        .collect();
+    if auto_tangent {
+        fill_auto_tangents(&mut keyframes, Vec2::ZERO);
+    }
+    let duration = keyframes.last()?.time;
+    let (segment_deltas, segments, segment_offsets) = build_segment_cache_vec2(&keyframes);
+    Some(ClipVec2Track {
+        interpolation,
+        auto_tangent,
+        keyframes: Arc::from(keyframes.into_boxed_slice()),
+        duration,
+        duration_inv: if duration > 0.0 { 1.0 / duration } else { 0.0 },
+        segment_deltas,
+        segments,
+        segment_offsets,
+    })
+}
+
+fn scalar_track(
+    interpolation: ClipInterpolation,
+    auto_tangent: bool,
+    keys: &[EditedKey],
+) -> Option<ClipScalarTrack> {
+    let mut keyframes: Vec<ClipKeyframe<f32>> = keys
+        .iter()
+        .filter_map(|key| {
+            Some(ClipKeyframe {
+                time: key.time,
+                value: key.value.as_scalar()?,
+                in_tangent: key.in_tangent.and_then(ClipKeyValue::as_scalar).filter(|_| !auto_tangent),
+                out_tangent: key.out_tangent.and_then(ClipKeyValue::as_scalar).filter(|_| !auto_tangent),
+            })
+        })
+        .collect();
+    if auto_tangent {
+        fill_auto_tangents(&mut keyframes, 0.0);
+    }
+    let duration = keyframes.last()?.time;
+    let (segment_deltas, segments, segment_offsets) = build_segment_cache_scalar(&keyframes);
+    Some(ClipScalarTrack {
+        interpolation,
+        auto_tangent,
+        keyframes: Arc::from(keyframes.into_boxed_slice()),
+        duration,
+        duration_inv: if duration > 0.0 { 1.0 / duration } else { 0.0 },
+        segment_deltas,
+        segments,
+        segment_offsets,
+    })
+}
+
+fn vec4_track(
+    interpolation: ClipInterpolation,
+    auto_tangent: bool,
+    keys: &[EditedKey],
+) -> Option<ClipVec4Track> {
+    let mut keyframes: Vec<ClipKeyframe<Vec4>> = keys
+        .iter()
+        .filter_map(|key| {
+            Some(ClipKeyframe {
+                time: key.time,
+                value: key.value.as_vec4()?,
+                in_tangent: key.in_tangent.and_then(ClipKeyValue::as_vec4).filter(|_| !auto_tangent),
+                out_tangent: key.out_tangent.and_then(ClipKeyValue::as_vec4).filter(|_| !auto_tangent),
+            })
+        })
+        .collect();
+    if auto_tangent {
+        fill_auto_tangents(&mut keyframes, Vec4::ZERO);
+    }
+    let duration = keyframes.last()?.time;
+    let (segment_deltas, segments, segment_offsets) = build_segment_cache_vec4(&keyframes);
+    Some(ClipVec4Track {
+        interpolation,
+        auto_tangent,
+        keyframes: Arc::from(keyframes.into_boxed_slice()),
+        duration,
+        duration_inv: if duration > 0.0 { 1.0 / duration } else { 0.0 },
+        segment_deltas,
+        segments,
+        segment_offsets,
+    })
+}
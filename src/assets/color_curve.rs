@@ -0,0 +1,91 @@
+use super::{
+    build_keyframes, convert_interpolation, default_clip_interpolation, ClipInterpolation,
+    ClipInterpolationFile, ClipKeyframe,
+};
+use anyhow::{anyhow, Context, Result};
+use glam::Vec4;
+use serde::Deserialize;
+use std::sync::Arc;
+
+/// Colour gradient shared by every [`TintCurve`](crate::ecs::TintCurve) that references it.
+/// Stop times are normalized to `[0, 1]` and stretched over each user's own duration.
+#[derive(Clone)]
+pub struct ColorCurve {
+    pub name: Arc<str>,
+    pub interpolation: ClipInterpolation,
+    pub stops: Arc<[ClipKeyframe<Vec4>]>,
+    pub version: u32,
+}
+
+impl ColorCurve {
+    /// Samples the curve at normalized time `t`; times outside the stops hold the nearest stop.
+    pub fn sample(&self, t: f32) -> Vec4 {
+        let stops = self.stops.as_ref();
+        let (Some(first), Some(last)) = (stops.first(), stops.last()) else {
+            return Vec4::ONE;
+        };
+        if t <= first.time {
+            return first.value;
+        }
+        if t >= last.time {
+            return last.value;
+        }
+        let next = stops.partition_point(|stop| stop.time <= t);
+        let start = &stops[next - 1];
+        let end = &stops[next];
+        match self.interpolation {
+            ClipInterpolation::Step => start.value,
+            ClipInterpolation::Linear => {
+                let span = (end.time - start.time).max(f32::EPSILON);
+                start.value.lerp(end.value, (t - start.time) / span)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ColorCurveFile {
+    version: u32,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default = "default_clip_interpolation")]
+    interpolation: ClipInterpolationFile,
+    stops: Vec<ColorCurveStopFile>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ColorCurveStopFile {
+    time: f32,
+    color: [f32; 4],
+}
+
+pub fn parse_color_curve_bytes(bytes: &[u8], key_hint: &str, source_label: &str) -> Result<ColorCurve> {
+    let file: ColorCurveFile =
+        serde_json::from_slice(bytes).with_context(|| format!("parse color curve JSON ({source_label})"))?;
+    let name = file.name.unwrap_or_else(|| key_hint.to_string());
+    if file.version == 0 {
+        return Err(anyhow!(
+            "Color curve '{name}' has unsupported version 0 (expected >= 1) in {source_label}"
+        ));
+    }
+    if file.stops.is_empty() {
+        return Err(anyhow!("Color curve '{name}' must contain at least one stop ({source_label})"));
+    }
+    let (stops, _) = build_keyframes(file.stops, |stop| {
+        let value = Vec4::from(stop.color);
+        if !value.is_finite() {
+            return Err(anyhow!("Color curve stop contains non-finite color value"));
+        }
+        if stop.time > 1.0 {
+            return Err(anyhow!("Color curve stop time must be within [0, 1]"));
+        }
+        Ok(ClipKeyframe::new(stop.time, value))
+    })
+    .with_context(|| format!("Color curve '{name}' ({source_label})"))?;
+    Ok(ColorCurve {
+        name: Arc::from(name),
+        interpolation: convert_interpolation(file.interpolation),
+        stops,
+        version: file.version,
+    })
+}
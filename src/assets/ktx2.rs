@@ -0,0 +1,885 @@
+//! KTX2 containers holding block-compressed (BC1/BC3/BC7) textures.
+//!
+//! Only plain 2D textures are read: no supercompression, array layers, cube faces or depth. The
+//! level data is uploaded as-is when the device has `TEXTURE_COMPRESSION_BC`; otherwise each mip is
+//! decoded on the CPU into RGBA8. [`write_bc7_sidecar`] is the offline "compress on import" path:
+//! it transcodes a PNG into a BC7 `.ktx2` next to it, which [`preferred_texture_path`] picks up on
+//! the next load for as long as it is at least as new as the source image.
+
+use anyhow::{anyhow, bail, Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::SystemTime;
+
+pub const KTX2_EXTENSION: &str = "ktx2";
+
+const IDENTIFIER: [u8; 12] = [0xAB, 0x4B, 0x54, 0x58, 0x20, 0x32, 0x30, 0xBB, 0x0D, 0x0A, 0x1A, 0x0A];
+const HEADER_LEN: usize = 80;
+const LEVEL_INDEX_ENTRY_LEN: usize = 24;
+
+const VK_FORMAT_BC1_RGB_UNORM: u32 = 131;
+const VK_FORMAT_BC1_RGB_SRGB: u32 = 132;
+const VK_FORMAT_BC1_RGBA_UNORM: u32 = 133;
+const VK_FORMAT_BC1_RGBA_SRGB: u32 = 134;
+const VK_FORMAT_BC3_UNORM: u32 = 137;
+const VK_FORMAT_BC3_SRGB: u32 = 138;
+const VK_FORMAT_BC7_UNORM: u32 = 145;
+const VK_FORMAT_BC7_SRGB: u32 = 146;
+
+static FALLBACK_WARNED: AtomicBool = AtomicBool::new(false);
+
+/// A parsed KTX2 texture with its mip chain kept in GPU block layout.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ktx2Texture {
+    pub format: wgpu::TextureFormat,
+    pub width: u32,
+    pub height: u32,
+    /// Mip levels from the base level down, each holding tightly packed 4x4 blocks.
+    pub levels: Vec<Vec<u8>>,
+}
+
+impl Ktx2Texture {
+    pub fn parse(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < HEADER_LEN || bytes[..12] != IDENTIFIER {
+            bail!("not a KTX2 file");
+        }
+        let vk_format = read_u32(bytes, 12);
+        let format = format_from_vk(vk_format).ok_or_else(|| {
+            anyhow!("unsupported KTX2 vkFormat {vk_format}; only BC1, BC3 and BC7 are accepted")
+        })?;
+        let width = read_u32(bytes, 20);
+        let height = read_u32(bytes, 24);
+        let depth = read_u32(bytes, 28);
+        let layers = read_u32(bytes, 32);
+        let faces = read_u32(bytes, 36);
+        let level_count = read_u32(bytes, 40).max(1);
+        let supercompression = read_u32(bytes, 44);
+        if width == 0 || height == 0 {
+            bail!("KTX2 texture has zero size ({width}x{height})");
+        }
+        if depth > 1 || layers > 1 || faces != 1 {
+            bail!("only 2D KTX2 textures are supported (depth {depth}, layers {layers}, faces {faces})");
+        }
+        if supercompression != 0 {
+            bail!("supercompressed KTX2 textures are not supported (scheme {supercompression})");
+        }
+        let max_levels = 32 - width.max(height).leading_zeros();
+        if level_count > max_levels {
+            bail!("KTX2 declares {level_count} mip levels but a {width}x{height} texture has at most {max_levels}");
+        }
+        let index_end = HEADER_LEN + level_count as usize * LEVEL_INDEX_ENTRY_LEN;
+        if bytes.len() < index_end {
+            bail!("KTX2 level index is truncated");
+        }
+        let mut levels = Vec::with_capacity(level_count as usize);
+        for level in 0..level_count {
+            let entry = HEADER_LEN + level as usize * LEVEL_INDEX_ENTRY_LEN;
+            let offset = read_u64(bytes, entry);
+            let length = read_u64(bytes, entry + 8);
+            let (level_width, level_height) = mip_size(width, height, level);
+            let expected = block_data_len(format, level_width, level_height);
+            if length != expected as u64 {
+                bail!("KTX2 level {level} holds {length} bytes; a {level_width}x{level_height} level needs {expected}");
+            }
+            let start =
+                usize::try_from(offset).map_err(|_| anyhow!("KTX2 level {level} offset overflows"))?;
+            let data = start
+                .checked_add(expected)
+                .and_then(|end| bytes.get(start..end))
+                .ok_or_else(|| anyhow!("KTX2 level {level} lies outside the file"))?;
+            levels.push(data.to_vec());
+        }
+        Ok(Self { format, width, height, levels })
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let bytes = fs::read(path).with_context(|| format!("read KTX2 texture '{}'", path.display()))?;
+        Self::parse(&bytes).with_context(|| format!("parse KTX2 texture '{}'", path.display()))
+    }
+
+    /// Serializes the texture with a basic data format descriptor and no key/value data. Level
+    /// data is stored smallest mip first, as the KTX2 layout requires.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let level_count = self.levels.len();
+        let dfd = data_format_descriptor(self.format);
+        let dfd_offset = HEADER_LEN + level_count * LEVEL_INDEX_ENTRY_LEN;
+        let mut out =
+            Vec::with_capacity(dfd_offset + dfd.len() + self.levels.iter().map(Vec::len).sum::<usize>());
+        out.extend_from_slice(&IDENTIFIER);
+        for value in [vk_format(self.format), 1, self.width, self.height, 0, 0, 1, level_count as u32, 0] {
+            out.extend_from_slice(&value.to_le_bytes());
+        }
+        out.extend_from_slice(&(dfd_offset as u32).to_le_bytes());
+        out.extend_from_slice(&(dfd.len() as u32).to_le_bytes());
+        out.extend_from_slice(&[0; 24]); // No key/value or supercompression global data.
+        out.resize(dfd_offset, 0);
+        out.extend_from_slice(&dfd);
+        let alignment = block_bytes(self.format);
+        let mut placements = vec![(0u64, 0u64); level_count];
+        for (level, data) in self.levels.iter().enumerate().rev() {
+            out.resize(out.len().next_multiple_of(alignment), 0);
+            placements[level] = (out.len() as u64, data.len() as u64);
+            out.extend_from_slice(data);
+        }
+        for (level, (offset, length)) in placements.into_iter().enumerate() {
+            let entry = HEADER_LEN + level * LEVEL_INDEX_ENTRY_LEN;
+            out[entry..entry + 8].copy_from_slice(&offset.to_le_bytes());
+            out[entry + 8..entry + 16].copy_from_slice(&length.to_le_bytes());
+            out[entry + 16..entry + 24].copy_from_slice(&length.to_le_bytes());
+        }
+        out
+    }
+
+    pub fn mip_level_count(&self) -> u32 {
+        self.levels.len() as u32
+    }
+
+    pub fn level_size(&self, level: u32) -> (u32, u32) {
+        mip_size(self.width, self.height, level)
+    }
+
+    /// Whether `device` can sample this texture without a CPU decode. Block-compressed textures
+    /// also need a base size that is a multiple of the 4x4 block.
+    pub fn is_supported_by(&self, device: &wgpu::Device) -> bool {
+        device.features().contains(self.format.required_features())
+            && self.width.is_multiple_of(4)
+            && self.height.is_multiple_of(4)
+    }
+
+    /// Decodes one mip level into tightly packed RGBA8 texels.
+    pub fn decode_level_rgba8(&self, level: u32) -> Result<Vec<u8>> {
+        let data =
+            self.levels.get(level as usize).ok_or_else(|| anyhow!("KTX2 texture has no mip {level}"))?;
+        let (width, height) = self.level_size(level);
+        let block_len = block_bytes(self.format);
+        let blocks_x = width.div_ceil(4) as usize;
+        let mut out = vec![0u8; width as usize * height as usize * 4];
+        let mut texels = [[0u8; 4]; 16];
+        for (index, block) in data.chunks_exact(block_len).enumerate() {
+            match self.format {
+                wgpu::TextureFormat::Bc1RgbaUnorm | wgpu::TextureFormat::Bc1RgbaUnormSrgb => {
+                    decode_bc1_block(block, false, &mut texels)
+                }
+                wgpu::TextureFormat::Bc3RgbaUnorm | wgpu::TextureFormat::Bc3RgbaUnormSrgb => {
+                    decode_bc3_block(block, &mut texels)
+                }
+                _ => decode_bc7_block(block, &mut texels),
+            }
+            let (bx, by) = ((index % blocks_x) * 4, (index / blocks_x) * 4);
+            for (texel, rgba) in texels.iter().enumerate() {
+                let (x, y) = (bx + texel % 4, by + texel / 4);
+                if x < width as usize && y < height as usize {
+                    let offset = (y * width as usize + x) * 4;
+                    out[offset..offset + 4].copy_from_slice(rgba);
+                }
+            }
+        }
+        Ok(out)
+    }
+}
+
+/// Creates and fills a sampled texture for `source`, sampled as sRGB or linear per `srgb`
+/// regardless of the file's transfer function. Formats the device cannot sample are decoded to
+/// RGBA8 (keeping the mip chain), with a warning printed the first time that happens.
+pub(crate) fn create_texture(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    source: &Ktx2Texture,
+    srgb: bool,
+    label: &str,
+) -> Result<wgpu::Texture> {
+    let native = source.is_supported_by(device);
+    let format = match (native, srgb) {
+        (true, true) => source.format.add_srgb_suffix(),
+        (true, false) => source.format.remove_srgb_suffix(),
+        (false, true) => wgpu::TextureFormat::Rgba8UnormSrgb,
+        (false, false) => wgpu::TextureFormat::Rgba8Unorm,
+    };
+    if !native && !FALLBACK_WARNED.swap(true, Ordering::Relaxed) {
+        eprintln!(
+            "[assets] Warning: adapter cannot sample {:?} textures; decoding KTX2 textures to RGBA8 instead",
+            source.format
+        );
+    }
+    let size = wgpu::Extent3d { width: source.width, height: source.height, depth_or_array_layers: 1 };
+    let texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: Some(label),
+        size,
+        mip_level_count: source.mip_level_count(),
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format,
+        usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+        view_formats: &[],
+    });
+    for level in 0..source.mip_level_count() {
+        let extent = size.mip_level_size(level, wgpu::TextureDimension::D2).physical_size(format);
+        let decoded;
+        let (data, bytes_per_row, rows) = if native {
+            let blocks_x = extent.width / 4;
+            let row_bytes = blocks_x * block_bytes(format) as u32;
+            (source.levels[level as usize].as_slice(), row_bytes, extent.height / 4)
+        } else {
+            decoded = source.decode_level_rgba8(level)?;
+            (decoded.as_slice(), extent.width * 4, extent.height)
+        };
+        queue.write_texture(
+            wgpu::TexelCopyTextureInfo {
+                texture: &texture,
+                mip_level: level,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            data,
+            wgpu::TexelCopyBufferLayout {
+                offset: 0,
+                bytes_per_row: Some(bytes_per_row),
+                rows_per_image: Some(rows),
+            },
+            extent,
+        );
+    }
+    Ok(texture)
+}
+
+/// Where a compressed copy of `image` is written by [`write_bc7_sidecar`].
+pub fn sidecar_path(image: &Path) -> PathBuf {
+    image.with_extension(KTX2_EXTENSION)
+}
+
+/// Returns the file that should back `image`: the image itself when it already is a KTX2 file or
+/// has no sidecar, otherwise the sidecar when it is at least as new as the image.
+pub fn preferred_texture_path(image: &Path) -> PathBuf {
+    if is_ktx2_path(image) {
+        return image.to_path_buf();
+    }
+    let sidecar = sidecar_path(image);
+    let modified = |path: &Path| fs::metadata(path).and_then(|meta| meta.modified()).ok();
+    match (modified(&sidecar), modified(image)) {
+        (Some(sidecar_time), Some(image_time)) if sidecar_time >= image_time => sidecar,
+        (Some(_), None) => sidecar,
+        _ => image.to_path_buf(),
+    }
+}
+
+pub fn is_ktx2_path(path: &Path) -> bool {
+    path.extension().and_then(|ext| ext.to_str()).is_some_and(|ext| ext.eq_ignore_ascii_case(KTX2_EXTENSION))
+}
+
+/// Transcodes `image` to sRGB BC7 with a full mip chain and writes it next to the image.
+pub fn write_bc7_sidecar(image: &Path) -> Result<PathBuf> {
+    let rgba = image::open(image).with_context(|| format!("decode '{}'", image.display()))?.to_rgba8();
+    let (width, height) = rgba.dimensions();
+    let texture = encode_bc7(rgba.as_raw(), width, height, true);
+    let sidecar = sidecar_path(image);
+    fs::write(&sidecar, texture.to_bytes()).with_context(|| format!("write '{}'", sidecar.display()))?;
+    // Keep the sidecar preferred even on filesystems with coarse timestamps.
+    if let Ok(file) = fs::File::options().write(true).open(&sidecar) {
+        let _ = file.set_modified(SystemTime::now());
+    }
+    Ok(sidecar)
+}
+
+/// Encodes tightly packed RGBA8 texels as BC7 (mode 6) and box-filters a full mip chain.
+pub fn encode_bc7(rgba: &[u8], width: u32, height: u32, srgb: bool) -> Ktx2Texture {
+    assert_eq!(
+        rgba.len(),
+        width as usize * height as usize * 4,
+        "RGBA8 data does not match {width}x{height}"
+    );
+    let format = if srgb { wgpu::TextureFormat::Bc7RgbaUnormSrgb } else { wgpu::TextureFormat::Bc7RgbaUnorm };
+    let level_count = 32 - width.max(height).leading_zeros();
+    let mut levels = Vec::with_capacity(level_count as usize);
+    let mut current = rgba.to_vec();
+    let (mut level_width, mut level_height) = (width, height);
+    for level in 0..level_count {
+        if level > 0 {
+            current = downsample(&current, level_width, level_height);
+            (level_width, level_height) = ((level_width / 2).max(1), (level_height / 2).max(1));
+        }
+        levels.push(encode_bc7_level(&current, level_width, level_height));
+    }
+    Ktx2Texture { format, width, height, levels }
+}
+
+fn encode_bc7_level(rgba: &[u8], width: u32, height: u32) -> Vec<u8> {
+    let (blocks_x, blocks_y) = (width.div_ceil(4), height.div_ceil(4));
+    let mut out = Vec::with_capacity(blocks_x as usize * blocks_y as usize * 16);
+    let mut texels = [[0u8; 4]; 16];
+    for by in 0..blocks_y {
+        for bx in 0..blocks_x {
+            for (index, texel) in texels.iter_mut().enumerate() {
+                // Partial edge blocks repeat the last row/column.
+                let x = (bx * 4 + index as u32 % 4).min(width - 1) as usize;
+                let y = (by * 4 + index as u32 / 4).min(height - 1) as usize;
+                let offset = (y * width as usize + x) * 4;
+                texel.copy_from_slice(&rgba[offset..offset + 4]);
+            }
+            out.extend_from_slice(&encode_bc7_mode6_block(&texels));
+        }
+    }
+    out
+}
+
+fn downsample(rgba: &[u8], width: u32, height: u32) -> Vec<u8> {
+    let (next_width, next_height) = ((width / 2).max(1), (height / 2).max(1));
+    let mut out = Vec::with_capacity(next_width as usize * next_height as usize * 4);
+    for y in 0..next_height {
+        for x in 0..next_width {
+            let mut sum = [0u32; 4];
+            for (dx, dy) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+                let sx = (x * 2 + dx).min(width - 1) as usize;
+                let sy = (y * 2 + dy).min(height - 1) as usize;
+                let offset = (sy * width as usize + sx) * 4;
+                for (channel, total) in sum.iter_mut().enumerate() {
+                    *total += rgba[offset + channel] as u32;
+                }
+            }
+            out.extend(sum.map(|total| ((total + 2) / 4) as u8));
+        }
+    }
+    out
+}
+
+fn format_from_vk(vk_format: u32) -> Option<wgpu::TextureFormat> {
+    use wgpu::TextureFormat as F;
+    Some(match vk_format {
+        VK_FORMAT_BC1_RGB_UNORM | VK_FORMAT_BC1_RGBA_UNORM => F::Bc1RgbaUnorm,
+        VK_FORMAT_BC1_RGB_SRGB | VK_FORMAT_BC1_RGBA_SRGB => F::Bc1RgbaUnormSrgb,
+        VK_FORMAT_BC3_UNORM => F::Bc3RgbaUnorm,
+        VK_FORMAT_BC3_SRGB => F::Bc3RgbaUnormSrgb,
+        VK_FORMAT_BC7_UNORM => F::Bc7RgbaUnorm,
+        VK_FORMAT_BC7_SRGB => F::Bc7RgbaUnormSrgb,
+        _ => return None,
+    })
+}
+
+fn vk_format(format: wgpu::TextureFormat) -> u32 {
+    use wgpu::TextureFormat as F;
+    match format {
+        F::Bc1RgbaUnorm => VK_FORMAT_BC1_RGBA_UNORM,
+        F::Bc1RgbaUnormSrgb => VK_FORMAT_BC1_RGBA_SRGB,
+        F::Bc3RgbaUnorm => VK_FORMAT_BC3_UNORM,
+        F::Bc3RgbaUnormSrgb => VK_FORMAT_BC3_SRGB,
+        F::Bc7RgbaUnormSrgb => VK_FORMAT_BC7_SRGB,
+        _ => VK_FORMAT_BC7_UNORM,
+    }
+}
+
+fn block_bytes(format: wgpu::TextureFormat) -> usize {
+    format.block_copy_size(None).unwrap_or(16) as usize
+}
+
+fn block_data_len(format: wgpu::TextureFormat, width: u32, height: u32) -> usize {
+    width.div_ceil(4) as usize * height.div_ceil(4) as usize * block_bytes(format)
+}
+
+fn mip_size(width: u32, height: u32, level: u32) -> (u32, u32) {
+    ((width >> level).max(1), (height >> level).max(1))
+}
+
+/// Basic data format descriptor (Khronos Data Format 1.3) for the supported block formats.
+fn data_format_descriptor(format: wgpu::TextureFormat) -> Vec<u8> {
+    const MODEL_BC1A: u8 = 128;
+    const MODEL_BC3: u8 = 130;
+    const MODEL_BC7: u8 = 134;
+    const CHANNEL_COLOR: u8 = 0;
+    const CHANNEL_BC1_ALPHA: u8 = 1;
+    const CHANNEL_BC3_ALPHA: u8 = 15;
+    let (model, samples): (u8, &[(u16, u8, u8)]) = match format {
+        wgpu::TextureFormat::Bc1RgbaUnorm | wgpu::TextureFormat::Bc1RgbaUnormSrgb => {
+            (MODEL_BC1A, &[(0, 63, CHANNEL_BC1_ALPHA)])
+        }
+        wgpu::TextureFormat::Bc3RgbaUnorm | wgpu::TextureFormat::Bc3RgbaUnormSrgb => {
+            (MODEL_BC3, &[(0, 63, CHANNEL_BC3_ALPHA), (64, 63, CHANNEL_COLOR)])
+        }
+        _ => (MODEL_BC7, &[(0, 127, CHANNEL_COLOR)]),
+    };
+    let block_size = 24 + 16 * samples.len();
+    let mut dfd = Vec::with_capacity(4 + block_size);
+    dfd.extend_from_slice(&((4 + block_size) as u32).to_le_bytes());
+    dfd.extend_from_slice(&0u32.to_le_bytes()); // Khronos vendor, basic descriptor type.
+    dfd.extend_from_slice(&2u16.to_le_bytes());
+    dfd.extend_from_slice(&(block_size as u16).to_le_bytes());
+    let transfer = if format.is_srgb() { 2 } else { 1 };
+    dfd.extend_from_slice(&[model, 1, transfer, 0, 3, 3, 0, 0]);
+    let mut planes = [0u8; 8];
+    planes[0] = block_bytes(format) as u8;
+    dfd.extend_from_slice(&planes);
+    for &(bit_offset, bit_length, channel) in samples {
+        dfd.extend_from_slice(&bit_offset.to_le_bytes());
+        dfd.extend_from_slice(&[bit_length, channel, 0, 0, 0, 0]);
+        dfd.extend_from_slice(&0u32.to_le_bytes());
+        dfd.extend_from_slice(&u32::MAX.to_le_bytes());
+    }
+    dfd
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(bytes[offset..offset + 4].try_into().expect("4 bytes"))
+}
+
+fn read_u64(bytes: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(bytes[offset..offset + 8].try_into().expect("8 bytes"))
+}
+
+fn expand_565(color: u16) -> [i32; 3] {
+    let r = ((color >> 11) & 31) as i32;
+    let g = ((color >> 5) & 63) as i32;
+    let b = (color & 31) as i32;
+    [(r << 3) | (r >> 2), (g << 2) | (g >> 4), (b << 3) | (b >> 2)]
+}
+
+fn decode_bc1_block(block: &[u8], always_four_colors: bool, out: &mut [[u8; 4]; 16]) {
+    let c0 = u16::from_le_bytes([block[0], block[1]]);
+    let c1 = u16::from_le_bytes([block[2], block[3]]);
+    let (e0, e1) = (expand_565(c0), expand_565(c1));
+    let mix = |w0: i32, w1: i32, total: i32| -> [u8; 4] {
+        let channel = |i: usize| ((e0[i] * w0 + e1[i] * w1) / total) as u8;
+        [channel(0), channel(1), channel(2), 255]
+    };
+    let palette = if always_four_colors || c0 > c1 {
+        [mix(1, 0, 1), mix(0, 1, 1), mix(2, 1, 3), mix(1, 2, 3)]
+    } else {
+        [mix(1, 0, 1), mix(0, 1, 1), mix(1, 1, 2), [0, 0, 0, 0]]
+    };
+    let indices = u32::from_le_bytes([block[4], block[5], block[6], block[7]]);
+    for (texel, rgba) in out.iter_mut().enumerate() {
+        *rgba = palette[((indices >> (texel * 2)) & 3) as usize];
+    }
+}
+
+fn decode_bc3_block(block: &[u8], out: &mut [[u8; 4]; 16]) {
+    decode_bc1_block(&block[8..16], true, out);
+    let (a0, a1) = (block[0] as u32, block[1] as u32);
+    let mut alphas = [a0, a1, 0, 0, 0, 0, 0, 255];
+    if a0 > a1 {
+        for (step, alpha) in alphas.iter_mut().enumerate().skip(2) {
+            let w = step as u32 - 1;
+            *alpha = ((7 - w) * a0 + w * a1) / 7;
+        }
+    } else {
+        for (step, alpha) in alphas.iter_mut().enumerate().take(6).skip(2) {
+            let w = step as u32 - 1;
+            *alpha = ((5 - w) * a0 + w * a1) / 5;
+        }
+    }
+    let mut bits = [0u8; 8];
+    bits[..6].copy_from_slice(&block[2..8]);
+    let indices = u64::from_le_bytes(bits);
+    for (texel, rgba) in out.iter_mut().enumerate() {
+        rgba[3] = alphas[((indices >> (texel * 3)) & 7) as usize] as u8;
+    }
+}
+
+struct Bc7Mode {
+    subsets: usize,
+    partition_bits: u32,
+    rotation_bits: u32,
+    index_selection_bits: u32,
+    color_bits: u32,
+    alpha_bits: u32,
+    endpoint_pbits: bool,
+    shared_pbits: bool,
+    index_bits: u32,
+    secondary_index_bits: u32,
+}
+
+/// `header_bits` are the partition, rotation and index selection widths; `endpoint_bits` the
+/// color and alpha widths.
+const fn bc7_mode(
+    subsets: usize,
+    header_bits: (u32, u32, u32),
+    endpoint_bits: (u32, u32),
+    pbits: (bool, bool),
+    index_bits: (u32, u32),
+) -> Bc7Mode {
+    Bc7Mode {
+        subsets,
+        partition_bits: header_bits.0,
+        rotation_bits: header_bits.1,
+        index_selection_bits: header_bits.2,
+        color_bits: endpoint_bits.0,
+        alpha_bits: endpoint_bits.1,
+        endpoint_pbits: pbits.0,
+        shared_pbits: pbits.1,
+        index_bits: index_bits.0,
+        secondary_index_bits: index_bits.1,
+    }
+}
+
+const BC7_MODES: [Bc7Mode; 8] = [
+    bc7_mode(3, (4, 0, 0), (4, 0), (true, false), (3, 0)),
+    bc7_mode(2, (6, 0, 0), (6, 0), (false, true), (3, 0)),
+    bc7_mode(3, (6, 0, 0), (5, 0), (false, false), (2, 0)),
+    bc7_mode(2, (6, 0, 0), (7, 0), (true, false), (2, 0)),
+    bc7_mode(1, (0, 2, 1), (5, 6), (false, false), (2, 3)),
+    bc7_mode(1, (0, 2, 0), (7, 8), (false, false), (2, 2)),
+    bc7_mode(1, (0, 0, 0), (7, 7), (true, false), (4, 0)),
+    bc7_mode(2, (6, 0, 0), (5, 5), (true, false), (2, 0)),
+];
+
+/// Two-subset partitions; bit `i` is the subset of texel `i`.
+const BC7_PARTITIONS_2: [u16; 64] = [
+    0xCCCC, 0x8888, 0xEEEE, 0xECC8, 0xC880, 0xFEEC, 0xFEC8, 0xEC80, 0xC800, 0xFFEC, 0xFE80, 0xE800, 0xFFE8,
+    0xFF00, 0xFFF0, 0xF000, 0xF710, 0x008E, 0x7100, 0x08CE, 0x008C, 0x7310, 0x3100, 0x8CCE, 0x088C, 0x3110,
+    0x6666, 0x366C, 0x17E8, 0x0FF0, 0x718E, 0x399C, 0xAAAA, 0xF0F0, 0x5A5A, 0x33CC, 0x3C3C, 0x55AA, 0x9696,
+    0xA55A, 0x73CE, 0x13C8, 0x324C, 0x3BDC, 0x6996, 0xC33C, 0x9966, 0x0660, 0x0272, 0x04E4, 0x4E40, 0x2720,
+    0xC936, 0x936C, 0x39C6, 0x639C, 0x9336, 0x9CC6, 0x817E, 0xE718, 0xCCF0, 0x0FCC, 0x7744, 0xEE22,
+];
+
+/// Three-subset partitions; bits `2i..2i+2` hold the subset of texel `i`.
+const BC7_PARTITIONS_3: [u32; 64] = [
+    0xAA685050, 0x6A5A5040, 0x5A5A4200, 0x5450A0A8, 0xA5A50000, 0xA0A05050, 0x5555A0A0, 0x5A5A5050,
+    0xAA550000, 0xAA555500, 0xAAAA5500, 0x90909090, 0x94949494, 0xA4A4A4A4, 0xA9A59450, 0x2A0A4250,
+    0xA5945040, 0x0A425054, 0xA5A5A500, 0x55A0A0A0, 0xA8A85454, 0x6A6A4040, 0xA4A45000, 0x1A1A0500,
+    0x0050A4A4, 0xAAA59090, 0x14696914, 0x69691400, 0xA08585A0, 0xAA821414, 0x50A4A450, 0x6A5A0200,
+    0xA9A58000, 0x5090A0A8, 0xA8A09050, 0x24242424, 0x00AA5500, 0x24924924, 0x24499224, 0x50A50A50,
+    0x500AA550, 0xAAAA4444, 0x66660000, 0xA5A0A5A0, 0x50A050A0, 0x69286928, 0x44AAAA44, 0x66666600,
+    0xAA444444, 0x54A854A8, 0x95809580, 0x96969600, 0xA85454A8, 0x80959580, 0xAA141414, 0x96960000,
+    0xAAAA1414, 0xA05050A0, 0xA0A5A5A0, 0x96000000, 0x40804080, 0xA9A8A9A8, 0xAAAAAA44, 0x2A4A5254,
+];
+
+const BC7_ANCHORS_2: [u8; 64] = [
+    15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 2, 8, 2, 2, 8, 8, 15, 2, 8, 2, 2, 8,
+    8, 2, 2, 15, 15, 6, 8, 2, 8, 15, 15, 2, 8, 2, 2, 2, 15, 15, 6, 6, 2, 6, 8, 15, 15, 2, 2, 15, 15, 15, 15,
+    15, 2, 2, 15,
+];
+
+const BC7_ANCHORS_3_SECOND: [u8; 64] = [
+    3, 3, 15, 15, 8, 3, 15, 15, 8, 8, 6, 6, 6, 5, 3, 3, 3, 3, 8, 15, 3, 3, 6, 10, 5, 8, 8, 6, 8, 5, 15, 15,
+    8, 15, 3, 5, 6, 10, 8, 15, 15, 3, 15, 5, 15, 15, 15, 15, 3, 15, 5, 5, 5, 8, 5, 10, 5, 10, 8, 13, 15, 12,
+    3, 3,
+];
+
+const BC7_ANCHORS_3_THIRD: [u8; 64] = [
+    15, 8, 8, 3, 15, 15, 3, 8, 15, 15, 15, 15, 15, 15, 15, 8, 15, 8, 15, 3, 15, 8, 15, 8, 3, 15, 6, 10, 15,
+    15, 10, 8, 15, 3, 15, 10, 10, 8, 9, 10, 6, 15, 8, 15, 3, 6, 6, 8, 15, 3, 15, 15, 15, 15, 15, 15, 15, 15,
+    15, 15, 3, 15, 15, 8,
+];
+
+const BC7_WEIGHTS_2: [u32; 4] = [0, 21, 43, 64];
+const BC7_WEIGHTS_3: [u32; 8] = [0, 9, 18, 27, 37, 46, 55, 64];
+const BC7_WEIGHTS_4: [u32; 16] = [0, 4, 9, 13, 17, 21, 26, 30, 34, 38, 43, 47, 51, 55, 60, 64];
+
+fn bc7_weights(bits: u32) -> &'static [u32] {
+    match bits {
+        2 => &BC7_WEIGHTS_2,
+        3 => &BC7_WEIGHTS_3,
+        _ => &BC7_WEIGHTS_4,
+    }
+}
+
+fn bc7_subset(subsets: usize, partition: usize, texel: usize) -> usize {
+    match subsets {
+        2 => ((BC7_PARTITIONS_2[partition] >> texel) & 1) as usize,
+        3 => ((BC7_PARTITIONS_3[partition] >> (texel * 2)) & 3) as usize,
+        _ => 0,
+    }
+}
+
+fn bc7_is_anchor(subsets: usize, partition: usize, texel: usize) -> bool {
+    texel == 0
+        || match subsets {
+            2 => texel == BC7_ANCHORS_2[partition] as usize,
+            3 => {
+                texel == BC7_ANCHORS_3_SECOND[partition] as usize
+                    || texel == BC7_ANCHORS_3_THIRD[partition] as usize
+            }
+            _ => false,
+        }
+}
+
+struct BitReader {
+    bits: u128,
+    position: u32,
+}
+
+impl BitReader {
+    fn read(&mut self, count: u32) -> u32 {
+        if count == 0 {
+            return 0;
+        }
+        let value = (self.bits >> self.position) & ((1u128 << count) - 1);
+        self.position += count;
+        value as u32
+    }
+}
+
+fn expand_bits(value: u32, bits: u32) -> u8 {
+    let value = value << (8 - bits);
+    (value | (value >> bits)) as u8
+}
+
+fn decode_bc7_block(block: &[u8], out: &mut [[u8; 4]; 16]) {
+    let bits = u128::from_le_bytes(block.try_into().expect("16-byte BC7 block"));
+    let mode_index = (bits as u8).trailing_zeros() as usize;
+    if mode_index >= BC7_MODES.len() {
+        // Reserved mode: decoders must output transparent black.
+        *out = [[0; 4]; 16];
+        return;
+    }
+    let mode = &BC7_MODES[mode_index];
+    let mut reader = BitReader { bits, position: mode_index as u32 + 1 };
+    let partition = reader.read(mode.partition_bits) as usize;
+    let rotation = reader.read(mode.rotation_bits);
+    let index_selection = reader.read(mode.index_selection_bits);
+
+    let endpoint_count = mode.subsets * 2;
+    let mut endpoints = [[0u32; 4]; 6];
+    for channel in 0..3 {
+        for endpoint in endpoints.iter_mut().take(endpoint_count) {
+            endpoint[channel] = reader.read(mode.color_bits);
+        }
+    }
+    for endpoint in endpoints.iter_mut().take(endpoint_count) {
+        endpoint[3] = reader.read(mode.alpha_bits);
+    }
+    let (mut color_bits, mut alpha_bits) = (mode.color_bits, mode.alpha_bits);
+    if mode.endpoint_pbits || mode.shared_pbits {
+        let pbits: Vec<u32> = if mode.endpoint_pbits {
+            (0..endpoint_count).map(|_| reader.read(1)).collect()
+        } else {
+            (0..mode.subsets)
+                .flat_map(|_| {
+                    let bit = reader.read(1);
+                    [bit, bit]
+                })
+                .collect()
+        };
+        for (endpoint, pbit) in endpoints.iter_mut().zip(pbits) {
+            for channel in endpoint.iter_mut() {
+                *channel = (*channel << 1) | pbit;
+            }
+        }
+        color_bits += 1;
+        if alpha_bits > 0 {
+            alpha_bits += 1;
+        }
+    }
+    let mut colors = [[0u8; 4]; 6];
+    for (color, endpoint) in colors.iter_mut().zip(endpoints.iter()).take(endpoint_count) {
+        for channel in 0..3 {
+            color[channel] = expand_bits(endpoint[channel], color_bits);
+        }
+        color[3] = if alpha_bits > 0 { expand_bits(endpoint[3], alpha_bits) } else { 255 };
+    }
+
+    let mut primary = [0u32; 16];
+    for (texel, index) in primary.iter_mut().enumerate() {
+        let anchor = bc7_is_anchor(mode.subsets, partition, texel);
+        *index = reader.read(mode.index_bits - anchor as u32);
+    }
+    let mut secondary = [0u32; 16];
+    if mode.secondary_index_bits > 0 {
+        for (texel, index) in secondary.iter_mut().enumerate() {
+            *index = reader.read(mode.secondary_index_bits - (texel == 0) as u32);
+        }
+    }
+
+    for (texel, rgba) in out.iter_mut().enumerate() {
+        let subset = bc7_subset(mode.subsets, partition, texel);
+        let (e0, e1) = (colors[subset * 2], colors[subset * 2 + 1]);
+        let (color_weight, alpha_weight) = if mode.secondary_index_bits == 0 {
+            let weight = bc7_weights(mode.index_bits)[primary[texel] as usize];
+            (weight, weight)
+        } else if index_selection == 0 {
+            (
+                bc7_weights(mode.index_bits)[primary[texel] as usize],
+                bc7_weights(mode.secondary_index_bits)[secondary[texel] as usize],
+            )
+        } else {
+            (
+                bc7_weights(mode.secondary_index_bits)[secondary[texel] as usize],
+                bc7_weights(mode.index_bits)[primary[texel] as usize],
+            )
+        };
+        let interpolate =
+            |a: u8, b: u8, weight: u32| (((64 - weight) * a as u32 + weight * b as u32 + 32) >> 6) as u8;
+        for channel in 0..3 {
+            rgba[channel] = interpolate(e0[channel], e1[channel], color_weight);
+        }
+        rgba[3] = interpolate(e0[3], e1[3], alpha_weight);
+        match rotation {
+            1 => rgba.swap(0, 3),
+            2 => rgba.swap(1, 3),
+            3 => rgba.swap(2, 3),
+            _ => {}
+        }
+    }
+}
+
+/// Encodes one block with BC7 mode 6: a single RGBA line with 7-bit endpoints plus a p-bit each
+/// and 4-bit indices. Endpoints follow the block's principal axis.
+fn encode_bc7_mode6_block(texels: &[[u8; 4]; 16]) -> [u8; 16] {
+    let pixels: Vec<[f32; 4]> = texels.iter().map(|texel| texel.map(|channel| channel as f32)).collect();
+    let mut mean = [0f32; 4];
+    for pixel in &pixels {
+        for channel in 0..4 {
+            mean[channel] += pixel[channel] / 16.0;
+        }
+    }
+    let mut covariance = [[0f32; 4]; 4];
+    for pixel in &pixels {
+        for row in 0..4 {
+            for column in 0..4 {
+                covariance[row][column] += (pixel[row] - mean[row]) * (pixel[column] - mean[column]);
+            }
+        }
+    }
+    // Power iteration seeded with the highest-variance channel's covariance row, which cannot be
+    // orthogonal to the principal axis.
+    let seed = (0..4).max_by(|&a, &b| covariance[a][a].total_cmp(&covariance[b][b])).unwrap_or(0);
+    let seed_length = covariance[seed].iter().map(|value| value * value).sum::<f32>().sqrt();
+    let mut axis =
+        if seed_length < 1e-6 { [0.5; 4] } else { covariance[seed].map(|value| value / seed_length) };
+    for _ in 0..8 {
+        let mut next = [0f32; 4];
+        for row in 0..4 {
+            for column in 0..4 {
+                next[row] += covariance[row][column] * axis[column];
+            }
+        }
+        let length = next.iter().map(|value| value * value).sum::<f32>().sqrt();
+        if length < 1e-6 {
+            break;
+        }
+        axis = next.map(|value| value / length);
+    }
+    let project = |pixel: &[f32; 4]| {
+        (0..4).map(|channel| (pixel[channel] - mean[channel]) * axis[channel]).sum::<f32>()
+    };
+    let (mut low, mut high) = (f32::MAX, f32::MIN);
+    for pixel in &pixels {
+        let t = project(pixel);
+        low = low.min(t);
+        high = high.max(t);
+    }
+    let endpoint_at = |t: f32| -> [f32; 4] {
+        std::array::from_fn(|channel| (mean[channel] + axis[channel] * t).clamp(0.0, 255.0))
+    };
+    let mut endpoints =
+        [quantize_mode6_endpoint(endpoint_at(low)), quantize_mode6_endpoint(endpoint_at(high))];
+
+    let decoded = endpoints.map(|(values, pbit)| values.map(|value| ((value << 1) | pbit) as u8));
+    let mut indices = [0u32; 16];
+    for (texel, index) in texels.iter().zip(indices.iter_mut()) {
+        let mut best_error = u32::MAX;
+        for (candidate, &weight) in BC7_WEIGHTS_4.iter().enumerate() {
+            let error: u32 = (0..4)
+                .map(|channel| {
+                    let value = ((64 - weight) * decoded[0][channel] as u32
+                        + weight * decoded[1][channel] as u32
+                        + 32)
+                        >> 6;
+                    let diff = value as i32 - texel[channel] as i32;
+                    (diff * diff) as u32
+                })
+                .sum();
+            if error < best_error {
+                best_error = error;
+                *index = candidate as u32;
+            }
+        }
+    }
+    // The anchor index has an implicit leading zero bit; flip the line if it would need one.
+    if indices[0] >= 8 {
+        endpoints.swap(0, 1);
+        for index in indices.iter_mut() {
+            *index = 15 - *index;
+        }
+    }
+
+    let mut bits: u128 = 1 << 6;
+    let mut position = 7;
+    let mut write = |value: u32, count: u32| {
+        bits |= (value as u128) << position;
+        position += count;
+    };
+    for channel in 0..4 {
+        for (values, _) in &endpoints {
+            write(values[channel], 7);
+        }
+    }
+    for (_, pbit) in &endpoints {
+        write(*pbit, 1);
+    }
+    for (texel, index) in indices.iter().enumerate() {
+        write(*index, if texel == 0 { 3 } else { 4 });
+    }
+    bits.to_le_bytes()
+}
+
+/// Picks the 7-bit values and shared p-bit whose 8-bit reconstruction is closest to `color`.
+fn quantize_mode6_endpoint(color: [f32; 4]) -> ([u32; 4], u32) {
+    let mut best = ([0u32; 4], 0u32, f32::MAX);
+    for pbit in 0..2u32 {
+        let values = color.map(|channel| (((channel - pbit as f32) / 2.0).round().clamp(0.0, 127.0)) as u32);
+        let error: f32 = (0..4)
+            .map(|channel| {
+                let diff = ((values[channel] << 1) | pbit) as f32 - color[channel];
+                diff * diff
+            })
+            .sum();
+        if error < best.2 {
+            best = (values, pbit, error);
+        }
+    }
+    (best.0, best.1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn partition_anchors_belong_to_their_subset() {
+        for partition in 0..64 {
+            assert_eq!(bc7_subset(2, partition, 0), 0);
+            assert_eq!(
+                bc7_subset(2, partition, BC7_ANCHORS_2[partition] as usize),
+                1,
+                "partition {partition}"
+            );
+            assert_eq!(bc7_subset(3, partition, 0), 0);
+            assert_eq!(
+                bc7_subset(3, partition, BC7_ANCHORS_3_SECOND[partition] as usize),
+                1,
+                "partition {partition}"
+            );
+            assert_eq!(
+                bc7_subset(3, partition, BC7_ANCHORS_3_THIRD[partition] as usize),
+                2,
+                "partition {partition}"
+            );
+        }
+    }
+
+    #[test]
+    fn bc1_block_uses_three_colors_and_transparency_when_endpoints_are_ordered_low_first() {
+        // c0 = black, c1 = white; indices 0, 1, 2, 3 across the first row.
+        let block = [0x00, 0x00, 0xFF, 0xFF, 0b1110_0100, 0, 0, 0];
+        let mut texels = [[0u8; 4]; 16];
+        decode_bc1_block(&block, false, &mut texels);
+        assert_eq!(&texels[..4], &[[0, 0, 0, 255], [255, 255, 255, 255], [127, 127, 127, 255], [0, 0, 0, 0]]);
+    }
+
+    #[test]
+    fn bc7_mode6_round_trips_a_gradient_closely() {
+        let mut texels = [[0u8; 4]; 16];
+        for (index, texel) in texels.iter_mut().enumerate() {
+            let t = index as u8 * 16;
+            *texel = [t, 255 - t, t / 2, 255 - t / 4];
+        }
+        let block = encode_bc7_mode6_block(&texels);
+        let mut decoded = [[0u8; 4]; 16];
+        decode_bc7_block(&block, &mut decoded);
+        for (expected, actual) in texels.iter().zip(decoded.iter()) {
+            for channel in 0..4 {
+                let diff = (expected[channel] as i32 - actual[channel] as i32).abs();
+                assert!(diff <= 6, "expected {expected:?}, decoded {actual:?}");
+            }
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use anyhow::{anyhow, Result};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Decodes a custom on-disk format into an in-memory asset.
+///
+/// Loaders are registered per asset type (atlases and clips on [`super::AssetManager`], meshes on
+/// [`crate::mesh_registry::MeshRegistry`]) and are consulted by the load, retain and reload paths
+/// before the built-in JSON/glTF handlers whenever the source path has one of their extensions.
+pub trait AssetLoader: Send + Sync {
+    type Asset;
+
+    /// File extensions handled by this loader, without the leading dot (matched case-insensitively).
+    fn extensions(&self) -> &[&str];
+
+    /// `key` is the asset key being loaded and `source` the path the bytes were read from.
+    fn load(&self, bytes: &[u8], key: &str, source: &str) -> Result<Self::Asset>;
+}
+
+/// Extension-keyed set of [`AssetLoader`]s for one asset type.
+pub struct AssetLoaderRegistry<A> {
+    loaders: Vec<Box<dyn AssetLoader<Asset = A>>>,
+    by_extension: HashMap<String, usize>,
+}
+
+impl<A> Default for AssetLoaderRegistry<A> {
+    fn default() -> Self {
+        Self { loaders: Vec::new(), by_extension: HashMap::new() }
+    }
+}
+
+impl<A> AssetLoaderRegistry<A> {
+    /// Fails without registering anything if any of the loader's extensions is already claimed.
+    pub fn register(&mut self, loader: Box<dyn AssetLoader<Asset = A>>) -> Result<()> {
+        let extensions: Vec<String> =
+            loader.extensions().iter().map(|ext| normalize_extension(ext)).collect();
+        if extensions.is_empty() || extensions.iter().any(|ext| ext.is_empty()) {
+            return Err(anyhow!("Asset loader must declare at least one non-empty extension"));
+        }
+        for (index, ext) in extensions.iter().enumerate() {
+            if self.by_extension.contains_key(ext) || extensions[..index].contains(ext) {
+                return Err(anyhow!("An asset loader is already registered for '.{ext}'"));
+            }
+        }
+        let slot = self.loaders.len();
+        self.loaders.push(loader);
+        for ext in extensions {
+            self.by_extension.insert(ext, slot);
+        }
+        Ok(())
+    }
+
+    pub fn loader_for(&self, path: impl AsRef<Path>) -> Option<&dyn AssetLoader<Asset = A>> {
+        let ext = path.as_ref().extension()?.to_str()?;
+        let slot = *self.by_extension.get(&normalize_extension(ext))?;
+        Some(self.loaders[slot].as_ref())
+    }
+
+    pub fn extensions(&self) -> Vec<String> {
+        let mut extensions: Vec<String> = self.by_extension.keys().cloned().collect();
+        extensions.sort();
+        extensions
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.loaders.is_empty()
+    }
+}
+
+fn normalize_extension(ext: &str) -> String {
+    ext.trim().trim_start_matches('.').to_ascii_lowercase()
+}
@@ -344,7 +344,7 @@ fn build_keyframes<T: Clone>(times: &[f32], values: Vec<T>) -> Result<Arc<[ClipK
                 continue;
             }
         }
-        frames.push(ClipKeyframe { time, value });
+        frames.push(ClipKeyframe::new(time, value));
     }
     Ok(Arc::from(frames.into_boxed_slice()))
 }
@@ -7,8 +7,15 @@ use rodio::source::{SineWave, Source};
 use rodio::{OutputStream, OutputStreamHandle, Sink, SpatialSink};
 use std::any::Any;
 use std::collections::VecDeque;
+use std::f32::consts::{FRAC_1_SQRT_2, PI};
 use std::time::Duration;
 
+/// Sample rate of the synthesized trigger voices (rodio's `SineWave` is fixed at 48 kHz).
+const VOICE_SAMPLE_RATE_HZ: u32 = 48_000;
+const MIN_FILTER_CUTOFF_HZ: f32 = 20.0;
+/// Upper bound for [`AudioManager::set_voice_count`]; each voice owns a rodio sink and its mixer input.
+pub const MAX_VOICE_COUNT: usize = 256;
+
 #[derive(Clone, Copy, Debug)]
 pub struct AudioListenerState {
     pub position: Vec3,
@@ -22,6 +29,196 @@ pub struct AudioSpatialConfig {
     pub min_distance: f32,
     pub max_distance: f32,
     pub pan_width: f32,
+    /// Cutoff of the optional low-pass stage applied to every voice.
+    pub lpf_cutoff_hz: Option<f32>,
+    /// Cutoff of the optional high-pass stage applied to every voice.
+    pub hpf_cutoff_hz: Option<f32>,
+}
+
+impl Default for AudioSpatialConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_distance: 0.1,
+            max_distance: 25.0,
+            pan_width: 10.0,
+            lpf_cutoff_hz: None,
+            hpf_cutoff_hz: None,
+        }
+    }
+}
+
+/// Normalized biquad coefficients (RBJ cookbook, Butterworth Q).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BiquadCoefficients {
+    b0: f32,
+    b1: f32,
+    b2: f32,
+    a1: f32,
+    a2: f32,
+}
+
+impl BiquadCoefficients {
+    pub fn low_pass(cutoff_hz: f32, sample_rate_hz: u32) -> Self {
+        let (cos_w0, alpha) = Self::prewarp(cutoff_hz, sample_rate_hz);
+        let b1 = 1.0 - cos_w0;
+        Self::normalized(b1 * 0.5, b1, b1 * 0.5, cos_w0, alpha)
+    }
+
+    pub fn high_pass(cutoff_hz: f32, sample_rate_hz: u32) -> Self {
+        let (cos_w0, alpha) = Self::prewarp(cutoff_hz, sample_rate_hz);
+        let b1 = 1.0 + cos_w0;
+        Self::normalized(b1 * 0.5, -b1, b1 * 0.5, cos_w0, alpha)
+    }
+
+    fn prewarp(cutoff_hz: f32, sample_rate_hz: u32) -> (f32, f32) {
+        let sample_rate = sample_rate_hz.max(1) as f32;
+        let cutoff = clamp_cutoff(cutoff_hz, sample_rate_hz).min(sample_rate * 0.49);
+        let w0 = 2.0 * PI * cutoff / sample_rate;
+        (w0.cos(), w0.sin() * 0.5 / FRAC_1_SQRT_2)
+    }
+
+    fn normalized(b0: f32, b1: f32, b2: f32, cos_w0: f32, alpha: f32) -> Self {
+        let a0 = 1.0 + alpha;
+        Self { b0: b0 / a0, b1: b1 / a0, b2: b2 / a0, a1: -2.0 * cos_w0 / a0, a2: (1.0 - alpha) / a0 }
+    }
+}
+
+fn clamp_cutoff(cutoff_hz: f32, sample_rate_hz: u32) -> f32 {
+    let nyquist = sample_rate_hz.max(1) as f32 * 0.5;
+    if cutoff_hz.is_finite() {
+        cutoff_hz.clamp(MIN_FILTER_CUTOFF_HZ, nyquist.max(MIN_FILTER_CUTOFF_HZ))
+    } else {
+        nyquist
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct BiquadState {
+    x1: f32,
+    x2: f32,
+    y1: f32,
+    y2: f32,
+}
+
+impl BiquadState {
+    fn process(&mut self, coeffs: &BiquadCoefficients, x: f32) -> f32 {
+        let y = coeffs.b0 * x + coeffs.b1 * self.x1 + coeffs.b2 * self.x2
+            - coeffs.a1 * self.y1
+            - coeffs.a2 * self.y2;
+        self.x2 = self.x1;
+        self.x1 = x;
+        self.y2 = self.y1;
+        self.y1 = y;
+        y
+    }
+}
+
+/// Precomputed filter stages shared by every voice started with the current config.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct VoiceFilter {
+    pub low_pass: Option<BiquadCoefficients>,
+    pub high_pass: Option<BiquadCoefficients>,
+}
+
+impl VoiceFilter {
+    pub fn from_config(cfg: &AudioSpatialConfig, sample_rate_hz: u32) -> Self {
+        Self {
+            low_pass: cfg.lpf_cutoff_hz.map(|hz| BiquadCoefficients::low_pass(hz, sample_rate_hz)),
+            high_pass: cfg.hpf_cutoff_hz.map(|hz| BiquadCoefficients::high_pass(hz, sample_rate_hz)),
+        }
+    }
+
+    pub fn is_bypass(&self) -> bool {
+        self.low_pass.is_none() && self.high_pass.is_none()
+    }
+
+    /// Wraps a mono source so each sample runs through the low-pass then high-pass stage.
+    pub fn apply<S>(self, source: S) -> FilteredSource<S>
+    where
+        S: Source<Item = f32>,
+    {
+        FilteredSource {
+            inner: source,
+            filter: self,
+            low: BiquadState::default(),
+            high: BiquadState::default(),
+        }
+    }
+}
+
+pub struct FilteredSource<S> {
+    inner: S,
+    filter: VoiceFilter,
+    low: BiquadState,
+    high: BiquadState,
+}
+
+impl<S> Iterator for FilteredSource<S>
+where
+    S: Source<Item = f32>,
+{
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        let mut sample = self.inner.next()?;
+        if let Some(coeffs) = self.filter.low_pass.as_ref() {
+            sample = self.low.process(coeffs, sample);
+        }
+        if let Some(coeffs) = self.filter.high_pass.as_ref() {
+            sample = self.high.process(coeffs, sample);
+        }
+        Some(sample)
+    }
+}
+
+impl<S> Source for FilteredSource<S>
+where
+    S: Source<Item = f32>,
+{
+    fn current_frame_len(&self) -> Option<usize> {
+        self.inner.current_frame_len()
+    }
+
+    fn channels(&self) -> u16 {
+        self.inner.channels()
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.inner.sample_rate()
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        self.inner.total_duration()
+    }
+}
+
+/// Sink a playing voice is routed through; kept so the voice can be stopped when the pool shrinks.
+enum VoiceSink {
+    Mono(Sink),
+    Spatial(SpatialSink),
+}
+
+impl VoiceSink {
+    fn finished(&self) -> bool {
+        match self {
+            VoiceSink::Mono(sink) => sink.empty(),
+            VoiceSink::Spatial(sink) => sink.empty(),
+        }
+    }
+
+    fn stop(&self) {
+        match self {
+            VoiceSink::Mono(sink) => sink.stop(),
+            VoiceSink::Spatial(sink) => sink.stop(),
+        }
+    }
+}
+
+struct ActiveVoice {
+    sink: VoiceSink,
+    /// Amplitude after distance attenuation; the quietest voice is the first to be stolen.
+    amplitude: f32,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -44,6 +241,10 @@ pub struct AudioManager {
     sample_rate_hz: Option<u32>,
     listener: AudioListenerState,
     spatial: AudioSpatialConfig,
+    voice_filter: VoiceFilter,
+    voice_limit: usize,
+    voices: Vec<ActiveVoice>,
+    stolen_voices: u32,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -54,6 +255,10 @@ pub struct AudioHealthSnapshot {
     pub last_error: Option<String>,
     pub device_name: Option<String>,
     pub sample_rate_hz: Option<u32>,
+    pub active_voices: usize,
+    pub voice_limit: usize,
+    /// Voices cut short (or never started) because the polyphony limit was reached.
+    pub stolen_voices: u32,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -63,13 +268,14 @@ struct AudioDeviceInfo {
 }
 
 impl AudioManager {
+    /// `capacity` sets both the polyphony limit and the length of the recent trigger log.
     pub fn new(capacity: usize) -> Self {
         let capacity = capacity.max(1);
         let device_info = AudioDeviceInfo::detect();
         let listener =
             AudioListenerState { position: Vec3::ZERO, forward: Vec3::new(0.0, 0.0, -1.0), up: Vec3::Y };
-        let spatial =
-            AudioSpatialConfig { enabled: true, min_distance: 0.1, max_distance: 25.0, pan_width: 10.0 };
+        let spatial = AudioSpatialConfig::default();
+        let voice_filter = VoiceFilter::from_config(&spatial, VOICE_SAMPLE_RATE_HZ);
         match OutputStream::try_default() {
             Ok((stream, handle)) => Self {
                 enabled: false,
@@ -84,6 +290,10 @@ impl AudioManager {
                 sample_rate_hz: device_info.sample_rate_hz,
                 listener,
                 spatial,
+                voice_filter,
+                voice_limit: capacity,
+                voices: Vec::new(),
+                stolen_voices: 0,
             },
             Err(err) => {
                 eprintln!(
@@ -103,6 +313,10 @@ impl AudioManager {
                     sample_rate_hz: device_info.sample_rate_hz,
                     listener,
                     spatial,
+                    voice_filter,
+                    voice_limit: capacity,
+                    voices: Vec::new(),
+                    stolen_voices: 0,
                 }
             }
         }
@@ -126,9 +340,28 @@ impl AudioManager {
     pub fn clear(&mut self) {
         self.triggers.clear();
         self.failed_playbacks = 0;
+        self.stolen_voices = 0;
         self.last_error = None;
     }
 
+    pub fn voice_count(&self) -> usize {
+        self.voice_limit
+    }
+
+    pub fn active_voices(&self) -> usize {
+        self.voices.iter().filter(|voice| !voice.sink.finished()).count()
+    }
+
+    /// Changes how many voices may play at once. Shrinking below the number currently playing stops
+    /// the quietest voices. The count is clamped to `1..=MAX_VOICE_COUNT`.
+    pub fn set_voice_count(&mut self, count: usize) {
+        self.voice_limit = count.clamp(1, MAX_VOICE_COUNT);
+        self.prune_finished_voices();
+        while self.voices.len() > self.voice_limit {
+            self.steal_quietest_voice();
+        }
+    }
+
     pub fn set_listener_state(&mut self, state: AudioListenerState) {
         self.listener = state;
     }
@@ -142,7 +375,26 @@ impl AudioManager {
         cfg.min_distance = cfg.min_distance.max(0.0);
         cfg.max_distance = cfg.max_distance.max(cfg.min_distance + 0.001);
         cfg.pan_width = cfg.pan_width.max(0.1);
+        cfg.lpf_cutoff_hz = cfg.lpf_cutoff_hz.map(|hz| clamp_cutoff(hz, VOICE_SAMPLE_RATE_HZ));
+        cfg.hpf_cutoff_hz = cfg.hpf_cutoff_hz.map(|hz| clamp_cutoff(hz, VOICE_SAMPLE_RATE_HZ));
+        let filters_changed = cfg.lpf_cutoff_hz != self.spatial.lpf_cutoff_hz
+            || cfg.hpf_cutoff_hz != self.spatial.hpf_cutoff_hz;
         self.spatial = cfg;
+        if filters_changed {
+            self.voice_filter = VoiceFilter::from_config(&self.spatial, VOICE_SAMPLE_RATE_HZ);
+        }
+    }
+
+    pub fn set_low_pass_filter(&mut self, cutoff_hz: Option<f32>) {
+        self.set_spatial_config(AudioSpatialConfig { lpf_cutoff_hz: cutoff_hz, ..self.spatial });
+    }
+
+    pub fn set_high_pass_filter(&mut self, cutoff_hz: Option<f32>) {
+        self.set_spatial_config(AudioSpatialConfig { hpf_cutoff_hz: cutoff_hz, ..self.spatial });
+    }
+
+    pub fn voice_filter(&self) -> VoiceFilter {
+        self.voice_filter
     }
 
     pub fn recent_triggers(&self) -> impl ExactSizeIterator<Item = &String> {
@@ -157,6 +409,9 @@ impl AudioManager {
             last_error: self.last_error.clone(),
             device_name: self.device_name.clone(),
             sample_rate_hz: self.sample_rate_hz,
+            active_voices: self.active_voices(),
+            voice_limit: self.voice_limit,
+            stolen_voices: self.stolen_voices,
         }
     }
 
@@ -173,8 +428,27 @@ impl AudioManager {
                 (format!("collision_force:{force:.3}"), audio.as_ref(), 0.12 + amplitude * 0.2)
             }
             GameEvent::SpriteAnimationEvent { .. } => return,
+            GameEvent::AnimationFinished { .. } => return,
+            GameEvent::ClipFinished { .. } => return,
             GameEvent::ScriptMessage { .. } => return,
+            GameEvent::ScriptEvent { .. } => return,
+            GameEvent::BoneTransformResult { .. } => return,
+            GameEvent::ParticleCollision { .. } => (String::from("particle_collision"), None, 0.1),
         };
+        self.trigger(label, emitter, base_amp);
+    }
+
+    /// Plays a named sound requested by gameplay code; positioned sounds are spatialised like
+    /// collision cues and recorded as `sound:<clip>@x,y`.
+    pub fn play_sound(&mut self, clip: &str, emitter: Option<&AudioEmitter>) {
+        let label = match emitter {
+            Some(em) => format!("sound:{clip}@{:.1},{:.1}", em.position.x, em.position.y),
+            None => format!("sound:{clip}"),
+        };
+        self.trigger(label, emitter, 0.18);
+    }
+
+    fn trigger(&mut self, label: String, emitter: Option<&AudioEmitter>, base_amp: f32) {
         self.push_trigger(label.clone());
         if self.enabled && !self.playback_available {
             self.try_reinit_output();
@@ -204,9 +478,6 @@ impl AudioManager {
         if self.handle.is_none() && !self.try_reinit_output() {
             return;
         }
-        let Some(handle) = self.handle.as_ref() else {
-            return;
-        };
         let frequency_hz = if label.starts_with("spawn") {
             440.0
         } else if label == "despawn" {
@@ -215,6 +486,8 @@ impl AudioManager {
             560.0
         } else if label == "collision_end" {
             280.0
+        } else if label.starts_with("sound:") {
+            520.0
         } else if let Some(force_str) = label.strip_prefix("collision_force:") {
             if let Ok(force) = force_str.parse::<f32>() {
                 let clamped = force.clamp(0.0, 2000.0);
@@ -226,6 +499,12 @@ impl AudioManager {
             return;
         };
         let amplitude = base_amplitude * distance_gain;
+        if !self.reserve_voice(amplitude) {
+            return;
+        }
+        let Some(handle) = self.handle.as_ref() else {
+            return;
+        };
         if let Some(spatial) = spatial {
             if let Ok(sink) = SpatialSink::try_new(
                 handle,
@@ -235,8 +514,8 @@ impl AudioManager {
             ) {
                 let source =
                     SineWave::new(frequency_hz).take_duration(Duration::from_millis(140)).amplify(amplitude);
-                sink.append(source);
-                sink.detach();
+                sink.append(self.voice_filter.apply(source));
+                self.voices.push(ActiveVoice { sink: VoiceSink::Spatial(sink), amplitude });
                 self.last_error = None;
                 return;
             }
@@ -245,8 +524,8 @@ impl AudioManager {
             Ok(sink) => {
                 let source =
                     SineWave::new(frequency_hz).take_duration(Duration::from_millis(140)).amplify(amplitude);
-                sink.append(source);
-                sink.detach();
+                sink.append(self.voice_filter.apply(source));
+                self.voices.push(ActiveVoice { sink: VoiceSink::Mono(sink), amplitude });
                 self.last_error = None;
             }
             Err(err) => {
@@ -255,12 +534,47 @@ impl AudioManager {
         }
     }
 
+    /// Makes room for a voice of `amplitude`, stealing the quietest playing voice when the pool is
+    /// full. Returns `false` when every playing voice is louder, in which case the new one is dropped.
+    fn reserve_voice(&mut self, amplitude: f32) -> bool {
+        self.prune_finished_voices();
+        if self.voices.len() < self.voice_limit {
+            return true;
+        }
+        let quietest = self.voices.iter().map(|voice| voice.amplitude).fold(f32::INFINITY, f32::min);
+        if amplitude < quietest {
+            self.stolen_voices = self.stolen_voices.saturating_add(1);
+            return false;
+        }
+        self.steal_quietest_voice();
+        true
+    }
+
+    fn prune_finished_voices(&mut self) {
+        self.voices.retain(|voice| !voice.sink.finished());
+    }
+
+    fn steal_quietest_voice(&mut self) {
+        let Some(index) = self
+            .voices
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.amplitude.total_cmp(&b.amplitude))
+            .map(|(index, _)| index)
+        else {
+            return;
+        };
+        self.voices.remove(index).sink.stop();
+        self.stolen_voices = self.stolen_voices.saturating_add(1);
+    }
+
     fn record_failure(&mut self, message: impl Into<String>) {
         self.failed_playbacks = self.failed_playbacks.saturating_add(1);
         self.last_error = Some(message.into());
     }
 
     fn mark_output_failed(&mut self, message: impl Into<String>) {
+        self.voices.clear();
         self.playback_available = false;
         self.handle = None;
         self._stream = None;
@@ -350,6 +664,27 @@ impl AudioPlugin {
         self.manager.set_spatial_config(cfg);
     }
 
+    pub fn set_low_pass_filter(&mut self, cutoff_hz: Option<f32>) {
+        self.manager.set_low_pass_filter(cutoff_hz);
+    }
+
+    pub fn set_high_pass_filter(&mut self, cutoff_hz: Option<f32>) {
+        self.manager.set_high_pass_filter(cutoff_hz);
+    }
+
+    pub fn voice_count(&self) -> usize {
+        self.manager.voice_count()
+    }
+
+    /// Grows or shrinks the voice pool at runtime; shrinking stops the quietest playing voices.
+    pub fn set_voice_count(&mut self, count: usize) {
+        self.manager.set_voice_count(count);
+    }
+
+    pub fn play_sound(&mut self, clip: &str, emitter: Option<&AudioEmitter>) {
+        self.manager.play_sound(clip, emitter);
+    }
+
     pub fn recent_triggers(&self) -> impl ExactSizeIterator<Item = &String> {
         self.manager.recent_triggers()
     }
@@ -413,3 +748,81 @@ impl AudioDeviceInfo {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rms_after_settling(source: impl Iterator<Item = f32>) -> f32 {
+        let samples: Vec<f32> = source.skip(4_800).take(9_600).collect();
+        (samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32).sqrt()
+    }
+
+    fn tone() -> impl Source<Item = f32> {
+        SineWave::new(440.0).take_duration(Duration::from_millis(500))
+    }
+
+    #[test]
+    fn play_sound_queues_positioned_trigger() {
+        let mut manager = AudioManager::new(4);
+        let emitter = AudioEmitter { position: Vec3::new(3.0, -2.0, 0.0), max_distance: 25.0 };
+        manager.play_sound("hit", Some(&emitter));
+        manager.play_sound("ui_click", None);
+        let triggers: Vec<_> = manager.recent_triggers().cloned().collect();
+        assert_eq!(triggers, vec!["sound:hit@3.0,-2.0".to_string(), "sound:ui_click".to_string()]);
+    }
+
+    fn idle_voice(amplitude: f32) -> ActiveVoice {
+        let (sink, _output) = Sink::new_idle();
+        sink.append(tone().amplify(amplitude));
+        ActiveVoice { sink: VoiceSink::Mono(sink), amplitude }
+    }
+
+    #[test]
+    fn shrinking_voice_count_stops_the_quietest_voices() {
+        let mut manager = AudioManager::new(4);
+        for amplitude in [0.3, 0.05, 0.2, 0.1] {
+            manager.voices.push(idle_voice(amplitude));
+        }
+        assert_eq!(manager.active_voices(), 4);
+
+        manager.set_voice_count(2);
+        let kept: Vec<f32> = manager.voices.iter().map(|voice| voice.amplitude).collect();
+        assert_eq!(kept, vec![0.3, 0.2]);
+        let health = manager.health_snapshot();
+        assert_eq!((health.active_voices, health.voice_limit, health.stolen_voices), (2, 2, 2));
+
+        assert!(!manager.reserve_voice(0.1), "quieter voices are dropped while the pool is full");
+        assert!(manager.reserve_voice(0.25));
+        assert_eq!(manager.voices.len(), 1, "the quietest voice made room");
+
+        manager.set_voice_count(0);
+        assert_eq!(manager.voice_count(), 1);
+        manager.set_voice_count(32);
+        assert_eq!(manager.voices.len(), 1, "growing the pool keeps playing voices");
+    }
+
+    #[test]
+    fn low_pass_attenuates_tone_above_cutoff_by_20_db() {
+        let dry = rms_after_settling(tone());
+        let cfg = AudioSpatialConfig { lpf_cutoff_hz: Some(100.0), ..AudioSpatialConfig::default() };
+        let filter = VoiceFilter::from_config(&cfg, VOICE_SAMPLE_RATE_HZ);
+        let wet = rms_after_settling(filter.apply(tone()));
+        let attenuation_db = 20.0 * (dry / wet).log10();
+        assert!(attenuation_db >= 20.0, "440 Hz only attenuated by {attenuation_db:.1} dB");
+    }
+
+    #[test]
+    fn filters_pass_tone_inside_band() {
+        let dry = rms_after_settling(tone());
+        let cfg = AudioSpatialConfig {
+            lpf_cutoff_hz: Some(4_000.0),
+            hpf_cutoff_hz: Some(60.0),
+            ..AudioSpatialConfig::default()
+        };
+        let filter = VoiceFilter::from_config(&cfg, VOICE_SAMPLE_RATE_HZ);
+        assert!(!filter.is_bypass());
+        let wet = rms_after_settling(filter.apply(tone()));
+        assert!((20.0 * (dry / wet).log10()).abs() < 1.0, "in-band tone should pass: {dry} vs {wet}");
+    }
+}
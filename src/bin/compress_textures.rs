@@ -0,0 +1,85 @@
+use anyhow::{anyhow, Context, Result};
+use kestrel_engine::assets::ktx2;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("[compress_textures] error: {err:?}");
+        std::process::exit(1);
+    }
+}
+
+fn run() -> Result<()> {
+    let mut force = false;
+    let mut inputs = Vec::new();
+    let mut show_help = false;
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--help" | "-h" => show_help = true,
+            "--force" => force = true,
+            other => inputs.push(PathBuf::from(other)),
+        }
+    }
+    if show_help || inputs.is_empty() {
+        print_usage();
+        return Ok(());
+    }
+    let mut targets = Vec::new();
+    for input in &inputs {
+        collect_targets(input, &mut targets)
+            .with_context(|| format!("failed to enumerate '{}'", input.display()))?;
+    }
+    if targets.is_empty() {
+        return Err(anyhow!("no PNG files found in provided paths"));
+    }
+    let mut written = 0_usize;
+    for path in &targets {
+        if !force && ktx2::preferred_texture_path(path) != *path {
+            println!("Up to date {}", ktx2::sidecar_path(path).display());
+            continue;
+        }
+        let sidecar = ktx2::write_bc7_sidecar(path)
+            .with_context(|| format!("failed to compress '{}'", path.display()))?;
+        let before = fs::metadata(path).map(|meta| meta.len()).unwrap_or(0);
+        let after = fs::metadata(&sidecar).map(|meta| meta.len()).unwrap_or(0);
+        println!("Wrote {} ({} -> {} bytes on disk)", sidecar.display(), before, after);
+        written += 1;
+    }
+    println!("Processed {} images ({} written)", targets.len(), written);
+    Ok(())
+}
+
+fn print_usage() {
+    eprintln!(
+        "compress_textures
+
+Usage:
+  compress_textures [--force] <path> [<path>...]
+
+Each <path> may be a PNG file or directory. Every PNG gets a BC7 `.ktx2` sidecar
+with a full mip chain; atlases and materials load the sidecar instead of the PNG
+while it is at least as new as the image. Up-to-date sidecars are skipped unless
+--force is given.
+"
+    );
+}
+
+fn collect_targets(path: &Path, targets: &mut Vec<PathBuf>) -> Result<()> {
+    let is_png =
+        path.extension().and_then(|ext| ext.to_str()).is_some_and(|ext| ext.eq_ignore_ascii_case("png"));
+    if path.is_dir() {
+        let mut entries: Vec<PathBuf> =
+            fs::read_dir(path)?.map(|entry| entry.map(|e| e.path())).collect::<Result<_, _>>()?;
+        entries.sort();
+        for entry in entries {
+            collect_targets(&entry, targets)?;
+        }
+    } else if is_png {
+        targets.push(path.to_path_buf());
+    } else if !path.exists() {
+        return Err(anyhow!("path '{}' does not exist", path.display()));
+    }
+    Ok(())
+}
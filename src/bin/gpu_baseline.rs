@@ -1,15 +1,18 @@
 use anyhow::{anyhow, Context, Result};
-use glam::Vec3;
+use glam::{Vec2, Vec3, Vec4};
 use kestrel_engine::assets::AssetManager;
 use kestrel_engine::camera::Camera2D;
 use kestrel_engine::camera3d::Camera3D;
 use kestrel_engine::config::WindowConfig;
 use kestrel_engine::ecs::{EcsWorld, InstanceData};
 use kestrel_engine::environment::EnvironmentRegistry;
-use kestrel_engine::gpu_baseline::{compare_baselines, GpuBaselineSnapshot, GpuTimingAccumulator};
+use kestrel_engine::gpu_baseline::{
+    GpuAdapterIdentity, GpuBaselineReport, GpuBaselineSnapshot, GpuBaselineTolerances, GpuTimingAccumulator,
+    DEFAULT_BASELINE_PATH,
+};
 use kestrel_engine::material_registry::MaterialRegistry;
 use kestrel_engine::mesh_registry::MeshRegistry;
-use kestrel_engine::renderer::{MeshDraw, RenderViewport, Renderer, SpriteBatch};
+use kestrel_engine::renderer::{MeshDraw, RenderViewport, Renderer, ScenePointLight, SpriteBatch};
 use kestrel_engine::scene::Scene;
 use std::collections::HashMap;
 use std::env;
@@ -17,18 +20,45 @@ use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+const STRESS_SCENE_PATH: &str = "assets/scenes/blank.json";
+const STRESS_ATLAS_PATH: &str = "assets/images/atlas.json";
+const STRESS_SPRITE_REGIONS: [&str; 4] = ["checker", "redorb", "bluebox", "green"];
+const STRESS_SPRITE_GRID: (usize, usize) = (32, 18);
+const STRESS_MESH_GRID: usize = 8;
+const STRESS_POINT_LIGHTS: usize = 48;
+const STRESS_EMITTERS: usize = 8;
+const DEFAULT_CAPTURE_PATH: &str = "perf/gpu_baseline_current.json";
+
 fn main() -> Result<()> {
     let args = BaselineArgs::parse(env::args().skip(1))?;
     pollster::block_on(run_baseline(args))
 }
 
+/// `--default-tolerance` and `--pass-tolerance` took milliseconds before tolerances became
+/// percentages. They still parse, now as percentages, so existing CI invocations keep running.
+fn warn_renamed_flag(arg: &str, current: &str) {
+    if arg != current {
+        eprintln!(
+            "[gpu_baseline] warning: {arg} is deprecated and its value is now a percentage; use {current}"
+        );
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BaselineMode {
+    /// Capture the stress scene and store it as the new baseline.
+    Record,
+    /// Capture the stress scene and fail if any pass regressed against the stored baseline.
+    Compare,
+}
+
 #[derive(Debug)]
 struct BaselineArgs {
+    mode: BaselineMode,
     frames: usize,
     output: PathBuf,
-    baseline: Option<PathBuf>,
-    default_tolerance_ms: f32,
-    pass_tolerances: HashMap<String, f32>,
+    baseline: PathBuf,
+    tolerances: GpuBaselineTolerances,
 }
 
 impl BaselineArgs {
@@ -37,19 +67,26 @@ impl BaselineArgs {
         I: IntoIterator<Item = S>,
         S: Into<String>,
     {
+        let mut mode = None;
         let mut frames = 240usize;
-        let mut output = PathBuf::from("perf/gpu_baseline.json");
+        let mut output = None;
         let mut baseline = None;
-        let mut default_tol = 0.30f32;
-        let mut pass_tolerances = HashMap::from([
-            ("Shadow pass".to_string(), 0.30),
-            ("Mesh pass".to_string(), 0.20),
-            ("Sprite pass".to_string(), 0.15),
-        ]);
+        let mut tolerances = GpuBaselineTolerances::default();
         let mut iter = args.into_iter();
         while let Some(raw) = iter.next() {
             let arg = raw.into();
             match arg.as_str() {
+                "--gpu-baseline" => {
+                    let value: String = iter
+                        .next()
+                        .ok_or_else(|| anyhow!("--gpu-baseline expects record or compare"))?
+                        .into();
+                    mode = Some(match value.as_str() {
+                        "record" => BaselineMode::Record,
+                        "compare" => BaselineMode::Compare,
+                        other => return Err(anyhow!("Unknown --gpu-baseline mode '{other}'")),
+                    });
+                }
                 "--frames" => {
                     let value: String =
                         iter.next().ok_or_else(|| anyhow!("--frames requires a value"))?.into();
@@ -58,38 +95,60 @@ impl BaselineArgs {
                 "--output" => {
                     let value: String =
                         iter.next().ok_or_else(|| anyhow!("--output requires a value"))?.into();
-                    output = PathBuf::from(value);
+                    output = Some(PathBuf::from(value));
                 }
                 "--baseline" => {
                     let value: String =
                         iter.next().ok_or_else(|| anyhow!("--baseline requires a value"))?.into();
                     baseline = Some(PathBuf::from(value));
                 }
-                "--default-tolerance" => {
+                "--tolerance-pct" | "--default-tolerance" => {
+                    warn_renamed_flag(&arg, "--tolerance-pct");
                     let value: String =
-                        iter.next().ok_or_else(|| anyhow!("--default-tolerance requires a value"))?.into();
-                    default_tol = value.parse().context("invalid --default-tolerance value")?;
+                        iter.next().ok_or_else(|| anyhow!("--tolerance-pct requires a value"))?.into();
+                    tolerances.default_percent = value.parse().context("invalid --tolerance-pct value")?;
                 }
-                "--pass-tolerance" => {
-                    let value: String =
-                        iter.next().ok_or_else(|| anyhow!("--pass-tolerance expects label=value"))?.into();
+                "--pass-tolerance-pct" | "--pass-tolerance" => {
+                    warn_renamed_flag(&arg, "--pass-tolerance-pct");
+                    let value: String = iter
+                        .next()
+                        .ok_or_else(|| anyhow!("--pass-tolerance-pct expects label=percent"))?
+                        .into();
                     let mut split = value.splitn(2, '=');
                     let label = split.next().unwrap_or_default();
                     let tol = split
                         .next()
-                        .ok_or_else(|| anyhow!("--pass-tolerance expects label=value"))?
+                        .ok_or_else(|| anyhow!("--pass-tolerance-pct expects label=percent"))?
                         .parse()
                         .context("invalid pass tolerance value")?;
-                    pass_tolerances.insert(label.to_string(), tol);
+                    tolerances.pass_percent.insert(label.to_string(), tol);
+                }
+                "--min-drift-ms" => {
+                    let value: String =
+                        iter.next().ok_or_else(|| anyhow!("--min-drift-ms requires a value"))?.into();
+                    tolerances.min_drift_ms = value.parse().context("invalid --min-drift-ms value")?;
                 }
                 other => return Err(anyhow!("Unknown argument '{other}'")),
             }
         }
-        Ok(Self { frames, output, baseline, default_tolerance_ms: default_tol, pass_tolerances })
+        // Without an explicit mode, passing a baseline implies a comparison.
+        let mode =
+            mode.unwrap_or(if baseline.is_some() { BaselineMode::Compare } else { BaselineMode::Record });
+        let baseline = baseline.unwrap_or_else(|| PathBuf::from(DEFAULT_BASELINE_PATH));
+        let output = output.unwrap_or_else(|| match mode {
+            BaselineMode::Record => baseline.clone(),
+            BaselineMode::Compare => PathBuf::from(DEFAULT_CAPTURE_PATH),
+        });
+        Ok(Self { mode, frames: frames.max(1), output, baseline, tolerances })
     }
 }
 
 async fn run_baseline(args: BaselineArgs) -> Result<()> {
+    // Load the baseline up front so a missing file fails before spending time on the capture.
+    let baseline = match args.mode {
+        BaselineMode::Record => None,
+        BaselineMode::Compare => Some(GpuBaselineSnapshot::load(&args.baseline)?),
+    };
     if let Some(parent) = args.output.parent() {
         if !parent.as_os_str().is_empty() {
             std::fs::create_dir_all(parent).with_context(|| format!("creating {}", parent.display()))?;
@@ -101,6 +160,7 @@ async fn run_baseline(args: BaselineArgs) -> Result<()> {
         height: 720,
         vsync: false,
         fullscreen: false,
+        render_scale: 1.0,
     })
     .await;
     renderer.init_headless_for_test().await?;
@@ -114,18 +174,19 @@ async fn run_baseline(args: BaselineArgs) -> Result<()> {
     }
     renderer.prepare_headless_render_target()?;
 
-    let mut scene = BaselineScene::load(&mut renderer, Path::new("assets/scenes/quick_save.json"))?;
+    let mut scene = BaselineScene::load(&mut renderer, Path::new(STRESS_SCENE_PATH))?;
+    scene.populate_stress(&mut renderer)?;
     let mut accumulator = GpuTimingAccumulator::default();
-    let camera2d = Camera2D::new(1.2);
+    let mut camera2d = Camera2D::new(1.2);
     let viewport = RenderViewport { origin: (0.0, 0.0), size: (1280.0, 720.0) };
-    let mesh_camera = Camera3D::new(Vec3::new(6.0, 6.0, 10.0), Vec3::ZERO, 60f32.to_radians(), 0.1, 100.0);
     let mut frames_recorded = 0usize;
-    for _ in 0..(args.frames * 2) {
+    for frame_index in 0..(args.frames * 2) {
+        let mesh_camera = scripted_camera_path(&mut camera2d, frame_index, args.frames);
         let sprite_sampler = scene.sprite_sampler_arc();
         scene.step(1.0 / 60.0);
         let (instances, batches) = scene.build_sprite_batches()?;
         let mesh_draws = scene.build_mesh_draws(&mut renderer)?;
-        let frame = renderer.render_frame(
+        let (frame, _) = renderer.render_frame(
             &instances,
             &batches,
             sprite_sampler.as_ref(),
@@ -138,6 +199,8 @@ async fn run_baseline(args: BaselineArgs) -> Result<()> {
         let timings = renderer.take_gpu_timings();
         if !timings.is_empty() {
             accumulator.record_frame(&timings);
+            let particles = scene.ecs.particle_budget_metrics();
+            accumulator.record_scene_metrics(renderer.light_cluster_metrics(), &particles);
             frames_recorded += 1;
             if frames_recorded >= args.frames {
                 break;
@@ -148,35 +211,41 @@ async fn run_baseline(args: BaselineArgs) -> Result<()> {
         return Err(anyhow!("GPU timings unavailable; ensure profiling is supported on this adapter"));
     }
 
-    let snapshot = accumulator.snapshot(
-        "gpu_baseline",
+    let mut snapshot = accumulator.snapshot(
+        "gpu_baseline_stress",
         format!("{}", SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs()),
         current_git_commit().unwrap_or_else(|_| "unknown".into()),
     );
+    snapshot.adapter = renderer.adapter_info().map(GpuAdapterIdentity::from_adapter_info);
     snapshot.write_to_path(&args.output)?;
-    if let Some(path) = args.baseline {
-        let baseline = GpuBaselineSnapshot::load(&path)?;
-        let deltas =
-            compare_baselines(&baseline, &snapshot, &args.pass_tolerances, args.default_tolerance_ms)?;
-        let regressions: Vec<_> = deltas.iter().filter(|d| !d.within_tolerance).collect();
-        if !regressions.is_empty() {
-            eprintln!("GPU baseline regressions:");
-            for entry in regressions {
-                eprintln!(
-                    "  {}: current {:.3} ms (baseline {:.3} ms) drift {:.3} ms (limit {:.3} ms)",
-                    entry.label,
-                    entry.current_avg_ms,
-                    entry.baseline_avg_ms,
-                    entry.delta_ms,
-                    entry.allowed_drift_ms
-                );
-            }
-            return Err(anyhow!("GPU baseline drift exceeded tolerance"));
+    let Some(baseline) = baseline else {
+        println!("Recorded {} frames to {}", snapshot.frame_count, args.output.display());
+        for pass in &snapshot.passes {
+            println!("  {:<14} avg {:.3} ms, max {:.3} ms", pass.label, pass.average_ms, pass.max_ms);
         }
+        return Ok(());
+    };
+    let report = GpuBaselineReport::compare(&baseline, &snapshot, &args.tolerances)?;
+    for line in report.lines() {
+        println!("{line}");
+    }
+    if report.has_regressions() {
+        return Err(anyhow!("GPU baseline regression against {}", args.baseline.display()));
     }
     Ok(())
 }
 
+/// Fixed camera path so every run sees the same coverage: the 2D camera pans a small loop while
+/// zooming in and out, and the 3D camera orbits the mesh grid once over the capture.
+fn scripted_camera_path(camera2d: &mut Camera2D, frame_index: usize, frames: usize) -> Camera3D {
+    let t = (frame_index % frames) as f32 / frames as f32;
+    let angle = t * std::f32::consts::TAU;
+    camera2d.position = Vec2::new(angle.cos(), angle.sin()) * 0.4;
+    camera2d.zoom = 1.0 + 0.35 * (angle * 2.0).sin();
+    let eye = Vec3::new(angle.cos() * 14.0, 6.0 + 2.0 * angle.sin(), angle.sin() * 14.0);
+    Camera3D::new(eye, Vec3::ZERO, 60f32.to_radians(), 0.1, 100.0)
+}
+
 fn physical_size(viewport: RenderViewport) -> winit::dpi::PhysicalSize<u32> {
     winit::dpi::PhysicalSize::new(viewport.size.0 as u32, viewport.size.1 as u32)
 }
@@ -210,6 +279,7 @@ impl BaselineScene {
             &mut material_registry,
             &mut environment_registry,
         )?;
+        assets.retain_atlas("main", Some(STRESS_ATLAS_PATH)).context("Failed to retain stress atlas")?;
         let mut ecs = EcsWorld::new();
         ecs.load_scene_with_dependencies(
             &scene,
@@ -282,6 +352,55 @@ impl BaselineScene {
         Ok(())
     }
 
+    /// Fills the scene with a sprite grid, a grid of lit meshes, clustered point lights and
+    /// particle emitters so every pass carries a representative load.
+    fn populate_stress(&mut self, renderer: &mut Renderer) -> Result<()> {
+        let (columns, rows) = STRESS_SPRITE_GRID;
+        for row in 0..rows {
+            for column in 0..columns {
+                let region = STRESS_SPRITE_REGIONS[(row + column) % STRESS_SPRITE_REGIONS.len()];
+                let position = Vec2::new(
+                    (column as f32 / (columns - 1) as f32 - 0.5) * 3.2,
+                    (row as f32 / (rows - 1) as f32 - 0.5) * 2.0,
+                );
+                self.ecs.spawn_scripted_sprite(&self.assets, "main", region, position, 0.08, Vec2::ZERO)?;
+            }
+        }
+        let spacing = 2.5;
+        let half_extent = (STRESS_MESH_GRID - 1) as f32 * spacing * 0.5;
+        for x in 0..STRESS_MESH_GRID {
+            for z in 0..STRESS_MESH_GRID {
+                let translation =
+                    Vec3::new(x as f32 * spacing - half_extent, 0.0, z as f32 * spacing - half_extent);
+                self.ecs.spawn_mesh_entity("cube", translation, Vec3::ONE);
+            }
+        }
+        let lighting = renderer.lighting_mut();
+        lighting.point_lights = (0..STRESS_POINT_LIGHTS)
+            .map(|index| {
+                let angle = index as f32 / STRESS_POINT_LIGHTS as f32 * std::f32::consts::TAU;
+                let ring = if index % 2 == 0 { half_extent } else { half_extent * 0.5 };
+                let color = Vec3::new(0.5 + 0.5 * angle.cos(), 0.5 + 0.5 * angle.sin(), 0.8);
+                ScenePointLight::new(Vec3::new(angle.cos() * ring, 1.5, angle.sin() * ring), color, 6.0, 2.0)
+            })
+            .collect();
+        for index in 0..STRESS_EMITTERS {
+            let x = (index as f32 / (STRESS_EMITTERS - 1) as f32 - 0.5) * 2.8;
+            self.ecs.spawn_particle_emitter(
+                Vec2::new(x, -0.8),
+                120.0,
+                0.6,
+                0.9,
+                1.5,
+                Vec4::new(1.0, 0.8, 0.3, 1.0),
+                Vec4::new(1.0, 0.2, 0.1, 0.0),
+                0.05,
+                0.01,
+            );
+        }
+        Ok(())
+    }
+
     fn step(&mut self, dt: f32) {
         self.ecs.update(dt);
     }
@@ -310,7 +429,14 @@ impl BaselineScene {
                 instances.extend(batch_instances);
                 let end = instances.len();
                 let view = self.atlas_view(atlas.as_ref())?;
-                batches.push(SpriteBatch { atlas, range: start as u32..end as u32, view });
+                let dirty = self.ecs.sprite_atlas_dirty(atlas.as_ref());
+                batches.push(SpriteBatch {
+                    atlas,
+                    range: start as u32..end as u32,
+                    view,
+                    normal_view: None,
+                    dirty,
+                });
             }
         }
         Ok((instances, batches))
@@ -36,6 +36,7 @@ use std::fs;
 use std::io::{self, BufReader, BufWriter, Read, Seek, SeekFrom};
 use std::path::{Component, Path, PathBuf};
 use std::ptr;
+use std::sync::Arc;
 use std::time::Duration;
 
 thread_local! {
@@ -62,6 +63,7 @@ struct HostOptions {
     plugin_name: String,
     capabilities: Vec<PluginCapability>,
     trust: PluginTrust,
+    config: Option<Arc<serde_json::Value>>,
 }
 
 impl HostOptions {
@@ -70,6 +72,7 @@ impl HostOptions {
         let mut plugin_name = "<unknown>".to_string();
         let mut capabilities = Vec::new();
         let mut trust = PluginTrust::Isolated;
+        let mut config = None;
         let mut args = env::args().skip(1);
         while let Some(arg) = args.next() {
             match arg.as_str() {
@@ -98,12 +101,18 @@ impl HostOptions {
                         };
                     }
                 }
+                "--config" => {
+                    if let Some(raw) = args.next() {
+                        let value = serde_json::from_str(&raw).context("parsing --config JSON")?;
+                        config = Some(Arc::new(value));
+                    }
+                }
                 _ => {}
             }
         }
         let plugin_path =
             plugin_path.map(PathBuf::from).ok_or_else(|| anyhow!("--plugin argument missing"))?;
-        Ok(Self { plugin_path, plugin_name, capabilities, trust })
+        Ok(Self { plugin_path, plugin_name, capabilities, trust, config })
     }
 }
 
@@ -272,6 +281,7 @@ struct EngineState {
     plugin_name: String,
     capability_flags: CapabilityFlags,
     trust: PluginTrust,
+    config: Option<Arc<serde_json::Value>>,
 }
 
 impl EngineState {
@@ -294,6 +304,7 @@ impl EngineState {
             plugin_name: opts.plugin_name.clone(),
             capability_flags,
             trust: opts.trust,
+            config: opts.config.clone(),
         }
     }
 
@@ -321,6 +332,7 @@ impl EngineState {
                 state.capability_tracker.clone(),
             );
             ctx.set_active_plugin(&state.plugin_name, state.capability_flags, state.trust);
+            ctx.set_active_config(state.config.clone());
             let result = f(&mut ctx);
             ctx.clear_active_plugin();
             result
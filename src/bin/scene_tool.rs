@@ -80,25 +80,9 @@ fn cmd_validate(scene_path: &str) -> Result<()> {
         }
     }
 
+    issues.extend(scene.validate_entity_references().iter().map(ToString::to_string));
+
     for entity in &scene.entities {
-        if let Some(parent_id) = entity.parent_id.as_ref() {
-            if !ids.contains(parent_id.as_str()) {
-                issues.push(format!(
-                    "entity '{}' references missing parent '{}'",
-                    entity.id.as_str(),
-                    parent_id.as_str()
-                ));
-            }
-        }
-        if let Some(parent_index) = entity.parent {
-            if parent_index >= scene.entities.len() {
-                issues.push(format!(
-                    "entity '{}' has parent index {} outside entity list",
-                    entity.id.as_str(),
-                    parent_index
-                ));
-            }
-        }
         if let Some(sprite) = &entity.sprite {
             if !scene.dependencies.contains_atlas(&sprite.atlas) {
                 issues.push(format!(
@@ -177,8 +161,13 @@ fn cmd_extract(scene_path: &str, entity_id: &str, output_path: &str) -> Result<(
         return Err(anyhow!("no entities collected for subtree rooted at '{entity_id}'"));
     }
     let dependencies = scene.dependencies.subset_for_entities(&entities, scene.metadata.environment.as_ref());
-    let prefab =
-        Scene { metadata: scene.metadata.clone(), dependencies, entities: std::mem::take(&mut entities) };
+    let prefab = Scene {
+        metadata: scene.metadata.clone(),
+        dependencies,
+        entities: std::mem::take(&mut entities),
+        chunks: Vec::new(),
+        next_entity_id: scene.next_entity_id,
+    };
     prefab.save_to_path(output_path)?;
     println!("Extracted {} entities rooted at '{}' into '{}'", prefab.entities.len(), entity_id, output_path);
     Ok(())
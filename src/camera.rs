@@ -1,17 +1,126 @@
 use glam::{Mat4, Vec2, Vec3, Vec4};
 use winit::dpi::PhysicalSize;
 
+/// Trauma-based screen shake. Trauma decays linearly; the offset scales with trauma squared and
+/// is sampled from smooth noise so consecutive frames drift rather than jitter.
+#[derive(Debug, Clone)]
+pub struct CameraShake {
+    pub trauma: f32,
+    /// Trauma removed per second.
+    pub decay: f32,
+    /// Offset at full trauma, in world units.
+    pub max_offset: f32,
+    /// Roll at full trauma, in radians.
+    pub max_rotation: f32,
+    /// Noise samples per second.
+    pub frequency: f32,
+    time: f32,
+    offset: Vec2,
+    rotation: f32,
+}
+
+impl Default for CameraShake {
+    fn default() -> Self {
+        Self {
+            trauma: 0.0,
+            decay: 1.2,
+            max_offset: 0.08,
+            max_rotation: 0.05,
+            frequency: 18.0,
+            time: 0.0,
+            offset: Vec2::ZERO,
+            rotation: 0.0,
+        }
+    }
+}
+
+impl CameraShake {
+    pub fn add_trauma(&mut self, amount: f32) {
+        if amount.is_finite() {
+            self.trauma = (self.trauma + amount).clamp(0.0, 1.0);
+        }
+    }
+
+    pub fn update(&mut self, dt: f32) {
+        if !dt.is_finite() || dt <= 0.0 {
+            return;
+        }
+        self.trauma = (self.trauma - self.decay * dt).max(0.0);
+        if self.trauma <= 0.0 {
+            self.time = 0.0;
+            self.offset = Vec2::ZERO;
+            self.rotation = 0.0;
+            return;
+        }
+        self.time += dt;
+        let shake = self.trauma * self.trauma;
+        let t = self.time * self.frequency;
+        self.offset = Vec2::new(smooth_noise(0, t), smooth_noise(1, t)) * self.max_offset * shake;
+        self.rotation = smooth_noise(2, t) * self.max_rotation * shake;
+    }
+
+    pub fn offset(&self) -> Vec2 {
+        self.offset
+    }
+
+    pub fn rotation(&self) -> f32 {
+        self.rotation
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.trauma > 0.0
+    }
+}
+
+/// Value noise in `[-1, 1]`, interpolated with smoothstep between integer lattice points.
+fn smooth_noise(channel: u32, t: f32) -> f32 {
+    let cell = t.floor();
+    let frac = t - cell;
+    let a = lattice_value(channel, cell as i32);
+    let b = lattice_value(channel, cell as i32 + 1);
+    let blend = frac * frac * (3.0 - 2.0 * frac);
+    a + (b - a) * blend
+}
+
+fn lattice_value(channel: u32, index: i32) -> f32 {
+    let mut hash = (index as u32).wrapping_mul(0x9E37_79B1) ^ channel.wrapping_mul(0x85EB_CA77);
+    hash ^= hash >> 15;
+    hash = hash.wrapping_mul(0x2C1B_3C6D);
+    hash ^= hash >> 12;
+    (hash & 0xFFFF) as f32 / 32767.5 - 1.0
+}
+
 #[derive(Debug, Clone)]
 pub struct Camera2D {
     pub position: Vec2,
     pub zoom: f32,
+    pub shake: CameraShake,
     base_half_height: f32,
     zoom_limits: (f32, f32),
 }
 
 impl Camera2D {
     pub fn new(base_half_height: f32) -> Self {
-        Self { position: Vec2::ZERO, zoom: 1.0, base_half_height, zoom_limits: (0.25, 5.0) }
+        Self {
+            position: Vec2::ZERO,
+            zoom: 1.0,
+            shake: CameraShake::default(),
+            base_half_height,
+            zoom_limits: (0.25, 5.0),
+        }
+    }
+
+    pub fn add_trauma(&mut self, amount: f32) {
+        self.shake.add_trauma(amount);
+    }
+
+    pub fn update_shake(&mut self, dt: f32) {
+        self.shake.update(dt);
+    }
+
+    /// Position the view is rendered from this frame; `position` itself never includes shake.
+    pub fn view_position(&self) -> Vec2 {
+        self.position + self.shake.offset()
     }
 
     pub fn set_zoom_limits(&mut self, min: f32, max: f32) {
@@ -34,7 +143,9 @@ impl Camera2D {
         let half_height = self.base_half_height / self.zoom;
         let half_width = half_height * aspect;
         let proj = Mat4::orthographic_rh_gl(-half_width, half_width, -half_height, half_height, -1.0, 1.0);
-        let view = Mat4::from_translation(Vec3::new(-self.position.x, -self.position.y, 0.0));
+        let eye = self.view_position();
+        let view = Mat4::from_rotation_z(-self.shake.rotation())
+            * Mat4::from_translation(Vec3::new(-eye.x, -eye.y, 0.0));
         proj * view
     }
 
@@ -105,6 +216,19 @@ impl Camera2D {
         Some((min_screen, max_screen))
     }
 
+    /// Centres the view on the `min`..`max` box and zooms so it fits the viewport, leaving `padding`
+    /// (a fraction of the box size) as margin. The zoom still honours the configured limits.
+    pub fn frame_bounds(&mut self, min: Vec2, max: Vec2, size: PhysicalSize<u32>, padding: f32) -> bool {
+        if size.width == 0 || size.height == 0 || !min.is_finite() || !max.is_finite() {
+            return false;
+        }
+        self.position = (min + max) * 0.5;
+        let half = ((max - min) * 0.5 * (1.0 + padding.max(0.0))).max(Vec2::splat(f32::EPSILON));
+        let required_half_height = half.y.max(half.x / Self::aspect(size));
+        self.set_zoom(self.base_half_height / required_half_height);
+        true
+    }
+
     pub fn half_extents(&self, size: PhysicalSize<u32>) -> Option<(f32, f32)> {
         if size.width == 0 || size.height == 0 {
             return None;
@@ -123,3 +247,45 @@ impl Camera2D {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trauma_offsets_view_then_decays_to_rest() {
+        let size = PhysicalSize::new(800, 600);
+        let mut camera = Camera2D::new(1.2);
+        camera.position = Vec2::new(0.5, -0.25);
+        let rest = camera.view_projection(size);
+
+        camera.add_trauma(0.8);
+        camera.update_shake(1.0 / 60.0);
+        assert!(camera.view_position() != camera.position, "shake should offset the view");
+        assert!(camera.view_projection(size) != rest);
+        assert_eq!(camera.position, Vec2::new(0.5, -0.25), "stored position is untouched");
+
+        for _ in 0..120 {
+            camera.update_shake(1.0 / 60.0);
+        }
+        assert!(!camera.shake.is_active());
+        assert_eq!(camera.view_position(), camera.position);
+        assert_eq!(camera.view_projection(size), rest);
+    }
+
+    #[test]
+    fn frame_bounds_fits_the_wider_axis_with_padding() {
+        let size = PhysicalSize::new(800, 400);
+        let mut camera = Camera2D::new(1.0);
+        assert!(camera.frame_bounds(Vec2::new(-1.0, 0.0), Vec2::new(3.0, 1.0), size, 0.1));
+        assert_eq!(camera.position, Vec2::new(1.0, 0.5));
+        // The 4-unit width dominates a 2:1 viewport: half width 2.2 with padding, so half height 1.1.
+        let (half_width, half_height) = camera.half_extents(size).unwrap();
+        assert!((half_width - 2.2).abs() < 1e-4);
+        assert!((half_height - 1.1).abs() < 1e-4);
+
+        camera.frame_bounds(Vec2::splat(-100.0), Vec2::splat(100.0), size, 0.1);
+        assert_eq!(camera.zoom, 0.25, "zoom stays within the configured limits");
+        assert!(!camera.frame_bounds(Vec2::ZERO, Vec2::ONE, PhysicalSize::new(0, 0), 0.1));
+    }
+}
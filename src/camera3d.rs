@@ -1,3 +1,4 @@
+use anyhow::{bail, Result};
 use glam::{Mat4, Quat, Vec2, Vec3, Vec4};
 use winit::dpi::PhysicalSize;
 
@@ -19,6 +20,19 @@ impl Camera3D {
         Self { position, target, up: DEFAULT_UP, fov_y_radians, near, far }
     }
 
+    /// Replaces the clip planes, rejecting a non-positive near plane or a far plane at or before it.
+    pub fn set_clip_planes(&mut self, near: f32, far: f32) -> Result<()> {
+        if !(near.is_finite() && near > 0.0) {
+            bail!("near clip plane must be greater than zero (got {near})");
+        }
+        if !(far.is_finite() && far > near) {
+            bail!("far clip plane must be beyond the near plane (near {near}, far {far})");
+        }
+        self.near = near;
+        self.far = far;
+        Ok(())
+    }
+
     pub fn view_matrix(&self) -> Mat4 {
         Mat4::look_at_rh(self.position, self.target, self.up)
     }
@@ -67,6 +81,49 @@ impl Camera3D {
         let y = (1.0 - ndc.y) * 0.5 * viewport.height as f32;
         Some(Vec2::new(x, y))
     }
+
+    /// World-space view frustum for the given viewport, used to cull draws before submission.
+    pub fn frustum(&self, viewport: PhysicalSize<u32>) -> Frustum {
+        Frustum::from_view_projection(self.view_projection(viewport))
+    }
+}
+
+/// Six normalized clip planes (left, right, bottom, top, near, far) with normals pointing inward.
+#[derive(Debug, Clone, Copy)]
+pub struct Frustum {
+    pub planes: [Vec4; 6],
+}
+
+impl Frustum {
+    pub fn from_view_projection(matrix: Mat4) -> Self {
+        let m = matrix.to_cols_array();
+        let row = |i: usize| Vec4::new(m[i], m[i + 4], m[i + 8], m[i + 12]);
+        let row0 = row(0);
+        let row1 = row(1);
+        let row2 = row(2);
+        let row3 = row(3);
+        let mut planes = [row3 + row0, row3 - row0, row3 + row1, row3 - row1, row3 + row2, row3 - row2];
+        for plane in &mut planes {
+            let length = plane.truncate().length();
+            if length > 0.0 {
+                *plane /= length;
+            }
+        }
+        Self { planes }
+    }
+
+    pub fn intersects_sphere(&self, center: Vec3, radius: f32) -> bool {
+        self.planes.iter().all(|plane| plane.truncate().dot(center) + plane.w >= -radius)
+    }
+
+    /// Conservative box test: only rejects boxes lying entirely behind one of the planes.
+    pub fn intersects_aabb(&self, min: Vec3, max: Vec3) -> bool {
+        self.planes.iter().all(|plane| {
+            let normal = plane.truncate();
+            let farthest = Vec3::select(normal.cmpge(Vec3::ZERO), max, min);
+            normal.dot(farthest) + plane.w >= 0.0
+        })
+    }
 }
 
 /// Orbit-style controller storing yaw/pitch around a target.
@@ -112,6 +169,35 @@ mod tests {
         assert!(!vp.to_cols_array().iter().any(|v| v.is_nan() || v.is_infinite()));
     }
 
+    #[test]
+    fn frustum_rejects_boxes_outside_the_view() {
+        let camera = Camera3D::new(Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO, 60.0_f32.to_radians(), 0.1, 100.0);
+        let frustum = camera.frustum(PhysicalSize::new(1280, 720));
+        assert!(frustum.intersects_aabb(Vec3::splat(-0.5), Vec3::splat(0.5)));
+        assert!(frustum.intersects_sphere(Vec3::ZERO, 0.5));
+        assert!(!frustum.intersects_aabb(Vec3::new(50.0, -0.5, -0.5), Vec3::new(51.0, 0.5, 0.5)));
+        assert!(!frustum.intersects_aabb(Vec3::new(-0.5, -0.5, 6.0), Vec3::new(0.5, 0.5, 7.0)));
+        assert!(!frustum.intersects_aabb(Vec3::new(-0.5, -0.5, -200.0), Vec3::new(0.5, 0.5, -150.0)));
+        // A box straddling the left plane is kept even though its center is off-screen.
+        assert!(frustum.intersects_aabb(Vec3::new(-20.0, -0.5, -0.5), Vec3::new(0.0, 0.5, 0.5)));
+    }
+
+    #[test]
+    fn clip_planes_set_the_projection_depth_range() {
+        let mut camera = Camera3D::new(Vec3::ZERO, Vec3::NEG_Z, 60.0_f32.to_radians(), 0.1, 100.0);
+        camera.set_clip_planes(2.0, 500.0).expect("valid clip planes");
+        let projection = camera.projection_matrix(16.0 / 9.0);
+        let ndc_depth = |distance: f32| projection.project_point3(Vec3::new(0.0, 0.0, -distance)).z;
+        assert!((ndc_depth(2.0) + 1.0).abs() < 1e-4, "near plane maps to -1: {}", ndc_depth(2.0));
+        assert!((ndc_depth(500.0) - 1.0).abs() < 1e-4, "far plane maps to +1: {}", ndc_depth(500.0));
+        assert!(ndc_depth(1.0) < -1.0 && ndc_depth(600.0) > 1.0, "points outside the range are clipped");
+
+        assert!(camera.set_clip_planes(0.0, 10.0).is_err(), "near must be positive");
+        assert!(camera.set_clip_planes(5.0, 5.0).is_err(), "far must be beyond near");
+        assert!(camera.set_clip_planes(1.0, f32::INFINITY).is_err());
+        assert_eq!((camera.near, camera.far), (2.0, 500.0), "rejected planes leave the camera unchanged");
+    }
+
     #[test]
     fn orbit_camera_orbits_target() {
         let mut orbit = OrbitCamera::new(Vec3::ZERO, 5.0);
@@ -1,12 +1,18 @@
 use crate::config::AppConfigOverrides;
 use anyhow::{anyhow, bail, Context, Result};
 use std::env;
+use std::path::PathBuf;
 
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct CliOverrides {
     width: Option<u32>,
     height: Option<u32>,
     vsync: Option<bool>,
+    capture_frames: Option<u32>,
+    capture_dir: Option<PathBuf>,
+    scene: Option<String>,
+    reset_window: bool,
+    headless_frames: Option<u32>,
 }
 
 impl CliOverrides {
@@ -14,6 +20,11 @@ impl CliOverrides {
         Self::parse(env::args())
     }
 
+    /// Whether `flag` consumes the following argument as its value; switches like `--reset-window` don't.
+    pub fn flag_takes_value(flag: &str) -> bool {
+        flag != "--reset-window"
+    }
+
     pub fn parse<I, S>(args: I) -> Result<Self>
     where
         I: IntoIterator<Item = S>,
@@ -25,9 +36,15 @@ impl CliOverrides {
         while let Some(raw_flag) = iter.next() {
             let flag = raw_flag.as_ref();
             if !flag.starts_with("--") {
-                bail!("Unexpected argument '{flag}'. Use --width/--height/--vsync with values.");
+                bail!(
+                    "Unexpected argument '{flag}'. Use --width/--height/--vsync/--capture-frames/--capture-dir/--scene/--headless-run with values, or --reset-window."
+                );
             }
             let key = &flag[2..];
+            if !Self::flag_takes_value(flag) {
+                overrides.reset_window = true;
+                continue;
+            }
             let value =
                 iter.next().ok_or_else(|| anyhow!("Expected a value after '{flag}'"))?.as_ref().to_string();
             match key {
@@ -42,14 +59,49 @@ impl CliOverrides {
                 "vsync" => {
                     overrides.vsync = Some(parse_bool_flag("vsync", &value)?);
                 }
-                _ => bail!("Unknown flag '{flag}'. Supported flags: --width, --height, --vsync."),
+                "capture-frames" => {
+                    let frames = value
+                        .parse::<u32>()
+                        .with_context(|| format!("Invalid capture frame count '{value}'"))?;
+                    if frames == 0 {
+                        bail!("--capture-frames must be at least 1");
+                    }
+                    overrides.capture_frames = Some(frames);
+                }
+                "capture-dir" => {
+                    overrides.capture_dir = Some(PathBuf::from(value));
+                }
+                "scene" => {
+                    if value.trim().is_empty() {
+                        bail!("--scene requires a scene path");
+                    }
+                    overrides.scene = Some(value);
+                }
+                "headless-run" => {
+                    overrides.headless_frames = Some(parse_headless_run(&value)?);
+                }
+                _ => bail!(
+                    "Unknown flag '{flag}'. Supported flags: --width, --height, --vsync, --capture-frames, --capture-dir, --scene, --headless-run, --reset-window."
+                ),
             }
         }
+        if overrides.capture_dir.is_some() && overrides.capture_frames.is_none() {
+            bail!("--capture-dir requires --capture-frames");
+        }
         Ok(overrides)
     }
 
     pub fn into_config_overrides(self) -> AppConfigOverrides {
-        AppConfigOverrides { width: self.width, height: self.height, vsync: self.vsync }
+        AppConfigOverrides {
+            width: self.width,
+            height: self.height,
+            vsync: self.vsync,
+            capture_frames: self.capture_frames,
+            capture_dir: self.capture_dir,
+            startup_scene: self.scene,
+            reset_window: self.reset_window,
+            headless_frames: self.headless_frames,
+        }
     }
 
     #[cfg(test)]
@@ -58,6 +110,18 @@ impl CliOverrides {
     }
 }
 
+/// Parses the `frames=N` value of `--headless-run`.
+fn parse_headless_run(value: &str) -> Result<u32> {
+    let Some(frames) = value.strip_prefix("frames=") else {
+        bail!("Invalid --headless-run value '{value}'. Use frames=N, e.g. --headless-run frames=120.");
+    };
+    let frames = frames.parse::<u32>().with_context(|| format!("Invalid headless frame count '{frames}'"))?;
+    if frames == 0 {
+        bail!("--headless-run needs at least 1 frame");
+    }
+    Ok(frames)
+}
+
 fn parse_bool_flag(flag: &str, value: &str) -> Result<bool> {
     match value.to_ascii_lowercase().as_str() {
         "1" | "true" | "on" | "yes" => Ok(true),
@@ -69,6 +133,7 @@ fn parse_bool_flag(flag: &str, value: &str) -> Result<bool> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::AppConfig;
 
     #[test]
     fn parses_width_height_and_vsync() {
@@ -90,6 +155,61 @@ mod tests {
         assert!(err.to_string().contains("Expected a value"), "error should mention missing value");
     }
 
+    #[test]
+    fn parses_capture_flags() {
+        let args = ["app", "--capture-frames", "120", "--capture-dir", "captures/demo"];
+        let overrides = CliOverrides::parse(args).expect("parse overrides").into_config_overrides();
+        assert_eq!(overrides.capture_frames, Some(120));
+        assert_eq!(overrides.capture_dir, Some(PathBuf::from("captures/demo")));
+    }
+
+    #[test]
+    fn capture_dir_requires_frame_count() {
+        let err = CliOverrides::parse(["app", "--capture-dir", "out"]).unwrap_err();
+        assert!(err.to_string().contains("--capture-frames"), "error should point at the missing flag");
+        let err = CliOverrides::parse(["app", "--capture-frames", "0"]).unwrap_err();
+        assert!(err.to_string().contains("at least 1"));
+    }
+
+    #[test]
+    fn scene_flag_overrides_configured_startup_scene() {
+        let overrides = CliOverrides::parse(["app", "--scene", "assets/scenes/arena.json"])
+            .expect("parse overrides")
+            .into_config_overrides();
+        assert_eq!(overrides.startup_scene.as_deref(), Some("assets/scenes/arena.json"));
+        assert_eq!(overrides.applied_fields(), vec!["startup_scene"]);
+        let mut config =
+            AppConfig { startup_scene: Some("assets/scenes/menu.json".into()), ..Default::default() };
+        config.apply_overrides(&overrides);
+        assert_eq!(config.startup_scene.as_deref(), Some("assets/scenes/arena.json"));
+        assert!(CliOverrides::parse(["app", "--scene", " "]).is_err());
+    }
+
+    #[test]
+    fn reset_window_is_a_switch() {
+        let overrides = CliOverrides::parse(["app", "--reset-window", "--width", "1024"])
+            .expect("parse overrides")
+            .into_config_overrides();
+        assert!(overrides.reset_window);
+        assert_eq!(overrides.width, Some(1024));
+        assert_eq!(overrides.applied_fields(), vec!["width", "reset_window"]);
+        assert!(!CliOverrides::flag_takes_value("--reset-window"));
+        assert!(CliOverrides::flag_takes_value("--scene"));
+    }
+
+    #[test]
+    fn headless_run_populates_frame_override() {
+        let overrides = CliOverrides::parse(["app", "--headless-run", "frames=90"])
+            .expect("parse overrides")
+            .into_config_overrides();
+        assert_eq!(overrides.headless_frames, Some(90));
+        assert_eq!(overrides.applied_fields(), vec!["headless_frames"]);
+        assert!(!overrides.is_empty());
+        assert!(CliOverrides::parse(["app", "--headless-run", "90"]).is_err(), "frames= prefix is required");
+        assert!(CliOverrides::parse(["app", "--headless-run", "frames=0"]).is_err());
+        assert!(CliOverrides::parse(["app", "--headless-run", "frames=many"]).is_err());
+    }
+
     #[test]
     fn rejects_unknown_flags() {
         let err = CliOverrides::parse(["app", "--foo", "bar"]).unwrap_err();
@@ -1,7 +1,7 @@
 use anyhow::{Context, Result};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct WindowConfig {
@@ -10,6 +10,9 @@ pub struct WindowConfig {
     pub height: u32,
     pub vsync: bool,
     pub fullscreen: bool,
+    /// Fraction of the window resolution the scene renders at; see `Renderer::set_render_scale`.
+    #[serde(default = "WindowConfig::default_render_scale")]
+    pub render_scale: f32,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -20,6 +23,9 @@ pub struct ParticleConfig {
     pub max_total: u32,
     #[serde(default = "ParticleConfig::default_max_emitter_backlog")]
     pub max_emitter_backlog: f32,
+    /// Particle collision checks are skipped while more particles than this are alive.
+    #[serde(default = "ParticleConfig::default_max_collision_particles")]
+    pub max_collision_particles: u32,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
@@ -52,8 +58,31 @@ pub struct EditorConfig {
     pub sprite_guard_max_pixels: f32,
     #[serde(default = "EditorConfig::default_guardrail_mode")]
     pub sprite_guardrail_mode: SpriteGuardrailMode,
+    /// Bakes non-moving, non-animated sprites into a persistent instance buffer that is only
+    /// re-uploaded when the static set changes. Static sprites then draw beneath dynamic ones.
+    #[serde(default)]
+    pub static_sprite_batching: bool,
+    /// Applies the frame's camera pan before sampling the cursor, so picking and gizmo drags use
+    /// one sample resolved against the camera that frame renders with. Clicks and key presses
+    /// still take effect at frame start.
+    #[serde(default)]
+    pub low_latency_input: bool,
     #[serde(default)]
     pub gpu_timing: bool,
+    /// Estimates per-frame allocations in sprite and mesh collection without the `alloc_profiler`
+    /// global allocator; results appear in the animation budget HUD.
+    #[serde(default)]
+    pub alloc_sampling: bool,
+    /// Command used to open scripts at an error line. `{file}` and `{line}` are substituted;
+    /// without placeholders `+line file` is appended. Falls back to `$VISUAL`/`$EDITOR`.
+    #[serde(default)]
+    pub script_editor_command: Option<String>,
+    /// Fraction of the framed bounds added as margin when focusing all entities with `F`.
+    #[serde(default = "EditorConfig::default_focus_padding")]
+    pub focus_padding: f32,
+    /// Locale code for editor text, loaded from `assets/locale/{language}.json`.
+    #[serde(default = "EditorConfig::default_language")]
+    pub language: String,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Default)]
@@ -122,6 +151,16 @@ impl Default for TimingConfig {
     }
 }
 
+/// Opt-in determinism for replays and lockstep: particles and physics only advance on the fixed
+/// step, in scene-id order, with random draws taken from per-emitter streams derived from `seed`.
+#[derive(Debug, Clone, Copy, Deserialize, Default)]
+pub struct SimulationConfig {
+    #[serde(default)]
+    pub deterministic: bool,
+    #[serde(default)]
+    pub seed: u64,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct ShadowConfig {
     #[serde(default = "ShadowConfig::default_cascade_count")]
@@ -134,6 +173,84 @@ pub struct ShadowConfig {
     pub pcf_radius: f32,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct TextureConfig {
+    /// Max anisotropy for the default texture sampler (1 disables it). Clamped to adapter support.
+    #[serde(default = "TextureConfig::default_anisotropy")]
+    pub anisotropy: u16,
+}
+
+impl TextureConfig {
+    const fn default_anisotropy() -> u16 {
+        1
+    }
+}
+
+impl Default for TextureConfig {
+    fn default() -> Self {
+        Self { anisotropy: Self::default_anisotropy() }
+    }
+}
+
+/// Color handling for the sprite pass.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SpriteColorSpace {
+    /// Legacy behavior: tints multiply the sampled texel unconverted.
+    #[default]
+    Gamma,
+    /// sRGB-correct: tints are decoded from sRGB, blending happens in linear space and the result
+    /// is encoded on output, matching how meshes treat color.
+    Linear,
+}
+
+impl SpriteColorSpace {
+    pub fn label(self) -> &'static str {
+        match self {
+            SpriteColorSpace::Gamma => "Gamma (legacy)",
+            SpriteColorSpace::Linear => "Linear (sRGB-correct)",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct SpriteConfig {
+    #[serde(default)]
+    pub color_space: SpriteColorSpace,
+}
+
+/// Draw order of sprites inside the sprite pass.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SpriteSortMode {
+    /// Higher world Y draws first, so sprites lower on screen overlap the ones behind them.
+    YSort,
+    /// Ascending `ZOrder` component value; ties keep creation order.
+    ZOrder,
+    /// Entity order grouped by atlas, the behavior before sort modes existed.
+    #[default]
+    CreationOrder,
+}
+
+impl SpriteSortMode {
+    pub const ALL: [SpriteSortMode; 3] =
+        [SpriteSortMode::YSort, SpriteSortMode::ZOrder, SpriteSortMode::CreationOrder];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SpriteSortMode::YSort => "Y-sort",
+            SpriteSortMode::ZOrder => "Z-order",
+            SpriteSortMode::CreationOrder => "Creation order",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct RenderConfig {
+    #[serde(default)]
+    pub sprite_sort_mode: SpriteSortMode,
+}
+
 #[derive(Debug, Clone, Deserialize, Default)]
 pub struct AppConfig {
     pub window: WindowConfig,
@@ -144,11 +261,25 @@ pub struct AppConfig {
     #[serde(default)]
     pub shadow: ShadowConfig,
     #[serde(default)]
+    pub textures: TextureConfig,
+    #[serde(default)]
+    pub sprites: SpriteConfig,
+    #[serde(default)]
+    pub render: RenderConfig,
+    #[serde(default)]
     pub editor: EditorConfig,
     #[serde(default)]
     pub timing: TimingConfig,
     #[serde(default)]
     pub scripts: ScriptsConfig,
+    #[serde(default)]
+    pub simulation: SimulationConfig,
+    /// Scene file loaded once the renderer is up, ahead of the project's startup scene.
+    #[serde(default)]
+    pub startup_scene: Option<String>,
+    /// Upper bound on live entities. Bursts are truncated and scripted spawns refused once reached.
+    #[serde(default)]
+    pub max_entities: Option<usize>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -156,6 +287,13 @@ pub struct AppConfigOverrides {
     pub width: Option<u32>,
     pub height: Option<u32>,
     pub vsync: Option<bool>,
+    pub capture_frames: Option<u32>,
+    pub capture_dir: Option<PathBuf>,
+    pub startup_scene: Option<String>,
+    /// Ignore the window geometry saved at the end of the previous session.
+    pub reset_window: bool,
+    /// Run this many frames and exit, failing on any engine error (`--headless-run frames=N`).
+    pub headless_frames: Option<u32>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -175,9 +313,22 @@ impl Default for ScriptsConfig {
     }
 }
 
+impl WindowConfig {
+    const fn default_render_scale() -> f32 {
+        1.0
+    }
+}
+
 impl Default for WindowConfig {
     fn default() -> Self {
-        Self { title: "Kestrel Engine".to_string(), width: 1280, height: 720, vsync: true, fullscreen: false }
+        Self {
+            title: "Kestrel Engine".to_string(),
+            width: 1280,
+            height: 720,
+            vsync: true,
+            fullscreen: false,
+            render_scale: Self::default_render_scale(),
+        }
     }
 }
 
@@ -193,6 +344,10 @@ impl ParticleConfig {
     fn default_max_emitter_backlog() -> f32 {
         64.0
     }
+
+    const fn default_max_collision_particles() -> u32 {
+        1_000
+    }
 }
 
 impl Default for ParticleConfig {
@@ -201,6 +356,7 @@ impl Default for ParticleConfig {
             max_spawn_per_frame: Self::default_max_spawn_per_frame(),
             max_total: Self::default_max_total(),
             max_emitter_backlog: Self::default_max_emitter_backlog(),
+            max_collision_particles: Self::default_max_collision_particles(),
         }
     }
 }
@@ -221,6 +377,14 @@ impl EditorConfig {
     fn default_guardrail_mode() -> SpriteGuardrailMode {
         SpriteGuardrailMode::Warn
     }
+
+    const fn default_focus_padding() -> f32 {
+        0.1
+    }
+
+    fn default_language() -> String {
+        crate::locale::DEFAULT_LANGUAGE.to_string()
+    }
 }
 
 impl Default for EditorConfig {
@@ -230,7 +394,13 @@ impl Default for EditorConfig {
             camera_zoom_max: Self::default_zoom_max(),
             sprite_guard_max_pixels: Self::default_sprite_guard_max_pixels(),
             sprite_guardrail_mode: Self::default_guardrail_mode(),
+            static_sprite_batching: false,
+            low_latency_input: false,
             gpu_timing: false,
+            alloc_sampling: false,
+            script_editor_command: None,
+            focus_padding: Self::default_focus_padding(),
+            language: Self::default_language(),
         }
     }
 }
@@ -284,6 +454,30 @@ impl AppConfig {
         }
     }
 
+    /// Writes `render.sprite_sort_mode` into the config file at `path`, leaving every other key
+    /// as it was. A missing file is created with just that setting.
+    pub fn persist_sprite_sort_mode(path: impl AsRef<Path>, mode: SpriteSortMode) -> Result<()> {
+        let path = path.as_ref();
+        let mut root = match fs::read(path) {
+            Ok(bytes) => serde_json::from_slice::<serde_json::Value>(&bytes)
+                .with_context(|| format!("Failed to parse config file {}", path.display()))?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => serde_json::json!({}),
+            Err(err) => {
+                return Err(err).with_context(|| format!("Failed to read config file {}", path.display()))
+            }
+        };
+        let Some(object) = root.as_object_mut() else {
+            anyhow::bail!("Config file {} is not a JSON object", path.display());
+        };
+        let render = object.entry("render").or_insert_with(|| serde_json::json!({}));
+        let Some(render) = render.as_object_mut() else {
+            anyhow::bail!("'render' in config file {} is not a JSON object", path.display());
+        };
+        render.insert("sprite_sort_mode".to_string(), serde_json::to_value(mode)?);
+        let text = serde_json::to_string_pretty(&root)?;
+        fs::write(path, text).with_context(|| format!("Failed to write config file {}", path.display()))
+    }
+
     pub fn apply_overrides(&mut self, overrides: &AppConfigOverrides) {
         if let Some(width) = overrides.width {
             self.window.width = width;
@@ -294,12 +488,22 @@ impl AppConfig {
         if let Some(vsync) = overrides.vsync {
             self.window.vsync = vsync;
         }
+        if let Some(scene) = overrides.startup_scene.as_ref() {
+            self.startup_scene = Some(scene.clone());
+        }
     }
 }
 
 impl AppConfigOverrides {
     pub fn is_empty(&self) -> bool {
-        self.width.is_none() && self.height.is_none() && self.vsync.is_none()
+        self.width.is_none()
+            && self.height.is_none()
+            && self.vsync.is_none()
+            && self.capture_frames.is_none()
+            && self.capture_dir.is_none()
+            && self.startup_scene.is_none()
+            && !self.reset_window
+            && self.headless_frames.is_none()
     }
 
     pub fn applied_fields(&self) -> Vec<&'static str> {
@@ -313,6 +517,21 @@ impl AppConfigOverrides {
         if self.vsync.is_some() {
             fields.push("vsync");
         }
+        if self.capture_frames.is_some() {
+            fields.push("capture_frames");
+        }
+        if self.capture_dir.is_some() {
+            fields.push("capture_dir");
+        }
+        if self.startup_scene.is_some() {
+            fields.push("startup_scene");
+        }
+        if self.reset_window {
+            fields.push("reset_window");
+        }
+        if self.headless_frames.is_some() {
+            fields.push("headless_frames");
+        }
         fields
     }
 }
@@ -1,12 +1,16 @@
 mod physics;
+mod plugin_components;
 mod profiler;
+mod snapshot;
 mod systems;
 mod transform;
 mod types;
 mod world;
 
 pub use physics::*;
+pub use plugin_components::*;
 pub use profiler::*;
+pub use snapshot::*;
 pub use systems::*;
 pub use transform::*;
 pub use types::*;
@@ -1,11 +1,13 @@
+use crate::ecs::types::{ColliderGeometry, ColliderKind};
 use bevy_ecs::prelude::*;
 use glam::Vec2;
 use rapier2d::geometry::{CollisionEvent, CollisionEventFlags};
 use rapier2d::pipeline::{ActiveEvents, EventHandler};
 use rapier2d::prelude::{
     CCDSolver, Collider, ColliderBuilder, ColliderHandle, ColliderSet, ContactPair, DefaultBroadPhase,
-    ImpulseJointSet, IntegrationParameters, IslandManager, MultibodyJointSet, NarrowPhase, PhysicsPipeline,
-    QueryPipeline, Real, RigidBody, RigidBodyBuilder, RigidBodyHandle, RigidBodySet, SharedShape, Vector,
+    ImpulseJointSet, IntegrationParameters, IslandManager, Isometry, MultibodyJointSet, NarrowPhase,
+    PhysicsPipeline, QueryPipeline, Real, RigidBody, RigidBodyBuilder, RigidBodyHandle, RigidBodySet,
+    SharedShape, Vector,
 };
 use smallvec::SmallVec;
 use std::collections::{HashMap, HashSet};
@@ -116,6 +118,34 @@ pub struct RapierState {
     event_collector: CollisionEventCollector,
     boundary_entity: Entity,
     bounds: WorldBounds,
+    /// Disabled bodies of despawned pooled sprites, reused by `reuse_dynamic_body`.
+    parked_bodies: Vec<(RigidBodyHandle, ColliderHandle)>,
+}
+
+/// Copies the simulation state. The pipeline and event collector only hold per-step scratch,
+/// so the copy gets fresh ones.
+impl Clone for RapierState {
+    fn clone(&self) -> Self {
+        Self {
+            pipeline: PhysicsPipeline::new(),
+            gravity: self.gravity,
+            integration_parameters: self.integration_parameters,
+            island_manager: self.island_manager.clone(),
+            broad_phase: self.broad_phase.clone(),
+            narrow_phase: self.narrow_phase.clone(),
+            bodies: self.bodies.clone(),
+            colliders: self.colliders.clone(),
+            impulse_joints: self.impulse_joints.clone(),
+            multibody_joints: self.multibody_joints.clone(),
+            ccd_solver: self.ccd_solver.clone(),
+            query_pipeline: self.query_pipeline.clone(),
+            collider_entities: self.collider_entities.clone(),
+            event_collector: CollisionEventCollector::new(),
+            boundary_entity: self.boundary_entity,
+            bounds: self.bounds,
+            parked_bodies: self.parked_bodies.clone(),
+        }
+    }
 }
 
 impl RapierState {
@@ -137,6 +167,7 @@ impl RapierState {
             event_collector: CollisionEventCollector::new(),
             boundary_entity,
             bounds: *bounds,
+            parked_bodies: Vec::new(),
         };
         state.init_bounds();
         state
@@ -175,6 +206,15 @@ impl RapierState {
         self.collider_entities.insert(handle, self.boundary_entity);
     }
 
+    /// Points colliders owned by respawned entities at their new ids.
+    pub(crate) fn remap_entities(&mut self, map: &HashMap<Entity, Entity>) {
+        for entity in self.collider_entities.values_mut().chain(std::iter::once(&mut self.boundary_entity)) {
+            if let Some(mapped) = map.get(entity) {
+                *entity = *mapped;
+            }
+        }
+    }
+
     pub fn spawn_dynamic_body(
         &mut self,
         position: Vec2,
@@ -200,12 +240,73 @@ impl RapierState {
         (body_handle, collider_handle)
     }
 
+    /// Takes a parked body back out of the pool and resets it the way `spawn_dynamic_body` builds
+    /// a new one. Returns `None` when the pool is empty.
+    pub fn reuse_dynamic_body(
+        &mut self,
+        position: Vec2,
+        half: Vec2,
+        mass: f32,
+        velocity: Vec2,
+    ) -> Option<(RigidBodyHandle, ColliderHandle)> {
+        let (body_handle, collider_handle) = self.parked_bodies.pop()?;
+        let body = self.bodies.get_mut(body_handle)?;
+        body.set_enabled(true);
+        body.set_position(Isometry::translation(position.x, position.y), true);
+        body.set_additional_mass(mass.max(0.0), true);
+        body.set_linvel(Vector::new(velocity.x, velocity.y), true);
+        body.set_angvel(0.0, true);
+        body.reset_forces(true);
+        body.reset_torques(true);
+        body.wake_up(true);
+        let collider = self.colliders.get_mut(collider_handle)?;
+        collider.set_shape(SharedShape::cuboid(half.x, half.y));
+        collider.set_translation_wrt_parent(Vector::zeros());
+        collider.set_restitution(0.3);
+        collider.set_friction(0.6);
+        Some((body_handle, collider_handle))
+    }
+
+    /// Disables `handle` and keeps it for `reuse_dynamic_body` instead of removing it, up to
+    /// `capacity` parked bodies; past that the body is removed as usual.
+    pub fn park_body(&mut self, handle: RigidBodyHandle, capacity: usize) {
+        let collider = self.bodies.get(handle).and_then(|body| match body.colliders() {
+            [collider] => Some(*collider),
+            _ => None,
+        });
+        let Some(collider) = collider.filter(|_| self.parked_bodies.len() < capacity) else {
+            self.remove_body(handle);
+            return;
+        };
+        self.collider_entities.remove(&collider);
+        if let Some(body) = self.bodies.get_mut(handle) {
+            body.set_linvel(Vector::zeros(), false);
+            body.set_enabled(false);
+        }
+        self.parked_bodies.push((handle, collider));
+    }
+
+    pub fn parked_body_count(&self) -> usize {
+        self.parked_bodies.len()
+    }
+
     pub fn resize_collider(&mut self, handle: ColliderHandle, half: Vec2) {
         if let Some(collider) = self.colliders.get_mut(handle) {
             collider.set_shape(SharedShape::cuboid(half.x, half.y));
         }
     }
 
+    pub fn set_collider_geometry(&mut self, handle: ColliderHandle, geometry: &ColliderGeometry) {
+        if let Some(collider) = self.colliders.get_mut(handle) {
+            let shape = match geometry.kind {
+                ColliderKind::Rect => SharedShape::cuboid(geometry.half.x, geometry.half.y),
+                ColliderKind::Circle => SharedShape::ball(geometry.radius()),
+            };
+            collider.set_shape(shape);
+            collider.set_translation_wrt_parent(Vector::new(geometry.offset.x, geometry.offset.y));
+        }
+    }
+
     pub fn set_body_mass(&mut self, handle: RigidBodyHandle, mass: f32) {
         if let Some(body) = self.bodies.get_mut(handle) {
             body.set_additional_mass(mass, true);
@@ -238,6 +339,7 @@ impl RapierState {
         for handle in to_remove {
             self.remove_body(handle);
         }
+        self.parked_bodies.clear();
     }
 
     pub fn step(&mut self, dt: f32) {
@@ -0,0 +1,461 @@
+use super::PluginBlobStore;
+use bevy_ecs::prelude::{Component, Entity, Resource, World};
+use glam::{Vec2, Vec3, Vec4};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+
+/// Widget the inspector uses for one field of a plugin-defined component.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ComponentFieldKind {
+    F32 { min: f32, max: f32 },
+    Vec2,
+    Vec3,
+    Color,
+    Text,
+    Bool,
+    Enum { options: Vec<String> },
+}
+
+impl ComponentFieldKind {
+    /// Value used when a saved component predates the field or stores it with the wrong shape.
+    pub fn default_value(&self) -> ComponentFieldValue {
+        match self {
+            Self::F32 { min, max } => ComponentFieldValue::F32(0.0_f32.clamp(*min, *max)),
+            Self::Vec2 => ComponentFieldValue::Vec2(Vec2::ZERO),
+            Self::Vec3 => ComponentFieldValue::Vec3(Vec3::ZERO),
+            Self::Color => ComponentFieldValue::Color(Vec4::ONE),
+            Self::Text => ComponentFieldValue::Text(String::new()),
+            Self::Bool => ComponentFieldValue::Bool(false),
+            Self::Enum { .. } => ComponentFieldValue::Enum(0),
+        }
+    }
+
+    /// Decodes a scene value; enums are stored by option label so reordering options is harmless.
+    pub fn value_from_json(&self, value: &Value) -> Option<ComponentFieldValue> {
+        let floats = |len: usize| -> Option<Vec<f32>> {
+            let array = value.as_array().filter(|array| array.len() == len)?;
+            array.iter().map(|v| v.as_f64().map(|f| f as f32)).collect()
+        };
+        Some(match self {
+            Self::F32 { .. } => ComponentFieldValue::F32(value.as_f64()? as f32),
+            Self::Vec2 => ComponentFieldValue::Vec2(Vec2::from_slice(&floats(2)?)),
+            Self::Vec3 => ComponentFieldValue::Vec3(Vec3::from_slice(&floats(3)?)),
+            Self::Color => ComponentFieldValue::Color(Vec4::from_slice(&floats(4)?)),
+            Self::Text => ComponentFieldValue::Text(value.as_str()?.to_string()),
+            Self::Bool => ComponentFieldValue::Bool(value.as_bool()?),
+            Self::Enum { options } => {
+                let label = value.as_str()?;
+                ComponentFieldValue::Enum(options.iter().position(|option| option == label)?)
+            }
+        })
+    }
+
+    pub fn value_to_json(&self, value: &ComponentFieldValue) -> Value {
+        match (self, value) {
+            (Self::Enum { options }, ComponentFieldValue::Enum(index)) => {
+                options.get(*index).map(|label| Value::from(label.as_str())).unwrap_or(Value::Null)
+            }
+            (_, ComponentFieldValue::F32(v)) => Value::from(*v),
+            (_, ComponentFieldValue::Vec2(v)) => Value::from(v.to_array().to_vec()),
+            (_, ComponentFieldValue::Vec3(v)) => Value::from(v.to_array().to_vec()),
+            (_, ComponentFieldValue::Color(v)) => Value::from(v.to_array().to_vec()),
+            (_, ComponentFieldValue::Text(v)) => Value::from(v.as_str()),
+            (_, ComponentFieldValue::Bool(v)) => Value::from(*v),
+            (_, ComponentFieldValue::Enum(index)) => Value::from(*index),
+        }
+    }
+
+    fn accepts(&self, value: &ComponentFieldValue) -> bool {
+        matches!(
+            (self, value),
+            (Self::F32 { .. }, ComponentFieldValue::F32(_))
+                | (Self::Vec2, ComponentFieldValue::Vec2(_))
+                | (Self::Vec3, ComponentFieldValue::Vec3(_))
+                | (Self::Color, ComponentFieldValue::Color(_))
+                | (Self::Text, ComponentFieldValue::Text(_))
+                | (Self::Bool, ComponentFieldValue::Bool(_))
+                | (Self::Enum { .. }, ComponentFieldValue::Enum(_))
+        )
+    }
+
+    fn sanitize(&self, value: ComponentFieldValue) -> ComponentFieldValue {
+        match (self, value) {
+            (Self::F32 { min, max }, ComponentFieldValue::F32(v)) => {
+                ComponentFieldValue::F32(v.clamp(*min, *max))
+            }
+            (Self::Enum { options }, ComponentFieldValue::Enum(index)) => {
+                ComponentFieldValue::Enum(index.min(options.len().saturating_sub(1)))
+            }
+            (_, value) => value,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ComponentFieldValue {
+    F32(f32),
+    Vec2(Vec2),
+    Vec3(Vec3),
+    Color(Vec4),
+    Text(String),
+    Bool(bool),
+    /// Index into the field's option list.
+    Enum(usize),
+}
+
+/// Name and widget of a field, without the accessors.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComponentFieldSchema {
+    pub name: String,
+    pub kind: ComponentFieldKind,
+}
+
+impl ComponentFieldSchema {
+    pub fn new(name: impl Into<String>, kind: ComponentFieldKind) -> Self {
+        Self { name: name.into(), kind }
+    }
+}
+
+type FieldGetter<T> = Box<dyn Fn(&T) -> ComponentFieldValue + Send + Sync>;
+type FieldSetter<T> = Box<dyn Fn(&mut T, ComponentFieldValue) + Send + Sync>;
+
+/// Typed accessor pair for one field of a plugin component `T`.
+pub struct ComponentField<T> {
+    schema: ComponentFieldSchema,
+    get: FieldGetter<T>,
+    set: FieldSetter<T>,
+}
+
+macro_rules! field_constructor {
+    ($fn_name:ident, $ty:ty, $variant:ident, $kind:expr) => {
+        pub fn $fn_name(
+            name: impl Into<String>,
+            get: impl Fn(&T) -> $ty + Send + Sync + 'static,
+            set: impl Fn(&mut T, $ty) + Send + Sync + 'static,
+        ) -> Self {
+            Self::new(
+                name,
+                $kind,
+                move |component| ComponentFieldValue::$variant(get(component)),
+                move |component, value| {
+                    if let ComponentFieldValue::$variant(value) = value {
+                        set(component, value);
+                    }
+                },
+            )
+        }
+    };
+}
+
+impl<T: 'static> ComponentField<T> {
+    fn new(
+        name: impl Into<String>,
+        kind: ComponentFieldKind,
+        get: impl Fn(&T) -> ComponentFieldValue + Send + Sync + 'static,
+        set: impl Fn(&mut T, ComponentFieldValue) + Send + Sync + 'static,
+    ) -> Self {
+        Self { schema: ComponentFieldSchema::new(name, kind), get: Box::new(get), set: Box::new(set) }
+    }
+
+    /// Slider clamped to `range`.
+    pub fn f32(
+        name: impl Into<String>,
+        range: RangeInclusive<f32>,
+        get: impl Fn(&T) -> f32 + Send + Sync + 'static,
+        set: impl Fn(&mut T, f32) + Send + Sync + 'static,
+    ) -> Self {
+        let kind = ComponentFieldKind::F32 { min: *range.start(), max: *range.end() };
+        Self::new(
+            name,
+            kind,
+            move |component| ComponentFieldValue::F32(get(component)),
+            move |component, value| {
+                if let ComponentFieldValue::F32(value) = value {
+                    set(component, value);
+                }
+            },
+        )
+    }
+
+    field_constructor!(vec2, Vec2, Vec2, ComponentFieldKind::Vec2);
+    field_constructor!(vec3, Vec3, Vec3, ComponentFieldKind::Vec3);
+    field_constructor!(color, Vec4, Color, ComponentFieldKind::Color);
+    field_constructor!(text, String, Text, ComponentFieldKind::Text);
+    field_constructor!(bool, bool, Bool, ComponentFieldKind::Bool);
+
+    /// Combo box over `options`; the accessors work with the option index.
+    pub fn enumeration(
+        name: impl Into<String>,
+        options: &[&str],
+        get: impl Fn(&T) -> usize + Send + Sync + 'static,
+        set: impl Fn(&mut T, usize) + Send + Sync + 'static,
+    ) -> Self {
+        let kind = ComponentFieldKind::Enum { options: options.iter().map(|s| s.to_string()).collect() };
+        Self::new(
+            name,
+            kind,
+            move |component| ComponentFieldValue::Enum(get(component)),
+            move |component, value| {
+                if let ComponentFieldValue::Enum(value) = value {
+                    set(component, value);
+                }
+            },
+        )
+    }
+
+    pub fn schema(&self) -> &ComponentFieldSchema {
+        &self.schema
+    }
+}
+
+/// Type-erased access to a registered bevy component, so the registry can hold components of
+/// any type without the type itself crossing into the editor.
+trait TypedComponentAccess: Send + Sync {
+    fn read(&self, world: &World, entity: Entity) -> Option<Vec<ComponentFieldValue>>;
+    fn write(&self, world: &mut World, entity: Entity, field: usize, value: ComponentFieldValue) -> bool;
+    /// Inserts a default component with `values` applied, replacing any existing one.
+    fn insert(&self, world: &mut World, entity: Entity, values: &[Option<ComponentFieldValue>]);
+}
+
+struct TypedAccess<T> {
+    fields: Vec<ComponentField<T>>,
+}
+
+impl<T: Component + Default> TypedComponentAccess for TypedAccess<T> {
+    fn read(&self, world: &World, entity: Entity) -> Option<Vec<ComponentFieldValue>> {
+        let component = world.get::<T>(entity)?;
+        Some(self.fields.iter().map(|field| (field.get)(component)).collect())
+    }
+
+    fn write(&self, world: &mut World, entity: Entity, field: usize, value: ComponentFieldValue) -> bool {
+        let (Some(field), Some(mut component)) = (self.fields.get(field), world.get_mut::<T>(entity)) else {
+            return false;
+        };
+        (field.set)(&mut component, value);
+        true
+    }
+
+    fn insert(&self, world: &mut World, entity: Entity, values: &[Option<ComponentFieldValue>]) {
+        let mut component = T::default();
+        for (field, value) in self.fields.iter().zip(values) {
+            if let Some(value) = value {
+                (field.set)(&mut component, value.clone());
+            }
+        }
+        if let Ok(mut entity) = world.get_entity_mut(entity) {
+            entity.insert(component);
+        }
+    }
+}
+
+enum ComponentStorage {
+    /// A real component on the entity, edited through the plugin's closures.
+    Typed(Box<dyn TypedComponentAccess>),
+    /// A JSON object kept in the [`PluginBlobStore`] under the component key; used by dynamic
+    /// plugins whose types can't be shared with the host.
+    Blob,
+}
+
+struct RegisteredComponent {
+    fields: Vec<ComponentFieldSchema>,
+    storage: ComponentStorage,
+}
+
+/// Inspector snapshot of one plugin component on an entity.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PluginComponentView {
+    pub key: String,
+    pub fields: Vec<PluginComponentFieldView>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PluginComponentFieldView {
+    pub name: String,
+    pub kind: ComponentFieldKind,
+    pub value: ComponentFieldValue,
+}
+
+/// Plugin-defined components the inspector and scene serializer know how to handle, keyed by a
+/// plugin-qualified name such as `"spinner.spin"`. Saved components use the same key in
+/// `SceneEntity::plugin_data`, so components whose plugin isn't registered stay opaque blobs.
+#[derive(Resource, Default)]
+pub struct PluginComponentRegistry {
+    components: BTreeMap<String, RegisteredComponent>,
+}
+
+impl PluginComponentRegistry {
+    pub fn contains(&self, key: &str) -> bool {
+        self.components.contains_key(key)
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.components.keys().map(String::as_str)
+    }
+
+    pub fn fields(&self, key: &str) -> Option<&[ComponentFieldSchema]> {
+        self.components.get(key).map(|component| component.fields.as_slice())
+    }
+
+    pub fn len(&self) -> usize {
+        self.components.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+
+    pub(crate) fn register_typed<T: Component + Default>(
+        &mut self,
+        key: &str,
+        fields: Vec<ComponentField<T>>,
+    ) {
+        let schema = fields.iter().map(|field| field.schema.clone()).collect();
+        let storage = ComponentStorage::Typed(Box::new(TypedAccess { fields }));
+        self.components.insert(key.to_string(), RegisteredComponent { fields: schema, storage });
+    }
+
+    pub(crate) fn register_blob(&mut self, key: &str, fields: Vec<ComponentFieldSchema>) {
+        self.components
+            .insert(key.to_string(), RegisteredComponent { fields, storage: ComponentStorage::Blob });
+    }
+
+    /// Drops every component registered under `owner`'s key prefix (`"<owner>."`) and returns how
+    /// many there were. Saved blobs stay in the [`PluginBlobStore`], so a later registration picks
+    /// them up again.
+    pub fn unregister_owner(&mut self, owner: &str) -> usize {
+        let prefix = format!("{owner}.");
+        let before = self.components.len();
+        self.components.retain(|key, _| !key.starts_with(&prefix));
+        before - self.components.len()
+    }
+
+    pub(crate) fn views(&self, world: &World, entity: Entity) -> Vec<PluginComponentView> {
+        self.components
+            .iter()
+            .filter_map(|(key, component)| {
+                let values = match &component.storage {
+                    ComponentStorage::Typed(access) => access.read(world, entity)?,
+                    ComponentStorage::Blob => {
+                        let bytes = world.resource::<PluginBlobStore>().get(entity, key)?;
+                        decode_fields(&component.fields, bytes)
+                            .into_iter()
+                            .zip(&component.fields)
+                            .map(|(value, field)| value.unwrap_or_else(|| field.kind.default_value()))
+                            .collect()
+                    }
+                };
+                let fields = component
+                    .fields
+                    .iter()
+                    .zip(values)
+                    .map(|(field, value)| PluginComponentFieldView {
+                        name: field.name.clone(),
+                        kind: field.kind.clone(),
+                        value,
+                    })
+                    .collect();
+                Some(PluginComponentView { key: key.clone(), fields })
+            })
+            .collect()
+    }
+
+    pub(crate) fn set_field(
+        &self,
+        world: &mut World,
+        entity: Entity,
+        key: &str,
+        field: &str,
+        value: ComponentFieldValue,
+    ) -> bool {
+        let Some(component) = self.components.get(key) else {
+            return false;
+        };
+        let Some(index) = component.fields.iter().position(|f| f.name == field) else {
+            return false;
+        };
+        let kind = &component.fields[index].kind;
+        if !kind.accepts(&value) {
+            return false;
+        }
+        let value = kind.sanitize(value);
+        match &component.storage {
+            ComponentStorage::Typed(access) => access.write(world, entity, index, value),
+            ComponentStorage::Blob => {
+                let mut blobs = world.resource_mut::<PluginBlobStore>();
+                let Some(bytes) = blobs.get(entity, key) else {
+                    return false;
+                };
+                let mut object = match serde_json::from_slice::<Value>(bytes) {
+                    Ok(Value::Object(object)) => object,
+                    _ => Map::new(),
+                };
+                object.insert(field.to_string(), kind.value_to_json(&value));
+                blobs.insert(entity, key, Value::Object(object).to_string().into_bytes());
+                true
+            }
+        }
+    }
+
+    /// Serialized `plugin_data` entries for the typed components on `entity`. Blob-backed
+    /// components already live in the [`PluginBlobStore`] and are saved from there.
+    pub(crate) fn encode_typed(&self, world: &World, entity: Entity) -> BTreeMap<String, Vec<u8>> {
+        let mut out = BTreeMap::new();
+        for (key, component) in &self.components {
+            let ComponentStorage::Typed(access) = &component.storage else {
+                continue;
+            };
+            let Some(values) = access.read(world, entity) else {
+                continue;
+            };
+            let object: Map<String, Value> = component
+                .fields
+                .iter()
+                .zip(&values)
+                .map(|(field, value)| (field.name.clone(), field.kind.value_to_json(value)))
+                .collect();
+            out.insert(key.clone(), Value::Object(object).to_string().into_bytes());
+        }
+        out
+    }
+
+    /// Turns saved blobs for typed components on `entity` into live components. The blob is
+    /// kept when it can't be parsed so a broken save never loses data.
+    pub(crate) fn materialize(&self, world: &mut World, entity: Entity) {
+        for (key, component) in &self.components {
+            let ComponentStorage::Typed(access) = &component.storage else {
+                continue;
+            };
+            let Some(bytes) = world.resource::<PluginBlobStore>().get(entity, key) else {
+                continue;
+            };
+            if serde_json::from_slice::<Map<String, Value>>(bytes).is_err() {
+                continue;
+            }
+            let values = decode_fields(&component.fields, bytes);
+            access.insert(world, entity, &values);
+            world.resource_mut::<PluginBlobStore>().remove(entity, key);
+        }
+    }
+
+    pub(crate) fn materialize_all(&self, world: &mut World) {
+        let entities: Vec<Entity> = world.resource::<PluginBlobStore>().entities().collect();
+        for entity in entities {
+            self.materialize(world, entity);
+        }
+    }
+}
+
+fn decode_fields(fields: &[ComponentFieldSchema], bytes: &[u8]) -> Vec<Option<ComponentFieldValue>> {
+    let object = match serde_json::from_slice::<Value>(bytes) {
+        Ok(Value::Object(object)) => object,
+        _ => Map::new(),
+    };
+    fields
+        .iter()
+        .map(|field| {
+            let value = object.get(&field.name)?;
+            field.kind.value_from_json(value).map(|value| field.kind.sanitize(value))
+        })
+        .collect()
+}
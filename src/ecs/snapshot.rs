@@ -0,0 +1,333 @@
+use super::*;
+use crate::nav::NavGrid;
+use crate::scene::SceneEntityId;
+use bevy_ecs::prelude::{Entity, World};
+use bevy_ecs::world::{EntityRef, EntityWorldMut};
+use blake3::Hasher as Blake3Hasher;
+use rand::rngs::StdRng;
+use rand::Rng;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+macro_rules! snapshot_components {
+    ($($field:ident: $ty:ty),* $(,)?) => {
+        /// Clones of the simulation components one entity carried when the snapshot was taken.
+        #[derive(Clone, Default)]
+        struct EntityComponents {
+            $($field: Option<$ty>,)*
+        }
+
+        impl EntityComponents {
+            fn capture(entity: EntityRef<'_>) -> Self {
+                Self { $($field: entity.get::<$ty>().cloned(),)* }
+            }
+
+            /// Takes the first captured component off so the entity leaves its table; see
+            /// [`WorldSnapshot::apply`].
+            fn detach(&self, entity: &mut EntityWorldMut<'_>) {
+                $(
+                    if self.$field.is_some() {
+                        entity.remove::<$ty>();
+                        return;
+                    }
+                )*
+            }
+
+            fn apply(&self, entity: &mut EntityWorldMut<'_>) {
+                $(
+                    match &self.$field {
+                        Some(component) => {
+                            entity.insert(component.clone());
+                        }
+                        None => {
+                            entity.remove::<$ty>();
+                        }
+                    }
+                )*
+            }
+
+            fn presence_mask(&self) -> u32 {
+                let mut mask = 0u32;
+                let mut bit = 0u32;
+                $(
+                    if self.$field.is_some() {
+                        mask |= 1 << bit;
+                    }
+                    bit += 1;
+                )*
+                let _ = bit;
+                mask
+            }
+        }
+    };
+}
+
+snapshot_components! {
+    transform: Transform,
+    world_transform: WorldTransform,
+    parent: Parent,
+    children: Children,
+    scene_tag: SceneEntityTag,
+    spin: Spin,
+    sprite: Sprite,
+    sprite_frame: SpriteFrameState,
+    z_order: ZOrder,
+    tint: Tint,
+    velocity: Velocity,
+    aabb: Aabb,
+    mass: Mass,
+    force: Force,
+    force_field: ForceField,
+    attractor: ParticleAttractor,
+    trail: ParticleTrail,
+    emitter: ParticleEmitter,
+    burst_emitter: BurstEmitter,
+    particle: Particle,
+    particle_visual: ParticleVisual,
+    particle_collision: ParticleCollision,
+    rapier_body: RapierBody,
+    rapier_collider: RapierCollider,
+    orbit: OrbitController,
+    path_agent: PathAgent,
+    collider_shape: ColliderShape,
+    lifetime: Lifetime,
+    despawn_out_of_bounds: DespawnOutOfBounds,
+    pooled_sprite: PooledSprite,
+    tags: Tags,
+}
+
+impl EntityComponents {
+    fn remap_entities(&mut self, map: &HashMap<Entity, Entity>) {
+        let remap = |entity: &mut Entity| {
+            if let Some(mapped) = map.get(entity) {
+                *entity = *mapped;
+            }
+        };
+        if let Some(parent) = self.parent.as_mut() {
+            remap(&mut parent.0);
+        }
+        if let Some(children) = self.children.as_mut() {
+            children.0.iter_mut().for_each(remap);
+        }
+        if let Some(collision) = self.particle_collision.as_mut() {
+            remap(&mut collision.emitter);
+        }
+    }
+
+    /// Bytes of the simulated values, independent of entity ids so respawned entities hash the same.
+    fn hash_record(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(128);
+        let mut floats = |values: &[f32]| {
+            for value in values {
+                out.extend_from_slice(&value.to_bits().to_le_bytes());
+            }
+        };
+        if let Some(t) = self.transform {
+            floats(&[t.translation.x, t.translation.y, t.rotation, t.scale.x, t.scale.y]);
+        }
+        if let Some(world) = self.world_transform {
+            floats(&world.0.to_cols_array());
+        }
+        if let Some(spin) = self.spin {
+            floats(&[spin.speed]);
+        }
+        if let Some(tint) = self.tint {
+            floats(&tint.0.to_array());
+        }
+        if let Some(velocity) = self.velocity {
+            floats(&velocity.0.to_array());
+        }
+        if let Some(aabb) = self.aabb {
+            floats(&aabb.half.to_array());
+        }
+        if let Some(shape) = self.collider_shape {
+            floats(&[shape.offset.x, shape.offset.y, shape.kind as u8 as f32]);
+        }
+        if let Some(mass) = self.mass {
+            floats(&[mass.0]);
+        }
+        if let Some(force) = self.force {
+            floats(&force.0.to_array());
+        }
+        if let Some(emitter) = self.emitter.as_ref() {
+            floats(&[emitter.accumulator, emitter.pending_burst as f32]);
+        }
+        if let Some(burst) = self.burst_emitter.as_ref() {
+            for (count, impulse_speed) in burst.requests() {
+                floats(&[count as f32, impulse_speed]);
+            }
+        }
+        if let Some(particle) = self.particle {
+            floats(&[particle.lifetime, particle.max_lifetime]);
+        }
+        if let Some(stuck_at) = self.particle_collision.and_then(|collision| collision.stuck_at) {
+            floats(&stuck_at.to_array());
+        }
+        if let Some(orbit) = self.orbit {
+            floats(&[orbit.center.x, orbit.center.y, orbit.angular_speed]);
+        }
+        if let Some(agent) = self.path_agent.as_ref() {
+            floats(&agent.goal.map(|goal| goal.to_array()).unwrap_or([f32::NAN; 2]));
+            floats(&[agent.next_waypoint as f32]);
+        }
+        out.extend_from_slice(&self.presence_mask().to_le_bytes());
+        if let Some(tag) = self.scene_tag.as_ref() {
+            out.extend_from_slice(tag.id.as_str().as_bytes());
+        }
+        out
+    }
+}
+
+/// Copy of the simulation state of an [`EcsWorld`]: the gameplay components of every entity plus
+/// the physics world, navigation grid and particle RNG, so [`EcsWorld::restore`] followed by the
+/// same steps and inputs reproduces the same state. Render-side state (sprite animation playback,
+/// meshes, scripts) is not captured.
+#[derive(Clone)]
+pub struct WorldSnapshot {
+    entities: Vec<(Entity, EntityComponents)>,
+    rapier: RapierState,
+    nav_grid: NavGrid,
+    particle_rng: StdRng,
+    emitter_rngs: HashMap<SceneEntityId, StdRng>,
+    particle_state: ParticleState,
+    next_scene_id: u64,
+}
+
+impl WorldSnapshot {
+    pub(crate) fn capture(world: &World) -> Self {
+        let entities =
+            world.iter_entities().map(|entity| (entity.id(), EntityComponents::capture(entity))).collect();
+        Self {
+            entities,
+            rapier: world.resource::<RapierState>().clone(),
+            nav_grid: world.resource::<NavGrid>().clone(),
+            particle_rng: world.resource::<ParticleSpawnScratch>().rng.clone(),
+            emitter_rngs: world.resource::<ParticleSpawnScratch>().emitter_rngs.clone(),
+            particle_state: *world.resource::<ParticleState>(),
+            next_scene_id: world.resource::<SceneIdAllocator>().next_value(),
+        }
+    }
+
+    /// Despawns entities created since the capture, respawns the ones that were despawned (under
+    /// new ids, with references to them remapped) and overwrites the tracked components of the rest.
+    /// Returns the old-to-new ids of respawned entities.
+    pub(crate) fn apply(&self, world: &mut World) -> HashMap<Entity, Entity> {
+        let captured: HashSet<Entity> = self.entities.iter().map(|(entity, _)| *entity).collect();
+        let spawned_since: Vec<Entity> = world
+            .iter_entities()
+            .map(|entity| entity.id())
+            .filter(|entity| !captured.contains(entity))
+            .collect();
+        for entity in spawned_since {
+            world.despawn(entity);
+        }
+        let mut respawned = HashMap::new();
+        for (entity, _) in &self.entities {
+            if world.get_entity(*entity).is_err() {
+                respawned.insert(*entity, world.spawn_empty().id());
+            }
+        }
+        // Queries walk tables in row order, and float sums (e.g. contact impulses) depend on that
+        // order. Detaching every entity first and re-attaching them in capture order rebuilds the
+        // rows exactly as they were, so the restored world steps bit-for-bit like the original.
+        for (entity, components) in &self.entities {
+            if let Ok(mut entity) = world.get_entity_mut(*entity) {
+                components.detach(&mut entity);
+            }
+        }
+        for (entity, components) in &self.entities {
+            let mut entity = world.entity_mut(respawned.get(entity).copied().unwrap_or(*entity));
+            if respawned.is_empty() {
+                components.apply(&mut entity);
+            } else {
+                let mut components = components.clone();
+                components.remap_entities(&respawned);
+                components.apply(&mut entity);
+            }
+        }
+
+        let mut rapier = self.rapier.clone();
+        rapier.remap_entities(&respawned);
+        world.insert_resource(rapier);
+        let mut nav_grid = self.nav_grid.clone();
+        nav_grid.remap_entities(&respawned);
+        world.insert_resource(nav_grid);
+        let mut spawn_scratch = world.resource_mut::<ParticleSpawnScratch>();
+        spawn_scratch.rng = self.particle_rng.clone();
+        spawn_scratch.emitter_rngs = self.emitter_rngs.clone();
+        world.insert_resource(self.particle_state);
+        world.insert_resource(SceneIdAllocator::starting_at(self.next_scene_id));
+        respawned
+    }
+
+    pub fn entity_count(&self) -> usize {
+        self.entities.len()
+    }
+
+    /// Hash of the captured simulation values. Entity ids are left out, so two snapshots hash equal
+    /// whenever they hold the same entities in the same state, however they were allocated.
+    pub fn content_hash(&self) -> u128 {
+        let mut records: Vec<Vec<u8>> =
+            self.entities.iter().map(|(_, components)| components.hash_record()).collect();
+        records.sort_unstable();
+        let mut hasher = Blake3Hasher::new();
+        for record in &records {
+            hasher.update(&(record.len() as u32).to_le_bytes());
+            hasher.update(record);
+        }
+        // Drawing from a copy fingerprints the RNG stream without advancing the real one.
+        hasher.update(&self.particle_rng.clone().gen::<u64>().to_le_bytes());
+        let emitter_rngs: BTreeMap<&str, &StdRng> =
+            self.emitter_rngs.iter().map(|(id, rng)| (id.as_str(), rng)).collect();
+        for (id, rng) in emitter_rngs {
+            hasher.update(id.as_bytes());
+            hasher.update(&rng.clone().gen::<u64>().to_le_bytes());
+        }
+        hasher.update(&self.particle_state.active_particles.to_le_bytes());
+        hasher.update(&self.next_scene_id.to_le_bytes());
+        let mut out = [0u8; 16];
+        out.copy_from_slice(&hasher.finalize().as_bytes()[..16]);
+        u128::from_le_bytes(out)
+    }
+}
+
+/// Inputs applied to the simulation, keyed by the fixed-step frame they were applied on, so a
+/// rollback can restore a [`WorldSnapshot`] and feed the same inputs back through
+/// [`EcsWorld::replay`].
+#[derive(Clone, Debug)]
+pub struct FrameInputLog<I> {
+    frames: BTreeMap<u64, Vec<I>>,
+}
+
+impl<I> Default for FrameInputLog<I> {
+    fn default() -> Self {
+        Self { frames: BTreeMap::new() }
+    }
+}
+
+impl<I> FrameInputLog<I> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&mut self, frame: u64, input: I) {
+        self.frames.entry(frame).or_default().push(input);
+    }
+
+    /// Inputs recorded for `frame`, in the order they were recorded.
+    pub fn inputs(&self, frame: u64) -> &[I] {
+        self.frames.get(&frame).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Drops the inputs of `frame` and every later frame, e.g. when corrected remote inputs arrive.
+    pub fn discard_from(&mut self, frame: u64) {
+        self.frames.split_off(&frame);
+    }
+
+    pub fn latest_frame(&self) -> Option<u64> {
+        self.frames.keys().next_back().copied()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+}
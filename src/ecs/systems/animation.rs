@@ -3,9 +3,9 @@ use crate::assets::skeletal::{JointQuatTrack, JointVec3Track, SkeletalClip};
 use crate::assets::{ClipInterpolation, ClipKeyframe};
 use crate::ecs::profiler::SystemProfiler;
 use crate::ecs::{
-    BoneTransforms, ClipInstance, ClipSample, FastSpriteAnimator, PropertyTrackPlayer, SkeletonInstance,
-    Sprite, SpriteAnimation, SpriteAnimationLoopMode, SpriteFrameState, Tint, Transform,
-    TransformTrackPlayer,
+    BoneTransforms, ClipInstance, ClipSample, FastSpriteAnimator, PropertyTrackPlayer, SkeletalBlendLayer,
+    SkeletonInstance, Sprite, SpriteAnimation, SpriteAnimationLoopMode, SpriteFrameState, Tint, TintCurve,
+    Transform, TransformTrackPlayer,
 };
 #[cfg(feature = "sprite_anim_soa")]
 use crate::ecs::{SpriteAnimationFrame, SpriteFrameHotData};
@@ -13,7 +13,7 @@ use crate::events::{EventBus, GameEvent};
 use bevy_ecs::prelude::{
     Added, Changed, Commands, Entity, Mut, Or, Query, Res, ResMut, Resource, With, Without,
 };
-use glam::{Mat4, Quat, Vec3};
+use glam::{Mat4, Quat, Vec3, Vec4};
 use std::cell::Cell;
 #[cfg(feature = "sprite_anim_soa")]
 use std::collections::HashMap;
@@ -115,6 +115,7 @@ pub struct SpriteAnimatorSoa {
     #[cfg(feature = "sprite_anim_fixed_point")]
     frame_offsets_fp: Vec<Arc<[u32]>>,
     group: Vec<Option<Arc<str>>>,
+    usage_id: Vec<Option<SpriteTimelineUsageId>>,
     flags: Vec<SpriteAnimatorFlags>,
     entity_to_slot: HashMap<Entity, u32>,
 }
@@ -216,6 +217,7 @@ impl SpriteAnimatorSoa {
         self.frame_durations.push(Arc::clone(&animation.frame_durations));
         self.frame_offsets.push(Arc::clone(&animation.frame_offsets));
         self.group.push(animation.group.as_ref().map(|g| Arc::<str>::from(g.as_str())));
+        self.usage_id.push(animation.usage_id);
         let mut flags = SpriteAnimatorFlags::from_animation(animation);
         #[cfg(feature = "sprite_anim_simd")]
         flags.set_const_dt(const_dt_duration.is_some());
@@ -264,6 +266,7 @@ impl SpriteAnimatorSoa {
         self.frame_durations[slot] = Arc::clone(&animation.frame_durations);
         self.frame_offsets[slot] = Arc::clone(&animation.frame_offsets);
         self.group[slot] = animation.group.as_ref().map(|g| Arc::<str>::from(g.as_str()));
+        self.usage_id[slot] = animation.usage_id;
         let mut flags = SpriteAnimatorFlags::from_animation(animation);
         #[cfg(feature = "sprite_anim_simd")]
         flags.set_const_dt(const_dt_duration.is_some());
@@ -313,6 +316,7 @@ impl SpriteAnimatorSoa {
             self.frame_durations.swap(slot, last);
             self.frame_offsets.swap(slot, last);
             self.group.swap(slot, last);
+            self.usage_id.swap(slot, last);
             if let Some(entry) = self.entity_to_slot.get_mut(&self.entities[slot]) {
                 *entry = slot as u32;
             }
@@ -356,6 +360,7 @@ impl SpriteAnimatorSoa {
         self.frame_durations.pop();
         self.frame_offsets.pop();
         self.group.pop();
+        self.usage_id.pop();
     }
 }
 
@@ -901,6 +906,145 @@ impl Drop for SpriteAnimPerfFrame<'_> {
     }
 }
 
+/// Interned `(atlas, timeline)` slot in [`SpriteTimelineUsage`].
+pub type SpriteTimelineUsageId = u32;
+
+/// Session counters for one `(atlas, timeline)` pair.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SpriteTimelineUsageEntry {
+    pub atlas: Arc<str>,
+    pub timeline: Arc<str>,
+    /// Times the timeline was assigned or restarted.
+    pub plays: u64,
+    /// Completed cycles: wraps for looping timelines, full round trips for ping-pong ones.
+    pub loops: u64,
+    /// Fire count per frame-event name, in the order the names first fired.
+    pub events: Vec<(Arc<str>, u64)>,
+}
+
+impl SpriteTimelineUsageEntry {
+    pub fn event_count(&self, name: &str) -> u64 {
+        self.events.iter().find(|(event, _)| event.as_ref() == name).map_or(0, |(_, count)| *count)
+    }
+
+    pub fn total_events(&self) -> u64 {
+        self.events.iter().map(|(_, count)| *count).sum()
+    }
+}
+
+/// Which sprite timelines actually ran this session and how often their frame events fired.
+///
+/// Keys are interned once when a timeline is assigned and the id is stored on the
+/// [`SpriteAnimation`], so the animation drivers only bump counters by index and never hash or
+/// allocate strings per frame.
+#[derive(Resource, Default)]
+pub struct SpriteTimelineUsage {
+    slots: HashMap<(Arc<str>, Arc<str>), SpriteTimelineUsageId>,
+    entries: Vec<SpriteTimelineUsageEntry>,
+    revision: u64,
+}
+
+impl SpriteTimelineUsage {
+    pub fn intern(&mut self, atlas: &Arc<str>, timeline: &Arc<str>) -> SpriteTimelineUsageId {
+        let key = (Arc::clone(atlas), Arc::clone(timeline));
+        if let Some(id) = self.slots.get(&key) {
+            return *id;
+        }
+        let id = self.entries.len() as SpriteTimelineUsageId;
+        self.entries.push(SpriteTimelineUsageEntry {
+            atlas: Arc::clone(atlas),
+            timeline: Arc::clone(timeline),
+            plays: 0,
+            loops: 0,
+            events: Vec::new(),
+        });
+        self.slots.insert(key, id);
+        id
+    }
+
+    pub fn record_play(&mut self, id: SpriteTimelineUsageId) {
+        if let Some(entry) = self.entries.get_mut(id as usize) {
+            entry.plays += 1;
+            self.revision = self.revision.wrapping_add(1);
+        }
+    }
+
+    fn record_loops(&mut self, id: SpriteTimelineUsageId, loops: u32) {
+        if let Some(entry) = self.entries.get_mut(id as usize) {
+            entry.loops += loops as u64;
+            self.revision = self.revision.wrapping_add(1);
+        }
+    }
+
+    fn record_event(&mut self, id: SpriteTimelineUsageId, name: &Arc<str>) {
+        let Some(entry) = self.entries.get_mut(id as usize) else {
+            return;
+        };
+        // Frame events are shared `Arc`s from the atlas, so the pointer check almost always hits.
+        match entry.events.iter_mut().find(|(event, _)| Arc::ptr_eq(event, name) || event == name) {
+            Some((_, count)) => *count += 1,
+            None => entry.events.push((Arc::clone(name), 1)),
+        }
+        self.revision = self.revision.wrapping_add(1);
+    }
+
+    /// Entries that played, looped or fired at least once, in interning order.
+    pub fn entries(&self) -> impl Iterator<Item = &SpriteTimelineUsageEntry> {
+        self.entries.iter().filter(|entry| entry.plays > 0 || entry.loops > 0 || !entry.events.is_empty())
+    }
+
+    pub fn get(&self, atlas: &str, timeline: &str) -> Option<&SpriteTimelineUsageEntry> {
+        self.entries.iter().find(|entry| entry.atlas.as_ref() == atlas && entry.timeline.as_ref() == timeline)
+    }
+
+    /// Bumped on every recorded play, loop or event; lets consumers skip unchanged frames.
+    pub fn revision(&self) -> u64 {
+        self.revision
+    }
+
+    /// Zeroes every counter. Interned ids stay valid because live animations still hold them.
+    pub fn clear(&mut self) {
+        for entry in &mut self.entries {
+            entry.plays = 0;
+            entry.loops = 0;
+            entry.events.clear();
+        }
+        self.revision = self.revision.wrapping_add(1);
+    }
+}
+
+thread_local! {
+    static SPRITE_TIMELINE_USAGE_PTR: Cell<usize> = const { Cell::new(0) };
+}
+
+fn usage_set_sink(usage: Option<*mut SpriteTimelineUsage>) {
+    SPRITE_TIMELINE_USAGE_PTR.with(|cell| cell.set(usage.map_or(0, |ptr| ptr as usize)));
+}
+
+fn usage_with<F: FnOnce(&mut SpriteTimelineUsage)>(f: F) {
+    SPRITE_TIMELINE_USAGE_PTR.with(|cell| {
+        let ptr = cell.get();
+        if ptr == 0 {
+            return;
+        }
+        unsafe {
+            f(&mut *(ptr as *mut SpriteTimelineUsage));
+        }
+    });
+}
+
+fn usage_record_loops(id: Option<SpriteTimelineUsageId>, loops: u32) {
+    if let Some(id) = id.filter(|_| loops > 0) {
+        usage_with(|usage| usage.record_loops(id, loops));
+    }
+}
+
+fn usage_record_event(id: Option<SpriteTimelineUsageId>, name: &Arc<str>) {
+    if let Some(id) = id {
+        usage_with(|usage| usage.record_event(id, name));
+    }
+}
+
 thread_local! {
     static SPRITE_PERF_SAMPLE_PTR: Cell<usize> = const { Cell::new(0) };
     static SPRITE_PERF_STEP_KIND: Cell<u8> = const { Cell::new(SpriteAnimStepKind::Variable.as_u8()) };
@@ -1229,6 +1373,7 @@ pub fn sys_drive_sprite_animations(
     mut events: ResMut<EventBus>,
     mut frame_updates: ResMut<SpriteFrameApplyQueue>,
     mut perf: ResMut<SpriteAnimPerfTelemetry>,
+    mut timeline_usage: ResMut<SpriteTimelineUsage>,
     #[cfg(feature = "sprite_anim_soa")] mut runtime: ResMut<SpriteAnimatorSoa>,
     #[cfg(feature = "sprite_anim_soa")] mut fast_sprite_states: Query<
         &mut SpriteFrameState,
@@ -1239,7 +1384,7 @@ pub fn sys_drive_sprite_animations(
         With<FastSpriteAnimator>,
     >,
     mut general_animations: Query<
-        (Entity, &mut SpriteAnimation, &mut SpriteFrameState),
+        (Entity, &mut SpriteAnimation, &mut SpriteFrameState, &Sprite),
         Without<FastSpriteAnimator>,
     >,
 ) {
@@ -1261,6 +1406,7 @@ pub fn sys_drive_sprite_animations(
         sample_ref as *mut SpriteAnimPerfSample
     };
     perf_set_sample(Some(sample_ptr));
+    usage_set_sink(Some(&mut *timeline_usage as *mut SpriteTimelineUsage));
     let step_kind = match plan {
         AnimationDelta::Fixed { .. } => SpriteAnimStepKind::Fixed,
         _ => SpriteAnimStepKind::Variable,
@@ -1338,6 +1484,7 @@ pub fn sys_drive_sprite_animations(
         }
     }
     perf_set_sample(None);
+    usage_set_sink(None);
 }
 
 pub fn sys_init_sprite_frame_state(
@@ -1553,10 +1700,11 @@ mod tests {
         let mut state: SystemState<Query<(Entity, &mut SkeletonInstance, Option<Mut<BoneTransforms>>)>> =
             SystemState::new(&mut world);
         let animation_time = AnimationTime::default();
+        let mut events = EventBus::default();
 
         {
             let mut query = state.get_mut(&mut world);
-            drive_skeletal_clips(0.1, false, &animation_time, &mut query);
+            drive_skeletal_clips(0.1, false, &animation_time, &mut events, &mut query);
         }
         state.apply(&mut world);
 
@@ -1578,7 +1726,7 @@ mod tests {
         }
         {
             let mut query = state.get_mut(&mut world);
-            drive_skeletal_clips(0.0, false, &animation_time, &mut query);
+            drive_skeletal_clips(0.0, false, &animation_time, &mut events, &mut query);
         }
         state.apply(&mut world);
 
@@ -1604,6 +1752,7 @@ mod tests {
 
         let mut world = World::new();
         world.insert_resource(SpriteAnimPerfTelemetry::new(240));
+        world.insert_resource(SpriteTimelineUsage::default());
         world.insert_resource(SystemProfiler::new());
         world.insert_resource(AnimationPlan { delta: AnimationDelta::Single(0.05) });
         world.insert_resource(AnimationTime::default());
@@ -1649,9 +1798,13 @@ mod tests {
             ResMut<EventBus>,
             ResMut<SpriteFrameApplyQueue>,
             ResMut<SpriteAnimPerfTelemetry>,
+            ResMut<SpriteTimelineUsage>,
             ResMut<SpriteAnimatorSoa>,
             Query<&mut SpriteFrameState, With<FastSpriteAnimator>>,
-            Query<(Entity, &mut SpriteAnimation, &mut SpriteFrameState), Without<FastSpriteAnimator>>,
+            Query<
+                (Entity, &mut SpriteAnimation, &mut SpriteFrameState, &Sprite),
+                Without<FastSpriteAnimator>,
+            >,
         )>::new(&mut world);
         #[cfg(not(feature = "sprite_anim_soa"))]
         let mut system_state = SystemState::<(
@@ -1661,16 +1814,30 @@ mod tests {
             ResMut<EventBus>,
             ResMut<SpriteFrameApplyQueue>,
             ResMut<SpriteAnimPerfTelemetry>,
+            ResMut<SpriteTimelineUsage>,
             Query<
                 (Entity, &mut SpriteAnimation, &mut SpriteFrameState, &mut Sprite),
                 With<FastSpriteAnimator>,
             >,
-            Query<(Entity, &mut SpriteAnimation, &mut SpriteFrameState), Without<FastSpriteAnimator>>,
+            Query<
+                (Entity, &mut SpriteAnimation, &mut SpriteFrameState, &Sprite),
+                Without<FastSpriteAnimator>,
+            >,
         )>::new(&mut world);
         #[cfg(feature = "sprite_anim_soa")]
         {
-            let (profiler, plan, time, events, frame_updates, perf, runtime, fast_states, general_animations) =
-                system_state.get_mut(&mut world);
+            let (
+                profiler,
+                plan,
+                time,
+                events,
+                frame_updates,
+                perf,
+                usage,
+                runtime,
+                fast_states,
+                general_animations,
+            ) = system_state.get_mut(&mut world);
             sys_drive_sprite_animations(
                 profiler,
                 plan,
@@ -1678,6 +1845,7 @@ mod tests {
                 events,
                 frame_updates,
                 perf,
+                usage,
                 runtime,
                 fast_states,
                 general_animations,
@@ -1685,8 +1853,17 @@ mod tests {
         }
         #[cfg(not(feature = "sprite_anim_soa"))]
         {
-            let (profiler, plan, time, events, frame_updates, perf, fast_animations, general_animations) =
-                system_state.get_mut(&mut world);
+            let (
+                profiler,
+                plan,
+                time,
+                events,
+                frame_updates,
+                perf,
+                usage,
+                fast_animations,
+                general_animations,
+            ) = system_state.get_mut(&mut world);
             sys_drive_sprite_animations(
                 profiler,
                 plan,
@@ -1694,6 +1871,7 @@ mod tests {
                 events,
                 frame_updates,
                 perf,
+                usage,
                 fast_animations,
                 general_animations,
             );
@@ -2321,6 +2499,7 @@ mod tests {
 
         let mut world = World::new();
         world.insert_resource(SpriteAnimPerfTelemetry::new(240));
+        world.insert_resource(SpriteTimelineUsage::default());
         world.insert_resource(SystemProfiler::new());
         world.insert_resource(AnimationPlan { delta: AnimationDelta::Fixed { step: 0.1, steps: 3 } });
         world.insert_resource(AnimationTime::default());
@@ -2377,9 +2556,13 @@ mod tests {
             ResMut<EventBus>,
             ResMut<SpriteFrameApplyQueue>,
             ResMut<SpriteAnimPerfTelemetry>,
+            ResMut<SpriteTimelineUsage>,
             ResMut<SpriteAnimatorSoa>,
             Query<&mut SpriteFrameState, With<FastSpriteAnimator>>,
-            Query<(Entity, &mut SpriteAnimation, &mut SpriteFrameState), Without<FastSpriteAnimator>>,
+            Query<
+                (Entity, &mut SpriteAnimation, &mut SpriteFrameState, &Sprite),
+                Without<FastSpriteAnimator>,
+            >,
         )>::new(&mut world);
         #[cfg(not(feature = "sprite_anim_soa"))]
         let mut system_state = SystemState::<(
@@ -2389,18 +2572,32 @@ mod tests {
             ResMut<EventBus>,
             ResMut<SpriteFrameApplyQueue>,
             ResMut<SpriteAnimPerfTelemetry>,
+            ResMut<SpriteTimelineUsage>,
             Query<
                 (Entity, &mut SpriteAnimation, &mut SpriteFrameState, &mut Sprite),
                 With<FastSpriteAnimator>,
             >,
-            Query<(Entity, &mut SpriteAnimation, &mut SpriteFrameState), Without<FastSpriteAnimator>>,
+            Query<
+                (Entity, &mut SpriteAnimation, &mut SpriteFrameState, &Sprite),
+                Without<FastSpriteAnimator>,
+            >,
         )>::new(&mut world);
 
         let _guard = DriveFixedRecorderGuard::enable();
         #[cfg(feature = "sprite_anim_soa")]
         {
-            let (profiler, plan, time, events, frame_updates, perf, runtime, fast_states, general_animations) =
-                system_state.get_mut(&mut world);
+            let (
+                profiler,
+                plan,
+                time,
+                events,
+                frame_updates,
+                perf,
+                usage,
+                runtime,
+                fast_states,
+                general_animations,
+            ) = system_state.get_mut(&mut world);
             sys_drive_sprite_animations(
                 profiler,
                 plan,
@@ -2408,6 +2605,7 @@ mod tests {
                 events,
                 frame_updates,
                 perf,
+                usage,
                 runtime,
                 fast_states,
                 general_animations,
@@ -2415,8 +2613,17 @@ mod tests {
         }
         #[cfg(not(feature = "sprite_anim_soa"))]
         {
-            let (profiler, plan, time, events, frame_updates, perf, fast_animations, general_animations) =
-                system_state.get_mut(&mut world);
+            let (
+                profiler,
+                plan,
+                time,
+                events,
+                frame_updates,
+                perf,
+                usage,
+                fast_animations,
+                general_animations,
+            ) = system_state.get_mut(&mut world);
             sys_drive_sprite_animations(
                 profiler,
                 plan,
@@ -2424,6 +2631,7 @@ mod tests {
                 events,
                 frame_updates,
                 perf,
+                usage,
                 fast_animations,
                 general_animations,
             );
@@ -2479,6 +2687,7 @@ mod tests {
         world.insert_resource(SystemProfiler::new());
         world.insert_resource(AnimationPlan { delta: AnimationDelta::Single(0.1) });
         world.insert_resource(AnimationTime::default());
+        world.insert_resource(EventBus::default());
 
         let entity = world
             .spawn((
@@ -2492,6 +2701,7 @@ mod tests {
             ResMut<SystemProfiler>,
             Res<AnimationPlan>,
             Res<AnimationTime>,
+            ResMut<EventBus>,
             Query<(
                 Entity,
                 &mut ClipInstance,
@@ -2503,8 +2713,8 @@ mod tests {
         )>::new(&mut world);
 
         {
-            let (profiler, plan, time, clips) = system_state.get_mut(&mut world);
-            sys_drive_transform_clips(profiler, plan, time, clips);
+            let (profiler, plan, time, events, clips) = system_state.get_mut(&mut world);
+            sys_drive_transform_clips(profiler, plan, time, events, clips);
         }
         system_state.apply(&mut world);
 
@@ -2513,7 +2723,7 @@ mod tests {
     }
 
     fn zero_duration_translation_clip(value: Vec2) -> Arc<AnimationClip> {
-        let keyframes = Arc::from(vec![ClipKeyframe { time: 0.0, value }].into_boxed_slice());
+        let keyframes = Arc::from(vec![ClipKeyframe::new(0.0, value)].into_boxed_slice());
         let empty_vec2 = Arc::from(Vec::<Vec2>::new().into_boxed_slice());
         let empty_segments = Arc::from(Vec::<ClipSegment<Vec2>>::new().into_boxed_slice());
         let empty_offsets = Arc::from(Vec::<f32>::new().into_boxed_slice());
@@ -2523,6 +2733,7 @@ mod tests {
             duration_inv: 0.0,
             translation: Some(ClipVec2Track {
                 interpolation: ClipInterpolation::Linear,
+                auto_tangent: false,
                 keyframes,
                 duration: 0.0,
                 duration_inv: 0.0,
@@ -2556,6 +2767,7 @@ pub fn sys_drive_transform_clips(
     mut profiler: ResMut<SystemProfiler>,
     animation_plan: Res<AnimationPlan>,
     animation_time: Res<AnimationTime>,
+    mut events: ResMut<EventBus>,
     mut clips: Query<(
         Entity,
         &mut ClipInstance,
@@ -2580,13 +2792,14 @@ pub fn sys_drive_transform_clips(
     if delta == 0.0 {
         return;
     }
-    drive_transform_clips(delta, has_group_scales, animation_time_ref, &mut clips);
+    drive_transform_clips(delta, has_group_scales, animation_time_ref, &mut events, &mut clips);
 }
 
 pub fn sys_drive_skeletal_clips(
     mut profiler: ResMut<SystemProfiler>,
     animation_plan: Res<AnimationPlan>,
     animation_time: Res<AnimationTime>,
+    mut events: ResMut<EventBus>,
     mut skeletons: Query<(Entity, &mut SkeletonInstance, Option<Mut<BoneTransforms>>)>,
 ) {
     let _span = profiler.scope("sys_drive_skeletal_clips");
@@ -2604,17 +2817,42 @@ pub fn sys_drive_skeletal_clips(
     if delta == 0.0 {
         return;
     }
-    drive_skeletal_clips(delta, has_group_scales, animation_time_ref, &mut skeletons);
+    drive_skeletal_clips(delta, has_group_scales, animation_time_ref, &mut events, &mut skeletons);
+}
+
+pub fn sys_drive_tint_curves(
+    mut profiler: ResMut<SystemProfiler>,
+    animation_plan: Res<AnimationPlan>,
+    mut commands: Commands,
+    mut curves: Query<(Entity, &mut TintCurve, &mut Tint)>,
+) {
+    let _span = profiler.scope("sys_drive_tint_curves");
+    let delta = match animation_plan.delta {
+        AnimationDelta::None => return,
+        AnimationDelta::Single(amount) => amount,
+        AnimationDelta::Fixed { step, steps } => step * steps as f32,
+    };
+    if delta == 0.0 {
+        return;
+    }
+    for (entity, mut curve, mut tint) in &mut curves {
+        curve.advance(delta);
+        tint.0 = curve.sample();
+        if curve.loop_mode == SpriteAnimationLoopMode::OnceStop && curve.finished() {
+            commands.entity(entity).remove::<TintCurve>();
+        }
+    }
 }
 
 fn drive_skeletal_clips(
     delta: f32,
     has_group_scales: bool,
     animation_time: &AnimationTime,
+    events: &mut EventBus,
     skeletons: &mut Query<(Entity, &mut SkeletonInstance, Option<Mut<BoneTransforms>>)>, 
 ) {
     let mut group_cache = has_group_scales.then(|| GroupScaleCache::new(animation_time));
-    for (_entity, mut instance, bone_transforms) in skeletons.iter_mut() {
+    for (entity, mut instance, bone_transforms) in skeletons.iter_mut() {
         instance.ensure_capacity();
         let clip = match instance.active_clip.clone() {
             Some(clip) => clip,
@@ -2651,13 +2889,27 @@ fn drive_skeletal_clips(
             continue;
         }
 
-        if instance.playing && scaled != 0.0 {
-            let current_time = instance.time;
-            instance.set_time(current_time + scaled);
-        }
+        if !instance.blend_layers.is_empty() {
+            if instance.playing && scaled != 0.0 {
+                instance.advance_blend_phase(scaled);
+            }
+            let layers = std::mem::take(&mut instance.blend_layers);
+            let phase = instance.blend_phase;
+            evaluate_blended_skeleton_pose(&mut instance, &layers, phase);
+            instance.blend_layers = layers;
+        } else {
+            if instance.playing && scaled != 0.0 {
+                instance.advance_time(scaled);
+                if !instance.playing {
+                    if let Some(clip_key) = instance.active_clip_key.as_ref() {
+                        events.push(GameEvent::ClipFinished { entity, clip: Arc::clone(clip_key) });
+                    }
+                }
+            }
 
-        let pose_time = instance.time;
-        evaluate_skeleton_pose(&mut instance, &clip, pose_time);
+            let pose_time = instance.time;
+            evaluate_skeleton_pose(&mut instance, &clip, pose_time);
+        }
 
         if let Some(mut bones) = bone_transforms {
             bones.ensure_joint_count(instance.joint_count());
@@ -2706,6 +2958,78 @@ pub(crate) fn evaluate_skeleton_pose(instance: &mut SkeletonInstance, clip: &Ske
         instance.local_poses[index] = Mat4::from_scale_rotation_translation(scale, rotation, translation);
     }
 
+    propagate_skeleton_pose(instance);
+}
+
+/// Poses the skeleton from several clips sampled at the same normalised `phase`, mixing each
+/// joint's TRS by layer weight. Rotations are blended with a sign-corrected normalised lerp.
+pub(crate) fn evaluate_blended_skeleton_pose(
+    instance: &mut SkeletonInstance,
+    layers: &[SkeletalBlendLayer],
+    phase: f32,
+) {
+    let joint_count = instance.joint_count();
+    if joint_count == 0 {
+        return;
+    }
+
+    instance.ensure_capacity();
+    let mut translations = vec![Vec3::ZERO; joint_count];
+    let mut rotations = vec![Vec4::ZERO; joint_count];
+    let mut scales = vec![Vec3::ZERO; joint_count];
+    for layer in layers {
+        let clip = layer.clip.as_ref();
+        let time = phase * clip.duration.max(0.0);
+        let channel_map = &mut instance.joint_channel_map;
+        for slot in channel_map.iter_mut() {
+            *slot = None;
+        }
+        for (curve_index, curve) in clip.channels.iter().enumerate() {
+            let index = curve.joint_index as usize;
+            if index < channel_map.len() {
+                channel_map[index] = Some(curve_index);
+            }
+        }
+        for (index, joint) in instance.skeleton.joints.iter().enumerate() {
+            let mut translation = joint.rest_translation;
+            let mut rotation = joint.rest_rotation;
+            let mut scale = joint.rest_scale;
+            if let Some(curve_index) = instance.joint_channel_map[index] {
+                let curve = &clip.channels[curve_index];
+                if let Some(track) = &curve.translation {
+                    translation = sample_vec3_track(track, time, clip.looped);
+                }
+                if let Some(track) = &curve.rotation {
+                    rotation = sample_quat_track(track, time, clip.looped);
+                }
+                if let Some(track) = &curve.scale {
+                    scale = sample_vec3_track(track, time, clip.looped);
+                }
+            }
+            let mut rotation = Vec4::from(rotation);
+            if rotations[index].dot(rotation) < 0.0 {
+                rotation = -rotation;
+            }
+            translations[index] += translation * layer.weight;
+            rotations[index] += rotation * layer.weight;
+            scales[index] += scale * layer.weight;
+        }
+    }
+
+    for index in 0..joint_count {
+        let rotation = match rotations[index].try_normalize() {
+            Some(rotation) => Quat::from_vec4(rotation),
+            None => instance.skeleton.joints[index].rest_rotation,
+        };
+        instance.local_poses[index] =
+            Mat4::from_scale_rotation_translation(scales[index], rotation, translations[index]);
+    }
+
+    propagate_skeleton_pose(instance);
+}
+
+fn propagate_skeleton_pose(instance: &mut SkeletonInstance) {
+    let joint_count = instance.joint_count();
     let mut visited = std::mem::take(&mut instance.joint_visited);
     if visited.len() != joint_count {
         visited.resize(joint_count, false);
@@ -2827,6 +3151,7 @@ fn drive_transform_clips(
     delta: f32,
     has_group_scales: bool,
     animation_time: &AnimationTime,
+    events: &mut EventBus,
     clips: &mut Query<(
         Entity,
         &mut ClipInstance,
@@ -2839,9 +3164,9 @@ fn drive_transform_clips(
     #[cfg(feature = "anim_stats")]
     let mut stats = TransformClipStatAccumulator::default();
     let mut group_cache = has_group_scales.then(|| GroupScaleCache::new(animation_time));
-    for (_entity, mut instance, transform_player, property_player, mut transform, mut tint) in
-        clips.iter_mut()
+    for (entity, mut instance, transform_player, property_player, mut transform, mut tint) in clips.iter_mut()
     {
+        let previous_time = instance.time;
         if !instance.playing {
             #[cfg(feature = "anim_stats")]
             {
@@ -2903,8 +3228,14 @@ fn drive_transform_clips(
         let has_tint_target = wants_tint && tint.is_some();
         if !transform_available && !has_tint_target {
             if instance.playing {
-                let current_time = instance.time;
-                instance.set_time(current_time + scaled);
+                if scaled > 0.0 {
+                    instance.advance_time(scaled);
+                    emit_clip_events(&instance, previous_time, events);
+                    emit_clip_finished(entity, &instance, events);
+                } else {
+                    let current_time = instance.time;
+                    instance.set_time(current_time + scaled);
+                }
             }
             instance.last_translation = None;
             instance.last_rotation = None;
@@ -2991,9 +3322,11 @@ fn drive_transform_clips(
                     stats.zero_delta_calls += 1;
                 }
             }
+            emit_clip_finished(entity, &instance, events);
             if applied <= 0.0 {
                 continue;
             }
+            emit_clip_events(&instance, previous_time, events);
 
             #[cfg(feature = "anim_stats")]
             let sample_timer = Instant::now();
@@ -3075,6 +3408,7 @@ fn drive_transform_clips(
                 stats.zero_delta_calls += 1;
             }
         }
+        emit_clip_finished(entity, &instance, events);
         if applied <= 0.0 {
             #[cfg(feature = "anim_stats")]
             {
@@ -3082,6 +3416,7 @@ fn drive_transform_clips(
             }
             continue;
         }
+        emit_clip_events(&instance, previous_time, events);
         #[cfg(feature = "anim_stats")]
         let sample_timer = Instant::now();
         let sample =
@@ -3110,6 +3445,20 @@ fn drive_transform_clips(
     stats.flush();
 }
 
+fn emit_clip_events(instance: &ClipInstance, previous_time: f32, events: &mut EventBus) {
+    instance.for_each_crossed_event(previous_time, |name| {
+        events.push(GameEvent::ScriptMessage { message: name.to_string() });
+    });
+}
+
+/// Called right after a playing clip advances, so a clip that just reached its end (one-shot or
+/// out of counted loops) reports completion exactly once.
+fn emit_clip_finished(entity: Entity, instance: &ClipInstance, events: &mut EventBus) {
+    if !instance.playing {
+        events.push(GameEvent::ClipFinished { entity, clip: Arc::clone(&instance.clip_key) });
+    }
+}
+
 #[inline(always)]
 fn apply_clip_sample(
     instance: &mut ClipInstance,
@@ -3212,6 +3561,7 @@ pub(crate) fn initialize_animation_phase(animation: &mut SpriteAnimation, entity
         offset = offset.min(total);
     }
 
+    animation.loops_completed = 0;
     if offset <= 0.0 {
         return true;
     }
@@ -3220,6 +3570,8 @@ pub(crate) fn initialize_animation_phase(animation: &mut SpriteAnimation, entity
     animation.playing = true;
     let changed = advance_animation(animation, offset, entity, None, false);
     animation.playing = was_playing;
+    // A start offset picks a phase inside the first cycle; it never uses up counted loops.
+    animation.loops_completed = 0;
     animation.refresh_pending_start_events();
     changed
 }
@@ -3266,6 +3618,19 @@ pub(crate) fn advance_animation(
 
             match animation.mode {
                 SpriteAnimationLoopMode::Loop => {
+                    if animation.frame_index + 1 >= len {
+                        usage_record_loops(animation.usage_id, 1);
+                    }
+                    if animation.frame_index + 1 >= len && animation.loop_count.is_some() {
+                        animation.loops_completed = animation.loops_completed.saturating_add(1);
+                        if animation.loop_limit_reached() {
+                            animation.elapsed_in_frame = animation.current_duration;
+                            if respect_terminal_behavior {
+                                animation.playing = false;
+                            }
+                            break;
+                        }
+                    }
                     let next = if len > 0 { (animation.frame_index + 1) % len } else { 0 };
                     animation.set_frame_metrics_unchecked(next);
                     emit_frame_event = true;
@@ -3316,6 +3681,17 @@ pub(crate) fn advance_animation(
                         changed_this_step = true;
                         emit_frame_event = true;
                     } else {
+                        usage_record_loops(animation.usage_id, 1);
+                        if animation.loop_count.is_some() {
+                            animation.loops_completed = animation.loops_completed.saturating_add(1);
+                            if animation.loop_limit_reached() {
+                                animation.elapsed_in_frame = animation.current_duration;
+                                if respect_terminal_behavior {
+                                    animation.playing = false;
+                                }
+                                break;
+                            }
+                        }
                         animation.forward = true;
                         let next = if len > 1 { 1 } else { 0 };
                         animation.set_frame_metrics_unchecked(next);
@@ -3351,6 +3727,18 @@ pub(crate) fn advance_animation(
 
             match animation.mode {
                 SpriteAnimationLoopMode::Loop => {
+                    if animation.frame_index == 0 {
+                        usage_record_loops(animation.usage_id, 1);
+                    }
+                    if animation.frame_index == 0 && animation.loop_count.is_some() {
+                        animation.loops_completed = animation.loops_completed.saturating_add(1);
+                        if animation.loop_limit_reached() {
+                            if respect_terminal_behavior {
+                                animation.playing = false;
+                            }
+                            break;
+                        }
+                    }
                     if len > 1 {
                         let prev =
                             if animation.frame_index == 0 { len - 1 } else { animation.frame_index - 1 };
@@ -3458,6 +3846,7 @@ fn emit_sprite_animation_events(entity: Entity, animation: &SpriteAnimation, eve
     if let Some(frame) = animation.frames.get(animation.frame_index) {
         let mut emitted = 0_u32;
         for name in frame.events.iter() {
+            usage_record_event(animation.usage_id, name);
             events.push(GameEvent::SpriteAnimationEvent {
                 entity,
                 timeline: Arc::clone(&animation.timeline),
@@ -3469,6 +3858,24 @@ fn emit_sprite_animation_events(entity: Entity, animation: &SpriteAnimation, eve
     }
 }
 
+/// Called once when a drive loop observes a non-looping (or loop-count exhausted) animation stop;
+/// the animation stays paused until it is reset, so the finished event cannot repeat on its own.
+fn emit_animation_finished(
+    entity: Entity,
+    animation: &SpriteAnimation,
+    sprite: &Sprite,
+    events: &mut EventBus,
+) {
+    if animation.looped && !animation.loop_limit_reached() {
+        return;
+    }
+    events.push(GameEvent::AnimationFinished {
+        entity,
+        atlas: Arc::clone(&sprite.atlas_key),
+        timeline: Arc::clone(&animation.timeline),
+    });
+}
+
 fn stable_random_fraction(entity: Entity, timeline: &str) -> f32 {
     let mut hasher = DefaultHasher::new();
     entity.hash(&mut hasher);
@@ -3525,6 +3932,9 @@ fn advance_animation_loop_no_events(animation: &mut SpriteAnimation, delta: f32)
     }
 
     let wrapped = raw_position < 0.0 || raw_position >= total;
+    if wrapped {
+        usage_record_loops(animation.usage_id, (raw_position / total).floor().abs() as u32);
+    }
 
     #[cfg(feature = "anim_stats")]
     if wrapped {
@@ -3620,6 +4030,9 @@ fn advance_animation_loop_no_events_slot(runtime: &mut SpriteAnimatorSoa, slot:
     }
 
     let wrapped = raw_position < 0.0 || raw_position >= total;
+    if wrapped {
+        usage_record_loops(runtime.usage_id[slot], (raw_position / total).floor().abs() as u32);
+    }
 
     #[cfg(feature = "anim_stats")]
     if wrapped {
@@ -3711,10 +4124,12 @@ fn advance_animation_fast_loop(animation: &mut SpriteAnimation, delta: f32) -> b
         }
         remaining -= current_duration;
         let mut threshold;
+        let mut wraps = 0_u32;
         loop {
             index += 1;
             if index == frame_count {
                 index = 0;
+                wraps += 1;
             }
             unsafe {
                 current_duration = (*durations.add(index)).max(max_epsilon);
@@ -3722,6 +4137,7 @@ fn advance_animation_fast_loop(animation: &mut SpriteAnimation, delta: f32) -> b
             }
             threshold = current_duration;
             if remaining <= threshold {
+                usage_record_loops(animation.usage_id, wraps);
                 animation.frame_index = index;
                 animation.current_duration = current_duration;
                 animation.current_frame_offset = current_offset;
@@ -3738,7 +4154,11 @@ fn advance_animation_fast_loop(animation: &mut SpriteAnimation, delta: f32) -> b
             return false;
         }
         remaining = -remaining;
+        let mut wraps = 0_u32;
         loop {
+            if index == 0 {
+                wraps += 1;
+            }
             index = if index == 0 { frame_count - 1 } else { index - 1 };
             unsafe {
                 current_duration = (*durations.add(index)).max(max_epsilon);
@@ -3746,6 +4166,7 @@ fn advance_animation_fast_loop(animation: &mut SpriteAnimation, delta: f32) -> b
             }
             let threshold = current_duration;
             if remaining <= threshold {
+                usage_record_loops(animation.usage_id, wraps);
                 animation.frame_index = index;
                 animation.current_duration = current_duration;
                 animation.current_frame_offset = current_offset;
@@ -4089,6 +4510,7 @@ fn apply_const_dt_simd_chunk(
         let frames = runtime.const_dt_frame_count[slot].max(1) as usize;
         let mut index = runtime.frame_index[slot] as usize;
         if frames > 0 {
+            usage_record_loops(runtime.usage_id[slot], ((index as u64 + steps) / frames as u64) as u32);
             let advance = (steps as usize) % frames;
             index = (index + advance) % frames;
         } else {
@@ -4163,7 +4585,7 @@ fn drive_general_fixed(
     events: &mut EventBus,
     frame_updates: &mut SpriteFrameApplyQueue,
     animations: &mut Query<
-        (Entity, &mut SpriteAnimation, &mut SpriteFrameState),
+        (Entity, &mut SpriteAnimation, &mut SpriteFrameState, &Sprite),
         Without<FastSpriteAnimator>,
     >,
 ) {
@@ -4178,7 +4600,7 @@ fn drive_general_fixed(
 
     perf_record_general_bucket_frame();
 
-    for (entity, mut animation, mut sprite_state) in animations.iter_mut() {
+    for (entity, mut animation, mut sprite_state, sprite) in animations.iter_mut() {
         let frame_count = animation.frames.len();
         if !prepare_animation(&mut animation, frame_count) {
             continue;
@@ -4231,6 +4653,7 @@ fn drive_general_fixed(
                 sprite_changed = true;
             }
             if !animation.playing {
+                emit_animation_finished(entity, &animation, sprite, events);
                 break;
             }
         }
@@ -4348,10 +4771,12 @@ fn advance_animation_fast_loop_slot(runtime: &mut SpriteAnimatorSoa, slot: usize
             return false;
         }
         remaining -= threshold;
+        let mut wraps = 0_u32;
         loop {
             index += 1;
             if index == frame_count {
                 index = 0;
+                wraps += 1;
             }
             unsafe {
                 current_offset = *offsets.get_unchecked(index);
@@ -4359,6 +4784,7 @@ fn advance_animation_fast_loop_slot(runtime: &mut SpriteAnimatorSoa, slot: usize
             current_duration = next_duration;
             threshold = current_duration.max(CLIP_TIME_EPSILON);
             if remaining <= threshold {
+                usage_record_loops(runtime.usage_id[slot], wraps);
                 runtime.frame_index[slot] = index as u32;
                 runtime.current_duration[slot] = current_duration;
                 runtime.current_frame_offset[slot] = current_offset;
@@ -4380,7 +4806,11 @@ fn advance_animation_fast_loop_slot(runtime: &mut SpriteAnimatorSoa, slot: usize
             return false;
         }
         remaining = -remaining;
+        let mut wraps = 0_u32;
         loop {
+            if index == 0 {
+                wraps += 1;
+            }
             index = if index == 0 { frame_count - 1 } else { index - 1 };
             unsafe {
                 current_duration = *durations.get_unchecked(index);
@@ -4388,6 +4818,7 @@ fn advance_animation_fast_loop_slot(runtime: &mut SpriteAnimatorSoa, slot: usize
             }
             let threshold = current_duration.max(CLIP_TIME_EPSILON);
             if remaining <= threshold {
+                usage_record_loops(runtime.usage_id[slot], wraps);
                 runtime.frame_index[slot] = index as u32;
                 runtime.current_duration[slot] = current_duration;
                 runtime.current_frame_offset[slot] = current_offset;
@@ -4441,10 +4872,12 @@ fn advance_animation_fast_loop_slot(runtime: &mut SpriteAnimatorSoa, slot: usize
             return false;
         }
         remaining -= threshold;
+        let mut wraps = 0_u32;
         loop {
             index += 1;
             if index == frame_count {
                 index = 0;
+                wraps += 1;
             }
             unsafe {
                 current_offset_fp = *offsets.get_unchecked(index);
@@ -4452,6 +4885,7 @@ fn advance_animation_fast_loop_slot(runtime: &mut SpriteAnimatorSoa, slot: usize
             current_duration_fp = next_duration_fp.max(FP_CLIP_EPSILON);
             threshold = current_duration_fp as u64;
             if remaining <= threshold {
+                usage_record_loops(runtime.usage_id[slot], wraps);
                 runtime.frame_index[slot] = index as u32;
                 runtime.current_duration_fp[slot] = current_duration_fp;
                 runtime.current_duration[slot] = f32_from_fp(current_duration_fp);
@@ -4481,7 +4915,11 @@ fn advance_animation_fast_loop_slot(runtime: &mut SpriteAnimatorSoa, slot: usize
             return false;
         }
         remaining = -remaining;
+        let mut wraps = 0_u32;
         loop {
+            if index == 0 {
+                wraps += 1;
+            }
             index = if index == 0 { frame_count - 1 } else { index - 1 };
             unsafe {
                 current_duration_fp = (*durations.get_unchecked(index)).max(FP_CLIP_EPSILON);
@@ -4489,6 +4927,7 @@ fn advance_animation_fast_loop_slot(runtime: &mut SpriteAnimatorSoa, slot: usize
             }
             let threshold = current_duration_fp as i64;
             if remaining <= threshold {
+                usage_record_loops(runtime.usage_id[slot], wraps);
                 runtime.frame_index[slot] = index as u32;
                 runtime.current_duration_fp[slot] = current_duration_fp;
                 runtime.current_duration[slot] = f32_from_fp(current_duration_fp);
@@ -4655,7 +5094,7 @@ fn drive_general_single(
     events: &mut EventBus,
     frame_updates: &mut SpriteFrameApplyQueue,
     animations: &mut Query<
-        (Entity, &mut SpriteAnimation, &mut SpriteFrameState),
+        (Entity, &mut SpriteAnimation, &mut SpriteFrameState, &Sprite),
         Without<FastSpriteAnimator>,
     >,
 ) {
@@ -4664,7 +5103,7 @@ fn drive_general_single(
 
     perf_record_general_bucket_frame();
 
-    for (entity, mut animation, mut sprite_state) in animations.iter_mut() {
+    for (entity, mut animation, mut sprite_state, sprite) in animations.iter_mut() {
         let frame_count = animation.frames.len();
         if !prepare_animation(&mut animation, frame_count) {
             continue;
@@ -4703,6 +5142,9 @@ fn drive_general_single(
         } else if advance_animation(&mut animation, advance_delta, entity, None, true) {
             sprite_changed = true;
         }
+        if !animation.playing {
+            emit_animation_finished(entity, &animation, sprite, events);
+        }
 
         if sprite_changed {
             queue_sprite_frame_update(entity, &animation, &mut sprite_state, frame_updates);
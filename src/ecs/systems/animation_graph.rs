@@ -0,0 +1,56 @@
+use super::{AnimationDelta, AnimationPlan};
+use crate::ecs::profiler::SystemProfiler;
+use crate::ecs::types::*;
+use bevy_ecs::prelude::*;
+use std::sync::Arc;
+
+pub fn sys_drive_animation_graphs(
+    mut profiler: ResMut<SystemProfiler>,
+    animation_plan: Res<AnimationPlan>,
+    mut commands: Commands,
+    mut graphs: Query<(
+        Entity,
+        &mut AnimationGraphInstance,
+        Option<&mut ClipInstance>,
+        Option<&mut SkeletonInstance>,
+    )>,
+) {
+    let _span = profiler.scope("sys_drive_animation_graphs");
+    // Graphs still react to parameter changes while animation time is paused; only
+    // transition blends need elapsed time.
+    let delta = match animation_plan.delta {
+        AnimationDelta::None => 0.0,
+        AnimationDelta::Single(amount) => amount,
+        AnimationDelta::Fixed { step, steps } => step * steps as f32,
+    };
+    for (entity, mut graph, clip_instance, skeleton) in &mut graphs {
+        let entered = graph.evaluate(delta);
+        if let Some(state) = entered.as_ref() {
+            if let Some(AnimationGraphClip::Transform { key, clip }) = graph.state_clip(state) {
+                match clip_instance {
+                    Some(mut instance) => instance.replace_clip(Arc::clone(key), Arc::clone(clip)),
+                    None => {
+                        commands.entity(entity).insert(ClipInstance::new(Arc::clone(key), Arc::clone(clip)));
+                    }
+                }
+            }
+        }
+        // Skeletal states are re-weighted every frame so blend parameters and cross-fades track
+        // the graph continuously; states without skeletal content leave the skeleton alone.
+        let Some(mut instance) = skeleton else {
+            continue;
+        };
+        let layers = graph.skeletal_blend_layers();
+        if layers.is_empty() {
+            continue;
+        }
+        instance.set_blend_layers(layers);
+        if entered.is_some() {
+            if graph.is_transitioning() {
+                instance.set_playing(true);
+            } else {
+                instance.restart_blend();
+            }
+        }
+    }
+}
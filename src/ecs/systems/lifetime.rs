@@ -0,0 +1,54 @@
+use super::TimeDelta;
+use crate::ecs::physics::WorldBounds;
+use crate::ecs::profiler::SystemProfiler;
+use crate::ecs::types::*;
+use bevy_ecs::prelude::*;
+use glam::Vec2;
+
+/// Entities the lifetime and bounds systems flagged this step. `EcsWorld` despawns them through
+/// `despawn_entity` once the schedule has run, so they get the same cleanup and events as any
+/// other despawn.
+#[derive(Resource, Default)]
+pub struct PendingDespawns(pub Vec<Entity>);
+
+/// Camera view (min, max) the `DespawnOutOfBounds` rect is widened to cover, so entities still on
+/// screen outside the world bounds are kept.
+#[derive(Resource, Clone, Copy, Default)]
+pub struct DespawnView(pub Option<(Vec2, Vec2)>);
+
+pub fn sys_tick_lifetimes(
+    mut profiler: ResMut<SystemProfiler>,
+    dt: Res<TimeDelta>,
+    mut pending: ResMut<PendingDespawns>,
+    mut lifetimes: Query<(Entity, &mut Lifetime)>,
+) {
+    let _span = profiler.scope("sys_tick_lifetimes");
+    for (entity, mut lifetime) in lifetimes.iter_mut() {
+        lifetime.seconds -= dt.0;
+        if lifetime.seconds <= 0.0 {
+            pending.0.push(entity);
+        }
+    }
+}
+
+pub fn sys_despawn_out_of_bounds(
+    mut profiler: ResMut<SystemProfiler>,
+    bounds: Res<WorldBounds>,
+    view: Res<DespawnView>,
+    mut pending: ResMut<PendingDespawns>,
+    entities: Query<(Entity, &WorldTransform, &DespawnOutOfBounds)>,
+) {
+    let _span = profiler.scope("sys_despawn_out_of_bounds");
+    let (mut min, mut max) = (bounds.min, bounds.max);
+    if let Some((view_min, view_max)) = view.0 {
+        min = min.min(view_min);
+        max = max.max(view_max);
+    }
+    for (entity, world, despawn) in entities.iter() {
+        let position = Vec2::new(world.0.w_axis.x, world.0.w_axis.y);
+        let margin = Vec2::splat(despawn.margin.max(0.0));
+        if position.cmplt(min - margin).any() || position.cmpgt(max + margin).any() {
+            pending.0.push(entity);
+        }
+    }
+}
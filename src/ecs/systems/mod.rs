@@ -1,12 +1,19 @@
-use bevy_ecs::prelude::Resource;
+use crate::ecs::types::SceneEntityTag;
+use bevy_ecs::prelude::{Entity, Res, Resource};
 use std::collections::HashMap;
 
 mod animation;
+mod animation_graph;
+mod lifetime;
+mod nav;
 mod particles;
 mod physics;
 mod picking;
 
 pub use animation::*;
+pub use animation_graph::*;
+pub use lifetime::*;
+pub use nav::*;
 pub use particles::*;
 pub use physics::*;
 pub use picking::*;
@@ -14,6 +21,43 @@ pub use picking::*;
 #[derive(Resource, Clone, Copy)]
 pub struct TimeDelta(pub f32);
 
+/// Set through [`crate::ecs::EcsWorld::set_deterministic`]. While `deterministic` is on, spin and
+/// particles advance on the fixed step instead of the frame delta, systems whose result depends on
+/// visit order walk entities sorted by scene id, and emitters draw from streams seeded by `seed`.
+#[derive(Resource, Clone, Copy, Default)]
+pub struct SimulationMode {
+    pub deterministic: bool,
+    pub seed: u64,
+}
+
+/// Run condition for simulation systems that follow the frame delta outside deterministic mode.
+pub fn on_variable_step(mode: Res<SimulationMode>) -> bool {
+    !mode.deterministic
+}
+
+/// Run condition for the same systems once deterministic mode moves them onto the fixed step.
+pub fn on_fixed_step(mode: Res<SimulationMode>) -> bool {
+    mode.deterministic
+}
+
+/// Fills `out` with the entities of `entities`, sorted by scene id in deterministic mode (untagged
+/// entities such as particles first, by entity) and in query order otherwise.
+pub(crate) fn visit_order<'a>(
+    mode: &SimulationMode,
+    entities: impl Iterator<Item = (Entity, Option<&'a SceneEntityTag>)>,
+    out: &mut Vec<Entity>,
+) {
+    out.clear();
+    if !mode.deterministic {
+        out.extend(entities.map(|(entity, _)| entity));
+        return;
+    }
+    let mut keyed: Vec<(Option<&str>, Entity)> =
+        entities.map(|(entity, tag)| (tag.map(|tag| tag.id.as_str()), entity)).collect();
+    keyed.sort_unstable();
+    out.extend(keyed.into_iter().map(|(_, entity)| entity));
+}
+
 #[derive(Resource, Clone, Copy)]
 pub struct AnimationPlan {
     pub delta: AnimationDelta,
@@ -0,0 +1,86 @@
+use super::TimeDelta;
+use crate::ecs::physics::RapierState;
+use crate::ecs::profiler::SystemProfiler;
+use crate::ecs::types::*;
+use crate::nav::NavGrid;
+use bevy_ecs::prelude::*;
+use glam::Vec2;
+use rapier2d::prelude::Vector;
+
+/// Colliders that block navigation: anything with bounds that is not itself moving under
+/// velocity integration or steered by a path agent.
+pub type NavObstacleFilter = (Without<Velocity>, Without<PathAgent>);
+
+pub fn nav_obstacle_bounds(
+    world: &WorldTransform,
+    aabb: &Aabb,
+    shape: Option<&ColliderShape>,
+) -> (Vec2, Vec2) {
+    let center = Vec2::new(world.0.w_axis.x, world.0.w_axis.y) + ColliderShape::offset_of(shape);
+    (center - aabb.half, center + aabb.half)
+}
+
+pub fn sys_sync_nav_grid(
+    mut profiler: ResMut<SystemProfiler>,
+    mut grid: ResMut<NavGrid>,
+    obstacles: Query<(Entity, &WorldTransform, &Aabb, Option<&ColliderShape>), NavObstacleFilter>,
+) {
+    let _span = profiler.scope("sys_sync_nav_grid");
+    grid.sync_obstacles(obstacles.iter().map(|(entity, world, aabb, shape)| {
+        let (min, max) = nav_obstacle_bounds(world, aabb, shape);
+        (entity, min, max)
+    }));
+}
+
+pub fn sys_drive_path_agents(
+    mut profiler: ResMut<SystemProfiler>,
+    grid: Res<NavGrid>,
+    mut rapier: ResMut<RapierState>,
+    dt: Res<TimeDelta>,
+    mut agents: Query<(&mut PathAgent, &Transform, Option<&mut Velocity>, Option<&RapierBody>)>,
+) {
+    let _span = profiler.scope("sys_drive_path_agents");
+    for (mut agent, transform, velocity, body) in &mut agents {
+        let Some(goal) = agent.goal else {
+            continue;
+        };
+        let position = transform.translation;
+        let revision = grid.revision();
+        if agent.nav_revision != revision {
+            // Only re-plan when an obstacle change actually cuts the remaining route.
+            if grid.path_is_clear(position, agent.remaining_waypoints()) {
+                agent.nav_revision = revision;
+            } else if let Some(path) = grid.find_path(position, goal) {
+                agent.set_path(goal, path, revision);
+            } else {
+                agent.clear_path();
+            }
+        }
+        while let Some(target) = agent.remaining_waypoints().first().copied() {
+            if target.distance(position) > agent.arrive_radius {
+                break;
+            }
+            agent.next_waypoint += 1;
+        }
+        let desired = match agent.remaining_waypoints().first().copied() {
+            Some(target) => {
+                let offset = target - position;
+                let distance = offset.length();
+                let speed = if dt.0 > 0.0 { agent.speed.min(distance / dt.0) } else { agent.speed };
+                offset / distance * speed
+            }
+            None => {
+                agent.clear_path();
+                Vec2::ZERO
+            }
+        };
+        if let Some(mut velocity) = velocity {
+            velocity.0 = desired;
+        }
+        if let Some(body) = body {
+            if let Some(rb) = rapier.body_mut(body.handle) {
+                rb.set_linvel(Vector::new(desired.x, desired.y), true);
+            }
+        }
+    }
+}
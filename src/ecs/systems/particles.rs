@@ -1,62 +1,87 @@
-use super::TimeDelta;
+use super::{visit_order, SimulationMode, TimeDelta};
 use crate::ecs::profiler::SystemProfiler;
 use crate::ecs::types::*;
+use crate::scene::SceneEntityId;
 use bevy_ecs::prelude::*;
 use bevy_ecs::system::{Commands, Res};
 use glam::Vec2;
 use rand::rngs::StdRng;
 use rand::{Rng, SeedableRng};
+use std::collections::HashMap;
 use std::sync::Arc;
 
+/// Components of one spawned particle.
+pub type ParticleBundle =
+    (Transform, Velocity, Force, Mass, Sprite, Tint, Aabb, Particle, ParticleVisual, ParticleCollision);
+
+/// Components of one spawned particle whose emitter leaves a trail.
+pub type TrailParticleBundle = (
+    Transform,
+    Velocity,
+    Force,
+    Mass,
+    Sprite,
+    Tint,
+    Aabb,
+    Particle,
+    ParticleVisual,
+    ParticleCollision,
+    ParticleTrail,
+);
+
 #[derive(Resource)]
 pub struct ParticleSpawnScratch {
+    /// Shared stream; in deterministic mode only emitters without a scene id draw from it.
     pub rng: StdRng,
-    pub batch_plain: Vec<(
-        Transform,
-        Velocity,
-        Force,
-        Mass,
-        Sprite,
-        Tint,
-        Aabb,
-        Particle,
-        ParticleVisual,
-    )>,
-    pub batch_with_trail: Vec<(
-        Transform,
-        Velocity,
-        Force,
-        Mass,
-        Sprite,
-        Tint,
-        Aabb,
-        Particle,
-        ParticleVisual,
-        ParticleTrail,
-    )>,
+    /// Per-emitter streams used in deterministic mode, keyed by scene id so they survive respawns.
+    pub emitter_rngs: HashMap<SceneEntityId, StdRng>,
+    pub order: Vec<Entity>,
+    pub batch_plain: Vec<ParticleBundle>,
+    pub batch_with_trail: Vec<TrailParticleBundle>,
 }
 
 impl Default for ParticleSpawnScratch {
     fn default() -> Self {
         Self {
             rng: StdRng::from_entropy(),
+            emitter_rngs: HashMap::new(),
+            order: Vec::new(),
             batch_plain: Vec::new(),
             batch_with_trail: Vec::new(),
         }
     }
 }
 
+impl ParticleSpawnScratch {
+    /// Reseeds the shared stream and drops the per-emitter ones so they restart from `seed`.
+    pub fn reseed(&mut self, seed: u64) {
+        self.rng = StdRng::seed_from_u64(seed);
+        self.emitter_rngs.clear();
+    }
+}
+
+/// Seed of the stream an emitter draws from in deterministic mode. FNV-1a rather than the std
+/// hasher so the value is the same in every process.
+fn emitter_stream_seed(seed: u64, id: &SceneEntityId) -> u64 {
+    id.as_str()
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325 ^ seed, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3))
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn sys_update_emitters(
     mut profiler: ResMut<SystemProfiler>,
     mut commands: Commands,
-    mut emitters: Query<(&mut ParticleEmitter, &Transform)>,
+    mut emitters: Query<(Entity, &mut ParticleEmitter, &Transform, Option<&SceneEntityTag>)>,
     caps: Res<ParticleCaps>,
     mut particle_state: ResMut<ParticleState>,
     dt: Res<TimeDelta>,
+    mode: Res<SimulationMode>,
     mut spawn_scratch: ResMut<ParticleSpawnScratch>,
 ) {
     let _span = profiler.scope("sys_update_emitters");
-    let ParticleSpawnScratch { rng, batch_plain, batch_with_trail } = &mut *spawn_scratch;
+    let ParticleSpawnScratch { rng: shared_rng, emitter_rngs, order, batch_plain, batch_with_trail } =
+        &mut *spawn_scratch;
     let max_total = caps.max_total as i32;
     let max_spawn_per_frame = caps.max_spawn_per_frame as i32;
     let mut active_particles = particle_state.active_particles.min(caps.max_total) as i32;
@@ -66,7 +91,12 @@ pub fn sys_update_emitters(
     batch_plain.clear();
     batch_with_trail.clear();
 
-    for (mut emitter, transform) in emitters.iter_mut() {
+    // The frame budget goes to emitters in visit order, so deterministic mode fixes that order.
+    visit_order(&mode, emitters.iter().map(|(entity, _, _, tag)| (entity, tag)), order);
+    for &emitter_entity in order.iter() {
+        let Ok((_, mut emitter, transform, tag)) = emitters.get_mut(emitter_entity) else {
+            continue;
+        };
         let spawn_rate = emitter.rate.max(0.0);
         emitter.accumulator = (emitter.accumulator + spawn_rate * dt.0).min(caps.max_emitter_backlog);
 
@@ -74,7 +104,8 @@ pub fn sys_update_emitters(
             continue;
         }
 
-        let desired = emitter.accumulator.floor() as i32;
+        let burst = emitter.pending_burst.min(i32::MAX as u32) as i32;
+        let desired = (emitter.accumulator.floor() as i32).saturating_add(burst);
         if desired <= 0 {
             continue;
         }
@@ -82,44 +113,152 @@ pub fn sys_update_emitters(
         if to_spawn <= 0 {
             continue;
         }
-        emitter.accumulator -= to_spawn as f32;
+        let from_burst = to_spawn.min(burst);
+        emitter.pending_burst -= from_burst as u32;
+        emitter.accumulator -= (to_spawn - from_burst) as f32;
+        let rng = emitter_rng(shared_rng, emitter_rngs, tag, &mode);
         for _ in 0..to_spawn {
-            let angle = rng.gen_range(-emitter.spread..=emitter.spread);
-            let dir = Vec2::from_angle(transform.rotation + std::f32::consts::FRAC_PI_2 + angle);
-            let velocity = dir * emitter.speed;
-            let lifetime = emitter.lifetime;
-            let start_size = emitter.start_size.max(0.01);
-            let base = (
-                Transform {
-                    translation: transform.translation + dir * 0.05,
-                    rotation: 0.0,
-                    scale: Vec2::splat(start_size),
-                },
-                Velocity(velocity),
-                Force::default(),
-                Mass(0.2),
-                Sprite::uninitialized(Arc::clone(&emitter.atlas), Arc::clone(&emitter.region)),
-                Tint(emitter.start_color),
-                Aabb { half: Vec2::splat((start_size * 0.5).max(0.01)) },
-                Particle { lifetime, max_lifetime: lifetime },
-                ParticleVisual {
-                    start_color: emitter.start_color,
-                    end_color: emitter.end_color,
-                    start_size: emitter.start_size,
-                    end_size: emitter.end_size,
-                },
+            push_particle(
+                batch_plain,
+                batch_with_trail,
+                emitter_entity,
+                &emitter,
+                transform,
+                emitter.speed,
+                rng,
             );
-            if let Some(trail) = emitter.trail {
-                batch_with_trail.push((base.0, base.1, base.2, base.3, base.4, base.5, base.6, base.7, base.8, trail));
-            } else {
-                batch_plain.push(base);
-            }
         }
         frame_budget -= to_spawn;
         remaining_headroom -= to_spawn;
         active_particles = (active_particles + to_spawn).min(max_total);
     }
 
+    flush_particle_batches(&mut commands, batch_plain, batch_with_trail);
+    particle_state.active_particles = active_particles.max(0) as u32;
+}
+
+/// Fires every pending `BurstEmitter` in one go, ahead of the continuous emitters so a burst gets
+/// first claim on the spawn budget. Bursts are consumed even when the caps truncate them.
+pub fn sys_fire_burst_emitters(
+    mut profiler: ResMut<SystemProfiler>,
+    mut commands: Commands,
+    bursts: Query<(Entity, &BurstEmitter, &ParticleEmitter, &Transform, Option<&SceneEntityTag>)>,
+    caps: Res<ParticleCaps>,
+    mut particle_state: ResMut<ParticleState>,
+    mode: Res<SimulationMode>,
+    mut spawn_scratch: ResMut<ParticleSpawnScratch>,
+) {
+    let _span = profiler.scope("sys_fire_burst_emitters");
+    if bursts.is_empty() {
+        return;
+    }
+    let ParticleSpawnScratch { rng: shared_rng, emitter_rngs, order, batch_plain, batch_with_trail } =
+        &mut *spawn_scratch;
+    let active_particles = particle_state.active_particles.min(caps.max_total);
+    let mut frame_budget = (caps.max_total - active_particles).min(caps.max_spawn_per_frame);
+    let mut spawned = 0;
+    batch_plain.clear();
+    batch_with_trail.clear();
+
+    visit_order(&mode, bursts.iter().map(|(entity, _, _, _, tag)| (entity, tag)), order);
+    for &emitter_entity in order.iter() {
+        let Ok((_, burst, emitter, transform, tag)) = bursts.get(emitter_entity) else {
+            continue;
+        };
+        commands.entity(emitter_entity).remove::<BurstEmitter>();
+        let rng = emitter_rng(shared_rng, emitter_rngs, tag, &mode);
+        for (count, impulse_speed) in burst.requests() {
+            let to_spawn = count.min(frame_budget);
+            for _ in 0..to_spawn {
+                push_particle(
+                    batch_plain,
+                    batch_with_trail,
+                    emitter_entity,
+                    emitter,
+                    transform,
+                    impulse_speed,
+                    rng,
+                );
+            }
+            frame_budget -= to_spawn;
+            spawned += to_spawn;
+        }
+    }
+
+    flush_particle_batches(&mut commands, batch_plain, batch_with_trail);
+    particle_state.active_particles = (active_particles + spawned).min(caps.max_total);
+}
+
+/// Stream an emitter draws from; see [`ParticleSpawnScratch`].
+fn emitter_rng<'a>(
+    shared_rng: &'a mut StdRng,
+    emitter_rngs: &'a mut HashMap<SceneEntityId, StdRng>,
+    tag: Option<&SceneEntityTag>,
+    mode: &SimulationMode,
+) -> &'a mut StdRng {
+    match tag {
+        Some(tag) if mode.deterministic => emitter_rngs
+            .entry(tag.id.clone())
+            .or_insert_with(|| StdRng::seed_from_u64(emitter_stream_seed(mode.seed, &tag.id))),
+        _ => shared_rng,
+    }
+}
+
+/// Queues one particle from `emitter`, launched at `speed` along a direction sampled from its shape.
+fn push_particle(
+    batch_plain: &mut Vec<ParticleBundle>,
+    batch_with_trail: &mut Vec<TrailParticleBundle>,
+    emitter_entity: Entity,
+    emitter: &ParticleEmitter,
+    transform: &Transform,
+    speed: f32,
+    rng: &mut StdRng,
+) {
+    let facing = Vec2::from_angle(transform.rotation);
+    let (offset, dir) = sample_emitter_shape(emitter.shape, emitter.spread, rng);
+    let dir = facing.rotate(dir);
+    let velocity = dir * speed;
+    let lifetime = emitter.lifetime;
+    let start_size = emitter.start_size.max(0.01);
+    let base = (
+        Transform {
+            translation: transform.translation + facing.rotate(offset),
+            rotation: 0.0,
+            scale: Vec2::splat(start_size),
+        },
+        Velocity(velocity),
+        Force::default(),
+        Mass(0.2),
+        Sprite::uninitialized(Arc::clone(&emitter.atlas), Arc::clone(&emitter.region)),
+        Tint(emitter.start_color),
+        Aabb { half: Vec2::splat((start_size * 0.5).max(0.01)) },
+        Particle { lifetime, max_lifetime: lifetime },
+        ParticleVisual {
+            start_color: emitter.start_color,
+            end_color: emitter.end_color,
+            start_size: emitter.start_size,
+            end_size: emitter.end_size,
+        },
+        ParticleCollision {
+            emitter: emitter_entity,
+            mode: emitter.collision_mode,
+            emit_events: emitter.collision_events,
+            stuck_at: None,
+        },
+    );
+    if let Some(trail) = emitter.trail {
+        batch_with_trail
+            .push((base.0, base.1, base.2, base.3, base.4, base.5, base.6, base.7, base.8, base.9, trail));
+    } else {
+        batch_plain.push(base);
+    }
+}
+
+fn flush_particle_batches(
+    commands: &mut Commands,
+    batch_plain: &mut Vec<ParticleBundle>,
+    batch_with_trail: &mut Vec<TrailParticleBundle>,
+) {
     if !batch_plain.is_empty() {
         let drained: Vec<_> = batch_plain.drain(..).collect();
         commands.spawn_batch(drained);
@@ -128,8 +267,38 @@ pub fn sys_update_emitters(
         let drained: Vec<_> = batch_with_trail.drain(..).collect();
         commands.spawn_batch(drained);
     }
+}
 
-    particle_state.active_particles = active_particles.max(0) as u32;
+/// Picks a spawn offset and launch direction for one particle in the emitter's local frame,
+/// where +Y is the facing direction.
+fn sample_emitter_shape(shape: EmitterShape, spread: f32, rng: &mut StdRng) -> (Vec2, Vec2) {
+    use std::f32::consts::{FRAC_PI_2, TAU};
+    let facing_jittered = |rng: &mut StdRng| Vec2::from_angle(FRAC_PI_2 + rng.gen_range(-spread..=spread));
+    match shape {
+        EmitterShape::Point => (Vec2::ZERO, Vec2::from_angle(rng.gen_range(0.0..TAU))),
+        EmitterShape::Cone => {
+            let dir = facing_jittered(rng);
+            (dir * 0.05, dir)
+        }
+        EmitterShape::CircleEdge { radius } => {
+            let normal = Vec2::from_angle(rng.gen_range(0.0..TAU));
+            (normal * radius, normal)
+        }
+        EmitterShape::Disc { radius } => {
+            let normal = Vec2::from_angle(rng.gen_range(0.0..TAU));
+            // sqrt keeps the samples uniform over the area instead of clumping at the centre.
+            (normal * radius * rng.gen_range(0.0f32..=1.0).sqrt(), normal)
+        }
+        EmitterShape::Box { half_width, half_height } => {
+            let offset =
+                Vec2::new(rng.gen_range(-half_width..=half_width), rng.gen_range(-half_height..=half_height));
+            (offset, facing_jittered(rng))
+        }
+        EmitterShape::Line { length } => {
+            let half = length * 0.5;
+            (Vec2::new(rng.gen_range(-half..=half), 0.0), facing_jittered(rng))
+        }
+    }
 }
 
 #[allow(clippy::type_complexity)]
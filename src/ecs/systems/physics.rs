@@ -1,4 +1,4 @@
-use super::TimeDelta;
+use super::{visit_order, SimulationMode, TimeDelta};
 use crate::ecs::physics::{
     CollisionEventKind, ParticleContacts, PhysicsParams, RapierState, SpatialHash, SpatialIndexConfig,
     SpatialMetrics, SpatialMode, SpatialQuadtree, SpatialScratch, WorldBounds,
@@ -12,7 +12,7 @@ use bevy_ecs::system::{Res, ResMut};
 use glam::Vec2;
 use rapier2d::prelude::Vector;
 use smallvec::SmallVec;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 pub fn sys_apply_spin(
     mut profiler: ResMut<SystemProfiler>,
@@ -150,6 +150,19 @@ pub fn sys_world_bounds_bounce(
     }
 }
 
+type SpatialHashColliders<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static Transform,
+        &'static Aabb,
+        Option<&'static ColliderShape>,
+        Option<&'static SceneEntityTag>,
+    ),
+    Without<RapierBody>,
+>;
+
 #[allow(clippy::too_many_arguments)]
 pub fn sys_build_spatial_hash(
     mut profiler: ResMut<SystemProfiler>,
@@ -159,15 +172,20 @@ pub fn sys_build_spatial_hash(
     bounds: Res<WorldBounds>,
     settings: Res<SpatialIndexConfig>,
     mut metrics: ResMut<SpatialMetrics>,
-    q: Query<(Entity, &Transform, &Aabb), Without<RapierBody>>,
+    mode: Res<SimulationMode>,
+    q: SpatialHashColliders,
+    mut order: Local<Vec<Entity>>,
 ) {
     let _span = profiler.scope("sys_build_spatial_hash");
     grid.begin_frame();
     scratch.colliders.clear();
     let collider_data = &mut scratch.colliders;
-    for (e, t, a) in &q {
-        grid.insert(e, t.translation, a.half);
-        collider_data.push((e, t.translation, a.half));
+    // Cell lists keep insertion order, which decides how contact impulses are summed.
+    visit_order(&mode, q.iter().map(|(e, _, _, _, tag)| (e, tag)), &mut order);
+    for (e, t, a, shape, _) in order.iter().filter_map(|&e| q.get(e).ok()) {
+        let center = t.translation + ColliderShape::offset_of(shape);
+        grid.insert(e, center, a.half);
+        collider_data.push((e, center, a.half));
     }
     let occupied_cells = grid.occupied_cells();
     let mut total_entries = 0usize;
@@ -199,16 +217,179 @@ pub fn sys_build_spatial_hash(
     };
 }
 
-#[allow(clippy::too_many_arguments)]
+/// Particle collision events raised per emitter per fixed step; further contacts that step stay silent.
+const MAX_PARTICLE_COLLISION_EVENTS_PER_EMITTER: u32 = 1;
+/// Share of tangential speed a bouncing particle loses on each contact, so settled particles stop sliding.
+const PARTICLE_CONTACT_FRICTION: f32 = 0.1;
+
+/// Resolves particles against the non-particle colliders in the spatial hash, treating each particle
+/// as a circle and each collider as a box rotated by its transform.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn sys_collide_particles(
+    mut profiler: ResMut<SystemProfiler>,
+    mut commands: Commands,
+    grid: Res<SpatialHash>,
+    caps: Res<ParticleCaps>,
+    particle_state: Res<ParticleState>,
+    params: Res<PhysicsParams>,
+    dt: Res<TimeDelta>,
+    mode: Res<SimulationMode>,
+    mut stats: ResMut<ParticleCollisionStats>,
+    mut events: ResMut<EventBus>,
+    mut particles: Query<
+        (Entity, &mut Transform, &mut Velocity, &mut ParticleCollision, &Aabb),
+        (With<Particle>, Without<RapierBody>),
+    >,
+    colliders: Query<(&Transform, &Aabb, Option<&ColliderShape>), (Without<Particle>, Without<RapierBody>)>,
+    mut emitted: Local<HashMap<Entity, u32>>,
+    mut order: Local<Vec<Entity>>,
+) {
+    let _span = profiler.scope("sys_collide_particles");
+    *stats = ParticleCollisionStats::default();
+    if particle_state.active_particles > caps.max_collision_particles {
+        stats.skipped = true;
+        return;
+    }
+    emitted.clear();
+    // Rebounds slower than a couple of steps of gravity are dropped so resting particles stop jittering.
+    let settle_speed = (params.gravity.length() * dt.0 * 2.0).max(0.01);
+    let mut checked: SmallVec<[Entity; 16]> = SmallVec::new();
+    // Which contact raises an emitter's capped event depends on visit order.
+    visit_order(&mode, particles.iter().map(|(entity, ..)| (entity, None)), &mut order);
+    for &entity in order.iter() {
+        let Ok((_, mut transform, mut velocity, mut collision, aabb)) = particles.get_mut(entity) else {
+            continue;
+        };
+        if let Some(anchor) = collision.stuck_at {
+            transform.translation = anchor;
+            velocity.0 = Vec2::ZERO;
+            continue;
+        }
+        if !collision.mode.is_enabled() {
+            continue;
+        }
+        let center = transform.translation;
+        let radius = aabb.half.min_element();
+        let (kx0, ky0) = grid.key(center - Vec2::splat(radius));
+        let (kx1, ky1) = grid.key(center + Vec2::splat(radius));
+        checked.clear();
+        let mut deepest: Option<(Vec2, f32)> = None;
+        for ky in ky0..=ky1 {
+            for kx in kx0..=kx1 {
+                let Some(list) = grid.grid.get(&(kx, ky)) else {
+                    continue;
+                };
+                for &other in list {
+                    if other == collision.emitter || checked.contains(&other) {
+                        continue;
+                    }
+                    checked.push(other);
+                    let Ok((other_transform, other_aabb, other_shape)) = colliders.get(other) else {
+                        continue;
+                    };
+                    stats.tests = stats.tests.saturating_add(1);
+                    if let Some((normal, depth)) = circle_box_contact(
+                        center,
+                        radius,
+                        other_transform.translation + ColliderShape::offset_of(other_shape),
+                        other_aabb.half,
+                        other_transform.rotation,
+                    ) {
+                        if deepest.is_none_or(|(_, best)| depth > best) {
+                            deepest = Some((normal, depth));
+                        }
+                    }
+                }
+            }
+        }
+        let Some((normal, depth)) = deepest else {
+            continue;
+        };
+        stats.hits = stats.hits.saturating_add(1);
+        let contact = center + normal * (depth - radius);
+        match collision.mode {
+            ParticleCollisionMode::None => {}
+            ParticleCollisionMode::Bounce { restitution } => {
+                transform.translation += normal * depth;
+                let normal_speed = velocity.0.dot(normal);
+                if normal_speed < 0.0 {
+                    let tangent = velocity.0 - normal * normal_speed;
+                    let rebound = -normal_speed * restitution;
+                    let rebound = if rebound > settle_speed { rebound } else { 0.0 };
+                    velocity.0 = tangent * (1.0 - PARTICLE_CONTACT_FRICTION) + normal * rebound;
+                }
+            }
+            ParticleCollisionMode::Kill => commands.entity(entity).despawn(),
+            ParticleCollisionMode::Stick => {
+                transform.translation += normal * depth;
+                velocity.0 = Vec2::ZERO;
+                collision.stuck_at = Some(transform.translation);
+            }
+        }
+        if collision.emit_events {
+            let raised = emitted.entry(collision.emitter).or_insert(0);
+            if *raised < MAX_PARTICLE_COLLISION_EVENTS_PER_EMITTER {
+                *raised += 1;
+                stats.events = stats.events.saturating_add(1);
+                events.push(GameEvent::ParticleCollision { emitter: collision.emitter, position: contact });
+            }
+        }
+    }
+}
+
+/// Overlap between a circle and a box rotated by `rotation` radians, as the unit normal pointing from
+/// the box towards the circle plus the penetration depth along it.
+fn circle_box_contact(
+    center: Vec2,
+    radius: f32,
+    box_center: Vec2,
+    half: Vec2,
+    rotation: f32,
+) -> Option<(Vec2, f32)> {
+    let local = Vec2::from_angle(-rotation).rotate(center - box_center);
+    let closest = local.clamp(-half, half);
+    let (normal, depth) = if closest != local {
+        let offset = local - closest;
+        let distance = offset.length();
+        if distance >= radius {
+            return None;
+        }
+        (offset / distance, radius - distance)
+    } else {
+        // Centre is inside the box: push out through the nearest face.
+        let gap = half - local.abs();
+        if gap.x < gap.y {
+            (Vec2::new(local.x.signum(), 0.0), gap.x + radius)
+        } else {
+            (Vec2::new(0.0, local.y.signum()), gap.y + radius)
+        }
+    };
+    Some((Vec2::from_angle(rotation).rotate(normal), depth))
+}
+
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn sys_collide_spatial(
     mut profiler: ResMut<SystemProfiler>,
     grid: Res<SpatialHash>,
     quadtree: Res<SpatialQuadtree>,
     metrics: Res<SpatialMetrics>,
-    mut movers: Query<(Entity, &Transform, &Aabb, &mut Velocity, Option<&Mass>), Without<RapierBody>>,
-    positions: Query<(&Transform, &Aabb, Option<&Mass>), Without<RapierBody>>,
+    mut movers: Query<
+        (
+            Entity,
+            &Transform,
+            &Aabb,
+            Option<&ColliderShape>,
+            &mut Velocity,
+            Option<&Mass>,
+            Option<&SceneEntityTag>,
+        ),
+        Without<RapierBody>,
+    >,
+    positions: ColliderPositions,
     mut events: ResMut<EventBus>,
     mut contacts: ResMut<ParticleContacts>,
+    mode: Res<SimulationMode>,
+    mut order: Local<Vec<Entity>>,
 ) {
     let _span = profiler.scope("sys_collide_spatial");
     let ParticleContacts { pairs: current_pairs, previous_pairs } = &mut *contacts;
@@ -217,18 +398,24 @@ pub fn sys_collide_spatial(
     let mut checked: SmallVec<[Entity; 16]> = SmallVec::new();
     let mut candidates: SmallVec<[Entity; 16]> = SmallVec::new();
     let neighbors = [(-1, -1), (0, -1), (1, -1), (-1, 0), (0, 0), (1, 0), (-1, 1), (0, 1), (1, 1)];
-    for (e, t, a, mut v, mass) in &mut movers {
+    // Contact events are pushed in visit order.
+    visit_order(&mode, movers.iter().map(|(e, _, _, _, _, _, tag)| (e, tag)), &mut order);
+    for &e in order.iter() {
+        let Ok((_, t, a, shape, mut v, mass, _)) = movers.get_mut(e) else {
+            continue;
+        };
+        let center = t.translation + ColliderShape::offset_of(shape);
         let mut impulse = Vec2::ZERO;
         checked.clear();
         let self_mass = mass.map(|m| m.0).unwrap_or(1.0);
         match metrics.mode {
             SpatialMode::Grid => {
-                let key = grid.key(t.translation);
+                let key = grid.key(center);
                 for (dx, dy) in neighbors {
                     if let Some(list) = grid.grid.get(&(key.0 + dx, key.1 + dy)) {
                         process_neighbors(
                             e,
-                            t.translation,
+                            center,
                             a.half,
                             list.iter().copied(),
                             &positions,
@@ -246,10 +433,10 @@ pub fn sys_collide_spatial(
                 if quadtree.node_count() == 0 {
                     continue;
                 }
-                quadtree.query(t.translation, a.half * 1.05, &mut candidates);
+                quadtree.query(center, a.half * 1.05, &mut candidates);
                 process_neighbors(
                     e,
-                    t.translation,
+                    center,
                     a.half,
                     candidates.iter().copied(),
                     &positions,
@@ -264,11 +451,22 @@ pub fn sys_collide_spatial(
         }
         v.0 += impulse;
     }
-    for pair in previous_pairs.drain() {
+    let mut ended: Vec<(Entity, Entity)> = previous_pairs.drain().collect();
+    if mode.deterministic {
+        ended.sort_unstable();
+    }
+    for pair in ended {
         events.push(GameEvent::collision_ended(pair.0, pair.1));
     }
 }
 
+type ColliderPositions<'w, 's> = Query<
+    'w,
+    's,
+    (&'static Transform, &'static Aabb, Option<&'static ColliderShape>, Option<&'static Mass>),
+    Without<RapierBody>,
+>;
+
 fn overlap(a_pos: Vec2, a_half: Vec2, b_pos: Vec2, b_half: Vec2) -> bool {
     (a_pos.x - b_pos.x).abs() < (a_half.x + b_half.x) && (a_pos.y - b_pos.y).abs() < (a_half.y + b_half.y)
 }
@@ -279,7 +477,7 @@ fn process_neighbors<I>(
     translation: Vec2,
     half: Vec2,
     neighbors: I,
-    positions: &Query<(&Transform, &Aabb, Option<&Mass>), Without<RapierBody>>,
+    positions: &ColliderPositions,
     checked: &mut SmallVec<[Entity; 16]>,
     self_mass: f32,
     impulse: &mut Vec2,
@@ -294,9 +492,10 @@ fn process_neighbors<I>(
             continue;
         }
         checked.push(other);
-        if let Ok((ot, oa, other_mass)) = positions.get(other) {
-            if overlap(translation, half, ot.translation, oa.half) {
-                let delta = translation - ot.translation;
+        if let Ok((ot, oa, other_shape, other_mass)) = positions.get(other) {
+            let other_center = ot.translation + ColliderShape::offset_of(other_shape);
+            if overlap(translation, half, other_center, oa.half) {
+                let delta = translation - other_center;
                 let overlap_x = half.x + oa.half.x - delta.x.abs();
                 let overlap_y = half.y + oa.half.y - delta.y.abs();
                 if overlap_x > 0.0 && overlap_y > 0.0 {
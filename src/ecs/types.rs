@@ -1,20 +1,27 @@
 #![allow(clippy::too_many_arguments, clippy::collapsible_if)]
 use crate::assets::{
     skeletal::{SkeletalClip, SkeletonAsset},
-    AnimationClip, ClipInterpolation, ClipKeyframe, ClipScalarTrack, ClipVec2Track, ClipVec4Track,
+    AnimationClip, AnimationGraphAsset, AnimationGraphBlend1D, ClipInterpolation, ClipKeyframe,
+    ClipScalarTrack, ClipVec2Track, ClipVec4Track, ColorCurve,
 };
+use crate::ecs::systems::SpriteTimelineUsageId;
 #[cfg(feature = "anim_stats")]
 use crate::ecs::systems::{record_transform_advance_time, record_transform_segment_crosses};
+use crate::ecs::PluginComponentView;
 use crate::scene::{MeshLightingData, SceneEntityId};
+use bevy_ecs::component::ComponentId;
 use bevy_ecs::prelude::*;
+use bevy_ecs::world::DeferredWorld;
 use glam::{Mat4, Quat, Vec2, Vec3, Vec4};
 use rapier2d::prelude::{ColliderHandle, RigidBodyHandle};
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use std::sync::Arc;
 #[cfg(feature = "anim_stats")]
 use std::time::Instant;
 
 const PLAYBACK_RATE_SMOOTH_ALPHA: f32 = 0.2;
+const SKELETAL_BLEND_WEIGHT_EPSILON: f32 = 1e-4;
 
 fn smoothed_playback_rate(current: f32, desired: f32) -> f32 {
     if desired == 0.0 || !desired.is_finite() {
@@ -69,7 +76,7 @@ pub struct WorldTransform(pub Mat4);
 pub struct WorldTransform3D(pub Mat4);
 #[derive(Component, Clone, Copy)]
 pub struct Parent(pub Entity);
-#[derive(Component, Default)]
+#[derive(Component, Clone, Default)]
 pub struct Children(pub Vec<Entity>);
 
 #[derive(Component, Clone)]
@@ -82,7 +89,42 @@ impl SceneEntityTag {
         Self { id }
     }
 }
-#[derive(Component)]
+
+/// Hands out sequential [`SceneEntityId`]s. The counter only moves forward, so ids of despawned
+/// entities are never handed out again; scene loads and exports carry it in `Scene::next_entity_id`.
+#[derive(Resource, Debug, Default)]
+pub struct SceneIdAllocator {
+    next: u64,
+}
+
+impl SceneIdAllocator {
+    pub fn starting_at(next: u64) -> Self {
+        Self { next }
+    }
+
+    pub fn allocate(&mut self) -> SceneEntityId {
+        let id = SceneEntityId::sequential(self.next);
+        self.next += 1;
+        id
+    }
+
+    /// Raises the counter so it never hands out a value below `floor`.
+    pub fn reserve(&mut self, floor: u64) {
+        self.next = self.next.max(floor);
+    }
+
+    pub fn next_value(&self) -> u64 {
+        self.next
+    }
+}
+
+/// Streamed chunks that reference this entity. It is despawned when the last one unloads, and
+/// excluded from master-scene exports.
+#[derive(Component, Clone, Debug, Default)]
+pub struct SceneChunkMember {
+    pub chunks: Vec<Arc<str>>,
+}
+#[derive(Component, Clone, Copy)]
 pub struct Spin {
     pub speed: f32,
 }
@@ -119,6 +161,128 @@ impl Sprite {
     }
 }
 
+/// Painter's-order key for sprites sharing an atlas; higher values draw on top.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ZOrder(pub i16);
+
+/// Tangent-space normal map the sprite shader uses to light the sprite from the scene's
+/// directional light. Holds the image path, which is also the key `AssetManager` loads it under;
+/// the renderer host resolves the texture view the same way it does for atlases. Each component
+/// holds one reference on that image: its hooks queue the retain and release in
+/// [`NormalMapRefQueue`], so despawns on any path and scene-loaded sprites are counted too.
+#[derive(Component, Clone, Debug, PartialEq, Eq)]
+#[component(on_insert = queue_normal_map_retain, on_replace = queue_normal_map_release)]
+pub struct NormalMap(pub Arc<str>);
+
+fn queue_normal_map_retain(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+    let Some(path) = world.get::<NormalMap>(entity).map(|normal_map| Arc::clone(&normal_map.0)) else {
+        return;
+    };
+    world.resource_mut::<NormalMapRefQueue>().retains.push(path);
+}
+
+fn queue_normal_map_release(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+    let Some(path) = world.get::<NormalMap>(entity).map(|normal_map| Arc::clone(&normal_map.0)) else {
+        return;
+    };
+    world.resource_mut::<NormalMapRefQueue>().releases.push(path);
+}
+
+/// Normal map references gained and dropped since the last `EcsWorld::sync_normal_map_refs`,
+/// maintained by the [`NormalMap`] hooks.
+#[derive(Resource, Default)]
+pub struct NormalMapRefQueue {
+    pub(crate) retains: Vec<Arc<str>>,
+    pub(crate) releases: Vec<Arc<str>>,
+}
+
+/// Afterimage of a fast-moving sprite: `collect_sprite_instances` records the sprite's transform each
+/// time it runs and draws faded copies at the last `length` transforms, behind the sprite and in the
+/// same atlas batch. The history lives on the component, so despawning the entity drops it.
+#[derive(Component, Clone, Debug)]
+pub struct SpriteTrail {
+    length: usize,
+    fade: f32,
+    history: VecDeque<Mat4>,
+    /// Copies drawn by the previous collection, so the batch is re-uploaded when the last one fades.
+    drawn: usize,
+}
+
+impl SpriteTrail {
+    /// Upper bound on `length`; every copy is a full sprite instance.
+    pub const MAX_LENGTH: usize = 16;
+    /// Copies drawn across all trails in one collection; trails past the budget draw only their sprite.
+    pub const MAX_COPIES_PER_FRAME: usize = 2_048;
+
+    /// `fade` scales the alpha once per step back along the trail and is clamped to `[0, 1]`.
+    pub fn new(length: usize, fade: f32) -> Self {
+        let length = length.min(Self::MAX_LENGTH);
+        let fade = if fade.is_finite() { fade.clamp(0.0, 1.0) } else { 0.5 };
+        Self { length, fade, history: VecDeque::with_capacity(length), drawn: 0 }
+    }
+
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    pub fn fade(&self) -> f32 {
+        self.fade
+    }
+
+    /// Recorded transforms, most recent first.
+    pub fn history(&self) -> impl Iterator<Item = &Mat4> {
+        self.history.iter()
+    }
+
+    /// Forgets the recorded transforms, e.g. after teleporting the sprite.
+    pub fn clear(&mut self) {
+        self.history.clear();
+    }
+
+    /// Faded copies of `instance` at the recorded transforms that differ from `model`, oldest first
+    /// so newer copies draw over older ones. Records `model` afterwards and returns whether the
+    /// drawn trail changed since the previous call.
+    pub(crate) fn emit_copies(
+        &mut self,
+        model: Mat4,
+        instance: &SpriteInstance,
+        budget: &mut usize,
+        out: &mut Vec<SpriteInstance>,
+    ) -> bool {
+        let mut drawn = 0;
+        for (steps_back, past) in self.history.iter().enumerate().rev() {
+            if *budget == 0 {
+                break;
+            }
+            let alpha = instance.tint[3] * self.fade.powi(steps_back as i32 + 1);
+            if *past == model || alpha <= 0.0 {
+                continue;
+            }
+            let transform = SpriteInstanceTransform::from_mat4(*past);
+            let mut tint = instance.tint;
+            tint[3] = alpha;
+            out.push(SpriteInstance {
+                atlas: Arc::clone(&instance.atlas),
+                transform,
+                world_half_extent: transform.half_extent_2d(),
+                tint,
+                static_sprite: false,
+                normal_map: instance.normal_map.clone(),
+                ..*instance
+            });
+            *budget -= 1;
+            drawn += 1;
+        }
+        if self.length > 0 {
+            self.history.truncate(self.length - 1);
+            self.history.push_front(model);
+        }
+        let changed = drawn > 0 || self.drawn > 0;
+        self.drawn = drawn;
+        changed
+    }
+}
+
 #[derive(Component, Clone)]
 pub struct SpriteFrameState {
     pub region_id: u16,
@@ -212,6 +376,11 @@ pub struct SpriteAnimation {
     pub fast_loop: bool,
     pub pending_start_events: bool,
     pub prev_forward: bool,
+    /// Cycles a looping timeline plays before holding its final frame; `None` loops forever.
+    pub loop_count: Option<u32>,
+    pub loops_completed: u32,
+    /// Slot in `SpriteTimelineUsage`, assigned with the timeline; `None` leaves the animator uncounted.
+    pub usage_id: Option<SpriteTimelineUsageId>,
 }
 
 /// Marker used to route animators through the fast-path update loop.
@@ -260,6 +429,9 @@ impl SpriteAnimation {
             fast_loop,
             pending_start_events: false,
             prev_forward: true,
+            loop_count: None,
+            loops_completed: 0,
+            usage_id: None,
         };
         animation.refresh_pending_start_events();
         animation
@@ -270,10 +442,29 @@ impl SpriteAnimation {
         self.looped = mode.looped();
         self.forward = true;
         self.prev_forward = true;
-        self.fast_loop = !self.has_events && matches!(self.mode, SpriteAnimationLoopMode::Loop);
+        self.refresh_fast_loop();
         self.refresh_pending_start_events();
     }
 
+    /// Limits a looping timeline to `count` cycles (at least one); counted loops run on the
+    /// general path so the final cycle can stop and report completion.
+    pub fn set_loop_count(&mut self, count: Option<u32>) {
+        self.loop_count = count.map(|count| count.max(1));
+        self.loops_completed = 0;
+        self.refresh_fast_loop();
+    }
+
+    /// True once a counted loop has played all of its cycles.
+    pub fn loop_limit_reached(&self) -> bool {
+        self.loop_count.is_some_and(|count| self.loops_completed >= count)
+    }
+
+    pub fn refresh_fast_loop(&mut self) {
+        self.fast_loop = !self.has_events
+            && self.loop_count.is_none()
+            && matches!(self.mode, SpriteAnimationLoopMode::Loop);
+    }
+
     pub fn set_start_offset(&mut self, offset: f32) {
         self.start_offset = offset.max(0.0);
     }
@@ -472,8 +663,12 @@ pub struct ClipInstance {
     pub time: f32,
     pub playing: bool,
     pub looped: bool,
+    /// Cycles a looping clip plays before holding its end pose; `None` loops forever.
+    pub loop_count: Option<u32>,
+    pub loops_completed: u32,
     pub speed: f32,
     pub group: Option<String>,
+    pub events: Vec<(f32, String)>,
     pub playback_rate: f32,
     pub playback_rate_dirty: bool,
     pub last_translation: Option<Vec2>,
@@ -532,8 +727,11 @@ impl ClipInstance {
             time: 0.0,
             playing: true,
             looped,
+            loop_count: None,
+            loops_completed: 0,
             speed: 1.0,
             group: None,
+            events: Vec::new(),
             playback_rate: 0.0,
             playback_rate_dirty: true,
             last_translation: None,
@@ -595,6 +793,7 @@ impl ClipInstance {
         self.looped = self.clip.looped;
         self.time = 0.0;
         self.playing = true;
+        self.loops_completed = 0;
         self.speed = previous_speed;
         self.group = previous_group;
         self.playback_rate = 0.0;
@@ -630,9 +829,16 @@ impl ClipInstance {
         self.playing = playing;
     }
 
+    /// Limits a looping clip to `count` cycles (at least one) and restarts the count.
+    pub fn set_loop_count(&mut self, count: Option<u32>) {
+        self.loop_count = count.map(|count| count.max(1));
+        self.loops_completed = 0;
+    }
+
     pub fn reset(&mut self) {
         self.time = 0.0;
         self.playing = true;
+        self.loops_completed = 0;
         self.last_translation = None;
         self.last_rotation = None;
         self.last_scale = None;
@@ -674,6 +880,42 @@ impl ClipInstance {
         self.playback_rate_dirty = true;
     }
 
+    /// Replaces the clip event markers, dropping non-finite timestamps and keeping them sorted by time.
+    pub fn set_events(&mut self, events: Vec<(f32, String)>) {
+        let mut events: Vec<(f32, String)> =
+            events.into_iter().filter(|(time, _)| time.is_finite()).collect();
+        events.sort_by(|a, b| a.0.total_cmp(&b.0));
+        self.events = events;
+    }
+
+    /// Visits every event whose timestamp was crossed while the playhead moved forward from
+    /// `previous_time` to the current time. A current time earlier than `previous_time` is treated as a
+    /// loop wrap; callers must not invoke this for backwards playback.
+    pub fn for_each_crossed_event<F: FnMut(&str)>(&self, previous_time: f32, mut visit: F) {
+        if self.events.is_empty() {
+            return;
+        }
+        let current_time = self.time;
+        if current_time >= previous_time {
+            for (time, name) in &self.events {
+                if *time > previous_time && *time <= current_time {
+                    visit(name);
+                }
+            }
+        } else if self.looped {
+            for (time, name) in &self.events {
+                if *time > previous_time {
+                    visit(name);
+                }
+            }
+            for (time, name) in &self.events {
+                if *time <= current_time {
+                    visit(name);
+                }
+            }
+        }
+    }
+
     #[inline(always)]
     fn clear_current_sample(&mut self) {
         self.current_sample = ClipSample::default();
@@ -732,6 +974,16 @@ impl ClipInstance {
             if next >= 0.0 && next < (duration - CLIP_TIME_EPSILON) {
                 self.time = next;
                 delta
+            } else if self.consume_loops(next, duration) {
+                // Counted loops are exhausted: hold the end pose like a one-shot clip.
+                let applied = (duration - self.time).max(0.0);
+                self.time = duration;
+                self.playing = false;
+                self.reset_cursors();
+                self.rebuild_track_cursors();
+                let reset_mask = ClipChannelMask::all().intersect(self.clip_channels);
+                self.advance_track_states(0.0, reset_mask);
+                return applied;
             } else {
                 let duration_inv = self.clip.duration_inv;
                 let mut wrapped = wrap_time_looped(next, duration, duration_inv);
@@ -765,6 +1017,22 @@ impl ClipInstance {
         applied
     }
 
+    /// Counts the loop boundaries crossed on the way to `next` and reports whether the final
+    /// counted cycle has now finished.
+    fn consume_loops(&mut self, next: f32, duration: f32) -> bool {
+        let Some(count) = self.loop_count else {
+            return false;
+        };
+        let wraps = ((next + CLIP_TIME_EPSILON) / duration).floor().max(1.0) as u32;
+        self.loops_completed = self.loops_completed.saturating_add(wraps);
+        if self.loops_completed >= count {
+            self.loops_completed = count;
+            true
+        } else {
+            false
+        }
+    }
+
     pub fn duration(&self) -> f32 {
         self.clip.duration.max(0.0)
     }
@@ -1543,7 +1811,8 @@ impl ClipInstance {
                 start
             }
         } else if span > 0.0 {
-            start + slope * offset.clamp(0.0, span)
+            let offset = offset.clamp(0.0, span);
+            curve_segment_value(track.keyframes.as_ref(), index, offset).unwrap_or(start + slope * offset)
         } else {
             start
         }
@@ -1565,7 +1834,8 @@ impl ClipInstance {
                 start
             }
         } else if span > 0.0 {
-            start + slope * offset.clamp(0.0, span)
+            let offset = offset.clamp(0.0, span);
+            curve_segment_value(track.keyframes.as_ref(), index, offset).unwrap_or(start + slope * offset)
         } else {
             start
         }
@@ -1587,7 +1857,8 @@ impl ClipInstance {
                 start
             }
         } else if span > 0.0 {
-            start + slope * offset.clamp(0.0, span)
+            let offset = offset.clamp(0.0, span);
+            curve_segment_value(track.keyframes.as_ref(), index, offset).unwrap_or(start + slope * offset)
         } else {
             start
         }
@@ -1689,6 +1960,8 @@ impl ClipInstance {
                         );
                         if result.advanced {
                             let mut kept_clean = false;
+                            // Curves are resampled rather than stepped along the slope.
+                            let curved = segment_is_curved(track.keyframes.as_ref(), self.translation_cursor);
                             if sample_was_clean && !result.segment_changed {
                                 if let Some(value) = self.current_sample.translation.as_mut() {
                                     if matches!(track.interpolation, ClipInterpolation::Step) {
@@ -1701,7 +1974,7 @@ impl ClipInstance {
                                     }
                                 }
                             }
-                            self.translation_sample_dirty = !kept_clean;
+                            self.translation_sample_dirty = !kept_clean || curved;
                         }
                     }
                 } else if delta > 0.0 {
@@ -1752,6 +2025,8 @@ impl ClipInstance {
                         );
                         if result.advanced {
                             let mut kept_clean = false;
+                            // Curves are resampled rather than stepped along the slope.
+                            let curved = segment_is_curved(track.keyframes.as_ref(), self.rotation_cursor);
                             if sample_was_clean && !result.segment_changed {
                                 if let Some(value) = self.current_sample.rotation.as_mut() {
                                     if matches!(track.interpolation, ClipInterpolation::Step) {
@@ -1764,7 +2039,7 @@ impl ClipInstance {
                                     }
                                 }
                             }
-                            self.rotation_sample_dirty = !kept_clean;
+                            self.rotation_sample_dirty = !kept_clean || curved;
                         }
                     }
                 } else if delta > 0.0 {
@@ -1815,6 +2090,8 @@ impl ClipInstance {
                         );
                         if result.advanced {
                             let mut kept_clean = false;
+                            // Curves are resampled rather than stepped along the slope.
+                            let curved = segment_is_curved(track.keyframes.as_ref(), self.scale_cursor);
                             if sample_was_clean && !result.segment_changed {
                                 if let Some(value) = self.current_sample.scale.as_mut() {
                                     if matches!(track.interpolation, ClipInterpolation::Step) {
@@ -1827,7 +2104,7 @@ impl ClipInstance {
                                     }
                                 }
                             }
-                            self.scale_sample_dirty = !kept_clean;
+                            self.scale_sample_dirty = !kept_clean || curved;
                         }
                     }
                 } else if delta > 0.0 {
@@ -1878,6 +2155,8 @@ impl ClipInstance {
                         );
                         if result.advanced {
                             let mut kept_clean = false;
+                            // Curves are resampled rather than stepped along the slope.
+                            let curved = segment_is_curved(track.keyframes.as_ref(), self.tint_cursor);
                             if sample_was_clean && !result.segment_changed {
                                 if let Some(value) = self.current_sample.tint.as_mut() {
                                     if matches!(track.interpolation, ClipInterpolation::Step) {
@@ -1890,7 +2169,7 @@ impl ClipInstance {
                                     }
                                 }
                             }
-                            self.tint_sample_dirty = !kept_clean;
+                            self.tint_sample_dirty = !kept_clean || curved;
                         }
                     }
                 } else if delta > 0.0 {
@@ -2055,6 +2334,10 @@ impl ClipInstance {
         if matches!(track.interpolation, ClipInterpolation::Step) {
             return;
         }
+        if segment_is_curved(track.keyframes.as_ref(), self.translation_cursor) {
+            self.translation_sample_dirty = true;
+            return;
+        }
         if let Some(value) = self.current_sample.translation.as_mut() {
             *value += self.translation_segment_slope * delta;
         } else {
@@ -2073,6 +2356,10 @@ impl ClipInstance {
         if matches!(track.interpolation, ClipInterpolation::Step) {
             return;
         }
+        if segment_is_curved(track.keyframes.as_ref(), self.rotation_cursor) {
+            self.rotation_sample_dirty = true;
+            return;
+        }
         if let Some(value) = self.current_sample.rotation.as_mut() {
             *value += self.rotation_segment_slope * delta;
         } else {
@@ -2091,6 +2378,10 @@ impl ClipInstance {
         if matches!(track.interpolation, ClipInterpolation::Step) {
             return;
         }
+        if segment_is_curved(track.keyframes.as_ref(), self.scale_cursor) {
+            self.scale_sample_dirty = true;
+            return;
+        }
         if let Some(value) = self.current_sample.scale.as_mut() {
             *value += self.scale_segment_slope * delta;
         } else {
@@ -2109,6 +2400,10 @@ impl ClipInstance {
         if matches!(track.interpolation, ClipInterpolation::Step) {
             return;
         }
+        if segment_is_curved(track.keyframes.as_ref(), self.tint_cursor) {
+            self.tint_sample_dirty = true;
+            return;
+        }
         if let Some(value) = self.current_sample.tint.as_mut() {
             *value += self.tint_segment_slope * delta;
         } else {
@@ -2319,6 +2614,65 @@ impl PropertyTrackPlayer {
     }
 }
 
+/// Drives [`Tint`] from a shared [`ColorCurve`] stretched over `duration` seconds. Runs after
+/// transform clips, so it wins over a clip tint track on the same entity.
+#[derive(Component, Clone)]
+pub struct TintCurve {
+    pub curve_key: Arc<str>,
+    pub curve: Arc<ColorCurve>,
+    pub duration: f32,
+    pub loop_mode: SpriteAnimationLoopMode,
+    pub elapsed: f32,
+}
+
+impl TintCurve {
+    pub fn new(
+        curve_key: Arc<str>,
+        curve: Arc<ColorCurve>,
+        duration: f32,
+        loop_mode: SpriteAnimationLoopMode,
+    ) -> Self {
+        Self { curve_key, curve, duration: duration.max(f32::EPSILON), loop_mode, elapsed: 0.0 }
+    }
+
+    /// Advances playback, wrapping looped modes so `elapsed` stays bounded.
+    pub fn advance(&mut self, delta: f32) {
+        let elapsed = self.elapsed + delta;
+        self.elapsed = match self.loop_mode {
+            SpriteAnimationLoopMode::Loop => elapsed.rem_euclid(self.duration),
+            SpriteAnimationLoopMode::PingPong => elapsed.rem_euclid(self.duration * 2.0),
+            SpriteAnimationLoopMode::OnceHold | SpriteAnimationLoopMode::OnceStop => {
+                elapsed.clamp(0.0, self.duration)
+            }
+        };
+    }
+
+    /// Normalized position on the curve for the current elapsed time.
+    pub fn progress(&self) -> f32 {
+        let t = self.elapsed / self.duration;
+        match self.loop_mode {
+            SpriteAnimationLoopMode::Loop => t.rem_euclid(1.0),
+            SpriteAnimationLoopMode::PingPong => {
+                let phase = t.rem_euclid(2.0);
+                if phase > 1.0 {
+                    2.0 - phase
+                } else {
+                    phase
+                }
+            }
+            SpriteAnimationLoopMode::OnceHold | SpriteAnimationLoopMode::OnceStop => t.clamp(0.0, 1.0),
+        }
+    }
+
+    pub fn finished(&self) -> bool {
+        !self.loop_mode.looped() && self.elapsed >= self.duration
+    }
+
+    pub fn sample(&self) -> Vec4 {
+        self.curve.sample(self.progress())
+    }
+}
+
 #[inline(always)]
 fn sample_vec2_track(track: &ClipVec2Track, time: f32, looped: bool) -> Option<Vec2> {
     let frames = track.keyframes.as_ref();
@@ -2374,7 +2728,7 @@ fn sample_vec2_track_from_state(track: &ClipVec2Track, cursor: usize, segment_ti
     } else if let Some(seg) = segment {
         let span = seg.span.max(0.0);
         let t = if span > 0.0 { segment_time.clamp(0.0, span) } else { 0.0 };
-        Some(start.value + seg.slope * t)
+        Some(start.curve_to(end, t).unwrap_or(start.value + seg.slope * t))
     } else {
         Some(start.value)
     }
@@ -2405,7 +2759,7 @@ fn sample_scalar_track_from_state(track: &ClipScalarTrack, cursor: usize, segmen
     } else if let Some(seg) = segment {
         let span = seg.span.max(0.0);
         let t = if span > 0.0 { segment_time.clamp(0.0, span) } else { 0.0 };
-        Some(start.value + seg.slope * t)
+        Some(start.curve_to(end, t).unwrap_or(start.value + seg.slope * t))
     } else {
         Some(start.value)
     }
@@ -2436,7 +2790,7 @@ fn sample_vec4_track_from_state(track: &ClipVec4Track, cursor: usize, segment_ti
     } else if let Some(seg) = segment {
         let span = seg.span.max(0.0);
         let t = if span > 0.0 { segment_time.clamp(0.0, span) } else { 0.0 };
-        Some(start.value + seg.slope * t)
+        Some(start.curve_to(end, t).unwrap_or(start.value + seg.slope * t))
     } else {
         Some(start.value)
     }
@@ -2626,6 +2980,7 @@ fn sample_keyframes<T, L>(
 ) -> T
 where
     T: Copy,
+    T: std::ops::Add<Output = T> + std::ops::Sub<Output = T> + std::ops::Mul<f32, Output = T>,
     L: Fn(T, T, f32) -> T,
 {
     if frames.len() == 1 || time <= frames[0].time {
@@ -2643,6 +2998,9 @@ where
         let start = &window[0];
         let end = &window[1];
         if time <= end.time {
+            if let Some(value) = start.curve_to(end, time - start.time) {
+                return value;
+            }
             let span = (end.time - start.time).max(f32::EPSILON);
             let alpha = ((time - start.time) / span).clamp(0.0, 1.0);
             return lerp(start.value, end.value, alpha);
@@ -2651,6 +3009,24 @@ where
     frames.last().unwrap().value
 }
 
+/// Value `offset` seconds into segment `index` when its keys define tangents; `None` for straight
+/// segments, which callers evaluate from the cached slope.
+#[inline(always)]
+fn curve_segment_value<T>(frames: &[ClipKeyframe<T>], index: usize, offset: f32) -> Option<T>
+where
+    T: Copy + std::ops::Add<Output = T> + std::ops::Sub<Output = T> + std::ops::Mul<f32, Output = T>,
+{
+    frames.get(index)?.curve_to(frames.get(index + 1)?, offset)
+}
+
+#[inline(always)]
+fn segment_is_curved<T>(frames: &[ClipKeyframe<T>], index: usize) -> bool {
+    match (frames.get(index), frames.get(index + 1)) {
+        (Some(start), Some(end)) => start.out_tangent.is_some() || end.in_tangent.is_some(),
+        _ => false,
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum SpriteAnimationLoopMode {
     Loop,
@@ -2750,7 +3126,7 @@ mod tests {
     fn linear_rotation_clip_cached_sample_stays_in_sync() {
         fn rotation_clip() -> Arc<AnimationClip> {
             let keyframes: Arc<[ClipKeyframe<f32>]> = Arc::from(
-                vec![ClipKeyframe { time: 0.0, value: 0.0 }, ClipKeyframe { time: 0.5, value: TAU }]
+                vec![ClipKeyframe::new(0.0, 0.0), ClipKeyframe::new(0.5, TAU)]
                     .into_boxed_slice(),
             );
             let span = (keyframes[1].time - keyframes[0].time).max(f32::EPSILON);
@@ -2767,6 +3143,7 @@ mod tests {
                 translation: None,
                 rotation: Some(ClipScalarTrack {
                     interpolation: ClipInterpolation::Linear,
+                    auto_tangent: false,
                     keyframes,
                     duration: span,
                     duration_inv: inv_span,
@@ -2810,6 +3187,8 @@ pub struct MeshRef {
 pub struct MeshSurface {
     pub material: Option<String>,
     pub lighting: MeshLighting,
+    /// Camera distance beyond which the mesh is culled; `None` draws it at any range.
+    pub max_draw_distance: Option<f32>,
 }
 #[derive(Clone)]
 pub struct MeshLighting {
@@ -2853,6 +3232,10 @@ pub struct SkeletonInstance {
     pub time: f32,
     pub playing: bool,
     pub looped: bool,
+    /// Cycles a looping clip plays before holding its final pose; `None` loops forever.
+    /// Blended graph playback ignores it.
+    pub loop_count: Option<u32>,
+    pub loops_completed: u32,
     pub speed: f32,
     pub group: Option<String>,
     pub playback_rate: f32,
@@ -2863,9 +3246,21 @@ pub struct SkeletonInstance {
     pub joint_channel_map: Vec<Option<usize>>,
     pub joint_children: Vec<Vec<usize>>,
     pub joint_visited: Vec<bool>,
+    /// Weighted clips posed together instead of `active_clip` alone; written by animation graphs.
+    pub blend_layers: Vec<SkeletalBlendLayer>,
+    /// Normalised playback position shared by every blend layer.
+    pub blend_phase: f32,
     pub dirty: bool,
 }
 
+/// One clip contributing to a blended skeleton pose.
+#[derive(Clone)]
+pub struct SkeletalBlendLayer {
+    pub clip_key: Arc<str>,
+    pub clip: Arc<SkeletalClip>,
+    pub weight: f32,
+}
+
 impl SkeletonInstance {
     pub fn new(skeleton_key: Arc<str>, skeleton: Arc<SkeletonAsset>) -> Self {
         let joint_count = skeleton.joints.len();
@@ -2894,6 +3289,8 @@ impl SkeletonInstance {
             time: 0.0,
             playing: true,
             looped: true,
+            loop_count: None,
+            loops_completed: 0,
             speed: 1.0,
             group: None,
             playback_rate: 0.0,
@@ -2904,6 +3301,8 @@ impl SkeletonInstance {
             joint_channel_map,
             joint_children,
             joint_visited,
+            blend_layers: Vec::new(),
+            blend_phase: 0.0,
             dirty: false,
         }
     }
@@ -2914,6 +3313,8 @@ impl SkeletonInstance {
     }
 
     pub fn set_active_clip(&mut self, clip_key: Option<Arc<str>>, clip: Option<Arc<SkeletalClip>>) {
+        self.blend_layers.clear();
+        self.blend_phase = 0.0;
         self.active_clip_key = clip_key;
         if let Some(ref clip) = clip {
             self.looped = clip.looped;
@@ -2921,11 +3322,62 @@ impl SkeletonInstance {
         self.active_clip = clip;
         self.time = 0.0;
         self.playing = true;
+        self.loops_completed = 0;
         self.playback_rate = 0.0;
         self.playback_rate_dirty = true;
         self.dirty = true;
     }
 
+    /// Poses the skeleton from weighted clips. The heaviest layer becomes the active clip so
+    /// playback state and inspectors keep reporting a single clip; the shared phase is kept.
+    pub fn set_blend_layers(&mut self, layers: Vec<SkeletalBlendLayer>) {
+        let unchanged = layers.len() == self.blend_layers.len()
+            && layers
+                .iter()
+                .zip(&self.blend_layers)
+                .all(|(a, b)| a.clip_key == b.clip_key && a.weight == b.weight);
+        if unchanged {
+            return;
+        }
+        let dominant = layers.iter().max_by(|a, b| a.weight.total_cmp(&b.weight));
+        if let Some(layer) = dominant {
+            if self.active_clip_key.as_ref() != Some(&layer.clip_key) {
+                self.active_clip_key = Some(Arc::clone(&layer.clip_key));
+                self.active_clip = Some(Arc::clone(&layer.clip));
+                self.looped = layer.clip.looped;
+            }
+        }
+        self.blend_layers = layers;
+        self.time = self.blend_phase * self.clip_duration();
+        self.dirty = true;
+    }
+
+    /// Restarts blended playback from the beginning of every layer.
+    pub fn restart_blend(&mut self) {
+        self.blend_phase = 0.0;
+        self.time = 0.0;
+        self.playing = true;
+        self.dirty = true;
+    }
+
+    /// Advances the shared blend phase by `delta` seconds of the weight-averaged layer duration.
+    pub fn advance_blend_phase(&mut self, delta: f32) {
+        let duration: f32 =
+            self.blend_layers.iter().map(|layer| layer.weight * layer.clip.duration.max(0.0)).sum();
+        if duration <= 0.0 {
+            self.blend_phase = 0.0;
+        } else if self.looped {
+            self.blend_phase = (self.blend_phase + delta / duration).rem_euclid(1.0);
+        } else {
+            self.blend_phase = (self.blend_phase + delta / duration).clamp(0.0, 1.0);
+            if self.blend_phase >= 1.0 {
+                self.playing = false;
+            }
+        }
+        self.time = self.blend_phase * self.clip_duration();
+        self.dirty = true;
+    }
+
     pub fn set_playing(&mut self, playing: bool) {
         self.playing = playing;
     }
@@ -2940,6 +3392,34 @@ impl SkeletonInstance {
         self.playback_rate_dirty = true;
     }
 
+    /// Limits a looping clip to `count` cycles (at least one) and restarts the count.
+    pub fn set_loop_count(&mut self, count: Option<u32>) {
+        self.loop_count = count.map(|count| count.max(1));
+        self.loops_completed = 0;
+    }
+
+    /// Moves playback forward by `delta` seconds, stopping on the final pose once the counted
+    /// loops have played.
+    pub fn advance_time(&mut self, delta: f32) -> f32 {
+        let duration = self.clip_duration();
+        let next = self.time + delta;
+        if let Some(count) = self.loop_count.filter(|_| self.looped && duration > 0.0 && delta > 0.0) {
+            if next >= duration - CLIP_TIME_EPSILON {
+                let wraps = ((next + CLIP_TIME_EPSILON) / duration).floor().max(1.0) as u32;
+                self.loops_completed = self.loops_completed.saturating_add(wraps);
+                if self.loops_completed >= count {
+                    self.loops_completed = count;
+                    self.playing = false;
+                    self.time = duration;
+                    self.blend_phase = 1.0;
+                    self.dirty = true;
+                    return duration;
+                }
+            }
+        }
+        self.set_time(next)
+    }
+
     pub fn set_time(&mut self, time: f32) -> f32 {
         let mut clamped = time;
         if let Some(clip) = self.active_clip.as_ref() {
@@ -2962,6 +3442,8 @@ impl SkeletonInstance {
             clamped = 0.0;
         }
         self.time = clamped;
+        let duration = self.clip_duration();
+        self.blend_phase = if duration > 0.0 { clamped / duration } else { 0.0 };
         self.dirty = true;
         clamped
     }
@@ -3097,12 +3579,63 @@ impl SkinMesh {
         self.joint_count as usize
     }
 }
+/// Counts `seconds` down and despawns the entity once they run out. Ticks with the frame delta, or
+/// on the fixed step in deterministic mode.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct Lifetime {
+    pub seconds: f32,
+}
+/// Despawns the entity once it leaves the world bounds, widened to cover the camera view set with
+/// `EcsWorld::set_despawn_view`, grown by `margin` on every side.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct DespawnOutOfBounds {
+    pub margin: f32,
+}
+/// Scripted sprite whose physics body is parked for reuse when it is despawned; see
+/// `EcsWorld::spawn_scripted_sprite`.
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct PooledSprite;
 #[derive(Component, Clone, Copy)]
 pub struct Velocity(pub Vec2);
 #[derive(Component, Clone, Copy)]
 pub struct Aabb {
     pub half: Vec2,
 }
+/// Smallest collider half extent (or radius) the collider setters accept.
+pub const MIN_COLLIDER_HALF_EXTENT: f32 = 0.01;
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColliderKind {
+    #[default]
+    Rect,
+    /// Uses `Aabb::half.x` as the radius; the `Aabb` stays square so broad-phase bounds still fit.
+    Circle,
+}
+/// Collider geometry authored independently of the entity scale. Once present, `set_scale` no longer
+/// resizes the entity's `Aabb` and the collider is centred `offset` away from the entity origin.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+pub struct ColliderShape {
+    pub kind: ColliderKind,
+    pub offset: Vec2,
+}
+
+impl ColliderShape {
+    pub fn offset_of(shape: Option<&ColliderShape>) -> Vec2 {
+        shape.map_or(Vec2::ZERO, |shape| shape.offset)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ColliderGeometry {
+    pub kind: ColliderKind,
+    pub half: Vec2,
+    pub offset: Vec2,
+}
+
+impl ColliderGeometry {
+    pub fn radius(&self) -> f32 {
+        self.half.x
+    }
+}
 #[derive(Component, Clone, Copy)]
 pub struct Tint(pub Vec4);
 #[derive(Component, Clone, Copy, Default)]
@@ -3173,7 +3706,100 @@ impl Default for ParticleTrail {
     }
 }
 
-#[derive(Component)]
+/// Region particles spawn from, in the emitter's local frame where +Y is the facing direction.
+///
+/// Cone, box and line shapes launch particles along the facing direction jittered by the
+/// emitter spread; point, circle edge and disc shapes launch them radially outward.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum EmitterShape {
+    Point,
+    #[default]
+    Cone,
+    CircleEdge {
+        radius: f32,
+    },
+    Disc {
+        radius: f32,
+    },
+    Box {
+        half_width: f32,
+        half_height: f32,
+    },
+    /// Segment of `length` centred on the emitter, perpendicular to its facing direction.
+    Line {
+        length: f32,
+    },
+}
+
+impl EmitterShape {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Point => "Point",
+            Self::Cone => "Cone",
+            Self::CircleEdge { .. } => "Circle edge",
+            Self::Disc { .. } => "Disc",
+            Self::Box { .. } => "Box",
+            Self::Line { .. } => "Line",
+        }
+    }
+
+    /// Copy with negative or non-finite extents clamped to zero.
+    pub fn sanitized(self) -> Self {
+        let extent = |value: f32| if value.is_finite() { value.max(0.0) } else { 0.0 };
+        match self {
+            Self::CircleEdge { radius } => Self::CircleEdge { radius: extent(radius) },
+            Self::Disc { radius } => Self::Disc { radius: extent(radius) },
+            Self::Box { half_width, half_height } => {
+                Self::Box { half_width: extent(half_width), half_height: extent(half_height) }
+            }
+            Self::Line { length } => Self::Line { length: extent(length) },
+            other => other,
+        }
+    }
+}
+
+/// How particles react to non-particle colliders found in the spatial hash.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ParticleCollisionMode {
+    #[default]
+    None,
+    /// Reflects off the collider keeping `restitution` of the impact speed; slow impacts settle.
+    Bounce {
+        restitution: f32,
+    },
+    Kill,
+    /// Pins the particle to the contact point for the rest of its lifetime.
+    Stick,
+}
+
+impl ParticleCollisionMode {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::Bounce { .. } => "Bounce",
+            Self::Kill => "Kill",
+            Self::Stick => "Stick",
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        !matches!(self, Self::None)
+    }
+
+    /// Copy with restitution clamped to `[0, 1]`.
+    pub fn sanitized(self) -> Self {
+        match self {
+            Self::Bounce { restitution } => Self::Bounce {
+                restitution: if restitution.is_finite() { restitution.clamp(0.0, 1.0) } else { 0.5 },
+            },
+            other => other,
+        }
+    }
+}
+
+#[derive(Component, Clone)]
 pub struct ParticleEmitter {
     pub rate: f32,
     pub spread: f32,
@@ -3188,13 +3814,51 @@ pub struct ParticleEmitter {
     pub region: Arc<str>,
     pub source: Option<Arc<str>>,
     pub trail: Option<ParticleTrail>,
+    pub shape: EmitterShape,
+    /// One-shot particles still owed from `EcsWorld::emitter_burst`, drained within the
+    /// per-frame spawn budget ahead of the continuous rate.
+    pub pending_burst: u32,
+    pub collision_mode: ParticleCollisionMode,
+    /// Raise throttled `GameEvent::ParticleCollision` events when this emitter's particles hit colliders.
+    pub collision_events: bool,
 }
-#[derive(Component)]
+/// One-shot burst set by `EcsWorld::set_emitter_burst`. The next fixed step spawns all `remaining`
+/// particles at once, launched at `impulse_speed` instead of the emitter speed, and removes the
+/// component; whatever the particle caps leave no room for is dropped rather than carried over.
+/// Bursts requested again before that step land in `queued` and keep their own speed.
+#[derive(Component, Clone, Debug, PartialEq)]
+pub struct BurstEmitter {
+    pub remaining: u32,
+    pub impulse_speed: f32,
+    pub queued: Vec<(u32, f32)>,
+}
+
+impl BurstEmitter {
+    pub fn new(remaining: u32, impulse_speed: f32) -> Self {
+        Self { remaining, impulse_speed, queued: Vec::new() }
+    }
+
+    /// Every pending request as `(count, impulse_speed)`, oldest first.
+    pub fn requests(&self) -> impl Iterator<Item = (u32, f32)> + '_ {
+        std::iter::once((self.remaining, self.impulse_speed)).chain(self.queued.iter().copied())
+    }
+}
+#[derive(Component, Clone, Copy)]
 pub struct Particle {
     pub lifetime: f32,
     pub max_lifetime: f32,
 }
-#[derive(Component)]
+/// Collision settings copied from the spawning emitter onto each particle.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct ParticleCollision {
+    pub emitter: Entity,
+    pub mode: ParticleCollisionMode,
+    pub emit_events: bool,
+    /// Contact point a `Stick` particle is pinned to once it has hit something.
+    pub stuck_at: Option<Vec2>,
+}
+
+#[derive(Component, Clone, Copy)]
 pub struct ParticleVisual {
     pub start_color: Vec4,
     pub end_color: Vec4,
@@ -3207,19 +3871,170 @@ pub struct ParticleCaps {
     pub max_spawn_per_frame: u32,
     pub max_total: u32,
     pub max_emitter_backlog: f32,
+    /// Particle collision checks are skipped entirely while more particles than this are alive.
+    pub max_collision_particles: u32,
 }
 
 impl Default for ParticleCaps {
     fn default() -> Self {
-        Self { max_spawn_per_frame: 256, max_total: 2_000, max_emitter_backlog: 64.0 }
+        Self {
+            max_spawn_per_frame: 256,
+            max_total: 2_000,
+            max_emitter_backlog: 64.0,
+            max_collision_particles: Self::DEFAULT_MAX_COLLISION_PARTICLES,
+        }
     }
 }
 
 impl ParticleCaps {
+    pub const DEFAULT_MAX_COLLISION_PARTICLES: u32 = 1_000;
+
     pub fn new(max_spawn_per_frame: u32, max_total: u32, max_emitter_backlog: f32) -> Self {
         let backlog = max_emitter_backlog.max(0.0);
         let spawn = max_spawn_per_frame.min(max_total);
-        Self { max_spawn_per_frame: spawn, max_total, max_emitter_backlog: backlog }
+        Self {
+            max_spawn_per_frame: spawn,
+            max_total,
+            max_emitter_backlog: backlog,
+            max_collision_particles: Self::DEFAULT_MAX_COLLISION_PARTICLES,
+        }
+    }
+
+    pub fn with_max_collision_particles(mut self, max_collision_particles: u32) -> Self {
+        self.max_collision_particles = max_collision_particles;
+        self
+    }
+}
+
+/// Side table of opaque plugin-owned component data, keyed by entity and then by a
+/// plugin-qualified name such as `"plugin.health"`.
+#[derive(Resource, Default)]
+pub struct PluginBlobStore {
+    blobs: HashMap<Entity, BTreeMap<String, Vec<u8>>>,
+}
+
+impl PluginBlobStore {
+    pub fn insert(&mut self, entity: Entity, name: &str, bytes: Vec<u8>) -> Option<Vec<u8>> {
+        self.blobs.entry(entity).or_default().insert(name.to_string(), bytes)
+    }
+
+    pub fn get(&self, entity: Entity, name: &str) -> Option<&[u8]> {
+        self.blobs.get(&entity)?.get(name).map(Vec::as_slice)
+    }
+
+    pub fn remove(&mut self, entity: Entity, name: &str) -> Option<Vec<u8>> {
+        let blobs = self.blobs.get_mut(&entity)?;
+        let removed = blobs.remove(name);
+        if blobs.is_empty() {
+            self.blobs.remove(&entity);
+        }
+        removed
+    }
+
+    pub fn entity_blobs(&self, entity: Entity) -> Option<&BTreeMap<String, Vec<u8>>> {
+        self.blobs.get(&entity)
+    }
+
+    pub fn entities(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.blobs.keys().copied()
+    }
+
+    pub fn remove_entity(&mut self, entity: Entity) -> Option<BTreeMap<String, Vec<u8>>> {
+        self.blobs.remove(&entity)
+    }
+
+    pub fn clear(&mut self) {
+        self.blobs.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.blobs.values().map(BTreeMap::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.blobs.is_empty()
+    }
+}
+
+/// String tags for ad-hoc categorization (e.g. `"enemy"`, `"collectible"`). Inserting or replacing
+/// the component keeps the [`TagIndex`] in sync through component hooks, so despawns on any path drop
+/// the entity from the index.
+#[derive(Component, Clone, Debug, Default, PartialEq, Eq)]
+#[component(on_insert = index_entity_tags, on_replace = unindex_entity_tags)]
+pub struct Tags(pub HashSet<Arc<str>>);
+
+impl Tags {
+    pub fn contains(&self, tag: &str) -> bool {
+        self.0.contains(tag)
+    }
+
+    /// Tags in lexical order, for stable display and serialization.
+    pub fn sorted(&self) -> Vec<Arc<str>> {
+        let mut tags: Vec<Arc<str>> = self.0.iter().cloned().collect();
+        tags.sort();
+        tags
+    }
+}
+
+fn index_entity_tags(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+    let Some(tags) = world.get::<Tags>(entity).map(|tags| tags.0.clone()) else {
+        return;
+    };
+    let mut index = world.resource_mut::<TagIndex>();
+    for tag in tags {
+        index.insert(tag, entity);
+    }
+}
+
+fn unindex_entity_tags(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+    let Some(tags) = world.get::<Tags>(entity).map(|tags| tags.0.clone()) else {
+        return;
+    };
+    let mut index = world.resource_mut::<TagIndex>();
+    for tag in tags {
+        index.remove(&tag, entity);
+    }
+}
+
+/// Inverted index from tag to the entities carrying it, maintained by the [`Tags`] hooks.
+#[derive(Resource, Default)]
+pub struct TagIndex {
+    entities: HashMap<Arc<str>, HashSet<Entity>>,
+}
+
+impl TagIndex {
+    /// Returns the shared string for `tag` when any entity already carries it.
+    pub fn interned(&self, tag: &str) -> Option<Arc<str>> {
+        self.entities.get_key_value(tag).map(|(key, _)| Arc::clone(key))
+    }
+
+    pub fn entities(&self, tag: &str) -> Option<&HashSet<Entity>> {
+        self.entities.get(tag)
+    }
+
+    pub fn tags(&self) -> impl Iterator<Item = (&Arc<str>, &HashSet<Entity>)> + '_ {
+        self.entities.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    fn insert(&mut self, tag: Arc<str>, entity: Entity) {
+        self.entities.entry(tag).or_default().insert(entity);
+    }
+
+    fn remove(&mut self, tag: &str, entity: Entity) {
+        if let Some(entities) = self.entities.get_mut(tag) {
+            entities.remove(&entity);
+            if entities.is_empty() {
+                self.entities.remove(tag);
+            }
+        }
     }
 }
 
@@ -3228,6 +4043,16 @@ pub struct ParticleState {
     pub active_particles: u32,
 }
 
+/// Work done by the particle collision pass during the last fixed step.
+#[derive(Resource, Clone, Copy, Default)]
+pub struct ParticleCollisionStats {
+    pub tests: u32,
+    pub hits: u32,
+    pub events: u32,
+    /// True when the pass early-outed because too many particles were alive.
+    pub skipped: bool,
+}
+
 #[derive(Resource, Default)]
 pub struct ParticleScratch {
     pub force_fields: Vec<(Vec2, ForceField)>,
@@ -3247,6 +4072,13 @@ pub struct ParticleBudgetMetrics {
     pub emitter_backlog_total: f32,
     pub emitter_backlog_max_observed: f32,
     pub emitter_backlog_limit: f32,
+    pub burst_backlog_total: u32,
+    pub collision_emitters: u32,
+    /// Circle-vs-box tests run by the particle collision pass in the last fixed step.
+    pub collision_tests: u32,
+    pub collision_hits: u32,
+    pub collision_particle_limit: u32,
+    pub collision_checks_skipped: bool,
 }
 
 impl ParticleBudgetMetrics {
@@ -3283,6 +4115,252 @@ pub struct OrbitController {
     pub angular_speed: f32,
 }
 
+/// Clip a graph state plays when it becomes active, resolved when the graph is attached.
+#[derive(Clone)]
+pub enum AnimationGraphClip {
+    Transform { key: Arc<str>, clip: Arc<AnimationClip> },
+    Skeletal { key: Arc<str>, clip: Arc<SkeletalClip> },
+    SkeletalBlend { blend: AnimationGraphBlend1D, clips: Vec<Option<(Arc<str>, Arc<SkeletalClip>)>> },
+}
+
+impl AnimationGraphClip {
+    pub fn same_binding(&self, other: &AnimationGraphClip) -> bool {
+        match (self, other) {
+            (Self::Transform { key: a, .. }, Self::Transform { key: b, .. })
+            | (Self::Skeletal { key: a, .. }, Self::Skeletal { key: b, .. }) => a == b,
+            _ => false,
+        }
+    }
+}
+
+/// Runtime state for an [`AnimationGraphAsset`] attached to an entity.
+///
+/// Parameters default to `0.0` (bool parameters use `0.0`/`1.0`). Triggers raised with
+/// [`AnimationGraphInstance::trigger`] are consumed by the next evaluation that is not
+/// mid-transition. The target clip starts as soon as a transition fires; `transition_progress`
+/// then ramps to `1.0` over the transition's duration and no further transitions are taken
+/// until it completes. Skeletal states cross-fade from `previous_state` while it ramps.
+#[derive(Component, Clone)]
+pub struct AnimationGraphInstance {
+    pub graph_key: Arc<str>,
+    pub graph: AnimationGraphAsset,
+    pub current_state: String,
+    pub previous_state: Option<String>,
+    pub transition_progress: f32,
+    pub transition_duration: f32,
+    pub parameters: HashMap<String, f32>,
+    pub pending_triggers: Vec<String>,
+    pub state_clips: HashMap<Arc<str>, AnimationGraphClip>,
+}
+
+impl AnimationGraphInstance {
+    pub fn new(
+        graph_key: Arc<str>,
+        graph: AnimationGraphAsset,
+        state_clips: HashMap<Arc<str>, AnimationGraphClip>,
+    ) -> Self {
+        let parameters = graph.parameters.iter().map(|parameter| (parameter.name.to_string(), 0.0)).collect();
+        Self {
+            graph_key,
+            current_state: graph.entry_state.to_string(),
+            previous_state: None,
+            graph,
+            transition_progress: 1.0,
+            transition_duration: 0.0,
+            parameters,
+            pending_triggers: Vec::new(),
+            state_clips,
+        }
+    }
+
+    pub fn is_transitioning(&self) -> bool {
+        self.transition_progress < 1.0
+    }
+
+    /// Sets a declared parameter; returns false for names the graph does not declare.
+    pub fn set_parameter(&mut self, name: &str, value: f32) -> bool {
+        match self.parameters.get_mut(name) {
+            Some(slot) => {
+                *slot = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn parameter(&self, name: &str) -> Option<f32> {
+        self.parameters.get(name).copied()
+    }
+
+    pub fn trigger(&mut self, event: &str) {
+        if !self.pending_triggers.iter().any(|pending| pending == event) {
+            self.pending_triggers.push(event.to_string());
+        }
+    }
+
+    pub fn state_clip(&self, state: &str) -> Option<&AnimationGraphClip> {
+        self.state_clips.get(state)
+    }
+
+    /// Swaps in a reloaded graph, keeping the values of parameters it still declares. The current
+    /// state survives if the new graph still has it; otherwise the instance falls back to the entry
+    /// state. Any in-flight transition is finished. Returns true if the state changed.
+    pub fn rebind(
+        &mut self,
+        graph: AnimationGraphAsset,
+        state_clips: HashMap<Arc<str>, AnimationGraphClip>,
+    ) -> bool {
+        let parameters = graph
+            .parameters
+            .iter()
+            .map(|parameter| {
+                let name = parameter.name.to_string();
+                let value = self.parameters.get(&name).copied().unwrap_or(0.0);
+                (name, value)
+            })
+            .collect();
+        let keeps_state = graph.states.iter().any(|state| state.name.as_ref() == self.current_state);
+        if !keeps_state {
+            self.current_state = graph.entry_state.to_string();
+        }
+        self.graph = graph;
+        self.parameters = parameters;
+        self.previous_state = None;
+        self.transition_progress = 1.0;
+        self.transition_duration = 0.0;
+        self.pending_triggers.clear();
+        self.state_clips = state_clips;
+        !keeps_state
+    }
+
+    /// Weighted skeletal clips for the current pose: the active state's clip or blend-space
+    /// weights, cross-faded against the previous state while a transition is in progress.
+    /// Weights of clips shared by both states are merged and the result sums to one.
+    pub fn skeletal_blend_layers(&self) -> Vec<SkeletalBlendLayer> {
+        let mut layers = Vec::new();
+        let current_weight = if self.is_transitioning() { self.transition_progress } else { 1.0 };
+        self.push_state_layers(&self.current_state, current_weight, &mut layers);
+        if let Some(previous) = self.previous_state.as_deref().filter(|_| self.is_transitioning()) {
+            self.push_state_layers(previous, 1.0 - current_weight, &mut layers);
+        }
+        layers.retain(|layer| layer.weight > SKELETAL_BLEND_WEIGHT_EPSILON);
+        let total: f32 = layers.iter().map(|layer| layer.weight).sum();
+        if total > 0.0 {
+            for layer in &mut layers {
+                layer.weight /= total;
+            }
+        }
+        layers
+    }
+
+    fn push_state_layers(&self, state: &str, weight: f32, layers: &mut Vec<SkeletalBlendLayer>) {
+        let mut push = |key: &Arc<str>, clip: &Arc<SkeletalClip>, weight: f32| match layers
+            .iter_mut()
+            .find(|layer| layer.clip_key == *key)
+        {
+            Some(layer) => layer.weight += weight,
+            None => {
+                layers.push(SkeletalBlendLayer { clip_key: Arc::clone(key), clip: Arc::clone(clip), weight })
+            }
+        };
+        match self.state_clips.get(state) {
+            Some(AnimationGraphClip::Skeletal { key, clip }) => push(key, clip, weight),
+            Some(AnimationGraphClip::SkeletalBlend { blend, clips }) => {
+                let value = self.parameter(&blend.parameter).unwrap_or(0.0);
+                for (clip_weight, entry) in blend.weights(value).into_iter().zip(clips) {
+                    if let Some((key, clip)) = entry {
+                        push(key, clip, weight * clip_weight);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Advances the active transition by `delta` seconds, or takes the first transition out of the
+    /// current state whose trigger and conditions are satisfied. Returns the state that was entered.
+    pub fn evaluate(&mut self, delta: f32) -> Option<Arc<str>> {
+        if self.is_transitioning() {
+            let step = if self.transition_duration > 0.0 { delta / self.transition_duration } else { 1.0 };
+            self.transition_progress = (self.transition_progress + step.max(0.0)).min(1.0);
+            return None;
+        }
+        let next = self.graph.transitions.iter().find(|transition| {
+            transition.from.as_ref() == self.current_state
+                && transition
+                    .trigger
+                    .as_deref()
+                    .is_none_or(|trigger| self.pending_triggers.iter().any(|pending| pending == trigger))
+                && transition.conditions.iter().all(|condition| {
+                    self.parameters
+                        .get(condition.parameter.as_ref())
+                        .is_some_and(|value| condition.holds(*value))
+                })
+        });
+        let entered = next.map(|transition| {
+            let previous = std::mem::replace(&mut self.current_state, transition.to.to_string());
+            self.previous_state = Some(previous);
+            self.transition_duration = transition.duration;
+            self.transition_progress = if transition.duration > 0.0 { 0.0 } else { 1.0 };
+            Arc::clone(&transition.to)
+        });
+        self.pending_triggers.clear();
+        entered
+    }
+}
+
+/// Steers an entity along a path from the navigation grid by writing its velocity each fixed step.
+#[derive(Component, Clone, Debug)]
+pub struct PathAgent {
+    pub speed: f32,
+    pub arrive_radius: f32,
+    pub goal: Option<Vec2>,
+    pub waypoints: Vec<Vec2>,
+    pub next_waypoint: usize,
+    pub nav_revision: u64,
+}
+
+impl Default for PathAgent {
+    fn default() -> Self {
+        Self {
+            speed: 0.6,
+            arrive_radius: 0.02,
+            goal: None,
+            waypoints: Vec::new(),
+            next_waypoint: 0,
+            nav_revision: 0,
+        }
+    }
+}
+
+impl PathAgent {
+    pub fn with_speed(speed: f32) -> Self {
+        Self { speed, ..Self::default() }
+    }
+
+    pub fn is_moving(&self) -> bool {
+        self.goal.is_some() && self.next_waypoint < self.waypoints.len()
+    }
+
+    pub fn remaining_waypoints(&self) -> &[Vec2] {
+        self.waypoints.get(self.next_waypoint..).unwrap_or(&[])
+    }
+
+    pub fn set_path(&mut self, goal: Vec2, waypoints: Vec<Vec2>, nav_revision: u64) {
+        self.goal = Some(goal);
+        // The first waypoint is the agent's own position.
+        self.next_waypoint = usize::from(waypoints.len() > 1);
+        self.waypoints = waypoints;
+        self.nav_revision = nav_revision;
+    }
+
+    pub fn clear_path(&mut self) {
+        self.goal = None;
+        self.waypoints.clear();
+        self.next_waypoint = 0;
+    }
+}
+
 #[repr(C)]
 #[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
 pub struct InstanceData {
@@ -3300,6 +4378,12 @@ pub struct SpriteInstance {
     pub uv_rect: [f32; 4],
     pub tint: [f32; 4],
     pub world_half_extent: Vec2,
+    pub z_order: i16,
+    /// True for sprites without velocity or animation whose data did not change this collection;
+    /// these can be drawn from a baked instance buffer.
+    pub static_sprite: bool,
+    /// Path of the sprite's normal map; sprites only share a batch when this matches too.
+    pub normal_map: Option<Arc<str>>,
 }
 
 impl SpriteInstance {
@@ -3315,6 +4399,71 @@ impl SpriteInstance {
     }
 }
 
+/// Per-atlas change tracking for `EcsWorld::collect_sprite_instances`.
+///
+/// A bucket stays clean when none of its sprites changed since the previous collection and the
+/// entity order is identical, so the renderer can keep the instance data it already uploaded.
+#[derive(Resource, Default)]
+pub struct SpriteInstanceTracker {
+    last_collect: Option<bevy_ecs::component::Tick>,
+    previous: HashMap<Arc<str>, Vec<Entity>>,
+    current: HashMap<Arc<str>, Vec<Entity>>,
+    dirty: HashSet<Arc<str>>,
+    previous_static: Vec<Entity>,
+    current_static: Vec<Entity>,
+    static_dirty: bool,
+}
+
+impl SpriteInstanceTracker {
+    pub(crate) fn begin(&mut self) -> Option<bevy_ecs::component::Tick> {
+        std::mem::swap(&mut self.previous, &mut self.current);
+        for entities in self.current.values_mut() {
+            entities.clear();
+        }
+        self.dirty.clear();
+        std::mem::swap(&mut self.previous_static, &mut self.current_static);
+        self.current_static.clear();
+        self.last_collect
+    }
+
+    pub(crate) fn record(&mut self, atlas: &Arc<str>, entity: Entity, changed: bool, is_static: bool) {
+        if is_static {
+            self.current_static.push(entity);
+        }
+        match self.current.get_mut(atlas) {
+            Some(entities) => entities.push(entity),
+            None => {
+                self.current.insert(Arc::clone(atlas), vec![entity]);
+            }
+        }
+        if changed && !self.dirty.contains(atlas) {
+            self.dirty.insert(Arc::clone(atlas));
+        }
+    }
+
+    pub(crate) fn finish(&mut self, collect_tick: bevy_ecs::component::Tick) {
+        self.current.retain(|_, entities| !entities.is_empty());
+        for (atlas, entities) in &self.current {
+            if self.previous.get(atlas) != Some(entities) {
+                self.dirty.insert(Arc::clone(atlas));
+            }
+        }
+        // Static sprites are unchanged by definition, so only membership can invalidate the bake.
+        self.static_dirty = self.previous_static != self.current_static;
+        self.last_collect = Some(collect_tick);
+    }
+
+    /// Returns true when the atlas bucket changed during the most recent collection.
+    pub fn is_dirty(&self, atlas: &str) -> bool {
+        self.dirty.contains(atlas) || !self.current.contains_key(atlas)
+    }
+
+    /// Returns true when the set of static sprites changed during the most recent collection.
+    pub fn static_dirty(&self) -> bool {
+        self.static_dirty
+    }
+}
+
 #[derive(Clone, Copy)]
 pub struct SpriteInstanceTransform {
     pub axis_x: Vec3,
@@ -3343,10 +4492,12 @@ pub struct TransformClipInfo {
     pub clip_key: String,
     pub playing: bool,
     pub looped: bool,
+    pub loop_count: Option<u32>,
     pub speed: f32,
     pub time: f32,
     pub duration: f32,
     pub group: Option<String>,
+    pub events: Vec<(f32, String)>,
     pub has_translation: bool,
     pub has_rotation: bool,
     pub has_scale: bool,
@@ -3357,11 +4508,21 @@ pub struct TransformClipInfo {
     pub sample_tint: Option<Vec4>,
 }
 
+#[derive(Clone)]
+pub struct TintCurveInfo {
+    pub curve_key: String,
+    pub duration: f32,
+    pub loop_mode: SpriteAnimationLoopMode,
+    pub elapsed: f32,
+    pub sample: Vec4,
+}
+
 #[derive(Clone)]
 pub struct SkeletonClipInfo {
     pub clip_key: String,
     pub playing: bool,
     pub looped: bool,
+    pub loop_count: Option<u32>,
     pub speed: f32,
     pub time: f32,
     pub duration: f32,
@@ -3375,6 +4536,43 @@ pub struct SkeletonInfo {
     pub has_bone_transforms: bool,
     pub palette_joint_count: usize,
     pub clip: Option<SkeletonClipInfo>,
+    /// Current pose of every joint, in skeleton order.
+    pub joints: Vec<SkeletonJointPose>,
+}
+
+/// Tracks of the active skeletal clip that drive one joint.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct JointClipTracks {
+    pub translation: bool,
+    pub rotation: bool,
+    pub scale: bool,
+}
+
+/// One joint of a skeleton's current pose, copied from the poses the animation systems already
+/// computed rather than evaluated again.
+#[derive(Clone, Debug)]
+pub struct SkeletonJointPose {
+    pub name: Arc<str>,
+    pub parent: Option<usize>,
+    /// Transform relative to the parent joint; for an animated joint this holds the sampled track values.
+    pub local: Mat4,
+    /// Transform in world space, placed by the entity's world transform.
+    pub world: Mat4,
+    /// `None` when the active clip does not animate this joint.
+    pub clip_tracks: Option<JointClipTracks>,
+}
+
+#[derive(Clone)]
+pub struct AnimationGraphInfo {
+    pub graph_key: String,
+    pub state: String,
+    /// State being faded out while a transition is in progress.
+    pub previous_state: Option<String>,
+    pub transition_progress: f32,
+    /// Declared parameters, sorted by name.
+    pub parameters: Vec<(String, f32)>,
+    /// Skeletal clips currently contributing to the pose and their normalised weights.
+    pub blend_weights: Vec<(String, f32)>,
 }
 
 #[derive(Clone)]
@@ -3394,6 +4592,9 @@ pub struct ParticleEmitterInfo {
     pub start_size: f32,
     pub end_size: f32,
     pub trail: Option<ParticleTrail>,
+    pub shape: EmitterShape,
+    pub collision_mode: ParticleCollisionMode,
+    pub collision_events: bool,
 }
 
 #[derive(Clone)]
@@ -3408,6 +4609,7 @@ pub struct ScriptInfo {
 #[derive(Clone)]
 pub struct EntityInfo {
     pub scene_id: SceneEntityId,
+    pub parent: Option<SceneEntityId>,
     pub translation: Vec2,
     pub rotation: f32,
     pub scale: Vec2,
@@ -3420,11 +4622,15 @@ pub struct EntityInfo {
     pub mesh: Option<MeshInfo>,
     pub mesh_transform: Option<Transform3DInfo>,
     pub tint: Option<Vec4>,
+    pub tint_curve: Option<TintCurveInfo>,
     pub skeleton: Option<SkeletonInfo>,
+    pub animation_graph: Option<AnimationGraphInfo>,
     pub skin_mesh: Option<SkinMeshInfo>,
     pub particle_emitter: Option<ParticleEmitterInfo>,
     pub force_field: Option<ForceField>,
     pub attractor: Option<ParticleAttractor>,
+    pub collider: Option<ColliderGeometry>,
+    pub plugin_components: Vec<PluginComponentView>,
 }
 
 #[derive(Clone)]
@@ -3432,6 +4638,22 @@ pub struct SpriteInfo {
     pub atlas: String,
     pub region: String,
     pub animation: Option<SpriteAnimationInfo>,
+    pub z_order: i16,
+    pub normal_map: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AtlasReferenceKind {
+    Region,
+    Timeline,
+}
+
+/// A sprite that still names an atlas region or timeline the atlas no longer defines.
+#[derive(Clone, Debug)]
+pub struct MissingAtlasReference {
+    pub entity: Entity,
+    pub kind: AtlasReferenceKind,
+    pub name: Arc<str>,
 }
 
 #[derive(Clone)]
@@ -3452,6 +4674,7 @@ pub struct SpriteAnimationInfo {
     pub start_offset: f32,
     pub random_start: bool,
     pub group: Option<String>,
+    pub loop_count: Option<u32>,
 }
 
 #[derive(Clone)]
@@ -3459,6 +4682,7 @@ pub struct MeshInfo {
     pub key: String,
     pub material: Option<String>,
     pub lighting: MeshLightingInfo,
+    pub max_draw_distance: Option<f32>,
 }
 
 #[derive(Clone)]
@@ -3490,6 +4714,7 @@ pub struct MeshInstance {
     pub material: Option<String>,
     pub lighting: MeshLightingInfo,
     pub skin: Option<MeshSkinInstance>,
+    pub max_draw_distance: Option<f32>,
 }
 
 #[derive(Clone)]
@@ -1,28 +1,38 @@
 use super::*;
-use crate::assets::AssetManager;
+use crate::alloc_sampler::{self, AllocationSite};
+use crate::assets::{AnimationGraphAsset, AssetManager};
 #[cfg(feature = "anim_stats")]
 use crate::ecs::systems::record_transform_looped_resume;
 use crate::ecs::systems::{
-    initialize_animation_phase, sys_flag_fast_sprite_animators, AnimationDelta, AnimationPlan, AnimationTime,
-    ParticleSpawnScratch, SpriteAnimPerfSample, SpriteAnimPerfTelemetry, SpriteFrameApplyQueue, TimeDelta,
+    initialize_animation_phase, on_fixed_step, on_variable_step, sys_flag_fast_sprite_animators,
+    AnimationDelta, AnimationPlan, AnimationTime, ParticleSpawnScratch, SimulationMode, SpriteAnimPerfSample,
+    SpriteAnimPerfTelemetry, SpriteFrameApplyQueue, SpriteTimelineUsage, TimeDelta,
 };
 #[cfg(feature = "sprite_anim_soa")]
 use crate::ecs::systems::{sys_cleanup_sprite_animator_soa, SpriteAnimatorSoa};
 use crate::events::{EventBus, GameEvent};
+use crate::mesh::{ImportedNode, ImportedNodeMesh};
 use crate::mesh_registry::MeshRegistry;
+use crate::nav::{NavGrid, NavGridConfig, NavGridStats};
+use crate::prefab::PrefabOverrides;
 use crate::scene::{
     ColliderData, ColorData, ForceFieldData, MeshData, MeshLightingData, OrbitControllerData, ParticleAttractorData,
     ParticleEmitterData, ParticleTrailData, Scene, SceneDependencies, SceneEntity, SceneEntityId, ScriptData,
-    SkeletonClipData, SkeletonData, SpriteAnimationData, SpriteData, Transform3DData, TransformClipData, TransformData,
+    SkeletonClipData, SkeletonData, SpriteAnimationData, SpriteData, TintCurveData, Transform3DData, TransformClipData,
+    TransformData,
 };
 use crate::scripts::{ScriptBehaviour, ScriptPersistedState};
-use anyhow::{anyhow, Result};
-use bevy_ecs::prelude::{Entity, Schedule, With, World};
+use anyhow::{anyhow, Context, Result};
+use bevy_ecs::component::Tick;
+use bevy_ecs::prelude::{
+    Component, DetectChanges, DetectChangesMut, Entity, Has, Mut, Ref, Schedule, With, World,
+};
 use bevy_ecs::schedule::IntoSystemConfigs;
 use glam::{EulerRot, Mat4, Quat, Vec2, Vec3, Vec4};
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use rapier2d::prelude::{Rotation, Vector};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 use std::sync::Arc;
 
@@ -58,8 +68,14 @@ pub struct EcsWorld {
     pub world: World,
     schedule_var: Schedule,
     schedule_fixed: Schedule,
+    max_entities: Option<usize>,
+    sprite_pool_capacity: usize,
 }
 
+/// Physics bodies kept parked for scripted sprite spawns unless changed with
+/// [`EcsWorld::set_sprite_pool_capacity`].
+pub const DEFAULT_SPRITE_POOL_CAPACITY: usize = 4096;
+
 impl Default for EcsWorld {
     fn default() -> Self {
         Self::new()
@@ -70,6 +86,7 @@ impl EcsWorld {
     pub fn new() -> Self {
         let mut world = World::new();
         world.insert_resource(TimeDelta(0.0));
+        world.insert_resource(SimulationMode::default());
         world.insert_resource(AnimationTime::default());
         world.insert_resource(AnimationPlan::default());
         world.insert_resource(SpatialHash::new(0.25));
@@ -78,16 +95,29 @@ impl EcsWorld {
         world.insert_resource(SpatialMetrics::default());
         world.insert_resource(SpatialScratch::default());
         world.insert_resource(ParticleContacts::default());
+        world.insert_resource(PluginBlobStore::default());
+        world.insert_resource(TagIndex::default());
+        world.insert_resource(NormalMapRefQueue::default());
+        world.insert_resource(PluginComponentRegistry::default());
+        world.insert_resource(SceneIdAllocator::default());
         world.insert_resource(ParticleCaps::default());
         world.insert_resource(ParticleState::default());
+        world.insert_resource(ParticleCollisionStats::default());
         world.insert_resource(ParticleScratch::default());
         world.insert_resource(ParticleSpawnScratch::default());
         world.insert_resource(TransformPropagationStats::default());
+        world.insert_resource(PendingDespawns::default());
+        world.insert_resource(DespawnView::default());
         let world_bounds =
             WorldBounds { min: Vec2::new(-1.4, -1.0), max: Vec2::new(1.4, 1.0), thickness: 0.05 };
         world.insert_resource(world_bounds);
         let physics_params = PhysicsParams { gravity: Vec2::new(0.0, -0.6), linear_damping: 0.3 };
         world.insert_resource(physics_params);
+        world.insert_resource(NavGrid::new(NavGridConfig {
+            min: world_bounds.min,
+            max: world_bounds.max,
+            ..NavGridConfig::default()
+        }));
         let boundary_entity = world.spawn_empty().id();
         world.insert_resource(RapierState::new(&physics_params, &world_bounds, boundary_entity));
         world.insert_resource(EventBus::default());
@@ -95,19 +125,22 @@ impl EcsWorld {
         world.insert_resource(SystemProfiler::new());
         world.insert_resource(SpriteFrameApplyQueue::default());
         world.insert_resource(SpriteAnimPerfTelemetry::new(240));
+        world.insert_resource(SpriteTimelineUsage::default());
         #[cfg(feature = "sprite_anim_soa")]
         world.insert_resource(SpriteAnimatorSoa::default());
 
         let mut schedule_var = Schedule::default();
         schedule_var.add_systems(
             (
-                sys_apply_spin,
+                sys_apply_spin.run_if(on_variable_step),
                 sys_propagate_scene_transforms,
                 sys_sync_world3d,
-                sys_update_emitters,
-                sys_update_particles,
+                sys_update_emitters.run_if(on_variable_step),
+                sys_update_particles.run_if(on_variable_step),
+                sys_drive_animation_graphs,
                 sys_drive_transform_clips,
                 sys_drive_skeletal_clips,
+                sys_drive_tint_curves,
                 sys_init_sprite_frame_state,
                 sys_flag_fast_sprite_animators,
                 sys_drive_sprite_animations,
@@ -115,22 +148,154 @@ impl EcsWorld {
             )
                 .chain(),
         );
+        schedule_var.add_systems(
+            (sys_tick_lifetimes.run_if(on_variable_step), sys_despawn_out_of_bounds)
+                .chain()
+                .after(sys_propagate_scene_transforms),
+        );
         #[cfg(feature = "sprite_anim_soa")]
         schedule_var.add_systems((sys_cleanup_sprite_animator_soa,));
 
         let mut schedule_fixed = Schedule::default();
-        schedule_fixed.add_systems((
-            sys_solve_forces,
-            sys_integrate_positions,
-            sys_drive_orbits,
-            sys_step_rapier,
-            sys_sync_from_rapier,
-            sys_world_bounds_bounce,
-            sys_build_spatial_hash,
-            sys_collide_spatial,
-        ));
+        // Chained so the step runs in the same order every time; snapshot replays rely on it.
+        schedule_fixed.add_systems(
+            (
+                sys_solve_forces,
+                sys_integrate_positions,
+                sys_drive_orbits,
+                sys_step_rapier,
+                sys_sync_from_rapier,
+                sys_world_bounds_bounce,
+                sys_build_spatial_hash,
+                sys_collide_spatial,
+            )
+                .chain(),
+        );
+        schedule_fixed.add_systems(
+            sys_collide_particles
+                .after(sys_integrate_positions)
+                .after(sys_world_bounds_bounce)
+                .after(sys_build_spatial_hash)
+                .after(sys_collide_spatial),
+        );
+        schedule_fixed.add_systems(
+            (sys_apply_spin, sys_update_emitters, sys_update_particles)
+                .chain()
+                .run_if(on_fixed_step)
+                .before(sys_solve_forces),
+        );
+        schedule_fixed.add_systems(sys_fire_burst_emitters.before(sys_apply_spin).before(sys_solve_forces));
+        schedule_fixed.add_systems(
+            (sys_sync_nav_grid, sys_drive_path_agents)
+                .chain()
+                .after(sys_solve_forces)
+                .before(sys_integrate_positions)
+                .before(sys_step_rapier),
+        );
+        schedule_fixed.add_systems(sys_tick_lifetimes.run_if(on_fixed_step).after(sys_collide_spatial));
+
+        Self {
+            world,
+            schedule_var,
+            schedule_fixed,
+            max_entities: None,
+            sprite_pool_capacity: DEFAULT_SPRITE_POOL_CAPACITY,
+        }
+    }
+
+    /// Caps how many entities bursts, scripted spawns and prefab instantiation may grow the world to.
+    /// `None` removes the limit.
+    pub fn set_max_entities(&mut self, max: Option<usize>) {
+        self.max_entities = max;
+    }
+
+    pub fn max_entities(&self) -> Option<usize> {
+        self.max_entities
+    }
+
+    /// Caps how many physics bodies of despawned scripted sprites are parked for reuse. Lowering
+    /// the cap only affects later despawns.
+    pub fn set_sprite_pool_capacity(&mut self, capacity: usize) {
+        self.sprite_pool_capacity = capacity;
+    }
+
+    /// Number of parked sprite bodies waiting to be reused by the next scripted spawns.
+    pub fn pooled_sprite_count(&self) -> usize {
+        self.world.resource::<RapierState>().parked_body_count()
+    }
+
+    /// Turns deterministic simulation on with the given seed, or off with `None`; see
+    /// [`SimulationMode`]. Random streams restart from the seed either way.
+    pub fn set_deterministic(&mut self, seed: Option<u64>) {
+        let mode = SimulationMode { deterministic: seed.is_some(), seed: seed.unwrap_or_default() };
+        self.world.insert_resource(mode);
+        let mut scratch = self.world.resource_mut::<ParticleSpawnScratch>();
+        match seed {
+            Some(seed) => scratch.reseed(seed),
+            None => scratch.reseed(rand::thread_rng().gen()),
+        }
+    }
+
+    pub fn deterministic_seed(&self) -> Option<u64> {
+        let mode = self.world.resource::<SimulationMode>();
+        mode.deterministic.then_some(mode.seed)
+    }
 
-        Self { world, schedule_var, schedule_fixed }
+    /// Checksum of every entity's transform plus the live particle count, for comparing two runs
+    /// frame by frame. Entity ids are left out, so it only changes when the simulated state does.
+    pub fn world_state_hash(&self) -> u64 {
+        let mut records: Vec<Vec<u8>> = Vec::new();
+        let mut particles = 0u32;
+        for entity in self.world.iter_entities() {
+            if entity.contains::<Particle>() {
+                particles += 1;
+            }
+            let Some(transform) = entity.get::<Transform>() else {
+                continue;
+            };
+            let mut record = Vec::with_capacity(32);
+            for value in [
+                transform.translation.x,
+                transform.translation.y,
+                transform.rotation,
+                transform.scale.x,
+                transform.scale.y,
+            ] {
+                record.extend_from_slice(&value.to_bits().to_le_bytes());
+            }
+            if let Some(tag) = entity.get::<SceneEntityTag>() {
+                record.extend_from_slice(tag.id.as_str().as_bytes());
+            }
+            records.push(record);
+        }
+        records.sort_unstable();
+        let mut hasher = blake3::Hasher::new();
+        for record in &records {
+            hasher.update(&(record.len() as u32).to_le_bytes());
+            hasher.update(record);
+        }
+        hasher.update(&particles.to_le_bytes());
+        hasher.update(&self.world.resource::<ParticleState>().active_particles.to_le_bytes());
+        let mut out = [0u8; 8];
+        out.copy_from_slice(&hasher.finalize().as_bytes()[..8]);
+        u64::from_le_bytes(out)
+    }
+
+    /// Returns how many of `requested` spawns fit under the entity cap, emitting a warning when
+    /// the request has to be truncated.
+    fn spawn_allowance(&mut self, requested: usize, source: &str) -> usize {
+        let Some(max) = self.max_entities else {
+            return requested;
+        };
+        let available = max.saturating_sub(self.entity_count());
+        if requested > available {
+            self.emit(GameEvent::ScriptMessage {
+                message: format!(
+                    "{source}: entity cap of {max} reached; {available} of {requested} requested spawns allowed"
+                ),
+            });
+        }
+        requested.min(available)
     }
 
     fn ensure_sprite_frame_state(&mut self, entity: Entity) {
@@ -172,6 +337,15 @@ impl EcsWorld {
         self.world.resource_mut::<EventBus>().drain()
     }
 
+    /// Subscribes to events of type `E`; see [`EventBus::subscribe`].
+    pub fn subscribe_event<E: 'static>(&mut self, handler: impl Fn(&E) + Send + 'static) {
+        self.world.resource_mut::<EventBus>().subscribe(handler);
+    }
+
+    pub fn publish_event<E: 'static>(&self, event: E) {
+        self.world.resource::<EventBus>().publish(event);
+    }
+
     pub fn push_event(&mut self, event: GameEvent) {
         self.emit(event);
     }
@@ -341,8 +515,13 @@ impl EcsWorld {
     }
 
     pub fn spawn_burst(&mut self, _assets: &AssetManager, count: usize) {
+        let count = self.spawn_allowance(count, "spawn_burst");
         let regions = ["checker", "redorb", "bluebox", "green"];
-        let mut rng = rand::thread_rng();
+        let mut rng = if self.deterministic_seed().is_some() {
+            StdRng::seed_from_u64(self.world.resource_mut::<ParticleSpawnScratch>().rng.gen())
+        } else {
+            StdRng::from_entropy()
+        };
         for _ in 0..count {
             let rname = regions[rng.gen_range(0..regions.len())];
             let pos = Vec2::new(rng.gen_range(-1.2..1.2), rng.gen_range(-0.9..0.9));
@@ -413,6 +592,10 @@ impl EcsWorld {
                     region: Arc::from("green"),
                     source: None,
                     trail: None,
+                    shape: EmitterShape::default(),
+                    pending_burst: 0,
+                    collision_mode: ParticleCollisionMode::None,
+                    collision_events: false,
                 },
             ))
             .id();
@@ -484,6 +667,47 @@ impl EcsWorld {
         }
     }
 
+    pub fn set_emitter_shape(&mut self, entity: Entity, shape: EmitterShape) {
+        if let Some(mut emitter) = self.world.get_mut::<ParticleEmitter>(entity) {
+            emitter.shape = shape.sanitized();
+        }
+    }
+
+    /// Sets how future particles from the emitter react to colliders; live particles keep theirs.
+    pub fn set_emitter_collision(&mut self, entity: Entity, mode: ParticleCollisionMode, events: bool) {
+        if let Some(mut emitter) = self.world.get_mut::<ParticleEmitter>(entity) {
+            emitter.collision_mode = mode.sanitized();
+            emitter.collision_events = events;
+        }
+    }
+
+    /// Queues `count` one-shot particles on the emitter. Bursts larger than the per-frame spawn
+    /// budget are spread over the following frames; the backlog is capped at the particle total.
+    pub fn emitter_burst(&mut self, entity: Entity, count: u32) -> bool {
+        let max_total = self.world.resource::<ParticleCaps>().max_total;
+        let Some(mut emitter) = self.world.get_mut::<ParticleEmitter>(entity) else {
+            return false;
+        };
+        emitter.pending_burst = emitter.pending_burst.saturating_add(count).min(max_total);
+        true
+    }
+
+    /// Sets a one-shot burst of `count` particles launched at `impulse_speed`, all spawned on the
+    /// next fixed step instead of being spread over frames like [`Self::emitter_burst`]. A second
+    /// call before that step replaces the pending burst. Returns `false` without an emitter.
+    pub fn set_emitter_burst(&mut self, entity: Entity, count: u32, impulse_speed: f32) -> bool {
+        if self.world.get::<ParticleEmitter>(entity).is_none() {
+            return false;
+        }
+        let impulse_speed = if impulse_speed.is_finite() { impulse_speed.max(0.0) } else { 0.0 };
+        if let Some(mut burst) = self.world.get_mut::<BurstEmitter>(entity) {
+            burst.queued.push((count, impulse_speed));
+        } else {
+            self.world.entity_mut(entity).insert(BurstEmitter::new(count, impulse_speed));
+        }
+        true
+    }
+
     pub fn set_force_field(&mut self, entity: Entity, field: Option<ForceField>) {
         let mut entity_mut = self.world.entity_mut(entity);
         match field {
@@ -541,6 +765,7 @@ impl EcsWorld {
         let mut emitters = self.world.query::<&mut ParticleEmitter>();
         for mut emitter in emitters.iter_mut(&mut self.world) {
             emitter.accumulator = 0.0;
+            emitter.pending_burst = 0;
         }
         self.world.resource_mut::<ParticleContacts>().pairs.clear();
         if let Some(mut state) = self.world.get_resource_mut::<ParticleState>() {
@@ -557,17 +782,24 @@ impl EcsWorld {
         let mut trail_emitters = 0u32;
         let mut backlog_total = 0.0f32;
         let mut backlog_max = 0.0f32;
+        let mut burst_backlog = 0u32;
+        let mut collision_emitters = 0u32;
         for emitter in emitter_query.iter(&self.world) {
             total_emitters += 1;
+            if emitter.collision_mode.is_enabled() {
+                collision_emitters += 1;
+            }
             if emitter.trail.is_some() {
                 trail_emitters += 1;
             }
             backlog_total += emitter.accumulator;
             backlog_max = backlog_max.max(emitter.accumulator);
+            burst_backlog = burst_backlog.saturating_add(emitter.pending_burst);
         }
         let force_fields = self.world.query::<&ForceField>().iter(&self.world).count() as u32;
         let attractors = self.world.query::<&ParticleAttractor>().iter(&self.world).count() as u32;
         let available_spawn = caps.max_total.saturating_sub(active_particles).min(caps.max_spawn_per_frame);
+        let collision_stats = *self.world.resource::<ParticleCollisionStats>();
         ParticleBudgetMetrics {
             active_particles,
             available_spawn_this_frame: available_spawn,
@@ -580,6 +812,12 @@ impl EcsWorld {
             emitter_backlog_total: backlog_total,
             emitter_backlog_max_observed: backlog_max,
             emitter_backlog_limit: caps.max_emitter_backlog,
+            burst_backlog_total: burst_backlog,
+            collision_emitters,
+            collision_tests: collision_stats.tests,
+            collision_hits: collision_stats.hits,
+            collision_particle_limit: caps.max_collision_particles,
+            collision_checks_skipped: collision_stats.skipped,
         }
     }
 
@@ -600,6 +838,17 @@ impl EcsWorld {
         }
     }
 
+    /// Per-timeline play, loop and frame-event counters gathered since startup or the last reset.
+    pub fn sprite_timeline_usage(&self) -> Option<&SpriteTimelineUsage> {
+        self.world.get_resource::<SpriteTimelineUsage>()
+    }
+
+    pub fn reset_sprite_timeline_usage(&mut self) {
+        if let Some(mut usage) = self.world.get_resource_mut::<SpriteTimelineUsage>() {
+            usage.clear();
+        }
+    }
+
     pub fn clear_world(&mut self) {
         {
             let mut rapier = self.world.resource_mut::<RapierState>();
@@ -615,6 +864,7 @@ impl EcsWorld {
             let _ = self.world.despawn(entity);
         }
         self.world.resource_mut::<ParticleContacts>().pairs.clear();
+        self.world.resource_mut::<PluginBlobStore>().clear();
     }
 
     pub fn set_particle_caps(&mut self, caps: ParticleCaps) {
@@ -651,10 +901,96 @@ impl EcsWorld {
         }
         self.schedule_var.run(&mut self.world);
         self.world.resource_mut::<AnimationPlan>().delta = AnimationDelta::None;
+        self.despawn_pending();
     }
     pub fn fixed_step(&mut self, dt: f32) {
         self.world.resource_mut::<TimeDelta>().0 = dt;
         self.schedule_fixed.run(&mut self.world);
+        self.despawn_pending();
+    }
+
+    /// Despawns what `Lifetime` and `DespawnOutOfBounds` flagged during the last schedule run, in
+    /// entity order so deterministic runs free ids the same way.
+    fn despawn_pending(&mut self) {
+        let mut pending = std::mem::take(&mut self.world.resource_mut::<PendingDespawns>().0);
+        if pending.is_empty() {
+            return;
+        }
+        pending.sort_unstable();
+        pending.dedup();
+        for entity in pending.drain(..) {
+            self.despawn_entity(entity);
+        }
+        self.world.resource_mut::<PendingDespawns>().0 = pending;
+    }
+
+    /// Gives `entity` a [`Lifetime`] of `seconds`, or removes it with `None`.
+    pub fn set_lifetime(&mut self, entity: Entity, seconds: Option<f32>) -> bool {
+        let Ok(mut entity_mut) = self.world.get_entity_mut(entity) else {
+            return false;
+        };
+        match seconds {
+            Some(seconds) if seconds.is_finite() => {
+                entity_mut.insert(Lifetime { seconds });
+            }
+            Some(_) => return false,
+            None => {
+                entity_mut.remove::<Lifetime>();
+            }
+        }
+        true
+    }
+
+    /// Gives `entity` a [`DespawnOutOfBounds`] with `margin`, or removes it with `None`.
+    pub fn set_despawn_out_of_bounds(&mut self, entity: Entity, margin: Option<f32>) -> bool {
+        let Ok(mut entity_mut) = self.world.get_entity_mut(entity) else {
+            return false;
+        };
+        match margin {
+            Some(margin) if margin.is_finite() => {
+                entity_mut.insert(DespawnOutOfBounds { margin: margin.max(0.0) });
+            }
+            Some(_) => return false,
+            None => {
+                entity_mut.remove::<DespawnOutOfBounds>();
+            }
+        }
+        true
+    }
+
+    /// Widens the [`DespawnOutOfBounds`] rect to cover the camera view `(min, max)`; `None` falls
+    /// back to the world bounds alone.
+    pub fn set_despawn_view(&mut self, view: Option<(Vec2, Vec2)>) {
+        self.world.resource_mut::<DespawnView>().0 = view;
+    }
+
+    /// Captures the simulation state (gameplay components, physics, nav grid and particle RNG) so it
+    /// can be rolled back to with [`EcsWorld::restore`].
+    pub fn snapshot(&self) -> WorldSnapshot {
+        WorldSnapshot::capture(&self.world)
+    }
+
+    /// Rolls the simulation back to `snapshot`. Entities despawned since the capture come back under
+    /// new ids; the returned map translates their captured ids for callers holding on to them.
+    pub fn restore(&mut self, snapshot: &WorldSnapshot) -> HashMap<Entity, Entity> {
+        snapshot.apply(&mut self.world)
+    }
+
+    /// Restores `snapshot` (taken at the start of `frames.start`) and re-simulates `frames`, handing
+    /// `step` the inputs logged for each frame. `step` applies the inputs and advances the world the
+    /// same way the original run did, so the result matches it exactly.
+    pub fn replay<I>(
+        &mut self,
+        snapshot: &WorldSnapshot,
+        log: &FrameInputLog<I>,
+        frames: std::ops::Range<u64>,
+        mut step: impl FnMut(&mut EcsWorld, &[I]),
+    ) -> HashMap<Entity, Entity> {
+        let respawned = self.restore(snapshot);
+        for frame in frames {
+            step(self, log.inputs(frame));
+        }
+        respawned
     }
     pub fn adjust_root_spin(&mut self, delta: f32) {
         let mut q = self.world.query::<&mut Spin>();
@@ -670,6 +1006,24 @@ impl EcsWorld {
         position: Vec2,
         scale: f32,
         velocity: Vec2,
+    ) -> Result<Entity> {
+        self.spawn_scripted_sprite_with_lifetime(assets, atlas, region, position, scale, velocity, None)
+    }
+
+    /// Spawns a scripted sprite that despawns itself after `lifetime` seconds when one is given.
+    /// Scripted sprites share one archetype, so their table rows and entity slots are reused after
+    /// a despawn; their physics bodies are parked in a pool and picked up again here instead of
+    /// being rebuilt.
+    #[allow(clippy::too_many_arguments)]
+    pub fn spawn_scripted_sprite_with_lifetime(
+        &mut self,
+        assets: &AssetManager,
+        atlas: &str,
+        region: &str,
+        position: Vec2,
+        scale: f32,
+        velocity: Vec2,
+        lifetime: Option<f32>,
     ) -> Result<Entity> {
         if scale <= 0.0 {
             return Err(anyhow!("Scale must be positive"));
@@ -677,31 +1031,42 @@ impl EcsWorld {
         let Some((region_name, info)) = assets.atlas_region_info(atlas, region) else {
             return Err(anyhow!("Region '{region}' not found in atlas '{atlas}'"));
         };
+        if self.spawn_allowance(1, "spawn_sprite") == 0 {
+            return Err(anyhow!("Entity cap of {} reached", self.max_entities.unwrap_or_default()));
+        }
+        if lifetime.is_some_and(|seconds| !seconds.is_finite()) {
+            return Err(anyhow!("Lifetime must be finite"));
+        }
         let half = Vec2::splat(scale * 0.5);
         let (body_handle, collider_handle) = {
             let mut rapier = self.world.resource_mut::<RapierState>();
-            rapier.spawn_dynamic_body(position, half, 1.0, velocity)
+            match rapier.reuse_dynamic_body(position, half, 1.0, velocity) {
+                Some(handles) => handles,
+                None => rapier.spawn_dynamic_body(position, half, 1.0, velocity),
+            }
+        };
+        let bundle = (
+            Transform { translation: position, rotation: 0.0, scale: Vec2::splat(scale) },
+            WorldTransform::default(),
+            Sprite {
+                atlas_key: Arc::from(atlas.to_string()),
+                region: Arc::clone(region_name),
+                region_id: info.id,
+                uv: info.uv,
+            },
+            Aabb { half },
+            Velocity(velocity),
+            Force::default(),
+            Mass(1.0),
+            RapierBody { handle: body_handle },
+            RapierCollider { handle: collider_handle },
+            SceneEntityTag::new(self.allocate_scene_entity_id()),
+            PooledSprite,
+        );
+        let entity = match lifetime {
+            Some(seconds) => self.world.spawn((bundle, Lifetime { seconds })).id(),
+            None => self.world.spawn(bundle).id(),
         };
-        let entity = self
-            .world
-            .spawn((
-                Transform { translation: position, rotation: 0.0, scale: Vec2::splat(scale) },
-                WorldTransform::default(),
-                Sprite {
-                    atlas_key: Arc::from(atlas.to_string()),
-                    region: Arc::clone(region_name),
-                    region_id: info.id,
-                    uv: info.uv,
-                },
-                Aabb { half },
-                Velocity(velocity),
-                Force::default(),
-                Mass(1.0),
-                RapierBody { handle: body_handle },
-                RapierCollider { handle: collider_handle },
-            ))
-            .id();
-        self.ensure_scene_entity_tag(entity);
         {
             let mut rapier = self.world.resource_mut::<RapierState>();
             rapier.register_collider_entity(collider_handle, entity);
@@ -716,23 +1081,59 @@ impl EcsWorld {
     }
 
     pub fn spawn_mesh_entity(&mut self, mesh_key: &str, translation: Vec3, scale: Vec3) -> Entity {
-        let transform3d = Transform3D { translation, rotation: Quat::IDENTITY, scale };
-        let world3d =
-            WorldTransform3D(Mat4::from_scale_rotation_translation(scale, Quat::IDENTITY, translation));
-        let entity = self
-            .world
-            .spawn((
-                Transform::default(),
-                WorldTransform::default(),
-                transform3d,
-                world3d,
-                MeshRef { key: mesh_key.to_string() },
-                MeshSurface::default(),
-            ))
-            .id();
+        let entity =
+            self.spawn_transform3d_node(Transform3D { translation, rotation: Quat::IDENTITY, scale });
+        self.world.entity_mut(entity).insert((MeshRef { key: mesh_key.to_string() }, MeshSurface::default()));
+        entity
+    }
+
+    /// Spawns a glTF scene-graph import (see `MeshRegistry::import_gltf_scene`) as parented 3D
+    /// entities under a new root at `translation` and returns the root. A node with one mesh part
+    /// draws it itself; a node with several gets one child entity per part.
+    pub fn spawn_mesh_hierarchy(&mut self, nodes: &[ImportedNode], translation: Vec3) -> Result<Entity> {
+        let root = self.spawn_transform3d_node(Transform3D { translation, ..Default::default() });
+        let mut spawned: Vec<Entity> = Vec::with_capacity(nodes.len());
+        for node in nodes {
+            let parent = match node.parent {
+                Some(index) => *spawned
+                    .get(index)
+                    .ok_or_else(|| anyhow!("Mesh node '{}' is listed before its parent", node.name))?,
+                None => root,
+            };
+            let transform =
+                Transform3D { translation: node.translation, rotation: node.rotation, scale: node.scale };
+            let entity = self.spawn_transform3d_node(transform);
+            self.set_parent(entity, parent)?;
+            if let [part] = node.meshes.as_slice() {
+                self.attach_mesh_part(entity, part);
+            } else {
+                for part in &node.meshes {
+                    let child = self.spawn_transform3d_node(Transform3D::default());
+                    self.attach_mesh_part(child, part);
+                    self.set_parent(child, entity)?;
+                }
+            }
+            spawned.push(entity);
+        }
+        Ok(root)
+    }
+
+    fn spawn_transform3d_node(&mut self, transform: Transform3D) -> Entity {
+        let world3d = WorldTransform3D(Mat4::from_scale_rotation_translation(
+            transform.scale,
+            transform.rotation,
+            transform.translation,
+        ));
+        let entity =
+            self.world.spawn((Transform::default(), WorldTransform::default(), transform, world3d)).id();
         self.ensure_scene_entity_tag(entity);
         entity
     }
+
+    fn attach_mesh_part(&mut self, entity: Entity, part: &ImportedNodeMesh) {
+        let surface = MeshSurface { material: part.material.clone(), ..Default::default() };
+        self.world.entity_mut(entity).insert((MeshRef { key: part.key.clone() }, surface));
+    }
     pub fn set_velocity(&mut self, entity: Entity, velocity: Vec2) -> bool {
         let mut updated = false;
         {
@@ -793,6 +1194,9 @@ impl EcsWorld {
             transform.scale = scale;
             changed = true;
         }
+        if self.world.get::<ColliderShape>(entity).is_some() {
+            return changed;
+        }
         let half = Vec2::new(scale.x.abs(), scale.y.abs()) * 0.5;
         let previous_half = self.world.get::<Aabb>(entity).map(|a| a.half);
         if let Some(mut aabb) = self.world.get_mut::<Aabb>(entity) {
@@ -824,6 +1228,15 @@ impl EcsWorld {
         }
         changed
     }
+    /// Returns the entity to the identity transform (origin, no rotation, unit scale) through the
+    /// regular setters so physics bodies and colliders follow.
+    pub fn reset_transform(&mut self, entity: Entity) -> bool {
+        let translated = self.set_translation(entity, Vec2::ZERO);
+        let rotated = self.set_rotation(entity, 0.0);
+        let scaled = self.set_scale(entity, Vec2::ONE);
+        translated && rotated && scaled
+    }
+
     pub fn set_tint(&mut self, entity: Entity, color: Option<Vec4>) -> bool {
         match color {
             Some(color) => {
@@ -913,6 +1326,17 @@ impl EcsWorld {
         }
     }
 
+    /// Plays a looping clip `count` times before it stops on its end pose and emits
+    /// `ClipFinished`. `None` restores endless looping.
+    pub fn set_transform_clip_loop_count(&mut self, entity: Entity, count: Option<u32>) -> bool {
+        if let Some(mut instance) = self.world.get_mut::<ClipInstance>(entity) {
+            instance.set_loop_count(count);
+            true
+        } else {
+            false
+        }
+    }
+
     pub fn set_transform_clip_speed(&mut self, entity: Entity, speed: f32) -> bool {
         if let Some(mut instance) = self.world.get_mut::<ClipInstance>(entity) {
             if speed.is_finite() {
@@ -933,6 +1357,15 @@ impl EcsWorld {
         }
     }
 
+    pub fn set_transform_clip_events(&mut self, entity: Entity, events: Vec<(f32, String)>) -> bool {
+        if let Some(mut instance) = self.world.get_mut::<ClipInstance>(entity) {
+            instance.set_events(events);
+            true
+        } else {
+            false
+        }
+    }
+
     pub fn set_transform_clip_time(&mut self, entity: Entity, time: f32) -> bool {
         if let Some(mut instance) = self.world.get_mut::<ClipInstance>(entity) {
             instance.set_time(time);
@@ -957,6 +1390,72 @@ impl EcsWorld {
         }
     }
 
+    /// Starts the shared `curve_key` curve on the entity's tint, replacing any running curve. The
+    /// first stop is applied immediately so the entity never renders a frame with its old tint.
+    pub fn set_tint_curve(
+        &mut self,
+        entity: Entity,
+        assets: &AssetManager,
+        curve_key: &str,
+        duration: f32,
+        loop_mode: SpriteAnimationLoopMode,
+    ) -> bool {
+        let Some(curve) = assets.color_curve(curve_key) else {
+            return false;
+        };
+        if !duration.is_finite() || duration <= 0.0 {
+            return false;
+        }
+        let Ok(mut entity_mut) = self.world.get_entity_mut(entity) else {
+            return false;
+        };
+        let tint_curve = TintCurve::new(Arc::from(curve_key), curve, duration, loop_mode);
+        entity_mut.insert((Tint(tint_curve.sample()), tint_curve));
+        true
+    }
+
+    pub fn clear_tint_curve(&mut self, entity: Entity) -> bool {
+        if self.world.get::<TintCurve>(entity).is_some() {
+            self.world.entity_mut(entity).remove::<TintCurve>();
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn set_tint_curve_elapsed(&mut self, entity: Entity, elapsed: f32) -> bool {
+        if !elapsed.is_finite() {
+            return false;
+        }
+        let Some(mut curve) = self.world.get_mut::<TintCurve>(entity) else {
+            return false;
+        };
+        curve.elapsed = 0.0;
+        curve.advance(elapsed.max(0.0));
+        let color = curve.sample();
+        if let Some(mut tint) = self.world.get_mut::<Tint>(entity) {
+            tint.0 = color;
+        }
+        true
+    }
+
+    /// Points every [`TintCurve`] using `curve_key` at the asset manager's current copy of the curve,
+    /// e.g. after a hot reload. Returns how many entities were rebound.
+    pub fn refresh_color_curve(&mut self, assets: &AssetManager, curve_key: &str) -> usize {
+        let Some(curve) = assets.color_curve(curve_key) else {
+            return 0;
+        };
+        let mut rebound = 0;
+        let mut query = self.world.query::<&mut TintCurve>();
+        for mut tint_curve in query.iter_mut(&mut self.world) {
+            if tint_curve.curve_key.as_ref() == curve_key {
+                tint_curve.curve = Arc::clone(&curve);
+                rebound += 1;
+            }
+        }
+        rebound
+    }
+
     pub fn set_skeleton(&mut self, entity: Entity, assets: &AssetManager, skeleton_key: &str) -> bool {
         let skeleton = match assets.skeleton(skeleton_key) {
             Some(value) => value,
@@ -1015,45 +1514,245 @@ impl EcsWorld {
         self.refresh_skeleton_pose(entity)
     }
 
-    pub fn clear_skeleton_clip(&mut self, entity: Entity) -> bool {
-        match self.world.get_mut::<SkeletonInstance>(entity) {
-            Some(mut instance) => {
-                if instance.active_clip.is_none() {
-                    return false;
-                }
-                instance.set_active_clip(None, None);
-                instance.set_time(0.0);
-            }
-            None => return false,
+    /// Attaches an animation graph to `entity`, resolving each state's clip as a transform clip or,
+    /// failing that, a skeletal clip, and starts the entry state's clip. Blend states resolve each
+    /// of their clips as skeletal clips.
+    pub fn set_animation_graph(&mut self, entity: Entity, assets: &AssetManager, graph_key: &str) -> bool {
+        let Some(graph) = assets.animation_graph(graph_key).cloned() else {
+            return false;
+        };
+        if self.world.get_entity(entity).is_err() {
+            return false;
         }
-        self.refresh_skeleton_pose(entity)
+        let (state_clips, requires_tint) = self.resolve_graph_state_clips(entity, assets, &graph);
+        let entry_clip = state_clips.get(graph.entry_state.as_ref()).cloned();
+        self.insert_graph_track_players(entity, &state_clips, requires_tint);
+        self.world.entity_mut(entity).insert(AnimationGraphInstance::new(
+            Arc::from(graph_key),
+            graph,
+            state_clips,
+        ));
+        self.start_graph_state_clip(entity, assets, entry_clip);
+        true
     }
 
-    pub fn set_skeleton_clip_playing(&mut self, entity: Entity, playing: bool) -> bool {
-        if let Some(mut instance) = self.world.get_mut::<SkeletonInstance>(entity) {
-            instance.set_playing(playing);
-            true
-        } else {
-            false
+    /// Rebinds every entity playing `graph_key` to the graph currently registered in `assets`,
+    /// typically after a hot reload. Parameter values carry over; entities whose state no longer
+    /// exists fall back to the entry state. Returns the number of rebound entities.
+    pub fn rebind_animation_graphs(&mut self, assets: &AssetManager, graph_key: &str) -> usize {
+        let Some(graph) = assets.animation_graph(graph_key).cloned() else {
+            return 0;
+        };
+        let entities: Vec<Entity> = self
+            .world
+            .query::<(Entity, &AnimationGraphInstance)>()
+            .iter(&self.world)
+            .filter(|(_, instance)| instance.graph_key.as_ref() == graph_key)
+            .map(|(entity, _)| entity)
+            .collect();
+        for &entity in &entities {
+            let (state_clips, requires_tint) = self.resolve_graph_state_clips(entity, assets, &graph);
+            self.insert_graph_track_players(entity, &state_clips, requires_tint);
+            let Some(mut instance) = self.world.get_mut::<AnimationGraphInstance>(entity) else {
+                continue;
+            };
+            let previous_clip = instance.state_clip(&instance.current_state).cloned();
+            let fell_back = instance.rebind(graph.clone(), state_clips);
+            let state_clip = instance.state_clip(&instance.current_state).cloned();
+            let rebinding_changed = match (&previous_clip, &state_clip) {
+                (Some(previous), Some(current)) => !previous.same_binding(current),
+                (None, None) => false,
+                _ => true,
+            };
+            if fell_back || rebinding_changed {
+                self.start_graph_state_clip(entity, assets, state_clip);
+            }
         }
+        entities.len()
     }
 
-    pub fn set_skeleton_clip_speed(&mut self, entity: Entity, speed: f32) -> bool {
-        if let Some(mut instance) = self.world.get_mut::<SkeletonInstance>(entity) {
-            if speed.is_finite() {
-                instance.set_speed(speed);
+    fn resolve_graph_state_clips(
+        &self,
+        entity: Entity,
+        assets: &AssetManager,
+        graph: &AnimationGraphAsset,
+    ) -> (HashMap<Arc<str>, AnimationGraphClip>, bool) {
+        let skeleton_names = self
+            .world
+            .get::<SkeletonInstance>(entity)
+            .map(|instance| (Arc::clone(&instance.skeleton_key), Arc::clone(&instance.skeleton.name)));
+        let skeletal_clip = |clip_key: &str| {
+            assets.skeletal_clip(clip_key).filter(|clip| {
+                skeleton_names.as_ref().is_none_or(|(skeleton_key, skeleton_name)| {
+                    clip.skeleton == *skeleton_key || clip.skeleton == *skeleton_name
+                })
+            })
+        };
+        let mut state_clips = HashMap::new();
+        let mut requires_tint = false;
+        for state in graph.states.iter() {
+            if let Some(blend) = state.blend.as_ref() {
+                let clips = blend
+                    .clips
+                    .iter()
+                    .map(|entry| {
+                        skeletal_clip(&entry.clip).map(|clip| (Arc::from(entry.clip.as_str()), clip))
+                    })
+                    .collect::<Vec<_>>();
+                if clips.iter().any(Option::is_some) {
+                    state_clips.insert(
+                        Arc::clone(&state.name),
+                        AnimationGraphClip::SkeletalBlend { blend: blend.clone(), clips },
+                    );
+                }
+                continue;
+            }
+            let Some(clip_key) = state.clip.as_deref() else {
+                continue;
+            };
+            let key: Arc<str> = Arc::from(clip_key);
+            if let Some(clip) = assets.clip(clip_key) {
+                requires_tint |= clip.tint.is_some();
+                let clip = Arc::new(clip.clone());
+                state_clips.insert(Arc::clone(&state.name), AnimationGraphClip::Transform { key, clip });
+            } else if let Some(clip) = skeletal_clip(clip_key) {
+                state_clips.insert(Arc::clone(&state.name), AnimationGraphClip::Skeletal { key, clip });
             }
-            true
-        } else {
-            false
         }
+        (state_clips, requires_tint)
     }
 
-    pub fn set_skeleton_clip_group(&mut self, entity: Entity, group: Option<&str>) -> bool {
-        if let Some(mut instance) = self.world.get_mut::<SkeletonInstance>(entity) {
-            instance.set_group(group.map(|value| value.to_string()));
-            true
-        } else {
+    fn insert_graph_track_players(
+        &mut self,
+        entity: Entity,
+        state_clips: &HashMap<Arc<str>, AnimationGraphClip>,
+        requires_tint: bool,
+    ) {
+        let has_transform_clips =
+            state_clips.values().any(|clip| matches!(clip, AnimationGraphClip::Transform { .. }));
+        let mut entity_mut = self.world.entity_mut(entity);
+        if has_transform_clips && !entity_mut.contains::<TransformTrackPlayer>() {
+            entity_mut.insert(TransformTrackPlayer::default());
+        }
+        if requires_tint && !entity_mut.contains::<PropertyTrackPlayer>() {
+            entity_mut.insert(PropertyTrackPlayer::default());
+        }
+        if requires_tint && !entity_mut.contains::<Tint>() {
+            entity_mut.insert(Tint(Vec4::ONE));
+        }
+    }
+
+    /// Starts a state's clip immediately; blend states are posed by the graph system on its next run.
+    fn start_graph_state_clip(
+        &mut self,
+        entity: Entity,
+        assets: &AssetManager,
+        clip: Option<AnimationGraphClip>,
+    ) {
+        match clip {
+            Some(AnimationGraphClip::Transform { key, .. }) => {
+                self.set_transform_clip(entity, assets, key.as_ref());
+            }
+            Some(AnimationGraphClip::Skeletal { key, .. }) => {
+                self.set_skeleton_clip(entity, assets, key.as_ref());
+            }
+            Some(AnimationGraphClip::SkeletalBlend { .. }) => {
+                if let Some(mut instance) = self.world.get_mut::<SkeletonInstance>(entity) {
+                    instance.restart_blend();
+                }
+            }
+            None => {}
+        }
+    }
+
+    pub fn clear_animation_graph(&mut self, entity: Entity) -> bool {
+        if self.world.get::<AnimationGraphInstance>(entity).is_some() {
+            self.world.entity_mut(entity).remove::<AnimationGraphInstance>();
+            if let Some(mut instance) = self.world.get_mut::<SkeletonInstance>(entity) {
+                if !instance.blend_layers.is_empty() {
+                    instance.blend_layers.clear();
+                    instance.dirty = true;
+                }
+            }
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn set_graph_parameter(&mut self, entity: Entity, name: &str, value: f32) -> bool {
+        if !value.is_finite() {
+            return false;
+        }
+        match self.world.get_mut::<AnimationGraphInstance>(entity) {
+            Some(mut graph) => graph.set_parameter(name, value),
+            None => false,
+        }
+    }
+
+    pub fn trigger_graph_event(&mut self, entity: Entity, event: &str) -> bool {
+        match self.world.get_mut::<AnimationGraphInstance>(entity) {
+            Some(mut graph) => {
+                graph.trigger(event);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn animation_graph_state(&self, entity: Entity) -> Option<String> {
+        self.world.get::<AnimationGraphInstance>(entity).map(|graph| graph.current_state.clone())
+    }
+
+    pub fn clear_skeleton_clip(&mut self, entity: Entity) -> bool {
+        match self.world.get_mut::<SkeletonInstance>(entity) {
+            Some(mut instance) => {
+                if instance.active_clip.is_none() {
+                    return false;
+                }
+                instance.set_active_clip(None, None);
+                instance.set_time(0.0);
+            }
+            None => return false,
+        }
+        self.refresh_skeleton_pose(entity)
+    }
+
+    pub fn set_skeleton_clip_playing(&mut self, entity: Entity, playing: bool) -> bool {
+        if let Some(mut instance) = self.world.get_mut::<SkeletonInstance>(entity) {
+            instance.set_playing(playing);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Skeletal counterpart of [`EcsWorld::set_transform_clip_loop_count`].
+    pub fn set_skeleton_clip_loop_count(&mut self, entity: Entity, count: Option<u32>) -> bool {
+        if let Some(mut instance) = self.world.get_mut::<SkeletonInstance>(entity) {
+            instance.set_loop_count(count);
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn set_skeleton_clip_speed(&mut self, entity: Entity, speed: f32) -> bool {
+        if let Some(mut instance) = self.world.get_mut::<SkeletonInstance>(entity) {
+            if speed.is_finite() {
+                instance.set_speed(speed);
+            }
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn set_skeleton_clip_group(&mut self, entity: Entity, group: Option<&str>) -> bool {
+        if let Some(mut instance) = self.world.get_mut::<SkeletonInstance>(entity) {
+            instance.set_group(group.map(|value| value.to_string()));
+            true
+        } else {
             false
         }
     }
@@ -1077,11 +1776,56 @@ impl EcsWorld {
             let Some(mut instance) = self.world.get_mut::<SkeletonInstance>(entity) else {
                 return false;
             };
+            instance.loops_completed = 0;
             instance.set_time(0.0);
         }
         self.refresh_skeleton_pose(entity)
     }
 
+    /// `(bone name, world matrix)` for every joint of the skeleton on `entity` in its current pose,
+    /// for attaching props to bones. The pose is placed by the entity's 3D world transform when it has
+    /// one and by its 2D world transform otherwise.
+    pub fn collect_skeleton_bone_world_transforms(&self, entity: Entity) -> Option<Vec<(String, Mat4)>> {
+        let instance = self.world.get::<SkeletonInstance>(entity)?;
+        let entity_world = self.skeleton_entity_world(entity);
+        let bones = instance
+            .skeleton
+            .joints
+            .iter()
+            .zip(&instance.model_poses)
+            .map(|(joint, model)| (joint.name.to_string(), entity_world * *model))
+            .collect();
+        Some(bones)
+    }
+
+    /// Current pose of every joint of the skeleton on `entity`, for debug overlays and the inspector.
+    /// Reads the local and model poses left by the last animation update without sampling clips.
+    pub fn skeleton_joint_poses(&self, entity: Entity) -> Option<Vec<SkeletonJointPose>> {
+        let instance = self.world.get::<SkeletonInstance>(entity)?;
+        Some(skeleton_joint_poses(instance, self.skeleton_entity_world(entity)))
+    }
+
+    /// Joint poses for every skeleton in the world, ordered by entity.
+    pub fn all_skeleton_joint_poses(&mut self) -> Vec<(Entity, Vec<SkeletonJointPose>)> {
+        let mut query = self.world.query::<(Entity, &SkeletonInstance)>();
+        let mut poses: Vec<(Entity, Vec<SkeletonJointPose>)> = query
+            .iter(&self.world)
+            .map(|(entity, instance)| {
+                (entity, skeleton_joint_poses(instance, self.skeleton_entity_world(entity)))
+            })
+            .collect();
+        poses.sort_by_key(|(entity, _)| *entity);
+        poses
+    }
+
+    fn skeleton_entity_world(&self, entity: Entity) -> Mat4 {
+        self.world
+            .get::<WorldTransform3D>(entity)
+            .map(|world| world.0)
+            .or_else(|| self.world.get::<WorldTransform>(entity).map(|world| world.0))
+            .unwrap_or(Mat4::IDENTITY)
+    }
+
     pub fn attach_skin_mesh(&mut self, entity: Entity, joint_count: usize) -> bool {
         if self.world.get_entity(entity).is_err() {
             return false;
@@ -1288,6 +2032,108 @@ impl EcsWorld {
         }
     }
 
+    pub fn set_sprite_z_order(&mut self, entity: Entity, z_order: i16) -> bool {
+        if self.world.get::<Sprite>(entity).is_none() {
+            return false;
+        }
+        // Keep the component (even at 0) so the change tick marks the atlas bucket dirty.
+        if let Some(mut current) = self.world.get_mut::<ZOrder>(entity) {
+            if current.0 != z_order {
+                current.0 = z_order;
+            }
+        } else {
+            self.world.entity_mut(entity).insert(ZOrder(z_order));
+        }
+        true
+    }
+
+    pub fn sprite_z_order(&self, entity: Entity) -> i16 {
+        self.world.get::<ZOrder>(entity).map(|z| z.0).unwrap_or_default()
+    }
+
+    /// Assigns the normal map at `path` to a sprite, or clears it with `None`. The new image is
+    /// retained through `assets` and the previous one released. Returns `Ok(false)` when the entity
+    /// has no sprite.
+    pub fn set_sprite_normal_map(
+        &mut self,
+        entity: Entity,
+        path: Option<&str>,
+        assets: &mut AssetManager,
+    ) -> Result<bool> {
+        if self.world.get::<Sprite>(entity).is_none() {
+            return Ok(false);
+        }
+        let previous = self.world.get::<NormalMap>(entity).map(|normal_map| Arc::clone(&normal_map.0));
+        if previous.as_deref() == path {
+            return Ok(true);
+        }
+        // Hold a reference across the swap so an image that fails to load leaves the sprite as is.
+        if let Some(path) = path {
+            assets.retain_normal_map(path)?;
+        }
+        match path {
+            Some(path) => {
+                self.world.entity_mut(entity).insert(NormalMap(Arc::from(path)));
+            }
+            None => {
+                self.world.entity_mut(entity).remove::<NormalMap>();
+                // Removals are invisible to change ticks, so flag the sprite to dirty its bucket.
+                if let Some(mut sprite) = self.world.get_mut::<Sprite>(entity) {
+                    sprite.set_changed();
+                }
+            }
+        }
+        self.sync_normal_map_refs(assets);
+        if let Some(path) = path {
+            assets.release_normal_map(path);
+        }
+        Ok(true)
+    }
+
+    /// Applies the normal map references [`NormalMap`] components gained and dropped since the last
+    /// call, whether through `set_sprite_normal_map`, scene loads or despawns. Retains run first so
+    /// an image moving between sprites stays loaded. Images that fail to load are reported and their
+    /// sprites draw unlit.
+    pub fn sync_normal_map_refs(&mut self, assets: &mut AssetManager) {
+        let (retains, releases) = {
+            let mut queue = self.world.resource_mut::<NormalMapRefQueue>();
+            (std::mem::take(&mut queue.retains), std::mem::take(&mut queue.releases))
+        };
+        for path in retains {
+            if let Err(err) = assets.retain_normal_map(&path) {
+                eprintln!("[normal_map] '{path}' unavailable, its sprites draw unlit: {err:?}");
+            }
+        }
+        for path in releases {
+            assets.release_normal_map(&path);
+        }
+    }
+
+    pub fn sprite_normal_map(&self, entity: Entity) -> Option<Arc<str>> {
+        self.world.get::<NormalMap>(entity).map(|normal_map| Arc::clone(&normal_map.0))
+    }
+
+    /// Adds or replaces the sprite's afterimage trail, or removes it with `None`. Returns `false` when
+    /// the entity has no sprite.
+    pub fn set_sprite_trail(&mut self, entity: Entity, trail: Option<SpriteTrail>) -> bool {
+        if self.world.get::<Sprite>(entity).is_none() {
+            return false;
+        }
+        match trail {
+            Some(trail) => {
+                self.world.entity_mut(entity).insert(trail);
+            }
+            None => {
+                self.world.entity_mut(entity).remove::<SpriteTrail>();
+                // Removals are invisible to change ticks, so flag the sprite to drop the faded copies.
+                if let Some(mut sprite) = self.world.get_mut::<Sprite>(entity) {
+                    sprite.set_changed();
+                }
+            }
+        }
+        true
+    }
+
     pub fn set_sprite_timeline(
         &mut self,
         entity: Entity,
@@ -1299,9 +2145,9 @@ impl EcsWorld {
                 let previous_config = self
                     .world
                     .get::<SpriteAnimation>(entity)
-                    .map(|anim| (anim.start_offset, anim.random_start, anim.group.clone()));
+                    .map(|anim| (anim.start_offset, anim.random_start, anim.group.clone(), anim.loop_count));
                 let atlas = if let Some(sprite) = self.world.get::<Sprite>(entity) {
-                    sprite.atlas_key.to_string()
+                    Arc::clone(&sprite.atlas_key)
                 } else {
                     return false;
                 };
@@ -1318,7 +2164,7 @@ impl EcsWorld {
                 let offsets = Arc::clone(&definition.frame_offsets);
                 let total_duration = definition.total_duration;
                 let loop_mode = definition.loop_mode;
-                let component = SpriteAnimation::new(
+                let mut component = SpriteAnimation::new(
                     Arc::clone(&definition.name),
                     frames,
                     hot_frames,
@@ -1327,13 +2173,16 @@ impl EcsWorld {
                     total_duration,
                     loop_mode,
                 );
+                component.usage_id =
+                    Some(self.world.resource_mut::<SpriteTimelineUsage>().intern(&atlas, &definition.name));
                 self.world.entity_mut(entity).insert(component);
                 self.ensure_sprite_frame_state(entity);
                 if let Some(mut animation) = self.world.get_mut::<SpriteAnimation>(entity) {
-                    if let Some((offset, random, group)) = previous_config {
+                    if let Some((offset, random, group, loop_count)) = previous_config {
                         animation.start_offset = offset;
                         animation.random_start = random;
                         animation.group = group;
+                        animation.set_loop_count(loop_count);
                     }
                 }
                 self.reset_sprite_animation(entity);
@@ -1460,6 +2309,17 @@ impl EcsWorld {
         }
     }
 
+    /// Plays a looping timeline `count` times, then holds the last frame and emits
+    /// `AnimationFinished`. `None` restores endless looping.
+    pub fn set_sprite_animation_loop_count(&mut self, entity: Entity, count: Option<u32>) -> bool {
+        if let Some(mut animation) = self.world.get_mut::<SpriteAnimation>(entity) {
+            animation.set_loop_count(count);
+            true
+        } else {
+            false
+        }
+    }
+
     pub fn set_sprite_animation_loop_mode(&mut self, entity: Entity, mode: SpriteAnimationLoopMode) -> bool {
         if let Some(mut animation) = self.world.get_mut::<SpriteAnimation>(entity) {
             animation.set_mode(mode);
@@ -1528,8 +2388,7 @@ impl EcsWorld {
             animation.frame_durations = Arc::clone(&definition.durations);
             animation.timeline = Arc::clone(&definition.name);
             animation.has_events = animation.frames.iter().any(|frame| !frame.events.is_empty());
-            animation.fast_loop =
-                !animation.has_events && matches!(animation.mode, SpriteAnimationLoopMode::Loop);
+            animation.refresh_fast_loop();
 
             if animation.frames.is_empty() {
                 animation.frame_index = 0;
@@ -1640,6 +2499,96 @@ impl EcsWorld {
         updated
     }
 
+    /// Lists sprites on `atlas_key` whose region or animation timeline is no longer defined by the atlas.
+    /// Sprites driven by a live timeline are only checked for the timeline, since it owns their region.
+    pub fn missing_atlas_references(
+        &mut self,
+        atlas_key: &str,
+        assets: &AssetManager,
+    ) -> Vec<MissingAtlasReference> {
+        let mut missing = Vec::new();
+        let mut query = self.world.query::<(Entity, &Sprite, Option<&SpriteAnimation>)>();
+        for (entity, sprite, animation) in query.iter(&self.world) {
+            if sprite.atlas_key.as_ref() != atlas_key {
+                continue;
+            }
+            if let Some(animation) = animation {
+                if assets.atlas_timeline(atlas_key, animation.timeline.as_ref()).is_none() {
+                    missing.push(MissingAtlasReference {
+                        entity,
+                        kind: AtlasReferenceKind::Timeline,
+                        name: Arc::clone(&animation.timeline),
+                    });
+                } else {
+                    continue;
+                }
+            }
+            if assets.atlas_region_info(atlas_key, sprite.region.as_ref()).is_none() {
+                missing.push(MissingAtlasReference {
+                    entity,
+                    kind: AtlasReferenceKind::Region,
+                    name: Arc::clone(&sprite.region),
+                });
+            }
+        }
+        missing.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.entity.index().cmp(&b.entity.index())));
+        missing
+    }
+
+    /// Points every sprite on `atlas_key` that uses region `from` at region `to`. Returns the number of
+    /// sprites updated; sprites driven by a live timeline are left alone.
+    pub fn remap_sprite_region(
+        &mut self,
+        atlas_key: &str,
+        from: &str,
+        to: &str,
+        assets: &AssetManager,
+    ) -> usize {
+        if assets.atlas_region_info(atlas_key, to).is_none() {
+            return 0;
+        }
+        let targets: Vec<Entity> = {
+            let mut query = self.world.query::<(Entity, &Sprite, Option<&SpriteAnimation>)>();
+            query
+                .iter(&self.world)
+                .filter(|(_, sprite, animation)| {
+                    sprite.atlas_key.as_ref() == atlas_key
+                        && sprite.region.as_ref() == from
+                        && animation.is_none_or(|animation| {
+                            assets.atlas_timeline(atlas_key, animation.timeline.as_ref()).is_none()
+                        })
+                })
+                .map(|(entity, _, _)| entity)
+                .collect()
+        };
+        targets.into_iter().filter(|entity| self.set_sprite_region(*entity, assets, to)).count()
+    }
+
+    /// Switches every sprite on `atlas_key` playing timeline `from` over to timeline `to`. Returns the
+    /// number of sprites updated.
+    pub fn remap_sprite_timeline(
+        &mut self,
+        atlas_key: &str,
+        from: &str,
+        to: &str,
+        assets: &AssetManager,
+    ) -> usize {
+        if assets.atlas_timeline(atlas_key, to).is_none() {
+            return 0;
+        }
+        let targets: Vec<Entity> = {
+            let mut query = self.world.query::<(Entity, &Sprite, &SpriteAnimation)>();
+            query
+                .iter(&self.world)
+                .filter(|(_, sprite, animation)| {
+                    sprite.atlas_key.as_ref() == atlas_key && animation.timeline.as_ref() == from
+                })
+                .map(|(entity, _, _)| entity)
+                .collect()
+        };
+        targets.into_iter().filter(|entity| self.set_sprite_timeline(*entity, assets, Some(to))).count()
+    }
+
     pub fn reset_sprite_animation(&mut self, entity: Entity) -> bool {
         if let Some(mut animation) = self.world.get_mut::<SpriteAnimation>(entity) {
             if animation.frames.is_empty() {
@@ -1650,10 +2599,15 @@ impl EcsWorld {
             animation.playing = true;
             animation.forward = true;
             animation.prev_forward = true;
+            animation.loops_completed = 0;
             animation.refresh_current_duration();
             animation.refresh_pending_start_events();
+            let usage_id = animation.usage_id;
             let snapshot = Self::current_frame_snapshot(&animation);
             self.apply_sprite_snapshot(entity, snapshot);
+            if let Some(id) = usage_id {
+                self.world.resource_mut::<SpriteTimelineUsage>().record_play(id);
+            }
             true
         } else {
             false
@@ -1661,9 +2615,44 @@ impl EcsWorld {
     }
     pub fn collect_sprite_instances(&mut self, assets: &AssetManager) -> Result<Vec<SpriteInstance>> {
         let mut out = Vec::new();
-        let mut q =
-            self.world.query::<(&mut Sprite, Option<&WorldTransform>, Option<&Transform>, Option<&Tint>)>();
-        for (mut sprite, world, local, tint) in q.iter_mut(&mut self.world) {
+        let mut tracker = self.world.remove_resource::<SpriteInstanceTracker>().unwrap_or_default();
+        let last_collect = tracker.begin();
+        let this_run = self.world.change_tick();
+        let changed_since = |tick: Tick| last_collect.is_none_or(|last| tick.is_newer_than(last, this_run));
+        let mut q = self.world.query::<(
+            Entity,
+            &mut Sprite,
+            Option<Ref<WorldTransform>>,
+            Option<Ref<Transform>>,
+            Option<Ref<Tint>>,
+            Option<Ref<ZOrder>>,
+            Option<Ref<NormalMap>>,
+            Has<Velocity>,
+            Has<SpriteAnimation>,
+            Has<ClipInstance>,
+            Option<&mut SpriteTrail>,
+        )>();
+        let mut trail_budget = SpriteTrail::MAX_COPIES_PER_FRAME;
+        for (
+            entity,
+            mut sprite,
+            world,
+            local,
+            tint,
+            z_order,
+            normal_map,
+            moving,
+            sprite_animated,
+            clip_animated,
+            trail,
+        ) in q.iter_mut(&mut self.world)
+        {
+            let mut changed = changed_since(sprite.last_changed())
+                || world.as_ref().is_some_and(|wt| changed_since(wt.last_changed()))
+                || local.as_ref().is_some_and(|t| changed_since(t.last_changed()))
+                || tint.as_ref().is_some_and(|t| changed_since(t.last_changed()))
+                || z_order.as_ref().is_some_and(|z| changed_since(z.last_changed()))
+                || normal_map.as_ref().is_some_and(|n| changed_since(n.last_changed()));
             let atlas_key = Arc::clone(&sprite.atlas_key);
             let atlas_key_str = atlas_key.as_ref();
             let uv_rect = if sprite.is_initialized() {
@@ -1674,6 +2663,7 @@ impl EcsWorld {
                 sprite.region = region.clone();
                 sprite.region_id = info.id;
                 sprite.uv = info.uv;
+                changed = true;
                 info.uv
             } else {
                 sprite.uv
@@ -1688,11 +2678,52 @@ impl EcsWorld {
             let color = tint.map(|t| t.0.to_array()).unwrap_or([1.0, 1.0, 1.0, 1.0]);
             let transform = SpriteInstanceTransform::from_mat4(model_mat);
             let world_half_extent = transform.half_extent_2d();
-            out.push(SpriteInstance { atlas: atlas_key, transform, uv_rect, tint: color, world_half_extent });
+            let z_order = z_order.map(|z| z.0).unwrap_or_default();
+            let mut instance = SpriteInstance {
+                atlas: atlas_key,
+                transform,
+                uv_rect,
+                tint: color,
+                world_half_extent,
+                z_order,
+                static_sprite: false,
+                normal_map: normal_map.map(|n| Arc::clone(&n.0)),
+            };
+            if let Some(mut trail) = trail {
+                changed |= trail.emit_copies(model_mat, &instance, &mut trail_budget, &mut out);
+            }
+            let static_sprite = !changed && !moving && !sprite_animated && !clip_animated;
+            tracker.record(&instance.atlas, entity, changed, static_sprite);
+            instance.static_sprite = static_sprite;
+            out.push(instance);
+        }
+        alloc_sampler::record_growth::<SpriteInstance>(AllocationSite::SpriteInstances, 0, out.capacity());
+        // Anything touched after this point lands on a newer tick and shows up next collection.
+        tracker.finish(self.world.increment_change_tick());
+        self.world.insert_resource(tracker);
+        // Stable sort keeps creation order among equal keys; most scenes never set a z-order. Atlas
+        // groups keep the order their first sprite appeared in, as batching expects.
+        if out.iter().any(|instance| instance.z_order != 0) {
+            let mut atlas_rank: HashMap<Arc<str>, usize> = HashMap::new();
+            for instance in &out {
+                let next = atlas_rank.len();
+                atlas_rank.entry(Arc::clone(&instance.atlas)).or_insert(next);
+            }
+            out.sort_by_key(|instance| (atlas_rank[&instance.atlas], instance.z_order));
         }
         Ok(out)
     }
 
+    /// Whether the atlas bucket changed during the last `collect_sprite_instances` call.
+    pub fn sprite_atlas_dirty(&self, atlas: &str) -> bool {
+        self.world.get_resource::<SpriteInstanceTracker>().is_none_or(|tracker| tracker.is_dirty(atlas))
+    }
+
+    /// Whether the set of static sprites changed during the last `collect_sprite_instances` call.
+    pub fn static_sprites_dirty(&self) -> bool {
+        self.world.get_resource::<SpriteInstanceTracker>().is_none_or(|tracker| tracker.static_dirty())
+    }
+
     pub fn collect_mesh_instances(&mut self) -> Vec<MeshInstance> {
         let mut instances = Vec::new();
         let mut query = self.world.query::<(
@@ -1705,6 +2736,7 @@ impl EcsWorld {
         for (wt, mesh, surface, bone_transforms, skin_mesh) in query.iter(&self.world) {
             let lighting = surface.map(|s| MeshLightingInfo::from(&s.lighting)).unwrap_or_default();
             let material = surface.and_then(|s| s.material.clone());
+            let max_draw_distance = surface.and_then(|s| s.max_draw_distance);
             let skin = match (bone_transforms, skin_mesh) {
                 (Some(bones), Some(skin)) if skin.joints() > 0 && bones.palette.len() >= skin.joints() => {
                     let mut palette = bones.palette.clone();
@@ -1713,8 +2745,16 @@ impl EcsWorld {
                 }
                 _ => None,
             };
-            instances.push(MeshInstance { key: mesh.key.clone(), model: wt.0, material, lighting, skin });
+            instances.push(MeshInstance {
+                key: mesh.key.clone(),
+                model: wt.0,
+                material,
+                lighting,
+                skin,
+                max_draw_distance,
+            });
         }
+        alloc_sampler::record_growth::<MeshInstance>(AllocationSite::MeshInstances, 0, instances.capacity());
         instances
     }
 
@@ -1776,6 +2816,16 @@ impl EcsWorld {
         }
     }
 
+    /// Sets the camera distance past which the mesh is culled; non-positive values clear the limit.
+    pub fn set_mesh_max_draw_distance(&mut self, entity: Entity, distance: Option<f32>) -> bool {
+        if let Some(mut surface) = self.world.get_mut::<MeshSurface>(entity) {
+            surface.max_draw_distance = distance.filter(|value| value.is_finite() && *value > 0.0);
+            true
+        } else {
+            false
+        }
+    }
+
     pub fn set_mesh_material_params(
         &mut self,
         entity: Entity,
@@ -1810,7 +2860,8 @@ impl EcsWorld {
     }
     pub fn entity_count(&self) -> usize {
         let boundary = self.world.resource::<RapierState>().boundary_entity();
-        self.world.iter_entities().filter(|entity_ref| entity_ref.id() != boundary).count()
+        let live = self.world.entities().len() as usize;
+        live - usize::from(self.world.entities().contains(boundary))
     }
 
     pub fn transform_clip_metrics(&mut self) -> TransformClipMetrics {
@@ -1974,14 +3025,158 @@ impl EcsWorld {
 
     pub fn collider_rects(&mut self) -> Vec<(Vec2, Vec2)> {
         let mut rects = Vec::new();
-        let mut query = self.world.query::<(&WorldTransform, &Aabb)>();
-        for (wt, aabb) in query.iter(&self.world) {
-            let center = Vec2::new(wt.0.w_axis.x, wt.0.w_axis.y);
+        let mut query = self.world.query::<(&WorldTransform, &Aabb, Option<&ColliderShape>)>();
+        for (wt, aabb, shape) in query.iter(&self.world) {
+            let center = Vec2::new(wt.0.w_axis.x, wt.0.w_axis.y) + ColliderShape::offset_of(shape);
             rects.push((center - aabb.half, center + aabb.half));
         }
         rects
     }
 
+    pub fn collider_geometry(&self, entity: Entity) -> Option<ColliderGeometry> {
+        let half = self.world.get::<Aabb>(entity)?.half;
+        let shape = self.world.get::<ColliderShape>(entity).copied().unwrap_or_default();
+        Some(ColliderGeometry { kind: shape.kind, half, offset: shape.offset })
+    }
+
+    /// Replaces the collider's shape, size and offset without touching the entity scale, keeping the
+    /// rapier collider in sync. Entities without an `Aabb` have no collider and are left untouched.
+    pub fn set_collider_geometry(&mut self, entity: Entity, geometry: ColliderGeometry) -> bool {
+        if self.world.get::<Aabb>(entity).is_none() {
+            return false;
+        }
+        let half = match geometry.kind {
+            ColliderKind::Rect => geometry.half.abs().max(Vec2::splat(MIN_COLLIDER_HALF_EXTENT)),
+            ColliderKind::Circle => Vec2::splat(geometry.half.x.abs().max(MIN_COLLIDER_HALF_EXTENT)),
+        };
+        let geometry = ColliderGeometry { half, ..geometry };
+        if let Some(mut aabb) = self.world.get_mut::<Aabb>(entity) {
+            aabb.half = half;
+        }
+        self.world.entity_mut(entity).insert(ColliderShape { kind: geometry.kind, offset: geometry.offset });
+        if let Some(handle) = self.world.get::<RapierCollider>(entity).map(|c| c.handle) {
+            self.world.resource_mut::<RapierState>().set_collider_geometry(handle, &geometry);
+        }
+        true
+    }
+
+    /// Resizes a rectangular collider; circle colliders become rectangles.
+    pub fn set_collider_half_extents(&mut self, entity: Entity, half: Vec2) -> bool {
+        let Some(geometry) = self.collider_geometry(entity) else {
+            return false;
+        };
+        self.set_collider_geometry(entity, ColliderGeometry { kind: ColliderKind::Rect, half, ..geometry })
+    }
+
+    /// Turns the collider into a circle of `radius` around its current offset.
+    pub fn set_collider_radius(&mut self, entity: Entity, radius: f32) -> bool {
+        let Some(geometry) = self.collider_geometry(entity) else {
+            return false;
+        };
+        self.set_collider_geometry(
+            entity,
+            ColliderGeometry { kind: ColliderKind::Circle, half: Vec2::splat(radius), ..geometry },
+        )
+    }
+
+    pub fn set_collider_offset(&mut self, entity: Entity, offset: Vec2) -> bool {
+        let Some(geometry) = self.collider_geometry(entity) else {
+            return false;
+        };
+        self.set_collider_geometry(entity, ColliderGeometry { offset, ..geometry })
+    }
+
+    pub fn configure_nav_grid(&mut self, config: NavGridConfig) {
+        self.world.insert_resource(NavGrid::new(config));
+        self.sync_nav_grid();
+    }
+
+    pub fn nav_grid_config(&self) -> NavGridConfig {
+        self.world.resource::<NavGrid>().config()
+    }
+
+    pub fn nav_grid_stats(&self) -> NavGridStats {
+        self.world.resource::<NavGrid>().stats()
+    }
+
+    /// Restamps every obstacle, e.g. after bulk edits that bypass the incremental sync.
+    pub fn rebuild_nav_grid(&mut self) -> usize {
+        self.world.resource_mut::<NavGrid>().clear();
+        self.sync_nav_grid()
+    }
+
+    /// Brings the grid up to date with collider changes, touching only cells under obstacles
+    /// that moved, resized, appeared, or were removed. Returns the number of touched cells.
+    pub fn sync_nav_grid(&mut self) -> usize {
+        let mut query = self
+            .world
+            .query_filtered::<(Entity, &WorldTransform, &Aabb, Option<&ColliderShape>), NavObstacleFilter>();
+        let obstacles: Vec<(Entity, Vec2, Vec2)> = query
+            .iter(&self.world)
+            .map(|(entity, world, aabb, shape)| {
+                let (min, max) = nav_obstacle_bounds(world, aabb, shape);
+                (entity, min, max)
+            })
+            .collect();
+        self.world.resource_mut::<NavGrid>().sync_obstacles(obstacles)
+    }
+
+    pub fn find_path(&mut self, from: Vec2, to: Vec2) -> Option<Vec<Vec2>> {
+        self.sync_nav_grid();
+        self.world.resource::<NavGrid>().find_path(from, to)
+    }
+
+    /// Plans a route for `entity` and hands it to its `PathAgent`, adding the agent (and a
+    /// velocity for non-physics bodies) when missing. Returns false when no path exists.
+    pub fn request_path(&mut self, entity: Entity, target: Vec2) -> bool {
+        let Some(position) = self.world.get::<Transform>(entity).map(|transform| transform.translation)
+        else {
+            return false;
+        };
+        let path = self.find_path(position, target);
+        let revision = self.world.resource::<NavGrid>().revision();
+        let needs_velocity =
+            self.world.get::<Velocity>(entity).is_none() && self.world.get::<RapierBody>(entity).is_none();
+        let Ok(mut entity_mut) = self.world.get_entity_mut(entity) else {
+            return false;
+        };
+        if needs_velocity {
+            entity_mut.insert(Velocity(Vec2::ZERO));
+        }
+        if !entity_mut.contains::<PathAgent>() {
+            entity_mut.insert(PathAgent::default());
+        }
+        let mut agent = entity_mut.get_mut::<PathAgent>().expect("path agent inserted");
+        match path {
+            Some(waypoints) => {
+                agent.set_path(target, waypoints, revision);
+                true
+            }
+            None => {
+                agent.clear_path();
+                false
+            }
+        }
+    }
+
+    pub fn nav_blocked_rects(&self) -> Vec<(Vec2, Vec2)> {
+        self.world.resource::<NavGrid>().blocked_cell_bounds()
+    }
+
+    /// Remaining route of every moving agent, starting at its current position.
+    pub fn nav_agent_paths(&mut self) -> Vec<Vec<Vec2>> {
+        let mut query = self.world.query::<(&PathAgent, &Transform)>();
+        query
+            .iter(&self.world)
+            .filter(|(agent, _)| agent.is_moving())
+            .map(|(agent, transform)| {
+                std::iter::once(transform.translation)
+                    .chain(agent.remaining_waypoints().iter().copied())
+                    .collect()
+            })
+            .collect()
+    }
+
     pub fn spatial_hash_rects(&self) -> Vec<(Vec2, Vec2)> {
         let grid = self.world.resource::<SpatialHash>();
         let cell = grid.cell;
@@ -2019,10 +3214,12 @@ impl EcsWorld {
                 clip_key: instance.clip_key.as_ref().to_string(),
                 playing: instance.playing,
                 looped: instance.looped,
+                loop_count: instance.loop_count,
                 speed: instance.speed,
                 time: instance.time,
                 duration: instance.duration(),
                 group: instance.group.clone(),
+                events: instance.events.clone(),
                 has_translation: clip.translation.is_some(),
                 has_rotation: clip.rotation.is_some(),
                 has_scale: clip.scale.is_some(),
@@ -2061,13 +3258,16 @@ impl EcsWorld {
                     start_offset: anim.start_offset,
                     random_start: anim.random_start,
                     group: anim.group.clone(),
+                    loop_count: anim.loop_count,
                 }
             });
             let region = animation
                 .as_ref()
                 .and_then(|anim| anim.frame_region.clone())
                 .unwrap_or_else(|| sprite.region.to_string());
-            Some(SpriteInfo { atlas, region, animation })
+            let z_order = self.world.get::<ZOrder>(entity).map(|z| z.0).unwrap_or_default();
+            let normal_map = self.world.get::<NormalMap>(entity).map(|normal_map| normal_map.0.to_string());
+            Some(SpriteInfo { atlas, region, animation, z_order, normal_map })
         } else {
             None
         };
@@ -2076,7 +3276,8 @@ impl EcsWorld {
             let material = mesh_surface.and_then(|surface| surface.material.clone());
             let lighting =
                 mesh_surface.map(|surface| MeshLightingInfo::from(&surface.lighting)).unwrap_or_default();
-            MeshInfo { key: mesh_ref.key.clone(), material, lighting }
+            let max_draw_distance = mesh_surface.and_then(|surface| surface.max_draw_distance);
+            MeshInfo { key: mesh_ref.key.clone(), material, lighting, max_draw_distance }
         });
         let script = self.world.get::<ScriptBehaviour>(entity).and_then(|behaviour| {
             let path = behaviour.script_path.trim();
@@ -2107,6 +3308,7 @@ impl EcsWorld {
                 clip_key: format!("{}::{}", instance.skeleton_key.as_ref(), clip.name.as_ref()),
                 playing: instance.playing,
                 looped: instance.looped,
+                loop_count: instance.loop_count,
                 speed: instance.speed,
                 time: instance.time,
                 duration: clip.duration,
@@ -2118,6 +3320,25 @@ impl EcsWorld {
                 has_bone_transforms,
                 palette_joint_count,
                 clip: clip_info,
+                joints: skeleton_joint_poses(instance, self.skeleton_entity_world(entity)),
+            }
+        });
+        let animation_graph = self.world.get::<AnimationGraphInstance>(entity).map(|graph| {
+            let mut parameters: Vec<(String, f32)> =
+                graph.parameters.iter().map(|(name, value)| (name.clone(), *value)).collect();
+            parameters.sort_by(|a, b| a.0.cmp(&b.0));
+            let blend_weights = graph
+                .skeletal_blend_layers()
+                .into_iter()
+                .map(|layer| (layer.clip_key.as_ref().to_string(), layer.weight))
+                .collect();
+            AnimationGraphInfo {
+                graph_key: graph.graph_key.as_ref().to_string(),
+                state: graph.current_state.clone(),
+                previous_state: graph.previous_state.clone().filter(|_| graph.is_transitioning()),
+                transition_progress: graph.transition_progress,
+                parameters,
+                blend_weights,
             }
         });
         let skin_mesh = self.world.get::<SkinMesh>(entity).map(|skin| {
@@ -2136,11 +3357,26 @@ impl EcsWorld {
             start_size: emitter.start_size,
             end_size: emitter.end_size,
             trail: emitter.trail,
+            shape: emitter.shape,
+            collision_mode: emitter.collision_mode,
+            collision_events: emitter.collision_events,
         });
         let force_field = self.world.get::<ForceField>(entity).copied();
         let attractor = self.world.get::<ParticleAttractor>(entity).copied();
+        let tint_curve = self.world.get::<TintCurve>(entity).map(|curve| TintCurveInfo {
+            curve_key: curve.curve_key.as_ref().to_string(),
+            duration: curve.duration,
+            loop_mode: curve.loop_mode,
+            elapsed: curve.elapsed,
+            sample: curve.sample(),
+        });
+        let parent = self
+            .world
+            .get::<Parent>(entity)
+            .and_then(|parent| self.world.get::<SceneEntityTag>(parent.0).map(|tag| tag.id.clone()));
         Some(EntityInfo {
             scene_id,
+            parent,
             translation,
             rotation: transform.rotation,
             scale: transform.scale,
@@ -2153,11 +3389,15 @@ impl EcsWorld {
             mesh,
             mesh_transform,
             tint,
+            tint_curve,
             skeleton,
+            animation_graph,
             skin_mesh,
             particle_emitter,
             force_field,
             attractor,
+            collider: self.collider_geometry(entity),
+            plugin_components: self.plugin_components(entity),
         })
     }
     pub fn entity_exists(&self, entity: Entity) -> bool {
@@ -2175,16 +3415,149 @@ impl EcsWorld {
             removed |= self.despawn_entity(child);
         }
         if let Some(handle) = self.world.get::<RapierBody>(entity).map(|b| b.handle) {
+            let pooled = self.world.get::<PooledSprite>(entity).is_some();
+            let capacity = self.sprite_pool_capacity;
             let mut rapier = self.world.resource_mut::<RapierState>();
-            rapier.remove_body(handle);
+            if pooled {
+                rapier.park_body(handle, capacity);
+            } else {
+                rapier.remove_body(handle);
+            }
         }
         let entity_removed = self.world.despawn(entity);
         if entity_removed {
+            self.world.resource_mut::<PluginBlobStore>().remove_entity(entity);
             removed = true;
             self.emit(GameEvent::EntityDespawned { entity });
         }
         removed
     }
+    /// Attaches an opaque, plugin-owned blob to `entity` under `name`. The bytes are saved with the
+    /// scene as-is, so data from plugins that aren't loaded survives a save/load round trip.
+    pub fn insert_blob(&mut self, entity: Entity, name: &str, bytes: impl Into<Vec<u8>>) -> bool {
+        if name.is_empty() || !self.entity_exists(entity) {
+            return false;
+        }
+        self.world.resource_mut::<PluginBlobStore>().insert(entity, name, bytes.into());
+        true
+    }
+
+    pub fn blob(&self, entity: Entity, name: &str) -> Option<&[u8]> {
+        self.world.resource::<PluginBlobStore>().get(entity, name)
+    }
+
+    pub fn remove_blob(&mut self, entity: Entity, name: &str) -> Option<Vec<u8>> {
+        self.world.resource_mut::<PluginBlobStore>().remove(entity, name)
+    }
+
+    pub fn blob_names(&self, entity: Entity) -> Vec<String> {
+        self.world
+            .resource::<PluginBlobStore>()
+            .entity_blobs(entity)
+            .map(|blobs| blobs.keys().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Adds `tag` to `entity`'s [`Tags`]. Returns false for an empty tag or a missing entity.
+    pub fn set_entity_tag(&mut self, entity: Entity, tag: &str) -> bool {
+        if tag.is_empty() || !self.entity_exists(entity) {
+            return false;
+        }
+        let mut tags = self.world.get::<Tags>(entity).cloned().unwrap_or_default();
+        if tags.contains(tag) {
+            return true;
+        }
+        let tag = self.world.resource::<TagIndex>().interned(tag).unwrap_or_else(|| Arc::from(tag));
+        tags.0.insert(tag);
+        self.world.entity_mut(entity).insert(tags);
+        true
+    }
+
+    /// Removes `tag` from `entity`, dropping the [`Tags`] component once it is empty. Returns
+    /// whether the entity carried the tag.
+    pub fn remove_entity_tag(&mut self, entity: Entity, tag: &str) -> bool {
+        let Some(mut tags) = self.world.get::<Tags>(entity).cloned() else {
+            return false;
+        };
+        if !tags.0.remove(tag) {
+            return false;
+        }
+        if tags.0.is_empty() {
+            self.world.entity_mut(entity).remove::<Tags>();
+        } else {
+            self.world.entity_mut(entity).insert(tags);
+        }
+        true
+    }
+
+    /// Tags on `entity` in lexical order.
+    pub fn entity_tags(&self, entity: Entity) -> Vec<Arc<str>> {
+        self.world.get::<Tags>(entity).map(Tags::sorted).unwrap_or_default()
+    }
+
+    /// Entities carrying `tag`, sorted for deterministic iteration.
+    pub fn query_entities_with_tag(&self, tag: &str) -> Vec<Entity> {
+        let mut entities: Vec<Entity> = self
+            .world
+            .resource::<TagIndex>()
+            .entities(tag)
+            .map(|entities| entities.iter().copied().collect())
+            .unwrap_or_default();
+        entities.sort();
+        entities
+    }
+
+    /// Registers a plugin-defined component with the inspector and scene serializer under `key`.
+    /// Blobs already saved under `key` (e.g. from a scene loaded before the plugin) become live
+    /// components.
+    pub fn register_component_inspector<T: Component + Default>(
+        &mut self,
+        key: &str,
+        fields: Vec<ComponentField<T>>,
+    ) -> bool {
+        if key.is_empty() {
+            return false;
+        }
+        self.world.resource_mut::<PluginComponentRegistry>().register_typed(key, fields);
+        self.world
+            .resource_scope(|world, registry: Mut<PluginComponentRegistry>| registry.materialize_all(world));
+        true
+    }
+
+    /// Like [`Self::register_component_inspector`], but the component is a JSON object stored as
+    /// the `key` blob, for plugins that can't share their Rust types with the host.
+    pub fn register_dynamic_component_inspector(
+        &mut self,
+        key: &str,
+        fields: Vec<ComponentFieldSchema>,
+    ) -> bool {
+        if key.is_empty() {
+            return false;
+        }
+        self.world.resource_mut::<PluginComponentRegistry>().register_blob(key, fields);
+        true
+    }
+
+    pub fn plugin_component_registry(&self) -> &PluginComponentRegistry {
+        self.world.resource::<PluginComponentRegistry>()
+    }
+
+    pub fn plugin_components(&self, entity: Entity) -> Vec<PluginComponentView> {
+        self.world.resource::<PluginComponentRegistry>().views(&self.world, entity)
+    }
+
+    pub fn set_plugin_component_field(
+        &mut self,
+        entity: Entity,
+        key: &str,
+        field: &str,
+        value: ComponentFieldValue,
+    ) -> bool {
+        self.world.resource_scope(|world, registry: Mut<PluginComponentRegistry>| {
+            registry.set_field(world, entity, key, field, value)
+        })
+    }
+
     pub fn set_root_spin(&mut self, speed: f32) {
         let mut query = self.world.query::<&mut Spin>();
         if let Some(mut spin) = query.iter_mut(&mut self.world).next() {
@@ -2287,17 +3660,41 @@ impl EcsWorld {
         H: FnMut(&str, Option<&str>) -> Result<()>,
     {
         let scene = Scene::load_from_path(path)?;
-        self.ensure_scene_dependencies_with_resolvers(
+        self.load_scene_resolving_dependencies(
             &scene,
             assets,
             mesh_loader,
             material_loader,
             environment_loader,
         )?;
-        self.load_scene_internal(&scene, assets)?;
         Ok(scene)
     }
 
+    /// Like [`Self::load_scene_with_dependencies`], but loads atlases, clips, color curves and
+    /// skeletons the scene declares with a path instead of requiring them up front.
+    pub fn load_scene_resolving_dependencies<F, G, H>(
+        &mut self,
+        scene: &Scene,
+        assets: &mut AssetManager,
+        mesh_loader: F,
+        material_loader: G,
+        environment_loader: H,
+    ) -> Result<()>
+    where
+        F: FnMut(&str, Option<&str>) -> Result<()>,
+        G: FnMut(&str, Option<&str>) -> Result<()>,
+        H: FnMut(&str, Option<&str>) -> Result<()>,
+    {
+        self.ensure_scene_dependencies_with_resolvers(
+            scene,
+            assets,
+            mesh_loader,
+            material_loader,
+            environment_loader,
+        )?;
+        self.load_scene_internal(scene, assets)
+    }
+
     fn ensure_scene_dependencies_with_resolvers<F, G, H>(
         &self,
         scene: &Scene,
@@ -2348,6 +3745,26 @@ impl EcsWorld {
             ));
         }
 
+        let mut curve_missing = Vec::new();
+        for dep in scene.dependencies.color_curve_dependencies() {
+            if assets.color_curve(dep.key()).is_some() {
+                continue;
+            }
+            if let Some(path) = dep.path() {
+                if let Err(err) = assets.load_color_curve(dep.key(), path) {
+                    curve_missing.push(format!("{} ({}): {err}", dep.key(), path));
+                }
+            } else {
+                curve_missing.push(format!("{} (no path provided)", dep.key()));
+            }
+        }
+        if !curve_missing.is_empty() {
+            return Err(anyhow!(
+                "Scene requires color curves that could not be loaded: {}",
+                curve_missing.join(", ")
+            ));
+        }
+
         let mut skeleton_missing = Vec::new();
         for dep in scene.dependencies.skeleton_dependencies() {
             if assets.skeleton(dep.key()).is_some() {
@@ -2475,6 +3892,14 @@ impl EcsWorld {
                 ));
             }
         }
+        for dep in scene.dependencies.color_curve_dependencies() {
+            if assets.color_curve(dep.key()).is_none() {
+                return Err(anyhow!(
+                    "Scene requires color curve '{}' which is not loaded. Call AssetManager::load_color_curve before loading the scene.",
+                    dep.key()
+                ));
+            }
+        }
         let mut mesh_missing = Vec::new();
         for dep in scene.dependencies.mesh_dependencies() {
             if let Err(err) = mesh_loader(dep.key(), dep.path()) {
@@ -2516,6 +3941,7 @@ impl EcsWorld {
 
     fn load_scene_internal(&mut self, scene: &Scene, assets: &AssetManager) -> Result<()> {
         self.clear_scene_entities();
+        self.world.insert_resource(SceneIdAllocator::starting_at(scene.entity_id_floor()));
         let mut entity_map = Vec::with_capacity(scene.entities.len());
         let mut id_map: HashMap<SceneEntityId, Entity> = HashMap::with_capacity(scene.entities.len());
         for entity_data in &scene.entities {
@@ -2525,20 +3951,14 @@ impl EcsWorld {
                 return Err(anyhow!("Scene contains duplicate entity id '{}'", entity_data.id.as_str()));
             }
         }
+        // Dangling parent links leave the child as a root; `Scene::validate_entity_references`
+        // reports them to the caller.
         let mut parent_entities: Vec<Option<Entity>> = Vec::with_capacity(scene.entities.len());
         for entity_data in &scene.entities {
             let parent = if let Some(parent_id) = entity_data.parent_id.as_ref() {
-                Some(
-                    *id_map
-                        .get(parent_id)
-                        .ok_or_else(|| anyhow!("Scene references missing parent '{}'", parent_id.as_str()))?,
-                )
+                id_map.get(parent_id).copied()
             } else if let Some(parent_index) = entity_data.parent {
-                Some(
-                    *entity_map
-                        .get(parent_index)
-                        .ok_or_else(|| anyhow!("Scene entity parent index {parent_index} out of bounds"))?,
-                )
+                entity_map.get(parent_index).copied()
             } else {
                 None
             };
@@ -2553,6 +3973,59 @@ impl EcsWorld {
         Ok(())
     }
 
+    /// Allocates the next sequential scene id. Ids are never reused until another scene is loaded.
+    pub fn allocate_scene_entity_id(&mut self) -> SceneEntityId {
+        self.world.resource_mut::<SceneIdAllocator>().allocate()
+    }
+
+    /// Copy of `scene` with every entity re-keyed from this world's id allocator, for instantiating
+    /// prefabs and pasted entities next to existing ones.
+    pub fn with_allocated_entity_ids(&mut self, scene: &Scene) -> Scene {
+        let mut allocator = self.world.resource_mut::<SceneIdAllocator>();
+        scene.with_entity_ids_from(|| allocator.allocate())
+    }
+
+    /// Re-parents `child` under `parent`. The child's `Transform` is kept as-is and becomes relative
+    /// to the new parent, matching how scene links are restored on load.
+    pub fn set_parent(&mut self, child: Entity, parent: Entity) -> Result<()> {
+        if child == parent {
+            return Err(anyhow!("Entity cannot be its own parent"));
+        }
+        if !self.entity_exists(child) || !self.entity_exists(parent) {
+            return Err(anyhow!("Parent or child entity does not exist"));
+        }
+        let mut ancestor = Some(parent);
+        while let Some(current) = ancestor {
+            if current == child {
+                return Err(anyhow!("Parenting would create a cycle"));
+            }
+            ancestor = self.world.get::<Parent>(current).map(|p| p.0);
+        }
+        self.detach_from_parent(child);
+        self.attach_child_to_parent(child, parent);
+        Ok(())
+    }
+
+    /// Turns `child` back into a root entity. Returns false when it had no parent.
+    pub fn detach_from_parent(&mut self, child: Entity) -> bool {
+        let Some(parent) = self.world.get::<Parent>(child).copied() else {
+            return false;
+        };
+        if let Some(mut siblings) = self.world.get_mut::<Children>(parent.0) {
+            siblings.0.retain(|&sibling| sibling != child);
+        }
+        self.world.entity_mut(child).remove::<Parent>();
+        true
+    }
+
+    pub fn get_parent(&self, entity: Entity) -> Option<Entity> {
+        self.world.get::<Parent>(entity).map(|parent| parent.0)
+    }
+
+    pub fn get_children(&self, entity: Entity) -> Vec<Entity> {
+        self.world.get::<Children>(entity).map(|children| children.0.clone()).unwrap_or_default()
+    }
+
     fn attach_child_to_parent(&mut self, child_entity: Entity, parent_entity: Entity) {
         self.world.entity_mut(child_entity).insert(Parent(parent_entity));
         if let Some(mut children) = self.world.get_mut::<Children>(parent_entity) {
@@ -2568,7 +4041,7 @@ impl EcsWorld {
         if let Some(tag) = self.world.get::<SceneEntityTag>(entity).cloned() {
             return tag.id;
         }
-        let id = SceneEntityId::new();
+        let id = self.allocate_scene_entity_id();
         self.world.entity_mut(entity).insert(SceneEntityTag::new(id.clone()));
         id
     }
@@ -2595,10 +4068,12 @@ impl EcsWorld {
         G: Fn(&str) -> Option<String>,
     {
         let mut scene = Scene::default();
-        let mut query = self.world.query::<(Entity, Option<&Parent>, Option<&Transform>)>();
+        let mut query =
+            self.world.query::<(Entity, Option<&Parent>, Option<&Transform>, Option<&SceneChunkMember>)>();
         let mut roots = Vec::new();
-        for (entity, parent, transform) in query.iter(&self.world) {
-            if parent.is_none() && transform.is_some() {
+        for (entity, parent, transform, chunk) in query.iter(&self.world) {
+            // Streamed chunk content is saved in the chunk's own scene file.
+            if parent.is_none() && transform.is_some() && chunk.is_none() {
                 roots.push(entity);
             }
         }
@@ -2607,6 +4082,8 @@ impl EcsWorld {
         }
         scene.dependencies =
             SceneDependencies::from_entities(&scene.entities, assets, mesh_source, material_source);
+        let allocated = self.world.resource::<SceneIdAllocator>().next_value();
+        scene.next_entity_id = allocated.max(scene.entity_id_floor());
         scene
     }
 
@@ -2638,6 +4115,97 @@ impl EcsWorld {
         Some(Scene { entities, dependencies, ..Scene::default() })
     }
 
+    /// Exports `selection` as a standalone scene listing only the dependencies those entities use.
+    /// Selected entities below another selected entity stay nested under it; with
+    /// `include_children` unselected descendants are exported too. Entities whose parent is not
+    /// part of the export become roots with their world transform baked in.
+    pub fn export_selection_with_sources<F, G>(
+        &mut self,
+        selection: &[Entity],
+        include_children: bool,
+        assets: &AssetManager,
+        mesh_source: F,
+        material_source: G,
+    ) -> Option<Scene>
+    where
+        F: Fn(&str) -> Option<String>,
+        G: Fn(&str) -> Option<String>,
+    {
+        let mut selected: Vec<Entity> = Vec::with_capacity(selection.len());
+        for &entity in selection {
+            if self.entity_exists(entity) && !selected.contains(&entity) {
+                selected.push(entity);
+            }
+        }
+        let roots: Vec<Entity> = selected
+            .iter()
+            .copied()
+            .filter(|&entity| {
+                let mut ancestor = self.get_parent(entity);
+                while let Some(current) = ancestor {
+                    if selected.contains(&current) {
+                        return false;
+                    }
+                    ancestor = self.get_parent(current);
+                }
+                true
+            })
+            .collect();
+        let mut entities = Vec::new();
+        for root in roots {
+            self.collect_scene_entity(root, None, None, assets, &mut entities);
+        }
+        if entities.is_empty() {
+            return None;
+        }
+
+        let mut by_id: HashMap<SceneEntityId, Entity> = HashMap::new();
+        let mut query = self.world.query::<(Entity, &SceneEntityTag)>();
+        for (entity, tag) in query.iter(&self.world) {
+            by_id.insert(tag.id.clone(), entity);
+        }
+        if !include_children {
+            entities.retain(|data| by_id.get(&data.id).is_some_and(|entity| selected.contains(entity)));
+        }
+        let index_by_id: HashMap<SceneEntityId, usize> =
+            entities.iter().enumerate().map(|(index, data)| (data.id.clone(), index)).collect();
+        for data in &mut entities {
+            data.parent = data.parent_id.as_ref().and_then(|id| index_by_id.get(id).copied());
+            if data.parent.is_some() {
+                continue;
+            }
+            data.parent_id = None;
+            let Some(&entity) = by_id.get(&data.id) else {
+                continue;
+            };
+            if self.get_parent(entity).is_some() {
+                self.bake_world_transform(entity, data);
+            }
+        }
+
+        let dependencies =
+            SceneDependencies::from_entities(&entities, assets, &mesh_source, &material_source);
+        Some(Scene { entities, dependencies, ..Scene::default() })
+    }
+
+    /// Replaces the parent-relative transforms of `data` with `entity`'s world transform.
+    fn bake_world_transform(&self, entity: Entity, data: &mut SceneEntity) {
+        if let Some(world) = self.world.get::<WorldTransform>(entity) {
+            let (scale, rotation, translation) = world.0.to_scale_rotation_translation();
+            data.transform = TransformData::from_components(
+                translation.truncate(),
+                rotation.to_euler(EulerRot::ZYX).0,
+                scale.truncate(),
+            );
+        }
+        if data.transform3d.is_some() {
+            if let Some(world) = self.world.get::<WorldTransform3D>(entity) {
+                let (scale, rotation, translation) = world.0.to_scale_rotation_translation();
+                data.transform3d = Some(Transform3DData::from_components(translation, rotation, scale));
+            }
+        }
+    }
+
     pub fn instantiate_prefab(&mut self, scene: &Scene, assets: &AssetManager) -> Result<Vec<Entity>> {
         self.instantiate_scene_entities(scene, assets)
     }
@@ -2661,6 +4229,241 @@ impl EcsWorld {
         self.instantiate_scene_entities(scene, assets)
     }
 
+    /// Instantiates `scene` and then applies `overrides` to its root entity. Overrides that cannot be
+    /// applied are logged and skipped.
+    pub fn instantiate_prefab_with_overrides(
+        &mut self,
+        scene: &Scene,
+        assets: &AssetManager,
+        overrides: &PrefabOverrides,
+    ) -> Result<Vec<Entity>> {
+        let spawned = self.instantiate_scene_entities(scene, assets)?;
+        if let Some(&root) = spawned.first() {
+            for warning in self.apply_prefab_overrides(root, overrides) {
+                eprintln!("[prefab] {warning}");
+            }
+        }
+        Ok(spawned)
+    }
+
+    /// Applies instantiate-time overrides to a freshly spawned prefab root. Returns one warning per
+    /// override that was ignored because its key is unknown or its value does not fit.
+    pub fn apply_prefab_overrides(&mut self, root: Entity, overrides: &PrefabOverrides) -> Vec<String> {
+        let mut warnings = Vec::new();
+        for (key, value) in overrides {
+            let floats = override_floats(value);
+            let applied = match key.as_str() {
+                "position" => match floats.as_deref() {
+                    Some(&[x, y]) => self.set_translation(root, Vec2::new(x, y)),
+                    Some(&[x, y, z]) => {
+                        let moved = self.set_translation(root, Vec2::new(x, y));
+                        self.set_mesh_translation(root, Vec3::new(x, y, z)) || moved
+                    }
+                    _ => false,
+                },
+                "rotation" => value.as_f64().is_some_and(|rotation| self.set_rotation(root, rotation as f32)),
+                "scale" => match floats.as_deref() {
+                    Some(&[x, y]) => self.set_scale(root, Vec2::new(x, y)),
+                    _ => false,
+                },
+                "velocity" => match floats.as_deref() {
+                    Some(&[x, y]) => self.set_velocity(root, Vec2::new(x, y)),
+                    _ => false,
+                },
+                "tint" => match floats.as_deref() {
+                    Some(&[r, g, b]) => self.set_tint(root, Some(Vec4::new(r, g, b, 1.0))),
+                    Some(&[r, g, b, a]) => self.set_tint(root, Some(Vec4::new(r, g, b, a))),
+                    _ => false,
+                },
+                "z_order" => value
+                    .as_i64()
+                    .and_then(|z| i16::try_from(z).ok())
+                    .is_some_and(|z| self.set_sprite_z_order(root, z)),
+                _ => {
+                    warnings.push(format!("Unknown prefab override '{key}' ignored."));
+                    continue;
+                }
+            };
+            if !applied {
+                warnings.push(format!("Prefab override '{key}' = {value} could not be applied."));
+            }
+        }
+        warnings
+    }
+
+    /// Serializes `entity` and its children as prefab-style scene JSON, suitable for the clipboard.
+    pub fn serialize_entity(&mut self, entity: Entity, assets: &AssetManager) -> Result<String> {
+        let scene = self
+            .export_prefab(entity, assets)
+            .ok_or_else(|| anyhow!("Entity {} does not exist or cannot be serialized.", entity.index()))?;
+        Ok(serde_json::to_string_pretty(&scene)?)
+    }
+
+    /// Spawns a copy of JSON produced by [`EcsWorld::serialize_entity`] under fresh scene IDs and
+    /// returns the copy's root entity. Atlas and clip dependencies are loaded on demand.
+    pub fn deserialize_entity(&mut self, json: &str, assets: &mut AssetManager) -> Result<Entity> {
+        self.deserialize_entity_with_mesh(json, None, assets, |_, _| Ok(()))
+    }
+
+    /// Like [`EcsWorld::deserialize_entity`], optionally moving the root to `position` in 2D and
+    /// resolving mesh dependencies through `mesh_loader`.
+    pub fn deserialize_entity_with_mesh<F>(
+        &mut self,
+        json: &str,
+        position: Option<Vec2>,
+        assets: &mut AssetManager,
+        mesh_loader: F,
+    ) -> Result<Entity>
+    where
+        F: FnMut(&str, Option<&str>) -> Result<()>,
+    {
+        let scene: Scene = serde_json::from_str(json).context("Clipboard does not contain an entity.")?;
+        let Some(root) = scene.entities.first() else {
+            return Err(anyhow!("Serialized entity contains no entities."));
+        };
+        let current: Vec2 = root.transform.translation.clone().into();
+        let mut scene = self.with_allocated_entity_ids(&scene);
+        if let Some(position) = position {
+            scene.offset_entities_2d(position - current);
+        }
+        let spawned = self.instantiate_prefab_with_mesh(&scene, assets, mesh_loader)?;
+        spawned.first().copied().ok_or_else(|| anyhow!("Serialized entity spawned nothing."))
+    }
+
+    /// Merges `scene` into the current world and returns every spawned entity. Scene IDs that are
+    /// already in use are re-keyed, and the root entities are shifted so their centroid lands on
+    /// `position` (the origin when `None`). Atlas keys that are loaded from a different file than
+    /// the scene expects are reported as an error instead of being unified.
+    pub fn import_scene_with_mesh<F>(
+        &mut self,
+        scene: &Scene,
+        position: Option<Vec2>,
+        assets: &mut AssetManager,
+        mesh_loader: F,
+    ) -> Result<Vec<Entity>>
+    where
+        F: FnMut(&str, Option<&str>) -> Result<()>,
+    {
+        let Some(centroid) = scene.root_centroid_2d() else {
+            return Err(anyhow!("Scene contains no entities to import."));
+        };
+        let conflicts =
+            scene.dependencies.atlas_conflicts(|key| assets.atlas_source(key).map(str::to_string));
+        if !conflicts.is_empty() {
+            let details: Vec<String> = conflicts.iter().map(|conflict| conflict.to_string()).collect();
+            return Err(anyhow!("Conflicting atlas keys: {}", details.join("; ")));
+        }
+        let mut taken: HashSet<String> = HashSet::new();
+        let mut query = self.world.query::<&SceneEntityTag>();
+        for tag in query.iter(&self.world) {
+            taken.insert(tag.id.as_str().to_string());
+        }
+        let mut allocator = self.world.resource_mut::<SceneIdAllocator>();
+        allocator.reserve(scene.entity_id_floor());
+        let mut scene =
+            scene.with_colliding_entity_ids_from(|id| taken.contains(id), || allocator.allocate());
+        scene.offset_root_entities_2d(position.unwrap_or(Vec2::ZERO) - centroid);
+        self.instantiate_prefab_with_mesh(&scene, assets, mesh_loader)
+    }
+
+    /// Spawns a streamed chunk's entities alongside the current world. Entities whose scene ID is
+    /// already present (overlapping chunks listing the same entity) are shared, not duplicated.
+    pub fn load_scene_chunk(
+        &mut self,
+        chunk: &str,
+        scene: &Scene,
+        assets: &AssetManager,
+    ) -> Result<Vec<Entity>> {
+        for dep in scene.dependencies.atlas_dependencies() {
+            if !assets.has_atlas(dep.key()) {
+                return Err(anyhow!("Chunk '{chunk}' requires atlas '{}' which is not loaded.", dep.key()));
+            }
+        }
+        let chunk_key: Arc<str> = Arc::from(chunk);
+        self.world.resource_mut::<SceneIdAllocator>().reserve(scene.entity_id_floor());
+        let mut existing: HashMap<SceneEntityId, Entity> = HashMap::new();
+        let mut query = self.world.query::<(Entity, &SceneEntityTag)>();
+        for (entity, tag) in query.iter(&self.world) {
+            existing.insert(tag.id.clone(), entity);
+        }
+        let mut spawned = Vec::new();
+        let mut entity_map: Vec<Option<Entity>> = Vec::with_capacity(scene.entities.len());
+        let mut id_map: HashMap<SceneEntityId, Entity> = HashMap::with_capacity(scene.entities.len());
+        for entity_data in &scene.entities {
+            if let Some(&shared) = existing.get(&entity_data.id) {
+                // Entities owned by the master scene have no membership and stay put.
+                if let Some(mut member) = self.world.get_mut::<SceneChunkMember>(shared) {
+                    if !member.chunks.iter().any(|key| key.as_ref() == chunk) {
+                        member.chunks.push(Arc::clone(&chunk_key));
+                    }
+                }
+                entity_map.push(None);
+                continue;
+            }
+            let entity = match self.spawn_scene_entity(entity_data, assets) {
+                Ok(entity) => entity,
+                Err(err) => {
+                    for entity in spawned {
+                        self.despawn_entity(entity);
+                    }
+                    self.release_chunk_membership(chunk);
+                    return Err(err.context(format!("Loading chunk '{chunk}'")));
+                }
+            };
+            self.world.entity_mut(entity).insert(SceneChunkMember { chunks: vec![Arc::clone(&chunk_key)] });
+            id_map.insert(entity_data.id.clone(), entity);
+            entity_map.push(Some(entity));
+            spawned.push(entity);
+        }
+        for (index, entity_data) in scene.entities.iter().enumerate() {
+            let Some(child_entity) = entity_map[index] else {
+                continue;
+            };
+            let by_index = entity_data.parent.and_then(|index| entity_map.get(index).copied().flatten());
+            let parent = entity_data
+                .parent_id
+                .as_ref()
+                .and_then(|parent_id| id_map.get(parent_id).copied())
+                .or(by_index);
+            if let Some(parent_entity) = parent {
+                self.attach_child_to_parent(child_entity, parent_entity);
+            }
+        }
+        Ok(spawned)
+    }
+
+    /// Drops `chunk` from every member entity and despawns the ones no other loaded chunk shares.
+    /// Returns the number of entities despawned.
+    pub fn unload_scene_chunk(&mut self, chunk: &str) -> usize {
+        let orphaned = self.release_chunk_membership(chunk);
+        let mut removed = 0;
+        for entity in orphaned {
+            if self.world.get_entity(entity).is_ok() && self.despawn_entity(entity) {
+                removed += 1;
+            }
+        }
+        removed
+    }
+
+    pub fn scene_chunk_entity_count(&mut self, chunk: &str) -> usize {
+        let mut query = self.world.query::<&SceneChunkMember>();
+        query.iter(&self.world).filter(|member| member.chunks.iter().any(|key| key.as_ref() == chunk)).count()
+    }
+
+    fn release_chunk_membership(&mut self, chunk: &str) -> Vec<Entity> {
+        let mut orphaned = Vec::new();
+        let mut query = self.world.query::<(Entity, &mut SceneChunkMember)>();
+        for (entity, mut member) in query.iter_mut(&mut self.world) {
+            if member.chunks.iter().any(|key| key.as_ref() == chunk) {
+                member.chunks.retain(|key| key.as_ref() != chunk);
+                if member.chunks.is_empty() {
+                    orphaned.push(entity);
+                }
+            }
+        }
+        orphaned
+    }
+
     pub fn first_emitter(&mut self) -> Option<Entity> {
         let mut query = self.world.query::<(Entity, &ParticleEmitter)>();
         query.iter(&self.world).map(|(entity, _)| entity).next()
@@ -2684,6 +4487,14 @@ impl EcsWorld {
         if scene.entities.is_empty() {
             return Ok(Vec::new());
         }
+        if self.spawn_allowance(scene.entities.len(), "instantiate_prefab") < scene.entities.len() {
+            return Err(anyhow!(
+                "Prefab needs {} entities but the entity cap of {} would be exceeded",
+                scene.entities.len(),
+                self.max_entities.unwrap_or_default()
+            ));
+        }
+        self.world.resource_mut::<SceneIdAllocator>().reserve(scene.entity_id_floor());
         let mut entity_map = Vec::with_capacity(scene.entities.len());
         let mut id_map: HashMap<SceneEntityId, Entity> = HashMap::with_capacity(scene.entities.len());
         for entity_data in &scene.entities {
@@ -2714,6 +4525,7 @@ impl EcsWorld {
         let rotation = data.transform.rotation;
         let velocity_vec: Vec2 = data.velocity.as_ref().map(|v| Vec2::from(v.clone())).unwrap_or(Vec2::ZERO);
         let collider_half = data.collider.as_ref().map(|c| Vec2::from(c.half_extents.clone()));
+        let collider_geometry = data.collider.as_ref().and_then(ColliderData::geometry);
 
         let mut body_handle = None;
         let mut collider_handle = None;
@@ -2790,6 +4602,10 @@ impl EcsWorld {
                 region: Arc::from(emitter.region.as_str()),
                 source: emitter.atlas_source.as_deref().map(Arc::from),
                 trail: emitter.trail.as_ref().map(|trail| ParticleTrail::from(trail.clone())),
+                shape: emitter.shape.sanitized(),
+                pending_burst: 0,
+                collision_mode: emitter.collision_mode.sanitized(),
+                collision_events: emitter.collision_events,
             });
         }
         if let Some(field) = data.force_field.clone() {
@@ -2817,6 +4633,12 @@ impl EcsWorld {
                 region_id: info.id,
                 uv: info.uv,
             });
+            if sprite.z_order != 0 {
+                entity.insert(ZOrder(sprite.z_order));
+            }
+            if let Some(normal_map) = sprite.normal_map.as_deref() {
+                entity.insert(NormalMap(Arc::from(normal_map)));
+            }
         }
 
         if let Some(mesh) = data.mesh.as_ref() {
@@ -2824,6 +4646,7 @@ impl EcsWorld {
             let surface = MeshSurface {
                 material: mesh.material.clone(),
                 lighting: MeshLighting::from(mesh.lighting.clone()),
+                max_draw_distance: mesh.max_draw_distance,
             };
             entity.insert(surface);
         }
@@ -2841,6 +4664,9 @@ impl EcsWorld {
             let mut rapier = self.world.resource_mut::<RapierState>();
             rapier.register_collider_entity(collider, entity_id);
         }
+        if let Some(geometry) = collider_geometry {
+            self.set_collider_geometry(entity_id, geometry);
+        }
 
         if let Some(skeleton) = data.skeleton.as_ref() {
             if !self.set_skeleton(entity_id, assets, &skeleton.key) {
@@ -2856,6 +4682,7 @@ impl EcsWorld {
                 let _ = self.set_skeleton_clip_playing(entity_id, clip.playing);
                 if let Some(mut instance) = self.world.get_mut::<SkeletonInstance>(entity_id) {
                     instance.looped = clip.looped;
+                    instance.set_loop_count(clip.loop_count);
                 }
             }
         }
@@ -2876,6 +4703,7 @@ impl EcsWorld {
             let _ = self.set_transform_clip_speed(entity_id, clip.speed);
             let _ = self.set_transform_clip_time(entity_id, clip.time);
             let _ = self.set_transform_clip_playing(entity_id, clip.playing);
+            let _ = self.set_transform_clip_loop_count(entity_id, clip.loop_count);
 
             if !clip.apply_translation || !clip.apply_rotation || !clip.apply_scale {
                 if let Some(mut transform_comp) = self.world.get_mut::<Transform>(entity_id) {
@@ -2893,6 +4721,18 @@ impl EcsWorld {
             }
         }
 
+        if let Some(curve) = data.tint_curve.as_ref() {
+            let loop_mode = curve
+                .loop_mode
+                .as_deref()
+                .map(SpriteAnimationLoopMode::parse)
+                .unwrap_or(SpriteAnimationLoopMode::Loop);
+            if !self.set_tint_curve(entity_id, assets, &curve.curve_key, curve.duration, loop_mode) {
+                return Err(anyhow!("Scene references unknown color curve '{}'", curve.curve_key));
+            }
+            let _ = self.set_tint_curve_elapsed(entity_id, curve.elapsed);
+        }
+
         if let Some(sprite) = data.sprite.as_ref().and_then(|sprite_data| sprite_data.animation.as_ref()) {
             if !self.set_sprite_timeline(entity_id, assets, Some(&sprite.timeline)) {
                 eprintln!(
@@ -2911,10 +4751,25 @@ impl EcsWorld {
                 } else {
                     self.set_sprite_animation_looped(entity_id, sprite.looped);
                 }
+                self.set_sprite_animation_loop_count(entity_id, sprite.loop_count);
                 self.set_sprite_animation_playing(entity_id, sprite.playing);
             }
         }
 
+        for tag in &data.tags {
+            self.set_entity_tag(entity_id, tag);
+        }
+
+        if !data.plugin_data.is_empty() {
+            let mut blobs = self.world.resource_mut::<PluginBlobStore>();
+            for (name, bytes) in &data.plugin_data {
+                blobs.insert(entity_id, name, bytes.clone());
+            }
+            self.world.resource_scope(|world, registry: Mut<PluginComponentRegistry>| {
+                registry.materialize(world, entity_id)
+            });
+        }
+
         if data.sprite.is_some() {
             if let Some(sprite) = self.world.get::<Sprite>(entity_id) {
                 self.emit(GameEvent::SpriteSpawned {
@@ -2954,12 +4809,13 @@ impl EcsWorld {
                     instance.clip_key.as_ref().to_string(),
                     instance.playing,
                     instance.looped,
+                    instance.loop_count,
                     instance.speed,
                     instance.time,
                     instance.group.clone(),
                 )
             })
-            .map(|(clip_key, playing, looped, speed, time, group)| {
+            .map(|(clip_key, playing, looped, loop_count, speed, time, group)| {
                 let mask = self.world.get::<TransformTrackPlayer>(entity).copied().unwrap_or_default();
                 let property_mask =
                     self.world.get::<PropertyTrackPlayer>(entity).copied().unwrap_or_default();
@@ -2967,6 +4823,7 @@ impl EcsWorld {
                     clip_key,
                     playing,
                     looped,
+                    loop_count,
                     speed,
                     time,
                     group,
@@ -2981,6 +4838,7 @@ impl EcsWorld {
                 clip_key: clip_key.as_ref().to_string(),
                 playing: instance.playing,
                 looped: instance.looped,
+                loop_count: instance.loop_count,
                 speed: instance.speed,
                 time: instance.time,
                 group: instance.group.clone(),
@@ -3025,25 +4883,42 @@ impl EcsWorld {
                             start_offset: anim.start_offset,
                             random_start: anim.random_start,
                             group: anim.group.clone(),
+                            loop_count: anim.loop_count,
                         });
-                    SpriteData { atlas, region, animation }
+                    let z_order = self.world.get::<ZOrder>(entity).map(|z| z.0).unwrap_or_default();
+                    let normal_map =
+                        self.world.get::<NormalMap>(entity).map(|normal_map| normal_map.0.to_string());
+                    SpriteData { atlas, region, animation, z_order, normal_map }
                 }),
             transform3d: self
                 .world
                 .get::<Transform3D>(entity)
                 .map(|t| Transform3DData::from_components(t.translation, t.rotation, t.scale)),
             mesh: self.world.get::<MeshRef>(entity).map(|mesh| {
-                let (material, lighting) = if let Some(surface) = mesh_surface {
-                    (surface.material.clone(), MeshLightingData::from(&surface.lighting))
+                let (material, lighting, max_draw_distance) = if let Some(surface) = mesh_surface {
+                    (
+                        surface.material.clone(),
+                        MeshLightingData::from(&surface.lighting),
+                        surface.max_draw_distance,
+                    )
                 } else {
-                    (None, MeshLightingData::default())
+                    (None, MeshLightingData::default(), None)
                 };
-                MeshData { key: mesh.key.clone(), material, lighting }
+                MeshData { key: mesh.key.clone(), material, lighting, max_draw_distance }
             }),
             tint: self.world.get::<Tint>(entity).map(|t| ColorData::from(t.0)),
+            tint_curve: self.world.get::<TintCurve>(entity).map(|curve| TintCurveData {
+                curve_key: curve.curve_key.as_ref().to_string(),
+                duration: curve.duration,
+                loop_mode: Some(curve.loop_mode.as_str().to_string()),
+                elapsed: curve.elapsed,
+            }),
             velocity: self.world.get::<Velocity>(entity).map(|v| v.0.into()),
             mass: self.world.get::<Mass>(entity).map(|m| m.0),
-            collider: self.world.get::<Aabb>(entity).map(|a| ColliderData { half_extents: a.half.into() }),
+            collider: self
+                .world
+                .get::<Aabb>(entity)
+                .map(|a| ColliderData::from_shape(a.half, self.world.get::<ColliderShape>(entity).copied())),
             particle_emitter: self.world.get::<ParticleEmitter>(entity).map(|emitter| ParticleEmitterData {
                 rate: emitter.rate,
                 spread: emitter.spread,
@@ -3061,6 +4936,9 @@ impl EcsWorld {
                     .map(|s| s.as_ref().to_string())
                     .or_else(|| assets.atlas_source(emitter.atlas.as_ref()).map(|p| p.to_string())),
                 trail: emitter.trail.map(ParticleTrailData::from),
+                shape: emitter.shape,
+                collision_mode: emitter.collision_mode,
+                collision_events: emitter.collision_events,
             }),
             force_field: self.world.get::<ForceField>(entity).map(|field| ForceFieldData::from(*field)),
             attractor: self
@@ -3072,6 +4950,19 @@ impl EcsWorld {
                 angular_speed: orbit.angular_speed,
             }),
             spin: self.world.get::<Spin>(entity).map(|s| s.speed),
+            tags: self.entity_tags(entity).iter().map(|tag| tag.to_string()).collect(),
+            plugin_data: {
+                let mut data = self
+                    .world
+                    .resource::<PluginBlobStore>()
+                    .entity_blobs(entity)
+                    .cloned()
+                    .unwrap_or_default();
+                data.extend(
+                    self.world.resource::<PluginComponentRegistry>().encode_typed(&self.world, entity),
+                );
+                data
+            },
             parent_id: parent_id.clone(),
             parent: parent_index,
         };
@@ -3109,3 +5000,34 @@ impl EcsWorld {
         self.world.resource_mut::<ParticleContacts>().pairs.clear();
     }
 }
+
+fn override_floats(value: &serde_json::Value) -> Option<Vec<f32>> {
+    value.as_array()?.iter().map(|item| item.as_f64().map(|v| v as f32)).collect()
+}
+
+fn skeleton_joint_poses(instance: &SkeletonInstance, entity_world: Mat4) -> Vec<SkeletonJointPose> {
+    let joints = instance.skeleton.joints.as_ref();
+    let mut clip_tracks = vec![None; joints.len()];
+    if let Some(clip) = instance.active_clip.as_ref() {
+        for curve in clip.channels.iter() {
+            if let Some(slot) = clip_tracks.get_mut(curve.joint_index as usize) {
+                *slot = Some(JointClipTracks {
+                    translation: curve.translation.is_some(),
+                    rotation: curve.rotation.is_some(),
+                    scale: curve.scale.is_some(),
+                });
+            }
+        }
+    }
+    joints
+        .iter()
+        .enumerate()
+        .map(|(index, joint)| SkeletonJointPose {
+            name: Arc::clone(&joint.name),
+            parent: joint.parent.map(|parent| parent as usize),
+            local: instance.local_poses.get(index).copied().unwrap_or(joint.rest_local),
+            world: entity_world * instance.model_poses.get(index).copied().unwrap_or(joint.rest_world),
+            clip_tracks: clip_tracks[index],
+        })
+        .collect()
+}
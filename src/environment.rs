@@ -1,4 +1,4 @@
-use crate::renderer::Renderer;
+use crate::renderer::{estimate_texture_bytes, Renderer};
 use anyhow::{anyhow, Context, Result};
 use glam::{Vec2, Vec3};
 use half::f16;
@@ -80,16 +80,50 @@ struct HdrImage {
 }
 
 pub struct EnvironmentGpu {
-    _diffuse_texture: Arc<wgpu::Texture>,
+    diffuse_texture: Arc<wgpu::Texture>,
     diffuse_view: Arc<wgpu::TextureView>,
-    _specular_texture: Arc<wgpu::Texture>,
+    specular_texture: Arc<wgpu::Texture>,
     specular_view: Arc<wgpu::TextureView>,
-    _brdf_texture: Arc<wgpu::Texture>,
+    brdf_texture: Arc<wgpu::Texture>,
     brdf_view: Arc<wgpu::TextureView>,
     sampler: Arc<wgpu::Sampler>,
     specular_mip_count: u32,
 }
 
+/// Scrolls the active environment horizontally over time, as if its equirect's U coordinate were
+/// offset, so skies drift without re-baking the cubemaps. A zero speed keeps the environment static.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct EnvironmentScroll {
+    /// Equirect widths scrolled per second; negative values scroll the other way.
+    pub speed: f32,
+    offset: f32,
+}
+
+impl EnvironmentScroll {
+    pub fn new(speed: f32) -> Self {
+        Self { speed, offset: 0.0 }
+    }
+
+    /// Advances the offset by `dt` seconds and returns it, wrapped to `[0, 1)`.
+    pub fn advance(&mut self, dt: f32) -> f32 {
+        if self.speed.is_finite() && dt.is_finite() {
+            self.offset = (self.offset + self.speed * dt).rem_euclid(1.0);
+        }
+        self.offset
+    }
+
+    pub fn offset(&self) -> f32 {
+        self.offset
+    }
+
+    /// Rotates a lookup direction about +Y by the current offset. Matches `environment_direction` in
+    /// `mesh_basic.wgsl`.
+    pub fn rotate_direction(&self, dir: Vec3) -> Vec3 {
+        let (sin, cos) = (self.offset * TAU).sin_cos();
+        Vec3::new(cos * dir.x - sin * dir.z, dir.y, sin * dir.x + cos * dir.z)
+    }
+}
+
 impl EnvironmentRegistry {
     pub fn new() -> Self {
         let (default_definition, default_maps) = EnvironmentDefinition::generated_default();
@@ -229,6 +263,11 @@ impl EnvironmentRegistry {
         self.environments.get(key).map(|entry| entry.ref_count)
     }
 
+    /// Estimated bytes held by uploaded diffuse, specular, and BRDF textures.
+    pub fn gpu_memory_bytes(&self) -> u64 {
+        self.environments.values().filter_map(|entry| entry.gpu.as_ref()).map(|gpu| gpu.memory_bytes()).sum()
+    }
+
     pub fn version(&self) -> u64 {
         self.revision
     }
@@ -265,6 +304,15 @@ impl EnvironmentRegistry {
         Ok(gpu)
     }
 
+    /// Drops the uploaded maps and sampler, e.g. after the device was lost. `ensure_gpu` uploads
+    /// again, reloading from the recorded source when the CPU maps were not kept.
+    pub fn release_gpu(&mut self) {
+        self.sampler = None;
+        for entry in self.environments.values_mut() {
+            entry.gpu = None;
+        }
+    }
+
     pub fn sampler(&mut self, renderer: &mut Renderer) -> Result<Arc<wgpu::Sampler>> {
         self.ensure_sampler(renderer)
     }
@@ -491,11 +539,11 @@ impl EnvironmentGpu {
         }));
 
         Ok(Self {
-            _diffuse_texture: diffuse_texture,
+            diffuse_texture,
             diffuse_view,
-            _specular_texture: specular_texture,
+            specular_texture,
             specular_view,
-            _brdf_texture: brdf_texture,
+            brdf_texture,
             brdf_view,
             sampler,
             specular_mip_count: mip_count,
@@ -521,6 +569,12 @@ impl EnvironmentGpu {
     pub fn specular_mip_count(&self) -> u32 {
         self.specular_mip_count
     }
+
+    pub fn memory_bytes(&self) -> u64 {
+        estimate_texture_bytes(&self.diffuse_texture)
+            + estimate_texture_bytes(&self.specular_texture)
+            + estimate_texture_bytes(&self.brdf_texture)
+    }
 }
 
 impl Default for EnvironmentRegistry {
@@ -708,6 +762,38 @@ mod tests {
         assert!(registry.version() > before, "refcount change should bump revision");
     }
 
+    #[test]
+    fn environment_scroll_offset_advances_and_wraps() {
+        let mut scroll = EnvironmentScroll::new(0.25);
+        assert_eq!(scroll.offset(), 0.0);
+        let first = scroll.advance(1.0);
+        assert!((first - 0.25).abs() < 1e-6);
+        let second = scroll.advance(1.0);
+        assert!(second > first, "offset keeps moving while animated");
+        let wrapped = scroll.advance(3.0);
+        assert!((wrapped - 0.25).abs() < 1e-5, "offset wraps to [0, 1), got {wrapped}");
+
+        let mut still = EnvironmentScroll::default();
+        assert_eq!(still.advance(10.0), 0.0, "zero speed keeps the environment static");
+    }
+
+    #[test]
+    fn environment_scroll_shifts_equirect_lookups() {
+        let width = 8;
+        let image =
+            HdrImage { width, height: 1, pixels: (0..width).map(|x| Vec3::splat(x as f32)).collect() };
+        let mut scroll = EnvironmentScroll::new(1.0);
+        scroll.advance(0.25);
+        let dir = Vec3::new(1.0, 0.0, 0.0);
+        let base = sample_equirect(&image, dir);
+        let shifted = sample_equirect(&image, scroll.rotate_direction(dir));
+        let expected_shift = 0.25 * (width as f32 - 1.0);
+        assert!(
+            (shifted.x - base.x - expected_shift).abs() < 1e-3,
+            "quarter-turn offset moves the lookup a quarter of the equirect: {base:?} -> {shifted:?}"
+        );
+    }
+
     #[test]
     fn release_bumps_version_for_permanent_environment() {
         let mut registry = EnvironmentRegistry::new();
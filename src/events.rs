@@ -1,7 +1,10 @@
+use crate::scripts::ScriptHandle;
 use bevy_ecs::prelude::{Entity, Resource};
-use glam::Vec3;
+use glam::{Quat, Vec2, Vec3};
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
 use std::fmt;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, PoisonError};
 
 #[derive(Debug, Clone)]
 pub struct AudioEmitter {
@@ -11,13 +14,63 @@ pub struct AudioEmitter {
 
 #[derive(Debug, Clone)]
 pub enum GameEvent {
-    SpriteSpawned { entity: Entity, atlas: String, region: String, audio: Option<AudioEmitter> },
-    SpriteAnimationEvent { entity: Entity, timeline: Arc<str>, event: Arc<str> },
-    EntityDespawned { entity: Entity },
-    CollisionStarted { a: Entity, b: Entity, audio: Option<AudioEmitter> },
-    CollisionEnded { a: Entity, b: Entity, audio: Option<AudioEmitter> },
-    CollisionForce { a: Entity, b: Entity, force: f32, audio: Option<AudioEmitter> },
-    ScriptMessage { message: String },
+    SpriteSpawned {
+        entity: Entity,
+        atlas: String,
+        region: String,
+        audio: Option<AudioEmitter>,
+    },
+    SpriteAnimationEvent {
+        entity: Entity,
+        timeline: Arc<str>,
+        event: Arc<str>,
+    },
+    AnimationFinished {
+        entity: Entity,
+        atlas: Arc<str>,
+        timeline: Arc<str>,
+    },
+    ClipFinished {
+        entity: Entity,
+        clip: Arc<str>,
+    },
+    EntityDespawned {
+        entity: Entity,
+    },
+    CollisionStarted {
+        a: Entity,
+        b: Entity,
+        audio: Option<AudioEmitter>,
+    },
+    CollisionEnded {
+        a: Entity,
+        b: Entity,
+        audio: Option<AudioEmitter>,
+    },
+    CollisionForce {
+        a: Entity,
+        b: Entity,
+        force: f32,
+        audio: Option<AudioEmitter>,
+    },
+    ScriptMessage {
+        message: String,
+    },
+    ScriptEvent {
+        name: String,
+        payload: Option<String>,
+    },
+    /// A particle from `emitter` hit a collider. Throttled per emitter, so not every contact is reported.
+    ParticleCollision {
+        emitter: Entity,
+        position: Vec2,
+    },
+    /// Answer to a script's `request_bone_transform`: the bone's world-space position and rotation.
+    BoneTransformResult {
+        handle: ScriptHandle,
+        position: Vec3,
+        rotation: Quat,
+    },
 }
 
 impl GameEvent {
@@ -40,6 +93,121 @@ impl GameEvent {
         let (a, b) = Self::ordered_pair(a, b);
         GameEvent::CollisionForce { a, b, force, audio: None }
     }
+
+    /// Publishes the typed struct matching this variant, e.g. [`CollisionStarted`], to the
+    /// subscriptions of `owner`.
+    fn publish_variant(&self, bus: &EventBus, owner: Option<&str>) {
+        match self.clone() {
+            GameEvent::SpriteSpawned { entity, atlas, region, audio } => {
+                bus.deliver(owner, SpriteSpawned { entity, atlas, region, audio })
+            }
+            GameEvent::SpriteAnimationEvent { entity, timeline, event } => {
+                bus.deliver(owner, SpriteAnimationEvent { entity, timeline, event })
+            }
+            GameEvent::AnimationFinished { entity, atlas, timeline } => {
+                bus.deliver(owner, AnimationFinished { entity, atlas, timeline })
+            }
+            GameEvent::ClipFinished { entity, clip } => bus.deliver(owner, ClipFinished { entity, clip }),
+            GameEvent::EntityDespawned { entity } => bus.deliver(owner, EntityDespawned { entity }),
+            GameEvent::CollisionStarted { a, b, audio } => {
+                bus.deliver(owner, CollisionStarted { a, b, audio })
+            }
+            GameEvent::CollisionEnded { a, b, audio } => bus.deliver(owner, CollisionEnded { a, b, audio }),
+            GameEvent::CollisionForce { a, b, force, audio } => {
+                bus.deliver(owner, CollisionForce { a, b, force, audio })
+            }
+            GameEvent::ScriptMessage { message } => bus.deliver(owner, ScriptMessage { message }),
+            GameEvent::ScriptEvent { name, payload } => bus.deliver(owner, ScriptEvent { name, payload }),
+            GameEvent::ParticleCollision { emitter, position } => {
+                bus.deliver(owner, ParticleCollision { emitter, position })
+            }
+            GameEvent::BoneTransformResult { handle, position, rotation } => {
+                bus.deliver(owner, BoneTransformResult { handle, position, rotation })
+            }
+        }
+    }
+}
+
+// Typed forms of the `GameEvent` variants, so `EventBus` subscribers can listen for one kind of
+// event without matching the whole enum.
+
+#[derive(Debug, Clone)]
+pub struct SpriteSpawned {
+    pub entity: Entity,
+    pub atlas: String,
+    pub region: String,
+    pub audio: Option<AudioEmitter>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SpriteAnimationEvent {
+    pub entity: Entity,
+    pub timeline: Arc<str>,
+    pub event: Arc<str>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AnimationFinished {
+    pub entity: Entity,
+    pub atlas: Arc<str>,
+    pub timeline: Arc<str>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ClipFinished {
+    pub entity: Entity,
+    pub clip: Arc<str>,
+}
+
+#[derive(Debug, Clone)]
+pub struct EntityDespawned {
+    pub entity: Entity,
+}
+
+#[derive(Debug, Clone)]
+pub struct CollisionStarted {
+    pub a: Entity,
+    pub b: Entity,
+    pub audio: Option<AudioEmitter>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CollisionEnded {
+    pub a: Entity,
+    pub b: Entity,
+    pub audio: Option<AudioEmitter>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CollisionForce {
+    pub a: Entity,
+    pub b: Entity,
+    pub force: f32,
+    pub audio: Option<AudioEmitter>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ScriptMessage {
+    pub message: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ScriptEvent {
+    pub name: String,
+    pub payload: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ParticleCollision {
+    pub emitter: Entity,
+    pub position: Vec2,
+}
+
+#[derive(Debug, Clone)]
+pub struct BoneTransformResult {
+    pub handle: ScriptHandle,
+    pub position: Vec3,
+    pub rotation: Quat,
 }
 
 impl fmt::Display for GameEvent {
@@ -57,6 +225,12 @@ impl fmt::Display for GameEvent {
                     event
                 )
             }
+            GameEvent::AnimationFinished { entity, atlas, timeline } => {
+                write!(f, "AnimationFinished entity={} atlas={} timeline={}", entity.index(), atlas, timeline)
+            }
+            GameEvent::ClipFinished { entity, clip } => {
+                write!(f, "ClipFinished entity={} clip={}", entity.index(), clip)
+            }
             GameEvent::EntityDespawned { entity } => {
                 write!(f, "EntityDespawned entity={}", entity.index())
             }
@@ -70,13 +244,42 @@ impl fmt::Display for GameEvent {
                 write!(f, "CollisionForce a={} b={} force={:.3}", a.index(), b.index(), force)
             }
             GameEvent::ScriptMessage { message } => write!(f, "ScriptMessage {message}"),
+            GameEvent::ScriptEvent { name, payload: Some(payload) } => {
+                write!(f, "ScriptEvent name={name} payload={payload}")
+            }
+            GameEvent::ScriptEvent { name, payload: None } => write!(f, "ScriptEvent name={name}"),
+            GameEvent::ParticleCollision { emitter, position } => write!(
+                f,
+                "ParticleCollision emitter={} position=({:.2}, {:.2})",
+                emitter.index(),
+                position.x,
+                position.y
+            ),
+            GameEvent::BoneTransformResult { handle, position, rotation } => write!(
+                f,
+                "BoneTransformResult handle={handle} position=({:.2}, {:.2}, {:.2}) rotation=({:.3}, {:.3}, {:.3}, {:.3})",
+                position.x, position.y, position.z, rotation.x, rotation.y, rotation.z, rotation.w
+            ),
         }
     }
 }
 
+type Handler = Box<dyn Fn(&dyn Any) + Send>;
+
+struct Subscriber {
+    /// Plugin that registered the handler, or `None` for host code.
+    owner: Option<String>,
+    handler: Handler,
+}
+
+/// Queue of [`GameEvent`]s raised during a frame, plus typed subscriptions. Subscribers are keyed
+/// by event type and outlive [`Self::drain`]; plugin subscriptions also record the owning plugin so
+/// they can be dispatched inside its panic guard and dropped when it goes away.
 #[derive(Default, Resource)]
 pub struct EventBus {
     events: Vec<GameEvent>,
+    // Behind a mutex only because resources must be `Sync`; publishing never nests.
+    subscribers: Mutex<HashMap<TypeId, Vec<Subscriber>>>,
 }
 
 impl EventBus {
@@ -87,4 +290,87 @@ impl EventBus {
     pub fn drain(&mut self) -> Vec<GameEvent> {
         self.events.drain(..).collect()
     }
+
+    /// Calls `handler` for every event of type `E` published from now on.
+    pub fn subscribe<E: 'static>(&mut self, handler: impl Fn(&E) + Send + 'static) {
+        self.add_subscriber(None, handler);
+    }
+
+    /// Like [`Self::subscribe`], but owned by the plugin `owner`: [`Self::dispatch`] skips it in
+    /// favour of [`Self::dispatch_to`], and [`Self::unsubscribe_owner`] removes it.
+    pub fn subscribe_as<E: 'static>(&mut self, owner: &str, handler: impl Fn(&E) + Send + 'static) {
+        self.add_subscriber(Some(owner.to_string()), handler);
+    }
+
+    fn add_subscriber<E: 'static>(&mut self, owner: Option<String>, handler: impl Fn(&E) + Send + 'static) {
+        let handler: Handler = Box::new(move |event| {
+            if let Some(event) = event.downcast_ref::<E>() {
+                handler(event);
+            }
+        });
+        let subscribers = self.subscribers.get_mut().unwrap_or_else(PoisonError::into_inner);
+        subscribers.entry(TypeId::of::<E>()).or_default().push(Subscriber { owner, handler });
+    }
+
+    /// Drops every subscription registered by `owner` and returns how many there were.
+    pub fn unsubscribe_owner(&mut self, owner: &str) -> usize {
+        let subscribers = self.subscribers.get_mut().unwrap_or_else(PoisonError::into_inner);
+        let mut removed = 0;
+        subscribers.retain(|_, list| {
+            let before = list.len();
+            list.retain(|subscriber| subscriber.owner.as_deref() != Some(owner));
+            removed += before - list.len();
+            !list.is_empty()
+        });
+        removed
+    }
+
+    /// Hands `event` to every subscriber of `E`, host or plugin. Unlike [`Self::push`], nothing is
+    /// queued.
+    pub fn publish<E: 'static>(&self, event: E) {
+        let subscribers = self.subscribers.lock().unwrap_or_else(PoisonError::into_inner);
+        for subscriber in subscribers.get(&TypeId::of::<E>()).into_iter().flatten() {
+            (subscriber.handler)(&event);
+        }
+    }
+
+    /// Hands `event` to the subscribers of `E` registered by `owner`; `None` selects host code.
+    fn deliver<E: 'static>(&self, owner: Option<&str>, event: E) {
+        let subscribers = self.subscribers.lock().unwrap_or_else(PoisonError::into_inner);
+        for subscriber in subscribers.get(&TypeId::of::<E>()).into_iter().flatten() {
+            if subscriber.owner.as_deref() == owner {
+                (subscriber.handler)(&event);
+            }
+        }
+    }
+
+    pub fn subscriber_count<E: 'static>(&self) -> usize {
+        let subscribers = self.subscribers.lock().unwrap_or_else(PoisonError::into_inner);
+        subscribers.get(&TypeId::of::<E>()).map_or(0, Vec::len)
+    }
+
+    /// Number of subscriptions, of any event type, registered by `owner`.
+    pub fn owner_subscriber_count(&self, owner: &str) -> usize {
+        let subscribers = self.subscribers.lock().unwrap_or_else(PoisonError::into_inner);
+        subscribers.values().flatten().filter(|subscriber| subscriber.owner.as_deref() == Some(owner)).count()
+    }
+
+    /// Publishes `event` to the host subscribers, both as a [`GameEvent`] and as its typed variant
+    /// struct. Plugin subscriptions are reached through [`Self::dispatch_to`].
+    pub fn dispatch(&self, event: &GameEvent) {
+        self.dispatch_for(None, event);
+    }
+
+    /// Publishes `event` to the subscriptions `owner` registered, as in [`Self::dispatch`].
+    pub fn dispatch_to(&self, owner: &str, event: &GameEvent) {
+        self.dispatch_for(Some(owner), event);
+    }
+
+    fn dispatch_for(&self, owner: Option<&str>, event: &GameEvent) {
+        if self.subscribers.lock().unwrap_or_else(PoisonError::into_inner).is_empty() {
+            return;
+        }
+        self.deliver(owner, event.clone());
+        event.publish_variant(self, owner);
+    }
 }
@@ -1,10 +1,14 @@
-use crate::renderer::GpuPassTiming;
+use crate::ecs::ParticleBudgetMetrics;
+use crate::renderer::{GpuPassTiming, LightClusterMetrics};
 use anyhow::{anyhow, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
 use std::path::Path;
 
+/// Checked-in baseline that CI and the studio telemetry panel compare against.
+pub const DEFAULT_BASELINE_PATH: &str = "perf/gpu_baseline.json";
+
 #[derive(Debug, Default)]
 struct PassSamples {
     samples: Vec<f32>,
@@ -32,11 +36,23 @@ impl PassSamples {
     }
 }
 
+#[derive(Debug, Default)]
+struct SceneMetricSums {
+    samples: usize,
+    visible_lights: u64,
+    active_clusters: u64,
+    max_lights_per_cluster: u32,
+    overflow_clusters: u32,
+    active_particles: u64,
+    max_active_particles: u32,
+}
+
 /// Collects GPU pass timings across multiple frames and produces a serialized snapshot.
 #[derive(Debug, Default)]
 pub struct GpuTimingAccumulator {
     frame_count: usize,
     passes: BTreeMap<&'static str, PassSamples>,
+    scene: SceneMetricSums,
 }
 
 impl GpuTimingAccumulator {
@@ -51,6 +67,30 @@ impl GpuTimingAccumulator {
         }
     }
 
+    /// Records a pass's samples captured elsewhere, e.g. the analytics plugin's rolling window.
+    pub fn record_pass_samples(&mut self, label: &'static str, samples: &[f32]) {
+        if samples.is_empty() {
+            return;
+        }
+        let entry = self.passes.entry(label).or_default();
+        for value in samples {
+            entry.record(*value);
+        }
+        self.frame_count = self.frame_count.max(entry.samples.len());
+    }
+
+    /// Records the light clustering and particle load the frame was rendered with.
+    pub fn record_scene_metrics(&mut self, lights: &LightClusterMetrics, particles: &ParticleBudgetMetrics) {
+        let scene = &mut self.scene;
+        scene.samples += 1;
+        scene.visible_lights += u64::from(lights.visible_lights);
+        scene.active_clusters += u64::from(lights.active_clusters);
+        scene.max_lights_per_cluster = scene.max_lights_per_cluster.max(lights.max_lights_per_cluster);
+        scene.overflow_clusters = scene.overflow_clusters.max(lights.overflow_clusters);
+        scene.active_particles += u64::from(particles.active_particles);
+        scene.max_active_particles = scene.max_active_particles.max(particles.active_particles);
+    }
+
     /// Creates a serializable snapshot using the provided metadata.
     pub fn snapshot(
         &self,
@@ -74,16 +114,72 @@ impl GpuTimingAccumulator {
             })
             .collect();
         passes.sort_by(|a, b| a.label.cmp(&b.label));
+        let scene = &self.scene;
+        let average = |sum: u64| if scene.samples == 0 { 0.0 } else { sum as f32 / scene.samples as f32 };
         GpuBaselineSnapshot {
             label: label.into(),
             timestamp: timestamp.into(),
             commit: commit.into(),
             frame_count: self.frame_count,
             passes,
+            adapter: None,
+            scene_metrics: GpuBaselineSceneMetrics {
+                average_visible_lights: average(scene.visible_lights),
+                average_active_clusters: average(scene.active_clusters),
+                max_lights_per_cluster: scene.max_lights_per_cluster,
+                overflow_clusters: scene.overflow_clusters,
+                average_active_particles: average(scene.active_particles),
+                max_active_particles: scene.max_active_particles,
+            },
         }
     }
 }
 
+/// Hardware a snapshot was captured on. Timings from different adapters are not comparable, so a
+/// mismatch is reported but never fails a comparison on its own.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GpuAdapterIdentity {
+    pub name: String,
+    pub driver: String,
+    pub driver_info: String,
+    pub backend: String,
+}
+
+impl GpuAdapterIdentity {
+    pub fn from_adapter_info(info: &wgpu::AdapterInfo) -> Self {
+        Self {
+            name: info.name.clone(),
+            driver: info.driver.clone(),
+            driver_info: info.driver_info.clone(),
+            backend: format!("{:?}", info.backend),
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        let driver = [self.driver.as_str(), self.driver_info.as_str()]
+            .into_iter()
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+        if driver.is_empty() {
+            format!("{} ({})", self.name, self.backend)
+        } else {
+            format!("{} ({}, {driver})", self.name, self.backend)
+        }
+    }
+}
+
+/// Scene load the timings were captured under, averaged over the recorded frames.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GpuBaselineSceneMetrics {
+    pub average_visible_lights: f32,
+    pub average_active_clusters: f32,
+    pub max_lights_per_cluster: u32,
+    pub overflow_clusters: u32,
+    pub average_active_particles: f32,
+    pub max_active_particles: u32,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GpuBaselineSnapshot {
     pub label: String,
@@ -91,6 +187,10 @@ pub struct GpuBaselineSnapshot {
     pub commit: String,
     pub frame_count: usize,
     pub passes: Vec<GpuPassSnapshot>,
+    #[serde(default)]
+    pub adapter: Option<GpuAdapterIdentity>,
+    #[serde(default)]
+    pub scene_metrics: GpuBaselineSceneMetrics,
 }
 
 impl GpuBaselineSnapshot {
@@ -134,6 +234,164 @@ pub struct GpuBaselineDelta {
     pub status: BaselinePassStatus,
 }
 
+impl GpuBaselineDelta {
+    /// Slower than the baseline allows, or a baseline pass that no longer runs. Passes that got
+    /// faster or are new only warrant re-recording the baseline.
+    pub fn is_regression(&self) -> bool {
+        match self.status {
+            BaselinePassStatus::Matched => self.delta_ms > self.allowed_drift_ms,
+            BaselinePassStatus::MissingInCurrent => true,
+            BaselinePassStatus::MissingInBaseline => false,
+        }
+    }
+
+    pub fn delta_percent(&self) -> Option<f32> {
+        (self.baseline_avg_ms > 0.0).then(|| self.delta_ms / self.baseline_avg_ms * 100.0)
+    }
+}
+
+/// Allowed slowdown per pass as a percentage of the baseline average.
+#[derive(Debug, Clone)]
+pub struct GpuBaselineTolerances {
+    pub default_percent: f32,
+    pub pass_percent: HashMap<String, f32>,
+    /// Lower bound on the allowed drift so passes that take a few microseconds are not failed by
+    /// timer jitter.
+    pub min_drift_ms: f32,
+}
+
+impl Default for GpuBaselineTolerances {
+    fn default() -> Self {
+        Self {
+            default_percent: 10.0,
+            pass_percent: HashMap::from([
+                ("Shadow pass".to_string(), 15.0),
+                ("Mesh pass".to_string(), 10.0),
+                ("Sprite pass".to_string(), 10.0),
+            ]),
+            min_drift_ms: 0.05,
+        }
+    }
+}
+
+impl GpuBaselineTolerances {
+    pub fn percent_for(&self, label: &str) -> f32 {
+        self.pass_percent.get(label).copied().unwrap_or(self.default_percent)
+    }
+
+    /// Converts the percentages into per-pass millisecond drifts against `baseline`.
+    pub fn drift_limits_ms(&self, baseline: &GpuBaselineSnapshot) -> HashMap<String, f32> {
+        baseline
+            .passes
+            .iter()
+            .map(|pass| {
+                let allowed = pass.average_ms * self.percent_for(&pass.label) / 100.0;
+                (pass.label.clone(), allowed.max(self.min_drift_ms))
+            })
+            .collect()
+    }
+}
+
+/// Outcome of comparing a capture against a stored baseline.
+#[derive(Debug, Clone)]
+pub struct GpuBaselineReport {
+    pub baseline_commit: String,
+    pub current_commit: String,
+    pub deltas: Vec<GpuBaselineDelta>,
+    /// Set when the two snapshots were captured on different adapters.
+    pub adapter_mismatch: Option<String>,
+    pub baseline_scene: GpuBaselineSceneMetrics,
+    pub current_scene: GpuBaselineSceneMetrics,
+}
+
+impl GpuBaselineReport {
+    pub fn compare(
+        baseline: &GpuBaselineSnapshot,
+        current: &GpuBaselineSnapshot,
+        tolerances: &GpuBaselineTolerances,
+    ) -> Result<Self> {
+        let limits = tolerances.drift_limits_ms(baseline);
+        let deltas = compare_baselines(baseline, current, &limits, tolerances.min_drift_ms)?;
+        let adapter_mismatch = match (&baseline.adapter, &current.adapter) {
+            (Some(recorded), Some(running)) if recorded != running => Some(format!(
+                "baseline recorded on {}, running on {}",
+                recorded.describe(),
+                running.describe()
+            )),
+            (None, Some(_)) => Some("baseline has no adapter identity".to_string()),
+            _ => None,
+        };
+        Ok(Self {
+            baseline_commit: baseline.commit.clone(),
+            current_commit: current.commit.clone(),
+            deltas,
+            adapter_mismatch,
+            baseline_scene: baseline.scene_metrics.clone(),
+            current_scene: current.scene_metrics.clone(),
+        })
+    }
+
+    pub fn regressions(&self) -> impl Iterator<Item = &GpuBaselineDelta> {
+        self.deltas.iter().filter(|delta| delta.is_regression())
+    }
+
+    pub fn has_regressions(&self) -> bool {
+        self.regressions().next().is_some()
+    }
+
+    /// Human-readable per-pass report, shared by the CLI output and the studio telemetry panel.
+    pub fn lines(&self) -> Vec<String> {
+        let mut lines = vec![format!("Baseline {} vs current {}", self.baseline_commit, self.current_commit)];
+        if let Some(mismatch) = &self.adapter_mismatch {
+            lines.push(format!("note: adapter differs ({mismatch}); timings may not be comparable"));
+        }
+        for delta in &self.deltas {
+            let verdict = if delta.is_regression() {
+                "REGRESSION"
+            } else if delta.within_tolerance {
+                "ok"
+            } else {
+                "changed"
+            };
+            let line = match delta.status {
+                BaselinePassStatus::Matched => format!(
+                    "{:<14} {:>7.3} ms -> {:>7.3} ms ({:+.1}%, limit {:.3} ms) {verdict}",
+                    delta.label,
+                    delta.baseline_avg_ms,
+                    delta.current_avg_ms,
+                    delta.delta_percent().unwrap_or(0.0),
+                    delta.allowed_drift_ms
+                ),
+                BaselinePassStatus::MissingInCurrent => {
+                    format!("{:<14} missing from this run {verdict}", delta.label)
+                }
+                BaselinePassStatus::MissingInBaseline => {
+                    format!("{:<14} {:>7.3} ms, not in baseline", delta.label, delta.current_avg_ms)
+                }
+            };
+            lines.push(line);
+        }
+        let (base, curr) = (&self.baseline_scene, &self.current_scene);
+        lines.push(format!(
+            "Lights visible {:.1} -> {:.1}, max/cluster {} -> {}, overflow {} -> {}",
+            base.average_visible_lights,
+            curr.average_visible_lights,
+            base.max_lights_per_cluster,
+            curr.max_lights_per_cluster,
+            base.overflow_clusters,
+            curr.overflow_clusters
+        ));
+        lines.push(format!(
+            "Particles active {:.0} -> {:.0} (peak {} -> {})",
+            base.average_active_particles,
+            curr.average_active_particles,
+            base.max_active_particles,
+            curr.max_active_particles
+        ));
+        lines
+    }
+}
+
 /// Compares two snapshots and returns per-pass deltas against the supplied tolerances.
 pub fn compare_baselines(
     baseline: &GpuBaselineSnapshot,
@@ -226,6 +484,8 @@ mod tests {
             timestamp: "t0".into(),
             commit: "abc".into(),
             frame_count: 1,
+            adapter: None,
+            scene_metrics: GpuBaselineSceneMetrics::default(),
             passes: vec![
                 GpuPassSnapshot {
                     label: "Sprite pass".into(),
@@ -248,6 +508,8 @@ mod tests {
             timestamp: "t1".into(),
             commit: "def".into(),
             frame_count: 1,
+            adapter: None,
+            scene_metrics: GpuBaselineSceneMetrics::default(),
             passes: vec![
                 GpuPassSnapshot {
                     label: "Sprite pass".into(),
@@ -285,6 +547,8 @@ mod tests {
             timestamp: "t0".into(),
             commit: "abc".into(),
             frame_count: 1,
+            adapter: None,
+            scene_metrics: GpuBaselineSceneMetrics::default(),
             passes: vec![GpuPassSnapshot {
                 label: "Mesh pass".into(),
                 latest_ms: 1.0,
@@ -298,6 +562,8 @@ mod tests {
             timestamp: "t1".into(),
             commit: "def".into(),
             frame_count: 1,
+            adapter: None,
+            scene_metrics: GpuBaselineSceneMetrics::default(),
             passes: vec![GpuPassSnapshot {
                 label: "Mesh pass".into(),
                 latest_ms: 0.3,
@@ -321,6 +587,8 @@ mod tests {
             timestamp: "t0".into(),
             commit: "abc".into(),
             frame_count: 1,
+            adapter: None,
+            scene_metrics: GpuBaselineSceneMetrics::default(),
             passes: vec![
                 GpuPassSnapshot {
                     label: "Sprite pass".into(),
@@ -343,6 +611,8 @@ mod tests {
             timestamp: "t1".into(),
             commit: "def".into(),
             frame_count: 1,
+            adapter: None,
+            scene_metrics: GpuBaselineSceneMetrics::default(),
             passes: vec![
                 GpuPassSnapshot {
                     label: "Sprite pass".into(),
@@ -374,4 +644,52 @@ mod tests {
         assert_eq!(lighting.current_avg_ms, 0.7);
         assert!(!lighting.within_tolerance);
     }
+
+    fn mesh_snapshot(commit: &str, mesh_ms: f32, adapter_name: &str) -> GpuBaselineSnapshot {
+        let mut accumulator = GpuTimingAccumulator::default();
+        accumulator.record_pass_samples("Mesh pass", &[mesh_ms; 4]);
+        accumulator.record_pass_samples("Sprite pass", &[1.0; 4]);
+        let mut snapshot = accumulator.snapshot("stress", "t", commit);
+        snapshot.adapter = Some(GpuAdapterIdentity {
+            name: adapter_name.into(),
+            driver: "driver".into(),
+            driver_info: "1.0".into(),
+            backend: "Vulkan".into(),
+        });
+        snapshot
+    }
+
+    #[test]
+    fn report_flags_a_twenty_percent_mesh_regression() {
+        let baseline = mesh_snapshot("abc", 2.0, "GPU");
+        let current = mesh_snapshot("def", 2.4, "GPU");
+        let report =
+            GpuBaselineReport::compare(&baseline, &current, &GpuBaselineTolerances::default()).unwrap();
+        assert!(report.has_regressions());
+        let regressed: Vec<_> = report.regressions().map(|delta| delta.label.as_str()).collect();
+        assert_eq!(regressed, vec!["Mesh pass"]);
+        assert!(report.adapter_mismatch.is_none());
+        assert!(report
+            .lines()
+            .iter()
+            .any(|line| line.starts_with("Mesh pass") && line.ends_with("REGRESSION")));
+    }
+
+    #[test]
+    fn report_treats_speedups_and_adapter_mismatch_as_informational() {
+        let baseline = mesh_snapshot("abc", 2.0, "GPU A");
+        let current = mesh_snapshot("def", 1.0, "GPU B");
+        let report =
+            GpuBaselineReport::compare(&baseline, &current, &GpuBaselineTolerances::default()).unwrap();
+        assert!(!report.has_regressions());
+        assert!(report.adapter_mismatch.as_deref().is_some_and(|note| note.contains("GPU A")));
+    }
+
+    #[test]
+    fn percentage_tolerance_has_a_millisecond_floor() {
+        let baseline = mesh_snapshot("abc", 0.1, "GPU");
+        let limits = GpuBaselineTolerances::default().drift_limits_ms(&baseline);
+        assert_eq!(limits["Mesh pass"], 0.05);
+        assert!((limits["Sprite pass"] - 0.1).abs() < 1e-6);
+    }
 }
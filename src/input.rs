@@ -1,5 +1,5 @@
 use serde::Deserialize;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::Path;
 use winit::event::{DeviceEvent, ElementState, MouseButton, MouseScrollDelta, WindowEvent};
@@ -15,6 +15,17 @@ pub struct Input {
     mesh_toggle_pressed: bool,
     camera_mode_toggle_pressed: bool,
     delete_selection_pressed: bool,
+    copy_selection_pressed: bool,
+    paste_selection_pressed: bool,
+    duplicate_selection_pressed: bool,
+    cycle_selection_pressed: bool,
+    focus_selection_pressed: bool,
+    reset_camera_pressed: bool,
+    zoom_steps: i32,
+    pan_up_held: bool,
+    pan_down_held: bool,
+    pan_left_held: bool,
+    pan_right_held: bool,
     forward_held: bool,
     backward_held: bool,
     left_held: bool,
@@ -31,6 +42,8 @@ pub struct Input {
     left_pressed: bool,
     left_clicked: bool,
     right_pressed: bool,
+    held_keys: HashSet<InputKeyBinding>,
+    fired_chords: HashSet<String>,
 }
 
 impl Input {
@@ -54,6 +67,17 @@ impl Input {
             mesh_toggle_pressed: false,
             camera_mode_toggle_pressed: false,
             delete_selection_pressed: false,
+            copy_selection_pressed: false,
+            paste_selection_pressed: false,
+            duplicate_selection_pressed: false,
+            cycle_selection_pressed: false,
+            focus_selection_pressed: false,
+            reset_camera_pressed: false,
+            zoom_steps: 0,
+            pan_up_held: false,
+            pan_down_held: false,
+            pan_left_held: false,
+            pan_right_held: false,
             forward_held: false,
             backward_held: false,
             left_held: false,
@@ -70,13 +94,17 @@ impl Input {
             left_pressed: false,
             left_clicked: false,
             right_pressed: false,
+            held_keys: HashSet::new(),
+            fired_chords: HashSet::new(),
         }
     }
 
     pub fn push(&mut self, ev: InputEvent) {
         match &ev {
             InputEvent::Key { key, pressed } => {
-                self.apply_key_binding(key, *pressed);
+                if !self.apply_chords(key, *pressed) && !self.apply_clipboard_shortcut(key, *pressed) {
+                    self.apply_key_binding(key, *pressed);
+                }
             }
             InputEvent::MouseMove { dx, dy } => {
                 self.mouse_delta.0 += *dx;
@@ -116,7 +144,15 @@ impl Input {
         self.camera_mode_toggle_pressed = false;
         self.frustum_lock_toggle = false;
         self.delete_selection_pressed = false;
+        self.copy_selection_pressed = false;
+        self.paste_selection_pressed = false;
+        self.duplicate_selection_pressed = false;
+        self.cycle_selection_pressed = false;
+        self.focus_selection_pressed = false;
+        self.reset_camera_pressed = false;
+        self.zoom_steps = 0;
         self.cursor_world = None;
+        self.fired_chords.clear();
     }
 
     pub fn consume_wheel_delta(&mut self) -> Option<f32> {
@@ -221,6 +257,134 @@ impl Input {
         pressed
     }
 
+    pub fn take_copy_selection(&mut self) -> bool {
+        let pressed = self.copy_selection_pressed;
+        self.copy_selection_pressed = false;
+        pressed
+    }
+
+    pub fn take_paste_selection(&mut self) -> bool {
+        let pressed = self.paste_selection_pressed;
+        self.paste_selection_pressed = false;
+        pressed
+    }
+
+    pub fn take_duplicate_selection(&mut self) -> bool {
+        let pressed = self.duplicate_selection_pressed;
+        self.duplicate_selection_pressed = false;
+        pressed
+    }
+
+    pub fn take_cycle_selection(&mut self) -> bool {
+        let pressed = self.cycle_selection_pressed;
+        self.cycle_selection_pressed = false;
+        pressed
+    }
+
+    pub fn take_focus_selection(&mut self) -> bool {
+        let pressed = self.focus_selection_pressed;
+        self.focus_selection_pressed = false;
+        pressed
+    }
+
+    pub fn take_reset_camera(&mut self) -> bool {
+        let pressed = self.reset_camera_pressed;
+        self.reset_camera_pressed = false;
+        pressed
+    }
+
+    /// Net zoom-in presses since the last call; zoom-out presses count as negative steps.
+    pub fn take_zoom_steps(&mut self) -> i32 {
+        std::mem::take(&mut self.zoom_steps)
+    }
+
+    /// Held viewport pan keys as an (x, y) axis with +y pointing up.
+    pub fn viewport_pan_axis(&self) -> (f32, f32) {
+        let axis = |positive: bool, negative: bool| positive as i32 as f32 - negative as i32 as f32;
+        (axis(self.pan_right_held, self.pan_left_held), axis(self.pan_up_held, self.pan_down_held))
+    }
+
+    /// True while every key in `keys` is held. Keys the bindings cannot represent never count as held.
+    pub fn chord_pressed(&self, keys: &[Key]) -> bool {
+        !keys.is_empty()
+            && keys.iter().all(|key| {
+                InputKeyBinding::from_event_key(key).is_some_and(|binding| self.held_keys.contains(&binding))
+            })
+    }
+
+    /// Binds `name` to a key combination such as Ctrl+Shift+S, replacing any earlier chord with
+    /// that name. Poll it with [`Input::take_chord`].
+    pub fn register_chord(&mut self, name: &str, keys: Vec<Key>) {
+        let Some(bindings) = keys.iter().map(InputKeyBinding::from_event_key).collect::<Option<Vec<_>>>()
+        else {
+            eprintln!("[input] chord '{name}' uses a key that cannot be bound, ignoring.");
+            return;
+        };
+        if bindings.is_empty() {
+            eprintln!("[input] chord '{name}' has no keys, ignoring.");
+            return;
+        }
+        self.bindings.chords.insert(name.to_string(), bindings);
+    }
+
+    /// Whether the named chord fired since the last call or frame.
+    pub fn take_chord(&mut self, name: &str) -> bool {
+        self.fired_chords.remove(name)
+    }
+
+    /// Tracks held keys and fires registered chords. A chord fires when its last key goes down,
+    /// unless that key is a modifier (so Shift+Ctrl+S and Ctrl+Shift+S behave the same); when
+    /// several chords match, only the longest fire, so Ctrl+Shift+S does not also trigger Ctrl+S.
+    /// Returns true when the press completed a chord and the key's own binding should be skipped.
+    fn apply_chords(&mut self, key: &Key, pressed: bool) -> bool {
+        let Some(binding) = InputKeyBinding::from_event_key(key) else {
+            return false;
+        };
+        if !pressed {
+            self.held_keys.remove(&binding);
+            return false;
+        }
+        let newly_pressed = self.held_keys.insert(binding.clone());
+        let matched: Vec<(&String, &Vec<InputKeyBinding>)> = self
+            .bindings
+            .chords
+            .iter()
+            .filter(|(_, keys)| keys.contains(&binding) && keys.iter().all(|k| self.held_keys.contains(k)))
+            .collect();
+        let Some(longest) = matched.iter().map(|(_, keys)| keys.len()).max() else {
+            return false;
+        };
+        let is_modifier = binding.is_modifier();
+        let fired: Vec<String> = matched
+            .into_iter()
+            .filter(|(_, keys)| keys.len() == longest)
+            .filter(|(_, keys)| !is_modifier || keys.iter().all(InputKeyBinding::is_modifier))
+            .map(|(name, _)| name.clone())
+            .collect();
+        if newly_pressed {
+            self.fired_chords.extend(fired);
+        }
+        !is_modifier
+    }
+
+    /// Ctrl+C / Ctrl+V / Ctrl+D are fixed editor shortcuts; the press is swallowed so the plain
+    /// "c"/"v"/"d" bindings (roll, camera mode, freefly right) do not also fire.
+    fn apply_clipboard_shortcut(&mut self, key: &Key, pressed: bool) -> bool {
+        if !pressed || !self.ctrl_held {
+            return false;
+        }
+        let Key::Character(ch) = key else {
+            return false;
+        };
+        match ch.to_lowercase().as_str() {
+            "c" => self.copy_selection_pressed = true,
+            "v" => self.paste_selection_pressed = true,
+            "d" => self.duplicate_selection_pressed = true,
+            _ => return false,
+        }
+        true
+    }
+
     fn apply_key_binding(&mut self, key: &Key, pressed: bool) {
         if let Some(binding_key) = InputKeyBinding::from_event_key(key) {
             let actions: Vec<_> = self.bindings.actions_for_key(&binding_key).collect();
@@ -262,6 +426,35 @@ impl Input {
                     self.frustum_lock_toggle = true;
                 }
             }
+            InputAction::CycleSelection => {
+                if pressed {
+                    self.cycle_selection_pressed = true;
+                }
+            }
+            InputAction::FocusSelection => {
+                if pressed {
+                    self.focus_selection_pressed = true;
+                }
+            }
+            InputAction::ResetCamera => {
+                if pressed {
+                    self.reset_camera_pressed = true;
+                }
+            }
+            InputAction::ViewportZoomIn => {
+                if pressed {
+                    self.zoom_steps += 1;
+                }
+            }
+            InputAction::ViewportZoomOut => {
+                if pressed {
+                    self.zoom_steps -= 1;
+                }
+            }
+            InputAction::ViewportPanUp => self.pan_up_held = pressed,
+            InputAction::ViewportPanDown => self.pan_down_held = pressed,
+            InputAction::ViewportPanLeft => self.pan_left_held = pressed,
+            InputAction::ViewportPanRight => self.pan_right_held = pressed,
             InputAction::FreeflyForward => self.forward_held = pressed,
             InputAction::FreeflyBackward => self.backward_held = pressed,
             InputAction::FreeflyLeft => self.left_held = pressed,
@@ -285,6 +478,7 @@ impl Default for Input {
 #[derive(Debug, Clone)]
 struct InputBindings {
     key_to_actions: HashMap<InputKeyBinding, Vec<InputAction>>,
+    chords: HashMap<String, Vec<InputKeyBinding>>,
 }
 
 impl InputBindings {
@@ -312,8 +506,10 @@ impl InputBindings {
     }
 
     fn from_config(config: InputConfigFile, origin: &str) -> Self {
-        let overrides = config.into_overrides(origin);
-        Self::with_overrides(overrides)
+        let (overrides, chords) = config.into_overrides(origin);
+        let mut bindings = Self::with_overrides(overrides);
+        bindings.chords.extend(chords);
+        bindings
     }
 
     fn with_overrides(overrides: HashMap<InputAction, Vec<InputKeyBinding>>) -> Self {
@@ -327,6 +523,13 @@ impl InputBindings {
         Self::from_action_map(action_map)
     }
 
+    fn default_chords() -> HashMap<String, Vec<InputKeyBinding>> {
+        HashMap::from([(
+            "save_scene".to_string(),
+            vec![InputKeyBinding::named(NamedKeyCode::Control), InputKeyBinding::character("s")],
+        )])
+    }
+
     fn default_action_map() -> HashMap<InputAction, Vec<InputKeyBinding>> {
         use InputAction::*;
         let mut map = HashMap::new();
@@ -336,6 +539,27 @@ impl InputBindings {
         map.insert(CameraModeToggle, vec![InputKeyBinding::character("v")]);
         map.insert(DeleteSelection, vec![InputKeyBinding::named(NamedKeyCode::Delete)]);
         map.insert(FrustumLockToggle, vec![InputKeyBinding::character("l")]);
+        map.insert(CycleSelection, vec![InputKeyBinding::named(NamedKeyCode::Tab)]);
+        map.insert(FocusSelection, vec![InputKeyBinding::character("f")]);
+        map.insert(ResetCamera, vec![InputKeyBinding::named(NamedKeyCode::Home)]);
+        map.insert(ViewportZoomIn, vec![InputKeyBinding::character("+"), InputKeyBinding::character("=")]);
+        map.insert(ViewportZoomOut, vec![InputKeyBinding::character("-")]);
+        map.insert(
+            ViewportPanUp,
+            vec![InputKeyBinding::character("w"), InputKeyBinding::named(NamedKeyCode::ArrowUp)],
+        );
+        map.insert(
+            ViewportPanDown,
+            vec![InputKeyBinding::character("s"), InputKeyBinding::named(NamedKeyCode::ArrowDown)],
+        );
+        map.insert(
+            ViewportPanLeft,
+            vec![InputKeyBinding::character("a"), InputKeyBinding::named(NamedKeyCode::ArrowLeft)],
+        );
+        map.insert(
+            ViewportPanRight,
+            vec![InputKeyBinding::character("d"), InputKeyBinding::named(NamedKeyCode::ArrowRight)],
+        );
         map.insert(FreeflyForward, vec![InputKeyBinding::character("w")]);
         map.insert(FreeflyBackward, vec![InputKeyBinding::character("s")]);
         map.insert(FreeflyLeft, vec![InputKeyBinding::character("a")]);
@@ -356,7 +580,7 @@ impl InputBindings {
                 key_to_actions.entry(key).or_default().push(action);
             }
         }
-        Self { key_to_actions }
+        Self { key_to_actions, chords: Self::default_chords() }
     }
 
     fn actions_for_key(&self, key: &InputKeyBinding) -> impl Iterator<Item = InputAction> + '_ {
@@ -400,6 +624,10 @@ impl InputKeyBinding {
         }
     }
 
+    fn is_modifier(&self) -> bool {
+        matches!(self, Self::Named(NamedKeyCode::Shift | NamedKeyCode::Control | NamedKeyCode::Alt))
+    }
+
     fn from_config_value(raw: &str) -> Result<Self, ()> {
         let normalized = raw.trim().to_lowercase();
         if normalized.is_empty() {
@@ -420,7 +648,14 @@ enum NamedKeyCode {
     Space,
     Shift,
     Control,
+    Alt,
     Delete,
+    Tab,
+    Home,
+    ArrowUp,
+    ArrowDown,
+    ArrowLeft,
+    ArrowRight,
 }
 
 impl NamedKeyCode {
@@ -429,7 +664,14 @@ impl NamedKeyCode {
             NamedKey::Space => Some(Self::Space),
             NamedKey::Shift => Some(Self::Shift),
             NamedKey::Control => Some(Self::Control),
+            NamedKey::Alt => Some(Self::Alt),
             NamedKey::Delete => Some(Self::Delete),
+            NamedKey::Tab => Some(Self::Tab),
+            NamedKey::Home => Some(Self::Home),
+            NamedKey::ArrowUp => Some(Self::ArrowUp),
+            NamedKey::ArrowDown => Some(Self::ArrowDown),
+            NamedKey::ArrowLeft => Some(Self::ArrowLeft),
+            NamedKey::ArrowRight => Some(Self::ArrowRight),
             _ => None,
         }
     }
@@ -439,7 +681,14 @@ impl NamedKeyCode {
             "space" => Some(Self::Space),
             "shift" | "left_shift" | "right_shift" => Some(Self::Shift),
             "ctrl" | "control" | "left_ctrl" | "right_ctrl" => Some(Self::Control),
+            "alt" | "option" | "left_alt" | "right_alt" => Some(Self::Alt),
             "delete" | "del" => Some(Self::Delete),
+            "tab" => Some(Self::Tab),
+            "home" => Some(Self::Home),
+            "up" | "arrow_up" => Some(Self::ArrowUp),
+            "down" | "arrow_down" => Some(Self::ArrowDown),
+            "left" | "arrow_left" => Some(Self::ArrowLeft),
+            "right" | "arrow_right" => Some(Self::ArrowRight),
             _ => None,
         }
     }
@@ -453,6 +702,15 @@ enum InputAction {
     CameraModeToggle,
     DeleteSelection,
     FrustumLockToggle,
+    CycleSelection,
+    FocusSelection,
+    ResetCamera,
+    ViewportZoomIn,
+    ViewportZoomOut,
+    ViewportPanUp,
+    ViewportPanDown,
+    ViewportPanLeft,
+    ViewportPanRight,
     FreeflyForward,
     FreeflyBackward,
     FreeflyLeft,
@@ -474,6 +732,15 @@ impl InputAction {
             "camera_mode_toggle" => Some(Self::CameraModeToggle),
             "delete_selection" => Some(Self::DeleteSelection),
             "frustum_lock_toggle" => Some(Self::FrustumLockToggle),
+            "cycle_selection" => Some(Self::CycleSelection),
+            "focus_selection" => Some(Self::FocusSelection),
+            "reset_camera" => Some(Self::ResetCamera),
+            "viewport_zoom_in" => Some(Self::ViewportZoomIn),
+            "viewport_zoom_out" => Some(Self::ViewportZoomOut),
+            "viewport_pan_up" => Some(Self::ViewportPanUp),
+            "viewport_pan_down" => Some(Self::ViewportPanDown),
+            "viewport_pan_left" => Some(Self::ViewportPanLeft),
+            "viewport_pan_right" => Some(Self::ViewportPanRight),
             "freefly_forward" => Some(Self::FreeflyForward),
             "freefly_backward" => Some(Self::FreeflyBackward),
             "freefly_left" => Some(Self::FreeflyLeft),
@@ -492,14 +759,49 @@ impl InputAction {
 #[derive(Debug, Deserialize)]
 struct InputConfigFile {
     #[serde(default)]
-    bindings: HashMap<String, Vec<String>>,
+    bindings: HashMap<String, BindingConfig>,
+}
+
+/// A `bindings` entry: either a list of alternative keys for a built-in action, or
+/// `{ "chord": ["ctrl", "shift", "s"] }` registering a named chord.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum BindingConfig {
+    Keys(Vec<String>),
+    Chord { chord: Vec<String> },
 }
 
+type ParsedBindings = (HashMap<InputAction, Vec<InputKeyBinding>>, HashMap<String, Vec<InputKeyBinding>>);
+
 impl InputConfigFile {
-    fn into_overrides(self, origin: &str) -> HashMap<InputAction, Vec<InputKeyBinding>> {
+    fn into_overrides(self, origin: &str) -> ParsedBindings {
         let mut overrides = HashMap::new();
-        for (action_name, keys) in self.bindings {
+        let mut chords = HashMap::new();
+        for (action_name, binding) in self.bindings {
             let action_key = action_name.trim().to_lowercase();
+            let keys = match binding {
+                BindingConfig::Keys(keys) => keys,
+                BindingConfig::Chord { chord } => {
+                    if InputAction::from_str(&action_key).is_some() {
+                        eprintln!(
+                            "[input] {origin}: '{action_name}' is a built-in action and takes a key list, not a chord; ignoring."
+                        );
+                        continue;
+                    }
+                    let parsed: Result<Vec<_>, _> =
+                        chord.iter().map(|key| InputKeyBinding::from_config_value(key)).collect();
+                    match parsed {
+                        Ok(keys) if !keys.is_empty() => {
+                            chords.insert(action_key, keys);
+                        }
+                        Ok(_) => eprintln!("[input] {origin}: chord '{action_name}' has no keys, ignoring."),
+                        Err(_) => eprintln!(
+                            "[input] {origin}: chord '{action_name}' has an unknown key in {chord:?}, ignoring."
+                        ),
+                    }
+                    continue;
+                }
+            };
             match InputAction::from_str(&action_key) {
                 Some(action) => {
                     let mut parsed = Vec::new();
@@ -522,7 +824,7 @@ impl InputConfigFile {
                 None => eprintln!("[input] {origin}: unknown action '{action_name}', ignoring."),
             }
         }
-        overrides
+        (overrides, chords)
     }
 }
 
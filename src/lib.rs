@@ -1,5 +1,6 @@
 #[cfg(feature = "alloc_profiler")]
 pub mod alloc_profiler;
+pub mod alloc_sampler;
 pub mod analytics;
 pub mod animation_validation;
 pub mod assets;
@@ -13,9 +14,11 @@ pub mod environment;
 pub mod events;
 pub mod gpu_baseline;
 pub mod input;
+pub mod locale;
 pub mod material_registry;
 pub mod mesh;
 pub mod mesh_registry;
+pub mod nav;
 pub mod plugin_rpc;
 pub mod plugins;
 pub mod prefab;
@@ -23,6 +26,8 @@ pub mod renderer;
 pub mod runtime_host;
 pub mod scene;
 pub mod scene_capture;
+pub mod scene_screenshot;
+pub mod scene_streaming;
 pub mod script_harness;
 pub mod scripts;
 pub mod sprite_perf_guard;
@@ -0,0 +1,208 @@
+//! Translated UI strings. Each language is a flat JSON object in `assets/locale/{lang}.json`
+//! mapping dotted keys (`panel.stats`) to text, with `{name}` placeholders filled by [`tr!`].
+//! English is the fallback for keys a translation does not cover; each key that falls back is logged
+//! once, and [`Locale::write_untranslated_keys`] dumps everything a translation still lacks.
+
+use anyhow::{Context, Result};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt::Display;
+use std::fs;
+use std::path::Path;
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
+
+pub const LOCALE_DIR: &str = "assets/locale";
+pub const DEFAULT_LANGUAGE: &str = "en";
+/// Key every locale file uses for the language's own name, shown in the language picker.
+const NAME_KEY: &str = "locale.name";
+
+static ACTIVE: RwLock<Option<Arc<Locale>>> = RwLock::new(None);
+
+fn embedded_english() -> &'static Arc<HashMap<String, String>> {
+    static ENGLISH: OnceLock<Arc<HashMap<String, String>>> = OnceLock::new();
+    ENGLISH.get_or_init(|| {
+        let strings = serde_json::from_str(include_str!("../assets/locale/en.json"))
+            .expect("embedded English locale is valid JSON");
+        Arc::new(strings)
+    })
+}
+
+pub struct Locale {
+    language: String,
+    strings: HashMap<String, String>,
+    fallback: Arc<HashMap<String, String>>,
+    missing: Mutex<BTreeSet<String>>,
+}
+
+impl Locale {
+    /// The built-in English strings; needs no files on disk.
+    pub fn english() -> Self {
+        Self::from_strings(DEFAULT_LANGUAGE, embedded_english().as_ref().clone())
+    }
+
+    /// Strings for `language` backed by the built-in English fallback.
+    pub fn from_strings(language: impl Into<String>, strings: HashMap<String, String>) -> Self {
+        Self {
+            language: language.into(),
+            strings,
+            fallback: Arc::clone(embedded_english()),
+            missing: Mutex::new(BTreeSet::new()),
+        }
+    }
+
+    /// Reads `{dir}/{language}.json`. A missing English file falls back to the built-in strings so
+    /// the editor always has text to show.
+    pub fn load(dir: impl AsRef<Path>, language: &str) -> Result<Self> {
+        let path = dir.as_ref().join(format!("{language}.json"));
+        if language == DEFAULT_LANGUAGE && !path.exists() {
+            return Ok(Self::english());
+        }
+        let raw =
+            fs::read_to_string(&path).with_context(|| format!("read locale file '{}'", path.display()))?;
+        let strings: HashMap<String, String> =
+            serde_json::from_str(&raw).with_context(|| format!("parse locale file '{}'", path.display()))?;
+        Ok(Self::from_strings(language, strings))
+    }
+
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+
+    pub fn display_name(&self) -> &str {
+        self.strings.get(NAME_KEY).map(String::as_str).unwrap_or(&self.language)
+    }
+
+    /// Text for `key`, falling back to English and then to the key itself.
+    pub fn lookup<'a>(&'a self, key: &'a str) -> &'a str {
+        if let Some(text) = self.strings.get(key) {
+            return text;
+        }
+        self.note_missing(key);
+        self.fallback.get(key).map(String::as_str).unwrap_or(key)
+    }
+
+    /// Looks up `key` and replaces each `{name}` placeholder with the matching argument.
+    pub fn format(&self, key: &str, args: &[(&str, &dyn Display)]) -> String {
+        let mut text = self.lookup(key).to_string();
+        for (name, value) in args {
+            let placeholder = format!("{{{name}}}");
+            if text.contains(&placeholder) {
+                text = text.replace(&placeholder, &value.to_string());
+            }
+        }
+        text
+    }
+
+    /// Keys looked up so far that this locale had to fall back to English (or to the raw key) for.
+    pub fn missing_keys(&self) -> Vec<String> {
+        self.missing.lock().map(|missing| missing.iter().cloned().collect()).unwrap_or_default()
+    }
+
+    /// English keys this locale has no text for, plus any unknown keys looked up so far, mapped to
+    /// their English text (empty for unknown keys).
+    pub fn untranslated(&self) -> BTreeMap<String, String> {
+        let mut entries: BTreeMap<String, String> = self
+            .fallback
+            .iter()
+            .filter(|(key, _)| !self.strings.contains_key(*key))
+            .map(|(key, english)| (key.clone(), english.clone()))
+            .collect();
+        for key in self.missing_keys() {
+            entries.entry(key).or_default();
+        }
+        entries
+    }
+
+    /// Writes [`Self::untranslated`] as a JSON object translators can fill in and returns how many
+    /// keys were written.
+    pub fn write_untranslated_keys(&self, path: impl AsRef<Path>) -> Result<usize> {
+        let path = path.as_ref();
+        let entries = self.untranslated();
+        if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            fs::create_dir_all(parent).with_context(|| format!("create '{}'", parent.display()))?;
+        }
+        let json = serde_json::to_string_pretty(&entries).context("serialize untranslated locale keys")?;
+        fs::write(path, json)
+            .with_context(|| format!("write untranslated locale keys '{}'", path.display()))?;
+        Ok(entries.len())
+    }
+
+    fn note_missing(&self, key: &str) {
+        let Ok(mut missing) = self.missing.lock() else {
+            return;
+        };
+        if missing.contains(key) {
+            return;
+        }
+        missing.insert(key.to_string());
+        if self.fallback.contains_key(key) {
+            if self.language != DEFAULT_LANGUAGE {
+                eprintln!("[locale] '{key}' has no '{}' translation; using English", self.language);
+            }
+        } else {
+            eprintln!("[locale] unknown string key '{key}'");
+        }
+    }
+}
+
+/// `(code, display name)` of every locale file in `dir`, sorted by code.
+pub fn available_languages(dir: impl AsRef<Path>) -> Vec<(String, String)> {
+    let mut languages = Vec::new();
+    if let Ok(entries) = fs::read_dir(dir.as_ref()) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                continue;
+            }
+            let Some(code) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+            let name = Locale::load(dir.as_ref(), code)
+                .map(|locale| locale.display_name().to_string())
+                .unwrap_or_else(|_| code.to_string());
+            languages.push((code.to_string(), name));
+        }
+    }
+    if !languages.iter().any(|(code, _)| code == DEFAULT_LANGUAGE) {
+        languages.push((DEFAULT_LANGUAGE.to_string(), Locale::english().display_name().to_string()));
+    }
+    languages.sort_by(|a, b| a.0.cmp(&b.0));
+    languages
+}
+
+/// Makes `locale` the one [`tr!`] reads from. Takes effect on the next lookup.
+pub fn set_active(locale: Locale) {
+    if let Ok(mut active) = ACTIVE.write() {
+        *active = Some(Arc::new(locale));
+    }
+}
+
+/// The active locale, English until [`set_active`] is called.
+pub fn active() -> Arc<Locale> {
+    if let Some(locale) = ACTIVE.read().ok().and_then(|active| active.clone()) {
+        return locale;
+    }
+    let Ok(mut active) = ACTIVE.write() else {
+        return Arc::new(Locale::english());
+    };
+    Arc::clone(active.get_or_insert_with(|| Arc::new(Locale::english())))
+}
+
+pub fn tr(key: &str) -> String {
+    active().lookup(key).to_string()
+}
+
+pub fn tr_args(key: &str, args: &[(&str, &dyn Display)]) -> String {
+    active().format(key, args)
+}
+
+/// Translates a string key through the active locale: `tr!("panel.stats")` or
+/// `tr!("status.saved_scene", path = scene_path)`.
+#[macro_export]
+macro_rules! tr {
+    ($key:expr $(,)?) => {
+        $crate::locale::tr($key)
+    };
+    ($key:expr, $($name:ident = $value:expr),+ $(,)?) => {
+        $crate::locale::tr_args($key, &[$((stringify!($name), &$value as &dyn ::std::fmt::Display)),+])
+    };
+}
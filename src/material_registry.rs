@@ -1,8 +1,11 @@
+use crate::assets::ktx2::{self, Ktx2Texture};
 use crate::mesh::{ImportedMaterial, ImportedTexture, MaterialTextureBinding};
-use crate::renderer::Renderer;
-use anyhow::{anyhow, Result};
+use crate::renderer::{estimate_texture_bytes, Renderer};
+use anyhow::{anyhow, Context, Result};
 use bytemuck::{Pod, Zeroable};
+use glam::{Vec3, Vec4};
 use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::Arc;
 use wgpu::util::DeviceExt;
 
@@ -11,10 +14,62 @@ use wgpu::util::DeviceExt;
 struct MaterialUniform {
     base_color_factor: [f32; 4],
     emissive_factor: [f32; 4],
-    params: [f32; 4],        // metallic, roughness, normal_scale, padding
+    params: [f32; 4],        // metallic, roughness, normal_scale, use_vertex_colors
     texture_flags: [f32; 4], // base_color, metallic_roughness, normal, emissive
 }
 
+/// Which triangle faces a material hides. Meshes with inverted winding render with `Front`, and
+/// double-sided surfaces such as foliage or cloth with `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MaterialCullMode {
+    #[default]
+    Back,
+    Front,
+    None,
+}
+
+impl MaterialCullMode {
+    pub const ALL: [MaterialCullMode; 3] = [Self::Back, Self::Front, Self::None];
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "back" => Some(Self::Back),
+            "front" => Some(Self::Front),
+            "none" => Some(Self::None),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Back => "back",
+            Self::Front => "front",
+            Self::None => "none",
+        }
+    }
+
+    pub fn face(self) -> Option<wgpu::Face> {
+        match self {
+            Self::Back => Some(wgpu::Face::Back),
+            Self::Front => Some(wgpu::Face::Front),
+            Self::None => None,
+        }
+    }
+}
+
+/// Fixed-function state that selects one of the mesh pipeline variants the renderer builds up
+/// front, so switching a material's state never recompiles shaders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct MeshPipelineKey {
+    pub cull_mode: MaterialCullMode,
+}
+
+impl MeshPipelineKey {
+    pub fn all() -> impl Iterator<Item = MeshPipelineKey> {
+        MaterialCullMode::ALL.into_iter().map(|cull_mode| MeshPipelineKey { cull_mode })
+    }
+}
+
 #[derive(Clone)]
 pub struct MaterialDefinition {
     pub key: String,
@@ -27,9 +82,47 @@ pub struct MaterialDefinition {
     pub metallic_roughness_texture: Option<MaterialTextureBinding>,
     pub normal_texture: Option<MaterialTextureBinding>,
     pub emissive_texture: Option<MaterialTextureBinding>,
+    /// Multiplies the mesh's vertex colors into the base color. Meshes without colors read as white.
+    pub use_vertex_colors: bool,
+    pub cull_mode: MaterialCullMode,
     pub source: Option<String>,
 }
 
+impl MaterialDefinition {
+    pub fn pipeline_key(&self) -> MeshPipelineKey {
+        MeshPipelineKey { cull_mode: self.cull_mode }
+    }
+}
+
+/// Factors for a material built in code rather than loaded from a file; see
+/// [`MaterialRegistry::create_material_from_params`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaterialParams {
+    pub base_color: Vec4,
+    pub metallic: f32,
+    pub roughness: f32,
+    pub emissive: Vec3,
+    /// Image decoded as the sRGB base color texture. KTX2 files (or a fresh `.ktx2` sidecar next
+    /// to the image) are uploaded block-compressed when the adapter supports it.
+    pub albedo_texture: Option<PathBuf>,
+    pub use_vertex_colors: bool,
+    pub cull_mode: MaterialCullMode,
+}
+
+impl Default for MaterialParams {
+    fn default() -> Self {
+        Self {
+            base_color: Vec4::ONE,
+            metallic: 0.0,
+            roughness: 1.0,
+            emissive: Vec3::ZERO,
+            albedo_texture: None,
+            use_vertex_colors: true,
+            cull_mode: MaterialCullMode::Back,
+        }
+    }
+}
+
 pub struct MaterialRegistry {
     materials: HashMap<String, MaterialEntry>,
     textures: HashMap<String, TextureEntry>,
@@ -37,6 +130,7 @@ pub struct MaterialRegistry {
     default_material: String,
     default_textures: Option<DefaultTextures>,
     sampler: Option<Arc<wgpu::Sampler>>,
+    anisotropy: u16,
     texture_upload_scratch: Vec<u8>,
 }
 
@@ -54,6 +148,8 @@ struct TextureEntry {
     width: u32,
     height: u32,
     data: Vec<u8>,
+    /// Block-compressed levels uploaded instead of `data` when present.
+    compressed: Option<Arc<Ktx2Texture>>,
     gpu_srgb: Option<Arc<GpuTexture>>,
     gpu_linear: Option<Arc<GpuTexture>>,
 }
@@ -74,6 +170,7 @@ pub struct MaterialGpu {
     metallic_roughness: Arc<GpuTexture>,
     normal: Arc<GpuTexture>,
     emissive: Arc<GpuTexture>,
+    pipeline_key: MeshPipelineKey,
 }
 
 #[allow(dead_code)]
@@ -93,6 +190,7 @@ impl MaterialRegistry {
             default_material: default_material.clone(),
             default_textures: None,
             sampler: None,
+            anisotropy: 1,
             texture_upload_scratch: Vec::new(),
         };
         let default_definition = MaterialDefinition {
@@ -106,6 +204,8 @@ impl MaterialRegistry {
             metallic_roughness_texture: None,
             normal_texture: None,
             emissive_texture: None,
+            use_vertex_colors: true,
+            cull_mode: MaterialCullMode::Back,
             source: None,
         };
         registry.materials.insert(
@@ -141,6 +241,7 @@ impl MaterialRegistry {
                     entry.width = texture.width;
                     entry.height = texture.height;
                     entry.data = texture.data.clone();
+                    entry.compressed = None;
                     entry.gpu_srgb = None;
                     entry.gpu_linear = None;
                 })
@@ -148,6 +249,7 @@ impl MaterialRegistry {
                     width: texture.width,
                     height: texture.height,
                     data: texture.data.clone(),
+                    compressed: None,
                     gpu_srgb: None,
                     gpu_linear: None,
                 });
@@ -170,6 +272,12 @@ impl MaterialRegistry {
                 metallic_roughness_texture: material.metallic_roughness_texture.clone(),
                 normal_texture: material.normal_texture.clone(),
                 emissive_texture: material.emissive_texture.clone(),
+                use_vertex_colors: true,
+                cull_mode: if material.double_sided {
+                    MaterialCullMode::None
+                } else {
+                    MaterialCullMode::Back
+                },
                 source: material.source.clone(),
             };
             if let Some(mut entry) = self.materials.remove(&material.key) {
@@ -189,6 +297,76 @@ impl MaterialRegistry {
         snapshot
     }
 
+    /// Registers a material with no backing file, already retained once; the matching
+    /// [`MaterialRegistry::release`] removes it along with its albedo texture.
+    pub fn create_material_from_params(&mut self, key: &str, params: MaterialParams) -> Result<()> {
+        if key.trim().is_empty() {
+            return Err(anyhow!("Material key cannot be empty"));
+        }
+        if self.materials.contains_key(key) {
+            return Err(anyhow!("Material '{key}' is already registered"));
+        }
+        let finite = params.base_color.is_finite()
+            && params.metallic.is_finite()
+            && params.roughness.is_finite()
+            && params.emissive.is_finite();
+        if !finite {
+            return Err(anyhow!("Material '{key}' has non-finite parameters"));
+        }
+        let base_color_texture = match params.albedo_texture.as_ref() {
+            Some(path) => {
+                let source = ktx2::preferred_texture_path(path);
+                let entry = if ktx2::is_ktx2_path(&source) {
+                    let compressed = Ktx2Texture::load(&source)
+                        .with_context(|| format!("Failed to load albedo texture '{}'", path.display()))?;
+                    TextureEntry {
+                        width: compressed.width,
+                        height: compressed.height,
+                        data: Vec::new(),
+                        compressed: Some(Arc::new(compressed)),
+                        gpu_srgb: None,
+                        gpu_linear: None,
+                    }
+                } else {
+                    let image = image::open(path)
+                        .with_context(|| format!("Failed to load albedo texture '{}'", path.display()))?
+                        .to_rgba8();
+                    TextureEntry {
+                        width: image.width(),
+                        height: image.height(),
+                        data: image.into_raw(),
+                        compressed: None,
+                        gpu_srgb: None,
+                        gpu_linear: None,
+                    }
+                };
+                let texture_key = format!("{key}::albedo");
+                self.textures.insert(texture_key.clone(), entry);
+                Some(MaterialTextureBinding { texture_key, tex_coord: 0, srgb: true, scale: 1.0 })
+            }
+            None => None,
+        };
+        let definition = MaterialDefinition {
+            key: key.to_string(),
+            label: key.to_string(),
+            base_color_factor: params.base_color.to_array(),
+            metallic_factor: params.metallic.clamp(0.0, 1.0),
+            roughness_factor: params.roughness.clamp(0.0, 1.0),
+            emissive_factor: params.emissive.to_array(),
+            base_color_texture,
+            metallic_roughness_texture: None,
+            normal_texture: None,
+            emissive_texture: None,
+            use_vertex_colors: params.use_vertex_colors,
+            cull_mode: params.cull_mode,
+            source: None,
+        };
+        self.bump_texture_refs(&definition, 1);
+        self.materials
+            .insert(key.to_string(), MaterialEntry { definition, gpu: None, ref_count: 1, permanent: false });
+        Ok(())
+    }
+
     pub fn default_key(&self) -> &str {
         &self.default_material
     }
@@ -235,6 +413,64 @@ impl MaterialRegistry {
         self.materials.get(key).map(|entry| entry.ref_count)
     }
 
+    /// Estimated bytes held by uploaded material textures and uniform buffers.
+    pub fn gpu_memory_bytes(&self) -> u64 {
+        let textures: u64 = self
+            .textures
+            .values()
+            .flat_map(|entry| entry.gpu_srgb.iter().chain(entry.gpu_linear.iter()))
+            .map(|texture| texture.memory_bytes())
+            .sum();
+        let defaults = self.default_textures.as_ref().map_or(0, |defaults| {
+            [&defaults.base_color, &defaults.metallic_roughness, &defaults.normal, &defaults.emissive]
+                .iter()
+                .map(|texture| texture.memory_bytes())
+                .sum()
+        });
+        let uniforms: u64 = self
+            .materials
+            .values()
+            .filter_map(|entry| entry.gpu.as_ref())
+            .map(|gpu| gpu.uniform_buffer.size())
+            .sum();
+        textures + defaults + uniforms
+    }
+
+    /// The part of [`MaterialRegistry::gpu_memory_bytes`] held in block-compressed textures.
+    pub fn compressed_gpu_memory_bytes(&self) -> u64 {
+        self.textures
+            .values()
+            .flat_map(|entry| entry.gpu_srgb.iter().chain(entry.gpu_linear.iter()))
+            .filter(|texture| texture.texture.format().is_compressed())
+            .map(|texture| texture.memory_bytes())
+            .sum()
+    }
+
+    /// Toggles vertex color modulation for `key`; the GPU uniform is rebuilt on next use.
+    pub fn set_use_vertex_colors(&mut self, key: &str, enabled: bool) -> Result<()> {
+        let entry = self.materials.get_mut(key).ok_or_else(|| anyhow!("Material '{key}' not registered"))?;
+        if entry.definition.use_vertex_colors != enabled {
+            entry.definition.use_vertex_colors = enabled;
+            entry.gpu = None;
+        }
+        Ok(())
+    }
+
+    /// Sets which faces `key` culls; draws switch pipeline variant once the material is re-prepared.
+    pub fn set_cull_mode(&mut self, key: &str, cull_mode: MaterialCullMode) -> Result<()> {
+        let entry = self.materials.get_mut(key).ok_or_else(|| anyhow!("Material '{key}' not registered"))?;
+        if entry.definition.cull_mode != cull_mode {
+            entry.definition.cull_mode = cull_mode;
+            entry.gpu = None;
+        }
+        Ok(())
+    }
+
+    /// Mesh pipeline variant that draws with `key`.
+    pub fn pipeline_key(&self, key: &str) -> Option<MeshPipelineKey> {
+        self.definition(key).map(MaterialDefinition::pipeline_key)
+    }
+
     pub fn definition(&self, key: &str) -> Option<&MaterialDefinition> {
         self.materials.get(key).map(|entry| &entry.definition)
     }
@@ -297,7 +533,12 @@ impl MaterialRegistry {
                 definition.emissive_factor[2],
                 1.0,
             ],
-            params: [definition.metallic_factor, definition.roughness_factor, normal_scale, 0.0],
+            params: [
+                definition.metallic_factor,
+                definition.roughness_factor,
+                normal_scale,
+                definition.use_vertex_colors as u32 as f32,
+            ],
             texture_flags: [
                 definition.base_color_texture.is_some() as u32 as f32,
                 definition.metallic_roughness_texture.is_some() as u32 as f32,
@@ -347,6 +588,7 @@ impl MaterialRegistry {
             metallic_roughness: metallic_roughness_texture,
             normal: normal_texture,
             emissive: emissive_texture,
+            pipeline_key: definition.pipeline_key(),
         });
         if let Some(entry) = self.materials.get_mut(key) {
             entry.gpu = Some(gpu.clone());
@@ -354,6 +596,34 @@ impl MaterialRegistry {
         Ok(gpu)
     }
 
+    /// Sets the material sampler's max anisotropy (already clamped by the caller). Cached
+    /// material bind groups are dropped so they rebuild against the new sampler.
+    pub fn set_anisotropy(&mut self, anisotropy: u16) {
+        let anisotropy = anisotropy.max(1);
+        if anisotropy == self.anisotropy {
+            return;
+        }
+        self.anisotropy = anisotropy;
+        self.sampler = None;
+        for entry in self.materials.values_mut() {
+            entry.gpu = None;
+        }
+    }
+
+    /// Drops every GPU texture, sampler and bind group, e.g. after the device was lost.
+    /// `prepare_material_gpu` rebuilds them from the retained texture data.
+    pub fn release_gpu(&mut self) {
+        self.sampler = None;
+        self.default_textures = None;
+        for entry in self.materials.values_mut() {
+            entry.gpu = None;
+        }
+        for texture in self.textures.values_mut() {
+            texture.gpu_srgb = None;
+            texture.gpu_linear = None;
+        }
+    }
+
     fn ensure_sampler(&mut self, device: &wgpu::Device) -> Arc<wgpu::Sampler> {
         if let Some(sampler) = &self.sampler {
             return sampler.clone();
@@ -366,6 +636,7 @@ impl MaterialRegistry {
             mag_filter: wgpu::FilterMode::Linear,
             min_filter: wgpu::FilterMode::Linear,
             mipmap_filter: wgpu::FilterMode::Linear,
+            anisotropy_clamp: self.anisotropy,
             ..Default::default()
         });
         let sampler = Arc::new(sampler);
@@ -446,6 +717,13 @@ impl MaterialRegistry {
         if let Some(texture) = cache {
             return Ok(texture.clone());
         }
+        if let Some(compressed) = entry.compressed.as_ref() {
+            let texture = ktx2::create_texture(device, queue, compressed, srgb, "Material Texture")?;
+            let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+            let gpu_texture = Arc::new(GpuTexture::new(texture, view, srgb));
+            *cache = Some(gpu_texture.clone());
+            return Ok(gpu_texture);
+        }
 
         let data_owned = std::mem::take(&mut entry.data);
         let width = entry.width;
@@ -620,6 +898,10 @@ impl MaterialGpu {
     pub fn bind_group(&self) -> &wgpu::BindGroup {
         self.bind_group.as_ref()
     }
+
+    pub fn pipeline_key(&self) -> MeshPipelineKey {
+        self.pipeline_key
+    }
 }
 
 impl GpuTexture {
@@ -630,4 +912,131 @@ impl GpuTexture {
     fn view(&self) -> &wgpu::TextureView {
         self.view.as_ref()
     }
+
+    fn memory_bytes(&self) -> u64 {
+        estimate_texture_bytes(&self.texture)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn programmatic_material_is_retained_once_and_removed_on_release() {
+        let mut registry = MaterialRegistry::new();
+        let params = MaterialParams {
+            base_color: Vec4::new(0.8, 0.2, 0.1, 1.0),
+            roughness: 0.35,
+            emissive: Vec3::new(0.0, 0.1, 0.0),
+            ..MaterialParams::default()
+        };
+        registry.create_material_from_params("script::ember", params.clone()).expect("create material");
+        assert_eq!(registry.ref_count("script::ember"), Some(1));
+        let definition = registry.definition("script::ember").expect("definition");
+        assert_eq!(definition.base_color_factor, [0.8, 0.2, 0.1, 1.0]);
+        assert_eq!(definition.roughness_factor, 0.35);
+        assert!(definition.source.is_none());
+        assert!(registry.create_material_from_params("script::ember", params).is_err(), "keys are unique");
+
+        registry.release("script::ember");
+        assert!(!registry.has("script::ember"));
+    }
+
+    #[test]
+    fn vertex_colors_toggle_per_material() {
+        let mut registry = MaterialRegistry::new();
+        let default_key = registry.default_key().to_string();
+        assert!(registry.definition(&default_key).unwrap().use_vertex_colors);
+        let params = MaterialParams { use_vertex_colors: false, ..MaterialParams::default() };
+        registry.create_material_from_params("script::flat", params).expect("create material");
+        assert!(!registry.definition("script::flat").unwrap().use_vertex_colors);
+        registry.set_use_vertex_colors("script::flat", true).expect("toggle");
+        assert!(registry.definition("script::flat").unwrap().use_vertex_colors);
+        assert!(registry.set_use_vertex_colors("script::missing", true).is_err());
+    }
+
+    #[test]
+    fn cull_mode_none_selects_a_different_pipeline_variant() {
+        let mut registry = MaterialRegistry::new();
+        let default_key = registry.default_key().to_string();
+        let default_pipeline = registry.pipeline_key(&default_key).expect("default material");
+        assert_eq!(default_pipeline.cull_mode, MaterialCullMode::Back);
+
+        let params = MaterialParams { cull_mode: MaterialCullMode::None, ..MaterialParams::default() };
+        registry.create_material_from_params("script::leaf", params).expect("create material");
+        let leaf_pipeline = registry.pipeline_key("script::leaf").expect("leaf material");
+        assert_eq!(leaf_pipeline.cull_mode, MaterialCullMode::None);
+        assert_ne!(leaf_pipeline, default_pipeline);
+        assert_eq!(MeshPipelineKey::all().filter(|key| *key == leaf_pipeline).count(), 1);
+
+        registry.set_cull_mode("script::leaf", MaterialCullMode::Back).expect("set cull mode");
+        assert_eq!(registry.pipeline_key("script::leaf"), Some(default_pipeline));
+        assert!(registry.set_cull_mode("script::missing", MaterialCullMode::Front).is_err());
+        assert_eq!(MaterialCullMode::parse(" None "), Some(MaterialCullMode::None));
+        assert_eq!(MaterialCullMode::parse("sideways"), None);
+    }
+
+    #[test]
+    fn double_sided_gltf_materials_import_without_culling() {
+        let imported = |key: &str, double_sided: bool| ImportedMaterial {
+            key: key.to_string(),
+            label: key.to_string(),
+            base_color_factor: [1.0; 4],
+            metallic_factor: 0.0,
+            roughness_factor: 1.0,
+            emissive_factor: [0.0; 3],
+            base_color_texture: None,
+            metallic_roughness_texture: None,
+            normal_texture: None,
+            emissive_texture: None,
+            double_sided,
+            source: None,
+        };
+        let mut registry = MaterialRegistry::new();
+        registry.register_gltf_import(
+            &[imported("tree.gltf::leaf", true), imported("tree.gltf::bark", false)],
+            &[],
+        );
+        assert_eq!(registry.definition("tree.gltf::leaf").unwrap().cull_mode, MaterialCullMode::None);
+        assert_eq!(registry.definition("tree.gltf::bark").unwrap().cull_mode, MaterialCullMode::Back);
+    }
+
+    #[test]
+    fn albedo_texture_is_loaded_and_dropped_with_the_material() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = dir.path().join("albedo.png");
+        image::RgbaImage::from_pixel(2, 2, image::Rgba([255, 0, 0, 255])).save(&path).expect("write png");
+        let mut registry = MaterialRegistry::new();
+        let params = MaterialParams { albedo_texture: Some(path), ..MaterialParams::default() };
+        registry.create_material_from_params("script::red", params).expect("create material");
+        let binding = registry.definition("script::red").unwrap().base_color_texture.clone().expect("albedo");
+        assert!(registry.textures.contains_key(&binding.texture_key));
+
+        registry.release("script::red");
+        assert!(!registry.textures.contains_key(&binding.texture_key));
+
+        let missing =
+            MaterialParams { albedo_texture: Some(dir.path().join("missing.png")), ..Default::default() };
+        assert!(registry.create_material_from_params("script::missing", missing).is_err());
+        assert!(!registry.has("script::missing"));
+    }
+
+    #[test]
+    fn fresh_ktx2_sidecar_is_kept_compressed_instead_of_decoding_the_png() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = dir.path().join("albedo.png");
+        image::RgbaImage::from_pixel(8, 8, image::Rgba([0, 255, 0, 255])).save(&path).expect("write png");
+        ktx2::write_bc7_sidecar(&path).expect("write sidecar");
+        let mut registry = MaterialRegistry::new();
+        let params = MaterialParams { albedo_texture: Some(path), ..MaterialParams::default() };
+        registry.create_material_from_params("script::green", params).expect("create material");
+        let binding =
+            registry.definition("script::green").unwrap().base_color_texture.clone().expect("albedo");
+        let entry = &registry.textures[&binding.texture_key];
+        let compressed = entry.compressed.as_ref().expect("sidecar loaded");
+        assert_eq!(compressed.format, wgpu::TextureFormat::Bc7RgbaUnormSrgb);
+        assert_eq!((entry.width, entry.height), (8, 8));
+        assert!(entry.data.is_empty(), "the PNG is not decoded");
+    }
 }
@@ -1,8 +1,10 @@
 use anyhow::{anyhow, bail, Context, Result};
-use glam::{Mat3, Mat4, Vec2, Vec3, Vec4};
+use glam::{Mat3, Mat4, Quat, Vec2, Vec3, Vec4};
 use gltf::mesh::Mode;
+use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
-use std::path::Path;
+use std::fs;
+use std::path::{Path, PathBuf};
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, bytemuck::Pod, bytemuck::Zeroable)]
@@ -13,6 +15,7 @@ pub struct MeshVertex {
     pub uv: [f32; 2],
     pub joints: [u16; 4],
     pub weights: [f32; 4],
+    pub color: [f32; 4],
 }
 
 impl MeshVertex {
@@ -24,6 +27,7 @@ impl MeshVertex {
             uv: uv.to_array(),
             joints: [0; 4],
             weights: [0.0; 4],
+            color: [1.0; 4],
         }
     }
 
@@ -33,6 +37,11 @@ impl MeshVertex {
         self
     }
 
+    pub fn with_color(mut self, color: Vec4) -> Self {
+        self.color = color.to_array();
+        self
+    }
+
     pub fn layout<'a>() -> wgpu::VertexBufferLayout<'a> {
         use std::mem;
         wgpu::VertexBufferLayout {
@@ -69,6 +78,11 @@ impl MeshVertex {
                     shader_location: 5,
                     format: wgpu::VertexFormat::Float32x4,
                 },
+                wgpu::VertexAttribute {
+                    offset: 72,
+                    shader_location: 6,
+                    format: wgpu::VertexFormat::Float32x4,
+                },
             ],
         }
     }
@@ -126,6 +140,8 @@ pub struct ImportedMaterial {
     pub metallic_roughness_texture: Option<MaterialTextureBinding>,
     pub normal_texture: Option<MaterialTextureBinding>,
     pub emissive_texture: Option<MaterialTextureBinding>,
+    /// glTF `doubleSided`: both faces render, so the material imports without culling.
+    pub double_sided: bool,
     pub source: Option<String>,
 }
 
@@ -136,13 +152,161 @@ pub struct MeshImport {
     pub textures: Vec<ImportedTexture>,
 }
 
+/// Up axis a glTF file was authored with; Z-up files are rotated into the engine's Y-up space.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MeshAxisConvention {
+    #[default]
+    YUp,
+    ZUp,
+}
+
+impl MeshAxisConvention {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::YUp => "Y up",
+            Self::ZUp => "Z up",
+        }
+    }
+
+    /// Rotation taking this convention's up axis onto +Y.
+    pub fn to_y_up(self) -> Quat {
+        match self {
+            Self::YUp => Quat::IDENTITY,
+            Self::ZUp => Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2),
+        }
+    }
+}
+
+/// Per-file glTF import options, stored next to the source as `<file>.import.json` and re-read on
+/// every import, re-import and hot reload of that file.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MeshImportSettings {
+    /// Uniform scale applied to vertex positions and node translations.
+    pub scale: f32,
+    pub axis: MeshAxisConvention,
+    /// Scene-graph imports register one mesh per node (`file#node`) instead of one per primitive;
+    /// a merged node draws with the material of its first primitive.
+    pub merge_primitives: bool,
+}
+
+impl Default for MeshImportSettings {
+    fn default() -> Self {
+        Self { scale: 1.0, axis: MeshAxisConvention::YUp, merge_primitives: false }
+    }
+}
+
+impl MeshImportSettings {
+    pub fn sidecar_path(source: &Path) -> PathBuf {
+        let mut name = source.file_name().map(|name| name.to_os_string()).unwrap_or_default();
+        name.push(".import.json");
+        source.with_file_name(name)
+    }
+
+    /// Settings recorded for `source`, or the defaults when it has no readable sidecar.
+    pub fn load_for(source: &Path) -> Self {
+        let sidecar = Self::sidecar_path(source);
+        let Ok(bytes) = fs::read(&sidecar) else {
+            return Self::default();
+        };
+        match serde_json::from_slice::<Self>(&bytes) {
+            Ok(settings) => settings,
+            Err(err) => {
+                eprintln!("[mesh] ignoring unreadable import settings {}: {err}", sidecar.display());
+                Self::default()
+            }
+        }
+    }
+
+    pub fn save_for(&self, source: &Path) -> Result<()> {
+        let sidecar = Self::sidecar_path(source);
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(&sidecar, json).with_context(|| format!("write import settings {}", sidecar.display()))
+    }
+
+    fn root_transform(&self) -> Mat4 {
+        Mat4::from_scale_rotation_translation(Vec3::splat(self.scale), self.axis.to_y_up(), Vec3::ZERO)
+    }
+}
+
+/// Registry key of a scene-graph mesh part: `file#node/primitive`, or `file#node` when merged.
+pub fn gltf_part_key(source: &Path, node: &str, primitive: Option<usize>) -> String {
+    match primitive {
+        Some(primitive) => format!("{}#{node}/{primitive}", source.display()),
+        None => format!("{}#{node}", source.display()),
+    }
+}
+
+/// Source file of a scene-graph part key produced by [`gltf_part_key`].
+pub fn gltf_part_source(key: &str) -> Option<&str> {
+    key.split_once('#').map(|(source, _)| source)
+}
+
+/// Mesh part of a glTF node, referenced by its registry key.
+#[derive(Clone, Debug)]
+pub struct ImportedNodeMesh {
+    pub key: String,
+    pub material: Option<String>,
+}
+
+/// glTF node of a scene-graph import with its transform relative to `parent`. Parents always come
+/// before their children.
+#[derive(Clone, Debug)]
+pub struct ImportedNode {
+    pub name: String,
+    pub parent: Option<usize>,
+    pub translation: Vec3,
+    pub rotation: Quat,
+    pub scale: Vec3,
+    pub meshes: Vec<ImportedNodeMesh>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ImportedMeshPart {
+    pub key: String,
+    pub mesh: Mesh,
+}
+
+#[derive(Clone, Debug)]
+pub struct GltfSceneImport {
+    pub settings: MeshImportSettings,
+    pub nodes: Vec<ImportedNode>,
+    pub parts: Vec<ImportedMeshPart>,
+    pub materials: Vec<ImportedMaterial>,
+    pub textures: Vec<ImportedTexture>,
+}
+
+struct GltfMaterialSet {
+    materials: Vec<ImportedMaterial>,
+    textures: Vec<ImportedTexture>,
+    key_map: HashMap<usize, String>,
+    default_key: String,
+}
+
+/// Walks a glTF node forest, splitting node meshes into registry parts.
+struct GltfSceneBuilder<'a> {
+    path: &'a Path,
+    settings: &'a MeshImportSettings,
+    buffers: &'a [gltf::buffer::Data],
+    material_key_map: &'a HashMap<usize, String>,
+    default_material_key: &'a str,
+    visited: HashSet<usize>,
+    names: HashSet<String>,
+    nodes: Vec<ImportedNode>,
+    parts: Vec<ImportedMeshPart>,
+    scratch: MeshScratch,
+}
+
 #[derive(Default)]
 struct MeshScratch {
     positions: Vec<Vec3>,
     normals: Vec<Vec3>,
+    tangents: Vec<Vec4>,
     tex_coords: Vec<Vec2>,
     joints: Vec<[u16; 4]>,
     weights: Vec<[f32; 4]>,
+    colors: Vec<Vec4>,
     local_indices: Vec<u32>,
 }
 
@@ -201,18 +365,205 @@ impl Mesh {
             indices.extend_from_slice(&[base, base + 1, base + 2, base, base + 2, base + 3]);
         }
 
-        compute_tangents(&mut vertices, &indices);
-
-        Self::new(vertices, indices)
+        let mut cube = Self::new(vertices, indices);
+        recompute_tangents(&mut cube);
+        cube
     }
 
     pub fn load_gltf_with_materials(path: impl AsRef<Path>) -> Result<MeshImport> {
         let path_ref = path.as_ref();
+        let settings = MeshImportSettings::load_for(path_ref);
         let (document, buffers, images) = gltf::import(path_ref)
             .with_context(|| format!("Failed to import glTF from {}", path_ref.display()))?;
         if document.meshes().next().is_none() {
             return Err(anyhow!("No meshes found in {}", path_ref.display()));
         }
+        let GltfMaterialSet {
+            materials,
+            textures,
+            key_map: material_key_map,
+            default_key: default_material_key,
+        } = Self::import_materials(&document, &images, path_ref)?;
+        let root_transform = settings.root_transform();
+
+        let mut vertices: Vec<MeshVertex> = Vec::new();
+        let mut indices: Vec<u32> = Vec::new();
+        let mut subsets: Vec<MeshSubset> = Vec::new();
+        let mut scratch = MeshScratch::default();
+
+        // Pre-size the buffers based on primitive counts to reduce reallocations.
+        let mut estimated_vertices = 0usize;
+        let mut estimated_indices = 0usize;
+        let mut estimated_subsets = 0usize;
+        for mesh in document.meshes() {
+            for primitive in mesh.primitives() {
+                if primitive.mode() != Mode::Triangles {
+                    continue;
+                }
+                estimated_subsets += 1;
+                if let Some(accessor) = primitive.get(&gltf::Semantic::Positions) {
+                    estimated_vertices += accessor.count();
+                }
+                if let Some(accessor) = primitive.indices() {
+                    estimated_indices += accessor.count();
+                }
+            }
+        }
+        vertices.reserve(estimated_vertices);
+        indices.reserve(estimated_indices);
+        subsets.reserve(estimated_subsets);
+        let mut processed_nodes = false;
+
+        if let Some(scene) = document.default_scene() {
+            for node in scene.nodes() {
+                processed_nodes |= Self::traverse_node_primitives(
+                    &node,
+                    root_transform,
+                    &buffers,
+                    &material_key_map,
+                    &default_material_key,
+                    path_ref,
+                    &mut vertices,
+                    &mut indices,
+                    &mut subsets,
+                    &mut scratch,
+                )?;
+            }
+        } else if let Some(scene) = document.scenes().next() {
+            for node in scene.nodes() {
+                processed_nodes |= Self::traverse_node_primitives(
+                    &node,
+                    root_transform,
+                    &buffers,
+                    &material_key_map,
+                    &default_material_key,
+                    path_ref,
+                    &mut vertices,
+                    &mut indices,
+                    &mut subsets,
+                    &mut scratch,
+                )?;
+            }
+        }
+
+        if !processed_nodes {
+            let roots = Self::root_nodes(&document);
+            for node in roots {
+                processed_nodes |= Self::traverse_node_primitives(
+                    &node,
+                    root_transform,
+                    &buffers,
+                    &material_key_map,
+                    &default_material_key,
+                    path_ref,
+                    &mut vertices,
+                    &mut indices,
+                    &mut subsets,
+                    &mut scratch,
+                )?;
+            }
+        }
+
+        if !processed_nodes {
+            for mesh in document.meshes() {
+                Self::append_mesh_primitives(
+                    &mesh,
+                    mesh.name(),
+                    root_transform,
+                    Self::normal_matrix_from_transform(root_transform),
+                    &buffers,
+                    &material_key_map,
+                    &default_material_key,
+                    path_ref,
+                    &mut vertices,
+                    &mut indices,
+                    &mut subsets,
+                    &mut scratch,
+                )?;
+            }
+        }
+
+        if subsets.is_empty() {
+            return Err(anyhow!("Mesh in {} contains no triangle primitives", path_ref.display()));
+        }
+
+        let bounds = MeshBounds::from_vertices(&vertices);
+
+        let mut mesh = Mesh { vertices, indices, subsets, bounds };
+        fill_missing_normals_and_tangents(&mut mesh);
+
+        Ok(MeshImport { mesh, materials, textures })
+    }
+
+    /// Imports the node hierarchy of a glTF file instead of flattening it. Every node keeps its
+    /// own transform and every triangle primitive becomes a mesh part keyed by [`gltf_part_key`]
+    /// with vertices in node-local space. The file's [`MeshImportSettings`] scale all vertices and
+    /// translations, rotate the root nodes into Y-up and decide whether primitives are merged.
+    pub fn load_gltf_scene(path: impl AsRef<Path>) -> Result<GltfSceneImport> {
+        let path_ref = path.as_ref();
+        let settings = MeshImportSettings::load_for(path_ref);
+        let (document, buffers, images) = gltf::import(path_ref)
+            .with_context(|| format!("Failed to import glTF from {}", path_ref.display()))?;
+        let material_set = Self::import_materials(&document, &images, path_ref)?;
+        let roots: Vec<gltf::Node<'_>> = match document.default_scene().or_else(|| document.scenes().next()) {
+            Some(scene) => scene.nodes().collect(),
+            None => Self::root_nodes(&document),
+        };
+        let mut builder = GltfSceneBuilder {
+            path: path_ref,
+            settings: &settings,
+            buffers: &buffers,
+            material_key_map: &material_set.key_map,
+            default_material_key: &material_set.default_key,
+            visited: HashSet::new(),
+            names: HashSet::new(),
+            nodes: Vec::new(),
+            parts: Vec::new(),
+            scratch: MeshScratch::default(),
+        };
+        for node in &roots {
+            builder.visit(node, None)?;
+        }
+        let GltfSceneBuilder { nodes, parts, .. } = builder;
+        if parts.is_empty() {
+            return Err(anyhow!("Scene in {} contains no triangle primitives", path_ref.display()));
+        }
+        Ok(GltfSceneImport {
+            settings,
+            nodes,
+            parts,
+            materials: material_set.materials,
+            textures: material_set.textures,
+        })
+    }
+
+    /// Re-imports the single scene-graph part `key` of `path` together with the file's materials.
+    pub fn load_gltf_part(path: impl AsRef<Path>, key: &str) -> Result<MeshImport> {
+        let path_ref = path.as_ref();
+        let GltfSceneImport { parts, materials, textures, .. } = Self::load_gltf_scene(path_ref)?;
+        let mesh = parts
+            .into_iter()
+            .find(|part| part.key == key)
+            .map(|part| part.mesh)
+            .ok_or_else(|| anyhow!("{} no longer contains mesh part '{key}'", path_ref.display()))?;
+        Ok(MeshImport { mesh, materials, textures })
+    }
+
+    /// Loads the mesh registered as `key` from `path`: scene-graph part keys re-import just their
+    /// part, any other key imports the whole file flattened into one mesh.
+    pub fn load_gltf_for_key(path: impl AsRef<Path>, key: &str) -> Result<MeshImport> {
+        if gltf_part_source(key).is_some() {
+            Self::load_gltf_part(path, key)
+        } else {
+            Self::load_gltf_with_materials(path)
+        }
+    }
+
+    fn import_materials(
+        document: &gltf::Document,
+        images: &[gltf::image::Data],
+        path_ref: &Path,
+    ) -> Result<GltfMaterialSet> {
         let mut textures = Vec::new();
         let mut texture_key_map: HashMap<usize, String> = HashMap::new();
         for texture in document.textures() {
@@ -295,6 +646,7 @@ impl Mesh {
                 metallic_roughness_texture,
                 normal_texture,
                 emissive_texture,
+                double_sided: material.double_sided(),
                 source: Some(path_ref.display().to_string()),
             });
         }
@@ -311,118 +663,17 @@ impl Mesh {
                 metallic_roughness_texture: None,
                 normal_texture: None,
                 emissive_texture: None,
+                double_sided: false,
                 source: None,
             });
         }
 
-        let mut vertices: Vec<MeshVertex> = Vec::new();
-        let mut indices: Vec<u32> = Vec::new();
-        let mut subsets: Vec<MeshSubset> = Vec::new();
-        let mut scratch = MeshScratch::default();
-
-        // Pre-size the buffers based on primitive counts to reduce reallocations.
-        let mut estimated_vertices = 0usize;
-        let mut estimated_indices = 0usize;
-        let mut estimated_subsets = 0usize;
-        for mesh in document.meshes() {
-            for primitive in mesh.primitives() {
-                if primitive.mode() != Mode::Triangles {
-                    continue;
-                }
-                estimated_subsets += 1;
-                if let Some(accessor) = primitive.get(&gltf::Semantic::Positions) {
-                    estimated_vertices += accessor.count();
-                }
-                if let Some(accessor) = primitive.indices() {
-                    estimated_indices += accessor.count();
-                }
-            }
-        }
-        vertices.reserve(estimated_vertices);
-        indices.reserve(estimated_indices);
-        subsets.reserve(estimated_subsets);
-        let mut processed_nodes = false;
-
-        if let Some(scene) = document.default_scene() {
-            for node in scene.nodes() {
-                processed_nodes |= Self::traverse_node_primitives(
-                    &node,
-                    Mat4::IDENTITY,
-                    &buffers,
-                    &material_key_map,
-                    &default_material_key,
-                    path_ref,
-                    &mut vertices,
-                    &mut indices,
-                    &mut subsets,
-                    &mut scratch,
-                )?;
-            }
-        } else if let Some(scene) = document.scenes().next() {
-            for node in scene.nodes() {
-                processed_nodes |= Self::traverse_node_primitives(
-                    &node,
-                    Mat4::IDENTITY,
-                    &buffers,
-                    &material_key_map,
-                    &default_material_key,
-                    path_ref,
-                    &mut vertices,
-                    &mut indices,
-                    &mut subsets,
-                    &mut scratch,
-                )?;
-            }
-        }
-
-        if !processed_nodes {
-            let roots = Self::root_nodes(&document);
-            for node in roots {
-                processed_nodes |= Self::traverse_node_primitives(
-                    &node,
-                    Mat4::IDENTITY,
-                    &buffers,
-                    &material_key_map,
-                    &default_material_key,
-                    path_ref,
-                    &mut vertices,
-                    &mut indices,
-                    &mut subsets,
-                    &mut scratch,
-                )?;
-            }
-        }
-
-        if !processed_nodes {
-            for mesh in document.meshes() {
-                Self::append_mesh_primitives(
-                    &mesh,
-                    mesh.name(),
-                    Mat4::IDENTITY,
-                    Mat3::IDENTITY,
-                    &buffers,
-                    &material_key_map,
-                    &default_material_key,
-                    path_ref,
-                    &mut vertices,
-                    &mut indices,
-                    &mut subsets,
-                    &mut scratch,
-                )?;
-            }
-        }
-
-        compute_tangents(&mut vertices, &indices);
-
-        if subsets.is_empty() {
-            return Err(anyhow!("Mesh in {} contains no triangle primitives", path_ref.display()));
-        }
-
-        let bounds = MeshBounds::from_vertices(&vertices);
-
-        let mesh = Mesh { vertices, indices, subsets, bounds };
-
-        Ok(MeshImport { mesh, materials, textures })
+        Ok(GltfMaterialSet {
+            materials,
+            textures,
+            key_map: material_key_map,
+            default_key: default_material_key,
+        })
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -508,11 +759,15 @@ impl Mesh {
                 continue;
             }
 
+            // Zero normals/tangents mark attributes the source lacks; they are derived once the
+            // primitives are assembled (see `fill_missing_normals_and_tangents`).
             scratch.normals.clear();
             if let Some(read_normals) = reader.read_normals() {
                 scratch.normals.extend(read_normals.map(Vec3::from_array));
-            } else {
-                scratch.normals.resize(scratch.positions.len(), Vec3::ZERO);
+            }
+            scratch.tangents.clear();
+            if let Some(read_tangents) = reader.read_tangents() {
+                scratch.tangents.extend(read_tangents.map(Vec4::from_array));
             }
 
             scratch.tex_coords.clear();
@@ -534,6 +789,12 @@ impl Mesh {
             } else {
                 scratch.weights.resize(scratch.positions.len(), [0.0; 4]);
             }
+            scratch.colors.clear();
+            if let Some(c) = reader.read_colors(0) {
+                scratch.colors.extend(c.into_rgba_f32().map(Vec4::from_array));
+            } else {
+                scratch.colors.resize(scratch.positions.len(), Vec4::ONE);
+            }
 
             scratch.local_indices.clear();
             if let Some(read) = reader.read_indices() {
@@ -542,11 +803,13 @@ impl Mesh {
                 scratch.local_indices.extend(0..scratch.positions.len() as u32);
             }
 
-            if scratch.normals.is_empty()
-                || scratch.normals.len() != scratch.positions.len()
-                || scratch.normals.iter().all(|n| n.length_squared() == 0.0)
-            {
-                scratch.normals = compute_normals(&scratch.positions, &scratch.local_indices);
+            if scratch.normals.len() != scratch.positions.len() {
+                scratch.normals.clear();
+                scratch.normals.resize(scratch.positions.len(), Vec3::ZERO);
+            }
+            if scratch.tangents.len() != scratch.positions.len() {
+                scratch.tangents.clear();
+                scratch.tangents.resize(scratch.positions.len(), Vec4::ZERO);
             }
 
             if scratch.tex_coords.len() != scratch.positions.len() {
@@ -558,17 +821,31 @@ impl Mesh {
             if scratch.weights.len() != scratch.positions.len() {
                 scratch.weights.resize(scratch.positions.len(), [0.0; 4]);
             }
+            if scratch.colors.len() != scratch.positions.len() {
+                scratch.colors.resize(scratch.positions.len(), Vec4::ONE);
+            }
 
             let base_vertex = vertices.len() as u32;
+            let handedness = if transform.determinant() < 0.0 { -1.0 } else { 1.0 };
             vertices.extend(scratch.positions.iter().enumerate().map(|(i, pos)| {
-                let norm = scratch.normals.get(i).copied().unwrap_or(Vec3::Y).normalize_or_zero();
+                let norm = scratch.normals.get(i).copied().unwrap_or(Vec3::ZERO).normalize_or_zero();
                 let transformed_pos = transform.transform_point3(*pos);
                 let transformed_normal = (normal_matrix * norm).normalize_or_zero();
+                let tangent = scratch.tangents.get(i).copied().unwrap_or(Vec4::ZERO);
+                let transformed_tangent = transform.transform_vector3(tangent.truncate()).normalize_or_zero();
                 let uv = scratch.tex_coords.get(i).copied().unwrap_or(Vec2::ZERO);
                 let joint_indices = scratch.joints.get(i).copied().unwrap_or([0; 4]);
                 let weight_values = scratch.weights.get(i).copied().unwrap_or([0.0; 4]);
-                MeshVertex::new(transformed_pos, transformed_normal, Vec4::new(1.0, 0.0, 0.0, 1.0), uv)
-                    .with_skin(joint_indices, weight_values)
+                let color = scratch.colors.get(i).copied().unwrap_or(Vec4::ONE);
+                let tangent_w = if tangent.w < 0.0 { -handedness } else { handedness };
+                MeshVertex::new(
+                    transformed_pos,
+                    transformed_normal,
+                    transformed_tangent.extend(tangent_w),
+                    uv,
+                )
+                .with_skin(joint_indices, weight_values)
+                .with_color(color)
             }));
 
             let index_offset = indices.len() as u32;
@@ -636,6 +913,106 @@ impl Mesh {
     }
 }
 
+impl GltfSceneBuilder<'_> {
+    fn visit(&mut self, node: &gltf::Node<'_>, parent: Option<usize>) -> Result<()> {
+        if !self.visited.insert(node.index()) {
+            return Ok(());
+        }
+        let (translation, rotation, scale) = node.transform().decomposed();
+        let mut local = Mat4::from_scale_rotation_translation(
+            Vec3::from_array(scale),
+            Quat::from_array(rotation),
+            Vec3::from_array(translation) * self.settings.scale,
+        );
+        if parent.is_none() {
+            local = Mat4::from_quat(self.settings.axis.to_y_up()) * local;
+        }
+        let (scale, rotation, translation) = local.to_scale_rotation_translation();
+        let index = self.nodes.len();
+        let name = self.unique_name(node);
+        self.nodes.push(ImportedNode { name, parent, translation, rotation, scale, meshes: Vec::new() });
+        if let Some(mesh) = node.mesh() {
+            self.append_node_meshes(index, node.name(), &mesh)?;
+        }
+        for child in node.children() {
+            self.visit(&child, Some(index))?;
+        }
+        Ok(())
+    }
+
+    /// Node name usable inside a part key: `#` and `/` are replaced and duplicates get the node index.
+    fn unique_name(&mut self, node: &gltf::Node<'_>) -> String {
+        let base = match node.name() {
+            Some(name) if !name.is_empty() => name.replace(['#', '/'], "_"),
+            _ => format!("node{}", node.index()),
+        };
+        let name = if self.names.contains(&base) { format!("{base}_{}", node.index()) } else { base };
+        self.names.insert(name.clone());
+        name
+    }
+
+    fn append_node_meshes(
+        &mut self,
+        index: usize,
+        node_name: Option<&str>,
+        mesh: &gltf::Mesh<'_>,
+    ) -> Result<()> {
+        let mut vertices = Vec::new();
+        let mut indices = Vec::new();
+        let mut subsets = Vec::new();
+        Mesh::append_mesh_primitives(
+            mesh,
+            node_name,
+            Mat4::from_scale(Vec3::splat(self.settings.scale)),
+            Mat3::IDENTITY,
+            self.buffers,
+            self.material_key_map,
+            self.default_material_key,
+            self.path,
+            &mut vertices,
+            &mut indices,
+            &mut subsets,
+            &mut self.scratch,
+        )?;
+        if subsets.is_empty() {
+            return Ok(());
+        }
+        let name = self.nodes[index].name.clone();
+        if self.settings.merge_primitives {
+            let material = subsets.iter().find_map(|subset| subset.material.clone());
+            let bounds = MeshBounds::from_vertices(&vertices);
+            let key = gltf_part_key(self.path, &name, None);
+            let mut part = Mesh { vertices, indices, subsets, bounds };
+            fill_missing_normals_and_tangents(&mut part);
+            self.push_part(index, key, part, material);
+            return Ok(());
+        }
+        for (primitive, subset) in subsets.into_iter().enumerate() {
+            let start = subset.index_offset as usize;
+            let range = &indices[start..start + subset.index_count as usize];
+            let (Some(&first), Some(&last)) = (range.iter().min(), range.iter().max()) else {
+                continue;
+            };
+            let part_vertices = vertices[first as usize..=last as usize].to_vec();
+            let part_indices: Vec<u32> = range.iter().map(|idx| idx - first).collect();
+            let bounds = MeshBounds::from_vertices(&part_vertices);
+            let material = subset.material.clone();
+            let part_subset = MeshSubset { index_offset: 0, ..subset };
+            let key = gltf_part_key(self.path, &name, Some(primitive));
+            let mut part =
+                Mesh { vertices: part_vertices, indices: part_indices, subsets: vec![part_subset], bounds };
+            fill_missing_normals_and_tangents(&mut part);
+            self.push_part(index, key, part, material);
+        }
+        Ok(())
+    }
+
+    fn push_part(&mut self, node: usize, key: String, mesh: Mesh, material: Option<String>) {
+        self.nodes[node].meshes.push(ImportedNodeMesh { key: key.clone(), material });
+        self.parts.push(ImportedMeshPart { key, mesh });
+    }
+}
+
 fn convert_image_to_rgba(image: &gltf::image::Data) -> Result<Vec<u8>> {
     match image.format {
         gltf::image::Format::R8 => {
@@ -664,55 +1041,83 @@ fn convert_image_to_rgba(image: &gltf::image::Data) -> Result<Vec<u8>> {
     }
 }
 
-fn compute_normals(positions: &[Vec3], indices: &[u32]) -> Vec<Vec3> {
-    let mut normals = vec![Vec3::ZERO; positions.len()];
-    for tri in indices.chunks(3) {
-        if tri.len() < 3 {
-            continue;
-        }
-        let i0 = tri[0] as usize;
-        let i1 = tri[1] as usize;
-        let i2 = tri[2] as usize;
-        if i0 >= positions.len() || i1 >= positions.len() || i2 >= positions.len() {
+/// Replaces every vertex normal with the area-weighted average of the faces using the vertex.
+/// Degenerate triangles contribute nothing; vertices left without a face get +Y.
+pub fn recompute_normals(mesh: &mut Mesh) {
+    let all = vec![true; mesh.vertices.len()];
+    derive_normals(&mut mesh.vertices, &mesh.indices, &all);
+}
+
+/// Derives tangents from positions, normals and the first UV set, storing the bitangent sign in
+/// `w`. Triangles without area in position or UV space are skipped; vertices they leave uncovered
+/// get an arbitrary tangent perpendicular to their normal, so the basis never contains NaNs.
+pub fn recompute_tangents(mesh: &mut Mesh) {
+    let all = vec![true; mesh.vertices.len()];
+    derive_tangents(&mut mesh.vertices, &mesh.indices, &all);
+}
+
+/// Derives the normals and tangents a source left out (zero or non-finite vectors) and keeps the
+/// authored ones. Vertices whose normal is derived get a derived tangent too.
+pub fn fill_missing_normals_and_tangents(mesh: &mut Mesh) {
+    let missing_normals: Vec<bool> =
+        mesh.vertices.iter().map(|vertex| !is_direction(Vec3::from_array(vertex.normal))).collect();
+    if missing_normals.contains(&true) {
+        derive_normals(&mut mesh.vertices, &mesh.indices, &missing_normals);
+    }
+    let missing_tangents: Vec<bool> = mesh
+        .vertices
+        .iter()
+        .zip(&missing_normals)
+        .map(|(vertex, &normal_missing)| {
+            normal_missing || !is_direction(Vec4::from(vertex.tangent).truncate())
+        })
+        .collect();
+    if missing_tangents.contains(&true) {
+        derive_tangents(&mut mesh.vertices, &mesh.indices, &missing_tangents);
+    }
+}
+
+fn is_direction(v: Vec3) -> bool {
+    v.is_finite() && v.length_squared() > 1e-12
+}
+
+fn triangle(indices: &[u32], vertex_count: usize) -> Option<[usize; 3]> {
+    let [i0, i1, i2] = [indices[0] as usize, indices[1] as usize, indices[2] as usize];
+    (i0 < vertex_count && i1 < vertex_count && i2 < vertex_count).then_some([i0, i1, i2])
+}
+
+fn derive_normals(vertices: &mut [MeshVertex], indices: &[u32], mask: &[bool]) {
+    let mut sums = vec![Vec3::ZERO; vertices.len()];
+    for tri in indices.chunks_exact(3) {
+        let Some([i0, i1, i2]) = triangle(tri, vertices.len()) else {
             continue;
-        }
-        let a = positions[i0];
-        let b = positions[i1];
-        let c = positions[i2];
+        };
+        let a = Vec3::from_array(vertices[i0].position);
+        let b = Vec3::from_array(vertices[i1].position);
+        let c = Vec3::from_array(vertices[i2].position);
         let normal = (b - a).cross(c - a);
-        if normal.length_squared() > 0.0 {
-            normals[i0] += normal;
-            normals[i1] += normal;
-            normals[i2] += normal;
+        if !is_direction(normal) {
+            continue;
         }
+        sums[i0] += normal;
+        sums[i1] += normal;
+        sums[i2] += normal;
     }
-    for normal in &mut normals {
-        if normal.length_squared() > 0.0 {
-            *normal = normal.normalize();
-        } else {
-            *normal = Vec3::Y;
+    for ((vertex, sum), &derive) in vertices.iter_mut().zip(sums).zip(mask) {
+        if derive {
+            vertex.normal = sum.try_normalize().unwrap_or(Vec3::Y).to_array();
         }
     }
-    normals
 }
 
-fn compute_tangents(vertices: &mut [MeshVertex], indices: &[u32]) {
-    if vertices.is_empty() || indices.is_empty() {
-        return;
-    }
+fn derive_tangents(vertices: &mut [MeshVertex], indices: &[u32], mask: &[bool]) {
     let mut tan1 = vec![Vec3::ZERO; vertices.len()];
     let mut tan2 = vec![Vec3::ZERO; vertices.len()];
 
-    for tri in indices.chunks(3) {
-        if tri.len() < 3 {
-            continue;
-        }
-        let i0 = tri[0] as usize;
-        let i1 = tri[1] as usize;
-        let i2 = tri[2] as usize;
-        if i0 >= vertices.len() || i1 >= vertices.len() || i2 >= vertices.len() {
+    for tri in indices.chunks_exact(3) {
+        let Some([i0, i1, i2]) = triangle(tri, vertices.len()) else {
             continue;
-        }
+        };
 
         let v0 = Vec3::from_array(vertices[i0].position);
         let v1 = Vec3::from_array(vertices[i1].position);
@@ -727,6 +1132,9 @@ fn compute_tangents(vertices: &mut [MeshVertex], indices: &[u32]) {
         let delta_uv1 = uv1 - uv0;
         let delta_uv2 = uv2 - uv0;
 
+        if !is_direction(delta_pos1.cross(delta_pos2)) {
+            continue;
+        }
         let denom = delta_uv1.x * delta_uv2.y - delta_uv1.y * delta_uv2.x;
         if denom.abs() < 1e-8 {
             continue;
@@ -734,6 +1142,9 @@ fn compute_tangents(vertices: &mut [MeshVertex], indices: &[u32]) {
         let r = 1.0 / denom;
         let sdir = (delta_pos1 * delta_uv2.y - delta_pos2 * delta_uv1.y) * r;
         let tdir = (delta_pos2 * delta_uv1.x - delta_pos1 * delta_uv2.x) * r;
+        if !sdir.is_finite() || !tdir.is_finite() {
+            continue;
+        }
 
         tan1[i0] += sdir;
         tan1[i1] += sdir;
@@ -745,17 +1156,20 @@ fn compute_tangents(vertices: &mut [MeshVertex], indices: &[u32]) {
     }
 
     for (i, vertex) in vertices.iter_mut().enumerate() {
-        let normal = Vec3::from_array(vertex.normal);
-        let t1 = tan1[i];
-        if t1.length_squared() > 0.0 {
-            let tangent = (t1 - normal * normal.dot(t1)).normalize_or_zero();
-            let bitangent = tan2[i];
-            let w = if normal.cross(t1).dot(bitangent) < 0.0 { -1.0 } else { 1.0 };
-            vertex.tangent = [tangent.x, tangent.y, tangent.z, w];
-        } else {
-            let tangent = Vec3::X;
-            vertex.tangent = [tangent.x, tangent.y, tangent.z, 1.0];
+        if !mask[i] {
+            continue;
         }
+        let normal = Vec3::from_array(vertex.normal).try_normalize().unwrap_or(Vec3::Y);
+        let t1 = tan1[i];
+        // Gram-Schmidt against the normal; a tangent parallel to it leaves nothing to keep.
+        let tangent = (t1 - normal * normal.dot(t1)).try_normalize();
+        vertex.tangent = match tangent {
+            Some(tangent) => {
+                let w = if normal.cross(t1).dot(tan2[i]) < 0.0 { -1.0 } else { 1.0 };
+                tangent.extend(w).to_array()
+            }
+            None => normal.any_orthonormal_vector().extend(1.0).to_array(),
+        };
     }
 }
 
@@ -779,6 +1193,26 @@ impl MeshBounds {
         }
         MeshBounds { min, max, center, radius }
     }
+
+    /// Bounding sphere after applying `model`, scaled by the largest axis scale.
+    pub fn world_sphere(&self, model: Mat4) -> (Vec3, f32) {
+        let center = model.transform_point3(self.center);
+        let scale_x = model.x_axis.truncate().length();
+        let scale_y = model.y_axis.truncate().length();
+        let scale_z = model.z_axis.truncate().length();
+        let max_scale = scale_x.max(scale_y).max(scale_z).max(0.0001);
+        (center, self.radius * max_scale)
+    }
+
+    /// Axis-aligned box enclosing the local bounds after applying `model`.
+    pub fn world_aabb(&self, model: Mat4) -> (Vec3, Vec3) {
+        let center = model.transform_point3((self.min + self.max) * 0.5);
+        let half = (self.max - self.min) * 0.5;
+        let extent = model.x_axis.truncate().abs() * half.x
+            + model.y_axis.truncate().abs() * half.y
+            + model.z_axis.truncate().abs() * half.z;
+        (center - extent, center + extent)
+    }
 }
 
 #[cfg(test)]
@@ -804,6 +1238,139 @@ mod tests {
         }
     }
 
+    #[test]
+    fn recomputed_quad_tangents_are_orthogonal_to_normals() {
+        let corners = [
+            (Vec3::ZERO, Vec2::ZERO),
+            (Vec3::X, Vec2::X),
+            (Vec3::ONE.with_z(0.0), Vec2::ONE),
+            (Vec3::Y, Vec2::Y),
+            // Only used by a triangle without UV area, so no tangent can be derived for it.
+            (Vec3::new(0.5, -1.0, 0.0), Vec2::ZERO),
+        ];
+        let vertices: Vec<MeshVertex> = corners
+            .iter()
+            .map(|&(position, uv)| MeshVertex::new(position, Vec3::ZERO, Vec4::ZERO, uv))
+            .collect();
+        // The quad, a collapsed triangle and one with no UV area; the last two must be skipped.
+        let indices = vec![0, 1, 2, 0, 2, 3, 1, 1, 2, 0, 4, 1];
+        let mut mesh = Mesh::new(vertices, indices);
+        recompute_normals(&mut mesh);
+        recompute_tangents(&mut mesh);
+        for (index, vertex) in mesh.vertices.iter().enumerate() {
+            let normal = Vec3::from_array(vertex.normal);
+            let tangent = Vec4::from(vertex.tangent);
+            assert!(normal.is_finite() && tangent.is_finite(), "no NaNs: {normal:?} {tangent:?}");
+            assert!((normal.length() - 1.0).abs() < 1e-5);
+            assert!((tangent.truncate().length() - 1.0).abs() < 1e-5);
+            assert!(tangent.truncate().dot(normal).abs() < 1e-5, "tangent {tangent:?} not orthogonal");
+            if index < 4 {
+                assert!((normal - Vec3::Z).length() < 1e-5, "quad normal should face +Z: {normal:?}");
+                assert!((tangent.truncate() - Vec3::X).length() < 1e-5, "tangent should follow +U");
+                assert_eq!(tangent.w, 1.0);
+            }
+        }
+    }
+
+    #[test]
+    fn fill_missing_keeps_authored_tangents() {
+        let authored = Vec4::new(0.0, 1.0, 0.0, -1.0);
+        let vertices = vec![
+            MeshVertex::new(Vec3::ZERO, Vec3::Z, authored, Vec2::ZERO),
+            MeshVertex::new(Vec3::X, Vec3::ZERO, Vec4::ZERO, Vec2::X),
+            MeshVertex::new(Vec3::Y, Vec3::Z, Vec4::splat(f32::NAN), Vec2::Y),
+        ];
+        let mut mesh = Mesh::new(vertices, vec![0, 1, 2]);
+        fill_missing_normals_and_tangents(&mut mesh);
+        assert_eq!(Vec4::from(mesh.vertices[0].tangent), authored);
+        assert_eq!(Vec3::from_array(mesh.vertices[1].normal), Vec3::Z);
+        for vertex in &mesh.vertices[1..] {
+            let tangent = Vec4::from(vertex.tangent);
+            assert!(tangent.is_finite() && (tangent.truncate() - Vec3::X).length() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn world_aabb_encloses_rotated_and_scaled_bounds() {
+        let bounds = Mesh::cube(1.0).bounds;
+        let model = Mat4::from_scale_rotation_translation(
+            Vec3::new(2.0, 1.0, 1.0),
+            glam::Quat::from_rotation_y(std::f32::consts::FRAC_PI_4),
+            Vec3::new(10.0, 0.0, 0.0),
+        );
+        let (min, max) = bounds.world_aabb(model);
+        let corners = [bounds.min, bounds.max, Vec3::new(bounds.min.x, bounds.max.y, bounds.max.z)];
+        for corner in corners {
+            let world = model.transform_point3(corner);
+            assert!(world.cmpge(min - Vec3::splat(1e-4)).all() && world.cmple(max + Vec3::splat(1e-4)).all());
+        }
+        assert!((min.y - bounds.min.y).abs() < 1e-4 && (max.y - bounds.max.y).abs() < 1e-4);
+        let (center, radius) = bounds.world_sphere(model);
+        assert!((center - Vec3::new(10.0, 0.0, 0.0)).length() < 1e-4);
+        assert!((radius - bounds.radius * 2.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn load_gltf_reads_vertex_colors() {
+        use std::io::Write;
+        use tempfile::NamedTempFile;
+
+        const GLTF_JSON: &str = r#"{
+  "asset": { "version": "2.0" },
+  "buffers": [
+    {
+      "uri": "data:application/octet-stream;base64,AAAAAAAAAAAAAAAAAACAPwAAAAAAAAAAAAAAAAAAgD8AAAAAAAAAAAAAAAAAAIA/AAAAAAAAAAAAAIA/AAAAAAAAAAAAAIA/AAAAAAAAAAAAAIA/AAAAAAAAAAAAAIA/AACAPwAAAAAAAAAAAACAPwAAAAAAAIA/AAAAAAAAgD8AAAAAAAAAAAAAgD8AAAA/AAAAAAEAAAACAAAA",
+      "byteLength": 156
+    }
+  ],
+  "bufferViews": [
+    { "buffer": 0, "byteOffset": 0, "byteLength": 36, "target": 34962 },
+    { "buffer": 0, "byteOffset": 36, "byteLength": 36, "target": 34962 },
+    { "buffer": 0, "byteOffset": 72, "byteLength": 24, "target": 34962 },
+    { "buffer": 0, "byteOffset": 96, "byteLength": 48, "target": 34962 },
+    { "buffer": 0, "byteOffset": 144, "byteLength": 12, "target": 34963 }
+  ],
+  "accessors": [
+    { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 0] },
+    { "bufferView": 1, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 1], "max": [0, 0, 1] },
+    { "bufferView": 2, "componentType": 5126, "count": 3, "type": "VEC2", "min": [0, 0], "max": [1, 1] },
+    { "bufferView": 3, "componentType": 5126, "count": 3, "type": "VEC4" },
+    { "bufferView": 4, "componentType": 5125, "count": 3, "type": "SCALAR", "min": [0], "max": [2] }
+  ],
+  "meshes": [
+    {
+      "name": "Tri",
+      "primitives": [
+        { "attributes": { "POSITION": 0, "NORMAL": 1, "TEXCOORD_0": 2, "COLOR_0": 3 }, "indices": 4 }
+      ]
+    }
+  ],
+  "nodes": [{ "mesh": 0 }],
+  "scenes": [{ "nodes": [0] }],
+  "scene": 0
+}"#;
+
+        let mut gltf_file = NamedTempFile::new().expect("temp gltf file");
+        gltf_file.write_all(GLTF_JSON.as_bytes()).expect("write gltf");
+
+        let mesh = Mesh::load_gltf(gltf_file.path()).expect("load colored gltf");
+        let colors: Vec<[f32; 4]> = mesh.vertices.iter().map(|v| v.color).collect();
+        assert_eq!(colors, vec![[1.0, 0.0, 0.0, 1.0], [0.0, 1.0, 0.0, 1.0], [0.0, 0.0, 1.0, 0.5]]);
+
+        let layout = MeshVertex::layout();
+        assert_eq!(layout.array_stride, std::mem::size_of::<MeshVertex>() as wgpu::BufferAddress);
+        let color_attribute = layout
+            .attributes
+            .iter()
+            .find(|attribute| attribute.shader_location == 6)
+            .expect("vertex layout should include the color attribute");
+        assert_eq!(color_attribute.format, wgpu::VertexFormat::Float32x4);
+        assert_eq!(color_attribute.offset, std::mem::offset_of!(MeshVertex, color) as wgpu::BufferAddress);
+
+        let plain = Mesh::load_gltf("assets/models/demo_triangle.gltf").expect("demo gltf should load");
+        assert!(plain.vertices.iter().all(|v| v.color == [1.0; 4]), "missing colors default to white");
+    }
+
     #[test]
     fn load_gltf_imports_all_nodes() {
         use std::io::Write;
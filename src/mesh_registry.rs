@@ -10,9 +10,13 @@ use std::time::UNIX_EPOCH;
 
 use anyhow::{anyhow, Result};
 
+use crate::assets::{AssetLoader, AssetLoaderRegistry};
 use crate::config::MeshHashAlgorithm;
 use crate::material_registry::MaterialRegistry;
-use crate::mesh::{Mesh, MeshBounds, MeshImport, MeshSubset};
+use crate::mesh::{
+    fill_missing_normals_and_tangents, GltfSceneImport, ImportedNode, Mesh, MeshBounds, MeshImport,
+    MeshSubset,
+};
 use crate::renderer::{GpuMesh, Renderer};
 use std::sync::mpsc;
 use std::thread;
@@ -34,10 +38,13 @@ pub struct MeshRegistry {
     hash_tx: Option<mpsc::Sender<HashJob>>,
     hash_rx: Option<mpsc::Receiver<HashJobResult>>,
     pending_hashes: HashMap<PathBuf, PendingHash>,
+    loaders: AssetLoaderRegistry<Mesh>,
 }
 
 struct MeshEntry {
     mesh: Mesh,
+    /// `index_count / 3`, computed whenever `mesh` is (re)loaded.
+    triangle_count: u64,
     gpu: Option<GpuMesh>,
     source: Option<PathBuf>,
     fingerprint: Option<u128>,
@@ -101,6 +108,7 @@ impl MeshRegistry {
             hash_tx: None,
             hash_rx: None,
             pending_hashes: HashMap::new(),
+            loaders: AssetLoaderRegistry::default(),
         };
         registry
             .insert_entry("cube", Mesh::cube(1.0), None, None, Vec::new(), true)
@@ -153,7 +161,7 @@ impl MeshRegistry {
     fn insert_entry(
         &mut self,
         key: impl Into<String>,
-        mesh: Mesh,
+        mut mesh: Mesh,
         source: Option<PathBuf>,
         fingerprint: Option<u128>,
         material_keys: Vec<String>,
@@ -163,14 +171,40 @@ impl MeshRegistry {
         if self.entries.contains_key(&key_str) {
             return Err(anyhow!("Mesh '{key_str}' already registered in registry"));
         }
+        fill_missing_normals_and_tangents(&mut mesh);
+        let triangle_count = triangle_count(&mesh);
         self.entries.insert(
             key_str,
-            MeshEntry { mesh, gpu: None, source, fingerprint, ref_count: 0, permanent, material_keys },
+            MeshEntry {
+                mesh,
+                triangle_count,
+                gpu: None,
+                source,
+                fingerprint,
+                ref_count: 0,
+                permanent,
+                material_keys,
+            },
         );
         self.bump_revision();
         Ok(())
     }
 
+    /// Routes mesh paths with one of the loader's extensions through it instead of the glTF importer.
+    /// Custom meshes carry no materials. Errors if another mesh loader already claims an extension.
+    pub fn register_loader(&mut self, loader: Box<dyn AssetLoader<Asset = Mesh>>) -> Result<()> {
+        self.loaders.register(loader)
+    }
+
+    fn import_from_path(&self, path: &Path, key: &str) -> Result<MeshImport> {
+        let Some(loader) = self.loaders.loader_for(path) else {
+            return Mesh::load_gltf_for_key(path, key);
+        };
+        let bytes = fs::read(path).map_err(|err| anyhow!("Reading mesh '{}': {err}", path.display()))?;
+        let mesh = loader.load(&bytes, key, &path.to_string_lossy())?;
+        Ok(MeshImport { mesh, materials: Vec::new(), textures: Vec::new() })
+    }
+
     pub fn default_key(&self) -> &str {
         &self.default
     }
@@ -224,7 +258,7 @@ impl MeshRegistry {
             return Err(anyhow!("Mesh '{key}' already registered in registry"));
         }
         let path_ref = path.as_ref();
-        let import = Mesh::load_gltf_with_materials(path_ref)?;
+        let import = self.import_from_path(path_ref, key)?;
         let snapshot = materials.register_gltf_import_with_snapshot(&import.materials, &import.textures);
         let mesh = import.mesh;
         let material_keys: Vec<String> = import.materials.iter().map(|mat| mat.key.clone()).collect();
@@ -264,7 +298,8 @@ impl MeshRegistry {
             (entry.ref_count, entry.permanent, entry.material_keys.clone())
         };
 
-        let import = Mesh::load_gltf_with_materials(path)?;
+        let mut import = self.import_from_path(path, key)?;
+        fill_missing_normals_and_tangents(&mut import.mesh);
         let snapshot = materials.register_gltf_import_with_snapshot(&import.materials, &import.textures);
         let material_keys: Vec<String> = import.materials.iter().map(|mat| mat.key.clone()).collect();
         let mut retained: Vec<String> = Vec::new();
@@ -282,6 +317,7 @@ impl MeshRegistry {
         let fingerprint = self.mesh_source_fingerprint(path);
 
         if let Some(entry) = self.entries.get_mut(key) {
+            entry.triangle_count = triangle_count(&import.mesh);
             entry.mesh = import.mesh;
             entry.gpu = None;
             entry.source = Some(path.to_path_buf());
@@ -307,52 +343,88 @@ impl MeshRegistry {
         fingerprint: Option<u128>,
         materials: &mut MaterialRegistry,
     ) -> Result<()> {
-        let (ref_count, permanent, old_materials, previous_fingerprint) =
-            if let Some(entry) = self.entries.get(key) {
-                (entry.ref_count, entry.permanent, entry.material_keys.clone(), entry.fingerprint)
-            } else {
-                (0, false, Vec::new(), None)
-            };
-
+        let previous_fingerprint = self.entries.get(key).and_then(|entry| entry.fingerprint);
         let snapshot = materials.register_gltf_import_with_snapshot(&import.materials, &import.textures);
         let material_keys: Vec<String> = import.materials.iter().map(|mat| mat.key.clone()).collect();
+        let new_fingerprint =
+            fingerprint.or_else(|| self.mesh_source_fingerprint(&source)).or(previous_fingerprint);
+        if let Err(err) =
+            self.install_mesh(key, import.mesh, source, new_fingerprint, material_keys, materials)
+        {
+            snapshot.rollback(materials);
+            return Err(err);
+        }
+        Ok(())
+    }
+
+    /// Registers every mesh part of a glTF scene graph (see [`Mesh::load_gltf_scene`]) under its
+    /// `file#node/primitive` key, replacing parts that are already loaded, and returns the node
+    /// hierarchy for [`EcsWorld::spawn_mesh_hierarchy`](crate::ecs::EcsWorld::spawn_mesh_hierarchy).
+    pub fn import_gltf_scene(
+        &mut self,
+        path: impl AsRef<Path>,
+        materials: &mut MaterialRegistry,
+    ) -> Result<Vec<ImportedNode>> {
+        let path_ref = path.as_ref();
+        let GltfSceneImport { nodes, parts, materials: imported, textures, .. } =
+            Mesh::load_gltf_scene(path_ref)?;
+        let snapshot = materials.register_gltf_import_with_snapshot(&imported, &textures);
+        let material_keys: Vec<String> = imported.iter().map(|mat| mat.key.clone()).collect();
+        let fingerprint = self.mesh_source_fingerprint(path_ref);
+        for part in parts {
+            let source = path_ref.to_path_buf();
+            if let Err(err) =
+                self.install_mesh(&part.key, part.mesh, source, fingerprint, material_keys.clone(), materials)
+            {
+                snapshot.rollback(materials);
+                return Err(err);
+            }
+        }
+        Ok(nodes)
+    }
+
+    /// Stores `mesh` under `key`, keeping the ref count of an existing entry, and moves the entry's
+    /// material references over to `material_keys`, which must already be registered.
+    fn install_mesh(
+        &mut self,
+        key: &str,
+        mut mesh: Mesh,
+        source: PathBuf,
+        fingerprint: Option<u128>,
+        material_keys: Vec<String>,
+        materials: &mut MaterialRegistry,
+    ) -> Result<()> {
         let mut retained: Vec<String> = Vec::new();
         for mat_key in &material_keys {
             if let Err(err) = materials.retain(mat_key) {
                 for retained_key in retained {
                     materials.release(&retained_key);
                 }
-                snapshot.rollback(materials);
                 return Err(err);
             }
             retained.push(mat_key.clone());
         }
 
-        let new_fingerprint =
-            fingerprint.or_else(|| self.mesh_source_fingerprint(&source)).or(previous_fingerprint);
-        if let Some(entry) = self.entries.get_mut(key) {
-            entry.mesh = import.mesh;
+        fill_missing_normals_and_tangents(&mut mesh);
+        let old_materials = if let Some(entry) = self.entries.get_mut(key) {
+            entry.triangle_count = triangle_count(&mesh);
+            entry.mesh = mesh;
             entry.gpu = None;
             entry.source = Some(source);
-            entry.fingerprint = new_fingerprint;
-            entry.material_keys = material_keys;
-            entry.ref_count = ref_count;
-            entry.permanent = permanent;
+            entry.fingerprint = fingerprint;
+            let old_materials = std::mem::replace(&mut entry.material_keys, material_keys);
             self.bump_revision();
-        } else if let Err(err) = self.insert_entry(
-            key.to_string(),
-            import.mesh,
-            Some(source),
-            new_fingerprint,
-            material_keys,
-            false,
-        ) {
+            old_materials
+        } else if let Err(err) =
+            self.insert_entry(key.to_string(), mesh, Some(source), fingerprint, material_keys, false)
+        {
             for retained_key in retained {
                 materials.release(&retained_key);
             }
-            snapshot.rollback(materials);
             return Err(err);
-        }
+        } else {
+            Vec::new()
+        };
 
         for mat_key in old_materials {
             materials.release(&mat_key);
@@ -399,6 +471,10 @@ impl MeshRegistry {
         self.entries.get(key).map(|entry| entry.ref_count)
     }
 
+    pub fn mesh_triangle_count(&self, key: &str) -> Option<u64> {
+        self.entries.get(key).map(|entry| entry.triangle_count)
+    }
+
     pub fn ensure_gpu<'a>(&'a mut self, key: &str, renderer: &mut Renderer) -> Result<&'a GpuMesh> {
         let entry =
             self.entries.get_mut(key).ok_or_else(|| anyhow!("Mesh '{key}' not registered in registry"))?;
@@ -409,10 +485,57 @@ impl MeshRegistry {
         Ok(entry.gpu.as_ref().expect("GPU mesh populated"))
     }
 
+    /// Uploads every listed mesh that is not on the GPU yet with a single command submission.
+    /// Returns the keys that have GPU buffers afterwards; unregistered keys are skipped.
+    pub fn batch_ensure_gpu<'k>(
+        &mut self,
+        keys: &[&'k str],
+        renderer: &mut Renderer,
+    ) -> Result<Vec<&'k str>> {
+        let mut ready = Vec::with_capacity(keys.len());
+        let mut pending: Vec<&'k str> = Vec::new();
+        for &key in keys {
+            match self.entries.get(key) {
+                Some(entry) if entry.gpu.is_some() => ready.push(key),
+                Some(_) if !pending.contains(&key) => pending.push(key),
+                _ => {}
+            }
+        }
+        if pending.is_empty() {
+            return Ok(ready);
+        }
+        let meshes: Vec<&Mesh> = pending.iter().map(|key| &self.entries[*key].mesh).collect();
+        let uploaded = renderer.create_gpu_meshes(&meshes)?;
+        for (key, gpu) in pending.iter().zip(uploaded) {
+            if let Some(entry) = self.entries.get_mut(*key) {
+                entry.gpu = Some(gpu);
+            }
+        }
+        ready.extend(pending);
+        Ok(ready)
+    }
+
     pub fn mesh_source(&self, key: &str) -> Option<&Path> {
         self.entries.get(key).and_then(|entry| entry.source.as_deref())
     }
 
+    /// Estimated bytes held by uploaded vertex and index buffers.
+    pub fn gpu_memory_bytes(&self) -> u64 {
+        self.entries
+            .values()
+            .filter_map(|entry| entry.gpu.as_ref())
+            .map(|gpu| gpu.vertex_buffer.size() + gpu.index_buffer.size())
+            .sum()
+    }
+
+    /// Drops every uploaded GPU mesh, e.g. after the device was lost; the next `ensure_gpu`
+    /// re-uploads against the current device.
+    pub fn release_gpu(&mut self) {
+        for entry in self.entries.values_mut() {
+            entry.gpu = None;
+        }
+    }
+
     pub fn gpu_mesh(&self, key: &str) -> Option<&GpuMesh> {
         self.entries.get(key).and_then(|entry| entry.gpu.as_ref())
     }
@@ -657,6 +780,10 @@ struct FingerprintResult {
     sample: Option<u64>,
 }
 
+fn triangle_count(mesh: &Mesh) -> u64 {
+    mesh.indices.len() as u64 / 3
+}
+
 fn hash_file_with_blake3(path: &Path, file_len: u64) -> Option<FingerprintResult> {
     let mut file = fs::File::open(path).ok()?;
     let mut hasher = Blake3Hasher::new();
@@ -868,6 +995,9 @@ mod tests {
             .retain_mesh("temp_triangle", gltf.path().to_str(), &mut materials)
             .expect("retain temp mesh");
         assert_eq!(registry.mesh_ref_count("temp_triangle"), Some(1));
+        assert_eq!(registry.mesh_triangle_count("temp_triangle"), Some(1));
+        assert_eq!(registry.mesh_triangle_count("cube"), Some(12));
+        assert_eq!(registry.mesh_triangle_count("missing"), None);
         registry.release_mesh("temp_triangle", &mut materials);
         assert!(!registry.has("temp_triangle"), "non-permanent mesh should be removed at refcount 0");
     }
@@ -0,0 +1,461 @@
+use bevy_ecs::prelude::{Entity, Resource};
+use glam::Vec2;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+const SQRT_2: f32 = std::f32::consts::SQRT_2;
+const NEIGHBOR_OFFSETS: [(i32, i32); 8] =
+    [(1, 0), (-1, 0), (0, 1), (0, -1), (1, 1), (1, -1), (-1, 1), (-1, -1)];
+
+/// Rectangle and resolution the navigation grid covers. Obstacles are inflated by `clearance`
+/// so agents treated as points keep their bodies clear of walls.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NavGridConfig {
+    pub min: Vec2,
+    pub max: Vec2,
+    pub cell_size: f32,
+    pub clearance: f32,
+}
+
+impl Default for NavGridConfig {
+    fn default() -> Self {
+        Self { min: Vec2::new(-1.4, -1.0), max: Vec2::new(1.4, 1.0), cell_size: 0.05, clearance: 0.05 }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct CellRect {
+    min: (usize, usize),
+    max: (usize, usize),
+}
+
+impl CellRect {
+    fn cells(self) -> impl Iterator<Item = (usize, usize)> {
+        (self.min.1..=self.max.1).flat_map(move |y| (self.min.0..=self.max.0).map(move |x| (x, y)))
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NavGridStats {
+    pub cols: usize,
+    pub rows: usize,
+    pub blocked_cells: usize,
+    pub obstacles: usize,
+    pub last_sync_touched_cells: usize,
+}
+
+/// Occupancy grid built from collider bounds. Each cell counts the obstacles covering it so
+/// moving or removing one collider only restamps the cells under its old and new footprint.
+#[derive(Resource, Debug, Clone)]
+pub struct NavGrid {
+    config: NavGridConfig,
+    cols: usize,
+    rows: usize,
+    occupancy: Vec<u16>,
+    footprints: HashMap<Entity, CellRect>,
+    revision: u64,
+    last_sync_touched_cells: usize,
+}
+
+impl Default for NavGrid {
+    fn default() -> Self {
+        Self::new(NavGridConfig::default())
+    }
+}
+
+#[derive(Clone, Copy)]
+struct OpenNode {
+    cost: f32,
+    index: usize,
+}
+
+impl PartialEq for OpenNode {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for OpenNode {}
+
+impl PartialOrd for OpenNode {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OpenNode {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // BinaryHeap is a max-heap; invert so the cheapest node pops first.
+        other.cost.total_cmp(&self.cost).then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+impl NavGrid {
+    pub fn new(config: NavGridConfig) -> Self {
+        let config = NavGridConfig {
+            min: config.min.min(config.max),
+            max: config.min.max(config.max),
+            cell_size: config.cell_size.max(1e-3),
+            clearance: config.clearance.max(0.0),
+        };
+        let extent = config.max - config.min;
+        let cols = ((extent.x / config.cell_size).ceil() as usize).max(1);
+        let rows = ((extent.y / config.cell_size).ceil() as usize).max(1);
+        Self {
+            config,
+            cols,
+            rows,
+            occupancy: vec![0; cols * rows],
+            footprints: HashMap::new(),
+            revision: 0,
+            last_sync_touched_cells: 0,
+        }
+    }
+
+    pub fn config(&self) -> NavGridConfig {
+        self.config
+    }
+
+    pub fn dimensions(&self) -> (usize, usize) {
+        (self.cols, self.rows)
+    }
+
+    /// Bumped whenever any cell changes occupancy; agents compare it to decide whether their
+    /// remaining path needs to be re-validated.
+    pub fn revision(&self) -> u64 {
+        self.revision
+    }
+
+    pub fn stats(&self) -> NavGridStats {
+        NavGridStats {
+            cols: self.cols,
+            rows: self.rows,
+            blocked_cells: self.occupancy.iter().filter(|count| **count > 0).count(),
+            obstacles: self.footprints.len(),
+            last_sync_touched_cells: self.last_sync_touched_cells,
+        }
+    }
+
+    /// Drops every stamped obstacle so the next sync rebuilds the grid from scratch.
+    pub fn clear(&mut self) {
+        if self.footprints.is_empty() {
+            return;
+        }
+        self.occupancy.iter_mut().for_each(|count| *count = 0);
+        self.footprints.clear();
+        self.revision = self.revision.wrapping_add(1);
+    }
+
+    pub fn cell_at(&self, position: Vec2) -> Option<(usize, usize)> {
+        let local = (position - self.config.min) / self.config.cell_size;
+        if !local.x.is_finite() || !local.y.is_finite() || local.x < 0.0 || local.y < 0.0 {
+            return None;
+        }
+        let (x, y) = (local.x as usize, local.y as usize);
+        (x < self.cols && y < self.rows).then_some((x, y))
+    }
+
+    pub fn cell_center(&self, cell: (usize, usize)) -> Vec2 {
+        self.config.min + (Vec2::new(cell.0 as f32, cell.1 as f32) + Vec2::splat(0.5)) * self.config.cell_size
+    }
+
+    pub fn cell_bounds(&self, cell: (usize, usize)) -> (Vec2, Vec2) {
+        let min = self.config.min + Vec2::new(cell.0 as f32, cell.1 as f32) * self.config.cell_size;
+        (min, min + Vec2::splat(self.config.cell_size))
+    }
+
+    pub fn is_blocked(&self, cell: (usize, usize)) -> bool {
+        self.occupancy[cell.1 * self.cols + cell.0] > 0
+    }
+
+    pub fn is_position_blocked(&self, position: Vec2) -> bool {
+        self.cell_at(position).is_none_or(|cell| self.is_blocked(cell))
+    }
+
+    pub fn blocked_cell_bounds(&self) -> Vec<(Vec2, Vec2)> {
+        self.occupancy
+            .iter()
+            .enumerate()
+            .filter(|(_, count)| **count > 0)
+            .map(|(index, _)| self.cell_bounds((index % self.cols, index / self.cols)))
+            .collect()
+    }
+
+    /// Re-keys obstacle footprints of respawned entities so the next sync does not restamp them.
+    pub(crate) fn remap_entities(&mut self, map: &HashMap<Entity, Entity>) {
+        let moved: Vec<(Entity, CellRect)> = map
+            .iter()
+            .filter_map(|(old, new)| self.footprints.remove(old).map(|footprint| (*new, footprint)))
+            .collect();
+        self.footprints.extend(moved);
+    }
+
+    /// Reconciles the stamped obstacles with the current collider set. Only obstacles whose
+    /// cell footprint changed (or that disappeared) touch the occupancy grid.
+    pub fn sync_obstacles<I>(&mut self, obstacles: I) -> usize
+    where
+        I: IntoIterator<Item = (Entity, Vec2, Vec2)>,
+    {
+        let mut touched = 0;
+        let mut seen = HashSet::with_capacity(self.footprints.len());
+        for (entity, min, max) in obstacles {
+            seen.insert(entity);
+            let footprint = self.footprint(min, max);
+            let previous = self.footprints.get(&entity).copied();
+            if previous == footprint {
+                continue;
+            }
+            if let Some(old) = previous {
+                touched += self.stamp(old, false);
+                self.footprints.remove(&entity);
+            }
+            if let Some(new) = footprint {
+                touched += self.stamp(new, true);
+                self.footprints.insert(entity, new);
+            }
+        }
+        let stale: Vec<Entity> =
+            self.footprints.keys().filter(|entity| !seen.contains(entity)).copied().collect();
+        for entity in stale {
+            if let Some(old) = self.footprints.remove(&entity) {
+                touched += self.stamp(old, false);
+            }
+        }
+        if touched > 0 {
+            self.revision = self.revision.wrapping_add(1);
+        }
+        self.last_sync_touched_cells = touched;
+        touched
+    }
+
+    fn footprint(&self, min: Vec2, max: Vec2) -> Option<CellRect> {
+        let min = min - Vec2::splat(self.config.clearance);
+        let max = max + Vec2::splat(self.config.clearance);
+        if !min.is_finite() || !max.is_finite() {
+            return None;
+        }
+        if max.x <= self.config.min.x
+            || max.y <= self.config.min.y
+            || min.x >= self.config.max.x
+            || min.y >= self.config.max.y
+        {
+            return None;
+        }
+        let to_cell = |value: f32, origin: f32, limit: usize| {
+            (((value - origin) / self.config.cell_size).floor().max(0.0) as usize).min(limit - 1)
+        };
+        // Shrink the max edge slightly so a collider ending exactly on a cell boundary does not
+        // claim the neighbouring cell.
+        let max = max - Vec2::splat(self.config.cell_size * 1e-3);
+        let min_cell =
+            (to_cell(min.x, self.config.min.x, self.cols), to_cell(min.y, self.config.min.y, self.rows));
+        let max_cell =
+            (to_cell(max.x, self.config.min.x, self.cols), to_cell(max.y, self.config.min.y, self.rows));
+        Some(CellRect { min: min_cell, max: (max_cell.0.max(min_cell.0), max_cell.1.max(min_cell.1)) })
+    }
+
+    fn stamp(&mut self, rect: CellRect, add: bool) -> usize {
+        let mut touched = 0;
+        for (x, y) in rect.cells() {
+            let count = &mut self.occupancy[y * self.cols + x];
+            *count = if add { count.saturating_add(1) } else { count.saturating_sub(1) };
+            touched += 1;
+        }
+        touched
+    }
+
+    /// A* over the 8-connected grid followed by line-of-sight smoothing. The returned path
+    /// starts at `from` and ends at `to`; `None` when either end is off-grid, the goal sits
+    /// inside an obstacle, or no route exists.
+    pub fn find_path(&self, from: Vec2, to: Vec2) -> Option<Vec<Vec2>> {
+        let start = self.cell_at(from)?;
+        let goal = self.cell_at(to)?;
+        if self.is_blocked(goal) {
+            return None;
+        }
+        if start == goal || self.line_of_sight(from, to) {
+            return Some(vec![from, to]);
+        }
+        let cells = self.search(start, goal)?;
+        let mut points = Vec::with_capacity(cells.len() + 1);
+        points.push(from);
+        points.extend(
+            cells.iter().skip(1).take(cells.len().saturating_sub(2)).map(|cell| self.cell_center(*cell)),
+        );
+        points.push(to);
+        Some(self.smooth(points))
+    }
+
+    fn search(&self, start: (usize, usize), goal: (usize, usize)) -> Option<Vec<(usize, usize)>> {
+        let index_of = |cell: (usize, usize)| cell.1 * self.cols + cell.0;
+        let heuristic = |cell: (usize, usize)| {
+            let dx = cell.0.abs_diff(goal.0) as f32;
+            let dy = cell.1.abs_diff(goal.1) as f32;
+            dx.max(dy) + (SQRT_2 - 1.0) * dx.min(dy)
+        };
+        let total = self.cols * self.rows;
+        let mut g_score = vec![f32::INFINITY; total];
+        let mut came_from = vec![usize::MAX; total];
+        let mut closed = vec![false; total];
+        let mut open = BinaryHeap::new();
+        let start_index = index_of(start);
+        let goal_index = index_of(goal);
+        g_score[start_index] = 0.0;
+        open.push(OpenNode { cost: heuristic(start), index: start_index });
+
+        while let Some(OpenNode { index, .. }) = open.pop() {
+            if index == goal_index {
+                let mut path = vec![goal];
+                let mut cursor = index;
+                while cursor != start_index {
+                    cursor = came_from[cursor];
+                    path.push((cursor % self.cols, cursor / self.cols));
+                }
+                path.reverse();
+                return Some(path);
+            }
+            if closed[index] {
+                continue;
+            }
+            closed[index] = true;
+            let cell = (index % self.cols, index / self.cols);
+            for (dx, dy) in NEIGHBOR_OFFSETS {
+                let Some(next) = self.offset_cell(cell, dx, dy) else {
+                    continue;
+                };
+                if self.is_blocked(next) {
+                    continue;
+                }
+                let diagonal = dx != 0 && dy != 0;
+                if diagonal {
+                    // Disallow squeezing between two obstacles that only touch at a corner.
+                    let side_a = self.offset_cell(cell, dx, 0);
+                    let side_b = self.offset_cell(cell, 0, dy);
+                    if side_a.is_none_or(|c| self.is_blocked(c)) || side_b.is_none_or(|c| self.is_blocked(c))
+                    {
+                        continue;
+                    }
+                }
+                let next_index = index_of(next);
+                if closed[next_index] {
+                    continue;
+                }
+                let tentative = g_score[index] + if diagonal { SQRT_2 } else { 1.0 };
+                if tentative < g_score[next_index] {
+                    g_score[next_index] = tentative;
+                    came_from[next_index] = index;
+                    open.push(OpenNode { cost: tentative + heuristic(next), index: next_index });
+                }
+            }
+        }
+        None
+    }
+
+    fn offset_cell(&self, cell: (usize, usize), dx: i32, dy: i32) -> Option<(usize, usize)> {
+        let x = cell.0.checked_add_signed(dx as isize)?;
+        let y = cell.1.checked_add_signed(dy as isize)?;
+        (x < self.cols && y < self.rows).then_some((x, y))
+    }
+
+    fn smooth(&self, points: Vec<Vec2>) -> Vec<Vec2> {
+        if points.len() <= 2 {
+            return points;
+        }
+        let mut smoothed = vec![points[0]];
+        let mut anchor = 0;
+        while anchor < points.len() - 1 {
+            let mut next = anchor + 1;
+            for candidate in (anchor + 2..points.len()).rev() {
+                if self.line_of_sight(points[anchor], points[candidate]) {
+                    next = candidate;
+                    break;
+                }
+            }
+            smoothed.push(points[next]);
+            anchor = next;
+        }
+        smoothed
+    }
+
+    /// Samples the segment at quarter-cell spacing and reports whether it avoids blocked cells.
+    pub fn line_of_sight(&self, from: Vec2, to: Vec2) -> bool {
+        let delta = to - from;
+        let length = delta.length();
+        let steps = ((length / (self.config.cell_size * 0.25)).ceil() as usize).max(1);
+        (0..=steps).all(|step| {
+            let point = from + delta * (step as f32 / steps as f32);
+            match self.cell_at(point) {
+                Some(cell) => !self.is_blocked(cell),
+                None => false,
+            }
+        })
+    }
+
+    /// True when every leg of `waypoints`, starting at `from`, is still unobstructed.
+    pub fn path_is_clear(&self, from: Vec2, waypoints: &[Vec2]) -> bool {
+        let mut cursor = from;
+        for point in waypoints {
+            if !self.line_of_sight(cursor, *point) {
+                return false;
+            }
+            cursor = *point;
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wall_grid() -> NavGrid {
+        let mut grid = NavGrid::new(NavGridConfig {
+            min: Vec2::new(-1.0, -1.0),
+            max: Vec2::new(1.0, 1.0),
+            cell_size: 0.1,
+            clearance: 0.0,
+        });
+        grid.sync_obstacles([(Entity::from_raw(1), Vec2::new(-0.05, -0.8), Vec2::new(0.05, 1.0))]);
+        grid
+    }
+
+    #[test]
+    fn path_routes_around_wall_and_is_smoothed() {
+        let grid = wall_grid();
+        let from = Vec2::new(-0.5, 0.5);
+        let to = Vec2::new(0.5, 0.5);
+        let path = grid.find_path(from, to).expect("path around wall");
+        assert_eq!(path.first().copied(), Some(from));
+        assert_eq!(path.last().copied(), Some(to));
+        assert!(path.iter().any(|point| point.y < -0.8), "path should dip under the wall: {path:?}");
+        assert!(path.len() <= 4, "smoothing should collapse the grid staircase: {path:?}");
+        assert!(grid.path_is_clear(from, &path[1..]));
+    }
+
+    #[test]
+    fn moving_an_obstacle_only_touches_its_footprint() {
+        let mut grid = wall_grid();
+        let other = Entity::from_raw(2);
+        let wall = (Entity::from_raw(1), Vec2::new(-0.05, -0.8), Vec2::new(0.05, 1.0));
+        grid.sync_obstacles([wall, (other, Vec2::new(0.52, 0.52), Vec2::new(0.58, 0.58))]);
+        let revision = grid.revision();
+
+        assert_eq!(grid.sync_obstacles([wall, (other, Vec2::new(0.52, 0.52), Vec2::new(0.58, 0.58))]), 0);
+        assert_eq!(grid.revision(), revision, "unchanged obstacles keep the revision");
+
+        let touched = grid.sync_obstacles([wall, (other, Vec2::new(-0.58, 0.52), Vec2::new(-0.52, 0.58))]);
+        assert_eq!(touched, 2, "one cell cleared, one cell stamped");
+        assert!(grid.revision() > revision);
+
+        grid.sync_obstacles([(other, Vec2::new(-0.58, 0.52), Vec2::new(-0.52, 0.58))]);
+        assert!(grid.find_path(Vec2::new(-0.3, 0.5), Vec2::new(0.3, 0.5)).is_some());
+        assert_eq!(grid.stats().blocked_cells, 1);
+    }
+
+    #[test]
+    fn blocked_goal_has_no_path() {
+        let grid = wall_grid();
+        assert!(grid.find_path(Vec2::new(-0.5, 0.0), Vec2::new(0.0, 0.0)).is_none());
+        assert!(grid.find_path(Vec2::new(-0.5, 0.0), Vec2::new(5.0, 0.0)).is_none());
+    }
+}
@@ -2,7 +2,7 @@ use crate::events::{AudioEmitter, GameEvent};
 use crate::plugins::PluginCapability;
 use bevy_ecs::entity::Entity;
 use bincode::Options;
-use glam::Vec3;
+use glam::{Quat, Vec2, Vec3};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::io::{self, Read, Write};
 use std::sync::Arc;
@@ -51,11 +51,16 @@ pub struct RpcAudioEmitter {
 pub enum RpcGameEvent {
     SpriteSpawned { entity: RpcEntity, atlas: String, region: String, audio: Option<RpcAudioEmitter> },
     SpriteAnimationEvent { entity: RpcEntity, timeline: String, event: String },
+    AnimationFinished { entity: RpcEntity, atlas: String, timeline: String },
+    ClipFinished { entity: RpcEntity, clip: String },
     EntityDespawned { entity: RpcEntity },
     CollisionStarted { a: RpcEntity, b: RpcEntity, audio: Option<RpcAudioEmitter> },
     CollisionEnded { a: RpcEntity, b: RpcEntity, audio: Option<RpcAudioEmitter> },
     CollisionForce { a: RpcEntity, b: RpcEntity, force: f32, audio: Option<RpcAudioEmitter> },
     ScriptMessage { message: String },
+    ScriptEvent { name: String, payload: Option<String> },
+    ParticleCollision { emitter: RpcEntity, position: [f32; 2] },
+    BoneTransformResult { handle: i64, position: [f32; 3], rotation: [f32; 4] },
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -259,6 +264,14 @@ impl From<GameEvent> for RpcGameEvent {
                     event: event.as_ref().to_string(),
                 }
             }
+            GameEvent::AnimationFinished { entity, atlas, timeline } => RpcGameEvent::AnimationFinished {
+                entity: entity.into(),
+                atlas: atlas.as_ref().to_string(),
+                timeline: timeline.as_ref().to_string(),
+            },
+            GameEvent::ClipFinished { entity, clip } => {
+                RpcGameEvent::ClipFinished { entity: entity.into(), clip: clip.as_ref().to_string() }
+            }
             GameEvent::EntityDespawned { entity } => RpcGameEvent::EntityDespawned { entity: entity.into() },
             GameEvent::CollisionStarted { a, b, audio } => RpcGameEvent::CollisionStarted {
                 a: a.into(),
@@ -277,6 +290,17 @@ impl From<GameEvent> for RpcGameEvent {
                 audio: audio.map(RpcAudioEmitter::from),
             },
             GameEvent::ScriptMessage { message } => RpcGameEvent::ScriptMessage { message },
+            GameEvent::ScriptEvent { name, payload } => RpcGameEvent::ScriptEvent { name, payload },
+            GameEvent::ParticleCollision { emitter, position } => {
+                RpcGameEvent::ParticleCollision { emitter: emitter.into(), position: position.to_array() }
+            }
+            GameEvent::BoneTransformResult { handle, position, rotation } => {
+                RpcGameEvent::BoneTransformResult {
+                    handle,
+                    position: position.to_array(),
+                    rotation: rotation.to_array(),
+                }
+            }
         }
     }
 }
@@ -297,6 +321,14 @@ impl From<RpcGameEvent> for GameEvent {
                     event: Arc::<str>::from(event),
                 }
             }
+            RpcGameEvent::AnimationFinished { entity, atlas, timeline } => GameEvent::AnimationFinished {
+                entity: entity.into(),
+                atlas: Arc::<str>::from(atlas),
+                timeline: Arc::<str>::from(timeline),
+            },
+            RpcGameEvent::ClipFinished { entity, clip } => {
+                GameEvent::ClipFinished { entity: entity.into(), clip: Arc::<str>::from(clip) }
+            }
             RpcGameEvent::EntityDespawned { entity } => GameEvent::EntityDespawned { entity: entity.into() },
             RpcGameEvent::CollisionStarted { a, b, audio } => {
                 GameEvent::CollisionStarted { a: a.into(), b: b.into(), audio: audio.map(AudioEmitter::from) }
@@ -311,6 +343,17 @@ impl From<RpcGameEvent> for GameEvent {
                 audio: audio.map(AudioEmitter::from),
             },
             RpcGameEvent::ScriptMessage { message } => GameEvent::ScriptMessage { message },
+            RpcGameEvent::ScriptEvent { name, payload } => GameEvent::ScriptEvent { name, payload },
+            RpcGameEvent::ParticleCollision { emitter, position } => {
+                GameEvent::ParticleCollision { emitter: emitter.into(), position: Vec2::from_array(position) }
+            }
+            RpcGameEvent::BoneTransformResult { handle, position, rotation } => {
+                GameEvent::BoneTransformResult {
+                    handle,
+                    position: Vec3::from_array(position),
+                    rotation: Quat::from_array(rotation),
+                }
+            }
         }
     }
 }
@@ -1,7 +1,7 @@
-use crate::assets::AssetManager;
-use crate::ecs::EcsWorld;
+use crate::assets::{AnimationClip, AssetLoader, AssetManager, TextureAtlas};
+use crate::ecs::{ComponentField, ComponentFieldSchema, EcsWorld, PluginComponentRegistry};
 use crate::environment::EnvironmentRegistry;
-use crate::events::GameEvent;
+use crate::events::{EventBus, GameEvent};
 use crate::input::Input;
 use crate::material_registry::MaterialRegistry;
 use crate::mesh_registry::MeshRegistry;
@@ -12,26 +12,34 @@ use crate::plugin_rpc::{
     RpcReadComponentsRequest, RpcReadComponentsResponse, RpcRequestId, RpcResponseData, RpcSnapshotFormat,
     RpcSpriteInfo,
 };
-use crate::renderer::Renderer;
+use crate::renderer::{
+    CompletedReadback, ReadbackError, ReadbackRegion, ReadbackTarget, ReadbackTicket, Renderer,
+};
 use crate::time::Time;
 use anyhow::{anyhow, bail, Context, Result};
-use bevy_ecs::prelude::Entity;
+use bevy_ecs::prelude::{Component, Entity};
 use bitflags::bitflags;
+use glam::Mat4;
+#[cfg(feature = "debug_draw")]
+use glam::{Vec3, Vec4};
 use libloading::Library;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use std::alloc::{self, Layout};
 use std::any::Any;
-use std::cell::{Ref, RefCell, RefMut};
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::cell::{Cell, Ref, RefCell, RefMut};
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
 use std::env;
 use std::fs;
 use std::io::{self, BufReader};
-use std::mem;
-use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::mem::{self, ManuallyDrop};
+use std::panic::{self, catch_unwind, AssertUnwindSafe};
 use std::path::{Path, PathBuf};
 use std::process::{Child, ChildStdin, Command, Stdio};
 use std::ptr;
 use std::rc::Rc;
-use std::sync::{mpsc, Arc};
+use std::sync::{mpsc, Arc, Once};
 use std::time::{Duration, Instant, SystemTime};
 
 const ISOLATED_RPC_TIMEOUT: Duration = Duration::from_secs(10);
@@ -64,6 +72,8 @@ bitflags! {
         const ANALYTICS = 1 << 5;
         const TIME = 1 << 6;
         const EVENTS = 1 << 7;
+        const SCREENSHOT = 1 << 8;
+        const AUDIO = 1 << 9;
     }
 }
 
@@ -78,6 +88,8 @@ pub enum PluginCapability {
     Analytics,
     Time,
     Events,
+    Screenshot,
+    Audio,
     All,
 }
 
@@ -92,6 +104,8 @@ impl PluginCapability {
             PluginCapability::Analytics => CapabilityFlags::ANALYTICS,
             PluginCapability::Time => CapabilityFlags::TIME,
             PluginCapability::Events => CapabilityFlags::EVENTS,
+            PluginCapability::Screenshot => CapabilityFlags::SCREENSHOT,
+            PluginCapability::Audio => CapabilityFlags::AUDIO,
             PluginCapability::All => CapabilityFlags::all(),
         }
     }
@@ -106,6 +120,8 @@ impl PluginCapability {
             PluginCapability::Analytics => "analytics",
             PluginCapability::Time => "time",
             PluginCapability::Events => "events",
+            PluginCapability::Screenshot => "screenshot",
+            PluginCapability::Audio => "audio",
             PluginCapability::All => "all",
         }
     }
@@ -120,6 +136,8 @@ impl PluginCapability {
             "analytics" => Some(PluginCapability::Analytics),
             "time" => Some(PluginCapability::Time),
             "events" => Some(PluginCapability::Events),
+            "screenshot" => Some(PluginCapability::Screenshot),
+            "audio" => Some(PluginCapability::Audio),
             "all" => Some(PluginCapability::All),
             _ => None,
         }
@@ -163,6 +181,7 @@ fn default_capabilities() -> Vec<PluginCapability> {
         PluginCapability::Input,
         PluginCapability::Events,
         PluginCapability::Time,
+        PluginCapability::Audio,
     ]
 }
 
@@ -212,6 +231,15 @@ pub struct PluginWatchdogEvent {
     pub last_request: String,
 }
 
+fn write_screenshot(path: &Path, region: ReadbackRegion, pixels: Vec<u8>) -> Result<()> {
+    let image = image::RgbaImage::from_raw(region.width, region.height, pixels)
+        .context("screenshot pixels do not match the captured region")?;
+    if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+        fs::create_dir_all(parent).with_context(|| format!("creating '{}'", parent.display()))?;
+    }
+    image.save(path).with_context(|| format!("writing '{}'", path.display()))
+}
+
 fn summarize_asset_payload(payload: &RpcAssetReadbackPayload) -> (String, String) {
     match payload {
         RpcAssetReadbackPayload::AtlasMeta { atlas_id } => ("atlas_meta".to_string(), atlas_id.clone()),
@@ -230,6 +258,54 @@ fn describe_panic(payload: Box<dyn Any + Send>) -> String {
     }
 }
 
+thread_local! {
+    static PLUGIN_CALL_DEPTH: Cell<u32> = const { Cell::new(0) };
+    static PLUGIN_PANIC_BACKTRACE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Chains a panic hook that keeps a backtrace of panics raised inside plugin calls, since the
+/// unwind payload caught by `catch_unwind` no longer carries one. Capturing follows
+/// `RUST_BACKTRACE`/`RUST_LIB_BACKTRACE`, so the backtrace is absent when those are unset.
+fn install_plugin_panic_hook() {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if PLUGIN_CALL_DEPTH.with(|depth| depth.get() > 0) {
+                let backtrace = Backtrace::capture();
+                if backtrace.status() == BacktraceStatus::Captured {
+                    PLUGIN_PANIC_BACKTRACE.with(|slot| *slot.borrow_mut() = Some(backtrace.to_string()));
+                }
+            }
+            previous(info);
+        }));
+    });
+}
+
+struct PluginPanic {
+    message: String,
+    backtrace: Option<String>,
+}
+
+/// Runs one plugin entry point, turning a panic into a [`PluginPanic`] instead of unwinding into
+/// the engine.
+fn call_plugin<R>(call: impl FnOnce() -> R) -> std::result::Result<R, PluginPanic> {
+    install_plugin_panic_hook();
+    PLUGIN_PANIC_BACKTRACE.with(|slot| slot.borrow_mut().take());
+    PLUGIN_CALL_DEPTH.with(|depth| depth.set(depth.get() + 1));
+    let result = catch_unwind(AssertUnwindSafe(call));
+    PLUGIN_CALL_DEPTH.with(|depth| depth.set(depth.get() - 1));
+    result.map_err(|payload| PluginPanic {
+        message: describe_panic(payload),
+        backtrace: PLUGIN_PANIC_BACKTRACE.with(|slot| slot.borrow_mut().take()),
+    })
+}
+
+fn hash_plugin_library(path: &Path) -> Option<String> {
+    let bytes = fs::read(path).ok()?;
+    Some(blake3::hash(&bytes).to_hex().to_string())
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 enum AssetCacheKey {
     AtlasMeta(String),
@@ -349,6 +425,12 @@ impl CapabilityTrackerInner {
         self.snapshot = None;
     }
 
+    fn unregister(&mut self, name: &str) {
+        self.metrics.remove(name);
+        self.events.retain(|event| event.plugin != name);
+        self.snapshot = None;
+    }
+
     fn log_violation(&mut self, name: &str, capability: PluginCapability) {
         let timestamp = SystemTime::now();
         let entry = self.metrics.entry(name.to_string()).or_default();
@@ -389,6 +471,10 @@ impl CapabilityTracker {
         self.0.borrow_mut().register(name);
     }
 
+    fn unregister(&self, name: &str) {
+        self.0.borrow_mut().unregister(name);
+    }
+
     fn log_violation(&self, name: &str, capability: PluginCapability) {
         self.0.borrow_mut().log_violation(name, capability);
     }
@@ -439,6 +525,10 @@ impl PluginHandle {
         self.data.is_null() || self.vtable.is_null()
     }
 
+    /// The handle does not own the plugin: dropping it leaks the box. Exactly one of
+    /// [`PluginHandle::into_box`] or [`PluginHandle::release_without_drop`] must reclaim it, and
+    /// dropping the reclaimed box runs the plugin's `Drop` impl, i.e. code from its library.
+    ///
     /// # Safety
     /// Caller must ensure the boxed plugin was allocated by this process and remains valid for the handle lifetime.
     pub unsafe fn from_box(plugin: Box<dyn EnginePlugin>) -> Self {
@@ -463,6 +553,21 @@ impl PluginHandle {
     pub unsafe fn into_box(self) -> Box<dyn EnginePlugin> {
         Box::from_raw(self.into_raw())
     }
+
+    /// Frees the plugin allocation without calling into the plugin: its `Drop` impl is skipped,
+    /// so anything the plugin owns is leaked. Used for quarantined plugins, whose state can no
+    /// longer be trusted after a panic.
+    ///
+    /// # Safety
+    /// Same requirements as [`PluginHandle::into_box`]; the allocation must come from the global
+    /// allocator of this process.
+    pub unsafe fn release_without_drop(self) {
+        let raw = self.into_raw();
+        let layout = Layout::for_value(&*raw);
+        if layout.size() != 0 {
+            alloc::dealloc(raw as *mut u8, layout);
+        }
+    }
 }
 
 pub type PluginEntryFn = unsafe extern "C" fn() -> PluginExport;
@@ -544,6 +649,154 @@ impl FeatureRegistryHandle {
     }
 }
 
+/// Asset categories a plugin can ask the host to hot-reload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AssetKind {
+    Atlas,
+    Clip,
+    Graph,
+    Skeleton,
+    Material,
+}
+
+impl AssetKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            AssetKind::Atlas => "atlas",
+            AssetKind::Clip => "clip",
+            AssetKind::Graph => "graph",
+            AssetKind::Skeleton => "skeleton",
+            AssetKind::Material => "material",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssetReloadRequest {
+    pub key: String,
+    pub kind: AssetKind,
+}
+
+/// Minimum spacing between reload requests for the same asset, so a plugin that reacts to its own
+/// reload cannot keep the host reloading forever.
+pub const ASSET_RELOAD_MIN_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Default)]
+struct AssetReloadQueueInner {
+    pending: Vec<AssetReloadRequest>,
+    last_requested: HashMap<(AssetKind, String), Instant>,
+}
+
+/// Shared queue of plugin-initiated asset reloads, drained by the host once per frame.
+#[derive(Clone, Default)]
+pub struct AssetReloadQueueHandle(Rc<RefCell<AssetReloadQueueInner>>);
+
+impl AssetReloadQueueHandle {
+    pub fn isolated() -> Self {
+        Self::default()
+    }
+
+    /// Queues a reload of `key`, returning `false` when the same asset was already requested within
+    /// [`ASSET_RELOAD_MIN_INTERVAL`] of `now`.
+    pub fn request(&self, key: &str, kind: AssetKind, now: Instant) -> bool {
+        let mut inner = self.0.borrow_mut();
+        let slot = (kind, key.to_string());
+        if inner
+            .last_requested
+            .get(&slot)
+            .is_some_and(|last| now.saturating_duration_since(*last) < ASSET_RELOAD_MIN_INTERVAL)
+        {
+            return false;
+        }
+        inner.last_requested.insert(slot, now);
+        inner.pending.push(AssetReloadRequest { key: key.to_string(), kind });
+        true
+    }
+
+    pub fn drain(&self) -> Vec<AssetReloadRequest> {
+        let mut inner = self.0.borrow_mut();
+        let now = Instant::now();
+        inner
+            .last_requested
+            .retain(|_, last| now.saturating_duration_since(*last) < ASSET_RELOAD_MIN_INTERVAL);
+        mem::take(&mut inner.pending)
+    }
+}
+
+/// Output paths of plugin screenshots keyed by the readback that captures them.
+#[derive(Clone, Default)]
+pub struct ScreenshotRequestsHandle(Rc<RefCell<HashMap<ReadbackTicket, PathBuf>>>);
+
+impl ScreenshotRequestsHandle {
+    pub fn isolated() -> Self {
+        Self::default()
+    }
+
+    fn insert(&self, ticket: ReadbackTicket, path: PathBuf) {
+        self.0.borrow_mut().insert(ticket, path);
+    }
+
+    fn take(&self, ticket: ReadbackTicket) -> Option<PathBuf> {
+        self.0.borrow_mut().remove(&ticket)
+    }
+}
+
+/// Audio settings plugins asked for through [`PluginContext::audio_mut`]; the host applies them to
+/// the audio plugin once per frame.
+#[derive(Debug, Default)]
+pub struct AudioRequests {
+    voice_count: Option<usize>,
+}
+
+impl AudioRequests {
+    /// Requests a new polyphony limit; the latest request in a frame wins.
+    pub fn set_voice_count(&mut self, count: usize) {
+        self.voice_count = Some(count);
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct AudioRequestsHandle(Rc<RefCell<AudioRequests>>);
+
+impl AudioRequestsHandle {
+    pub fn isolated() -> Self {
+        Self::default()
+    }
+
+    pub fn take_voice_count(&self) -> Option<usize> {
+        self.0.borrow_mut().voice_count.take()
+    }
+}
+
+/// Scene-level custom metadata plugins read and write through
+/// [`PluginContext::set_scene_custom_metadata`]; the host copies it into
+/// [`SceneMetadata::custom`](crate::scene::SceneMetadata::custom) on save and back on load.
+#[derive(Clone, Default)]
+pub struct SceneCustomMetadataHandle(Rc<RefCell<serde_json::Map<String, serde_json::Value>>>);
+
+impl SceneCustomMetadataHandle {
+    pub fn isolated() -> Self {
+        Self::default()
+    }
+
+    pub fn snapshot(&self) -> serde_json::Map<String, serde_json::Value> {
+        self.0.borrow().clone()
+    }
+
+    /// Replaces every stored value, e.g. with the `custom` block of a freshly loaded scene.
+    pub fn replace(&self, values: serde_json::Map<String, serde_json::Value>) {
+        *self.0.borrow_mut() = values;
+    }
+
+    fn get(&self, key: &str) -> Option<Ref<'_, serde_json::Value>> {
+        Ref::filter_map(self.0.borrow(), |values| values.get(key)).ok()
+    }
+
+    fn insert(&self, key: &str, value: serde_json::Value) {
+        self.0.borrow_mut().insert(key.to_string(), value);
+    }
+}
+
 pub struct PluginContext<'a> {
     renderer: &'a mut Renderer,
     ecs: &'a mut EcsWorld,
@@ -558,8 +811,14 @@ pub struct PluginContext<'a> {
     emit_event: fn(&mut EcsWorld, GameEvent),
     active_capabilities: CapabilityFlags,
     active_trust: PluginTrust,
+    active_dynamic: bool,
     active_plugin: Option<String>,
+    active_config: Option<Arc<serde_json::Value>>,
     capability_tracker: CapabilityTracker,
+    asset_reloads: Option<AssetReloadQueueHandle>,
+    screenshots: ScreenshotRequestsHandle,
+    audio: AudioRequestsHandle,
+    scene_custom: SceneCustomMetadataHandle,
 }
 
 impl<'a> PluginContext<'a> {
@@ -592,11 +851,44 @@ impl<'a> PluginContext<'a> {
             emit_event,
             active_capabilities: CapabilityFlags::all(),
             active_trust: PluginTrust::Full,
+            active_dynamic: false,
             active_plugin: None,
+            active_config: None,
             capability_tracker: capability_tracker.tracker(),
+            asset_reloads: None,
+            screenshots: ScreenshotRequestsHandle::isolated(),
+            audio: AudioRequestsHandle::isolated(),
+            scene_custom: SceneCustomMetadataHandle::isolated(),
         }
     }
 
+    /// Routes [`PluginContext::request_asset_reload`] into `queue`; without it requests fail, since
+    /// nothing would drain them.
+    pub fn with_asset_reload_queue(mut self, queue: AssetReloadQueueHandle) -> Self {
+        self.asset_reloads = Some(queue);
+        self
+    }
+
+    /// Lets the manager that owns `requests` write the PNGs for [`PluginContext::request_screenshot`];
+    /// without it the captured pixels are dropped.
+    pub fn with_screenshot_requests(mut self, requests: ScreenshotRequestsHandle) -> Self {
+        self.screenshots = requests;
+        self
+    }
+
+    /// Routes [`PluginContext::audio_mut`] requests into `requests`; without it they are dropped.
+    pub fn with_audio_requests(mut self, requests: AudioRequestsHandle) -> Self {
+        self.audio = requests;
+        self
+    }
+
+    /// Shares scene custom metadata with the host that owns `metadata`; without it values set by
+    /// plugins never reach a saved scene.
+    pub fn with_scene_custom_metadata(mut self, metadata: SceneCustomMetadataHandle) -> Self {
+        self.scene_custom = metadata;
+        self
+    }
+
     pub fn features(&self) -> Ref<'_, FeatureRegistry> {
         self.feature_registry.borrow()
     }
@@ -686,6 +978,11 @@ impl<'a> PluginContext<'a> {
         Ok(&mut *self.environment_registry)
     }
 
+    pub fn audio_mut(&mut self) -> Result<RefMut<'_, AudioRequests>, CapabilityError> {
+        self.require_capability(PluginCapability::Audio)?;
+        Ok(self.audio.0.borrow_mut())
+    }
+
     pub fn time(&self) -> Result<&Time, CapabilityError> {
         self.require_capability(PluginCapability::Time)?;
         Ok(self.time)
@@ -695,6 +992,32 @@ impl<'a> PluginContext<'a> {
         self.selected_entity
     }
 
+    /// The `config` object of the running plugin's manifest entry, if it has one.
+    pub fn config(&self) -> Option<&serde_json::Value> {
+        self.active_config.as_deref()
+    }
+
+    /// Deserializes [`PluginContext::config`] into `T`; `Ok(None)` when the entry has no config.
+    pub fn config_as<T: DeserializeOwned>(&self) -> Result<Option<T>> {
+        let Some(config) = self.config() else {
+            return Ok(None);
+        };
+        let plugin = self.active_plugin.as_deref().unwrap_or("<unknown>");
+        T::deserialize(config)
+            .map(Some)
+            .with_context(|| format!("parsing manifest config for plugin '{plugin}'"))
+    }
+
+    /// Reads a value stored in the current scene's custom metadata.
+    pub fn scene_custom_metadata(&self, key: &str) -> Option<Ref<'_, serde_json::Value>> {
+        self.scene_custom.get(key)
+    }
+
+    /// Stores `value` under `key` in the scene's custom metadata; it is written with the next scene save.
+    pub fn set_scene_custom_metadata(&mut self, key: &str, value: serde_json::Value) {
+        self.scene_custom.insert(key, value);
+    }
+
     pub fn emit_event(&mut self, event: GameEvent) -> Result<(), CapabilityError> {
         self.require_capability(PluginCapability::Events)?;
         (self.emit_event)(self.ecs, event);
@@ -705,6 +1028,180 @@ impl<'a> PluginContext<'a> {
         self.emit_event(GameEvent::ScriptMessage { message: message.into() })
     }
 
+    /// Calls `handler` for every `E` published on the event bus, including the typed variant
+    /// structs in [`crate::events`] that [`PluginManager::handle_events`] fans out. The subscription
+    /// belongs to the active plugin: it is only dispatched while the plugin is, and is dropped when
+    /// the plugin is quarantined or unloaded. Dynamic plugins must use [`EnginePlugin::on_events`]
+    /// instead, since a handler would outlive the library that holds its code.
+    pub fn subscribe_event<E: 'static>(&mut self, handler: impl Fn(&E) + Send + 'static) -> Result<()> {
+        self.require_capability(PluginCapability::Events)?;
+        let Some(plugin) = self.active_plugin.as_deref() else {
+            self.ecs.subscribe_event(handler);
+            return Ok(());
+        };
+        if self.active_dynamic {
+            bail!("plugin '{plugin}' is dynamic and cannot subscribe to events; use on_events instead");
+        }
+        self.ecs.world.resource_mut::<EventBus>().subscribe_as(plugin, handler);
+        Ok(())
+    }
+
+    /// Delivers `event` to its subscribers immediately.
+    pub fn publish_event<E: 'static>(&mut self, event: E) -> Result<(), CapabilityError> {
+        self.require_capability(PluginCapability::Events)?;
+        self.ecs.publish_event(event);
+        Ok(())
+    }
+
+    /// Attaches opaque component data to `entity`; see [`EcsWorld::insert_blob`].
+    pub fn insert_blob(
+        &mut self,
+        entity: Entity,
+        name: &str,
+        bytes: impl Into<Vec<u8>>,
+    ) -> Result<bool, CapabilityError> {
+        Ok(self.ecs_mut()?.insert_blob(entity, name, bytes))
+    }
+
+    pub fn blob(&self, entity: Entity, name: &str) -> Result<Option<&[u8]>, CapabilityError> {
+        Ok(self.ecs()?.blob(entity, name))
+    }
+
+    pub fn remove_blob(&mut self, entity: Entity, name: &str) -> Result<Option<Vec<u8>>, CapabilityError> {
+        Ok(self.ecs_mut()?.remove_blob(entity, name))
+    }
+
+    /// Tags `entity` for lookup with [`Self::query_entities_with_tag`]; see
+    /// [`EcsWorld::set_entity_tag`].
+    pub fn set_entity_tag(&mut self, entity: Entity, tag: &str) -> Result<bool, CapabilityError> {
+        Ok(self.ecs_mut()?.set_entity_tag(entity, tag))
+    }
+
+    pub fn remove_entity_tag(&mut self, entity: Entity, tag: &str) -> Result<bool, CapabilityError> {
+        Ok(self.ecs_mut()?.remove_entity_tag(entity, tag))
+    }
+
+    pub fn query_entities_with_tag(&self, tag: &str) -> Result<Vec<Entity>, CapabilityError> {
+        Ok(self.ecs()?.query_entities_with_tag(tag))
+    }
+
+    /// World matrix of every bone on the skeleton `entity`; see
+    /// [`EcsWorld::collect_skeleton_bone_world_transforms`].
+    pub fn skeleton_bone_world_transforms(
+        &self,
+        entity: Entity,
+    ) -> Result<Option<Vec<(String, Mat4)>>, CapabilityError> {
+        Ok(self.ecs()?.collect_skeleton_bone_world_transforms(entity))
+    }
+
+    /// Makes the plugin component `T` visible and editable in the inspector and saved with the
+    /// scene. Returns the scene key, `name` prefixed with the plugin name (`"<plugin>.<name>"`).
+    /// The registration is dropped when the plugin is quarantined or unloaded. Dynamic plugins must
+    /// use [`Self::register_dynamic_component_inspector`], since the field accessors would outlive
+    /// the library that holds their code.
+    pub fn register_component_inspector<T: Component + Default>(
+        &mut self,
+        name: &str,
+        fields: Vec<ComponentField<T>>,
+    ) -> Result<String> {
+        if self.active_dynamic {
+            let plugin = self.active_plugin.as_deref().unwrap_or("engine");
+            bail!("plugin '{plugin}' is dynamic; use register_dynamic_component_inspector instead");
+        }
+        let key = self.component_key(name);
+        self.ecs_mut()?.register_component_inspector(&key, fields);
+        Ok(key)
+    }
+
+    /// Variant of [`Self::register_component_inspector`] for dynamic plugins: the component is the
+    /// JSON object stored in the blob named by the returned key, so no Rust type crosses the ABI.
+    pub fn register_dynamic_component_inspector(
+        &mut self,
+        name: &str,
+        fields: Vec<ComponentFieldSchema>,
+    ) -> Result<String, CapabilityError> {
+        let key = self.component_key(name);
+        self.ecs_mut()?.register_dynamic_component_inspector(&key, fields);
+        Ok(key)
+    }
+
+    fn component_key(&self, name: &str) -> String {
+        format!("{}.{}", self.active_plugin.as_deref().unwrap_or("engine"), name)
+    }
+
+    /// Queues a world-space debug line drawn on top of the next frame.
+    #[cfg(feature = "debug_draw")]
+    pub fn debug_draw_line(&mut self, a: Vec3, b: Vec3, color: Vec4) -> Result<(), CapabilityError> {
+        self.renderer_mut()?.debug_draw_line(a, b, color);
+        Ok(())
+    }
+
+    /// Queues a wireframe axis-aligned box drawn on top of the next frame.
+    #[cfg(feature = "debug_draw")]
+    pub fn debug_draw_aabb(&mut self, min: Vec3, max: Vec3, color: Vec4) -> Result<(), CapabilityError> {
+        self.renderer_mut()?.debug_draw_aabb(min, max, color);
+        Ok(())
+    }
+
+    /// Asks the host to hot-reload `key` from its source on the next frame, e.g. after a plugin
+    /// rewrote the file. Returns `Ok(false)` when the asset was already requested within
+    /// [`ASSET_RELOAD_MIN_INTERVAL`], and an error when the host attached no reload queue.
+    pub fn request_asset_reload(&mut self, key: &str, kind: AssetKind) -> Result<bool> {
+        self.require_capability(PluginCapability::Assets)?;
+        let queue =
+            self.asset_reloads.as_ref().ok_or_else(|| anyhow!("this host does not process asset reloads"))?;
+        Ok(queue.request(key, kind, Instant::now()))
+    }
+
+    /// Queues an asynchronous copy of `region` from `target`. The pixels arrive as tightly packed
+    /// RGBA8 in [`EnginePlugin::on_readback_complete`] on a later frame. Requests over the
+    /// per-plugin in-flight limit or the per-frame byte budget fail immediately and are logged as
+    /// renderer capability events.
+    pub fn request_texture_readback(
+        &mut self,
+        target: ReadbackTarget,
+        region: ReadbackRegion,
+    ) -> Result<ReadbackTicket> {
+        self.require_capability(PluginCapability::Renderer)?;
+        self.queue_readback(PluginCapability::Renderer, |renderer, owner| {
+            renderer.request_texture_readback(owner, target, region)
+        })
+    }
+
+    /// Captures the next rendered frame (as it stands after the sprite pass) to a PNG at `path`.
+    /// The file is written once the readback completes and recorded as a `screenshot` asset
+    /// readback event. Requires `Screenshot`, which plugins only get by declaring it. The capture
+    /// covers the whole frame target at the size it has when the copy is recorded and counts
+    /// against the per-plugin in-flight limit, but not the per-frame byte budget.
+    pub fn request_screenshot(&mut self, path: impl Into<PathBuf>) -> Result<ReadbackTicket> {
+        self.require_capability(PluginCapability::Screenshot)?;
+        let ticket = self.queue_readback(PluginCapability::Screenshot, |renderer, owner| {
+            renderer.request_frame_capture(owner)
+        })?;
+        self.screenshots.insert(ticket, path.into());
+        Ok(ticket)
+    }
+
+    fn queue_readback(
+        &mut self,
+        capability: PluginCapability,
+        request: impl FnOnce(&mut Renderer, &str) -> Result<ReadbackTicket, ReadbackError>,
+    ) -> Result<ReadbackTicket> {
+        let owner = self.active_plugin.clone().unwrap_or_else(|| "engine".to_string());
+        match request(self.renderer, &owner) {
+            Ok(ticket) => Ok(ticket),
+            Err(err) => {
+                if matches!(
+                    err,
+                    ReadbackError::TooManyInFlight { .. } | ReadbackError::FrameBudgetExceeded { .. }
+                ) {
+                    self.capability_tracker.log_violation(&owner, capability);
+                }
+                Err(anyhow!(err))
+            }
+        }
+    }
+
     pub fn renderer_api(&mut self) -> Result<RendererApi<'_>, CapabilityError> {
         let renderer = self.renderer_mut()?;
         Ok(RendererApi { renderer })
@@ -721,8 +1218,22 @@ impl<'a> PluginContext<'a> {
         self.active_trust = trust;
     }
 
+    /// Sets the manifest config [`PluginContext::config`] returns until the next
+    /// [`PluginContext::clear_active_plugin`].
+    pub fn set_active_config(&mut self, config: Option<Arc<serde_json::Value>>) {
+        self.active_config = config;
+    }
+
+    /// Marks the active plugin as loaded from a dynamic library until the next
+    /// [`PluginContext::clear_active_plugin`].
+    pub fn set_active_dynamic(&mut self, dynamic: bool) {
+        self.active_dynamic = dynamic;
+    }
+
     pub fn clear_active_plugin(&mut self) {
         self.active_plugin = None;
+        self.active_dynamic = false;
+        self.active_config = None;
         self.active_capabilities = CapabilityFlags::all();
         self.active_trust = PluginTrust::Full;
     }
@@ -765,6 +1276,20 @@ impl<'a> AssetApi<'a> {
     pub fn release_atlas(&mut self, key: &str) {
         self.assets.release_atlas(key);
     }
+
+    pub fn register_atlas_loader(
+        &mut self,
+        loader: Box<dyn AssetLoader<Asset = TextureAtlas>>,
+    ) -> Result<()> {
+        self.assets.register_atlas_loader(loader)
+    }
+
+    pub fn register_clip_loader(
+        &mut self,
+        loader: Box<dyn AssetLoader<Asset = AnimationClip>>,
+    ) -> Result<()> {
+        self.assets.register_clip_loader(loader)
+    }
 }
 
 #[derive(Debug)]
@@ -818,6 +1343,27 @@ pub trait EnginePlugin: Any + 'static {
         Ok(())
     }
 
+    /// Receives the RGBA8 pixels of a readback requested through
+    /// [`PluginContext::request_texture_readback`]. Failed readbacks are logged and not delivered.
+    fn on_readback_complete(
+        &mut self,
+        _ctx: &mut PluginContext<'_>,
+        _ticket: ReadbackTicket,
+        _data: &[u8],
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    /// Called when the `config` object of the plugin's manifest entry changes while it is loaded.
+    /// [`PluginContext::config`] already returns the new value.
+    fn on_config_changed(
+        &mut self,
+        _ctx: &mut PluginContext<'_>,
+        _config: Option<&serde_json::Value>,
+    ) -> Result<()> {
+        Ok(())
+    }
+
     fn shutdown(&mut self, _ctx: &mut PluginContext<'_>) -> Result<()> {
         Ok(())
     }
@@ -831,6 +1377,17 @@ pub enum PluginState {
     Loaded,
     Disabled(String),
     Failed(String),
+    /// Panicked inside an entry point; skipped until re-enabled or reloaded from a different library.
+    Quarantined(PluginQuarantine),
+}
+
+#[derive(Clone, Debug)]
+pub struct PluginQuarantine {
+    pub reason: String,
+    pub backtrace: Option<String>,
+    /// blake3 hash of the plugin library, for dynamic plugins loaded in-process.
+    pub library_hash: Option<String>,
+    pub timestamp: SystemTime,
 }
 
 #[derive(Clone, Debug)]
@@ -843,6 +1400,10 @@ pub struct PluginStatus {
     pub capabilities: Vec<PluginCapability>,
     pub trust: PluginTrust,
     pub state: PluginState,
+    /// Skipped by `update`, `fixed_update` and `handle_events`; see [`PluginManager::set_plugin_muted`].
+    pub muted: bool,
+    /// See [`PluginManager::set_plugin_soloed`].
+    pub soloed: bool,
 }
 
 pub struct PluginManager {
@@ -862,11 +1423,18 @@ pub struct PluginManager {
     watchdog_events: HashMap<String, VecDeque<PluginWatchdogEvent>>,
     pending_watchdog_events: Vec<PluginWatchdogEvent>,
     watchdog_snapshot: Option<Arc<HashMap<String, Vec<PluginWatchdogEvent>>>>,
+    /// Quarantines of unloaded dynamic plugins, reapplied when the same library is loaded again.
+    remembered_quarantines: HashMap<String, PluginQuarantine>,
+    asset_reloads: AssetReloadQueueHandle,
+    screenshots: ScreenshotRequestsHandle,
+    audio_requests: AudioRequestsHandle,
+    scene_custom: SceneCustomMetadataHandle,
 }
 
 struct PluginSlot {
     name: String,
-    plugin: Box<dyn EnginePlugin>,
+    /// Released by `Drop for PluginSlot`, before `_library` unloads the code behind it.
+    plugin: ManuallyDrop<Box<dyn EnginePlugin>>,
     provides: Vec<String>,
     depends_on: Vec<String>,
     dynamic: bool,
@@ -874,10 +1442,28 @@ struct PluginSlot {
     capabilities: CapabilityFlags,
     capability_list: Vec<PluginCapability>,
     asset_filters: PluginAssetFilters,
-    failed_reason: Option<String>,
+    /// The manifest entry's `config` object, handed to the plugin through [`PluginContext::config`].
+    config: Option<Arc<serde_json::Value>>,
+    quarantine: Option<PluginQuarantine>,
+    muted: bool,
+    soloed: bool,
+    library_hash: Option<String>,
     _library: Option<Library>,
 }
 
+impl Drop for PluginSlot {
+    fn drop(&mut self) {
+        // SAFETY: `plugin` is taken exactly once, here, and never touched again.
+        let plugin = unsafe { ManuallyDrop::take(&mut self.plugin) };
+        if self.quarantine.is_some() && self._library.is_some() {
+            // SAFETY: the box came from `PluginHandle::into_box` for this library, which is still loaded.
+            unsafe { PluginHandle::from_box(plugin).release_without_drop() };
+        } else if let Err(panic) = call_plugin(|| drop(plugin)) {
+            eprintln!("[plugin:{}] drop panicked: {}", self.name, panic.message);
+        }
+    }
+}
+
 impl PluginSlot {
     fn isolated_proxy(&mut self) -> Option<&mut IsolatedPluginProxy> {
         if self.trust != PluginTrust::Isolated {
@@ -906,6 +1492,11 @@ impl Default for PluginManager {
             watchdog_events: HashMap::new(),
             pending_watchdog_events: Vec::new(),
             watchdog_snapshot: None,
+            remembered_quarantines: HashMap::new(),
+            asset_reloads: AssetReloadQueueHandle::default(),
+            screenshots: ScreenshotRequestsHandle::default(),
+            audio_requests: AudioRequestsHandle::default(),
+            scene_custom: SceneCustomMetadataHandle::default(),
         }
     }
 }
@@ -919,6 +1510,26 @@ impl PluginManager {
         CapabilityTrackerHandle::new(self.capability_tracker.clone())
     }
 
+    pub fn asset_reload_handle(&self) -> AssetReloadQueueHandle {
+        self.asset_reloads.clone()
+    }
+
+    pub fn drain_asset_reload_requests(&mut self) -> Vec<AssetReloadRequest> {
+        self.asset_reloads.drain()
+    }
+
+    pub fn screenshot_requests_handle(&self) -> ScreenshotRequestsHandle {
+        self.screenshots.clone()
+    }
+
+    pub fn audio_requests_handle(&self) -> AudioRequestsHandle {
+        self.audio_requests.clone()
+    }
+
+    pub fn scene_custom_metadata_handle(&self) -> SceneCustomMetadataHandle {
+        self.scene_custom.clone()
+    }
+
     pub fn capability_metrics(&self) -> Arc<HashMap<String, CapabilityViolationLog>> {
         self.capability_tracker.snapshot()
     }
@@ -1206,16 +1817,33 @@ impl PluginManager {
         }
     }
 
-    fn mark_plugin_failed(&mut self, idx: usize, reason: String) {
+    /// Pulls a plugin that panicked out of every dispatch loop until [`PluginManager::reenable_plugin`]
+    /// or a reload of a different library, and drops its event subscriptions and component
+    /// registrations. A watchdog event records the panic.
+    fn quarantine_plugin(
+        &mut self,
+        ctx: &mut PluginContext<'_>,
+        idx: usize,
+        reason: String,
+        backtrace: Option<String>,
+    ) {
         if idx >= self.plugins.len() {
             return;
         }
-        if self.plugins[idx].failed_reason.is_some() {
+        if self.plugins[idx].quarantine.is_some() {
             return;
         }
         let plugin_name = self.plugins[idx].name.clone();
-        self.plugins[idx].failed_reason = Some(reason.clone());
-        self.update_status_state(&plugin_name, PluginState::Failed(reason.clone()));
+        ctx.ecs.world.resource_mut::<EventBus>().unsubscribe_owner(&plugin_name);
+        ctx.ecs.world.resource_mut::<PluginComponentRegistry>().unregister_owner(&plugin_name);
+        let quarantine = PluginQuarantine {
+            reason: reason.clone(),
+            backtrace,
+            library_hash: self.plugins[idx].library_hash.clone(),
+            timestamp: SystemTime::now(),
+        };
+        self.plugins[idx].quarantine = Some(quarantine.clone());
+        self.update_status_state(&plugin_name, PluginState::Quarantined(quarantine));
         self.log_watchdog_event(PluginWatchdogEvent {
             plugin: plugin_name,
             timestamp: SystemTime::now(),
@@ -1241,6 +1869,8 @@ impl PluginManager {
                 capabilities: slot.capability_list.clone(),
                 trust: slot.trust,
                 state,
+                muted: slot.muted,
+                soloed: slot.soloed,
             });
         }
     }
@@ -1266,7 +1896,16 @@ impl PluginManager {
     }
 
     pub fn register(&mut self, plugin: Box<dyn EnginePlugin>, ctx: &mut PluginContext<'_>) -> Result<()> {
-        self.insert_plugin(plugin, None, false, Vec::new(), default_capabilities(), PluginTrust::Full, ctx)
+        self.insert_plugin(
+            plugin,
+            None,
+            false,
+            Vec::new(),
+            default_capabilities(),
+            PluginTrust::Full,
+            None,
+            ctx,
+        )
     }
 
     pub fn register_with_features(
@@ -1275,7 +1914,16 @@ impl PluginManager {
         provides: Vec<String>,
         ctx: &mut PluginContext<'_>,
     ) -> Result<()> {
-        self.insert_plugin(plugin, None, false, provides, default_capabilities(), PluginTrust::Full, ctx)
+        self.insert_plugin(
+            plugin,
+            None,
+            false,
+            provides,
+            default_capabilities(),
+            PluginTrust::Full,
+            None,
+            ctx,
+        )
     }
 
     pub fn register_with_capabilities(
@@ -1285,7 +1933,7 @@ impl PluginManager {
         capabilities: Vec<PluginCapability>,
         ctx: &mut PluginContext<'_>,
     ) -> Result<()> {
-        self.insert_plugin(plugin, None, false, provides, capabilities, PluginTrust::Full, ctx)
+        self.insert_plugin(plugin, None, false, provides, capabilities, PluginTrust::Full, None, ctx)
     }
 
     pub fn load_manifest(path: impl AsRef<Path>) -> Result<Option<PluginManifest>> {
@@ -1311,6 +1959,8 @@ impl PluginManager {
                         capabilities: slot.capability_list.clone(),
                         trust: slot.trust,
                         state: PluginState::Loaded,
+                        muted: slot.muted,
+                        soloed: slot.soloed,
                     });
                 }
                 continue;
@@ -1327,6 +1977,8 @@ impl PluginManager {
                     capabilities: entry_caps.clone(),
                     trust: entry_trust,
                     state: PluginState::Disabled("disabled in manifest".to_string()),
+                    muted: false,
+                    soloed: false,
                 });
                 continue;
             }
@@ -1340,6 +1992,8 @@ impl PluginManager {
                     capabilities: entry_caps.clone(),
                     trust: entry_trust,
                     state: PluginState::Failed("missing plugin path".to_string()),
+                    muted: false,
+                    soloed: false,
                 });
                 continue;
             }
@@ -1359,12 +2013,39 @@ impl PluginManager {
                     capabilities: entry_caps.clone(),
                     trust: entry_trust,
                     state: PluginState::Disabled(msg.clone()),
+                    muted: false,
+                    soloed: false,
                 });
                 eprintln!("[plugin:{}] {msg}", entry.name);
                 continue;
             }
+            let library_hash = hash_plugin_library(&plugin_path);
+            if let Some(quarantine) = self.remembered_quarantine(&entry.name, library_hash.as_deref()) {
+                eprintln!(
+                    "[plugin:{}] library unchanged since it panicked; keeping it quarantined",
+                    entry.name
+                );
+                self.push_status(PluginStatus {
+                    name: entry.name.clone(),
+                    version: entry.version.clone(),
+                    dynamic: true,
+                    provides: entry.provides_features.clone(),
+                    depends_on: Vec::new(),
+                    capabilities: entry_caps.clone(),
+                    trust: entry_trust,
+                    state: PluginState::Quarantined(quarantine),
+                    muted: false,
+                    soloed: false,
+                });
+                continue;
+            }
             match self.load_entry(entry, plugin_path, ctx) {
-                Ok(name) => loaded.push(name),
+                Ok(name) => {
+                    if let Some(slot) = self.plugins.iter_mut().find(|slot| slot.name == name) {
+                        slot.library_hash = library_hash;
+                    }
+                    loaded.push(name);
+                }
                 Err(err) => {
                     self.push_status(PluginStatus {
                         name: entry.name.clone(),
@@ -1375,6 +2056,8 @@ impl PluginManager {
                         capabilities: entry_caps.clone(),
                         trust: entry_trust,
                         state: PluginState::Failed(err.to_string()),
+                        muted: false,
+                        soloed: false,
                     });
                 }
             }
@@ -1382,6 +2065,108 @@ impl PluginManager {
         Ok(loaded)
     }
 
+    /// Returns the remembered quarantine for `plugin_name` when `library_hash` matches the library
+    /// that panicked; a different library clears it.
+    fn remembered_quarantine(
+        &mut self,
+        plugin_name: &str,
+        library_hash: Option<&str>,
+    ) -> Option<PluginQuarantine> {
+        let quarantine = self.remembered_quarantines.get(plugin_name)?;
+        if library_hash.is_some() && quarantine.library_hash.as_deref() == library_hash {
+            return Some(quarantine.clone());
+        }
+        self.remembered_quarantines.remove(plugin_name);
+        None
+    }
+
+    /// Lifts the quarantine of a plugin that panicked so it is dispatched again. Event subscriptions
+    /// dropped by the quarantine are not restored. For a dynamic plugin that was unloaded while
+    /// quarantined, the next reload loads its library again.
+    pub fn reenable_plugin(&mut self, plugin_name: &str) -> Result<()> {
+        let remembered = self.remembered_quarantines.remove(plugin_name).is_some();
+        if let Some(slot) = self.plugins.iter_mut().find(|slot| slot.name == plugin_name) {
+            if slot.quarantine.take().is_none() {
+                bail!("plugin '{plugin_name}' is not quarantined");
+            }
+            self.update_status_state(plugin_name, PluginState::Loaded);
+            return Ok(());
+        }
+        if !remembered {
+            bail!("plugin '{plugin_name}' is not quarantined");
+        }
+        self.update_status_state(
+            plugin_name,
+            PluginState::Disabled("quarantine lifted; reload plugins to load it".to_string()),
+        );
+        Ok(())
+    }
+
+    /// Stops dispatching `update`, `fixed_update` and events to a loaded plugin without unloading
+    /// it. The plugin keeps its status entry, flagged as muted. Reloading a plugin unmutes it.
+    pub fn set_plugin_muted(&mut self, plugin_name: &str, muted: bool) -> Result<()> {
+        let slot = self
+            .plugins
+            .iter_mut()
+            .find(|slot| slot.name == plugin_name)
+            .ok_or_else(|| anyhow!("plugin '{plugin_name}' is not loaded"))?;
+        slot.muted = muted;
+        self.sync_dispatch_status(plugin_name);
+        Ok(())
+    }
+
+    /// While any plugin is soloed, only soloed plugins are dispatched; the rest stay loaded but
+    /// skipped, as if muted. Reloading a plugin clears its solo flag.
+    pub fn set_plugin_soloed(&mut self, plugin_name: &str, soloed: bool) -> Result<()> {
+        let slot = self
+            .plugins
+            .iter_mut()
+            .find(|slot| slot.name == plugin_name)
+            .ok_or_else(|| anyhow!("plugin '{plugin_name}' is not loaded"))?;
+        slot.soloed = soloed;
+        self.sync_dispatch_status(plugin_name);
+        Ok(())
+    }
+
+    pub fn is_plugin_muted(&self, plugin_name: &str) -> bool {
+        self.plugins.iter().any(|slot| slot.name == plugin_name && slot.muted)
+    }
+
+    pub fn is_plugin_soloed(&self, plugin_name: &str) -> bool {
+        self.plugins.iter().any(|slot| slot.name == plugin_name && slot.soloed)
+    }
+
+    /// Whether `update`, `fixed_update` and events currently reach the plugin, taking quarantine,
+    /// mute and solo into account.
+    pub fn is_plugin_dispatched(&self, plugin_name: &str) -> bool {
+        let solo_active = self.solo_active();
+        self.plugins
+            .iter()
+            .position(|slot| slot.name == plugin_name)
+            .is_some_and(|idx| !self.skips_dispatch(idx, solo_active))
+    }
+
+    fn solo_active(&self) -> bool {
+        self.plugins.iter().any(|slot| slot.soloed)
+    }
+
+    fn skips_dispatch(&self, idx: usize, solo_active: bool) -> bool {
+        let slot = &self.plugins[idx];
+        slot.quarantine.is_some() || slot.muted || (solo_active && !slot.soloed)
+    }
+
+    fn sync_dispatch_status(&mut self, plugin_name: &str) {
+        let Some(slot) = self.plugins.iter().find(|slot| slot.name == plugin_name) else {
+            return;
+        };
+        let (muted, soloed) = (slot.muted, slot.soloed);
+        if let Some(status) = self.statuses.iter_mut().find(|status| status.name == plugin_name) {
+            status.muted = muted;
+            status.soloed = soloed;
+            self.invalidate_status_cache();
+        }
+    }
+
     pub fn record_builtin_disabled(&mut self, name: &str, reason: &str) {
         self.push_status(PluginStatus {
             name: name.to_string(),
@@ -1392,23 +2177,78 @@ impl PluginManager {
             capabilities: default_capabilities(),
             trust: PluginTrust::Full,
             state: PluginState::Disabled(reason.to_string()),
+            muted: false,
+            soloed: false,
         });
     }
 
+    /// Delivers changed manifest `config` objects to loaded dynamic plugins through
+    /// [`EnginePlugin::on_config_changed`] and returns the names of the plugins that were notified.
+    pub fn apply_manifest_config(
+        &mut self,
+        manifest: &PluginManifest,
+        ctx: &mut PluginContext<'_>,
+    ) -> Vec<String> {
+        let mut notified = Vec::new();
+        let mut panicked = Vec::new();
+        for idx in 0..self.plugins.len() {
+            // Isolated plugins receive their config when the host process spawns; edits apply on reload.
+            let slot = &self.plugins[idx];
+            if !slot.dynamic || slot.trust == PluginTrust::Isolated || slot.quarantine.is_some() {
+                continue;
+            }
+            let plugin_name = self.plugins[idx].name.clone();
+            let Some(entry) = manifest.entries().iter().find(|entry| entry.name == plugin_name) else {
+                continue;
+            };
+            if self.plugins[idx].config.as_deref() == entry.config.as_ref() {
+                continue;
+            }
+            let config = entry.config.clone().map(Arc::new);
+            self.plugins[idx].config = config.clone();
+            ctx.set_active_plugin(&plugin_name, self.plugins[idx].capabilities, self.plugins[idx].trust);
+            ctx.set_active_config(config.clone());
+            ctx.set_active_dynamic(true);
+            let result = {
+                let slot = &mut self.plugins[idx];
+                call_plugin(|| slot.plugin.on_config_changed(ctx, config.as_deref()))
+            };
+            match result {
+                Ok(Ok(())) => {}
+                Ok(Err(err)) => eprintln!("[plugin:{plugin_name}] on_config_changed failed: {err:?}"),
+                Err(panic) => {
+                    let summary = format!("on_config_changed panicked: {}", panic.message);
+                    eprintln!("[plugin:{plugin_name}] {summary}");
+                    panicked.push((idx, summary, panic.backtrace));
+                }
+            }
+            ctx.clear_active_plugin();
+            notified.push(plugin_name);
+        }
+        for (idx, reason, backtrace) in panicked {
+            self.quarantine_plugin(ctx, idx, reason, backtrace);
+        }
+        notified
+    }
+
     pub fn update(&mut self, ctx: &mut PluginContext<'_>, dt: f32) {
+        self.dispatch_texture_readbacks(ctx);
         let mut watchdog_events = Vec::new();
         let mut panicked = Vec::new();
+        let solo_active = self.solo_active();
         for idx in 0..self.plugins.len() {
-            if self.plugins[idx].failed_reason.is_some() {
+            if self.skips_dispatch(idx, solo_active) {
                 continue;
             }
             let plugin_name = self.plugins[idx].name.clone();
             let capability_flags = self.plugins[idx].capabilities;
             let trust = self.plugins[idx].trust;
             ctx.set_active_plugin(&plugin_name, capability_flags, trust);
+            ctx.set_active_config(self.plugins[idx].config.clone());
+            ctx.set_active_dynamic(self.plugins[idx].dynamic);
             let result = {
                 let slot = &mut self.plugins[idx];
-                catch_unwind(AssertUnwindSafe(|| slot.plugin.update(ctx, dt)))
+                call_plugin(|| slot.plugin.update(ctx, dt))
             };
             match result {
                 Ok(Ok(())) => {}
@@ -1420,10 +2260,10 @@ impl PluginManager {
                         watchdog_events.push(event);
                     }
                 }
-                Err(payload) => {
-                    let summary = format!("update panicked: {}", describe_panic(payload));
+                Err(panic) => {
+                    let summary = format!("update panicked: {}", panic.message);
                     eprintln!("[plugin:{}] {summary}", plugin_name);
-                    panicked.push((idx, summary));
+                    panicked.push((idx, summary, panic.backtrace));
                 }
             }
             ctx.clear_active_plugin();
@@ -1431,25 +2271,102 @@ impl PluginManager {
         for event in watchdog_events {
             self.log_watchdog_event(event);
         }
-        for (idx, reason) in panicked {
-            self.mark_plugin_failed(idx, reason);
+        for (idx, reason, backtrace) in panicked {
+            self.quarantine_plugin(ctx, idx, reason, backtrace);
+        }
+    }
+
+    /// Delivers finished texture readbacks to their plugins, or writes them out for screenshot
+    /// requests, and records them as asset readback events so they show up alongside RPC readbacks
+    /// in analytics.
+    fn dispatch_texture_readbacks(&mut self, ctx: &mut PluginContext<'_>) {
+        for owner in ctx.renderer.take_throttled_readbacks() {
+            self.asset_metrics.entry(owner).or_default().throttled += 1;
+            self.asset_metrics_snapshot = None;
+        }
+        let mut panicked = Vec::new();
+        for readback in ctx.renderer.take_completed_readbacks() {
+            let CompletedReadback { ticket, owner, target, region, data, elapsed, .. } = readback;
+            let bytes = data.as_ref().map(|pixels| pixels.len() as u64).unwrap_or(0);
+            let stats = self.asset_metrics.entry(owner.clone()).or_default();
+            stats.requests += 1;
+            stats.bytes += bytes;
+            self.asset_metrics_snapshot = None;
+            let screenshot_path = self.screenshots.take(ticket);
+            let (kind, target) = match &screenshot_path {
+                Some(path) => ("screenshot", path.display().to_string()),
+                None => (
+                    "texture",
+                    format!("{target} {}x{}+{}+{}", region.width, region.height, region.x, region.y),
+                ),
+            };
+            self.asset_readback_events.push(PluginAssetReadbackEvent {
+                plugin: owner.clone(),
+                kind: kind.to_string(),
+                target,
+                bytes,
+                duration_ms: elapsed.as_secs_f32() * 1000.0,
+                cache_hit: false,
+                timestamp: SystemTime::now(),
+            });
+            let pixels = match data {
+                Ok(pixels) => pixels,
+                Err(err) => {
+                    eprintln!("[plugin:{owner}] {kind} readback {} failed: {err}", ticket.id());
+                    continue;
+                }
+            };
+            if let Some(path) = screenshot_path {
+                if let Err(err) = write_screenshot(&path, region, pixels) {
+                    eprintln!("[plugin:{owner}] screenshot '{}' failed: {err:?}", path.display());
+                }
+                continue;
+            }
+            let Some(idx) =
+                self.plugins.iter().position(|slot| slot.name == owner && slot.quarantine.is_none())
+            else {
+                continue;
+            };
+            ctx.set_active_plugin(&owner, self.plugins[idx].capabilities, self.plugins[idx].trust);
+            ctx.set_active_config(self.plugins[idx].config.clone());
+            ctx.set_active_dynamic(self.plugins[idx].dynamic);
+            let result = {
+                let slot = &mut self.plugins[idx];
+                call_plugin(|| slot.plugin.on_readback_complete(ctx, ticket, &pixels))
+            };
+            match result {
+                Ok(Ok(())) => {}
+                Ok(Err(err)) => eprintln!("[plugin:{owner}] on_readback_complete failed: {err:?}"),
+                Err(panic) => {
+                    let summary = format!("on_readback_complete panicked: {}", panic.message);
+                    eprintln!("[plugin:{owner}] {summary}");
+                    panicked.push((idx, summary, panic.backtrace));
+                }
+            }
+            ctx.clear_active_plugin();
+        }
+        for (idx, reason, backtrace) in panicked {
+            self.quarantine_plugin(ctx, idx, reason, backtrace);
         }
     }
 
     pub fn fixed_update(&mut self, ctx: &mut PluginContext<'_>, dt: f32) {
         let mut watchdog_events = Vec::new();
         let mut panicked = Vec::new();
+        let solo_active = self.solo_active();
         for idx in 0..self.plugins.len() {
-            if self.plugins[idx].failed_reason.is_some() {
+            if self.skips_dispatch(idx, solo_active) {
                 continue;
             }
             let plugin_name = self.plugins[idx].name.clone();
             let capability_flags = self.plugins[idx].capabilities;
             let trust = self.plugins[idx].trust;
             ctx.set_active_plugin(&plugin_name, capability_flags, trust);
+            ctx.set_active_config(self.plugins[idx].config.clone());
+            ctx.set_active_dynamic(self.plugins[idx].dynamic);
             let result = {
                 let slot = &mut self.plugins[idx];
-                catch_unwind(AssertUnwindSafe(|| slot.plugin.fixed_update(ctx, dt)))
+                call_plugin(|| slot.plugin.fixed_update(ctx, dt))
             };
             match result {
                 Ok(Ok(())) => {}
@@ -1461,10 +2378,10 @@ impl PluginManager {
                         watchdog_events.push(event);
                     }
                 }
-                Err(payload) => {
-                    let summary = format!("fixed_update panicked: {}", describe_panic(payload));
+                Err(panic) => {
+                    let summary = format!("fixed_update panicked: {}", panic.message);
                     eprintln!("[plugin:{}] {summary}", plugin_name);
-                    panicked.push((idx, summary));
+                    panicked.push((idx, summary, panic.backtrace));
                 }
             }
             ctx.clear_active_plugin();
@@ -1472,8 +2389,8 @@ impl PluginManager {
         for event in watchdog_events {
             self.log_watchdog_event(event);
         }
-        for (idx, reason) in panicked {
-            self.mark_plugin_failed(idx, reason);
+        for (idx, reason, backtrace) in panicked {
+            self.quarantine_plugin(ctx, idx, reason, backtrace);
         }
     }
 
@@ -1481,19 +2398,34 @@ impl PluginManager {
         if events.is_empty() {
             return;
         }
+        {
+            let bus = ctx.ecs.world.resource::<EventBus>();
+            for event in events {
+                bus.dispatch(event);
+            }
+        }
         let mut watchdog_events = Vec::new();
         let mut panicked = Vec::new();
+        let solo_active = self.solo_active();
         for idx in 0..self.plugins.len() {
-            if self.plugins[idx].failed_reason.is_some() {
+            if self.skips_dispatch(idx, solo_active) {
                 continue;
             }
             let plugin_name = self.plugins[idx].name.clone();
             let capability_flags = self.plugins[idx].capabilities;
             let trust = self.plugins[idx].trust;
             ctx.set_active_plugin(&plugin_name, capability_flags, trust);
+            ctx.set_active_config(self.plugins[idx].config.clone());
+            ctx.set_active_dynamic(self.plugins[idx].dynamic);
             let result = {
                 let slot = &mut self.plugins[idx];
-                catch_unwind(AssertUnwindSafe(|| slot.plugin.on_events(ctx, events)))
+                call_plugin(|| {
+                    let bus = ctx.ecs.world.resource::<EventBus>();
+                    for event in events {
+                        bus.dispatch_to(&slot.name, event);
+                    }
+                    slot.plugin.on_events(ctx, events)
+                })
             };
             match result {
                 Ok(Ok(())) => {}
@@ -1505,10 +2437,10 @@ impl PluginManager {
                         watchdog_events.push(event);
                     }
                 }
-                Err(payload) => {
-                    let summary = format!("event hook panicked: {}", describe_panic(payload));
+                Err(panic) => {
+                    let summary = format!("event hook panicked: {}", panic.message);
                     eprintln!("[plugin:{}] {summary}", plugin_name);
-                    panicked.push((idx, summary));
+                    panicked.push((idx, summary, panic.backtrace));
                 }
             }
             ctx.clear_active_plugin();
@@ -1516,19 +2448,23 @@ impl PluginManager {
         for event in watchdog_events {
             self.log_watchdog_event(event);
         }
-        for (idx, reason) in panicked {
-            self.mark_plugin_failed(idx, reason);
+        for (idx, reason, backtrace) in panicked {
+            self.quarantine_plugin(ctx, idx, reason, backtrace);
         }
     }
 
     pub fn shutdown(&mut self, ctx: &mut PluginContext<'_>) {
         for slot in &mut self.plugins {
-            if slot.failed_reason.is_some() {
+            if slot.quarantine.is_some() {
                 continue;
             }
             ctx.set_active_plugin(&slot.name, slot.capabilities, slot.trust);
-            if let Err(err) = slot.plugin.shutdown(ctx) {
-                eprintln!("[plugin:{}] shutdown failed: {err:?}", slot.name);
+            ctx.set_active_config(slot.config.clone());
+            ctx.set_active_dynamic(slot.dynamic);
+            match call_plugin(|| slot.plugin.shutdown(ctx)) {
+                Ok(Ok(())) => {}
+                Ok(Err(err)) => eprintln!("[plugin:{}] shutdown failed: {err:?}", slot.name),
+                Err(panic) => eprintln!("[plugin:{}] shutdown panicked: {}", slot.name, panic.message),
             }
             ctx.clear_active_plugin();
         }
@@ -1568,8 +2504,8 @@ impl PluginManager {
         self.plugins.iter().any(|slot| slot.name == name)
     }
 
-    pub fn unload_dynamic(&mut self, ctx: &mut PluginContext<'_>) {
-        self.unload_dynamic_plugins(ctx);
+    pub fn unload_dynamic(&mut self, ctx: &mut PluginContext<'_>) -> Vec<String> {
+        self.unload_dynamic_plugins(ctx)
     }
 
     pub fn clear_dynamic_statuses(&mut self) {
@@ -1577,32 +2513,92 @@ impl PluginManager {
         self.invalidate_status_cache();
     }
 
-    pub fn unload_dynamic_plugins(&mut self, ctx: &mut PluginContext<'_>) {
+    /// Shuts down and drops every dynamic plugin, closing its library, and forgets the capability
+    /// metrics and watchdog history recorded for it. Quarantined plugins are released without
+    /// calling into them, and their quarantine is kept for a later load of the same library.
+    /// Returns the names of plugins whose `shutdown` returned an error or panicked; those are
+    /// unloaded all the same.
+    pub fn unload_dynamic_plugins(&mut self, ctx: &mut PluginContext<'_>) -> Vec<String> {
+        let (shutdown_failures, removed_names) = self.drop_dynamic_plugins(ctx);
+        for name in &removed_names {
+            self.forget_plugin_diagnostics(name);
+        }
+        shutdown_failures
+    }
+
+    /// Unloads every dynamic plugin and loads the enabled entries of `manifest` again. Capability
+    /// metrics and watchdog history carry over for plugins that come back; only those that are no
+    /// longer loaded are forgotten.
+    pub fn reload_dynamic_from_manifest(
+        &mut self,
+        manifest: &PluginManifest,
+        ctx: &mut PluginContext<'_>,
+    ) -> Result<Vec<String>> {
+        let (_, removed_names) = self.drop_dynamic_plugins(ctx);
+        let result = self.load_dynamic_from_manifest(manifest, ctx);
+        let gone: Vec<String> =
+            removed_names.into_iter().filter(|name| !self.loaded_names.contains(name)).collect();
+        for name in &gone {
+            self.forget_plugin_diagnostics(name);
+        }
+        result
+    }
+
+    /// Shared by unload and reload: everything but forgetting diagnostics. Returns the plugins
+    /// whose `shutdown` failed and the names of every plugin dropped.
+    fn drop_dynamic_plugins(&mut self, ctx: &mut PluginContext<'_>) -> (Vec<String>, Vec<String>) {
         if self.plugins.iter().all(|slot| !slot.dynamic) {
             self.clear_dynamic_statuses();
-            return;
+            return (Vec::new(), Vec::new());
         }
 
         let mut removed_features = Vec::new();
+        let mut removed_names = Vec::new();
+        let mut shutdown_failures = Vec::new();
         let mut retained = Vec::with_capacity(self.plugins.len());
         for mut slot in self.plugins.drain(..) {
             if slot.dynamic {
-                ctx.set_active_plugin(&slot.name, slot.capabilities, slot.trust);
-                if let Err(err) = slot.plugin.shutdown(ctx) {
-                    eprintln!("[plugin:{}] shutdown failed during unload: {err:?}", slot.name);
+                if let Some(quarantine) = slot.quarantine.as_ref() {
+                    if quarantine.library_hash.is_some() {
+                        self.remembered_quarantines.insert(slot.name.clone(), quarantine.clone());
+                    }
+                } else {
+                    ctx.set_active_plugin(&slot.name, slot.capabilities, slot.trust);
+                    ctx.set_active_config(slot.config.clone());
+                    ctx.set_active_dynamic(true);
+                    match call_plugin(|| slot.plugin.shutdown(ctx)) {
+                        Ok(Ok(())) => {}
+                        Ok(Err(err)) => {
+                            eprintln!("[plugin:{}] shutdown failed during unload: {err:?}", slot.name);
+                            shutdown_failures.push(slot.name.clone());
+                        }
+                        Err(panic) => {
+                            eprintln!(
+                                "[plugin:{}] shutdown panicked during unload: {}",
+                                slot.name, panic.message
+                            );
+                            shutdown_failures.push(slot.name.clone());
+                        }
+                    }
+                    ctx.clear_active_plugin();
                 }
-                ctx.clear_active_plugin();
                 self.loaded_names.remove(&slot.name);
                 removed_features.extend(slot.provides.clone());
+                removed_names.push(slot.name.clone());
+                // Dropping the slot drops the plugin before the library that holds its code.
             } else {
                 retained.push(slot);
             }
         }
         self.plugins = retained;
+        for name in &removed_names {
+            ctx.ecs.world.resource_mut::<EventBus>().unsubscribe_owner(name);
+            ctx.ecs.world.resource_mut::<PluginComponentRegistry>().unregister_owner(name);
+        }
 
         if removed_features.is_empty() {
             self.clear_dynamic_statuses();
-            return;
+            return (shutdown_failures, removed_names);
         }
 
         let removed_unique: BTreeSet<String> = removed_features.into_iter().collect();
@@ -1622,6 +2618,19 @@ impl PluginManager {
         }
 
         self.clear_dynamic_statuses();
+        (shutdown_failures, removed_names)
+    }
+
+    fn forget_plugin_diagnostics(&mut self, plugin_name: &str) {
+        self.capability_tracker.unregister(plugin_name);
+        self.watchdog_events.remove(plugin_name);
+        self.pending_watchdog_events.retain(|event| event.plugin != plugin_name);
+        self.watchdog_snapshot = None;
+        self.asset_metrics.remove(plugin_name);
+        self.asset_metrics_snapshot = None;
+        self.ecs_query_history.remove(plugin_name);
+        self.ecs_history_snapshot = None;
+        self.last_asset_payload.remove(plugin_name);
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -1633,6 +2642,7 @@ impl PluginManager {
         provides: Vec<String>,
         capabilities: Vec<PluginCapability>,
         trust: PluginTrust,
+        config: Option<Arc<serde_json::Value>>,
         ctx: &mut PluginContext<'_>,
     ) -> Result<()> {
         let name = plugin.name().to_string();
@@ -1642,10 +2652,25 @@ impl PluginManager {
         self.ensure_dependencies(plugin.depends_on(), &name)?;
         let capability_flags = CapabilityFlags::from(capabilities.as_slice());
         self.capability_tracker.register(&name);
+        // Subscriptions left by an earlier instance would otherwise fire twice after a reload.
+        ctx.ecs.world.resource_mut::<EventBus>().unsubscribe_owner(&name);
         ctx.set_active_plugin(&name, capability_flags, trust);
-        let build_result = plugin.build(ctx);
+        ctx.set_active_config(config.clone());
+        ctx.set_active_dynamic(is_dynamic);
+        let build_result = call_plugin(|| plugin.build(ctx));
         ctx.clear_active_plugin();
-        build_result?;
+        match build_result {
+            Ok(result) => result?,
+            Err(panic) => {
+                eprintln!("[plugin:{name}] build panicked: {}", panic.message);
+                if library.is_some() {
+                    // SAFETY: dynamic plugins come from `PluginHandle::into_box` while `library` is loaded.
+                    unsafe { PluginHandle::from_box(plugin).release_without_drop() };
+                }
+                drop(library);
+                bail!("build panicked: {}", panic.message);
+            }
+        }
         let version = plugin.version().to_string();
         let depends = plugin.depends_on().iter().map(|s| s.to_string()).collect::<Vec<_>>();
         {
@@ -1662,10 +2687,12 @@ impl PluginManager {
             capabilities: capabilities.clone(),
             trust,
             state: PluginState::Loaded,
+            muted: false,
+            soloed: false,
         });
         self.plugins.push(PluginSlot {
             name,
-            plugin,
+            plugin: ManuallyDrop::new(plugin),
             provides,
             depends_on: depends,
             dynamic: is_dynamic,
@@ -1673,7 +2700,11 @@ impl PluginManager {
             capabilities: capability_flags,
             capability_list: capabilities,
             asset_filters: PluginAssetFilters::default(),
-            failed_reason: None,
+            config,
+            quarantine: None,
+            muted: false,
+            soloed: false,
+            library_hash: None,
             _library: library,
         });
         Ok(())
@@ -1746,6 +2777,7 @@ impl PluginManager {
             entry.provides_features.clone(),
             entry.capabilities.clone(),
             entry.trust,
+            entry.config.clone().map(Arc::new),
             ctx,
         )?;
         Ok(entry.name.clone())
@@ -1765,6 +2797,7 @@ impl PluginManager {
             entry.provides_features.clone(),
             entry.capabilities.clone(),
             entry.trust,
+            entry.config.clone().map(Arc::new),
             ctx,
         )?;
         if let Some(slot) = self.plugins.iter_mut().find(|slot| slot.name == entry.name) {
@@ -1854,6 +2887,9 @@ impl IsolatedPluginProxy {
         for capability in &entry.capabilities {
             command.arg("--cap").arg(capability.label());
         }
+        if let Some(config) = &entry.config {
+            command.arg("--config").arg(config.to_string());
+        }
         let cwd = env::current_dir().context("resolve working directory for isolated host")?;
         command.current_dir(&cwd);
         let mut child = command
@@ -2303,6 +3339,9 @@ pub struct PluginManifestEntry {
     pub trust: PluginTrust,
     #[serde(default)]
     pub asset_filters: PluginAssetFilters,
+    /// Free-form settings delivered to the plugin through [`PluginContext::config`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config: Option<serde_json::Value>,
 }
 
 fn default_enabled() -> bool {
@@ -2382,6 +3421,44 @@ pub struct ManifestDynamicToggleOutcome {
     pub changed: bool,
 }
 
+/// Replaces the `config` object of a manifest entry; `None` removes it.
+#[derive(Debug, Clone)]
+pub struct ManifestConfigUpdate {
+    pub name: String,
+    pub config: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Default)]
+pub struct ManifestConfigUpdateOutcome {
+    pub updated: Vec<String>,
+    pub missing: Vec<String>,
+    pub changed: bool,
+}
+
+pub fn apply_manifest_config_updates(
+    manifest: &mut PluginManifest,
+    updates: &[ManifestConfigUpdate],
+) -> ManifestConfigUpdateOutcome {
+    let mut outcome = ManifestConfigUpdateOutcome::default();
+    let mut dedup: BTreeMap<&str, &ManifestConfigUpdate> = BTreeMap::new();
+    for update in updates {
+        dedup.insert(update.name.as_str(), update);
+    }
+    for update in dedup.values() {
+        match manifest.entry_mut(&update.name) {
+            Some(entry) => {
+                if entry.config != update.config {
+                    entry.config = update.config.clone();
+                    outcome.changed = true;
+                    outcome.updated.push(update.name.clone());
+                }
+            }
+            None => outcome.missing.push(update.name.clone()),
+        }
+    }
+    outcome
+}
+
 pub fn apply_manifest_dynamic_toggles(
     manifest: &mut PluginManifest,
     toggles: &[ManifestDynamicToggle],
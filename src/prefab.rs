@@ -1,3 +1,6 @@
+use crate::assets::AssetManager;
+use crate::mesh_registry::MeshRegistry;
+use crate::scene::{Scene, SceneEntity, SceneEntityId, SceneReferenceKind};
 use anyhow::{Context, Result};
 use serde_json;
 use std::collections::{BTreeMap, HashMap};
@@ -42,6 +45,11 @@ impl PrefabFormat {
     }
 }
 
+/// Values applied to a prefab's root entity right after it spawns, keyed by name: `position`
+/// (`[x, y]` or `[x, y, z]`), `rotation` (radians), `scale` and `velocity` (`[x, y]`), `tint`
+/// (`[r, g, b]` or `[r, g, b, a]`) and `z_order`. See [`crate::ecs::EcsWorld::apply_prefab_overrides`].
+pub type PrefabOverrides = BTreeMap<String, serde_json::Value>;
+
 #[derive(Debug, Clone)]
 pub struct PrefabDescriptor {
     pub name: String,
@@ -63,6 +71,61 @@ pub struct PrefabStatusMessage {
     pub message: String,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrefabValidationKind {
+    /// The prefab file could not be read or parsed; `target` holds the error.
+    Load,
+    /// A parent link inside the prefab that does not resolve to one of its entities.
+    Entity(SceneReferenceKind),
+    Atlas,
+    AtlasRegion,
+    Clip,
+    Skeleton,
+    SkeletalClip,
+    Mesh,
+}
+
+/// A prefab reference that would fail at instantiation, reported by [`PrefabLibrary::validate_all`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrefabValidationError {
+    pub prefab: String,
+    pub path: PathBuf,
+    pub kind: PrefabValidationKind,
+    /// Entity holding the reference; `None` for [`PrefabValidationKind::Load`].
+    pub source: Option<SceneEntityId>,
+    /// The unresolved key; regions are written as `atlas/region`.
+    pub target: String,
+}
+
+impl std::fmt::Display for PrefabValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let source = self.source.as_ref().map(SceneEntityId::as_str).unwrap_or("<prefab>");
+        let target = &self.target;
+        write!(f, "prefab '{}': ", self.prefab)?;
+        match self.kind {
+            PrefabValidationKind::Load => write!(f, "failed to load ({target})"),
+            PrefabValidationKind::Entity(SceneReferenceKind::ParentIndex) => {
+                write!(f, "entity '{source}' has parent index {target} outside entity list")
+            }
+            PrefabValidationKind::Entity(_) => {
+                write!(f, "entity '{source}' references missing parent '{target}'")
+            }
+            PrefabValidationKind::Atlas => write!(f, "entity '{source}' references missing atlas '{target}'"),
+            PrefabValidationKind::AtlasRegion => {
+                write!(f, "entity '{source}' references missing atlas region '{target}'")
+            }
+            PrefabValidationKind::Clip => write!(f, "entity '{source}' references missing clip '{target}'"),
+            PrefabValidationKind::Skeleton => {
+                write!(f, "entity '{source}' references missing skeleton '{target}'")
+            }
+            PrefabValidationKind::SkeletalClip => {
+                write!(f, "entity '{source}' references missing skeletal clip '{target}'")
+            }
+            PrefabValidationKind::Mesh => write!(f, "entity '{source}' references missing mesh '{target}'"),
+        }
+    }
+}
+
 pub struct PrefabLibrary {
     root: PathBuf,
     entries: Vec<PrefabDescriptor>,
@@ -174,6 +237,43 @@ impl PrefabLibrary {
         self.revision
     }
 
+    /// Loads every prefab in the library and checks its atlas, clip, skeleton and mesh keys against
+    /// the loaded assets, plus its internal parent links. A key the prefab declares as a dependency
+    /// with a path on disk counts as available, since instantiation loads it.
+    pub fn validate_all(
+        &self,
+        assets: &AssetManager,
+        mesh_registry: &MeshRegistry,
+    ) -> Vec<PrefabValidationError> {
+        let mut errors = Vec::new();
+        for entry in &self.entries {
+            let error = |kind, source: Option<&SceneEntityId>, target: String| PrefabValidationError {
+                prefab: entry.name.clone(),
+                path: entry.path.clone(),
+                kind,
+                source: source.cloned(),
+                target,
+            };
+            let scene = match Scene::load_from_path(&entry.path) {
+                Ok(scene) => scene,
+                Err(err) => {
+                    errors.push(error(PrefabValidationKind::Load, None, format!("{err:#}")));
+                    continue;
+                }
+            };
+            for warning in scene.validate_entity_references() {
+                let kind = PrefabValidationKind::Entity(warning.kind);
+                errors.push(error(kind, warning.source.as_ref(), warning.target));
+            }
+            for entity in &scene.entities {
+                for (kind, target) in missing_entity_references(&scene, entity, assets, mesh_registry) {
+                    errors.push(error(kind, Some(&entity.id), target));
+                }
+            }
+        }
+        errors
+    }
+
     fn load_aliases(&self) -> Result<HashMap<String, String>> {
         let path = self.root.join("aliases.json");
         if !path.exists() {
@@ -191,6 +291,62 @@ impl PrefabLibrary {
     }
 }
 
+fn declared_on_disk(path: Option<&str>) -> bool {
+    path.is_some_and(|path| Path::new(path).is_file())
+}
+
+fn missing_entity_references(
+    scene: &Scene,
+    entity: &SceneEntity,
+    assets: &AssetManager,
+    mesh_registry: &MeshRegistry,
+) -> Vec<(PrefabValidationKind, String)> {
+    let deps = &scene.dependencies;
+    let atlas_declared =
+        |key: &str| deps.atlas_dependencies().any(|dep| dep.key() == key && declared_on_disk(dep.path()));
+    let clip_declared =
+        |key: &str| deps.clip_dependencies().any(|dep| dep.key() == key && declared_on_disk(dep.path()));
+    let skeleton_declared =
+        |key: &str| deps.skeleton_dependencies().any(|dep| dep.key() == key && declared_on_disk(dep.path()));
+    let mesh_declared =
+        |key: &str| deps.mesh_dependencies().any(|dep| dep.key() == key && declared_on_disk(dep.path()));
+    let mut missing = Vec::new();
+    if let Some(sprite) = entity.sprite.as_ref() {
+        if assets.has_atlas(&sprite.atlas) {
+            if !assets.atlas_region_exists(&sprite.atlas, &sprite.region) {
+                let region = format!("{}/{}", sprite.atlas, sprite.region);
+                missing.push((PrefabValidationKind::AtlasRegion, region));
+            }
+        } else if !atlas_declared(&sprite.atlas) {
+            missing.push((PrefabValidationKind::Atlas, sprite.atlas.clone()));
+        }
+    }
+    if let Some(clip) = entity.transform_clip.as_ref() {
+        if assets.clip(&clip.clip_key).is_none() && !clip_declared(&clip.clip_key) {
+            missing.push((PrefabValidationKind::Clip, clip.clip_key.clone()));
+        }
+    }
+    if let Some(skeleton) = entity.skeleton.as_ref() {
+        let loaded = assets.skeleton(&skeleton.key).is_some();
+        let declared = skeleton_declared(&skeleton.key);
+        if !loaded && !declared {
+            missing.push((PrefabValidationKind::Skeleton, skeleton.key.clone()));
+        }
+        if let Some(clip) = skeleton.clip.as_ref() {
+            // Skeletal clips are imported alongside their skeleton, so a declared skeleton covers them.
+            if loaded && !declared && assets.skeletal_clip(&clip.clip_key).is_none() {
+                missing.push((PrefabValidationKind::SkeletalClip, clip.clip_key.clone()));
+            }
+        }
+    }
+    if let Some(mesh) = entity.mesh.as_ref() {
+        if !mesh_registry.has(&mesh.key) && !mesh_declared(&mesh.key) {
+            missing.push((PrefabValidationKind::Mesh, mesh.key.clone()));
+        }
+    }
+    missing
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,19 +1,29 @@
+mod auto_exposure;
+#[cfg(feature = "debug_draw")]
+mod debug_draw_pass;
 #[cfg(feature = "editor")]
 mod egui_pass;
 mod light_clusters;
 mod mesh_pass;
+mod readback;
+mod scaled_target;
 mod shadow_pass;
+mod sprite_batching;
 mod sprite_pass;
+mod window_geometry;
 mod window_surface;
 
-use crate::camera3d::Camera3D;
-use crate::config::WindowConfig;
-use crate::ecs::{InstanceData, MeshLightingInfo};
+use crate::camera3d::{Camera3D, Frustum};
+use crate::config::{SpriteColorSpace, SpriteSortMode, WindowConfig};
+use crate::ecs::{InstanceData, MeshLightingInfo, SpriteInstance};
 use crate::environment::EnvironmentGpu;
-use crate::material_registry::MaterialGpu;
+use crate::material_registry::{MaterialGpu, MeshPipelineKey};
 use crate::mesh::{Mesh, MeshBounds, MeshVertex};
-use anyhow::{Context, Result};
-use glam::{Mat4, Vec3, Vec4};
+use anyhow::{anyhow, Context, Result};
+#[cfg(feature = "debug_draw")]
+use glam::Vec4;
+use glam::{Mat4, Vec3};
+use serde::{Deserialize, Serialize};
 #[cfg(feature = "editor")]
 use std::collections::HashMap;
 use std::collections::HashSet;
@@ -28,11 +38,27 @@ use winit::event_loop::ActiveEventLoop;
 use winit::window::Window;
 
 // egui
+use self::auto_exposure::{AutoExposurePass, AUTO_EXPOSURE_READBACK_OWNER, AUTO_EXPOSURE_SAMPLE_SIZE};
+pub use self::auto_exposure::{average_luminance, AutoExposure, AutoExposureSettings};
+#[cfg(feature = "debug_draw")]
+use self::debug_draw_pass::DebugDrawPass;
+#[cfg(feature = "debug_draw")]
+pub use self::debug_draw_pass::{DebugDrawBuffer, DebugPrimitive};
 pub use self::light_clusters::LightClusterMetrics;
 use self::light_clusters::{LightClusterParams, LightClusterPass, LightClusterScratch};
 use self::mesh_pass::{MeshDrawData, MeshFrameData, MeshPass, MeshPipelineResources, PaletteUploadStats};
+use self::readback::ReadbackQueue;
+pub use self::readback::{
+    CompletedReadback, ReadbackError, ReadbackRegion, ReadbackTarget, ReadbackTicket,
+    READBACK_MAX_BYTES_PER_FRAME, READBACK_MAX_IN_FLIGHT_PER_OWNER,
+};
+use self::scaled_target::ScaledTargetPass;
+pub use self::scaled_target::{clamp_render_scale, scaled_render_size, MAX_RENDER_SCALE, MIN_RENDER_SCALE};
 use self::shadow_pass::{ShadowPass, ShadowPassParams};
-use self::sprite_pass::{SpritePass, SpriteUploadStats};
+pub use self::sprite_batching::{SpriteBatchKey, SpriteBatchTextures, SpriteBatcher};
+use self::sprite_pass::SpritePass;
+pub use self::sprite_pass::SpriteUploadStats;
+pub use self::window_geometry::{MonitorBounds, WindowGeometry, WindowPlacement, MIN_WINDOW_LOGICAL_SIZE};
 pub use self::window_surface::SurfaceFrame;
 use self::window_surface::WindowSurface;
 #[cfg(feature = "editor")]
@@ -56,6 +82,8 @@ const GPU_TIMER_READBACK_RING: usize = 3;
 struct PointLightGpu {
     position_radius: [f32; 4],
     color_intensity: [f32; 4],
+    /// x: [`PointLightFalloff::gpu_mode`], yzw unused.
+    params: [u32; 4],
 }
 
 #[repr(C)]
@@ -84,6 +112,22 @@ struct ClusterLightUniform {
     lights: [PointLightGpu; LIGHT_CLUSTER_MAX_LIGHTS],
 }
 
+/// Draw submissions encoded for one frame, counted on the CPU while the passes are recorded so
+/// reading them never waits on the GPU. Triangles cover every pass, shadow cascades included.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RenderStats {
+    pub sprite_draw_calls: u32,
+    pub mesh_draw_calls: u32,
+    pub total_triangles: u64,
+    pub shadow_draw_calls: u32,
+}
+
+impl RenderStats {
+    pub fn draw_calls(&self) -> u32 {
+        self.sprite_draw_calls + self.mesh_draw_calls + self.shadow_draw_calls
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct RenderViewport {
     pub origin: (f32, f32),
@@ -95,6 +139,11 @@ pub struct SpriteBatch {
     pub atlas: Arc<str>,
     pub range: Range<u32>,
     pub view: Arc<wgpu::TextureView>,
+    /// Normal map sampled alongside the atlas to light the batch from the scene's directional light.
+    pub normal_view: Option<Arc<wgpu::TextureView>>,
+    /// False when the batch contents are known to match the previous frame, letting the sprite
+    /// pass reuse the uploaded region for an identical range without comparing instances.
+    pub dirty: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -103,6 +152,67 @@ pub struct GpuPassTiming {
     pub duration_ms: f32,
 }
 
+/// Estimated GPU memory held by uploaded assets, in bytes.
+///
+/// Figures are derived from texture dimensions/formats and buffer sizes rather than
+/// driver queries, so they ignore alignment padding and allocator overhead.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct GpuMemoryReport {
+    pub atlas_bytes: u64,
+    pub mesh_bytes: u64,
+    pub material_bytes: u64,
+    pub environment_bytes: u64,
+    /// Atlas and material texture bytes stored in block-compressed (BC) formats.
+    pub compressed_texture_bytes: u64,
+}
+
+impl GpuMemoryReport {
+    pub fn texture_bytes(&self) -> u64 {
+        self.atlas_bytes + self.material_bytes + self.environment_bytes
+    }
+
+    pub fn total_bytes(&self) -> u64 {
+        self.texture_bytes() + self.mesh_bytes
+    }
+}
+
+/// Estimates the bytes backing `texture` across all mips and layers.
+pub fn estimate_texture_bytes(texture: &wgpu::Texture) -> u64 {
+    let format = texture.format();
+    let block_bytes = format.block_copy_size(None).unwrap_or(4) as u64;
+    let (block_w, block_h) = format.block_dimensions();
+    let size = texture.size();
+    let layers = match texture.dimension() {
+        wgpu::TextureDimension::D3 => 1,
+        _ => size.depth_or_array_layers as u64,
+    };
+    let mut total = 0u64;
+    for mip in 0..texture.mip_level_count() {
+        let mip_size = size.mip_level_size(mip, texture.dimension());
+        let blocks_x = mip_size.width.div_ceil(block_w) as u64;
+        let blocks_y = mip_size.height.div_ceil(block_h) as u64;
+        let depth = match texture.dimension() {
+            wgpu::TextureDimension::D3 => mip_size.depth_or_array_layers as u64,
+            _ => 1,
+        };
+        total += blocks_x * blocks_y * depth * block_bytes;
+    }
+    total * layers * texture.sample_count() as u64
+}
+
+/// Reorders collected sprites for `mode`. Both sorts are stable, so sprites that compare equal keep
+/// the order `EcsWorld::collect_sprite_instances` produced; [`SpriteSortMode::CreationOrder`] leaves
+/// the slice untouched.
+pub fn sort_sprite_instances(instances: &mut [SpriteInstance], mode: SpriteSortMode) {
+    match mode {
+        SpriteSortMode::YSort => {
+            instances.sort_by(|a, b| b.transform.translation.y.total_cmp(&a.transform.translation.y))
+        }
+        SpriteSortMode::ZOrder => instances.sort_by_key(|instance| instance.z_order),
+        SpriteSortMode::CreationOrder => {}
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 enum GpuTimestampLabel {
     FrameStart,
@@ -440,6 +550,8 @@ pub struct SceneLightingState {
     pub ambient: Vec3,
     pub exposure: f32,
     pub environment_intensity: f32,
+    /// Horizontal scroll of the environment lookups in turns, see `EnvironmentScroll`.
+    pub environment_offset: f32,
     pub shadow_distance: f32,
     pub shadow_bias: f32,
     pub shadow_strength: f32,
@@ -447,6 +559,8 @@ pub struct SceneLightingState {
     pub shadow_resolution: u32,
     pub shadow_split_lambda: f32,
     pub shadow_pcf_radius: f32,
+    /// When enabled, replaces `exposure` with one adapted to the rendered scene's brightness.
+    pub auto_exposure: AutoExposureSettings,
     pub point_lights: Vec<ScenePointLight>,
 }
 
@@ -458,6 +572,7 @@ impl Default for SceneLightingState {
             ambient: Vec3::splat(0.03),
             exposure: 1.0,
             environment_intensity: 1.0,
+            environment_offset: 0.0,
             shadow_distance: 35.0,
             shadow_bias: 0.002,
             shadow_strength: 1.0,
@@ -465,22 +580,70 @@ impl Default for SceneLightingState {
             shadow_resolution: 2048,
             shadow_split_lambda: 0.6,
             shadow_pcf_radius: 1.25,
+            auto_exposure: AutoExposureSettings::default(),
             point_lights: Vec::new(),
         }
     }
 }
 
+/// How a point light fades from full intensity at its position to zero at its radius.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PointLightFalloff {
+    /// `(1 - d / r)^2`: bright core with a long soft tail, the original point light curve.
+    #[default]
+    InverseSquare,
+    /// Hermite ease from the center to the radius, giving a softer, volumetric-looking core.
+    Smoothstep,
+    /// `1 - d / r`: even fade with a visible edge at the radius.
+    Linear,
+}
+
+impl PointLightFalloff {
+    pub const ALL: [PointLightFalloff; 3] =
+        [PointLightFalloff::InverseSquare, PointLightFalloff::Smoothstep, PointLightFalloff::Linear];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            PointLightFalloff::InverseSquare => "Inverse square",
+            PointLightFalloff::Smoothstep => "Smoothstep",
+            PointLightFalloff::Linear => "Linear",
+        }
+    }
+
+    /// Mode selector read by `point_light_attenuation` in `mesh_basic.wgsl`.
+    pub fn gpu_mode(self) -> u32 {
+        match self {
+            PointLightFalloff::InverseSquare => 0,
+            PointLightFalloff::Smoothstep => 1,
+            PointLightFalloff::Linear => 2,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct ScenePointLight {
     pub position: Vec3,
     pub color: Vec3,
     pub radius: f32,
     pub intensity: f32,
+    pub falloff: PointLightFalloff,
 }
 
 impl ScenePointLight {
     pub fn new(position: Vec3, color: Vec3, radius: f32, intensity: f32) -> Self {
-        Self { position, color, radius: radius.max(0.0), intensity: intensity.max(0.0) }
+        Self {
+            position,
+            color,
+            radius: radius.max(0.0),
+            intensity: intensity.max(0.0),
+            falloff: PointLightFalloff::default(),
+        }
+    }
+
+    pub fn with_falloff(mut self, falloff: PointLightFalloff) -> Self {
+        self.falloff = falloff;
+        self
     }
 }
 
@@ -493,11 +656,25 @@ pub struct Renderer {
     lighting: SceneLightingState,
     environment_state: Option<RendererEnvironmentState>,
     sprite_pass: SpritePass,
+    sprite_sort_mode: SpriteSortMode,
+    render_scale: f32,
+    scaled_target: ScaledTargetPass,
+    auto_exposure: AutoExposure,
+    auto_exposure_pass: AutoExposurePass,
     gpu_timer: GpuTimer,
     skinning_limit_warnings: HashSet<usize>,
     sprite_bind_groups: Vec<(Range<u32>, Arc<wgpu::BindGroup>)>,
+    static_sprite_batches: Vec<SpriteBatch>,
+    static_sprite_bind_groups: Vec<(Range<u32>, Arc<wgpu::BindGroup>)>,
+    pending_static_sprites: Option<Vec<InstanceData>>,
     palette_stats_frame: PaletteUploadStats,
+    render_stats_frame: RenderStats,
     culled_mesh_indices: Vec<usize>,
+    readbacks: ReadbackQueue,
+    #[cfg(feature = "debug_draw")]
+    debug_draw_pass: DebugDrawPass,
+    #[cfg(feature = "debug_draw")]
+    debug_draw: DebugDrawBuffer,
 }
 
 impl Renderer {
@@ -511,14 +688,38 @@ impl Renderer {
             lighting: SceneLightingState::default(),
             environment_state: None,
             sprite_pass: SpritePass::new(),
+            sprite_sort_mode: SpriteSortMode::default(),
+            render_scale: clamp_render_scale(window_cfg.render_scale),
+            scaled_target: ScaledTargetPass::new(),
+            auto_exposure: AutoExposure::new(1.0),
+            auto_exposure_pass: AutoExposurePass::new(),
             gpu_timer: GpuTimer::default(),
             skinning_limit_warnings: HashSet::new(),
             sprite_bind_groups: Vec::new(),
+            static_sprite_batches: Vec::new(),
+            static_sprite_bind_groups: Vec::new(),
+            pending_static_sprites: None,
             palette_stats_frame: PaletteUploadStats::default(),
+            render_stats_frame: RenderStats::default(),
             culled_mesh_indices: Vec::new(),
+            readbacks: ReadbackQueue::new(),
+            #[cfg(feature = "debug_draw")]
+            debug_draw_pass: DebugDrawPass::new(),
+            #[cfg(feature = "debug_draw")]
+            debug_draw: DebugDrawBuffer::default(),
         }
     }
 
+    /// Geometry to restore when the window is first created; see [`WindowGeometry::resolve`].
+    pub fn set_restore_geometry(&mut self, geometry: Option<WindowGeometry>) {
+        self.window_surface.set_restore_geometry(geometry);
+    }
+
+    /// Current window placement, for persisting at the end of a session.
+    pub fn window_geometry(&self) -> Option<WindowGeometry> {
+        self.window_surface.window_geometry()
+    }
+
     pub fn ensure_window(&mut self, event_loop: &ActiveEventLoop) -> Result<()> {
         self.window_surface.ensure_window(event_loop)?;
         if let Ok((device, queue)) = self.window_surface.device_and_queue() {
@@ -584,6 +785,10 @@ impl Renderer {
         self.lighting.environment_intensity = intensity.max(0.0);
     }
 
+    pub fn set_environment_offset(&mut self, offset: f32) {
+        self.lighting.environment_offset = if offset.is_finite() { offset } else { 0.0 };
+    }
+
     pub fn environment_parameters(&self) -> Option<(u32, f32)> {
         self.environment_state.as_ref().map(|state| (state.mip_count, state.intensity))
     }
@@ -602,9 +807,86 @@ impl Renderer {
         sampler: wgpu::Sampler,
     ) -> Result<()> {
         self.sprite_pass.clear_bind_cache();
-        let device = self.window_surface.device()?;
+        let (device, queue) = self.window_surface.device_and_queue()?;
         let format = self.window_surface.surface_format()?;
-        self.sprite_pass.init_pipeline_with_atlas(device, format, atlas_view, sampler)
+        self.sprite_pass.init_pipeline_with_atlas(device, queue, format, atlas_view, sampler)
+    }
+
+    /// Selects the sprite color handling; applied when the sprite pipeline is next initialized.
+    pub fn set_sprite_color_space(&mut self, color_space: SpriteColorSpace) {
+        self.sprite_pass.set_color_space(color_space);
+    }
+
+    pub fn sprite_color_space(&self) -> SpriteColorSpace {
+        self.sprite_pass.color_space()
+    }
+
+    pub fn sprite_color_space_summary(&self) -> String {
+        self.sprite_pass.color_space_summary()
+    }
+
+    /// Selects the sprite draw order the host applies via [`Renderer::sort_sprite_instances`]
+    /// before building sprite batches.
+    pub fn set_sprite_sort_mode(&mut self, mode: SpriteSortMode) {
+        self.sprite_sort_mode = mode;
+    }
+
+    pub fn sprite_sort_mode(&self) -> SpriteSortMode {
+        self.sprite_sort_mode
+    }
+
+    /// Renders the scene at `scale` times the window size (clamped to
+    /// [`MIN_RENDER_SCALE`]..=[`MAX_RENDER_SCALE`]) and stretches it over the swapchain with a
+    /// bilinear blit. Debug lines and the editor UI still draw at native resolution.
+    pub fn set_render_scale(&mut self, scale: f32) {
+        self.render_scale = clamp_render_scale(scale);
+        if !self.renders_offscreen() {
+            self.scaled_target.release();
+        }
+    }
+
+    pub fn render_scale(&self) -> f32 {
+        self.render_scale
+    }
+
+    /// The scene goes through the scaled target when it is resized or sampled for auto exposure.
+    fn renders_offscreen(&self) -> bool {
+        self.render_scale != 1.0 || self.lighting.auto_exposure.enabled
+    }
+
+    /// Exposure the mesh pass uses: the adapted value while auto exposure is enabled, otherwise the
+    /// manual `SceneLightingState::exposure`.
+    pub fn effective_exposure(&self) -> f32 {
+        if self.lighting.auto_exposure.enabled {
+            self.auto_exposure.exposure()
+        } else {
+            self.lighting.exposure
+        }
+    }
+
+    /// Average luminance of the latest auto exposure sample, if one has completed.
+    pub fn auto_exposure_luminance(&self) -> Option<f32> {
+        self.auto_exposure.average_luminance()
+    }
+
+    /// Advances auto exposure by the time since the last frame. Switching it on starts from the
+    /// manual exposure so the image does not jump.
+    fn update_auto_exposure(&mut self) {
+        let settings = self.lighting.auto_exposure;
+        if settings.enabled {
+            self.auto_exposure.advance(&settings);
+        } else {
+            self.auto_exposure.reset(settings.clamp_exposure(self.lighting.exposure));
+        }
+    }
+
+    /// Pixel size of the target the scene is rendered into for the current window size and scale.
+    pub fn render_size(&self) -> PhysicalSize<u32> {
+        scaled_render_size(self.window_surface.size(), self.render_scale)
+    }
+
+    pub fn sort_sprite_instances(&self, instances: &mut [SpriteInstance]) {
+        sort_sprite_instances(instances, self.sprite_sort_mode);
     }
 
     pub fn init_mesh_pipeline(&mut self) -> Result<()> {
@@ -849,41 +1131,44 @@ impl Renderer {
             write_mask: wgpu::ColorWrites::ALL,
         });
 
-        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-            label: Some("Mesh Pipeline"),
-            layout: Some(&pipeline_layout),
-            vertex: wgpu::VertexState {
-                module: &shader,
-                entry_point: Some("vs_main"),
-                buffers: &[mesh_vertex_layout],
-                compilation_options: wgpu::PipelineCompilationOptions::default(),
-            },
-            fragment: Some(wgpu::FragmentState {
-                module: &shader,
-                entry_point: Some("fs_main"),
-                targets: &[color_target],
-                compilation_options: wgpu::PipelineCompilationOptions::default(),
-            }),
-            primitive: wgpu::PrimitiveState {
-                topology: wgpu::PrimitiveTopology::TriangleList,
-                cull_mode: Some(wgpu::Face::Back),
-                front_face: wgpu::FrontFace::Ccw,
-                ..Default::default()
-            },
-            depth_stencil: Some(wgpu::DepthStencilState {
-                format: DEPTH_FORMAT,
-                depth_write_enabled: true,
-                depth_compare: wgpu::CompareFunction::LessEqual,
-                stencil: wgpu::StencilState::default(),
-                bias: wgpu::DepthBiasState::default(),
-            }),
-            multisample: wgpu::MultisampleState::default(),
-            multiview: None,
-            cache: None,
-        });
+        let create_pipeline = |key: MeshPipelineKey| {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("Mesh Pipeline"),
+                layout: Some(&pipeline_layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: Some("vs_main"),
+                    buffers: std::slice::from_ref(&mesh_vertex_layout),
+                    compilation_options: wgpu::PipelineCompilationOptions::default(),
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: Some("fs_main"),
+                    targets: std::slice::from_ref(&color_target),
+                    compilation_options: wgpu::PipelineCompilationOptions::default(),
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleList,
+                    cull_mode: key.cull_mode.face(),
+                    front_face: wgpu::FrontFace::Ccw,
+                    ..Default::default()
+                },
+                depth_stencil: Some(wgpu::DepthStencilState {
+                    format: DEPTH_FORMAT,
+                    depth_write_enabled: true,
+                    depth_compare: wgpu::CompareFunction::LessEqual,
+                    stencil: wgpu::StencilState::default(),
+                    bias: wgpu::DepthBiasState::default(),
+                }),
+                multisample: wgpu::MultisampleState::default(),
+                multiview: None,
+                cache: None,
+            })
+        };
+        let pipelines = MeshPipelineKey::all().map(|key| (key, create_pipeline(key))).collect();
 
         self.mesh_pass.resources = Some(MeshPipelineResources {
-            pipeline,
+            pipelines,
             frame_draw_bgl: frame_draw_bgl.clone(),
             skinning_bgl: skinning_bgl.clone(),
             material_bgl: material_bgl.clone(),
@@ -920,6 +1205,61 @@ impl Renderer {
         })
     }
 
+    /// Uploads several meshes through one staging buffer and a single command submission instead
+    /// of creating and filling every vertex/index buffer separately.
+    pub fn create_gpu_meshes(&self, meshes: &[&Mesh]) -> Result<Vec<GpuMesh>> {
+        if meshes.is_empty() {
+            return Ok(Vec::new());
+        }
+        let (device, queue) = self.device_and_queue()?;
+        let aligned = |len: usize| (len as u64).next_multiple_of(wgpu::COPY_BUFFER_ALIGNMENT);
+        let mut staging_bytes: Vec<u8> = Vec::new();
+        let mut ranges = Vec::with_capacity(meshes.len());
+        for mesh in meshes {
+            let mut push = |bytes: &[u8]| {
+                let offset = staging_bytes.len() as u64;
+                let size = aligned(bytes.len());
+                staging_bytes.extend_from_slice(bytes);
+                staging_bytes.resize((offset + size) as usize, 0);
+                (offset, size)
+            };
+            let vertices = push(bytemuck::cast_slice(&mesh.vertices));
+            let indices = push(bytemuck::cast_slice(&mesh.indices));
+            ranges.push((vertices, indices));
+        }
+        let staging = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Mesh Batch Staging Buffer"),
+            contents: &staging_bytes,
+            usage: wgpu::BufferUsages::COPY_SRC,
+        });
+        let mut encoder = device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor { label: Some("Mesh Batch Upload") });
+        let mut copy_into = |label: &str, usage: wgpu::BufferUsages, (offset, size): (u64, u64)| {
+            let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some(label),
+                size: size.max(wgpu::COPY_BUFFER_ALIGNMENT),
+                usage: usage | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            });
+            if size > 0 {
+                encoder.copy_buffer_to_buffer(&staging, offset, &buffer, 0, size);
+            }
+            buffer
+        };
+        let gpu_meshes = meshes
+            .iter()
+            .zip(ranges)
+            .map(|(mesh, (vertices, indices))| GpuMesh {
+                vertex_buffer: copy_into("Mesh Vertex Buffer", wgpu::BufferUsages::VERTEX, vertices),
+                index_buffer: copy_into("Mesh Index Buffer", wgpu::BufferUsages::INDEX, indices),
+                index_count: mesh.indices.len() as u32,
+                bounds: mesh.bounds.clone(),
+            })
+            .collect();
+        queue.submit(Some(encoder.finish()));
+        Ok(gpu_meshes)
+    }
+
     pub fn encode_mesh_pass(
         &mut self,
         encoder: &mut wgpu::CommandEncoder,
@@ -953,8 +1293,11 @@ impl Renderer {
         let mesh_resources = self.mesh_pass.resources.as_ref().context("Mesh pipeline not initialized")?;
         let frame_draw_layout = mesh_resources.frame_draw_bgl.clone();
         let skinning_layout = mesh_resources.skinning_bgl.clone();
-        let pipeline = mesh_resources.pipeline.clone();
-        let depth_view = self.window_surface.depth_view()?;
+        let pipelines = mesh_resources.pipelines.clone();
+        let (depth_view, target_size) = match self.scaled_target.active_depth() {
+            Some(active) => active,
+            None => (self.window_surface.depth_view()?, self.window_surface.size()),
+        };
         let queue = self.queue()?.clone();
         let skinned_draws = if let Some(indices) = visible_indices {
             indices
@@ -987,10 +1330,10 @@ impl Renderer {
             light_color: [self.lighting.color.x, self.lighting.color.y, self.lighting.color.z, 1.0],
             ambient_color: [self.lighting.ambient.x, self.lighting.ambient.y, self.lighting.ambient.z, 1.0],
             exposure_params: [
-                self.lighting.exposure,
+                self.effective_exposure(),
                 environment_mip_count.max(1) as f32,
                 environment_intensity,
-                0.0,
+                self.lighting.environment_offset,
             ],
             cascade_splits: self.shadow_pass.cascade_splits(),
         };
@@ -1097,14 +1440,12 @@ impl Renderer {
             occlusion_query_set: None,
             timestamp_writes: None,
         });
-        pass.set_pipeline(&pipeline);
         let mut sc_x = viewport.origin.0.max(0.0).floor() as u32;
         let mut sc_y = viewport.origin.1.max(0.0).floor() as u32;
         let mut sc_w = viewport.size.0.max(1.0).floor() as u32;
         let mut sc_h = viewport.size.1.max(1.0).floor() as u32;
-        let surface_size = self.window_surface.size();
-        let limit_w = surface_size.width.max(1);
-        let limit_h = surface_size.height.max(1);
+        let limit_w = target_size.width.max(1);
+        let limit_h = target_size.height.max(1);
         if sc_x >= limit_w {
             sc_x = limit_w.saturating_sub(1);
         }
@@ -1132,6 +1473,7 @@ impl Renderer {
         pass.set_bind_group(5, light_cluster_bind_group, &[]);
 
         self.mesh_pass.skinning_cursor = 0;
+        let mut bound_pipeline = None;
         let identity_cols = Mat4::IDENTITY.to_cols_array();
         let draw_iter: Box<dyn Iterator<Item = &MeshDraw>> = if let Some(indices) = visible_indices {
             Box::new(indices.iter().filter_map(move |&idx| draws.get(idx)))
@@ -1139,6 +1481,12 @@ impl Renderer {
             Box::new(draws.iter())
         };
         for draw in draw_iter {
+            let pipeline_key = draw.material.pipeline_key();
+            if bound_pipeline != Some(pipeline_key) {
+                let pipeline = pipelines.get(&pipeline_key).context("Mesh pipeline variant missing")?;
+                pass.set_pipeline(pipeline);
+                bound_pipeline = Some(pipeline_key);
+            }
             let base_color = draw.lighting.base_color;
             let emissive = draw.lighting.emissive.unwrap_or(Vec3::ZERO);
             let metallic = draw.lighting.metallic.clamp(0.0, 1.0);
@@ -1213,6 +1561,8 @@ impl Renderer {
             pass.set_vertex_buffer(0, draw.mesh.vertex_buffer.slice(..));
             pass.set_index_buffer(draw.mesh.index_buffer.slice(..), wgpu::IndexFormat::Uint32);
             pass.draw_indexed(0..draw.mesh.index_count, 0, 0..1);
+            self.render_stats_frame.mesh_draw_calls += 1;
+            self.render_stats_frame.total_triangles += u64::from(draw.mesh.index_count / 3);
         }
 
         let skinning_cursor = self.mesh_pass.skinning_cursor;
@@ -1276,6 +1626,7 @@ impl Renderer {
             queue: &queue,
             skinning_limit_warnings: &mut self.skinning_limit_warnings,
             palette_stats: &mut self.palette_stats_frame,
+            render_stats: &mut self.render_stats_frame,
         })
     }
 
@@ -1297,6 +1648,49 @@ impl Renderer {
         self.window_surface.window()
     }
 
+    /// True once the GPU device has been lost (driver reset/TDR, adapter removed, or destroyed).
+    /// Frames fail until [`Renderer::recover_device`] succeeds.
+    pub fn device_lost(&self) -> bool {
+        self.window_surface.device_lost()
+    }
+
+    /// Replaces a lost device. Every GPU object the renderer owns is dropped: pipelines, bind
+    /// groups, instance buffers, baked static sprites and the environment binding. GPU timings still
+    /// in flight are discarded and pending readbacks fail. Settings such as lighting, render scale,
+    /// sprite color space and GPU timing stay as they were.
+    ///
+    /// Afterwards the caller re-uploads what it owns against the new device: the sprite pipeline
+    /// (`init_sprite_pipeline_with_atlas`), meshes, materials, environments and any egui renderer.
+    pub fn recover_device(&mut self) -> Result<()> {
+        let color_space = self.sprite_pass.color_space();
+        let timing_requested = self.gpu_timer.requested_enabled;
+        self.mesh_pass = MeshPass::new();
+        self.shadow_pass = ShadowPass::new();
+        self.light_clusters = LightClusterPass::new();
+        self.environment_state = None;
+        self.sprite_pass = SpritePass::new();
+        self.sprite_pass.set_color_space(color_space);
+        self.scaled_target = ScaledTargetPass::new();
+        self.auto_exposure_pass = AutoExposurePass::new();
+        self.auto_exposure.reset(self.auto_exposure.exposure());
+        self.gpu_timer = GpuTimer::default();
+        self.sprite_bind_groups.clear();
+        self.static_sprite_batches.clear();
+        self.static_sprite_bind_groups.clear();
+        self.pending_static_sprites = None;
+        self.readbacks.fail_all("GPU device lost");
+        #[cfg(feature = "debug_draw")]
+        {
+            self.debug_draw_pass = DebugDrawPass::new();
+        }
+        self.window_surface.recreate_device()?;
+        let (device, queue) = self.window_surface.device_and_queue()?;
+        let supported = self.window_surface.gpu_timing_supported();
+        self.gpu_timer.configure(device, queue, supported);
+        self.gpu_timer.set_enabled(timing_requested);
+        Ok(())
+    }
+
     #[cfg(test)]
     pub fn resize_invocations_for_test(&self) -> usize {
         self.window_surface.resize_invocations_for_test()
@@ -1306,6 +1700,18 @@ impl Renderer {
         self.window_surface.prepare_headless_render_target()
     }
 
+    pub fn set_offscreen_capture(&mut self, enabled: bool) -> Result<()> {
+        self.window_surface.set_offscreen_capture(enabled)
+    }
+
+    pub fn offscreen_capture(&self) -> bool {
+        self.window_surface.offscreen_capture()
+    }
+
+    pub fn read_offscreen_frame(&self) -> Result<image::RgbaImage> {
+        self.window_surface.read_offscreen_target()
+    }
+
     #[cfg(test)]
     pub fn inject_surface_error_for_test(&mut self, error: wgpu::SurfaceError) {
         self.window_surface.inject_surface_error_for_test(error);
@@ -1335,6 +1741,84 @@ impl Renderer {
         self.sprite_pass.invalidate_bind_group(atlas);
     }
 
+    /// Replaces the baked static sprite set. The instances are uploaded once on the next frame and
+    /// drawn beneath the per-frame batches until the set is baked again or cleared.
+    pub fn bake_static_sprites(&mut self, instances: Vec<InstanceData>, batches: Vec<SpriteBatch>) {
+        self.pending_static_sprites = Some(instances);
+        self.static_sprite_batches = batches;
+    }
+
+    pub fn clear_static_sprites(&mut self) {
+        self.pending_static_sprites = None;
+        self.static_sprite_batches.clear();
+        self.static_sprite_bind_groups.clear();
+        self.sprite_pass.clear_static_instances();
+    }
+
+    pub fn has_static_sprites(&self) -> bool {
+        !self.static_sprite_batches.is_empty()
+    }
+
+    /// Queues a world-space line for the next frame.
+    #[cfg(feature = "debug_draw")]
+    pub fn debug_draw_line(&mut self, a: Vec3, b: Vec3, color: Vec4) {
+        self.debug_draw.line(a, b, color);
+    }
+
+    /// Queues a wireframe box for the next frame.
+    #[cfg(feature = "debug_draw")]
+    pub fn debug_draw_aabb(&mut self, min: Vec3, max: Vec3, color: Vec4) {
+        self.debug_draw.aabb(min, max, color);
+    }
+
+    #[cfg(feature = "debug_draw")]
+    pub fn debug_draw_buffer(&self) -> &DebugDrawBuffer {
+        &self.debug_draw
+    }
+
+    /// Drops the queued debug primitives. Hosts call this once at the start of every frame, so
+    /// the queue cannot grow on frames that never reach the debug pass.
+    #[cfg(feature = "debug_draw")]
+    pub fn clear_debug_draw(&mut self) {
+        self.debug_draw.clear();
+    }
+
+    /// Queues an asynchronous copy of `region` from `target` on behalf of `owner`. The copy is
+    /// recorded during the next rendered frame and shows up in [`Self::take_completed_readbacks`]
+    /// once the GPU has finished with it, usually a frame or two later.
+    pub fn request_texture_readback(
+        &mut self,
+        owner: &str,
+        target: ReadbackTarget,
+        region: ReadbackRegion,
+    ) -> Result<ReadbackTicket, ReadbackError> {
+        let target_size = match &target {
+            ReadbackTarget::SpritePass => self.size(),
+            ReadbackTarget::Texture(texture) => PhysicalSize::new(texture.width(), texture.height()),
+        };
+        self.readbacks.request(owner, target, region, target_size)
+    }
+
+    /// Queues a copy of the whole frame target as it stands after the sprite pass. The region is
+    /// taken from the frame target when the copy is recorded, and full-frame captures are exempt
+    /// from the per-frame byte budget so they work at any window size.
+    pub fn request_frame_capture(&mut self, owner: &str) -> Result<ReadbackTicket, ReadbackError> {
+        self.readbacks.request_frame_capture(owner)
+    }
+
+    pub fn readbacks_in_flight_for(&self, owner: &str) -> usize {
+        self.readbacks.in_flight_for(owner)
+    }
+
+    pub fn take_completed_readbacks(&mut self) -> Vec<CompletedReadback> {
+        self.readbacks.take_completed()
+    }
+
+    /// Owners whose readback requests were rejected by a budget since the last call.
+    pub fn take_throttled_readbacks(&mut self) -> Vec<String> {
+        self.readbacks.take_throttled()
+    }
+
     fn trim_skinning_cache(mesh_pass: &mut MeshPass, active_slots: usize) {
         let desired = active_slots.saturating_add(SKINNING_CACHE_HEADROOM);
         if mesh_pass.skinning_palette_buffers.len() > desired {
@@ -1403,56 +1887,16 @@ impl Renderer {
             viewport.size.0.max(1.0).round() as u32,
             viewport.size.1.max(1.0).round() as u32,
         );
-        let view_proj = camera.view_projection(vp_size);
-        let planes = Self::extract_frustum_planes(view_proj);
+        let frustum = camera.frustum(vp_size);
         for (idx, draw) in draws.iter().enumerate() {
-            let (center, radius) = Self::transform_bounds(draw.model, &draw.mesh.bounds);
-            if radius <= 0.0 || Self::sphere_in_frustum(center, radius, &planes) {
+            let (center, radius) = draw.mesh.bounds.world_sphere(draw.model);
+            if radius <= 0.0 || frustum.intersects_sphere(center, radius) {
                 self.culled_mesh_indices.push(idx);
             }
         }
         self.culled_mesh_indices.len()
     }
 
-    fn transform_bounds(model: Mat4, bounds: &MeshBounds) -> (Vec3, f32) {
-        let center = model.transform_point3(bounds.center);
-        let scale_x = model.x_axis.truncate().length();
-        let scale_y = model.y_axis.truncate().length();
-        let scale_z = model.z_axis.truncate().length();
-        let max_scale = scale_x.max(scale_y).max(scale_z).max(0.0001);
-        let radius = bounds.radius * max_scale;
-        (center, radius)
-    }
-
-    fn sphere_in_frustum(center: Vec3, radius: f32, planes: &[Vec4; 6]) -> bool {
-        for plane in planes {
-            let normal = plane.truncate();
-            let distance = normal.dot(center) + plane.w;
-            if distance < -radius {
-                return false;
-            }
-        }
-        true
-    }
-
-    fn extract_frustum_planes(matrix: Mat4) -> [Vec4; 6] {
-        let m = matrix.to_cols_array();
-        let row = |i: usize| Vec4::new(m[i], m[i + 4], m[i + 8], m[i + 12]);
-        let row0 = row(0);
-        let row1 = row(1);
-        let row2 = row(2);
-        let row3 = row(3);
-        let mut planes = [row3 + row0, row3 - row0, row3 + row1, row3 - row1, row3 + row2, row3 - row2];
-        for plane in &mut planes {
-            let normal = plane.truncate();
-            let length = normal.length();
-            if length > 0.0 {
-                *plane /= length;
-            }
-        }
-        planes
-    }
-
     #[allow(clippy::too_many_arguments)]
     pub fn render_frame(
         &mut self,
@@ -1463,25 +1907,54 @@ impl Renderer {
         viewport: RenderViewport,
         mesh_draws: &[MeshDraw],
         mesh_camera: Option<&Camera3D>,
-    ) -> Result<SurfaceFrame> {
+    ) -> Result<(SurfaceFrame, RenderStats)> {
+        if self.device_lost() {
+            return Err(anyhow!("GPU device lost; recover_device must run before the next frame"));
+        }
         self.palette_stats_frame = PaletteUploadStats::default();
+        self.render_stats_frame = RenderStats::default();
         self.light_clusters.reset_metrics();
         let frame = self.window_surface.acquire_surface_frame()?;
         let device = self.device()?.clone();
         let queue = self.queue()?.clone();
-        self.sprite_pass.write_globals(&queue, sprite_view_proj)?;
-        let view = frame.view();
+        self.sprite_pass.write_globals(&queue, sprite_view_proj, self.lighting.direction)?;
+        self.update_auto_exposure();
+        #[cfg(feature = "debug_draw")]
+        let native_viewport = viewport;
+        let (scene_view, viewport, target_size) = if !self.renders_offscreen() {
+            self.scaled_target.release();
+            (None, viewport, self.window_surface.size())
+        } else {
+            let size = self.render_size();
+            let color = self.scaled_target.prepare(&device, self.surface_format()?, size)?;
+            let scale = self.render_scale;
+            let scaled = RenderViewport {
+                origin: (viewport.origin.0 * scale, viewport.origin.1 * scale),
+                size: (viewport.size.0 * scale, viewport.size.1 * scale),
+            };
+            (Some(color), scaled, size)
+        };
+        let view = scene_view.as_ref().unwrap_or(frame.view());
         let encoder_label =
             format!("Frame Encoder (sprites={}, meshes={})", instances.len(), mesh_draws.len());
         let mut encoder = device
             .create_command_encoder(&wgpu::CommandEncoderDescriptor { label: Some(encoder_label.as_str()) });
-        self.sprite_pass.upload_instances(&device, &queue, instances)?;
+        self.sprite_pass.upload_instances(&device, &queue, instances, sprite_batches)?;
+        if let Some(static_instances) = self.pending_static_sprites.take() {
+            self.sprite_pass.upload_static_instances(&device, &queue, &static_instances)?;
+        }
         self.gpu_timer.begin_frame();
         self.gpu_timer.write_timestamp(&mut encoder, GpuTimestampLabel::FrameStart);
 
         self.sprite_bind_groups.clear();
         for batch in sprite_batches {
-            match self.sprite_pass.sprite_bind_group(&device, batch.atlas.as_ref(), &batch.view, sampler) {
+            match self.sprite_pass.sprite_bind_group(
+                &device,
+                batch.atlas.as_ref(),
+                &batch.view,
+                batch.normal_view.as_ref(),
+                sampler,
+            ) {
                 Ok(bind_group) => self.sprite_bind_groups.push((batch.range.clone(), bind_group)),
                 Err(err) => {
                     eprintln!(
@@ -1491,9 +1964,28 @@ impl Renderer {
                 }
             }
         }
+        self.static_sprite_bind_groups.clear();
+        for batch in &self.static_sprite_batches {
+            match self.sprite_pass.sprite_bind_group(
+                &device,
+                batch.atlas.as_ref(),
+                &batch.view,
+                batch.normal_view.as_ref(),
+                sampler,
+            ) {
+                Ok(bind_group) => self.static_sprite_bind_groups.push((batch.range.clone(), bind_group)),
+                Err(err) => {
+                    eprintln!(
+                        "Failed to prepare static sprite bind group for atlas '{}': {err:?}",
+                        batch.atlas.as_ref()
+                    );
+                }
+            }
+        }
 
         let clear_color = wgpu::Color { r: 0.05, g: 0.06, b: 0.1, a: 1.0 };
         let mut sprite_load_op = wgpu::LoadOp::Clear(clear_color);
+        let mut meshes_drawn = false;
         if let Some(camera) = mesh_camera {
             let visible_mesh_count = self.cull_mesh_draw_indices(mesh_draws, camera, viewport);
             if visible_mesh_count > 0 {
@@ -1519,6 +2011,7 @@ impl Renderer {
                 }
                 self.gpu_timer.write_timestamp(&mut encoder, GpuTimestampLabel::MeshEnd);
                 sprite_load_op = wgpu::LoadOp::Load;
+                meshes_drawn = true;
                 self.culled_mesh_indices = mesh_indices_owned;
             }
         }
@@ -1540,17 +2033,111 @@ impl Renderer {
             self.sprite_pass.encode_pass(
                 &mut pass,
                 viewport,
-                self.window_surface.size(),
+                target_size,
                 instances,
                 &self.sprite_bind_groups,
+                &self.static_sprite_bind_groups,
+                &mut self.render_stats_frame,
             )?;
         }
         self.gpu_timer.write_timestamp(&mut encoder, GpuTimestampLabel::SpriteEnd);
+        // Exposure only reaches lit meshes, so frames without them say nothing about it.
+        if let (Some(scene), true) = (scene_view.as_ref(), meshes_drawn) {
+            if self.lighting.auto_exposure.enabled && self.auto_exposure.wants_sample() {
+                self.request_auto_exposure_sample(&device, &mut encoder, scene)?;
+            }
+        }
+        if scene_view.is_some() {
+            self.scaled_target.encode_blit(&mut encoder, frame.view())?;
+        }
+        self.readbacks.encode_copies(&device, &mut encoder, frame.copyable_texture());
+        #[cfg(feature = "debug_draw")]
+        self.encode_debug_draw_pass(
+            &device,
+            &queue,
+            &mut encoder,
+            frame.view(),
+            native_viewport,
+            sprite_view_proj,
+            mesh_camera,
+        )?;
         self.gpu_timer.write_timestamp(&mut encoder, GpuTimestampLabel::FrameEnd);
 
         queue.submit(std::iter::once(encoder.finish()));
+        self.readbacks.map_submitted();
         let _ = device.poll(wgpu::PollType::Poll);
-        Ok(frame)
+        self.readbacks.collect_mapped();
+        self.readbacks.reset_frame_budget();
+        let settings = self.lighting.auto_exposure;
+        for done in self.readbacks.take_completed_for(AUTO_EXPOSURE_READBACK_OWNER) {
+            let pixels = done.data.as_deref().map_err(String::as_str);
+            self.auto_exposure.sample_completed(&settings, done.ticket, pixels);
+        }
+        Ok((frame, self.render_stats_frame))
+    }
+
+    fn request_auto_exposure_sample(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        scene: &wgpu::TextureView,
+    ) -> Result<()> {
+        let texture = self.auto_exposure_pass.encode(device, encoder, scene)?;
+        let size = PhysicalSize::new(AUTO_EXPOSURE_SAMPLE_SIZE, AUTO_EXPOSURE_SAMPLE_SIZE);
+        let region = ReadbackRegion::new(0, 0, size.width, size.height);
+        let target = ReadbackTarget::Texture(texture);
+        match self.readbacks.request(AUTO_EXPOSURE_READBACK_OWNER, target, region, size) {
+            Ok(ticket) => self.auto_exposure.sample_requested(ticket, self.auto_exposure.exposure()),
+            // A busy frame budget just delays the next sample.
+            Err(ReadbackError::FrameBudgetExceeded { .. }) => {}
+            Err(err) => return Err(anyhow!(err)).context("Auto exposure readback"),
+        }
+        Ok(())
+    }
+
+    /// Draws the queued debug lines on top of the frame. Lines use the mesh camera when one is
+    /// active and the sprite projection otherwise.
+    #[cfg(feature = "debug_draw")]
+    #[allow(clippy::too_many_arguments)]
+    fn encode_debug_draw_pass(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        encoder: &mut wgpu::CommandEncoder,
+        view: &wgpu::TextureView,
+        viewport: RenderViewport,
+        sprite_view_proj: Mat4,
+        mesh_camera: Option<&Camera3D>,
+    ) -> Result<()> {
+        if self.debug_draw.is_empty() {
+            return Ok(());
+        }
+        let view_proj = match mesh_camera {
+            Some(camera) => camera.view_projection(PhysicalSize::new(
+                viewport.size.0.max(1.0).round() as u32,
+                viewport.size.1.max(1.0).round() as u32,
+            )),
+            None => sprite_view_proj,
+        };
+        let surface_format = self.surface_format()?;
+        let vertex_count =
+            self.debug_draw_pass.prepare(device, queue, surface_format, view_proj, &self.debug_draw)?;
+        if vertex_count == 0 {
+            return Ok(());
+        }
+        let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Debug Draw Pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view,
+                depth_slice: None,
+                resolve_target: None,
+                ops: wgpu::Operations { load: wgpu::LoadOp::Load, store: wgpu::StoreOp::Store },
+            })],
+            depth_stencil_attachment: None,
+            occlusion_query_set: None,
+            timestamp_writes: None,
+        });
+        self.debug_draw_pass.encode_pass(&mut pass, viewport, vertex_count)
     }
 
     #[cfg(feature = "editor")]
@@ -1570,6 +2157,16 @@ impl Renderer {
         self.gpu_timer.supported
     }
 
+    /// Highest sampler anisotropy the adapter supports (1 when unsupported or uninitialized).
+    pub fn max_anisotropy(&self) -> u16 {
+        self.window_surface.max_anisotropy()
+    }
+
+    /// Name, driver and backend of the adapter picked at initialization.
+    pub fn adapter_info(&self) -> Option<&wgpu::AdapterInfo> {
+        self.window_surface.adapter_info()
+    }
+
     pub fn set_gpu_timing_enabled(&mut self, enabled: bool) {
         self.gpu_timer.set_enabled(enabled);
     }
@@ -1612,8 +2209,14 @@ mod surface_tests {
 
     #[test]
     fn headless_render_recovers_from_surface_loss() {
-        let window_config =
-            WindowConfig { title: "Headless".into(), width: 64, height: 64, vsync: false, fullscreen: false };
+        let window_config = WindowConfig {
+            title: "Headless".into(),
+            width: 64,
+            height: 64,
+            vsync: false,
+            fullscreen: false,
+            render_scale: 1.0,
+        };
         let mut renderer = block_on(Renderer::new(&window_config));
         block_on(renderer.init_headless_for_test()).expect("init headless");
         let (pipeline_sampler, draw_sampler, atlas_view) = {
@@ -1653,7 +2256,7 @@ mod surface_tests {
         };
 
         let render_once = |renderer: &mut Renderer| -> anyhow::Result<()> {
-            let frame =
+            let (frame, _) =
                 renderer.render_frame(&[], &[], &draw_sampler, Mat4::IDENTITY, viewport, &[], None)?;
             frame.present();
             Ok(())
@@ -1667,12 +2270,585 @@ mod surface_tests {
         renderer.prepare_headless_render_target().expect("headless target reinit");
         render_once(&mut renderer).expect("render after recovery");
     }
+
+    #[test]
+    fn headless_render_recovers_from_device_loss() {
+        let window_config = WindowConfig {
+            title: "Headless".into(),
+            width: 32,
+            height: 32,
+            vsync: false,
+            fullscreen: false,
+            render_scale: 1.0,
+        };
+        let mut renderer = block_on(Renderer::new(&window_config));
+        block_on(renderer.init_headless_for_test()).expect("init headless");
+        let init_sprites = |renderer: &mut Renderer| -> wgpu::Sampler {
+            let device = renderer.device().expect("device");
+            let atlas_texture = device.create_texture(&wgpu::TextureDescriptor {
+                label: Some("Test Atlas"),
+                size: wgpu::Extent3d { width: 1, height: 1, depth_or_array_layers: 1 },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: wgpu::TextureFormat::Rgba8UnormSrgb,
+                usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+                view_formats: &[],
+            });
+            let atlas_view = atlas_texture.create_view(&wgpu::TextureViewDescriptor::default());
+            let sampler = device.create_sampler(&wgpu::SamplerDescriptor::default());
+            renderer
+                .init_sprite_pipeline_with_atlas(atlas_view, sampler.clone())
+                .expect("init sprite pipeline");
+            renderer.prepare_headless_render_target().expect("headless target");
+            sampler
+        };
+        let viewport = RenderViewport { origin: (0.0, 0.0), size: (32.0, 32.0) };
+        let render_once = |renderer: &mut Renderer, sampler: &wgpu::Sampler| -> anyhow::Result<()> {
+            let (frame, _) = renderer.render_frame(&[], &[], sampler, Mat4::IDENTITY, viewport, &[], None)?;
+            frame.present();
+            Ok(())
+        };
+
+        let sampler = init_sprites(&mut renderer);
+        render_once(&mut renderer, &sampler).expect("initial render");
+        assert!(!renderer.device_lost());
+
+        let device = renderer.device().expect("device");
+        device.destroy();
+        let _ = device.poll(wgpu::PollType::Poll);
+        assert!(renderer.device_lost(), "destroying the device reports a loss");
+        let err = render_once(&mut renderer, &sampler).expect_err("lost device refuses to render");
+        assert!(err.to_string().contains("device lost"), "{err}");
+
+        renderer.recover_device().expect("recover device");
+        assert!(!renderer.device_lost());
+        let sampler = init_sprites(&mut renderer);
+        render_once(&mut renderer, &sampler).expect("render on the recreated device");
+    }
+
+    #[test]
+    fn offscreen_capture_reads_back_rendered_frame() {
+        let window_config = WindowConfig {
+            title: "Headless".into(),
+            width: 48,
+            height: 32,
+            vsync: false,
+            fullscreen: false,
+            render_scale: 1.0,
+        };
+        let mut renderer = block_on(Renderer::new(&window_config));
+        block_on(renderer.init_headless_for_test()).expect("init headless");
+        let (sampler, atlas_view) = {
+            let device = renderer.device().expect("device");
+            let atlas_texture = device.create_texture(&wgpu::TextureDescriptor {
+                label: Some("Test Atlas"),
+                size: wgpu::Extent3d { width: 1, height: 1, depth_or_array_layers: 1 },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: wgpu::TextureFormat::Rgba8UnormSrgb,
+                usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+                view_formats: &[],
+            });
+            let atlas_view = atlas_texture.create_view(&wgpu::TextureViewDescriptor::default());
+            (device.create_sampler(&wgpu::SamplerDescriptor::default()), atlas_view)
+        };
+        renderer.init_sprite_pipeline_with_atlas(atlas_view, sampler.clone()).expect("init sprite pipeline");
+        renderer.set_offscreen_capture(true).expect("enable capture");
+        assert!(renderer.offscreen_capture());
+        let viewport = RenderViewport { origin: (0.0, 0.0), size: (48.0, 32.0) };
+        let (frame, _) =
+            renderer.render_frame(&[], &[], &sampler, Mat4::IDENTITY, viewport, &[], None).expect("render");
+        frame.present();
+        let image = renderer.read_offscreen_frame().expect("read back frame");
+        assert_eq!(image.dimensions(), (48, 32));
+    }
+
+    #[test]
+    fn scaled_render_is_blitted_to_the_full_window() {
+        let window_config = WindowConfig {
+            title: "Headless".into(),
+            width: 48,
+            height: 32,
+            vsync: false,
+            fullscreen: false,
+            render_scale: 0.5,
+        };
+        let mut renderer = block_on(Renderer::new(&window_config));
+        block_on(renderer.init_headless_for_test()).expect("init headless");
+        assert_eq!(renderer.render_scale(), 0.5);
+        assert_eq!(renderer.render_size(), PhysicalSize::new(24, 16));
+        let (sampler, atlas_view) = {
+            let device = renderer.device().expect("device");
+            let atlas_texture = device.create_texture(&wgpu::TextureDescriptor {
+                label: Some("Test Atlas"),
+                size: wgpu::Extent3d { width: 1, height: 1, depth_or_array_layers: 1 },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: wgpu::TextureFormat::Rgba8UnormSrgb,
+                usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+                view_formats: &[],
+            });
+            let atlas_view = atlas_texture.create_view(&wgpu::TextureViewDescriptor::default());
+            (device.create_sampler(&wgpu::SamplerDescriptor::default()), atlas_view)
+        };
+        renderer.init_sprite_pipeline_with_atlas(atlas_view, sampler.clone()).expect("init sprite pipeline");
+        renderer.set_offscreen_capture(true).expect("enable capture");
+        let viewport = RenderViewport { origin: (0.0, 0.0), size: (48.0, 32.0) };
+        let render = |renderer: &mut Renderer| {
+            let (frame, _) =
+                renderer.render_frame(&[], &[], &sampler, Mat4::IDENTITY, viewport, &[], None).expect("render");
+            frame.present();
+            renderer.read_offscreen_frame().expect("read back frame")
+        };
+
+        let scaled = render(&mut renderer);
+        assert_eq!(scaled.dimensions(), (48, 32));
+        renderer.set_render_scale(1.0);
+        let native = render(&mut renderer);
+        assert_eq!(scaled.get_pixel(24, 16), native.get_pixel(24, 16), "the blit keeps the clear color");
+
+        renderer.set_render_scale(8.0);
+        assert_eq!(renderer.render_scale(), MAX_RENDER_SCALE);
+        assert_eq!(render(&mut renderer).dimensions(), (48, 32));
+    }
+
+    fn render_gray_sprite_under_half_alpha_tint(
+        color_space: SpriteColorSpace,
+        normal_texel: Option<[u8; 4]>,
+    ) -> [u8; 4] {
+        let window_config = WindowConfig {
+            title: "Headless".into(),
+            width: 16,
+            height: 16,
+            vsync: false,
+            fullscreen: false,
+            render_scale: 1.0,
+        };
+        let mut renderer = block_on(Renderer::new(&window_config));
+        block_on(renderer.init_headless_for_test()).expect("init headless");
+        let (sampler, atlas_view, normal_view) = {
+            let device = renderer.device().expect("device");
+            let queue = renderer.queue().expect("queue");
+            let atlas_texture = device.create_texture(&wgpu::TextureDescriptor {
+                label: Some("Gray Atlas"),
+                size: wgpu::Extent3d { width: 1, height: 1, depth_or_array_layers: 1 },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: wgpu::TextureFormat::Rgba8UnormSrgb,
+                usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+                view_formats: &[],
+            });
+            queue.write_texture(
+                wgpu::TexelCopyTextureInfo {
+                    texture: &atlas_texture,
+                    mip_level: 0,
+                    origin: wgpu::Origin3d::ZERO,
+                    aspect: wgpu::TextureAspect::All,
+                },
+                &[128, 128, 128, 255],
+                wgpu::TexelCopyBufferLayout { offset: 0, bytes_per_row: Some(4), rows_per_image: Some(1) },
+                wgpu::Extent3d { width: 1, height: 1, depth_or_array_layers: 1 },
+            );
+            let atlas_view = atlas_texture.create_view(&wgpu::TextureViewDescriptor::default());
+            let normal_view = normal_texel.map(|texel| {
+                let normal_texture = device.create_texture_with_data(
+                    queue,
+                    &wgpu::TextureDescriptor {
+                        label: Some("Test Normal Map"),
+                        size: wgpu::Extent3d { width: 1, height: 1, depth_or_array_layers: 1 },
+                        mip_level_count: 1,
+                        sample_count: 1,
+                        dimension: wgpu::TextureDimension::D2,
+                        format: wgpu::TextureFormat::Rgba8Unorm,
+                        usage: wgpu::TextureUsages::TEXTURE_BINDING,
+                        view_formats: &[],
+                    },
+                    wgpu::util::TextureDataOrder::LayerMajor,
+                    &texel,
+                );
+                Arc::new(normal_texture.create_view(&wgpu::TextureViewDescriptor::default()))
+            });
+            (device.create_sampler(&wgpu::SamplerDescriptor::default()), atlas_view, normal_view)
+        };
+        renderer.set_sprite_color_space(color_space);
+        renderer
+            .init_sprite_pipeline_with_atlas(atlas_view.clone(), sampler.clone())
+            .expect("init sprite pipeline");
+        assert!(renderer.surface_format().expect("format").is_srgb(), "headless target is sRGB");
+        renderer.set_offscreen_capture(true).expect("enable capture");
+        let viewport = RenderViewport { origin: (0.0, 0.0), size: (16.0, 16.0) };
+        let instances = [InstanceData {
+            axis_x: [2.0, 0.0, 0.0, 0.0],
+            axis_y: [0.0, 2.0, 0.0, 0.0],
+            translation: [0.0, 0.0, 0.0, 1.0],
+            uv_rect: [0.0, 0.0, 1.0, 1.0],
+            tint: [0.5, 0.5, 0.5, 0.5],
+        }];
+        let batch = SpriteBatch {
+            atlas: Arc::from("gray"),
+            range: 0..1,
+            view: Arc::new(atlas_view),
+            normal_view,
+            dirty: true,
+        };
+        let (frame, _) = renderer
+            .render_frame(&instances, &[batch], &sampler, Mat4::IDENTITY, viewport, &[], None)
+            .expect("render");
+        frame.present();
+        renderer.read_offscreen_frame().expect("read back frame").get_pixel(8, 8).0
+    }
+
+    #[test]
+    fn linear_sprite_color_space_blends_in_linear_space() {
+        let decode = |c: f32| if c <= 0.04045 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) };
+        let encode = |c: f32| {
+            let c = if c <= 0.0031308 { c * 12.92 } else { 1.055 * c.powf(1.0 / 2.4) - 0.055 };
+            (c * 255.0).round() as i32
+        };
+        let texel = decode(128.0 / 255.0);
+        let clear = [0.05, 0.06, 0.1];
+        let expected = |tint: f32| clear.map(|background| encode(texel * tint * 0.5 + background * 0.5));
+
+        let linear = render_gray_sprite_under_half_alpha_tint(SpriteColorSpace::Linear, None);
+        let legacy = render_gray_sprite_under_half_alpha_tint(SpriteColorSpace::Gamma, None);
+        for channel in 0..3 {
+            let want = expected(decode(0.5))[channel];
+            assert!(
+                (linear[channel] as i32 - want).abs() <= 2,
+                "linear channel {channel}: got {}, expected {want}",
+                linear[channel]
+            );
+            let want = expected(0.5)[channel];
+            assert!(
+                (legacy[channel] as i32 - want).abs() <= 2,
+                "legacy channel {channel}: got {}, expected {want}",
+                legacy[channel]
+            );
+        }
+        assert_ne!(linear, legacy);
+    }
+
+    #[test]
+    fn normal_mapped_sprites_are_shaded_by_the_directional_light() {
+        let unlit = render_gray_sprite_under_half_alpha_tint(SpriteColorSpace::Gamma, None);
+        // The default light points along (0.4, 0.8, 0.35); this normal faces straight back at it.
+        let toward_light =
+            render_gray_sprite_under_half_alpha_tint(SpriteColorSpace::Gamma, Some([74, 22, 174, 255]));
+        let facing_viewer =
+            render_gray_sprite_under_half_alpha_tint(SpriteColorSpace::Gamma, Some([128, 128, 255, 255]));
+        let unweighted =
+            render_gray_sprite_under_half_alpha_tint(SpriteColorSpace::Gamma, Some([128, 128, 255, 0]));
+        for channel in 0..3 {
+            assert!((toward_light[channel] as i32 - unlit[channel] as i32).abs() <= 3);
+            assert!(facing_viewer[channel] + 5 < unlit[channel], "{facing_viewer:?} vs {unlit:?}");
+            assert_eq!(unweighted[channel], unlit[channel], "zero alpha disables lighting");
+        }
+    }
+
+    #[test]
+    fn texture_readback_completes_on_a_later_frame() {
+        let window_config = WindowConfig {
+            title: "Headless".into(),
+            width: 48,
+            height: 32,
+            vsync: false,
+            fullscreen: false,
+            render_scale: 1.0,
+        };
+        let mut renderer = block_on(Renderer::new(&window_config));
+        block_on(renderer.init_headless_for_test()).expect("init headless");
+        let (sampler, atlas_view) = {
+            let device = renderer.device().expect("device");
+            let atlas_texture = device.create_texture(&wgpu::TextureDescriptor {
+                label: Some("Test Atlas"),
+                size: wgpu::Extent3d { width: 1, height: 1, depth_or_array_layers: 1 },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: wgpu::TextureFormat::Rgba8UnormSrgb,
+                usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+                view_formats: &[],
+            });
+            let atlas_view = atlas_texture.create_view(&wgpu::TextureViewDescriptor::default());
+            (device.create_sampler(&wgpu::SamplerDescriptor::default()), atlas_view)
+        };
+        renderer.init_sprite_pipeline_with_atlas(atlas_view, sampler.clone()).expect("init sprite pipeline");
+        renderer.prepare_headless_render_target().expect("headless target");
+        let viewport = RenderViewport { origin: (0.0, 0.0), size: (48.0, 32.0) };
+        let region = ReadbackRegion::new(8, 4, 16, 8);
+        let ticket =
+            renderer.request_texture_readback("probe", ReadbackTarget::SpritePass, region).expect("request");
+        assert_eq!(renderer.readbacks_in_flight_for("probe"), 1);
+
+        let mut completed = Vec::new();
+        for _ in 0..64 {
+            let (frame, _) =
+                renderer.render_frame(&[], &[], &sampler, Mat4::IDENTITY, viewport, &[], None).expect("render");
+            frame.present();
+            completed = renderer.take_completed_readbacks();
+            if !completed.is_empty() {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        }
+        assert_eq!(completed.len(), 1, "readback should finish within a few frames");
+        let readback = &completed[0];
+        assert_eq!(readback.ticket, ticket);
+        assert_eq!(readback.target, "sprite_pass");
+        let pixels = readback.data.as_ref().expect("readback data");
+        assert_eq!(pixels.len() as u64, region.byte_len());
+        assert!(pixels.chunks_exact(4).all(|pixel| pixel[3] == 255), "clear color is opaque");
+        assert_eq!(renderer.readbacks_in_flight_for("probe"), 0);
+    }
+
+    #[test]
+    fn auto_exposure_downsample_reads_back_the_scene_luminance() {
+        let window_config = WindowConfig {
+            title: "Headless".into(),
+            width: 48,
+            height: 32,
+            vsync: false,
+            fullscreen: false,
+            render_scale: 1.0,
+        };
+        let mut renderer = block_on(Renderer::new(&window_config));
+        block_on(renderer.init_headless_for_test()).expect("init headless");
+        let (sampler, atlas_view) = {
+            let device = renderer.device().expect("device");
+            let atlas_texture = device.create_texture(&wgpu::TextureDescriptor {
+                label: Some("Test Atlas"),
+                size: wgpu::Extent3d { width: 1, height: 1, depth_or_array_layers: 1 },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: wgpu::TextureFormat::Rgba8UnormSrgb,
+                usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+                view_formats: &[],
+            });
+            let atlas_view = atlas_texture.create_view(&wgpu::TextureViewDescriptor::default());
+            (device.create_sampler(&wgpu::SamplerDescriptor::default()), atlas_view)
+        };
+        renderer.init_sprite_pipeline_with_atlas(atlas_view, sampler.clone()).expect("init sprite pipeline");
+        renderer.prepare_headless_render_target().expect("headless target");
+
+        // A uniform sRGB grey of 128 is 0.2158 in linear light.
+        let (width, height) = (64u32, 48u32);
+        let device = renderer.device().expect("device").clone();
+        let queue = renderer.queue().expect("queue").clone();
+        let scene = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Test Scene"),
+            size: wgpu::Extent3d { width, height, depth_or_array_layers: 1 },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8UnormSrgb,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+            view_formats: &[],
+        });
+        queue.write_texture(
+            scene.as_image_copy(),
+            &[128u8, 128, 128, 255].repeat((width * height) as usize),
+            wgpu::TexelCopyBufferLayout {
+                offset: 0,
+                bytes_per_row: Some(width * 4),
+                rows_per_image: Some(height),
+            },
+            wgpu::Extent3d { width, height, depth_or_array_layers: 1 },
+        );
+        let scene_view = scene.create_view(&wgpu::TextureViewDescriptor::default());
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        let sample =
+            renderer.auto_exposure_pass.encode(&device, &mut encoder, &scene_view).expect("downsample");
+        queue.submit(std::iter::once(encoder.finish()));
+        let region = ReadbackRegion::new(0, 0, AUTO_EXPOSURE_SAMPLE_SIZE, AUTO_EXPOSURE_SAMPLE_SIZE);
+        renderer.request_texture_readback("probe", ReadbackTarget::Texture(sample), region).expect("request");
+
+        let viewport = RenderViewport { origin: (0.0, 0.0), size: (48.0, 32.0) };
+        let mut completed = Vec::new();
+        for _ in 0..64 {
+            let (frame, _) =
+                renderer.render_frame(&[], &[], &sampler, Mat4::IDENTITY, viewport, &[], None).expect("render");
+            frame.present();
+            completed = renderer.take_completed_readbacks();
+            if !completed.is_empty() {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        }
+        assert_eq!(completed.len(), 1, "readback should finish within a few frames");
+        let pixels = completed[0].data.as_ref().expect("readback data");
+        let luminance = average_luminance(pixels).expect("luminance");
+        assert!((luminance - 0.2158).abs() < 0.01, "luminance {luminance}");
+    }
+
+    #[test]
+    fn static_sprite_batches_skip_instance_uploads() {
+        let window_config = WindowConfig {
+            title: "Headless".into(),
+            width: 64,
+            height: 64,
+            vsync: false,
+            fullscreen: false,
+            render_scale: 1.0,
+        };
+        let mut renderer = block_on(Renderer::new(&window_config));
+        block_on(renderer.init_headless_for_test()).expect("init headless");
+        let (sampler, atlas_view) = {
+            let device = renderer.device().expect("device");
+            let atlas_texture = device.create_texture(&wgpu::TextureDescriptor {
+                label: Some("Test Atlas"),
+                size: wgpu::Extent3d { width: 1, height: 1, depth_or_array_layers: 1 },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: wgpu::TextureFormat::Rgba8UnormSrgb,
+                usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+                view_formats: &[],
+            });
+            let atlas_view = atlas_texture.create_view(&wgpu::TextureViewDescriptor::default());
+            (device.create_sampler(&wgpu::SamplerDescriptor::default()), atlas_view)
+        };
+        renderer
+            .init_sprite_pipeline_with_atlas(atlas_view.clone(), sampler.clone())
+            .expect("init sprite pipeline");
+        renderer.prepare_headless_render_target().expect("headless target");
+        let viewport = RenderViewport { origin: (0.0, 0.0), size: (64.0, 64.0) };
+        let stride = std::mem::size_of::<InstanceData>() as u64;
+
+        let mut instances: Vec<InstanceData> = (0..10_000)
+            .map(|i| InstanceData {
+                axis_x: [0.01, 0.0, 0.0, 0.0],
+                axis_y: [0.0, 0.01, 0.0, 0.0],
+                translation: [i as f32 * 1e-4, 0.0, 0.0, 1.0],
+                uv_rect: [0.0, 0.0, 1.0, 1.0],
+                tint: [1.0; 4],
+            })
+            .collect();
+        let view = Arc::new(atlas_view);
+        let render = |renderer: &mut Renderer, instances: &[InstanceData], dirty: bool| {
+            let batch = SpriteBatch {
+                atlas: Arc::from("test"),
+                range: 0..instances.len() as u32,
+                view: Arc::clone(&view),
+                normal_view: None,
+                dirty,
+            };
+            let (frame, _) = renderer
+                .render_frame(instances, &[batch], &sampler, Mat4::IDENTITY, viewport, &[], None)
+                .expect("render");
+            frame.present();
+            renderer.take_sprite_upload_stats()
+        };
+
+        let first = render(&mut renderer, &instances, true);
+        assert_eq!(first.bytes_uploaded, 10_000 * stride);
+
+        let clean = render(&mut renderer, &instances, false);
+        assert_eq!(clean.bytes_uploaded, 0, "clean batch with an unchanged range should be reused");
+        assert_eq!(clean.bytes_reused, 10_000 * stride);
+
+        let unchanged_dirty = render(&mut renderer, &instances, true);
+        assert_eq!(unchanged_dirty.bytes_uploaded, 0, "identical contents should not be rewritten");
+
+        instances[5_000].tint = [0.5; 4];
+        let one_changed = render(&mut renderer, &instances, true);
+        assert_eq!(one_changed.bytes_uploaded, stride);
+        assert_eq!(one_changed.write_calls, 1);
+
+        instances.remove(10);
+        let removed = render(&mut renderer, &instances, true);
+        assert_eq!(removed.bytes_uploaded, (instances.len() as u64 - 10) * stride);
+    }
+
+    #[test]
+    fn baked_static_sprites_upload_once() {
+        let window_config = WindowConfig {
+            title: "Headless".into(),
+            width: 64,
+            height: 64,
+            vsync: false,
+            fullscreen: false,
+            render_scale: 1.0,
+        };
+        let mut renderer = block_on(Renderer::new(&window_config));
+        block_on(renderer.init_headless_for_test()).expect("init headless");
+        let (sampler, atlas_view) = {
+            let device = renderer.device().expect("device");
+            let atlas_texture = device.create_texture(&wgpu::TextureDescriptor {
+                label: Some("Test Atlas"),
+                size: wgpu::Extent3d { width: 1, height: 1, depth_or_array_layers: 1 },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: wgpu::TextureFormat::Rgba8UnormSrgb,
+                usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+                view_formats: &[],
+            });
+            let atlas_view = atlas_texture.create_view(&wgpu::TextureViewDescriptor::default());
+            (device.create_sampler(&wgpu::SamplerDescriptor::default()), atlas_view)
+        };
+        renderer
+            .init_sprite_pipeline_with_atlas(atlas_view.clone(), sampler.clone())
+            .expect("init sprite pipeline");
+        renderer.prepare_headless_render_target().expect("headless target");
+        let viewport = RenderViewport { origin: (0.0, 0.0), size: (64.0, 64.0) };
+        let stride = std::mem::size_of::<InstanceData>() as u64;
+        let view = Arc::new(atlas_view);
+        let instance = |x: f32| InstanceData {
+            axis_x: [0.01, 0.0, 0.0, 0.0],
+            axis_y: [0.0, 0.01, 0.0, 0.0],
+            translation: [x, 0.0, 0.0, 1.0],
+            uv_rect: [0.0, 0.0, 1.0, 1.0],
+            tint: [1.0; 4],
+        };
+        let batch = |len: usize| SpriteBatch {
+            atlas: Arc::from("test"),
+            range: 0..len as u32,
+            view: Arc::clone(&view),
+            normal_view: None,
+            dirty: true,
+        };
+        let static_set: Vec<InstanceData> = (0..5_000).map(|i| instance(i as f32 * 1e-4)).collect();
+        let dynamic = vec![instance(0.5)];
+        let render = |renderer: &mut Renderer| {
+            let (frame, _) = renderer
+                .render_frame(&dynamic, &[batch(1)], &sampler, Mat4::IDENTITY, viewport, &[], None)
+                .expect("render");
+            frame.present();
+            renderer.take_sprite_upload_stats()
+        };
+
+        renderer.bake_static_sprites(static_set.clone(), vec![batch(static_set.len())]);
+        assert!(renderer.has_static_sprites());
+        let first = render(&mut renderer);
+        assert_eq!(first.static_uploads, 1);
+        assert_eq!(first.static_bytes_uploaded, 5_000 * stride);
+        for _ in 0..3 {
+            let steady = render(&mut renderer);
+            assert_eq!(steady.static_uploads, 0, "an unchanged static set must not be re-uploaded");
+            assert_eq!(steady.static_bytes_uploaded, 0);
+        }
+
+        renderer.bake_static_sprites(static_set[..10].to_vec(), vec![batch(10)]);
+        let rebaked = render(&mut renderer);
+        assert_eq!(rebaked.static_uploads, 1);
+        assert_eq!(rebaked.static_bytes_uploaded, 10 * stride);
+
+        renderer.clear_static_sprites();
+        assert!(!renderer.has_static_sprites());
+        assert_eq!(render(&mut renderer).static_uploads, 0);
+    }
 }
 
 #[cfg(all(test, feature = "editor"))]
 mod pass_tests {
     use super::*;
     use crate::ecs::MeshLightingInfo;
+    use crate::environment::EnvironmentRegistry;
     use crate::material_registry::MaterialRegistry;
     use crate::mesh::Mesh;
     use egui_wgpu::RendererOptions;
@@ -1680,7 +2856,14 @@ mod pass_tests {
     use pollster::block_on;
 
     fn test_window_config() -> WindowConfig {
-        WindowConfig { title: "PassTests".into(), width: 96, height: 64, vsync: false, fullscreen: false }
+        WindowConfig {
+            title: "PassTests".into(),
+            width: 96,
+            height: 64,
+            vsync: false,
+            fullscreen: false,
+            render_scale: 1.0,
+        }
     }
 
     fn create_headless_renderer() -> Renderer {
@@ -1767,7 +2950,7 @@ mod pass_tests {
         renderer.init_sprite_pipeline_with_atlas(atlas_view, pipeline_sampler).expect("sprite pipeline");
         let cfg = test_window_config();
         let viewport = RenderViewport { origin: (0.0, 0.0), size: (cfg.width as f32, cfg.height as f32) };
-        let frame = renderer
+        let (frame, _) = renderer
             .render_frame(&[], &[], &draw_sampler, Mat4::IDENTITY, viewport, &[], None)
             .expect("render frame");
         let format = renderer.surface_format().expect("format");
@@ -1784,6 +2967,77 @@ mod pass_tests {
         }
     }
 
+    #[test]
+    fn render_frame_reports_draw_calls_and_triangles() {
+        let mut renderer = create_headless_renderer();
+        renderer.prepare_headless_render_target().expect("headless target");
+        let device = renderer.device().expect("device").clone();
+        let queue = renderer.queue().expect("queue").clone();
+        let (atlas_view, pipeline_sampler, draw_sampler) = create_test_atlas(&device, &queue);
+        let atlas_view = Arc::new(atlas_view);
+        renderer
+            .init_sprite_pipeline_with_atlas((*atlas_view).clone(), pipeline_sampler)
+            .expect("sprite pipeline");
+        let mesh = Mesh::cube(1.0);
+        let gpu_mesh = renderer.create_gpu_mesh(&mesh).expect("gpu mesh");
+        let mut registry = MaterialRegistry::new();
+        let default_key = registry.default_key().to_string();
+        let material = registry.prepare_material_gpu(&default_key, &mut renderer).expect("material gpu");
+        let mut environments = EnvironmentRegistry::new();
+        let environment_key = environments.default_key().to_string();
+        let environment = environments.ensure_gpu(&environment_key, &mut renderer).expect("environment gpu");
+        renderer.set_environment(&environment, 1.0).expect("bind environment");
+        let draws = vec![MeshDraw {
+            mesh: &gpu_mesh,
+            model: Mat4::IDENTITY,
+            lighting: MeshLightingInfo::default(),
+            material,
+            casts_shadows: true,
+            skin_palette: None,
+        }];
+        let instances = [InstanceData {
+            axis_x: [0.1, 0.0, 0.0, 0.0],
+            axis_y: [0.0, 0.1, 0.0, 0.0],
+            translation: [0.0, 0.0, 0.0, 1.0],
+            uv_rect: [0.0, 0.0, 1.0, 1.0],
+            tint: [1.0; 4],
+        }; 3];
+        let batch = SpriteBatch {
+            atlas: Arc::from("test"),
+            range: 0..3,
+            view: atlas_view,
+            normal_view: None,
+            dirty: true,
+        };
+        let camera = Camera3D::new(Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO, 60f32.to_radians(), 0.1, 100.0);
+        let cfg = test_window_config();
+        let viewport = RenderViewport { origin: (0.0, 0.0), size: (cfg.width as f32, cfg.height as f32) };
+
+        let (frame, stats) = renderer
+            .render_frame(
+                &instances,
+                &[batch],
+                &draw_sampler,
+                Mat4::IDENTITY,
+                viewport,
+                &draws,
+                Some(&camera),
+            )
+            .expect("render frame");
+        frame.present();
+        let cube_triangles = u64::from(gpu_mesh.index_count / 3);
+        assert_eq!(stats.sprite_draw_calls, 1);
+        assert_eq!(stats.mesh_draw_calls, 1);
+        assert_eq!(stats.total_triangles, 2 * 3 + cube_triangles * u64::from(1 + stats.shadow_draw_calls));
+        assert_eq!(stats.draw_calls(), 2 + stats.shadow_draw_calls);
+
+        let (frame, stats) = renderer
+            .render_frame(&[], &[], &draw_sampler, Mat4::IDENTITY, viewport, &[], None)
+            .expect("render empty frame");
+        frame.present();
+        assert_eq!(stats, RenderStats::default(), "stats are per frame");
+    }
+
     #[test]
     fn light_cluster_metrics_track_visible_lights() {
         let mut renderer = create_headless_renderer();
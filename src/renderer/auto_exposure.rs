@@ -0,0 +1,386 @@
+use std::collections::VecDeque;
+use std::time::Instant;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use super::readback::ReadbackTicket;
+
+/// Edge length of the square the scene is averaged down to before its luminance is read back.
+pub const AUTO_EXPOSURE_SAMPLE_SIZE: u32 = 16;
+/// Readback owner the renderer uses for its luminance samples; these never reach
+/// `Renderer::take_completed_readbacks`.
+pub const AUTO_EXPOSURE_READBACK_OWNER: &str = "renderer.auto_exposure";
+/// Luminance samples in flight at once. Older samples describe older exposures, so there is no
+/// point in queueing more.
+const MAX_PENDING_SAMPLES: usize = 2;
+/// Longest frame time fed into the adaptation, so a hitch does not snap the exposure.
+const MAX_ADAPTATION_STEP: f32 = 0.25;
+const SAMPLE_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8UnormSrgb;
+
+fn default_target_luminance() -> f32 {
+    0.18
+}
+
+fn default_adaptation_rate() -> f32 {
+    1.5
+}
+
+fn default_min_exposure() -> f32 {
+    0.1
+}
+
+fn default_max_exposure() -> f32 {
+    8.0
+}
+
+/// Drives `SceneLightingState::exposure` from the average luminance of the rendered scene.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AutoExposureSettings {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Average scene luminance the exposure steers toward (middle grey by default).
+    #[serde(default = "default_target_luminance")]
+    pub target_luminance: f32,
+    /// How quickly the exposure closes the gap to its target, per second.
+    #[serde(default = "default_adaptation_rate")]
+    pub adaptation_rate: f32,
+    #[serde(default = "default_min_exposure")]
+    pub min_exposure: f32,
+    #[serde(default = "default_max_exposure")]
+    pub max_exposure: f32,
+}
+
+impl Default for AutoExposureSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            target_luminance: default_target_luminance(),
+            adaptation_rate: default_adaptation_rate(),
+            min_exposure: default_min_exposure(),
+            max_exposure: default_max_exposure(),
+        }
+    }
+}
+
+impl AutoExposureSettings {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Clamps `exposure` to the configured range, tolerating a swapped or non-positive range.
+    pub fn clamp_exposure(&self, exposure: f32) -> f32 {
+        let min = self.min_exposure.max(0.001);
+        let max = self.max_exposure.max(min);
+        if exposure.is_finite() {
+            exposure.clamp(min, max)
+        } else {
+            min
+        }
+    }
+}
+
+/// Exposure adaptation state. Each luminance sample sets a new target; `advance` then eases the
+/// exposure toward it at the configured rate.
+#[derive(Debug, Clone)]
+pub struct AutoExposure {
+    exposure: f32,
+    target: f32,
+    average_luminance: Option<f32>,
+    last_advance: Option<Instant>,
+    /// Readbacks in flight with the exposure their frame was rendered at.
+    pending: VecDeque<(ReadbackTicket, f32)>,
+}
+
+impl AutoExposure {
+    pub fn new(exposure: f32) -> Self {
+        Self {
+            exposure,
+            target: exposure,
+            average_luminance: None,
+            last_advance: None,
+            pending: VecDeque::new(),
+        }
+    }
+
+    pub fn exposure(&self) -> f32 {
+        self.exposure
+    }
+
+    pub fn target(&self) -> f32 {
+        self.target
+    }
+
+    /// Average luminance of the last sample, before exposure adaptation.
+    pub fn average_luminance(&self) -> Option<f32> {
+        self.average_luminance
+    }
+
+    /// Starts adapting from `exposure`, e.g. the manual exposure when auto exposure is switched on.
+    pub fn reset(&mut self, exposure: f32) {
+        *self = Self::new(exposure);
+    }
+
+    /// Sets a new target from the average luminance of a frame rendered at `sampled_exposure`.
+    pub fn record_luminance(&mut self, settings: &AutoExposureSettings, average: f32, sampled_exposure: f32) {
+        if !average.is_finite() || !sampled_exposure.is_finite() {
+            return;
+        }
+        self.average_luminance = Some(average);
+        let target = sampled_exposure * settings.target_luminance.max(0.0) / average.max(1e-4);
+        self.target = settings.clamp_exposure(target);
+    }
+
+    /// Moves the exposure toward the target over `dt` seconds and returns it.
+    pub fn update(&mut self, settings: &AutoExposureSettings, dt: f32) -> f32 {
+        let dt = dt.clamp(0.0, MAX_ADAPTATION_STEP);
+        let blend = 1.0 - (-settings.adaptation_rate.max(0.0) * dt).exp();
+        self.exposure = settings.clamp_exposure(self.exposure + (self.target - self.exposure) * blend);
+        self.exposure
+    }
+
+    /// `update` with the time since the previous call.
+    pub(super) fn advance(&mut self, settings: &AutoExposureSettings) -> f32 {
+        let now = Instant::now();
+        let dt = self.last_advance.map_or(0.0, |last| now.duration_since(last).as_secs_f32());
+        self.last_advance = Some(now);
+        self.update(settings, dt)
+    }
+
+    pub(super) fn wants_sample(&self) -> bool {
+        self.pending.len() < MAX_PENDING_SAMPLES
+    }
+
+    pub(super) fn sample_requested(&mut self, ticket: ReadbackTicket, exposure: f32) {
+        self.pending.push_back((ticket, exposure));
+    }
+
+    /// Feeds a finished readback; failed samples are dropped.
+    pub(super) fn sample_completed(
+        &mut self,
+        settings: &AutoExposureSettings,
+        ticket: ReadbackTicket,
+        pixels: Result<&[u8], &str>,
+    ) {
+        let Some(index) = self.pending.iter().position(|(pending, _)| *pending == ticket) else {
+            return;
+        };
+        let (_, sampled_exposure) = self.pending.remove(index).expect("pending sample");
+        if let Ok(pixels) = pixels {
+            if let Some(average) = average_luminance(pixels) {
+                self.record_luminance(settings, average, sampled_exposure);
+            }
+        }
+    }
+}
+
+/// Mean Rec. 709 luminance of tightly packed sRGB-encoded RGBA8 pixels.
+pub fn average_luminance(pixels: &[u8]) -> Option<f32> {
+    let count = pixels.len() / 4;
+    if count == 0 {
+        return None;
+    }
+    let decode = |value: u8| {
+        let c = value as f32 / 255.0;
+        if c <= 0.04045 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    let total: f32 = pixels
+        .chunks_exact(4)
+        .map(|pixel| 0.2126 * decode(pixel[0]) + 0.7152 * decode(pixel[1]) + 0.0722 * decode(pixel[2]))
+        .sum();
+    Some(total / count as f32)
+}
+
+/// Averages the scene target down to an [`AUTO_EXPOSURE_SAMPLE_SIZE`] square texture that the
+/// readback queue can copy out.
+#[derive(Default)]
+pub(super) struct AutoExposurePass {
+    pipeline: Option<wgpu::RenderPipeline>,
+    bind_group_layout: Option<wgpu::BindGroupLayout>,
+    sampler: Option<wgpu::Sampler>,
+    sample_texture: Option<(wgpu::Texture, wgpu::TextureView)>,
+}
+
+impl AutoExposurePass {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Renders the downsampled scene and returns the texture to read back.
+    pub fn encode(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        scene: &wgpu::TextureView,
+    ) -> Result<wgpu::Texture> {
+        if self.pipeline.is_none() {
+            self.init_pipeline(device);
+        }
+        let pipeline = self.pipeline.as_ref().context("Auto exposure pipeline missing")?;
+        let layout = self.bind_group_layout.as_ref().context("Auto exposure layout missing")?;
+        let sampler = self.sampler.as_ref().context("Auto exposure sampler missing")?;
+        let (texture, view) = self.sample_texture.get_or_insert_with(|| {
+            let texture = device.create_texture(&wgpu::TextureDescriptor {
+                label: Some("Auto Exposure Sample Texture"),
+                size: wgpu::Extent3d {
+                    width: AUTO_EXPOSURE_SAMPLE_SIZE,
+                    height: AUTO_EXPOSURE_SAMPLE_SIZE,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: SAMPLE_FORMAT,
+                usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+                view_formats: &[],
+            });
+            let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+            (texture, view)
+        });
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Auto Exposure BG"),
+            layout,
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: wgpu::BindingResource::TextureView(scene) },
+                wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::Sampler(sampler) },
+            ],
+        });
+        let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Auto Exposure Downsample Pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view,
+                depth_slice: None,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: None,
+            occlusion_query_set: None,
+            timestamp_writes: None,
+        });
+        pass.set_pipeline(pipeline);
+        pass.set_bind_group(0, &bind_group, &[]);
+        pass.draw(0..3, 0..1);
+        Ok(texture.clone())
+    }
+
+    fn init_pipeline(&mut self, device: &wgpu::Device) {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Auto Exposure Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("../../assets/shaders/auto_exposure.wgsl").into()),
+        });
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Auto Exposure BGL"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+        });
+        let layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Auto Exposure Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Auto Exposure Pipeline"),
+            layout: Some(&layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_main"),
+                buffers: &[],
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_main"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: SAMPLE_FORMAT,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            }),
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+            cache: None,
+        });
+        self.sampler = Some(device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("Auto Exposure Sampler"),
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        }));
+        self.pipeline = Some(pipeline);
+        self.bind_group_layout = Some(bind_group_layout);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bright_samples_lower_the_exposure_target_over_successive_updates() {
+        let settings = AutoExposureSettings { enabled: true, ..AutoExposureSettings::default() };
+        let mut auto = AutoExposure::new(1.0);
+        let bright = [255u8, 255, 255, 255].repeat(16);
+        let luminance = average_luminance(&bright).expect("luminance");
+        assert!((luminance - 1.0).abs() < 1e-4);
+
+        let mut previous_target = auto.target();
+        let mut previous_exposure = auto.exposure();
+        for _ in 0..8 {
+            // The scene stays bright: each sample is taken at the exposure of the previous update.
+            auto.record_luminance(&settings, luminance, auto.exposure());
+            assert!(auto.target() < previous_target, "{} < {previous_target}", auto.target());
+            let exposure = auto.update(&settings, 1.0 / 30.0);
+            assert!(exposure < previous_exposure, "exposure eases toward the lower target");
+            assert!(exposure >= settings.min_exposure);
+            previous_target = auto.target();
+            previous_exposure = exposure;
+        }
+    }
+
+    #[test]
+    fn exposure_is_clamped_and_adapts_at_the_configured_rate() {
+        let settings =
+            AutoExposureSettings { enabled: true, max_exposure: 4.0, ..AutoExposureSettings::default() };
+        let mut auto = AutoExposure::new(1.0);
+        auto.record_luminance(&settings, 0.0, 1.0);
+        assert_eq!(auto.target(), 4.0, "a black frame pushes the target to the maximum");
+
+        let mut slow = auto.clone();
+        let fast_settings = AutoExposureSettings { adaptation_rate: 6.0, ..settings };
+        let fast = auto.update(&fast_settings, 0.1);
+        let slow = slow.update(&AutoExposureSettings { adaptation_rate: 0.5, ..settings }, 0.1);
+        assert!(fast > slow && slow > 1.0, "fast {fast} vs slow {slow}");
+        for _ in 0..200 {
+            auto.update(&fast_settings, 0.1);
+        }
+        assert!((auto.exposure() - 4.0).abs() < 1e-3);
+    }
+}
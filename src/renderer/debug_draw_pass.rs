@@ -0,0 +1,273 @@
+use anyhow::{Context, Result};
+use glam::{Mat4, Vec3, Vec4};
+
+use super::RenderViewport;
+
+const MIN_VERTEX_CAPACITY: usize = 256;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DebugPrimitive {
+    Line { a: Vec3, b: Vec3, color: Vec4 },
+    Aabb { min: Vec3, max: Vec3, color: Vec4 },
+}
+
+/// Debug geometry queued for the current frame. `Renderer::render_frame` draws it and the host's
+/// frame loop clears it through `Renderer::clear_debug_draw`.
+#[derive(Clone, Debug, Default)]
+pub struct DebugDrawBuffer {
+    primitives: Vec<DebugPrimitive>,
+}
+
+impl DebugDrawBuffer {
+    pub fn line(&mut self, a: Vec3, b: Vec3, color: Vec4) {
+        self.primitives.push(DebugPrimitive::Line { a, b, color });
+    }
+
+    pub fn aabb(&mut self, min: Vec3, max: Vec3, color: Vec4) {
+        self.primitives.push(DebugPrimitive::Aabb { min: min.min(max), max: min.max(max), color });
+    }
+
+    pub fn primitives(&self) -> &[DebugPrimitive] {
+        &self.primitives
+    }
+
+    pub fn len(&self) -> usize {
+        self.primitives.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.primitives.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.primitives.clear();
+    }
+
+    /// Expands every primitive into line-list vertex pairs.
+    fn append_vertices(&self, out: &mut Vec<DebugVertex>) {
+        for primitive in &self.primitives {
+            match *primitive {
+                DebugPrimitive::Line { a, b, color } => {
+                    out.push(DebugVertex::new(a, color));
+                    out.push(DebugVertex::new(b, color));
+                }
+                DebugPrimitive::Aabb { min, max, color } => {
+                    let corner = |i: usize| {
+                        Vec3::new(
+                            if i & 1 == 0 { min.x } else { max.x },
+                            if i & 2 == 0 { min.y } else { max.y },
+                            if i & 4 == 0 { min.z } else { max.z },
+                        )
+                    };
+                    for i in 0..8 {
+                        for axis in [1, 2, 4] {
+                            if i & axis == 0 {
+                                out.push(DebugVertex::new(corner(i), color));
+                                out.push(DebugVertex::new(corner(i | axis), color));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct DebugVertex {
+    position: [f32; 3],
+    color: [f32; 4],
+}
+
+impl DebugVertex {
+    fn new(position: Vec3, color: Vec4) -> Self {
+        Self { position: position.to_array(), color: color.to_array() }
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+struct Globals {
+    proj: [[f32; 4]; 4],
+}
+
+#[derive(Default)]
+pub(super) struct DebugDrawPass {
+    pipeline: Option<wgpu::RenderPipeline>,
+    globals_buf: Option<wgpu::Buffer>,
+    globals_bg: Option<wgpu::BindGroup>,
+    vertex_buffer: Option<wgpu::Buffer>,
+    vertex_capacity: usize,
+    vertices: Vec<DebugVertex>,
+}
+
+impl DebugDrawPass {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn init_pipeline(&mut self, device: &wgpu::Device, surface_format: wgpu::TextureFormat) {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Debug Line Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("../../assets/shaders/debug_lines.wgsl").into()),
+        });
+        let globals_bgl = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Debug Line Globals BGL"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::VERTEX,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        });
+        let globals_buf = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Debug Line Globals Buffer"),
+            size: std::mem::size_of::<Globals>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let globals_bg = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Debug Line Globals BG"),
+            layout: &globals_bgl,
+            entries: &[wgpu::BindGroupEntry { binding: 0, resource: globals_buf.as_entire_binding() }],
+        });
+        let layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Debug Line Pipeline Layout"),
+            bind_group_layouts: &[&globals_bgl],
+            push_constant_ranges: &[],
+        });
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Debug Line Pipeline"),
+            layout: Some(&layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_main"),
+                buffers: &[wgpu::VertexBufferLayout {
+                    array_stride: std::mem::size_of::<DebugVertex>() as u64,
+                    step_mode: wgpu::VertexStepMode::Vertex,
+                    attributes: &[
+                        wgpu::VertexAttribute {
+                            shader_location: 0,
+                            format: wgpu::VertexFormat::Float32x3,
+                            offset: 0,
+                        },
+                        wgpu::VertexAttribute {
+                            shader_location: 1,
+                            format: wgpu::VertexFormat::Float32x4,
+                            offset: 12,
+                        },
+                    ],
+                }],
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_main"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: surface_format,
+                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::LineList,
+                ..Default::default()
+            },
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+            cache: None,
+        });
+        self.pipeline = Some(pipeline);
+        self.globals_buf = Some(globals_buf);
+        self.globals_bg = Some(globals_bg);
+    }
+
+    /// Uploads the buffered primitives. Returns the number of vertices to draw.
+    pub fn prepare(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        surface_format: wgpu::TextureFormat,
+        view_proj: Mat4,
+        buffer: &DebugDrawBuffer,
+    ) -> Result<u32> {
+        self.vertices.clear();
+        buffer.append_vertices(&mut self.vertices);
+        if self.vertices.is_empty() {
+            return Ok(0);
+        }
+        if self.pipeline.is_none() {
+            self.init_pipeline(device, surface_format);
+        }
+        let globals = self.globals_buf.as_ref().context("Debug line globals buffer missing")?;
+        queue.write_buffer(globals, 0, bytemuck::bytes_of(&Globals { proj: view_proj.to_cols_array_2d() }));
+        if self.vertex_buffer.is_none() || self.vertex_capacity < self.vertices.len() {
+            let capacity = self.vertices.len().next_power_of_two().max(MIN_VERTEX_CAPACITY);
+            self.vertex_buffer = Some(device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("Debug Line Vertex Buffer"),
+                size: (capacity * std::mem::size_of::<DebugVertex>()) as u64,
+                usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            }));
+            self.vertex_capacity = capacity;
+        }
+        let vertex_buffer = self.vertex_buffer.as_ref().context("Debug line vertex buffer missing")?;
+        queue.write_buffer(vertex_buffer, 0, bytemuck::cast_slice(&self.vertices));
+        Ok(self.vertices.len() as u32)
+    }
+
+    pub fn encode_pass(
+        &self,
+        pass: &mut wgpu::RenderPass<'_>,
+        viewport: RenderViewport,
+        vertex_count: u32,
+    ) -> Result<()> {
+        pass.set_pipeline(self.pipeline.as_ref().context("Debug line pipeline missing")?);
+        pass.set_bind_group(
+            0,
+            self.globals_bg.as_ref().context("Debug line globals bind group missing")?,
+            &[],
+        );
+        let vertex_buffer = self.vertex_buffer.as_ref().context("Debug line vertex buffer missing")?;
+        let byte_len = vertex_count as u64 * std::mem::size_of::<DebugVertex>() as u64;
+        pass.set_vertex_buffer(0, vertex_buffer.slice(..byte_len));
+        let (vp_x, vp_y) = viewport.origin;
+        let (vp_w, vp_h) = viewport.size;
+        pass.set_viewport(vp_x, vp_y, vp_w.max(1.0), vp_h.max(1.0), 0.0, 1.0);
+        pass.draw(0..vertex_count, 0..1);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aabb_expands_to_twelve_edges() {
+        let mut buffer = DebugDrawBuffer::default();
+        buffer.aabb(Vec3::ONE, Vec3::ZERO, Vec4::ONE);
+        buffer.line(Vec3::ZERO, Vec3::X, Vec4::ONE);
+        assert_eq!(
+            buffer.primitives()[0],
+            DebugPrimitive::Aabb { min: Vec3::ZERO, max: Vec3::ONE, color: Vec4::ONE }
+        );
+        let mut vertices = Vec::new();
+        buffer.append_vertices(&mut vertices);
+        assert_eq!(vertices.len(), 12 * 2 + 2);
+        for edge in vertices[..24].chunks(2) {
+            let a = Vec3::from(edge[0].position);
+            let b = Vec3::from(edge[1].position);
+            assert!(((b - a).length() - 1.0).abs() < 1e-6, "edges follow a single axis");
+        }
+        buffer.clear();
+        assert!(buffer.is_empty());
+    }
+}
@@ -6,8 +6,8 @@ use std::sync::Arc;
 use winit::dpi::PhysicalSize;
 
 use super::{
-    Camera3D, ClusterConfigUniform, ClusterLightUniform, ClusterRecordGpu, PointLightGpu, SceneLightingState,
-    ScenePointLight, LIGHT_CLUSTER_CACHE_QUANTIZE, LIGHT_CLUSTER_MAX_LIGHTS,
+    Camera3D, ClusterConfigUniform, ClusterLightUniform, ClusterRecordGpu, Frustum, PointLightGpu,
+    SceneLightingState, ScenePointLight, LIGHT_CLUSTER_CACHE_QUANTIZE, LIGHT_CLUSTER_MAX_LIGHTS,
     LIGHT_CLUSTER_MAX_LIGHTS_PER_CLUSTER, LIGHT_CLUSTER_RECORD_STRIDE_WORDS, LIGHT_CLUSTER_TILE_SIZE,
     LIGHT_CLUSTER_Z_SLICES,
 };
@@ -267,7 +267,7 @@ fn build_light_cluster_data<'a>(
     let depth_range = (far - near).max(0.0001);
     let inv_depth_range = 1.0 / depth_range;
     let view_proj = proj * view;
-    let frustum_planes = super::Renderer::extract_frustum_planes(view_proj);
+    let frustum = Frustum::from_view_projection(view_proj);
     let width_f = width as f32;
     let height_f = height as f32;
     let viewport_inv_width = if width == 0 { 0.0 } else { 1.0 / width as f32 };
@@ -308,7 +308,7 @@ fn build_light_cluster_data<'a>(
             continue;
         }
         let radius = light.radius.max(0.01);
-        if !super::Renderer::sphere_in_frustum(light.position, radius, &frustum_planes) {
+        if !frustum.intersects_sphere(light.position, radius) {
             continue;
         }
         let world_pos = light.position.extend(1.0);
@@ -363,6 +363,7 @@ fn build_light_cluster_data<'a>(
         scratch.gpu_lights.push(PointLightGpu {
             position_radius: [light.position.x, light.position.y, light.position.z, light.radius],
             color_intensity: [light.color.x, light.color.y, light.color.z, light.intensity],
+            params: [light.falloff.gpu_mode(), 0, 0, 0],
         });
 
         for z in start_z..=end_z {
@@ -513,6 +514,7 @@ fn hash_point_lights(lights: &[ScenePointLight]) -> u64 {
             light.color.z.to_bits(),
             light.radius.to_bits(),
             light.intensity.to_bits(),
+            light.falloff.gpu_mode(),
         ] {
             hash ^= value as u64;
             hash = hash.wrapping_mul(FNV_PRIME);
@@ -533,6 +535,7 @@ fn quantize_matrix(mat: Mat4) -> [i32; 16] {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::renderer::PointLightFalloff;
 
     #[test]
     fn build_light_cluster_data_counts_visible_lights() {
@@ -550,4 +553,29 @@ mod tests {
         assert!(data.metrics.visible_lights >= 1);
         assert!(data.metrics.total_clusters > 0);
     }
+
+    #[test]
+    fn falloff_mode_is_written_to_the_light_uniform() {
+        let camera = Camera3D::new(Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO, 60.0_f32.to_radians(), 0.1, 100.0);
+        let viewport = PhysicalSize::new(640, 480);
+        let view = camera.view_matrix();
+        let proj = camera.projection_matrix(viewport.width as f32 / viewport.height as f32);
+        let mut scratch = LightClusterScratch::default();
+        let lights = vec![
+            ScenePointLight::new(Vec3::ZERO, Vec3::splat(1.0), 4.0, 1.0),
+            ScenePointLight::new(Vec3::new(1.0, 0.0, 0.0), Vec3::splat(1.0), 4.0, 1.0)
+                .with_falloff(PointLightFalloff::Smoothstep),
+            ScenePointLight::new(Vec3::new(-1.0, 0.0, 0.0), Vec3::splat(1.0), 4.0, 1.0)
+                .with_falloff(PointLightFalloff::Linear),
+        ];
+        let data = build_light_cluster_data(&lights, &camera, viewport, view, proj, &mut scratch);
+        assert_eq!(data.metrics.visible_lights, 3);
+        let modes: Vec<u32> = data.uniform.lights[..3].iter().map(|light| light.params[0]).collect();
+        assert_eq!(modes, vec![0, 1, 2]);
+        assert_ne!(
+            hash_point_lights(&lights[..1]),
+            hash_point_lights(&[lights[0].with_falloff(PointLightFalloff::Linear)]),
+            "changing only the falloff must invalidate the cached upload"
+        );
+    }
 }
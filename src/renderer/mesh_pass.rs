@@ -1,3 +1,5 @@
+use crate::material_registry::MeshPipelineKey;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 #[repr(C)]
@@ -44,7 +46,8 @@ impl MeshPass {
 }
 
 pub(super) struct MeshPipelineResources {
-    pub pipeline: wgpu::RenderPipeline,
+    /// One variant per [`MeshPipelineKey`], sharing the shader module and layout.
+    pub pipelines: HashMap<MeshPipelineKey, wgpu::RenderPipeline>,
     pub frame_draw_bgl: Arc<wgpu::BindGroupLayout>,
     pub skinning_bgl: Arc<wgpu::BindGroupLayout>,
     pub material_bgl: Arc<wgpu::BindGroupLayout>,
@@ -0,0 +1,475 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use winit::dpi::PhysicalSize;
+
+/// Maximum readbacks a single owner may have queued or in flight at once.
+pub const READBACK_MAX_IN_FLIGHT_PER_OWNER: usize = 4;
+/// Maximum bytes all owners together may request in one frame.
+pub const READBACK_MAX_BYTES_PER_FRAME: u64 = 4 * 1024 * 1024;
+
+const MAP_PENDING: u8 = 0;
+const MAP_READY: u8 = 1;
+const MAP_FAILED: u8 = 2;
+
+/// Texture a readback copies from.
+#[derive(Clone, Debug)]
+pub enum ReadbackTarget {
+    /// The frame color target as it stands right after the sprite pass.
+    SpritePass,
+    /// Any texture created with `COPY_SRC` usage.
+    Texture(wgpu::Texture),
+}
+
+impl ReadbackTarget {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ReadbackTarget::SpritePass => "sprite_pass",
+            ReadbackTarget::Texture(_) => "texture",
+        }
+    }
+}
+
+/// Pixel rectangle to read back, in texels from the top-left corner.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReadbackRegion {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl ReadbackRegion {
+    pub fn new(x: u32, y: u32, width: u32, height: u32) -> Self {
+        Self { x, y, width, height }
+    }
+
+    /// Size of the tightly packed RGBA8 result.
+    pub fn byte_len(&self) -> u64 {
+        self.width as u64 * self.height as u64 * 4
+    }
+
+    fn fits(&self, size: PhysicalSize<u32>) -> bool {
+        self.width > 0
+            && self.height > 0
+            && self.x.checked_add(self.width).is_some_and(|right| right <= size.width)
+            && self.y.checked_add(self.height).is_some_and(|bottom| bottom <= size.height)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ReadbackTicket(u64);
+
+impl ReadbackTicket {
+    pub fn id(self) -> u64 {
+        self.0
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReadbackError {
+    EmptyRegion,
+    OutOfBounds { region: ReadbackRegion, size: PhysicalSize<u32> },
+    UnsupportedFormat(wgpu::TextureFormat),
+    TooManyInFlight { owner: String, limit: usize },
+    FrameBudgetExceeded { requested: u64, remaining: u64 },
+}
+
+impl std::fmt::Display for ReadbackError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReadbackError::EmptyRegion => write!(f, "readback region is empty"),
+            ReadbackError::OutOfBounds { region, size } => write!(
+                f,
+                "readback region {}x{}+{}+{} exceeds {}x{} target",
+                region.width, region.height, region.x, region.y, size.width, size.height
+            ),
+            ReadbackError::UnsupportedFormat(format) => {
+                write!(f, "readback does not support {format:?} textures")
+            }
+            ReadbackError::TooManyInFlight { owner, limit } => {
+                write!(f, "'{owner}' already has {limit} readbacks in flight")
+            }
+            ReadbackError::FrameBudgetExceeded { requested, remaining } => write!(
+                f,
+                "readback of {requested} bytes exceeds the remaining frame budget ({remaining} bytes)"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ReadbackError {}
+
+/// A finished readback. `data` holds tightly packed RGBA8 rows on success.
+#[derive(Clone, Debug)]
+pub struct CompletedReadback {
+    pub ticket: ReadbackTicket,
+    pub owner: String,
+    pub target: &'static str,
+    pub region: ReadbackRegion,
+    pub data: Result<Vec<u8>, String>,
+    pub elapsed: Duration,
+    pub frames_waited: u32,
+}
+
+struct QueuedReadback {
+    ticket: ReadbackTicket,
+    owner: String,
+    target: ReadbackTarget,
+    region: ReadbackRegion,
+    /// Covers the whole frame target; `region` is filled in once the copy is recorded.
+    full_frame: bool,
+    requested_at: Instant,
+    frames_waited: u32,
+}
+
+struct InFlightReadback {
+    request: QueuedReadback,
+    buffer: wgpu::Buffer,
+    padded_row_bytes: u32,
+    swap_red_blue: bool,
+    map_state: Arc<AtomicU8>,
+    mapping: bool,
+}
+
+/// Non-blocking texture readbacks: requests are copied into staging buffers during a frame, mapped
+/// asynchronously after submission, and collected on later frames once the GPU has finished.
+#[derive(Default)]
+pub(super) struct ReadbackQueue {
+    next_ticket: u64,
+    queued: Vec<QueuedReadback>,
+    in_flight: Vec<InFlightReadback>,
+    completed: Vec<CompletedReadback>,
+    per_owner: HashMap<String, usize>,
+    throttled: Vec<String>,
+    frame_bytes: u64,
+}
+
+impl ReadbackQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues a readback on behalf of `owner`. `target_size` is the current size of the target and
+    /// is used to reject regions that cannot fit.
+    pub fn request(
+        &mut self,
+        owner: &str,
+        target: ReadbackTarget,
+        region: ReadbackRegion,
+        target_size: PhysicalSize<u32>,
+    ) -> Result<ReadbackTicket, ReadbackError> {
+        if region.width == 0 || region.height == 0 {
+            return Err(ReadbackError::EmptyRegion);
+        }
+        if !region.fits(target_size) {
+            return Err(ReadbackError::OutOfBounds { region, size: target_size });
+        }
+        if let ReadbackTarget::Texture(texture) = &target {
+            texel_swizzle(texture.format())?;
+        }
+        self.check_in_flight(owner)?;
+        let remaining = READBACK_MAX_BYTES_PER_FRAME.saturating_sub(self.frame_bytes);
+        if region.byte_len() > remaining {
+            self.throttled.push(owner.to_string());
+            return Err(ReadbackError::FrameBudgetExceeded { requested: region.byte_len(), remaining });
+        }
+        self.frame_bytes += region.byte_len();
+        Ok(self.enqueue(owner, target, region, false))
+    }
+
+    /// Queues a copy of the whole sprite-pass frame target on behalf of `owner`. The region is
+    /// resolved from the frame texture when the copy is recorded, and the request only counts
+    /// against the per-owner in-flight limit, not the per-frame byte budget.
+    pub fn request_frame_capture(&mut self, owner: &str) -> Result<ReadbackTicket, ReadbackError> {
+        self.check_in_flight(owner)?;
+        Ok(self.enqueue(owner, ReadbackTarget::SpritePass, ReadbackRegion::new(0, 0, 0, 0), true))
+    }
+
+    fn check_in_flight(&mut self, owner: &str) -> Result<(), ReadbackError> {
+        let active = self.per_owner.get(owner).copied().unwrap_or(0);
+        if active >= READBACK_MAX_IN_FLIGHT_PER_OWNER {
+            self.throttled.push(owner.to_string());
+            return Err(ReadbackError::TooManyInFlight {
+                owner: owner.to_string(),
+                limit: READBACK_MAX_IN_FLIGHT_PER_OWNER,
+            });
+        }
+        Ok(())
+    }
+
+    fn enqueue(
+        &mut self,
+        owner: &str,
+        target: ReadbackTarget,
+        region: ReadbackRegion,
+        full_frame: bool,
+    ) -> ReadbackTicket {
+        *self.per_owner.entry(owner.to_string()).or_default() += 1;
+        self.next_ticket += 1;
+        let ticket = ReadbackTicket(self.next_ticket);
+        self.queued.push(QueuedReadback {
+            ticket,
+            owner: owner.to_string(),
+            target,
+            region,
+            full_frame,
+            requested_at: Instant::now(),
+            frames_waited: 0,
+        });
+        ticket
+    }
+
+    pub fn in_flight_for(&self, owner: &str) -> usize {
+        self.per_owner.get(owner).copied().unwrap_or(0)
+    }
+
+    /// Opens the next frame's byte budget; called once the current frame's copies are submitted.
+    pub fn reset_frame_budget(&mut self) {
+        self.frame_bytes = 0;
+    }
+
+    /// Records copies for every queued request whose source is available this frame. Sprite-pass
+    /// requests are skipped when `frame_texture` is `None` (e.g. the surface lacks `COPY_SRC`).
+    pub fn encode_copies(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        frame_texture: Option<&wgpu::Texture>,
+    ) {
+        for mut request in std::mem::take(&mut self.queued) {
+            let source = match &request.target {
+                ReadbackTarget::SpritePass => match frame_texture {
+                    Some(texture) => texture.clone(),
+                    None => {
+                        self.fail(request, "frame target does not support readback".to_string());
+                        continue;
+                    }
+                },
+                ReadbackTarget::Texture(texture) => texture.clone(),
+            };
+            let size = PhysicalSize::new(source.width(), source.height());
+            if request.full_frame {
+                request.region = ReadbackRegion::new(0, 0, size.width, size.height);
+            }
+            if !request.region.fits(size) {
+                let message = ReadbackError::OutOfBounds { region: request.region, size }.to_string();
+                self.fail(request, message);
+                continue;
+            }
+            let swap_red_blue = match texel_swizzle(source.format()) {
+                Ok(swap) => swap,
+                Err(err) => {
+                    self.fail(request, err.to_string());
+                    continue;
+                }
+            };
+            let region = request.region;
+            let row_bytes = region.width * 4;
+            let alignment = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+            let padded_row_bytes = row_bytes.div_ceil(alignment) * alignment;
+            let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("Texture Readback Staging Buffer"),
+                size: padded_row_bytes as u64 * region.height as u64,
+                usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+                mapped_at_creation: false,
+            });
+            encoder.copy_texture_to_buffer(
+                wgpu::TexelCopyTextureInfo {
+                    texture: &source,
+                    mip_level: 0,
+                    origin: wgpu::Origin3d { x: region.x, y: region.y, z: 0 },
+                    aspect: wgpu::TextureAspect::All,
+                },
+                wgpu::TexelCopyBufferInfo {
+                    buffer: &buffer,
+                    layout: wgpu::TexelCopyBufferLayout {
+                        offset: 0,
+                        bytes_per_row: Some(padded_row_bytes),
+                        rows_per_image: Some(region.height),
+                    },
+                },
+                wgpu::Extent3d { width: region.width, height: region.height, depth_or_array_layers: 1 },
+            );
+            self.in_flight.push(InFlightReadback {
+                request,
+                buffer,
+                padded_row_bytes,
+                swap_red_blue,
+                map_state: Arc::new(AtomicU8::new(MAP_PENDING)),
+                mapping: false,
+            });
+        }
+    }
+
+    /// Requests mapping for copies submitted this frame. Must run after the encoder is submitted.
+    pub fn map_submitted(&mut self) {
+        for readback in self.in_flight.iter_mut().filter(|readback| !readback.mapping) {
+            readback.mapping = true;
+            let state = Arc::clone(&readback.map_state);
+            readback.buffer.slice(..).map_async(wgpu::MapMode::Read, move |result| {
+                state.store(if result.is_ok() { MAP_READY } else { MAP_FAILED }, Ordering::Release);
+            });
+        }
+    }
+
+    /// Moves readbacks whose mapping has resolved into the completed list without waiting on the GPU.
+    pub fn collect_mapped(&mut self) {
+        let mut index = 0;
+        while index < self.in_flight.len() {
+            let state = self.in_flight[index].map_state.load(Ordering::Acquire);
+            if state == MAP_PENDING {
+                self.in_flight[index].request.frames_waited += 1;
+                index += 1;
+                continue;
+            }
+            let readback = self.in_flight.swap_remove(index);
+            let data = if state == MAP_READY {
+                let region = readback.request.region;
+                let row_bytes = (region.width * 4) as usize;
+                let mut pixels = Vec::with_capacity(row_bytes * region.height as usize);
+                {
+                    let mapped = readback.buffer.slice(..).get_mapped_range();
+                    for row in mapped.chunks(readback.padded_row_bytes as usize) {
+                        pixels.extend_from_slice(&row[..row_bytes]);
+                    }
+                }
+                readback.buffer.unmap();
+                if readback.swap_red_blue {
+                    for pixel in pixels.chunks_exact_mut(4) {
+                        pixel.swap(0, 2);
+                    }
+                }
+                Ok(pixels)
+            } else {
+                Err("mapping the readback buffer failed".to_string())
+            };
+            self.finish(readback.request, data);
+        }
+    }
+
+    /// Fails every queued and in-flight request, e.g. when the device their buffers belong to is lost.
+    /// Owners still receive a completed entry for each ticket.
+    pub fn fail_all(&mut self, message: &str) {
+        let requests: Vec<QueuedReadback> = std::mem::take(&mut self.queued)
+            .into_iter()
+            .chain(std::mem::take(&mut self.in_flight).into_iter().map(|readback| readback.request))
+            .collect();
+        for request in requests {
+            self.fail(request, message.to_string());
+        }
+        self.frame_bytes = 0;
+    }
+
+    pub fn take_completed(&mut self) -> Vec<CompletedReadback> {
+        std::mem::take(&mut self.completed)
+    }
+
+    /// Removes and returns only `owner`'s finished readbacks, leaving the rest for `take_completed`.
+    pub fn take_completed_for(&mut self, owner: &str) -> Vec<CompletedReadback> {
+        let (taken, rest) =
+            std::mem::take(&mut self.completed).into_iter().partition(|done| done.owner == owner);
+        self.completed = rest;
+        taken
+    }
+
+    /// Owners of requests rejected by a budget since the last call, one entry per rejection.
+    pub fn take_throttled(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.throttled)
+    }
+
+    fn fail(&mut self, request: QueuedReadback, message: String) {
+        self.finish(request, Err(message));
+    }
+
+    fn finish(&mut self, request: QueuedReadback, data: Result<Vec<u8>, String>) {
+        if let Some(count) = self.per_owner.get_mut(&request.owner) {
+            *count = count.saturating_sub(1);
+            if *count == 0 {
+                self.per_owner.remove(&request.owner);
+            }
+        }
+        self.completed.push(CompletedReadback {
+            ticket: request.ticket,
+            owner: request.owner,
+            target: request.target.label(),
+            region: request.region,
+            data,
+            elapsed: request.requested_at.elapsed(),
+            frames_waited: request.frames_waited,
+        });
+    }
+}
+
+/// Returns whether texels need their red and blue channels swapped to produce RGBA8.
+fn texel_swizzle(format: wgpu::TextureFormat) -> Result<bool, ReadbackError> {
+    match format {
+        wgpu::TextureFormat::Bgra8Unorm | wgpu::TextureFormat::Bgra8UnormSrgb => Ok(true),
+        wgpu::TextureFormat::Rgba8Unorm | wgpu::TextureFormat::Rgba8UnormSrgb => Ok(false),
+        other => Err(ReadbackError::UnsupportedFormat(other)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn crop() -> ReadbackRegion {
+        ReadbackRegion::new(0, 0, 256, 256)
+    }
+
+    #[test]
+    fn requests_are_validated_against_the_target() {
+        let mut queue = ReadbackQueue::new();
+        let size = PhysicalSize::new(300, 200);
+        assert_eq!(
+            queue.request("a", ReadbackTarget::SpritePass, ReadbackRegion::new(0, 0, 0, 4), size),
+            Err(ReadbackError::EmptyRegion)
+        );
+        assert!(matches!(
+            queue.request("a", ReadbackTarget::SpritePass, crop(), size),
+            Err(ReadbackError::OutOfBounds { .. })
+        ));
+        let ticket = queue
+            .request("a", ReadbackTarget::SpritePass, ReadbackRegion::new(44, 0, 256, 200), size)
+            .expect("fits");
+        assert_eq!(queue.in_flight_for("a"), 1);
+        assert!(queue.take_throttled().is_empty());
+        assert_eq!(ticket.id(), 1);
+    }
+
+    #[test]
+    fn budgets_limit_in_flight_requests_and_frame_bytes() {
+        let mut queue = ReadbackQueue::new();
+        let size = PhysicalSize::new(4096, 4096);
+        for _ in 0..READBACK_MAX_IN_FLIGHT_PER_OWNER {
+            queue.request("greedy", ReadbackTarget::SpritePass, crop(), size).expect("within budget");
+        }
+        assert!(matches!(
+            queue.request("greedy", ReadbackTarget::SpritePass, crop(), size),
+            Err(ReadbackError::TooManyInFlight { .. })
+        ));
+
+        let large = ReadbackRegion::new(0, 0, 1024, 1024);
+        assert!(matches!(
+            queue.request("other", ReadbackTarget::SpritePass, large, size),
+            Err(ReadbackError::FrameBudgetExceeded { .. })
+        ));
+        queue.reset_frame_budget();
+        queue.request("other", ReadbackTarget::SpritePass, large, size).expect("fresh frame budget");
+        assert_eq!(queue.take_throttled(), vec!["greedy".to_string(), "other".to_string()]);
+        queue.request_frame_capture("other").expect("full-frame captures skip the byte budget");
+        assert!(matches!(queue.request_frame_capture("greedy"), Err(ReadbackError::TooManyInFlight { .. })));
+        assert_eq!(queue.take_throttled(), vec!["greedy".to_string()]);
+
+        let dropped = queue.queued.remove(0);
+        queue.fail(dropped, "dropped".to_string());
+        assert_eq!(queue.in_flight_for("greedy"), READBACK_MAX_IN_FLIGHT_PER_OWNER - 1);
+        let completed = queue.take_completed();
+        assert_eq!(completed.len(), 1);
+        assert_eq!(completed[0].owner, "greedy");
+        assert!(completed[0].data.is_err());
+    }
+}
@@ -0,0 +1,218 @@
+use anyhow::{Context, Result};
+use winit::dpi::PhysicalSize;
+
+use super::window_surface::create_depth_texture;
+
+/// Smallest and largest factors accepted by `Renderer::set_render_scale`.
+pub const MIN_RENDER_SCALE: f32 = 0.25;
+pub const MAX_RENDER_SCALE: f32 = 2.0;
+
+/// Clamps `scale` to the supported range; non-finite values fall back to native resolution.
+pub fn clamp_render_scale(scale: f32) -> f32 {
+    if scale.is_finite() {
+        scale.clamp(MIN_RENDER_SCALE, MAX_RENDER_SCALE)
+    } else {
+        1.0
+    }
+}
+
+/// Size of the scene target for a `surface` rendered at `scale`, never smaller than one pixel.
+pub fn scaled_render_size(surface: PhysicalSize<u32>, scale: f32) -> PhysicalSize<u32> {
+    let scale_axis = |extent: u32| ((extent as f32 * scale).round() as u32).max(1);
+    PhysicalSize::new(scale_axis(surface.width), scale_axis(surface.height))
+}
+
+struct SceneTarget {
+    size: PhysicalSize<u32>,
+    format: wgpu::TextureFormat,
+    color_view: wgpu::TextureView,
+    depth_view: wgpu::TextureView,
+    blit_bind_group: wgpu::BindGroup,
+}
+
+/// Offscreen color/depth target the scene renders into when the render scale is not 1.0, plus
+/// the bilinear blit that stretches it over the swapchain image.
+#[derive(Default)]
+pub(super) struct ScaledTargetPass {
+    pipeline: Option<(wgpu::TextureFormat, wgpu::RenderPipeline)>,
+    bind_group_layout: Option<wgpu::BindGroupLayout>,
+    sampler: Option<wgpu::Sampler>,
+    target: Option<SceneTarget>,
+}
+
+impl ScaledTargetPass {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// (Re)creates the target when the size or format changed and returns its color view.
+    pub fn prepare(
+        &mut self,
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        size: PhysicalSize<u32>,
+    ) -> Result<wgpu::TextureView> {
+        if self.pipeline.as_ref().is_none_or(|(pipeline_format, _)| *pipeline_format != format) {
+            self.init_pipeline(device, format);
+        }
+        let stale = self.target.as_ref().is_none_or(|target| target.size != size || target.format != format);
+        if stale {
+            self.target = Some(self.create_target(device, format, size)?);
+        }
+        let target = self.target.as_ref().context("Scaled render target missing")?;
+        Ok(target.color_view.clone())
+    }
+
+    /// Drops the offscreen target, e.g. once rendering is back at native resolution.
+    pub fn release(&mut self) {
+        self.target = None;
+    }
+
+    /// Depth view and size of the prepared target, or `None` while rendering at native resolution.
+    pub fn active_depth(&self) -> Option<(&wgpu::TextureView, PhysicalSize<u32>)> {
+        self.target.as_ref().map(|target| (&target.depth_view, target.size))
+    }
+
+    /// Samples the whole scene target bilinearly into `output`, replacing its contents.
+    pub fn encode_blit(&self, encoder: &mut wgpu::CommandEncoder, output: &wgpu::TextureView) -> Result<()> {
+        let (_, pipeline) = self.pipeline.as_ref().context("Scaled blit pipeline missing")?;
+        let target = self.target.as_ref().context("Scaled render target missing")?;
+        let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Render Scale Blit Pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: output,
+                depth_slice: None,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: None,
+            occlusion_query_set: None,
+            timestamp_writes: None,
+        });
+        pass.set_pipeline(pipeline);
+        pass.set_bind_group(0, &target.blit_bind_group, &[]);
+        pass.draw(0..3, 0..1);
+        Ok(())
+    }
+
+    fn init_pipeline(&mut self, device: &wgpu::Device, format: wgpu::TextureFormat) {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Render Scale Blit Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("../../assets/shaders/scaled_blit.wgsl").into()),
+        });
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Render Scale Blit BGL"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+        });
+        let layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Render Scale Blit Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Render Scale Blit Pipeline"),
+            layout: Some(&layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_main"),
+                buffers: &[],
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_main"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            }),
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+            cache: None,
+        });
+        self.sampler = Some(device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("Render Scale Blit Sampler"),
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        }));
+        self.pipeline = Some((format, pipeline));
+        self.bind_group_layout = Some(bind_group_layout);
+        // Bind groups reference the old layout.
+        self.target = None;
+    }
+
+    fn create_target(
+        &self,
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        size: PhysicalSize<u32>,
+    ) -> Result<SceneTarget> {
+        let layout = self.bind_group_layout.as_ref().context("Scaled blit layout missing")?;
+        let sampler = self.sampler.as_ref().context("Scaled blit sampler missing")?;
+        let color = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Render Scale Color Target"),
+            size: wgpu::Extent3d { width: size.width, height: size.height, depth_or_array_layers: 1 },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        });
+        let color_view = color.create_view(&wgpu::TextureViewDescriptor::default());
+        let (_, depth_view) = create_depth_texture(device, size)?;
+        let blit_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Render Scale Blit BG"),
+            layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&color_view),
+                },
+                wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::Sampler(sampler) },
+            ],
+        });
+        Ok(SceneTarget { size, format, color_view, depth_view, blit_bind_group })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_scale_is_clamped_and_sizes_round_to_whole_pixels() {
+        assert_eq!(clamp_render_scale(0.1), MIN_RENDER_SCALE);
+        assert_eq!(clamp_render_scale(3.0), MAX_RENDER_SCALE);
+        assert_eq!(clamp_render_scale(f32::NAN), 1.0);
+        assert_eq!(scaled_render_size(PhysicalSize::new(1280, 720), 0.5), PhysicalSize::new(640, 360));
+        assert_eq!(scaled_render_size(PhysicalSize::new(3, 1), 0.25), PhysicalSize::new(1, 1));
+    }
+}
@@ -1,18 +1,21 @@
 use anyhow::{Context, Result};
 use glam::{Mat4, Vec3, Vec4};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::time::Instant;
 use wgpu::util::DeviceExt;
 use winit::dpi::PhysicalSize;
 
+use crate::material_registry::MeshPipelineKey;
+
 use super::{
-    mesh_pass::PaletteUploadStats, Camera3D, MeshDraw, RenderViewport, SceneLightingState, DEPTH_FORMAT,
-    MAX_SHADOW_CASCADES, MAX_SKIN_JOINTS, SKINNING_CACHE_HEADROOM,
+    mesh_pass::PaletteUploadStats, Camera3D, Frustum, MeshDraw, RenderStats, RenderViewport,
+    SceneLightingState, DEPTH_FORMAT, MAX_SHADOW_CASCADES, MAX_SKIN_JOINTS, SKINNING_CACHE_HEADROOM,
 };
 
 struct ShadowPipelineResources {
-    pipeline: wgpu::RenderPipeline,
+    /// One variant per material cull mode, so double-sided casters shadow from both faces.
+    pipelines: HashMap<MeshPipelineKey, wgpu::RenderPipeline>,
     skinning_bgl: Arc<wgpu::BindGroupLayout>,
 }
 
@@ -53,6 +56,7 @@ pub struct ShadowPassParams<'a> {
     pub queue: &'a wgpu::Queue,
     pub skinning_limit_warnings: &'a mut HashSet<usize>,
     pub palette_stats: &'a mut PaletteUploadStats,
+    pub render_stats: &'a mut RenderStats,
 }
 
 impl ShadowPass {
@@ -147,9 +151,9 @@ impl ShadowPass {
         }
         self.cascade_splits = splits;
 
-        let (pipeline, skinning_bgl) = {
+        let (pipelines, skinning_bgl) = {
             let resources = self.resources.as_ref().context("Shadow pipeline resources missing")?;
-            (resources.pipeline.clone(), resources.skinning_bgl.clone())
+            (resources.pipelines.clone(), resources.skinning_bgl.clone())
         };
         let frame_bg = self.frame_bind_group.as_ref().context("Shadow frame bind group missing")?.clone();
         let draw_bg = self.draw_bind_group.as_ref().context("Shadow draw bind group missing")?.clone();
@@ -217,13 +221,25 @@ impl ShadowPass {
                 occlusion_query_set: None,
                 timestamp_writes: None,
             });
-            pass.set_pipeline(&pipeline);
+            let mut bound_pipeline = None;
             let res_f = resolution as f32;
             pass.set_viewport(0.0, 0.0, res_f, res_f, 0.0, 1.0);
             pass.set_scissor_rect(0, 0, resolution, resolution);
             pass.set_bind_group(0, &frame_bg, &[]);
 
+            // Casters outside this cascade's light volume would be clipped anyway; skip their uploads.
+            let cascade_frustum = Frustum::from_view_projection(self.cascade_matrices[cascade_index]);
             for draw in &casters {
+                let (center, radius) = draw.mesh.bounds.world_sphere(draw.model);
+                if radius > 0.0 && !cascade_frustum.intersects_sphere(center, radius) {
+                    continue;
+                }
+                let pipeline_key = draw.material.pipeline_key();
+                if bound_pipeline != Some(pipeline_key) {
+                    let pipeline = pipelines.get(&pipeline_key).context("Shadow pipeline variant missing")?;
+                    pass.set_pipeline(pipeline);
+                    bound_pipeline = Some(pipeline_key);
+                }
                 let palette_len = draw.skin_palette.as_ref().map(|palette| palette.len()).unwrap_or(0);
                 if palette_len > MAX_SKIN_JOINTS && params.skinning_limit_warnings.insert(palette_len) {
                     eprintln!(
@@ -284,6 +300,8 @@ impl ShadowPass {
                 pass.set_vertex_buffer(0, draw.mesh.vertex_buffer.slice(..));
                 pass.set_index_buffer(draw.mesh.index_buffer.slice(..), wgpu::IndexFormat::Uint32);
                 pass.draw_indexed(0..draw.mesh.index_count, 0, 0..1);
+                params.render_stats.shadow_draw_calls += 1;
+                params.render_stats.total_triangles += u64::from(draw.mesh.index_count / 3);
             }
         }
 
@@ -353,35 +371,38 @@ impl ShadowPass {
                 push_constant_ranges: &[],
             });
 
-            let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-                label: Some("Shadow Pipeline"),
-                layout: Some(&pipeline_layout),
-                vertex: wgpu::VertexState {
-                    module: &shader,
-                    entry_point: Some("vs_main"),
-                    buffers: &[crate::mesh::MeshVertex::layout()],
-                    compilation_options: wgpu::PipelineCompilationOptions::default(),
-                },
-                fragment: None,
-                primitive: wgpu::PrimitiveState {
-                    topology: wgpu::PrimitiveTopology::TriangleList,
-                    front_face: wgpu::FrontFace::Ccw,
-                    cull_mode: Some(wgpu::Face::Back),
-                    ..Default::default()
-                },
-                depth_stencil: Some(wgpu::DepthStencilState {
-                    format: DEPTH_FORMAT,
-                    depth_write_enabled: true,
-                    depth_compare: wgpu::CompareFunction::LessEqual,
-                    stencil: wgpu::StencilState::default(),
-                    bias: wgpu::DepthBiasState::default(),
-                }),
-                multisample: wgpu::MultisampleState::default(),
-                multiview: None,
-                cache: None,
-            });
-
-            self.resources = Some(ShadowPipelineResources { pipeline, skinning_bgl });
+            let create_pipeline = |key: MeshPipelineKey| {
+                device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                    label: Some("Shadow Pipeline"),
+                    layout: Some(&pipeline_layout),
+                    vertex: wgpu::VertexState {
+                        module: &shader,
+                        entry_point: Some("vs_main"),
+                        buffers: &[crate::mesh::MeshVertex::layout()],
+                        compilation_options: wgpu::PipelineCompilationOptions::default(),
+                    },
+                    fragment: None,
+                    primitive: wgpu::PrimitiveState {
+                        topology: wgpu::PrimitiveTopology::TriangleList,
+                        front_face: wgpu::FrontFace::Ccw,
+                        cull_mode: key.cull_mode.face(),
+                        ..Default::default()
+                    },
+                    depth_stencil: Some(wgpu::DepthStencilState {
+                        format: DEPTH_FORMAT,
+                        depth_write_enabled: true,
+                        depth_compare: wgpu::CompareFunction::LessEqual,
+                        stencil: wgpu::StencilState::default(),
+                        bias: wgpu::DepthBiasState::default(),
+                    }),
+                    multisample: wgpu::MultisampleState::default(),
+                    multiview: None,
+                    cache: None,
+                })
+            };
+            let pipelines = MeshPipelineKey::all().map(|key| (key, create_pipeline(key))).collect();
+
+            self.resources = Some(ShadowPipelineResources { pipelines, skinning_bgl });
             self.skinning_identity_buffer = None;
             self.skinning_identity_bind_group = None;
 
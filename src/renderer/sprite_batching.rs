@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+use std::mem;
+use std::sync::Arc;
+
+use super::{InstanceData, SpriteBatch};
+use crate::alloc_sampler::{self, AllocationSite};
+use crate::ecs::SpriteInstance;
+
+/// Atlas key plus normal-map path; sprites that share both can be drawn in one batch.
+pub type SpriteBatchKey = (Arc<str>, Option<Arc<str>>);
+
+/// Views and upload hint for one batch, resolved by the caller of [`SpriteBatcher::build`].
+pub struct SpriteBatchTextures {
+    pub view: Arc<wgpu::TextureView>,
+    pub normal_view: Option<Arc<wgpu::TextureView>>,
+    /// See [`SpriteBatch::dirty`].
+    pub dirty: bool,
+}
+
+/// Turns collected sprites into the instance buffer and [`SpriteBatch`] list that
+/// `Renderer::render_frame` draws, keeping its per-batch buffers between frames. The editor and
+/// headless screenshots both batch through it, so they draw sprites in the same order.
+#[derive(Default)]
+pub struct SpriteBatcher {
+    batch_map: HashMap<SpriteBatchKey, Vec<InstanceData>>,
+    batch_order: Vec<SpriteBatchKey>,
+    pool: Vec<Vec<InstanceData>>,
+}
+
+impl SpriteBatcher {
+    /// Batches `sprites` in the order given. When `sorted` (the sprites went through
+    /// [`super::sort_sprite_instances`] in a mode other than creation order), each batch is a run of
+    /// neighbouring sprites sharing an atlas and normal map; otherwise there is one batch per pair,
+    /// in the order the pair first appears. `textures` resolves the views for a batch's atlas and
+    /// normal map, or returns `None` to drop the batch, e.g. when the atlas is unavailable.
+    pub fn build(
+        &mut self,
+        sprites: Vec<SpriteInstance>,
+        sorted: bool,
+        mut textures: impl FnMut(&str, Option<&str>) -> Option<SpriteBatchTextures>,
+    ) -> (Vec<InstanceData>, Vec<SpriteBatch>) {
+        self.recycle();
+        let mut batch_runs: Vec<(SpriteBatchKey, Vec<InstanceData>)> = Vec::new();
+        if sorted {
+            // Sorted modes interleave atlases, so batches become runs of neighbouring sprites.
+            for instance in sprites {
+                let normal_map = instance.normal_map.clone();
+                let (atlas_key, gpu_data) = instance.into_gpu();
+                let batch_key = (atlas_key, normal_map);
+                match batch_runs.last_mut() {
+                    Some((last_key, bucket)) if *last_key == batch_key => bucket.push(gpu_data),
+                    _ => {
+                        let mut bucket = self.take_buffer();
+                        bucket.push(gpu_data);
+                        batch_runs.push((batch_key, bucket));
+                    }
+                }
+            }
+        } else {
+            for instance in sprites {
+                let normal_map = instance.normal_map.clone();
+                let (atlas_key, gpu_data) = instance.into_gpu();
+                let batch_key = (atlas_key, normal_map);
+                if let Some(existing) = self.batch_map.get_mut(&batch_key) {
+                    existing.push(gpu_data);
+                } else {
+                    let mut bucket = self.take_buffer();
+                    bucket.push(gpu_data);
+                    self.batch_order.push(batch_key.clone());
+                    self.batch_map.insert(batch_key, bucket);
+                }
+            }
+            let mut ordered_keys = mem::take(&mut self.batch_order);
+            for batch_key in ordered_keys.drain(..) {
+                if let Some(bucket) = self.batch_map.remove(&batch_key) {
+                    batch_runs.push((batch_key, bucket));
+                }
+            }
+            self.batch_order = ordered_keys;
+        }
+        let mut instances: Vec<InstanceData> = Vec::new();
+        let total_instances: usize = batch_runs.iter().map(|(_, bucket)| bucket.len()).sum();
+        instances.reserve(total_instances);
+        let mut sprite_batches: Vec<SpriteBatch> = Vec::new();
+        let mut batch_runs = batch_runs.into_iter();
+        for (batch_key, mut batch_instances) in batch_runs.by_ref() {
+            let (atlas, normal_map) = batch_key;
+            if batch_instances.is_empty() {
+                self.pool.push(batch_instances);
+                continue;
+            }
+            let start_len = instances.len();
+            instances.append(&mut batch_instances);
+            if instances.len() > u32::MAX as usize {
+                eprintln!("Too many sprite instances to render ({}).", instances.len());
+                instances.truncate(start_len);
+                batch_instances.clear();
+                self.pool.push(batch_instances);
+                break;
+            }
+            let start = start_len as u32;
+            let end = instances.len() as u32;
+            match textures(atlas.as_ref(), normal_map.as_deref()) {
+                Some(SpriteBatchTextures { view, normal_view, dirty }) => {
+                    sprite_batches.push(SpriteBatch { atlas, range: start..end, view, normal_view, dirty });
+                }
+                None => instances.truncate(start_len),
+            }
+            batch_instances.clear();
+            self.pool.push(batch_instances);
+        }
+        for (_, mut leftover) in batch_runs {
+            leftover.clear();
+            self.pool.push(leftover);
+        }
+        alloc_sampler::record_growth::<InstanceData>(AllocationSite::SpriteBatches, 0, instances.capacity());
+        alloc_sampler::record_growth::<SpriteBatch>(
+            AllocationSite::SpriteBatches,
+            0,
+            sprite_batches.capacity(),
+        );
+        (instances, sprite_batches)
+    }
+
+    fn take_buffer(&mut self) -> Vec<InstanceData> {
+        self.pool.pop().unwrap_or_default()
+    }
+
+    fn recycle(&mut self) {
+        if self.batch_map.is_empty() && self.batch_order.is_empty() {
+            return;
+        }
+        for (_, mut instances) in self.batch_map.drain() {
+            instances.clear();
+            self.pool.push(instances);
+        }
+        self.batch_order.clear();
+    }
+}
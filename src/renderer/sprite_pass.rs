@@ -4,20 +4,26 @@ use std::sync::Arc;
 use std::time::Instant;
 
 use anyhow::{Context, Result};
-use glam::Mat4;
+use bytemuck::Zeroable;
+use glam::{Mat4, Vec3};
 use wgpu::util::DeviceExt;
 use winit::dpi::PhysicalSize;
 
-use super::{InstanceData, RenderViewport};
+use super::{InstanceData, RenderStats, RenderViewport, SpriteBatch};
+use crate::alloc_sampler::{self, AllocationSite};
+use crate::config::SpriteColorSpace;
 
 #[repr(C)]
 #[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
 struct Globals {
     proj: [[f32; 4]; 4],
+    /// xyz: directional light direction (pointing away from the light), w: normal-map ambient term.
+    light: [f32; 4],
 }
 
 struct SpriteBindCacheEntry {
     view: Arc<wgpu::TextureView>,
+    normal_view: Option<Arc<wgpu::TextureView>>,
     sampler_id: u64,
     bind_group: Arc<wgpu::BindGroup>,
 }
@@ -26,10 +32,21 @@ struct SpriteBindCacheEntry {
 pub struct SpriteUploadStats {
     pub frames: u32,
     pub bytes_uploaded: u64,
+    pub bytes_reused: u64,
+    pub write_calls: u32,
     pub total_cpu_ms: f32,
+    /// Number of times the baked static sprite buffer was rewritten.
+    pub static_uploads: u32,
+    pub static_bytes_uploaded: u64,
 }
 
 const SPRITE_BIND_CACHE_LIMIT: usize = 128;
+/// Share of full brightness a normal-mapped texel keeps when it faces away from the light.
+const SPRITE_NORMAL_AMBIENT: f32 = 0.3;
+/// Bound when a batch has no normal map; zero alpha leaves the sprite unlit.
+const FLAT_NORMAL_TEXEL: [u8; 4] = [128, 128, 255, 0];
+/// Changed runs separated by at most this many unchanged instances are merged into one write.
+const INSTANCE_RUN_MERGE_GAP: usize = 16;
 
 pub struct SpritePass {
     pipeline: Option<wgpu::RenderPipeline>,
@@ -40,13 +57,22 @@ pub struct SpritePass {
     globals_bgl: Option<wgpu::BindGroupLayout>,
     texture_bg: Option<wgpu::BindGroup>,
     texture_bgl: Option<wgpu::BindGroupLayout>,
+    flat_normal_view: Option<wgpu::TextureView>,
     instance_buffer: Option<wgpu::Buffer>,
     instance_capacity: usize,
     bind_cache: HashMap<String, SpriteBindCacheEntry>,
     bind_cache_order: VecDeque<String>,
     instance_span: Range<wgpu::BufferAddress>,
-    instance_cursor: wgpu::BufferAddress,
+    resident_instances: Vec<InstanceData>,
+    resident_batches: HashMap<Arc<str>, Range<u32>>,
+    dirty_runs: Vec<Range<usize>>,
+    static_buffer: Option<wgpu::Buffer>,
+    static_capacity: usize,
+    static_count: usize,
     upload_stats: SpriteUploadStats,
+    color_space: SpriteColorSpace,
+    /// Output format the pipeline was last built for.
+    target_format: Option<wgpu::TextureFormat>,
 }
 
 impl Default for SpritePass {
@@ -60,13 +86,21 @@ impl Default for SpritePass {
             globals_bgl: None,
             texture_bg: None,
             texture_bgl: None,
+            flat_normal_view: None,
             instance_buffer: None,
             instance_capacity: 0,
             bind_cache: HashMap::new(),
             bind_cache_order: VecDeque::new(),
             instance_span: 0..0,
-            instance_cursor: 0,
+            resident_instances: Vec::new(),
+            resident_batches: HashMap::new(),
+            dirty_runs: Vec::new(),
+            static_buffer: None,
+            static_capacity: 0,
+            static_count: 0,
             upload_stats: SpriteUploadStats::default(),
+            color_space: SpriteColorSpace::default(),
+            target_format: None,
         }
     }
 }
@@ -76,9 +110,32 @@ impl SpritePass {
         Self::default()
     }
 
+    pub fn color_space(&self) -> SpriteColorSpace {
+        self.color_space
+    }
+
+    /// Selects the color handling used the next time the pipeline is built.
+    pub fn set_color_space(&mut self, color_space: SpriteColorSpace) {
+        self.color_space = color_space;
+    }
+
+    /// Describes how sprite colors reach the current target, for diagnostics.
+    pub fn color_space_summary(&self) -> String {
+        match (self.color_space, self.target_format) {
+            (SpriteColorSpace::Gamma, _) => "Gamma (legacy): tints applied unconverted".to_string(),
+            (SpriteColorSpace::Linear, Some(format)) if !format.is_srgb() => format!(
+                "Linear: sRGB tints decoded, output encoded in shader; {format:?} target blends in gamma space"
+            ),
+            (SpriteColorSpace::Linear, _) => {
+                "Linear: sRGB tints decoded, blended in linear space, encoded by the sRGB target".to_string()
+            }
+        }
+    }
+
     pub fn init_pipeline_with_atlas(
         &mut self,
         device: &wgpu::Device,
+        queue: &wgpu::Queue,
         surface_format: wgpu::TextureFormat,
         atlas_view: wgpu::TextureView,
         sampler: wgpu::Sampler,
@@ -133,8 +190,34 @@ impl SpritePass {
                     ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
                     count: None,
                 },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        multisampled: false,
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                    },
+                    count: None,
+                },
             ],
         });
+        let flat_normal = device.create_texture_with_data(
+            queue,
+            &wgpu::TextureDescriptor {
+                label: Some("Sprite Flat Normal"),
+                size: wgpu::Extent3d { width: 1, height: 1, depth_or_array_layers: 1 },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: wgpu::TextureFormat::Rgba8Unorm,
+                usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+                view_formats: &[],
+            },
+            wgpu::util::TextureDataOrder::LayerMajor,
+            &FLAT_NORMAL_TEXEL,
+        );
+        let flat_normal_view = flat_normal.create_view(&wgpu::TextureViewDescriptor::default());
         let texture_bg = device.create_bind_group(&wgpu::BindGroupDescriptor {
             label: Some("Sprite Texture BG"),
             layout: &texture_bgl,
@@ -144,6 +227,10 @@ impl SpritePass {
                     resource: wgpu::BindingResource::TextureView(&atlas_view),
                 },
                 wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::Sampler(&sampler) },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::TextureView(&flat_normal_view),
+                },
             ],
         });
 
@@ -171,6 +258,11 @@ impl SpritePass {
             push_constant_ranges: &[],
         });
 
+        let linear = self.color_space == SpriteColorSpace::Linear;
+        let color_constants = [
+            ("LINEAR_TINT", if linear { 1.0 } else { 0.0 }),
+            ("ENCODE_OUTPUT", if linear && !surface_format.is_srgb() { 1.0 } else { 0.0 }),
+        ];
         let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
             label: Some("Sprite Pipeline"),
             layout: Some(&pipeline_layout),
@@ -236,7 +328,10 @@ impl SpritePass {
                     blend: Some(wgpu::BlendState::ALPHA_BLENDING),
                     write_mask: wgpu::ColorWrites::ALL,
                 })],
-                compilation_options: wgpu::PipelineCompilationOptions::default(),
+                compilation_options: wgpu::PipelineCompilationOptions {
+                    constants: &color_constants,
+                    ..Default::default()
+                },
             }),
             primitive: wgpu::PrimitiveState {
                 topology: wgpu::PrimitiveTopology::TriangleList,
@@ -249,6 +344,7 @@ impl SpritePass {
         });
 
         self.pipeline = Some(pipeline);
+        self.target_format = Some(surface_format);
         self.vertex_buffer = Some(vertex_buffer);
         self.index_buffer = Some(index_buffer);
         self.globals_bgl = Some(globals_bgl);
@@ -256,79 +352,195 @@ impl SpritePass {
         self.globals_bg = Some(globals_bg);
         self.texture_bgl = Some(texture_bgl);
         self.texture_bg = Some(texture_bg);
+        self.flat_normal_view = Some(flat_normal_view);
         Ok(())
     }
 
     pub fn clear_bind_cache(&mut self) {
         self.bind_cache.clear();
         self.bind_cache_order.clear();
+        self.resident_batches.clear();
     }
 
     pub fn invalidate_bind_group(&mut self, atlas: &str) {
-        self.bind_cache.remove(atlas);
-        self.bind_cache_order.retain(|key| key != atlas);
+        let prefix = format!("{atlas}|");
+        self.bind_cache.retain(|key, _| key != atlas && !key.starts_with(&prefix));
+        self.bind_cache_order.retain(|key| key != atlas && !key.starts_with(&prefix));
+        // A reloaded atlas must not be trusted on the clean-batch fast path.
+        self.resident_batches.remove(atlas);
     }
 
-    pub fn write_globals(&self, queue: &wgpu::Queue, sprite_view_proj: Mat4) -> Result<()> {
+    /// Writes the sprite projection and the directional light used by normal-mapped sprites.
+    pub fn write_globals(
+        &self,
+        queue: &wgpu::Queue,
+        sprite_view_proj: Mat4,
+        light_direction: Vec3,
+    ) -> Result<()> {
         let globals = self.globals_buf.as_ref().context("Sprite globals buffer missing")?;
+        let light = light_direction.normalize_or(Vec3::NEG_Z);
         queue.write_buffer(
             globals,
             0,
-            bytemuck::bytes_of(&Globals { proj: sprite_view_proj.to_cols_array_2d() }),
+            bytemuck::bytes_of(&Globals {
+                proj: sprite_view_proj.to_cols_array_2d(),
+                light: [light.x, light.y, light.z, SPRITE_NORMAL_AMBIENT],
+            }),
         );
         Ok(())
     }
 
+    /// Uploads `instances`, rewriting only the ranges that differ from what the buffer already holds.
+    ///
+    /// Batches flagged clean whose range matches the previous frame are trusted as-is; every other
+    /// instance is compared against the resident copy and changed runs go through `write_buffer`.
     pub fn upload_instances(
         &mut self,
         device: &wgpu::Device,
         queue: &wgpu::Queue,
         instances: &[InstanceData],
+        batches: &[SpriteBatch],
     ) -> Result<()> {
         self.ensure_instance_capacity(device, instances.len())?;
-        self.instance_span = 0..0;
+        let stride = std::mem::size_of::<InstanceData>();
+        self.instance_span = 0..std::mem::size_of_val(instances) as wgpu::BufferAddress;
         if instances.is_empty() {
             return Ok(());
         }
         let instance_buffer = self.instance_buffer.as_ref().context("Instance buffer missing")?;
-        let byte_len = std::mem::size_of_val(instances) as wgpu::BufferAddress;
-        let capacity_bytes = self.instance_capacity_bytes();
-        let alignment = wgpu::COPY_BUFFER_ALIGNMENT;
-        let mut write_offset = align_to(self.instance_cursor, alignment);
-        if write_offset + byte_len > capacity_bytes {
-            write_offset = 0;
-        }
-        let span_end = write_offset + byte_len;
-        let cursor_advance = align_to(byte_len, alignment);
-        self.instance_cursor = span_end + (cursor_advance - byte_len);
-        self.instance_span = write_offset..span_end;
         let upload_start = Instant::now();
-        queue.write_buffer(instance_buffer, write_offset, bytemuck::cast_slice(instances));
+
+        let mut trusted: Vec<Range<usize>> = batches
+            .iter()
+            .filter(|batch| {
+                !batch.dirty
+                    && batch.range.end as usize <= self.resident_instances.len()
+                    && self.resident_batches.get(&batch.atlas) == Some(&batch.range)
+            })
+            .map(|batch| batch.range.start as usize..batch.range.end as usize)
+            .collect();
+        alloc_sampler::record_growth::<Range<usize>>(AllocationSite::SpriteBatches, 0, trusted.capacity());
+        trusted.sort_by_key(|range| range.start);
+
+        let mut runs = std::mem::take(&mut self.dirty_runs);
+        runs.clear();
+        let mut cursor = 0usize;
+        for range in trusted.iter().chain(std::iter::once(&(instances.len()..instances.len()))) {
+            let end = range.start.min(instances.len());
+            if cursor < end {
+                collect_changed_runs(&self.resident_instances, instances, cursor..end, &mut runs);
+            }
+            cursor = cursor.max(range.end);
+        }
+
+        let resident_capacity = self.resident_instances.capacity();
+        self.resident_instances.resize(instances.len(), InstanceData::zeroed());
+        alloc_sampler::record_growth::<InstanceData>(
+            AllocationSite::SpriteBatches,
+            resident_capacity,
+            self.resident_instances.capacity(),
+        );
+        let mut rewritten = 0usize;
+        for run in &runs {
+            queue.write_buffer(
+                instance_buffer,
+                (run.start * stride) as wgpu::BufferAddress,
+                bytemuck::cast_slice(&instances[run.clone()]),
+            );
+            self.resident_instances[run.clone()].copy_from_slice(&instances[run.clone()]);
+            rewritten += run.len();
+        }
+        self.resident_batches.clear();
+        for batch in batches {
+            self.resident_batches.insert(Arc::clone(&batch.atlas), batch.range.clone());
+        }
+
         let elapsed_ms = upload_start.elapsed().as_secs_f32() * 1000.0;
         self.upload_stats.frames = self.upload_stats.frames.saturating_add(1);
         self.upload_stats.bytes_uploaded =
-            self.upload_stats.bytes_uploaded.saturating_add(byte_len as u64);
+            self.upload_stats.bytes_uploaded.saturating_add((rewritten * stride) as u64);
+        self.upload_stats.bytes_reused =
+            self.upload_stats.bytes_reused.saturating_add(((instances.len() - rewritten) * stride) as u64);
+        self.upload_stats.write_calls = self.upload_stats.write_calls.saturating_add(runs.len() as u32);
         self.upload_stats.total_cpu_ms += elapsed_ms;
+        self.dirty_runs = runs;
         Ok(())
     }
 
+    /// Replaces the baked static sprite instances with a single full upload.
+    ///
+    /// The baked buffer is left untouched until the next call, so callers should only upload when
+    /// the static set actually changed.
+    pub fn upload_static_instances(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        instances: &[InstanceData],
+    ) -> Result<()> {
+        self.static_count = instances.len();
+        if instances.is_empty() {
+            return Ok(());
+        }
+        if self.static_capacity < instances.len() || self.static_buffer.is_none() {
+            let capacity = instances.len().next_power_of_two().max(256);
+            self.static_buffer = Some(device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("Static Instance Buffer"),
+                size: (capacity * std::mem::size_of::<InstanceData>()) as u64,
+                usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            }));
+            self.static_capacity = capacity;
+        }
+        let buffer = self.static_buffer.as_ref().context("Static instance buffer missing")?;
+        let bytes: &[u8] = bytemuck::cast_slice(instances);
+        queue.write_buffer(buffer, 0, bytes);
+        self.upload_stats.static_uploads = self.upload_stats.static_uploads.saturating_add(1);
+        self.upload_stats.static_bytes_uploaded =
+            self.upload_stats.static_bytes_uploaded.saturating_add(bytes.len() as u64);
+        Ok(())
+    }
+
+    /// Drops the baked static instances; the buffer is kept for the next bake.
+    pub fn clear_static_instances(&mut self) {
+        self.static_count = 0;
+    }
+
+    /// Forgets the resident instance copy so the next upload rewrites the whole buffer.
+    fn invalidate_instances(&mut self) {
+        self.resident_instances.clear();
+        self.resident_batches.clear();
+    }
+
     pub fn sprite_bind_group(
         &mut self,
         device: &wgpu::Device,
         atlas: &str,
         view: &Arc<wgpu::TextureView>,
+        normal_view: Option<&Arc<wgpu::TextureView>>,
         sampler: &wgpu::Sampler,
     ) -> Result<Arc<wgpu::BindGroup>> {
         let sampler_id = sampler as *const wgpu::Sampler as usize as u64;
-        if let Some(entry) = self.bind_cache.get(atlas) {
-            if Arc::ptr_eq(&entry.view, view) && entry.sampler_id == sampler_id {
+        // Normal-mapped batches of an atlas get their own entries, prefixed so atlas
+        // invalidation drops them too.
+        let key = match normal_view {
+            Some(normal_view) => format!("{atlas}|{:p}", Arc::as_ptr(normal_view)),
+            None => atlas.to_string(),
+        };
+        if let Some(entry) = self.bind_cache.get(&key) {
+            let same_normal = match (entry.normal_view.as_ref(), normal_view) {
+                (Some(cached), Some(normal_view)) => Arc::ptr_eq(cached, normal_view),
+                (None, None) => true,
+                _ => false,
+            };
+            if Arc::ptr_eq(&entry.view, view) && same_normal && entry.sampler_id == sampler_id {
                 let bind_group = entry.bind_group.clone();
-                self.touch_bind_cache(atlas);
+                self.touch_bind_cache(&key);
                 return Ok(bind_group);
             }
         }
 
         let layout = self.texture_bgl.as_ref().context("Texture bind group layout missing")?;
+        let flat_normal_view = self.flat_normal_view.as_ref().context("Flat normal texture missing")?;
         let bind_group = Arc::new(device.create_bind_group(&wgpu::BindGroupDescriptor {
             label: Some("Sprite Atlas Bind Group"),
             layout,
@@ -338,13 +550,26 @@ impl SpritePass {
                     resource: wgpu::BindingResource::TextureView(view.as_ref()),
                 },
                 wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::Sampler(sampler) },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::TextureView(
+                        normal_view.map(|view| view.as_ref()).unwrap_or(flat_normal_view),
+                    ),
+                },
             ],
         }));
 
-        let key = atlas.to_string();
+        if self.bind_cache.remove(&key).is_some() {
+            self.bind_cache_order.retain(|cached| cached != &key);
+        }
         self.bind_cache.insert(
             key.clone(),
-            SpriteBindCacheEntry { view: view.clone(), sampler_id, bind_group: bind_group.clone() },
+            SpriteBindCacheEntry {
+                view: view.clone(),
+                normal_view: normal_view.cloned(),
+                sampler_id,
+                bind_group: bind_group.clone(),
+            },
         );
         self.bind_cache_order.push_back(key);
         self.evict_bind_cache();
@@ -352,6 +577,8 @@ impl SpritePass {
         Ok(bind_group)
     }
 
+    /// Draws the baked static batches first, then the per-frame dynamic batches on top.
+    #[allow(clippy::too_many_arguments)]
     pub fn encode_pass(
         &self,
         pass: &mut wgpu::RenderPass<'_>,
@@ -359,18 +586,19 @@ impl SpritePass {
         surface_size: PhysicalSize<u32>,
         instances: &[InstanceData],
         sprite_bind_groups: &[(Range<u32>, Arc<wgpu::BindGroup>)],
+        static_bind_groups: &[(Range<u32>, Arc<wgpu::BindGroup>)],
+        stats: &mut RenderStats,
     ) -> Result<()> {
         pass.set_pipeline(self.pipeline.as_ref().context("Sprite pipeline missing")?);
         pass.set_bind_group(0, self.globals_bg.as_ref().context("Sprite globals bind group missing")?, &[]);
         let vertex_buffer = self.vertex_buffer.as_ref().context("Sprite vertex buffer missing")?;
         pass.set_vertex_buffer(0, vertex_buffer.slice(..));
         let instance_buffer = self.instance_buffer.as_ref().context("Instance buffer missing")?;
-        if instances.is_empty() {
+        let static_buffer = self.static_buffer.as_ref().filter(|_| self.static_count > 0);
+        if instances.is_empty() && (static_buffer.is_none() || static_bind_groups.is_empty()) {
             // No sprites to draw, so skip binding the per-instance buffer to avoid zero-length slices.
             return Ok(());
         }
-        let instance_span = self.instance_span.clone();
-        pass.set_vertex_buffer(1, instance_buffer.slice(instance_span));
         let (vp_x, vp_y) = viewport.origin;
         let (vp_w, vp_h) = viewport.size;
         let vp_w = vp_w.max(1.0);
@@ -404,17 +632,31 @@ impl SpritePass {
             wgpu::IndexFormat::Uint16,
         );
 
+        if let Some(static_buffer) = static_buffer {
+            let span = (self.static_count * std::mem::size_of::<InstanceData>()) as wgpu::BufferAddress;
+            pass.set_vertex_buffer(1, static_buffer.slice(0..span));
+            for (range, bind_group) in static_bind_groups.iter() {
+                pass.set_bind_group(1, bind_group.as_ref(), &[]);
+                pass.draw_indexed(0..6, 0, range.clone());
+                record_sprite_draw(stats, range.len());
+            }
+        }
+        if instances.is_empty() {
+            return Ok(());
+        }
+        let instance_span = self.instance_span.clone();
+        pass.set_vertex_buffer(1, instance_buffer.slice(instance_span));
         if sprite_bind_groups.is_empty() {
-            if !instances.is_empty() {
-                if let Some(bg) = self.texture_bg.as_ref() {
-                    pass.set_bind_group(1, bg, &[]);
-                    pass.draw_indexed(0..6, 0, 0..(instances.len() as u32));
-                }
+            if let Some(bg) = self.texture_bg.as_ref() {
+                pass.set_bind_group(1, bg, &[]);
+                pass.draw_indexed(0..6, 0, 0..(instances.len() as u32));
+                record_sprite_draw(stats, instances.len());
             }
         } else {
             for (range, bind_group) in sprite_bind_groups.iter() {
                 pass.set_bind_group(1, bind_group.as_ref(), &[]);
                 pass.draw_indexed(0..6, 0, range.clone());
+                record_sprite_draw(stats, range.len());
             }
         }
         Ok(())
@@ -442,14 +684,10 @@ impl SpritePass {
         self.instance_buffer = Some(new_buf);
         self.instance_capacity = new_cap;
         self.instance_span = 0..0;
-        self.instance_cursor = 0;
+        self.invalidate_instances();
         Ok(())
     }
 
-    fn instance_capacity_bytes(&self) -> wgpu::BufferAddress {
-        (self.instance_capacity.max(1) * std::mem::size_of::<InstanceData>()) as wgpu::BufferAddress
-    }
-
     fn touch_bind_cache(&mut self, atlas: &str) {
         if let Some(pos) = self.bind_cache_order.iter().position(|key| key == atlas) {
             if let Some(key) = self.bind_cache_order.remove(pos) {
@@ -473,10 +711,39 @@ impl SpritePass {
     }
 }
 
-fn align_to(value: wgpu::BufferAddress, alignment: wgpu::BufferAddress) -> wgpu::BufferAddress {
-    if alignment == 0 {
-        value
-    } else {
-        value.div_ceil(alignment) * alignment
+/// Appends runs of instances in `span` that differ from `resident`, merging nearby runs.
+fn collect_changed_runs(
+    resident: &[InstanceData],
+    instances: &[InstanceData],
+    span: Range<usize>,
+    runs: &mut Vec<Range<usize>>,
+) {
+    let comparable = span.start..span.end.min(resident.len()).max(span.start);
+    if comparable.end == span.end
+        && bytemuck::cast_slice::<_, u8>(&resident[comparable.clone()])
+            == bytemuck::cast_slice::<_, u8>(&instances[comparable.clone()])
+    {
+        return;
+    }
+    let push = |index: usize, runs: &mut Vec<Range<usize>>| match runs.last_mut() {
+        Some(last) if index <= last.end + INSTANCE_RUN_MERGE_GAP => last.end = index + 1,
+        _ => runs.push(index..index + 1),
+    };
+    for index in comparable.clone() {
+        if bytemuck::bytes_of(&resident[index]) != bytemuck::bytes_of(&instances[index]) {
+            push(index, runs);
+        }
     }
+    if comparable.end < span.end {
+        push(comparable.end, runs);
+        if let Some(last) = runs.last_mut() {
+            last.end = span.end;
+        }
+    }
+}
+
+/// Each sprite instance is one quad, two triangles.
+fn record_sprite_draw(stats: &mut RenderStats, instance_count: usize) {
+    stats.sprite_draw_calls += 1;
+    stats.total_triangles += instance_count as u64 * 2;
 }
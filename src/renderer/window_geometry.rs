@@ -0,0 +1,155 @@
+use serde::{Deserialize, Serialize};
+use winit::dpi::{LogicalSize, PhysicalPosition, PhysicalSize};
+
+/// Smallest logical size a restored window is allowed to open at.
+pub const MIN_WINDOW_LOGICAL_SIZE: [f64; 2] = [640.0, 360.0];
+
+/// End-of-session window placement. Sizes are logical so a DPI change between sessions keeps the
+/// window the same apparent size; the position is the physical outer position on the desktop.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WindowGeometry {
+    #[serde(default)]
+    pub position: Option<[i32; 2]>,
+    pub size: [f64; 2],
+    #[serde(default)]
+    pub maximized: bool,
+    #[serde(default)]
+    pub fullscreen: bool,
+    #[serde(default)]
+    pub monitor: Option<String>,
+}
+
+/// Desktop rectangle of a connected monitor, in physical pixels.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonitorBounds {
+    pub name: Option<String>,
+    pub position: PhysicalPosition<i32>,
+    pub size: PhysicalSize<u32>,
+    pub scale_factor: f64,
+}
+
+impl MonitorBounds {
+    fn contains(&self, x: f64, y: f64) -> bool {
+        let left = self.position.x as f64;
+        let top = self.position.y as f64;
+        x >= left && y >= top && x < left + self.size.width as f64 && y < top + self.size.height as f64
+    }
+}
+
+/// Where and how to open the window once a saved [`WindowGeometry`] has been checked against the
+/// monitors that are actually connected.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowPlacement {
+    /// Index into the monitor list the geometry was resolved against.
+    pub monitor: Option<usize>,
+    pub position: Option<PhysicalPosition<i32>>,
+    pub size: LogicalSize<f64>,
+    pub maximized: bool,
+    pub fullscreen: bool,
+}
+
+impl WindowGeometry {
+    /// Resolves the saved geometry against `monitors`, whose first entry is treated as the primary.
+    /// A missing monitor or an off-screen position re-centres the window on the primary monitor, and
+    /// the size is clamped so it neither exceeds the monitor nor collapses below the minimum.
+    pub fn resolve(&self, monitors: &[MonitorBounds]) -> WindowPlacement {
+        let size = LogicalSize::new(
+            self.size[0].max(MIN_WINDOW_LOGICAL_SIZE[0]),
+            self.size[1].max(MIN_WINDOW_LOGICAL_SIZE[1]),
+        );
+        let saved_monitor = self
+            .monitor
+            .as_ref()
+            .and_then(|name| monitors.iter().position(|monitor| monitor.name.as_ref() == Some(name)));
+        let Some(index) = saved_monitor.or(if monitors.is_empty() { None } else { Some(0) }) else {
+            return WindowPlacement {
+                monitor: None,
+                position: None,
+                size,
+                maximized: self.maximized,
+                fullscreen: self.fullscreen,
+            };
+        };
+        let monitor = &monitors[index];
+        let scale = monitor.scale_factor.max(f64::EPSILON);
+        let bounds = monitor.size.to_logical::<f64>(scale);
+        let size = LogicalSize::new(
+            size.width.min(bounds.width.max(MIN_WINDOW_LOGICAL_SIZE[0])),
+            size.height.min(bounds.height.max(MIN_WINDOW_LOGICAL_SIZE[1])),
+        );
+        let physical = size.to_physical::<f64>(scale);
+        let on_screen = saved_monitor.is_some()
+            && self.position.is_some_and(|[x, y]| {
+                monitor.contains(x as f64 + physical.width * 0.5, y as f64 + physical.height * 0.5)
+            });
+        let position = if on_screen {
+            self.position.map(|[x, y]| PhysicalPosition::new(x, y))
+        } else {
+            let x = monitor.position.x as f64 + (monitor.size.width as f64 - physical.width).max(0.0) * 0.5;
+            let y = monitor.position.y as f64 + (monitor.size.height as f64 - physical.height).max(0.0) * 0.5;
+            Some(PhysicalPosition::new(x.round() as i32, y.round() as i32))
+        };
+        WindowPlacement {
+            monitor: Some(index),
+            position,
+            size,
+            maximized: self.maximized,
+            fullscreen: self.fullscreen,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monitor(name: &str, x: i32, width: u32, height: u32, scale_factor: f64) -> MonitorBounds {
+        MonitorBounds {
+            name: Some(name.to_string()),
+            position: PhysicalPosition::new(x, 0),
+            size: PhysicalSize::new(width, height),
+            scale_factor,
+        }
+    }
+
+    fn geometry(position: Option<[i32; 2]>, size: [f64; 2], monitor: &str) -> WindowGeometry {
+        WindowGeometry {
+            position,
+            size,
+            maximized: false,
+            fullscreen: false,
+            monitor: Some(monitor.to_string()),
+        }
+    }
+
+    #[test]
+    fn restores_position_on_saved_secondary_monitor() {
+        let monitors = [monitor("primary", 0, 1920, 1080, 1.0), monitor("side", 1920, 2560, 1440, 1.0)];
+        let saved = WindowGeometry { maximized: true, ..geometry(Some([2000, 40]), [1280.0, 720.0], "side") };
+        let placement = saved.resolve(&monitors);
+        assert_eq!(placement.monitor, Some(1));
+        assert_eq!(placement.position, Some(PhysicalPosition::new(2000, 40)));
+        assert_eq!(placement.size, LogicalSize::new(1280.0, 720.0));
+        assert!(placement.maximized);
+    }
+
+    #[test]
+    fn missing_monitor_or_off_screen_position_recentres_on_primary() {
+        let monitors = [monitor("primary", 0, 1920, 1080, 1.0)];
+        let placement = geometry(Some([3000, 40]), [1280.0, 720.0], "unplugged").resolve(&monitors);
+        assert_eq!(placement.monitor, Some(0));
+        assert_eq!(placement.position, Some(PhysicalPosition::new(320, 180)));
+
+        let placement = geometry(Some([-5000, -5000]), [1280.0, 720.0], "primary").resolve(&monitors);
+        assert_eq!(placement.position, Some(PhysicalPosition::new(320, 180)));
+    }
+
+    #[test]
+    fn logical_size_is_clamped_to_monitor_and_minimum() {
+        let monitors = [monitor("hidpi", 0, 2560, 1440, 2.0)];
+        let giant = geometry(None, [4000.0, 3000.0], "hidpi").resolve(&monitors);
+        assert_eq!(giant.size, LogicalSize::new(1280.0, 720.0));
+        let tiny = geometry(None, [10.0, 10.0], "hidpi").resolve(&monitors);
+        assert_eq!(tiny.size, LogicalSize::new(MIN_WINDOW_LOGICAL_SIZE[0], MIN_WINDOW_LOGICAL_SIZE[1]));
+    }
+}
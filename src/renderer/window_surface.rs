@@ -1,10 +1,12 @@
 use crate::config::WindowConfig;
 use anyhow::{anyhow, Context, Result};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use winit::dpi::PhysicalSize;
 use winit::event_loop::ActiveEventLoop;
 use winit::window::{Fullscreen, Window};
 
+use super::window_geometry::{MonitorBounds, WindowGeometry};
 use super::DEPTH_FORMAT;
 
 const DEFAULT_PRESENT_MODES: [wgpu::PresentMode; 1] = [wgpu::PresentMode::Fifo];
@@ -13,22 +15,33 @@ const DEFAULT_PRESENT_MODES: [wgpu::PresentMode; 1] = [wgpu::PresentMode::Fifo];
 pub struct SurfaceFrame {
     view: wgpu::TextureView,
     surface: Option<wgpu::SurfaceTexture>,
+    headless_texture: Option<wgpu::Texture>,
 }
 
 impl SurfaceFrame {
     fn new(surface: wgpu::SurfaceTexture) -> Self {
         let view = surface.texture.create_view(&wgpu::TextureViewDescriptor::default());
-        Self { view, surface: Some(surface) }
+        Self { view, surface: Some(surface), headless_texture: None }
     }
 
-    fn headless(view: wgpu::TextureView) -> Self {
-        Self { view, surface: None }
+    fn headless(texture: &wgpu::Texture) -> Self {
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        Self { view, surface: None, headless_texture: Some(texture.clone()) }
     }
 
     pub fn view(&self) -> &wgpu::TextureView {
         &self.view
     }
 
+    /// Backing texture when it can be copied from (`COPY_SRC`), for readbacks.
+    pub fn copyable_texture(&self) -> Option<&wgpu::Texture> {
+        let texture = match &self.surface {
+            Some(surface) => &surface.texture,
+            None => self.headless_texture.as_ref()?,
+        };
+        texture.usage().contains(wgpu::TextureUsages::COPY_SRC).then_some(texture)
+    }
+
     pub fn present(mut self) {
         if let Some(surface) = self.surface.take() {
             surface.present();
@@ -58,11 +71,17 @@ pub struct WindowSurface {
     title: String,
     vsync: bool,
     fullscreen: bool,
+    restore_geometry: Option<WindowGeometry>,
     depth_texture: Option<wgpu::Texture>,
     depth_view: Option<wgpu::TextureView>,
     present_modes: Vec<wgpu::PresentMode>,
     headless_target: Option<HeadlessTarget>,
+    offscreen_capture: bool,
     gpu_timing_supported: bool,
+    max_anisotropy: u16,
+    adapter_info: Option<wgpu::AdapterInfo>,
+    /// Set from the current device's lost callback; replaced with a fresh flag per device.
+    device_lost: Arc<AtomicBool>,
     #[cfg(test)]
     resize_invocations: usize,
     #[cfg(test)]
@@ -81,11 +100,16 @@ impl WindowSurface {
             title: window_cfg.title.clone(),
             vsync: window_cfg.vsync,
             fullscreen: window_cfg.fullscreen,
+            restore_geometry: None,
             depth_texture: None,
             depth_view: None,
             present_modes: Vec::new(),
             headless_target: None,
+            offscreen_capture: false,
             gpu_timing_supported: false,
+            max_anisotropy: 1,
+            adapter_info: None,
+            device_lost: Arc::new(AtomicBool::new(false)),
             #[cfg(test)]
             resize_invocations: 0,
             #[cfg(test)]
@@ -99,26 +123,69 @@ impl WindowSurface {
         }
         let mut attrs =
             Window::default_attributes().with_title(self.title.clone()).with_inner_size(self.size);
-        if self.fullscreen {
+        let mut maximize = !self.fullscreen;
+        if let Some(geometry) = self.restore_geometry.take() {
+            let mut handles: Vec<_> = event_loop.available_monitors().collect();
+            if let Some(primary) = event_loop.primary_monitor() {
+                if let Some(index) = handles.iter().position(|handle| *handle == primary) {
+                    handles.swap(0, index);
+                }
+            }
+            let monitors: Vec<MonitorBounds> = handles
+                .iter()
+                .map(|handle| MonitorBounds {
+                    name: handle.name(),
+                    position: handle.position(),
+                    size: handle.size(),
+                    scale_factor: handle.scale_factor(),
+                })
+                .collect();
+            let placement = geometry.resolve(&monitors);
+            let monitor = placement.monitor.and_then(|index| handles.get(index).cloned());
+            attrs = attrs.with_inner_size(placement.size);
+            if let Some(position) = placement.position {
+                attrs = attrs.with_position(position);
+            }
+            self.fullscreen = placement.fullscreen;
+            maximize = placement.maximized && !placement.fullscreen;
+            if self.fullscreen {
+                attrs = attrs.with_fullscreen(Some(Fullscreen::Borderless(monitor)));
+            }
+        } else if self.fullscreen {
             attrs = attrs.with_fullscreen(Some(Fullscreen::Borderless(None)));
-        } else {
-            attrs = attrs.with_maximized(true);
         }
+        attrs = attrs.with_maximized(maximize);
         let window = Arc::new(event_loop.create_window(attrs).context("Failed to create window")?);
-        if !self.fullscreen {
+        if maximize {
             window.set_maximized(true);
         }
         pollster::block_on(self.init_wgpu(&window))?;
         if !self.fullscreen {
-            let maximized_size = window.inner_size();
-            if maximized_size.width > 0 && maximized_size.height > 0 && maximized_size != self.size {
-                self.resize(maximized_size);
+            let inner_size = window.inner_size();
+            if inner_size.width > 0 && inner_size.height > 0 && inner_size != self.size {
+                self.resize(inner_size);
             }
         }
         self.window = Some(window);
         Ok(())
     }
 
+    pub fn set_restore_geometry(&mut self, geometry: Option<WindowGeometry>) {
+        self.restore_geometry = geometry;
+    }
+
+    pub fn window_geometry(&self) -> Option<WindowGeometry> {
+        let window = self.window.as_ref()?;
+        let size = window.inner_size().to_logical::<f64>(window.scale_factor());
+        Some(WindowGeometry {
+            position: window.outer_position().ok().map(|position| [position.x, position.y]),
+            size: [size.width, size.height],
+            maximized: window.is_maximized(),
+            fullscreen: window.fullscreen().is_some(),
+            monitor: window.current_monitor().and_then(|monitor| monitor.name()),
+        })
+    }
+
     pub fn device_and_queue(&self) -> Result<(&wgpu::Device, &wgpu::Queue)> {
         Ok((self.device()?, self.queue()?))
     }
@@ -197,14 +264,20 @@ impl WindowSurface {
         if let Some(err) = self.surface_error_injector.take() {
             return Err(self.handle_surface_error(&err));
         }
+        if self.offscreen_capture {
+            if self.headless_target.is_none() {
+                self.prepare_headless_render_target()?;
+            }
+            let target = self.headless_target.as_ref().context("Offscreen render target missing")?;
+            return Ok(SurfaceFrame::headless(&target.texture));
+        }
         if let Some(surface) = self.surface.as_ref() {
             match surface.get_current_texture() {
                 Ok(frame) => Ok(SurfaceFrame::new(frame)),
                 Err(err) => Err(self.handle_surface_error(&err)),
             }
         } else if let Some(target) = self.headless_target.as_ref() {
-            let view = target.texture.create_view(&wgpu::TextureViewDescriptor::default());
-            Ok(SurfaceFrame::headless(view))
+            Ok(SurfaceFrame::headless(&target.texture))
         } else {
             Err(anyhow!("Surface not initialized"))
         }
@@ -215,6 +288,7 @@ impl WindowSurface {
         if self.size.width == 0 || self.size.height == 0 {
             return Err(anyhow!("Headless render target requires non-zero dimensions"));
         }
+        let format = self.config.as_ref().map_or(wgpu::TextureFormat::Bgra8UnormSrgb, |config| config.format);
         let texture = device.create_texture(&wgpu::TextureDescriptor {
             label: Some("Headless Render Target"),
             size: wgpu::Extent3d {
@@ -225,7 +299,7 @@ impl WindowSurface {
             mip_level_count: 1,
             sample_count: 1,
             dimension: wgpu::TextureDimension::D2,
-            format: wgpu::TextureFormat::Bgra8UnormSrgb,
+            format,
             usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
             view_formats: &[],
         });
@@ -233,6 +307,82 @@ impl WindowSurface {
         Ok(())
     }
 
+    /// Routes frames into the offscreen target instead of the window surface so they can be read back.
+    pub fn set_offscreen_capture(&mut self, enabled: bool) -> Result<()> {
+        self.offscreen_capture = enabled;
+        // Before the device exists the target is created lazily on the first acquired frame.
+        if enabled && self.headless_target.is_none() && self.device.is_some() {
+            self.prepare_headless_render_target()?;
+        }
+        Ok(())
+    }
+
+    pub fn offscreen_capture(&self) -> bool {
+        self.offscreen_capture
+    }
+
+    /// Copies the offscreen target back to the CPU as tightly packed RGBA8 pixels.
+    pub fn read_offscreen_target(&self) -> Result<image::RgbaImage> {
+        let target = self.headless_target.as_ref().context("Offscreen render target missing")?;
+        let device = self.device()?;
+        let queue = self.queue()?;
+        let size = target.texture.size();
+        let swap_red_blue = match target.texture.format() {
+            wgpu::TextureFormat::Bgra8Unorm | wgpu::TextureFormat::Bgra8UnormSrgb => true,
+            wgpu::TextureFormat::Rgba8Unorm | wgpu::TextureFormat::Rgba8UnormSrgb => false,
+            other => return Err(anyhow!("Offscreen readback does not support {other:?}")),
+        };
+        let row_bytes = size.width * 4;
+        let alignment = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+        let padded_row_bytes = row_bytes.div_ceil(alignment) * alignment;
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Offscreen Readback Buffer"),
+            size: padded_row_bytes as u64 * size.height as u64,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Offscreen Readback Encoder"),
+        });
+        encoder.copy_texture_to_buffer(
+            target.texture.as_image_copy(),
+            wgpu::TexelCopyBufferInfo {
+                buffer: &buffer,
+                layout: wgpu::TexelCopyBufferLayout {
+                    offset: 0,
+                    bytes_per_row: Some(padded_row_bytes),
+                    rows_per_image: Some(size.height),
+                },
+            },
+            size,
+        );
+        let submission = queue.submit(Some(encoder.finish()));
+        let slice = buffer.slice(..);
+        let (sender, receiver) = std::sync::mpsc::channel();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+        device
+            .poll(wgpu::PollType::Wait { submission_index: Some(submission), timeout: None })
+            .context("Waiting for offscreen readback")?;
+        receiver.recv().context("Offscreen readback was dropped")?.context("Mapping offscreen readback")?;
+        let mut pixels = Vec::with_capacity((row_bytes * size.height) as usize);
+        {
+            let mapped = slice.get_mapped_range();
+            for row in mapped.chunks(padded_row_bytes as usize) {
+                pixels.extend_from_slice(&row[..row_bytes as usize]);
+            }
+        }
+        buffer.unmap();
+        if swap_red_blue {
+            for pixel in pixels.chunks_exact_mut(4) {
+                pixel.swap(0, 2);
+            }
+        }
+        image::RgbaImage::from_raw(size.width, size.height, pixels)
+            .context("Offscreen readback size mismatch")
+    }
+
     pub fn ensure_depth_texture(&mut self) -> Result<()> {
         if self.depth_texture.is_some() {
             return Ok(());
@@ -244,6 +394,65 @@ impl WindowSurface {
         self.gpu_timing_supported
     }
 
+    pub fn max_anisotropy(&self) -> u16 {
+        self.max_anisotropy
+    }
+
+    pub fn adapter_info(&self) -> Option<&wgpu::AdapterInfo> {
+        self.adapter_info.as_ref()
+    }
+
+    fn adapter_max_anisotropy(adapter: &wgpu::Adapter) -> u16 {
+        let flags = adapter.get_downlevel_capabilities().flags;
+        if flags.contains(wgpu::DownlevelFlags::ANISOTROPIC_FILTERING) {
+            16
+        } else {
+            1
+        }
+    }
+
+    /// True once the driver reset, the adapter went away, or the device was destroyed. Nothing
+    /// created from the device is usable again; see [`WindowSurface::recreate_device`].
+    pub fn device_lost(&self) -> bool {
+        self.device_lost.load(Ordering::Acquire)
+    }
+
+    /// Drops the lost device with its surface and targets, then requests a new adapter and device.
+    /// The window is kept; the adapter may differ from the previous one (e.g. an iGPU/dGPU switch).
+    pub fn recreate_device(&mut self) -> Result<()> {
+        let had_headless_target = self.headless_target.is_some();
+        self.headless_target = None;
+        self.depth_view = None;
+        self.depth_texture = None;
+        self.surface = None;
+        self.queue = None;
+        self.device = None;
+        match self.window.clone() {
+            Some(window) => {
+                pollster::block_on(self.init_wgpu(&window))?;
+                let inner_size = window.inner_size();
+                if inner_size.width > 0 && inner_size.height > 0 && inner_size != self.size {
+                    self.resize(inner_size);
+                }
+            }
+            None => pollster::block_on(self.init_headless_for_test())?,
+        }
+        if had_headless_target {
+            self.prepare_headless_render_target()?;
+        }
+        Ok(())
+    }
+
+    fn watch_device_loss(&mut self, device: &wgpu::Device) {
+        let lost = Arc::new(AtomicBool::new(false));
+        let flag = Arc::clone(&lost);
+        device.set_device_lost_callback(move |reason, message| {
+            eprintln!("[renderer] GPU device lost ({reason:?}): {message}");
+            flag.store(true, Ordering::Release);
+        });
+        self.device_lost = lost;
+    }
+
     #[cfg(test)]
     pub fn resize_invocations_for_test(&self) -> usize {
         self.resize_invocations
@@ -272,6 +481,8 @@ impl WindowSurface {
         let supports_encoder_queries =
             adapter_features.contains(wgpu::Features::TIMESTAMP_QUERY_INSIDE_ENCODERS);
         self.gpu_timing_supported = supports_timestamp && supports_encoder_queries;
+        self.max_anisotropy = Self::adapter_max_anisotropy(&adapter);
+        self.adapter_info = Some(adapter.get_info());
         let mut required_features = wgpu::Features::empty();
         if supports_timestamp {
             required_features |= wgpu::Features::TIMESTAMP_QUERY;
@@ -279,6 +490,8 @@ impl WindowSurface {
         if supports_encoder_queries {
             required_features |= wgpu::Features::TIMESTAMP_QUERY_INSIDE_ENCODERS;
         }
+        // KTX2 textures upload as-is when the adapter samples BC formats.
+        required_features |= adapter_features & wgpu::Features::TEXTURE_COMPRESSION_BC;
         let mut required_limits = adapter.limits();
         required_limits.max_bind_groups = required_limits.max_bind_groups.max(6);
         required_limits.max_storage_buffers_per_shader_stage =
@@ -293,6 +506,7 @@ impl WindowSurface {
         };
         let (device, queue) =
             adapter.request_device(&device_desc).await.context("Failed to request headless device")?;
+        self.watch_device_loss(&device);
         self.device = Some(device);
         self.queue = Some(queue);
         if self.config.is_none() {
@@ -377,19 +591,29 @@ impl WindowSurface {
     async fn init_wgpu(&mut self, window: &Arc<Window>) -> Result<()> {
         let instance = wgpu::Instance::default();
         let surface = instance.create_surface(window.clone()).context("Failed to create WGPU surface")?;
-        let adapter = instance
-            .request_adapter(&wgpu::RequestAdapterOptions {
-                power_preference: wgpu::PowerPreference::HighPerformance,
-                compatible_surface: Some(&surface),
-                force_fallback_adapter: false,
-            })
-            .await
-            .context("Failed to request WGPU adapter")?;
+        let adapter_options = |force_fallback_adapter| wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::HighPerformance,
+            compatible_surface: Some(&surface),
+            force_fallback_adapter,
+        };
+        let adapter = match instance.request_adapter(&adapter_options(false)).await {
+            Ok(adapter) => adapter,
+            Err(err) => {
+                // Machines without a GPU (CI runners, VMs) may still expose a software rasterizer.
+                eprintln!("[renderer] No hardware adapter ({err}); trying a software fallback adapter");
+                instance
+                    .request_adapter(&adapter_options(true))
+                    .await
+                    .context("Failed to request WGPU adapter")?
+            }
+        };
         let adapter_features = adapter.features();
         let supports_timestamp = adapter_features.contains(wgpu::Features::TIMESTAMP_QUERY);
         let supports_encoder_queries =
             adapter_features.contains(wgpu::Features::TIMESTAMP_QUERY_INSIDE_ENCODERS);
         self.gpu_timing_supported = supports_timestamp && supports_encoder_queries;
+        self.max_anisotropy = Self::adapter_max_anisotropy(&adapter);
+        self.adapter_info = Some(adapter.get_info());
         let mut required_features = wgpu::Features::empty();
         if supports_timestamp {
             required_features |= wgpu::Features::TIMESTAMP_QUERY;
@@ -397,6 +621,8 @@ impl WindowSurface {
         if supports_encoder_queries {
             required_features |= wgpu::Features::TIMESTAMP_QUERY_INSIDE_ENCODERS;
         }
+        // KTX2 textures upload as-is when the adapter samples BC formats.
+        required_features |= adapter_features & wgpu::Features::TEXTURE_COMPRESSION_BC;
         let mut required_limits = adapter.limits();
         required_limits.max_bind_groups = required_limits.max_bind_groups.max(6);
         required_limits.max_storage_buffers_per_shader_stage =
@@ -411,12 +637,14 @@ impl WindowSurface {
         };
         let (device, queue) =
             adapter.request_device(&device_desc).await.context("Failed to request WGPU device")?;
+        self.watch_device_loss(&device);
 
         let caps = surface.get_capabilities(&adapter);
         let format = Self::choose_surface_format(&caps.formats);
         let size = window.inner_size();
         let config = wgpu::SurfaceConfiguration {
-            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            // COPY_SRC lets plugins read back the frame when the platform allows it.
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | (caps.usages & wgpu::TextureUsages::COPY_SRC),
             format,
             width: size.width,
             height: size.height,
@@ -522,8 +750,14 @@ mod tests {
 
     #[test]
     fn headless_render_recovers_from_surface_loss() {
-        let window_config =
-            WindowConfig { title: "Headless".into(), width: 64, height: 64, vsync: false, fullscreen: false };
+        let window_config = WindowConfig {
+            title: "Headless".into(),
+            width: 64,
+            height: 64,
+            vsync: false,
+            fullscreen: false,
+            render_scale: 1.0,
+        };
         let mut surface = block_on(async {
             let mut s = WindowSurface::new(&window_config);
             s.init_headless_for_test().await.expect("init headless");
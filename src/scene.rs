@@ -1,5 +1,9 @@
 use crate::assets::AssetManager;
-use crate::ecs::{ForceFalloff, ForceField, ForceFieldKind, ParticleAttractor, ParticleTrail};
+use crate::ecs::{
+    ColliderGeometry, ColliderKind, ColliderShape, EmitterShape, ForceFalloff, ForceField, ForceFieldKind,
+    ParticleAttractor, ParticleCollisionMode, ParticleTrail,
+};
+use crate::renderer::{AutoExposureSettings, PointLightFalloff};
 #[cfg(feature = "binary_scene")]
 use anyhow::anyhow;
 use anyhow::{bail, Context, Result};
@@ -17,6 +21,7 @@ use uuid::Uuid;
 const BINARY_SCENE_MAGIC: [u8; 4] = *b"KSCN";
 #[cfg(feature = "binary_scene")]
 const BINARY_SCENE_VERSION: u32 = 1;
+const SEQUENTIAL_ENTITY_ID_PREFIX: &str = "entity-";
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Scene {
@@ -26,6 +31,27 @@ pub struct Scene {
     pub dependencies: SceneDependencies,
     #[serde(default)]
     pub entities: Vec<SceneEntity>,
+    /// Child scenes streamed in and out by camera position; only meaningful on a master scene.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub chunks: Vec<SceneChunkRef>,
+    /// Next value of the scene's entity id counter. Persisted so ids of deleted entities are never
+    /// handed out again, even after the scene is saved and reopened by someone else.
+    #[serde(default, skip_serializing_if = "is_zero_entity_counter")]
+    pub next_entity_id: u64,
+}
+
+/// A child scene file plus the world-space rectangle it covers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SceneChunkRef {
+    pub key: String,
+    pub path: String,
+    pub bounds: SceneChunkBounds,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SceneChunkBounds {
+    pub min: Vec2Data,
+    pub max: Vec2Data,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -46,6 +72,9 @@ pub struct SceneMetadata {
     pub lighting: Option<SceneLightingData>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub environment: Option<SceneEnvironment>,
+    /// Plugin-owned values stored verbatim under plugin-chosen keys.
+    #[serde(default, skip_serializing_if = "serde_json::Map::is_empty")]
+    pub custom: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -75,6 +104,10 @@ pub struct ScenePreviewCamera {
     pub frustum_focus: Vec3Data,
     #[serde(default)]
     pub frustum_distance: f32,
+    #[serde(default = "default_preview_near")]
+    pub near: f32,
+    #[serde(default = "default_preview_far")]
+    pub far: f32,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -86,6 +119,16 @@ impl SceneEntityId {
         Self(Uuid::new_v4().to_string())
     }
 
+    /// Id handed out by a scene's entity counter (see [`Scene::next_entity_id`]).
+    pub fn sequential(value: u64) -> Self {
+        Self(format!("{SEQUENTIAL_ENTITY_ID_PREFIX}{value}"))
+    }
+
+    /// Counter value encoded in a sequential id; `None` for UUIDs and hand-written ids.
+    pub fn sequence(&self) -> Option<u64> {
+        self.0.strip_prefix(SEQUENTIAL_ENTITY_ID_PREFIX)?.parse().ok()
+    }
+
     pub fn as_str(&self) -> &str {
         &self.0
     }
@@ -101,6 +144,40 @@ impl Default for SceneEntityId {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SceneReferenceKind {
+    Parent,
+    ParentIndex,
+    CameraFollow,
+}
+
+/// A scene reference that does not resolve, reported by [`Scene::validate_entity_references`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SceneReferenceWarning {
+    pub kind: SceneReferenceKind,
+    /// Entity holding the reference; `None` for scene metadata.
+    pub source: Option<SceneEntityId>,
+    /// The unresolved entity id, or the parent index for [`SceneReferenceKind::ParentIndex`].
+    pub target: String,
+}
+
+impl std::fmt::Display for SceneReferenceWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let source = self.source.as_ref().map(SceneEntityId::as_str).unwrap_or("<scene>");
+        match self.kind {
+            SceneReferenceKind::Parent => {
+                write!(f, "entity '{source}' references missing parent '{}'", self.target)
+            }
+            SceneReferenceKind::ParentIndex => {
+                write!(f, "entity '{source}' has parent index {} outside entity list", self.target)
+            }
+            SceneReferenceKind::CameraFollow => {
+                write!(f, "camera follow target '{}' does not exist", self.target)
+            }
+        }
+    }
+}
+
 fn default_light_direction() -> Vec3Data {
     let dir = glam::Vec3::new(0.4, 0.8, 0.35).normalize();
     Vec3Data::from(dir)
@@ -130,6 +207,8 @@ pub struct SceneLightingData {
     pub exposure: f32,
     #[serde(default)]
     pub shadow: SceneShadowData,
+    #[serde(default, skip_serializing_if = "AutoExposureSettings::is_default")]
+    pub auto_exposure: AutoExposureSettings,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub point_lights: Vec<ScenePointLightData>,
 }
@@ -142,6 +221,7 @@ impl Default for SceneLightingData {
             ambient: default_light_ambient(),
             exposure: default_light_exposure(),
             shadow: SceneShadowData::default(),
+            auto_exposure: AutoExposureSettings::default(),
             point_lights: Vec::new(),
         }
     }
@@ -180,6 +260,8 @@ pub struct ScenePointLightData {
     pub radius: f32,
     #[serde(default = "default_light_intensity")]
     pub intensity: f32,
+    #[serde(default)]
+    pub falloff: PointLightFalloff,
 }
 
 impl Default for ScenePointLightData {
@@ -189,6 +271,7 @@ impl Default for ScenePointLightData {
             color: default_light_color(),
             radius: default_light_radius(),
             intensity: default_light_intensity(),
+            falloff: PointLightFalloff::default(),
         }
     }
 }
@@ -202,14 +285,26 @@ pub struct SceneEnvironment {
     pub key: String,
     #[serde(default = "default_environment_intensity")]
     pub intensity: f32,
+    /// Equirect widths per second the environment scrolls by; zero keeps it static.
+    #[serde(default, skip_serializing_if = "is_zero_scroll_speed")]
+    pub scroll_speed: f32,
 }
 
 impl SceneEnvironment {
     pub fn new(key: String, intensity: f32) -> Self {
-        Self { key, intensity }
+        Self { key, intensity, scroll_speed: 0.0 }
+    }
+
+    pub fn with_scroll_speed(mut self, scroll_speed: f32) -> Self {
+        self.scroll_speed = scroll_speed;
+        self
     }
 }
 
+fn is_zero_scroll_speed(value: &f32) -> bool {
+    *value == 0.0
+}
+
 fn default_shadow_distance() -> f32 {
     35.0
 }
@@ -230,6 +325,14 @@ fn default_shadow_resolution() -> u32 {
     2048
 }
 
+fn default_preview_near() -> f32 {
+    0.1
+}
+
+fn default_preview_far() -> f32 {
+    100.0
+}
+
 fn default_shadow_split_lambda() -> f32 {
     0.6
 }
@@ -279,6 +382,8 @@ impl Default for ScenePreviewCamera {
             frustum_lock: false,
             frustum_focus: Vec3Data { x: 0.0, y: 0.0, z: 0.0 },
             frustum_distance: 5.0,
+            near: default_preview_near(),
+            far: default_preview_far(),
         }
     }
 }
@@ -416,6 +521,26 @@ impl ClipDependency {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct ColorCurveDependency {
+    key: String,
+    path: Option<String>,
+}
+
+impl ColorCurveDependency {
+    pub fn new(key: String, path: Option<String>) -> Self {
+        Self { key, path }
+    }
+
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn path(&self) -> Option<&str> {
+        self.path.as_deref()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct SkeletonDependency {
     key: String,
@@ -505,6 +630,55 @@ impl From<ClipDependencyRepr> for ClipDependency {
     }
 }
 
+pub struct ColorCurveDependencyView<'a> {
+    key: &'a str,
+    path: Option<&'a str>,
+}
+
+impl<'a> ColorCurveDependencyView<'a> {
+    fn new(key: &'a str, path: Option<&'a str>) -> Self {
+        Self { key, path }
+    }
+
+    pub fn key(&self) -> &str {
+        self.key
+    }
+
+    pub fn path(&self) -> Option<&str> {
+        self.path
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+enum ColorCurveDependencyRepr {
+    Key(String),
+    Detailed {
+        key: String,
+        #[serde(default)]
+        path: Option<String>,
+    },
+}
+
+impl From<ColorCurveDependency> for ColorCurveDependencyRepr {
+    fn from(dep: ColorCurveDependency) -> Self {
+        if let Some(path) = dep.path {
+            ColorCurveDependencyRepr::Detailed { key: dep.key, path: Some(path) }
+        } else {
+            ColorCurveDependencyRepr::Key(dep.key)
+        }
+    }
+}
+
+impl From<ColorCurveDependencyRepr> for ColorCurveDependency {
+    fn from(repr: ColorCurveDependencyRepr) -> Self {
+        match repr {
+            ColorCurveDependencyRepr::Key(key) => ColorCurveDependency::new(key, None),
+            ColorCurveDependencyRepr::Detailed { key, path } => ColorCurveDependency::new(key, path),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 enum SkeletonDependencyRepr {
@@ -748,6 +922,8 @@ pub struct SceneDependencies {
     atlases: Vec<AtlasDependencyRepr>,
     #[serde(default)]
     clips: Vec<ClipDependencyRepr>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    color_curves: Vec<ColorCurveDependencyRepr>,
     #[serde(default)]
     skeletons: Vec<SkeletonDependencyRepr>,
     #[serde(default)]
@@ -758,10 +934,29 @@ pub struct SceneDependencies {
     environments: Vec<EnvironmentDependencyRepr>,
 }
 
+/// An atlas key that already resolves to one file while an incoming scene expects another.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AtlasKeyConflict {
+    pub key: String,
+    pub existing: String,
+    pub incoming: String,
+}
+
+impl std::fmt::Display for AtlasKeyConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "atlas '{}' is loaded from '{}' but the scene expects '{}'",
+            self.key, self.existing, self.incoming
+        )
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct SceneDependencyFingerprints {
     pub atlases: u64,
     pub clips: u64,
+    pub color_curves: u64,
     pub skeletons: u64,
     pub meshes: u64,
     pub materials: u64,
@@ -773,6 +968,7 @@ impl SceneDependencyFingerprints {
         let mut hasher = DefaultHasher::new();
         self.atlases.hash(&mut hasher);
         self.clips.hash(&mut hasher);
+        self.color_curves.hash(&mut hasher);
         self.skeletons.hash(&mut hasher);
         self.meshes.hash(&mut hasher);
         self.materials.hash(&mut hasher);
@@ -833,6 +1029,7 @@ impl SceneDependencies {
                 })
                 .collect(),
             clips: Vec::new(),
+            color_curves: Vec::new(),
             skeletons: Vec::new(),
             meshes: Vec::new(),
             materials: Vec::new(),
@@ -851,6 +1048,19 @@ impl SceneDependencies {
                 ClipDependencyRepr::from(ClipDependency::new(key, path))
             })
             .collect();
+        let mut curve_set = BTreeSet::new();
+        for entity in entities {
+            if let Some(curve) = &entity.tint_curve {
+                curve_set.insert(curve.curve_key.clone());
+            }
+        }
+        deps.color_curves = curve_set
+            .into_iter()
+            .map(|key| {
+                let path = assets.color_curve_source(&key).map(|p| p.to_string());
+                ColorCurveDependencyRepr::from(ColorCurveDependency::new(key, path))
+            })
+            .collect();
         let mut skeleton_set = BTreeSet::new();
         for entity in entities {
             if let Some(skeleton) = &entity.skeleton {
@@ -900,6 +1110,37 @@ impl SceneDependencies {
         self.atlas_dependencies().any(|dep| dep.key() == key)
     }
 
+    /// Adds every dependency of `other` to this list. Entries already present keep their path
+    /// unless they had none; the environment is left untouched because a scene only has one.
+    pub fn merge(&mut self, other: &SceneDependencies) {
+        self.atlases.extend(other.atlases.iter().cloned());
+        self.clips.extend(other.clips.iter().cloned());
+        self.color_curves.extend(other.color_curves.iter().cloned());
+        self.skeletons.extend(other.skeletons.iter().cloned());
+        self.meshes.extend(other.meshes.iter().cloned());
+        self.materials.extend(other.materials.iter().cloned());
+        self.normalize();
+    }
+
+    /// Atlas dependencies whose path differs from the one `existing_path` reports for the same key.
+    /// Keys without a path on either side are never reported.
+    pub fn atlas_conflicts<F>(&self, mut existing_path: F) -> Vec<AtlasKeyConflict>
+    where
+        F: FnMut(&str) -> Option<String>,
+    {
+        self.atlas_dependencies()
+            .filter_map(|dep| {
+                let incoming = dep.path()?;
+                let existing = existing_path(dep.key())?;
+                (Path::new(&existing) != Path::new(incoming)).then(|| AtlasKeyConflict {
+                    key: dep.key().to_string(),
+                    existing,
+                    incoming: incoming.to_string(),
+                })
+            })
+            .collect()
+    }
+
     pub fn fingerprints(&self) -> SceneDependencyFingerprints {
         self.fingerprints_with(|dep| dep.path().and_then(|p| dependency_path_fingerprint(Path::new(p))))
     }
@@ -933,6 +1174,12 @@ impl SceneDependencies {
                     .map(|dep| (dep.key().to_string(), dep.path().map(|p| p.to_string()), None))
                     .collect(),
             ),
+            color_curves: hash_entries(
+                "color_curve",
+                self.color_curve_dependencies()
+                    .map(|dep| (dep.key().to_string(), dep.path().map(|p| p.to_string()), None))
+                    .collect(),
+            ),
             skeletons: hash_entries(
                 "skeleton",
                 self.skeleton_dependencies()
@@ -987,6 +1234,19 @@ impl SceneDependencies {
         })
     }
 
+    pub fn contains_color_curve(&self, key: &str) -> bool {
+        self.color_curve_dependencies().any(|dep| dep.key() == key)
+    }
+
+    pub fn color_curve_dependencies(&self) -> impl Iterator<Item = ColorCurveDependencyView<'_>> {
+        self.color_curves.iter().map(|repr| match repr {
+            ColorCurveDependencyRepr::Key(key) => ColorCurveDependencyView::new(key, None),
+            ColorCurveDependencyRepr::Detailed { key, path } => {
+                ColorCurveDependencyView::new(key, path.as_deref())
+            }
+        })
+    }
+
     pub fn contains_skeleton(&self, key: &str) -> bool {
         self.skeleton_dependencies().any(|dep| dep.key() == key)
     }
@@ -1100,6 +1360,7 @@ impl SceneDependencies {
         let mut atlas_keys = BTreeSet::new();
         let mut emitter_sources: HashMap<String, String> = HashMap::new();
         let mut clip_keys = BTreeSet::new();
+        let mut curve_keys = BTreeSet::new();
         let mut skeleton_keys = BTreeSet::new();
         let mut mesh_keys = BTreeSet::new();
         let mut material_keys = BTreeSet::new();
@@ -1116,6 +1377,9 @@ impl SceneDependencies {
             if let Some(clip) = &entity.transform_clip {
                 clip_keys.insert(clip.clip_key.clone());
             }
+            if let Some(curve) = &entity.tint_curve {
+                curve_keys.insert(curve.curve_key.clone());
+            }
             if let Some(skeleton) = &entity.skeleton {
                 skeleton_keys.insert(skeleton.key.clone());
             }
@@ -1191,6 +1455,25 @@ impl SceneDependencies {
                 ClipDependencyRepr::from(dep)
             })
             .collect();
+        let curve_lookup: HashMap<_, _> = self
+            .color_curves
+            .iter()
+            .cloned()
+            .map(|repr| {
+                let dep: ColorCurveDependency = repr.into();
+                (dep.key().to_string(), dep)
+            })
+            .collect();
+        let color_curves = curve_keys
+            .into_iter()
+            .map(|key| {
+                let dep = curve_lookup
+                    .get(&key)
+                    .cloned()
+                    .unwrap_or_else(|| ColorCurveDependency::new(key.clone(), None));
+                ColorCurveDependencyRepr::from(dep)
+            })
+            .collect();
         let skeleton_lookup: HashMap<_, _> = self
             .skeletons
             .iter()
@@ -1240,7 +1523,8 @@ impl SceneDependencies {
             })
             .collect();
 
-        let mut subset = SceneDependencies { atlases, clips, skeletons, meshes, materials, environments };
+        let mut subset =
+            SceneDependencies { atlases, clips, color_curves, skeletons, meshes, materials, environments };
         subset.normalize();
         subset
     }
@@ -1284,6 +1568,25 @@ impl SceneDependencies {
         }
         self.clips = clip_map.into_values().map(ClipDependencyRepr::from).collect();
 
+        let mut curve_map: BTreeMap<String, ColorCurveDependency> = BTreeMap::new();
+        for repr in std::mem::take(&mut self.color_curves) {
+            let dep = ColorCurveDependency::from(repr);
+            let key = dep.key().to_string();
+            let path_opt = dep.path().map(|s| s.to_string());
+            match curve_map.entry(key) {
+                std::collections::btree_map::Entry::Occupied(mut entry) => {
+                    if entry.get().path.is_none() {
+                        entry.get_mut().path = path_opt;
+                    }
+                }
+                std::collections::btree_map::Entry::Vacant(entry) => {
+                    let key_clone = entry.key().clone();
+                    entry.insert(ColorCurveDependency::new(key_clone, path_opt));
+                }
+            }
+        }
+        self.color_curves = curve_map.into_values().map(ColorCurveDependencyRepr::from).collect();
+
         let mut skeleton_map: BTreeMap<String, SkeletonDependency> = BTreeMap::new();
         for repr in std::mem::take(&mut self.skeletons) {
             let dep = SkeletonDependency::from(repr);
@@ -1384,6 +1687,8 @@ pub struct SceneEntity {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tint: Option<ColorData>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tint_curve: Option<TintCurveData>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub velocity: Option<Vec2Data>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub mass: Option<f32>,
@@ -1399,12 +1704,24 @@ pub struct SceneEntity {
     pub attractor: Option<ParticleAttractorData>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub spin: Option<f32>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Opaque per-plugin component data keyed by plugin-qualified name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub plugin_data: BTreeMap<String, Vec<u8>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub parent_id: Option<SceneEntityId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub parent: Option<usize>,
 }
 
+impl SceneEntity {
+    /// True when the entity has neither a parent id nor a parent index.
+    pub fn is_root(&self) -> bool {
+        self.parent_id.is_none() && self.parent.is_none()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransformData {
     pub translation: Vec2Data,
@@ -1436,6 +1753,12 @@ pub struct SpriteData {
     pub region: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub animation: Option<SpriteAnimationData>,
+    /// Draw order within the atlas batch; omitted from JSON when 0.
+    #[serde(default, skip_serializing_if = "is_zero_z_order")]
+    pub z_order: i16,
+    /// Path of the tangent-space normal map used to light the sprite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub normal_map: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1455,6 +1778,8 @@ pub struct SpriteAnimationData {
     pub random_start: bool,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub group: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub loop_count: Option<u32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1464,6 +1789,8 @@ pub struct TransformClipData {
     pub playing: bool,
     #[serde(default = "default_transform_clip_looped")]
     pub looped: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub loop_count: Option<u32>,
     #[serde(default = "default_transform_clip_speed")]
     pub speed: f32,
     #[serde(default)]
@@ -1480,6 +1807,16 @@ pub struct TransformClipData {
     pub apply_tint: bool,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TintCurveData {
+    pub curve_key: String,
+    pub duration: f32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub loop_mode: Option<String>,
+    #[serde(default)]
+    pub elapsed: f32,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SkeletonData {
     pub key: String,
@@ -1494,6 +1831,8 @@ pub struct SkeletonClipData {
     pub playing: bool,
     #[serde(default = "default_skeleton_clip_looped")]
     pub looped: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub loop_count: Option<u32>,
     #[serde(default = "default_skeleton_clip_speed")]
     pub speed: f32,
     #[serde(default)]
@@ -1509,6 +1848,8 @@ pub struct MeshData {
     pub material: Option<String>,
     #[serde(default)]
     pub lighting: MeshLightingData,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_draw_distance: Option<f32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -1564,6 +1905,14 @@ const fn default_sprite_anim_playing() -> bool {
     true
 }
 
+fn is_zero_z_order(value: &i16) -> bool {
+    *value == 0
+}
+
+fn is_zero_entity_counter(value: &u64) -> bool {
+    *value == 0
+}
+
 const fn default_sprite_anim_speed() -> f32 {
     1.0
 }
@@ -1628,6 +1977,37 @@ impl Default for MeshLightingData {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ColliderData {
     pub half_extents: Vec2Data,
+    /// Written once the collider has been edited apart from the entity scale.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offset: Option<Vec2Data>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub radius: Option<f32>,
+}
+
+impl ColliderData {
+    pub fn from_shape(half: Vec2, shape: Option<ColliderShape>) -> Self {
+        let Some(shape) = shape else {
+            return Self { half_extents: half.into(), offset: None, radius: None };
+        };
+        let radius = (shape.kind == ColliderKind::Circle).then_some(half.x);
+        Self { half_extents: half.into(), offset: Some(shape.offset.into()), radius }
+    }
+
+    /// Authored geometry, or `None` for colliders that still follow the entity scale.
+    pub fn geometry(&self) -> Option<ColliderGeometry> {
+        if self.offset.is_none() && self.radius.is_none() {
+            return None;
+        }
+        let offset = self.offset.clone().map(Vec2::from).unwrap_or(Vec2::ZERO);
+        Some(match self.radius {
+            Some(radius) => {
+                ColliderGeometry { kind: ColliderKind::Circle, half: Vec2::splat(radius), offset }
+            }
+            None => {
+                ColliderGeometry { kind: ColliderKind::Rect, half: self.half_extents.clone().into(), offset }
+            }
+        })
+    }
 }
 
 fn default_particle_emitter_atlas() -> String {
@@ -1676,6 +2056,12 @@ pub struct ParticleEmitterData {
     pub atlas_source: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub trail: Option<ParticleTrailData>,
+    #[serde(default)]
+    pub shape: EmitterShape,
+    #[serde(default)]
+    pub collision_mode: ParticleCollisionMode,
+    #[serde(default)]
+    pub collision_events: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1862,16 +2248,20 @@ impl Scene {
     }
 
     fn normalize_entities(&mut self) {
+        let mut next_id = self.entity_id_floor();
         let mut seen = HashSet::new();
         for entity in &mut self.entities {
             if entity.id.is_empty() || !seen.insert(entity.id.clone()) {
-                let mut replacement = SceneEntityId::new();
+                let mut replacement = SceneEntityId::sequential(next_id);
+                next_id += 1;
                 while !seen.insert(replacement.clone()) {
-                    replacement = SceneEntityId::new();
+                    replacement = SceneEntityId::sequential(next_id);
+                    next_id += 1;
                 }
                 entity.id = replacement;
             }
         }
+        self.next_entity_id = next_id;
 
         let inferred_parent_ids: Vec<Option<SceneEntityId>> = (0..self.entities.len())
             .map(|index| {
@@ -2002,11 +2392,17 @@ impl Scene {
     }
 
     pub fn with_fresh_entity_ids(&self) -> Self {
+        self.with_entity_ids_from(SceneEntityId::new)
+    }
+
+    /// Like [`Scene::with_fresh_entity_ids`], but takes the new ids from `next_id` so callers can draw
+    /// them from a world's sequential allocator.
+    pub fn with_entity_ids_from(&self, mut next_id: impl FnMut() -> SceneEntityId) -> Self {
         let mut cloned = self.clone();
         let mut remap: HashMap<String, SceneEntityId> = HashMap::with_capacity(cloned.entities.len());
         for entity in &mut cloned.entities {
             let old_id = entity.id.clone();
-            let new_id = SceneEntityId::new();
+            let new_id = next_id();
             remap.insert(old_id.as_str().to_string(), new_id.clone());
             entity.id = new_id;
         }
@@ -2022,6 +2418,88 @@ impl Scene {
         cloned
     }
 
+    /// Copy of the scene where only the ids `is_taken` reports are re-keyed from `next_id`; parent
+    /// links and the camera follow target move with their entity. Used when merging a scene into a
+    /// world that already uses some of its ids.
+    pub fn with_colliding_entity_ids_from(
+        &self,
+        mut is_taken: impl FnMut(&str) -> bool,
+        mut next_id: impl FnMut() -> SceneEntityId,
+    ) -> Self {
+        let mut cloned = self.clone();
+        let mut remap: HashMap<String, SceneEntityId> = HashMap::new();
+        for entity in &mut cloned.entities {
+            if is_taken(entity.id.as_str()) {
+                let new_id = next_id();
+                remap.insert(entity.id.as_str().to_string(), new_id.clone());
+                entity.id = new_id;
+            }
+        }
+        if remap.is_empty() {
+            return cloned;
+        }
+        let references = cloned
+            .entities
+            .iter_mut()
+            .filter_map(|entity| entity.parent_id.as_mut())
+            .chain(cloned.metadata.camera_follow_entity.as_mut());
+        for reference in references {
+            if let Some(mapped) = remap.get(reference.as_str()) {
+                *reference = mapped.clone();
+            }
+        }
+        cloned
+    }
+
+    /// Smallest counter value that cannot collide with any id the scene uses or references.
+    pub fn entity_id_floor(&self) -> u64 {
+        let referenced = self
+            .entities
+            .iter()
+            .flat_map(|entity| std::iter::once(&entity.id).chain(entity.parent_id.as_ref()))
+            .chain(self.metadata.camera_follow_entity.as_ref());
+        referenced
+            .filter_map(SceneEntityId::sequence)
+            .map(|value| value + 1)
+            .fold(self.next_entity_id, u64::max)
+    }
+
+    /// Lists references (parent links, camera follow target) that do not resolve to an entity in
+    /// this scene.
+    pub fn validate_entity_references(&self) -> Vec<SceneReferenceWarning> {
+        let ids: HashSet<&str> = self.entities.iter().map(|entity| entity.id.as_str()).collect();
+        let mut warnings = Vec::new();
+        for entity in &self.entities {
+            if let Some(parent_id) = entity.parent_id.as_ref() {
+                if !ids.contains(parent_id.as_str()) {
+                    warnings.push(SceneReferenceWarning {
+                        kind: SceneReferenceKind::Parent,
+                        source: Some(entity.id.clone()),
+                        target: parent_id.as_str().to_string(),
+                    });
+                }
+            } else if let Some(parent_index) = entity.parent {
+                if parent_index >= self.entities.len() {
+                    warnings.push(SceneReferenceWarning {
+                        kind: SceneReferenceKind::ParentIndex,
+                        source: Some(entity.id.clone()),
+                        target: parent_index.to_string(),
+                    });
+                }
+            }
+        }
+        if let Some(follow) = self.metadata.camera_follow_entity.as_ref() {
+            if !ids.contains(follow.as_str()) {
+                warnings.push(SceneReferenceWarning {
+                    kind: SceneReferenceKind::CameraFollow,
+                    source: None,
+                    target: follow.as_str().to_string(),
+                });
+            }
+        }
+        warnings
+    }
+
     pub fn offset_entities_2d(&mut self, offset: Vec2) {
         if offset.length_squared() == 0.0 {
             return;
@@ -2039,6 +2517,39 @@ impl Scene {
         }
     }
 
+    /// Average 2D translation of the entities without a parent, or `None` for an empty scene.
+    pub fn root_centroid_2d(&self) -> Option<Vec2> {
+        let roots: Vec<Vec2> = self
+            .entities
+            .iter()
+            .filter(|entity| entity.is_root())
+            .map(|entity| entity.transform.translation.clone().into())
+            .collect();
+        if roots.is_empty() {
+            return None;
+        }
+        Some(roots.iter().copied().sum::<Vec2>() / roots.len() as f32)
+    }
+
+    /// Like [`Scene::offset_entities_2d`], but only moves root entities so children keep their
+    /// parent-relative transforms.
+    pub fn offset_root_entities_2d(&mut self, offset: Vec2) {
+        if offset.length_squared() == 0.0 {
+            return;
+        }
+        for entity in self.entities.iter_mut().filter(|entity| entity.is_root()) {
+            let mut translation: Vec2 = entity.transform.translation.clone().into();
+            translation += offset;
+            entity.transform.translation = translation.into();
+            if let Some(transform3d) = entity.transform3d.as_mut() {
+                let mut translation3: Vec3 = transform3d.translation.clone().into();
+                translation3.x += offset.x;
+                translation3.y += offset.y;
+                transform3d.translation = translation3.into();
+            }
+        }
+    }
+
     pub fn offset_entities_3d(&mut self, offset: Vec3) {
         if offset.length_squared() == 0.0 {
             return;
@@ -2134,6 +2645,7 @@ mod tests {
             transform3d: None,
             mesh: None,
             tint: None,
+            tint_curve: None,
             velocity: None,
             mass: None,
             collider: None,
@@ -2150,11 +2662,16 @@ mod tests {
                 region: "spark".to_string(),
                 atlas_source: Some("assets/atlases/fx_atlas.json".to_string()),
                 trail: None,
+                shape: EmitterShape::default(),
+                collision_mode: ParticleCollisionMode::None,
+                collision_events: false,
             }),
             force_field: None,
             attractor: None,
             orbit: None,
             spin: None,
+            tags: Vec::new(),
+            plugin_data: BTreeMap::new(),
             parent_id: None,
             parent: None,
         }
@@ -2174,6 +2691,18 @@ mod tests {
         let subset = deps.subset_for_entities(&[entity], None);
         assert!(subset.contains_atlas("fx_atlas"), "subset dependencies should retain emitter atlases");
     }
+
+    #[test]
+    fn duplicate_ids_are_replaced_from_the_scene_counter() {
+        let mut first = entity_with_emitter();
+        first.id = SceneEntityId::sequential(4);
+        let duplicate = first.clone();
+        let mut scene = Scene { entities: vec![first, duplicate], next_entity_id: 2, ..Scene::default() };
+        scene.normalize_entities();
+        assert_eq!(scene.entities[0].id, SceneEntityId::sequential(4));
+        assert_eq!(scene.entities[1].id, SceneEntityId::sequential(5));
+        assert_eq!(scene.next_entity_id, 6);
+    }
 }
 
 impl From<ColorData> for glam::Vec4 {
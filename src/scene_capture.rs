@@ -1,3 +1,4 @@
+use crate::renderer::PointLightFalloff;
 use crate::scene::{
     ColorData, Scene, SceneCamera2D, SceneDependencies, SceneEntity, SceneEnvironment, SceneLightingData,
     ScenePointLightData, SceneViewportMode, SpriteAnimationData, SpriteData, TransformClipData, Vec2Data,
@@ -97,6 +98,8 @@ pub struct SceneCapturePointLight {
     pub color: [f32; 3],
     pub radius: f32,
     pub intensity: f32,
+    #[serde(default)]
+    pub falloff: PointLightFalloff,
 }
 
 impl From<&ScenePointLightData> for SceneCapturePointLight {
@@ -106,6 +109,7 @@ impl From<&ScenePointLightData> for SceneCapturePointLight {
             color: vec3_to_array(&light.color),
             radius: light.radius,
             intensity: light.intensity,
+            falloff: light.falloff,
         }
     }
 }
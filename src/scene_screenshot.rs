@@ -0,0 +1,513 @@
+use crate::assets::AssetManager;
+use crate::camera::Camera2D;
+use crate::camera3d::{Camera3D, OrbitCamera};
+use crate::config::{SpriteSortMode, WindowConfig};
+use crate::ecs::{EcsWorld, InstanceData};
+use crate::environment::EnvironmentRegistry;
+use crate::material_registry::MaterialRegistry;
+use crate::mesh_registry::MeshRegistry;
+use crate::renderer::{
+    MeshDraw, RenderViewport, Renderer, ScenePointLight, SpriteBatch, SpriteBatchTextures, SpriteBatcher,
+    MAX_SHADOW_CASCADES,
+};
+use crate::scene::{Scene, SceneLightingData, SceneMetadata, ScenePreviewCamera, ScenePreviewCameraMode};
+use anyhow::{anyhow, bail, Context, Result};
+use glam::{EulerRot, Quat, Vec2, Vec3};
+use std::collections::HashMap;
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Size used when `--size` is omitted.
+pub const DEFAULT_SCREENSHOT_SIZE: (u32, u32) = (1280, 720);
+/// Atlas bound to the sprite pipeline when the scene does not declare a `main` atlas itself.
+pub const DEFAULT_MAIN_ATLAS_PATH: &str = "assets/images/atlas.json";
+const DEFAULT_ENVIRONMENTS_DIR: &str = "assets/environments";
+/// Matches the editor viewport so captures frame the scene the way it was saved.
+const CAMERA_BASE_HALF_HEIGHT: f32 = 1.2;
+const PREVIEW_CAMERA_FOV_RADIANS: f32 = 60.0_f32.to_radians();
+
+/// Arguments following `--screenshot`: `<scene> <out.png> [--size WxH] [--camera-bookmark NAME]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScreenshotArgs {
+    pub scene: PathBuf,
+    pub output: PathBuf,
+    pub width: u32,
+    pub height: u32,
+    pub camera_bookmark: Option<String>,
+    /// Draw order for sprites; not a flag, callers pass the project's `render.sprite_sort_mode`.
+    pub sprite_sort_mode: SpriteSortMode,
+}
+
+impl ScreenshotArgs {
+    pub const USAGE: &'static str = "--screenshot <scene> <out.png> [--size WxH] [--camera-bookmark NAME]";
+
+    pub fn parse<I, S>(args: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut positional = Vec::new();
+        let (mut width, mut height) = DEFAULT_SCREENSHOT_SIZE;
+        let mut camera_bookmark = None;
+        let mut iter = args.into_iter();
+        while let Some(raw) = iter.next() {
+            let arg = raw.as_ref();
+            match arg {
+                "--size" => {
+                    let value = iter.next().ok_or_else(|| anyhow!("--size requires WIDTHxHEIGHT"))?;
+                    (width, height) = parse_size(value.as_ref())?;
+                }
+                "--camera-bookmark" => {
+                    let value = iter.next().ok_or_else(|| anyhow!("--camera-bookmark requires a name"))?;
+                    camera_bookmark = Some(value.as_ref().to_string());
+                }
+                flag if flag.starts_with("--") => {
+                    bail!("Unknown screenshot flag '{flag}'. Usage: {}", Self::USAGE)
+                }
+                path => positional.push(PathBuf::from(path)),
+            }
+        }
+        let [scene, output]: [PathBuf; 2] = positional
+            .try_into()
+            .map_err(|_| anyhow!("Expected a scene path and an output path. Usage: {}", Self::USAGE))?;
+        Ok(Self {
+            scene,
+            output,
+            width,
+            height,
+            camera_bookmark,
+            sprite_sort_mode: SpriteSortMode::default(),
+        })
+    }
+}
+
+fn parse_size(value: &str) -> Result<(u32, u32)> {
+    let (width, height) = value
+        .split_once(['x', 'X'])
+        .ok_or_else(|| anyhow!("Invalid --size '{value}'. Use WIDTHxHEIGHT, e.g. 1920x1080."))?;
+    let width = width.trim().parse::<u32>().with_context(|| format!("Invalid --size width '{width}'"))?;
+    let height = height.trim().parse::<u32>().with_context(|| format!("Invalid --size height '{height}'"))?;
+    if width == 0 || height == 0 {
+        bail!("--size must be at least 1x1 (got {value})");
+    }
+    Ok((width, height))
+}
+
+/// Why a screenshot was not written. Loading and rendering fail with distinct exit codes so
+/// scripts can tell a broken scene apart from a broken GPU setup.
+#[derive(Debug)]
+pub enum ScreenshotError {
+    /// The scene or one of its dependencies could not be loaded.
+    Load(anyhow::Error),
+    /// The device could not be created, the frame failed to render, or the PNG could not be written.
+    Render(anyhow::Error),
+}
+
+impl ScreenshotError {
+    pub const LOAD_EXIT_CODE: i32 = 3;
+    pub const RENDER_EXIT_CODE: i32 = 4;
+
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::Load(_) => Self::LOAD_EXIT_CODE,
+            Self::Render(_) => Self::RENDER_EXIT_CODE,
+        }
+    }
+}
+
+impl fmt::Display for ScreenshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Load(err) => write!(f, "failed to load scene: {err:#}"),
+            Self::Render(err) => write!(f, "failed to render screenshot: {err:#}"),
+        }
+    }
+}
+
+impl std::error::Error for ScreenshotError {}
+
+/// Cameras a screenshot is rendered from.
+#[derive(Debug, Clone)]
+pub struct ScreenshotCameras {
+    pub camera2d: Camera2D,
+    pub camera3d: Camera3D,
+    /// Set when the requested bookmark does not exist and the saved camera was used instead.
+    pub warning: Option<String>,
+}
+
+/// Picks the 2D camera from the named bookmark, falling back to the scene's active bookmark, then
+/// its saved 2D camera, then the default view. The 3D camera comes from the saved preview camera.
+pub fn resolve_screenshot_cameras(metadata: &SceneMetadata, bookmark: Option<&str>) -> ScreenshotCameras {
+    let find = |name: &str| metadata.camera_bookmarks.iter().find(|candidate| candidate.name == name);
+    let mut warning = None;
+    let mut camera2d = Camera2D::new(CAMERA_BASE_HALF_HEIGHT);
+    let requested = bookmark.and_then(|name| {
+        let found = find(name);
+        if found.is_none() {
+            warning = Some(format!("camera bookmark '{name}' not found; using the scene's saved camera"));
+        }
+        found
+    });
+    if let Some(saved) = requested.or_else(|| metadata.active_camera_bookmark.as_deref().and_then(find)) {
+        camera2d.position = Vec2::from(saved.position.clone());
+        camera2d.set_zoom(saved.zoom);
+    } else if let Some(saved) = metadata.camera2d.as_ref() {
+        camera2d.position = Vec2::from(saved.position.clone());
+        camera2d.set_zoom(saved.zoom);
+    }
+    let camera3d = preview_camera3d(metadata.preview_camera.as_ref());
+    ScreenshotCameras { camera2d, camera3d, warning }
+}
+
+fn preview_camera3d(preview: Option<&ScenePreviewCamera>) -> Camera3D {
+    let Some(preview) = preview else {
+        return OrbitCamera::new(Vec3::ZERO, 5.0).to_camera(PREVIEW_CAMERA_FOV_RADIANS, 0.1, 100.0);
+    };
+    let (near, far) = if preview.near > 0.0 && preview.far > preview.near {
+        (preview.near, preview.far)
+    } else {
+        (0.1, 100.0)
+    };
+    match preview.mode {
+        ScenePreviewCameraMode::Disabled | ScenePreviewCameraMode::Orbit => {
+            let mut orbit =
+                OrbitCamera::new(Vec3::from(preview.orbit.target.clone()), preview.orbit.radius.max(0.1));
+            orbit.yaw_radians = preview.orbit.yaw;
+            orbit.pitch_radians = preview
+                .orbit
+                .pitch
+                .clamp(-std::f32::consts::FRAC_PI_2 + 0.01, std::f32::consts::FRAC_PI_2 - 0.01);
+            orbit.to_camera(PREVIEW_CAMERA_FOV_RADIANS, near, far)
+        }
+        ScenePreviewCameraMode::Freefly => {
+            let freefly = &preview.freefly;
+            let position = Vec3::from(freefly.position.clone());
+            let orientation = Quat::from_euler(EulerRot::YXZ, freefly.yaw, freefly.pitch, freefly.roll);
+            let forward = orientation * Vec3::new(0.0, 0.0, -1.0);
+            let mut camera =
+                Camera3D::new(position, position + forward, PREVIEW_CAMERA_FOV_RADIANS, near, far);
+            let up = (orientation * Vec3::Y).normalize_or_zero();
+            camera.up = if up.length_squared() > 0.0 { up } else { Vec3::Y };
+            camera
+        }
+    }
+}
+
+/// Loads `args.scene` with its dependencies into a window-less renderer, renders a single frame
+/// offscreen and writes it to `args.output` as a PNG.
+pub async fn render_scene_screenshot(args: &ScreenshotArgs) -> Result<(), ScreenshotError> {
+    let scene = Scene::load_from_path(&args.scene)
+        .with_context(|| format!("Loading scene {}", args.scene.display()))
+        .map_err(ScreenshotError::Load)?;
+    let mut renderer = Renderer::new(&WindowConfig {
+        title: "Scene Screenshot".into(),
+        width: args.width,
+        height: args.height,
+        vsync: false,
+        fullscreen: false,
+        render_scale: 1.0,
+    })
+    .await;
+    renderer.init_headless_for_test().await.map_err(ScreenshotError::Render)?;
+    renderer.prepare_headless_render_target().map_err(ScreenshotError::Render)?;
+    renderer.set_offscreen_capture(true).map_err(ScreenshotError::Render)?;
+    renderer.set_sprite_sort_mode(args.sprite_sort_mode);
+
+    let mut capture = ScreenshotScene::load(&mut renderer, &scene).map_err(ScreenshotError::Load)?;
+    let cameras = resolve_screenshot_cameras(&scene.metadata, args.camera_bookmark.as_deref());
+    if let Some(warning) = &cameras.warning {
+        eprintln!("[screenshot] warning: {warning}");
+    }
+    let frame = capture
+        .render(&mut renderer, &cameras, (args.width, args.height))
+        .map_err(ScreenshotError::Render)?;
+    if let Some(parent) = args.output.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Creating directory {}", parent.display()))
+            .map_err(ScreenshotError::Render)?;
+    }
+    frame
+        .save(&args.output)
+        .with_context(|| format!("Writing {}", args.output.display()))
+        .map_err(ScreenshotError::Render)?;
+    Ok(())
+}
+
+struct ScreenshotScene {
+    ecs: EcsWorld,
+    assets: AssetManager,
+    mesh_registry: MeshRegistry,
+    material_registry: MaterialRegistry,
+    atlas_views: HashMap<String, Arc<wgpu::TextureView>>,
+    normal_views: HashMap<String, Option<Arc<wgpu::TextureView>>>,
+    sprite_sampler: Arc<wgpu::Sampler>,
+}
+
+impl ScreenshotScene {
+    fn load(renderer: &mut Renderer, scene: &Scene) -> Result<Self> {
+        let mut assets = AssetManager::new();
+        assets.set_device(renderer.device()?, renderer.queue()?);
+        let mut material_registry = MaterialRegistry::new();
+        let mut mesh_registry = MeshRegistry::new(&mut material_registry);
+        let mut environment_registry = EnvironmentRegistry::new();
+        environment_registry.load_directory(DEFAULT_ENVIRONMENTS_DIR)?;
+
+        if !scene.dependencies.atlas_dependencies().any(|dep| dep.key() == "main") {
+            assets
+                .retain_atlas("main", Some(DEFAULT_MAIN_ATLAS_PATH))
+                .context("Failed to retain atlas 'main'")?;
+        }
+        // Meshes register their glTF materials, so they are prepared before materials are retained.
+        for dep in scene.dependencies.mesh_dependencies() {
+            mesh_registry
+                .ensure_mesh(dep.key(), dep.path(), &mut material_registry)
+                .with_context(|| format!("Failed to prepare mesh '{}'", dep.key()))?;
+        }
+        for dep in scene.dependencies.material_dependencies() {
+            material_registry
+                .retain(dep.key())
+                .with_context(|| format!("Failed to retain material '{}'", dep.key()))?;
+        }
+        let mut ecs = EcsWorld::new();
+        ecs.load_scene_resolving_dependencies(
+            scene,
+            &mut assets,
+            |key, path| mesh_registry.ensure_mesh(key, path, &mut material_registry),
+            |_, _| Ok(()),
+            |key, path| environment_registry.retain(key, path),
+        )?;
+        ecs.sync_normal_map_refs(&mut assets);
+
+        let sampler = Arc::new(assets.default_sampler().clone());
+        renderer
+            .init_sprite_pipeline_with_atlas(assets.atlas_texture_view("main")?, sampler.as_ref().clone())?;
+        let (env_key, intensity) = match scene.metadata.environment.as_ref() {
+            Some(environment) => (environment.key.clone(), environment.intensity.max(0.0)),
+            None => (environment_registry.default_key().to_string(), 1.0),
+        };
+        let env_gpu = environment_registry
+            .ensure_gpu(&env_key, renderer)
+            .with_context(|| format!("Environment '{env_key}' unavailable"))?;
+        renderer.set_environment(env_gpu.as_ref(), intensity)?;
+        if let Some(lighting) = scene.metadata.lighting.as_ref() {
+            apply_scene_lighting(renderer, lighting);
+        }
+        Ok(Self {
+            ecs,
+            assets,
+            mesh_registry,
+            material_registry,
+            atlas_views: HashMap::new(),
+            normal_views: HashMap::new(),
+            sprite_sampler: sampler,
+        })
+    }
+
+    fn render(
+        &mut self,
+        renderer: &mut Renderer,
+        cameras: &ScreenshotCameras,
+        (width, height): (u32, u32),
+    ) -> Result<image::RgbaImage> {
+        // A zero-length step settles transforms and animation poses without advancing the scene.
+        self.ecs.update(0.0);
+        let viewport = RenderViewport { origin: (0.0, 0.0), size: (width as f32, height as f32) };
+        let view_proj = cameras.camera2d.view_projection(winit::dpi::PhysicalSize::new(width, height));
+        let (instances, batches) = self.build_sprite_batches(renderer)?;
+        let sampler = self.sprite_sampler.clone();
+        let mesh_draws = self.build_mesh_draws(renderer)?;
+        let (frame, _) = renderer.render_frame(
+            &instances,
+            &batches,
+            sampler.as_ref(),
+            view_proj,
+            viewport,
+            &mesh_draws,
+            Some(&cameras.camera3d),
+        )?;
+        frame.present();
+        renderer.read_offscreen_frame()
+    }
+
+    /// Sorts and batches sprites the way the editor viewport does for the renderer's sort mode.
+    fn build_sprite_batches(&mut self, renderer: &Renderer) -> Result<(Vec<InstanceData>, Vec<SpriteBatch>)> {
+        let mut sprites = self.ecs.collect_sprite_instances(&self.assets)?;
+        renderer.sort_sprite_instances(&mut sprites);
+        let sorted = renderer.sprite_sort_mode() != SpriteSortMode::CreationOrder;
+        let mut atlas_error = None;
+        // A single frame has nothing to reuse, so every batch is uploaded.
+        let batches = SpriteBatcher::default().build(sprites, sorted, |atlas, normal_map| {
+            let view = match self.atlas_view(atlas) {
+                Ok(view) => view,
+                Err(err) => {
+                    atlas_error.get_or_insert(err);
+                    return None;
+                }
+            };
+            let normal_view = normal_map.and_then(|path| self.normal_map_view(path));
+            Some(SpriteBatchTextures { view, normal_view, dirty: true })
+        });
+        match atlas_error {
+            Some(err) => Err(err),
+            None => Ok(batches),
+        }
+    }
+
+    fn build_mesh_draws(&mut self, renderer: &mut Renderer) -> Result<Vec<MeshDraw<'_>>> {
+        let mesh_instances = self.ecs.collect_mesh_instances();
+        for instance in &mesh_instances {
+            self.mesh_registry.ensure_gpu(&instance.key, renderer)?;
+        }
+        let mut draws = Vec::new();
+        for instance in mesh_instances {
+            let gpu_mesh = self
+                .mesh_registry
+                .gpu_mesh(&instance.key)
+                .ok_or_else(|| anyhow!("GPU mesh '{}' missing", instance.key))?;
+            let material_key =
+                instance.material.clone().unwrap_or_else(|| self.material_registry.default_key().to_string());
+            self.material_registry.retain(&material_key)?;
+            let material_gpu = self.material_registry.prepare_material_gpu(&material_key, renderer)?;
+            draws.push(MeshDraw {
+                mesh: gpu_mesh,
+                model: instance.model,
+                lighting: instance.lighting.clone(),
+                material: material_gpu,
+                casts_shadows: instance.lighting.cast_shadows,
+                skin_palette: instance.skin.as_ref().map(|skin| skin.palette.clone()),
+            });
+        }
+        Ok(draws)
+    }
+
+    fn atlas_view(&mut self, key: &str) -> Result<Arc<wgpu::TextureView>> {
+        if let Some(view) = self.atlas_views.get(key) {
+            return Ok(view.clone());
+        }
+        let view = Arc::new(self.assets.atlas_texture_view(key)?);
+        self.atlas_views.insert(key.to_string(), view.clone());
+        Ok(view)
+    }
+
+    /// Like the editor, a normal map that fails to load is reported and its sprites drawn unlit.
+    fn normal_map_view(&mut self, path: &str) -> Option<Arc<wgpu::TextureView>> {
+        if let Some(view) = self.normal_views.get(path) {
+            return view.clone();
+        }
+        let view = match self.assets.normal_map_texture_view(path) {
+            Ok(view) => Some(Arc::new(view)),
+            Err(err) => {
+                eprintln!("[screenshot] warning: normal map '{path}' unavailable, drawing unlit: {err:?}");
+                None
+            }
+        };
+        self.normal_views.insert(path.to_string(), view.clone());
+        view
+    }
+}
+
+/// Applies saved scene lighting with the same clamps the editor uses when it opens a scene.
+fn apply_scene_lighting(renderer: &mut Renderer, lighting: &SceneLightingData) {
+    let (mut direction, color, ambient, exposure, shadow, point_lights) = lighting.components();
+    if !direction.is_finite() || direction.length_squared() < 1e-4 {
+        direction = Vec3::new(0.4, 0.8, 0.35);
+    }
+    let state = renderer.lighting_mut();
+    state.direction = direction.normalize_or_zero();
+    state.color = color;
+    state.ambient = ambient;
+    state.exposure = exposure;
+    state.shadow_distance = shadow.distance.clamp(1.0, 500.0);
+    state.shadow_bias = shadow.bias.clamp(0.00005, 0.05);
+    state.shadow_strength = shadow.strength.clamp(0.0, 1.0);
+    state.shadow_cascade_count = shadow.cascade_count.clamp(1, MAX_SHADOW_CASCADES as u32);
+    state.shadow_resolution = shadow.resolution.clamp(256, 8192);
+    state.shadow_split_lambda = shadow.split_lambda.clamp(0.0, 1.0);
+    state.shadow_pcf_radius = shadow.pcf_radius.clamp(0.0, 10.0);
+    state.auto_exposure = lighting.auto_exposure;
+    state.point_lights = point_lights
+        .into_iter()
+        .map(|data| ScenePointLight {
+            position: Vec3::from(data.position),
+            color: Vec3::from(data.color),
+            radius: data.radius.max(0.0),
+            intensity: data.intensity.max(0.0),
+            falloff: data.falloff,
+        })
+        .collect();
+    renderer.mark_shadow_settings_dirty();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scene::{SceneCamera2D, SceneCameraBookmark, Vec2Data};
+
+    fn bookmark(name: &str, x: f32, zoom: f32) -> SceneCameraBookmark {
+        SceneCameraBookmark { name: name.to_string(), position: Vec2Data { x, y: 0.0 }, zoom }
+    }
+
+    #[test]
+    fn parses_paths_size_and_bookmark() {
+        let args = ScreenshotArgs::parse([
+            "scene.json",
+            "out.png",
+            "--size",
+            "1920x1080",
+            "--camera-bookmark",
+            "Overview",
+        ])
+        .expect("parse screenshot args");
+        assert_eq!(args.scene, PathBuf::from("scene.json"));
+        assert_eq!(args.output, PathBuf::from("out.png"));
+        assert_eq!((args.width, args.height), (1920, 1080));
+        assert_eq!(args.camera_bookmark.as_deref(), Some("Overview"));
+
+        let defaults = ScreenshotArgs::parse(["scene.json", "out.png"]).expect("parse defaults");
+        assert_eq!((defaults.width, defaults.height), DEFAULT_SCREENSHOT_SIZE);
+        assert!(defaults.camera_bookmark.is_none());
+    }
+
+    #[test]
+    fn rejects_bad_sizes_and_missing_paths() {
+        assert!(ScreenshotArgs::parse(["scene.json", "out.png", "--size", "1920"]).is_err());
+        assert!(ScreenshotArgs::parse(["scene.json", "out.png", "--size", "0x1080"]).is_err());
+        assert!(ScreenshotArgs::parse(["scene.json"]).is_err());
+        assert!(ScreenshotArgs::parse(["a.json", "b.png", "c.png"]).is_err());
+        assert!(ScreenshotArgs::parse(["scene.json", "out.png", "--fov", "60"]).is_err());
+    }
+
+    #[test]
+    fn missing_bookmark_falls_back_to_saved_camera_with_warning() {
+        let mut metadata = SceneMetadata {
+            camera2d: Some(SceneCamera2D { position: Vec2Data { x: 3.0, y: -1.0 }, zoom: 2.0 }),
+            camera_bookmarks: vec![bookmark("Overview", 10.0, 0.5)],
+            ..Default::default()
+        };
+
+        let named = resolve_screenshot_cameras(&metadata, Some("Overview"));
+        assert!(named.warning.is_none());
+        assert_eq!(named.camera2d.position, Vec2::new(10.0, 0.0));
+        assert_eq!(named.camera2d.zoom, 0.5);
+
+        let missing = resolve_screenshot_cameras(&metadata, Some("Boss Room"));
+        assert!(missing.warning.as_deref().is_some_and(|warning| warning.contains("Boss Room")));
+        assert_eq!(missing.camera2d.position, Vec2::new(3.0, -1.0));
+        assert_eq!(missing.camera2d.zoom, 2.0);
+
+        metadata.active_camera_bookmark = Some("Overview".to_string());
+        let active = resolve_screenshot_cameras(&metadata, None);
+        assert!(active.warning.is_none());
+        assert_eq!(active.camera2d.position, Vec2::new(10.0, 0.0));
+    }
+
+    #[test]
+    fn load_and_render_failures_use_distinct_exit_codes() {
+        let load = ScreenshotError::Load(anyhow!("missing"));
+        let render = ScreenshotError::Render(anyhow!("no adapter"));
+        assert_ne!(load.exit_code(), render.exit_code());
+        assert_ne!(load.exit_code(), 0);
+        assert_ne!(render.exit_code(), 0);
+    }
+}
@@ -0,0 +1,171 @@
+use crate::scene::{SceneChunkBounds, SceneChunkRef};
+use glam::Vec2;
+
+/// Extra distance (in multiples of the load margin) a chunk must fall outside the view before it
+/// unloads, so a camera hovering on a boundary does not thrash loads.
+const UNLOAD_MARGIN_SCALE: f32 = 2.0;
+
+/// Streaming state for one child scene of a master scene.
+#[derive(Debug, Clone)]
+pub struct StreamedChunk {
+    pub key: String,
+    pub path: String,
+    pub min: Vec2,
+    pub max: Vec2,
+    pub loaded: bool,
+    /// Last load failure; failed chunks are not retried until the streamer is rebuilt.
+    pub error: Option<String>,
+}
+
+impl StreamedChunk {
+    fn intersects(&self, view_min: Vec2, view_max: Vec2, margin: f32) -> bool {
+        let view_min = view_min - Vec2::splat(margin);
+        let view_max = view_max + Vec2::splat(margin);
+        self.min.x <= view_max.x
+            && self.max.x >= view_min.x
+            && self.min.y <= view_max.y
+            && self.max.y >= view_min.y
+    }
+}
+
+/// Chunks to load and unload for the current view, in declaration order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChunkStreamPlan {
+    pub load: Vec<String>,
+    pub unload: Vec<String>,
+}
+
+impl ChunkStreamPlan {
+    pub fn is_empty(&self) -> bool {
+        self.load.is_empty() && self.unload.is_empty()
+    }
+}
+
+/// Decides which chunks of a master scene should be resident for a camera rectangle.
+///
+/// The streamer only tracks bounds and loaded flags; callers perform the actual scene loads and
+/// report back through [`ChunkStreamer::set_loaded`].
+#[derive(Debug, Clone, Default)]
+pub struct ChunkStreamer {
+    chunks: Vec<StreamedChunk>,
+}
+
+impl ChunkStreamer {
+    pub fn new(chunks: &[SceneChunkRef]) -> Self {
+        let chunks = chunks
+            .iter()
+            .map(|chunk| {
+                let a = Vec2::from(chunk.bounds.min.clone());
+                let b = Vec2::from(chunk.bounds.max.clone());
+                StreamedChunk {
+                    key: chunk.key.clone(),
+                    path: chunk.path.clone(),
+                    min: a.min(b),
+                    max: a.max(b),
+                    loaded: false,
+                    error: None,
+                }
+            })
+            .collect();
+        Self { chunks }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.chunks.is_empty()
+    }
+
+    pub fn chunks(&self) -> &[StreamedChunk] {
+        &self.chunks
+    }
+
+    pub fn chunk(&self, key: &str) -> Option<&StreamedChunk> {
+        self.chunks.iter().find(|chunk| chunk.key == key)
+    }
+
+    pub fn chunk_refs(&self) -> Vec<SceneChunkRef> {
+        self.chunks
+            .iter()
+            .map(|chunk| SceneChunkRef {
+                key: chunk.key.clone(),
+                path: chunk.path.clone(),
+                bounds: SceneChunkBounds { min: chunk.min.into(), max: chunk.max.into() },
+            })
+            .collect()
+    }
+
+    pub fn loaded_keys(&self) -> Vec<String> {
+        self.chunks.iter().filter(|chunk| chunk.loaded).map(|chunk| chunk.key.clone()).collect()
+    }
+
+    pub fn set_loaded(&mut self, key: &str, loaded: bool) -> bool {
+        match self.chunks.iter_mut().find(|chunk| chunk.key == key) {
+            Some(chunk) => {
+                chunk.loaded = loaded;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn set_error(&mut self, key: &str, error: Option<String>) {
+        if let Some(chunk) = self.chunks.iter_mut().find(|chunk| chunk.key == key) {
+            chunk.error = error;
+        }
+    }
+
+    /// Chunks overlapping the view grown by `margin` load; loaded chunks unload once they fall
+    /// outside a wider band so small camera jitter does not reload them.
+    pub fn plan(&self, view_min: Vec2, view_max: Vec2, margin: f32) -> ChunkStreamPlan {
+        let margin = margin.max(0.0);
+        let mut plan = ChunkStreamPlan::default();
+        for chunk in &self.chunks {
+            if chunk.loaded {
+                if !chunk.intersects(view_min, view_max, margin * UNLOAD_MARGIN_SCALE) {
+                    plan.unload.push(chunk.key.clone());
+                }
+            } else if chunk.error.is_none() && chunk.intersects(view_min, view_max, margin) {
+                plan.load.push(chunk.key.clone());
+            }
+        }
+        plan
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scene::Vec2Data;
+
+    fn chunk(key: &str, min: (f32, f32), max: (f32, f32)) -> SceneChunkRef {
+        SceneChunkRef {
+            key: key.to_string(),
+            path: format!("{key}.json"),
+            bounds: SceneChunkBounds {
+                min: Vec2Data { x: min.0, y: min.1 },
+                max: Vec2Data { x: max.0, y: max.1 },
+            },
+        }
+    }
+
+    #[test]
+    fn plan_loads_overlapping_chunks_and_unloads_with_hysteresis() {
+        let mut streamer = ChunkStreamer::new(&[
+            chunk("west", (-2.0, -1.0), (0.0, 1.0)),
+            chunk("east", (0.0, -1.0), (2.0, 1.0)),
+        ]);
+        let plan = streamer.plan(Vec2::new(-1.5, -0.5), Vec2::new(-0.5, 0.5), 0.2);
+        assert_eq!(plan.load, vec!["west".to_string()]);
+        assert!(plan.unload.is_empty());
+        streamer.set_loaded("west", true);
+
+        // Within the unload band the chunk stays resident.
+        let plan = streamer.plan(Vec2::new(0.3, -0.5), Vec2::new(1.3, 0.5), 0.2);
+        assert_eq!(plan.load, vec!["east".to_string()]);
+        assert!(plan.unload.is_empty());
+        streamer.set_loaded("east", true);
+
+        let plan = streamer.plan(Vec2::new(0.5, -0.5), Vec2::new(1.5, 0.5), 0.2);
+        assert_eq!(plan.unload, vec!["west".to_string()]);
+        assert!(plan.load.is_empty());
+    }
+}
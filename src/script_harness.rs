@@ -264,9 +264,40 @@ fn apply_commands(commands: &[ScriptCommand], ecs: &mut EcsWorld, handles: &mut
             ScriptCommand::EntitySetScale { entity, scale } => set_scale(ecs, *entity, *scale),
             ScriptCommand::EntitySetVelocity { entity, velocity } => set_velocity(ecs, *entity, *velocity),
             ScriptCommand::EntitySetTint { entity, tint } => set_tint(ecs, *entity, *tint),
+            ScriptCommand::EntitySetTag { entity, tag, tagged } => {
+                if *tagged {
+                    let _ = ecs.set_entity_tag(*entity, tag);
+                } else {
+                    let _ = ecs.remove_entity_tag(*entity, tag);
+                }
+            }
             ScriptCommand::EntityDespawn { entity } => {
                 let _ = ecs.world.despawn(*entity);
             }
+            ScriptCommand::SetClipEvents { entity, events } => {
+                let _ = ecs.set_transform_clip_events(*entity, events.clone());
+            }
+            ScriptCommand::RequestPath { handle, target } => {
+                if let Some(entity) = handles.get(handle).copied() {
+                    let _ = ecs.request_path(entity, *target);
+                }
+            }
+            ScriptCommand::EmitterBurst { handle, count } => {
+                if let Some(entity) = handles.get(handle).copied() {
+                    let _ = ecs.emitter_burst(entity, *count);
+                }
+            }
+            ScriptCommand::Burst { handle, count, speed } => {
+                if let Some(entity) = handles.get(handle).copied() {
+                    let _ = ecs.set_emitter_burst(entity, *count, *speed);
+                }
+            }
+            ScriptCommand::SetGraphParameter { entity, name, value } => {
+                let _ = ecs.set_graph_parameter(*entity, name, *value);
+            }
+            ScriptCommand::TriggerGraphEvent { entity, event } => {
+                let _ = ecs.trigger_graph_event(*entity, event);
+            }
             _ => {}
         }
     }
@@ -339,7 +370,7 @@ fn summarize_command(cmd: &ScriptCommand) -> CommandSummary {
     use ScriptCommand::*;
     #[allow(unreachable_patterns)]
     match cmd {
-        Spawn { handle, atlas, region, position, scale, velocity } => CommandSummary {
+        Spawn { handle, atlas, region, position, scale, velocity, .. } => CommandSummary {
             kind: "spawn".into(),
             handle: Some(*handle),
             entity: None,
@@ -354,7 +385,7 @@ fn summarize_command(cmd: &ScriptCommand) -> CommandSummary {
             tint: None,
             details: None,
         },
-        SpawnPrefab { handle, path, tag } => CommandSummary {
+        SpawnPrefab { handle, path, tag, .. } => CommandSummary {
             kind: "spawn_prefab".into(),
             handle: Some(*handle),
             entity: None,
@@ -444,6 +475,21 @@ fn summarize_command(cmd: &ScriptCommand) -> CommandSummary {
             tint: None,
             details: None,
         },
+        RequestPath { handle, target } => CommandSummary {
+            kind: "request_path".into(),
+            handle: Some(*handle),
+            entity: None,
+            atlas: None,
+            region: None,
+            template: None,
+            prefab: None,
+            position: Some([target.x, target.y]),
+            scale: None,
+            rotation: None,
+            velocity: None,
+            tint: None,
+            details: None,
+        },
         SetTint { handle, tint } => CommandSummary {
             kind: "set_tint".into(),
             handle: Some(*handle),
@@ -459,6 +505,21 @@ fn summarize_command(cmd: &ScriptCommand) -> CommandSummary {
             tint: tint.map(|t| [t.x, t.y, t.z, t.w]),
             details: None,
         },
+        SetTintCurve { handle, curve, duration } => CommandSummary {
+            kind: "set_tint_curve".into(),
+            handle: Some(*handle),
+            entity: None,
+            atlas: None,
+            region: None,
+            template: None,
+            prefab: None,
+            position: None,
+            scale: None,
+            rotation: None,
+            velocity: None,
+            tint: None,
+            details: Some(format!("{curve}:{duration}")),
+        },
         SetSpriteRegion { handle, region } => CommandSummary {
             kind: "set_sprite_region".into(),
             handle: Some(*handle),
@@ -549,6 +610,21 @@ fn summarize_command(cmd: &ScriptCommand) -> CommandSummary {
             tint: tint.map(|t| [t.x, t.y, t.z, t.w]),
             details: None,
         },
+        EntitySetTag { entity, tag, tagged } => CommandSummary {
+            kind: if *tagged { "entity_set_tag" } else { "entity_remove_tag" }.into(),
+            handle: None,
+            entity: Some(entity.to_bits()),
+            atlas: None,
+            region: None,
+            template: None,
+            prefab: None,
+            position: None,
+            scale: None,
+            rotation: None,
+            velocity: None,
+            tint: None,
+            details: Some(tag.clone()),
+        },
         EntitySetVelocity { entity, velocity } => CommandSummary {
             kind: "entity_set_velocity".into(),
             handle: None,
@@ -579,6 +655,191 @@ fn summarize_command(cmd: &ScriptCommand) -> CommandSummary {
             tint: None,
             details: None,
         },
+        SetClipEvents { entity, events } => CommandSummary {
+            kind: "set_clip_events".into(),
+            handle: None,
+            entity: Some(entity.to_bits()),
+            atlas: None,
+            region: None,
+            template: None,
+            prefab: None,
+            position: None,
+            scale: None,
+            rotation: None,
+            velocity: None,
+            tint: None,
+            details: Some(
+                events.iter().map(|(time, name)| format!("{time}:{name}")).collect::<Vec<_>>().join(","),
+            ),
+        },
+        SetGraphParameter { entity, name, value } => CommandSummary {
+            kind: "set_graph_parameter".into(),
+            handle: None,
+            entity: Some(entity.to_bits()),
+            atlas: None,
+            region: None,
+            template: None,
+            prefab: None,
+            position: None,
+            scale: None,
+            rotation: None,
+            velocity: None,
+            tint: None,
+            details: Some(format!("{name}={value}")),
+        },
+        TriggerGraphEvent { entity, event } => CommandSummary {
+            kind: "trigger_graph_event".into(),
+            handle: None,
+            entity: Some(entity.to_bits()),
+            atlas: None,
+            region: None,
+            template: None,
+            prefab: None,
+            position: None,
+            scale: None,
+            rotation: None,
+            velocity: None,
+            tint: None,
+            details: Some(event.clone()),
+        },
+        PlaySound { clip, position } => CommandSummary {
+            kind: "play_sound".into(),
+            handle: None,
+            entity: None,
+            atlas: None,
+            region: None,
+            template: None,
+            prefab: None,
+            position: Some([position.x, position.y]),
+            scale: None,
+            rotation: None,
+            velocity: None,
+            tint: None,
+            details: Some(clip.clone()),
+        },
+        EmitEvent { name, payload } => CommandSummary {
+            kind: "emit_event".into(),
+            handle: None,
+            entity: None,
+            atlas: None,
+            region: None,
+            template: None,
+            prefab: None,
+            position: None,
+            scale: None,
+            rotation: None,
+            velocity: None,
+            tint: None,
+            details: Some(match payload {
+                Some(payload) => format!("{name}:{payload}"),
+                None => name.clone(),
+            }),
+        },
+        CreateMaterial { key, params } => CommandSummary {
+            kind: "create_material".into(),
+            handle: None,
+            entity: None,
+            atlas: None,
+            region: None,
+            template: None,
+            prefab: None,
+            position: None,
+            scale: None,
+            rotation: None,
+            velocity: None,
+            tint: Some(params.base_color.to_array()),
+            details: Some(key.clone()),
+        },
+        ReleaseMaterial { key } => CommandSummary {
+            kind: "release_material".into(),
+            handle: None,
+            entity: None,
+            atlas: None,
+            region: None,
+            template: None,
+            prefab: None,
+            position: None,
+            scale: None,
+            rotation: None,
+            velocity: None,
+            tint: None,
+            details: Some(key.clone()),
+        },
+        EmitterBurst { handle, count } => CommandSummary {
+            kind: "emitter_burst".into(),
+            handle: Some(*handle),
+            entity: None,
+            atlas: None,
+            region: None,
+            template: None,
+            prefab: None,
+            position: None,
+            scale: None,
+            rotation: None,
+            velocity: None,
+            tint: None,
+            details: Some(count.to_string()),
+        },
+        Burst { handle, count, speed } => CommandSummary {
+            kind: "set_emitter_burst".into(),
+            handle: Some(*handle),
+            entity: None,
+            atlas: None,
+            region: None,
+            template: None,
+            prefab: None,
+            position: None,
+            scale: None,
+            rotation: None,
+            velocity: None,
+            tint: None,
+            details: Some(format!("{count} @ {speed}")),
+        },
+        SetAudioVoiceCount { count } => CommandSummary {
+            kind: "set_audio_voice_count".into(),
+            handle: None,
+            entity: None,
+            atlas: None,
+            region: None,
+            template: None,
+            prefab: None,
+            position: None,
+            scale: None,
+            rotation: None,
+            velocity: None,
+            tint: None,
+            details: Some(count.to_string()),
+        },
+        GetWallTime => CommandSummary {
+            kind: "get_wall_time".into(),
+            handle: None,
+            entity: None,
+            atlas: None,
+            region: None,
+            template: None,
+            prefab: None,
+            position: None,
+            scale: None,
+            rotation: None,
+            velocity: None,
+            tint: None,
+            details: None,
+        },
+        GetBoneTransform { handle, bone_name } => CommandSummary {
+            kind: "get_bone_transform".into(),
+            handle: Some(*handle),
+            entity: None,
+            atlas: None,
+            region: None,
+            template: None,
+            prefab: None,
+            position: None,
+            scale: None,
+            rotation: None,
+            velocity: None,
+            tint: None,
+            details: Some(bone_name.clone()),
+        },
         SetAutoSpawnRate { rate } => CommandSummary {
             kind: "set_auto_spawn_rate".into(),
             handle: None,
@@ -9,9 +9,10 @@ use std::sync::{Arc, RwLock};
 use std::time::{Duration, Instant, SystemTime};
 
 use crate::assets::AssetManager;
+use crate::audio::MAX_VOICE_COUNT;
 use crate::plugins::{EnginePlugin, PluginContext};
 use anyhow::{anyhow, Context, Error, Result};
-use glam::{Vec2, Vec4};
+use glam::{Vec2, Vec3, Vec4};
 use rapier2d::prelude::{
     ColliderHandle, Isometry, Point, QueryFilter as RapierQueryFilter, QueryFilterFlags, Ray as RapierRay,
     RayIntersection, SharedShape, Vector,
@@ -27,6 +28,8 @@ use bevy_ecs::prelude::{Component, Entity};
 use crate::ecs::{Aabb, SceneEntityTag, Tint, Transform, Velocity, WorldTransform};
 use std::fmt::Write as FmtWrite;
 use crate::input::Input;
+use crate::material_registry::{MaterialCullMode, MaterialParams};
+use crate::prefab::PrefabOverrides;
 
 pub type ScriptHandle = rhai::INT;
 pub type ListenerHandle = rhai::INT;
@@ -123,12 +126,21 @@ impl ScriptBehaviour {
 
 #[derive(Debug, Clone)]
 pub enum ScriptCommand {
-    Spawn { handle: ScriptHandle, atlas: String, region: String, position: Vec2, scale: f32, velocity: Vec2 },
+    Spawn {
+        handle: ScriptHandle,
+        atlas: String,
+        region: String,
+        position: Vec2,
+        scale: f32,
+        velocity: Vec2,
+        lifetime: Option<f32>,
+    },
     SetVelocity { handle: ScriptHandle, velocity: Vec2 },
     SetPosition { handle: ScriptHandle, position: Vec2 },
     SetRotation { handle: ScriptHandle, rotation: f32 },
     SetScale { handle: ScriptHandle, scale: Vec2 },
     SetTint { handle: ScriptHandle, tint: Option<Vec4> },
+    SetTintCurve { handle: ScriptHandle, curve: String, duration: f32 },
     SetSpriteRegion { handle: ScriptHandle, region: String },
     Despawn { handle: ScriptHandle },
     SetAutoSpawnRate { rate: f32 },
@@ -141,14 +153,34 @@ pub enum ScriptCommand {
     SetEmitterEndColor { color: Vec4 },
     SetEmitterStartSize { size: f32 },
     SetEmitterEndSize { size: f32 },
-    SpawnPrefab { handle: ScriptHandle, path: String, tag: Option<String> },
+    /// Queues a one-shot burst on the emitter owned by `handle`, amortized over the spawn budget.
+    EmitterBurst { handle: ScriptHandle, count: u32 },
+    /// Fires `count` particles from the emitter owned by `handle` on the next fixed step, launched at `speed`.
+    Burst { handle: ScriptHandle, count: u32, speed: f32 },
+    SpawnPrefab { handle: ScriptHandle, path: String, tag: Option<String>, overrides: PrefabOverrides },
     SpawnTemplate { handle: ScriptHandle, template: String, tag: Option<String> },
     EntitySetPosition { entity: Entity, position: Vec2 },
     EntitySetRotation { entity: Entity, rotation: f32 },
     EntitySetScale { entity: Entity, scale: Vec2 },
     EntitySetTint { entity: Entity, tint: Option<Vec4> },
+    /// Adds (`tagged`) or removes `tag` on the entity's [`crate::ecs::Tags`].
+    EntitySetTag { entity: Entity, tag: String, tagged: bool },
     EntitySetVelocity { entity: Entity, velocity: Vec2 },
     EntityDespawn { entity: Entity },
+    SetClipEvents { entity: Entity, events: Vec<(f32, String)> },
+    RequestPath { handle: ScriptHandle, target: Vec2 },
+    SetGraphParameter { entity: Entity, name: String, value: f32 },
+    TriggerGraphEvent { entity: Entity, event: String },
+    PlaySound { clip: String, position: Vec2 },
+    SetAudioVoiceCount { count: usize },
+    EmitEvent { name: String, payload: Option<String> },
+    CreateMaterial { key: String, params: MaterialParams },
+    ReleaseMaterial { key: String },
+    /// Asks the host for real elapsed seconds; it answers with a `wall_time <secs>` script message.
+    GetWallTime,
+    /// Asks the host where `bone_name` on the skeleton `handle` resolves to is in the world; it answers
+    /// with a `GameEvent::BoneTransformResult`.
+    GetBoneTransform { handle: ScriptHandle, bone_name: String },
 }
 
 #[derive(Clone)]
@@ -191,6 +223,58 @@ pub struct ScriptTimingOffender {
     pub last_ms: f32,
 }
 
+/// Script source position an error was raised at, resolved to the innermost Rhai frame.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScriptErrorLocation {
+    pub script_path: String,
+    pub line: usize,
+    pub column: Option<usize>,
+}
+
+impl ScriptErrorLocation {
+    fn from_position(script_path: &str, pos: rhai::Position) -> Option<Self> {
+        let line = pos.line()?;
+        Some(Self { script_path: script_path.to_string(), line, column: pos.position() })
+    }
+}
+
+/// Structured form of the host's last error, used by tooling to show source context.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScriptErrorInfo {
+    pub message: String,
+    /// Script callback (or event handler) that was running when the error was raised.
+    pub callback: Option<String>,
+    pub location: Option<ScriptErrorLocation>,
+    /// Source lines surrounding `location` as `(line number, text)` pairs.
+    pub snippet: Vec<(usize, String)>,
+    /// Number of consecutive times this exact error has been reported.
+    pub repeat_count: u32,
+}
+
+impl ScriptErrorInfo {
+    pub fn from_message(message: impl Into<String>) -> Self {
+        Self { message: message.into(), callback: None, location: None, snippet: Vec::new(), repeat_count: 1 }
+    }
+}
+
+/// Error wrapper that keeps the script location of compile/global-statement failures so it
+/// survives being threaded through `anyhow` contexts.
+#[derive(Debug)]
+struct LocatedScriptError {
+    message: String,
+    location: Option<ScriptErrorLocation>,
+}
+
+impl std::fmt::Display for LocatedScriptError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for LocatedScriptError {}
+
+const SCRIPT_ERROR_SNIPPET_CONTEXT: usize = 2;
+
 #[derive(Clone, Debug, Default)]
 pub struct ScriptSafetyMetrics {
     pub invalid_handle_uses: u64,
@@ -384,6 +468,7 @@ struct SharedState {
     entity_snapshots: HashMap<Entity, EntitySnapshot>,
     entity_scene_ids: HashMap<Entity, Arc<str>>,
     scene_id_entities: HashMap<Arc<str>, Entity>,
+    tag_entities: HashMap<Arc<str>, Vec<Entity>>,
     input_snapshot: Option<InputSnapshot>,
     spatial_index: ScriptSpatialIndex,
     physics_ctx: Option<PhysicsQueryContext>,
@@ -429,6 +514,7 @@ impl Default for SharedState {
             entity_snapshots: HashMap::new(),
             entity_scene_ids: HashMap::new(),
             scene_id_entities: HashMap::new(),
+            tag_entities: HashMap::new(),
             input_snapshot: None,
             spatial_index: ScriptSpatialIndex::default(),
             physics_ctx: None,
@@ -944,6 +1030,20 @@ impl ScriptWorld {
         entities.into_iter().map(|entity| Dynamic::from(entity_to_rhai(entity))).collect()
     }
 
+    /// Entities carrying `tag` in their ECS [`crate::ecs::Tags`] as of the start of the frame.
+    fn query_entities_with_tag(&mut self, tag: &str) -> Array {
+        let trimmed = tag.trim();
+        if trimmed.is_empty() {
+            return Array::new();
+        }
+        let state = self.state.borrow();
+        state
+            .tag_entities
+            .get(trimmed)
+            .map(|entities| entities.iter().map(|entity| Dynamic::from(entity_to_rhai(*entity))).collect())
+            .unwrap_or_default()
+    }
+
     fn find_scene_entity(&mut self, scene_id: &str) -> Dynamic {
         let trimmed = scene_id.trim();
         if trimmed.is_empty() {
@@ -1601,9 +1701,26 @@ impl ScriptWorld {
         vx: FLOAT,
         vy: FLOAT,
     ) -> ScriptHandle {
-        self.spawn_sprite_internal(atlas, region, x, y, scale, vx, vy)
+        self.spawn_sprite_internal(atlas, region, x, y, scale, vx, vy, None)
     }
 
+    /// Like `spawn_sprite`, but the sprite despawns itself after `lifetime` seconds.
+    #[allow(clippy::too_many_arguments)]
+    fn spawn_sprite_timed(
+        &mut self,
+        atlas: &str,
+        region: &str,
+        x: FLOAT,
+        y: FLOAT,
+        scale: FLOAT,
+        vx: FLOAT,
+        vy: FLOAT,
+        lifetime: FLOAT,
+    ) -> ScriptHandle {
+        self.spawn_sprite_internal(atlas, region, x, y, scale, vx, vy, Some(lifetime))
+    }
+
+    #[allow(clippy::too_many_arguments)]
     fn spawn_sprite_internal(
         &mut self,
         atlas: &str,
@@ -1613,16 +1730,18 @@ impl ScriptWorld {
         scale: FLOAT,
         vx: FLOAT,
         vy: FLOAT,
+        lifetime: Option<FLOAT>,
     ) -> ScriptHandle {
         let x = x as f32;
         let y = y as f32;
         let scale = scale as f32;
         let vx = vx as f32;
         let vy = vy as f32;
-        if !self.ensure_finite("spawn_sprite", &[x, y, scale, vx, vy]) {
+        let lifetime = lifetime.map(|seconds| seconds as f32);
+        if !self.ensure_finite("spawn_sprite", &[x, y, scale, vx, vy, lifetime.unwrap_or_default()]) {
             return -1;
         }
-        if scale <= 0.0 {
+        if scale <= 0.0 || lifetime.is_some_and(|seconds| seconds <= 0.0) {
             return -1;
         }
         self.push_command_with_handle(|handle| ScriptCommand::Spawn {
@@ -1632,6 +1751,7 @@ impl ScriptWorld {
             position: Vec2::new(x, y),
             scale,
             velocity: Vec2::new(vx, vy),
+            lifetime,
         })
     }
 
@@ -1645,7 +1765,7 @@ impl ScriptWorld {
         vx: FLOAT,
         vy: FLOAT,
     ) -> Dynamic {
-        let handle = self.spawn_sprite_internal(atlas, region, x, y, scale, vx, vy);
+        let handle = self.spawn_sprite_internal(atlas, region, x, y, scale, vx, vy, None);
         if handle < 0 {
             Dynamic::UNIT
         } else {
@@ -1666,6 +1786,149 @@ impl ScriptWorld {
         self.push_command_plain(ScriptCommand::SetVelocity { handle, velocity: Vec2::new(vx, vy) })
     }
 
+    fn request_path(&mut self, handle: ScriptHandle, x: FLOAT, y: FLOAT) -> bool {
+        let x = x as f32;
+        let y = y as f32;
+        if !self.ensure_finite("request_path", &[x, y]) {
+            return false;
+        }
+        if !self.handle_is_usable(handle) {
+            self.state.borrow_mut().record_invalid_handle_use(Some("request_path"));
+            return false;
+        }
+        self.push_command_plain(ScriptCommand::RequestPath { handle, target: Vec2::new(x, y) })
+    }
+
+    fn play_sound(&mut self, clip: &str, x: FLOAT, y: FLOAT) -> bool {
+        let x = x as f32;
+        let y = y as f32;
+        if !self.ensure_finite("play_sound", &[x, y]) {
+            return false;
+        }
+        let clip = clip.trim();
+        if clip.is_empty() {
+            self.log("play_sound requires a clip name; command ignored");
+            return false;
+        }
+        self.push_command_plain(ScriptCommand::PlaySound {
+            clip: clip.to_string(),
+            position: Vec2::new(x, y),
+        })
+    }
+
+    fn set_audio_voice_count(&mut self, count: i64) -> bool {
+        if count <= 0 {
+            self.log("set_audio_voice_count requires at least one voice; command ignored");
+            return false;
+        }
+        let count = (count as usize).min(MAX_VOICE_COUNT);
+        self.push_command_plain(ScriptCommand::SetAudioVoiceCount { count })
+    }
+
+    /// `params` may set `base_color` ([r, g, b, a]), `metallic`, `roughness`, `emissive` ([r, g, b]),
+    /// `albedo_texture` (image path), `use_vertex_colors` (bool) and `cull_mode` (`"back"`, `"front"`
+    /// or `"none"`); omitted fields keep the defaults.
+    fn create_material(&mut self, key: &str, params: Map) -> bool {
+        let key = key.trim();
+        if key.is_empty() {
+            self.log("create_material requires a material key; command ignored");
+            return false;
+        }
+        let mut parsed = MaterialParams::default();
+        let components = |name: &str, count: usize| -> Option<Option<Vec<f32>>> {
+            let Some(value) = params.get(name) else {
+                return Some(None);
+            };
+            let values: Vec<f32> = value
+                .clone()
+                .try_cast::<Array>()?
+                .iter()
+                .map(|item| item.clone().try_cast::<FLOAT>().map(|v| v as f32))
+                .collect::<Option<_>>()?;
+            (values.len() == count).then_some(Some(values))
+        };
+        let scalar = |name: &str| -> Option<Option<f32>> {
+            match params.get(name) {
+                Some(value) => value.clone().try_cast::<FLOAT>().map(|v| Some(v as f32)),
+                None => Some(None),
+            }
+        };
+        let (Some(base_color), Some(emissive), Some(metallic), Some(roughness)) =
+            (components("base_color", 4), components("emissive", 3), scalar("metallic"), scalar("roughness"))
+        else {
+            self.log(&format!("create_material '{key}' has malformed parameters; command ignored"));
+            return false;
+        };
+        if let Some(values) = base_color {
+            parsed.base_color = Vec4::from_slice(&values);
+        }
+        if let Some(values) = emissive {
+            parsed.emissive = Vec3::from_slice(&values);
+        }
+        parsed.metallic = metallic.unwrap_or(parsed.metallic);
+        parsed.roughness = roughness.unwrap_or(parsed.roughness);
+        let mut finite = parsed.base_color.to_array().to_vec();
+        finite.extend(parsed.emissive.to_array());
+        finite.extend([parsed.metallic, parsed.roughness]);
+        if !self.ensure_finite("create_material", &finite) {
+            return false;
+        }
+        if let Some(path) = params.get("albedo_texture") {
+            match path.clone().try_cast::<String>() {
+                Some(path) if !path.trim().is_empty() => {
+                    parsed.albedo_texture = Some(PathBuf::from(path.trim()))
+                }
+                _ => {
+                    self.log(&format!(
+                        "create_material '{key}' albedo_texture must be a path; command ignored"
+                    ));
+                    return false;
+                }
+            }
+        }
+        if let Some(value) = params.get("use_vertex_colors") {
+            let Some(enabled) = value.clone().try_cast::<bool>() else {
+                self.log(&format!("create_material '{key}' use_vertex_colors must be a bool; command ignored"));
+                return false;
+            };
+            parsed.use_vertex_colors = enabled;
+        }
+        if let Some(value) = params.get("cull_mode") {
+            let Some(cull_mode) = value.clone().try_cast::<String>().and_then(|v| MaterialCullMode::parse(&v))
+            else {
+                self.log(&format!("create_material '{key}' has an unknown cull_mode; command ignored"));
+                return false;
+            };
+            parsed.cull_mode = cull_mode;
+        }
+        self.push_command_plain(ScriptCommand::CreateMaterial { key: key.to_string(), params: parsed })
+    }
+
+    fn release_material(&mut self, key: &str) -> bool {
+        let key = key.trim();
+        if key.is_empty() {
+            self.log("release_material requires a material key; command ignored");
+            return false;
+        }
+        self.push_command_plain(ScriptCommand::ReleaseMaterial { key: key.to_string() })
+    }
+
+    fn request_wall_time(&mut self) -> bool {
+        self.push_command_plain(ScriptCommand::GetWallTime)
+    }
+
+    fn request_bone_transform(&mut self, handle: ScriptHandle, bone_name: &str) -> bool {
+        if bone_name.is_empty() {
+            self.log("request_bone_transform requires a bone name; command ignored");
+            return false;
+        }
+        if !self.handle_is_usable(handle) {
+            self.state.borrow_mut().record_invalid_handle_use(Some("request_bone_transform"));
+            return false;
+        }
+        self.push_command_plain(ScriptCommand::GetBoneTransform { handle, bone_name: bone_name.to_string() })
+    }
+
     fn set_position(&mut self, handle: ScriptHandle, x: FLOAT, y: FLOAT) -> bool {
         let x = x as f32;
         let y = y as f32;
@@ -1728,6 +1991,18 @@ impl ScriptWorld {
         self.push_command_plain(ScriptCommand::SetTint { handle, tint: None })
     }
 
+    fn set_tint_curve(&mut self, handle: ScriptHandle, curve: &str, duration: FLOAT) -> bool {
+        let duration = duration as f32;
+        if !self.ensure_finite("set_tint_curve", &[duration]) || duration <= 0.0 || curve.is_empty() {
+            return false;
+        }
+        if !self.handle_is_usable(handle) {
+            self.state.borrow_mut().record_invalid_handle_use(Some("set_tint_curve"));
+            return false;
+        }
+        self.push_command_plain(ScriptCommand::SetTintCurve { handle, curve: curve.to_string(), duration })
+    }
+
     fn set_sprite_region(&mut self, handle: ScriptHandle, region: &str) -> bool {
         if !self.handle_is_usable(handle) {
             self.state.borrow_mut().record_invalid_handle_use(Some("set_sprite_region"));
@@ -1758,6 +2033,30 @@ impl ScriptWorld {
     }
 
     fn spawn_prefab_with_tag_internal(&mut self, path: &str, tag: Option<String>) -> ScriptHandle {
+        self.spawn_prefab_internal(path, tag, PrefabOverrides::new())
+    }
+
+    /// `spawn_prefab(path, #{ position: [x, y], tint: [r, g, b, a] })`: overrides are applied to the
+    /// prefab root once it spawns; unknown keys are reported and ignored.
+    fn spawn_prefab_with_overrides(&mut self, path: &str, overrides: Map) -> ScriptHandle {
+        let mut parsed = PrefabOverrides::new();
+        for (key, value) in overrides {
+            match rhai::serde::from_dynamic::<serde_json::Value>(&value) {
+                Ok(value) => {
+                    parsed.insert(key.to_string(), value);
+                }
+                Err(err) => self.log(&format!("prefab override '{key}' ignored: {err}")),
+            }
+        }
+        self.spawn_prefab_internal(path, None, parsed)
+    }
+
+    fn spawn_prefab_internal(
+        &mut self,
+        path: &str,
+        tag: Option<String>,
+        overrides: PrefabOverrides,
+    ) -> ScriptHandle {
         let trimmed = path.trim();
         if trimmed.is_empty() {
             return -1;
@@ -1767,8 +2066,11 @@ impl ScriptWorld {
             if trimmed.is_empty() { None } else { Some(trimmed.to_string()) }
         });
         let path_owned = trimmed.to_string();
-        self.push_command_with_handle(move |handle| {
-            ScriptCommand::SpawnPrefab { handle, path: path_owned.clone(), tag: tag.clone() }
+        self.push_command_with_handle(move |handle| ScriptCommand::SpawnPrefab {
+            handle,
+            path: path_owned.clone(),
+            tag: tag.clone(),
+            overrides: overrides.clone(),
         })
     }
 
@@ -1930,6 +2232,27 @@ impl ScriptWorld {
         self.push_command_plain(ScriptCommand::EntitySetTint { entity, tint: None })
     }
 
+    fn entity_set_tag(&mut self, entity_bits: ScriptHandle, tag: &str) -> bool {
+        self.push_entity_tag("entity_set_tag", entity_bits, tag, true)
+    }
+
+    fn entity_remove_tag(&mut self, entity_bits: ScriptHandle, tag: &str) -> bool {
+        self.push_entity_tag("entity_remove_tag", entity_bits, tag, false)
+    }
+
+    fn push_entity_tag(&mut self, label: &str, entity_bits: ScriptHandle, tag: &str, tagged: bool) -> bool {
+        let trimmed = tag.trim();
+        if trimmed.is_empty() {
+            return false;
+        }
+        let entity = Entity::from_bits(entity_bits as u64);
+        if !self.entity_is_alive(entity) {
+            self.state.borrow_mut().record_invalid_handle_use(Some(label));
+            return false;
+        }
+        self.push_command_plain(ScriptCommand::EntitySetTag { entity, tag: trimmed.to_string(), tagged })
+    }
+
     fn entity_set_velocity(&mut self, entity_bits: ScriptHandle, vx: FLOAT, vy: FLOAT) -> bool {
         let entity = Entity::from_bits(entity_bits as u64);
         let vx = vx as f32;
@@ -1953,6 +2276,64 @@ impl ScriptWorld {
         self.push_command_plain(ScriptCommand::EntityDespawn { entity })
     }
 
+    fn entity_set_clip_events(&mut self, entity_bits: ScriptHandle, events: Array) -> bool {
+        let entity = Entity::from_bits(entity_bits as u64);
+        if !self.entity_is_alive(entity) {
+            self.state.borrow_mut().record_invalid_handle_use(Some("entity_set_clip_events"));
+            return false;
+        }
+        let mut parsed = Vec::with_capacity(events.len());
+        for event in &events {
+            let Some(pair) = event.clone().try_cast::<Array>() else {
+                self.log("entity_set_clip_events expects [time, name] pairs; command ignored");
+                return false;
+            };
+            let time = pair.first().and_then(|value| {
+                value
+                    .clone()
+                    .try_cast::<FLOAT>()
+                    .or_else(|| value.clone().try_cast::<rhai::INT>().map(|v| v as FLOAT))
+            });
+            let name = pair.get(1).and_then(|value| value.clone().try_cast::<rhai::ImmutableString>());
+            let (Some(time), Some(name)) = (time, name) else {
+                self.log("entity_set_clip_events expects [time, name] pairs; command ignored");
+                return false;
+            };
+            let time = time as f32;
+            if !self.ensure_finite("entity_set_clip_events", &[time]) {
+                return false;
+            }
+            parsed.push((time, name.to_string()));
+        }
+        self.push_command_plain(ScriptCommand::SetClipEvents { entity, events: parsed })
+    }
+
+    fn entity_set_graph_parameter(&mut self, entity_bits: ScriptHandle, name: &str, value: FLOAT) -> bool {
+        let entity = Entity::from_bits(entity_bits as u64);
+        let value = value as f32;
+        if !self.ensure_finite("entity_set_graph_parameter", &[value]) {
+            return false;
+        }
+        if !self.entity_is_alive(entity) {
+            self.state.borrow_mut().record_invalid_handle_use(Some("entity_set_graph_parameter"));
+            return false;
+        }
+        self.push_command_plain(ScriptCommand::SetGraphParameter { entity, name: name.to_string(), value })
+    }
+
+    fn entity_set_graph_bool(&mut self, entity_bits: ScriptHandle, name: &str, value: bool) -> bool {
+        self.entity_set_graph_parameter(entity_bits, name, if value { 1.0 } else { 0.0 })
+    }
+
+    fn entity_trigger_graph_event(&mut self, entity_bits: ScriptHandle, event: &str) -> bool {
+        let entity = Entity::from_bits(entity_bits as u64);
+        if !self.entity_is_alive(entity) {
+            self.state.borrow_mut().record_invalid_handle_use(Some("entity_trigger_graph_event"));
+            return false;
+        }
+        self.push_command_plain(ScriptCommand::TriggerGraphEvent { entity, event: event.to_string() })
+    }
+
     fn despawn_safe(&mut self, handle: ScriptHandle) -> bool {
         if self.handle_is_alive(handle) {
             self.despawn(handle)
@@ -1975,6 +2356,31 @@ impl ScriptWorld {
         let _ = self.push_command_plain(ScriptCommand::SetSpawnPerPress { count: clamped });
     }
 
+    fn emitter_burst(&mut self, handle: ScriptHandle, count: i64) -> bool {
+        if count <= 0 {
+            return false;
+        }
+        if !self.handle_is_usable(handle) {
+            self.state.borrow_mut().record_invalid_handle_use(Some("emitter_burst"));
+            return false;
+        }
+        let count = count.min(u32::MAX as i64) as u32;
+        self.push_command_plain(ScriptCommand::EmitterBurst { handle, count })
+    }
+
+    fn set_emitter_burst(&mut self, handle: ScriptHandle, count: i64, speed: FLOAT) -> bool {
+        let speed = speed as f32;
+        if count <= 0 || !self.ensure_finite("set_emitter_burst", &[speed]) {
+            return false;
+        }
+        if !self.handle_is_usable(handle) {
+            self.state.borrow_mut().record_invalid_handle_use(Some("set_emitter_burst"));
+            return false;
+        }
+        let count = count.min(u32::MAX as i64) as u32;
+        self.push_command_plain(ScriptCommand::Burst { handle, count, speed })
+    }
+
     fn set_emitter_rate(&mut self, rate: FLOAT) {
         let rate = rate as f32;
         if !self.ensure_finite("set_emitter_rate", &[rate]) {
@@ -2192,11 +2598,20 @@ impl ScriptWorld {
     }
 
     fn emit(&mut self, name: &str) -> bool {
-        self.enqueue_event(name, Dynamic::UNIT, None)
+        if !self.enqueue_event(name, Dynamic::UNIT, None) {
+            return false;
+        }
+        self.push_command_plain(ScriptCommand::EmitEvent { name: name.trim().to_string(), payload: None });
+        true
     }
 
     fn emit_with_payload(&mut self, name: &str, payload: Dynamic) -> bool {
-        self.enqueue_event(name, payload, None)
+        let text = (!payload.is_unit()).then(|| payload.to_string());
+        if !self.enqueue_event(name, payload, None) {
+            return false;
+        }
+        self.push_command_plain(ScriptCommand::EmitEvent { name: name.trim().to_string(), payload: text });
+        true
     }
 
     fn emit_to(&mut self, name: &str, entity_bits: ScriptHandle) -> bool {
@@ -2384,7 +2799,10 @@ pub struct ScriptHost {
     last_digest_check: Option<Instant>,
     last_asset_revision: Option<u64>,
     callback_budget_ms: Option<f32>,
-    error: Option<String>,
+    error: Option<ScriptErrorInfo>,
+    last_error_repeat: Option<(String, u32)>,
+    /// Set whenever an error is recorded; a frame that ends without one resets `last_error_repeat`.
+    error_reported_this_frame: bool,
     enabled: bool,
     initialized: bool,
     shared: Rc<RefCell<SharedState>>,
@@ -2471,12 +2889,12 @@ impl ScriptHost {
                         listener.handler.as_ref(),
                         (world, map),
                     ) {
-                        let message = Self::format_rhai_error(
+                        let info = Self::rhai_error_info(
                             err.as_ref(),
                             self.script_path.to_string_lossy().as_ref(),
                             listener.handler.as_ref(),
                         );
-                        self.error = Some(message);
+                        self.set_error_info(info);
                         stale_listeners.insert(listener.id);
                     }
                     let elapsed_ms = start.elapsed().as_secs_f32() * 1000.0;
@@ -2518,7 +2936,7 @@ impl ScriptHost {
                             elapsed_ms = start.elapsed().as_secs_f32() * 1000.0;
                             if let Err(err) = call_result {
                                 instance.errored = true;
-                                error_message = Some(Self::format_rhai_error(
+                                error_message = Some(Self::rhai_error_info(
                                     err.as_ref(),
                                     &script_path,
                                     listener.handler.as_ref(),
@@ -2532,8 +2950,8 @@ impl ScriptHost {
                         stale_listeners.insert(listener.id);
                         continue;
                     }
-                    if let Some(message) = error_message {
-                        self.set_instance_error_message(id, message);
+                    if let Some(info) = error_message {
+                        self.set_instance_error(id, info);
                         stale_listeners.insert(listener.id);
                     }
                     self.record_timing_elapsed("event", elapsed_ms);
@@ -2569,6 +2987,37 @@ impl ScriptHost {
         message
     }
 
+    fn rhai_error_info(err: &EvalAltResult, script_path: &str, fn_name: &str) -> ScriptErrorInfo {
+        ScriptErrorInfo {
+            callback: Some(fn_name.to_string()),
+            location: Self::rhai_error_location(err, script_path),
+            ..ScriptErrorInfo::from_message(Self::format_rhai_error(err, script_path, fn_name))
+        }
+    }
+
+    /// Walks nested call/module frames so the location points at the statement that failed
+    /// rather than the outermost call site.
+    fn rhai_error_location(err: &EvalAltResult, script_path: &str) -> Option<ScriptErrorLocation> {
+        let mut source = script_path.to_string();
+        let mut location = ScriptErrorLocation::from_position(&source, err.position());
+        let mut current = err;
+        loop {
+            let (frame_source, inner) = match current {
+                EvalAltResult::ErrorInFunctionCall(_, src, inner, _) => (src.as_str(), inner.as_ref()),
+                EvalAltResult::ErrorInModule(module, inner, _) => (module.as_str(), inner.as_ref()),
+                _ => break,
+            };
+            if !frame_source.is_empty() {
+                source = frame_source.to_string();
+            }
+            current = inner;
+            if let Some(inner_location) = ScriptErrorLocation::from_position(&source, current.position()) {
+                location = Some(inner_location);
+            }
+        }
+        location
+    }
+
     fn format_location(source: &str, pos: rhai::Position) -> String {
         let base = if source.is_empty() { "<unknown>".to_string() } else { source.to_string() };
         match (pos.line(), pos.position()) {
@@ -2697,9 +3146,9 @@ impl ScriptHost {
         self.instances.get(&instance_id).map_or(false, |instance| instance.mute_errors)
     }
 
-    fn set_instance_error_message(&mut self, instance_id: u64, message: String) {
+    fn set_instance_error(&mut self, instance_id: u64, info: ScriptErrorInfo) {
         if !self.instance_muted(instance_id) {
-            self.error = Some(message);
+            self.set_error_info(info);
         }
     }
 
@@ -2715,7 +3164,7 @@ impl ScriptHost {
                 message.push_str(&format!(" [instance {id}]"));
                 instance.errored = true;
             }
-            self.set_instance_error_message(id, message);
+            self.set_instance_error(id, ScriptErrorInfo::from_message(message));
         } else {
             self.set_error_message(message);
         }
@@ -2764,6 +3213,8 @@ impl ScriptHost {
             last_asset_revision: None,
             callback_budget_ms: None,
             error: None,
+            last_error_repeat: None,
+            error_reported_this_frame: false,
             enabled: true,
             initialized: false,
             shared: Rc::new(RefCell::new(shared)),
@@ -2795,7 +3246,11 @@ impl ScriptHost {
     }
 
     pub fn last_error(&self) -> Option<&str> {
-        self.error.as_deref()
+        self.error.as_ref().map(|info| info.message.as_str())
+    }
+
+    pub fn last_error_info(&self) -> Option<&ScriptErrorInfo> {
+        self.error.as_ref()
     }
 
     pub fn set_entity_snapshots(
@@ -2820,6 +3275,11 @@ impl ScriptHost {
         shared.scene_id_entities = scene_id_entities;
     }
 
+    /// Replaces the tag lookup behind `query_entities_with_tag`; entity lists should be sorted.
+    pub fn set_entity_tag_index(&mut self, tag_entities: HashMap<Arc<str>, Vec<Entity>>) {
+        self.shared.borrow_mut().tag_entities = tag_entities;
+    }
+
     pub fn set_input_snapshot(&mut self, snapshot: InputSnapshot) {
         let mut shared = self.shared.borrow_mut();
         shared.input_snapshot = Some(snapshot);
@@ -2942,9 +3402,7 @@ impl ScriptHost {
     }
 
     pub fn set_error_message(&mut self, msg: impl Into<String>) {
-        let msg = msg.into();
-        eprintln!("[script] {msg}");
-        self.error = Some(msg);
+        self.report_error(ScriptErrorInfo::from_message(msg));
     }
 
     pub fn set_error_with_details(&mut self, err: &Error) {
@@ -2953,7 +3411,47 @@ impl ScriptHost {
         for cause in err.chain().skip(1) {
             let _ = write!(&mut buf, "\ncaused by: {cause}");
         }
-        self.set_error_message(buf);
+        let location = err
+            .chain()
+            .find_map(|cause| cause.downcast_ref::<LocatedScriptError>())
+            .and_then(|located| located.location.clone());
+        self.report_error(ScriptErrorInfo { location, ..ScriptErrorInfo::from_message(buf) });
+    }
+
+    fn report_error(&mut self, info: ScriptErrorInfo) {
+        self.set_error_info(info);
+        if let Some(info) = self.error.as_ref().filter(|info| info.repeat_count == 1) {
+            eprintln!("[script] {}", info.message);
+        }
+    }
+
+    /// Records `info` as the current error. Identical consecutive errors only bump the repeat
+    /// counter so a callback failing every frame doesn't flood tooling.
+    fn set_error_info(&mut self, mut info: ScriptErrorInfo) {
+        self.error_reported_this_frame = true;
+        let repeat_count = match self.last_error_repeat.as_mut() {
+            Some((message, count)) if *message == info.message => {
+                *count = count.saturating_add(1);
+                *count
+            }
+            _ => {
+                self.last_error_repeat = Some((info.message.clone(), 1));
+                1
+            }
+        };
+        if let Some(current) = self.error.as_mut().filter(|current| current.message == info.message) {
+            current.repeat_count = repeat_count;
+            return;
+        }
+        if let Some(location) = info.location.as_mut() {
+            let resolved = self.resolve_script_path(&location.script_path);
+            if let Ok(source) = std::fs::read_to_string(&resolved) {
+                info.snippet = source_snippet(&source, location.line, SCRIPT_ERROR_SNIPPET_CONTEXT);
+                location.script_path = resolved.to_string_lossy().into_owned();
+            }
+        }
+        info.repeat_count = repeat_count;
+        self.error = Some(info);
     }
 
     pub fn force_reload(&mut self, assets: Option<&AssetManager>) -> Result<()> {
@@ -3004,6 +3502,10 @@ impl ScriptHost {
         let ast = self
             .engine
             .compile(source)
+            .map_err(|err| LocatedScriptError {
+                message: err.to_string(),
+                location: ScriptErrorLocation::from_position(path, err.position()),
+            })
             .with_context(|| format!("Compiling Rhai script '{}'", path))?;
         let import_digests = self.import_resolver.compute_import_digests(source)?;
         let (has_ready, has_process, has_physics_process, has_exit) = detect_callbacks(&ast);
@@ -3050,8 +3552,9 @@ impl ScriptHost {
             instance.errored = false;
             if let Err(err) = self.engine.run_ast_with_scope(&mut instance.scope, &compiled.ast) {
                 instance.errored = true;
-                let message = Self::format_rhai_error(err.as_ref(), script_path, "globals");
-                self.error = Some(message.clone());
+                let info = Self::rhai_error_info(err.as_ref(), script_path, "globals");
+                let message = info.message.clone();
+                self.set_error_info(info);
                 return Err(anyhow!(message));
             }
         }
@@ -3087,7 +3590,7 @@ impl ScriptHost {
                     }
                     Err(err) => {
                         instance.errored = true;
-                        error_message = Some(Self::format_rhai_error(err.as_ref(), &script_path, "ready"));
+                        error_message = Some(Self::rhai_error_info(err.as_ref(), &script_path, "ready"));
                     }
                 }
             }
@@ -3096,8 +3599,9 @@ impl ScriptHost {
         self.record_timing_elapsed("ready", elapsed_ms);
         self.record_offender_entry(&script_path, "ready", Some(entity), elapsed_ms);
         self.enforce_budget(elapsed_ms, &script_path, "ready", Some(instance_id));
-        if let Some(message) = error_message {
-            self.set_instance_error_message(instance_id, message.clone());
+        if let Some(info) = error_message {
+            let message = info.message.clone();
+            self.set_instance_error(instance_id, info);
             Err(anyhow!(message))
         } else {
             Ok(())
@@ -3134,7 +3638,7 @@ impl ScriptHost {
                 elapsed_ms = start.elapsed().as_secs_f32() * 1000.0;
                 if let Err(err) = result {
                     instance.errored = true;
-                    error_message = Some(Self::format_rhai_error(err.as_ref(), &script_path, "process"));
+                    error_message = Some(Self::rhai_error_info(err.as_ref(), &script_path, "process"));
                 }
             }
             (script_path, elapsed_ms, error_message, entity)
@@ -3142,8 +3646,9 @@ impl ScriptHost {
         self.record_timing_elapsed("process", elapsed_ms);
         self.record_offender_entry(&script_path, "process", Some(entity), elapsed_ms);
         self.enforce_budget(elapsed_ms, &script_path, "process", Some(instance_id));
-        if let Some(message) = error_message {
-            self.set_instance_error_message(instance_id, message.clone());
+        if let Some(info) = error_message {
+            let message = info.message.clone();
+            self.set_instance_error(instance_id, info);
             Err(anyhow!(message))
         } else {
             Ok(())
@@ -3181,7 +3686,7 @@ impl ScriptHost {
                 if let Err(err) = result {
                     instance.errored = true;
                     error_message =
-                        Some(Self::format_rhai_error(err.as_ref(), &script_path, "physics_process"));
+                        Some(Self::rhai_error_info(err.as_ref(), &script_path, "physics_process"));
                 }
             }
             (script_path, elapsed_ms, error_message, entity)
@@ -3189,8 +3694,9 @@ impl ScriptHost {
         self.record_timing_elapsed("physics_process", elapsed_ms);
         self.record_offender_entry(&script_path, "physics_process", Some(entity), elapsed_ms);
         self.enforce_budget(elapsed_ms, &script_path, "physics_process", Some(instance_id));
-        if let Some(message) = error_message {
-            self.set_instance_error_message(instance_id, message.clone());
+        if let Some(info) = error_message {
+            let message = info.message.clone();
+            self.set_instance_error(instance_id, info);
             Err(anyhow!(message))
         } else {
             Ok(())
@@ -3221,7 +3727,7 @@ impl ScriptHost {
                 elapsed_ms = start.elapsed().as_secs_f32() * 1000.0;
                 if let Err(err) = result {
                     instance.errored = true;
-                    error_message = Some(Self::format_rhai_error(err.as_ref(), &script_path, "exit"));
+                    error_message = Some(Self::rhai_error_info(err.as_ref(), &script_path, "exit"));
                 }
             }
             (script_path, elapsed_ms, error_message, entity)
@@ -3229,8 +3735,9 @@ impl ScriptHost {
         self.record_timing_elapsed("exit", elapsed_ms);
         self.record_offender_entry(&script_path, "exit", Some(entity), elapsed_ms);
         self.enforce_budget(elapsed_ms, &script_path, "exit", Some(instance_id));
-        if let Some(message) = error_message {
-            self.set_instance_error_message(instance_id, message.clone());
+        if let Some(info) = error_message {
+            let message = info.message.clone();
+            self.set_instance_error(instance_id, info);
             Err(anyhow!(message))
         } else {
             Ok(())
@@ -3262,8 +3769,12 @@ impl ScriptHost {
     }
 
     pub fn update(&mut self, dt: f32, run_scripts: bool, assets: Option<&AssetManager>) -> f32 {
+        // An error that skipped a whole frame is no longer repeating; the next one logs afresh.
+        if !std::mem::take(&mut self.error_reported_this_frame) {
+            self.last_error_repeat = None;
+        }
         if let Err(err) = self.reload_if_needed(assets) {
-            self.error = Some(err.to_string());
+            self.set_error_with_details(&err);
             return 0.0;
         }
 
@@ -3310,7 +3821,10 @@ impl ScriptHost {
                                     "{}: Script function 'init' has wrong signature; expected init(world).",
                                     self.script_path.display()
                                 );
-                                self.error = Some(msg);
+                                self.set_error_info(ScriptErrorInfo {
+                                    callback: Some("init".to_string()),
+                                    ..ScriptErrorInfo::from_message(msg)
+                                });
                                 self.dispatch_script_events();
                                 return dt_scaled;
                             }
@@ -3319,8 +3833,8 @@ impl ScriptHost {
                             return dt_scaled;
                         }
                     }
-                    let msg = Self::format_rhai_error(err.as_ref(), script_path.as_ref(), "init");
-                    self.error = Some(msg);
+                    let info = Self::rhai_error_info(err.as_ref(), script_path.as_ref(), "init");
+                    self.set_error_info(info);
                     return dt_scaled;
                 }
             }
@@ -3351,7 +3865,10 @@ impl ScriptHost {
                                 "{}: Script function 'update' has wrong signature; expected update(world, dt: number).",
                                 self.script_path.display()
                             );
-                            self.error = Some(msg);
+                            self.set_error_info(ScriptErrorInfo {
+                                callback: Some("update".to_string()),
+                                ..ScriptErrorInfo::from_message(msg)
+                            });
                         } else {
                             self.error = None;
                         }
@@ -3359,9 +3876,12 @@ impl ScriptHost {
                         return dt_scaled;
                     }
                 }
-                let msg =
-                    Self::format_rhai_error(err.as_ref(), self.script_path.to_string_lossy().as_ref(), "update");
-                self.error = Some(msg);
+                let info = Self::rhai_error_info(
+                    err.as_ref(),
+                    self.script_path.to_string_lossy().as_ref(),
+                    "update",
+                );
+                self.set_error_info(info);
             }
         }
         self.dispatch_script_events();
@@ -3479,11 +3999,20 @@ impl ScriptHost {
     }
 
     pub fn forget_entity(&mut self, entity: Entity) {
+        self.forget_entities(&HashSet::from([entity]));
+    }
+
+    pub fn forget_entities(&mut self, entities: &HashSet<Entity>) {
+        if entities.is_empty() {
+            return;
+        }
         {
             let mut shared = self.shared.borrow_mut();
-            shared.entity_tags.remove(&entity);
+            for entity in entities {
+                shared.entity_tags.remove(entity);
+            }
             self.handle_map.retain(|handle, value| {
-                if *value == entity {
+                if entities.contains(value) {
                     shared.pending_handles.remove(handle);
                     shared.handle_tags.remove(handle);
                     false
@@ -3608,11 +4137,20 @@ impl ScriptHost {
             let cache = ScriptAstCache::new(root.clone());
             cache.store(&self.script_path, script_digest, &import_digests);
         }
-        let ast = self.engine.compile(&source).with_context(|| "Compiling Rhai script")?;
+        let script_path = self.script_path.to_string_lossy().into_owned();
+        let ast = self
+            .engine
+            .compile(&source)
+            .map_err(|err| LocatedScriptError {
+                message: err.to_string(),
+                location: ScriptErrorLocation::from_position(&script_path, err.position()),
+            })
+            .with_context(|| "Compiling Rhai script")?;
         self.scope = Scope::new();
-        self.engine
-            .run_ast_with_scope(&mut self.scope, &ast)
-            .map_err(|err| anyhow!("Evaluating script global statements: {err}"))?;
+        self.engine.run_ast_with_scope(&mut self.scope, &ast).map_err(|err| LocatedScriptError {
+            message: format!("Evaluating script global statements: {err}"),
+            location: Self::rhai_error_location(err.as_ref(), &script_path),
+        })?;
         self.last_import_digests = import_digests.clone();
         self.last_modified = Some(modified);
         self.last_len = Some(len);
@@ -3635,6 +4173,18 @@ impl ScriptHost {
     }
 }
 
+fn source_snippet(source: &str, line: usize, context: usize) -> Vec<(usize, String)> {
+    let first = line.saturating_sub(context).max(1);
+    source
+        .lines()
+        .enumerate()
+        .map(|(index, text)| (index + 1, text))
+        .skip(first - 1)
+        .take_while(|(number, _)| *number <= line + context)
+        .map(|(number, text)| (number, text.to_string()))
+        .collect()
+}
+
 fn hash_source(source: &str) -> u64 {
     let mut hasher = DefaultHasher::new();
     source.hash(&mut hasher);
@@ -3889,6 +4439,12 @@ impl ScriptPlugin {
         self.host.forget_entity(entity);
     }
 
+    /// Drops the handles of every entity in `entities` in one pass, e.g. after a batch of
+    /// `GameEvent::EntityDespawned` events.
+    pub fn forget_entities(&mut self, entities: &HashSet<Entity>) {
+        self.host.forget_entities(entities);
+    }
+
     pub fn clear_handles(&mut self) {
         self.host.clear_handles();
     }
@@ -4010,6 +4566,17 @@ impl ScriptPlugin {
         }
         drop(shared);
         self.host.set_entity_snapshots(snapshots, cell_size, spatial_cells, scene_ids);
+        let tag_entities = ecs
+            .world
+            .resource::<crate::ecs::TagIndex>()
+            .tags()
+            .map(|(tag, entities)| {
+                let mut entities: Vec<Entity> = entities.iter().copied().collect();
+                entities.sort_by_key(|entity| entity.to_bits());
+                (Arc::clone(tag), entities)
+            })
+            .collect();
+        self.host.set_entity_tag_index(tag_entities);
     }
 
     fn snapshot_from_input(input: &Input) -> InputSnapshot {
@@ -4064,6 +4631,21 @@ impl ScriptPlugin {
             ScriptCommand::EntitySetTint { .. } => 23,
             ScriptCommand::EntitySetVelocity { .. } => 24,
             ScriptCommand::EntityDespawn { .. } => 25,
+            ScriptCommand::SetClipEvents { .. } => 26,
+            ScriptCommand::RequestPath { .. } => 27,
+            ScriptCommand::SetGraphParameter { .. } => 28,
+            ScriptCommand::TriggerGraphEvent { .. } => 29,
+            ScriptCommand::PlaySound { .. } => 30,
+            ScriptCommand::EmitEvent { .. } => 31,
+            ScriptCommand::CreateMaterial { .. } => 32,
+            ScriptCommand::ReleaseMaterial { .. } => 33,
+            ScriptCommand::GetWallTime => 34,
+            ScriptCommand::EmitterBurst { .. } => 35,
+            ScriptCommand::SetTintCurve { .. } => 36,
+            ScriptCommand::GetBoneTransform { .. } => 37,
+            ScriptCommand::SetAudioVoiceCount { .. } => 38,
+            ScriptCommand::Burst { .. } => 39,
+            ScriptCommand::EntitySetTag { .. } => 40,
         }
     }
 
@@ -4089,14 +4671,15 @@ impl ScriptPlugin {
         rank_a
             .cmp(&rank_b)
             .then_with(|| match (a, b) {
-                (Spawn { handle: ha, atlas: aa, region: ra, position: pa, scale: sa, velocity: va },
-                 Spawn { handle: hb, atlas: ab, region: rb, position: pb, scale: sb, velocity: vb }) => {
+                (Spawn { handle: ha, atlas: aa, region: ra, position: pa, scale: sa, velocity: va, lifetime: la },
+                 Spawn { handle: hb, atlas: ab, region: rb, position: pb, scale: sb, velocity: vb, lifetime: lb }) => {
                     ha.cmp(hb)
                         .then_with(|| aa.cmp(ab))
                         .then_with(|| ra.cmp(rb))
                         .then_with(|| Self::cmp_vec2(pa, pb))
                         .then_with(|| Self::cmp_float(*sa, *sb))
                         .then_with(|| Self::cmp_vec2(va, vb))
+                        .then_with(|| Self::cmp_float(la.unwrap_or(-1.0), lb.unwrap_or(-1.0)))
                 }
                 (SetVelocity { handle: ha, velocity: va }, SetVelocity { handle: hb, velocity: vb }) => {
                     ha.cmp(hb).then_with(|| Self::cmp_vec2(va, vb))
@@ -4134,9 +4717,28 @@ impl ScriptPlugin {
                 (SetEmitterEndColor { color: ca }, SetEmitterEndColor { color: cb }) => Self::cmp_vec4(ca, cb),
                 (SetEmitterStartSize { size: sa }, SetEmitterStartSize { size: sb }) => Self::cmp_float(*sa, *sb),
                 (SetEmitterEndSize { size: sa }, SetEmitterEndSize { size: sb }) => Self::cmp_float(*sa, *sb),
-                (SpawnPrefab { handle: ha, path: pa, tag: taga }, SpawnPrefab { handle: hb, path: pb, tag: tagb }) => {
-                    ha.cmp(hb).then_with(|| pa.cmp(pb)).then_with(|| taga.cmp(tagb))
+                (EmitterBurst { handle: ha, count: ca }, EmitterBurst { handle: hb, count: cb }) => {
+                    ha.cmp(hb).then_with(|| ca.cmp(cb))
                 }
+                (Burst { handle: ha, count: ca, speed: sa }, Burst { handle: hb, count: cb, speed: sb }) => {
+                    ha.cmp(hb).then_with(|| ca.cmp(cb)).then_with(|| Self::cmp_float(*sa, *sb))
+                }
+                (
+                    GetBoneTransform { handle: ha, bone_name: na },
+                    GetBoneTransform { handle: hb, bone_name: nb },
+                ) => ha.cmp(hb).then_with(|| na.cmp(nb)),
+                (
+                    SetTintCurve { handle: ha, curve: ca, duration: da },
+                    SetTintCurve { handle: hb, curve: cb, duration: db },
+                ) => ha.cmp(hb).then_with(|| ca.cmp(cb)).then_with(|| Self::cmp_float(*da, *db)),
+                (
+                    SpawnPrefab { handle: ha, path: pa, tag: taga, overrides: oa },
+                    SpawnPrefab { handle: hb, path: pb, tag: tagb, overrides: ob },
+                ) => ha
+                    .cmp(hb)
+                    .then_with(|| pa.cmp(pb))
+                    .then_with(|| taga.cmp(tagb))
+                    .then_with(|| oa.keys().cmp(ob.keys())),
                 (
                     SpawnTemplate { handle: ha, template: ta, tag: taga },
                     SpawnTemplate { handle: hb, template: tb, tag: tagb },
@@ -4163,10 +4765,43 @@ impl ScriptPlugin {
                             (Some(a), Some(b)) => Self::cmp_vec4(a, b),
                         })
                 }
+                (EntitySetTag { entity: ea, tag: ta, .. }, EntitySetTag { entity: eb, tag: tb, .. }) => {
+                    ea.to_bits().cmp(&eb.to_bits()).then_with(|| ta.cmp(tb))
+                }
                 (EntitySetVelocity { entity: ea, velocity: va }, EntitySetVelocity { entity: eb, velocity: vb }) => {
                     ea.to_bits().cmp(&eb.to_bits()).then_with(|| Self::cmp_vec2(va, vb))
                 }
                 (EntityDespawn { entity: ea }, EntityDespawn { entity: eb }) => ea.to_bits().cmp(&eb.to_bits()),
+                (RequestPath { handle: ha, target: ta }, RequestPath { handle: hb, target: tb }) => {
+                    ha.cmp(hb).then_with(|| Self::cmp_vec2(ta, tb))
+                }
+                (
+                    SetGraphParameter { entity: ea, name: na, value: va },
+                    SetGraphParameter { entity: eb, name: nb, value: vb },
+                ) => {
+                    ea.to_bits().cmp(&eb.to_bits()).then_with(|| na.cmp(nb)).then_with(|| Self::cmp_float(*va, *vb))
+                }
+                (TriggerGraphEvent { entity: ea, event: na }, TriggerGraphEvent { entity: eb, event: nb }) => {
+                    ea.to_bits().cmp(&eb.to_bits()).then_with(|| na.cmp(nb))
+                }
+                (PlaySound { clip: ca, position: pa }, PlaySound { clip: cb, position: pb }) => {
+                    ca.cmp(cb).then_with(|| Self::cmp_vec2(pa, pb))
+                }
+                (SetAudioVoiceCount { count: ca }, SetAudioVoiceCount { count: cb }) => ca.cmp(cb),
+                (EmitEvent { name: na, payload: pa }, EmitEvent { name: nb, payload: pb }) => {
+                    na.cmp(nb).then_with(|| pa.cmp(pb))
+                }
+                (CreateMaterial { key: ka, .. }, CreateMaterial { key: kb, .. }) => ka.cmp(kb),
+                (ReleaseMaterial { key: ka }, ReleaseMaterial { key: kb }) => ka.cmp(kb),
+                (SetClipEvents { entity: ea, events: eva }, SetClipEvents { entity: eb, events: evb }) => {
+                    ea.to_bits().cmp(&eb.to_bits()).then_with(|| {
+                        eva.iter()
+                            .zip(evb.iter())
+                            .map(|((ta, na), (tb, nb))| Self::cmp_float(*ta, *tb).then_with(|| na.cmp(nb)))
+                            .find(|ordering| ordering.is_ne())
+                            .unwrap_or_else(|| eva.len().cmp(&evb.len()))
+                    })
+                }
                 _ => std::cmp::Ordering::Equal,
             })
     }
@@ -4511,6 +5146,10 @@ impl ScriptPlugin {
         self.host.last_error()
     }
 
+    pub fn last_error_info(&self) -> Option<&ScriptErrorInfo> {
+        self.host.last_error_info()
+    }
+
     pub fn entity_has_errored_instance(&self, entity: Entity) -> bool {
         self.host.entity_has_errored_instance(entity)
     }
@@ -4630,6 +5269,7 @@ fn register_api(engine: &mut Engine) {
     engine.register_fn("find_scene_entity", ScriptWorld::find_scene_entity);
     engine.register_fn("spawn_sprite", ScriptWorld::spawn_sprite);
     engine.register_fn("spawn_sprite_safe", ScriptWorld::spawn_sprite_safe);
+    engine.register_fn("spawn_sprite_timed", ScriptWorld::spawn_sprite_timed);
     engine.register_fn("spawn_prefab_safe", ScriptWorld::spawn_prefab_safe);
     engine.register_fn("spawn_prefab_safe", ScriptWorld::spawn_prefab_safe_with_tag);
     engine.register_fn("spawn_template_safe", ScriptWorld::spawn_template_safe);
@@ -4639,14 +5279,23 @@ fn register_api(engine: &mut Engine) {
     engine.register_fn("spawn_enemy", ScriptWorld::spawn_enemy);
     engine.register_fn("spawn_enemy_safe", ScriptWorld::spawn_enemy_safe);
     engine.register_fn("set_velocity", ScriptWorld::set_velocity);
+    engine.register_fn("request_path", ScriptWorld::request_path);
+    engine.register_fn("play_sound", ScriptWorld::play_sound);
+    engine.register_fn("set_audio_voice_count", ScriptWorld::set_audio_voice_count);
+    engine.register_fn("create_material", ScriptWorld::create_material);
+    engine.register_fn("release_material", ScriptWorld::release_material);
+    engine.register_fn("request_wall_time", ScriptWorld::request_wall_time);
+    engine.register_fn("request_bone_transform", ScriptWorld::request_bone_transform);
     engine.register_fn("set_position", ScriptWorld::set_position);
     engine.register_fn("set_rotation", ScriptWorld::set_rotation);
     engine.register_fn("set_scale", ScriptWorld::set_scale);
     engine.register_fn("set_tint", ScriptWorld::set_tint);
     engine.register_fn("clear_tint", ScriptWorld::clear_tint);
+    engine.register_fn("set_tint_curve", ScriptWorld::set_tint_curve);
     engine.register_fn("set_sprite_region", ScriptWorld::set_sprite_region);
     engine.register_fn("despawn", ScriptWorld::despawn);
     engine.register_fn("spawn_prefab", ScriptWorld::spawn_prefab);
+    engine.register_fn("spawn_prefab", ScriptWorld::spawn_prefab_with_overrides);
     engine.register_fn("spawn_template", ScriptWorld::spawn_template);
     engine.register_fn("set_auto_spawn_rate", ScriptWorld::set_auto_spawn_rate);
     engine.register_fn("set_spawn_per_press", ScriptWorld::set_spawn_per_press);
@@ -4658,6 +5307,8 @@ fn register_api(engine: &mut Engine) {
     engine.register_fn("set_emitter_end_color", ScriptWorld::set_emitter_end_color);
     engine.register_fn("set_emitter_start_size", ScriptWorld::set_emitter_start_size);
     engine.register_fn("set_emitter_end_size", ScriptWorld::set_emitter_end_size);
+    engine.register_fn("emitter_burst", ScriptWorld::emitter_burst);
+    engine.register_fn("set_emitter_burst", ScriptWorld::set_emitter_burst);
     engine.register_fn("entity_set_position", ScriptWorld::entity_set_position);
     engine.register_fn("entity_set_rotation", ScriptWorld::entity_set_rotation);
     engine.register_fn("entity_set_scale", ScriptWorld::entity_set_scale);
@@ -4665,11 +5316,18 @@ fn register_api(engine: &mut Engine) {
     engine.register_fn("entity_clear_tint", ScriptWorld::entity_clear_tint);
     engine.register_fn("entity_set_velocity", ScriptWorld::entity_set_velocity);
     engine.register_fn("entity_despawn", ScriptWorld::entity_despawn);
+    engine.register_fn("entity_set_clip_events", ScriptWorld::entity_set_clip_events);
+    engine.register_fn("entity_set_graph_parameter", ScriptWorld::entity_set_graph_parameter);
+    engine.register_fn("entity_set_graph_parameter", ScriptWorld::entity_set_graph_bool);
+    engine.register_fn("entity_trigger_graph_event", ScriptWorld::entity_trigger_graph_event);
     engine.register_fn("despawn_safe", ScriptWorld::despawn_safe);
     engine.register_fn("entity_snapshot", ScriptWorld::entity_snapshot);
     engine.register_fn("entity_position", ScriptWorld::entity_position);
     engine.register_fn("entity_rotation", ScriptWorld::entity_rotation);
     engine.register_fn("entity_tag", ScriptWorld::entity_tag);
+    engine.register_fn("entity_set_tag", ScriptWorld::entity_set_tag);
+    engine.register_fn("entity_remove_tag", ScriptWorld::entity_remove_tag);
+    engine.register_fn("query_entities_with_tag", ScriptWorld::query_entities_with_tag);
     engine.register_fn("entity_handle", ScriptWorld::entity_handle);
     engine.register_fn("entity_scene_id", ScriptWorld::entity_scene_id);
     engine.register_fn("entity_scale", ScriptWorld::entity_scale);
@@ -4821,6 +5479,224 @@ mod tests {
         assert!(matches!(&commands[..], [ScriptCommand::SetSpawnPerPress { count }] if *count == 7));
     }
 
+    #[test]
+    fn play_sound_and_emit_enqueue_commands() {
+        let script = write_script(
+            r#"
+                fn init(world) {}
+                fn update(world, dt) {}
+            "#,
+        );
+        let mut host = ScriptHost::new(script.path());
+        host.force_reload(None).expect("load script");
+
+        host.eval_repl(r#"world.play_sound("hit", 3.0, -2.0); world.emit("level_complete");"#)
+            .expect("repl commands");
+        host.eval_repl(r#"world.emit("score", 12); world.play_sound("", 0.0, 0.0);"#).expect("repl commands");
+        let commands = host.drain_commands();
+        assert!(
+            matches!(&commands[..], [
+                ScriptCommand::PlaySound { clip, position },
+                ScriptCommand::EmitEvent { name, payload: None },
+                ScriptCommand::EmitEvent { name: scored, payload: Some(score) },
+            ] if clip == "hit" && *position == Vec2::new(3.0, -2.0) && name == "level_complete"
+                && scored == "score" && score == "12"),
+            "unexpected commands: {commands:?}"
+        );
+    }
+
+    #[test]
+    fn create_and_release_material_enqueue_commands() {
+        let script = write_script(
+            r#"
+                fn init(world) {}
+                fn update(world, dt) {}
+            "#,
+        );
+        let mut host = ScriptHost::new(script.path());
+        host.force_reload(None).expect("load script");
+
+        host.eval_repl(
+            r#"world.create_material("ember",
+                   #{ base_color: [1.0, 0.5, 0.0, 1.0], roughness: 0.4, cull_mode: "none" });
+               world.create_material("broken", #{ base_color: [1.0, 0.5] });
+               world.create_material("sideways", #{ cull_mode: "sideways" });
+               world.release_material("ember");"#,
+        )
+        .expect("repl commands");
+        let commands = host.drain_commands();
+        assert!(
+            matches!(&commands[..], [
+                ScriptCommand::CreateMaterial { key, params },
+                ScriptCommand::ReleaseMaterial { key: released },
+            ] if key == "ember" && params.base_color == Vec4::new(1.0, 0.5, 0.0, 1.0)
+                && params.roughness == 0.4 && params.metallic == 0.0 && params.albedo_texture.is_none()
+                && params.cull_mode == MaterialCullMode::None && released == "ember"),
+            "unexpected commands: {commands:?}"
+        );
+    }
+
+    #[test]
+    fn set_audio_voice_count_enqueues_clamped_command() {
+        let script = write_script(
+            r#"
+                fn init(world) {}
+                fn update(world, dt) {}
+            "#,
+        );
+        let mut host = ScriptHost::new(script.path());
+        host.force_reload(None).expect("load script");
+
+        host.eval_repl("world.set_audio_voice_count(32); world.set_audio_voice_count(0);")
+            .expect("repl command");
+        host.eval_repl("world.set_audio_voice_count(100000);").expect("repl command");
+        let commands = host.drain_commands();
+        assert!(
+            matches!(&commands[..], [
+                ScriptCommand::SetAudioVoiceCount { count: 32 },
+                ScriptCommand::SetAudioVoiceCount { count },
+            ] if *count == MAX_VOICE_COUNT),
+            "unexpected commands: {commands:?}"
+        );
+    }
+
+    #[test]
+    fn request_wall_time_enqueues_command() {
+        let script = write_script(
+            r#"
+                fn init(world) {}
+                fn update(world, dt) {}
+            "#,
+        );
+        let mut host = ScriptHost::new(script.path());
+        host.force_reload(None).expect("load script");
+
+        host.eval_repl("world.request_wall_time();").expect("repl command");
+        let commands = host.drain_commands();
+        assert!(matches!(&commands[..], [ScriptCommand::GetWallTime]), "unexpected commands: {commands:?}");
+    }
+
+    #[test]
+    fn request_bone_transform_enqueues_command_for_live_handles() {
+        let script = write_script(
+            r#"
+                fn init(world) {}
+                fn update(world, dt) {}
+            "#,
+        );
+        let mut host = ScriptHost::new(script.path());
+        host.force_reload(None).expect("load script");
+
+        host.eval_repl(
+            r#"
+                let h = world.spawn_sprite("main", "green", 0.0, 0.0, 1.0, 0.0, 0.0);
+                world.request_bone_transform(h, "hand");
+                world.request_bone_transform(h, "");
+                world.request_bone_transform(9999, "hand");
+            "#,
+        )
+        .expect("repl command");
+        let commands = host.drain_commands();
+        assert!(
+            matches!(
+                &commands[..],
+                [ScriptCommand::Spawn { .. }, ScriptCommand::GetBoneTransform { bone_name, .. }] if bone_name == "hand"
+            ),
+            "unexpected commands: {commands:?}"
+        );
+    }
+
+    #[test]
+    fn emitter_burst_enqueues_command_for_live_handles() {
+        let script = write_script(
+            r#"
+                fn init(world) {}
+                fn update(world, dt) {}
+            "#,
+        );
+        let mut host = ScriptHost::new(script.path());
+        host.force_reload(None).expect("load script");
+
+        host.eval_repl(
+            r#"
+                let h = world.spawn_sprite("main", "green", 0.0, 0.0, 1.0, 0.0, 0.0);
+                world.emitter_burst(h, 500);
+                world.emitter_burst(h, 0);
+                world.emitter_burst(9999, 10);
+            "#,
+        )
+        .expect("repl command");
+        let commands = host.drain_commands();
+        assert!(
+            matches!(
+                &commands[..],
+                [ScriptCommand::Spawn { .. }, ScriptCommand::EmitterBurst { count: 500, .. }]
+            ),
+            "unexpected commands: {commands:?}"
+        );
+    }
+
+    #[test]
+    fn set_emitter_burst_enqueues_burst_with_speed() {
+        let script = write_script(
+            r#"
+                fn init(world) {}
+                fn update(world, dt) {}
+            "#,
+        );
+        let mut host = ScriptHost::new(script.path());
+        host.force_reload(None).expect("load script");
+
+        host.eval_repl(
+            r#"
+                let h = world.spawn_sprite("main", "green", 0.0, 0.0, 1.0, 0.0, 0.0);
+                world.set_emitter_burst(h, 40, 3.5);
+                world.set_emitter_burst(h, 0, 3.5);
+                world.set_emitter_burst(9999, 10, 1.0);
+            "#,
+        )
+        .expect("repl command");
+        let commands = host.drain_commands();
+        assert!(
+            matches!(
+                &commands[..],
+                [ScriptCommand::Spawn { .. }, ScriptCommand::Burst { count: 40, speed, .. }] if *speed == 3.5
+            ),
+            "unexpected commands: {commands:?}"
+        );
+    }
+
+    #[test]
+    fn set_tint_curve_rejects_invalid_durations() {
+        let script = write_script(
+            r#"
+                fn init(world) {}
+                fn update(world, dt) {}
+            "#,
+        );
+        let mut host = ScriptHost::new(script.path());
+        host.force_reload(None).expect("load script");
+
+        host.eval_repl(
+            r#"
+                let h = world.spawn_sprite("main", "green", 0.0, 0.0, 1.0, 0.0, 0.0);
+                world.set_tint_curve(h, "damage_flash", 0.25);
+                world.set_tint_curve(h, "damage_flash", 0.0);
+                world.set_tint_curve(h, "", 1.0);
+            "#,
+        )
+        .expect("repl command");
+        let commands = host.drain_commands();
+        assert!(
+            matches!(
+                &commands[..],
+                [ScriptCommand::Spawn { .. }, ScriptCommand::SetTintCurve { curve, duration, .. }]
+                    if curve == "damage_flash" && (*duration - 0.25).abs() < 1e-6
+            ),
+            "unexpected commands: {commands:?}"
+        );
+    }
+
     #[test]
     fn reload_detects_changes_when_metadata_is_stable() {
         let script = write_script(
@@ -4878,6 +5754,65 @@ mod tests {
         assert!(err.contains("update") && err.contains("signature"), "unexpected error: {err}");
     }
 
+    #[test]
+    fn syntax_error_reports_location_snippet_and_repeats() {
+        let script = write_script(
+            "fn init(world) { }\nfn update(world, dt) {\n    let speed = 1.0;\n    let x = speed +* 2.0;\n}\n",
+        );
+        let mut host = ScriptHost::new(script.path());
+        let _ = host.update(0.016, true, None);
+        let info = host.last_error_info().expect("compile error recorded").clone();
+        let location = info.location.as_ref().expect("compile error location");
+        assert_eq!(location.line, 4, "unexpected location: {location:?}");
+        assert!(location.column.is_some());
+        assert_eq!(info.snippet.first().map(|(line, _)| *line), Some(2));
+        assert!(info.snippet.iter().any(|(line, text)| *line == 4 && text.contains("+*")));
+        assert_eq!(info.repeat_count, 1);
+
+        let _ = host.update(0.016, true, None);
+        let repeated = host.last_error_info().expect("error still recorded");
+        assert_eq!(repeated.message, info.message);
+        assert_eq!(repeated.repeat_count, 2, "identical consecutive errors are counted, not duplicated");
+    }
+
+    #[test]
+    fn repeat_count_resets_after_an_error_free_frame() {
+        let script = write_script(
+            r#"
+                fn init(world) { }
+                fn update(world, dt) { if dt > 0.0 { throw "boom"; } }
+            "#,
+        );
+        let mut host = ScriptHost::new(script.path());
+        host.force_reload(None).expect("initial load");
+        let _ = host.update(0.016, true, None);
+        let _ = host.update(0.016, true, None);
+        assert_eq!(host.last_error_info().map(|info| info.repeat_count), Some(2));
+
+        let _ = host.update(0.0, true, None);
+        assert!(host.last_error_info().is_none(), "a clean frame clears the error");
+        let _ = host.update(0.016, true, None);
+        assert_eq!(
+            host.last_error_info().map(|info| info.repeat_count),
+            Some(1),
+            "the error starts a new streak after a clean frame"
+        );
+    }
+
+    #[test]
+    fn event_handler_errors_record_callback_and_line() {
+        let script = write_script(
+            "fn init(world) {\n    world.listen(\"boom\", \"on_boom\");\n    let _ok = world.emit(\"boom\");\n}\nfn update(world, dt) { }\nfn on_boom(world, event) {\n    let value = 1;\n    value.explode();\n}\n",
+        );
+        let mut host = ScriptHost::new(script.path());
+        host.force_reload(None).expect("initial load");
+        let _ = host.update(0.016, true, None);
+        let info = host.last_error_info().expect("handler error recorded");
+        assert_eq!(info.callback.as_deref(), Some("on_boom"));
+        assert_eq!(info.location.as_ref().map(|location| location.line), Some(8), "{info:?}");
+        assert!(info.snippet.iter().any(|(line, text)| *line == 8 && text.contains("explode")));
+    }
+
     #[test]
     fn digest_check_is_throttled() {
         let script = write_script(
@@ -5352,9 +6287,19 @@ mod tests {
         plugin.set_deterministic_ordering(true);
         {
             let mut shared = plugin.host.shared.borrow_mut();
-            shared.commands.push(ScriptCommand::SpawnPrefab { handle: 2, path: "b".into(), tag: None });
+            shared.commands.push(ScriptCommand::SpawnPrefab {
+                handle: 2,
+                path: "b".into(),
+                tag: None,
+                overrides: PrefabOverrides::new(),
+            });
             shared.commands.push(ScriptCommand::SetPosition { handle: 1, position: Vec2::new(1.0, 0.0) });
-            shared.commands.push(ScriptCommand::SpawnPrefab { handle: 0, path: "a".into(), tag: None });
+            shared.commands.push(ScriptCommand::SpawnPrefab {
+                handle: 0,
+                path: "a".into(),
+                tag: None,
+                overrides: PrefabOverrides::new(),
+            });
         }
         let cmds = plugin.drain_host_commands();
         assert!(
@@ -5618,6 +6563,25 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn spawn_prefab_with_overrides_carries_the_override_map() {
+        let state = Rc::new(RefCell::new(SharedState::default()));
+        let mut world = ScriptWorld::new(state.clone());
+        let mut overrides = Map::new();
+        let position: Array = vec![Dynamic::from(3.0 as FLOAT), Dynamic::from(-1.5 as FLOAT)];
+        overrides.insert("position".into(), Dynamic::from(position));
+        let handle = world.spawn_prefab_with_overrides("assets/scenes/example.json", overrides);
+        assert!(handle >= 0);
+        let cmds = state.borrow().commands.clone();
+        match cmds.as_slice() {
+            [ScriptCommand::SpawnPrefab { handle: h, overrides, .. }] => {
+                assert_eq!(*h, handle);
+                assert_eq!(overrides.get("position"), Some(&serde_json::json!([3.0, -1.5])));
+            }
+            other => panic!("unexpected commands: {other:?}"),
+        }
+    }
+
     #[test]
     fn spawn_template_enqueues_command_with_handle() {
         let state = Rc::new(RefCell::new(SharedState::default()));
@@ -5742,6 +6706,47 @@ mod tests {
         assert_eq!(world.entities_with_tag("missing").len(), 0);
     }
 
+    #[test]
+    fn entity_tag_api_queues_commands_and_queries_index() {
+        let mut host = ScriptHost::new("assets/scripts/main.rhai");
+        let entity = Entity::from_raw(42);
+        let mut snaps = HashMap::new();
+        snaps.insert(
+            entity,
+            EntitySnapshot {
+                translation: Vec2::ZERO,
+                rotation: 0.0,
+                scale: Vec2::ONE,
+                velocity: None,
+                tint: None,
+                half_extents: None,
+            },
+        );
+        host.set_entity_snapshots(snaps, 1.0, None, HashMap::new());
+        host.set_entity_tag_index(HashMap::from([(Arc::from("collectible"), vec![entity])]));
+
+        let mut world = ScriptWorld::new(host.shared.clone());
+        let bits = entity.to_bits() as ScriptHandle;
+        let entities = world.query_entities_with_tag(" collectible ");
+        assert_eq!(entities.len(), 1);
+        assert_eq!(entities[0].clone_cast::<ScriptHandle>(), bits);
+        assert!(world.query_entities_with_tag("enemy").is_empty());
+
+        assert!(world.entity_set_tag(bits, "enemy"));
+        assert!(world.entity_remove_tag(bits, "collectible"));
+        assert!(!world.entity_set_tag(bits, "  "), "blank tags are rejected");
+        assert!(!world.entity_set_tag(Entity::from_raw(7).to_bits() as ScriptHandle, "enemy"));
+        let cmds = host.shared.borrow().commands.clone();
+        assert!(matches!(
+            cmds.as_slice(),
+            [
+                ScriptCommand::EntitySetTag { tag: added, tagged: true, .. },
+                ScriptCommand::EntitySetTag { tag: removed, tagged: false, .. },
+            ] if added == "enemy" && removed == "collectible"
+        ));
+        assert_eq!(host.shared.borrow().invalid_handle_uses, 1);
+    }
+
     #[test]
     fn entity_tag_and_handle_return_spawn_metadata() {
         let mut host = ScriptHost::new("assets/scripts/main.rhai");
@@ -5827,6 +6832,20 @@ mod tests {
         assert!(world.state.borrow().commands.is_empty(), "no command should be queued on invalid spawn");
     }
 
+    #[test]
+    fn spawn_sprite_timed_carries_lifetime() {
+        let mut world = ScriptWorld::new(Rc::new(RefCell::new(SharedState::default())));
+        assert!(world.spawn_sprite_timed("main", "green", 0.0, 0.0, 0.1, 1.0, 0.0, 0.0) < 0);
+        let handle = world.spawn_sprite_timed("main", "green", 0.0, 0.0, 0.1, 1.0, 0.0, 0.75);
+        assert!(handle >= 0, "positive lifetime spawns");
+        let state = world.state.borrow();
+        assert!(
+            matches!(&state.commands[..], [ScriptCommand::Spawn { lifetime: Some(l), .. }] if *l == 0.75),
+            "unexpected commands: {:?}",
+            state.commands
+        );
+    }
+
     #[test]
     fn pending_spawn_handle_is_usable_before_materialize() {
         let mut host = ScriptHost::new("assets/scripts/main.rhai");
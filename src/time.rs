@@ -21,6 +21,15 @@ impl Time {
     pub fn elapsed_seconds(&self) -> f32 {
         self.last.duration_since(self.start).as_secs_f32()
     }
+    /// Real seconds since this clock was created, read from `Instant::now()` rather than the last
+    /// tick. Unaffected by `AnimationTime` scaling or pausing.
+    pub fn wall_clock_elapsed(&self) -> f32 {
+        self.start.elapsed().as_secs_f32()
+    }
+    /// Same as [`Time::wall_clock_elapsed`]; named to match the `*_secs` accessors plugins use.
+    pub fn wall_elapsed_secs(&self) -> f32 {
+        self.wall_clock_elapsed()
+    }
 }
 
 impl Default for Time {
@@ -28,3 +37,21 @@ impl Default for Time {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::{AnimationDelta, AnimationTime};
+
+    #[test]
+    fn wall_clock_advances_while_animation_time_is_paused() {
+        let time = Time::new();
+        let mut animation_time = AnimationTime { paused: true, ..AnimationTime::default() };
+        let before = time.wall_clock_elapsed();
+        std::thread::sleep(Duration::from_millis(5));
+        assert!(matches!(animation_time.consume(0.005), AnimationDelta::None), "paused game time stays put");
+        let after = time.wall_elapsed_secs();
+        assert!(after >= before + 0.005, "wall clock advanced from {before} to {after}");
+        assert_eq!(time.elapsed_seconds(), 0.0, "tick-based elapsed time only moves on tick");
+    }
+}
@@ -0,0 +1,108 @@
+use glam::{Mat4, Vec2};
+use kestrel_engine::alloc_sampler::{self, AllocationPressureSample};
+use kestrel_engine::assets::AssetManager;
+use kestrel_engine::config::WindowConfig;
+use kestrel_engine::ecs::{EcsWorld, Sprite, Transform, WorldTransform};
+use kestrel_engine::renderer::{RenderViewport, Renderer, SpriteBatch};
+use pollster::block_on;
+use std::sync::Arc;
+
+fn headless_renderer() -> (Renderer, wgpu::Sampler, Arc<wgpu::TextureView>) {
+    let window_config = WindowConfig {
+        title: "Headless".into(),
+        width: 48,
+        height: 32,
+        vsync: false,
+        fullscreen: false,
+        render_scale: 1.0,
+    };
+    let mut renderer = block_on(Renderer::new(&window_config));
+    block_on(renderer.init_headless_for_test()).expect("init headless");
+    let (sampler, atlas_view) = {
+        let device = renderer.device().expect("device");
+        let atlas_texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Test Atlas"),
+            size: wgpu::Extent3d { width: 1, height: 1, depth_or_array_layers: 1 },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8UnormSrgb,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+            view_formats: &[],
+        });
+        let atlas_view = atlas_texture.create_view(&wgpu::TextureViewDescriptor::default());
+        (device.create_sampler(&wgpu::SamplerDescriptor::default()), atlas_view)
+    };
+    let atlas_view = Arc::new(atlas_view);
+    renderer
+        .init_sprite_pipeline_with_atlas((*atlas_view).clone(), sampler.clone())
+        .expect("init sprite pipeline");
+    renderer.prepare_headless_render_target().expect("headless target");
+    (renderer, sampler, atlas_view)
+}
+
+fn spawn_sprites(ecs: &mut EcsWorld, indices: std::ops::Range<usize>) {
+    for index in indices {
+        let translation = Vec2::new(index as f32, 0.0);
+        ecs.world.spawn((
+            Transform { translation, rotation: 0.0, scale: Vec2::ONE },
+            WorldTransform(Mat4::from_translation(translation.extend(0.0))),
+            Sprite::uninitialized(Arc::from("main"), Arc::from("redorb")),
+        ));
+    }
+}
+
+fn render_sprite_frame(
+    ecs: &mut EcsWorld,
+    assets: &AssetManager,
+    renderer: &mut Renderer,
+    sampler: &wgpu::Sampler,
+    view: &Arc<wgpu::TextureView>,
+) {
+    let instances: Vec<_> = ecs
+        .collect_sprite_instances(assets)
+        .expect("collect sprites")
+        .into_iter()
+        .map(|instance| instance.into_gpu().1)
+        .collect();
+    let batches = [SpriteBatch {
+        atlas: Arc::from("main"),
+        range: 0..instances.len() as u32,
+        view: Arc::clone(view),
+        normal_view: None,
+        dirty: true,
+    }];
+    let viewport = RenderViewport { origin: (0.0, 0.0), size: (48.0, 32.0) };
+    let (frame, _) = renderer
+        .render_frame(&instances, &batches, sampler, Mat4::IDENTITY, viewport, &[], None)
+        .expect("render");
+    frame.present();
+}
+
+#[test]
+fn counters_increment_during_a_frame_that_builds_sprite_batches() {
+    let (mut renderer, sampler, view) = headless_renderer();
+    let assets = AssetManager::new();
+    let mut ecs = EcsWorld::new();
+    spawn_sprites(&mut ecs, 0..16);
+
+    render_sprite_frame(&mut ecs, &assets, &mut renderer, &sampler, &view);
+    assert_eq!(alloc_sampler::take_sample(), AllocationPressureSample::default(), "sampling starts disabled");
+
+    // Growing the scene forces the sprite pass to grow its resident instance copy.
+    spawn_sprites(&mut ecs, 16..64);
+    alloc_sampler::set_enabled(true);
+    render_sprite_frame(&mut ecs, &assets, &mut renderer, &sampler, &view);
+    let sample = alloc_sampler::take_sample();
+    alloc_sampler::set_enabled(false);
+
+    assert!(sample.sprite_instances.allocations > 0, "instance collection is counted: {sample:?}");
+    assert!(sample.sprite_instances.bytes > 0);
+    assert!(sample.sprite_batches.allocations > 0, "batch upload is counted: {sample:?}");
+    assert_eq!(sample.mesh_instances.allocations, 0);
+    assert_eq!(
+        alloc_sampler::take_sample(),
+        AllocationPressureSample::default(),
+        "taking a sample resets it"
+    );
+}
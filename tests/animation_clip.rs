@@ -1,4 +1,7 @@
-use kestrel_engine::assets::AssetManager;
+use glam::Vec2;
+use kestrel_engine::assets::{parse_animation_clip_bytes, AssetManager, ClipKeyValue, ClipTrack};
+use kestrel_engine::ecs::{ClipInstance, EcsWorld, Transform, WorldTransform};
+use std::sync::Arc;
 
 #[test]
 fn retain_clip_loads_fixture_tracks() {
@@ -27,3 +30,216 @@ fn retain_clip_loads_fixture_tracks() {
         "tint keyframes should preserve values"
     );
 }
+
+fn slime_clip() -> kestrel_engine::assets::AnimationClip {
+    let mut assets = AssetManager::new();
+    assets.retain_clip("slime", Some("fixtures/animation_clips/slime_bob.json")).expect("load clip fixture");
+    assets.clip("slime").expect("clip present").clone()
+}
+
+fn track_times(clip: &kestrel_engine::assets::AnimationClip, track: ClipTrack) -> Vec<f32> {
+    clip.keyframes(track).iter().map(|kf| kf.time).collect()
+}
+
+#[test]
+fn insert_keyframe_mid_track_keeps_keys_sorted() {
+    let clip = slime_clip();
+    assert_eq!(clip.keyframe_count(ClipTrack::Translation), 3);
+
+    let edited = clip
+        .insert_keyframe(ClipTrack::Translation, 0.1, ClipKeyValue::Vec2(Vec2::new(4.0, 2.0)), false)
+        .expect("insert keyframe");
+    assert_eq!(edited.keyframe_count(ClipTrack::Translation), 4);
+    assert_eq!(track_times(&edited, ClipTrack::Translation), vec![0.0, 0.1, 0.25, 0.5]);
+    assert_eq!(edited.keyframes(ClipTrack::Translation)[1].value, ClipKeyValue::Vec2(Vec2::new(4.0, 2.0)));
+    let translation = edited.translation.as_ref().expect("translation track");
+    assert_eq!(translation.segments.len(), 3, "segment cache should be rebuilt");
+    assert_eq!(clip.keyframe_count(ClipTrack::Translation), 3, "source clip is left untouched");
+
+    let replaced = edited
+        .insert_keyframe(ClipTrack::Translation, 0.25, ClipKeyValue::Vec2(Vec2::splat(9.0)), false)
+        .expect("replace keyframe");
+    assert_eq!(replaced.keyframe_count(ClipTrack::Translation), 4, "same-time insert replaces the value");
+    assert_eq!(replaced.keyframes(ClipTrack::Translation)[2].value, ClipKeyValue::Vec2(Vec2::splat(9.0)));
+    assert!(edited
+        .insert_keyframe(ClipTrack::Translation, 0.25, ClipKeyValue::Vec2(Vec2::ONE), true)
+        .is_err());
+    assert!(edited.insert_keyframe(ClipTrack::Rotation, 0.1, ClipKeyValue::Vec2(Vec2::ONE), false).is_err());
+
+    let extended =
+        edited.insert_keyframe(ClipTrack::Rotation, 1.0, ClipKeyValue::Scalar(0.5), false).unwrap();
+    assert!((extended.duration - 1.0).abs() < f32::EPSILON, "clip duration follows the longest track");
+}
+
+#[test]
+fn move_and_delete_keyframes_resort_the_track() {
+    let clip = slime_clip();
+    let moved = clip.move_keyframe(ClipTrack::Translation, 0, 0.4).expect("move keyframe");
+    assert_eq!(track_times(&moved, ClipTrack::Translation), vec![0.25, 0.4, 0.5]);
+
+    let deleted = moved.delete_keyframe(ClipTrack::Translation, 1).expect("delete keyframe");
+    assert_eq!(deleted.keyframe_count(ClipTrack::Translation), 2);
+    assert_eq!(track_times(&deleted, ClipTrack::Translation), vec![0.25, 0.5]);
+    assert!(deleted.delete_keyframe(ClipTrack::Translation, 5).is_err());
+
+    let cleared = deleted.delete_keyframes(ClipTrack::Translation, &[0, 1]).expect("delete all keys");
+    assert!(cleared.translation.is_none(), "removing every key drops the track");
+}
+
+const AUTO_TANGENT_CLIP: &str = r#"{
+    "version": 1,
+    "tracks": {
+        "rotation": {
+            "auto_tangent": true,
+            "keyframes": [
+                { "time": 0.0, "value": 0.0 },
+                { "time": 1.0, "value": 2.0 },
+                { "time": 2.0, "value": 6.0, "in_tangent": 1.0 },
+                { "time": 2.00005, "value": 6.0 },
+                { "time": 3.0, "value": 4.0 }
+            ]
+        },
+        "translation": {
+            "keyframes": [
+                { "time": 0.0, "value": [0.0, 0.0] },
+                { "time": 1.0, "value": [1.0, 1.0] }
+            ]
+        }
+    }
+}"#;
+
+#[test]
+fn auto_tangent_fills_missing_tangents_with_catmull_rom_slopes() {
+    let clip =
+        parse_animation_clip_bytes(AUTO_TANGENT_CLIP.as_bytes(), "auto", "inline").expect("parse clip");
+    let rotation = clip.rotation.as_ref().expect("rotation track");
+    assert!(rotation.auto_tangent);
+    let tangents: Vec<(Option<f32>, Option<f32>)> =
+        rotation.keyframes.iter().map(|kf| (kf.in_tangent, kf.out_tangent)).collect();
+    assert_eq!(tangents[0], (Some(0.0), Some(0.0)), "first key gets a zero tangent");
+    assert_eq!(tangents[1], (Some(3.0), Some(3.0)), "(6 - 0) / (2 - 0)");
+    assert_eq!(
+        tangents[2],
+        (Some(1.0), Some(3.0)),
+        "authored in tangent is kept; duplicate time copies key 1"
+    );
+    assert_eq!(tangents[3], (Some(3.0), Some(3.0)), "duplicate time copies the nearest computed tangent");
+    assert_eq!(tangents[4], (Some(0.0), Some(0.0)), "last key gets a zero tangent");
+
+    let translation = clip.translation.as_ref().expect("translation track");
+    assert!(!translation.auto_tangent);
+    assert!(translation.keyframes.iter().all(|kf| kf.in_tangent.is_none() && kf.out_tangent.is_none()));
+}
+
+#[test]
+fn auto_tangent_tracks_recompute_tangents_after_edits_and_round_trip() {
+    let clip =
+        parse_animation_clip_bytes(AUTO_TANGENT_CLIP.as_bytes(), "auto", "inline").expect("parse clip");
+    let edited =
+        clip.set_keyframe_value(ClipTrack::Rotation, 0, ClipKeyValue::Scalar(-2.0)).expect("edit key");
+    let rotation = edited.rotation.as_ref().expect("rotation track");
+    assert!(rotation.auto_tangent);
+    let tangent = rotation.keyframes[1].out_tangent.expect("recomputed tangent");
+    assert!((tangent - 4.0).abs() < 1e-3, "(6 - -2) / (2 - 0), got {tangent}");
+
+    let dir = tempfile::tempdir().expect("temp dir");
+    let path = dir.path().join("auto.json");
+    std::fs::write(&path, AUTO_TANGENT_CLIP).expect("write clip");
+    let mut assets = AssetManager::new();
+    assets.load_clip("auto", path.to_str().unwrap()).expect("load clip");
+    assets.save_clip("auto", &clip).expect("save clip");
+    let saved = std::fs::read(&path).expect("read saved clip");
+    let reloaded = parse_animation_clip_bytes(&saved, "auto", "saved").expect("parse saved clip");
+    let reloaded_rotation = reloaded.rotation.as_ref().expect("rotation track");
+    assert!(reloaded_rotation.auto_tangent, "flag is written back");
+    assert_eq!(reloaded_rotation.keyframes.as_ref(), clip.rotation.as_ref().unwrap().keyframes.as_ref());
+    let saved_json = String::from_utf8(saved).unwrap();
+    assert_eq!(saved_json.matches("in_tangent").count(), 5, "only the rotation keys carry tangents");
+}
+
+const EASED_CLIP: &str = r#"{
+    "version": 1,
+    "tracks": {
+        "translation": {
+            "keyframes": [
+                { "time": 0.0, "value": [0.0, 0.0], "out_tangent": [0.0, 0.0] },
+                { "time": 1.0, "value": [1.0, 0.0], "in_tangent": [0.0, 0.0] }
+            ]
+        },
+        "rotation": {
+            "keyframes": [
+                { "time": 0.0, "value": 0.0 },
+                { "time": 1.0, "value": 1.0 }
+            ]
+        }
+    }
+}"#;
+
+#[test]
+fn key_tangents_ease_clip_and_ecs_sampling() {
+    let clip = parse_animation_clip_bytes(EASED_CLIP.as_bytes(), "eased", "inline").expect("parse clip");
+    // Zero tangents on both keys: 3s² - 2s³ at s = 0.25.
+    let eased = 0.15625;
+    let instance = ClipInstance::new(Arc::from("eased"), Arc::new(clip.clone()));
+    let sample = instance.sample_at(0.25);
+    assert!((sample.translation.unwrap().x - eased).abs() < 1e-5, "curved segment eases in");
+    assert!((sample.rotation.unwrap() - 0.25).abs() < 1e-5, "keys without tangents stay linear");
+
+    let mut assets = AssetManager::new();
+    assets.load_clip_from_bytes("eased", "inline", EASED_CLIP.as_bytes()).expect("load clip");
+    let mut ecs = EcsWorld::new();
+    let entity = ecs.world.spawn((Transform::default(), WorldTransform::default())).id();
+    assert!(ecs.set_transform_clip(entity, &assets, "eased"));
+    for _ in 0..5 {
+        ecs.update(0.05);
+    }
+    let transform = ecs.world.get::<Transform>(entity).expect("transform");
+    assert!((transform.translation.x - eased).abs() < 1e-4, "got {}", transform.translation.x);
+    assert!((transform.rotation - 0.25).abs() < 1e-4);
+}
+
+#[test]
+fn key_tangent_edits_scale_and_round_trip() {
+    let clip = slime_clip();
+    assert!(clip.keyframes(ClipTrack::Rotation).iter().all(|kf| kf.in_tangent.is_none()));
+    let eased = clip
+        .set_keyframe_tangents(ClipTrack::Rotation, 0, None, Some(ClipKeyValue::Scalar(0.0)))
+        .expect("set tangents");
+    let keys = eased.keyframes(ClipTrack::Rotation);
+    assert_eq!((keys[0].in_tangent, keys[0].out_tangent), (None, Some(ClipKeyValue::Scalar(0.0))));
+    let wrong_type = Some(ClipKeyValue::Vec2(Vec2::ONE));
+    assert!(clip.set_keyframe_tangents(ClipTrack::Rotation, 0, wrong_type, None).is_err());
+    let not_finite = Some(ClipKeyValue::Scalar(f32::NAN));
+    assert!(clip.set_keyframe_tangents(ClipTrack::Rotation, 0, not_finite, None).is_err());
+
+    let end = keys[1].time;
+    let scaled = eased
+        .scale_keyframes(ClipTrack::Rotation, &[0, 1], 0.0, 2.0, Some((ClipKeyValue::Scalar(0.0), 0.5)))
+        .expect("scale keys");
+    let scaled_keys = scaled.keyframes(ClipTrack::Rotation);
+    assert!((scaled_keys[1].time - end * 2.0).abs() < 1e-5);
+    let ClipKeyValue::Scalar(end_value) = scaled_keys[1].value else { panic!("rotation is scalar") };
+    let ClipKeyValue::Scalar(original_end) = keys[1].value else { panic!("rotation is scalar") };
+    assert!((end_value - original_end * 0.5).abs() < 1e-5);
+    assert!(eased.scale_keyframes(ClipTrack::Rotation, &[0], 0.0, 0.0, None).is_err());
+
+    let auto = parse_animation_clip_bytes(AUTO_TANGENT_CLIP.as_bytes(), "auto", "inline").expect("parse");
+    let authored = auto
+        .set_keyframe_tangents(ClipTrack::Rotation, 1, Some(ClipKeyValue::Scalar(0.0)), None)
+        .expect("set tangents");
+    let rotation = authored.rotation.as_ref().expect("rotation track");
+    assert!(!rotation.auto_tangent, "hand-set tangents switch the track to authored tangents");
+    assert_eq!((rotation.keyframes[1].in_tangent, rotation.keyframes[1].out_tangent), (Some(0.0), None));
+    assert_eq!(rotation.keyframes[0].out_tangent, Some(0.0), "computed tangents on other keys are kept");
+
+    let dir = tempfile::tempdir().expect("temp dir");
+    let path = dir.path().join("eased.json");
+    std::fs::write(&path, EASED_CLIP).expect("write clip");
+    let mut assets = AssetManager::new();
+    assets.load_clip("eased", path.to_str().unwrap()).expect("load clip");
+    assets.save_clip("eased", &authored).expect("save clip");
+    let saved = std::fs::read(&path).expect("read saved clip");
+    let reloaded = parse_animation_clip_bytes(&saved, "eased", "saved").expect("parse saved clip");
+    assert_eq!(reloaded.rotation.as_ref().unwrap().keyframes.as_ref(), rotation.keyframes.as_ref());
+    assert!(!reloaded.rotation.as_ref().unwrap().auto_tangent);
+}
@@ -0,0 +1,290 @@
+use glam::{Mat4, Quat, Vec2, Vec3};
+use kestrel_engine::assets::skeletal::{
+    JointCurve, JointVec3Track, SkeletalClip, SkeletonAsset, SkeletonImport, SkeletonJoint,
+};
+use kestrel_engine::assets::{AssetManager, ClipInterpolation, ClipKeyframe};
+use kestrel_engine::ecs::{
+    AnimationGraphInstance, ClipInstance, EcsWorld, SkeletonInstance, Transform, WorldTransform,
+};
+use std::sync::Arc;
+
+fn constant_clip(name: &str, x: f32) -> String {
+    format!(
+        r#"{{
+            "version": 1,
+            "name": "{name}",
+            "looped": true,
+            "tracks": {{
+                "translation": {{
+                    "interpolation": "step",
+                    "keyframes": [
+                        {{ "time": 0.0, "value": [{x}, 0.0] }},
+                        {{ "time": 1.0, "value": [{x}, 0.0] }}
+                    ]
+                }}
+            }}
+        }}"#
+    )
+}
+
+const LOCOMOTION_GRAPH: &str = r#"{
+    "version": 1,
+    "name": "locomotion",
+    "entry_state": "Idle",
+    "states": [
+        { "name": "Idle", "clip": "idle" },
+        { "name": "Run", "clip": "run" },
+        { "name": "Jump", "clip": "jump" }
+    ],
+    "parameters": [
+        { "name": "speed", "kind": "float" },
+        { "name": "grounded", "kind": "bool" }
+    ],
+    "transitions": [
+        { "from": "Idle", "to": "Run", "conditions": [{ "parameter": "speed", "op": ">", "value": 0.5 }] },
+        { "from": "Run", "to": "Jump", "trigger": "jump", "duration": 0.09 },
+        { "from": "Run", "to": "Idle", "conditions": [{ "parameter": "speed", "op": "<=", "value": 0.5 }] },
+        { "from": "Jump", "to": "Idle", "conditions": [{ "parameter": "grounded", "op": "==", "value": 1.0 }] }
+    ]
+}"#;
+
+fn locomotion_assets() -> AssetManager {
+    let mut assets = AssetManager::new();
+    for (name, x) in [("idle", 0.0), ("run", 1.0), ("jump", 2.0)] {
+        assets.load_clip_from_bytes(name, "memory", constant_clip(name, x).as_bytes()).expect("load clip");
+    }
+    assets
+        .load_animation_graph_from_bytes("locomotion", "memory", LOCOMOTION_GRAPH.as_bytes())
+        .expect("load graph");
+    assets
+}
+
+fn active_clip(ecs: &EcsWorld, entity: bevy_ecs::prelude::Entity) -> String {
+    ecs.world.get::<ClipInstance>(entity).expect("clip instance").clip_key.to_string()
+}
+
+#[test]
+fn graph_parameters_and_triggers_switch_active_clip() {
+    let assets = locomotion_assets();
+    let mut ecs = EcsWorld::new();
+    let entity = ecs.world.spawn((Transform::default(), WorldTransform::default())).id();
+    assert!(ecs.set_animation_graph(entity, &assets, "locomotion"));
+    assert_eq!(ecs.animation_graph_state(entity).as_deref(), Some("Idle"));
+    assert_eq!(active_clip(&ecs, entity), "idle");
+
+    let dt = 1.0 / 60.0;
+    ecs.update(dt);
+    assert_eq!(ecs.animation_graph_state(entity).as_deref(), Some("Idle"), "no condition holds yet");
+
+    assert!(ecs.set_graph_parameter(entity, "speed", 0.8));
+    assert!(!ecs.set_graph_parameter(entity, "missing", 1.0), "undeclared parameters are rejected");
+    ecs.update(dt);
+    assert_eq!(ecs.animation_graph_state(entity).as_deref(), Some("Run"));
+    assert_eq!(active_clip(&ecs, entity), "run");
+    let translation = ecs.world.get::<Transform>(entity).unwrap().translation;
+    assert!((translation - Vec2::new(1.0, 0.0)).length() < 1e-5, "run clip drives the transform");
+
+    assert!(ecs.trigger_graph_event(entity, "jump"));
+    ecs.update(dt);
+    assert_eq!(ecs.animation_graph_state(entity).as_deref(), Some("Jump"));
+    assert_eq!(active_clip(&ecs, entity), "jump");
+    let graph = ecs.world.get::<AnimationGraphInstance>(entity).unwrap();
+    assert!(graph.is_transitioning());
+    assert_eq!(graph.transition_progress, 0.0);
+
+    // Landing is ignored until the 0.09s transition completes.
+    assert!(ecs.set_graph_parameter(entity, "grounded", 1.0));
+    for _ in 0..6 {
+        ecs.update(dt);
+        assert_eq!(ecs.animation_graph_state(entity).as_deref(), Some("Jump"));
+    }
+    ecs.update(dt);
+    assert_eq!(ecs.animation_graph_state(entity).as_deref(), Some("Idle"));
+    assert_eq!(active_clip(&ecs, entity), "idle");
+}
+
+#[test]
+fn graph_triggers_are_consumed_when_unused() {
+    let assets = locomotion_assets();
+    let mut ecs = EcsWorld::new();
+    let entity = ecs.world.spawn((Transform::default(), WorldTransform::default())).id();
+    assert!(ecs.set_animation_graph(entity, &assets, "locomotion"));
+
+    // A jump trigger raised while idle has no matching transition and is dropped.
+    assert!(ecs.trigger_graph_event(entity, "jump"));
+    ecs.update(1.0 / 60.0);
+    assert!(ecs.set_graph_parameter(entity, "speed", 1.0));
+    ecs.update(1.0 / 60.0);
+    ecs.update(1.0 / 60.0);
+    assert_eq!(ecs.animation_graph_state(entity).as_deref(), Some("Run"));
+
+    assert!(ecs.clear_animation_graph(entity));
+    assert!(!ecs.trigger_graph_event(entity, "jump"));
+    assert!(ecs.animation_graph_state(entity).is_none());
+}
+
+/// A single-joint rig whose clips pin the root at a constant x offset, so the blended pose
+/// reads back as the weighted average of those offsets.
+fn hero_import() -> SkeletonImport {
+    let root = SkeletonJoint {
+        name: Arc::from("root"),
+        parent: None,
+        rest_local: Mat4::IDENTITY,
+        rest_world: Mat4::IDENTITY,
+        rest_translation: Vec3::ZERO,
+        rest_rotation: Quat::IDENTITY,
+        rest_scale: Vec3::ONE,
+        inverse_bind: Mat4::IDENTITY,
+    };
+    let clip = |name: &str, x: f32, duration: f32| {
+        let keyframes = vec![
+            ClipKeyframe::new(0.0, Vec3::new(x, 0.0, 0.0)),
+            ClipKeyframe::new(duration, Vec3::new(x, 0.0, 0.0)),
+        ];
+        SkeletalClip {
+            name: Arc::from(name),
+            skeleton: Arc::from("hero"),
+            duration,
+            channels: Arc::from(vec![JointCurve {
+                joint_index: 0,
+                translation: Some(JointVec3Track {
+                    interpolation: ClipInterpolation::Linear,
+                    keyframes: Arc::from(keyframes.into_boxed_slice()),
+                }),
+                rotation: None,
+                scale: None,
+            }]),
+            looped: true,
+        }
+    };
+    SkeletonImport {
+        skeleton: SkeletonAsset {
+            name: Arc::from("hero"),
+            joints: Arc::from(vec![root]),
+            roots: Arc::from(vec![0_u32]),
+        },
+        clips: vec![clip("idle", 0.0, 1.0), clip("walk", 1.0, 1.0), clip("run", 3.0, 0.5)],
+    }
+}
+
+fn hero_graph(locomotion_state: &str) -> String {
+    format!(
+        r#"{{
+            "version": 1,
+            "name": "hero",
+            "entry_state": "Idle",
+            "states": [
+                {{ "name": "Idle", "clip": "hero::idle" }},
+                {{
+                    "name": "{locomotion_state}",
+                    "blend": {{
+                        "parameter": "speed",
+                        "clips": [
+                            {{ "clip": "hero::run", "position": 1.0 }},
+                            {{ "clip": "hero::walk", "position": 0.0 }}
+                        ]
+                    }}
+                }}
+            ],
+            "parameters": [
+                {{ "name": "speed", "kind": "float" }},
+                {{ "name": "moving", "kind": "bool" }}
+            ],
+            "transitions": [
+                {{
+                    "from": "Idle",
+                    "to": "{locomotion_state}",
+                    "duration": 0.2,
+                    "conditions": [{{ "parameter": "moving", "op": "==", "value": 1.0 }}]
+                }}
+            ]
+        }}"#
+    )
+}
+
+fn hero_assets() -> AssetManager {
+    let mut assets = AssetManager::new();
+    assets.replace_skeleton_from_import("hero", "memory", hero_import());
+    assets
+        .load_animation_graph_from_bytes("hero", "memory", hero_graph("Locomotion").as_bytes())
+        .expect("load graph");
+    assets
+}
+
+fn root_x(ecs: &EcsWorld, entity: bevy_ecs::prelude::Entity) -> f32 {
+    ecs.world.get::<SkeletonInstance>(entity).expect("skeleton").local_poses[0].w_axis.x
+}
+
+#[test]
+fn speed_parameter_blends_walk_and_run_with_crossfade() {
+    let assets = hero_assets();
+    let mut ecs = EcsWorld::new();
+    let entity = ecs.world.spawn((Transform::default(), WorldTransform::default())).id();
+    assert!(ecs.set_skeleton(entity, &assets, "hero"));
+    assert!(ecs.set_animation_graph(entity, &assets, "hero"));
+    let dt = 0.1;
+    ecs.update(dt);
+    assert_eq!(ecs.animation_graph_state(entity).as_deref(), Some("Idle"));
+    assert!(root_x(&ecs, entity).abs() < 1e-5);
+
+    assert!(ecs.set_graph_parameter(entity, "speed", 0.5));
+    assert!(ecs.set_graph_parameter(entity, "moving", 1.0));
+    ecs.update(dt);
+    assert_eq!(ecs.animation_graph_state(entity).as_deref(), Some("Locomotion"));
+    assert!(root_x(&ecs, entity).abs() < 1e-5, "cross-fade starts fully on the idle pose");
+
+    // Halfway through the 0.2s transition: 50% idle, 25% walk, 25% run.
+    ecs.update(dt);
+    assert!((root_x(&ecs, entity) - 1.0).abs() < 1e-4, "got {}", root_x(&ecs, entity));
+    let graph = ecs.world.get::<AnimationGraphInstance>(entity).unwrap();
+    assert_eq!(graph.previous_state.as_deref(), Some("Idle"));
+    assert!((graph.transition_progress - 0.5).abs() < 1e-4);
+
+    ecs.update(dt);
+    assert!((root_x(&ecs, entity) - 2.0).abs() < 1e-4, "even walk/run blend once the fade ends");
+    let graph = ecs.world.get::<AnimationGraphInstance>(entity).unwrap();
+    assert!(!graph.is_transitioning());
+    assert_eq!(graph.skeletal_blend_layers().len(), 2);
+
+    assert!(ecs.set_graph_parameter(entity, "speed", 1.0));
+    ecs.update(dt);
+    assert!((root_x(&ecs, entity) - 3.0).abs() < 1e-4, "full speed plays only the run clip");
+    let skeleton = ecs.world.get::<SkeletonInstance>(entity).unwrap();
+    assert_eq!(skeleton.active_clip_key.as_deref(), Some("hero::run"));
+
+    assert!(ecs.set_graph_parameter(entity, "speed", 0.25));
+    ecs.update(dt);
+    assert!((root_x(&ecs, entity) - 1.5).abs() < 1e-4);
+    let skeleton = ecs.world.get::<SkeletonInstance>(entity).unwrap();
+    assert_eq!(skeleton.active_clip_key.as_deref(), Some("hero::walk"));
+}
+
+#[test]
+fn reloaded_graph_rebinds_and_falls_back_to_entry_state() {
+    let mut assets = hero_assets();
+    let mut ecs = EcsWorld::new();
+    let entity = ecs.world.spawn((Transform::default(), WorldTransform::default())).id();
+    assert!(ecs.set_skeleton(entity, &assets, "hero"));
+    assert!(ecs.set_animation_graph(entity, &assets, "hero"));
+    assert!(ecs.set_graph_parameter(entity, "speed", 1.0));
+    assert!(ecs.set_graph_parameter(entity, "moving", 1.0));
+    for _ in 0..4 {
+        ecs.update(0.1);
+    }
+    assert_eq!(ecs.animation_graph_state(entity).as_deref(), Some("Locomotion"));
+
+    // Reloading an unchanged graph keeps the current state.
+    assert_eq!(ecs.rebind_animation_graphs(&assets, "hero"), 1);
+    assert_eq!(ecs.animation_graph_state(entity).as_deref(), Some("Locomotion"));
+
+    assets
+        .load_animation_graph_from_bytes("hero", "memory", hero_graph("Move").as_bytes())
+        .expect("reload graph");
+    assert_eq!(ecs.rebind_animation_graphs(&assets, "hero"), 1);
+    assert_eq!(ecs.animation_graph_state(entity).as_deref(), Some("Idle"));
+    let graph = ecs.world.get::<AnimationGraphInstance>(entity).unwrap();
+    assert_eq!(graph.parameter("speed"), Some(1.0), "parameter values survive the reload");
+    ecs.update(0.1);
+    assert_eq!(ecs.animation_graph_state(entity).as_deref(), Some("Move"));
+    assert_eq!(ecs.rebind_animation_graphs(&assets, "missing"), 0);
+}
@@ -715,30 +715,30 @@ fn seed_skeletal_clips(world: &mut EcsWorld, rigs: usize, bone_count: usize, ran
 fn bench_transform_clip() -> Arc<AnimationClip> {
     let translation_keys: Arc<[ClipKeyframe<Vec2>]> = Arc::from(
         vec![
-            ClipKeyframe { time: 0.0, value: Vec2::ZERO },
-            ClipKeyframe { time: 0.25, value: Vec2::new(0.0, 4.0) },
-            ClipKeyframe { time: 0.5, value: Vec2::ZERO },
+            ClipKeyframe::new(0.0, Vec2::ZERO),
+            ClipKeyframe::new(0.25, Vec2::new(0.0, 4.0)),
+            ClipKeyframe::new(0.5, Vec2::ZERO),
         ]
         .into_boxed_slice(),
     );
     let rotation_keys: Arc<[ClipKeyframe<f32>]> = Arc::from(
         vec![
-            ClipKeyframe { time: 0.0, value: 0.0 },
-            ClipKeyframe { time: 0.5, value: std::f32::consts::TAU },
+            ClipKeyframe::new(0.0, 0.0),
+            ClipKeyframe::new(0.5, std::f32::consts::TAU),
         ]
         .into_boxed_slice(),
     );
     let scale_keys: Arc<[ClipKeyframe<Vec2>]> = Arc::from(
         vec![
-            ClipKeyframe { time: 0.0, value: Vec2::splat(1.0) },
-            ClipKeyframe { time: 0.5, value: Vec2::new(1.2, 0.8) },
+            ClipKeyframe::new(0.0, Vec2::splat(1.0)),
+            ClipKeyframe::new(0.5, Vec2::new(1.2, 0.8)),
         ]
         .into_boxed_slice(),
     );
     let tint_keys: Arc<[ClipKeyframe<Vec4>]> = Arc::from(
         vec![
-            ClipKeyframe { time: 0.0, value: Vec4::ONE },
-            ClipKeyframe { time: 0.5, value: Vec4::new(0.6, 0.9, 1.0, 1.0) },
+            ClipKeyframe::new(0.0, Vec4::ONE),
+            ClipKeyframe::new(0.5, Vec4::new(0.6, 0.9, 1.0, 1.0)),
         ]
         .into_boxed_slice(),
     );
@@ -757,6 +757,7 @@ fn bench_transform_clip() -> Arc<AnimationClip> {
         duration_inv: 2.0,
         translation: Some(ClipVec2Track {
             interpolation: ClipInterpolation::Linear,
+            auto_tangent: false,
             keyframes: translation_keys,
             duration: 0.5,
             duration_inv: 2.0,
@@ -766,6 +767,7 @@ fn bench_transform_clip() -> Arc<AnimationClip> {
         }),
         rotation: Some(ClipScalarTrack {
             interpolation: ClipInterpolation::Linear,
+            auto_tangent: false,
             keyframes: rotation_keys,
             duration: 0.5,
             duration_inv: 2.0,
@@ -775,6 +777,7 @@ fn bench_transform_clip() -> Arc<AnimationClip> {
         }),
         scale: Some(ClipVec2Track {
             interpolation: ClipInterpolation::Step,
+            auto_tangent: false,
             keyframes: scale_keys,
             duration: 0.5,
             duration_inv: 2.0,
@@ -784,6 +787,7 @@ fn bench_transform_clip() -> Arc<AnimationClip> {
         }),
         tint: Some(ClipVec4Track {
             interpolation: ClipInterpolation::Linear,
+            auto_tangent: false,
             keyframes: tint_keys,
             duration: 0.5,
             duration_inv: 2.0,
@@ -864,9 +868,9 @@ fn bench_skeletal_clip(skeleton_key: Arc<str>, bone_count: usize) -> Arc<Skeleta
         let base_height = joint_index as f32;
         let translation_keys = Arc::from(
             vec![
-                ClipKeyframe { time: 0.0, value: Vec3::new(0.0, base_height, 0.0) },
-                ClipKeyframe { time: 0.5, value: Vec3::new(0.0, base_height + 0.2, 0.0) },
-                ClipKeyframe { time: 1.0, value: Vec3::new(0.0, base_height, 0.0) },
+                ClipKeyframe::new(0.0, Vec3::new(0.0, base_height, 0.0)),
+                ClipKeyframe::new(0.5, Vec3::new(0.0, base_height + 0.2, 0.0)),
+                ClipKeyframe::new(1.0, Vec3::new(0.0, base_height, 0.0)),
             ]
             .into_boxed_slice(),
         );
@@ -876,9 +880,9 @@ fn bench_skeletal_clip(skeleton_key: Arc<str>, bone_count: usize) -> Arc<Skeleta
         let rotation = if joint_index % 2 == 0 {
             let rotation_keys = Arc::from(
                 vec![
-                    ClipKeyframe { time: 0.0, value: Quat::IDENTITY },
-                    ClipKeyframe { time: 0.5, value: Quat::from_axis_angle(Vec3::Z, 0.5) },
-                    ClipKeyframe { time: 1.0, value: Quat::IDENTITY },
+                    ClipKeyframe::new(0.0, Quat::IDENTITY),
+                    ClipKeyframe::new(0.5, Quat::from_axis_angle(Vec3::Z, 0.5)),
+                    ClipKeyframe::new(1.0, Quat::IDENTITY),
                 ]
                 .into_boxed_slice(),
             );
@@ -890,9 +894,9 @@ fn bench_skeletal_clip(skeleton_key: Arc<str>, bone_count: usize) -> Arc<Skeleta
         let scale = if joint_index % 3 == 0 {
             let scale_keys = Arc::from(
                 vec![
-                    ClipKeyframe { time: 0.0, value: Vec3::ONE },
-                    ClipKeyframe { time: 0.5, value: Vec3::new(1.1, 0.9, 1.0) },
-                    ClipKeyframe { time: 1.0, value: Vec3::ONE },
+                    ClipKeyframe::new(0.0, Vec3::ONE),
+                    ClipKeyframe::new(0.5, Vec3::new(1.1, 0.9, 1.0)),
+                    ClipKeyframe::new(1.0, Vec3::ONE),
                 ]
                 .into_boxed_slice(),
             );
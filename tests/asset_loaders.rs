@@ -0,0 +1,77 @@
+use anyhow::{anyhow, Result};
+use kestrel_engine::assets::{AnimationClip, AssetLoader, AssetManager};
+use std::io::Write;
+use std::sync::Arc;
+use tempfile::Builder;
+
+/// `.foo` clips are plain `key=value` lines: `duration` in seconds and an optional `looped` flag.
+struct FooClipLoader;
+
+impl AssetLoader for FooClipLoader {
+    type Asset = AnimationClip;
+
+    fn extensions(&self) -> &[&str] {
+        &["foo"]
+    }
+
+    fn load(&self, bytes: &[u8], key: &str, source: &str) -> Result<AnimationClip> {
+        let text = std::str::from_utf8(bytes)?;
+        let mut duration = None;
+        let mut looped = false;
+        for line in text.lines() {
+            match line.split_once('=') {
+                Some(("duration", value)) => duration = Some(value.trim().parse::<f32>()?),
+                Some(("looped", value)) => looped = value.trim() == "true",
+                _ => {}
+            }
+        }
+        let duration = duration.ok_or_else(|| anyhow!("{source}: missing duration"))?;
+        Ok(AnimationClip {
+            name: Arc::from(key),
+            duration,
+            duration_inv: 1.0 / duration,
+            translation: None,
+            rotation: None,
+            scale: None,
+            tint: None,
+            looped,
+            version: 1,
+        })
+    }
+}
+
+fn write_foo(contents: &str) -> tempfile::NamedTempFile {
+    let mut file = Builder::new().suffix(".foo").tempfile().expect("temp clip");
+    file.write_all(contents.as_bytes()).expect("write temp clip");
+    file
+}
+
+#[test]
+fn custom_clip_loader_handles_its_extension() {
+    let mut assets = AssetManager::new();
+    assets.register_clip_loader(Box::new(FooClipLoader)).expect("register .foo loader");
+    let file = write_foo("duration=1.5\nlooped=true\n");
+    let path = file.path().to_str().expect("utf-8 temp path");
+
+    assets.retain_clip("bounce", Some(path)).expect("retain .foo clip");
+    let clip = assets.clip("bounce").expect("clip loaded through custom loader");
+    assert_eq!(clip.name.as_ref(), "bounce");
+    assert!(clip.looped);
+    assert!((clip.duration - 1.5).abs() < f32::EPSILON);
+
+    std::fs::write(file.path(), "duration=0.25\n").expect("rewrite temp clip");
+    assets.load_clip("bounce", path).expect("hot-reload .foo clip");
+    assert!((assets.clip("bounce").unwrap().duration - 0.25).abs() < f32::EPSILON);
+
+    assets
+        .retain_clip("slime", Some("fixtures/animation_clips/slime_bob.json"))
+        .expect("built-in JSON clips still load");
+}
+
+#[test]
+fn registering_two_loaders_for_one_extension_fails() {
+    let mut assets = AssetManager::new();
+    assets.register_clip_loader(Box::new(FooClipLoader)).expect("first loader registers");
+    let err = assets.register_clip_loader(Box::new(FooClipLoader)).expect_err("duplicate extension rejected");
+    assert!(err.to_string().contains(".foo"), "error names the conflicting extension: {err}");
+}
@@ -0,0 +1,67 @@
+use kestrel_engine::assets::{AssetManager, Rect};
+use std::path::Path;
+
+fn write_image(dir: &Path, name: &str, width: u32, height: u32, color: [u8; 4]) {
+    image::RgbaImage::from_pixel(width, height, image::Rgba(color))
+        .save(dir.join(name))
+        .expect("write image");
+}
+
+fn overlaps(a: &Rect, b: &Rect) -> bool {
+    a.x < b.x + b.w && b.x < a.x + a.w && a.y < b.y + b.h && b.y < a.y + a.h
+}
+
+#[test]
+fn loose_images_pack_into_non_overlapping_regions() {
+    let temp_dir = tempfile::tempdir().expect("temp dir");
+    write_image(temp_dir.path(), "coin.png", 8, 8, [255, 200, 0, 255]);
+    write_image(temp_dir.path(), "banner.png", 16, 4, [0, 128, 255, 255]);
+    write_image(temp_dir.path(), "pillar.PNG", 4, 12, [90, 90, 90, 255]);
+    std::fs::write(temp_dir.path().join("notes.txt"), "not an image").expect("write notes");
+    let dir = temp_dir.path().to_string_lossy().into_owned();
+
+    let mut assets = AssetManager::new();
+    let report = assets.pack_atlas("loose", &dir).expect("pack atlas");
+    assert_eq!(report.packed, 3);
+    assert!(report.overflow.is_empty());
+    let covered = 8 * 8 + 16 * 4 + 4 * 12;
+    assert_eq!(report.wasted_pixels, (report.width * report.height - covered) as u64);
+
+    assert_eq!(assets.atlas_region_names("loose"), vec!["banner", "coin", "pillar"]);
+    let mut rects = Vec::new();
+    for (name, size) in [("banner", (16, 4)), ("coin", (8, 8)), ("pillar", (4, 12))] {
+        let (_, region) = assets.atlas_region_info("loose", name).expect("region resolves");
+        assert_eq!((region.rect.w, region.rect.h), size, "{name} keeps its size");
+        assert!(
+            region.rect.x + region.rect.w <= report.width && region.rect.y + region.rect.h <= report.height
+        );
+        assert!(assets.atlas_region_uv("loose", name).is_ok());
+        rects.push(region.rect);
+    }
+    for (i, a) in rects.iter().enumerate() {
+        for b in &rects[i + 1..] {
+            assert!(!overlaps(a, b), "packed rects overlap: {a:?} vs {b:?}");
+        }
+    }
+    assert_eq!(assets.atlas_source("loose"), Some(dir.as_str()));
+    assert_eq!(assets.reload_atlas("loose").expect("re-pack").warnings.len(), 0);
+}
+
+#[test]
+fn images_larger_than_the_max_size_are_reported_as_overflow() {
+    let temp_dir = tempfile::tempdir().expect("temp dir");
+    write_image(temp_dir.path(), "small.png", 4, 4, [255, 0, 0, 255]);
+    write_image(temp_dir.path(), "wide.png", 40, 4, [0, 255, 0, 255]);
+    let dir = temp_dir.path().to_string_lossy().into_owned();
+
+    let mut assets = AssetManager::new();
+    let report = assets.pack_atlas_with_max_size("tiny", &dir, 16).expect("pack what fits");
+    assert_eq!(report.overflow, vec!["wide".to_string()]);
+    assert!(report.diagnostics.warnings.iter().any(|warning| warning.contains("'wide'")));
+    assert!(report.width <= 16 && report.height <= 16);
+    assert!(assets.atlas_region_exists("tiny", "small"));
+    assert!(!assets.atlas_region_exists("tiny", "wide"));
+
+    let empty = tempfile::tempdir().expect("temp dir");
+    assert!(assets.pack_atlas("empty", &empty.path().to_string_lossy()).is_err());
+}
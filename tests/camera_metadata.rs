@@ -1,5 +1,5 @@
 use kestrel_engine::scene::{
-    Scene, SceneCameraBookmark, SceneEntityId, SceneMetadata, SceneViewportMode, Vec2Data,
+    Scene, SceneCameraBookmark, SceneEntityId, SceneMetadata, ScenePreviewCamera, SceneViewportMode, Vec2Data,
 };
 
 #[test]
@@ -39,3 +39,15 @@ fn camera_metadata_defaults_are_empty() {
     assert!(metadata.camera_follow_entity.is_none());
     assert_eq!(metadata.viewport, SceneViewportMode::Ortho2D);
 }
+
+#[test]
+fn preview_camera_clip_planes_round_trip_and_default_for_old_scenes() {
+    let preview = ScenePreviewCamera { near: 0.5, far: 2500.0, ..ScenePreviewCamera::default() };
+    let json = serde_json::to_value(&preview).expect("preview camera serializes");
+    let restored: ScenePreviewCamera = serde_json::from_value(json).expect("preview camera deserializes");
+    assert_eq!((restored.near, restored.far), (0.5, 2500.0));
+
+    let legacy: ScenePreviewCamera =
+        serde_json::from_str(r#"{ "mode": "Orbit", "frustum_lock": false }"#).expect("legacy preview camera");
+    assert_eq!((legacy.near, legacy.far), (0.1, 100.0), "older scenes keep the defaults");
+}
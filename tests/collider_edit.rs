@@ -0,0 +1,93 @@
+use bevy_ecs::prelude::Entity;
+use glam::{Mat4, Vec2};
+use kestrel_engine::assets::AssetManager;
+use kestrel_engine::ecs::{
+    Aabb, ColliderGeometry, ColliderKind, EcsWorld, Mass, RapierBody, RapierCollider, RapierState, Transform,
+    WorldTransform,
+};
+
+fn spawn_box(world: &mut EcsWorld, position: Vec2) -> Entity {
+    let half = Vec2::splat(0.5);
+    let (body, collider) =
+        world.world.resource_mut::<RapierState>().spawn_dynamic_body(position, half, 1.0, Vec2::ZERO);
+    let entity = world
+        .world
+        .spawn((
+            Transform { translation: position, rotation: 0.0, scale: Vec2::ONE },
+            WorldTransform(Mat4::from_translation(position.extend(0.0))),
+            Aabb { half },
+            Mass(1.0),
+            RapierBody { handle: body },
+            RapierCollider { handle: collider },
+        ))
+        .id();
+    world.world.resource_mut::<RapierState>().register_collider_entity(collider, entity);
+    entity
+}
+
+fn rapier_collider(world: &EcsWorld, entity: Entity) -> rapier2d::geometry::Collider {
+    let handle = world.world.get::<RapierCollider>(entity).expect("rapier collider").handle;
+    world.world.resource::<RapierState>().collider(handle).expect("collider in rapier set").clone()
+}
+
+#[test]
+fn collider_setters_resize_and_offset_independently_of_scale() {
+    let mut world = EcsWorld::new();
+    let entity = spawn_box(&mut world, Vec2::new(1.0, 2.0));
+
+    assert!(world.set_collider_half_extents(entity, Vec2::new(0.75, 0.25)));
+    assert!(world.set_collider_offset(entity, Vec2::new(0.1, -0.2)));
+    assert!(world.set_scale(entity, Vec2::new(3.0, 3.0)));
+
+    let geometry = world.collider_geometry(entity).expect("collider geometry");
+    assert_eq!(geometry.kind, ColliderKind::Rect);
+    assert_eq!(geometry.half, Vec2::new(0.75, 0.25), "scaling leaves an edited collider alone");
+    assert_eq!(geometry.offset, Vec2::new(0.1, -0.2));
+    assert_eq!(world.world.get::<Mass>(entity).expect("mass").0, 1.0);
+
+    let collider = rapier_collider(&world, entity);
+    let cuboid = collider.shape().as_cuboid().expect("rect colliders stay cuboids");
+    assert!((cuboid.half_extents.x - 0.75).abs() < 1e-6 && (cuboid.half_extents.y - 0.25).abs() < 1e-6);
+    let offset = collider.position_wrt_parent().expect("attached to a body").translation.vector;
+    assert!((offset.x - 0.1).abs() < 1e-6 && (offset.y + 0.2).abs() < 1e-6);
+
+    assert!(world.set_collider_radius(entity, 0.4));
+    let collider = rapier_collider(&world, entity);
+    let ball = collider.shape().as_ball().expect("radius setter switches to a ball");
+    assert!((ball.radius - 0.4).abs() < 1e-6);
+    assert_eq!(world.collider_geometry(entity).expect("circle geometry").half, Vec2::splat(0.4));
+
+    let (min, max) = world.collider_rects()[0];
+    assert!((min - Vec2::new(0.7, 1.4)).length() < 1e-5, "debug rect follows the offset: {min:?}");
+    assert!((max - Vec2::new(1.5, 2.2)).length() < 1e-5, "debug rect follows the offset: {max:?}");
+}
+
+#[test]
+fn collider_setters_require_a_collider() {
+    let mut world = EcsWorld::new();
+    let entity = world.world.spawn((Transform::default(), WorldTransform::default())).id();
+    assert!(!world.set_collider_half_extents(entity, Vec2::ONE));
+    assert!(!world.set_collider_radius(entity, 1.0));
+    assert!(world.collider_geometry(entity).is_none());
+}
+
+#[test]
+fn edited_collider_round_trips_through_scenes() {
+    let mut world = EcsWorld::new();
+    let entity = spawn_box(&mut world, Vec2::ZERO);
+    let edited =
+        ColliderGeometry { kind: ColliderKind::Circle, half: Vec2::splat(0.3), offset: Vec2::new(0.0, 0.5) };
+    assert!(world.set_collider_geometry(entity, edited));
+
+    let assets = AssetManager::new();
+    let scene = world.export_scene(&assets);
+    let mut reloaded = EcsWorld::new();
+    reloaded.load_scene(&scene, &assets).expect("load exported scene");
+
+    let mut query = reloaded.world.query::<(Entity, &Aabb)>();
+    let loaded: Vec<Entity> = query.iter(&reloaded.world).map(|(entity, _)| entity).collect();
+    assert_eq!(loaded.len(), 1);
+    assert_eq!(reloaded.collider_geometry(loaded[0]), Some(edited));
+    let collider = rapier_collider(&reloaded, loaded[0]);
+    assert!(collider.shape().as_ball().is_some(), "reloaded rapier collider is a ball");
+}
@@ -0,0 +1,36 @@
+use glam::{Vec2, Vec4};
+use kestrel_engine::ecs::{EcsWorld, Tint, Transform, Velocity, WorldTransform};
+
+#[test]
+fn reset_transform_returns_edited_entity_to_identity() {
+    let mut ecs = EcsWorld::new();
+    let entity = ecs
+        .world
+        .spawn((Transform::default(), WorldTransform::default(), Velocity(Vec2::ZERO), Tint(Vec4::ONE)))
+        .id();
+
+    assert!(ecs.set_translation(entity, Vec2::new(3.0, -2.0)));
+    assert!(ecs.set_rotation(entity, 1.2));
+    assert!(ecs.set_scale(entity, Vec2::new(2.0, 0.5)));
+    assert!(ecs.set_velocity(entity, Vec2::new(4.0, 1.0)));
+    assert!(ecs.set_tint(entity, Some(Vec4::new(1.0, 0.2, 0.2, 0.5))));
+
+    assert!(ecs.reset_transform(entity));
+    let transform = *ecs.world.get::<Transform>(entity).expect("transform");
+    assert_eq!(transform.translation, Vec2::ZERO);
+    assert_eq!(transform.rotation, 0.0);
+    assert_eq!(transform.scale, Vec2::ONE);
+
+    // The inspector's per-component resets go through the plain setters.
+    assert!(ecs.set_velocity(entity, Vec2::ZERO));
+    assert!(ecs.set_tint(entity, Some(Vec4::ONE)));
+    assert_eq!(ecs.world.get::<Velocity>(entity).expect("velocity").0, Vec2::ZERO);
+    assert_eq!(ecs.world.get::<Tint>(entity).expect("tint").0, Vec4::ONE);
+}
+
+#[test]
+fn reset_transform_fails_without_a_transform() {
+    let mut ecs = EcsWorld::new();
+    let entity = ecs.world.spawn(Velocity(Vec2::ONE)).id();
+    assert!(!ecs.reset_transform(entity));
+}
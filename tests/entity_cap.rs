@@ -0,0 +1,49 @@
+use glam::Vec2;
+use kestrel_engine::assets::AssetManager;
+use kestrel_engine::ecs::EcsWorld;
+use kestrel_engine::events::GameEvent;
+
+#[test]
+fn burst_is_truncated_at_entity_cap_with_warning() {
+    let assets = AssetManager::new();
+    let mut ecs = EcsWorld::new();
+    ecs.set_max_entities(Some(10));
+    ecs.drain_events();
+
+    ecs.spawn_burst(&assets, 100);
+    assert_eq!(ecs.entity_count(), 10);
+    let events = ecs.drain_events();
+    let warning = events.iter().find_map(|event| match event {
+        GameEvent::ScriptMessage { message } => Some(message.clone()),
+        _ => None,
+    });
+    let warning = warning.expect("truncated burst emits a warning");
+    assert!(warning.contains("entity cap of 10"), "unexpected warning: {warning}");
+    assert_eq!(
+        events.iter().filter(|event| matches!(event, GameEvent::SpriteSpawned { .. })).count(),
+        10,
+        "only the allowed entities are spawned"
+    );
+
+    ecs.spawn_burst(&assets, 5);
+    assert_eq!(ecs.entity_count(), 10, "a full world refuses further bursts");
+
+    ecs.set_max_entities(None);
+    ecs.spawn_burst(&assets, 5);
+    assert_eq!(ecs.entity_count(), 15);
+}
+
+#[test]
+fn scripted_spawns_respect_entity_cap() {
+    let mut assets = AssetManager::new();
+    assets.retain_atlas("main", Some("assets/images/atlas.json")).expect("load main atlas");
+    let mut ecs = EcsWorld::new();
+    ecs.set_max_entities(Some(ecs.entity_count() + 1));
+
+    ecs.spawn_scripted_sprite(&assets, "main", "green", Vec2::ZERO, 0.2, Vec2::ZERO)
+        .expect("first spawn fits under the cap");
+    let err = ecs
+        .spawn_scripted_sprite(&assets, "main", "green", Vec2::ZERO, 0.2, Vec2::ZERO)
+        .expect_err("second spawn exceeds the cap");
+    assert!(err.to_string().contains("Entity cap"), "unexpected error: {err}");
+}
@@ -0,0 +1,98 @@
+use glam::Vec2;
+use kestrel_engine::assets::AssetManager;
+use kestrel_engine::ecs::{EcsWorld, Transform};
+use kestrel_engine::events::GameEvent;
+
+fn main_atlas() -> AssetManager {
+    let mut assets = AssetManager::new();
+    assets.retain_atlas("main", Some("assets/images/atlas.json")).expect("load main atlas");
+    assets
+}
+
+fn despawned(events: &[GameEvent]) -> usize {
+    events.iter().filter(|event| matches!(event, GameEvent::EntityDespawned { .. })).count()
+}
+
+#[test]
+fn lifetime_despawns_through_the_regular_cleanup() {
+    let assets = main_atlas();
+    let mut ecs = EcsWorld::new();
+    let entity = ecs
+        .spawn_scripted_sprite_with_lifetime(&assets, "main", "green", Vec2::ZERO, 0.1, Vec2::ZERO, Some(0.5))
+        .expect("spawn sprite");
+    ecs.drain_events();
+
+    ecs.update(0.3);
+    assert!(ecs.entity_exists(entity), "sprite outlives part of its lifetime");
+    ecs.update(0.3);
+    assert!(!ecs.entity_exists(entity), "sprite is gone once its lifetime runs out");
+    assert_eq!(despawned(&ecs.drain_events()), 1, "auto-despawn emits EntityDespawned");
+    assert_eq!(ecs.pooled_sprite_count(), 1, "the sprite's body is parked for reuse");
+
+    let reused = ecs
+        .spawn_scripted_sprite(&assets, "main", "green", Vec2::new(0.5, 0.0), 0.1, Vec2::ZERO)
+        .expect("spawn from pool");
+    assert_eq!(ecs.pooled_sprite_count(), 0, "the next spawn picks the parked body up");
+    ecs.fixed_step(1.0 / 60.0);
+    let translation = ecs.world.get::<Transform>(reused).expect("transform").translation;
+    assert!((translation.x - 0.5).abs() < 0.05, "reused body starts at the new position: {translation:?}");
+}
+
+#[test]
+fn out_of_bounds_entities_despawn_past_the_margin() {
+    let assets = main_atlas();
+    let mut ecs = EcsWorld::new();
+    let near = ecs.spawn_scripted_sprite(&assets, "main", "green", Vec2::ZERO, 0.1, Vec2::ZERO).unwrap();
+    let far = ecs.spawn_scripted_sprite(&assets, "main", "green", Vec2::ZERO, 0.1, Vec2::ZERO).unwrap();
+    assert!(ecs.set_despawn_out_of_bounds(near, Some(0.5)));
+    assert!(ecs.set_despawn_out_of_bounds(far, Some(0.5)));
+    ecs.world.get_mut::<Transform>(near).unwrap().translation = Vec2::new(1.7, 0.0);
+    ecs.world.get_mut::<Transform>(far).unwrap().translation = Vec2::new(2.5, 0.0);
+
+    ecs.update(0.0);
+    assert!(ecs.entity_exists(near), "inside the margin around the world bounds");
+    assert!(!ecs.entity_exists(far), "beyond the margin");
+
+    ecs.set_despawn_view(Some((Vec2::splat(-5.0), Vec2::splat(5.0))));
+    ecs.world.get_mut::<Transform>(near).unwrap().translation = Vec2::new(4.0, 0.0);
+    ecs.update(0.0);
+    assert!(ecs.entity_exists(near), "the camera view widens the rect");
+}
+
+#[test]
+fn bullet_hell_spawn_rate_holds_a_stable_entity_count() {
+    const FRAME: f32 = 1.0 / 60.0;
+    const SPAWNS_PER_FRAME: usize = 34; // ~2000 spawns per second
+    let assets = main_atlas();
+    let mut ecs = EcsWorld::new();
+    let baseline = ecs.entity_count();
+
+    let mut counts = Vec::new();
+    for frame in 0..300 {
+        for i in 0..SPAWNS_PER_FRAME {
+            let angle = (frame * SPAWNS_PER_FRAME + i) as f32 * 0.37;
+            let velocity = Vec2::new(angle.cos(), angle.sin()) * 2.0;
+            ecs.spawn_scripted_sprite_with_lifetime(
+                &assets,
+                "main",
+                "green",
+                Vec2::ZERO,
+                0.02,
+                velocity,
+                Some(0.5),
+            )
+            .expect("spawn bullet");
+        }
+        ecs.fixed_step(FRAME);
+        ecs.update(FRAME);
+        ecs.drain_events();
+        counts.push(ecs.entity_count() - baseline);
+    }
+
+    let settled = &counts[60..];
+    let min = *settled.iter().min().unwrap();
+    let max = *settled.iter().max().unwrap();
+    assert!(max <= SPAWNS_PER_FRAME * 31, "live bullets stay within one lifetime's worth: {max}");
+    assert!(max - min <= SPAWNS_PER_FRAME, "entity count is stable once warmed up: {min}..{max}");
+    assert!(ecs.pooled_sprite_count() <= SPAWNS_PER_FRAME, "parked bodies are recycled, not piling up");
+}
@@ -0,0 +1,59 @@
+use kestrel_engine::assets::AssetManager;
+use kestrel_engine::config::WindowConfig;
+use kestrel_engine::material_registry::MaterialRegistry;
+use kestrel_engine::mesh_registry::MeshRegistry;
+use kestrel_engine::renderer::Renderer;
+
+#[test]
+fn atlas_upload_reports_texture_bytes_until_released() {
+    let window_config = WindowConfig {
+        title: "Headless".to_string(),
+        width: 64,
+        height: 64,
+        vsync: false,
+        fullscreen: false,
+        render_scale: 1.0,
+    };
+    let mut renderer = pollster::block_on(Renderer::new(&window_config));
+    pollster::block_on(renderer.init_headless_for_test()).expect("headless init");
+    let (device, queue) = renderer.device_and_queue().expect("device");
+
+    let mut assets = AssetManager::new();
+    assets.set_device(device, queue);
+    assets.retain_atlas("main", Some("assets/images/atlas.json")).expect("load atlas");
+    assert_eq!(assets.atlas_gpu_bytes(), 0, "nothing uploaded before a view is requested");
+
+    assets.atlas_texture_view("main").expect("upload atlas");
+    assert_eq!(assets.atlas_gpu_bytes(), 416 * 160 * 4);
+
+    assert!(assets.release_atlas("main"));
+    assert_eq!(assets.atlas_gpu_bytes(), 0);
+}
+
+#[test]
+fn batch_mesh_upload_skips_unknown_keys() {
+    let window_config = WindowConfig {
+        title: "Headless".to_string(),
+        width: 64,
+        height: 64,
+        vsync: false,
+        fullscreen: false,
+        render_scale: 1.0,
+    };
+    let mut renderer = pollster::block_on(Renderer::new(&window_config));
+    pollster::block_on(renderer.init_headless_for_test()).expect("headless init");
+
+    let mut materials = MaterialRegistry::new();
+    let mut meshes = MeshRegistry::new(&mut materials);
+    assert_eq!(meshes.gpu_memory_bytes(), 0);
+
+    let ready = meshes.batch_ensure_gpu(&["cube", "missing", "cube"], &mut renderer).expect("batch upload");
+    assert_eq!(ready, vec!["cube"]);
+    let cube = meshes.gpu_mesh("cube").expect("cube uploaded");
+    assert_eq!(cube.index_count, meshes.mesh("cube").expect("cube mesh").indices.len() as u32);
+    assert!(meshes.gpu_memory_bytes() > 0);
+
+    // Already-uploaded meshes are reported without another upload.
+    let again = meshes.batch_ensure_gpu(&["cube"], &mut renderer).expect("second batch");
+    assert_eq!(again, vec!["cube"]);
+}
@@ -26,3 +26,90 @@ fn remapped_spawn_controls_override_defaults() {
     input.push(InputEvent::Key { key: Key::Character("b".into()), pressed: true });
     assert!(!input.take_b_pressed(), "original binding is ignored after remapping");
 }
+
+#[test]
+fn ctrl_c_and_ctrl_v_fire_clipboard_shortcuts_instead_of_bindings() {
+    let mut input = Input::new();
+
+    input.push(InputEvent::Key { key: Key::Character("v".into()), pressed: true });
+    assert!(input.take_camera_mode_toggle(), "plain v keeps its binding");
+    assert!(!input.take_paste_selection());
+
+    input.push(InputEvent::Key { key: Key::Named(NamedKey::Control), pressed: true });
+    input.push(InputEvent::Key { key: Key::Character("c".into()), pressed: true });
+    input.push(InputEvent::Key { key: Key::Character("v".into()), pressed: true });
+    assert!(input.take_copy_selection(), "ctrl+c copies");
+    assert!(input.take_paste_selection(), "ctrl+v pastes");
+    assert!(!input.take_camera_mode_toggle(), "ctrl+v does not toggle the camera mode");
+    assert!(!input.freefly_roll_right(), "ctrl+c does not start a roll");
+}
+
+#[test]
+fn viewport_navigation_keys_pan_zoom_and_cycle() {
+    let mut input = Input::new();
+
+    input.push(InputEvent::Key { key: Key::Named(NamedKey::ArrowUp), pressed: true });
+    input.push(InputEvent::Key { key: Key::Character("a".into()), pressed: true });
+    assert_eq!(input.viewport_pan_axis(), (-1.0, 1.0), "arrow and WASD keys both pan");
+    input.push(InputEvent::Key { key: Key::Named(NamedKey::ArrowUp), pressed: false });
+    input.push(InputEvent::Key { key: Key::Character("a".into()), pressed: false });
+    assert_eq!(input.viewport_pan_axis(), (0.0, 0.0));
+
+    input.push(InputEvent::Key { key: Key::Character("+".into()), pressed: true });
+    input.push(InputEvent::Key { key: Key::Character("=".into()), pressed: true });
+    input.push(InputEvent::Key { key: Key::Character("-".into()), pressed: true });
+    assert_eq!(input.take_zoom_steps(), 1, "zoom steps net out");
+    assert_eq!(input.take_zoom_steps(), 0, "zoom steps are consumed");
+
+    input.push(InputEvent::Key { key: Key::Named(NamedKey::Tab), pressed: true });
+    input.push(InputEvent::Key { key: Key::Named(NamedKey::Home), pressed: true });
+    input.push(InputEvent::Key { key: Key::Character("f".into()), pressed: true });
+    assert!(input.take_cycle_selection());
+    assert!(input.take_reset_camera());
+    assert!(input.take_focus_selection());
+    input.clear_frame();
+    assert!(!input.take_cycle_selection(), "one-shot presses reset every frame");
+}
+
+#[test]
+fn chords_fire_once_and_prefer_the_longest_match() {
+    let mut temp = NamedTempFile::new().expect("temp input config");
+    write!(temp, r#"{{"bindings":{{"save_scene_as":{{"chord":["Ctrl","Shift","S"]}}}}}}"#)
+        .expect("write chord config");
+    let mut input = Input::from_config(temp.path());
+    let ctrl = Key::Named(NamedKey::Control);
+    let shift = Key::Named(NamedKey::Shift);
+    let press = |input: &mut Input, key: &Key, pressed: bool| {
+        input.push(InputEvent::Key { key: key.clone(), pressed });
+    };
+
+    press(&mut input, &ctrl, true);
+    press(&mut input, &shift, true);
+    press(&mut input, &Key::Character("S".into()), true);
+    assert!(input.chord_pressed(&[ctrl.clone(), shift.clone(), Key::Character("s".into())]));
+    assert!(input.take_chord("save_scene_as"), "configured chord fires");
+    assert!(!input.take_chord("save_scene"), "the shorter default ctrl+s chord is shadowed");
+    assert!(!input.take_chord("save_scene_as"), "chords are consumed");
+    assert_eq!(input.viewport_pan_axis(), (0.0, 0.0), "the chord swallows the plain s binding");
+    assert!(input.ctrl_held() && input.shift_held(), "modifiers keep their own bindings");
+
+    press(&mut input, &Key::Character("S".into()), true);
+    assert!(!input.take_chord("save_scene_as"), "key repeat does not fire again");
+    press(&mut input, &Key::Character("s".into()), false);
+    press(&mut input, &shift, false);
+    assert!(!input.chord_pressed(&[ctrl.clone(), shift.clone()]));
+
+    input.register_chord(
+        "play_from_here",
+        vec![ctrl.clone(), Key::Named(NamedKey::Alt), Key::Character("p".into())],
+    );
+    press(&mut input, &Key::Named(NamedKey::Alt), true);
+    press(&mut input, &Key::Character("p".into()), true);
+    press(&mut input, &Key::Character("s".into()), true);
+    input.clear_frame();
+    assert!(!input.take_chord("play_from_here"), "fired chords reset every frame");
+    assert!(!input.take_chord("save_scene"));
+    press(&mut input, &Key::Character("p".into()), false);
+    press(&mut input, &Key::Character("p".into()), true);
+    assert!(input.take_chord("play_from_here"), "registered chords fire from code");
+}
@@ -0,0 +1,123 @@
+use kestrel_engine::assets::ktx2::{self, Ktx2Texture};
+use kestrel_engine::assets::AssetManager;
+use kestrel_engine::config::WindowConfig;
+use kestrel_engine::renderer::Renderer;
+use std::fs;
+use std::path::Path;
+
+fn headless_renderer() -> Renderer {
+    let window_config = WindowConfig {
+        title: "Headless".into(),
+        width: 64,
+        height: 64,
+        vsync: false,
+        fullscreen: false,
+        render_scale: 1.0,
+    };
+    let mut renderer = pollster::block_on(Renderer::new(&window_config));
+    pollster::block_on(renderer.init_headless_for_test()).expect("headless init");
+    renderer
+}
+
+fn write_atlas_json(dir: &Path, image: &str, size: u32) -> String {
+    let json = format!(
+        r#"{{"version": 2, "image": "{image}", "width": {size}, "height": {size},
+            "regions": {{"tile": {{"x": 0, "y": 0, "w": 4, "h": 4}}}}, "animations": {{}}}}"#
+    );
+    let path = dir.join("atlas.json");
+    fs::write(&path, json).expect("write atlas json");
+    path.to_string_lossy().into_owned()
+}
+
+#[test]
+fn bc7_textures_round_trip_through_ktx2_with_their_mip_chain() {
+    let (width, height) = (16u32, 8u32);
+    let rgba: Vec<u8> =
+        (0..width * height).flat_map(|i| [(i * 2) as u8, 255 - (i * 2) as u8, 64, 255]).collect();
+    let texture = ktx2::encode_bc7(&rgba, width, height, true);
+    assert_eq!(texture.format, wgpu::TextureFormat::Bc7RgbaUnormSrgb);
+    assert_eq!(texture.mip_level_count(), 5, "16x8 down to 1x1");
+    assert_eq!(texture.levels[0].len(), 4 * 2 * 16);
+    assert_eq!(texture.level_size(4), (1, 1));
+
+    let parsed = Ktx2Texture::parse(&texture.to_bytes()).expect("parse written KTX2");
+    assert_eq!(parsed, texture);
+
+    let decoded = parsed.decode_level_rgba8(0).expect("decode base level");
+    let max_error = decoded.iter().zip(&rgba).map(|(a, b)| (*a as i32 - *b as i32).abs()).max().unwrap();
+    assert!(max_error <= 8, "BC7 error {max_error} is too high for a smooth gradient");
+}
+
+#[test]
+fn ktx2_parser_rejects_formats_other_than_bc1_bc3_bc7() {
+    let texture = ktx2::encode_bc7(&[255; 4 * 4 * 4], 4, 4, false);
+    let mut bytes = texture.to_bytes();
+    bytes[12..16].copy_from_slice(&37u32.to_le_bytes()); // VK_FORMAT_R8G8B8A8_UNORM
+    let err = Ktx2Texture::parse(&bytes).expect_err("uncompressed formats are rejected");
+    assert!(err.to_string().contains("vkFormat 37"), "unexpected error: {err}");
+    assert!(Ktx2Texture::parse(b"\x89PNG\r\n\x1a\n").is_err());
+}
+
+#[test]
+fn bc7_atlas_uses_a_quarter_of_the_rgba8_footprint() {
+    let renderer = headless_renderer();
+    let (device, queue) = renderer.device_and_queue().expect("device");
+    let supports_bc = device.features().contains(wgpu::Features::TEXTURE_COMPRESSION_BC);
+    let dir = tempfile::tempdir().expect("temp dir");
+    let size = 4096u32;
+    let blocks = (size / 4) as usize * (size / 4) as usize;
+    let texture = Ktx2Texture {
+        format: wgpu::TextureFormat::Bc7RgbaUnormSrgb,
+        width: size,
+        height: size,
+        levels: vec![vec![0; blocks * 16]],
+    };
+    fs::write(dir.path().join("atlas.ktx2"), texture.to_bytes()).expect("write ktx2");
+    let json = write_atlas_json(dir.path(), "atlas.ktx2", size);
+
+    let mut assets = AssetManager::new();
+    assets.set_device(device, queue);
+    assets.retain_atlas("big", Some(&json)).expect("load atlas");
+    assets.atlas_texture_view("big").expect("upload atlas");
+    let rgba8_bytes = size as u64 * size as u64 * 4;
+    if supports_bc {
+        assert_eq!(assets.atlas_texture_format("big"), Some(wgpu::TextureFormat::Bc7RgbaUnormSrgb));
+        assert_eq!(assets.atlas_gpu_bytes(), rgba8_bytes / 4);
+        assert_eq!(assets.compressed_atlas_gpu_bytes(), rgba8_bytes / 4);
+    } else {
+        assert_eq!(assets.atlas_texture_format("big"), Some(wgpu::TextureFormat::Rgba8UnormSrgb));
+        assert_eq!(assets.atlas_gpu_bytes(), rgba8_bytes, "unsupported adapters decode to RGBA8");
+    }
+}
+
+#[test]
+fn compressed_sidecar_replaces_the_png_without_moving_regions() {
+    let renderer = headless_renderer();
+    let (device, queue) = renderer.device_and_queue().expect("device");
+    let supports_bc = device.features().contains(wgpu::Features::TEXTURE_COMPRESSION_BC);
+    let dir = tempfile::tempdir().expect("temp dir");
+    image::RgbaImage::from_pixel(16, 16, image::Rgba([200, 40, 40, 255]))
+        .save(dir.path().join("atlas.png"))
+        .expect("save png");
+    let json = write_atlas_json(dir.path(), "atlas.png", 16);
+
+    let mut assets = AssetManager::new();
+    assets.set_device(device, queue);
+    assets.retain_atlas("main", Some(&json)).expect("load atlas");
+    let uv_before = assets.atlas_region_uv("main", "tile").expect("region uv");
+    assets.atlas_texture_view("main").expect("upload png");
+    assert_eq!(assets.atlas_texture_format("main"), Some(wgpu::TextureFormat::Rgba8UnormSrgb));
+    assert_eq!(assets.atlas_gpu_bytes(), 16 * 16 * 4);
+
+    let sidecar = assets.compress_atlas_texture("main").expect("write sidecar");
+    assert_eq!(sidecar, dir.path().join("atlas.ktx2"));
+    assert_eq!(ktx2::preferred_texture_path(&dir.path().join("atlas.png")), sidecar);
+    assets.atlas_texture_view("main").expect("upload sidecar");
+    assert_eq!(assets.atlas_region_uv("main", "tile").expect("region uv"), uv_before);
+    let expected_format =
+        if supports_bc { wgpu::TextureFormat::Bc7RgbaUnormSrgb } else { wgpu::TextureFormat::Rgba8UnormSrgb };
+    assert_eq!(assets.atlas_texture_format("main"), Some(expected_format));
+    // Five mips (16x16 down to 1x1) at one byte per texel, with sub-block mips padded to 4x4.
+    let expected_bytes = if supports_bc { 256 + 64 + 16 + 16 + 16 } else { (256 + 64 + 16 + 4 + 1) * 4 };
+    assert_eq!(assets.atlas_gpu_bytes(), expected_bytes);
+}
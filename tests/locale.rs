@@ -0,0 +1,75 @@
+use kestrel_engine::locale::{self, Locale};
+use kestrel_engine::tr;
+use std::collections::HashMap;
+use std::fs;
+
+fn strings(entries: &[(&str, &str)]) -> HashMap<String, String> {
+    entries.iter().map(|(key, text)| (key.to_string(), text.to_string())).collect()
+}
+
+#[test]
+fn missing_keys_fall_back_to_english_then_the_key() {
+    let locale = Locale::from_strings("xx", strings(&[("panel.stats", "Statistiken")]));
+    assert_eq!(locale.lookup("panel.stats"), "Statistiken");
+    assert_eq!(locale.lookup("panel.profiler"), "Profiler");
+    assert_eq!(locale.lookup("no.such.key"), "no.such.key");
+    assert_eq!(locale.lookup("panel.profiler"), "Profiler", "repeat lookups keep falling back");
+    assert_eq!(locale.missing_keys(), vec!["no.such.key".to_string(), "panel.profiler".to_string()]);
+}
+
+#[test]
+fn placeholders_are_substituted_by_name() {
+    let locale = Locale::from_strings("xx", strings(&[("scene.saved", "{path} gespeichert ({path})")]));
+    let path = "scenes/demo.json";
+    assert_eq!(
+        locale.format("scene.saved", &[("path", &path), ("unused", &3)]),
+        "scenes/demo.json gespeichert (scenes/demo.json)"
+    );
+    assert_eq!(locale.format("inspector.burst_queued", &[("count", &12)]), "Queued a burst of 12 particles.");
+}
+
+#[test]
+fn untranslated_dump_lists_english_keys_the_locale_lacks() {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let locale = Locale::from_strings("xx", strings(&[("panel.stats", "Statistiken")]));
+    locale.lookup("no.such.key");
+    let path = dir.path().join("out/untranslated.json");
+    let written = locale.write_untranslated_keys(&path).expect("write dump");
+
+    let dumped: HashMap<String, String> =
+        serde_json::from_str(&fs::read_to_string(&path).expect("read dump")).expect("parse dump");
+    assert_eq!(written, dumped.len());
+    assert!(!dumped.contains_key("panel.stats"));
+    assert_eq!(dumped.get("panel.profiler").map(String::as_str), Some("Profiler"));
+    assert_eq!(dumped.get("no.such.key").map(String::as_str), Some(""));
+}
+
+#[test]
+fn shipped_locales_are_discovered_and_only_use_english_keys() {
+    let languages = locale::available_languages(locale::LOCALE_DIR);
+    let codes: Vec<&str> = languages.iter().map(|(code, _)| code.as_str()).collect();
+    assert!(codes.contains(&"en") && codes.contains(&"es"), "found {codes:?}");
+    assert!(languages.iter().any(|(code, name)| code == "es" && name == "Español"));
+
+    let english = Locale::load(locale::LOCALE_DIR, "en").expect("load en");
+    assert!(english.untranslated().is_empty());
+    let spanish = Locale::load(locale::LOCALE_DIR, "es").expect("load es");
+    let spanish_keys: HashMap<String, String> = serde_json::from_str(
+        &fs::read_to_string(format!("{}/es.json", locale::LOCALE_DIR)).expect("read es"),
+    )
+    .expect("parse es");
+    for key in spanish_keys.keys() {
+        assert_ne!(english.lookup(key), key.as_str(), "'{key}' is not an English key");
+    }
+    assert!(!spanish.untranslated().is_empty(), "the Spanish stub leaves strings to English");
+    assert!(Locale::load(locale::LOCALE_DIR, "zz").is_err());
+}
+
+#[test]
+fn tr_macro_reads_the_active_locale() {
+    locale::set_active(Locale::from_strings("xx", strings(&[("scene.loaded", "Geladen: {path}")])));
+    assert_eq!(tr!("scene.loaded", path = "a.json"), "Geladen: a.json");
+    assert_eq!(tr!("scene.demo_spawned"), "Spawned demo scene");
+    locale::set_active(Locale::english());
+    assert_eq!(tr!("scene.loaded", path = "a.json"), "Loaded a.json");
+}
@@ -0,0 +1,101 @@
+use glam::{Quat, Vec3};
+use kestrel_engine::ecs::{EcsWorld, MeshRef, MeshSurface, WorldTransform3D};
+use kestrel_engine::material_registry::MaterialRegistry;
+use kestrel_engine::mesh::{gltf_part_key, MeshAxisConvention, MeshImportSettings};
+use kestrel_engine::mesh_registry::MeshRegistry;
+use std::path::Path;
+
+const ROBOT: &str = "assets/models/robot_parts.gltf";
+
+fn world_translation(world: &EcsWorld, entity: bevy_ecs::prelude::Entity) -> Vec3 {
+    world.world.get::<WorldTransform3D>(entity).expect("world transform").0.w_axis.truncate()
+}
+
+#[test]
+fn scene_graph_import_registers_one_mesh_per_primitive() {
+    let path = Path::new(ROBOT);
+    let mut materials = MaterialRegistry::new();
+    let mut registry = MeshRegistry::new(&mut materials);
+    let nodes = registry.import_gltf_scene(path, &mut materials).expect("import robot");
+
+    let names: Vec<&str> = nodes.iter().map(|node| node.name.as_str()).collect();
+    assert_eq!(names, ["Body", "Head", "ArmPivot.R", "Arm", "ArmPivot.L", "Arm_5"]);
+    let head = &nodes[1];
+    assert_eq!(head.parent, Some(0));
+    let head_keys: Vec<&str> = head.meshes.iter().map(|mesh| mesh.key.as_str()).collect();
+    assert_eq!(head_keys, [gltf_part_key(path, "Head", Some(0)), gltf_part_key(path, "Head", Some(1))]);
+    for key in ["Body", "Head", "Arm", "Arm_5"].map(|node| gltf_part_key(path, node, Some(0))) {
+        assert!(registry.has(&key), "missing part {key}");
+        assert_eq!(registry.mesh_source(&key), Some(path));
+    }
+    let body = registry.mesh(&gltf_part_key(path, "Body", Some(0))).expect("body mesh");
+    assert_eq!(body.vertices.len(), 24);
+    assert!((body.bounds.max.y - 0.7).abs() < 1e-4, "vertices stay in node-local space");
+
+    let paint = head.meshes[0].material.as_deref().expect("head paint material");
+    let definition = materials.definition(paint).expect("paint registered");
+    assert!(definition.base_color_texture.is_some(), "embedded texture is extracted");
+    let visor = head.meshes[1].material.as_deref().expect("visor material");
+    assert_eq!(materials.definition(visor).expect("visor registered").emissive_factor, [0.2, 0.9, 1.0]);
+
+    let mut fresh = MeshRegistry::new(&mut materials);
+    let arm_key = gltf_part_key(path, "Arm_5", Some(0));
+    fresh.ensure_mesh(&arm_key, Some(ROBOT), &mut materials).expect("load single part by key");
+    assert_eq!(fresh.mesh(&arm_key).expect("arm part").indices.len(), 36);
+}
+
+#[test]
+fn spawned_hierarchy_preserves_node_transforms() {
+    let mut materials = MaterialRegistry::new();
+    let mut registry = MeshRegistry::new(&mut materials);
+    let nodes = registry.import_gltf_scene(ROBOT, &mut materials).expect("import robot");
+    let mut world = EcsWorld::new();
+    let root = world.spawn_mesh_hierarchy(&nodes, Vec3::new(3.0, 0.0, 0.0)).expect("spawn hierarchy");
+    world.update(0.0);
+
+    let body = world.get_children(root)[0];
+    assert!((world_translation(&world, body) - Vec3::new(3.0, 1.0, 0.0)).length() < 1e-4);
+    let body_children = world.get_children(body);
+    assert_eq!(body_children.len(), 3);
+    let head = body_children[0];
+    assert!(world.world.get::<MeshRef>(head).is_none(), "multi-part nodes hold parts as children");
+    let head_parts = world.get_children(head);
+    assert_eq!(head_parts.len(), 2);
+    for (part, node_mesh) in head_parts.iter().zip(&nodes[1].meshes) {
+        assert_eq!(world.world.get::<MeshRef>(*part).expect("mesh ref").key, node_mesh.key);
+        assert_eq!(world.world.get::<MeshSurface>(*part).expect("surface").material, node_mesh.material);
+        assert!((world_translation(&world, *part) - Vec3::new(3.0, 1.95, 0.0)).length() < 1e-4);
+    }
+
+    let right_pivot = body_children[1];
+    let right_arm = world.get_children(right_pivot)[0];
+    assert!(world.world.get::<MeshRef>(right_arm).is_some(), "single-part nodes carry their mesh");
+    let expected = Vec3::new(3.6, 1.5, 0.0) + Quat::from_rotation_z(-0.3) * Vec3::new(0.0, -0.4, 0.0);
+    assert!((world_translation(&world, right_arm) - expected).length() < 1e-3);
+}
+
+#[test]
+fn sidecar_settings_apply_on_import_and_reload() {
+    let temp_dir = tempfile::tempdir().expect("temp dir");
+    let path = temp_dir.path().join("robot.gltf");
+    std::fs::copy(ROBOT, &path).expect("copy robot");
+    let settings = MeshImportSettings { scale: 2.0, axis: MeshAxisConvention::ZUp, merge_primitives: true };
+    settings.save_for(&path).expect("write sidecar");
+    assert!(temp_dir.path().join("robot.gltf.import.json").exists());
+    assert_eq!(MeshImportSettings::load_for(&path), settings);
+
+    let mut materials = MaterialRegistry::new();
+    let mut registry = MeshRegistry::new(&mut materials);
+    let nodes = registry.import_gltf_scene(&path, &mut materials).expect("import with settings");
+    let head_key = gltf_part_key(&path, "Head", None);
+    assert_eq!(nodes[1].meshes.len(), 1, "merged nodes register one mesh");
+    assert_eq!(registry.mesh_subsets(&head_key).expect("head subsets").len(), 2);
+    assert!((nodes[0].translation - Vec3::new(0.0, 0.0, -2.0)).length() < 1e-4, "root rotated out of Z-up");
+    assert!((nodes[1].translation - Vec3::new(0.0, 1.9, 0.0)).length() < 1e-4, "translations scaled");
+    let body_key = gltf_part_key(&path, "Body", None);
+    assert!((registry.mesh(&body_key).expect("body").bounds.max.y - 1.4).abs() < 1e-4);
+
+    MeshImportSettings { scale: 0.5, ..settings }.save_for(&path).expect("rewrite sidecar");
+    let import = kestrel_engine::mesh::Mesh::load_gltf_for_key(&path, &body_key).expect("reload part");
+    assert!((import.mesh.bounds.max.y - 0.35).abs() < 1e-4, "reload re-reads the sidecar");
+}
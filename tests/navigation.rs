@@ -0,0 +1,96 @@
+use bevy_ecs::prelude::Entity;
+use glam::{Mat4, Vec2, Vec3};
+use kestrel_engine::ecs::{Aabb, EcsWorld, PathAgent, Transform, Velocity, WorldTransform};
+
+fn spawn_wall(ecs: &mut EcsWorld, center: Vec2, half: Vec2) -> Entity {
+    ecs.world
+        .spawn((
+            Transform { translation: center, rotation: 0.0, scale: Vec2::ONE },
+            WorldTransform(Mat4::from_translation(Vec3::new(center.x, center.y, 0.0))),
+            Aabb { half },
+        ))
+        .id()
+}
+
+fn move_wall(ecs: &mut EcsWorld, wall: Entity, center: Vec2) {
+    ecs.world.get_mut::<Transform>(wall).expect("wall transform").translation = center;
+    ecs.world.get_mut::<WorldTransform>(wall).expect("wall world transform").0 =
+        Mat4::from_translation(Vec3::new(center.x, center.y, 0.0));
+}
+
+#[test]
+fn find_path_routes_around_collider_walls() {
+    let mut ecs = EcsWorld::new();
+    spawn_wall(&mut ecs, Vec2::new(0.0, 0.2), Vec2::new(0.05, 0.8));
+
+    let from = Vec2::new(-0.8, 0.3);
+    let to = Vec2::new(0.8, 0.3);
+    let path = ecs.find_path(from, to).expect("path around the wall");
+    assert_eq!(path.first().copied(), Some(from));
+    assert_eq!(path.last().copied(), Some(to));
+    assert!(path.iter().any(|point| point.y < -0.6), "path should go under the wall: {path:?}");
+    assert!(ecs.find_path(from, Vec2::new(0.0, 0.2)).is_none(), "goal inside a wall is unreachable");
+}
+
+#[test]
+fn path_agent_navigates_to_destination() {
+    let mut ecs = EcsWorld::new();
+    spawn_wall(&mut ecs, Vec2::new(0.0, 0.2), Vec2::new(0.05, 0.8));
+    let start = Vec2::new(-0.8, 0.3);
+    let goal = Vec2::new(0.8, 0.3);
+    let agent = ecs
+        .world
+        .spawn((
+            Transform { translation: start, rotation: 0.0, scale: Vec2::splat(0.1) },
+            WorldTransform::default(),
+            Velocity(Vec2::ZERO),
+            PathAgent::with_speed(1.0),
+        ))
+        .id();
+    assert!(ecs.request_path(agent, goal));
+
+    let dt = 1.0 / 60.0;
+    for _ in 0..600 {
+        ecs.fixed_step(dt);
+        let position = ecs.world.get::<Transform>(agent).unwrap().translation;
+        assert!(
+            !(position.x.abs() < 0.05 && position.y > -0.6),
+            "agent walked through the wall at {position:?}"
+        );
+        if !ecs.world.get::<PathAgent>(agent).unwrap().is_moving() {
+            break;
+        }
+    }
+    let position = ecs.world.get::<Transform>(agent).unwrap().translation;
+    assert!(position.distance(goal) < 0.05, "agent stopped at {position:?}");
+    assert_eq!(ecs.world.get::<Velocity>(agent).unwrap().0, Vec2::ZERO);
+    assert!(ecs.nav_agent_paths().is_empty());
+}
+
+#[test]
+fn moving_obstacles_only_restamp_their_cells() {
+    let mut ecs = EcsWorld::new();
+    spawn_wall(&mut ecs, Vec2::new(-1.0, -0.7), Vec2::splat(0.1));
+    let pillar_cells = ecs.rebuild_nav_grid();
+    assert!(pillar_cells > 0);
+
+    let wall = spawn_wall(&mut ecs, Vec2::new(0.0, 0.2), Vec2::new(0.05, 0.8));
+    let wall_cells = ecs.sync_nav_grid();
+    assert_eq!(ecs.nav_grid_stats().blocked_cells, pillar_cells + wall_cells);
+    assert_eq!(ecs.sync_nav_grid(), 0, "unchanged colliders do not touch the grid");
+
+    move_wall(&mut ecs, wall, Vec2::new(0.3, 0.2));
+    let touched = ecs.sync_nav_grid();
+    let moved_wall_cells = ecs.rebuild_nav_grid() - pillar_cells;
+    assert_eq!(
+        touched,
+        wall_cells + moved_wall_cells,
+        "only the moved wall's old and new cells are restamped"
+    );
+
+    ecs.world.despawn(wall);
+    assert_eq!(ecs.sync_nav_grid(), moved_wall_cells);
+    assert_eq!(ecs.nav_grid_stats().obstacles, 1);
+    let path = ecs.find_path(Vec2::new(-0.8, 0.3), Vec2::new(0.8, 0.3)).expect("straight path");
+    assert_eq!(path.len(), 2, "with the wall gone the path is a straight line");
+}
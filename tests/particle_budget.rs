@@ -1,6 +1,7 @@
 use glam::{Vec2, Vec4};
 use kestrel_engine::ecs::{
-    EcsWorld, Particle, ParticleCaps, ParticleEmitter, ParticleState, Transform, Velocity,
+    BurstEmitter, EcsWorld, EmitterShape, Particle, ParticleCaps, ParticleEmitter, ParticleState, Transform,
+    Velocity,
 };
 use std::f32::consts::PI;
 
@@ -114,3 +115,93 @@ fn emitter_rotation_controls_spawn_direction() {
         );
     }
 }
+
+#[test]
+fn emitter_burst_amortizes_over_the_frame_budget() {
+    let mut world = EcsWorld::new();
+    world.set_particle_caps(ParticleCaps::new(200, 1_000, 64.0));
+
+    let emitter =
+        world.spawn_particle_emitter(Vec2::ZERO, 0.0, 0.2, 2.0, 5.0, Vec4::ONE, Vec4::ONE, 0.2, 0.1);
+    assert!(world.emitter_burst(emitter, 500));
+    assert_eq!(world.particle_budget_metrics().burst_backlog_total, 500);
+
+    let mut counts = Vec::new();
+    for _ in 0..3 {
+        world.update(0.016);
+        let mut particle_query = world.world.query::<&Particle>();
+        counts.push(particle_query.iter(&world.world).count());
+    }
+    assert_eq!(counts, vec![200, 400, 500], "burst should drain at most max_spawn_per_frame per frame");
+    assert_eq!(world.world.get::<ParticleEmitter>(emitter).unwrap().pending_burst, 0);
+    assert_eq!(world.particle_budget_metrics().burst_backlog_total, 0);
+}
+
+#[test]
+fn set_emitter_burst_fires_once_on_the_next_fixed_step_within_caps() {
+    let mut world = EcsWorld::new();
+    world.set_particle_caps(ParticleCaps::new(200, 1_000, 64.0));
+
+    let emitter =
+        world.spawn_particle_emitter(Vec2::ZERO, 0.0, 0.2, 2.0, 5.0, Vec4::ONE, Vec4::ONE, 0.05, 0.05);
+    assert!(world.set_emitter_burst(emitter, 500, 6.0));
+    assert_eq!(world.world.get::<BurstEmitter>(emitter).cloned(), Some(BurstEmitter::new(500, 6.0)));
+    let plain = world.world.spawn(Transform::default()).id();
+    assert!(!world.set_emitter_burst(plain, 10, 1.0), "entities without an emitter are rejected");
+
+    world.fixed_step(1.0 / 60.0);
+    let mut particle_query = world.world.query::<(&Velocity, &Particle)>();
+    let speeds: Vec<f32> =
+        particle_query.iter(&world.world).map(|(velocity, _)| velocity.0.length()).collect();
+    assert_eq!(speeds.len(), 200, "the burst is truncated to the per-frame spawn cap");
+    // Overlapping particles nudge each other on spawn, so allow a little slack on speed.
+    assert!(speeds.iter().all(|speed| (speed - 6.0).abs() < 1.0), "particles launch at the impulse speed");
+    assert!(world.world.get::<BurstEmitter>(emitter).is_none(), "the burst is consumed in one step");
+
+    world.fixed_step(1.0 / 60.0);
+    let mut particle_query = world.world.query::<&Particle>();
+    assert_eq!(particle_query.iter(&world.world).count(), 200, "the truncated remainder is dropped");
+}
+
+#[test]
+fn set_emitter_burst_keeps_the_speed_of_each_request() {
+    let mut world = EcsWorld::new();
+    world.set_particle_caps(ParticleCaps::new(200, 1_000, 64.0));
+
+    let emitter =
+        world.spawn_particle_emitter(Vec2::ZERO, 0.0, 0.2, 2.0, 5.0, Vec4::ONE, Vec4::ONE, 0.05, 0.05);
+    assert!(world.set_emitter_burst(emitter, 10, 2.0));
+    assert!(world.set_emitter_burst(emitter, 10, 12.0));
+
+    world.fixed_step(1.0 / 60.0);
+    let mut particle_query = world.world.query::<(&Velocity, &Particle)>();
+    let speeds: Vec<f32> =
+        particle_query.iter(&world.world).map(|(velocity, _)| velocity.0.length()).collect();
+    assert_eq!(speeds.len(), 20, "both bursts fire in the same step");
+    assert_eq!(speeds.iter().filter(|speed| (*speed - 2.0).abs() < 1.0).count(), 10);
+    assert_eq!(speeds.iter().filter(|speed| (*speed - 12.0).abs() < 1.0).count(), 10);
+    assert!(world.world.get::<BurstEmitter>(emitter).is_none());
+}
+
+#[test]
+fn circle_edge_shape_spawns_a_ring_moving_outward() {
+    let mut world = EcsWorld::new();
+    let center = Vec2::new(3.0, -2.0);
+    let emitter = world.spawn_particle_emitter(center, 0.0, 0.0, 4.0, 5.0, Vec4::ONE, Vec4::ONE, 0.05, 0.05);
+    world.set_emitter_shape(emitter, EmitterShape::CircleEdge { radius: 2.0 });
+    assert!(world.emitter_burst(emitter, 64));
+
+    world.update(0.0);
+
+    let mut particle_query = world.world.query::<(&Transform, &Velocity, &Particle)>();
+    let mut spawned = 0;
+    for (transform, velocity, _) in particle_query.iter(&world.world) {
+        let offset = transform.translation - center;
+        assert!((offset.length() - 2.0).abs() < 1e-3, "particle should start on the ring, got {offset:?}");
+        // Overlapping particles nudge each other on spawn, so allow a little slack on velocity.
+        assert!((velocity.0.length() - 4.0).abs() < 0.5);
+        assert!(velocity.0.normalize().dot(offset.normalize()) > 0.95, "ring particles should move outward");
+        spawned += 1;
+    }
+    assert_eq!(spawned, 64);
+}
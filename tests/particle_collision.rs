@@ -0,0 +1,213 @@
+use bevy_ecs::prelude::Entity;
+use glam::{Mat4, Vec2, Vec3, Vec4};
+use kestrel_engine::ecs::{
+    Aabb, EcsWorld, Force, Mass, Particle, ParticleCaps, ParticleCollision, ParticleCollisionMode,
+    ParticleState, Transform, Velocity, WorldTransform,
+};
+use kestrel_engine::events::GameEvent;
+
+const DT: f32 = 1.0 / 60.0;
+const PLATFORM_TOP: f32 = -0.35;
+
+fn spawn_platform(ecs: &mut EcsWorld, rotation: f32) -> Entity {
+    let center = Vec2::new(0.0, PLATFORM_TOP - 0.05);
+    ecs.world
+        .spawn((
+            Transform { translation: center, rotation, scale: Vec2::ONE },
+            WorldTransform(Mat4::from_translation(Vec3::new(center.x, center.y, 0.0))),
+            Aabb { half: Vec2::new(1.2, 0.05) },
+        ))
+        .id()
+}
+
+fn spawn_particle(
+    ecs: &mut EcsWorld,
+    emitter: Entity,
+    position: Vec2,
+    velocity: Vec2,
+    mode: ParticleCollisionMode,
+    emit_events: bool,
+) -> Entity {
+    ecs.world
+        .spawn((
+            Transform { translation: position, rotation: 0.0, scale: Vec2::splat(0.06) },
+            Velocity(velocity),
+            Force::default(),
+            Mass(0.2),
+            Aabb { half: Vec2::splat(0.03) },
+            Particle { lifetime: 10.0, max_lifetime: 10.0 },
+            ParticleCollision { emitter, mode, emit_events, stuck_at: None },
+        ))
+        .id()
+}
+
+fn particle_collision_events(ecs: &mut EcsWorld) -> usize {
+    ecs.drain_events().iter().filter(|event| matches!(event, GameEvent::ParticleCollision { .. })).count()
+}
+
+#[test]
+fn fountain_particles_bounce_and_settle_on_platform() {
+    let mut ecs = EcsWorld::new();
+    spawn_platform(&mut ecs, 0.0);
+    let emitter = ecs.spawn_particle_emitter(
+        Vec2::new(0.0, 0.2),
+        30.0,
+        0.4,
+        0.6,
+        20.0,
+        Vec4::ONE,
+        Vec4::ONE,
+        0.06,
+        0.06,
+    );
+    ecs.set_emitter_collision(emitter, ParticleCollisionMode::Bounce { restitution: 0.5 }, false);
+
+    for _ in 0..240 {
+        ecs.update(DT);
+        ecs.fixed_step(DT);
+    }
+    ecs.set_emitter_rate(emitter, 0.0);
+    for _ in 0..300 {
+        ecs.update(DT);
+        ecs.fixed_step(DT);
+    }
+
+    let mut query = ecs.world.query::<(&Transform, &Velocity, &Particle)>();
+    let particles: Vec<(Vec2, Vec2)> =
+        query.iter(&ecs.world).map(|(transform, velocity, _)| (transform.translation, velocity.0)).collect();
+    assert!(particles.len() > 50, "fountain should have produced particles, got {}", particles.len());
+    for (position, _) in &particles {
+        assert!(position.y > PLATFORM_TOP, "particle at {position:?} fell through the platform");
+    }
+    // Particles also push each other apart, so the fountain settles as a shallow pile on the platform.
+    let settled = particles
+        .iter()
+        .filter(|(position, velocity)| position.y < PLATFORM_TOP + 0.25 && velocity.length() < 0.1)
+        .count();
+    assert_eq!(settled, particles.len(), "every particle should come to rest on the platform");
+    let metrics = ecs.particle_budget_metrics();
+    assert_eq!(metrics.collision_emitters, 1);
+    assert!(metrics.collision_tests > 0);
+    assert!(metrics.collision_hits > 0);
+    assert!(!metrics.collision_checks_skipped);
+}
+
+#[test]
+fn bouncing_particle_rebounds_off_platform() {
+    let mut ecs = EcsWorld::new();
+    spawn_platform(&mut ecs, 0.0);
+    let emitter = ecs.world.spawn_empty().id();
+    let particle = spawn_particle(
+        &mut ecs,
+        emitter,
+        Vec2::new(0.0, PLATFORM_TOP + 0.04),
+        Vec2::new(0.0, -2.0),
+        ParticleCollisionMode::Bounce { restitution: 0.5 },
+        false,
+    );
+
+    ecs.fixed_step(DT);
+
+    let velocity = ecs.world.get::<Velocity>(particle).expect("particle velocity").0;
+    let position = ecs.world.get::<Transform>(particle).expect("particle transform").translation;
+    assert!(velocity.y > 0.5, "particle should rebound upwards, velocity {velocity:?}");
+    assert!(position.y >= PLATFORM_TOP + 0.03 - 1e-4, "particle should be pushed out, position {position:?}");
+}
+
+#[test]
+fn rotated_collider_deflects_along_its_surface_normal() {
+    let mut ecs = EcsWorld::new();
+    spawn_platform(&mut ecs, 0.5);
+    let emitter = ecs.world.spawn_empty().id();
+    let particle = spawn_particle(
+        &mut ecs,
+        emitter,
+        Vec2::new(0.0, PLATFORM_TOP + 0.04),
+        Vec2::new(0.0, -2.0),
+        ParticleCollisionMode::Bounce { restitution: 0.5 },
+        false,
+    );
+
+    ecs.fixed_step(DT);
+
+    let velocity = ecs.world.get::<Velocity>(particle).expect("particle velocity").0;
+    assert!(velocity.y > 0.0, "particle should leave the slope, velocity {velocity:?}");
+    assert!(velocity.x < -0.1, "slope tilted counter-clockwise should deflect to -X, velocity {velocity:?}");
+}
+
+#[test]
+fn kill_mode_despawns_and_stick_mode_pins_particles() {
+    let mut ecs = EcsWorld::new();
+    spawn_platform(&mut ecs, 0.0);
+    let emitter = ecs.world.spawn_empty().id();
+    let start = Vec2::new(0.3, PLATFORM_TOP + 0.04);
+    let killed =
+        spawn_particle(&mut ecs, emitter, start, Vec2::new(0.0, -2.0), ParticleCollisionMode::Kill, false);
+    let stuck = spawn_particle(
+        &mut ecs,
+        emitter,
+        Vec2::new(-0.3, start.y),
+        Vec2::new(0.5, -2.0),
+        ParticleCollisionMode::Stick,
+        false,
+    );
+
+    ecs.fixed_step(DT);
+    assert!(ecs.world.get_entity(killed).is_err(), "kill mode should despawn the particle on contact");
+    let pinned = ecs.world.get::<ParticleCollision>(stuck).and_then(|collision| collision.stuck_at);
+    let pinned = pinned.expect("stick mode should record the contact point");
+
+    for _ in 0..30 {
+        ecs.fixed_step(DT);
+    }
+    let position = ecs.world.get::<Transform>(stuck).expect("stuck transform").translation;
+    let velocity = ecs.world.get::<Velocity>(stuck).expect("stuck velocity").0;
+    assert!((position - pinned).length() < 1e-5, "stuck particle drifted from {pinned:?} to {position:?}");
+    assert_eq!(velocity, Vec2::ZERO);
+}
+
+#[test]
+fn collision_events_are_throttled_per_emitter() {
+    let mut ecs = EcsWorld::new();
+    spawn_platform(&mut ecs, 0.0);
+    let noisy = ecs.world.spawn_empty().id();
+    let other = ecs.world.spawn_empty().id();
+    let mode = ParticleCollisionMode::Bounce { restitution: 0.5 };
+    for i in 0..5 {
+        let position = Vec2::new(-0.5 + i as f32 * 0.2, PLATFORM_TOP + 0.01);
+        spawn_particle(&mut ecs, noisy, position, Vec2::new(0.0, -1.0), mode, true);
+    }
+    spawn_particle(&mut ecs, other, Vec2::new(0.6, PLATFORM_TOP + 0.01), Vec2::new(0.0, -1.0), mode, true);
+    spawn_particle(&mut ecs, other, Vec2::new(0.8, PLATFORM_TOP + 0.01), Vec2::new(0.0, -1.0), mode, false);
+    ecs.drain_events();
+
+    ecs.fixed_step(DT);
+
+    assert_eq!(particle_collision_events(&mut ecs), 2, "one event per emitter per fixed step");
+    assert_eq!(ecs.particle_budget_metrics().collision_hits, 7);
+}
+
+#[test]
+fn collision_checks_skip_above_particle_threshold() {
+    let mut ecs = EcsWorld::new();
+    ecs.set_particle_caps(ParticleCaps::new(64, 500, 32.0).with_max_collision_particles(2));
+    spawn_platform(&mut ecs, 0.0);
+    let emitter = ecs.world.spawn_empty().id();
+    let particle = spawn_particle(
+        &mut ecs,
+        emitter,
+        Vec2::new(0.0, PLATFORM_TOP + 0.01),
+        Vec2::new(0.0, -1.0),
+        ParticleCollisionMode::Kill,
+        true,
+    );
+    ecs.world.resource_mut::<ParticleState>().active_particles = 3;
+
+    ecs.fixed_step(DT);
+
+    assert!(ecs.world.get_entity(particle).is_ok(), "collision should not run above the threshold");
+    let metrics = ecs.particle_budget_metrics();
+    assert!(metrics.collision_checks_skipped);
+    assert_eq!(metrics.collision_tests, 0);
+    assert_eq!(metrics.collision_particle_limit, 2);
+}
@@ -1,5 +1,9 @@
-use glam::Vec2;
-use kestrel_engine::ecs::{Aabb, EcsWorld, Force, Mass, SceneEntityTag, Transform, Velocity, WorldTransform};
+use glam::{Vec2, Vec4};
+use kestrel_engine::assets::AssetManager;
+use kestrel_engine::ecs::{
+    Aabb, EcsWorld, EmitterShape, Force, FrameInputLog, Mass, ParticleCollisionMode, SceneEntityTag,
+    Transform, Velocity, WorldTransform,
+};
 use kestrel_engine::scene::SceneEntityId;
 
 const TEST_DT: f32 = 1.0 / 120.0;
@@ -24,6 +28,88 @@ fn fixed_step_remains_deterministic_across_worlds() {
     );
 }
 
+#[derive(Clone, Copy)]
+enum RecordedInput {
+    Burst(u32),
+    SpawnBodies(usize),
+    Push(Vec2),
+}
+
+#[test]
+fn deterministic_mode_reproduces_state_hashes_for_recorded_inputs() {
+    let mut log = FrameInputLog::new();
+    log.record(3, RecordedInput::Burst(40));
+    log.record(120, RecordedInput::SpawnBodies(6));
+    log.record(240, RecordedInput::Push(Vec2::new(0.4, 0.2)));
+    log.record(480, RecordedInput::Burst(80));
+    log.record(700, RecordedInput::SpawnBodies(3));
+
+    let first = run_recorded(&log, 7);
+    let second = run_recorded(&log, 7);
+    assert_eq!(first.len(), 1000);
+    if let Some(frame) = first.iter().zip(&second).position(|(a, b)| a != b) {
+        panic!("state hashes diverged at frame {frame}");
+    }
+    assert!(first.windows(2).any(|pair| pair[0] != pair[1]), "the world should change between frames");
+    assert_ne!(run_recorded(&log, 8), first, "the seed should drive the emitter streams");
+}
+
+/// Steps a deterministic world through 1000 frames of uneven frame deltas, applying `log` and
+/// recording the state hash after each frame.
+fn run_recorded(log: &FrameInputLog<RecordedInput>, seed: u64) -> Vec<u64> {
+    const FRAME_DTS: [f32; 4] = [1.0 / 50.0, 1.0 / 75.0, 1.0 / 61.0, 1.0 / 144.0];
+    let assets = AssetManager::new();
+    let mut world = EcsWorld::new();
+    world.set_deterministic(Some(seed));
+    let pushed = world.spawn_demo_scene();
+    let emitter = world.spawn_particle_emitter(
+        Vec2::new(0.2, 0.4),
+        60.0,
+        0.9,
+        1.2,
+        1.5,
+        Vec4::ONE,
+        Vec4::ZERO,
+        0.04,
+        0.01,
+    );
+    world.set_emitter_shape(emitter, EmitterShape::Disc { radius: 0.2 });
+    world.set_emitter_collision(emitter, ParticleCollisionMode::Bounce { restitution: 0.6 }, true);
+    world.world.spawn((
+        Transform { translation: Vec2::new(0.2, -0.4), rotation: 0.3, scale: Vec2::ONE },
+        WorldTransform::default(),
+        Aabb { half: Vec2::new(0.5, 0.05) },
+        SceneEntityTag::new(SceneEntityId::sequential(1_000)),
+    ));
+
+    let mut accumulator = 0.0;
+    let mut hashes = Vec::with_capacity(1000);
+    for frame in 0..1000u64 {
+        for input in log.inputs(frame) {
+            match *input {
+                RecordedInput::Burst(count) => {
+                    world.emitter_burst(emitter, count);
+                }
+                RecordedInput::SpawnBodies(count) => world.spawn_burst(&assets, count),
+                RecordedInput::Push(delta) => {
+                    if let Some(mut velocity) = world.world.get_mut::<Velocity>(pushed) {
+                        velocity.0 += delta;
+                    }
+                }
+            }
+        }
+        let dt = FRAME_DTS[frame as usize % FRAME_DTS.len()];
+        accumulator += dt;
+        while accumulator >= TEST_DT {
+            world.fixed_step(TEST_DT);
+            accumulator -= TEST_DT;
+        }
+        world.update(dt);
+        hashes.push(world.world_state_hash());
+    }
+    hashes
+}
+
 fn spawn_linear_motion_world() -> (EcsWorld, bevy_ecs::prelude::Entity) {
     let mut world = EcsWorld::new();
     let entity = world
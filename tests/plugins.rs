@@ -1,19 +1,27 @@
 use anyhow::Result;
+use bevy_ecs::prelude::Component;
+use glam::Mat4;
 use kestrel_engine::analytics::AnalyticsPlugin;
 use kestrel_engine::assets::AssetManager;
 use kestrel_engine::config::WindowConfig;
-use kestrel_engine::ecs::EcsWorld;
+use kestrel_engine::ecs::{
+    ComponentField, ComponentFieldKind, ComponentFieldSchema, ComponentFieldValue, EcsWorld, SceneEntityTag,
+    Transform, WorldTransform,
+};
 use kestrel_engine::environment::EnvironmentRegistry;
-use kestrel_engine::events::GameEvent;
+use kestrel_engine::events::{CollisionStarted, EventBus, GameEvent, ScriptMessage};
 use kestrel_engine::input::Input;
 use kestrel_engine::material_registry::MaterialRegistry;
 use kestrel_engine::mesh_registry::MeshRegistry;
 use kestrel_engine::plugin_rpc::RpcAssetReadbackPayload;
 use kestrel_engine::plugins::{
-    apply_manifest_builtin_toggles, apply_manifest_dynamic_toggles, EnginePlugin, ManifestBuiltinToggle,
-    ManifestDynamicToggle, PluginCapability, PluginContext, PluginManager, PluginState,
+    apply_manifest_builtin_toggles, apply_manifest_config_updates, apply_manifest_dynamic_toggles, AssetKind,
+    AssetReloadQueueHandle, AssetReloadRequest, CapabilityFlags, EnginePlugin, ManifestBuiltinToggle,
+    ManifestConfigUpdate, ManifestDynamicToggle, PluginCapability, PluginContext, PluginManager, PluginState,
+    PluginTrust, ASSET_RELOAD_MIN_INTERVAL,
 };
-use kestrel_engine::renderer::Renderer;
+use kestrel_engine::renderer::{RenderViewport, Renderer};
+use kestrel_engine::scene::SceneMetadata;
 use kestrel_engine::time::Time;
 use pollster::block_on;
 use serde_json::json;
@@ -22,7 +30,9 @@ use std::env;
 use std::fs;
 use std::path::PathBuf;
 use std::process::Command;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
 use tempfile::tempdir;
 
 fn push_event_bridge(ecs: &mut EcsWorld, event: GameEvent) {
@@ -110,6 +120,33 @@ impl EnginePlugin for PanickingPlugin {
     }
 }
 
+#[derive(Default)]
+struct LatePanicPlugin {
+    frames: usize,
+}
+
+impl EnginePlugin for LatePanicPlugin {
+    fn name(&self) -> &'static str {
+        "late_panicker"
+    }
+
+    fn update(&mut self, _ctx: &mut PluginContext<'_>, _dt: f32) -> Result<()> {
+        self.frames += 1;
+        if self.frames == 100 {
+            panic!("late panic on frame {}", self.frames);
+        }
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
 #[derive(Default)]
 struct FeaturePublishingPlugin;
 
@@ -132,6 +169,215 @@ impl EnginePlugin for FeaturePublishingPlugin {
     }
 }
 
+struct AtlasWritingPlugin;
+
+impl EnginePlugin for AtlasWritingPlugin {
+    fn name(&self) -> &'static str {
+        "atlas_writer"
+    }
+
+    fn build(&mut self, ctx: &mut PluginContext<'_>) -> Result<()> {
+        assert!(ctx.request_asset_reload("generated", AssetKind::Atlas)?);
+        assert!(!ctx.request_asset_reload("generated", AssetKind::Atlas)?, "repeat within a second");
+        assert!(ctx.request_asset_reload("generated", AssetKind::Clip)?, "limit is per asset kind");
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+struct Ping(usize);
+
+#[derive(Default)]
+struct CollisionListenerPlugin {
+    collisions: Arc<AtomicUsize>,
+    events: Arc<AtomicUsize>,
+    pings: Arc<AtomicUsize>,
+}
+
+impl EnginePlugin for CollisionListenerPlugin {
+    fn name(&self) -> &'static str {
+        "collision_listener"
+    }
+
+    fn build(&mut self, ctx: &mut PluginContext<'_>) -> Result<()> {
+        let collisions = Arc::clone(&self.collisions);
+        ctx.subscribe_event(move |_: &CollisionStarted| {
+            collisions.fetch_add(1, Ordering::SeqCst);
+        })?;
+        let events = Arc::clone(&self.events);
+        ctx.subscribe_event(move |_: &GameEvent| {
+            events.fetch_add(1, Ordering::SeqCst);
+        })?;
+        let pings = Arc::clone(&self.pings);
+        ctx.subscribe_event(move |ping: &Ping| {
+            pings.fetch_add(ping.0, Ordering::SeqCst);
+        })?;
+        ctx.publish_event(Ping(3))?;
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+struct MessageSubscriberPlugin {
+    name: &'static str,
+    panic_on_message: bool,
+    messages: Arc<AtomicUsize>,
+}
+
+impl MessageSubscriberPlugin {
+    fn new(name: &'static str, panic_on_message: bool) -> Self {
+        Self { name, panic_on_message, messages: Arc::default() }
+    }
+}
+
+impl EnginePlugin for MessageSubscriberPlugin {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn build(&mut self, ctx: &mut PluginContext<'_>) -> Result<()> {
+        let messages = Arc::clone(&self.messages);
+        let panic_on_message = self.panic_on_message;
+        ctx.subscribe_event(move |message: &ScriptMessage| {
+            messages.fetch_add(1, Ordering::SeqCst);
+            if panic_on_message {
+                panic!("subscriber panicked on '{}'", message.message);
+            }
+        })
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[derive(Component, Default)]
+struct Spin {
+    speed: f32,
+}
+
+struct SpinnerPlugin;
+
+impl EnginePlugin for SpinnerPlugin {
+    fn name(&self) -> &'static str {
+        "spinner"
+    }
+
+    fn build(&mut self, ctx: &mut PluginContext<'_>) -> Result<()> {
+        let key = ctx.register_component_inspector(
+            "spin",
+            vec![ComponentField::f32(
+                "speed",
+                -10.0..=10.0,
+                |spin: &Spin| spin.speed,
+                |spin, v| spin.speed = v,
+            )],
+        )?;
+        assert_eq!(key, "spinner.spin");
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+struct ScreenshotPlugin {
+    path: PathBuf,
+}
+
+impl EnginePlugin for ScreenshotPlugin {
+    fn name(&self) -> &'static str {
+        "screenshot_taker"
+    }
+
+    fn build(&mut self, ctx: &mut PluginContext<'_>) -> Result<()> {
+        ctx.request_screenshot(self.path.clone())?;
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+struct VoiceLimitPlugin {
+    count: usize,
+}
+
+impl EnginePlugin for VoiceLimitPlugin {
+    fn name(&self) -> &'static str {
+        "voice_limit"
+    }
+
+    fn build(&mut self, ctx: &mut PluginContext<'_>) -> Result<()> {
+        ctx.audio_mut()?.set_voice_count(self.count);
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+struct SceneTagPlugin;
+
+impl EnginePlugin for SceneTagPlugin {
+    fn name(&self) -> &'static str {
+        "scene_tag"
+    }
+
+    fn build(&mut self, ctx: &mut PluginContext<'_>) -> Result<()> {
+        ctx.set_scene_custom_metadata("scene_tag.level", json!({ "name": "intro", "par": 3 }));
+        Ok(())
+    }
+
+    fn update(&mut self, ctx: &mut PluginContext<'_>, _dt: f32) -> Result<()> {
+        let updates =
+            ctx.scene_custom_metadata("scene_tag.updates").and_then(|value| value.as_u64()).unwrap_or(0);
+        ctx.set_scene_custom_metadata("scene_tag.updates", json!(updates + 1));
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
 #[derive(Default)]
 struct RendererAccessPlugin;
 
@@ -249,10 +495,583 @@ fn plugins_receive_lifecycle_hooks() {
         manager.fixed_update(&mut ctx, 1.0 / 60.0);
     }
 
-    let events = vec![
-        GameEvent::ScriptMessage { message: "hello".to_string() },
-        GameEvent::ScriptMessage { message: "world".to_string() },
-    ];
+    let events = vec![
+        GameEvent::ScriptMessage { message: "hello".to_string() },
+        GameEvent::ScriptMessage { message: "world".to_string() },
+    ];
+    {
+        let mut ctx = PluginContext::new(
+            &mut renderer,
+            &mut ecs,
+            &mut assets,
+            &mut input,
+            &mut material_registry,
+            &mut mesh_registry,
+            &mut environment_registry,
+            &time,
+            push_event_bridge,
+            manager.feature_handle(),
+            None,
+            manager.capability_tracker_handle(),
+        );
+        manager.handle_events(&mut ctx, &events);
+    }
+
+    {
+        let mut ctx = PluginContext::new(
+            &mut renderer,
+            &mut ecs,
+            &mut assets,
+            &mut input,
+            &mut material_registry,
+            &mut mesh_registry,
+            &mut environment_registry,
+            &time,
+            push_event_bridge,
+            manager.feature_handle(),
+            None,
+            manager.capability_tracker_handle(),
+        );
+        manager.shutdown(&mut ctx);
+    }
+
+    let plugin = manager.get::<CountingPlugin>().expect("plugin stored");
+    assert_eq!(plugin.build_calls, 1);
+    assert_eq!(plugin.update_calls, 1);
+    assert_eq!(plugin.fixed_calls, 1);
+    assert_eq!(plugin.shutdown_calls, 1);
+    assert_eq!(plugin.update_dts, vec![0.5]);
+    assert_eq!(plugin.event_batches, vec![2]);
+}
+
+#[test]
+fn plugins_can_publish_features() {
+    let mut renderer = block_on(Renderer::new(&WindowConfig::default()));
+    let mut ecs = EcsWorld::new();
+    let mut assets = AssetManager::new();
+    let mut input = Input::new();
+    let mut material_registry = MaterialRegistry::new();
+    let mut mesh_registry = MeshRegistry::new(&mut material_registry);
+    let mut environment_registry = EnvironmentRegistry::new();
+    let time = Time::new();
+    let mut manager = PluginManager::default();
+
+    {
+        let mut ctx = PluginContext::new(
+            &mut renderer,
+            &mut ecs,
+            &mut assets,
+            &mut input,
+            &mut material_registry,
+            &mut mesh_registry,
+            &mut environment_registry,
+            &time,
+            push_event_bridge,
+            manager.feature_handle(),
+            None,
+            manager.capability_tracker_handle(),
+        );
+        manager.register(Box::new(FeaturePublishingPlugin), &mut ctx).expect("feature plugin registers");
+    }
+
+    let features: Vec<String> = manager.feature_handle().borrow().all().cloned().collect();
+    assert!(
+        features.iter().any(|feature| feature == "test.feature"),
+        "feature registry tracks plugin-provided capabilities"
+    );
+}
+
+#[test]
+fn plugin_asset_reload_requests_reach_the_manager_queue() {
+    let mut renderer = block_on(Renderer::new(&WindowConfig::default()));
+    let mut ecs = EcsWorld::new();
+    let mut assets = AssetManager::new();
+    let mut input = Input::new();
+    let mut material_registry = MaterialRegistry::new();
+    let mut mesh_registry = MeshRegistry::new(&mut material_registry);
+    let mut environment_registry = EnvironmentRegistry::new();
+    let time = Time::new();
+    let mut manager = PluginManager::default();
+
+    {
+        let mut ctx = PluginContext::new(
+            &mut renderer,
+            &mut ecs,
+            &mut assets,
+            &mut input,
+            &mut material_registry,
+            &mut mesh_registry,
+            &mut environment_registry,
+            &time,
+            push_event_bridge,
+            manager.feature_handle(),
+            None,
+            manager.capability_tracker_handle(),
+        )
+        .with_asset_reload_queue(manager.asset_reload_handle());
+        manager.register(Box::new(AtlasWritingPlugin), &mut ctx).expect("atlas plugin registers");
+    }
+
+    assert_eq!(
+        manager.drain_asset_reload_requests(),
+        vec![
+            AssetReloadRequest { key: "generated".to_string(), kind: AssetKind::Atlas },
+            AssetReloadRequest { key: "generated".to_string(), kind: AssetKind::Clip },
+        ]
+    );
+    assert!(manager.drain_asset_reload_requests().is_empty());
+
+    let mut detached = PluginContext::new(
+        &mut renderer,
+        &mut ecs,
+        &mut assets,
+        &mut input,
+        &mut material_registry,
+        &mut mesh_registry,
+        &mut environment_registry,
+        &time,
+        push_event_bridge,
+        manager.feature_handle(),
+        None,
+        manager.capability_tracker_handle(),
+    );
+    assert!(
+        detached.request_asset_reload("generated", AssetKind::Atlas).is_err(),
+        "hosts without a reload queue reject requests instead of dropping them"
+    );
+}
+
+#[test]
+fn plugin_component_is_editable_and_saved_with_the_scene() {
+    let mut renderer = block_on(Renderer::new(&WindowConfig::default()));
+    let mut ecs = EcsWorld::new();
+    let mut assets = AssetManager::new();
+    let mut input = Input::new();
+    let mut material_registry = MaterialRegistry::new();
+    let mut mesh_registry = MeshRegistry::new(&mut material_registry);
+    let mut environment_registry = EnvironmentRegistry::new();
+    let time = Time::new();
+    let mut manager = PluginManager::default();
+
+    {
+        let mut ctx = PluginContext::new(
+            &mut renderer,
+            &mut ecs,
+            &mut assets,
+            &mut input,
+            &mut material_registry,
+            &mut mesh_registry,
+            &mut environment_registry,
+            &time,
+            push_event_bridge,
+            manager.feature_handle(),
+            None,
+            manager.capability_tracker_handle(),
+        );
+        manager.register(Box::new(SpinnerPlugin), &mut ctx).expect("spinner plugin registers");
+    }
+
+    let entity = ecs.world.spawn((Transform::default(), WorldTransform::default(), Spin { speed: 1.0 })).id();
+    let views = ecs.plugin_components(entity);
+    assert_eq!(views.len(), 1);
+    assert_eq!(views[0].key, "spinner.spin");
+    assert_eq!(views[0].fields[0].value, ComponentFieldValue::F32(1.0));
+
+    // Inspector edits go through the registered setter and are clamped to the slider range.
+    assert!(ecs.set_plugin_component_field(entity, "spinner.spin", "speed", ComponentFieldValue::F32(4.5)));
+    assert_eq!(ecs.world.get::<Spin>(entity).expect("spin").speed, 4.5);
+    assert!(ecs.set_plugin_component_field(entity, "spinner.spin", "speed", ComponentFieldValue::F32(25.0)));
+    assert_eq!(ecs.world.get::<Spin>(entity).expect("spin").speed, 10.0);
+    assert!(!ecs.set_plugin_component_field(
+        entity,
+        "spinner.spin",
+        "speed",
+        ComponentFieldValue::Bool(true)
+    ));
+    assert!(ecs.set_plugin_component_field(entity, "spinner.spin", "speed", ComponentFieldValue::F32(4.5)));
+
+    let dir = tempdir().expect("temp dir");
+    let scene_path = dir.path().join("spin.json");
+    ecs.save_scene_to_path(&scene_path, &assets).expect("save scene");
+    let scene_id = ecs.world.get::<SceneEntityTag>(entity).expect("scene tag").id.clone();
+
+    // Without the plugin the component survives as an opaque blob...
+    let mut plain = EcsWorld::new();
+    plain.load_scene_from_path(&scene_path, &mut AssetManager::new()).expect("load without plugin");
+    let restored = plain.find_entity_by_scene_id(scene_id.as_str()).expect("entity restored");
+    assert!(plain.world.get::<Spin>(restored).is_none());
+    assert!(plain.blob(restored, "spinner.spin").is_some());
+    let resaved = dir.path().join("resaved.json");
+    plain.save_scene_to_path(&resaved, &AssetManager::new()).expect("resave scene");
+
+    // ...and becomes a live component again once the plugin registers.
+    let mut reloaded = EcsWorld::new();
+    reloaded.load_scene_from_path(&resaved, &mut AssetManager::new()).expect("reload");
+    let restored = reloaded.find_entity_by_scene_id(scene_id.as_str()).expect("entity restored");
+    reloaded.register_component_inspector(
+        "spinner.spin",
+        vec![ComponentField::f32("speed", -10.0..=10.0, |spin: &Spin| spin.speed, |spin, v| spin.speed = v)],
+    );
+    assert_eq!(reloaded.world.get::<Spin>(restored).expect("spin materialized").speed, 4.5);
+    assert!(reloaded.blob(restored, "spinner.spin").is_none(), "live components aren't duplicated as blobs");
+    let scene = reloaded.export_scene(&AssetManager::new());
+    let saved = scene.entities.iter().find(|e| e.id == scene_id).expect("entity exported");
+    let json: serde_json::Value = serde_json::from_slice(&saved.plugin_data["spinner.spin"]).expect("json");
+    assert_eq!(json, json!({ "speed": 4.5 }));
+}
+
+#[test]
+fn plugins_subscribe_to_events_by_type() {
+    let mut renderer = block_on(Renderer::new(&WindowConfig::default()));
+    let mut ecs = EcsWorld::new();
+    let mut assets = AssetManager::new();
+    let mut input = Input::new();
+    let mut material_registry = MaterialRegistry::new();
+    let mut mesh_registry = MeshRegistry::new(&mut material_registry);
+    let mut environment_registry = EnvironmentRegistry::new();
+    let time = Time::new();
+    let mut manager = PluginManager::default();
+    let a = ecs.world.spawn_empty().id();
+    let b = ecs.world.spawn_empty().id();
+    let listener = CollisionListenerPlugin::default();
+    let (collisions, events, pings) =
+        (Arc::clone(&listener.collisions), Arc::clone(&listener.events), Arc::clone(&listener.pings));
+
+    let mut ctx = PluginContext::new(
+        &mut renderer,
+        &mut ecs,
+        &mut assets,
+        &mut input,
+        &mut material_registry,
+        &mut mesh_registry,
+        &mut environment_registry,
+        &time,
+        push_event_bridge,
+        manager.feature_handle(),
+        None,
+        manager.capability_tracker_handle(),
+    );
+    manager.register(Box::new(listener), &mut ctx).expect("listener registers");
+    assert_eq!(pings.load(Ordering::SeqCst), 3, "custom event types are delivered on publish");
+
+    manager.handle_events(
+        &mut ctx,
+        &[GameEvent::collision_started(a, b), GameEvent::ScriptMessage { message: "hi".to_string() }],
+    );
+    assert_eq!(collisions.load(Ordering::SeqCst), 1, "only the collision variant reaches its subscriber");
+    assert_eq!(events.load(Ordering::SeqCst), 2, "GameEvent subscribers see every event");
+}
+
+#[test]
+fn panicking_event_subscriber_quarantines_its_plugin() {
+    let mut renderer = block_on(Renderer::new(&WindowConfig::default()));
+    let mut ecs = EcsWorld::new();
+    let mut assets = AssetManager::new();
+    let mut input = Input::new();
+    let mut material_registry = MaterialRegistry::new();
+    let mut mesh_registry = MeshRegistry::new(&mut material_registry);
+    let mut environment_registry = EnvironmentRegistry::new();
+    let time = Time::new();
+    let mut manager = PluginManager::default();
+    let panicker = MessageSubscriberPlugin::new("panicking_subscriber", true);
+    let healthy = MessageSubscriberPlugin::new("healthy_subscriber", false);
+    let (panicked, delivered) = (Arc::clone(&panicker.messages), Arc::clone(&healthy.messages));
+
+    let mut ctx = PluginContext::new(
+        &mut renderer,
+        &mut ecs,
+        &mut assets,
+        &mut input,
+        &mut material_registry,
+        &mut mesh_registry,
+        &mut environment_registry,
+        &time,
+        push_event_bridge,
+        manager.feature_handle(),
+        None,
+        manager.capability_tracker_handle(),
+    );
+    manager.register(Box::new(panicker), &mut ctx).expect("panicking subscriber registers");
+    manager.register(Box::new(healthy), &mut ctx).expect("healthy subscriber registers");
+    for plugin in ["panicking_subscriber", "healthy_subscriber"] {
+        ctx.set_active_plugin(plugin, CapabilityFlags::all(), PluginTrust::Full);
+        ctx.register_component_inspector(
+            "spin",
+            vec![ComponentField::<Spin>::bool("idle", |_| false, |_, _| {})],
+        )
+        .expect("built-in plugins register typed components");
+        ctx.clear_active_plugin();
+    }
+    let events = vec![GameEvent::ScriptMessage { message: "boom".to_string() }];
+
+    manager.handle_events(&mut ctx, &events);
+    manager.handle_events(&mut ctx, &events);
+    assert_eq!(panicked.load(Ordering::SeqCst), 1, "quarantined plugins stop receiving events");
+    assert_eq!(delivered.load(Ordering::SeqCst), 2, "other subscribers keep receiving events");
+    let status =
+        manager.statuses().iter().find(|status| status.name == "panicking_subscriber").expect("status");
+    match &status.state {
+        PluginState::Quarantined(quarantine) => {
+            assert!(quarantine.reason.contains("subscriber panicked on 'boom'"), "{}", quarantine.reason)
+        }
+        other => panic!("expected quarantined status, got {other:?}"),
+    }
+    drop(ctx);
+    let bus = ecs.world.resource::<EventBus>();
+    assert_eq!(bus.owner_subscriber_count("panicking_subscriber"), 0, "quarantine drops subscriptions");
+    assert_eq!(bus.owner_subscriber_count("healthy_subscriber"), 1);
+    let registry = ecs.plugin_component_registry();
+    assert!(!registry.contains("panicking_subscriber.spin"), "quarantine drops component registrations");
+    assert!(registry.contains("healthy_subscriber.spin"));
+}
+
+#[test]
+fn dynamic_plugins_cannot_register_typed_components() {
+    let mut renderer = block_on(Renderer::new(&WindowConfig::default()));
+    let mut ecs = EcsWorld::new();
+    let mut assets = AssetManager::new();
+    let mut input = Input::new();
+    let mut material_registry = MaterialRegistry::new();
+    let mut mesh_registry = MeshRegistry::new(&mut material_registry);
+    let mut environment_registry = EnvironmentRegistry::new();
+    let time = Time::new();
+    let manager = PluginManager::default();
+    let mut ctx = PluginContext::new(
+        &mut renderer,
+        &mut ecs,
+        &mut assets,
+        &mut input,
+        &mut material_registry,
+        &mut mesh_registry,
+        &mut environment_registry,
+        &time,
+        push_event_bridge,
+        manager.feature_handle(),
+        None,
+        manager.capability_tracker_handle(),
+    );
+    ctx.set_active_plugin("dyn", CapabilityFlags::all(), PluginTrust::Full);
+    ctx.set_active_dynamic(true);
+    let fields =
+        vec![ComponentField::f32("speed", -10.0..=10.0, |spin: &Spin| spin.speed, |spin, v| spin.speed = v)];
+    let err =
+        ctx.register_component_inspector("spin", fields).expect_err("typed accessors can't cross the ABI");
+    assert!(err.to_string().contains("register_dynamic_component_inspector"), "{err}");
+    let key = ctx
+        .register_dynamic_component_inspector(
+            "spin",
+            vec![ComponentFieldSchema::new("speed", ComponentFieldKind::Bool)],
+        )
+        .expect("blob-backed components are allowed");
+    ctx.clear_active_plugin();
+    drop(ctx);
+    assert_eq!(key, "dyn.spin");
+    assert_eq!(ecs.plugin_component_registry().keys().collect::<Vec<_>>(), ["dyn.spin"]);
+}
+
+#[test]
+fn event_subscriptions_follow_mute_and_solo() {
+    let mut renderer = block_on(Renderer::new(&WindowConfig::default()));
+    let mut ecs = EcsWorld::new();
+    let mut assets = AssetManager::new();
+    let mut input = Input::new();
+    let mut material_registry = MaterialRegistry::new();
+    let mut mesh_registry = MeshRegistry::new(&mut material_registry);
+    let mut environment_registry = EnvironmentRegistry::new();
+    let time = Time::new();
+    let mut manager = PluginManager::default();
+    let first = MessageSubscriberPlugin::new("first_subscriber", false);
+    let second = MessageSubscriberPlugin::new("second_subscriber", false);
+    let (first_count, second_count) = (Arc::clone(&first.messages), Arc::clone(&second.messages));
+
+    let mut ctx = PluginContext::new(
+        &mut renderer,
+        &mut ecs,
+        &mut assets,
+        &mut input,
+        &mut material_registry,
+        &mut mesh_registry,
+        &mut environment_registry,
+        &time,
+        push_event_bridge,
+        manager.feature_handle(),
+        None,
+        manager.capability_tracker_handle(),
+    );
+    manager.register(Box::new(first), &mut ctx).expect("first subscriber registers");
+    manager.register(Box::new(second), &mut ctx).expect("second subscriber registers");
+    let events = vec![GameEvent::ScriptMessage { message: "ping".to_string() }];
+
+    manager.set_plugin_muted("first_subscriber", true).expect("mute first");
+    manager.handle_events(&mut ctx, &events);
+    assert_eq!(first_count.load(Ordering::SeqCst), 0, "muted plugins' subscriptions are skipped");
+    assert_eq!(second_count.load(Ordering::SeqCst), 1);
+
+    manager.set_plugin_muted("first_subscriber", false).expect("unmute first");
+    manager.set_plugin_soloed("first_subscriber", true).expect("solo first");
+    manager.handle_events(&mut ctx, &events);
+    assert_eq!(first_count.load(Ordering::SeqCst), 1);
+    assert_eq!(second_count.load(Ordering::SeqCst), 1, "plugins outside the solo set are skipped");
+}
+
+#[test]
+fn dynamic_plugin_components_edit_their_json_blob() {
+    let mut ecs = EcsWorld::new();
+    let entity = ecs.world.spawn((Transform::default(), WorldTransform::default())).id();
+    assert!(ecs.register_dynamic_component_inspector(
+        "dyn.label",
+        vec![
+            ComponentFieldSchema::new("text", ComponentFieldKind::Text),
+            ComponentFieldSchema::new(
+                "mode",
+                ComponentFieldKind::Enum { options: vec!["plain".to_string(), "bold".to_string()] },
+            ),
+        ],
+    ));
+    assert!(ecs.plugin_components(entity).is_empty(), "the component exists once its blob does");
+
+    assert!(ecs.insert_blob(entity, "dyn.label", br#"{"text":"hi"}"#.to_vec()));
+    let views = ecs.plugin_components(entity);
+    assert_eq!(views[0].fields[0].value, ComponentFieldValue::Text("hi".to_string()));
+    assert_eq!(views[0].fields[1].value, ComponentFieldValue::Enum(0), "missing fields use the default");
+
+    assert!(ecs.set_plugin_component_field(entity, "dyn.label", "mode", ComponentFieldValue::Enum(1)));
+    let json: serde_json::Value =
+        serde_json::from_slice(ecs.blob(entity, "dyn.label").expect("blob")).expect("json");
+    assert_eq!(json, json!({ "text": "hi", "mode": "bold" }));
+}
+
+#[test]
+fn asset_reload_requests_are_rate_limited_per_key() {
+    let queue = AssetReloadQueueHandle::isolated();
+    let start = Instant::now();
+    assert!(queue.request("hero", AssetKind::Skeleton, start));
+    assert!(!queue.request("hero", AssetKind::Skeleton, start + Duration::from_millis(500)));
+    assert!(queue.request("villain", AssetKind::Skeleton, start + Duration::from_millis(500)));
+    assert!(queue.request("hero", AssetKind::Skeleton, start + ASSET_RELOAD_MIN_INTERVAL));
+    assert_eq!(queue.drain().len(), 3);
+}
+
+/// Registers a plugin that requests a screenshot, renders headless frames until the PNG appears,
+/// and checks the written image and its completion event cover the whole `width` x `height` frame.
+fn assert_plugin_screenshot_covers_the_frame(width: u32, height: u32, render_scale: f32) {
+    let dir = tempdir().expect("temp dir");
+    let path = dir.path().join("shots").join("capture.png");
+    let window_config = WindowConfig {
+        title: "Headless".into(),
+        width,
+        height,
+        vsync: false,
+        fullscreen: false,
+        render_scale,
+    };
+    let mut renderer = block_on(Renderer::new(&window_config));
+    block_on(renderer.init_headless_for_test()).expect("init headless");
+    let (sampler, atlas_view) = {
+        let device = renderer.device().expect("device");
+        let atlas_texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Test Atlas"),
+            size: wgpu::Extent3d { width: 1, height: 1, depth_or_array_layers: 1 },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8UnormSrgb,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+            view_formats: &[],
+        });
+        let atlas_view = atlas_texture.create_view(&wgpu::TextureViewDescriptor::default());
+        (device.create_sampler(&wgpu::SamplerDescriptor::default()), atlas_view)
+    };
+    renderer.init_sprite_pipeline_with_atlas(atlas_view, sampler.clone()).expect("init sprite pipeline");
+    renderer.prepare_headless_render_target().expect("headless target");
+    let viewport = RenderViewport { origin: (0.0, 0.0), size: (width as f32, height as f32) };
+
+    let mut ecs = EcsWorld::new();
+    let mut assets = AssetManager::new();
+    let mut input = Input::new();
+    let mut material_registry = MaterialRegistry::new();
+    let mut mesh_registry = MeshRegistry::new(&mut material_registry);
+    let mut environment_registry = EnvironmentRegistry::new();
+    let time = Time::new();
+    let mut manager = PluginManager::default();
+    macro_rules! plugin_context {
+        () => {
+            PluginContext::new(
+                &mut renderer,
+                &mut ecs,
+                &mut assets,
+                &mut input,
+                &mut material_registry,
+                &mut mesh_registry,
+                &mut environment_registry,
+                &time,
+                push_event_bridge,
+                manager.feature_handle(),
+                None,
+                manager.capability_tracker_handle(),
+            )
+            .with_screenshot_requests(manager.screenshot_requests_handle())
+        };
+    }
+
+    {
+        let mut ctx = plugin_context!();
+        manager
+            .register_with_capabilities(
+                Box::new(ScreenshotPlugin { path: path.clone() }),
+                Vec::new(),
+                vec![PluginCapability::Screenshot],
+                &mut ctx,
+            )
+            .expect("declared screenshot capability");
+    }
+    for _ in 0..64 {
+        let (frame, _) =
+            renderer.render_frame(&[], &[], &sampler, Mat4::IDENTITY, viewport, &[], None).expect("render");
+        frame.present();
+        manager.update(&mut plugin_context!(), 0.016);
+        if path.exists() {
+            break;
+        }
+        std::thread::sleep(Duration::from_millis(5));
+    }
+
+    let image = image::open(&path).expect("screenshot written");
+    assert_eq!((image.width(), image.height()), (width, height));
+    let events = manager.drain_asset_readback_events();
+    let event = events.iter().find(|event| event.kind == "screenshot").expect("completion event");
+    assert_eq!(event.plugin, "screenshot_taker");
+    assert_eq!(event.target, path.display().to_string());
+    assert_eq!(event.bytes, width as u64 * height as u64 * 4);
+    let violations = manager.capability_metrics().get("screenshot_taker").map_or(0, |log| log.count);
+    assert_eq!(violations, 0, "screenshots are exempt from the readback byte budget");
+}
+
+#[test]
+fn plugin_screenshot_is_written_after_the_frame_renders() {
+    assert_plugin_screenshot_covers_the_frame(48, 32, 1.0);
+}
+
+#[test]
+fn plugin_screenshot_captures_a_full_hd_frame_at_any_render_scale() {
+    // 1920x1080 RGBA8 is well over READBACK_MAX_BYTES_PER_FRAME, and the scene renders into a
+    // half-size target that is blitted onto the frame before the capture.
+    assert_plugin_screenshot_covers_the_frame(1920, 1080, 0.5);
+}
+
+#[test]
+fn screenshot_capability_must_be_declared() {
+    let mut renderer = block_on(Renderer::new(&WindowConfig::default()));
+    let mut ecs = EcsWorld::new();
+    let mut assets = AssetManager::new();
+    let mut input = Input::new();
+    let mut material_registry = MaterialRegistry::new();
+    let mut mesh_registry = MeshRegistry::new(&mut material_registry);
+    let mut environment_registry = EnvironmentRegistry::new();
+    let time = Time::new();
+    let mut manager = PluginManager::default();
+    let dir = tempdir().expect("temp dir");
+    let path = dir.path().join("denied.png");
     {
         let mut ctx = PluginContext::new(
             &mut renderer,
@@ -268,9 +1087,35 @@ fn plugins_receive_lifecycle_hooks() {
             None,
             manager.capability_tracker_handle(),
         );
-        manager.handle_events(&mut ctx, &events);
+        // An empty list grants the default capabilities, which never include screenshots.
+        manager
+            .register_with_capabilities(
+                Box::new(ScreenshotPlugin { path: path.clone() }),
+                Vec::new(),
+                Vec::new(),
+                &mut ctx,
+            )
+            .expect_err("screenshot capability should be required");
     }
+    let metrics = manager.capability_metrics();
+    let log = metrics.get("screenshot_taker").expect("violation recorded");
+    assert_eq!(log.count, 1);
+    assert!(matches!(log.last_capability, Some(PluginCapability::Screenshot)));
+    assert!(!path.exists());
+}
 
+#[test]
+fn audio_requests_reach_the_host_handle() {
+    let mut renderer = block_on(Renderer::new(&WindowConfig::default()));
+    let mut ecs = EcsWorld::new();
+    let mut assets = AssetManager::new();
+    let mut input = Input::new();
+    let mut material_registry = MaterialRegistry::new();
+    let mut mesh_registry = MeshRegistry::new(&mut material_registry);
+    let mut environment_registry = EnvironmentRegistry::new();
+    let time = Time::new();
+    let mut manager = PluginManager::default();
+    let requests = manager.audio_requests_handle();
     {
         let mut ctx = PluginContext::new(
             &mut renderer,
@@ -285,21 +1130,23 @@ fn plugins_receive_lifecycle_hooks() {
             manager.feature_handle(),
             None,
             manager.capability_tracker_handle(),
-        );
-        manager.shutdown(&mut ctx);
+        )
+        .with_audio_requests(manager.audio_requests_handle());
+        manager
+            .register_with_capabilities(
+                Box::new(VoiceLimitPlugin { count: 48 }),
+                Vec::new(),
+                Vec::new(),
+                &mut ctx,
+            )
+            .expect("audio is a default capability");
     }
-
-    let plugin = manager.get::<CountingPlugin>().expect("plugin stored");
-    assert_eq!(plugin.build_calls, 1);
-    assert_eq!(plugin.update_calls, 1);
-    assert_eq!(plugin.fixed_calls, 1);
-    assert_eq!(plugin.shutdown_calls, 1);
-    assert_eq!(plugin.update_dts, vec![0.5]);
-    assert_eq!(plugin.event_batches, vec![2]);
+    assert_eq!(requests.take_voice_count(), Some(48));
+    assert_eq!(requests.take_voice_count(), None, "requests are consumed once");
 }
 
 #[test]
-fn plugins_can_publish_features() {
+fn scene_custom_metadata_set_by_plugins_round_trips_through_scene_json() {
     let mut renderer = block_on(Renderer::new(&WindowConfig::default()));
     let mut ecs = EcsWorld::new();
     let mut assets = AssetManager::new();
@@ -309,30 +1156,46 @@ fn plugins_can_publish_features() {
     let mut environment_registry = EnvironmentRegistry::new();
     let time = Time::new();
     let mut manager = PluginManager::default();
-
-    {
-        let mut ctx = PluginContext::new(
-            &mut renderer,
-            &mut ecs,
-            &mut assets,
-            &mut input,
-            &mut material_registry,
-            &mut mesh_registry,
-            &mut environment_registry,
-            &time,
-            push_event_bridge,
-            manager.feature_handle(),
-            None,
-            manager.capability_tracker_handle(),
-        );
-        manager.register(Box::new(FeaturePublishingPlugin), &mut ctx).expect("feature plugin registers");
+    let metadata = manager.scene_custom_metadata_handle();
+    macro_rules! plugin_context {
+        () => {
+            PluginContext::new(
+                &mut renderer,
+                &mut ecs,
+                &mut assets,
+                &mut input,
+                &mut material_registry,
+                &mut mesh_registry,
+                &mut environment_registry,
+                &time,
+                push_event_bridge,
+                manager.feature_handle(),
+                None,
+                manager.capability_tracker_handle(),
+            )
+            .with_scene_custom_metadata(manager.scene_custom_metadata_handle())
+        };
     }
 
-    let features: Vec<String> = manager.feature_handle().borrow().all().cloned().collect();
-    assert!(
-        features.iter().any(|feature| feature == "test.feature"),
-        "feature registry tracks plugin-provided capabilities"
-    );
+    manager.register(Box::new(SceneTagPlugin), &mut plugin_context!()).expect("register scene tag plugin");
+    manager.update(&mut plugin_context!(), 0.016);
+    manager.update(&mut plugin_context!(), 0.016);
+
+    let scene_metadata = SceneMetadata { custom: metadata.snapshot(), ..SceneMetadata::default() };
+    let saved = serde_json::to_value(&scene_metadata).expect("serialize metadata");
+    assert_eq!(saved["custom"]["scene_tag.level"], json!({ "name": "intro", "par": 3 }));
+    assert_eq!(saved["custom"]["scene_tag.updates"], json!(2));
+
+    metadata.replace(serde_json::Map::new());
+    assert!(plugin_context!().scene_custom_metadata("scene_tag.updates").is_none());
+    let loaded: SceneMetadata = serde_json::from_value(saved).expect("deserialize metadata");
+    metadata.replace(loaded.custom);
+    manager.update(&mut plugin_context!(), 0.016);
+    let ctx = plugin_context!();
+    assert_eq!(*ctx.scene_custom_metadata("scene_tag.updates").expect("restored counter"), json!(3));
+
+    let empty = serde_json::to_value(SceneMetadata::default()).expect("serialize default metadata");
+    assert!(empty.get("custom").is_none(), "empty custom metadata is omitted");
 }
 
 #[test]
@@ -451,6 +1314,76 @@ fn manifest_toggle_reports_missing_entries() {
     assert_eq!(outcome.missing, vec!["ghost".to_string()], "missing entry is reported");
 }
 
+#[derive(Debug, PartialEq, serde::Deserialize)]
+struct WatchdogKnobs {
+    sleep_ms: u64,
+    #[serde(default)]
+    force_panic: bool,
+}
+
+#[test]
+fn manifest_config_updates_persist_and_reach_the_plugin_context() {
+    let dir = tempdir().expect("temp dir created");
+    let manifest_path = dir.path().join("plugins.json");
+    let manifest_json = r#"
+{
+  "disable_builtins": [],
+  "plugins": [
+    { "name": "alpha", "path": "alpha.dll", "config": { "sleep_ms": 10 } }
+  ]
+}
+"#;
+    fs::write(&manifest_path, manifest_json).expect("manifest written");
+
+    let mut manifest =
+        PluginManager::load_manifest(&manifest_path).expect("manifest read").expect("manifest present");
+    assert_eq!(manifest.entries()[0].config, Some(json!({ "sleep_ms": 10 })));
+    let updates = vec![
+        ManifestConfigUpdate { name: "alpha".to_string(), config: Some(json!({ "sleep_ms": 250 })) },
+        ManifestConfigUpdate { name: "ghost".to_string(), config: None },
+    ];
+    let outcome = apply_manifest_config_updates(&mut manifest, &updates);
+    assert!(outcome.changed, "config change detected");
+    assert_eq!(outcome.updated, vec!["alpha".to_string()]);
+    assert_eq!(outcome.missing, vec!["ghost".to_string()]);
+    manifest.save().expect("manifest saved");
+    let reloaded =
+        PluginManager::load_manifest(&manifest_path).expect("reload ok").expect("manifest still present");
+    let config = reloaded.entries()[0].config.clone();
+    assert_eq!(config, Some(json!({ "sleep_ms": 250 })), "config persisted");
+
+    let mut renderer = block_on(Renderer::new(&WindowConfig::default()));
+    let mut ecs = EcsWorld::new();
+    let mut assets = AssetManager::new();
+    let mut input = Input::new();
+    let mut material_registry = MaterialRegistry::new();
+    let mut mesh_registry = MeshRegistry::new(&mut material_registry);
+    let mut environment_registry = EnvironmentRegistry::new();
+    let time = Time::new();
+    let manager = PluginManager::default();
+    let mut ctx = PluginContext::new(
+        &mut renderer,
+        &mut ecs,
+        &mut assets,
+        &mut input,
+        &mut material_registry,
+        &mut mesh_registry,
+        &mut environment_registry,
+        &time,
+        push_event_bridge,
+        manager.feature_handle(),
+        None,
+        manager.capability_tracker_handle(),
+    );
+    assert!(ctx.config_as::<WatchdogKnobs>().expect("no config is not an error").is_none());
+    ctx.set_active_config(config.map(Arc::new));
+    assert_eq!(ctx.config().and_then(|config| config.get("sleep_ms")), Some(&json!(250)));
+    let knobs = ctx.config_as::<WatchdogKnobs>().expect("config deserializes");
+    assert_eq!(knobs, Some(WatchdogKnobs { sleep_ms: 250, force_panic: false }));
+    ctx.set_active_config(Some(Arc::new(json!({ "sleep_ms": "slow" }))));
+    assert!(ctx.config_as::<WatchdogKnobs>().is_err(), "mismatched config reports an error");
+}
+
 #[test]
 fn manifest_builtin_toggle_updates_disable_list() {
     let dir = tempdir().expect("temp dir created");
@@ -949,6 +1882,107 @@ fn isolated_plugin_reload_cycle_does_not_accumulate_state() {
     }
 }
 
+#[test]
+fn unloading_dynamic_plugins_keeps_builtins_and_forgets_diagnostics() {
+    let _guard = isolated_test_guard();
+    let plugin_path = build_example_dynamic_plugin();
+    let manifest_dir = tempdir().expect("temp manifest dir");
+    let manifest_path = manifest_dir.path().join("plugins.json");
+    let manifest_json = json!({
+        "disable_builtins": [],
+        "plugins": [{
+            "name": "example_dynamic",
+            "path": plugin_path.to_string_lossy(),
+            "enabled": true,
+            "version": "0.1.0",
+            "requires_features": [],
+            "provides_features": [],
+            "capabilities": ["ecs","assets","events","time"],
+            "trust": "isolated"
+        }]
+    });
+    fs::write(&manifest_path, serde_json::to_string_pretty(&manifest_json).unwrap())
+        .expect("manifest written");
+    let manifest =
+        PluginManager::load_manifest(&manifest_path).expect("manifest read").expect("manifest present");
+
+    let mut renderer = block_on(Renderer::new(&WindowConfig::default()));
+    let mut ecs = EcsWorld::new();
+    let mut assets = AssetManager::new();
+    let mut input = Input::new();
+    let mut material_registry = MaterialRegistry::new();
+    let mut mesh_registry = MeshRegistry::new(&mut material_registry);
+    let mut environment_registry = EnvironmentRegistry::new();
+    let time = Time::new();
+    let mut manager = PluginManager::default();
+    let mut ctx = PluginContext::new(
+        &mut renderer,
+        &mut ecs,
+        &mut assets,
+        &mut input,
+        &mut material_registry,
+        &mut mesh_registry,
+        &mut environment_registry,
+        &time,
+        push_event_bridge,
+        manager.feature_handle(),
+        None,
+        manager.capability_tracker_handle(),
+    );
+    manager.register(Box::new(CountingPlugin::default()), &mut ctx).expect("builtin registers");
+    manager.load_dynamic_from_manifest(&manifest, &mut ctx).expect("manifest loads");
+    assert!(manager.is_plugin_loaded("example_dynamic"));
+    assert!(manager.capability_metrics().contains_key("example_dynamic"));
+
+    // A reload brings the plugin back, so its diagnostics are kept.
+    ctx.set_active_plugin("example_dynamic", CapabilityFlags::empty(), PluginTrust::Isolated);
+    assert!(ctx.renderer_mut().is_err());
+    ctx.clear_active_plugin();
+    manager.reload_dynamic_from_manifest(&manifest, &mut ctx).expect("manifest reloads");
+    assert!(manager.is_plugin_loaded("example_dynamic"));
+    assert_eq!(manager.capability_metrics().get("example_dynamic").map(|log| log.count), Some(1));
+
+    // Stands in for a subscription and an inspector registration left behind by the plugin;
+    // unloading must not keep either alive.
+    let host_ecs = ctx.ecs_mut().expect("host context has every capability");
+    host_ecs.world.resource_mut::<EventBus>().subscribe_as("example_dynamic", |_: &GameEvent| {});
+    host_ecs.register_dynamic_component_inspector(
+        "example_dynamic.label",
+        vec![ComponentFieldSchema::new("text", ComponentFieldKind::Text)],
+    );
+
+    let shutdown_failures = manager.unload_dynamic(&mut ctx);
+    assert!(shutdown_failures.is_empty(), "clean shutdown, got {shutdown_failures:?}");
+    assert!(!manager.is_plugin_loaded("example_dynamic"));
+    let bus = ctx.ecs().expect("host context has every capability").world.resource::<EventBus>();
+    assert_eq!(bus.owner_subscriber_count("example_dynamic"), 0, "unload drops the plugin's subscriptions");
+    let registry = ctx.ecs().expect("host context has every capability").plugin_component_registry();
+    assert!(!registry.contains("example_dynamic.label"), "unload drops the plugin's component registrations");
+    assert!(!manager.capability_metrics().contains_key("example_dynamic"));
+    assert!(!manager.watchdog_events().contains_key("example_dynamic"));
+    assert!(manager.statuses().iter().all(|status| !status.dynamic));
+
+    manager.update(&mut ctx, 0.016);
+    let counting = manager.get::<CountingPlugin>().expect("builtin still registered");
+    assert_eq!(counting.update_calls, 1, "builtins keep running after the unload");
+    assert_eq!(counting.shutdown_calls, 0);
+
+    // Reloading with the entry disabled removes the plugin, so its diagnostics go too.
+    manager.load_dynamic_from_manifest(&manifest, &mut ctx).expect("manifest loads again");
+    assert!(manager.capability_metrics().contains_key("example_dynamic"));
+    let mut disabled_json = manifest_json.clone();
+    disabled_json["plugins"][0]["enabled"] = json!(false);
+    fs::write(&manifest_path, serde_json::to_string_pretty(&disabled_json).unwrap())
+        .expect("manifest rewritten");
+    let disabled =
+        PluginManager::load_manifest(&manifest_path).expect("manifest read").expect("manifest present");
+    manager.reload_dynamic_from_manifest(&disabled, &mut ctx).expect("manifest reloads");
+    assert!(!manager.is_plugin_loaded("example_dynamic"));
+    assert!(!manager.capability_metrics().contains_key("example_dynamic"));
+
+    manager.shutdown(&mut ctx);
+}
+
 #[test]
 fn plugin_panic_marks_failure() {
     let mut renderer = block_on(Renderer::new(&WindowConfig::default()));
@@ -992,10 +2026,11 @@ fn plugin_panic_marks_failure() {
         .find(|status| status.name == "panicker")
         .expect("status for panicker plugin");
     match &status.state {
-        PluginState::Failed(reason) => {
-            assert!(reason.contains("panicked"), "failure state should mention panic cause: {reason}");
+        PluginState::Quarantined(quarantine) => {
+            let reason = &quarantine.reason;
+            assert!(reason.contains("panicked"), "quarantine should mention panic cause: {reason}");
         }
-        other => panic!("expected failed status, got {other:?}"),
+        other => panic!("expected quarantined status, got {other:?}"),
     }
     manager.shutdown(&mut ctx);
 }
@@ -1041,10 +2076,130 @@ fn plugin_panic_does_not_disrupt_other_plugins() {
         .find(|status| status.name == "panicker")
         .expect("status for panicker plugin");
     assert!(
-        matches!(panicker_status.state, PluginState::Failed(_)),
-        "panicking plugin should be marked failed"
+        matches!(panicker_status.state, PluginState::Quarantined(_)),
+        "panicking plugin should be quarantined"
+    );
+
+    manager.shutdown(&mut ctx);
+}
+
+#[test]
+fn plugin_panicking_mid_run_is_quarantined_until_reenabled() {
+    let mut renderer = block_on(Renderer::new(&WindowConfig::default()));
+    let mut ecs = EcsWorld::new();
+    let mut assets = AssetManager::new();
+    let mut input = Input::new();
+    let mut material_registry = MaterialRegistry::new();
+    let mut mesh_registry = MeshRegistry::new(&mut material_registry);
+    let mut environment_registry = EnvironmentRegistry::new();
+    let time = Time::new();
+    let mut manager = PluginManager::default();
+    let mut ctx = PluginContext::new(
+        &mut renderer,
+        &mut ecs,
+        &mut assets,
+        &mut input,
+        &mut material_registry,
+        &mut mesh_registry,
+        &mut environment_registry,
+        &time,
+        push_event_bridge,
+        manager.feature_handle(),
+        None,
+        manager.capability_tracker_handle(),
+    );
+
+    manager.register(Box::new(LatePanicPlugin::default()), &mut ctx).expect("register late panicker");
+    manager.register(Box::new(CountingPlugin::default()), &mut ctx).expect("register counter");
+    for _ in 0..150 {
+        manager.update(&mut ctx, 0.016);
+    }
+
+    assert_eq!(manager.get::<LatePanicPlugin>().expect("late panicker present").frames, 100);
+    assert_eq!(manager.get::<CountingPlugin>().expect("counter present").update_calls, 150);
+    let status = manager.statuses().iter().find(|status| status.name == "late_panicker").expect("status");
+    match &status.state {
+        PluginState::Quarantined(quarantine) => {
+            assert!(quarantine.reason.contains("late panic on frame 100"), "reason: {}", quarantine.reason);
+            assert!(quarantine.library_hash.is_none(), "built-in plugins have no library to hash");
+        }
+        other => panic!("expected quarantined status, got {other:?}"),
+    }
+    let watchdog = manager.drain_watchdog_events();
+    assert!(
+        watchdog.iter().any(|event| event.plugin == "late_panicker" && event.reason.contains("late panic")),
+        "panic should surface as a watchdog event"
+    );
+
+    manager.reenable_plugin("late_panicker").expect("re-enable quarantined plugin");
+    manager.update(&mut ctx, 0.016);
+    assert_eq!(manager.get::<LatePanicPlugin>().expect("late panicker present").frames, 101);
+    let status = manager.statuses().iter().find(|status| status.name == "late_panicker").expect("status");
+    assert!(matches!(status.state, PluginState::Loaded), "re-enabled plugin should be loaded again");
+    assert!(manager.reenable_plugin("counting").is_err(), "healthy plugins are not quarantined");
+
+    manager.shutdown(&mut ctx);
+}
+
+#[test]
+fn muted_and_unsoloed_plugins_are_skipped_until_restored() {
+    let mut renderer = block_on(Renderer::new(&WindowConfig::default()));
+    let mut ecs = EcsWorld::new();
+    let mut assets = AssetManager::new();
+    let mut input = Input::new();
+    let mut material_registry = MaterialRegistry::new();
+    let mut mesh_registry = MeshRegistry::new(&mut material_registry);
+    let mut environment_registry = EnvironmentRegistry::new();
+    let time = Time::new();
+    let mut manager = PluginManager::default();
+    let mut ctx = PluginContext::new(
+        &mut renderer,
+        &mut ecs,
+        &mut assets,
+        &mut input,
+        &mut material_registry,
+        &mut mesh_registry,
+        &mut environment_registry,
+        &time,
+        push_event_bridge,
+        manager.feature_handle(),
+        None,
+        manager.capability_tracker_handle(),
+    );
+    manager.register(Box::new(CountingPlugin::default()), &mut ctx).expect("register counter");
+    manager.register(Box::new(LatePanicPlugin::default()), &mut ctx).expect("register other plugin");
+    let events = vec![GameEvent::ScriptMessage { message: "ping".to_string() }];
+
+    manager.set_plugin_muted("counting", true).expect("mute counter");
+    for _ in 0..3 {
+        manager.update(&mut ctx, 0.016);
+        manager.fixed_update(&mut ctx, 1.0 / 60.0);
+        manager.handle_events(&mut ctx, &events);
+    }
+    let counter = manager.get::<CountingPlugin>().expect("counter present");
+    assert_eq!((counter.update_calls, counter.fixed_calls), (0, 0), "muted plugin must not be updated");
+    assert!(counter.event_batches.is_empty(), "muted plugin must not receive events");
+    assert_eq!(manager.get::<LatePanicPlugin>().expect("other present").frames, 3);
+    let status = manager.statuses().iter().find(|status| status.name == "counting").expect("status");
+    assert!(status.muted, "muted plugins stay listed with a muted flag");
+    assert!(matches!(status.state, PluginState::Loaded));
+    assert!(!manager.is_plugin_dispatched("counting"));
+
+    manager.set_plugin_muted("counting", false).expect("unmute counter");
+    manager.set_plugin_soloed("counting", true).expect("solo counter");
+    manager.update(&mut ctx, 0.016);
+    assert_eq!(manager.get::<CountingPlugin>().expect("counter present").update_calls, 1);
+    assert_eq!(
+        manager.get::<LatePanicPlugin>().expect("other present").frames,
+        3,
+        "plugins outside the solo set are skipped"
     );
 
+    manager.set_plugin_soloed("counting", false).expect("clear solo");
+    manager.update(&mut ctx, 0.016);
+    assert_eq!(manager.get::<LatePanicPlugin>().expect("other present").frames, 4);
+    assert!(manager.set_plugin_muted("missing", true).is_err());
+
     manager.shutdown(&mut ctx);
 }
 
@@ -1,10 +1,15 @@
 use glam::{Mat4, Quat, Vec2, Vec3, Vec4};
 use kestrel_engine::assets::AssetManager;
 use kestrel_engine::ecs::{
-    EcsWorld, PropertyTrackPlayer, Tint, Transform, Transform3D, TransformTrackPlayer, WorldTransform,
-    WorldTransform3D,
+    EcsWorld, PropertyTrackPlayer, Sprite, Tint, Transform, Transform3D, TransformTrackPlayer,
+    WorldTransform, WorldTransform3D,
 };
-use kestrel_engine::scene::Scene;
+use kestrel_engine::material_registry::MaterialRegistry;
+use kestrel_engine::mesh_registry::MeshRegistry;
+use kestrel_engine::prefab::{PrefabLibrary, PrefabOverrides, PrefabValidationKind};
+use kestrel_engine::scene::{Scene, SceneEntityId, SceneReferenceKind};
+use serde_json::json;
+use std::sync::Arc;
 
 #[test]
 fn prefab_export_and_instantiate_roundtrip() {
@@ -158,3 +163,137 @@ fn prefab_roundtrip_preserves_transform_clip_binding() {
     assert!((tint.y - 0.6).abs() < 1e-6);
     assert!((tint.z - 0.8).abs() < 1e-6);
 }
+
+#[test]
+fn serialized_sprite_entity_round_trips_with_fresh_scene_id() {
+    let mut ecs = EcsWorld::new();
+    let mut assets = AssetManager::new();
+    assets.retain_atlas("main", Some("assets/images/atlas.json")).expect("load main atlas");
+
+    let entity = ecs
+        .world
+        .spawn((
+            Transform { translation: Vec2::new(3.0, 1.0), rotation: 0.5, scale: Vec2::new(2.0, 1.5) },
+            WorldTransform::default(),
+            Sprite::uninitialized(Arc::from("main"), Arc::from("redorb")),
+            Tint(Vec4::new(0.25, 0.5, 0.75, 0.9)),
+        ))
+        .id();
+    assert!(ecs.set_sprite_timeline(entity, &assets, Some("demo_cycle")));
+    assert!(ecs.set_sprite_animation_speed(entity, 1.5));
+
+    let json = ecs.serialize_entity(entity, &assets).expect("serialize entity");
+    let copy = ecs.deserialize_entity(&json, &mut assets).expect("deserialize entity");
+    assert_ne!(copy, entity);
+
+    let original = ecs.entity_info(entity).expect("original info");
+    let pasted = ecs.entity_info(copy).expect("copy info");
+    assert_ne!(original.scene_id, pasted.scene_id, "paste gets a newly generated scene id");
+    assert_eq!(original.translation, pasted.translation);
+    assert_eq!(original.rotation, pasted.rotation);
+    assert_eq!(original.scale, pasted.scale);
+    assert_eq!(pasted.tint, Some(Vec4::new(0.25, 0.5, 0.75, 0.9)));
+
+    let original_sprite = original.sprite.expect("original sprite");
+    let pasted_sprite = pasted.sprite.expect("copy sprite");
+    assert_eq!(original_sprite.atlas, pasted_sprite.atlas);
+    assert_eq!(original_sprite.region, pasted_sprite.region);
+    let animation = pasted_sprite.animation.expect("copy keeps its animation");
+    assert_eq!(animation.timeline, "demo_cycle");
+    assert_eq!(animation.speed, 1.5);
+    assert!(animation.playing);
+
+    assert!(ecs.deserialize_entity("{}", &mut assets).is_err(), "empty scenes are rejected");
+    assert!(ecs.deserialize_entity("not json", &mut assets).is_err());
+}
+
+#[test]
+fn prefab_overrides_apply_to_the_spawned_root() {
+    let mut ecs = EcsWorld::new();
+    let assets = AssetManager::new();
+
+    let entity = ecs
+        .world
+        .spawn((
+            Transform { translation: Vec2::new(2.0, -4.0), rotation: 0.0, scale: Vec2::splat(1.0) },
+            WorldTransform::default(),
+        ))
+        .id();
+    let prefab = ecs.export_prefab(entity, &assets).expect("export prefab").with_fresh_entity_ids();
+
+    let mut overrides = PrefabOverrides::new();
+    overrides.insert("position".to_string(), json!([7.5, 3.0]));
+    overrides.insert("tint".to_string(), json!([1.0, 0.5, 0.25]));
+    overrides.insert("not_a_component".to_string(), json!(true));
+    let spawned =
+        ecs.instantiate_prefab_with_overrides(&prefab, &assets, &overrides).expect("instantiate prefab");
+    assert_eq!(spawned.len(), 1);
+
+    ecs.update(0.0);
+    let info = ecs.entity_info(spawned[0]).expect("spawned info");
+    assert!((info.translation - Vec2::new(7.5, 3.0)).length() < 1e-4, "position override applies");
+    assert_eq!(info.tint, Some(Vec4::new(1.0, 0.5, 0.25, 1.0)));
+
+    let warnings = ecs.apply_prefab_overrides(spawned[0], &overrides);
+    assert_eq!(warnings.len(), 1, "only the unknown key warns: {warnings:?}");
+    assert!(warnings[0].contains("not_a_component"));
+}
+
+#[test]
+fn validate_all_reports_unresolved_prefab_references() {
+    let mut ecs = EcsWorld::new();
+    let mut assets = AssetManager::new();
+    assets.retain_atlas("main", Some("assets/images/atlas.json")).expect("load main atlas");
+    let mut materials = MaterialRegistry::new();
+    let meshes = MeshRegistry::new(&mut materials);
+    let mesh_key = meshes.default_key().to_string();
+
+    let sprite = ecs
+        .world
+        .spawn((
+            Transform { translation: Vec2::ZERO, rotation: 0.0, scale: Vec2::ONE },
+            WorldTransform::default(),
+            Sprite::uninitialized(Arc::from("main"), Arc::from("redorb")),
+        ))
+        .id();
+    let sprite_prefab = ecs.export_prefab(sprite, &assets).expect("export sprite prefab");
+    let mesh = ecs.spawn_mesh_entity(&mesh_key, Vec3::ZERO, Vec3::ONE);
+    let mesh_prefab = ecs.export_prefab(mesh, &assets).expect("export mesh prefab");
+
+    let temp_dir = tempfile::tempdir().expect("temp dir");
+    let root = temp_dir.path();
+    sprite_prefab.save_to_path(root.join("orb.json")).expect("save valid sprite prefab");
+    mesh_prefab.save_to_path(root.join("block.json")).expect("save valid mesh prefab");
+
+    let mut renamed_atlas = sprite_prefab.clone();
+    renamed_atlas.entities[0].sprite.as_mut().unwrap().atlas = "legacy_main".to_string();
+    renamed_atlas.save_to_path(root.join("renamed_atlas.json")).expect("save renamed atlas prefab");
+    let mut missing_region = sprite_prefab.clone();
+    missing_region.entities[0].sprite.as_mut().unwrap().region = "no_such_region".to_string();
+    missing_region.save_to_path(root.join("missing_region.json")).expect("save missing region prefab");
+    let mut missing_mesh = mesh_prefab.clone();
+    missing_mesh.entities[0].mesh.as_mut().unwrap().key = "ghost_mesh".to_string();
+    missing_mesh.save_to_path(root.join("missing_mesh.json")).expect("save missing mesh prefab");
+    let mut orphan = mesh_prefab.clone();
+    orphan.entities[0].parent_id = Some(SceneEntityId::sequential(99));
+    orphan.save_to_path(root.join("orphan.json")).expect("save orphan prefab");
+    std::fs::write(root.join("broken.json"), "not json").expect("write broken prefab");
+
+    let mut library = PrefabLibrary::new(root);
+    library.refresh().expect("refresh library");
+    let errors = library.validate_all(&assets, &meshes);
+    let mut found: Vec<(&str, PrefabValidationKind, &str)> =
+        errors.iter().map(|error| (error.prefab.as_str(), error.kind, error.target.as_str())).collect();
+    found.sort_by(|a, b| a.0.cmp(b.0));
+
+    assert_eq!(found.len(), 5, "only the broken prefabs are reported: {errors:?}");
+    assert_eq!(found[0].0, "broken");
+    assert_eq!(found[0].1, PrefabValidationKind::Load);
+    assert_eq!(found[1], ("missing_mesh", PrefabValidationKind::Mesh, "ghost_mesh"));
+    assert_eq!(found[2], ("missing_region", PrefabValidationKind::AtlasRegion, "main/no_such_region"));
+    assert_eq!(found[3], ("orphan", PrefabValidationKind::Entity(SceneReferenceKind::Parent), "entity-99"));
+    assert_eq!(found[4], ("renamed_atlas", PrefabValidationKind::Atlas, "legacy_main"));
+    assert!(errors.iter().all(|error| error.kind == PrefabValidationKind::Load || error.source.is_some()));
+    let message = errors.iter().find(|error| error.prefab == "renamed_atlas").unwrap().to_string();
+    assert!(message.contains("missing atlas 'legacy_main'"), "{message}");
+}
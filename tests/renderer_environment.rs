@@ -10,6 +10,7 @@ fn renderer_binds_environment_textures() {
         height: 64,
         vsync: false,
         fullscreen: false,
+        render_scale: 1.0,
     };
     let mut renderer = pollster::block_on(Renderer::new(&window_config));
     pollster::block_on(renderer.init_headless_for_test()).expect("headless init");
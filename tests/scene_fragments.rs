@@ -0,0 +1,138 @@
+use glam::Vec2;
+use kestrel_engine::assets::AssetManager;
+use kestrel_engine::ecs::{EcsWorld, SceneEntityTag, Sprite, Transform, WorldTransform};
+use kestrel_engine::scene::{Scene, SceneDependencies, SceneEntityId};
+use std::sync::Arc;
+
+fn spawn_sprite(
+    world: &mut EcsWorld,
+    translation: Vec2,
+    atlas: &str,
+    region: &str,
+) -> bevy_ecs::prelude::Entity {
+    world
+        .world
+        .spawn((
+            Transform { translation, rotation: 0.0, scale: Vec2::ONE },
+            WorldTransform::default(),
+            Sprite::uninitialized(Arc::from(atlas), Arc::from(region)),
+        ))
+        .id()
+}
+
+fn scene_id(world: &EcsWorld, entity: bevy_ecs::prelude::Entity) -> SceneEntityId {
+    world.world.get::<SceneEntityTag>(entity).expect("exported entity is tagged").id.clone()
+}
+
+fn fixture_assets() -> AssetManager {
+    let mut assets = AssetManager::new();
+    assets.retain_atlas("main", Some("assets/images/atlas.json")).expect("load main atlas");
+    assets.retain_atlas("slime", Some("assets/images/slime_idle_atlas.json")).expect("load slime atlas");
+    assets
+}
+
+#[test]
+fn export_selection_keeps_only_needed_dependencies_and_bakes_detached_roots() {
+    let assets = fixture_assets();
+    let mut world = EcsWorld::new();
+    let building = spawn_sprite(&mut world, Vec2::new(10.0, 4.0), "main", "redorb");
+    let door = spawn_sprite(&mut world, Vec2::new(1.0, -1.0), "main", "redorb");
+    let slime = spawn_sprite(&mut world, Vec2::new(-5.0, 0.0), "slime", "slime_idle_0");
+    world.set_parent(door, building).expect("parent door");
+    world.update(0.0);
+
+    let fragment = world
+        .export_selection_with_sources(&[building], true, &assets, |_| None, |_| None)
+        .expect("export building");
+    assert_eq!(fragment.entities.len(), 2, "children are included on request");
+    let atlases: Vec<String> =
+        fragment.dependencies.atlas_dependencies().map(|dep| dep.key().to_string()).collect();
+    assert_eq!(atlases, vec!["main"], "unselected entities do not contribute dependencies");
+    assert_eq!(fragment.entities[1].parent, Some(0));
+    assert_eq!(fragment.entities[1].parent_id.as_ref(), Some(&scene_id(&world, building)));
+
+    let roots_only = world
+        .export_selection_with_sources(&[building, slime], false, &assets, |_| None, |_| None)
+        .expect("export without children");
+    assert_eq!(roots_only.entities.len(), 2);
+    assert!(roots_only.entities.iter().all(|entity| entity.id != scene_id(&world, door)));
+
+    let door_only = world
+        .export_selection_with_sources(&[door], false, &assets, |_| None, |_| None)
+        .expect("export child");
+    let root = &door_only.entities[0];
+    assert!(root.is_root(), "a child exported without its parent becomes a root");
+    let translation: Vec2 = root.transform.translation.clone().into();
+    assert!((translation - Vec2::new(11.0, 3.0)).length() < 1e-5, "world transform is baked in");
+}
+
+#[test]
+fn import_scene_remaps_colliding_ids_and_places_roots_at_position() {
+    let mut assets = fixture_assets();
+    let mut world = EcsWorld::new();
+    let building = spawn_sprite(&mut world, Vec2::new(10.0, 4.0), "main", "redorb");
+    let door = spawn_sprite(&mut world, Vec2::new(1.0, -1.0), "main", "redorb");
+    world.set_parent(door, building).expect("parent door");
+    world.update(0.0);
+    let fragment = world
+        .export_selection_with_sources(&[building], true, &assets, |_| None, |_| None)
+        .expect("export building");
+    let temp_dir = tempfile::tempdir().expect("temp dir");
+    let path = temp_dir.path().join("building_a.json");
+    fragment.save_to_path(&path).expect("save fragment");
+    let fragment = Scene::load_from_path(&path).expect("load fragment");
+
+    let spawned = world
+        .import_scene_with_mesh(&fragment, Some(Vec2::new(-3.0, 2.0)), &mut assets, |_, _| Ok(()))
+        .expect("import fragment into the same world");
+    assert_eq!(spawned.len(), 2);
+    world.update(0.0);
+
+    let original_ids = [scene_id(&world, building), scene_id(&world, door)];
+    let imported_ids: Vec<SceneEntityId> = spawned.iter().map(|&entity| scene_id(&world, entity)).collect();
+    for id in &imported_ids {
+        assert!(!original_ids.contains(id), "colliding id {} is remapped", id.as_str());
+    }
+    assert_eq!(world.get_parent(spawned[1]), Some(spawned[0]), "parent link follows the remap");
+    let root = world.entity_info(spawned[0]).expect("imported root");
+    let child = world.entity_info(spawned[1]).expect("imported child");
+    assert!((root.translation - Vec2::new(-3.0, 2.0)).length() < 1e-5);
+    assert!((child.translation - Vec2::new(-2.0, 1.0)).length() < 1e-5, "children stay parent-relative");
+
+    let mut empty = EcsWorld::new();
+    let spawned =
+        empty.import_scene_with_mesh(&fragment, None, &mut assets, |_, _| Ok(())).expect("import at origin");
+    empty.update(0.0);
+    assert_eq!(scene_id(&empty, spawned[0]), fragment.entities[0].id, "free ids are kept");
+    assert!(empty.entity_info(spawned[0]).expect("root").translation.length() < 1e-5);
+}
+
+#[test]
+fn import_scene_reports_conflicting_atlas_paths() {
+    let mut assets = fixture_assets();
+    let mut source = EcsWorld::new();
+    spawn_sprite(&mut source, Vec2::ZERO, "main", "redorb");
+    let mut fragment = source.export_scene(&assets);
+    let mut other_assets = AssetManager::new();
+    other_assets
+        .retain_atlas("main", Some("assets/images/slime_idle_atlas.json"))
+        .expect("load conflicting atlas");
+    fragment.dependencies =
+        SceneDependencies::from_entities(&fragment.entities, &other_assets, |_| None, |_| None);
+
+    let mut world = EcsWorld::new();
+    let err = world
+        .import_scene_with_mesh(&fragment, None, &mut assets, |_, _| Ok(()))
+        .expect_err("conflicting atlas keys are rejected");
+    let message = err.to_string();
+    assert!(message.contains("'main'"), "{message}");
+    assert!(message.contains("slime_idle_atlas.json"), "{message}");
+    assert_eq!(world.entity_count(), 0, "nothing is spawned when the import is rejected");
+
+    let mut merged = SceneDependencies::from_entities(&fragment.entities, &assets, |_| None, |_| None);
+    assert_eq!(merged.atlas_conflicts(|key| other_assets.atlas_source(key).map(str::to_string)).len(), 1);
+    merged.merge(&fragment.dependencies);
+    let paths: Vec<Option<String>> =
+        merged.atlas_dependencies().map(|dep| dep.path().map(str::to_string)).collect();
+    assert_eq!(paths, vec![Some("assets/images/atlas.json".to_string())], "merge keeps the existing path");
+}
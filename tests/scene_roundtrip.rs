@@ -2,16 +2,18 @@ use bevy_ecs::prelude::Entity;
 use glam::{EulerRot, Quat, Vec2, Vec3, Vec4};
 use kestrel_engine::assets::AssetManager;
 use kestrel_engine::ecs::{
-    Aabb, Children, EcsWorld, ForceField, ForceFieldKind, Mass, MeshLighting, MeshRef, MeshSurface, Parent,
-    ParticleAttractor, ParticleEmitter, PropertyTrackPlayer, SceneEntityTag, Sprite, Tint, Transform,
-    Transform3D, TransformTrackPlayer, Velocity, WorldTransform, WorldTransform3D,
+    Aabb, Children, EcsWorld, EmitterShape, ForceField, ForceFieldKind, Mass, MeshLighting, MeshRef,
+    MeshSurface, Parent, ParticleAttractor, ParticleCollisionMode, ParticleEmitter, PluginBlobStore,
+    PropertyTrackPlayer, SceneEntityTag, Sprite, TagIndex, Tint, Transform, Transform3D,
+    TransformTrackPlayer, Velocity, WorldTransform, WorldTransform3D,
 };
 use kestrel_engine::environment::EnvironmentRegistry;
 use kestrel_engine::material_registry::MaterialRegistry;
 use kestrel_engine::mesh_registry::MeshRegistry;
+use kestrel_engine::renderer::{AutoExposureSettings, PointLightFalloff};
 use kestrel_engine::scene::{
     EnvironmentDependency, Scene, SceneEntity, SceneEntityId, SceneEnvironment, SceneLightingData,
-    SceneShadowData, TransformData, Vec3Data,
+    ScenePointLightData, SceneReferenceKind, SceneShadowData, TransformData, Vec3Data,
 };
 use std::sync::Arc;
 use tempfile::NamedTempFile;
@@ -51,6 +53,7 @@ fn scene_roundtrip_preserves_entity_count() {
             metallic: 0.35,
             roughness: 0.42,
         },
+        max_draw_distance: Some(120.0),
     });
     let original_count = world.entity_count();
 
@@ -62,7 +65,8 @@ fn scene_roundtrip_preserves_entity_count() {
         ENVIRONMENT_KEY.to_string(),
         Some("assets/environments/test_environment.hdr".to_string()),
     )));
-    scene.metadata.environment = Some(SceneEnvironment::new(ENVIRONMENT_KEY.to_string(), 1.75));
+    scene.metadata.environment =
+        Some(SceneEnvironment::new(ENVIRONMENT_KEY.to_string(), 1.75).with_scroll_speed(0.02));
     assert!(scene.dependencies.contains_atlas("main"), "scene should track atlas dependency");
     let main_dep = scene
         .dependencies
@@ -104,6 +108,7 @@ fn scene_roundtrip_preserves_entity_count() {
     assert!((saved_base_color.z - 0.85).abs() < f32::EPSILON);
     assert!((saved_mesh.lighting.metallic - 0.35).abs() < f32::EPSILON);
     assert!((saved_mesh.lighting.roughness - 0.42).abs() < f32::EPSILON);
+    assert_eq!(saved_mesh.max_draw_distance, Some(120.0));
     let emissive_vec = saved_mesh
         .lighting
         .emissive
@@ -129,6 +134,7 @@ fn scene_roundtrip_preserves_entity_count() {
         loaded.metadata.environment.as_ref().expect("loaded scene should restore environment metadata");
     assert_eq!(loaded_env_meta.key.as_str(), ENVIRONMENT_KEY);
     assert!((loaded_env_meta.intensity - 1.75).abs() < f32::EPSILON);
+    assert!((loaded_env_meta.scroll_speed - 0.02).abs() < f32::EPSILON);
     let loaded_mesh_dep = loaded
         .dependencies
         .mesh_dependencies()
@@ -199,6 +205,7 @@ fn scene_roundtrip_preserves_entity_count() {
                 assert!((surface.lighting.base_color.z - 0.85).abs() < f32::EPSILON);
                 assert!((surface.lighting.metallic - 0.35).abs() < f32::EPSILON);
                 assert!((surface.lighting.roughness - 0.42).abs() < f32::EPSILON);
+                assert_eq!(surface.max_draw_distance, Some(120.0));
                 let emissive = surface.lighting.emissive.expect("emissive should exist");
                 assert!((emissive.x - 0.1).abs() < f32::EPSILON);
                 assert!((emissive.y - 0.2).abs() < f32::EPSILON);
@@ -283,6 +290,10 @@ fn scene_roundtrip_preserves_transforms_and_emitters() {
                 region: Arc::from("green"),
                 source: Some(Arc::from("assets/images/atlas.json")),
                 trail: None,
+                shape: EmitterShape::CircleEdge { radius: 1.25 },
+                pending_burst: 0,
+                collision_mode: ParticleCollisionMode::Bounce { restitution: 0.35 },
+                collision_events: true,
             },
             ForceField {
                 kind: ForceFieldKind::Radial,
@@ -386,6 +397,9 @@ fn scene_roundtrip_preserves_transforms_and_emitters() {
     assert!((emitter.end_size - 0.06).abs() < f32::EPSILON);
     assert!((emitter.start_color - emitter_color_start).length() < 1e-5);
     assert!((emitter.end_color - emitter_color_end).length() < 1e-5);
+    assert_eq!(emitter.shape, EmitterShape::CircleEdge { radius: 1.25 });
+    assert_eq!(emitter.collision_mode, ParticleCollisionMode::Bounce { restitution: 0.35 });
+    assert!(emitter.collision_events);
 
     let children =
         new_world.world.get::<Children>(parent_entity).expect("parent should retain children listing");
@@ -468,6 +482,11 @@ fn lighting_shadow_settings_roundtrip() {
             split_lambda: 0.75,
             pcf_radius: 1.6,
         },
+        auto_exposure: AutoExposureSettings {
+            enabled: true,
+            adaptation_rate: 0.75,
+            ..AutoExposureSettings::default()
+        },
         point_lights: Vec::new(),
     };
     let serialized = serde_json::to_string(&lighting).expect("serialize lighting");
@@ -480,6 +499,47 @@ fn lighting_shadow_settings_roundtrip() {
     assert_eq!(roundtrip.shadow.resolution, 1024);
     assert!((roundtrip.shadow.split_lambda - 0.75).abs() < f32::EPSILON);
     assert!((roundtrip.shadow.pcf_radius - 1.6).abs() < f32::EPSILON);
+    assert_eq!(roundtrip.auto_exposure, lighting.auto_exposure);
+    let manual: SceneLightingData = serde_json::from_str(r#"{"exposure": 1.5}"#).expect("legacy lighting");
+    assert!(!manual.auto_exposure.enabled, "scenes without the field keep manual exposure");
+}
+
+#[test]
+fn point_light_falloff_roundtrips_through_scene_metadata() {
+    let mut scene = Scene::default();
+    scene.metadata.lighting = Some(SceneLightingData {
+        point_lights: vec![
+            ScenePointLightData { falloff: PointLightFalloff::Smoothstep, ..ScenePointLightData::default() },
+            ScenePointLightData {
+                falloff: PointLightFalloff::Linear,
+                radius: 8.0,
+                ..ScenePointLightData::default()
+            },
+            ScenePointLightData::default(),
+        ],
+        ..SceneLightingData::default()
+    });
+    let temp_file = NamedTempFile::new().expect("temp scene file for point lights");
+    scene.save_to_path(temp_file.path()).expect("scene with point lights should save");
+    let loaded = Scene::load_from_path(temp_file.path()).expect("scene with point lights should load");
+    let falloffs: Vec<PointLightFalloff> = loaded
+        .metadata
+        .lighting
+        .as_ref()
+        .expect("lighting metadata should persist")
+        .point_lights
+        .iter()
+        .map(|light| light.falloff)
+        .collect();
+    assert_eq!(
+        falloffs,
+        vec![PointLightFalloff::Smoothstep, PointLightFalloff::Linear, PointLightFalloff::InverseSquare]
+    );
+
+    let legacy: ScenePointLightData =
+        serde_json::from_str(r#"{"position":{"x":1.0,"y":2.0,"z":3.0},"radius":4.0}"#)
+            .expect("point light saved before falloff existed should load");
+    assert_eq!(legacy.falloff, PointLightFalloff::InverseSquare);
 }
 
 #[test]
@@ -553,6 +613,7 @@ fn scene_clone_subtree_includes_descendants() {
             transform3d: None,
             mesh: None,
             tint: None,
+            tint_curve: None,
             velocity: None,
             mass: None,
             collider: None,
@@ -561,6 +622,8 @@ fn scene_clone_subtree_includes_descendants() {
             attractor: None,
             orbit: None,
             spin: None,
+            tags: Vec::new(),
+            plugin_data: Default::default(),
             parent_id,
             parent: None,
         }
@@ -644,6 +707,10 @@ fn scene_roundtrip_captures_hierarchy_dependencies_and_environment_metadata() {
                 region: Arc::from("green"),
                 source: Some(Arc::from("assets/images/atlas.json")),
                 trail: None,
+                shape: EmitterShape::default(),
+                pending_burst: 0,
+                collision_mode: ParticleCollisionMode::None,
+                collision_events: false,
             },
         ))
         .id();
@@ -662,6 +729,7 @@ fn scene_roundtrip_captures_hierarchy_dependencies_and_environment_metadata() {
             metallic: 0.9,
             roughness: 0.2,
         },
+        max_draw_distance: None,
     });
 
     world.world.entity_mut(child).insert(Children(vec![grandchild, mesh_entity]));
@@ -876,3 +944,237 @@ fn scene_roundtrip_preserves_transform_clip_binding() {
     assert!((tint.y - 0.4).abs() < 1e-6);
     assert!((tint.z - 0.8).abs() < 1e-6);
 }
+
+#[test]
+fn plugin_blobs_persist_through_scene_save_and_load() {
+    let mut world = EcsWorld::new();
+    let assets = AssetManager::new();
+    let entity = world.world.spawn((Transform::default(), WorldTransform::default())).id();
+    assert!(world.insert_blob(entity, "plugin.health", vec![100, 0, 7]));
+    assert!(world.insert_blob(entity, "other_plugin.tags", b"boss".to_vec()));
+    assert!(!world.insert_blob(entity, "", vec![1]), "blob names must be non-empty");
+
+    let temp_file = NamedTempFile::new().expect("temp scene file for blobs");
+    world.save_scene_to_path(temp_file.path(), &assets).expect("save scene with blobs");
+    let scene_id = world.world.get::<SceneEntityTag>(entity).expect("export tags entity").id.clone();
+
+    let mut loaded_world = EcsWorld::new();
+    let mut load_assets = AssetManager::new();
+    loaded_world.load_scene_from_path(temp_file.path(), &mut load_assets).expect("load scene with blobs");
+    let restored = loaded_world.find_entity_by_scene_id(scene_id.as_str()).expect("entity restored by id");
+    assert_eq!(loaded_world.blob(restored, "plugin.health"), Some(&[100u8, 0, 7][..]));
+    assert_eq!(loaded_world.blob(restored, "other_plugin.tags"), Some(&b"boss"[..]));
+    assert_eq!(loaded_world.blob_names(restored), vec!["other_plugin.tags", "plugin.health"]);
+
+    assert!(loaded_world.despawn_entity(restored));
+    assert!(loaded_world.blob(restored, "plugin.health").is_none(), "despawn cleans up blobs");
+
+    world.clear_world();
+    assert!(world.world.resource::<PluginBlobStore>().is_empty(), "clearing the world drops every blob");
+}
+
+#[test]
+fn entity_tags_index_and_persist_through_scene_save_and_load() {
+    let mut world = EcsWorld::new();
+    let assets = AssetManager::new();
+    let boss = world.world.spawn((Transform::default(), WorldTransform::default())).id();
+    let grunt = world.world.spawn((Transform::default(), WorldTransform::default())).id();
+    assert!(world.set_entity_tag(boss, "enemy"));
+    assert!(world.set_entity_tag(boss, "boss"));
+    assert!(world.set_entity_tag(grunt, "enemy"));
+    assert!(world.set_entity_tag(grunt, "enemy"), "re-tagging is a no-op");
+    assert!(!world.set_entity_tag(grunt, ""), "tags must be non-empty");
+    assert_eq!(world.query_entities_with_tag("enemy"), vec![boss, grunt]);
+    assert_eq!(world.query_entities_with_tag("boss"), vec![boss]);
+    assert!(world.query_entities_with_tag("collectible").is_empty());
+
+    assert!(world.remove_entity_tag(grunt, "enemy"));
+    assert!(!world.remove_entity_tag(grunt, "enemy"));
+    assert_eq!(world.query_entities_with_tag("enemy"), vec![boss]);
+
+    let scene = world.export_scene(&assets);
+    let json = serde_json::to_value(&scene).expect("serialize scene");
+    let tag_lists: Vec<_> =
+        json["entities"].as_array().expect("entities array").iter().filter_map(|e| e.get("tags")).collect();
+    assert_eq!(tag_lists, vec![&serde_json::json!(["boss", "enemy"])], "untagged entities omit the field");
+
+    let temp_file = NamedTempFile::new().expect("temp scene file for tags");
+    world.save_scene_to_path(temp_file.path(), &assets).expect("save scene with tags");
+    let scene_id = world.world.get::<SceneEntityTag>(boss).expect("export tags entity").id.clone();
+
+    let mut loaded_world = EcsWorld::new();
+    let mut load_assets = AssetManager::new();
+    loaded_world.load_scene_from_path(temp_file.path(), &mut load_assets).expect("load scene with tags");
+    let restored = loaded_world.find_entity_by_scene_id(scene_id.as_str()).expect("entity restored by id");
+    let tags: Vec<String> = loaded_world.entity_tags(restored).iter().map(|tag| tag.to_string()).collect();
+    assert_eq!(tags, vec!["boss", "enemy"]);
+    assert_eq!(loaded_world.query_entities_with_tag("enemy"), vec![restored]);
+
+    assert!(loaded_world.world.despawn(restored));
+    assert!(loaded_world.query_entities_with_tag("enemy").is_empty(), "despawn unindexes tags");
+    assert!(loaded_world.world.resource::<TagIndex>().is_empty());
+}
+
+#[test]
+fn sprite_z_order_persists_and_defaults_to_zero() {
+    let mut world = EcsWorld::new();
+    let mut assets = AssetManager::new();
+    assets.retain_atlas("main", Some("assets/images/atlas.json")).expect("load main atlas");
+    let spawn = |world: &mut EcsWorld| {
+        world
+            .world
+            .spawn((
+                Transform::default(),
+                WorldTransform::default(),
+                Sprite::uninitialized(Arc::from("main"), Arc::from("redorb")),
+            ))
+            .id()
+    };
+    let raised = spawn(&mut world);
+    let flat = spawn(&mut world);
+    assert!(world.set_sprite_z_order(raised, 3));
+
+    let scene = world.export_scene(&assets);
+    let json = serde_json::to_value(&scene).expect("serialize scene");
+    let sprites: Vec<_> = json["entities"]
+        .as_array()
+        .expect("entities array")
+        .iter()
+        .filter_map(|entity| entity.get("sprite"))
+        .collect();
+    assert_eq!(sprites.len(), 2);
+    assert_eq!(sprites.iter().filter(|sprite| sprite.get("z_order").is_some()).count(), 1, "zero is omitted");
+
+    let temp_file = NamedTempFile::new().expect("temp scene file for z-order");
+    world.save_scene_to_path(temp_file.path(), &assets).expect("save scene with z-order");
+    let raised_id = world.world.get::<SceneEntityTag>(raised).expect("scene tag").id.clone();
+    let flat_id = world.world.get::<SceneEntityTag>(flat).expect("scene tag").id.clone();
+
+    let mut loaded_world = EcsWorld::new();
+    let mut load_assets = AssetManager::new();
+    loaded_world.load_scene_from_path(temp_file.path(), &mut load_assets).expect("load scene with z-order");
+    let restored = loaded_world.find_entity_by_scene_id(raised_id.as_str()).expect("raised sprite restored");
+    let restored_flat = loaded_world.find_entity_by_scene_id(flat_id.as_str()).expect("flat sprite restored");
+    assert_eq!(loaded_world.sprite_z_order(restored), 3);
+    assert_eq!(loaded_world.sprite_z_order(restored_flat), 0);
+}
+
+#[test]
+fn set_parent_composes_world_transforms_and_exports_parent_ids() {
+    let mut world = EcsWorld::new();
+    let assets = AssetManager::new();
+    let spawn = |world: &mut EcsWorld, translation: Vec2| {
+        let entity = world
+            .world
+            .spawn((Transform { translation, rotation: 0.0, scale: Vec2::ONE }, WorldTransform::default()))
+            .id();
+        world.world.entity_mut(entity).insert(SceneEntityTag::new(SceneEntityId::new()));
+        entity
+    };
+    let parent = spawn(&mut world, Vec2::new(2.0, 1.0));
+    let child = spawn(&mut world, Vec2::new(0.5, 0.0));
+
+    assert!(world.set_parent(parent, parent).is_err(), "self-parenting is rejected");
+    world.set_parent(child, parent).expect("set parent");
+    assert!(world.set_parent(parent, child).is_err(), "cycles are rejected");
+    assert_eq!(world.get_children(parent), vec![child]);
+    assert_eq!(world.get_parent(child), Some(parent));
+    world.update(0.0);
+    let info = world.entity_info(child).expect("child info");
+    assert!((info.translation - Vec2::new(2.5, 1.0)).length() < 1e-5);
+    let parent_scene_id = world.entity_info(parent).expect("parent info").scene_id;
+    assert_eq!(info.parent.as_ref(), Some(&parent_scene_id));
+
+    let scene = world.export_scene(&assets);
+    let child_entry =
+        scene.entities.iter().find(|entity| entity.id == info.scene_id).expect("child exported");
+    assert_eq!(child_entry.parent_id.as_ref(), Some(&parent_scene_id));
+
+    assert!(world.detach_from_parent(child));
+    assert!(!world.detach_from_parent(child));
+    assert!(world.get_children(parent).is_empty());
+    world.update(0.0);
+    let info = world.entity_info(child).expect("child info");
+    assert!((info.translation - Vec2::new(0.5, 0.0)).length() < 1e-5);
+    assert!(info.parent.is_none());
+}
+
+#[test]
+fn scene_entity_ids_are_never_reused_across_save_load_cycles() {
+    let assets = AssetManager::new();
+    let mut world = EcsWorld::new();
+    let spawned: Vec<Entity> =
+        (0..3).map(|_| world.world.spawn((Transform::default(), WorldTransform::default())).id()).collect();
+    let mut scene = world.export_scene(&assets);
+    let ids: Vec<SceneEntityId> = spawned
+        .iter()
+        .map(|&entity| world.world.get::<SceneEntityTag>(entity).expect("export tags entity").id.clone())
+        .collect();
+    assert_eq!(
+        ids,
+        vec![SceneEntityId::sequential(0), SceneEntityId::sequential(1), SceneEntityId::sequential(2)]
+    );
+    assert_eq!(scene.next_entity_id, 3);
+    let followed = ids[1].clone();
+    scene.metadata.camera_follow_entity = Some(followed.clone());
+    let first_save = NamedTempFile::new().expect("temp scene file");
+    scene.save_to_path(first_save.path()).expect("save first session");
+
+    // Second session: delete the followed entity and add a new one.
+    let reloaded = Scene::load_from_path(first_save.path()).expect("reload first session");
+    assert!(reloaded.validate_entity_references().is_empty());
+    let mut second = EcsWorld::new();
+    second.load_scene(&reloaded, &assets).expect("load first session");
+    let followed_entity = second.find_entity_by_scene_id(followed.as_str()).expect("followed entity loads");
+    assert!(second.despawn_entity(followed_entity));
+    let added = second.world.spawn((Transform::default(), WorldTransform::default())).id();
+    let mut scene = second.export_scene(&assets);
+    let added_id = second.world.get::<SceneEntityTag>(added).expect("export tags new entity").id.clone();
+    assert_eq!(added_id, SceneEntityId::sequential(3), "deleted ids must not be handed out again");
+    scene.metadata.camera_follow_entity = Some(followed.clone());
+    let second_save = NamedTempFile::new().expect("temp scene file");
+    scene.save_to_path(second_save.path()).expect("save second session");
+
+    // Third session: the dangling follow target is reported and the counter keeps advancing.
+    let reloaded = Scene::load_from_path(second_save.path()).expect("reload second session");
+    assert_eq!(reloaded.next_entity_id, 4);
+    let warnings = reloaded.validate_entity_references();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].kind, SceneReferenceKind::CameraFollow);
+    assert_eq!(warnings[0].target, followed.as_str());
+    let mut third = EcsWorld::new();
+    third.load_scene(&reloaded, &assets).expect("load second session");
+    assert!(third.find_entity_by_scene_id(added_id.as_str()).is_some(), "surviving ids stay stable");
+    let newest = third.world.spawn((Transform::default(), WorldTransform::default())).id();
+    let scene = third.export_scene(&assets);
+    let newest_id = third.world.get::<SceneEntityTag>(newest).expect("export tags newest").id.clone();
+    assert_eq!(newest_id, SceneEntityId::sequential(4));
+    let mut unique: Vec<&str> = scene.entities.iter().map(|entity| entity.id.as_str()).collect();
+    unique.sort_unstable();
+    unique.dedup();
+    assert_eq!(unique.len(), scene.entities.len(), "exported ids stay unique");
+}
+
+#[test]
+fn dangling_parent_links_load_as_roots_and_are_reported() {
+    let assets = AssetManager::new();
+    let mut source = EcsWorld::new();
+    source.world.spawn((Transform::default(), WorldTransform::default()));
+    let mut scene = source.export_scene(&assets);
+    scene.entities[0].parent_id = Some(SceneEntityId::sequential(41));
+
+    let warnings = scene.validate_entity_references();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].kind, SceneReferenceKind::Parent);
+    assert_eq!(warnings[0].source.as_ref(), Some(&scene.entities[0].id));
+    assert!(warnings[0].to_string().contains("missing parent 'entity-41'"));
+
+    let mut world = EcsWorld::new();
+    world.load_scene(&scene, &assets).expect("dangling parent does not abort the load");
+    let entity = world.find_entity_by_scene_id(scene.entities[0].id.as_str()).expect("entity loads");
+    assert!(world.get_parent(entity).is_none(), "unresolved parent leaves the entity at the root");
+    let added = world.world.spawn((Transform::default(), WorldTransform::default())).id();
+    world.export_scene(&assets);
+    let added_id = world.world.get::<SceneEntityTag>(added).expect("export tags entity").id.clone();
+    assert_eq!(added_id, SceneEntityId::sequential(42), "referenced ids are reserved too");
+}
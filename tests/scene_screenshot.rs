@@ -0,0 +1,35 @@
+use kestrel_engine::scene_screenshot::{render_scene_screenshot, ScreenshotArgs, ScreenshotError};
+
+#[test]
+fn screenshot_writes_png_at_requested_size() {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let output = dir.path().join("shots/overview.png");
+    let args = ScreenshotArgs::parse([
+        "assets/scenes/animation_showcase.json",
+        output.to_str().expect("utf-8 path"),
+        "--size",
+        "160x90",
+        "--camera-bookmark",
+        "Missing",
+    ])
+    .expect("parse args");
+
+    pollster::block_on(render_scene_screenshot(&args)).expect("render screenshot");
+
+    let image = image::open(&output).expect("read screenshot").to_rgba8();
+    assert_eq!(image.dimensions(), (160, 90));
+    assert!(image.pixels().any(|pixel| pixel.0[3] == 255), "frame should be opaque");
+}
+
+#[test]
+fn screenshot_of_missing_scene_reports_load_failure() {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let output = dir.path().join("missing.png");
+    let args = ScreenshotArgs::parse(["assets/scenes/does_not_exist.json", output.to_str().unwrap()])
+        .expect("parse args");
+
+    let err = pollster::block_on(render_scene_screenshot(&args)).expect_err("scene should not load");
+    assert!(matches!(err, ScreenshotError::Load(_)));
+    assert_eq!(err.exit_code(), ScreenshotError::LOAD_EXIT_CODE);
+    assert!(!output.exists());
+}
@@ -0,0 +1,112 @@
+use glam::Vec2;
+use kestrel_engine::assets::AssetManager;
+use kestrel_engine::ecs::{EcsWorld, SceneChunkMember};
+use kestrel_engine::scene::{Scene, SceneChunkBounds, SceneChunkRef, Vec2Data};
+use kestrel_engine::scene_streaming::ChunkStreamer;
+use serde_json::json;
+use std::collections::HashMap;
+
+const CHUNK_SIZE: f32 = 10.0;
+const BRIDGE_CHUNKS: [&str; 2] = ["chunk_1_1", "chunk_2_1"];
+
+fn entity_json(id: &str, x: f32, y: f32) -> serde_json::Value {
+    json!({
+        "id": id,
+        "name": id,
+        "transform": {
+            "translation": { "x": x, "y": y },
+            "rotation": 0.0,
+            "scale": { "x": 1.0, "y": 1.0 }
+        }
+    })
+}
+
+/// A 3x3 grid of chunks centred on the origin. Each chunk owns one entity at its centre; the
+/// "bridge" entity straddles the border between the middle and east chunks and is listed by both.
+fn grid_world() -> (Vec<SceneChunkRef>, HashMap<String, Scene>) {
+    let mut refs = Vec::new();
+    let mut scenes = HashMap::new();
+    for row in 0..3 {
+        for col in 0..3 {
+            let key = format!("chunk_{col}_{row}");
+            let min = Vec2::new(col as f32 * CHUNK_SIZE - 15.0, row as f32 * CHUNK_SIZE - 15.0);
+            let center = min + Vec2::splat(CHUNK_SIZE * 0.5);
+            let mut entities = vec![entity_json(&format!("{key}_tile"), center.x, center.y)];
+            if BRIDGE_CHUNKS.contains(&key.as_str()) {
+                entities.push(entity_json("bridge", 5.0, 0.0));
+            }
+            let scene: Scene =
+                serde_json::from_value(json!({ "entities": entities })).expect("chunk scene parses");
+            refs.push(SceneChunkRef {
+                key: key.clone(),
+                path: format!("{key}.json"),
+                bounds: SceneChunkBounds {
+                    min: Vec2Data { x: min.x, y: min.y },
+                    max: Vec2Data { x: min.x + CHUNK_SIZE, y: min.y + CHUNK_SIZE },
+                },
+            });
+            scenes.insert(key, scene);
+        }
+    }
+    (refs, scenes)
+}
+
+fn streamed_entity_count(ecs: &mut EcsWorld) -> usize {
+    ecs.world.query::<&SceneChunkMember>().iter(&ecs.world).count()
+}
+
+#[test]
+fn panning_across_chunk_grid_streams_entities_without_duplicates() {
+    let (refs, scenes) = grid_world();
+    let assets = AssetManager::new();
+    let mut ecs = EcsWorld::new();
+    let mut streamer = ChunkStreamer::new(&refs);
+    let half = Vec2::new(3.0, 3.0);
+    let mut bridge_seen = false;
+
+    let mut x = -25.0;
+    while x <= 25.0 {
+        let center = Vec2::new(x, 0.0);
+        let plan = streamer.plan(center - half, center + half, 1.0);
+        for key in &plan.unload {
+            ecs.unload_scene_chunk(key);
+            streamer.set_loaded(key, false);
+        }
+        for key in &plan.load {
+            ecs.load_scene_chunk(key, &scenes[key], &assets).expect("chunk loads");
+            streamer.set_loaded(key, true);
+        }
+
+        let loaded = streamer.loaded_keys();
+        for key in &loaded {
+            let expected = if BRIDGE_CHUNKS.contains(&key.as_str()) { 2 } else { 1 };
+            assert_eq!(ecs.scene_chunk_entity_count(key), expected, "chunk {key} at x={x}");
+        }
+        let bridge_chunks_loaded = loaded.iter().filter(|key| BRIDGE_CHUNKS.contains(&key.as_str())).count();
+        let bridge_present = ecs.find_entity_by_scene_id("bridge").is_some();
+        assert_eq!(bridge_present, bridge_chunks_loaded > 0, "bridge lives while any owning chunk is loaded");
+        bridge_seen |= bridge_present;
+        let expected_total = loaded.len() + usize::from(bridge_chunks_loaded > 0);
+        assert_eq!(streamed_entity_count(&mut ecs), expected_total, "no duplicate spawns at x={x}");
+        assert!(loaded.iter().all(|key| key.ends_with("_1")), "only the middle row overlaps the view");
+        x += 0.5;
+    }
+    assert!(bridge_seen);
+
+    for key in streamer.loaded_keys() {
+        ecs.unload_scene_chunk(&key);
+    }
+    assert_eq!(streamed_entity_count(&mut ecs), 0);
+}
+
+#[test]
+fn chunk_entities_are_not_exported_with_the_master_scene() {
+    let (_, scenes) = grid_world();
+    let assets = AssetManager::new();
+    let mut ecs = EcsWorld::new();
+    ecs.load_scene_chunk("chunk_0_0", &scenes["chunk_0_0"], &assets).expect("chunk loads");
+    assert_eq!(ecs.scene_chunk_entity_count("chunk_0_0"), 1);
+    let exported = ecs.export_scene(&assets);
+    assert!(exported.entities.iter().all(|entity| entity.id.as_str() != "chunk_0_0_tile"));
+    assert_eq!(ecs.unload_scene_chunk("chunk_0_0"), 1);
+}
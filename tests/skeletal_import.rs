@@ -1,12 +1,13 @@
 use anyhow::{anyhow, Context, Result};
-use glam::{Quat, Vec3};
-use kestrel_engine::assets::skeletal::{self, SkeletonImport};
+use glam::{Mat4, Quat, Vec2, Vec3};
+use kestrel_engine::assets::skeletal::{self, SkeletonAsset, SkeletonImport, SkeletonJoint};
 use kestrel_engine::assets::AssetManager;
 use kestrel_engine::ecs::{
     BoneTransforms, EcsWorld, SceneEntityTag, SkeletonInstance, Transform, WorldTransform,
 };
 use kestrel_engine::scene::SceneEntityId;
 use std::path::Path;
+use std::sync::Arc;
 
 #[test]
 fn import_slime_rig_fixture() -> Result<()> {
@@ -167,3 +168,100 @@ fn skeletal_clip_supports_negative_speed() -> Result<()> {
     assert!((instance.time - expected).abs() < 1e-4, "expected time near {expected}, got {}", instance.time);
     Ok(())
 }
+
+fn joint(name: &str, parent: Option<u32>, translation: Vec3, parent_world: Mat4) -> SkeletonJoint {
+    let rest_local = Mat4::from_translation(translation);
+    let rest_world = parent_world * rest_local;
+    SkeletonJoint {
+        name: Arc::from(name),
+        parent,
+        rest_local,
+        rest_world,
+        rest_translation: translation,
+        rest_rotation: Quat::IDENTITY,
+        rest_scale: Vec3::ONE,
+        inverse_bind: rest_world.inverse(),
+    }
+}
+
+#[test]
+fn bone_world_transforms_follow_the_skeleton_entity() -> Result<()> {
+    let root = joint("root", None, Vec3::ZERO, Mat4::IDENTITY);
+    let hand = joint("hand", Some(0), Vec3::new(0.0, 1.0, 0.0), root.rest_world);
+    let skeleton = SkeletonAsset {
+        name: Arc::from("arm"),
+        joints: Arc::from(vec![root, hand]),
+        roots: Arc::from(vec![0]),
+    };
+    let mut assets = AssetManager::new();
+    assets.replace_skeleton_from_import("arm", "arm.gltf", SkeletonImport { skeleton, clips: Vec::new() });
+
+    let mut ecs = EcsWorld::new();
+    let entity = ecs
+        .world
+        .spawn((
+            Transform { translation: Vec2::new(2.0, -1.0), rotation: 0.5, scale: Vec2::splat(1.5) },
+            WorldTransform::default(),
+            SceneEntityTag::new(SceneEntityId::new()),
+        ))
+        .id();
+    assert!(ecs.collect_skeleton_bone_world_transforms(entity).is_none(), "no skeleton attached yet");
+    assert!(ecs.set_skeleton(entity, &assets, "arm"), "attach skeleton");
+    ecs.update(0.0);
+
+    let entity_world = ecs.world.get::<WorldTransform>(entity).expect("world transform").0;
+    assert_ne!(entity_world, Mat4::IDENTITY, "the entity transform should have been propagated");
+    let bones = ecs.collect_skeleton_bone_world_transforms(entity).expect("skeleton bones");
+    let names: Vec<&str> = bones.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, ["root", "hand"]);
+    assert!(bones[0].1.abs_diff_eq(entity_world, 1e-5), "root bone should sit on the entity");
+    let hand = bones[1].1.transform_point3(Vec3::ZERO);
+    let expected = entity_world.transform_point3(Vec3::new(0.0, 1.0, 0.0));
+    assert!(hand.abs_diff_eq(expected, 1e-5), "hand at {hand:?}, expected {expected:?}");
+    Ok(())
+}
+
+#[test]
+fn joint_poses_mirror_the_animated_pose_and_report_clip_tracks() -> Result<()> {
+    let mut assets = AssetManager::new();
+    assets
+        .retain_skeleton("slime", Some("fixtures/gltf/skeletons/slime_rig.gltf"))
+        .context("retain slime skeleton")?;
+
+    let mut ecs = EcsWorld::new();
+    let entity = ecs
+        .world
+        .spawn((
+            Transform { translation: Vec2::new(3.0, 1.0), rotation: 0.0, scale: Vec2::ONE },
+            WorldTransform::default(),
+            SceneEntityTag::new(SceneEntityId::new()),
+        ))
+        .id();
+    assert!(ecs.skeleton_joint_poses(entity).is_none(), "no skeleton attached yet");
+    assert!(ecs.set_skeleton(entity, &assets, "slime"), "attach skeleton");
+    let clip_key = assets
+        .skeletal_clip_keys_for("slime")
+        .and_then(|keys| keys.first().cloned())
+        .ok_or_else(|| anyhow!("slime clip key missing"))?;
+    assert!(ecs.set_skeleton_clip(entity, &assets, &clip_key), "assign clip");
+    ecs.update(0.25);
+
+    let joints = ecs.skeleton_joint_poses(entity).ok_or_else(|| anyhow!("joint poses missing"))?;
+    let entity_world = ecs.world.get::<WorldTransform>(entity).expect("world transform").0;
+    let instance =
+        ecs.world.get::<SkeletonInstance>(entity).ok_or_else(|| anyhow!("skeleton instance missing"))?;
+    assert_eq!(joints.len(), instance.joint_count());
+    for (index, joint) in joints.iter().enumerate() {
+        assert_eq!(joint.name, instance.skeleton.joints[index].name);
+        assert_eq!(joint.local, instance.local_poses[index]);
+        assert!(joint.world.abs_diff_eq(entity_world * instance.model_poses[index], 1e-5));
+    }
+    assert!(joints.iter().any(|joint| joint.clip_tracks.is_some()), "the clip animates at least one joint");
+
+    let all = ecs.all_skeleton_joint_poses();
+    assert_eq!(all.len(), 1);
+    assert_eq!(all[0].0, entity);
+    let info = ecs.entity_info(entity).ok_or_else(|| anyhow!("entity info unavailable"))?;
+    assert_eq!(info.skeleton.map(|skeleton| skeleton.joints.len()), Some(joints.len()));
+    Ok(())
+}
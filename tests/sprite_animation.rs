@@ -2,7 +2,8 @@ use bevy_ecs::prelude::Entity;
 use glam::Vec2;
 use kestrel_engine::assets::AssetManager;
 use kestrel_engine::ecs::{
-    EcsWorld, SceneEntityTag, Sprite, SpriteAnimation, SpriteAnimationLoopMode, Transform, WorldTransform,
+    AtlasReferenceKind, EcsWorld, SceneEntityTag, Sprite, SpriteAnimation, SpriteAnimationLoopMode,
+    Transform, Velocity, WorldTransform,
 };
 use kestrel_engine::events::GameEvent;
 use kestrel_engine::scene::SceneEntityId;
@@ -170,6 +171,70 @@ fn sprite_instances_use_local_transform_when_world_missing() {
     assert!((transform.translation.y - 4.0).abs() < 1e-6, "translation.y should populate transform");
 }
 
+#[test]
+fn sprite_instances_sort_by_z_order_within_atlas() {
+    let mut assets = AssetManager::new();
+    assets.retain_atlas("main", Some("assets/images/atlas.json")).expect("load main atlas");
+    let mut ecs = EcsWorld::new();
+    let spawn = |ecs: &mut EcsWorld, x: f32| {
+        ecs.world
+            .spawn((
+                Transform { translation: Vec2::new(x, 0.0), rotation: 0.0, scale: Vec2::ONE },
+                Sprite::uninitialized(Arc::from("main"), Arc::from("redorb")),
+            ))
+            .id()
+    };
+    let back = spawn(&mut ecs, 0.0);
+    let front = spawn(&mut ecs, 1.0);
+    let middle = spawn(&mut ecs, 2.0);
+    let order = |ecs: &mut EcsWorld| -> Vec<f32> {
+        ecs.collect_sprite_instances(&assets)
+            .expect("collect sprites")
+            .iter()
+            .map(|instance| instance.transform.translation.x)
+            .collect()
+    };
+    assert_eq!(order(&mut ecs), vec![0.0, 1.0, 2.0], "creation order without z-order");
+
+    assert!(ecs.set_sprite_z_order(front, 5));
+    assert!(ecs.set_sprite_z_order(back, -1));
+    assert_eq!(ecs.sprite_z_order(front), 5);
+    assert_eq!(ecs.sprite_z_order(middle), 0);
+    assert_eq!(order(&mut ecs), vec![0.0, 2.0, 1.0]);
+    assert!(ecs.sprite_atlas_dirty("main"), "z-order edits dirty the atlas bucket");
+    let bare = ecs.world.spawn(Transform::default()).id();
+    assert!(!ecs.set_sprite_z_order(bare, 1), "requires a sprite");
+}
+
+#[test]
+fn z_order_sort_keeps_atlas_groups_in_first_appearance_order() {
+    let mut assets = AssetManager::new();
+    assets.retain_atlas("zeta", Some("assets/images/atlas.json")).expect("load zeta atlas");
+    assets.retain_atlas("alpha", Some("assets/images/atlas.json")).expect("load alpha atlas");
+    let mut ecs = EcsWorld::new();
+    let mut spawn = |atlas: &str, x: f32, z_order: i16| {
+        let entity = ecs
+            .world
+            .spawn((
+                Transform { translation: Vec2::new(x, 0.0), rotation: 0.0, scale: Vec2::ONE },
+                Sprite::uninitialized(Arc::from(atlas), Arc::from("redorb")),
+            ))
+            .id();
+        assert!(ecs.set_sprite_z_order(entity, z_order));
+    };
+    spawn("zeta", 0.0, 3);
+    spawn("alpha", 1.0, 0);
+    spawn("zeta", 2.0, -2);
+    spawn("alpha", 3.0, -1);
+
+    let instances = ecs.collect_sprite_instances(&assets).expect("collect sprites");
+    let order: Vec<(&str, f32)> = instances
+        .iter()
+        .map(|instance| (instance.atlas.as_ref(), instance.transform.translation.x))
+        .collect();
+    assert_eq!(order, vec![("zeta", 2.0), ("zeta", 0.0), ("alpha", 3.0), ("alpha", 1.0)]);
+}
+
 #[test]
 fn sprite_animation_seek_updates_frame() {
     let mut assets = AssetManager::new();
@@ -333,6 +398,99 @@ fn sprite_animation_once_hold_stays_on_last_frame() {
     assert_eq!(held_region, "green", "last frame should remain active");
 }
 
+#[test]
+fn sprite_animation_finished_event_fires_once_per_playthrough() {
+    let mut assets = AssetManager::new();
+    assets.retain_atlas("main", Some("assets/images/atlas.json")).expect("load main atlas");
+    let mut ecs = EcsWorld::new();
+    let entity = ecs
+        .world
+        .spawn((
+            Transform::default(),
+            WorldTransform::default(),
+            Sprite::uninitialized(Arc::from("main"), Arc::from("redorb")),
+        ))
+        .id();
+    assert!(ecs.set_sprite_timeline(entity, &assets, Some("demo_cycle")));
+    assert!(ecs.set_sprite_animation_loop_mode(entity, SpriteAnimationLoopMode::OnceStop));
+
+    let count_finished = |events: &[GameEvent]| {
+        events
+            .iter()
+            .filter(|event| {
+                matches!(
+                    event,
+                    GameEvent::AnimationFinished { entity: finished, atlas, timeline }
+                        if *finished == entity && atlas.as_ref() == "main" && timeline.as_ref() == "demo_cycle"
+                )
+            })
+            .count()
+    };
+
+    ecs.drain_events();
+    let mut finished = 0;
+    for _ in 0..20 {
+        ecs.update(0.1);
+        finished += count_finished(&ecs.drain_events());
+    }
+    assert_eq!(finished, 1, "non-looping animation should report completion exactly once");
+
+    assert!(ecs.reset_sprite_animation(entity));
+    let mut replayed = 0;
+    for _ in 0..20 {
+        ecs.update(0.1);
+        replayed += count_finished(&ecs.drain_events());
+    }
+    assert_eq!(replayed, 1, "resetting the animation should re-arm the finished event");
+
+    assert!(ecs.set_sprite_animation_loop_mode(entity, SpriteAnimationLoopMode::Loop));
+    assert!(ecs.reset_sprite_animation(entity));
+    for _ in 0..20 {
+        ecs.update(0.1);
+        assert_eq!(count_finished(&ecs.drain_events()), 0, "looping animations never finish");
+    }
+}
+
+#[test]
+fn sprite_animation_loop_count_holds_last_frame_after_cycles() {
+    let mut assets = AssetManager::new();
+    assets.retain_atlas("main", Some("assets/images/atlas.json")).expect("load main atlas");
+    let mut ecs = EcsWorld::new();
+    let entity = ecs
+        .world
+        .spawn((
+            Transform::default(),
+            WorldTransform::default(),
+            Sprite::uninitialized(Arc::from("main"), Arc::from("redorb")),
+            SceneEntityTag::new(SceneEntityId::new()),
+        ))
+        .id();
+    assert!(ecs.set_sprite_timeline(entity, &assets, Some("demo_cycle")));
+    assert!(ecs.set_sprite_animation_loop_count(entity, Some(2)));
+    ecs.drain_events();
+
+    // demo_cycle is three 120 ms frames, so two cycles end at 0.72 s.
+    let mut finished = 0;
+    for step in 0..40 {
+        ecs.update(0.05);
+        finished += ecs
+            .drain_events()
+            .iter()
+            .filter(|event| matches!(event, GameEvent::AnimationFinished { entity: e, .. } if *e == entity))
+            .count();
+        if step < 13 {
+            assert_eq!(finished, 0, "still looping at step {step}");
+        }
+    }
+    assert_eq!(finished, 1, "loop-counted animation finishes exactly once");
+    let info = ecs.entity_info(entity).and_then(|info| info.sprite).and_then(|sprite| sprite.animation);
+    let info = info.expect("animation info");
+    assert!(!info.playing);
+    assert_eq!(info.frame_index, 2, "holds the final frame");
+    assert_eq!(info.loop_count, Some(2));
+    assert_eq!(sprite_region(&ecs, entity), "green");
+}
+
 #[test]
 fn sprite_animation_events_emit_on_frame_entry() {
     let temp = NamedTempFile::new().expect("temp atlas");
@@ -368,6 +526,48 @@ fn sprite_animation_events_emit_on_frame_entry() {
     );
 }
 
+#[test]
+fn sprite_timeline_usage_counts_plays_loops_and_events() {
+    let temp = NamedTempFile::new().expect("temp atlas");
+    let source = std::fs::read("assets/images/atlas.json").expect("read atlas");
+    let mut atlas_json: serde_json::Value = serde_json::from_slice(&source).expect("parse atlas");
+    atlas_json["animations"]["demo_cycle"]["events"] = json!([{ "frame": 1, "name": "footstep" }]);
+    atlas_json["animations"]["demo_cycle"]["loop_mode"] = json!("loop");
+    std::fs::write(&temp, serde_json::to_vec_pretty(&atlas_json).expect("encode"))
+        .expect("write modified atlas");
+
+    let mut assets = AssetManager::new();
+    assets.retain_atlas("main", temp.path().to_str()).expect("load atlas with events");
+    let mut ecs = EcsWorld::new();
+    let entity = ecs
+        .world
+        .spawn((
+            Transform::default(),
+            WorldTransform::default(),
+            Sprite::uninitialized(Arc::from("main"), Arc::from("redorb")),
+        ))
+        .id();
+    assert!(ecs.set_sprite_timeline(entity, &assets, Some("demo_cycle")));
+    for _ in 0..8 {
+        ecs.update(0.1);
+    }
+
+    let usage = ecs.sprite_timeline_usage().expect("timeline usage resource");
+    let entry = usage.get("main", "demo_cycle").expect("demo_cycle usage recorded");
+    assert_eq!(entry.plays, 1);
+    assert_eq!(entry.loops, 2, "0.8s over a 0.36s loop wraps twice");
+    assert_eq!(entry.event_count("footstep"), 2, "footstep fires at 0.12s and again after the first wrap");
+
+    ecs.reset_sprite_animation(entity);
+    let revision = ecs.sprite_timeline_usage().unwrap().revision();
+    assert_eq!(ecs.sprite_timeline_usage().unwrap().get("main", "demo_cycle").unwrap().plays, 2);
+
+    ecs.reset_sprite_timeline_usage();
+    let usage = ecs.sprite_timeline_usage().unwrap();
+    assert!(usage.revision() > revision);
+    assert_eq!(usage.entries().count(), 0, "reset clears every counter");
+}
+
 #[test]
 fn sprite_animation_info_reports_frame_metadata() {
     let temp = NamedTempFile::new().expect("temp atlas");
@@ -476,6 +676,63 @@ fn sprite_animation_hot_reload_handles_duplicate_regions() {
     assert_eq!(sprite.region.as_ref(), "redorb");
 }
 
+#[test]
+fn atlas_reload_reports_and_remaps_missing_references() {
+    let temp = NamedTempFile::new().expect("temp atlas");
+    let source = std::fs::read("assets/images/atlas.json").expect("read atlas");
+    std::fs::write(temp.path(), &source).expect("write copy");
+    let temp_path = temp.path().to_path_buf();
+
+    let mut assets = AssetManager::new();
+    assets.retain_atlas("main", temp_path.to_str()).expect("load atlas from temp");
+    let mut ecs = EcsWorld::new();
+    let boxes: Vec<Entity> = (0..3)
+        .map(|_| {
+            ecs.world
+                .spawn((Transform::default(), Sprite::uninitialized(Arc::from("main"), Arc::from("bluebox"))))
+                .id()
+        })
+        .collect();
+    let animated = ecs
+        .world
+        .spawn((Transform::default(), Sprite::uninitialized(Arc::from("main"), Arc::from("redorb"))))
+        .id();
+    assert!(ecs.set_sprite_timeline(animated, &assets, Some("checker_pulse")));
+    assert!(ecs.missing_atlas_references("main", &assets).is_empty());
+
+    let mut atlas_json: serde_json::Value = serde_json::from_slice(&source).expect("parse atlas");
+    let region = atlas_json["regions"]["bluebox"].take();
+    atlas_json["regions"].as_object_mut().expect("regions").remove("bluebox");
+    atlas_json["regions"]["cyanbox"] = region;
+    atlas_json["animations"]["demo_cycle"]["frames"][1]["region"] = json!("cyanbox");
+    let timeline = atlas_json["animations"]["checker_pulse"].take();
+    atlas_json["animations"].as_object_mut().expect("animations").remove("checker_pulse");
+    atlas_json["animations"]["checker_flash"] = timeline;
+    std::fs::write(&temp_path, serde_json::to_vec_pretty(&atlas_json).expect("encode"))
+        .expect("write renamed atlas");
+    assets.reload_atlas("main").expect("reload atlas");
+    ecs.refresh_sprite_animations_for_atlas("main", &assets);
+
+    let missing = ecs.missing_atlas_references("main", &assets);
+    assert_eq!(missing.len(), 4);
+    let regions: Vec<Entity> = missing
+        .iter()
+        .filter(|reference| reference.kind == AtlasReferenceKind::Region)
+        .map(|reference| reference.entity)
+        .collect();
+    assert_eq!(regions.len(), boxes.len());
+    assert!(boxes.iter().all(|entity| regions.contains(entity)));
+    assert!(missing.iter().any(|reference| reference.entity == animated
+        && reference.kind == AtlasReferenceKind::Timeline
+        && reference.name.as_ref() == "checker_pulse"));
+
+    assert_eq!(ecs.remap_sprite_region("main", "bluebox", "missing", &assets), 0);
+    assert_eq!(ecs.remap_sprite_region("main", "bluebox", "cyanbox", &assets), 3);
+    assert_eq!(ecs.remap_sprite_timeline("main", "checker_pulse", "checker_flash", &assets), 1);
+    assert!(ecs.missing_atlas_references("main", &assets).is_empty());
+    assert_eq!(sprite_region(&ecs, boxes[0]), "cyanbox");
+}
+
 #[test]
 fn sprite_animation_hot_reload_prefers_frame_names() {
     let temp = NamedTempFile::new().expect("temp atlas");
@@ -828,3 +1085,71 @@ fn simd_mixed_bucket_regression() {
         );
     }
 }
+
+#[test]
+fn sprite_instance_collection_tracks_dirty_atlases() {
+    let mut assets = AssetManager::new();
+    assets.retain_atlas("main", Some("assets/images/atlas.json")).expect("load main atlas");
+    let mut ecs = EcsWorld::new();
+    let spawn = |ecs: &mut EcsWorld, x: f32| {
+        ecs.world
+            .spawn((
+                Transform { translation: Vec2::new(x, 0.0), ..Default::default() },
+                Sprite::uninitialized(Arc::from("main"), Arc::from("redorb")),
+            ))
+            .id()
+    };
+    let first = spawn(&mut ecs, 0.0);
+    let second = spawn(&mut ecs, 1.0);
+    let _third = spawn(&mut ecs, 2.0);
+
+    ecs.collect_sprite_instances(&assets).expect("collect sprites");
+    assert!(ecs.sprite_atlas_dirty("main"), "first collection uploads everything");
+    ecs.collect_sprite_instances(&assets).expect("collect sprites");
+    assert!(!ecs.sprite_atlas_dirty("main"), "untouched sprites keep the bucket clean");
+
+    assert!(ecs.set_translation(first, Vec2::new(5.0, 5.0)));
+    ecs.collect_sprite_instances(&assets).expect("collect sprites");
+    assert!(ecs.sprite_atlas_dirty("main"), "moved sprite dirties its bucket");
+    ecs.collect_sprite_instances(&assets).expect("collect sprites");
+    assert!(!ecs.sprite_atlas_dirty("main"));
+
+    assert!(ecs.despawn_entity(second));
+    let instances = ecs.collect_sprite_instances(&assets).expect("collect sprites");
+    assert_eq!(instances.len(), 2);
+    assert!(ecs.sprite_atlas_dirty("main"), "removing a sprite mid-bucket dirties it");
+    assert!(ecs.sprite_atlas_dirty("unknown"), "atlases absent from the last collection report dirty");
+}
+
+#[test]
+fn static_sprite_set_tracks_membership_changes() {
+    let mut assets = AssetManager::new();
+    assets.retain_atlas("main", Some("assets/images/atlas.json")).expect("load main atlas");
+    let mut ecs = EcsWorld::new();
+    let still = ecs
+        .world
+        .spawn((Transform::default(), Sprite::uninitialized(Arc::from("main"), Arc::from("redorb"))))
+        .id();
+    ecs.world.spawn((
+        Transform { translation: Vec2::new(1.0, 0.0), ..Default::default() },
+        Sprite::uninitialized(Arc::from("main"), Arc::from("redorb")),
+        Velocity(Vec2::new(1.0, 0.0)),
+    ));
+    let static_count = |ecs: &mut EcsWorld| {
+        ecs.collect_sprite_instances(&assets)
+            .expect("collect sprites")
+            .iter()
+            .filter(|instance| instance.static_sprite)
+            .count()
+    };
+
+    assert_eq!(static_count(&mut ecs), 0, "freshly spawned sprites are not static yet");
+    assert_eq!(static_count(&mut ecs), 1, "moving sprites never join the static set");
+    assert!(ecs.static_sprites_dirty(), "a settled sprite changes the static set");
+    assert_eq!(static_count(&mut ecs), 1);
+    assert!(!ecs.static_sprites_dirty(), "an unchanged static set stays clean");
+
+    assert!(ecs.set_translation(still, Vec2::new(3.0, 3.0)));
+    assert_eq!(static_count(&mut ecs), 0);
+    assert!(ecs.static_sprites_dirty(), "moving a static sprite invalidates the bake");
+}
@@ -0,0 +1,125 @@
+use glam::Vec2;
+use kestrel_engine::assets::AssetManager;
+use kestrel_engine::ecs::{EcsWorld, SceneEntityTag, Sprite, Transform, WorldTransform};
+use kestrel_engine::scene::{Scene, SceneEntityId};
+use std::path::Path;
+use std::sync::Arc;
+
+fn write_flat_normal_map(path: &Path) {
+    image::RgbaImage::from_pixel(4, 4, image::Rgba([128, 128, 255, 255]))
+        .save(path)
+        .expect("write normal map");
+}
+
+fn spawn_sprite(world: &mut EcsWorld) -> bevy_ecs::prelude::Entity {
+    world
+        .world
+        .spawn((
+            Transform { translation: Vec2::ZERO, rotation: 0.0, scale: Vec2::ONE },
+            WorldTransform::default(),
+            SceneEntityTag::new(SceneEntityId::new()),
+            Sprite::uninitialized(Arc::from("main"), Arc::from("redorb")),
+        ))
+        .id()
+}
+
+fn main_atlas() -> AssetManager {
+    let mut assets = AssetManager::new();
+    assets.retain_atlas("main", Some("assets/images/atlas.json")).expect("load main atlas");
+    assets
+}
+
+#[test]
+fn set_sprite_normal_map_retains_and_releases_images() {
+    let temp_dir = tempfile::tempdir().expect("temp dir");
+    let first = temp_dir.path().join("first_n.png");
+    let second = temp_dir.path().join("second_n.png");
+    write_flat_normal_map(&first);
+    write_flat_normal_map(&second);
+    let (first, second) = (first.to_string_lossy().into_owned(), second.to_string_lossy().into_owned());
+    let mut assets = main_atlas();
+    let mut world = EcsWorld::new();
+    let sprite = spawn_sprite(&mut world);
+
+    assert!(world.set_sprite_normal_map(sprite, Some(&first), &mut assets).expect("assign normal map"));
+    assert_eq!(assets.normal_map_ref_count(&first), 1);
+    assert_eq!(assets.normal_map_dimensions(&first), Some((4, 4)));
+    assert!(world.set_sprite_normal_map(sprite, Some(&first), &mut assets).expect("reassign"));
+    assert_eq!(assets.normal_map_ref_count(&first), 1, "reassigning the same map keeps one reference");
+
+    assert!(world.set_sprite_normal_map(sprite, Some(&second), &mut assets).expect("swap normal map"));
+    assert!(!assets.has_normal_map(&first), "the replaced map is released");
+    assert_eq!(world.sprite_normal_map(sprite).as_deref(), Some(second.as_str()));
+
+    assert!(world.set_sprite_normal_map(sprite, None, &mut assets).expect("clear normal map"));
+    assert!(!assets.has_normal_map(&second));
+    assert!(world.sprite_normal_map(sprite).is_none());
+
+    let missing = temp_dir.path().join("missing.png");
+    assert!(world.set_sprite_normal_map(sprite, missing.to_str(), &mut assets).is_err());
+    assert!(world.sprite_normal_map(sprite).is_none(), "a failed load leaves the sprite unchanged");
+
+    let bare = world.world.spawn(Transform::default()).id();
+    assert!(!world.set_sprite_normal_map(bare, Some(&first), &mut assets).expect("no sprite"));
+    assert!(!assets.has_normal_map(&first), "nothing is retained for entities without a sprite");
+}
+
+#[test]
+fn normal_map_reaches_sprite_instances_and_scene_files() {
+    let temp_dir = tempfile::tempdir().expect("temp dir");
+    let normal_path = temp_dir.path().join("orb_n.png");
+    write_flat_normal_map(&normal_path);
+    let normal_path = normal_path.to_string_lossy().into_owned();
+    let mut assets = main_atlas();
+    let mut world = EcsWorld::new();
+    let lit = spawn_sprite(&mut world);
+    spawn_sprite(&mut world);
+    world.set_sprite_normal_map(lit, Some(&normal_path), &mut assets).expect("assign normal map");
+
+    let instances = world.collect_sprite_instances(&assets).expect("collect sprites");
+    let mut normal_maps: Vec<Option<&str>> =
+        instances.iter().map(|instance| instance.normal_map.as_deref()).collect();
+    normal_maps.sort();
+    assert_eq!(normal_maps, vec![None, Some(normal_path.as_str())]);
+    assert_eq!(
+        world.entity_info(lit).and_then(|info| info.sprite).and_then(|sprite| sprite.normal_map),
+        Some(normal_path.clone())
+    );
+
+    let scene_path = temp_dir.path().join("lit.json");
+    world.export_scene(&assets).save_to_path(&scene_path).expect("save scene");
+    let json = std::fs::read_to_string(&scene_path).expect("read scene");
+    assert_eq!(json.matches("normal_map").count(), 1, "unlit sprites omit the field");
+
+    let mut loaded = EcsWorld::new();
+    loaded
+        .load_scene(&Scene::load_from_path(&scene_path).expect("load scene"), &assets)
+        .expect("instantiate");
+    let instances = loaded.collect_sprite_instances(&assets).expect("collect loaded sprites");
+    assert_eq!(instances.iter().filter(|instance| instance.normal_map.is_some()).count(), 1);
+}
+
+#[test]
+fn normal_map_references_follow_scene_loads_despawns_and_clears() {
+    let temp_dir = tempfile::tempdir().expect("temp dir");
+    let normal_path = temp_dir.path().join("orb_n.png");
+    write_flat_normal_map(&normal_path);
+    let normal_path = normal_path.to_string_lossy().into_owned();
+    let mut assets = main_atlas();
+    let mut world = EcsWorld::new();
+    let lit = spawn_sprite(&mut world);
+    world.set_sprite_normal_map(lit, Some(&normal_path), &mut assets).expect("assign normal map");
+    let scene = world.export_scene(&assets);
+    world.despawn_entity(lit);
+    world.sync_normal_map_refs(&mut assets);
+    assert!(!assets.has_normal_map(&normal_path), "despawning the sprite releases its map");
+
+    let mut loaded = EcsWorld::new();
+    loaded.load_scene(&scene, &assets).expect("instantiate");
+    loaded.sync_normal_map_refs(&mut assets);
+    assert_eq!(assets.normal_map_ref_count(&normal_path), 1, "scene sprites retain their map");
+
+    loaded.clear_world();
+    loaded.sync_normal_map_refs(&mut assets);
+    assert!(!assets.has_normal_map(&normal_path), "clearing the scene releases every map");
+}
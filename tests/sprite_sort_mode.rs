@@ -0,0 +1,66 @@
+use glam::Vec2;
+use kestrel_engine::assets::AssetManager;
+use kestrel_engine::config::{AppConfig, SpriteSortMode};
+use kestrel_engine::ecs::{EcsWorld, SceneEntityTag, Sprite, Transform, WorldTransform, ZOrder};
+use kestrel_engine::renderer::sort_sprite_instances;
+use kestrel_engine::scene::SceneEntityId;
+use std::sync::Arc;
+
+fn spawn_sprite(world: &mut EcsWorld, y: f32, z_order: i16) {
+    world.world.spawn((
+        Transform { translation: Vec2::new(0.0, y), rotation: 0.0, scale: Vec2::ONE },
+        WorldTransform::default(),
+        SceneEntityTag::new(SceneEntityId::new()),
+        Sprite::uninitialized(Arc::from("main"), Arc::from("redorb")),
+        ZOrder(z_order),
+    ));
+}
+
+fn collected_ys(world: &mut EcsWorld, assets: &AssetManager, mode: SpriteSortMode) -> Vec<f32> {
+    let mut instances = world.collect_sprite_instances(assets).expect("collect sprites");
+    sort_sprite_instances(&mut instances, mode);
+    instances.iter().map(|instance| instance.transform.translation.y).collect()
+}
+
+#[test]
+fn sort_modes_order_sprites_by_y_z_or_creation() {
+    let mut assets = AssetManager::new();
+    assets.retain_atlas("main", Some("assets/images/atlas.json")).expect("load main atlas");
+    let mut world = EcsWorld::new();
+    spawn_sprite(&mut world, 1.0, 0);
+    spawn_sprite(&mut world, 3.0, 0);
+    spawn_sprite(&mut world, 2.0, 0);
+    world.update(0.0);
+
+    assert_eq!(collected_ys(&mut world, &assets, SpriteSortMode::CreationOrder), vec![1.0, 3.0, 2.0]);
+    assert_eq!(
+        collected_ys(&mut world, &assets, SpriteSortMode::YSort),
+        vec![3.0, 2.0, 1.0],
+        "higher sprites draw first"
+    );
+    assert_eq!(
+        collected_ys(&mut world, &assets, SpriteSortMode::ZOrder),
+        vec![1.0, 3.0, 2.0],
+        "equal z keeps creation order"
+    );
+
+    spawn_sprite(&mut world, 4.0, -1);
+    world.update(0.0);
+    assert_eq!(collected_ys(&mut world, &assets, SpriteSortMode::ZOrder)[0], 4.0);
+}
+
+#[test]
+fn sprite_sort_mode_defaults_and_persists_into_config() {
+    let window =
+        r#""window": { "title": "Sorted", "width": 640, "height": 480, "vsync": true, "fullscreen": false }"#;
+    let config: AppConfig = serde_json::from_str(&format!("{{ {window} }}")).expect("config parses");
+    assert_eq!(config.render.sprite_sort_mode, SpriteSortMode::CreationOrder);
+
+    let temp_dir = tempfile::tempdir().expect("temp dir");
+    let path = temp_dir.path().join("app.json");
+    std::fs::write(&path, format!(r#"{{ {window}, "render": {{}} }}"#)).expect("write config");
+    AppConfig::persist_sprite_sort_mode(&path, SpriteSortMode::YSort).expect("persist sort mode");
+    let config = AppConfig::load(&path).expect("reload config");
+    assert_eq!(config.render.sprite_sort_mode, SpriteSortMode::YSort);
+    assert_eq!(config.window.title, "Sorted", "other settings survive");
+}
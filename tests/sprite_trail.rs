@@ -0,0 +1,72 @@
+use bevy_ecs::prelude::Entity;
+use glam::Vec2;
+use kestrel_engine::assets::AssetManager;
+use kestrel_engine::ecs::{EcsWorld, Sprite, SpriteInstance, SpriteTrail, Transform, WorldTransform};
+use std::sync::Arc;
+
+fn spawn_trailing_sprite(world: &mut EcsWorld) -> Entity {
+    let entity = world
+        .world
+        .spawn((
+            Transform { translation: Vec2::ZERO, rotation: 0.0, scale: Vec2::ONE },
+            WorldTransform::default(),
+            Sprite::uninitialized(Arc::from("main"), Arc::from("redorb")),
+        ))
+        .id();
+    assert!(world.set_sprite_trail(entity, Some(SpriteTrail::new(3, 0.5))));
+    entity
+}
+
+fn step_to(world: &mut EcsWorld, assets: &AssetManager, entity: Entity, x: f32) -> Vec<SpriteInstance> {
+    world.world.get_mut::<Transform>(entity).expect("transform").translation = Vec2::new(x, 0.0);
+    world.update(0.016);
+    world.collect_sprite_instances(assets).expect("collect sprites")
+}
+
+#[test]
+fn moving_sprite_emits_faded_copies_at_its_previous_positions() {
+    let assets = AssetManager::new();
+    let mut world = EcsWorld::new();
+    let entity = spawn_trailing_sprite(&mut world);
+
+    for x in 0..3 {
+        step_to(&mut world, &assets, entity, x as f32);
+    }
+    let instances = step_to(&mut world, &assets, entity, 3.0);
+
+    assert_eq!(instances.len(), 4, "length-3 trail adds three copies");
+    assert!(
+        instances.iter().all(|instance| instance.atlas.as_ref() == "main"),
+        "copies share the atlas batch"
+    );
+    let positions: Vec<f32> = instances.iter().map(|instance| instance.transform.translation.x).collect();
+    assert_eq!(positions, [0.0, 1.0, 2.0, 3.0], "oldest copy first, sprite last");
+    let alphas: Vec<f32> = instances.iter().map(|instance| instance.tint[3]).collect();
+    assert_eq!(alphas, [0.125, 0.25, 0.5, 1.0], "each step back multiplies alpha by the fade");
+
+    // Standing still lets the copies catch up with the sprite until none are left.
+    let mut instances = Vec::new();
+    for _ in 0..4 {
+        instances = step_to(&mut world, &assets, entity, 3.0);
+    }
+    assert_eq!(instances.len(), 1);
+    assert!(world.sprite_atlas_dirty("main"), "the bucket is re-uploaded when the last copy disappears");
+}
+
+#[test]
+fn despawning_drops_the_trail_history() {
+    let assets = AssetManager::new();
+    let mut world = EcsWorld::new();
+    let entity = spawn_trailing_sprite(&mut world);
+    for x in 0..4 {
+        step_to(&mut world, &assets, entity, x as f32);
+    }
+    assert!(world.despawn_entity(entity));
+
+    let respawned = spawn_trailing_sprite(&mut world);
+    let instances = step_to(&mut world, &assets, respawned, 10.0);
+    assert_eq!(instances.len(), 1, "a new trail starts empty");
+    assert_eq!(SpriteTrail::new(100, 2.0).length(), SpriteTrail::MAX_LENGTH);
+    let plain = world.world.spawn(Transform::default()).id();
+    assert!(!world.set_sprite_trail(plain, Some(SpriteTrail::new(3, 0.5))), "trails need a sprite");
+}
@@ -0,0 +1,44 @@
+use kestrel_engine::assets::AssetManager;
+use kestrel_engine::config::AppConfig;
+use kestrel_engine::ecs::EcsWorld;
+use kestrel_engine::scene::{Scene, SceneCameraBookmark, SceneViewportMode, Vec2Data};
+
+fn write_startup_scene(path: &std::path::Path, assets: &AssetManager) -> usize {
+    let mut world = EcsWorld::new();
+    world.spawn_demo_scene();
+    let mut scene = world.export_scene(assets);
+    scene.metadata.viewport = SceneViewportMode::Perspective3D;
+    scene.metadata.camera_bookmarks = vec![SceneCameraBookmark {
+        name: "Start".to_string(),
+        position: Vec2Data { x: 3.0, y: -2.0 },
+        zoom: 1.5,
+    }];
+    scene.metadata.active_camera_bookmark = Some("Start".to_string());
+    scene.save_to_path(path).expect("save startup scene");
+    scene.entities.len()
+}
+
+#[test]
+fn configured_startup_scene_loads_entities_and_metadata() {
+    let mut assets = AssetManager::new();
+    assets.retain_atlas("main", Some("assets/images/atlas.json")).expect("main atlas should load");
+    let dir = tempfile::tempdir().expect("temp dir");
+    let scene_path = dir.path().join("startup.json");
+    let entity_count = write_startup_scene(&scene_path, &assets);
+    assert!(entity_count > 0);
+
+    let json = format!(
+        r#"{{ "window": {{ "title": "Kestrel", "width": 640, "height": 360, "vsync": false, "fullscreen": false }},
+            "startup_scene": {} }}"#,
+        serde_json::to_string(&scene_path.display().to_string()).unwrap()
+    );
+    let config: AppConfig = serde_json::from_str(&json).expect("config parses");
+    let startup = config.startup_scene.clone().expect("startup scene configured");
+
+    let scene = Scene::load_from_path(&startup).expect("startup scene loads");
+    let mut world = EcsWorld::new();
+    world.load_scene(&scene, &assets).expect("startup scene applies to the world");
+    assert_eq!(world.export_scene(&assets).entities.len(), entity_count);
+    assert_eq!(scene.metadata.viewport, SceneViewportMode::Perspective3D);
+    assert_eq!(scene.metadata.active_camera_bookmark.as_deref(), Some("Start"));
+}
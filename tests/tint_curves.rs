@@ -0,0 +1,155 @@
+use glam::Vec4;
+use kestrel_engine::assets::{parse_color_curve_bytes, AssetManager};
+use kestrel_engine::ecs::{EcsWorld, SpriteAnimationLoopMode, Tint, TintCurve, Transform, WorldTransform};
+use kestrel_engine::scripts::{ScriptCommand, ScriptHost};
+use std::sync::Arc;
+
+const DAMAGE_FLASH: &str = "assets/animations/curves/damage_flash.json";
+
+fn approx_vec4(a: Vec4, b: Vec4) -> bool {
+    (a - b).length_squared() <= 1e-6
+}
+
+fn spawn_target(ecs: &mut EcsWorld) -> bevy_ecs::prelude::Entity {
+    ecs.world.spawn((Transform::default(), WorldTransform::default())).id()
+}
+
+#[test]
+fn color_curve_parses_and_samples_normalized_stops() {
+    let curve = parse_color_curve_bytes(
+        br#"{
+            "version": 1,
+            "stops": [
+                { "time": 0.0, "color": [0.0, 0.0, 0.0, 1.0] },
+                { "time": 0.5, "color": [1.0, 1.0, 1.0, 1.0] }
+            ]
+        }"#,
+        "fade",
+        "inline",
+    )
+    .expect("parse curve");
+    assert_eq!(curve.name.as_ref(), "fade");
+    assert!(approx_vec4(curve.sample(0.25), Vec4::new(0.5, 0.5, 0.5, 1.0)));
+    assert!(approx_vec4(curve.sample(0.9), Vec4::ONE), "times past the last stop hold it");
+
+    let err = parse_color_curve_bytes(
+        br#"{ "version": 1, "stops": [{ "time": 1.5, "color": [1.0, 1.0, 1.0, 1.0] }] }"#,
+        "bad",
+        "inline",
+    )
+    .err()
+    .expect("stop times above 1 are rejected");
+    assert!(format!("{err:#}").contains("[0, 1]"), "unexpected error: {err:#}");
+}
+
+#[test]
+fn tint_curve_drives_tint_and_stops_when_finished() {
+    let mut assets = AssetManager::new();
+    assets.load_color_curve("damage_flash", DAMAGE_FLASH).expect("load curve");
+    let mut ecs = EcsWorld::new();
+    let entity = spawn_target(&mut ecs);
+
+    assert!(!ecs.set_tint_curve(entity, &assets, "missing", 1.0, SpriteAnimationLoopMode::Loop));
+    assert!(ecs.set_tint_curve(entity, &assets, "damage_flash", 1.0, SpriteAnimationLoopMode::OnceStop));
+    assert!(approx_vec4(ecs.world.get::<Tint>(entity).expect("tint inserted").0, Vec4::ONE));
+
+    ecs.update(0.15);
+    let flash = ecs.world.get::<Tint>(entity).expect("tint").0;
+    assert!(approx_vec4(flash, Vec4::new(1.0, 0.2, 0.2, 1.0)), "unexpected flash color {flash:?}");
+
+    ecs.update(2.0);
+    assert!(ecs.world.get::<TintCurve>(entity).is_none(), "once_stop removes the curve");
+    assert!(approx_vec4(ecs.world.get::<Tint>(entity).expect("tint").0, Vec4::ONE));
+}
+
+#[test]
+fn refreshing_a_color_curve_updates_every_user() {
+    let mut assets = AssetManager::new();
+    assets.load_color_curve("damage_flash", DAMAGE_FLASH).expect("load curve");
+    let mut ecs = EcsWorld::new();
+    let a = spawn_target(&mut ecs);
+    let b = spawn_target(&mut ecs);
+    for entity in [a, b] {
+        assert!(ecs.set_tint_curve(entity, &assets, "damage_flash", 2.0, SpriteAnimationLoopMode::Loop));
+    }
+    let original = Arc::clone(&ecs.world.get::<TintCurve>(a).expect("curve").curve);
+    assert!(Arc::ptr_eq(&original, &ecs.world.get::<TintCurve>(b).expect("curve").curve));
+
+    let replacement = parse_color_curve_bytes(
+        br#"{ "version": 2, "stops": [{ "time": 0.0, "color": [0.0, 1.0, 0.0, 1.0] }] }"#,
+        "damage_flash",
+        DAMAGE_FLASH,
+    )
+    .expect("parse replacement");
+    assets.replace_color_curve("damage_flash", DAMAGE_FLASH, replacement);
+    assert_eq!(ecs.refresh_color_curve(&assets, "damage_flash"), 2);
+
+    ecs.update(0.1);
+    for entity in [a, b] {
+        assert!(approx_vec4(ecs.world.get::<Tint>(entity).expect("tint").0, Vec4::new(0.0, 1.0, 0.0, 1.0)));
+        let curve = ecs.world.get::<TintCurve>(entity).expect("curve");
+        assert_eq!(curve.curve.version, 2);
+        assert!((curve.elapsed - 0.1).abs() < 1e-5, "refresh keeps playback position");
+    }
+}
+
+#[test]
+fn tint_curve_roundtrips_through_scene_with_dependency() {
+    let mut assets = AssetManager::new();
+    assets.load_color_curve("damage_flash", DAMAGE_FLASH).expect("load curve");
+    let mut ecs = EcsWorld::new();
+    let entity = spawn_target(&mut ecs);
+    assert!(ecs.set_tint_curve(entity, &assets, "damage_flash", 0.5, SpriteAnimationLoopMode::PingPong));
+    ecs.update(0.2);
+
+    let scene = ecs.export_scene(&assets);
+    let dependency =
+        scene.dependencies.color_curve_dependencies().next().expect("color curve dependency recorded");
+    assert_eq!(dependency.key(), "damage_flash");
+    assert_eq!(dependency.path(), Some(DAMAGE_FLASH));
+    let data = scene.entities.iter().find_map(|entity| entity.tint_curve.as_ref()).expect("tint curve data");
+    assert_eq!(data.loop_mode.as_deref(), Some("pingpong"));
+
+    let mut fresh_assets = AssetManager::new();
+    let mut restored = EcsWorld::new();
+    restored.load_scene(&scene, &fresh_assets).expect_err("unloaded curves are reported");
+    fresh_assets.load_color_curve("damage_flash", DAMAGE_FLASH).expect("load curve");
+    restored.load_scene(&scene, &fresh_assets).expect("load scene");
+    let mut query = restored.world.query::<&TintCurve>();
+    let curve = query.iter(&restored.world).next().expect("tint curve restored");
+    assert_eq!(curve.loop_mode, SpriteAnimationLoopMode::PingPong);
+    assert!((curve.duration - 0.5).abs() < 1e-6);
+    assert!((curve.elapsed - 0.2).abs() < 1e-5);
+}
+
+#[test]
+fn script_tint_curve_resolves_directory_preloaded_curve_without_scene() {
+    let mut assets = AssetManager::new();
+    let keys = assets.retain_color_curve_directory("assets/animations/curves").expect("preload curves");
+    assert!(keys.iter().any(|key| key == "damage_flash"), "unexpected keys {keys:?}");
+    assert!(assets
+        .retain_color_curve_directory("assets/animations/missing")
+        .expect("missing dir")
+        .is_empty());
+
+    let dir = tempfile::tempdir().expect("temp dir");
+    let script_path = dir.path().join("tint.rhai");
+    std::fs::write(&script_path, "fn init(world) {}\nfn update(world, dt) {}\n").expect("write script");
+    let mut host = ScriptHost::new(&script_path);
+    host.force_reload(None).expect("load script");
+    host.eval_repl(r#"let h = world.spawn_sprite("main", "green", 0.0, 0.0, 1.0, 0.0, 0.0); world.set_tint_curve(h, "damage_flash", 0.25);"#)
+        .expect("repl command");
+
+    let mut ecs = EcsWorld::new();
+    let entity = spawn_target(&mut ecs);
+    let (curve, duration) = host
+        .drain_commands()
+        .into_iter()
+        .find_map(|command| match command {
+            ScriptCommand::SetTintCurve { curve, duration, .. } => Some((curve, duration)),
+            _ => None,
+        })
+        .expect("set_tint_curve command");
+    assert!(ecs.set_tint_curve(entity, &assets, &curve, duration, SpriteAnimationLoopMode::OnceStop));
+    assert!(ecs.world.get::<TintCurve>(entity).is_some());
+}
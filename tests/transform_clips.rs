@@ -1,6 +1,7 @@
 use glam::{Vec2, Vec4};
 use kestrel_engine::assets::AssetManager;
 use kestrel_engine::ecs::{ClipInstance, EcsWorld, PropertyTrackPlayer, Tint, Transform, WorldTransform};
+use kestrel_engine::events::GameEvent;
 use std::sync::Arc;
 
 fn approx_vec2(a: Vec2, b: Vec2) -> bool {
@@ -416,3 +417,112 @@ fn transform_clip_applies_when_components_added_late() {
     let sample_tint = sample.tint.expect("tint track missing");
     assert!(approx_vec4(tint, sample_tint));
 }
+
+fn clip_event_messages(ecs: &mut EcsWorld) -> Vec<String> {
+    ecs.drain_events()
+        .into_iter()
+        .filter_map(|event| match event {
+            GameEvent::ScriptMessage { message } => Some(message),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn transform_clip_events_fire_on_forward_crossings() {
+    let mut assets = AssetManager::new();
+    assets.retain_clip("slime", Some("fixtures/animation_clips/slime_bob.json")).expect("load slime clip");
+
+    let mut ecs = EcsWorld::new();
+    let entity = ecs.world.spawn((Transform::default(), WorldTransform::default(), Tint(Vec4::ONE))).id();
+    assert!(ecs.set_transform_clip(entity, &assets, "slime"), "attach clip");
+    assert!(ecs.set_transform_clip_events(
+        entity,
+        vec![(0.3, "late".to_string()), (0.1, "early".to_string()), (0.0, "loop_start".to_string())]
+    ));
+    let _ = ecs.drain_events();
+
+    ecs.update(0.15);
+    assert_eq!(clip_event_messages(&mut ecs), vec!["early".to_string()]);
+
+    ecs.update(0.2);
+    assert_eq!(clip_event_messages(&mut ecs), vec!["late".to_string()]);
+
+    // Crossing the loop boundary fires the marker at t=0 once.
+    ecs.update(0.2);
+    assert_eq!(clip_event_messages(&mut ecs), vec!["loop_start".to_string()]);
+}
+
+#[test]
+fn transform_clip_events_do_not_refire_when_reversing() {
+    let mut assets = AssetManager::new();
+    assets.retain_clip("slime", Some("fixtures/animation_clips/slime_bob.json")).expect("load slime clip");
+
+    let mut ecs = EcsWorld::new();
+    let entity = ecs.world.spawn((Transform::default(), WorldTransform::default(), Tint(Vec4::ONE))).id();
+    assert!(ecs.set_transform_clip(entity, &assets, "slime"), "attach clip");
+    assert!(ecs.set_transform_clip_events(entity, vec![(0.1, "hit".to_string())]));
+    let _ = ecs.drain_events();
+
+    ecs.update(0.2);
+    assert_eq!(clip_event_messages(&mut ecs), vec!["hit".to_string()]);
+
+    ecs.set_animation_time_scale(-1.0);
+    ecs.update(0.15);
+    let rewound_time = ecs.world.get::<ClipInstance>(entity).expect("clip instance").time;
+    assert!(rewound_time < 0.1, "playhead should rewind past the event, got {rewound_time}");
+    assert!(clip_event_messages(&mut ecs).is_empty(), "rewinding must not re-fire past events");
+
+    ecs.update(0.02);
+    assert!(clip_event_messages(&mut ecs).is_empty(), "continued rewind must stay silent");
+}
+
+#[test]
+fn transform_clip_loop_count_stops_after_counted_cycles() {
+    let mut assets = AssetManager::new();
+    assets.retain_clip("slime", Some("fixtures/animation_clips/slime_bob.json")).expect("load slime clip");
+
+    let mut ecs = EcsWorld::new();
+    let entity = ecs.world.spawn((Transform::default(), WorldTransform::default(), Tint(Vec4::ONE))).id();
+    assert!(ecs.set_transform_clip(entity, &assets, "slime"));
+    assert!(ecs.set_transform_clip_loop_count(entity, Some(2)));
+    let duration = ecs.world.get::<ClipInstance>(entity).unwrap().duration();
+    ecs.drain_events();
+
+    let count_finished = |events: &[GameEvent]| {
+        events
+            .iter()
+            .filter(|event| {
+                matches!(event, GameEvent::ClipFinished { entity: finished, clip }
+                    if *finished == entity && clip.as_ref() == "slime")
+            })
+            .count()
+    };
+
+    let step = 0.05;
+    let mut elapsed = 0.0;
+    let mut finished = 0;
+    while elapsed + step < duration * 2.0 - 1e-3 {
+        ecs.update(step);
+        elapsed += step;
+        finished += count_finished(&ecs.drain_events());
+        assert!(ecs.world.get::<ClipInstance>(entity).unwrap().playing, "still inside the second cycle");
+    }
+    assert_eq!(finished, 0);
+
+    for _ in 0..20 {
+        ecs.update(step);
+        finished += count_finished(&ecs.drain_events());
+    }
+    assert_eq!(finished, 1, "counted loops report completion exactly once");
+    let instance = ecs.world.get::<ClipInstance>(entity).unwrap();
+    assert!(!instance.playing, "playback stops after two cycles");
+    assert_eq!(instance.loops_completed, 2);
+    assert!(approx_scalar(instance.time, duration), "holds the end of the clip");
+    let translation = ecs.world.get::<Transform>(entity).unwrap().translation;
+    assert!(approx_vec2(translation, Vec2::ZERO), "end pose is applied");
+
+    assert!(ecs.reset_transform_clip(entity));
+    ecs.update(duration * 1.5);
+    assert!(ecs.world.get::<ClipInstance>(entity).unwrap().playing, "reset restarts the loop count");
+}
@@ -0,0 +1,130 @@
+use bevy_ecs::prelude::Entity;
+use glam::{Vec2, Vec4};
+use kestrel_engine::ecs::{EcsWorld, FrameInputLog, Velocity};
+
+const TEST_DT: f32 = 1.0 / 60.0;
+
+#[derive(Clone, Copy)]
+enum TestInput {
+    Burst(u32),
+    Push(Vec2),
+}
+
+struct Fixture {
+    world: EcsWorld,
+    emitter: Entity,
+    pushed: Entity,
+}
+
+fn spawn_fixture() -> Fixture {
+    let mut world = EcsWorld::new();
+    let pushed = world.spawn_demo_scene();
+    let emitter = world.spawn_particle_emitter(
+        Vec2::new(0.2, -0.1),
+        40.0,
+        0.8,
+        1.5,
+        0.6,
+        Vec4::ONE,
+        Vec4::new(1.0, 0.4, 0.1, 0.0),
+        0.05,
+        0.01,
+    );
+    Fixture { world, emitter, pushed }
+}
+
+fn step(world: &mut EcsWorld, emitter: Entity, pushed: Entity, inputs: &[TestInput]) {
+    for input in inputs {
+        match *input {
+            TestInput::Burst(count) => {
+                world.emitter_burst(emitter, count);
+            }
+            TestInput::Push(delta) => {
+                if let Some(mut velocity) = world.world.get_mut::<Velocity>(pushed) {
+                    velocity.0 += delta;
+                }
+            }
+        }
+    }
+    world.fixed_step(TEST_DT);
+    world.update(TEST_DT);
+}
+
+fn scripted_inputs() -> FrameInputLog<TestInput> {
+    let mut log = FrameInputLog::new();
+    log.record(2, TestInput::Burst(12));
+    log.record(5, TestInput::Push(Vec2::new(0.3, -0.2)));
+    log.record(5, TestInput::Burst(4));
+    log.record(17, TestInput::Push(Vec2::new(-0.5, 0.1)));
+    log
+}
+
+#[test]
+fn restore_and_replay_reproduces_identical_state() {
+    let Fixture { mut world, emitter, pushed } = spawn_fixture();
+    for _ in 0..10 {
+        step(&mut world, emitter, pushed, &[]);
+    }
+
+    let snapshot = world.snapshot();
+    let log = scripted_inputs();
+    for frame in 0..30 {
+        step(&mut world, emitter, pushed, log.inputs(frame));
+    }
+    let original = world.snapshot();
+    assert_ne!(snapshot.content_hash(), original.content_hash(), "stepping should change the world state");
+
+    world.restore(&snapshot);
+    assert_eq!(
+        world.snapshot().content_hash(),
+        snapshot.content_hash(),
+        "restore returns to the captured state"
+    );
+
+    let respawned =
+        world.replay(&snapshot, &log, 0..30, |world, inputs| step(world, emitter, pushed, inputs));
+    assert!(
+        !respawned.contains_key(&emitter) && !respawned.contains_key(&pushed),
+        "only expired particles respawn"
+    );
+    let replayed = world.snapshot();
+    assert_eq!(replayed.entity_count(), original.entity_count());
+    assert_eq!(replayed.content_hash(), original.content_hash(), "replaying the same inputs diverged");
+}
+
+#[test]
+fn restore_respawns_entities_despawned_after_the_snapshot() {
+    let Fixture { mut world, emitter, pushed } = spawn_fixture();
+    for _ in 0..5 {
+        step(&mut world, emitter, pushed, &[]);
+    }
+    let snapshot = world.snapshot();
+    let log = scripted_inputs();
+    for frame in 0..20 {
+        step(&mut world, emitter, pushed, log.inputs(frame));
+    }
+    let original = world.snapshot();
+
+    world.restore(&snapshot);
+    world.world.despawn(emitter);
+    let respawned = world.restore(&snapshot);
+    let emitter = *respawned.get(&emitter).expect("despawned emitter is respawned");
+    assert_eq!(world.snapshot().content_hash(), snapshot.content_hash());
+
+    for frame in 0..20 {
+        step(&mut world, emitter, pushed, log.inputs(frame));
+    }
+    assert_eq!(world.snapshot().content_hash(), original.content_hash());
+}
+
+#[test]
+fn frame_input_log_discards_later_frames() {
+    let mut log = scripted_inputs();
+    assert_eq!(log.inputs(5).len(), 2);
+    assert_eq!(log.latest_frame(), Some(17));
+    log.discard_from(5);
+    assert!(log.inputs(5).is_empty());
+    assert_eq!(log.latest_frame(), Some(2));
+    log.discard_from(0);
+    assert!(log.is_empty());
+}